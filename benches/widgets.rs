@@ -0,0 +1,88 @@
+//! Benchmarks of the layout and draw paths of the widgets dominating the frame
+//! time of large user interfaces.
+//!
+//! Run them with `cargo bench --bench widgets`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iced_aw::{
+    core::renderer::gradient_bands,
+    menu::{MenuBar, MenuTree},
+    style::gradient::Gradient,
+    testing::Harness,
+    Grid, Wrap,
+};
+use iced_native::{widget::Text, Color, Rectangle, Size};
+
+/// The number of children of the benchmarked containers.
+const CHILDREN: usize = 1_000;
+
+/// The size of the simulated window.
+const WINDOW: Size = Size::new(1280.0, 720.0);
+
+fn grid(c: &mut Criterion) {
+    let grid = (0..CHILDREN).fold(Grid::with_columns(10), |grid, i| {
+        grid.push(Text::new(i.to_string()))
+    });
+    let mut harness = Harness::<()>::new(grid, WINDOW);
+
+    let _ = c.bench_function("grid layout", |b| {
+        b.iter(|| harness.resize(black_box(WINDOW)));
+    });
+}
+
+fn wrap(c: &mut Criterion) {
+    let wrap = Wrap::with_elements(
+        (0..CHILDREN)
+            .map(|i| Text::new(i.to_string()).into())
+            .collect(),
+    );
+    let mut harness = Harness::<()>::new(wrap, WINDOW);
+
+    let _ = c.bench_function("wrap layout", |b| {
+        b.iter(|| harness.resize(black_box(WINDOW)));
+    });
+}
+
+fn menu_bar(c: &mut Criterion) {
+    let menu_bar = MenuBar::new(
+        (0..50)
+            .map(|i| {
+                MenuTree::with_children(
+                    Text::new(format!("Menu {i}")),
+                    (0..20)
+                        .map(|j| MenuTree::new(Text::new(format!("Item {j}"))))
+                        .collect(),
+                )
+            })
+            .collect(),
+    );
+    let mut harness = Harness::<()>::new(menu_bar, WINDOW);
+
+    let _ = c.bench_function("menu bar layout", |b| {
+        b.iter(|| harness.resize(black_box(WINDOW)));
+    });
+    // Open the menu of the first root, so its items are drawn as well
+    let first = harness
+        .bounds_at(&[0])
+        .expect("The menu bar should have a first root")
+        .center();
+    let _ = harness.click(first);
+    let _ = harness.move_cursor(first);
+
+    let _ = c.bench_function("menu bar draw", |b| b.iter(|| harness.draw()));
+}
+
+fn gradient(c: &mut Criterion) {
+    let bounds = Rectangle::new(iced_native::Point::ORIGIN, WINDOW);
+    let gradient = Gradient::new(Color::BLACK, Color::WHITE);
+
+    let _ = c.bench_function("gradient bands", |b| {
+        b.iter(|| {
+            gradient_bands(black_box(bounds), 8.0.into(), &gradient)
+                .map(|(bounds, _, _)| bounds.height)
+                .sum::<f32>()
+        });
+    });
+}
+
+criterion_group!(benches, grid, wrap, menu_bar, gradient);
+criterion_main!(benches);
@@ -0,0 +1,163 @@
+//! Generates the `Icon` enum of `graphics::icons` from the glyph names of the
+//! bundled Bootstrap Icons font, so updating the font is enough to expose its
+//! new icons.
+//!
+//! The enum is only generated with the `icons` feature. Without it the widgets
+//! use the checked-in enum of the icons they require, so the build script does
+//! nothing and its font parser is not even compiled.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "icons")]
+    bootstrap::run();
+}
+
+/// The generation of the `Icon` enum of the full icon set.
+#[cfg(feature = "icons")]
+mod bootstrap {
+    use std::{
+        collections::BTreeMap,
+        env,
+        fmt::Write,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    /// Glyphs whose name in the font differs from the name of their `Icon` variant.
+    const RENAMED: &[(char, &str)] = &[('\u{f532}', "subtract")];
+
+    /// The font containing the full icon set and the names of its glyphs.
+    const BOOTSTRAP_FONT: &str = "src/graphics/fonts/bootstrap-icons.ttf";
+
+    /// Generates the `Icon` enum of the full icon set into `OUT_DIR`.
+    pub(super) fn run() {
+        let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+
+        println!("cargo:rerun-if-changed={BOOTSTRAP_FONT}");
+
+        let bootstrap = read_icons(Path::new(BOOTSTRAP_FONT));
+        write(&out_dir.join("bootstrap.rs"), &bootstrap);
+    }
+
+    /// Generates the icons of the font into the given file, naming every icon
+    /// after its glyph.
+    fn write(path: &Path, icons: &BTreeMap<char, Option<String>>) {
+        let icons: BTreeMap<&str, char> = icons
+            .iter()
+            .filter_map(|(character, name)| {
+                let name = RENAMED
+                    .iter()
+                    .find(|(renamed, _)| renamed == character)
+                    .map(|(_, name)| *name)
+                    .or(name.as_deref())?;
+
+                Some((name, *character))
+            })
+            .collect();
+
+        fs::write(path, generate(&icons))
+            .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+    }
+
+    /// Reads the code points in the private use area of the given font together
+    /// with the names of their glyphs, if the font has any.
+    fn read_icons(path: &Path) -> BTreeMap<char, Option<String>> {
+        let data =
+            fs::read(path).unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+        let face = font_parser::Face::parse(&data, 0)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()));
+        let cmap = face
+            .tables()
+            .cmap
+            .unwrap_or_else(|| panic!("{} has no cmap table", path.display()));
+
+        let mut icons = BTreeMap::new();
+
+        for subtable in cmap.subtables.into_iter().filter(|s| s.is_unicode()) {
+            subtable.codepoints(|codepoint| {
+                let Some(character) = char::from_u32(codepoint) else {
+                    return;
+                };
+                if !('\u{e000}'..='\u{f8ff}').contains(&character) {
+                    return;
+                }
+
+                let name = subtable
+                    .glyph_index(codepoint)
+                    .and_then(|glyph| face.glyph_name(glyph))
+                    .map(str::to_owned);
+
+                let _ = icons.entry(character).or_insert(name);
+            });
+        }
+
+        icons
+    }
+
+    /// Converts a kebab-case glyph name into a `CamelCase` variant name.
+    ///
+    /// Every segment starts with an upper case letter, as does every letter that
+    /// directly follows a digit (`arrow-90deg-up` becomes `Arrow90DegUp`).
+    fn variant_name(name: &str) -> String {
+        let mut variant = String::with_capacity(name.len());
+
+        for segment in name.split('-') {
+            let mut previous: Option<char> = None;
+
+            for character in segment.chars() {
+                if previous.map_or(true, |p| p.is_ascii_digit() && character.is_alphabetic()) {
+                    variant.extend(character.to_uppercase());
+                } else {
+                    variant.push(character);
+                }
+                previous = Some(character);
+            }
+        }
+
+        variant
+    }
+
+    /// Generates the `Icon` enum, its conversion into a `char` and the table of
+    /// the names of all icons.
+    fn generate(icons: &BTreeMap<&str, char>) -> String {
+        let mut code = String::new();
+
+        code.push_str("/// Bootstrap icons\n");
+        code.push_str("#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]\n");
+        code.push_str("pub enum Icon {\n");
+        for name in icons.keys() {
+            let _ = writeln!(code, "    /// {name}");
+            let _ = writeln!(code, "    {},", variant_name(name));
+        }
+        code.push_str("    /// A glyph of a registered icon font, see [`Icon::custom`].\n");
+        code.push_str("    Custom(super::CustomIcon),\n");
+        code.push_str("}\n\n");
+
+        code.push_str("/// Converts an icon into a char.\n");
+        code.push_str("#[must_use]\n");
+        code.push_str("#[allow(clippy::too_many_lines)]\n");
+        code.push_str("pub const fn icon_to_char(icon: Icon) -> char {\n");
+        code.push_str("    match icon {\n");
+        for (name, character) in icons {
+            let _ = writeln!(
+                code,
+                "        Icon::{} => '\\u{{{:x}}}',",
+                variant_name(name),
+                u32::from(*character)
+            );
+        }
+        code.push_str("        Icon::Custom(custom) => custom.codepoint,\n");
+        code.push_str("    }\n");
+        code.push_str("}\n\n");
+
+        code.push_str("/// The names of all icons, sorted by name.\n");
+        code.push_str("pub(super) const ICONS: &[(&str, Icon)] = &[\n");
+        for name in icons.keys() {
+            let _ = writeln!(code, "    (\"{name}\", Icon::{}),", variant_name(name));
+        }
+        code.push_str("];\n");
+
+        code
+    }
+}
@@ -0,0 +1,96 @@
+use iced::{
+    widget::{button, column, container, scrollable, text, toggler},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    accordion::{Mode, State},
+    Accordion,
+};
+
+fn main() -> iced::Result {
+    AccordionExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Toggle(usize),
+    Multiple(bool),
+    Increment,
+}
+
+struct AccordionExample {
+    state: State,
+    count: u32,
+}
+
+impl Sandbox for AccordionExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let mut state = State::new(Mode::Single);
+        state.open(0);
+
+        Self { state, count: 0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Accordion example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Toggle(index) => self.state.toggle(index),
+            Message::Multiple(multiple) => {
+                self.state = State::new(if multiple {
+                    Mode::Multiple
+                } else {
+                    Mode::Single
+                });
+            }
+            Message::Increment => self.count += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let accordion = Accordion::new(&self.state, Message::Toggle)
+            .push(
+                "What is an accordion?",
+                text(
+                    "An accordion is a list of titled sections. \
+                    Pressing the header of a section expands its content.",
+                ),
+            )
+            .push(
+                "Can several sections be open?",
+                text(
+                    "In the single mode opening a section closes the others. \
+                    Switch to the multiple mode to open several sections at once.",
+                ),
+            )
+            .push(
+                "Can the content be interactive?",
+                column![
+                    text(format!("The button was pressed {} times.", self.count)),
+                    button("Press me").on_press(Message::Increment),
+                ]
+                .spacing(10),
+            );
+
+        let mode = toggler(
+            String::from("Open several sections"),
+            self.state.mode() == Mode::Multiple,
+            Message::Multiple,
+        )
+        .width(Length::Shrink);
+
+        container(scrollable(
+            column![mode, accordion].spacing(20).max_width(600),
+        ))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .center_x()
+        .into()
+    }
+}
@@ -0,0 +1,70 @@
+use iced::{
+    widget::{column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    avatar::{AvatarGroup, AvatarSize, Presence, Shape},
+    Avatar,
+};
+
+fn main() -> iced::Result {
+    AvatarExample::run(Settings::default())
+}
+
+const TEAM: [&str; 6] = [
+    "Ada Lovelace",
+    "Grace Hopper",
+    "Alan Turing",
+    "Margaret Hamilton",
+    "Edsger Dijkstra",
+    "Barbara Liskov",
+];
+
+#[derive(Clone, Debug)]
+enum Message {}
+
+struct AvatarExample;
+
+impl Sandbox for AvatarExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn title(&self) -> String {
+        String::from("Avatar example")
+    }
+
+    fn update(&mut self, _message: Self::Message) {}
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let sizes = row![
+            Avatar::new("Ada Lovelace").size(AvatarSize::Small),
+            Avatar::new("Grace Hopper").presence(Presence::Online),
+            Avatar::new("Alan Turing")
+                .size(AvatarSize::Large)
+                .presence(Presence::Busy),
+            Avatar::new("Margaret Hamilton")
+                .size(72.0)
+                .shape(Shape::Rounded)
+                .presence(Presence::Away),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        let team = AvatarGroup::new(TEAM.iter().map(|name| Avatar::new(*name)).collect()).max(4);
+
+        let content = column![text("Sizes and presence"), sizes, text("Team"), team]
+            .spacing(20)
+            .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
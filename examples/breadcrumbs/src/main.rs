@@ -0,0 +1,77 @@
+use iced::{
+    widget::{button, column, container, row, slider, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Breadcrumbs;
+
+fn main() -> iced::Result {
+    BreadcrumbsExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Navigate(usize),
+    Open(&'static str),
+    Width(f32),
+}
+
+struct BreadcrumbsExample {
+    path: Vec<&'static str>,
+    width: f32,
+}
+
+impl Sandbox for BreadcrumbsExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            path: vec!["Home", "Documents", "Projects", "2023", "Reports", "Q4"],
+            width: 600.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Breadcrumbs example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Navigate(index) => self.path.truncate(index + 1),
+            Message::Open(folder) => self.path.push(folder),
+            Message::Width(width) => self.width = width,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let breadcrumbs = Breadcrumbs::new(
+            self.path
+                .iter()
+                .enumerate()
+                .map(|(index, &folder)| (folder, Message::Navigate(index))),
+        )
+        .separator("\u{203A}")
+        .width(self.width);
+
+        let folders = row(["Drafts", "Archive", "Shared"]
+            .into_iter()
+            .map(|folder| button(folder).on_press(Message::Open(folder)).into())
+            .collect())
+        .spacing(10);
+
+        container(
+            column![
+                breadcrumbs,
+                text("Open a folder:"),
+                folders,
+                text(format!("Width: {:.0}", self.width)),
+                slider(100.0..=800.0, self.width, Message::Width),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,79 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{calendar_heatmap::Date, CalendarHeatmap};
+
+fn main() -> iced::Result {
+    CalendarHeatmapExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Day(Date),
+}
+
+struct CalendarHeatmapExample {
+    commits: Vec<(Date, f32)>,
+    status: String,
+}
+
+impl Sandbox for CalendarHeatmapExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        // Some made up activity with days off
+        let commits = (1..=12)
+            .flat_map(|month| (1..=28).map(move |day| Date::from_ymd(2023, month, day)))
+            .enumerate()
+            .map(|(index, date)| {
+                let noise = (index * 7919 + 13) % 17;
+                let value = if noise < 6 { 0 } else { noise - 5 + index % 3 };
+
+                (date, value as f32)
+            })
+            .collect();
+
+        Self {
+            commits,
+            status: String::from("Click on a day"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("CalendarHeatmap example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Day(date) => {
+                let commits = self
+                    .commits
+                    .iter()
+                    .find(|(day, _)| {
+                        (day.year, day.month, day.day) == (date.year, date.month, date.day)
+                    })
+                    .map_or(0.0, |(_, value)| *value);
+
+                self.status = format!("{commits} commits on {date}");
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let heatmap = CalendarHeatmap::new(2023, self.commits.iter().copied())
+            .tooltip(|date, value| format!("{} commits on {date}", value.unwrap_or(0.0)))
+            .on_click(Message::Day);
+
+        let content =
+            column![text("Contributions in 2023"), heatmap, text(&self.status)].spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use iced::{
+    theme,
+    widget::{column, container, text},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Carousel;
+
+fn main() -> iced::Result {
+    CarouselExample::run(Settings::default())
+}
+
+const SLIDES: [(&str, Color); 4] = [
+    ("Sunrise", Color::from_rgb(0.95, 0.6, 0.3)),
+    ("Noon", Color::from_rgb(0.35, 0.65, 0.95)),
+    ("Sunset", Color::from_rgb(0.85, 0.35, 0.45)),
+    ("Midnight", Color::from_rgb(0.2, 0.2, 0.4)),
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Shown(usize),
+}
+
+struct CarouselExample {
+    shown: usize,
+}
+
+impl Sandbox for CarouselExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { shown: 0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Carousel example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Shown(shown) => self.shown = shown,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let slides = SLIDES
+            .iter()
+            .map(|(name, color)| {
+                container(text(*name).size(32).style(Color::WHITE))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y()
+                    .style(theme::Container::Custom(Box::new(Slide(*color))))
+                    .into()
+            })
+            .collect();
+
+        let content = column![
+            Carousel::new(slides, self.shown, Message::Shown)
+                .height(Length::Fixed(240.0))
+                .wrap_around(true)
+                .autoplay(Duration::from_secs(4)),
+            text(format!("Slide {} of {}", self.shown + 1, SLIDES.len())),
+        ]
+        .spacing(20)
+        .width(480)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+/// The colored background of a slide.
+struct Slide(Color);
+
+impl container::StyleSheet for Slide {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(self.0.into()),
+            ..container::Appearance::default()
+        }
+    }
+}
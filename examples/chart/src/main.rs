@@ -0,0 +1,118 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{BarChart, LineChart, PieChart, Series, Slice};
+
+fn main() -> iced::Result {
+    ChartExample::run(Settings::default())
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// The measurements of July were lost
+const BERLIN_TEMPERATURES: [f32; 12] = [
+    0.6,
+    2.3,
+    5.1,
+    10.2,
+    14.8,
+    17.9,
+    f32::NAN,
+    19.3,
+    15.3,
+    10.0,
+    5.3,
+    1.9,
+];
+
+const OSLO_TEMPERATURES: [f32; 12] = [
+    -4.3, -4.0, -0.2, 4.5, 10.8, 15.2, 16.4, 15.2, 10.8, 6.3, 0.7, -3.1,
+];
+
+const ENERGY_SOURCES: [(&str, f32); 5] = [
+    ("Wind", 139.8),
+    ("Solar", 59.9),
+    ("Biomass", 45.1),
+    ("Hydro", 18.4),
+    ("Fossil", 256.2),
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    SourcePressed(usize),
+}
+
+#[derive(Default)]
+struct ChartExample {
+    source: Option<usize>,
+}
+
+impl Sandbox for ChartExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Chart example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::SourcePressed(index) => self.source = Some(index),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let temperatures = LineChart::new(vec![
+            Series::new("Berlin", BERLIN_TEMPERATURES),
+            Series::new("Oslo", OSLO_TEMPERATURES),
+        ])
+        .labels(MONTHS);
+
+        let rainfall = BarChart::new(vec![
+            Series::new("Berlin", [42.0, 33.0, 40.0, 37.0, 54.0, 69.0]),
+            Series::new("Oslo", [49.0, 36.0, 47.0, 41.0, 53.0, 65.0]),
+        ])
+        .labels(MONTHS.into_iter().take(6));
+
+        let total: f32 = ENERGY_SOURCES.iter().map(|(_, value)| value).sum();
+        let energy = PieChart::new(
+            ENERGY_SOURCES
+                .into_iter()
+                .map(|(source, value)| Slice::new(source, value))
+                .collect(),
+        )
+        .donut(0.55)
+        .center(text(format!("{total:.0} TWh")))
+        .on_press(Message::SourcePressed);
+
+        let source = match self.source {
+            Some(index) => format!("Pressed: {}", ENERGY_SOURCES[index].0),
+            None => String::from("Press a slice to select a source"),
+        };
+
+        let content = column![
+            text("Mean temperature in °C"),
+            temperatures,
+            text("Rainfall in mm"),
+            rainfall,
+            text("Electricity generation in TWh"),
+            energy,
+            text(source),
+        ]
+        .spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_y()
+            .into()
+    }
+}
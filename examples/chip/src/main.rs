@@ -0,0 +1,105 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Chip, Wrap};
+
+fn main() -> iced::Result {
+    ChipExample::run(Settings::default())
+}
+
+const DIETS: [&str; 5] = [
+    "Vegetarian",
+    "Vegan",
+    "Gluten free",
+    "Lactose free",
+    "Halal",
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Toggled(usize, bool),
+    Removed(usize),
+    Reset,
+}
+
+struct ChipExample {
+    diets: Vec<bool>,
+    ingredients: Vec<String>,
+}
+
+impl Sandbox for ChipExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            diets: vec![false; DIETS.len()],
+            ingredients: default_ingredients(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Chip example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Toggled(index, selected) => self.diets[index] = selected,
+            Message::Removed(index) => {
+                let _ = self.ingredients.remove(index);
+            }
+            Message::Reset => self.ingredients = default_ingredients(),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let diets = DIETS
+            .iter()
+            .zip(&self.diets)
+            .enumerate()
+            .map(|(index, (diet, selected))| {
+                Chip::new(*diet)
+                    .selectable(*selected, move |selected| Message::Toggled(index, selected))
+                    .into()
+            })
+            .collect();
+
+        let ingredients = self
+            .ingredients
+            .iter()
+            .enumerate()
+            .map(|(index, ingredient)| {
+                Chip::new(ingredient.as_str())
+                    .on_delete(Message::Removed(index))
+                    .into()
+            })
+            .collect();
+
+        let content = column![
+            text("Diets"),
+            Wrap::with_elements(diets).spacing(8.0).line_spacing(8.0),
+            text("Ingredients"),
+            Wrap::with_elements(ingredients)
+                .spacing(8.0)
+                .line_spacing(8.0),
+            Chip::new("Reset ingredients").on_press(Message::Reset),
+        ]
+        .spacing(16)
+        .width(360);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+fn default_ingredients() -> Vec<String> {
+    ["Tomatoes", "Basil", "Mozzarella", "Olive oil", "Garlic"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
@@ -0,0 +1,70 @@
+use iced::{
+    widget::{column, container, row, slider, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{CircularProgress, CircularProgressStyles};
+
+fn main() -> iced::Result {
+    CircularProgressExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Progress(f32),
+}
+
+struct CircularProgressExample {
+    progress: f32,
+}
+
+impl Sandbox for CircularProgressExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { progress: 42.0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("CircularProgress example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Progress(progress) => self.progress = progress,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let style = if self.progress >= 100.0 {
+            CircularProgressStyles::Success
+        } else {
+            CircularProgressStyles::Default
+        };
+
+        let content = column![
+            row![
+                CircularProgress::new(self.progress / 100.0)
+                    .size(96.0)
+                    .thickness(8.0)
+                    .label(format!("{:.0}%", self.progress))
+                    .style(style),
+                CircularProgress::indeterminate(),
+            ]
+            .spacing(40)
+            .align_items(Alignment::Center),
+            slider(0.0..=100.0, self.progress, Message::Progress),
+            text("The small ring spins while the progress is not known"),
+        ]
+        .spacing(20)
+        .width(300)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,102 @@
+use iced::{
+    widget::{column, container, pick_list, row, text},
+    Alignment, Element, Length, Sandbox, Settings, Theme,
+};
+
+use iced_aw::{code_editor::highlighter, CodeEditor};
+
+const CODE: &str = r#"use std::collections::HashMap;
+
+/// Counts how often each word occurs in the text.
+fn count_words(text: &str) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+fn main() {
+    let counts = count_words("the quick brown fox jumps over the lazy dog");
+
+    println!("{counts:?}");
+}
+"#;
+
+fn main() -> iced::Result {
+    CodeEditorExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    CodeChanged(String),
+    ThemeSelected(highlighter::Theme),
+}
+
+struct CodeEditorExample {
+    code: String,
+    theme: highlighter::Theme,
+}
+
+impl Sandbox for CodeEditorExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            code: String::from(CODE),
+            theme: highlighter::Theme::default(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("CodeEditor example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::CodeChanged(code) => self.code = code,
+            Message::ThemeSelected(theme) => self.theme = theme,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let themes = row![
+            text("Theme:"),
+            pick_list(
+                &highlighter::Theme::ALL[..],
+                Some(self.theme),
+                Message::ThemeSelected
+            ),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let editor = CodeEditor::new(&self.code)
+            .on_change(Message::CodeChanged)
+            .highlight("rs", self.theme)
+            .text_size(16.0)
+            .height(Length::Fill);
+
+        let content = column![
+            themes,
+            editor,
+            text(format!("{} lines", self.code.lines().count())).size(14),
+        ]
+        .spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+
+    fn theme(&self) -> Theme {
+        match self.theme {
+            highlighter::Theme::InspiredGitHub | highlighter::Theme::SolarizedLight => Theme::Light,
+            _ => Theme::Dark,
+        }
+    }
+}
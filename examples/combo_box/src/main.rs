@@ -0,0 +1,102 @@
+use iced::{
+    widget::{checkbox, column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{combo_box::Mode, ComboBox};
+
+const COUNTRIES: [&str; 12] = [
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Belgium",
+    "Brazil",
+    "Canada",
+    "Denmark",
+    "France",
+    "Germany",
+    "Netherlands",
+    "New Zealand",
+    "Switzerland",
+];
+
+fn main() -> iced::Result {
+    ComboBoxExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Typed(String),
+    Picked(&'static str),
+    Strict(bool),
+}
+
+struct ComboBoxExample {
+    text: String,
+    picked: Option<&'static str>,
+    strict: bool,
+}
+
+impl Sandbox for ComboBoxExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            picked: None,
+            strict: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("ComboBox example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Typed(text) => self.text = text,
+            Message::Picked(country) => {
+                self.text = country.to_owned();
+                self.picked = Some(country);
+            }
+            Message::Strict(strict) => self.strict = strict,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let mode = if self.strict {
+            Mode::Strict
+        } else {
+            Mode::Free
+        };
+
+        let combo_box = ComboBox::new(
+            &COUNTRIES,
+            "Type a country",
+            &self.text,
+            Message::Typed,
+            Message::Picked,
+        )
+        .mode(mode)
+        .width(Length::Fixed(300.0));
+
+        let picked = text(match self.picked {
+            Some(country) => format!("Picked: {country}"),
+            None => String::from("Nothing picked yet"),
+        });
+
+        container(
+            column![
+                combo_box,
+                picked,
+                checkbox("Strict", self.strict, Message::Strict),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .padding(50)
+        .into()
+    }
+}
@@ -0,0 +1,108 @@
+use iced::{
+    widget::{column, container, row, text, Column},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{DragSource, DropTarget};
+
+const COLUMNS: [&str; 3] = ["To do", "Doing", "Done"];
+
+fn main() -> iced::Result {
+    DragAndDropExample::run(Settings::default())
+}
+
+/// A card dragged from one column to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Card {
+    column: usize,
+    index: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    DragStarted,
+    DragCancelled,
+    Dropped(Card, usize),
+}
+
+struct DragAndDropExample {
+    columns: Vec<Vec<String>>,
+    status: String,
+}
+
+impl Sandbox for DragAndDropExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            columns: vec![
+                vec![
+                    String::from("Write the docs"),
+                    String::from("Add an example"),
+                    String::from("Release"),
+                ],
+                vec![String::from("Review the pull request")],
+                vec![String::from("Fix the build")],
+            ],
+            status: String::from("Drag the cards between the columns"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Drag and drop example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::DragStarted => self.status = String::from("Dragging a card"),
+            Message::DragCancelled => self.status = String::from("Drag cancelled"),
+            Message::Dropped(card, target) => {
+                let title = self.columns[card.column].remove(card.index);
+                self.status = format!("Moved \"{title}\" to {}", COLUMNS[target]);
+                self.columns[target].push(title);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let columns = row(self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(column, cards)| {
+                let cards = Column::with_children(
+                    cards
+                        .iter()
+                        .enumerate()
+                        .map(|(index, title)| {
+                            DragSource::new(
+                                Card { column, index },
+                                container(text(title)).width(Length::Fill).padding(10),
+                            )
+                            .on_drag_start(Message::DragStarted)
+                            .on_cancel(Message::DragCancelled)
+                            .into()
+                        })
+                        .collect(),
+                )
+                .spacing(5);
+
+                DropTarget::new(column![text(COLUMNS[column]).size(24), cards].spacing(10))
+                    // Cards are only moved to other columns
+                    .accept(move |card: &Card| card.column != column)
+                    .on_drop(move |card| Message::Dropped(card, column))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(10)
+                    .into()
+            })
+            .collect())
+        .spacing(20);
+
+        container(column![text(&self.status), columns].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
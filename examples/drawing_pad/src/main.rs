@@ -0,0 +1,111 @@
+use iced::{
+    widget::{button, column, container, image, row, text},
+    Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    drawing_pad::{Drawing, Stroke},
+    DrawingPad,
+};
+
+fn main() -> iced::Result {
+    DrawingPadExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Stroke(Stroke),
+    Undo,
+    Clear,
+    Export,
+}
+
+struct DrawingPadExample {
+    signature: Drawing,
+    exported: Option<image::Handle>,
+    status: String,
+}
+
+impl Sandbox for DrawingPadExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            signature: Drawing::new(),
+            exported: None,
+            status: String::from("Sign with the mouse or a finger"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Drawing pad example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Stroke(stroke) => self.signature.push(stroke),
+            Message::Undo => {
+                let _ = self.signature.undo();
+            }
+            Message::Clear => {
+                self.signature.clear();
+                self.exported = None;
+            }
+            Message::Export => {
+                let points: usize = self
+                    .signature
+                    .strokes()
+                    .iter()
+                    .map(|stroke| stroke.points().len())
+                    .sum();
+
+                self.status = format!(
+                    "Exported {} strokes with {points} points",
+                    self.signature.strokes().len()
+                );
+                self.exported = self.signature.to_image(Color::BLACK, 2.0);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let pad = DrawingPad::new(&self.signature, Message::Stroke)
+            .placeholder("Sign here")
+            .height(Length::Fixed(200.0));
+
+        let has_strokes = !self.signature.is_empty();
+        // The actions are disabled while there is nothing to act on
+        let action = |label, message| {
+            let action = button(label);
+            if has_strokes {
+                action.on_press(message)
+            } else {
+                action
+            }
+        };
+
+        let mut content = column![
+            pad,
+            row![
+                action("Undo", Message::Undo),
+                action("Clear", Message::Clear),
+                action("Export as image", Message::Export),
+            ]
+            .spacing(10),
+            text(&self.status),
+        ]
+        .spacing(20)
+        .max_width(500);
+
+        if let Some(exported) = &self.exported {
+            content = content.push(image(exported.clone()));
+        }
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .into()
+    }
+}
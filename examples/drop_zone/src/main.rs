@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use iced::{
+    widget::{column, container, scrollable, text, Column},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::DropZone;
+
+fn main() -> iced::Result {
+    DropZoneExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    ImagesDropped(Vec<PathBuf>),
+    OtherFilesDropped(Vec<PathBuf>),
+}
+
+#[derive(Default)]
+struct DropZoneExample {
+    images: Vec<PathBuf>,
+    rejected: usize,
+}
+
+impl Sandbox for DropZoneExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("DropZone example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::ImagesDropped(paths) => self.images.extend(paths),
+            Message::OtherFilesDropped(paths) => self.rejected += paths.len(),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let drop_zone = DropZone::new(
+            column![
+                text("Drop images here"),
+                text("PNG, JPEG and GIF files are accepted").size(14),
+            ]
+            .spacing(5)
+            .align_items(Alignment::Center),
+        )
+        .accept(["png", "jpg", "jpeg", "gif"])
+        .on_drop(Message::ImagesDropped)
+        .on_reject(Message::OtherFilesDropped)
+        .height(Length::Fixed(160.0));
+
+        let images = self
+            .images
+            .iter()
+            .fold(Column::new().spacing(5), |column, path| {
+                column.push(text(path.display()))
+            });
+
+        let content = column![
+            drop_zone,
+            text(format!(
+                "{} images dropped, {} other files rejected",
+                self.images.len(),
+                self.rejected
+            )),
+            scrollable(images).height(Length::Fill),
+        ]
+        .spacing(20)
+        .max_width(500);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .into()
+    }
+}
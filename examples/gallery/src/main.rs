@@ -0,0 +1,83 @@
+use iced::{
+    widget::{column, container, image::Handle, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Gallery;
+
+fn main() -> iced::Result {
+    GalleryExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Selected(usize),
+}
+
+struct GalleryExample {
+    images: Vec<Handle>,
+    selected: usize,
+}
+
+impl Sandbox for GalleryExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        // Gradients of different sizes stand in for photos
+        let images = (0..12)
+            .map(|index| gradient(index, 320 + 40 * (index % 4), 240 + 60 * (index % 3)))
+            .collect();
+
+        Self {
+            images,
+            selected: 0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Gallery example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Selected(selected) => self.selected = selected,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let content = column![
+            Gallery::new(self.images.clone(), self.selected, Message::Selected),
+            text(format!(
+                "Image {} of {}, click the gallery and use the arrow keys to browse",
+                self.selected + 1,
+                self.images.len()
+            )),
+        ]
+        .spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
+
+/// Returns an image with a diagonal gradient in a color picked by the index.
+fn gradient(index: u32, width: u32, height: u32) -> Handle {
+    let hue = index as f32 / 12.0;
+    let pixels = (0..height)
+        .flat_map(|y| {
+            (0..width).flat_map(move |x| {
+                let light = (x + y) as f32 / (width + height) as f32;
+                let [r, g, b] = [0.0, 1.0 / 3.0, 2.0 / 3.0].map(|shift: f32| {
+                    let channel = (((hue + shift) * std::f32::consts::TAU).cos() + 1.0) / 2.0;
+                    (255.0 * (0.3 + 0.7 * channel * light)) as u8
+                });
+                [r, g, b, 255]
+            })
+        })
+        .collect::<Vec<u8>>();
+
+    Handle::from_pixels(width, height, pixels)
+}
@@ -0,0 +1,70 @@
+use iced::{
+    widget::{column, container, row, slider, text},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{gauge::Indicator, Gauge};
+
+fn main() -> iced::Result {
+    GaugeExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Temperature(f32),
+}
+
+struct GaugeExample {
+    temperature: f32,
+}
+
+impl Sandbox for GaugeExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { temperature: 64.0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Gauge example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Temperature(temperature) => self.temperature = temperature,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let warning = Color::from_rgb(0.9, 0.6, 0.1);
+        let critical = Color::from_rgb(0.8, 0.2, 0.2);
+
+        let content = column![
+            row![
+                Gauge::new(0.0..=120.0, self.temperature)
+                    .zone(80.0..=100.0, warning)
+                    .zone(100.0..=120.0, critical)
+                    .label(format!("{:.0} °C", self.temperature)),
+                Gauge::new(0.0..=120.0, self.temperature)
+                    .zone(80.0..=100.0, warning)
+                    .zone(100.0..=120.0, critical)
+                    .indicator(Indicator::Needle)
+                    .label(format!("{:.0} °C", self.temperature)),
+            ]
+            .spacing(40)
+            .align_items(Alignment::Center),
+            slider(0.0..=120.0, self.temperature, Message::Temperature),
+            text("Drag the slider to move the indicators"),
+        ]
+        .spacing(20)
+        .width(360)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,74 @@
+use iced::{
+    widget::{column, container, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{core::gesture::Swipe, GestureDetector};
+
+fn main() -> iced::Result {
+    GestureDetectorExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Tapped,
+    DoubleTapped,
+    LongPressed,
+    Swiped(Swipe),
+}
+
+#[derive(Default)]
+struct GestureDetectorExample {
+    last_gesture: Option<String>,
+}
+
+impl Sandbox for GestureDetectorExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("GestureDetector example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        self.last_gesture = Some(match message {
+            Message::Tapped => String::from("Tap"),
+            Message::DoubleTapped => String::from("Double tap"),
+            Message::LongPressed => String::from("Long press"),
+            Message::Swiped(swipe) => format!("Swipe {swipe:?}"),
+        });
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let area = GestureDetector::new(
+            container(text("Touch me"))
+                .width(Length::Fixed(300.0))
+                .height(Length::Fixed(300.0))
+                .center_x()
+                .center_y(),
+        )
+        .on_tap(Message::Tapped)
+        .on_double_tap(Message::DoubleTapped)
+        .on_long_press(Message::LongPressed)
+        .on_swipe(Message::Swiped);
+
+        let last_gesture = text(match &self.last_gesture {
+            Some(gesture) => format!("Last gesture: {gesture}"),
+            None => String::from("No gesture yet"),
+        });
+
+        let content = column![area, last_gesture]
+            .align_items(Alignment::Center)
+            .spacing(20);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
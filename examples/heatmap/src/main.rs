@@ -0,0 +1,114 @@
+use iced::{
+    widget::{column, container, row, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{ColorScale, Heatmap};
+
+fn main() -> iced::Result {
+    HeatmapExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Correlation(usize, usize),
+    Slot(usize, usize),
+}
+
+struct HeatmapExample {
+    status: String,
+}
+
+/// The variables of the correlation matrix.
+const VARIABLES: [&str; 5] = ["Height", "Weight", "Age", "Income", "Sleep"];
+
+/// The correlations between the variables.
+const CORRELATIONS: [[f32; 5]; 5] = [
+    [1.0, 0.72, 0.05, 0.12, -0.08],
+    [0.72, 1.0, 0.31, 0.09, -0.21],
+    [0.05, 0.31, 1.0, 0.44, -0.37],
+    [0.12, 0.09, 0.44, 1.0, -0.15],
+    [-0.08, -0.21, -0.37, -0.15, 1.0],
+];
+
+/// The days of the schedule.
+const DAYS: [&str; 5] = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+
+/// The hours of the schedule.
+const HOURS: [&str; 8] = ["9", "10", "11", "12", "13", "14", "15", "16"];
+
+impl Sandbox for HeatmapExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            status: String::from("Hover a cell to see its value, click it to select it"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Heatmap example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        self.status = match message {
+            Message::Correlation(row, column) => format!(
+                "Correlation of {} and {}: {:.2}",
+                VARIABLES[row], VARIABLES[column], CORRELATIONS[row][column]
+            ),
+            Message::Slot(row, column) => {
+                format!("Selected {} at {}:00", DAYS[row], HOURS[column])
+            }
+        };
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let correlations = Heatmap::new(CORRELATIONS.iter().map(|row| row.to_vec()).collect())
+            .row_labels(VARIABLES)
+            .column_labels(VARIABLES)
+            .scale(ColorScale::Diverging(0.0))
+            .range(-1.0..=1.0)
+            .tooltip(|row, column, value| {
+                format!("{} / {}: {value:.2}", VARIABLES[row], VARIABLES[column])
+            })
+            .on_click(Message::Correlation);
+
+        // Meetings per hour, the lunch hour on friday is left empty
+        let meetings = (0..DAYS.len())
+            .map(|day| {
+                (0..HOURS.len())
+                    .map(|hour| {
+                        if day == 4 && hour == 3 {
+                            f32::NAN
+                        } else {
+                            ((day * 7 + hour * 3) % 5) as f32
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let schedule = Heatmap::new(meetings)
+            .row_labels(DAYS)
+            .column_labels(HOURS)
+            .cell_size(24.0)
+            .on_click(Message::Slot);
+
+        container(
+            column![
+                row![
+                    column![text("Correlations"), correlations].spacing(10),
+                    column![text("Meetings per hour"), schedule].spacing(10),
+                ]
+                .spacing(40),
+                text(&self.status),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,91 @@
+use iced::{
+    widget::{column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Hotkey, HotkeyInput};
+
+fn main() -> iced::Result {
+    HotkeyInputExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Shortcut(usize, Option<Hotkey>),
+}
+
+struct HotkeyInputExample {
+    actions: Vec<(&'static str, Option<Hotkey>)>,
+    status: String,
+}
+
+impl Sandbox for HotkeyInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            actions: vec![
+                ("Open", None),
+                ("Save", None),
+                ("Find", None),
+                ("Toggle sidebar", None),
+            ],
+            status: String::from("Click a field and press a shortcut"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Hotkey input example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Shortcut(index, hotkey) => {
+                // A shortcut can only trigger one action
+                let taken = hotkey.and_then(|hotkey| {
+                    self.actions
+                        .iter()
+                        .enumerate()
+                        .find(|(other, (_, shortcut))| *other != index && *shortcut == Some(hotkey))
+                        .map(|(_, (name, _))| *name)
+                });
+
+                self.status = match (taken, hotkey) {
+                    (Some(name), Some(hotkey)) => {
+                        format!("{hotkey} is already the shortcut of {name}")
+                    }
+                    (_, Some(hotkey)) => {
+                        self.actions[index].1 = Some(hotkey);
+                        format!("{} is now {hotkey}", self.actions[index].0)
+                    }
+                    (_, None) => {
+                        self.actions[index].1 = None;
+                        format!("{} has no shortcut", self.actions[index].0)
+                    }
+                };
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let shortcuts = self.actions.iter().enumerate().fold(
+            column![].spacing(10),
+            |shortcuts, (index, (name, hotkey))| {
+                shortcuts.push(
+                    row![
+                        text(name).width(Length::Fixed(140.0)),
+                        HotkeyInput::new(*hotkey, move |hotkey| Message::Shortcut(index, hotkey)),
+                    ]
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+
+        container(column![shortcuts, text(&self.status)].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
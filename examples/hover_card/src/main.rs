@@ -0,0 +1,96 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{hover_card::Placement, HoverCard};
+
+fn main() -> iced::Result {
+    HoverCardExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Follow,
+    Open,
+}
+
+#[derive(Default)]
+struct HoverCardExample {
+    following: bool,
+    status: String,
+}
+
+impl Sandbox for HoverCardExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            following: false,
+            status: String::from("Hover the user or the link"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("HoverCard example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Follow => {
+                self.following = !self.following;
+                self.status = if self.following {
+                    String::from("Following @ferris")
+                } else {
+                    String::from("Unfollowed @ferris")
+                };
+            }
+            Message::Open => self.status = String::from("Opened the link"),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let following = self.following;
+        let profile = HoverCard::new(text("@ferris"), move || {
+            let follow =
+                button(if following { "Unfollow" } else { "Follow" }).on_press(Message::Follow);
+
+            column![
+                row![text("Ferris").size(24), follow]
+                    .spacing(20)
+                    .align_items(Alignment::Center),
+                text("Crab, Rustacean and mascot of the Rust programming language."),
+                text("1 337 followers"),
+            ]
+            .spacing(8)
+            .into()
+        });
+
+        let link = HoverCard::new(button("rust-lang.org").on_press(Message::Open), || {
+            column![
+                text("Rust Programming Language").size(20),
+                text("A language empowering everyone to build reliable and efficient software."),
+            ]
+            .spacing(4)
+            .into()
+        })
+        .placement(Placement::Top)
+        .alignment(Alignment::Center);
+
+        let content = column![
+            row![text("Posted by"), profile].spacing(5),
+            row![text("Read more at"), link]
+                .spacing(5)
+                .align_items(Alignment::Center),
+            text(&self.status),
+        ]
+        .spacing(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,101 @@
+use iced::{
+    widget::{button, column, container, image::Handle, row, text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use once_cell::sync::Lazy;
+
+use iced_aw::{
+    operation::{self, Id, Zoom},
+    ImageViewer,
+};
+
+static VIEWER: Lazy<Id> = Lazy::new(Id::unique);
+
+fn main() -> iced::Result {
+    ImageViewerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Zoom(Zoom),
+    Zoomed(f32),
+}
+
+struct ImageViewerExample {
+    image: Handle,
+    scale: f32,
+}
+
+impl Application for ImageViewerExample {
+    type Message = Message;
+    type Flags = ();
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Message>) {
+        (
+            Self {
+                image: checkerboard(1600, 1000, 50),
+                scale: 1.0,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("ImageViewer example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Zoom(zoom) => operation::zoom(VIEWER.clone(), zoom),
+            Message::Zoomed(scale) => {
+                self.scale = scale;
+
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let toolbar = row![
+            button("Fit").on_press(Message::Zoom(Zoom::Fit)),
+            button("Fill").on_press(Message::Zoom(Zoom::Fill)),
+            button("1:1").on_press(Message::Zoom(Zoom::Original)),
+            text(format!("{:.0}%", self.scale * 100.0)),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let viewer = ImageViewer::new(self.image.clone())
+            .id(VIEWER.clone())
+            .on_zoom(Message::Zoomed);
+
+        container(column![toolbar, viewer].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
+
+/// Returns a checkerboard image with tiles tinted along the diagonal.
+fn checkerboard(width: u32, height: u32, tile: u32) -> Handle {
+    let pixels = (0..height)
+        .flat_map(|y| {
+            (0..width).flat_map(move |x| {
+                let light = (x + y) as f32 / (width + height) as f32;
+                let shade = if (x / tile + y / tile) % 2 == 0 {
+                    0.3
+                } else {
+                    0.9
+                };
+                let channel = (255.0 * shade) as u8;
+
+                [channel, (255.0 * light) as u8, 255 - channel, 255]
+            })
+        })
+        .collect::<Vec<u8>>();
+
+    Handle::from_pixels(width, height, pixels)
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{column, container, text, Column},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::InfiniteScroll;
+
+const PAGE_SIZE: usize = 30;
+const PAGES: usize = 10;
+
+fn main() -> iced::Result {
+    InfiniteScrollExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    LoadMore,
+    Loaded(Vec<String>),
+}
+
+#[derive(Default)]
+struct InfiniteScrollExample {
+    items: Vec<String>,
+    loading: bool,
+}
+
+/// Loads the page of items starting at the index from a slow source.
+async fn load(start: usize) -> Vec<String> {
+    std::thread::sleep(Duration::from_millis(500));
+
+    (start..start + PAGE_SIZE)
+        .map(|index| format!("Item {index}"))
+        .collect()
+}
+
+impl Application for InfiniteScrollExample {
+    type Message = Message;
+    type Flags = ();
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("InfiniteScroll example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::LoadMore => {
+                self.loading = true;
+
+                Command::perform(load(self.items.len()), Message::Loaded)
+            }
+            Message::Loaded(items) => {
+                self.items.extend(items);
+                self.loading = false;
+
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let items =
+            Column::with_children(self.items.iter().map(|item| text(item).into()).collect())
+                .spacing(10)
+                .width(Length::Fill);
+
+        let mut infinite_scroll = InfiniteScroll::new(items)
+            .loading(self.loading)
+            .footer(text("Loading more items...").size(14));
+
+        // Nothing more is requested once every page is loaded
+        if self.items.len() < PAGE_SIZE * PAGES {
+            infinite_scroll = infinite_scroll.on_load_more(Message::LoadMore);
+        }
+
+        let loaded = text(format!("{} items loaded", self.items.len())).size(14);
+
+        container(column![loaded, infinite_scroll].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
@@ -0,0 +1,94 @@
+use iced::{
+    widget::{column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Knob;
+
+fn main() -> iced::Result {
+    KnobExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Gain(f32),
+    Pan(f32),
+    Octave(f32),
+}
+
+struct KnobExample {
+    gain: f32,
+    pan: f32,
+    octave: f32,
+}
+
+impl Sandbox for KnobExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            gain: -6.0,
+            pan: 0.0,
+            octave: 0.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Knob example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Gain(gain) => self.gain = gain,
+            Message::Pan(pan) => self.pan = pan,
+            Message::Octave(octave) => self.octave = octave,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let gain = column![
+            Knob::new(-60.0..=12.0, self.gain, Message::Gain)
+                .step(0.5)
+                .detents([0.0])
+                .size(64.0),
+            text(format!("Gain {:+.1} dB", self.gain)),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let pan = column![
+            Knob::new(-1.0..=1.0, self.pan, Message::Pan)
+                .detents([0.0])
+                .ticks(3)
+                .size(64.0),
+            text(format!("Pan {:+.2}", self.pan)),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let octave = column![
+            Knob::new(-3.0..=3.0, self.octave, Message::Octave)
+                .step(1.0)
+                .ticks(7)
+                .size(64.0),
+            text(format!("Octave {:+}", self.octave)),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let content = column![
+            row![gain, pan, octave].spacing(40),
+            text("Drag up or down, scroll, or focus a knob and use the arrow keys"),
+            text("Hold Shift while dragging to turn a knob finely"),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{button, column, container, text, Column},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::LoadingOverlay;
+
+fn main() -> iced::Result {
+    LoadingOverlayExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Reload,
+    Loaded(Vec<String>),
+}
+
+#[derive(Default)]
+struct LoadingOverlayExample {
+    orders: Vec<String>,
+    loading: bool,
+}
+
+/// Loads the orders from a slow source.
+async fn load() -> Vec<String> {
+    std::thread::sleep(Duration::from_secs(2));
+
+    (1..=8).map(|index| format!("Order #{index:04}")).collect()
+}
+
+impl Application for LoadingOverlayExample {
+    type Message = Message;
+    type Flags = ();
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Message>) {
+        (
+            Self {
+                orders: Vec::new(),
+                loading: true,
+            },
+            Command::perform(load(), Message::Loaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("LoadingOverlay example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Reload => {
+                self.loading = true;
+
+                Command::perform(load(), Message::Loaded)
+            }
+            Message::Loaded(orders) => {
+                self.orders = orders;
+                self.loading = false;
+
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let orders =
+            Column::with_children(self.orders.iter().map(|order| text(order).into()).collect())
+                .spacing(10);
+
+        let content = column![button("Reload").on_press(Message::Reload), orders]
+            .spacing(20)
+            .align_items(Alignment::Center);
+
+        let screen = container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y();
+
+        LoadingOverlay::new(screen, self.loading)
+            .message("Loading orders...")
+            .into()
+    }
+}
@@ -0,0 +1,97 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{masked_input::Entry, Mask, MaskedInput};
+
+fn main() -> iced::Result {
+    MaskedInputExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    PhoneTyped(Entry),
+    PlateTyped(Entry),
+}
+
+struct MaskedInputExample {
+    phone_mask: Mask,
+    plate_mask: Mask,
+    phone: Entry,
+    plate: Entry,
+}
+
+/// Returns a line describing the entry.
+fn describe(name: &str, entry: &Entry) -> String {
+    format!(
+        "{name}: raw \"{}\"{}",
+        entry.raw,
+        if entry.is_complete { ", complete" } else { "" }
+    )
+}
+
+impl Sandbox for MaskedInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let empty = Entry {
+            masked: String::new(),
+            raw: String::new(),
+            is_complete: false,
+        };
+
+        Self {
+            phone_mask: Mask::new("(###) ###-####"),
+            plate_mask: Mask::new("AA-99-AA"),
+            phone: empty.clone(),
+            plate: empty,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("MaskedInput example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PhoneTyped(entry) => self.phone = entry,
+            Message::PlateTyped(entry) => self.plate = entry,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let phone = MaskedInput::new(
+            &self.phone_mask,
+            &self.phone_mask.placeholder('_'),
+            &self.phone.masked,
+            Message::PhoneTyped,
+        )
+        .width(Length::Fixed(300.0))
+        .padding(8);
+
+        let plate = MaskedInput::new(
+            &self.plate_mask,
+            &self.plate_mask.placeholder('_'),
+            &self.plate.masked,
+            Message::PlateTyped,
+        )
+        .width(Length::Fixed(300.0))
+        .padding(8);
+
+        container(
+            column![
+                phone,
+                text(describe("Phone", &self.phone)),
+                plate,
+                text(describe("Plate", &self.plate)),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .padding(40)
+        .into()
+    }
+}
@@ -0,0 +1,59 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::MenuButton;
+
+fn main() -> iced::Result {
+    MenuButtonExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Picked(&'static str),
+}
+
+const ACTIONS: [&str; 5] = ["Rename", "Duplicate", "Move to...", "Share", "Delete"];
+
+struct MenuButtonExample {
+    last: Option<&'static str>,
+}
+
+impl Sandbox for MenuButtonExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { last: None }
+    }
+
+    fn title(&self) -> String {
+        String::from("MenuButton example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Picked(action) => self.last = Some(action),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let menu_button = MenuButton::new("Actions", &ACTIONS, Message::Picked);
+
+        let content = column![
+            menu_button,
+            text(self.last.map_or_else(
+                || String::from("Pick an action"),
+                |action| format!("Picked: {action}")
+            )),
+        ]
+        .spacing(20);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
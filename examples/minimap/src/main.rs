@@ -0,0 +1,169 @@
+use iced::{
+    widget::{column, container, row, scrollable, text},
+    Application, Color, Command, Element, Length, Point, Rectangle, Settings, Size, Theme,
+};
+use once_cell::sync::Lazy;
+
+use iced_aw::{Minimap, Navigation};
+
+static DOCUMENT: Lazy<scrollable::Id> = Lazy::new(scrollable::Id::unique);
+
+/// The width and the height of the visible part of the document.
+const VIEWPORT: f32 = 400.0;
+
+/// The space between the sections of the document.
+const SPACING: f32 = 10.0;
+
+fn main() -> iced::Result {
+    MinimapExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Scrolled(scrollable::RelativeOffset),
+    Navigate(Navigation),
+}
+
+struct MinimapExample {
+    /// The heights of the sections at a zoom of 100%.
+    sections: Vec<f32>,
+    offset: scrollable::RelativeOffset,
+    zoom: f32,
+}
+
+impl MinimapExample {
+    /// Returns the bounds of the sections in the document.
+    fn section_bounds(&self) -> Vec<Rectangle> {
+        let mut y = 0.0;
+
+        self.sections
+            .iter()
+            .map(|height| {
+                let bounds =
+                    Rectangle::new(Point::new(0.0, y), Size::new(VIEWPORT, height * self.zoom));
+                y += bounds.height + SPACING;
+                bounds
+            })
+            .collect()
+    }
+
+    /// Returns the size of the whole document.
+    fn content(&self) -> Size {
+        let height = self
+            .section_bounds()
+            .last()
+            .map_or(0.0, |last| last.y + last.height);
+
+        Size::new(VIEWPORT, height)
+    }
+
+    /// Returns how far the document can be scrolled.
+    fn scroll_range(&self) -> f32 {
+        (self.content().height - VIEWPORT).max(0.0)
+    }
+}
+
+impl Application for MinimapExample {
+    type Message = Message;
+    type Flags = ();
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Message>) {
+        (
+            Self {
+                sections: (0..30).map(|i| 80.0 + (i * 37 % 5) as f32 * 40.0).collect(),
+                offset: scrollable::RelativeOffset::START,
+                zoom: 1.0,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Minimap example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match message {
+            Message::Scrolled(offset) => {
+                self.offset = offset;
+                Command::none()
+            }
+            Message::Navigate(Navigation::ScrollTo(origin)) => {
+                let range = self.scroll_range();
+                if range > 0.0 {
+                    self.offset.y = origin.y / range;
+                }
+
+                scrollable::snap_to(DOCUMENT.clone(), self.offset)
+            }
+            Message::Navigate(Navigation::Zoom(factor)) => {
+                self.zoom = (self.zoom * factor).clamp(0.5, 3.0);
+
+                // The same part of the document stays in view
+                scrollable::snap_to(DOCUMENT.clone(), self.offset)
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let sections = self.section_bounds().into_iter().enumerate().fold(
+            column![].spacing(SPACING),
+            |sections, (i, bounds)| {
+                sections.push(
+                    container(text(format!("Section {}", i + 1)))
+                        .width(Length::Fixed(bounds.width))
+                        .height(Length::Fixed(bounds.height))
+                        .padding(10)
+                        .style(iced::theme::Container::Box),
+                )
+            },
+        );
+
+        let document = scrollable(sections)
+            .id(DOCUMENT.clone())
+            .height(Length::Fixed(VIEWPORT))
+            .on_scroll(Message::Scrolled);
+
+        let viewport = Rectangle::new(
+            Point::new(0.0, self.offset.y * self.scroll_range()),
+            Size::new(VIEWPORT, VIEWPORT),
+        );
+
+        // Every fifth section is highlighted like a chapter heading
+        let minimap = self.section_bounds().into_iter().enumerate().fold(
+            Minimap::new(self.content(), viewport, Message::Navigate)
+                .width(Length::Fixed(100.0))
+                .height(Length::Fixed(VIEWPORT))
+                .zoomable(true),
+            |minimap, (i, bounds)| {
+                if i % 5 == 0 {
+                    minimap.colored_item(bounds, Color::from_rgb(0.3, 0.6, 0.4))
+                } else {
+                    minimap.item(bounds)
+                }
+            },
+        );
+
+        container(
+            column![
+                row![
+                    container(document).width(Length::Fixed(VIEWPORT + 20.0)),
+                    minimap
+                ]
+                .spacing(20),
+                text(format!(
+                    "Zoom: {:.0}% (scroll over the minimap to zoom)",
+                    self.zoom * 100.0
+                )),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,92 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{month_view::Date, MonthView};
+
+fn main() -> iced::Result {
+    MonthViewExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Day(Date),
+    Meeting(usize),
+    Navigate(Date),
+}
+
+struct MonthViewExample {
+    year: i32,
+    month: u32,
+    selected: Option<Date>,
+    meetings: Vec<(Date, String)>,
+    status: String,
+}
+
+impl Sandbox for MonthViewExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let today = Date::today();
+        let day = |day| Date::from_ymd(today.year, today.month, day);
+
+        Self {
+            year: today.year,
+            month: today.month,
+            selected: None,
+            meetings: vec![
+                (day(3), String::from("Planning")),
+                (day(3), String::from("Design review")),
+                (day(12), String::from("Retrospective")),
+                (day(12), String::from("Lunch with Sam")),
+                (day(12), String::from("Release")),
+                (day(12), String::from("Support rotation")),
+                (day(12), String::from("Demo")),
+                (day(21), String::from("Offsite")),
+            ],
+            status: String::from("Click on a day or a meeting"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("MonthView example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Day(date) => {
+                self.selected = Some(date);
+                self.status = format!("Selected {date}");
+            }
+            Message::Meeting(index) => {
+                let (date, title) = &self.meetings[index];
+                self.status = format!("{title} on {date}");
+            }
+            Message::Navigate(date) => {
+                self.year = date.year;
+                self.month = date.month;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let month_view = self.meetings.iter().enumerate().fold(
+            MonthView::new(self.year, self.month)
+                .selected(self.selected)
+                .on_day(Message::Day)
+                .on_navigate(Message::Navigate),
+            |month_view, (index, (date, title))| {
+                month_view.event(*date, text(title).size(14), Message::Meeting(index))
+            },
+        );
+
+        let content = column![month_view, text(&self.status)].spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
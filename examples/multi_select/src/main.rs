@@ -0,0 +1,65 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::MultiSelect;
+
+const TOPPINGS: [&str; 8] = [
+    "Cheese",
+    "Ham",
+    "Mushrooms",
+    "Olives",
+    "Onions",
+    "Peppers",
+    "Pineapple",
+    "Salami",
+];
+
+fn main() -> iced::Result {
+    MultiSelectExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Selected(Vec<&'static str>),
+}
+
+struct MultiSelectExample {
+    selected: Vec<&'static str>,
+}
+
+impl Sandbox for MultiSelectExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            selected: vec!["Cheese"],
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("MultiSelect example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Selected(selected) => self.selected = selected,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let multi_select = MultiSelect::new(&TOPPINGS, &self.selected, Message::Selected)
+            .placeholder("Choose your toppings")
+            .width(Length::Fixed(250.0));
+
+        let count = text(format!("{} toppings chosen", self.selected.len()));
+
+        container(column![multi_select, count].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .padding(50)
+            .into()
+    }
+}
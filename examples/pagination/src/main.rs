@@ -0,0 +1,84 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Pagination;
+
+/// The number of entries of the list.
+const ENTRIES: usize = 1000;
+
+fn main() -> iced::Result {
+    PaginationExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Page(usize),
+    PageSize(usize),
+}
+
+struct PaginationExample {
+    page: usize,
+    page_size: usize,
+}
+
+impl PaginationExample {
+    fn pages(&self) -> usize {
+        (ENTRIES + self.page_size - 1) / self.page_size
+    }
+}
+
+impl Sandbox for PaginationExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            page: 0,
+            page_size: 25,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Pagination example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Page(page) => self.page = page,
+            Message::PageSize(page_size) => {
+                // Keep the first entry of the current page in view
+                let first = self.page * self.page_size;
+                self.page_size = page_size;
+                self.page = first / page_size;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let first = self.page * self.page_size;
+        let last = (first + self.page_size).min(ENTRIES);
+
+        let pagination = Pagination::new(self.pages(), self.page, Message::Page).page_sizes(
+            vec![10, 25, 50, 100],
+            self.page_size,
+            Message::PageSize,
+        );
+
+        container(
+            column![
+                text(format!(
+                    "Showing entries {} to {} of {ENTRIES}",
+                    first + 1,
+                    last
+                )),
+                pagination,
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
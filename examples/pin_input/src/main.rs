@@ -0,0 +1,71 @@
+use iced::{
+    widget::{checkbox, column, container, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::PinInput;
+
+fn main() -> iced::Result {
+    PinInputExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Typed(String),
+    Completed(String),
+    MaskedToggled(bool),
+}
+
+#[derive(Default)]
+struct PinInputExample {
+    code: String,
+    completed: Option<String>,
+    masked: bool,
+}
+
+impl Sandbox for PinInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("PinInput example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Typed(code) => {
+                self.code = code;
+                self.completed = None;
+            }
+            Message::Completed(code) => self.completed = Some(code),
+            Message::MaskedToggled(masked) => self.masked = masked,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let pin_input = PinInput::new(6, &self.code, Message::Typed)
+            .on_complete(Message::Completed)
+            .masked(self.masked);
+
+        let masked = checkbox("Hide the code", self.masked, Message::MaskedToggled);
+
+        let status = text(match &self.completed {
+            Some(code) => format!("Entered the code {code}"),
+            None => String::from("Type or paste a six digit code"),
+        });
+
+        container(
+            column![pin_input, masked, status]
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,89 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{popover::Placement, Popover};
+
+fn main() -> iced::Result {
+    PopoverExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    TogglePopover,
+    ClosePopover,
+    Placement(Placement),
+}
+
+#[derive(Default)]
+struct PopoverExample {
+    show_popover: bool,
+    placement: Placement,
+}
+
+impl Sandbox for PopoverExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Popover example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::TogglePopover => self.show_popover = !self.show_popover,
+            Message::ClosePopover => self.show_popover = false,
+            Message::Placement(placement) => self.placement = placement,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let placements = row(vec![
+            button("Top")
+                .on_press(Message::Placement(Placement::Top))
+                .into(),
+            button("Bottom")
+                .on_press(Message::Placement(Placement::Bottom))
+                .into(),
+            button("Left")
+                .on_press(Message::Placement(Placement::Left))
+                .into(),
+            button("Right")
+                .on_press(Message::Placement(Placement::Right))
+                .into(),
+        ])
+        .spacing(10);
+
+        let popover = Popover::new(
+            self.show_popover,
+            button("Toggle popover").on_press(Message::TogglePopover),
+            || {
+                column(vec![
+                    text("Some content in a bubble").into(),
+                    button("Close").on_press(Message::ClosePopover).into(),
+                ])
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into()
+            },
+        )
+        .placement(self.placement)
+        .arrow(8.0)
+        .on_dismiss(Message::ClosePopover);
+
+        container(
+            column(vec![placements.into(), popover.into()])
+                .spacing(100)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,87 @@
+use iced::{
+    widget::{button, checkbox, column, container, scrollable, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Portal;
+
+fn main() -> iced::Result {
+    PortalExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    UsePortal(bool),
+    Pressed(usize),
+}
+
+#[derive(Default)]
+struct PortalExample {
+    use_portal: bool,
+    last_pressed: Option<usize>,
+}
+
+impl Sandbox for PortalExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Portal example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::UsePortal(use_portal) => self.use_portal = use_portal,
+            Message::Pressed(index) => self.last_pressed = Some(index),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let items = (0..20).map(|index| {
+            let item: Element<'_, Message> = button(text(format!("Item {index}")))
+                .on_press(Message::Pressed(index))
+                .width(Length::Fill)
+                .into();
+
+            // The fifth item stays fully visible when it is scrolled out
+            if index == 4 && self.use_portal {
+                Portal::new(item).into()
+            } else {
+                item
+            }
+        });
+
+        let list = container(
+            scrollable(column(items.collect()).spacing(10).padding(10)).height(Length::Fill),
+        )
+        .width(Length::Fixed(200.0))
+        .height(Length::Fixed(200.0));
+
+        let last_pressed = text(match self.last_pressed {
+            Some(index) => format!("Last pressed: Item {index}"),
+            None => String::from("Nothing pressed yet"),
+        });
+
+        let content = column![
+            checkbox(
+                "Use a portal for item 4",
+                self.use_portal,
+                Message::UsePortal
+            ),
+            list,
+            last_pressed,
+        ]
+        .align_items(Alignment::Center)
+        .spacing(20);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,111 @@
+use iced::{
+    widget::{column, container, scrollable, text},
+    Color, Element, Length, Renderer, Sandbox, Settings,
+};
+
+use iced_aw::{
+    property_grid::{Action, Property, State, Value},
+    PropertyGrid,
+};
+
+fn main() -> iced::Result {
+    PropertyGridExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Property(Action),
+}
+
+struct PropertyGridExample {
+    state: State,
+    object: Vec<Property>,
+    transform: Vec<Property>,
+    appearance: Vec<Property>,
+    status: String,
+}
+
+impl Sandbox for PropertyGridExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            state: State::new(),
+            object: vec![
+                Property::new("Name", Value::Text(String::from("Rectangle 1"))),
+                Property::new("Visible", Value::Bool(true)),
+                Property::new(
+                    "Kind",
+                    Value::Choice {
+                        options: vec![
+                            String::from("Rectangle"),
+                            String::from("Ellipse"),
+                            String::from("Polygon"),
+                        ],
+                        selected: 0,
+                    },
+                ),
+            ],
+            transform: vec![
+                Property::new("X", Value::Number(40.0)).range(-1000.0, 1000.0),
+                Property::new("Y", Value::Number(25.0)).range(-1000.0, 1000.0),
+                Property::new("Rotation", Value::Number(0.0))
+                    .range(0.0, 360.0)
+                    .step(15.0),
+            ],
+            appearance: vec![
+                Property::new("Fill", Value::Color(Color::from_rgb(0.2, 0.5, 0.9))),
+                Property::new("Stroke", Value::Color(Color::BLACK)),
+                Property::new("Opacity", Value::Number(1.0))
+                    .range(0.0, 1.0)
+                    .step(0.1),
+            ],
+            status: String::from("Edit a property"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("PropertyGrid example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Property(action) => {
+                if let Some((name, value)) = self.state.update(action) {
+                    self.status = format!("{name} changed to {value:?}");
+
+                    if let Some(property) = self
+                        .object
+                        .iter_mut()
+                        .chain(&mut self.transform)
+                        .chain(&mut self.appearance)
+                        .find(|property| property.name() == name)
+                    {
+                        property.set_value(value);
+                    }
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let grid: PropertyGrid<'_, Message, Renderer> =
+            PropertyGrid::new(&self.state, Message::Property);
+
+        let grid = grid
+            .section("Object", &self.object)
+            .section("Transform", &self.transform)
+            .section("Appearance", &self.appearance)
+            .width(Length::Fixed(360.0));
+
+        let content =
+            column![scrollable(grid).height(Length::Fill), text(&self.status)].spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .into()
+    }
+}
@@ -0,0 +1,136 @@
+use iced::{
+    widget::{button, column, container, image, pick_list, row, slider, text, text_input},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    qr_code::{self, Data},
+    ErrorCorrection, QrCode,
+};
+
+const LEVELS: [Level; 4] = [
+    Level(ErrorCorrection::Low),
+    Level(ErrorCorrection::Medium),
+    Level(ErrorCorrection::Quartile),
+    Level(ErrorCorrection::High),
+];
+
+fn main() -> iced::Result {
+    QrCodeExample::run(Settings::default())
+}
+
+/// An error correction listed in the pick list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Level(ErrorCorrection);
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    DataChanged(String),
+    LevelPicked(Level),
+    ModuleSizeChanged(f32),
+    Export,
+}
+
+struct QrCodeExample {
+    input: String,
+    level: Level,
+    module_size: f32,
+    data: Result<Data, qr_code::Error>,
+    exported: Option<image::Handle>,
+}
+
+impl QrCodeExample {
+    fn encode(&mut self) {
+        self.data = Data::with_error_correction(&self.input, self.level.0);
+        self.exported = None;
+    }
+}
+
+impl Sandbox for QrCodeExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let input = String::from("https://github.com/iced-rs/iced_aw");
+        let level = Level(ErrorCorrection::High);
+
+        Self {
+            data: Data::with_error_correction(&input, level.0),
+            input,
+            level,
+            module_size: 6.0,
+            exported: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("QrCode example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::DataChanged(input) => {
+                self.input = input;
+                self.encode();
+            }
+            Message::LevelPicked(level) => {
+                self.level = level;
+                self.encode();
+            }
+            Message::ModuleSizeChanged(module_size) => self.module_size = module_size,
+            Message::Export => {
+                self.exported = self
+                    .data
+                    .as_ref()
+                    .ok()
+                    .map(|data| data.to_image(4, 4, Color::BLACK, Color::WHITE));
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let controls = column![
+            text_input("Data", &self.input).on_input(Message::DataChanged),
+            row![
+                text("Error correction"),
+                pick_list(&LEVELS[..], Some(self.level), Message::LevelPicked),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            row![
+                text("Module size"),
+                slider(2.0..=10.0, self.module_size, Message::ModuleSizeChanged),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            button("Export as image").on_press(Message::Export),
+        ]
+        .spacing(10)
+        .width(Length::Fixed(300.0));
+
+        let code: Element<'_, Message> = match &self.data {
+            Ok(data) => QrCode::new(data)
+                .module_size(self.module_size)
+                .logo(container(text("aw").size(24)).padding(4))
+                .into(),
+            Err(error) => text(format!("The data cannot be encoded: {error}")).into(),
+        };
+
+        let mut content = row![controls, code].spacing(40);
+        if let Some(exported) = &self.exported {
+            content = content.push(image(exported.clone()));
+        }
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,73 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Icon, RadialItem, RadialMenu};
+
+fn main() -> iced::Result {
+    RadialMenuExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Tool(&'static str),
+    Clear,
+    Dismissed,
+}
+
+struct RadialMenuExample {
+    status: String,
+}
+
+impl Sandbox for RadialMenuExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            status: String::from("No tool selected"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("RadialMenu example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        self.status = match message {
+            Message::Tool(tool) => format!("Selected the {tool}"),
+            Message::Clear => String::from("Cleared the canvas"),
+            Message::Dismissed => String::from("Closed the menu without picking a tool"),
+        };
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let shapes = vec![
+            RadialItem::new(Icon::Circle, "Circle", Message::Tool("circle")),
+            RadialItem::new(Icon::Square, "Square", Message::Tool("square")),
+            RadialItem::new(Icon::Triangle, "Triangle", Message::Tool("triangle")),
+        ];
+
+        let items = vec![
+            RadialItem::new(Icon::Pencil, "Pencil", Message::Tool("pencil")),
+            RadialItem::new(Icon::Brush, "Brush", Message::Tool("brush")),
+            RadialItem::new(Icon::Type, "Text", Message::Tool("text tool")),
+            RadialItem::submenu(Icon::StarFill, "Shapes", shapes),
+            RadialItem::new(Icon::Trash, "Clear", Message::Clear),
+        ];
+
+        let canvas = container(text("Right click or long press to pick a tool"))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y();
+
+        let menu = RadialMenu::new(canvas, items).on_dismiss(Message::Dismissed);
+
+        container(column![menu, text(&self.status)].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
@@ -0,0 +1,78 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::RangeSlider;
+
+fn main() -> iced::Result {
+    RangeSliderExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Price((u32, u32)),
+    Released,
+}
+
+const PRODUCTS: [(&str, u32); 6] = [
+    ("Keyboard", 80),
+    ("Mouse", 40),
+    ("Monitor", 320),
+    ("Headphones", 150),
+    ("Webcam", 90),
+    ("Desk", 480),
+];
+
+struct RangeSliderExample {
+    price: (u32, u32),
+    filter: (u32, u32),
+}
+
+impl Sandbox for RangeSliderExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            price: (50, 400),
+            filter: (50, 400),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Range slider example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Price(price) => self.price = price,
+            // Filter the products only once the handle is released
+            Message::Released => self.filter = self.price,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let (min, max) = self.price;
+
+        let slider = RangeSlider::new(0..=500, self.price, Message::Price)
+            .step(10)
+            .on_release(Message::Released);
+
+        let products = PRODUCTS
+            .iter()
+            .filter(|(_, price)| (self.filter.0..=self.filter.1).contains(price))
+            .fold(column![].spacing(5), |products, (name, price)| {
+                products.push(text(format!("{name}: ${price}")))
+            });
+
+        container(
+            column![text(format!("Price: ${min} - ${max}")), slider, products]
+                .spacing(20)
+                .max_width(400),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,71 @@
+use iced::{
+    widget::{column, container, row, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Icon, Rating};
+
+fn main() -> iced::Result {
+    RatingExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Rated(f32),
+    Liked(f32),
+}
+
+struct RatingExample {
+    rating: f32,
+    likes: f32,
+}
+
+impl Sandbox for RatingExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            rating: 3.5,
+            likes: 2.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Rating example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Rated(rating) => self.rating = rating,
+            Message::Liked(likes) => self.likes = likes,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let stars = Rating::new(self.rating)
+            .half_steps(true)
+            .size(32.0)
+            .on_change(Message::Rated);
+
+        let hearts = Rating::new(self.likes)
+            .icon(Icon::HeartFill)
+            .count(3)
+            .on_change(Message::Liked);
+
+        // Read-only ratings show any fraction of a point
+        let average = Rating::new(4.3);
+
+        container(
+            column![
+                row![stars, text(format!("{:.1}", self.rating))].spacing(10),
+                hearts,
+                row![average, text("4.3 on average")].spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
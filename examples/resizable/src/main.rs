@@ -0,0 +1,99 @@
+use iced::{
+    widget::{column, container, row, text},
+    Element, Length, Sandbox, Settings, Size,
+};
+
+use iced_aw::{resizable::Handle, Resizable};
+
+fn main() -> iced::Result {
+    ResizableExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NoteResized(Size),
+    PanelResized(Size),
+}
+
+struct ResizableExample {
+    note: Size,
+    panel: Size,
+}
+
+impl Sandbox for ResizableExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            note: Size::new(220.0, 160.0),
+            panel: Size::new(260.0, 200.0),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Resizable example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::NoteResized(size) => self.note = size,
+            Message::PanelResized(size) => self.panel = size,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let note = Resizable::new(
+            container(text(
+                "Drag the right or the bottom edge, or the corner, to resize this note.",
+            ))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(10)
+            .style(iced::theme::Container::Box),
+            self.note,
+            Message::NoteResized,
+        )
+        .min_size(Size::new(120.0, 80.0))
+        .max_size(Size::new(400.0, 300.0));
+
+        let panel = Resizable::new(
+            container(text(format!(
+                "This panel is {:.0} × {:.0} and can be resized from every side.",
+                self.panel.width, self.panel.height
+            )))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(10)
+            .style(iced::theme::Container::Box),
+            self.panel,
+            Message::PanelResized,
+        )
+        .handles([
+            Handle::Top,
+            Handle::Bottom,
+            Handle::Left,
+            Handle::Right,
+            Handle::TopLeft,
+            Handle::TopRight,
+            Handle::BottomLeft,
+            Handle::BottomRight,
+        ])
+        .min_size(Size::new(160.0, 120.0));
+
+        let content = column![
+            text(format!(
+                "The note is {:.0} × {:.0}",
+                self.note.width, self.note.height
+            )),
+            row![note, panel].spacing(20),
+        ]
+        .spacing(20);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
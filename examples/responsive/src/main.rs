@@ -0,0 +1,69 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{graphics::icons::Icon, split::Axis, Responsive, Split, TabBar, TabLabel};
+
+fn main() -> iced::Result {
+    ResponsiveExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    TabSelected(usize),
+    Resized(u16),
+}
+
+#[derive(Default)]
+struct ResponsiveExample {
+    active_tab: usize,
+    divider_position: Option<u16>,
+}
+
+impl Sandbox for ResponsiveExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Responsive example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::TabSelected(index) => self.active_tab = index,
+            Message::Resized(position) => self.divider_position = Some(position),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Responsive::new(|breakpoint| {
+            let tab_bar = TabBar::new(Message::TabSelected)
+                .push(0, TabLabel::from((Icon::House, "Home")))
+                .push(1, TabLabel::from((Icon::Envelope, "Messages")))
+                .push(2, TabLabel::from((Icon::Gear, "Settings")))
+                .set_active_tab(&self.active_tab)
+                .breakpoint(breakpoint);
+
+            let split = Split::new(
+                container(text("Navigation")).padding(10),
+                container(text(format!("Content for a {breakpoint:?} layout")))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y(),
+                self.divider_position,
+                Axis::Vertical,
+                Message::Resized,
+            )
+            .min_size_first(20)
+            .breakpoint(breakpoint);
+
+            column![tab_bar, split].into()
+        })
+        .into()
+    }
+}
@@ -0,0 +1,92 @@
+use iced::{
+    alignment::Horizontal,
+    widget::{column, container, text},
+    Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{RichText, Span};
+
+fn main() -> iced::Result {
+    RichTextExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    LinkPressed(&'static str),
+}
+
+#[derive(Default)]
+struct RichTextExample {
+    pressed: Option<&'static str>,
+}
+
+impl Sandbox for RichTextExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("RichText example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::LinkPressed(link) => self.pressed = Some(link),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let title = RichText::new(vec![
+            Span::new("Rich").size(40.0).bold(),
+            Span::new("Text")
+                .size(40.0)
+                .color(Color::from_rgb(0.2, 0.5, 0.9)),
+        ])
+        .width(Length::Fill)
+        .horizontal_alignment(Horizontal::Center);
+
+        let paragraph = RichText::new(vec![
+            Span::new("A rich text is made of spans, which can be "),
+            Span::new("bold").bold(),
+            Span::new(", "),
+            Span::new("colored").color(Color::from_rgb(0.8, 0.3, 0.3)),
+            Span::new(", "),
+            Span::new("larger").size(28.0),
+            Span::new(" or "),
+            Span::new("underlined").underline(),
+            Span::new(". Unlike a row of texts, the spans wrap as a whole, so a long paragraph flows "),
+            Span::new("across").color(Color::from_rgb(0.3, 0.6, 0.3)),
+            Span::new(" the lines while keeping the style of each word. Words made of several spans, like "),
+            Span::new("iced").bold(),
+            Span::new("_aw"),
+            Span::new(", stay together.\n\n"),
+            Span::new("Spans can also be links to "),
+            Span::new("the iced website").link(Message::LinkPressed("https://iced.rs")),
+            Span::new(" or "),
+            Span::new("the repository").link(Message::LinkPressed(
+                "https://github.com/iced-rs/iced_aw",
+            )),
+            Span::new("."),
+        ])
+        .width(Length::Fill);
+
+        let pressed = text(self.pressed.map_or_else(
+            || String::from("No link pressed"),
+            |link| format!("Pressed {link}"),
+        ))
+        .size(14);
+
+        let content = column![title, paragraph, pressed]
+            .spacing(20)
+            .max_width(500);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .into()
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Scrubber;
+
+fn main() -> iced::Result {
+    ScrubberExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Seek(Duration),
+    Preview(Duration),
+}
+
+struct ScrubberExample {
+    position: Duration,
+    status: String,
+}
+
+/// The length of the example movie.
+const DURATION: Duration = Duration::from_secs(95 * 60);
+
+/// The start and the title of each chapter of the example movie.
+const CHAPTERS: [(u64, &str); 4] = [
+    (0, "Opening"),
+    (12 * 60, "The journey"),
+    (48 * 60, "The storm"),
+    (81 * 60, "Homecoming"),
+];
+
+impl Sandbox for ScrubberExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            position: Duration::from_secs(30 * 60),
+            status: String::from("Drag the handle or focus the timeline and use the arrow keys"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Scrubber example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Seek(position) => {
+                self.position = position;
+                self.status = format!("Sought to {:.2}s", position.as_secs_f64());
+            }
+            Message::Preview(position) => {
+                self.status = format!("Previewing {:.2}s", position.as_secs_f64());
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let scrubber = CHAPTERS.iter().fold(
+            Scrubber::new(DURATION, self.position, Message::Seek)
+                .buffered(Duration::ZERO..Duration::from_secs(40 * 60))
+                .buffered(Duration::from_secs(60 * 60)..Duration::from_secs(70 * 60))
+                .on_preview(Message::Preview)
+                .frame_rate(24.0)
+                .width(Length::Fixed(600.0)),
+            |scrubber, (start, title)| scrubber.chapter(Duration::from_secs(*start), *title),
+        );
+
+        container(
+            column![
+                text(format!(
+                    "Position: {:.2}s of {}s",
+                    self.position.as_secs_f64(),
+                    DURATION.as_secs()
+                )),
+                scrubber,
+                text(&self.status),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
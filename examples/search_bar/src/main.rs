@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{column, container, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::SearchBar;
+
+const FRUITS: [&str; 12] = [
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blackberry",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+];
+
+fn main() -> iced::Result {
+    SearchBarExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Typed(String),
+    Search(String),
+    Found(Vec<String>),
+    Picked(String),
+}
+
+#[derive(Default)]
+struct SearchBarExample {
+    query: String,
+    results: Vec<String>,
+    loading: bool,
+    picked: Option<String>,
+}
+
+/// Searches the fruits containing the query in a slow source.
+async fn search(query: String) -> Vec<String> {
+    std::thread::sleep(Duration::from_millis(400));
+
+    let query = query.to_lowercase();
+    FRUITS
+        .iter()
+        .filter(|fruit| fruit.to_lowercase().contains(&query))
+        .map(|fruit| (*fruit).to_owned())
+        .collect()
+}
+
+impl Application for SearchBarExample {
+    type Message = Message;
+    type Flags = ();
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("SearchBar example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Typed(query) => {
+                self.query = query;
+
+                Command::none()
+            }
+            Message::Search(query) if query.is_empty() => {
+                self.results.clear();
+                self.loading = false;
+
+                Command::none()
+            }
+            Message::Search(query) => {
+                self.loading = true;
+
+                Command::perform(search(query), Message::Found)
+            }
+            Message::Found(results) => {
+                self.results = results;
+                self.loading = false;
+
+                Command::none()
+            }
+            Message::Picked(fruit) => {
+                self.query = fruit.clone();
+                self.picked = Some(fruit);
+
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let search_bar = SearchBar::new("Search a fruit", &self.query, Message::Typed)
+            .on_search(Message::Search)
+            .suggestions(&self.results, Message::Picked)
+            .loading(self.loading)
+            .width(Length::Fixed(300.0));
+
+        let picked = text(match &self.picked {
+            Some(fruit) => format!("Picked: {fruit}"),
+            None => String::from("Type to search and pick a fruit"),
+        });
+
+        container(column![search_bar, picked].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .padding(40)
+            .into()
+    }
+}
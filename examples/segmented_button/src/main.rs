@@ -0,0 +1,78 @@
+use iced::{
+    widget::{column, container, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{segmented_button::Segment, SegmentedButton};
+
+fn main() -> iced::Result {
+    SegmentedButtonExample::run(Settings::default())
+}
+
+const PERIODS: [&str; 4] = ["Day", "Week", "Month", "Year"];
+
+const DAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Period(usize),
+    Days(Vec<usize>),
+}
+
+struct SegmentedButtonExample {
+    period: usize,
+    days: Vec<usize>,
+}
+
+impl Sandbox for SegmentedButtonExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            period: 1,
+            days: vec![0, 2, 4],
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("SegmentedButton example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Period(period) => self.period = period,
+            Message::Days(days) => self.days = days,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        // The yearly view is not available yet
+        let periods = PERIODS
+            .iter()
+            .map(|period| Segment::new(*period).disabled(*period == "Year"))
+            .collect();
+
+        let days = DAYS.iter().map(|day| Segment::new(*day)).collect();
+
+        let selected_days: Vec<&str> = self.days.iter().map(|&day| DAYS[day]).collect();
+
+        let content = column![
+            SegmentedButton::new(periods, Some(self.period), Message::Period),
+            text(format!(
+                "Showing one {}",
+                PERIODS[self.period].to_lowercase()
+            )),
+            SegmentedButton::multiple(days, &self.days, Message::Days).padding(6.0),
+            text(format!("Repeating on: {}", selected_days.join(", "))),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,118 @@
+use iced::{
+    keyboard::{KeyCode, Modifiers},
+    widget::{button, column, container, horizontal_space, radio, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{hotkey_input::Platform, Hotkey, ShortcutHint, Tooltip};
+
+fn main() -> iced::Result {
+    ShortcutHintExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Platform(Platform),
+    Action(&'static str),
+}
+
+struct ShortcutHintExample {
+    platform: Platform,
+    status: String,
+}
+
+/// Returns the actions of the menu and their shortcuts.
+fn actions() -> [(&'static str, Hotkey); 4] {
+    [
+        ("New file", Hotkey::new(KeyCode::N, Modifiers::COMMAND)),
+        (
+            "Save as…",
+            Hotkey::new(KeyCode::S, Modifiers::COMMAND | Modifiers::SHIFT),
+        ),
+        (
+            "Command palette",
+            Hotkey::new(KeyCode::P, Modifiers::COMMAND | Modifiers::SHIFT),
+        ),
+        ("Go to line", Hotkey::new(KeyCode::G, Modifiers::CTRL)),
+    ]
+}
+
+impl Sandbox for ShortcutHintExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            platform: Platform::current(),
+            status: String::from("Pick an action"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Shortcut hint example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Platform(platform) => self.platform = platform,
+            Message::Action(name) => self.status = format!("{name} was picked"),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let platforms = [
+            ("macOS", Platform::MacOs),
+            ("Windows", Platform::Windows),
+            ("Linux", Platform::Linux),
+        ]
+        .into_iter()
+        .fold(row![].spacing(20), |platforms, (label, platform)| {
+            platforms.push(radio(
+                label,
+                platform,
+                Some(self.platform),
+                Message::Platform,
+            ))
+        });
+
+        // The hints sit at the end of the menu items, like in a menu bar
+        let menu = actions().into_iter().fold(
+            column![].width(Length::Fixed(320.0)),
+            |menu, (name, hotkey)| {
+                let hint = ShortcutHint::new(hotkey).platform(self.platform);
+
+                menu.push(
+                    button(
+                        row![text(name), horizontal_space(Length::Fill), hint]
+                            .align_items(Alignment::Center),
+                    )
+                    .width(Length::Fill)
+                    .style(iced::theme::Button::Text)
+                    .on_press(Message::Action(name)),
+                )
+            },
+        );
+
+        let platform = self.platform;
+        let chord = Tooltip::new(
+            button("Keyboard shortcuts").on_press(Message::Action("Keyboard shortcuts")),
+            move || {
+                row![
+                    text("Open the keyboard shortcuts with"),
+                    ShortcutHint::from_keys(["Ctrl", "K", "S"])
+                        .platform(platform)
+                        .separator(""),
+                ]
+                .spacing(8)
+                .align_items(Alignment::Center)
+                .into()
+            },
+        );
+
+        container(column![platforms, menu, chord, text(&self.status)].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,83 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{skeleton, Skeleton};
+
+fn main() -> iced::Result {
+    SkeletonExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Load,
+    Unload,
+}
+
+struct Profile {
+    name: String,
+    bio: String,
+}
+
+struct SkeletonExample {
+    profile: Option<Profile>,
+}
+
+impl Sandbox for SkeletonExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { profile: None }
+    }
+
+    fn title(&self) -> String {
+        String::from("Skeleton example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Load => {
+                self.profile = Some(Profile {
+                    name: String::from("Ada Lovelace"),
+                    bio: String::from(
+                        "Wrote the first algorithm intended to be carried out by a machine.",
+                    ),
+                });
+            }
+            Message::Unload => self.profile = None,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let profile = skeleton::loaded(
+            self.profile.as_ref(),
+            |profile| column![text(&profile.name).size(24), text(&profile.bio)].spacing(8),
+            || {
+                column![
+                    Skeleton::line().width(Length::Fixed(160.0)),
+                    skeleton::paragraph(3),
+                    Skeleton::rectangle(),
+                    skeleton::list_item(),
+                    skeleton::list_item(),
+                ]
+                .spacing(16)
+            },
+        );
+
+        let toggle = if self.profile.is_some() {
+            button("Reload").on_press(Message::Unload)
+        } else {
+            button("Load").on_press(Message::Load)
+        };
+
+        let content = column![row![toggle], profile].spacing(20).width(400);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,66 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::menu_item, menu::split_button::SplitButton};
+
+fn main() -> iced::Result {
+    SplitButtonExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Save,
+    SaveAs,
+    SaveAll,
+    Export,
+}
+
+struct SplitButtonExample {
+    last: &'static str,
+}
+
+impl Sandbox for SplitButtonExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            last: "Nothing yet",
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("SplitButton example")
+    }
+
+    fn update(&mut self, message: Message) {
+        self.last = match message {
+            Message::Save => "Saved",
+            Message::SaveAs => "Saved as...",
+            Message::SaveAll => "Saved all",
+            Message::Export => "Exported",
+        };
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let split_button = SplitButton::new(
+            text("Save"),
+            vec![
+                menu_item(text("Save as..."), Message::SaveAs),
+                menu_item(text("Save all"), Message::SaveAll),
+                menu_item(text("Export"), Message::Export),
+            ],
+        )
+        .on_press(Message::Save);
+
+        let content = column![split_button, text(self.last)].spacing(20);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
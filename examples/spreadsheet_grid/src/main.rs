@@ -0,0 +1,111 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    spreadsheet_grid::{Cell, Selection},
+    SpreadsheetGrid,
+};
+
+fn main() -> iced::Result {
+    SpreadsheetGridExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Edited(Cell, String),
+    Selected(Selection),
+}
+
+struct SpreadsheetGridExample {
+    cells: Vec<Vec<String>>,
+    selection: Selection,
+}
+
+impl SpreadsheetGridExample {
+    /// Returns the sum of the numbers in the selected cells.
+    fn sum(&self) -> f64 {
+        let (start, end) = self.selection.bounds();
+
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(row, _)| (start.row..=end.row).contains(row))
+            .flat_map(|(_, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(column, _)| (start.column..=end.column).contains(column))
+                    .filter_map(|(_, value)| value.trim().parse::<f64>().ok())
+            })
+            .sum()
+    }
+}
+
+impl Sandbox for SpreadsheetGridExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let cells = [
+            ["Item", "Quantity", "Price"],
+            ["Coffee", "2", "3.50"],
+            ["Croissant", "1", "2.20"],
+            ["Orange juice", "3", "4.10"],
+        ]
+        .iter()
+        .map(|row| row.iter().map(|value| (*value).to_owned()).collect())
+        .collect();
+
+        Self {
+            cells,
+            selection: Selection::default(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("SpreadsheetGrid example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Edited(cell, value) => {
+                if self.cells.len() <= cell.row {
+                    self.cells.resize_with(cell.row + 1, Vec::new);
+                }
+
+                let row = &mut self.cells[cell.row];
+                if row.len() <= cell.column {
+                    row.resize_with(cell.column + 1, String::new);
+                }
+
+                row[cell.column] = value;
+            }
+            Message::Selected(selection) => self.selection = selection,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let grid = SpreadsheetGrid::new(&self.cells)
+            .rows(30)
+            .columns(8)
+            .on_edit(Message::Edited)
+            .on_select(Message::Selected)
+            .height(Length::Fixed(400.0));
+
+        let content = column![
+            text("Edit the cells, or copy and paste ranges from another spreadsheet"),
+            grid,
+            text(format!("Sum of the selected numbers: {:.2}", self.sum())),
+        ]
+        .spacing(10)
+        .max_width(800);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
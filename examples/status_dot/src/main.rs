@@ -0,0 +1,59 @@
+use iced::{
+    widget::{checkbox, column, container},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{status_dot::Status, StatusDot};
+
+fn main() -> iced::Result {
+    StatusDotExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Blinking(bool),
+}
+
+struct StatusDotExample {
+    blinking: bool,
+}
+
+impl Sandbox for StatusDotExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { blinking: true }
+    }
+
+    fn title(&self) -> String {
+        String::from("StatusDot example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Blinking(blinking) => self.blinking = blinking,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = column![
+            StatusDot::new(Status::Ok).label("Database"),
+            StatusDot::new(Status::Warning)
+                .label("Message queue (reconnecting)")
+                .blinking(self.blinking),
+            StatusDot::new(Status::Error)
+                .label("Mail server")
+                .blinking(self.blinking),
+            StatusDot::new(Status::Offline).label("Backup drive"),
+            checkbox("Blinking", self.blinking, Message::Blinking),
+        ]
+        .spacing(15);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,104 @@
+use iced::{
+    widget::{checkbox, column, container, text, text_input},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    stepper::{Orientation, Step},
+    Stepper,
+};
+
+fn main() -> iced::Result {
+    StepperExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Back,
+    Next,
+    Jump(usize),
+    Name(String),
+    Email(String),
+    Vertical(bool),
+}
+
+struct StepperExample {
+    current: usize,
+    name: String,
+    email: String,
+    vertical: bool,
+}
+
+impl StepperExample {
+    fn is_email_valid(&self) -> bool {
+        self.email.is_empty() || self.email.contains('@')
+    }
+}
+
+impl Sandbox for StepperExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            current: 0,
+            name: String::new(),
+            email: String::new(),
+            vertical: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Stepper example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Back => self.current = self.current.saturating_sub(1),
+            Message::Next => self.current += 1,
+            Message::Jump(step) => self.current = step,
+            Message::Name(name) => self.name = name,
+            Message::Email(email) => self.email = email,
+            Message::Vertical(vertical) => self.vertical = vertical,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let steps = vec![
+            Step::new("Name"),
+            Step::new("Email").error(!self.is_email_valid()),
+            Step::new("Confirm"),
+        ];
+
+        let content: Element<'_, Message> = match self.current {
+            0 => text_input("Your name", &self.name)
+                .on_input(Message::Name)
+                .into(),
+            1 => text_input("Your email", &self.email)
+                .on_input(Message::Email)
+                .into(),
+            _ => text(format!("Sign up {} with {}?", self.name, self.email)).into(),
+        };
+
+        let stepper = Stepper::new(steps, self.current, content)
+            .orientation(if self.vertical {
+                Orientation::Vertical
+            } else {
+                Orientation::Horizontal
+            })
+            .on_back(Message::Back)
+            .on_next(Message::Next)
+            .on_jump(Message::Jump);
+
+        container(
+            column![
+                checkbox("Vertical", self.vertical, Message::Vertical),
+                stepper
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,85 @@
+use iced::{
+    widget::{column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{graphics::icons::Icon, Switch};
+
+fn main() -> iced::Result {
+    SwitchExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Wifi(bool),
+    Bluetooth(bool),
+    Favorite(bool),
+}
+
+struct SwitchExample {
+    wifi: bool,
+    bluetooth: bool,
+    favorite: bool,
+}
+
+impl Sandbox for SwitchExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            wifi: true,
+            bluetooth: false,
+            favorite: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Switch example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Wifi(is_on) => self.wifi = is_on,
+            Message::Bluetooth(is_on) => self.bluetooth = is_on,
+            Message::Favorite(is_on) => self.favorite = is_on,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = column![
+            row![
+                text("Wi-Fi").width(Length::Fixed(120.0)),
+                Switch::new(self.wifi).on_toggle(Message::Wifi),
+            ]
+            .align_items(Alignment::Center),
+            row![
+                text("Bluetooth").width(Length::Fixed(120.0)),
+                Switch::new(self.bluetooth)
+                    .on_toggle(Message::Bluetooth)
+                    .labels("ON", "OFF"),
+            ]
+            .align_items(Alignment::Center),
+            row![
+                text("Favorite").width(Length::Fixed(120.0)),
+                Switch::new(self.favorite)
+                    .on_toggle(Message::Favorite)
+                    .icons(Icon::Heart, Icon::X),
+            ]
+            .align_items(Alignment::Center),
+            row![
+                text("Airplane mode").width(Length::Fixed(120.0)),
+                // Without a message the switch is disabled
+                Switch::new(false),
+            ]
+            .align_items(Alignment::Center),
+        ]
+        .spacing(15);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,229 @@
+use iced::{
+    alignment::Horizontal,
+    widget::{button, column, container, scrollable, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    table::{Column, SortOrder},
+    Table,
+};
+
+fn main() -> iced::Result {
+    TableExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Sort(usize, SortOrder),
+    Select(usize),
+    Resize(usize, f32),
+    Reorder(usize, usize),
+    Delete(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Field {
+    Name,
+    Role,
+    Department,
+    Location,
+    Email,
+    Age,
+    Actions,
+}
+
+impl Field {
+    fn title(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Role => "Role",
+            Self::Department => "Department",
+            Self::Location => "Location",
+            Self::Email => "Email",
+            Self::Age => "Age",
+            Self::Actions => "",
+        }
+    }
+}
+
+struct Employee {
+    name: &'static str,
+    role: &'static str,
+    department: &'static str,
+    location: &'static str,
+    email: &'static str,
+    age: u32,
+}
+
+struct TableExample {
+    employees: Vec<Employee>,
+    fields: Vec<(Field, f32)>,
+    sort: Option<(usize, SortOrder)>,
+    selected: Option<usize>,
+}
+
+impl Sandbox for TableExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            employees: vec![
+                Employee {
+                    name: "Alice",
+                    role: "Engineer",
+                    department: "Platform",
+                    location: "Berlin",
+                    email: "alice@example.com",
+                    age: 32,
+                },
+                Employee {
+                    name: "Bob",
+                    role: "Designer",
+                    department: "Product",
+                    location: "Lisbon",
+                    email: "bob@example.com",
+                    age: 27,
+                },
+                Employee {
+                    name: "Carol",
+                    role: "Manager",
+                    department: "Platform",
+                    location: "Toronto",
+                    email: "carol@example.com",
+                    age: 45,
+                },
+                Employee {
+                    name: "Dave",
+                    role: "Engineer",
+                    department: "Mobile",
+                    location: "Berlin",
+                    email: "dave@example.com",
+                    age: 38,
+                },
+                Employee {
+                    name: "Eve",
+                    role: "Support",
+                    department: "Customer Care",
+                    location: "Seoul",
+                    email: "eve@example.com",
+                    age: 24,
+                },
+            ],
+            fields: vec![
+                (Field::Name, 120.0),
+                (Field::Role, 150.0),
+                (Field::Department, 180.0),
+                (Field::Location, 150.0),
+                (Field::Email, 220.0),
+                (Field::Age, 80.0),
+                (Field::Actions, 100.0),
+            ],
+            sort: None,
+            selected: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Table example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Sort(column, order) => {
+                let field = self.fields[column].0;
+
+                self.employees.sort_by(|a, b| {
+                    let ordering = match field {
+                        Field::Name => a.name.cmp(b.name),
+                        Field::Role => a.role.cmp(b.role),
+                        Field::Department => a.department.cmp(b.department),
+                        Field::Location => a.location.cmp(b.location),
+                        Field::Email => a.email.cmp(b.email),
+                        Field::Age | Field::Actions => a.age.cmp(&b.age),
+                    };
+
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+                self.sort = Some((column, order));
+                self.selected = None;
+            }
+            Message::Select(index) => self.selected = Some(index),
+            Message::Resize(column, width) => self.fields[column].1 = width,
+            Message::Reorder(from, to) => {
+                let field = self.fields.remove(from);
+                self.fields.insert(to, field);
+                self.sort = None;
+            }
+            Message::Delete(index) => {
+                let _ = self.employees.remove(index);
+                self.selected = None;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let columns = self
+            .fields
+            .iter()
+            .map(|&(field, width)| {
+                let column = Column::new(field.title()).width(width);
+
+                match field {
+                    Field::Age => column.align_x(Horizontal::Right).sortable(true),
+                    Field::Actions => column,
+                    _ => column.sortable(true),
+                }
+            })
+            .collect();
+
+        let mut table = Table::new(columns)
+            .on_sort(Message::Sort)
+            .on_select(Message::Select)
+            .on_resize(Message::Resize)
+            .on_reorder(Message::Reorder)
+            .frozen_columns(1)
+            .selected(self.selected);
+
+        if let Some((column, order)) = self.sort {
+            table = table.sorted_by(column, order);
+        }
+
+        for (index, employee) in self.employees.iter().enumerate() {
+            table = table.push(
+                self.fields
+                    .iter()
+                    .map(|&(field, _)| -> Element<'_, Message> {
+                        match field {
+                            Field::Name => text(employee.name).into(),
+                            Field::Role => text(employee.role).into(),
+                            Field::Department => text(employee.department).into(),
+                            Field::Location => text(employee.location).into(),
+                            Field::Email => text(employee.email).into(),
+                            Field::Age => text(employee.age).into(),
+                            Field::Actions => button(text("Delete"))
+                                .on_press(Message::Delete(index))
+                                .into(),
+                        }
+                    }),
+            );
+        }
+
+        let selected = self
+            .selected
+            .and_then(|index| self.employees.get(index))
+            .map_or_else(
+                || String::from("No employee selected"),
+                |employee| format!("Selected: {}", employee.name),
+            );
+
+        container(column![scrollable(table), text(selected)].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
@@ -0,0 +1,78 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::TagInput;
+
+const MAX_TAGS: usize = 8;
+
+fn main() -> iced::Result {
+    TagInputExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Typed(String),
+    Tagged(Vec<String>),
+}
+
+struct TagInputExample {
+    text: String,
+    tags: Vec<String>,
+    suggestions: Vec<String>,
+}
+
+impl Sandbox for TagInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            tags: vec![String::from("rust"), String::from("gui")],
+            suggestions: ["async", "desktop", "graphics", "iced", "wasm", "widgets"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("TagInput example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Typed(text) => self.text = text,
+            Message::Tagged(tags) => self.tags = tags,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let tag_input = TagInput::new(
+            &self.tags,
+            "Add a tag",
+            &self.text,
+            Message::Typed,
+            Message::Tagged,
+        )
+        .suggestions(&self.suggestions)
+        .max_tags(MAX_TAGS)
+        .validate(|tag| {
+            tag.chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        })
+        .width(Length::Fixed(400.0));
+
+        let hint = text(format!(
+            "Lowercase letters, digits and dashes only, up to {MAX_TAGS} tags"
+        ));
+
+        container(column![tag_input, hint].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .padding(50)
+            .into()
+    }
+}
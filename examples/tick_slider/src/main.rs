@@ -0,0 +1,84 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::TickSlider;
+
+fn main() -> iced::Result {
+    TickSliderExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Volume(u8),
+    Temperature(f32),
+    Zoom(f32),
+}
+
+struct TickSliderExample {
+    volume: u8,
+    temperature: f32,
+    zoom: f32,
+}
+
+impl Sandbox for TickSliderExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            volume: 40,
+            temperature: 21.0,
+            zoom: 1.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Tick slider example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Volume(volume) => self.volume = volume,
+            Message::Temperature(temperature) => self.temperature = temperature,
+            Message::Zoom(zoom) => self.zoom = zoom,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let volume = TickSlider::new(0..=100, self.volume, Message::Volume)
+            .step(10)
+            .major_ticks(5)
+            .labels(true)
+            .format(|volume| format!("{volume}%"));
+
+        let temperature = TickSlider::new(16.0..=28.0, self.temperature, Message::Temperature)
+            .step(0.5)
+            .major_ticks(4)
+            .labels(true)
+            .format(|temperature| format!("{temperature:.1} °C"));
+
+        // Without snapping the ticks only guide the eye
+        let zoom = TickSlider::new(0.5..=2.0, self.zoom, Message::Zoom)
+            .step(0.25)
+            .snap(false)
+            .format(|zoom| format!("{:.0}%", zoom * 100.0));
+
+        container(
+            column![
+                text(format!("Volume: {}%", self.volume)),
+                volume,
+                text(format!("Temperature: {:.1} °C", self.temperature)),
+                temperature,
+                text(format!("Zoom: {:.0}%", self.zoom * 100.0)),
+                zoom,
+            ]
+            .spacing(20)
+            .max_width(400),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
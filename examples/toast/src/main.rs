@@ -0,0 +1,103 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    toast::{Corner, Toast},
+    Toasts,
+};
+
+fn main() -> iced::Result {
+    ToastExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Push(Toast),
+    Close(usize),
+    Pressed(usize),
+    ToggleCorner,
+}
+
+#[derive(Default)]
+struct ToastExample {
+    toasts: Vec<Toast>,
+    corner: Corner,
+    last_pressed: Option<String>,
+}
+
+impl Sandbox for ToastExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Toast example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Push(toast) => self.toasts.push(toast),
+            Message::Close(index) => {
+                let _ = self.toasts.remove(index);
+            }
+            Message::Pressed(index) => {
+                self.last_pressed = self.toasts.get(index).map(|toast| toast.title.clone());
+            }
+            Message::ToggleCorner => {
+                self.corner = match self.corner {
+                    Corner::TopLeft => Corner::TopRight,
+                    Corner::TopRight => Corner::BottomRight,
+                    Corner::BottomRight => Corner::BottomLeft,
+                    Corner::BottomLeft => Corner::TopLeft,
+                };
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let buttons = row![
+            button(text("Info")).on_press(Message::Push(Toast::info(
+                "Update available",
+                "A new version can be installed."
+            ))),
+            button(text("Success")).on_press(Message::Push(Toast::success(
+                "Saved",
+                "The document was saved."
+            ))),
+            button(text("Warning")).on_press(Message::Push(Toast::warning(
+                "Low disk space",
+                "Only 2 GB are left on the drive."
+            ))),
+            button(text("Error")).on_press(Message::Push(Toast::error(
+                "Upload failed",
+                "The server could not be reached."
+            ))),
+        ]
+        .spacing(10);
+
+        let content = container(
+            column![
+                buttons,
+                button(text(format!("Corner: {:?}", self.corner))).on_press(Message::ToggleCorner),
+                text(match &self.last_pressed {
+                    Some(title) => format!("Last pressed: {title}"),
+                    None => String::from("Press a toast"),
+                }),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y();
+
+        Toasts::new(content, &self.toasts, Message::Close)
+            .on_press(Message::Pressed)
+            .corner(self.corner)
+            .into()
+    }
+}
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{button, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{tooltip::Placement, Tooltip};
+
+fn main() -> iced::Result {
+    TooltipExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Like,
+}
+
+#[derive(Default)]
+struct TooltipExample {
+    likes: usize,
+}
+
+impl Sandbox for TooltipExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Tooltip example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Like => self.likes += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let simple = Tooltip::new(button("Anchored"), || text("A plain tooltip").into())
+            .placement(Placement::Bottom);
+
+        let cursor = Tooltip::new(button("Follow the cursor"), || {
+            text("I follow the cursor").into()
+        })
+        .follow_cursor(true)
+        .placement(Placement::Right)
+        .gap(12.0)
+        .show_delay(Duration::ZERO);
+
+        let likes = self.likes;
+        let interactive = Tooltip::new(button("Interactive"), move || {
+            row![
+                text(format!("{likes} likes")),
+                button("Like").on_press(Message::Like),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .into()
+        })
+        .hide_delay(Duration::from_millis(400));
+
+        let content = row![simple, cursor, interactive]
+            .spacing(20)
+            .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,127 @@
+use iced::{
+    widget::{button, column, container, horizontal_space, row, text, text_input},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    operation::Id,
+    tour::{Placement, Target},
+    Tour, TourStep,
+};
+
+fn main() -> iced::Result {
+    TourExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Start,
+    Step(usize),
+    Skip,
+    Finish,
+    Search(String),
+    Noop,
+}
+
+struct TourExample {
+    step: Option<usize>,
+    search: String,
+    status: String,
+}
+
+impl Sandbox for TourExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            step: None,
+            search: String::new(),
+            status: String::from("Press \"Take the tour\" to get started"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Tour example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Start => self.step = Some(0),
+            Message::Step(step) => self.step = Some(step),
+            Message::Skip => {
+                self.step = None;
+                self.status = String::from("Skipped the tour");
+            }
+            Message::Finish => {
+                self.step = None;
+                self.status = String::from("Finished the tour");
+            }
+            Message::Search(search) => self.search = search,
+            Message::Noop => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let toolbar = row![
+            Target::new(
+                Id::new("new"),
+                button(text("New document")).on_press(Message::Noop),
+            ),
+            Target::new(
+                Id::new("search"),
+                text_input("Search", &self.search)
+                    .on_input(Message::Search)
+                    .width(240),
+            ),
+            horizontal_space(Length::Fill),
+            Target::new(
+                Id::new("settings"),
+                button(text("Settings")).on_press(Message::Noop),
+            ),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let content = column![
+            toolbar,
+            container(text(&self.status))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y(),
+            button(text("Take the tour")).on_press(Message::Start),
+        ]
+        .spacing(20)
+        .padding(20);
+
+        let steps = vec![
+            TourStep::new(
+                Id::new("new"),
+                "Create documents",
+                "Start a new document from scratch or from one of the templates.",
+            ),
+            TourStep::new(
+                Id::new("search"),
+                "Find anything",
+                "Search through the titles and the contents of all of your documents. \
+                 Give it a try, the search field stays usable during the tour.",
+            ),
+            TourStep::new(
+                Id::new("settings"),
+                "Make it yours",
+                "Change the theme, the shortcuts and where your documents are stored.",
+            )
+            .placement(Placement::Left),
+            TourStep::new(
+                Id::new("help"),
+                "That's it",
+                "You can take the tour again at any time.",
+            ),
+        ];
+
+        Tour::new(content, steps, self.step, Message::Step)
+            .on_skip(Message::Skip)
+            .on_finish(Message::Finish)
+            .into()
+    }
+}
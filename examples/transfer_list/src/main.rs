@@ -0,0 +1,78 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Transfer, TransferList};
+
+fn main() -> iced::Result {
+    TransferListExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Moved(Transfer<String>),
+}
+
+struct TransferListExample {
+    available: Vec<String>,
+    granted: Vec<String>,
+    last: Option<String>,
+}
+
+impl Sandbox for TransferListExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            available: [
+                "Read files",
+                "Write files",
+                "Delete files",
+                "Create users",
+                "Delete users",
+                "Manage groups",
+                "View logs",
+                "Change settings",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            granted: vec![String::from("Comment")],
+            last: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("TransferList example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Moved(transfer) => {
+                self.last = Some(format!(
+                    "Moved {} to the {:?} list",
+                    transfer.items.join(", "),
+                    transfer.to
+                ));
+
+                transfer.apply(&mut self.available, &mut self.granted);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let transfer_list = TransferList::new(&self.available, &self.granted, Message::Moved)
+            .titles("Available", "Granted")
+            .search_placeholder("Filter permissions")
+            .height(Length::Fixed(360.0));
+
+        let last = text(self.last.as_deref().unwrap_or("Move some permissions"));
+
+        container(column![transfer_list, last].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(40)
+            .into()
+    }
+}
@@ -0,0 +1,91 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use once_cell::sync::Lazy;
+
+use iced_aw::{
+    operation::{self, Id},
+    RowHeight, VirtualList,
+};
+
+const COUNT: usize = 100_000;
+
+static LIST: Lazy<Id> = Lazy::new(Id::unique);
+
+fn main() -> iced::Result {
+    VirtualListExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    RowPressed(usize),
+    ScrollTo(usize),
+}
+
+#[derive(Default)]
+struct VirtualListExample {
+    pressed: Option<usize>,
+}
+
+impl Application for VirtualListExample {
+    type Message = Message;
+    type Flags = ();
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+
+    fn new(_flags: Self::Flags) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("VirtualList example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::RowPressed(index) => {
+                self.pressed = Some(index);
+
+                Command::none()
+            }
+            Message::ScrollTo(index) => operation::scroll_to(LIST.clone(), index),
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let controls = row![
+            button("Top").on_press(Message::ScrollTo(0)),
+            button("Middle").on_press(Message::ScrollTo(COUNT / 2)),
+            button("Bottom").on_press(Message::ScrollTo(COUNT - 1)),
+            text(self.pressed.map_or_else(
+                || String::from("No row pressed"),
+                |index| format!("Pressed row {index}"),
+            )),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        // Every tenth row has a second line, so the rows are measured
+        let list = VirtualList::new(COUNT, |index| {
+            let label = if index % 10 == 0 {
+                format!("Row {index}\nThis row is a bit taller than the others.")
+            } else {
+                format!("Row {index}")
+            };
+
+            button(text(label))
+                .width(Length::Fill)
+                .on_press(Message::RowPressed(index))
+                .into()
+        })
+        .id(LIST.clone())
+        .row_height(RowHeight::Measured(32.0));
+
+        container(column![controls, list].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
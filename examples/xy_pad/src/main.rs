@@ -0,0 +1,92 @@
+use iced::{
+    widget::{column, container, row, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::XYPad;
+
+fn main() -> iced::Result {
+    XYPadExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    PanTilt((i32, i32)),
+    Color((f32, f32)),
+    Released,
+}
+
+struct XYPadExample {
+    pan_tilt: (i32, i32),
+    saturation_brightness: (f32, f32),
+    status: String,
+}
+
+impl Sandbox for XYPadExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            pan_tilt: (0, 0),
+            saturation_brightness: (0.5, 0.8),
+            status: String::from("Drag a handle or focus a pad and use the arrow keys"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("XY pad example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::PanTilt(pan_tilt) => self.pan_tilt = pan_tilt,
+            Message::Color(saturation_brightness) => {
+                self.saturation_brightness = saturation_brightness;
+            }
+            Message::Released => self.status = String::from("Released the handle"),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let (pan, tilt) = self.pan_tilt;
+        let (saturation, brightness) = self.saturation_brightness;
+
+        let camera = XYPad::new(-180..=180, -90..=90, self.pan_tilt, Message::PanTilt)
+            .step(5)
+            .width(Length::Fixed(240.0))
+            .height(Length::Fixed(120.0))
+            .on_release(Message::Released);
+
+        let color = XYPad::new(
+            0.0..=1.0,
+            0.0..=1.0,
+            self.saturation_brightness,
+            Message::Color,
+        )
+        .step(0.01)
+        .on_release(Message::Released);
+
+        container(
+            column![
+                row![
+                    column![text(format!("Pan: {pan}°, tilt: {tilt}°")), camera].spacing(10),
+                    column![
+                        text(format!(
+                            "Saturation: {saturation:.2}, brightness: {brightness:.2}"
+                        )),
+                        color,
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(40),
+                text(&self.status),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
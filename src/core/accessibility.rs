@@ -0,0 +1,177 @@
+//! Describe the widgets for assistive technologies.
+//!
+//! Some widgets of this crate describe themselves with an `accessibility`
+//! method as a tree of [`Node`](Node)s with roles, names, values and states.
+//! These are plain metadata: iced has no accessibility support yet, so nothing
+//! announces them to screen readers. An application can read them to label its
+//! widgets or to feed an accessibility adapter of its own.
+//!
+//! *This API requires the following crate features to be activated: accessibility*
+
+/// The role of a [`Node`](Node).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A group of related content, like a card.
+    Group,
+    /// A button.
+    Button,
+    /// A dialog shown on top of the other content.
+    Dialog,
+    /// A list of tabs.
+    TabList,
+    /// A tab of a tab list.
+    Tab,
+    /// The content of the selected tab.
+    TabPanel,
+    /// A bar of menus.
+    MenuBar,
+    /// A menu opened from a menu bar or another menu.
+    Menu,
+    /// An item of a menu bar or a menu.
+    MenuItem,
+    /// An input for a date.
+    DateInput,
+    /// An input for a time.
+    TimeInput,
+    /// An input for a color.
+    ColorWell,
+}
+
+/// The description of a widget for assistive technologies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node {
+    /// The role of the widget.
+    pub role: Role,
+    /// The name announced for the widget.
+    pub name: Option<String>,
+    /// The value of the widget, like the picked date.
+    pub value: Option<String>,
+    /// Whether the widget is expanded, if it can be expanded.
+    pub expanded: Option<bool>,
+    /// Whether the widget is selected, if it can be selected.
+    pub selected: Option<bool>,
+    /// Whether the widget is disabled.
+    pub disabled: bool,
+    /// The descriptions of the parts of the widget.
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a new [`Node`](Node) with the given [`Role`](Role).
+    #[must_use]
+    pub const fn new(role: Role) -> Self {
+        Self {
+            role,
+            name: None,
+            value: None,
+            expanded: None,
+            selected: None,
+            disabled: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the [`Node`](Node).
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the value of the [`Node`](Node).
+    #[must_use]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the [`Node`](Node) is expanded.
+    #[must_use]
+    pub const fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets whether the [`Node`](Node) is selected.
+    #[must_use]
+    pub const fn selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Sets whether the [`Node`](Node) is disabled.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Pushes the description of a part of the widget to the [`Node`](Node).
+    #[must_use]
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Returns the [`Node`](Node)s of the tree in depth-first order.
+    pub fn nodes(&self) -> impl Iterator<Item = &Self> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder() {
+        let node = Node::new(Role::Tab)
+            .name("One")
+            .selected(true)
+            .disabled(true);
+
+        assert_eq!(node.role, Role::Tab);
+        assert_eq!(node.name.as_deref(), Some("One"));
+        assert_eq!(node.value, None);
+        assert_eq!(node.expanded, None);
+        assert_eq!(node.selected, Some(true));
+        assert!(node.disabled);
+        assert!(node.children.is_empty());
+    }
+
+    #[test]
+    fn nodes() {
+        let node = Node::new(Role::MenuBar)
+            .push(
+                Node::new(Role::MenuItem)
+                    .name("File")
+                    .expanded(true)
+                    .push(Node::new(Role::Menu).push(Node::new(Role::MenuItem).name("Open"))),
+            )
+            .push(Node::new(Role::MenuItem).name("Edit").expanded(false));
+
+        let roles: Vec<_> = node.nodes().map(|node| node.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                Role::MenuBar,
+                Role::MenuItem,
+                Role::Menu,
+                Role::MenuItem,
+                Role::MenuItem
+            ]
+        );
+
+        let names: Vec<_> = node
+            .nodes()
+            .filter_map(|node| node.name.as_deref())
+            .collect();
+        assert_eq!(names, vec!["File", "Open", "Edit"]);
+    }
+}
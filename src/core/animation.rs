@@ -0,0 +1,324 @@
+//! Animate the properties of widgets over time.
+//!
+//! An [`Animation`](Animation) moves its progress between the start and the
+//! end over a duration and shapes it with an [`Easing`](Easing) curve. Widgets
+//! keep it in their state, advance it with the time of the
+//! [`RedrawRequested`](iced_native::window::Event::RedrawRequested) events and
+//! request the next frame while it runs.
+use iced_native::{
+    time::{Duration, Instant},
+    window, Color, Point, Shell, Size, Vector,
+};
+
+/// The time between two frames of a running [`Animation`](Animation).
+const FRAME: Duration = Duration::from_millis(1000 / 60);
+
+/// The curve shaping the progress of an [`Animation`](Animation).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    /// A constant speed.
+    #[default]
+    Linear,
+    /// Starts slow and speeds up.
+    EaseIn,
+    /// Starts fast and slows down.
+    EaseOut,
+    /// Starts and ends slow.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the curve to the linear progress between 0.0 and 1.0.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A value that can be interpolated by an [`Animation`](Animation).
+pub trait Interpolate: Copy {
+    /// Returns the value at the given progress between 0.0 (`self`) and 1.0
+    /// (`to`).
+    #[must_use]
+    fn interpolate(self, to: Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(self, to: Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, to: Self, t: f32) -> Self {
+        Self {
+            r: self.r.interpolate(to.r, t),
+            g: self.g.interpolate(to.g, t),
+            b: self.b.interpolate(to.b, t),
+            a: self.a.interpolate(to.a, t),
+        }
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(self, to: Self, t: f32) -> Self {
+        Self::new(self.x.interpolate(to.x, t), self.y.interpolate(to.y, t))
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(self, to: Self, t: f32) -> Self {
+        Self::new(self.x.interpolate(to.x, t), self.y.interpolate(to.y, t))
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(self, to: Self, t: f32) -> Self {
+        Self::new(
+            self.width.interpolate(to.width, t),
+            self.height.interpolate(to.height, t),
+        )
+    }
+}
+
+/// A timeline moving its progress between the start (0.0) and the end (1.0).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animation {
+    /// The time it takes to move from the start to the end.
+    duration: Duration,
+    /// The curve shaping the progress.
+    easing: Easing,
+    /// Whether the progress starts over at the end.
+    repeat: bool,
+    /// Whether the progress moves towards the end.
+    forward: bool,
+    /// The linear progress when the [`Animation`](Animation) was (re)started.
+    from: f32,
+    /// The time the [`Animation`](Animation) was (re)started, if it runs.
+    started: Option<Instant>,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`](Animation) resting at the start.
+    #[must_use]
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            easing: Easing::Linear,
+            repeat: false,
+            forward: true,
+            from: 0.0,
+            started: None,
+        }
+    }
+
+    /// Sets the [`Easing`](Easing) curve of the [`Animation`](Animation).
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Makes the [`Animation`](Animation) start over whenever it reaches the
+    /// end.
+    #[must_use]
+    pub const fn repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Sets the duration of the [`Animation`](Animation).
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Starts moving the progress towards the end or, if `forward` is false,
+    /// back towards the start from where it currently is.
+    pub fn go(&mut self, forward: bool, now: Instant) {
+        self.from = self.progress(now);
+        self.forward = forward;
+        self.started = Some(now);
+    }
+
+    /// Stops the [`Animation`](Animation) where it currently is.
+    pub fn stop(&mut self, now: Instant) {
+        self.from = self.progress(now);
+        self.started = None;
+    }
+
+    /// Stops the [`Animation`](Animation) at the linear progress, for example
+    /// where the user let go of a dragged element.
+    pub fn rest_at(&mut self, progress: f32) {
+        self.from = progress.clamp(0.0, 1.0);
+        self.started = None;
+    }
+
+    /// Returns the linear progress between 0.0 and 1.0.
+    #[must_use]
+    pub fn progress(&self, now: Instant) -> f32 {
+        let Some(started) = self.started else {
+            return self.from;
+        };
+
+        let delta = if self.duration.is_zero() {
+            1.0
+        } else {
+            now.saturating_duration_since(started).as_secs_f32() / self.duration.as_secs_f32()
+        };
+
+        match (self.forward, self.repeat) {
+            (true, true) => (self.from + delta).fract(),
+            (false, true) => 1.0 - (1.0 - self.from + delta).fract(),
+            (true, false) => (self.from + delta).min(1.0),
+            (false, false) => (self.from - delta).max(0.0),
+        }
+    }
+
+    /// Returns the progress shaped by the [`Easing`](Easing) curve.
+    #[must_use]
+    pub fn value(&self, now: Instant) -> f32 {
+        self.easing.apply(self.progress(now))
+    }
+
+    /// Returns the value between `from` and `to` at the current progress.
+    #[must_use]
+    pub fn interpolate<T: Interpolate>(&self, from: T, to: T, now: Instant) -> T {
+        from.interpolate(to, self.value(now))
+    }
+
+    /// Returns true if the progress is still moving.
+    #[must_use]
+    pub fn is_running(&self, now: Instant) -> bool {
+        self.started.is_some()
+            && (self.repeat || {
+                let progress = self.progress(now);
+                if self.forward {
+                    progress < 1.0
+                } else {
+                    progress > 0.0
+                }
+            })
+    }
+
+    /// Returns the [`RedrawRequest`](iced_native::window::RedrawRequest) for the
+    /// next frame while the [`Animation`](Animation) runs.
+    #[must_use]
+    pub fn redraw_request(&self, now: Instant) -> Option<window::RedrawRequest> {
+        self.is_running(now)
+            .then_some(window::RedrawRequest::At(now + FRAME))
+    }
+
+    /// Requests a redraw for the next frame while the
+    /// [`Animation`](Animation) runs.
+    pub fn request_redraw<Message>(&self, now: Instant, shell: &mut Shell<'_, Message>) {
+        if let Some(request) = self.redraw_request(now) {
+            shell.request_redraw(request);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::time::{Duration, Instant};
+
+    use super::{Animation, Easing, Interpolate};
+
+    #[test]
+    fn easing() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn forward_and_back() {
+        let now = Instant::now();
+        let mut animation = Animation::new(Duration::from_secs(1));
+
+        assert_eq!(animation.progress(now), 0.0);
+        assert!(!animation.is_running(now));
+
+        animation.go(true, now);
+        assert_eq!(animation.progress(now + Duration::from_millis(500)), 0.5);
+        assert!(animation.is_running(now + Duration::from_millis(500)));
+        assert_eq!(animation.progress(now + Duration::from_secs(2)), 1.0);
+        assert!(!animation.is_running(now + Duration::from_secs(2)));
+        assert!(animation
+            .redraw_request(now + Duration::from_secs(2))
+            .is_none());
+
+        // Turning around continues from the current progress
+        animation.go(false, now + Duration::from_millis(500));
+        assert_eq!(animation.progress(now + Duration::from_millis(750)), 0.25);
+        assert_eq!(animation.progress(now + Duration::from_secs(2)), 0.0);
+
+        assert_eq!(
+            animation.interpolate(10.0, 20.0, now + Duration::from_millis(750)),
+            12.5
+        );
+    }
+
+    #[test]
+    fn repeat() {
+        let now = Instant::now();
+        let mut animation = Animation::new(Duration::from_secs(1)).repeat(true);
+
+        animation.go(true, now);
+        assert_eq!(animation.progress(now + Duration::from_millis(1500)), 0.5);
+        assert!(animation.is_running(now + Duration::from_secs(10)));
+
+        animation.stop(now + Duration::from_millis(1250));
+        assert_eq!(animation.progress(now + Duration::from_secs(10)), 0.25);
+        assert!(!animation.is_running(now + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn rest_at() {
+        let now = Instant::now();
+        let mut animation = Animation::new(Duration::from_secs(1));
+
+        animation.rest_at(0.75);
+        assert_eq!(animation.progress(now), 0.75);
+        assert!(!animation.is_running(now));
+
+        animation.go(false, now);
+        assert_eq!(animation.progress(now + Duration::from_millis(500)), 0.25);
+
+        animation.rest_at(2.0);
+        assert_eq!(animation.progress(now), 1.0);
+    }
+
+    #[test]
+    fn interpolate() {
+        assert_eq!(0.0_f32.interpolate(10.0, 0.5), 5.0);
+        assert_eq!(
+            iced_native::Point::new(0.0, 10.0).interpolate(iced_native::Point::new(10.0, 0.0), 0.5),
+            iced_native::Point::new(5.0, 5.0)
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Name the width classes of an adaptive layout.
+//!
+//! The classes follow the window size classes of Material Design: phones in
+//! portrait are compact, tablets and unfolded foldables are medium and
+//! desktops are expanded. The
+//! [`Responsive`](crate::native::responsive::Responsive) widget hands the
+//! [`Breakpoint`](Breakpoint) of its width to a view function and widgets like
+//! the [`TabBar`](crate::native::tab_bar::TabBar) and the
+//! [`Split`](crate::native::split::Split) adapt to it.
+
+/// The default width in logical pixels from which on a layout is
+/// [`Medium`](Breakpoint::Medium).
+pub const MEDIUM: f32 = 600.0;
+
+/// The default width in logical pixels from which on a layout is
+/// [`Expanded`](Breakpoint::Expanded).
+pub const EXPANDED: f32 = 840.0;
+
+/// The width class of a layout.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Narrower than [`MEDIUM`](MEDIUM), like a phone in portrait.
+    Compact,
+    /// Narrower than [`EXPANDED`](EXPANDED), like a tablet.
+    Medium,
+    /// At least [`EXPANDED`](EXPANDED) wide, like a desktop window.
+    Expanded,
+}
+
+impl Breakpoint {
+    /// Returns the [`Breakpoint`](Breakpoint) of the width with the default
+    /// [`Breakpoints`](Breakpoints).
+    #[must_use]
+    pub fn from_width(width: f32) -> Self {
+        Breakpoints::default().breakpoint(width)
+    }
+}
+
+/// The widths separating the [`Breakpoint`](Breakpoint)s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    /// The width from which on a layout is [`Medium`](Breakpoint::Medium).
+    pub medium: f32,
+    /// The width from which on a layout is [`Expanded`](Breakpoint::Expanded).
+    pub expanded: f32,
+}
+
+impl Breakpoints {
+    /// Returns the [`Breakpoint`](Breakpoint) of the width.
+    #[must_use]
+    pub fn breakpoint(&self, width: f32) -> Breakpoint {
+        if width >= self.expanded {
+            Breakpoint::Expanded
+        } else if width >= self.medium {
+            Breakpoint::Medium
+        } else {
+            Breakpoint::Compact
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            medium: MEDIUM,
+            expanded: EXPANDED,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Breakpoint, Breakpoints};
+
+    #[test]
+    fn classifies_widths() {
+        assert_eq!(Breakpoint::from_width(360.0), Breakpoint::Compact);
+        assert_eq!(Breakpoint::from_width(600.0), Breakpoint::Medium);
+        assert_eq!(Breakpoint::from_width(839.5), Breakpoint::Medium);
+        assert_eq!(Breakpoint::from_width(1280.0), Breakpoint::Expanded);
+    }
+
+    #[test]
+    fn uses_custom_breakpoints() {
+        let breakpoints = Breakpoints {
+            medium: 400.0,
+            expanded: 1000.0,
+        };
+
+        assert_eq!(breakpoints.breakpoint(450.0), Breakpoint::Medium);
+        assert_eq!(breakpoints.breakpoint(900.0), Breakpoint::Medium);
+        assert!(Breakpoint::Compact < Breakpoint::Expanded);
+    }
+}
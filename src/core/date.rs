@@ -10,6 +10,7 @@ use lazy_static::lazy_static;
 
 /// The date value
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -175,6 +176,28 @@ pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsI
     }
 }
 
+/// Gets the first day shown in the month grid of the given year and month,
+/// the monday of the week containing the first day of the month.
+
+#[must_use]
+pub fn first_day_of_grid(year: i32, month: u32) -> NaiveDate {
+    let first_day =
+        NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
+
+    first_day - Duration::days(i64::from(first_day.weekday().num_days_from_monday()))
+}
+
+/// Gets the number of weeks the month grid of the given year and month spans.
+
+#[must_use]
+pub fn weeks_of_month(year: i32, month: u32) -> usize {
+    let first_day =
+        NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
+    let offset = first_day.weekday().num_days_from_monday();
+
+    ((offset + num_days_of_month(year, month) + 6) / 7) as usize
+}
+
 /// Checks if the given year is a leap year.
 
 const fn is_leap_year(year: i32) -> bool {
@@ -275,8 +298,8 @@ mod tests {
     use chrono::NaiveDate;
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        first_day_of_grid, is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year,
+        succ_month, succ_year, weeks_of_month, IsInMonth,
     };
 
     #[test]
@@ -415,6 +438,28 @@ mod tests {
         assert_eq!(is_in_month, IsInMonth::Next);
     }
 
+    #[test]
+    fn first_day_of_grid_test() {
+        // December 2020 starts on a tuesday
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 11, 30).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(first_day_of_grid(2020, 12), expected);
+
+        // June 2020 starts on a monday
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 6, 1).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(first_day_of_grid(2020, 6), expected);
+    }
+
+    #[test]
+    fn weeks_of_month_test() {
+        // February 2021 starts on a monday and fills exactly four weeks
+        assert_eq!(weeks_of_month(2021, 2), 4);
+        assert_eq!(weeks_of_month(2020, 12), 5);
+        // August 2021 starts on a sunday
+        assert_eq!(weeks_of_month(2021, 8), 6);
+    }
+
     #[test]
     fn is_leap_year_test() {
         assert!(is_leap_year(2020));
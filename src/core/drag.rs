@@ -0,0 +1,189 @@
+//! Share the payload of a drag between the widgets dragging and dropping it.
+//!
+//! A widget starting a drag puts its payload here, so any widget of the same
+//! window can find out what is dragged and take the payload when it is
+//! dropped onto it. The events of a release reach the widgets in the order of
+//! the widget tree, so the source of a drag only [`release`]s it and learns
+//! whether it was dropped with [`finish`] on a later event.
+//!
+//! *This API requires the following crate features to be activated: drag_and_drop*
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+};
+
+/// The phase of the current drag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Phase {
+    /// The payload follows the cursor.
+    Dragging,
+    /// The payload was released but not dropped onto a target yet.
+    Released,
+    /// The payload was dropped onto a target.
+    Dropped,
+}
+
+/// The payload of the current drag with the source it was dragged from.
+struct Drag {
+    /// The source the payload was dragged from.
+    source: u64,
+    /// The dragged payload.
+    payload: Box<dyn Any>,
+    /// The phase of the drag.
+    phase: Phase,
+}
+
+thread_local! {
+    /// The current drag, if any.
+    static DRAG: RefCell<Option<Drag>> = RefCell::new(None);
+
+    /// The identifier of the next source.
+    static NEXT_SOURCE: Cell<u64> = Cell::new(0);
+}
+
+/// Returns a new identifier for a source of drags.
+pub fn next_source() -> u64 {
+    NEXT_SOURCE.with(|next| {
+        let source = next.get();
+        next.set(source + 1);
+        source
+    })
+}
+
+/// Starts dragging the payload from the source, replacing the current drag.
+pub fn start<T: 'static>(source: u64, payload: T) {
+    DRAG.with(|drag| {
+        *drag.borrow_mut() = Some(Drag {
+            source,
+            payload: Box::new(payload),
+            phase: Phase::Dragging,
+        });
+    });
+}
+
+/// Returns true if the payload of the source is being dragged.
+pub fn is_dragging(source: u64) -> bool {
+    DRAG.with(|drag| {
+        drag.borrow().as_ref().map_or(false, |drag| {
+            drag.source == source && drag.phase == Phase::Dragging
+        })
+    })
+}
+
+/// Returns the payload being dragged, if it is of the type `T`.
+pub fn dragged<T: 'static + Clone>() -> Option<T> {
+    DRAG.with(|drag| {
+        drag.borrow()
+            .as_ref()
+            .filter(|drag| drag.phase == Phase::Dragging)
+            .and_then(|drag| drag.payload.downcast_ref::<T>())
+            .cloned()
+    })
+}
+
+/// Drops the payload being dragged or just released onto a target and
+/// returns it, if it is of the type `T` and passes the filter.
+pub fn accept<T: 'static + Clone>(filter: impl Fn(&T) -> bool) -> Option<T> {
+    DRAG.with(|drag| {
+        let mut drag = drag.borrow_mut();
+        let drag = drag.as_mut().filter(|drag| drag.phase != Phase::Dropped)?;
+        let payload = drag
+            .payload
+            .downcast_ref::<T>()
+            .filter(|payload| filter(payload))?
+            .clone();
+
+        drag.phase = Phase::Dropped;
+
+        Some(payload)
+    })
+}
+
+/// Releases the payload of the source, leaving it to the targets to drop it.
+pub fn release(source: u64) {
+    DRAG.with(|drag| {
+        if let Some(drag) = drag
+            .borrow_mut()
+            .as_mut()
+            .filter(|drag| drag.source == source && drag.phase == Phase::Dragging)
+        {
+            drag.phase = Phase::Released;
+        }
+    });
+}
+
+/// Ends the released drag of the source and returns whether its payload was
+/// dropped onto a target, if the source has a released drag.
+pub fn finish(source: u64) -> Option<bool> {
+    DRAG.with(|drag| {
+        let mut drag = drag.borrow_mut();
+        let dropped = drag
+            .as_ref()
+            .filter(|drag| drag.source == source && drag.phase != Phase::Dragging)
+            .map(|drag| drag.phase == Phase::Dropped)?;
+
+        *drag = None;
+
+        Some(dropped)
+    })
+}
+
+/// Cancels the drag of the source, if it has one.
+pub fn cancel(source: u64) {
+    DRAG.with(|drag| {
+        let mut drag = drag.borrow_mut();
+        if drag.as_ref().map_or(false, |drag| drag.source == source) {
+            *drag = None;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{accept, cancel, dragged, finish, is_dragging, next_source, release, start};
+
+    #[test]
+    fn drops_typed_payload_in_any_order() {
+        let source = next_source();
+        start(source, 7_u32);
+
+        assert!(is_dragging(source));
+        assert_eq!(dragged::<u32>(), Some(7));
+        assert_eq!(dragged::<String>(), None);
+
+        // The target may see the release before the source
+        assert_eq!(accept::<u32>(|_| true), Some(7));
+        release(source);
+
+        assert_eq!(dragged::<u32>(), None);
+        assert_eq!(accept::<u32>(|_| true), None);
+        assert_eq!(finish(source), Some(true));
+        assert_eq!(finish(source), None);
+    }
+
+    #[test]
+    fn reports_rejected_payload() {
+        let source = next_source();
+        start(source, String::from("card"));
+        release(source);
+
+        assert_eq!(accept::<String>(String::is_empty), None);
+        assert_eq!(finish(source), Some(false));
+    }
+
+    #[test]
+    fn ignores_other_sources() {
+        let source = next_source();
+        let other = next_source();
+        start(source, 1_i32);
+
+        release(other);
+        cancel(other);
+        assert!(is_dragging(source));
+        assert!(!is_dragging(other));
+        assert_eq!(finish(other), None);
+
+        cancel(source);
+        assert_eq!(dragged::<i32>(), None);
+    }
+}
@@ -0,0 +1,97 @@
+//! Control the propagation of events through composite widgets.
+//!
+//! `iced_native` hands an event to every child of a widget and merges the
+//! returned [`Status`](Status)es afterwards. A composite widget reacting to
+//! the event itself, like a [`Card`](crate::native::card::Card) with a close
+//! button, would then activate both itself and the child below the cursor.
+//! The functions of this module run the handlers in a fixed order and stop the
+//! event as soon as one of them captured it.
+use iced_native::{event::Status, Event};
+
+/// Handles the event on the widget itself before its children.
+///
+/// The children only receive the event if the widget ignored it.
+pub fn capture<F>(own: Status, children: F) -> Status
+where
+    F: FnOnce() -> Status,
+{
+    match own {
+        Status::Captured => Status::Captured,
+        Status::Ignored => children(),
+    }
+}
+
+/// Handles the event on the children of a widget before the widget itself.
+///
+/// The widget only handles the event if all its children ignored it.
+pub fn bubble<F>(children: Status, own: F) -> Status
+where
+    F: FnOnce() -> Status,
+{
+    match children {
+        Status::Captured => Status::Captured,
+        Status::Ignored => own(),
+    }
+}
+
+/// Returns `true` if the event is caused by the user interacting with the
+/// mouse, the keyboard or a touch screen.
+///
+/// Widgets blocking their content, like an open modal, don't forward these
+/// events to it.
+#[must_use]
+pub const fn is_input(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(_) | Event::Keyboard(_) | Event::Touch(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{event::Status, keyboard, window, Event};
+
+    use super::{bubble, capture, is_input};
+
+    #[test]
+    fn capture_stops_before_children() {
+        let mut reached = false;
+        let status = capture(Status::Captured, || {
+            reached = true;
+            Status::Ignored
+        });
+
+        assert_eq!(status, Status::Captured);
+        assert!(!reached);
+
+        assert_eq!(
+            capture(Status::Ignored, || Status::Captured),
+            Status::Captured
+        );
+    }
+
+    #[test]
+    fn bubble_stops_before_parent() {
+        let mut reached = false;
+        let status = bubble(Status::Captured, || {
+            reached = true;
+            Status::Ignored
+        });
+
+        assert_eq!(status, Status::Captured);
+        assert!(!reached);
+
+        assert_eq!(
+            bubble(Status::Ignored, || Status::Captured),
+            Status::Captured
+        );
+    }
+
+    #[test]
+    fn detects_input() {
+        assert!(is_input(&Event::Keyboard(
+            keyboard::Event::CharacterReceived('a')
+        )));
+        assert!(!is_input(&Event::Window(window::Event::Focused)));
+    }
+}
@@ -0,0 +1,442 @@
+//! Recognize touch gestures.
+//!
+//! A [`Recognizer`](Recognizer) follows the finger touching a widget and turns
+//! its presses, moves and lifts into taps, double taps, long presses and
+//! swipes. Long presses are recognized with the time of the
+//! [`RedrawRequested`](iced_native::window::Event::RedrawRequested) events, so
+//! the widget keeps requesting a redraw while one is pending.
+//!
+//! A [`Pinch`](Pinch) follows up to two fingers and turns their moves into
+//! pans and zooms.
+use iced_native::{
+    time::{Duration, Instant},
+    touch, window, Event, Point, Rectangle, Shell, Vector,
+};
+
+/// The time a finger has to rest to be recognized as a long press.
+pub const LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// The maximum time between two taps to be recognized as a double tap.
+pub const DOUBLE_TAP: Duration = Duration::from_millis(300);
+
+/// The distance a finger may move during a tap or a long press.
+pub const TAP_SLOP: f32 = 10.0;
+
+/// The minimum distance a finger has to move to be recognized as a swipe.
+pub const SWIPE_DISTANCE: f32 = 50.0;
+
+/// The direction of a swipe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Swipe {
+    /// The finger moved to the left.
+    Left,
+    /// The finger moved to the right.
+    Right,
+    /// The finger moved up.
+    Up,
+    /// The finger moved down.
+    Down,
+}
+
+/// A recognized gesture.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gesture {
+    /// A short touch at the position.
+    Tap(Point),
+    /// Two taps in quick succession at the position.
+    DoubleTap(Point),
+    /// A finger resting at the position.
+    LongPress(Point),
+    /// A finger moving in the direction.
+    Swipe(Swipe),
+}
+
+/// The finger currently touching the widget.
+#[derive(Clone, Copy, Debug)]
+struct Press {
+    /// The finger touching the widget.
+    finger: touch::Finger,
+    /// The position the finger was pressed at.
+    origin: Point,
+    /// The current position of the finger.
+    position: Point,
+    /// The time the finger was pressed.
+    since: Instant,
+    /// Whether the press was already recognized as a long press.
+    is_long: bool,
+}
+
+impl Press {
+    /// Returns true if the finger stayed at its origin.
+    fn is_resting(&self) -> bool {
+        self.origin.distance(self.position) <= TAP_SLOP
+    }
+}
+
+/// Turns the touch events of a widget into [`Gesture`](Gesture)s.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Recognizer {
+    /// The finger currently touching the widget.
+    press: Option<Press>,
+    /// The position and time of the last tap, waiting for a second one.
+    last_tap: Option<(Point, Instant)>,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`](Recognizer).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            press: None,
+            last_tap: None,
+        }
+    }
+
+    /// Follows the event and returns the [`Gesture`](Gesture) it completes, if
+    /// any. Only fingers pressed inside of the bounds are followed.
+    pub fn on_event(&mut self, event: &Event, bounds: Rectangle, now: Instant) -> Option<Gesture> {
+        match *event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if self.press.is_none() && bounds.contains(position) {
+                    self.press = Some(Press {
+                        finger: id,
+                        origin: position,
+                        position,
+                        since: now,
+                        is_long: false,
+                    });
+                }
+                None
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(press) = self.press.as_mut().filter(|press| press.finger == id) {
+                    press.position = position;
+                }
+                None
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                let press = self.press.filter(|press| press.finger == id)?;
+                self.press = None;
+
+                if press.is_long {
+                    return None;
+                }
+
+                let (dx, dy) = (position.x - press.origin.x, position.y - press.origin.y);
+                let distance = press.origin.distance(position);
+
+                if distance >= SWIPE_DISTANCE {
+                    self.last_tap = None;
+
+                    Some(Gesture::Swipe(if dx.abs() >= dy.abs() {
+                        if dx < 0.0 {
+                            Swipe::Left
+                        } else {
+                            Swipe::Right
+                        }
+                    } else if dy < 0.0 {
+                        Swipe::Up
+                    } else {
+                        Swipe::Down
+                    }))
+                } else if distance <= TAP_SLOP {
+                    match self.last_tap.take() {
+                        Some((last, at))
+                            if now.saturating_duration_since(at) <= DOUBLE_TAP
+                                && last.distance(position) <= 2.0 * TAP_SLOP =>
+                        {
+                            Some(Gesture::DoubleTap(position))
+                        }
+                        _ => {
+                            self.last_tap = Some((position, now));
+                            Some(Gesture::Tap(position))
+                        }
+                    }
+                } else {
+                    None
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                if matches!(self.press, Some(press) if press.finger == id) {
+                    self.press = None;
+                }
+                None
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let press = self.press.as_mut().filter(|press| {
+                    !press.is_long
+                        && press.is_resting()
+                        && now.saturating_duration_since(press.since) >= LONG_PRESS
+                })?;
+                press.is_long = true;
+                self.last_tap = None;
+
+                Some(Gesture::LongPress(press.origin))
+            }
+            _ => None,
+        }
+    }
+
+    /// Requests a redraw at the time a pending long press is recognized.
+    pub fn request_redraw<Message>(&self, shell: &mut Shell<'_, Message>) {
+        if let Some(press) = self
+            .press
+            .filter(|press| !press.is_long && press.is_resting())
+        {
+            shell.request_redraw(window::RedrawRequest::At(press.since + LONG_PRESS));
+        }
+    }
+}
+
+/// A pan and zoom made by the fingers followed by a [`Pinch`](Pinch).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// The factor the distance between the fingers changed by, 1 for a
+    /// single finger.
+    pub scale: f32,
+    /// The point between the fingers after the move.
+    pub center: Point,
+    /// How far the point between the fingers moved.
+    pub translation: Vector,
+}
+
+/// Follows up to two fingers touching a widget and turns their moves into
+/// [`Transform`](Transform)s.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pinch {
+    /// The fingers touching the widget with their positions.
+    fingers: [Option<(touch::Finger, Point)>; 2],
+}
+
+impl Pinch {
+    /// Creates a new [`Pinch`](Pinch).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fingers: [None, None],
+        }
+    }
+
+    /// Returns true if a finger touches the widget.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.fingers.iter().any(Option::is_some)
+    }
+
+    /// Follows the event and returns the [`Transform`](Transform) made by the
+    /// move of a finger, if any. Only fingers pressed inside of the bounds
+    /// are followed.
+    pub fn on_event(&mut self, event: &Event, bounds: Rectangle) -> Option<Transform> {
+        match *event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if bounds.contains(position) {
+                    if let Some(slot) = self.fingers.iter_mut().find(|slot| slot.is_none()) {
+                        *slot = Some((id, position));
+                    }
+                }
+                None
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let (previous_center, previous_spread) = self.center_and_spread()?;
+                let (_, moved) = self
+                    .fingers
+                    .iter_mut()
+                    .flatten()
+                    .find(|(finger, _)| *finger == id)?;
+                *moved = position;
+                let (center, spread) = self.center_and_spread()?;
+
+                Some(Transform {
+                    scale: if previous_spread > 0.0 {
+                        spread / previous_spread
+                    } else {
+                        1.0
+                    },
+                    center,
+                    translation: center - previous_center,
+                })
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                for slot in &mut self.fingers {
+                    if matches!(slot, Some((finger, _)) if *finger == id) {
+                        *slot = None;
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the point between the fingers and the distance between them,
+    /// 0 for a single finger.
+    fn center_and_spread(&self) -> Option<(Point, f32)> {
+        match self.fingers {
+            [Some((_, a)), Some((_, b))] => Some((
+                Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+                a.distance(b),
+            )),
+            [Some((_, a)), None] | [None, Some((_, a))] => Some((a, 0.0)),
+            [None, None] => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        time::{Duration, Instant},
+        touch, window, Event, Point, Rectangle, Vector,
+    };
+
+    use super::{Gesture, Pinch, Recognizer, Swipe, Transform, LONG_PRESS};
+
+    /// The bounds of the widget used by the tests.
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 200.0,
+    };
+
+    /// Creates a touch event of the first finger.
+    fn finger(event: fn(touch::Finger, Point) -> touch::Event, x: f32, y: f32) -> Event {
+        Event::Touch(event(touch::Finger(0), Point::new(x, y)))
+    }
+
+    /// Creates a press event.
+    fn pressed(id: touch::Finger, position: Point) -> touch::Event {
+        touch::Event::FingerPressed { id, position }
+    }
+
+    /// Creates a move event.
+    fn moved(id: touch::Finger, position: Point) -> touch::Event {
+        touch::Event::FingerMoved { id, position }
+    }
+
+    /// Creates a lift event.
+    fn lifted(id: touch::Finger, position: Point) -> touch::Event {
+        touch::Event::FingerLifted { id, position }
+    }
+
+    #[test]
+    fn tap_and_double_tap() {
+        let now = Instant::now();
+        let mut recognizer = Recognizer::new();
+
+        assert_eq!(
+            recognizer.on_event(&finger(pressed, 50.0, 50.0), BOUNDS, now),
+            None
+        );
+        assert_eq!(
+            recognizer.on_event(&finger(lifted, 52.0, 50.0), BOUNDS, now),
+            Some(Gesture::Tap(Point::new(52.0, 50.0)))
+        );
+
+        let later = now + Duration::from_millis(100);
+        let _ = recognizer.on_event(&finger(pressed, 50.0, 52.0), BOUNDS, later);
+        assert_eq!(
+            recognizer.on_event(&finger(lifted, 50.0, 52.0), BOUNDS, later),
+            Some(Gesture::DoubleTap(Point::new(50.0, 52.0)))
+        );
+
+        // Taps too far apart in time are single taps
+        let much_later = later + Duration::from_secs(1);
+        let _ = recognizer.on_event(&finger(pressed, 50.0, 50.0), BOUNDS, much_later);
+        let _ = recognizer.on_event(&finger(lifted, 50.0, 50.0), BOUNDS, much_later);
+        let latest = much_later + Duration::from_secs(1);
+        let _ = recognizer.on_event(&finger(pressed, 50.0, 50.0), BOUNDS, latest);
+        assert_eq!(
+            recognizer.on_event(&finger(lifted, 50.0, 50.0), BOUNDS, latest),
+            Some(Gesture::Tap(Point::new(50.0, 50.0)))
+        );
+    }
+
+    #[test]
+    fn long_press() {
+        let now = Instant::now();
+        let mut recognizer = Recognizer::new();
+
+        let _ = recognizer.on_event(&finger(pressed, 50.0, 50.0), BOUNDS, now);
+        let redraw = |at| Event::Window(window::Event::RedrawRequested(at));
+
+        assert_eq!(
+            recognizer.on_event(&redraw(now + Duration::from_millis(100)), BOUNDS, now),
+            None
+        );
+        assert_eq!(
+            recognizer.on_event(&redraw(now + LONG_PRESS), BOUNDS, now),
+            Some(Gesture::LongPress(Point::new(50.0, 50.0)))
+        );
+        assert_eq!(
+            recognizer.on_event(&redraw(now + 2 * LONG_PRESS), BOUNDS, now),
+            None
+        );
+        assert_eq!(
+            recognizer.on_event(&finger(lifted, 50.0, 50.0), BOUNDS, now + LONG_PRESS),
+            None
+        );
+    }
+
+    #[test]
+    fn swipe() {
+        let now = Instant::now();
+        let mut recognizer = Recognizer::new();
+
+        for ((x, y), swipe) in [
+            ((20.0, 100.0), Swipe::Left),
+            ((180.0, 100.0), Swipe::Right),
+            ((100.0, 20.0), Swipe::Up),
+            ((100.0, 180.0), Swipe::Down),
+        ] {
+            let _ = recognizer.on_event(&finger(pressed, 100.0, 100.0), BOUNDS, now);
+            let _ = recognizer.on_event(&finger(moved, x, y), BOUNDS, now);
+            assert_eq!(
+                recognizer.on_event(&finger(lifted, x, y), BOUNDS, now),
+                Some(Gesture::Swipe(swipe))
+            );
+        }
+
+        // Fingers pressed outside of the bounds are not followed
+        let _ = recognizer.on_event(&finger(pressed, 300.0, 100.0), BOUNDS, now);
+        assert_eq!(
+            recognizer.on_event(&finger(lifted, 100.0, 100.0), BOUNDS, now),
+            None
+        );
+    }
+
+    #[test]
+    fn pinch_and_pan() {
+        let mut pinch = Pinch::new();
+        let second = |event: fn(touch::Finger, Point) -> touch::Event, x, y| {
+            Event::Touch(event(touch::Finger(1), Point::new(x, y)))
+        };
+
+        let _ = pinch.on_event(&finger(pressed, 50.0, 100.0), BOUNDS);
+        assert_eq!(
+            pinch.on_event(&finger(moved, 60.0, 100.0), BOUNDS),
+            Some(Transform {
+                scale: 1.0,
+                center: Point::new(60.0, 100.0),
+                translation: Vector::new(10.0, 0.0),
+            })
+        );
+
+        // Moving the fingers apart doubles their distance
+        let _ = pinch.on_event(&second(pressed, 100.0, 100.0), BOUNDS);
+        assert_eq!(
+            pinch.on_event(&second(moved, 140.0, 100.0), BOUNDS),
+            Some(Transform {
+                scale: 2.0,
+                center: Point::new(100.0, 100.0),
+                translation: Vector::new(20.0, 0.0),
+            })
+        );
+
+        let _ = pinch.on_event(&finger(lifted, 60.0, 100.0), BOUNDS);
+        let _ = pinch.on_event(&second(lifted, 140.0, 100.0), BOUNDS);
+        assert!(!pinch.is_active());
+    }
+}
@@ -0,0 +1,115 @@
+//! The icon shown in the icon slot of a widget, either a glyph of an icon font
+//! or an [`Svg`](iced_native::svg::Handle).
+use iced_native::{
+    layout::{Limits, Node},
+    renderer, svg, Color, Element, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::{
+    core::renderer::{GlyphRenderer, IconRenderer},
+    graphics::icons::Icon,
+};
+
+/// The icon shown in the icon slot of a widget, like the icon of a
+/// [`Badge`](crate::native::Badge) or of a menu item.
+///
+/// The variants are always available, but an [`Svg`](IconSource::Svg) icon is
+/// only drawn with the crate feature `svg` activated.
+#[derive(Clone, Debug, Hash)]
+pub enum IconSource {
+    /// A glyph of an icon font.
+    Glyph(Icon),
+
+    /// An [`Svg`](iced_native::svg::Handle), drawn in the color of the
+    /// surrounding text.
+    ///
+    /// *This is only drawn with the crate feature `svg` activated.*
+    Svg(svg::Handle),
+}
+
+impl From<Icon> for IconSource {
+    fn from(icon: Icon) -> Self {
+        Self::Glyph(icon)
+    }
+}
+
+impl From<svg::Handle> for IconSource {
+    fn from(handle: svg::Handle) -> Self {
+        Self::Svg(handle)
+    }
+}
+
+/// Draws the [`IconSource`](IconSource) centered in the bounds.
+pub fn draw<Renderer>(renderer: &mut Renderer, icon: &IconSource, bounds: Rectangle, color: Color)
+where
+    Renderer: IconRenderer,
+{
+    match icon {
+        IconSource::Glyph(glyph) => {
+            renderer.fill_glyph((*glyph).into(), glyph.font(), bounds, bounds.height, color);
+        }
+        #[cfg(feature = "svg")]
+        IconSource::Svg(handle) => {
+            iced_native::svg::Renderer::draw(renderer, handle.clone(), Some(color), bounds);
+        }
+        #[cfg(not(feature = "svg"))]
+        IconSource::Svg(_) => {}
+    }
+}
+
+/// A square widget showing an [`IconSource`](IconSource) in the size of the
+/// text, used to put an icon in front of the item of a widget.
+pub(crate) struct Slot {
+    /// The icon to show.
+    icon: IconSource,
+    /// The size of the icon, defaulting to the text size of the renderer.
+    size: Option<f32>,
+}
+
+impl Slot {
+    /// Creates a new [`Slot`](Slot) showing the icon.
+    pub(crate) fn new(icon: IconSource, size: Option<f32>) -> Self {
+        Self { icon, size }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Slot
+where
+    Renderer: IconRenderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        Node::new(limits.resolve(Size::new(size, size)))
+    }
+
+    fn draw(
+        &self,
+        _state: &iced_native::widget::Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(renderer, &self.icon, layout.bounds(), style.text_color);
+    }
+}
+
+impl<'a, Message, Renderer> From<Slot> for Element<'a, Message, Renderer>
+where
+    Renderer: IconRenderer + 'a,
+{
+    fn from(slot: Slot) -> Self {
+        Self::new(slot)
+    }
+}
@@ -0,0 +1,123 @@
+//! Keep the layout of a widget in its state between the rebuilds of the view.
+//!
+//! A widget is laid out without its state, but the view is diffed against the
+//! widget tree right before it is laid out. So the widget takes the
+//! [`LayoutCache`](LayoutCache) of its state in `diff` and lays itself out
+//! again only when the key describing its content or the limits change.
+use std::{cell::RefCell, rc::Rc};
+
+use iced_native::{
+    layout::{Limits, Node},
+    Size,
+};
+
+/// A layout with the key and the limits it was laid out for.
+#[derive(Debug)]
+struct Entry<K> {
+    /// The key describing the content of the widget.
+    key: K,
+    /// The minimum, maximum and fill size of the limits.
+    limits: [Size; 3],
+    /// The layout.
+    node: Node,
+}
+
+/// The layout of a widget, shared between its state and the widget.
+#[derive(Debug)]
+pub(crate) struct LayoutCache<K> {
+    /// The cached layout, if the widget was laid out already.
+    entry: RefCell<Rc<RefCell<Option<Entry<K>>>>>,
+}
+
+impl<K> Default for LayoutCache<K> {
+    fn default() -> Self {
+        Self {
+            entry: RefCell::new(Rc::new(RefCell::new(None))),
+        }
+    }
+}
+
+impl<K: PartialEq> LayoutCache<K> {
+    /// Shares the layout kept in the state with the widget.
+    pub(crate) fn share(&self, state: &Self) {
+        *self.entry.borrow_mut() = Rc::clone(&state.entry.borrow());
+    }
+
+    /// Returns the layout kept for the key and the limits, or lays the widget
+    /// out and keeps its layout.
+    pub(crate) fn layout(&self, key: K, limits: &Limits, layout: impl FnOnce() -> Node) -> Node {
+        let limits = [limits.min(), limits.max(), limits.fill()];
+        let entry = self.entry.borrow();
+        let mut entry = entry.borrow_mut();
+
+        if let Some(cached) = entry
+            .as_ref()
+            .filter(|cached| cached.key == key && cached.limits == limits)
+        {
+            return cached.node.clone();
+        }
+
+        let node = layout();
+        *entry = Some(Entry {
+            key,
+            limits,
+            node: node.clone(),
+        });
+
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use iced_native::{
+        layout::{Limits, Node},
+        Size,
+    };
+
+    use super::LayoutCache;
+
+    #[test]
+    fn lays_out_again_on_changes() {
+        let state = LayoutCache::default();
+        let widget = LayoutCache::default();
+        widget.share(&state);
+
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let layouts = Cell::new(0);
+        let layout = |key, limits: &Limits| {
+            let _ = widget.layout(key, limits, || {
+                layouts.set(layouts.get() + 1);
+                Node::new(Size::new(10.0, 10.0))
+            });
+        };
+
+        layout(1, &limits);
+        layout(1, &limits);
+        assert_eq!(layouts.get(), 1);
+
+        layout(2, &limits);
+        assert_eq!(layouts.get(), 2);
+
+        layout(2, &Limits::new(Size::ZERO, Size::new(50.0, 50.0)));
+        assert_eq!(layouts.get(), 3);
+    }
+
+    #[test]
+    fn keeps_the_layout_in_the_state() {
+        let state = LayoutCache::default();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        let first = LayoutCache::default();
+        first.share(&state);
+        let _ = first.layout(1, &limits, || Node::new(Size::new(10.0, 10.0)));
+
+        // The widget of the next view finds the layout of the previous one
+        let second = LayoutCache::default();
+        second.share(&state);
+        let node = second.layout(1, &limits, || Node::new(Size::ZERO));
+        assert_eq!(node.size(), Size::new(10.0, 10.0));
+    }
+}
@@ -1,6 +1,22 @@
 //! A module fitting `iced_native`.
 
-#[cfg(feature = "date_picker")]
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
+
+pub mod animation;
+
+pub mod breakpoint;
+
+#[cfg(feature = "drag_and_drop")]
+pub mod drag;
+
+pub mod gesture;
+
+#[cfg(any(
+    feature = "date_picker",
+    feature = "month_view",
+    feature = "calendar_heatmap"
+))]
 //#[cfg(all(feature = "date_picker", not(target_arch = "wasm32")))]
 pub mod date;
 
@@ -10,9 +26,26 @@ pub mod clock;
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+pub mod event;
+
+pub mod icon_source;
+
+#[cfg(any(feature = "grid", feature = "tab_bar"))]
+pub(crate) mod layout_cache;
+
 pub mod overlay;
 
 pub mod renderer;
 
+pub mod sizing;
+
+#[cfg(any(
+    feature = "knob",
+    feature = "range_slider",
+    feature = "tick_slider",
+    feature = "xy_pad"
+))]
+pub(crate) mod snap;
+
 #[cfg(feature = "time_picker")]
 pub mod time;
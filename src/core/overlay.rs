@@ -1,33 +1,295 @@
 //! Helper functions for overlays
-use iced_native::{Point, Size};
+use iced_native::{event, keyboard, mouse, touch, Alignment, Event, Point, Rectangle, Size};
+
+/// The side of an anchor an overlay is placed at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Placement {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// Left of the anchor.
+    Left,
+    /// Right of the anchor.
+    Right,
+}
+
+impl Placement {
+    /// Returns the [`Placement`](Placement) at the opposite side of the anchor.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// The ways an overlay can be dismissed by the user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dismiss {
+    /// The ESC key was pressed.
+    Escape,
+    /// A mouse button or a finger was pressed outside of the overlay.
+    Outside,
+}
+
+impl Dismiss {
+    /// Returns how the event dismisses an overlay with the given bounds, if
+    /// it does.
+    ///
+    /// Presses inside of the excluded bounds, like the anchor toggling the
+    /// overlay itself, don't dismiss the overlay.
+    #[must_use]
+    pub fn from_event(
+        event: &Event,
+        cursor_position: Point,
+        bounds: Rectangle,
+        excluded: &[Rectangle],
+    ) -> Option<Self> {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => Some(Self::Escape),
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left | mouse::Button::Right,
+            ))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let inside = bounds.contains(cursor_position)
+                    || excluded
+                        .iter()
+                        .any(|bounds| bounds.contains(cursor_position));
+
+                (!inside).then_some(Self::Outside)
+            }
+            _ => None,
+        }
+    }
+
+    /// The status of the dismissing event.
+    ///
+    /// Pressing ESC is captured by the overlay, while a press outside of it is
+    /// left to the widget below.
+    #[must_use]
+    pub const fn status(self) -> event::Status {
+        match self {
+            Self::Escape => event::Status::Captured,
+            Self::Outside => event::Status::Ignored,
+        }
+    }
+}
 
 /// Trait containing functions for positioning of nodes.
 pub trait Position {
     /// Centers this node around the given position. If the node is over the
     /// specified bounds it's bouncing back to be fully visible on screen.
     fn center_and_bounce(&mut self, position: Point, bounds: Size);
+
+    /// Shifts this node back into the specified bounds if it overflows them.
+    ///
+    /// If the node is larger than the bounds, it is aligned with their top left
+    /// corner.
+    fn bounce(&mut self, bounds: Size);
+
+    /// Places this node at the given side of the anchor, keeping the offset to
+    /// it and aligning it along the side of the anchor.
+    ///
+    /// If the node does not fit into the specified bounds at that side, it is
+    /// flipped to the opposite side if it fits there. Finally it is shifted to
+    /// be fully visible on screen.
+    fn place(
+        &mut self,
+        anchor: Rectangle,
+        placement: Placement,
+        alignment: Alignment,
+        offset: f32,
+        bounds: Size,
+    );
 }
 
 impl Position for iced_native::layout::Node {
     fn center_and_bounce(&mut self, position: Point, bounds: Size) {
         self.move_to(Point::new(
-            (position.x - self.size().width / 2.0).max(0.0),
-            (position.y - self.size().height / 2.0).max(0.0),
+            position.x - self.size().width / 2.0,
+            position.y - self.size().height / 2.0,
         ));
 
+        self.bounce(bounds);
+    }
+
+    fn bounce(&mut self, bounds: Size) {
+        let node = self.bounds();
+
         self.move_to(Point::new(
-            if self.bounds().x + self.bounds().width > bounds.width {
-                (self.bounds().x - (self.bounds().width - (bounds.width - self.bounds().x)))
-                    .max(0.0)
-            } else {
-                self.bounds().x
-            },
-            if self.bounds().y + self.bounds().height > bounds.height {
-                (self.bounds().y - (self.bounds().height - (bounds.height - self.bounds().y)))
-                    .max(0.0)
-            } else {
-                self.bounds().y
-            },
+            node.x.min(bounds.width - node.width).max(0.0),
+            node.y.min(bounds.height - node.height).max(0.0),
         ));
     }
+
+    fn place(
+        &mut self,
+        anchor: Rectangle,
+        placement: Placement,
+        alignment: Alignment,
+        offset: f32,
+        bounds: Size,
+    ) {
+        let size = self.size();
+
+        let mut position = placed(anchor, size, placement, alignment, offset);
+        if !fits(position, size, placement, bounds) {
+            let flipped = placement.opposite();
+            let flipped_position = placed(anchor, size, flipped, alignment, offset);
+
+            if fits(flipped_position, size, flipped, bounds) {
+                position = flipped_position;
+            }
+        }
+
+        self.move_to(position);
+        self.bounce(bounds);
+    }
+}
+
+/// Returns the position of a node of the given size placed at the anchor.
+fn placed(
+    anchor: Rectangle,
+    size: Size,
+    placement: Placement,
+    alignment: Alignment,
+    offset: f32,
+) -> Point {
+    let align = |start: f32, anchor_length: f32, length: f32| match alignment {
+        Alignment::Start => start,
+        Alignment::Center => start + (anchor_length - length) / 2.0,
+        Alignment::End => start + anchor_length - length,
+    };
+
+    match placement {
+        Placement::Top => Point::new(
+            align(anchor.x, anchor.width, size.width),
+            anchor.y - offset - size.height,
+        ),
+        Placement::Bottom => Point::new(
+            align(anchor.x, anchor.width, size.width),
+            anchor.y + anchor.height + offset,
+        ),
+        Placement::Left => Point::new(
+            anchor.x - offset - size.width,
+            align(anchor.y, anchor.height, size.height),
+        ),
+        Placement::Right => Point::new(
+            anchor.x + anchor.width + offset,
+            align(anchor.y, anchor.height, size.height),
+        ),
+    }
+}
+
+/// Returns whether a node at the position fits into the bounds on the axis of
+/// its placement.
+fn fits(position: Point, size: Size, placement: Placement, bounds: Size) -> bool {
+    match placement {
+        Placement::Top | Placement::Bottom => {
+            position.y >= 0.0 && position.y + size.height <= bounds.height
+        }
+        Placement::Left | Placement::Right => {
+            position.x >= 0.0 && position.x + size.width <= bounds.width
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::{keyboard, layout::Node, mouse, Alignment, Event, Point, Rectangle, Size};
+
+    use super::{Dismiss, Placement, Position};
+
+    /// The bounds of the window used by the tests.
+    const BOUNDS: Size = Size::new(400.0, 300.0);
+
+    #[test]
+    fn center_and_bounce() {
+        let mut node = Node::new(Size::new(100.0, 100.0));
+
+        node.center_and_bounce(Point::new(200.0, 150.0), BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(150.0, 100.0));
+
+        node.center_and_bounce(Point::new(390.0, 290.0), BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(300.0, 200.0));
+
+        node.center_and_bounce(Point::new(10.0, 10.0), BOUNDS);
+        assert_eq!(node.bounds().position(), Point::ORIGIN);
+    }
+
+    #[test]
+    fn bounce_larger_than_bounds() {
+        let mut node = Node::new(Size::new(500.0, 100.0));
+        node.move_to(Point::new(50.0, 250.0));
+
+        node.bounce(BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(0.0, 200.0));
+    }
+
+    #[test]
+    fn place_at_side() {
+        let anchor = Rectangle::new(Point::new(100.0, 100.0), Size::new(50.0, 20.0));
+        let mut node = Node::new(Size::new(80.0, 40.0));
+
+        node.place(anchor, Placement::Bottom, Alignment::Center, 5.0, BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(85.0, 125.0));
+
+        node.place(anchor, Placement::Top, Alignment::Start, 5.0, BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(100.0, 55.0));
+
+        node.place(anchor, Placement::Right, Alignment::End, 0.0, BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(150.0, 80.0));
+    }
+
+    #[test]
+    fn place_flips_and_shifts() {
+        let mut node = Node::new(Size::new(80.0, 40.0));
+
+        // Not enough space below, so it is flipped above the anchor
+        let anchor = Rectangle::new(Point::new(100.0, 270.0), Size::new(50.0, 20.0));
+        node.place(anchor, Placement::Bottom, Alignment::Center, 5.0, BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(85.0, 225.0));
+
+        // Overflowing the right edge, so it is shifted back
+        let anchor = Rectangle::new(Point::new(380.0, 100.0), Size::new(20.0, 20.0));
+        node.place(anchor, Placement::Bottom, Alignment::Start, 0.0, BOUNDS);
+        assert_eq!(node.bounds().position(), Point::new(320.0, 120.0));
+    }
+
+    #[test]
+    fn dismiss() {
+        let bounds = Rectangle::new(Point::new(100.0, 100.0), Size::new(50.0, 50.0));
+        let anchor = Rectangle::new(Point::new(100.0, 80.0), Size::new(50.0, 20.0));
+        let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let escape = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            modifiers: keyboard::Modifiers::default(),
+        });
+
+        assert_eq!(
+            Dismiss::from_event(&escape, Point::new(120.0, 120.0), bounds, &[]),
+            Some(Dismiss::Escape)
+        );
+        assert_eq!(
+            Dismiss::from_event(&press, Point::new(10.0, 10.0), bounds, &[anchor]),
+            Some(Dismiss::Outside)
+        );
+        assert_eq!(
+            Dismiss::from_event(&press, Point::new(120.0, 120.0), bounds, &[anchor]),
+            None
+        );
+        assert_eq!(
+            Dismiss::from_event(&press, Point::new(120.0, 90.0), bounds, &[anchor]),
+            None
+        );
+    }
 }
@@ -1,6 +1,12 @@
 //! Helper struct for drawing
 
-use iced_native::{Layout, Point, Rectangle};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    renderer::{self, BorderRadius},
+    Color, Font, Layout, Point, Rectangle,
+};
+
+use crate::style::gradient::{Direction, Gradient};
 
 /// Collection of all necessary data to draw a widget.
 #[derive(Debug)]
@@ -18,3 +24,235 @@ pub struct DrawEnvironment<'a, Defaults, Style, Focus> {
     /// The focus to an input element on the widget.
     pub focus: Focus,
 }
+
+/// A renderer able to draw all icons supported by the widgets.
+///
+/// With the `svg` feature enabled, this also requires the renderer to draw
+/// [`Svg`](iced_native::svg::Handle) icons.
+#[cfg(feature = "svg")]
+pub trait IconRenderer:
+    iced_native::Renderer + iced_native::text::Renderer<Font = Font> + iced_native::svg::Renderer
+{
+}
+
+#[cfg(feature = "svg")]
+impl<T> IconRenderer for T where
+    T: iced_native::Renderer
+        + iced_native::text::Renderer<Font = Font>
+        + iced_native::svg::Renderer
+{
+}
+
+/// A renderer able to draw all icons supported by the widgets.
+///
+/// With the `svg` feature enabled, this also requires the renderer to draw
+/// [`Svg`](iced_native::svg::Handle) icons.
+#[cfg(not(feature = "svg"))]
+pub trait IconRenderer: iced_native::Renderer + iced_native::text::Renderer<Font = Font> {}
+
+#[cfg(not(feature = "svg"))]
+impl<T> IconRenderer for T where T: iced_native::Renderer + iced_native::text::Renderer<Font = Font> {}
+
+/// A renderer able to draw single glyphs of an icon font, like the close icon
+/// of a [`Card`](crate::native::card::Card).
+///
+/// It is implemented for all renderers drawing text. Renderers without text
+/// support can implement it on their own to draw the glyphs differently or to
+/// skip them, so that they can still use the widgets.
+pub trait GlyphRenderer: iced_native::Renderer {
+    /// Returns the size glyphs are drawn with by default.
+    fn default_glyph_size(&self) -> f32;
+
+    /// Draws the glyph of the font centered in the bounds.
+    fn fill_glyph(&mut self, glyph: char, font: Font, bounds: Rectangle, size: f32, color: Color);
+}
+
+impl<T> GlyphRenderer for T
+where
+    T: iced_native::Renderer + iced_native::text::Renderer<Font = Font>,
+{
+    fn default_glyph_size(&self) -> f32 {
+        self.default_size()
+    }
+
+    fn fill_glyph(&mut self, glyph: char, font: Font, bounds: Rectangle, size: f32, color: Color) {
+        let mut buffer = [0; 4];
+
+        self.fill_text(iced_native::text::Text {
+            content: glyph.encode_utf8(&mut buffer),
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size,
+            color,
+            font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+    }
+}
+
+/// The maximum size of a band of a drawn [`Gradient`](Gradient).
+const GRADIENT_BAND_SIZE: f32 = 2.0;
+
+/// The maximum number of bands a [`Gradient`](Gradient) is drawn with.
+const GRADIENT_MAX_BANDS: f32 = 128.0;
+
+/// Splits the bounds into the bands a [`Gradient`](Gradient) is drawn with.
+///
+/// The rounded corners at the start and at the end of the gradient are
+/// covered by a single band each, filled with the color at its center, since a
+/// quad can only be rounded as a whole. Each band is yielded with its bounds,
+/// the radii of its corners and its color. The bands are computed while
+/// drawing, so no buffer is allocated per frame.
+pub fn gradient_bands(
+    bounds: Rectangle,
+    border_radius: BorderRadius,
+    gradient: &Gradient,
+) -> impl Iterator<Item = (Rectangle, [f32; 4], Color)> + '_ {
+    bands(bounds, border_radius, gradient.direction, move |offset| {
+        gradient.color_at(offset)
+    })
+}
+
+/// Splits the bounds into bands running in the direction, like the ones of a
+/// [`Gradient`](Gradient), each filled with the color the function returns
+/// for the offset of its center between 0.0 and 1.0.
+pub(crate) fn bands<F>(
+    bounds: Rectangle,
+    border_radius: BorderRadius,
+    direction: Direction,
+    color_at: F,
+) -> impl Iterator<Item = (Rectangle, [f32; 4], Color)>
+where
+    F: Fn(f32) -> Color + Copy,
+{
+    let [top_left, top_right, bottom_right, bottom_left] = <[f32; 4]>::from(border_radius);
+
+    let (length, head_radii, tail_radii) = match direction {
+        Direction::Vertical => (
+            bounds.height,
+            [top_left, top_right, 0.0, 0.0],
+            [0.0, 0.0, bottom_right, bottom_left],
+        ),
+        Direction::Horizontal => (
+            bounds.width,
+            [top_left, 0.0, 0.0, bottom_left],
+            [0.0, top_right, bottom_right, 0.0],
+        ),
+    };
+
+    let max = |radii: [f32; 4]| radii.into_iter().fold(0.0, f32::max).min(length / 2.0);
+    let head = max(head_radii);
+    let tail = max(tail_radii);
+
+    let band = move |start: f32, size: f32, radii: [f32; 4]| {
+        let bounds = match direction {
+            Direction::Vertical => Rectangle {
+                y: bounds.y + start,
+                height: size,
+                ..bounds
+            },
+            Direction::Horizontal => Rectangle {
+                x: bounds.x + start,
+                width: size,
+                ..bounds
+            },
+        };
+
+        (bounds, radii, color_at((start + size / 2.0) / length))
+    };
+
+    let middle = length - head - tail;
+    let count = (middle / GRADIENT_BAND_SIZE)
+        .ceil()
+        .clamp(1.0, GRADIENT_MAX_BANDS);
+    let step = middle / count;
+
+    let middle_count = if middle > 0.0 { count as usize } else { 0 };
+
+    (head > 0.0)
+        .then(|| band(0.0, head, head_radii))
+        .into_iter()
+        .chain((0..middle_count).map(move |i| band(head + i as f32 * step, step, [0.0; 4])))
+        .chain((tail > 0.0).then(|| band(length - tail, tail, tail_radii)))
+}
+
+/// Fills the bounds with the [`Gradient`](Gradient), rounded by the border
+/// radius.
+pub fn fill_gradient<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    border_radius: BorderRadius,
+    gradient: &Gradient,
+) where
+    Renderer: iced_native::Renderer,
+{
+    for (bounds, border_radius, color) in gradient_bands(bounds, border_radius, gradient) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::{Color, Rectangle};
+
+    use super::gradient_bands;
+    use crate::style::gradient::{Direction, Gradient};
+
+    #[test]
+    fn gradient_bands_cover_bounds() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 40.0,
+        };
+        let gradient = Gradient::new(Color::BLACK, Color::WHITE);
+
+        let bands: Vec<_> =
+            gradient_bands(bounds, [5.0, 5.0, 0.0, 0.0].into(), &gradient).collect();
+
+        let (head, head_radii, head_color) = bands[0];
+        assert_eq!(head.y, 20.0);
+        assert_eq!(head.height, 5.0);
+        assert_eq!(head_radii, [5.0, 5.0, 0.0, 0.0]);
+        assert_eq!(head_color, gradient.color_at(2.5 / 40.0));
+
+        let height: f32 = bands.iter().map(|(bounds, _, _)| bounds.height).sum();
+        assert!((height - 40.0).abs() < f32::EPSILON * 100.0);
+        assert!(bands
+            .iter()
+            .all(|(band, _, _)| band.x == 10.0 && band.width == 100.0));
+        assert!(bands[1..].iter().all(|(_, radii, _)| radii == &[0.0; 4]));
+    }
+
+    #[test]
+    fn gradient_bands_horizontal() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 4.0,
+        };
+        let gradient = Gradient::new(Color::BLACK, Color::WHITE).direction(Direction::Horizontal);
+
+        let bands: Vec<_> = gradient_bands(bounds, 0.0.into(), &gradient).collect();
+
+        assert_eq!(bands.len(), 5);
+        assert!(bands.iter().all(|(band, _, _)| band.width == 2.0));
+        assert_eq!(bands[0].2, gradient.color_at(0.1));
+        assert_eq!(bands[4].2, gradient.color_at(0.9));
+    }
+}
@@ -0,0 +1,56 @@
+//! Size widgets on high-DPI displays.
+//!
+//! iced lays out widgets in logical pixels and the renderer multiplies them
+//! with the scale factor of the window. The widgets of this crate therefore
+//! keep paddings, spacings and icon sizes as fractional `f32` logical pixels
+//! and never truncate them to integers, which would misalign them once they
+//! are scaled. Small interactive parts, like close icons, get a hit target of
+//! at least [`MIN_HIT_TARGET`](MIN_HIT_TARGET) so they can still be hit with a
+//! finger.
+use iced_native::Rectangle;
+
+/// The minimum width and height of the area reacting to the cursor or a
+/// finger, in logical pixels.
+pub const MIN_HIT_TARGET: f32 = 24.0;
+
+/// Returns the bounds grown around their center to be at least
+/// [`MIN_HIT_TARGET`](MIN_HIT_TARGET) wide and high.
+#[must_use]
+pub fn hit_target(bounds: Rectangle) -> Rectangle {
+    let width = bounds.width.max(MIN_HIT_TARGET);
+    let height = bounds.height.max(MIN_HIT_TARGET);
+    let center = bounds.center();
+
+    Rectangle {
+        x: center.x - width / 2.0,
+        y: center.y - height / 2.0,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::{Point, Rectangle, Size};
+
+    use super::{hit_target, MIN_HIT_TARGET};
+
+    #[test]
+    fn grows_small_bounds() {
+        let bounds = Rectangle::new(Point::new(100.0, 50.0), Size::new(16.0, 16.0));
+        let target = hit_target(bounds);
+
+        assert_eq!(target.center(), bounds.center());
+        assert_eq!(target.width, MIN_HIT_TARGET);
+        assert_eq!(target.height, MIN_HIT_TARGET);
+        assert!(target.contains(Point::new(97.0, 47.0)));
+    }
+
+    #[test]
+    fn keeps_large_bounds() {
+        let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(40.0, 30.0));
+
+        assert_eq!(hit_target(bounds), bounds);
+    }
+}
@@ -0,0 +1,42 @@
+//! Snap the values of sliders and similar widgets to their steps.
+use num_traits::Float;
+
+/// Returns the value snapped to the nearest step from the minimum and clamped
+/// to the range. A step of zero only clamps the value.
+pub(crate) fn snap<F: Float>(value: F, min: F, max: F, step: F) -> F {
+    let value = if step > F::zero() {
+        min + ((value - min) / step).round() * step
+    } else {
+        value
+    };
+
+    num_traits::clamp(value, min, max.max(min))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snap;
+
+    #[test]
+    fn snaps_to_the_steps_of_the_range() {
+        assert!((snap(14.0, 0.0, 100.0, 10.0) - 10.0_f64).abs() < f64::EPSILON);
+        assert!((snap(15.0, 0.0, 100.0, 10.0) - 20.0_f64).abs() < f64::EPSILON);
+        assert!((snap(7.0, 5.0, 100.0, 10.0) - 5.0_f64).abs() < f64::EPSILON);
+        assert!((snap(13.0, -180.0, 180.0, 5.0) - 15.0_f64).abs() < f64::EPSILON);
+        assert!((snap(0.26, -1.0, 1.0, 0.25) - 0.25_f32).abs() < 1e-4);
+    }
+
+    #[test]
+    fn only_clamps_without_a_step() {
+        assert!((snap(0.3, 0.0, 1.0, 0.0) - 0.3_f64).abs() < f64::EPSILON);
+        assert!((snap(0.33, 0.0, 1.0, 0.0) - 0.33_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn clamps_to_the_range() {
+        assert!(snap(-20.0_f64, 0.0, 100.0, 10.0).abs() < f64::EPSILON);
+        assert!((snap(98.0, 0.0, 95.0, 10.0) - 95.0_f64).abs() < f64::EPSILON);
+        assert!((snap(120.0, 0.0, 100.0, 0.0) - 100.0_f64).abs() < f64::EPSILON);
+        assert!((snap(3.0, -1.0, 1.0, 0.25) - 1.0_f32).abs() < f32::EPSILON);
+    }
+}
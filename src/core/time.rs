@@ -7,6 +7,7 @@ use chrono::{Local, Timelike};
 
 /// The time value
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Time {
     /// The time value containing hour, minute and period.
     Hm {
@@ -119,6 +120,7 @@ impl Display for Time {
 
 /// The current period of the clock
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Period {
     /// No period - using 24 hour format.
     H24,
@@ -1,4 +1,6 @@
 //! The default icon font of the widgets of this library.
+use std::sync::RwLock;
+
 use iced_graphics::Font;
 
 #[cfg(feature = "icons")]
@@ -25,6 +27,138 @@ pub const ICON_FONT: Font = iced_native::Font::External {
     bytes: include_bytes!("./fonts/required-icons.ttf"),
 };
 
+/// The icon fonts registered with [`register`](register).
+static FONTS: RwLock<Vec<FontDef>> = RwLock::new(Vec::new());
+
+/// The definition of an additional icon font.
+#[derive(Clone, Copy, Debug)]
+pub struct FontDef {
+    /// The unique name of the font.
+    pub name: &'static str,
+    /// The bytes of the font file.
+    pub bytes: &'static [u8],
+    /// The names of the glyphs of the font and their code points.
+    pub glyphs: &'static [(&'static str, char)],
+}
+
+/// A handle to an icon font registered with [`register`](register).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct IconFont(usize);
+
+impl IconFont {
+    /// Returns the [`Font`](Font) of the [`IconFont`](IconFont).
+    ///
+    /// Falls back to the [`ICON_FONT`](ICON_FONT) if the registry is not
+    /// accessible.
+    #[must_use]
+    pub fn font(self) -> Font {
+        FONTS
+            .read()
+            .ok()
+            .and_then(|fonts| fonts.get(self.0).copied())
+            .map_or(ICON_FONT, |def| Font::External {
+                name: def.name,
+                bytes: def.bytes,
+            })
+    }
+
+    /// Returns the [`Icon`](Icon) of the glyph with the given name in the
+    /// [`IconFont`](IconFont), if it exists.
+    #[must_use]
+    pub fn glyph(self, name: &str) -> Option<Icon> {
+        let fonts = FONTS.read().ok()?;
+        let (_, codepoint) = fonts
+            .get(self.0)?
+            .glyphs
+            .iter()
+            .find(|(glyph, _)| *glyph == name)?;
+
+        Some(Icon::custom(self, *codepoint))
+    }
+}
+
+/// A glyph of an icon font registered with [`register`](register).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CustomIcon {
+    /// The font containing the glyph.
+    pub font: IconFont,
+    /// The code point of the glyph.
+    pub codepoint: char,
+}
+
+/// Registers an additional icon font, usually at the startup of the
+/// application.
+///
+/// The returned [`IconFont`](IconFont) can be used to create
+/// [`Icon`](Icon)s with [`Icon::custom`](Icon::custom), which can be used
+/// wherever the widgets of this library draw icons. Registering a font with
+/// the name of an already registered font returns the existing handle.
+pub fn register(font: FontDef) -> IconFont {
+    let mut fonts = match FONTS.write() {
+        Ok(fonts) => fonts,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    if let Some(index) = fonts.iter().position(|def| def.name == font.name) {
+        return IconFont(index);
+    }
+
+    fonts.push(font);
+    IconFont(fonts.len() - 1)
+}
+
+impl Icon {
+    /// Creates an [`Icon`](Icon) showing the glyph with the given code point
+    /// of a registered [`IconFont`](IconFont).
+    #[must_use]
+    pub const fn custom(font: IconFont, codepoint: char) -> Self {
+        Self::Custom(CustomIcon { font, codepoint })
+    }
+
+    /// Returns the [`Icon`](Icon) with the given name, like `"calendar-x"`,
+    /// if it exists.
+    ///
+    /// The names are the names of the icons on
+    /// [Bootstrap Icons](https://icons.getbootstrap.com).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        ICONS
+            .binary_search_by(|(icon, _)| (*icon).cmp(name))
+            .ok()
+            .map(|index| ICONS[index].1)
+    }
+
+    /// Returns the name of the [`Icon`](Icon), or `None` for an icon of a
+    /// registered [`IconFont`](IconFont).
+    #[must_use]
+    pub fn name(self) -> Option<&'static str> {
+        let codepoint = icon_to_char(self);
+
+        match self {
+            Self::Custom(_) => None,
+            _ => ICONS
+                .iter()
+                .find(|(_, icon)| icon_to_char(*icon) == codepoint)
+                .map(|(name, _)| *name),
+        }
+    }
+
+    /// Returns an iterator over all [`Icon`](Icon)s together with their
+    /// names, sorted by name.
+    pub fn all() -> impl Iterator<Item = (&'static str, Self)> {
+        ICONS.iter().copied()
+    }
+
+    /// Returns the [`Font`](Font) needed to draw the [`Icon`](Icon).
+    #[must_use]
+    pub fn font(self) -> Font {
+        match self {
+            Self::Custom(custom) => custom.font.font(),
+            _ => ICON_FONT,
+        }
+    }
+}
+
 impl From<Icon> for char {
     fn from(icon: Icon) -> Self {
         icon_to_char(icon)
@@ -1,3618 +1,6 @@
 //! Bootstrap icons.
-//! Machine generated code. Do not change!
+//!
+//! The [`Icon`] enum is generated at build time from the glyph names of
+//! `fonts/bootstrap-icons.ttf`, so updating the font adds its new icons.
 
-/// Bootstrap icons
-#[derive(Copy, Clone, Debug, Hash)]
-pub enum Icon {
-    /// alarm
-    Alarm,
-    /// alarm-fill
-    AlarmFill,
-    /// align-bottom
-    AlignBottom,
-    /// align-center
-    AlignCenter,
-    /// align-end
-    AlignEnd,
-    /// align-middle
-    AlignMiddle,
-    /// align-start
-    AlignStart,
-    /// align-top
-    AlignTop,
-    /// alt
-    Alt,
-    /// app
-    App,
-    /// app-indicator
-    AppIndicator,
-    /// archive
-    Archive,
-    /// archive-fill
-    ArchiveFill,
-    /// arrow-90deg-down
-    Arrow90DegDown,
-    /// arrow-90deg-left
-    Arrow90DegLeft,
-    /// arrow-90deg-right
-    Arrow90DegRight,
-    /// arrow-90deg-up
-    Arrow90DegUp,
-    /// arrow-bar-down
-    ArrowBarDown,
-    /// arrow-bar-left
-    ArrowBarLeft,
-    /// arrow-bar-right
-    ArrowBarRight,
-    /// arrow-bar-up
-    ArrowBarUp,
-    /// arrow-clockwise
-    ArrowClockwise,
-    /// arrow-counterclockwise
-    ArrowCounterclockwise,
-    /// arrow-down
-    ArrowDown,
-    /// arrow-down-circle
-    ArrowDownCircle,
-    /// arrow-down-circle-fill
-    ArrowDownCircleFill,
-    /// arrow-down-left
-    ArrowDownLeft,
-    /// arrow-down-left-circle
-    ArrowDownLeftCircle,
-    /// arrow-down-left-circle-fill
-    ArrowDownLeftCircleFill,
-    /// arrow-down-left-square
-    ArrowDownLeftSquare,
-    /// arrow-down-left-square-fill
-    ArrowDownLeftSquareFill,
-    /// arrow-down-right
-    ArrowDownRight,
-    /// arrow-down-right-circle
-    ArrowDownRightCircle,
-    /// arrow-down-right-circle-fill
-    ArrowDownRightCircleFill,
-    /// arrow-down-right-square
-    ArrowDownRightSquare,
-    /// arrow-down-right-square-fill
-    ArrowDownRightSquareFill,
-    /// arrow-down-short
-    ArrowDownShort,
-    /// arrow-down-square
-    ArrowDownSquare,
-    /// arrow-down-square-fill
-    ArrowDownSquareFill,
-    /// arrow-down-up
-    ArrowDownUp,
-    /// arrow-left
-    ArrowLeft,
-    /// arrow-left-circle
-    ArrowLeftCircle,
-    /// arrow-left-circle-fill
-    ArrowLeftCircleFill,
-    /// arrow-left-right
-    ArrowLeftRight,
-    /// arrow-left-short
-    ArrowLeftShort,
-    /// arrow-left-square
-    ArrowLeftSquare,
-    /// arrow-left-square-fill
-    ArrowLeftSquareFill,
-    /// arrow-repeat
-    ArrowRepeat,
-    /// arrow-return-left
-    ArrowReturnLeft,
-    /// arrow-return-right
-    ArrowReturnRight,
-    /// arrow-right
-    ArrowRight,
-    /// arrow-right-circle
-    ArrowRightCircle,
-    /// arrow-right-circle-fill
-    ArrowRightCircleFill,
-    /// arrow-right-short
-    ArrowRightShort,
-    /// arrow-right-square
-    ArrowRightSquare,
-    /// arrow-right-square-fill
-    ArrowRightSquareFill,
-    /// arrow-up
-    ArrowUp,
-    /// arrow-up-circle
-    ArrowUpCircle,
-    /// arrow-up-circle-fill
-    ArrowUpCircleFill,
-    /// arrow-up-left
-    ArrowUpLeft,
-    /// arrow-up-left-circle
-    ArrowUpLeftCircle,
-    /// arrow-up-left-circle-fill
-    ArrowUpLeftCircleFill,
-    /// arrow-up-left-square
-    ArrowUpLeftSquare,
-    /// arrow-up-left-square-fill
-    ArrowUpLeftSquareFill,
-    /// arrow-up-right
-    ArrowUpRight,
-    /// arrow-up-right-circle
-    ArrowUpRightCircle,
-    /// arrow-up-right-circle-fill
-    ArrowUpRightCircleFill,
-    /// arrow-up-right-square
-    ArrowUpRightSquare,
-    /// arrow-up-right-square-fill
-    ArrowUpRightSquareFill,
-    /// arrow-up-short
-    ArrowUpShort,
-    /// arrow-up-square
-    ArrowUpSquare,
-    /// arrow-up-square-fill
-    ArrowUpSquareFill,
-    /// arrows-angle-contract
-    ArrowsAngleContract,
-    /// arrows-angle-expand
-    ArrowsAngleExpand,
-    /// arrows-collapse
-    ArrowsCollapse,
-    /// arrows-expand
-    ArrowsExpand,
-    /// arrows-fullscreen
-    ArrowsFullscreen,
-    /// arrows-move
-    ArrowsMove,
-    /// aspect-ratio
-    AspectRatio,
-    /// aspect-ratio-fill
-    AspectRatioFill,
-    /// asterisk
-    Asterisk,
-    /// at
-    At,
-    /// award
-    Award,
-    /// award-fill
-    AwardFill,
-    /// back
-    Back,
-    /// backspace
-    Backspace,
-    /// backspace-fill
-    BackspaceFill,
-    /// backspace-reverse
-    BackspaceReverse,
-    /// backspace-reverse-fill
-    BackspaceReverseFill,
-    /// badge-4k
-    Badge4K,
-    /// badge-4k-fill
-    Badge4KFill,
-    /// badge-8k
-    Badge8K,
-    /// badge-8k-fill
-    Badge8KFill,
-    /// badge-ad
-    BadgeAd,
-    /// badge-ad-fill
-    BadgeAdFill,
-    /// badge-cc
-    BadgeCc,
-    /// badge-cc-fill
-    BadgeCcFill,
-    /// badge-hd
-    BadgeHd,
-    /// badge-hd-fill
-    BadgeHdFill,
-    /// badge-tm
-    BadgeTm,
-    /// badge-tm-fill
-    BadgeTmFill,
-    /// badge-vo
-    BadgeVo,
-    /// badge-vo-fill
-    BadgeVoFill,
-    /// bag
-    Bag,
-    /// bag-check
-    BagCheck,
-    /// bag-check-fill
-    BagCheckFill,
-    /// bag-dash
-    BagDash,
-    /// bag-dash-fill
-    BagDashFill,
-    /// bag-fill
-    BagFill,
-    /// bag-plus
-    BagPlus,
-    /// bag-plus-fill
-    BagPlusFill,
-    /// bag-x
-    BagX,
-    /// bag-x-fill
-    BagXFill,
-    /// bar-chart
-    BarChart,
-    /// bar-chart-fill
-    BarChartFill,
-    /// bar-chart-line
-    BarChartLine,
-    /// bar-chart-line-fill
-    BarChartLineFill,
-    /// bar-chart-steps
-    BarChartSteps,
-    /// basket
-    Basket,
-    /// basket-fill
-    BasketFill,
-    /// basket2
-    Basket2,
-    /// basket2-fill
-    Basket2Fill,
-    /// basket3
-    Basket3,
-    /// basket3-fill
-    Basket3Fill,
-    /// battery
-    Battery,
-    /// battery-charging
-    BatteryCharging,
-    /// battery-full
-    BatteryFull,
-    /// battery-half
-    BatteryHalf,
-    /// bell
-    Bell,
-    /// bell-fill
-    BellFill,
-    /// bezier
-    Bezier,
-    /// bezier2
-    Bezier2,
-    /// bicycle
-    Bicycle,
-    /// binoculars
-    Binoculars,
-    /// binoculars-fill
-    BinocularsFill,
-    /// blockquote-left
-    BlockquoteLeft,
-    /// blockquote-right
-    BlockquoteRight,
-    /// book
-    Book,
-    /// book-fill
-    BookFill,
-    /// book-half
-    BookHalf,
-    /// bookmark
-    Bookmark,
-    /// bookmark-check
-    BookmarkCheck,
-    /// bookmark-check-fill
-    BookmarkCheckFill,
-    /// bookmark-dash
-    BookmarkDash,
-    /// bookmark-dash-fill
-    BookmarkDashFill,
-    /// bookmark-fill
-    BookmarkFill,
-    /// bookmark-heart
-    BookmarkHeart,
-    /// bookmark-heart-fill
-    BookmarkHeartFill,
-    /// bookmark-plus
-    BookmarkPlus,
-    /// bookmark-plus-fill
-    BookmarkPlusFill,
-    /// bookmark-star
-    BookmarkStar,
-    /// bookmark-star-fill
-    BookmarkStarFill,
-    /// bookmark-x
-    BookmarkX,
-    /// bookmark-x-fill
-    BookmarkXFill,
-    /// bookmarks
-    Bookmarks,
-    /// bookmarks-fill
-    BookmarksFill,
-    /// bookshelf
-    Bookshelf,
-    /// bootstrap
-    Bootstrap,
-    /// bootstrap-fill
-    BootstrapFill,
-    /// bootstrap-reboot
-    BootstrapReboot,
-    /// border-style
-    BorderStyle,
-    /// border-width
-    BorderWidth,
-    /// bounding-box
-    BoundingBox,
-    /// bounding-box-circles
-    BoundingBoxCircles,
-    /// box
-    Box,
-    /// box-arrow-down
-    BoxArrowDown,
-    /// box-arrow-down-left
-    BoxArrowDownLeft,
-    /// box-arrow-down-right
-    BoxArrowDownRight,
-    /// box-arrow-in-down
-    BoxArrowInDown,
-    /// box-arrow-in-down-left
-    BoxArrowInDownLeft,
-    /// box-arrow-in-down-right
-    BoxArrowInDownRight,
-    /// box-arrow-in-left
-    BoxArrowInLeft,
-    /// box-arrow-in-right
-    BoxArrowInRight,
-    /// box-arrow-in-up
-    BoxArrowInUp,
-    /// box-arrow-in-up-left
-    BoxArrowInUpLeft,
-    /// box-arrow-in-up-right
-    BoxArrowInUpRight,
-    /// box-arrow-left
-    BoxArrowLeft,
-    /// box-arrow-right
-    BoxArrowRight,
-    /// box-arrow-up
-    BoxArrowUp,
-    /// box-arrow-up-left
-    BoxArrowUpLeft,
-    /// box-arrow-up-right
-    BoxArrowUpRight,
-    /// box-seam
-    BoxSeam,
-    /// braces
-    Braces,
-    /// bricks
-    Bricks,
-    /// briefcase
-    Briefcase,
-    /// briefcase-fill
-    BriefcaseFill,
-    /// brightness-alt-high
-    BrightnessAltHigh,
-    /// brightness-alt-high-fill
-    BrightnessAltHighFill,
-    /// brightness-alt-low
-    BrightnessAltLow,
-    /// brightness-alt-low-fill
-    BrightnessAltLowFill,
-    /// brightness-high
-    BrightnessHigh,
-    /// brightness-high-fill
-    BrightnessHighFill,
-    /// brightness-low
-    BrightnessLow,
-    /// brightness-low-fill
-    BrightnessLowFill,
-    /// broadcast
-    Broadcast,
-    /// broadcast-pin
-    BroadcastPin,
-    /// brush
-    Brush,
-    /// brush-fill
-    BrushFill,
-    /// bucket
-    Bucket,
-    /// bucket-fill
-    BucketFill,
-    /// bug
-    Bug,
-    /// bug-fill
-    BugFill,
-    /// building
-    Building,
-    /// bullseye
-    Bullseye,
-    /// calculator
-    Calculator,
-    /// calculator-fill
-    CalculatorFill,
-    /// calendar
-    Calendar,
-    /// calendar-check
-    CalendarCheck,
-    /// calendar-check-fill
-    CalendarCheckFill,
-    /// calendar-date
-    CalendarDate,
-    /// calendar-date-fill
-    CalendarDateFill,
-    /// calendar-day
-    CalendarDay,
-    /// calendar-day-fill
-    CalendarDayFill,
-    /// calendar-event
-    CalendarEvent,
-    /// calendar-event-fill
-    CalendarEventFill,
-    /// calendar-fill
-    CalendarFill,
-    /// calendar-minus
-    CalendarMinus,
-    /// calendar-minus-fill
-    CalendarMinusFill,
-    /// calendar-month
-    CalendarMonth,
-    /// calendar-month-fill
-    CalendarMonthFill,
-    /// calendar-plus
-    CalendarPlus,
-    /// calendar-plus-fill
-    CalendarPlusFill,
-    /// calendar-range
-    CalendarRange,
-    /// calendar-range-fill
-    CalendarRangeFill,
-    /// calendar-week
-    CalendarWeek,
-    /// calendar-week-fill
-    CalendarWeekFill,
-    /// calendar-x
-    CalendarX,
-    /// calendar-x-fill
-    CalendarXFill,
-    /// calendar2
-    Calendar2,
-    /// calendar2-check
-    Calendar2Check,
-    /// calendar2-check-fill
-    Calendar2CheckFill,
-    /// calendar2-date
-    Calendar2Date,
-    /// calendar2-date-fill
-    Calendar2DateFill,
-    /// calendar2-day
-    Calendar2Day,
-    /// calendar2-day-fill
-    Calendar2DayFill,
-    /// calendar2-event
-    Calendar2Event,
-    /// calendar2-event-fill
-    Calendar2EventFill,
-    /// calendar2-fill
-    Calendar2Fill,
-    /// calendar2-minus
-    Calendar2Minus,
-    /// calendar2-minus-fill
-    Calendar2MinusFill,
-    /// calendar2-month
-    Calendar2Month,
-    /// calendar2-month-fill
-    Calendar2MonthFill,
-    /// calendar2-plus
-    Calendar2Plus,
-    /// calendar2-plus-fill
-    Calendar2PlusFill,
-    /// calendar2-range
-    Calendar2Range,
-    /// calendar2-range-fill
-    Calendar2RangeFill,
-    /// calendar2-week
-    Calendar2Week,
-    /// calendar2-week-fill
-    Calendar2WeekFill,
-    /// calendar2-x
-    Calendar2X,
-    /// calendar2-x-fill
-    Calendar2XFill,
-    /// calendar3
-    Calendar3,
-    /// calendar3-event
-    Calendar3Event,
-    /// calendar3-event-fill
-    Calendar3EventFill,
-    /// calendar3-fill
-    Calendar3Fill,
-    /// calendar3-range
-    Calendar3Range,
-    /// calendar3-range-fill
-    Calendar3RangeFill,
-    /// calendar3-week
-    Calendar3Week,
-    /// calendar3-week-fill
-    Calendar3WeekFill,
-    /// calendar4
-    Calendar4,
-    /// calendar4-event
-    Calendar4Event,
-    /// calendar4-range
-    Calendar4Range,
-    /// calendar4-week
-    Calendar4Week,
-    /// camera
-    Camera,
-    /// camera-fill
-    CameraFill,
-    /// camera-reels
-    CameraReels,
-    /// camera-reels-fill
-    CameraReelsFill,
-    /// camera-video
-    CameraVideo,
-    /// camera-video-fill
-    CameraVideoFill,
-    /// camera-video-off
-    CameraVideoOff,
-    /// camera-video-off-fill
-    CameraVideoOffFill,
-    /// camera2
-    Camera2,
-    /// capslock
-    Capslock,
-    /// capslock-fill
-    CapslockFill,
-    /// card-checklist
-    CardChecklist,
-    /// card-heading
-    CardHeading,
-    /// card-image
-    CardImage,
-    /// card-list
-    CardList,
-    /// card-text
-    CardText,
-    /// caret-down
-    CaretDown,
-    /// caret-down-fill
-    CaretDownFill,
-    /// caret-down-square
-    CaretDownSquare,
-    /// caret-down-square-fill
-    CaretDownSquareFill,
-    /// caret-left
-    CaretLeft,
-    /// caret-left-fill
-    CaretLeftFill,
-    /// caret-left-square
-    CaretLeftSquare,
-    /// caret-left-square-fill
-    CaretLeftSquareFill,
-    /// caret-right
-    CaretRight,
-    /// caret-right-fill
-    CaretRightFill,
-    /// caret-right-square
-    CaretRightSquare,
-    /// caret-right-square-fill
-    CaretRightSquareFill,
-    /// caret-up
-    CaretUp,
-    /// caret-up-fill
-    CaretUpFill,
-    /// caret-up-square
-    CaretUpSquare,
-    /// caret-up-square-fill
-    CaretUpSquareFill,
-    /// cart
-    Cart,
-    /// cart-check
-    CartCheck,
-    /// cart-check-fill
-    CartCheckFill,
-    /// cart-dash
-    CartDash,
-    /// cart-dash-fill
-    CartDashFill,
-    /// cart-fill
-    CartFill,
-    /// cart-plus
-    CartPlus,
-    /// cart-plus-fill
-    CartPlusFill,
-    /// cart-x
-    CartX,
-    /// cart-x-fill
-    CartXFill,
-    /// cart2
-    Cart2,
-    /// cart3
-    Cart3,
-    /// cart4
-    Cart4,
-    /// cash
-    Cash,
-    /// cash-stack
-    CashStack,
-    /// cast
-    Cast,
-    /// chat
-    Chat,
-    /// chat-dots
-    ChatDots,
-    /// chat-dots-fill
-    ChatDotsFill,
-    /// chat-fill
-    ChatFill,
-    /// chat-left
-    ChatLeft,
-    /// chat-left-dots
-    ChatLeftDots,
-    /// chat-left-dots-fill
-    ChatLeftDotsFill,
-    /// chat-left-fill
-    ChatLeftFill,
-    /// chat-left-quote
-    ChatLeftQuote,
-    /// chat-left-quote-fill
-    ChatLeftQuoteFill,
-    /// chat-left-text
-    ChatLeftText,
-    /// chat-left-text-fill
-    ChatLeftTextFill,
-    /// chat-quote
-    ChatQuote,
-    /// chat-quote-fill
-    ChatQuoteFill,
-    /// chat-right
-    ChatRight,
-    /// chat-right-dots
-    ChatRightDots,
-    /// chat-right-dots-fill
-    ChatRightDotsFill,
-    /// chat-right-fill
-    ChatRightFill,
-    /// chat-right-quote
-    ChatRightQuote,
-    /// chat-right-quote-fill
-    ChatRightQuoteFill,
-    /// chat-right-text
-    ChatRightText,
-    /// chat-right-text-fill
-    ChatRightTextFill,
-    /// chat-square
-    ChatSquare,
-    /// chat-square-dots
-    ChatSquareDots,
-    /// chat-square-dots-fill
-    ChatSquareDotsFill,
-    /// chat-square-fill
-    ChatSquareFill,
-    /// chat-square-quote
-    ChatSquareQuote,
-    /// chat-square-quote-fill
-    ChatSquareQuoteFill,
-    /// chat-square-text
-    ChatSquareText,
-    /// chat-square-text-fill
-    ChatSquareTextFill,
-    /// chat-text
-    ChatText,
-    /// chat-text-fill
-    ChatTextFill,
-    /// check
-    Check,
-    /// check-all
-    CheckAll,
-    /// check-circle
-    CheckCircle,
-    /// check-circle-fill
-    CheckCircleFill,
-    /// check-square
-    CheckSquare,
-    /// check-square-fill
-    CheckSquareFill,
-    /// check2
-    Check2,
-    /// check2-all
-    Check2All,
-    /// check2-circle
-    Check2Circle,
-    /// check2-square
-    Check2Square,
-    /// chevron-bar-contract
-    ChevronBarContract,
-    /// chevron-bar-down
-    ChevronBarDown,
-    /// chevron-bar-expand
-    ChevronBarExpand,
-    /// chevron-bar-left
-    ChevronBarLeft,
-    /// chevron-bar-right
-    ChevronBarRight,
-    /// chevron-bar-up
-    ChevronBarUp,
-    /// chevron-compact-down
-    ChevronCompactDown,
-    /// chevron-compact-left
-    ChevronCompactLeft,
-    /// chevron-compact-right
-    ChevronCompactRight,
-    /// chevron-compact-up
-    ChevronCompactUp,
-    /// chevron-contract
-    ChevronContract,
-    /// chevron-double-down
-    ChevronDoubleDown,
-    /// chevron-double-left
-    ChevronDoubleLeft,
-    /// chevron-double-right
-    ChevronDoubleRight,
-    /// chevron-double-up
-    ChevronDoubleUp,
-    /// chevron-down
-    ChevronDown,
-    /// chevron-expand
-    ChevronExpand,
-    /// chevron-left
-    ChevronLeft,
-    /// chevron-right
-    ChevronRight,
-    /// chevron-up
-    ChevronUp,
-    /// circle
-    Circle,
-    /// circle-fill
-    CircleFill,
-    /// circle-half
-    CircleHalf,
-    /// circle-square
-    CircleSquare,
-    /// clipboard
-    Clipboard,
-    /// clipboard-check
-    ClipboardCheck,
-    /// clipboard-data
-    ClipboardData,
-    /// clipboard-minus
-    ClipboardMinus,
-    /// clipboard-plus
-    ClipboardPlus,
-    /// clipboard-x
-    ClipboardX,
-    /// clock
-    Clock,
-    /// clock-fill
-    ClockFill,
-    /// clock-history
-    ClockHistory,
-    /// cloud
-    Cloud,
-    /// cloud-arrow-down
-    CloudArrowDown,
-    /// cloud-arrow-down-fill
-    CloudArrowDownFill,
-    /// cloud-arrow-up
-    CloudArrowUp,
-    /// cloud-arrow-up-fill
-    CloudArrowUpFill,
-    /// cloud-check
-    CloudCheck,
-    /// cloud-check-fill
-    CloudCheckFill,
-    /// cloud-download
-    CloudDownload,
-    /// cloud-download-fill
-    CloudDownloadFill,
-    /// cloud-fill
-    CloudFill,
-    /// cloud-minus
-    CloudMinus,
-    /// cloud-minus-fill
-    CloudMinusFill,
-    /// cloud-plus
-    CloudPlus,
-    /// cloud-plus-fill
-    CloudPlusFill,
-    /// cloud-slash
-    CloudSlash,
-    /// cloud-slash-fill
-    CloudSlashFill,
-    /// cloud-upload
-    CloudUpload,
-    /// cloud-upload-fill
-    CloudUploadFill,
-    /// code
-    Code,
-    /// code-slash
-    CodeSlash,
-    /// code-square
-    CodeSquare,
-    /// collection
-    Collection,
-    /// collection-fill
-    CollectionFill,
-    /// collection-play
-    CollectionPlay,
-    /// collection-play-fill
-    CollectionPlayFill,
-    /// columns
-    Columns,
-    /// columns-gap
-    ColumnsGap,
-    /// command
-    Command,
-    /// compass
-    Compass,
-    /// compass-fill
-    CompassFill,
-    /// cone
-    Cone,
-    /// cone-striped
-    ConeStriped,
-    /// controller
-    Controller,
-    /// cpu
-    Cpu,
-    /// cpu-fill
-    CpuFill,
-    /// credit-card
-    CreditCard,
-    /// credit-card-2-back
-    CreditCard2Back,
-    /// credit-card-2-back-fill
-    CreditCard2BackFill,
-    /// credit-card-2-front
-    CreditCard2Front,
-    /// credit-card-2-front-fill
-    CreditCard2FrontFill,
-    /// credit-card-fill
-    CreditCardFill,
-    /// crop
-    Crop,
-    /// cup
-    Cup,
-    /// cup-fill
-    CupFill,
-    /// cup-straw
-    CupStraw,
-    /// cursor
-    Cursor,
-    /// cursor-fill
-    CursorFill,
-    /// cursor-text
-    CursorText,
-    /// dash
-    Dash,
-    /// dash-circle
-    DashCircle,
-    /// dash-circle-fill
-    DashCircleFill,
-    /// dash-square
-    DashSquare,
-    /// dash-square-fill
-    DashSquareFill,
-    /// diagram-2
-    Diagram2,
-    /// diagram-2-fill
-    Diagram2Fill,
-    /// diagram-3
-    Diagram3,
-    /// diagram-3-fill
-    Diagram3Fill,
-    /// diamond
-    Diamond,
-    /// diamond-fill
-    DiamondFill,
-    /// diamond-half
-    DiamondHalf,
-    /// dice-1
-    Dice1,
-    /// dice-1-fill
-    Dice1Fill,
-    /// dice-2
-    Dice2,
-    /// dice-2-fill
-    Dice2Fill,
-    /// dice-3
-    Dice3,
-    /// dice-3-fill
-    Dice3Fill,
-    /// dice-4
-    Dice4,
-    /// dice-4-fill
-    Dice4Fill,
-    /// dice-5
-    Dice5,
-    /// dice-5-fill
-    Dice5Fill,
-    /// dice-6
-    Dice6,
-    /// dice-6-fill
-    Dice6Fill,
-    /// disc
-    Disc,
-    /// disc-fill
-    DiscFill,
-    /// discord
-    Discord,
-    /// display
-    Display,
-    /// display-fill
-    DisplayFill,
-    /// distribute-horizontal
-    DistributeHorizontal,
-    /// distribute-vertical
-    DistributeVertical,
-    /// door-closed
-    DoorClosed,
-    /// door-closed-fill
-    DoorClosedFill,
-    /// door-open
-    DoorOpen,
-    /// door-open-fill
-    DoorOpenFill,
-    /// dot
-    Dot,
-    /// download
-    Download,
-    /// droplet
-    Droplet,
-    /// droplet-fill
-    DropletFill,
-    /// droplet-half
-    DropletHalf,
-    /// earbuds
-    Earbuds,
-    /// easel
-    Easel,
-    /// easel-fill
-    EaselFill,
-    /// egg
-    Egg,
-    /// egg-fill
-    EggFill,
-    /// egg-fried
-    EggFried,
-    /// eject
-    Eject,
-    /// eject-fill
-    EjectFill,
-    /// emoji-angry
-    EmojiAngry,
-    /// emoji-angry-fill
-    EmojiAngryFill,
-    /// emoji-dizzy
-    EmojiDizzy,
-    /// emoji-dizzy-fill
-    EmojiDizzyFill,
-    /// emoji-expressionless
-    EmojiExpressionless,
-    /// emoji-expressionless-fill
-    EmojiExpressionlessFill,
-    /// emoji-frown
-    EmojiFrown,
-    /// emoji-frown-fill
-    EmojiFrownFill,
-    /// emoji-heart-eyes
-    EmojiHeartEyes,
-    /// emoji-heart-eyes-fill
-    EmojiHeartEyesFill,
-    /// emoji-laughing
-    EmojiLaughing,
-    /// emoji-laughing-fill
-    EmojiLaughingFill,
-    /// emoji-neutral
-    EmojiNeutral,
-    /// emoji-neutral-fill
-    EmojiNeutralFill,
-    /// emoji-smile
-    EmojiSmile,
-    /// emoji-smile-fill
-    EmojiSmileFill,
-    /// emoji-smile-upside-down
-    EmojiSmileUpsideDown,
-    /// emoji-smile-upside-down-fill
-    EmojiSmileUpsideDownFill,
-    /// emoji-sunglasses
-    EmojiSunglasses,
-    /// emoji-sunglasses-fill
-    EmojiSunglassesFill,
-    /// emoji-wink
-    EmojiWink,
-    /// emoji-wink-fill
-    EmojiWinkFill,
-    /// envelope
-    Envelope,
-    /// envelope-fill
-    EnvelopeFill,
-    /// envelope-open
-    EnvelopeOpen,
-    /// envelope-open-fill
-    EnvelopeOpenFill,
-    /// exclamation
-    Exclamation,
-    /// exclamation-circle
-    ExclamationCircle,
-    /// exclamation-circle-fill
-    ExclamationCircleFill,
-    /// exclamation-diamond
-    ExclamationDiamond,
-    /// exclamation-diamond-fill
-    ExclamationDiamondFill,
-    /// exclamation-octagon
-    ExclamationOctagon,
-    /// exclamation-octagon-fill
-    ExclamationOctagonFill,
-    /// exclamation-square
-    ExclamationSquare,
-    /// exclamation-square-fill
-    ExclamationSquareFill,
-    /// exclamation-triangle
-    ExclamationTriangle,
-    /// exclamation-triangle-fill
-    ExclamationTriangleFill,
-    /// exclude
-    Exclude,
-    /// eye
-    Eye,
-    /// eye-fill
-    EyeFill,
-    /// eye-slash
-    EyeSlash,
-    /// eye-slash-fill
-    EyeSlashFill,
-    /// eyeglasses
-    Eyeglasses,
-    /// facebook
-    Facebook,
-    /// file
-    File,
-    /// file-arrow-down
-    FileArrowDown,
-    /// file-arrow-down-fill
-    FileArrowDownFill,
-    /// file-arrow-up
-    FileArrowUp,
-    /// file-arrow-up-fill
-    FileArrowUpFill,
-    /// file-bar-graph
-    FileBarGraph,
-    /// file-bar-graph-fill
-    FileBarGraphFill,
-    /// file-binary
-    FileBinary,
-    /// file-binary-fill
-    FileBinaryFill,
-    /// file-break
-    FileBreak,
-    /// file-break-fill
-    FileBreakFill,
-    /// file-check
-    FileCheck,
-    /// file-check-fill
-    FileCheckFill,
-    /// file-code
-    FileCode,
-    /// file-code-fill
-    FileCodeFill,
-    /// file-diff
-    FileDiff,
-    /// file-diff-fill
-    FileDiffFill,
-    /// file-earmark
-    FileEarmark,
-    /// file-earmark-arrow-down
-    FileEarmarkArrowDown,
-    /// file-earmark-arrow-down-fill
-    FileEarmarkArrowDownFill,
-    /// file-earmark-arrow-up
-    FileEarmarkArrowUp,
-    /// file-earmark-arrow-up-fill
-    FileEarmarkArrowUpFill,
-    /// file-earmark-bar-graph
-    FileEarmarkBarGraph,
-    /// file-earmark-bar-graph-fill
-    FileEarmarkBarGraphFill,
-    /// file-earmark-binary
-    FileEarmarkBinary,
-    /// file-earmark-binary-fill
-    FileEarmarkBinaryFill,
-    /// file-earmark-break
-    FileEarmarkBreak,
-    /// file-earmark-break-fill
-    FileEarmarkBreakFill,
-    /// file-earmark-check
-    FileEarmarkCheck,
-    /// file-earmark-check-fill
-    FileEarmarkCheckFill,
-    /// file-earmark-code
-    FileEarmarkCode,
-    /// file-earmark-code-fill
-    FileEarmarkCodeFill,
-    /// file-earmark-diff
-    FileEarmarkDiff,
-    /// file-earmark-diff-fill
-    FileEarmarkDiffFill,
-    /// file-earmark-easel
-    FileEarmarkEasel,
-    /// file-earmark-easel-fill
-    FileEarmarkEaselFill,
-    /// file-earmark-excel
-    FileEarmarkExcel,
-    /// file-earmark-excel-fill
-    FileEarmarkExcelFill,
-    /// file-earmark-fill
-    FileEarmarkFill,
-    /// file-earmark-font
-    FileEarmarkFont,
-    /// file-earmark-font-fill
-    FileEarmarkFontFill,
-    /// file-earmark-image
-    FileEarmarkImage,
-    /// file-earmark-image-fill
-    FileEarmarkImageFill,
-    /// file-earmark-lock
-    FileEarmarkLock,
-    /// file-earmark-lock-fill
-    FileEarmarkLockFill,
-    /// file-earmark-lock2
-    FileEarmarkLock2,
-    /// file-earmark-lock2-fill
-    FileEarmarkLock2Fill,
-    /// file-earmark-medical
-    FileEarmarkMedical,
-    /// file-earmark-medical-fill
-    FileEarmarkMedicalFill,
-    /// file-earmark-minus
-    FileEarmarkMinus,
-    /// file-earmark-minus-fill
-    FileEarmarkMinusFill,
-    /// file-earmark-music
-    FileEarmarkMusic,
-    /// file-earmark-music-fill
-    FileEarmarkMusicFill,
-    /// file-earmark-person
-    FileEarmarkPerson,
-    /// file-earmark-person-fill
-    FileEarmarkPersonFill,
-    /// file-earmark-play
-    FileEarmarkPlay,
-    /// file-earmark-play-fill
-    FileEarmarkPlayFill,
-    /// file-earmark-plus
-    FileEarmarkPlus,
-    /// file-earmark-plus-fill
-    FileEarmarkPlusFill,
-    /// file-earmark-post
-    FileEarmarkPost,
-    /// file-earmark-post-fill
-    FileEarmarkPostFill,
-    /// file-earmark-ppt
-    FileEarmarkPpt,
-    /// file-earmark-ppt-fill
-    FileEarmarkPptFill,
-    /// file-earmark-richtext
-    FileEarmarkRichtext,
-    /// file-earmark-richtext-fill
-    FileEarmarkRichtextFill,
-    /// file-earmark-ruled
-    FileEarmarkRuled,
-    /// file-earmark-ruled-fill
-    FileEarmarkRuledFill,
-    /// file-earmark-slides
-    FileEarmarkSlides,
-    /// file-earmark-slides-fill
-    FileEarmarkSlidesFill,
-    /// file-earmark-spreadsheet
-    FileEarmarkSpreadsheet,
-    /// file-earmark-spreadsheet-fill
-    FileEarmarkSpreadsheetFill,
-    /// file-earmark-text
-    FileEarmarkText,
-    /// file-earmark-text-fill
-    FileEarmarkTextFill,
-    /// file-earmark-word
-    FileEarmarkWord,
-    /// file-earmark-word-fill
-    FileEarmarkWordFill,
-    /// file-earmark-x
-    FileEarmarkX,
-    /// file-earmark-x-fill
-    FileEarmarkXFill,
-    /// file-earmark-zip
-    FileEarmarkZip,
-    /// file-earmark-zip-fill
-    FileEarmarkZipFill,
-    /// file-easel
-    FileEasel,
-    /// file-easel-fill
-    FileEaselFill,
-    /// file-excel
-    FileExcel,
-    /// file-excel-fill
-    FileExcelFill,
-    /// file-fill
-    FileFill,
-    /// file-font
-    FileFont,
-    /// file-font-fill
-    FileFontFill,
-    /// file-image
-    FileImage,
-    /// file-image-fill
-    FileImageFill,
-    /// file-lock
-    FileLock,
-    /// file-lock-fill
-    FileLockFill,
-    /// file-lock2
-    FileLock2,
-    /// file-lock2-fill
-    FileLock2Fill,
-    /// file-medical
-    FileMedical,
-    /// file-medical-fill
-    FileMedicalFill,
-    /// file-minus
-    FileMinus,
-    /// file-minus-fill
-    FileMinusFill,
-    /// file-music
-    FileMusic,
-    /// file-music-fill
-    FileMusicFill,
-    /// file-person
-    FilePerson,
-    /// file-person-fill
-    FilePersonFill,
-    /// file-play
-    FilePlay,
-    /// file-play-fill
-    FilePlayFill,
-    /// file-plus
-    FilePlus,
-    /// file-plus-fill
-    FilePlusFill,
-    /// file-post
-    FilePost,
-    /// file-post-fill
-    FilePostFill,
-    /// file-ppt
-    FilePpt,
-    /// file-ppt-fill
-    FilePptFill,
-    /// file-richtext
-    FileRichtext,
-    /// file-richtext-fill
-    FileRichtextFill,
-    /// file-ruled
-    FileRuled,
-    /// file-ruled-fill
-    FileRuledFill,
-    /// file-slides
-    FileSlides,
-    /// file-slides-fill
-    FileSlidesFill,
-    /// file-spreadsheet
-    FileSpreadsheet,
-    /// file-spreadsheet-fill
-    FileSpreadsheetFill,
-    /// file-text
-    FileText,
-    /// file-text-fill
-    FileTextFill,
-    /// file-word
-    FileWord,
-    /// file-word-fill
-    FileWordFill,
-    /// file-x
-    FileX,
-    /// file-x-fill
-    FileXFill,
-    /// file-zip
-    FileZip,
-    /// file-zip-fill
-    FileZipFill,
-    /// files
-    Files,
-    /// files-alt
-    FilesAlt,
-    /// film
-    Film,
-    /// filter
-    Filter,
-    /// filter-circle
-    FilterCircle,
-    /// filter-circle-fill
-    FilterCircleFill,
-    /// filter-left
-    FilterLeft,
-    /// filter-right
-    FilterRight,
-    /// filter-square
-    FilterSquare,
-    /// filter-square-fill
-    FilterSquareFill,
-    /// flag
-    Flag,
-    /// flag-fill
-    FlagFill,
-    /// flower1
-    Flower1,
-    /// flower2
-    Flower2,
-    /// flower3
-    Flower3,
-    /// folder
-    Folder,
-    /// folder-check
-    FolderCheck,
-    /// folder-fill
-    FolderFill,
-    /// folder-minus
-    FolderMinus,
-    /// folder-plus
-    FolderPlus,
-    /// folder-symlink
-    FolderSymlink,
-    /// folder-symlink-fill
-    FolderSymlinkFill,
-    /// folder-x
-    FolderX,
-    /// folder2
-    Folder2,
-    /// folder2-open
-    Folder2Open,
-    /// fonts
-    Fonts,
-    /// forward
-    Forward,
-    /// forward-fill
-    ForwardFill,
-    /// front
-    Front,
-    /// fullscreen
-    Fullscreen,
-    /// fullscreen-exit
-    FullscreenExit,
-    /// funnel
-    Funnel,
-    /// funnel-fill
-    FunnelFill,
-    /// gear
-    Gear,
-    /// gear-fill
-    GearFill,
-    /// gear-wide
-    GearWide,
-    /// gear-wide-connected
-    GearWideConnected,
-    /// gem
-    Gem,
-    /// geo
-    Geo,
-    /// geo-alt
-    GeoAlt,
-    /// geo-alt-fill
-    GeoAltFill,
-    /// geo-fill
-    GeoFill,
-    /// gift
-    Gift,
-    /// gift-fill
-    GiftFill,
-    /// github
-    Github,
-    /// globe
-    Globe,
-    /// globe2
-    Globe2,
-    /// google
-    Google,
-    /// graph-down
-    GraphDown,
-    /// graph-up
-    GraphUp,
-    /// grid
-    Grid,
-    /// grid-1x2
-    Grid1X2,
-    /// grid-1x2-fill
-    Grid1X2Fill,
-    /// grid-3x2
-    Grid3X2,
-    /// grid-3x2-gap
-    Grid3X2Gap,
-    /// grid-3x2-gap-fill
-    Grid3X2GapFill,
-    /// grid-3x3
-    Grid3X3,
-    /// grid-3x3-gap
-    Grid3X3Gap,
-    /// grid-3x3-gap-fill
-    Grid3X3GapFill,
-    /// grid-fill
-    GridFill,
-    /// grip-horizontal
-    GripHorizontal,
-    /// grip-vertical
-    GripVertical,
-    /// hammer
-    Hammer,
-    /// hand-index
-    HandIndex,
-    /// hand-index-thumb
-    HandIndexThumb,
-    /// hand-thumbs-down
-    HandThumbsDown,
-    /// hand-thumbs-up
-    HandThumbsUp,
-    /// handbag
-    Handbag,
-    /// handbag-fill
-    HandbagFill,
-    /// hash
-    Hash,
-    /// hdd
-    Hdd,
-    /// hdd-fill
-    HddFill,
-    /// hdd-network
-    HddNetwork,
-    /// hdd-network-fill
-    HddNetworkFill,
-    /// hdd-rack
-    HddRack,
-    /// hdd-rack-fill
-    HddRackFill,
-    /// hdd-stack
-    HddStack,
-    /// hdd-stack-fill
-    HddStackFill,
-    /// headphones
-    Headphones,
-    /// headset
-    Headset,
-    /// heart
-    Heart,
-    /// heart-fill
-    HeartFill,
-    /// heart-half
-    HeartHalf,
-    /// heptagon
-    Heptagon,
-    /// heptagon-fill
-    HeptagonFill,
-    /// heptagon-half
-    HeptagonHalf,
-    /// hexagon
-    Hexagon,
-    /// hexagon-fill
-    HexagonFill,
-    /// hexagon-half
-    HexagonHalf,
-    /// hourglass
-    Hourglass,
-    /// hourglass-bottom
-    HourglassBottom,
-    /// hourglass-split
-    HourglassSplit,
-    /// hourglass-top
-    HourglassTop,
-    /// house
-    House,
-    /// house-door
-    HouseDoor,
-    /// house-door-fill
-    HouseDoorFill,
-    /// house-fill
-    HouseFill,
-    /// hr
-    Hr,
-    /// image
-    Image,
-    /// image-alt
-    ImageAlt,
-    /// image-fill
-    ImageFill,
-    /// images
-    Images,
-    /// inbox
-    Inbox,
-    /// inbox-fill
-    InboxFill,
-    /// inboxes
-    Inboxes,
-    /// inboxes-fill
-    InboxesFill,
-    /// info
-    Info,
-    /// info-circle
-    InfoCircle,
-    /// info-circle-fill
-    InfoCircleFill,
-    /// info-square
-    InfoSquare,
-    /// info-square-fill
-    InfoSquareFill,
-    /// input-cursor
-    InputCursor,
-    /// input-cursor-text
-    InputCursorText,
-    /// instagram
-    Instagram,
-    /// intersect
-    Intersect,
-    /// journal
-    Journal,
-    /// journal-album
-    JournalAlbum,
-    /// journal-arrow-down
-    JournalArrowDown,
-    /// journal-arrow-up
-    JournalArrowUp,
-    /// journal-bookmark
-    JournalBookmark,
-    /// journal-bookmark-fill
-    JournalBookmarkFill,
-    /// journal-check
-    JournalCheck,
-    /// journal-code
-    JournalCode,
-    /// journal-medical
-    JournalMedical,
-    /// journal-minus
-    JournalMinus,
-    /// journal-plus
-    JournalPlus,
-    /// journal-richtext
-    JournalRichtext,
-    /// journal-text
-    JournalText,
-    /// journal-x
-    JournalX,
-    /// journals
-    Journals,
-    /// joystick
-    Joystick,
-    /// justify
-    Justify,
-    /// justify-left
-    JustifyLeft,
-    /// justify-right
-    JustifyRight,
-    /// kanban
-    Kanban,
-    /// kanban-fill
-    KanbanFill,
-    /// key
-    Key,
-    /// key-fill
-    KeyFill,
-    /// keyboard
-    Keyboard,
-    /// keyboard-fill
-    KeyboardFill,
-    /// ladder
-    Ladder,
-    /// lamp
-    Lamp,
-    /// lamp-fill
-    LampFill,
-    /// laptop
-    Laptop,
-    /// laptop-fill
-    LaptopFill,
-    /// layers
-    Layers,
-    /// layers-fill
-    LayersFill,
-    /// layers-half
-    LayersHalf,
-    /// layout-sidebar
-    LayoutSidebar,
-    /// layout-sidebar-inset
-    LayoutSidebarInset,
-    /// layout-sidebar-inset-reverse
-    LayoutSidebarInsetReverse,
-    /// layout-sidebar-reverse
-    LayoutSidebarReverse,
-    /// layout-split
-    LayoutSplit,
-    /// layout-text-sidebar
-    LayoutTextSidebar,
-    /// layout-text-sidebar-reverse
-    LayoutTextSidebarReverse,
-    /// layout-text-window
-    LayoutTextWindow,
-    /// layout-text-window-reverse
-    LayoutTextWindowReverse,
-    /// layout-three-columns
-    LayoutThreeColumns,
-    /// layout-wtf
-    LayoutWtf,
-    /// life-preserver
-    LifePreserver,
-    /// lightning
-    Lightning,
-    /// lightning-fill
-    LightningFill,
-    /// link
-    Link,
-    /// link-45deg
-    Link45Deg,
-    /// linkedin
-    Linkedin,
-    /// list
-    List,
-    /// list-check
-    ListCheck,
-    /// list-nested
-    ListNested,
-    /// list-ol
-    ListOl,
-    /// list-stars
-    ListStars,
-    /// list-task
-    ListTask,
-    /// list-ul
-    ListUl,
-    /// lock
-    Lock,
-    /// lock-fill
-    LockFill,
-    /// mailbox
-    Mailbox,
-    /// mailbox2
-    Mailbox2,
-    /// map
-    Map,
-    /// map-fill
-    MapFill,
-    /// markdown
-    Markdown,
-    /// markdown-fill
-    MarkdownFill,
-    /// menu-app
-    MenuApp,
-    /// menu-app-fill
-    MenuAppFill,
-    /// menu-button
-    MenuButton,
-    /// menu-button-fill
-    MenuButtonFill,
-    /// menu-button-wide
-    MenuButtonWide,
-    /// menu-button-wide-fill
-    MenuButtonWideFill,
-    /// menu-down
-    MenuDown,
-    /// menu-up
-    MenuUp,
-    /// mic
-    Mic,
-    /// mic-fill
-    MicFill,
-    /// mic-mute
-    MicMute,
-    /// mic-mute-fill
-    MicMuteFill,
-    /// minecart
-    Minecart,
-    /// minecart-loaded
-    MinecartLoaded,
-    /// moon
-    Moon,
-    /// mouse
-    Mouse,
-    /// mouse2
-    Mouse2,
-    /// mouse3
-    Mouse3,
-    /// music-note
-    MusicNote,
-    /// music-note-beamed
-    MusicNoteBeamed,
-    /// music-note-list
-    MusicNoteList,
-    /// music-player
-    MusicPlayer,
-    /// music-player-fill
-    MusicPlayerFill,
-    /// newspaper
-    Newspaper,
-    /// node-minus
-    NodeMinus,
-    /// node-minus-fill
-    NodeMinusFill,
-    /// node-plus
-    NodePlus,
-    /// node-plus-fill
-    NodePlusFill,
-    /// nut
-    Nut,
-    /// nut-fill
-    NutFill,
-    /// octagon
-    Octagon,
-    /// octagon-fill
-    OctagonFill,
-    /// octagon-half
-    OctagonHalf,
-    /// option
-    Option,
-    /// outlet
-    Outlet,
-    /// paperclip
-    Paperclip,
-    /// paragraph
-    Paragraph,
-    /// patch-check
-    PatchCheck,
-    /// patch-check-fll
-    PatchCheckFll,
-    /// patch-exclamation
-    PatchExclamation,
-    /// patch-exclamation-fll
-    PatchExclamationFll,
-    /// patch-minus
-    PatchMinus,
-    /// patch-minus-fll
-    PatchMinusFll,
-    /// patch-plus
-    PatchPlus,
-    /// patch-plus-fll
-    PatchPlusFll,
-    /// patch-question
-    PatchQuestion,
-    /// patch-question-fll
-    PatchQuestionFll,
-    /// pause
-    Pause,
-    /// pause-btn
-    PauseBtn,
-    /// pause-btn-fill
-    PauseBtnFill,
-    /// pause-circle
-    PauseCircle,
-    /// pause-circle-fill
-    PauseCircleFill,
-    /// pause-fill
-    PauseFill,
-    /// peace
-    Peace,
-    /// peace-fill
-    PeaceFill,
-    /// pen
-    Pen,
-    /// pen-fill
-    PenFill,
-    /// pencil
-    Pencil,
-    /// pencil-fill
-    PencilFill,
-    /// pencil-square
-    PencilSquare,
-    /// pentagon
-    Pentagon,
-    /// pentagon-fill
-    PentagonFill,
-    /// pentagon-half
-    PentagonHalf,
-    /// people
-    People,
-    /// people-fill
-    PeopleFill,
-    /// percent
-    Percent,
-    /// person
-    Person,
-    /// person-badge
-    PersonBadge,
-    /// person-badge-fill
-    PersonBadgeFill,
-    /// person-bounding-box
-    PersonBoundingBox,
-    /// person-check
-    PersonCheck,
-    /// person-check-fill
-    PersonCheckFill,
-    /// person-circle
-    PersonCircle,
-    /// person-dash
-    PersonDash,
-    /// person-dash-fill
-    PersonDashFill,
-    /// person-fill
-    PersonFill,
-    /// person-lines-fill
-    PersonLinesFill,
-    /// person-plus
-    PersonPlus,
-    /// person-plus-fill
-    PersonPlusFill,
-    /// person-square
-    PersonSquare,
-    /// person-x
-    PersonX,
-    /// person-x-fill
-    PersonXFill,
-    /// phone
-    Phone,
-    /// phone-fill
-    PhoneFill,
-    /// phone-landscape
-    PhoneLandscape,
-    /// phone-landscape-fill
-    PhoneLandscapeFill,
-    /// phone-vibrate
-    PhoneVibrate,
-    /// pie-chart
-    PieChart,
-    /// pie-chart-fill
-    PieChartFill,
-    /// pip
-    Pip,
-    /// pip-fill
-    PipFill,
-    /// play
-    Play,
-    /// play-btn
-    PlayBtn,
-    /// play-btn-fill
-    PlayBtnFill,
-    /// play-circle
-    PlayCircle,
-    /// play-circle-fill
-    PlayCircleFill,
-    /// play-fill
-    PlayFill,
-    /// plug
-    Plug,
-    /// plug-fill
-    PlugFill,
-    /// plus
-    Plus,
-    /// plus-circle
-    PlusCircle,
-    /// plus-circle-fill
-    PlusCircleFill,
-    /// plus-square
-    PlusSquare,
-    /// plus-square-fill
-    PlusSquareFill,
-    /// power
-    Power,
-    /// printer
-    Printer,
-    /// printer-fill
-    PrinterFill,
-    /// puzzle
-    Puzzle,
-    /// puzzle-fill
-    PuzzleFill,
-    /// question
-    Question,
-    /// question-circle
-    QuestionCircle,
-    /// question-circle-fill
-    QuestionCircleFill,
-    /// question-diamond
-    QuestionDiamond,
-    /// question-diamond-fill
-    QuestionDiamondFill,
-    /// question-octagon
-    QuestionOctagon,
-    /// question-octagon-fill
-    QuestionOctagonFill,
-    /// question-square
-    QuestionSquare,
-    /// question-square-fill
-    QuestionSquareFill,
-    /// receipt
-    Receipt,
-    /// receipt-cutoff
-    ReceiptCutoff,
-    /// reception-0
-    Reception0,
-    /// reception-1
-    Reception1,
-    /// reception-2
-    Reception2,
-    /// reception-3
-    Reception3,
-    /// reception-4
-    Reception4,
-    /// record
-    Record,
-    /// record-btn
-    RecordBtn,
-    /// record-btn-fill
-    RecordBtnFill,
-    /// record-circle
-    RecordCircle,
-    /// record-circle-fill
-    RecordCircleFill,
-    /// record-fill
-    RecordFill,
-    /// record2
-    Record2,
-    /// record2-fill
-    Record2Fill,
-    /// reply
-    Reply,
-    /// reply-all
-    ReplyAll,
-    /// reply-all-fill
-    ReplyAllFill,
-    /// reply-fill
-    ReplyFill,
-    /// rss
-    Rss,
-    /// rss-fill
-    RssFill,
-    /// scissors
-    Scissors,
-    /// screwdriver
-    Screwdriver,
-    /// search
-    Search,
-    /// segmented-nav
-    SegmentedNav,
-    /// server
-    Server,
-    /// share
-    Share,
-    /// share-fill
-    ShareFill,
-    /// shield
-    Shield,
-    /// shield-check
-    ShieldCheck,
-    /// shield-exclamation
-    ShieldExclamation,
-    /// shield-fill
-    ShieldFill,
-    /// shield-fill-check
-    ShieldFillCheck,
-    /// shield-fill-exclamation
-    ShieldFillExclamation,
-    /// shield-fill-minus
-    ShieldFillMinus,
-    /// shield-fill-plus
-    ShieldFillPlus,
-    /// shield-fill-x
-    ShieldFillX,
-    /// shield-lock
-    ShieldLock,
-    /// shield-lock-fill
-    ShieldLockFill,
-    /// shield-minus
-    ShieldMinus,
-    /// shield-plus
-    ShieldPlus,
-    /// shield-shaded
-    ShieldShaded,
-    /// shield-slash
-    ShieldSlash,
-    /// shield-slash-fill
-    ShieldSlashFill,
-    /// shield-x
-    ShieldX,
-    /// shift
-    Shift,
-    /// shift-fill
-    ShiftFill,
-    /// shop
-    Shop,
-    /// shop-window
-    ShopWindow,
-    /// shuffle
-    Shuffle,
-    /// signpost
-    Signpost,
-    /// signpost-2
-    Signpost2,
-    /// signpost-2-fill
-    Signpost2Fill,
-    /// signpost-fill
-    SignpostFill,
-    /// signpost-split
-    SignpostSplit,
-    /// signpost-split-fill
-    SignpostSplitFill,
-    /// sim
-    Sim,
-    /// sim-fill
-    SimFill,
-    /// skip-backward
-    SkipBackward,
-    /// skip-backward-btn
-    SkipBackwardBtn,
-    /// skip-backward-btn-fill
-    SkipBackwardBtnFill,
-    /// skip-backward-circle
-    SkipBackwardCircle,
-    /// skip-backward-circle-fill
-    SkipBackwardCircleFill,
-    /// skip-backward-fill
-    SkipBackwardFill,
-    /// skip-end
-    SkipEnd,
-    /// skip-end-btn
-    SkipEndBtn,
-    /// skip-end-btn-fill
-    SkipEndBtnFill,
-    /// skip-end-circle
-    SkipEndCircle,
-    /// skip-end-circle-fill
-    SkipEndCircleFill,
-    /// skip-end-fill
-    SkipEndFill,
-    /// skip-forward
-    SkipForward,
-    /// skip-forward-btn
-    SkipForwardBtn,
-    /// skip-forward-btn-fill
-    SkipForwardBtnFill,
-    /// skip-forward-circle
-    SkipForwardCircle,
-    /// skip-forward-circle-fill
-    SkipForwardCircleFill,
-    /// skip-forward-fill
-    SkipForwardFill,
-    /// skip-start
-    SkipStart,
-    /// skip-start-btn
-    SkipStartBtn,
-    /// skip-start-btn-fill
-    SkipStartBtnFill,
-    /// skip-start-circle
-    SkipStartCircle,
-    /// skip-start-circle-fill
-    SkipStartCircleFill,
-    /// skip-start-fill
-    SkipStartFill,
-    /// slack
-    Slack,
-    /// slash
-    Slash,
-    /// slash-circle
-    SlashCircle,
-    /// slash-circle-fill
-    SlashCircleFill,
-    /// slash-square
-    SlashSquare,
-    /// slash-square-fill
-    SlashSquareFill,
-    /// sliders
-    Sliders,
-    /// smartwatch
-    Smartwatch,
-    /// sort-alpha-down
-    SortAlphaDown,
-    /// sort-alpha-down-alt
-    SortAlphaDownAlt,
-    /// sort-alpha-up
-    SortAlphaUp,
-    /// sort-alpha-up-alt
-    SortAlphaUpAlt,
-    /// sort-down
-    SortDown,
-    /// sort-down-alt
-    SortDownAlt,
-    /// sort-numeric-down
-    SortNumericDown,
-    /// sort-numeric-down-alt
-    SortNumericDownAlt,
-    /// sort-numeric-up
-    SortNumericUp,
-    /// sort-numeric-up-alt
-    SortNumericUpAlt,
-    /// sort-up
-    SortUp,
-    /// sort-up-alt
-    SortUpAlt,
-    /// soundwave
-    Soundwave,
-    /// speaker
-    Speaker,
-    /// speaker-fill
-    SpeakerFill,
-    /// spellcheck
-    Spellcheck,
-    /// square
-    Square,
-    /// square-fill
-    SquareFill,
-    /// square-half
-    SquareHalf,
-    /// star
-    Star,
-    /// star-fill
-    StarFill,
-    /// star-half
-    StarHalf,
-    /// stickies
-    Stickies,
-    /// stickies-fill
-    StickiesFill,
-    /// sticky
-    Sticky,
-    /// sticky-fill
-    StickyFill,
-    /// stop
-    Stop,
-    /// stop-btn
-    StopBtn,
-    /// stop-btn-fill
-    StopBtnFill,
-    /// stop-circle
-    StopCircle,
-    /// stop-circle-fill
-    StopCircleFill,
-    /// stop-fill
-    StopFill,
-    /// stoplights
-    Stoplights,
-    /// stoplights-fill
-    StoplightsFill,
-    /// stopwatch
-    Stopwatch,
-    /// stopwatch-fill
-    StopwatchFill,
-    /// subtract
-    Subtract,
-    /// suit-club
-    SuitClub,
-    /// suit-club-fill
-    SuitClubFill,
-    /// suit-diamond
-    SuitDiamond,
-    /// suit-diamond-fill
-    SuitDiamondFill,
-    /// suit-heart
-    SuitHeart,
-    /// suit-heart-fill
-    SuitHeartFill,
-    /// suit-spade
-    SuitSpade,
-    /// suit-spade-fill
-    SuitSpadeFill,
-    /// sun
-    Sun,
-    /// sunglasses
-    Sunglasses,
-    /// table
-    Table,
-    /// tablet
-    Tablet,
-    /// tablet-fill
-    TabletFill,
-    /// tablet-landscape
-    TabletLandscape,
-    /// tablet-landscape-fill
-    TabletLandscapeFill,
-    /// tag
-    Tag,
-    /// tag-fill
-    TagFill,
-    /// tags
-    Tags,
-    /// tags-fill
-    TagsFill,
-    /// telephone
-    Telephone,
-    /// telephone-fill
-    TelephoneFill,
-    /// telephone-forward
-    TelephoneForward,
-    /// telephone-forward-fill
-    TelephoneForwardFill,
-    /// telephone-inbound
-    TelephoneInbound,
-    /// telephone-inbound-fill
-    TelephoneInboundFill,
-    /// telephone-minus
-    TelephoneMinus,
-    /// telephone-minus-fill
-    TelephoneMinusFill,
-    /// telephone-outbound
-    TelephoneOutbound,
-    /// telephone-outbound-fill
-    TelephoneOutboundFill,
-    /// telephone-plus
-    TelephonePlus,
-    /// telephone-plus-fill
-    TelephonePlusFill,
-    /// telephone-x
-    TelephoneX,
-    /// telephone-x-fill
-    TelephoneXFill,
-    /// terminal
-    Terminal,
-    /// terminal-fill
-    TerminalFill,
-    /// text-center
-    TextCenter,
-    /// text-indent-left
-    TextIndentLeft,
-    /// text-indent-right
-    TextIndentRight,
-    /// text-left
-    TextLeft,
-    /// text-paragraph
-    TextParagraph,
-    /// text-right
-    TextRight,
-    /// textarea
-    Textarea,
-    /// textarea-resize
-    TextareaResize,
-    /// textarea-t
-    TextareaT,
-    /// thermometer
-    Thermometer,
-    /// thermometer-half
-    ThermometerHalf,
-    /// three-dots
-    ThreeDots,
-    /// three-dots-vertical
-    ThreeDotsVertical,
-    /// toggle-off
-    ToggleOff,
-    /// toggle-on
-    ToggleOn,
-    /// toggle2-off
-    Toggle2Off,
-    /// toggle2-on
-    Toggle2On,
-    /// toggles
-    Toggles,
-    /// toggles2
-    Toggles2,
-    /// tools
-    Tools,
-    /// trash
-    Trash,
-    /// trash-fill
-    TrashFill,
-    /// trash2
-    Trash2,
-    /// trash2-fill
-    Trash2Fill,
-    /// tree
-    Tree,
-    /// tree-fill
-    TreeFill,
-    /// triangle
-    Triangle,
-    /// triangle-fill
-    TriangleFill,
-    /// triangle-half
-    TriangleHalf,
-    /// trophy
-    Trophy,
-    /// trophy-fill
-    TrophyFill,
-    /// truck
-    Truck,
-    /// truck-flatbed
-    TruckFlatbed,
-    /// tv
-    Tv,
-    /// tv-fill
-    TvFill,
-    /// twitch
-    Twitch,
-    /// twitter
-    Twitter,
-    /// type
-    Type,
-    /// type-bold
-    TypeBold,
-    /// type-h1
-    TypeH1,
-    /// type-h2
-    TypeH2,
-    /// type-h3
-    TypeH3,
-    /// type-italic
-    TypeItalic,
-    /// type-strikethrough
-    TypeStrikethrough,
-    /// type-underline
-    TypeUnderline,
-    /// ui-checks
-    UiChecks,
-    /// ui-checks-grid
-    UiChecksGrid,
-    /// ui-radios
-    UiRadios,
-    /// ui-radios-grid
-    UiRadiosGrid,
-    /// union
-    Union,
-    /// unlock
-    Unlock,
-    /// unlock-fill
-    UnlockFill,
-    /// upc
-    Upc,
-    /// upc-scan
-    UpcScan,
-    /// upload
-    Upload,
-    /// vector-pen
-    VectorPen,
-    /// view-list
-    ViewList,
-    /// view-stacked
-    ViewStacked,
-    /// vinyl
-    Vinyl,
-    /// vinyl-fill
-    VinylFill,
-    /// voicemail
-    Voicemail,
-    /// volume-down
-    VolumeDown,
-    /// volume-down-fill
-    VolumeDownFill,
-    /// volume-mute
-    VolumeMute,
-    /// volume-mute-fill
-    VolumeMuteFill,
-    /// volume-off
-    VolumeOff,
-    /// volume-off-fill
-    VolumeOffFill,
-    /// volume-up
-    VolumeUp,
-    /// volume-up-fill
-    VolumeUpFill,
-    /// vr
-    Vr,
-    /// wallet
-    Wallet,
-    /// wallet-fill
-    WalletFill,
-    /// wallet2
-    Wallet2,
-    /// watch
-    Watch,
-    /// wifi
-    Wifi,
-    /// wifi-1
-    Wifi1,
-    /// wifi-2
-    Wifi2,
-    /// wifi-off
-    WifiOff,
-    /// window
-    Window,
-    /// wrench
-    Wrench,
-    /// x
-    X,
-    /// x-circle
-    XCircle,
-    /// x-circle-fill
-    XCircleFill,
-    /// x-diamond
-    XDiamond,
-    /// x-diamond-fill
-    XDiamondFill,
-    /// x-octagon
-    XOctagon,
-    /// x-octagon-fill
-    XOctagonFill,
-    /// x-square
-    XSquare,
-    /// x-square-fill
-    XSquareFill,
-    /// youtube
-    Youtube,
-    /// zoom-in
-    ZoomIn,
-    /// zoom-out
-    ZoomOut,
-}
-
-/// Converts an icon into a char.
-#[must_use]
-#[allow(clippy::too_many_lines)]
-pub const fn icon_to_char(icon: Icon) -> char {
-    match icon {
-        Icon::Alarm => '\u{f102}',
-        Icon::AlarmFill => '\u{f101}',
-        Icon::AlignBottom => '\u{f103}',
-        Icon::AlignCenter => '\u{f104}',
-        Icon::AlignEnd => '\u{f105}',
-        Icon::AlignMiddle => '\u{f106}',
-        Icon::AlignStart => '\u{f107}',
-        Icon::AlignTop => '\u{f108}',
-        Icon::Alt => '\u{f109}',
-        Icon::App => '\u{f10b}',
-        Icon::AppIndicator => '\u{f10a}',
-        Icon::Archive => '\u{f10d}',
-        Icon::ArchiveFill => '\u{f10c}',
-        Icon::Arrow90DegDown => '\u{f10e}',
-        Icon::Arrow90DegLeft => '\u{f10f}',
-        Icon::Arrow90DegRight => '\u{f110}',
-        Icon::Arrow90DegUp => '\u{f111}',
-        Icon::ArrowBarDown => '\u{f112}',
-        Icon::ArrowBarLeft => '\u{f113}',
-        Icon::ArrowBarRight => '\u{f114}',
-        Icon::ArrowBarUp => '\u{f115}',
-        Icon::ArrowClockwise => '\u{f116}',
-        Icon::ArrowCounterclockwise => '\u{f117}',
-        Icon::ArrowDown => '\u{f128}',
-        Icon::ArrowDownCircle => '\u{f119}',
-        Icon::ArrowDownCircleFill => '\u{f118}',
-        Icon::ArrowDownLeft => '\u{f11e}',
-        Icon::ArrowDownLeftCircle => '\u{f11b}',
-        Icon::ArrowDownLeftCircleFill => '\u{f11a}',
-        Icon::ArrowDownLeftSquare => '\u{f11d}',
-        Icon::ArrowDownLeftSquareFill => '\u{f11c}',
-        Icon::ArrowDownRight => '\u{f123}',
-        Icon::ArrowDownRightCircle => '\u{f120}',
-        Icon::ArrowDownRightCircleFill => '\u{f11f}',
-        Icon::ArrowDownRightSquare => '\u{f122}',
-        Icon::ArrowDownRightSquareFill => '\u{f121}',
-        Icon::ArrowDownShort => '\u{f124}',
-        Icon::ArrowDownSquare => '\u{f126}',
-        Icon::ArrowDownSquareFill => '\u{f125}',
-        Icon::ArrowDownUp => '\u{f127}',
-        Icon::ArrowLeft => '\u{f12f}',
-        Icon::ArrowLeftCircle => '\u{f12a}',
-        Icon::ArrowLeftCircleFill => '\u{f129}',
-        Icon::ArrowLeftRight => '\u{f12b}',
-        Icon::ArrowLeftShort => '\u{f12c}',
-        Icon::ArrowLeftSquare => '\u{f12e}',
-        Icon::ArrowLeftSquareFill => '\u{f12d}',
-        Icon::ArrowRepeat => '\u{f130}',
-        Icon::ArrowReturnLeft => '\u{f131}',
-        Icon::ArrowReturnRight => '\u{f132}',
-        Icon::ArrowRight => '\u{f138}',
-        Icon::ArrowRightCircle => '\u{f134}',
-        Icon::ArrowRightCircleFill => '\u{f133}',
-        Icon::ArrowRightShort => '\u{f135}',
-        Icon::ArrowRightSquare => '\u{f137}',
-        Icon::ArrowRightSquareFill => '\u{f136}',
-        Icon::ArrowUp => '\u{f148}',
-        Icon::ArrowUpCircle => '\u{f13a}',
-        Icon::ArrowUpCircleFill => '\u{f139}',
-        Icon::ArrowUpLeft => '\u{f13f}',
-        Icon::ArrowUpLeftCircle => '\u{f13c}',
-        Icon::ArrowUpLeftCircleFill => '\u{f13b}',
-        Icon::ArrowUpLeftSquare => '\u{f13e}',
-        Icon::ArrowUpLeftSquareFill => '\u{f13d}',
-        Icon::ArrowUpRight => '\u{f144}',
-        Icon::ArrowUpRightCircle => '\u{f141}',
-        Icon::ArrowUpRightCircleFill => '\u{f140}',
-        Icon::ArrowUpRightSquare => '\u{f143}',
-        Icon::ArrowUpRightSquareFill => '\u{f142}',
-        Icon::ArrowUpShort => '\u{f145}',
-        Icon::ArrowUpSquare => '\u{f147}',
-        Icon::ArrowUpSquareFill => '\u{f146}',
-        Icon::ArrowsAngleContract => '\u{f149}',
-        Icon::ArrowsAngleExpand => '\u{f14a}',
-        Icon::ArrowsCollapse => '\u{f14b}',
-        Icon::ArrowsExpand => '\u{f14c}',
-        Icon::ArrowsFullscreen => '\u{f14d}',
-        Icon::ArrowsMove => '\u{f14e}',
-        Icon::AspectRatio => '\u{f150}',
-        Icon::AspectRatioFill => '\u{f14f}',
-        Icon::Asterisk => '\u{f151}',
-        Icon::At => '\u{f152}',
-        Icon::Award => '\u{f154}',
-        Icon::AwardFill => '\u{f153}',
-        Icon::Back => '\u{f155}',
-        Icon::Backspace => '\u{f159}',
-        Icon::BackspaceFill => '\u{f156}',
-        Icon::BackspaceReverse => '\u{f158}',
-        Icon::BackspaceReverseFill => '\u{f157}',
-        Icon::Badge4K => '\u{f15b}',
-        Icon::Badge4KFill => '\u{f15a}',
-        Icon::Badge8K => '\u{f15d}',
-        Icon::Badge8KFill => '\u{f15c}',
-        Icon::BadgeAd => '\u{f15f}',
-        Icon::BadgeAdFill => '\u{f15e}',
-        Icon::BadgeCc => '\u{f161}',
-        Icon::BadgeCcFill => '\u{f160}',
-        Icon::BadgeHd => '\u{f163}',
-        Icon::BadgeHdFill => '\u{f162}',
-        Icon::BadgeTm => '\u{f165}',
-        Icon::BadgeTmFill => '\u{f164}',
-        Icon::BadgeVo => '\u{f167}',
-        Icon::BadgeVoFill => '\u{f166}',
-        Icon::Bag => '\u{f171}',
-        Icon::BagCheck => '\u{f169}',
-        Icon::BagCheckFill => '\u{f168}',
-        Icon::BagDash => '\u{f16b}',
-        Icon::BagDashFill => '\u{f16a}',
-        Icon::BagFill => '\u{f16c}',
-        Icon::BagPlus => '\u{f16e}',
-        Icon::BagPlusFill => '\u{f16d}',
-        Icon::BagX => '\u{f170}',
-        Icon::BagXFill => '\u{f16f}',
-        Icon::BarChart => '\u{f176}',
-        Icon::BarChartFill => '\u{f172}',
-        Icon::BarChartLine => '\u{f174}',
-        Icon::BarChartLineFill => '\u{f173}',
-        Icon::BarChartSteps => '\u{f175}',
-        Icon::Basket => '\u{f178}',
-        Icon::BasketFill => '\u{f177}',
-        Icon::Basket2 => '\u{f17a}',
-        Icon::Basket2Fill => '\u{f179}',
-        Icon::Basket3 => '\u{f17c}',
-        Icon::Basket3Fill => '\u{f17b}',
-        Icon::Battery => '\u{f180}',
-        Icon::BatteryCharging => '\u{f17d}',
-        Icon::BatteryFull => '\u{f17e}',
-        Icon::BatteryHalf => '\u{f17f}',
-        Icon::Bell => '\u{f182}',
-        Icon::BellFill => '\u{f181}',
-        Icon::Bezier => '\u{f183}',
-        Icon::Bezier2 => '\u{f184}',
-        Icon::Bicycle => '\u{f185}',
-        Icon::Binoculars => '\u{f187}',
-        Icon::BinocularsFill => '\u{f186}',
-        Icon::BlockquoteLeft => '\u{f188}',
-        Icon::BlockquoteRight => '\u{f189}',
-        Icon::Book => '\u{f18c}',
-        Icon::BookFill => '\u{f18a}',
-        Icon::BookHalf => '\u{f18b}',
-        Icon::Bookmark => '\u{f19a}',
-        Icon::BookmarkCheck => '\u{f18e}',
-        Icon::BookmarkCheckFill => '\u{f18d}',
-        Icon::BookmarkDash => '\u{f190}',
-        Icon::BookmarkDashFill => '\u{f18f}',
-        Icon::BookmarkFill => '\u{f191}',
-        Icon::BookmarkHeart => '\u{f193}',
-        Icon::BookmarkHeartFill => '\u{f192}',
-        Icon::BookmarkPlus => '\u{f195}',
-        Icon::BookmarkPlusFill => '\u{f194}',
-        Icon::BookmarkStar => '\u{f197}',
-        Icon::BookmarkStarFill => '\u{f196}',
-        Icon::BookmarkX => '\u{f199}',
-        Icon::BookmarkXFill => '\u{f198}',
-        Icon::Bookmarks => '\u{f19c}',
-        Icon::BookmarksFill => '\u{f19b}',
-        Icon::Bookshelf => '\u{f19d}',
-        Icon::Bootstrap => '\u{f1a0}',
-        Icon::BootstrapFill => '\u{f19e}',
-        Icon::BootstrapReboot => '\u{f19f}',
-        Icon::BorderStyle => '\u{f1a1}',
-        Icon::BorderWidth => '\u{f1a2}',
-        Icon::BoundingBox => '\u{f1a4}',
-        Icon::BoundingBoxCircles => '\u{f1a3}',
-        Icon::Box => '\u{f1b6}',
-        Icon::BoxArrowDown => '\u{f1a7}',
-        Icon::BoxArrowDownLeft => '\u{f1a5}',
-        Icon::BoxArrowDownRight => '\u{f1a6}',
-        Icon::BoxArrowInDown => '\u{f1aa}',
-        Icon::BoxArrowInDownLeft => '\u{f1a8}',
-        Icon::BoxArrowInDownRight => '\u{f1a9}',
-        Icon::BoxArrowInLeft => '\u{f1ab}',
-        Icon::BoxArrowInRight => '\u{f1ac}',
-        Icon::BoxArrowInUp => '\u{f1af}',
-        Icon::BoxArrowInUpLeft => '\u{f1ad}',
-        Icon::BoxArrowInUpRight => '\u{f1ae}',
-        Icon::BoxArrowLeft => '\u{f1b0}',
-        Icon::BoxArrowRight => '\u{f1b1}',
-        Icon::BoxArrowUp => '\u{f1b4}',
-        Icon::BoxArrowUpLeft => '\u{f1b2}',
-        Icon::BoxArrowUpRight => '\u{f1b3}',
-        Icon::BoxSeam => '\u{f1b5}',
-        Icon::Braces => '\u{f1b7}',
-        Icon::Bricks => '\u{f1b8}',
-        Icon::Briefcase => '\u{f1ba}',
-        Icon::BriefcaseFill => '\u{f1b9}',
-        Icon::BrightnessAltHigh => '\u{f1bc}',
-        Icon::BrightnessAltHighFill => '\u{f1bb}',
-        Icon::BrightnessAltLow => '\u{f1be}',
-        Icon::BrightnessAltLowFill => '\u{f1bd}',
-        Icon::BrightnessHigh => '\u{f1c0}',
-        Icon::BrightnessHighFill => '\u{f1bf}',
-        Icon::BrightnessLow => '\u{f1c2}',
-        Icon::BrightnessLowFill => '\u{f1c1}',
-        Icon::Broadcast => '\u{f1c4}',
-        Icon::BroadcastPin => '\u{f1c3}',
-        Icon::Brush => '\u{f1c6}',
-        Icon::BrushFill => '\u{f1c5}',
-        Icon::Bucket => '\u{f1c8}',
-        Icon::BucketFill => '\u{f1c7}',
-        Icon::Bug => '\u{f1ca}',
-        Icon::BugFill => '\u{f1c9}',
-        Icon::Building => '\u{f1cb}',
-        Icon::Bullseye => '\u{f1cc}',
-        Icon::Calculator => '\u{f1ce}',
-        Icon::CalculatorFill => '\u{f1cd}',
-        Icon::Calendar => '\u{f1e4}',
-        Icon::CalendarCheck => '\u{f1d0}',
-        Icon::CalendarCheckFill => '\u{f1cf}',
-        Icon::CalendarDate => '\u{f1d2}',
-        Icon::CalendarDateFill => '\u{f1d1}',
-        Icon::CalendarDay => '\u{f1d4}',
-        Icon::CalendarDayFill => '\u{f1d3}',
-        Icon::CalendarEvent => '\u{f1d6}',
-        Icon::CalendarEventFill => '\u{f1d5}',
-        Icon::CalendarFill => '\u{f1d7}',
-        Icon::CalendarMinus => '\u{f1d9}',
-        Icon::CalendarMinusFill => '\u{f1d8}',
-        Icon::CalendarMonth => '\u{f1db}',
-        Icon::CalendarMonthFill => '\u{f1da}',
-        Icon::CalendarPlus => '\u{f1dd}',
-        Icon::CalendarPlusFill => '\u{f1dc}',
-        Icon::CalendarRange => '\u{f1df}',
-        Icon::CalendarRangeFill => '\u{f1de}',
-        Icon::CalendarWeek => '\u{f1e1}',
-        Icon::CalendarWeekFill => '\u{f1e0}',
-        Icon::CalendarX => '\u{f1e3}',
-        Icon::CalendarXFill => '\u{f1e2}',
-        Icon::Calendar2 => '\u{f1fa}',
-        Icon::Calendar2Check => '\u{f1e6}',
-        Icon::Calendar2CheckFill => '\u{f1e5}',
-        Icon::Calendar2Date => '\u{f1e8}',
-        Icon::Calendar2DateFill => '\u{f1e7}',
-        Icon::Calendar2Day => '\u{f1ea}',
-        Icon::Calendar2DayFill => '\u{f1e9}',
-        Icon::Calendar2Event => '\u{f1ec}',
-        Icon::Calendar2EventFill => '\u{f1eb}',
-        Icon::Calendar2Fill => '\u{f1ed}',
-        Icon::Calendar2Minus => '\u{f1ef}',
-        Icon::Calendar2MinusFill => '\u{f1ee}',
-        Icon::Calendar2Month => '\u{f1f1}',
-        Icon::Calendar2MonthFill => '\u{f1f0}',
-        Icon::Calendar2Plus => '\u{f1f3}',
-        Icon::Calendar2PlusFill => '\u{f1f2}',
-        Icon::Calendar2Range => '\u{f1f5}',
-        Icon::Calendar2RangeFill => '\u{f1f4}',
-        Icon::Calendar2Week => '\u{f1f7}',
-        Icon::Calendar2WeekFill => '\u{f1f6}',
-        Icon::Calendar2X => '\u{f1f9}',
-        Icon::Calendar2XFill => '\u{f1f8}',
-        Icon::Calendar3 => '\u{f202}',
-        Icon::Calendar3Event => '\u{f1fc}',
-        Icon::Calendar3EventFill => '\u{f1fb}',
-        Icon::Calendar3Fill => '\u{f1fd}',
-        Icon::Calendar3Range => '\u{f1ff}',
-        Icon::Calendar3RangeFill => '\u{f1fe}',
-        Icon::Calendar3Week => '\u{f201}',
-        Icon::Calendar3WeekFill => '\u{f200}',
-        Icon::Calendar4 => '\u{f206}',
-        Icon::Calendar4Event => '\u{f203}',
-        Icon::Calendar4Range => '\u{f204}',
-        Icon::Calendar4Week => '\u{f205}',
-        Icon::Camera => '\u{f20e}',
-        Icon::CameraFill => '\u{f207}',
-        Icon::CameraReels => '\u{f209}',
-        Icon::CameraReelsFill => '\u{f208}',
-        Icon::CameraVideo => '\u{f20d}',
-        Icon::CameraVideoFill => '\u{f20a}',
-        Icon::CameraVideoOff => '\u{f20c}',
-        Icon::CameraVideoOffFill => '\u{f20b}',
-        Icon::Camera2 => '\u{f20f}',
-        Icon::Capslock => '\u{f211}',
-        Icon::CapslockFill => '\u{f210}',
-        Icon::CardChecklist => '\u{f212}',
-        Icon::CardHeading => '\u{f213}',
-        Icon::CardImage => '\u{f214}',
-        Icon::CardList => '\u{f215}',
-        Icon::CardText => '\u{f216}',
-        Icon::CaretDown => '\u{f21a}',
-        Icon::CaretDownFill => '\u{f217}',
-        Icon::CaretDownSquare => '\u{f219}',
-        Icon::CaretDownSquareFill => '\u{f218}',
-        Icon::CaretLeft => '\u{f21e}',
-        Icon::CaretLeftFill => '\u{f21b}',
-        Icon::CaretLeftSquare => '\u{f21d}',
-        Icon::CaretLeftSquareFill => '\u{f21c}',
-        Icon::CaretRight => '\u{f222}',
-        Icon::CaretRightFill => '\u{f21f}',
-        Icon::CaretRightSquare => '\u{f221}',
-        Icon::CaretRightSquareFill => '\u{f220}',
-        Icon::CaretUp => '\u{f226}',
-        Icon::CaretUpFill => '\u{f223}',
-        Icon::CaretUpSquare => '\u{f225}',
-        Icon::CaretUpSquareFill => '\u{f224}',
-        Icon::Cart => '\u{f230}',
-        Icon::CartCheck => '\u{f228}',
-        Icon::CartCheckFill => '\u{f227}',
-        Icon::CartDash => '\u{f22a}',
-        Icon::CartDashFill => '\u{f229}',
-        Icon::CartFill => '\u{f22b}',
-        Icon::CartPlus => '\u{f22d}',
-        Icon::CartPlusFill => '\u{f22c}',
-        Icon::CartX => '\u{f22f}',
-        Icon::CartXFill => '\u{f22e}',
-        Icon::Cart2 => '\u{f231}',
-        Icon::Cart3 => '\u{f232}',
-        Icon::Cart4 => '\u{f233}',
-        Icon::Cash => '\u{f235}',
-        Icon::CashStack => '\u{f234}',
-        Icon::Cast => '\u{f236}',
-        Icon::Chat => '\u{f256}',
-        Icon::ChatDots => '\u{f238}',
-        Icon::ChatDotsFill => '\u{f237}',
-        Icon::ChatFill => '\u{f239}',
-        Icon::ChatLeft => '\u{f241}',
-        Icon::ChatLeftDots => '\u{f23b}',
-        Icon::ChatLeftDotsFill => '\u{f23a}',
-        Icon::ChatLeftFill => '\u{f23c}',
-        Icon::ChatLeftQuote => '\u{f23e}',
-        Icon::ChatLeftQuoteFill => '\u{f23d}',
-        Icon::ChatLeftText => '\u{f240}',
-        Icon::ChatLeftTextFill => '\u{f23f}',
-        Icon::ChatQuote => '\u{f243}',
-        Icon::ChatQuoteFill => '\u{f242}',
-        Icon::ChatRight => '\u{f24b}',
-        Icon::ChatRightDots => '\u{f245}',
-        Icon::ChatRightDotsFill => '\u{f244}',
-        Icon::ChatRightFill => '\u{f246}',
-        Icon::ChatRightQuote => '\u{f248}',
-        Icon::ChatRightQuoteFill => '\u{f247}',
-        Icon::ChatRightText => '\u{f24a}',
-        Icon::ChatRightTextFill => '\u{f249}',
-        Icon::ChatSquare => '\u{f253}',
-        Icon::ChatSquareDots => '\u{f24d}',
-        Icon::ChatSquareDotsFill => '\u{f24c}',
-        Icon::ChatSquareFill => '\u{f24e}',
-        Icon::ChatSquareQuote => '\u{f250}',
-        Icon::ChatSquareQuoteFill => '\u{f24f}',
-        Icon::ChatSquareText => '\u{f252}',
-        Icon::ChatSquareTextFill => '\u{f251}',
-        Icon::ChatText => '\u{f255}',
-        Icon::ChatTextFill => '\u{f254}',
-        Icon::Check => '\u{f25c}',
-        Icon::CheckAll => '\u{f257}',
-        Icon::CheckCircle => '\u{f259}',
-        Icon::CheckCircleFill => '\u{f258}',
-        Icon::CheckSquare => '\u{f25b}',
-        Icon::CheckSquareFill => '\u{f25a}',
-        Icon::Check2 => '\u{f260}',
-        Icon::Check2All => '\u{f25d}',
-        Icon::Check2Circle => '\u{f25e}',
-        Icon::Check2Square => '\u{f25f}',
-        Icon::ChevronBarContract => '\u{f261}',
-        Icon::ChevronBarDown => '\u{f262}',
-        Icon::ChevronBarExpand => '\u{f263}',
-        Icon::ChevronBarLeft => '\u{f264}',
-        Icon::ChevronBarRight => '\u{f265}',
-        Icon::ChevronBarUp => '\u{f266}',
-        Icon::ChevronCompactDown => '\u{f267}',
-        Icon::ChevronCompactLeft => '\u{f268}',
-        Icon::ChevronCompactRight => '\u{f269}',
-        Icon::ChevronCompactUp => '\u{f26a}',
-        Icon::ChevronContract => '\u{f26b}',
-        Icon::ChevronDoubleDown => '\u{f26c}',
-        Icon::ChevronDoubleLeft => '\u{f26d}',
-        Icon::ChevronDoubleRight => '\u{f26e}',
-        Icon::ChevronDoubleUp => '\u{f26f}',
-        Icon::ChevronDown => '\u{f270}',
-        Icon::ChevronExpand => '\u{f271}',
-        Icon::ChevronLeft => '\u{f272}',
-        Icon::ChevronRight => '\u{f273}',
-        Icon::ChevronUp => '\u{f274}',
-        Icon::Circle => '\u{f278}',
-        Icon::CircleFill => '\u{f275}',
-        Icon::CircleHalf => '\u{f276}',
-        Icon::CircleSquare => '\u{f277}',
-        Icon::Clipboard => '\u{f27e}',
-        Icon::ClipboardCheck => '\u{f279}',
-        Icon::ClipboardData => '\u{f27a}',
-        Icon::ClipboardMinus => '\u{f27b}',
-        Icon::ClipboardPlus => '\u{f27c}',
-        Icon::ClipboardX => '\u{f27d}',
-        Icon::Clock => '\u{f281}',
-        Icon::ClockFill => '\u{f27f}',
-        Icon::ClockHistory => '\u{f280}',
-        Icon::Cloud => '\u{f293}',
-        Icon::CloudArrowDown => '\u{f283}',
-        Icon::CloudArrowDownFill => '\u{f282}',
-        Icon::CloudArrowUp => '\u{f285}',
-        Icon::CloudArrowUpFill => '\u{f284}',
-        Icon::CloudCheck => '\u{f287}',
-        Icon::CloudCheckFill => '\u{f286}',
-        Icon::CloudDownload => '\u{f289}',
-        Icon::CloudDownloadFill => '\u{f288}',
-        Icon::CloudFill => '\u{f28a}',
-        Icon::CloudMinus => '\u{f28c}',
-        Icon::CloudMinusFill => '\u{f28b}',
-        Icon::CloudPlus => '\u{f28e}',
-        Icon::CloudPlusFill => '\u{f28d}',
-        Icon::CloudSlash => '\u{f290}',
-        Icon::CloudSlashFill => '\u{f28f}',
-        Icon::CloudUpload => '\u{f292}',
-        Icon::CloudUploadFill => '\u{f291}',
-        Icon::Code => '\u{f296}',
-        Icon::CodeSlash => '\u{f294}',
-        Icon::CodeSquare => '\u{f295}',
-        Icon::Collection => '\u{f29a}',
-        Icon::CollectionFill => '\u{f297}',
-        Icon::CollectionPlay => '\u{f299}',
-        Icon::CollectionPlayFill => '\u{f298}',
-        Icon::Columns => '\u{f29c}',
-        Icon::ColumnsGap => '\u{f29b}',
-        Icon::Command => '\u{f29d}',
-        Icon::Compass => '\u{f29f}',
-        Icon::CompassFill => '\u{f29e}',
-        Icon::Cone => '\u{f2a1}',
-        Icon::ConeStriped => '\u{f2a0}',
-        Icon::Controller => '\u{f2a2}',
-        Icon::Cpu => '\u{f2a4}',
-        Icon::CpuFill => '\u{f2a3}',
-        Icon::CreditCard => '\u{f2aa}',
-        Icon::CreditCard2Back => '\u{f2a6}',
-        Icon::CreditCard2BackFill => '\u{f2a5}',
-        Icon::CreditCard2Front => '\u{f2a8}',
-        Icon::CreditCard2FrontFill => '\u{f2a7}',
-        Icon::CreditCardFill => '\u{f2a9}',
-        Icon::Crop => '\u{f2ab}',
-        Icon::Cup => '\u{f2ae}',
-        Icon::CupFill => '\u{f2ac}',
-        Icon::CupStraw => '\u{f2ad}',
-        Icon::Cursor => '\u{f2b1}',
-        Icon::CursorFill => '\u{f2af}',
-        Icon::CursorText => '\u{f2b0}',
-        Icon::Dash => '\u{f2b6}',
-        Icon::DashCircle => '\u{f2b3}',
-        Icon::DashCircleFill => '\u{f2b2}',
-        Icon::DashSquare => '\u{f2b5}',
-        Icon::DashSquareFill => '\u{f2b4}',
-        Icon::Diagram2 => '\u{f2b8}',
-        Icon::Diagram2Fill => '\u{f2b7}',
-        Icon::Diagram3 => '\u{f2ba}',
-        Icon::Diagram3Fill => '\u{f2b9}',
-        Icon::Diamond => '\u{f2bd}',
-        Icon::DiamondFill => '\u{f2bb}',
-        Icon::DiamondHalf => '\u{f2bc}',
-        Icon::Dice1 => '\u{f2bf}',
-        Icon::Dice1Fill => '\u{f2be}',
-        Icon::Dice2 => '\u{f2c1}',
-        Icon::Dice2Fill => '\u{f2c0}',
-        Icon::Dice3 => '\u{f2c3}',
-        Icon::Dice3Fill => '\u{f2c2}',
-        Icon::Dice4 => '\u{f2c5}',
-        Icon::Dice4Fill => '\u{f2c4}',
-        Icon::Dice5 => '\u{f2c7}',
-        Icon::Dice5Fill => '\u{f2c6}',
-        Icon::Dice6 => '\u{f2c9}',
-        Icon::Dice6Fill => '\u{f2c8}',
-        Icon::Disc => '\u{f2cb}',
-        Icon::DiscFill => '\u{f2ca}',
-        Icon::Discord => '\u{f2cc}',
-        Icon::Display => '\u{f2ce}',
-        Icon::DisplayFill => '\u{f2cd}',
-        Icon::DistributeHorizontal => '\u{f2cf}',
-        Icon::DistributeVertical => '\u{f2d0}',
-        Icon::DoorClosed => '\u{f2d2}',
-        Icon::DoorClosedFill => '\u{f2d1}',
-        Icon::DoorOpen => '\u{f2d4}',
-        Icon::DoorOpenFill => '\u{f2d3}',
-        Icon::Dot => '\u{f2d5}',
-        Icon::Download => '\u{f2d6}',
-        Icon::Droplet => '\u{f2d9}',
-        Icon::DropletFill => '\u{f2d7}',
-        Icon::DropletHalf => '\u{f2d8}',
-        Icon::Earbuds => '\u{f2da}',
-        Icon::Easel => '\u{f2dc}',
-        Icon::EaselFill => '\u{f2db}',
-        Icon::Egg => '\u{f2df}',
-        Icon::EggFill => '\u{f2dd}',
-        Icon::EggFried => '\u{f2de}',
-        Icon::Eject => '\u{f2e1}',
-        Icon::EjectFill => '\u{f2e0}',
-        Icon::EmojiAngry => '\u{f2e3}',
-        Icon::EmojiAngryFill => '\u{f2e2}',
-        Icon::EmojiDizzy => '\u{f2e5}',
-        Icon::EmojiDizzyFill => '\u{f2e4}',
-        Icon::EmojiExpressionless => '\u{f2e7}',
-        Icon::EmojiExpressionlessFill => '\u{f2e6}',
-        Icon::EmojiFrown => '\u{f2e9}',
-        Icon::EmojiFrownFill => '\u{f2e8}',
-        Icon::EmojiHeartEyes => '\u{f2eb}',
-        Icon::EmojiHeartEyesFill => '\u{f2ea}',
-        Icon::EmojiLaughing => '\u{f2ed}',
-        Icon::EmojiLaughingFill => '\u{f2ec}',
-        Icon::EmojiNeutral => '\u{f2ef}',
-        Icon::EmojiNeutralFill => '\u{f2ee}',
-        Icon::EmojiSmile => '\u{f2f3}',
-        Icon::EmojiSmileFill => '\u{f2f0}',
-        Icon::EmojiSmileUpsideDown => '\u{f2f2}',
-        Icon::EmojiSmileUpsideDownFill => '\u{f2f1}',
-        Icon::EmojiSunglasses => '\u{f2f5}',
-        Icon::EmojiSunglassesFill => '\u{f2f4}',
-        Icon::EmojiWink => '\u{f2f7}',
-        Icon::EmojiWinkFill => '\u{f2f6}',
-        Icon::Envelope => '\u{f2fb}',
-        Icon::EnvelopeFill => '\u{f2f8}',
-        Icon::EnvelopeOpen => '\u{f2fa}',
-        Icon::EnvelopeOpenFill => '\u{f2f9}',
-        Icon::Exclamation => '\u{f306}',
-        Icon::ExclamationCircle => '\u{f2fd}',
-        Icon::ExclamationCircleFill => '\u{f2fc}',
-        Icon::ExclamationDiamond => '\u{f2ff}',
-        Icon::ExclamationDiamondFill => '\u{f2fe}',
-        Icon::ExclamationOctagon => '\u{f301}',
-        Icon::ExclamationOctagonFill => '\u{f300}',
-        Icon::ExclamationSquare => '\u{f303}',
-        Icon::ExclamationSquareFill => '\u{f302}',
-        Icon::ExclamationTriangle => '\u{f305}',
-        Icon::ExclamationTriangleFill => '\u{f304}',
-        Icon::Exclude => '\u{f307}',
-        Icon::Eye => '\u{f30b}',
-        Icon::EyeFill => '\u{f308}',
-        Icon::EyeSlash => '\u{f30a}',
-        Icon::EyeSlashFill => '\u{f309}',
-        Icon::Eyeglasses => '\u{f30c}',
-        Icon::Facebook => '\u{f30d}',
-        Icon::File => '\u{f389}',
-        Icon::FileArrowDown => '\u{f30f}',
-        Icon::FileArrowDownFill => '\u{f30e}',
-        Icon::FileArrowUp => '\u{f311}',
-        Icon::FileArrowUpFill => '\u{f310}',
-        Icon::FileBarGraph => '\u{f313}',
-        Icon::FileBarGraphFill => '\u{f312}',
-        Icon::FileBinary => '\u{f315}',
-        Icon::FileBinaryFill => '\u{f314}',
-        Icon::FileBreak => '\u{f317}',
-        Icon::FileBreakFill => '\u{f316}',
-        Icon::FileCheck => '\u{f319}',
-        Icon::FileCheckFill => '\u{f318}',
-        Icon::FileCode => '\u{f31b}',
-        Icon::FileCodeFill => '\u{f31a}',
-        Icon::FileDiff => '\u{f31d}',
-        Icon::FileDiffFill => '\u{f31c}',
-        Icon::FileEarmark => '\u{f35b}',
-        Icon::FileEarmarkArrowDown => '\u{f31f}',
-        Icon::FileEarmarkArrowDownFill => '\u{f31e}',
-        Icon::FileEarmarkArrowUp => '\u{f321}',
-        Icon::FileEarmarkArrowUpFill => '\u{f320}',
-        Icon::FileEarmarkBarGraph => '\u{f323}',
-        Icon::FileEarmarkBarGraphFill => '\u{f322}',
-        Icon::FileEarmarkBinary => '\u{f325}',
-        Icon::FileEarmarkBinaryFill => '\u{f324}',
-        Icon::FileEarmarkBreak => '\u{f327}',
-        Icon::FileEarmarkBreakFill => '\u{f326}',
-        Icon::FileEarmarkCheck => '\u{f329}',
-        Icon::FileEarmarkCheckFill => '\u{f328}',
-        Icon::FileEarmarkCode => '\u{f32b}',
-        Icon::FileEarmarkCodeFill => '\u{f32a}',
-        Icon::FileEarmarkDiff => '\u{f32d}',
-        Icon::FileEarmarkDiffFill => '\u{f32c}',
-        Icon::FileEarmarkEasel => '\u{f32f}',
-        Icon::FileEarmarkEaselFill => '\u{f32e}',
-        Icon::FileEarmarkExcel => '\u{f331}',
-        Icon::FileEarmarkExcelFill => '\u{f330}',
-        Icon::FileEarmarkFill => '\u{f332}',
-        Icon::FileEarmarkFont => '\u{f334}',
-        Icon::FileEarmarkFontFill => '\u{f333}',
-        Icon::FileEarmarkImage => '\u{f336}',
-        Icon::FileEarmarkImageFill => '\u{f335}',
-        Icon::FileEarmarkLock => '\u{f338}',
-        Icon::FileEarmarkLockFill => '\u{f337}',
-        Icon::FileEarmarkLock2 => '\u{f33a}',
-        Icon::FileEarmarkLock2Fill => '\u{f339}',
-        Icon::FileEarmarkMedical => '\u{f33c}',
-        Icon::FileEarmarkMedicalFill => '\u{f33b}',
-        Icon::FileEarmarkMinus => '\u{f33e}',
-        Icon::FileEarmarkMinusFill => '\u{f33d}',
-        Icon::FileEarmarkMusic => '\u{f340}',
-        Icon::FileEarmarkMusicFill => '\u{f33f}',
-        Icon::FileEarmarkPerson => '\u{f342}',
-        Icon::FileEarmarkPersonFill => '\u{f341}',
-        Icon::FileEarmarkPlay => '\u{f344}',
-        Icon::FileEarmarkPlayFill => '\u{f343}',
-        Icon::FileEarmarkPlus => '\u{f346}',
-        Icon::FileEarmarkPlusFill => '\u{f345}',
-        Icon::FileEarmarkPost => '\u{f348}',
-        Icon::FileEarmarkPostFill => '\u{f347}',
-        Icon::FileEarmarkPpt => '\u{f34a}',
-        Icon::FileEarmarkPptFill => '\u{f349}',
-        Icon::FileEarmarkRichtext => '\u{f34c}',
-        Icon::FileEarmarkRichtextFill => '\u{f34b}',
-        Icon::FileEarmarkRuled => '\u{f34e}',
-        Icon::FileEarmarkRuledFill => '\u{f34d}',
-        Icon::FileEarmarkSlides => '\u{f350}',
-        Icon::FileEarmarkSlidesFill => '\u{f34f}',
-        Icon::FileEarmarkSpreadsheet => '\u{f352}',
-        Icon::FileEarmarkSpreadsheetFill => '\u{f351}',
-        Icon::FileEarmarkText => '\u{f354}',
-        Icon::FileEarmarkTextFill => '\u{f353}',
-        Icon::FileEarmarkWord => '\u{f356}',
-        Icon::FileEarmarkWordFill => '\u{f355}',
-        Icon::FileEarmarkX => '\u{f358}',
-        Icon::FileEarmarkXFill => '\u{f357}',
-        Icon::FileEarmarkZip => '\u{f35a}',
-        Icon::FileEarmarkZipFill => '\u{f359}',
-        Icon::FileEasel => '\u{f35d}',
-        Icon::FileEaselFill => '\u{f35c}',
-        Icon::FileExcel => '\u{f35f}',
-        Icon::FileExcelFill => '\u{f35e}',
-        Icon::FileFill => '\u{f360}',
-        Icon::FileFont => '\u{f362}',
-        Icon::FileFontFill => '\u{f361}',
-        Icon::FileImage => '\u{f364}',
-        Icon::FileImageFill => '\u{f363}',
-        Icon::FileLock => '\u{f366}',
-        Icon::FileLockFill => '\u{f365}',
-        Icon::FileLock2 => '\u{f368}',
-        Icon::FileLock2Fill => '\u{f367}',
-        Icon::FileMedical => '\u{f36a}',
-        Icon::FileMedicalFill => '\u{f369}',
-        Icon::FileMinus => '\u{f36c}',
-        Icon::FileMinusFill => '\u{f36b}',
-        Icon::FileMusic => '\u{f36e}',
-        Icon::FileMusicFill => '\u{f36d}',
-        Icon::FilePerson => '\u{f370}',
-        Icon::FilePersonFill => '\u{f36f}',
-        Icon::FilePlay => '\u{f372}',
-        Icon::FilePlayFill => '\u{f371}',
-        Icon::FilePlus => '\u{f374}',
-        Icon::FilePlusFill => '\u{f373}',
-        Icon::FilePost => '\u{f376}',
-        Icon::FilePostFill => '\u{f375}',
-        Icon::FilePpt => '\u{f378}',
-        Icon::FilePptFill => '\u{f377}',
-        Icon::FileRichtext => '\u{f37a}',
-        Icon::FileRichtextFill => '\u{f379}',
-        Icon::FileRuled => '\u{f37c}',
-        Icon::FileRuledFill => '\u{f37b}',
-        Icon::FileSlides => '\u{f37e}',
-        Icon::FileSlidesFill => '\u{f37d}',
-        Icon::FileSpreadsheet => '\u{f380}',
-        Icon::FileSpreadsheetFill => '\u{f37f}',
-        Icon::FileText => '\u{f382}',
-        Icon::FileTextFill => '\u{f381}',
-        Icon::FileWord => '\u{f384}',
-        Icon::FileWordFill => '\u{f383}',
-        Icon::FileX => '\u{f386}',
-        Icon::FileXFill => '\u{f385}',
-        Icon::FileZip => '\u{f388}',
-        Icon::FileZipFill => '\u{f387}',
-        Icon::Files => '\u{f38b}',
-        Icon::FilesAlt => '\u{f38a}',
-        Icon::Film => '\u{f38c}',
-        Icon::Filter => '\u{f393}',
-        Icon::FilterCircle => '\u{f38e}',
-        Icon::FilterCircleFill => '\u{f38d}',
-        Icon::FilterLeft => '\u{f38f}',
-        Icon::FilterRight => '\u{f390}',
-        Icon::FilterSquare => '\u{f392}',
-        Icon::FilterSquareFill => '\u{f391}',
-        Icon::Flag => '\u{f395}',
-        Icon::FlagFill => '\u{f394}',
-        Icon::Flower1 => '\u{f396}',
-        Icon::Flower2 => '\u{f397}',
-        Icon::Flower3 => '\u{f398}',
-        Icon::Folder => '\u{f3a0}',
-        Icon::FolderCheck => '\u{f399}',
-        Icon::FolderFill => '\u{f39a}',
-        Icon::FolderMinus => '\u{f39b}',
-        Icon::FolderPlus => '\u{f39c}',
-        Icon::FolderSymlink => '\u{f39e}',
-        Icon::FolderSymlinkFill => '\u{f39d}',
-        Icon::FolderX => '\u{f39f}',
-        Icon::Folder2 => '\u{f3a2}',
-        Icon::Folder2Open => '\u{f3a1}',
-        Icon::Fonts => '\u{f3a3}',
-        Icon::Forward => '\u{f3a5}',
-        Icon::ForwardFill => '\u{f3a4}',
-        Icon::Front => '\u{f3a6}',
-        Icon::Fullscreen => '\u{f3a8}',
-        Icon::FullscreenExit => '\u{f3a7}',
-        Icon::Funnel => '\u{f3aa}',
-        Icon::FunnelFill => '\u{f3a9}',
-        Icon::Gear => '\u{f3ae}',
-        Icon::GearFill => '\u{f3ab}',
-        Icon::GearWide => '\u{f3ad}',
-        Icon::GearWideConnected => '\u{f3ac}',
-        Icon::Gem => '\u{f3af}',
-        Icon::Geo => '\u{f3b3}',
-        Icon::GeoAlt => '\u{f3b1}',
-        Icon::GeoAltFill => '\u{f3b0}',
-        Icon::GeoFill => '\u{f3b2}',
-        Icon::Gift => '\u{f3b5}',
-        Icon::GiftFill => '\u{f3b4}',
-        Icon::Github => '\u{f3b6}',
-        Icon::Globe => '\u{f3b7}',
-        Icon::Globe2 => '\u{f3b8}',
-        Icon::Google => '\u{f3b9}',
-        Icon::GraphDown => '\u{f3ba}',
-        Icon::GraphUp => '\u{f3bb}',
-        Icon::Grid => '\u{f3c5}',
-        Icon::Grid1X2 => '\u{f3bd}',
-        Icon::Grid1X2Fill => '\u{f3bc}',
-        Icon::Grid3X2 => '\u{f3c0}',
-        Icon::Grid3X2Gap => '\u{f3bf}',
-        Icon::Grid3X2GapFill => '\u{f3be}',
-        Icon::Grid3X3 => '\u{f3c3}',
-        Icon::Grid3X3Gap => '\u{f3c2}',
-        Icon::Grid3X3GapFill => '\u{f3c1}',
-        Icon::GridFill => '\u{f3c4}',
-        Icon::GripHorizontal => '\u{f3c6}',
-        Icon::GripVertical => '\u{f3c7}',
-        Icon::Hammer => '\u{f3c8}',
-        Icon::HandIndex => '\u{f3ca}',
-        Icon::HandIndexThumb => '\u{f3c9}',
-        Icon::HandThumbsDown => '\u{f3cb}',
-        Icon::HandThumbsUp => '\u{f3cc}',
-        Icon::Handbag => '\u{f3ce}',
-        Icon::HandbagFill => '\u{f3cd}',
-        Icon::Hash => '\u{f3cf}',
-        Icon::Hdd => '\u{f3d7}',
-        Icon::HddFill => '\u{f3d0}',
-        Icon::HddNetwork => '\u{f3d2}',
-        Icon::HddNetworkFill => '\u{f3d1}',
-        Icon::HddRack => '\u{f3d4}',
-        Icon::HddRackFill => '\u{f3d3}',
-        Icon::HddStack => '\u{f3d6}',
-        Icon::HddStackFill => '\u{f3d5}',
-        Icon::Headphones => '\u{f3d8}',
-        Icon::Headset => '\u{f3d9}',
-        Icon::Heart => '\u{f3dc}',
-        Icon::HeartFill => '\u{f3da}',
-        Icon::HeartHalf => '\u{f3db}',
-        Icon::Heptagon => '\u{f3df}',
-        Icon::HeptagonFill => '\u{f3dd}',
-        Icon::HeptagonHalf => '\u{f3de}',
-        Icon::Hexagon => '\u{f3e2}',
-        Icon::HexagonFill => '\u{f3e0}',
-        Icon::HexagonHalf => '\u{f3e1}',
-        Icon::Hourglass => '\u{f3e6}',
-        Icon::HourglassBottom => '\u{f3e3}',
-        Icon::HourglassSplit => '\u{f3e4}',
-        Icon::HourglassTop => '\u{f3e5}',
-        Icon::House => '\u{f3ea}',
-        Icon::HouseDoor => '\u{f3e8}',
-        Icon::HouseDoorFill => '\u{f3e7}',
-        Icon::HouseFill => '\u{f3e9}',
-        Icon::Hr => '\u{f3eb}',
-        Icon::Image => '\u{f3ee}',
-        Icon::ImageAlt => '\u{f3ec}',
-        Icon::ImageFill => '\u{f3ed}',
-        Icon::Images => '\u{f3ef}',
-        Icon::Inbox => '\u{f3f1}',
-        Icon::InboxFill => '\u{f3f0}',
-        Icon::Inboxes => '\u{f3f3}',
-        Icon::InboxesFill => '\u{f3f2}',
-        Icon::Info => '\u{f3f8}',
-        Icon::InfoCircle => '\u{f3f5}',
-        Icon::InfoCircleFill => '\u{f3f4}',
-        Icon::InfoSquare => '\u{f3f7}',
-        Icon::InfoSquareFill => '\u{f3f6}',
-        Icon::InputCursor => '\u{f3fa}',
-        Icon::InputCursorText => '\u{f3f9}',
-        Icon::Instagram => '\u{f3fb}',
-        Icon::Intersect => '\u{f3fc}',
-        Icon::Journal => '\u{f40a}',
-        Icon::JournalAlbum => '\u{f3fd}',
-        Icon::JournalArrowDown => '\u{f3fe}',
-        Icon::JournalArrowUp => '\u{f3ff}',
-        Icon::JournalBookmark => '\u{f401}',
-        Icon::JournalBookmarkFill => '\u{f400}',
-        Icon::JournalCheck => '\u{f402}',
-        Icon::JournalCode => '\u{f403}',
-        Icon::JournalMedical => '\u{f404}',
-        Icon::JournalMinus => '\u{f405}',
-        Icon::JournalPlus => '\u{f406}',
-        Icon::JournalRichtext => '\u{f407}',
-        Icon::JournalText => '\u{f408}',
-        Icon::JournalX => '\u{f409}',
-        Icon::Journals => '\u{f40b}',
-        Icon::Joystick => '\u{f40c}',
-        Icon::Justify => '\u{f40f}',
-        Icon::JustifyLeft => '\u{f40d}',
-        Icon::JustifyRight => '\u{f40e}',
-        Icon::Kanban => '\u{f411}',
-        Icon::KanbanFill => '\u{f410}',
-        Icon::Key => '\u{f413}',
-        Icon::KeyFill => '\u{f412}',
-        Icon::Keyboard => '\u{f415}',
-        Icon::KeyboardFill => '\u{f414}',
-        Icon::Ladder => '\u{f416}',
-        Icon::Lamp => '\u{f418}',
-        Icon::LampFill => '\u{f417}',
-        Icon::Laptop => '\u{f41a}',
-        Icon::LaptopFill => '\u{f419}',
-        Icon::Layers => '\u{f41d}',
-        Icon::LayersFill => '\u{f41b}',
-        Icon::LayersHalf => '\u{f41c}',
-        Icon::LayoutSidebar => '\u{f421}',
-        Icon::LayoutSidebarInset => '\u{f41f}',
-        Icon::LayoutSidebarInsetReverse => '\u{f41e}',
-        Icon::LayoutSidebarReverse => '\u{f420}',
-        Icon::LayoutSplit => '\u{f422}',
-        Icon::LayoutTextSidebar => '\u{f424}',
-        Icon::LayoutTextSidebarReverse => '\u{f423}',
-        Icon::LayoutTextWindow => '\u{f426}',
-        Icon::LayoutTextWindowReverse => '\u{f425}',
-        Icon::LayoutThreeColumns => '\u{f427}',
-        Icon::LayoutWtf => '\u{f428}',
-        Icon::LifePreserver => '\u{f429}',
-        Icon::Lightning => '\u{f42b}',
-        Icon::LightningFill => '\u{f42a}',
-        Icon::Link => '\u{f42d}',
-        Icon::Link45Deg => '\u{f42c}',
-        Icon::Linkedin => '\u{f42e}',
-        Icon::List => '\u{f435}',
-        Icon::ListCheck => '\u{f42f}',
-        Icon::ListNested => '\u{f430}',
-        Icon::ListOl => '\u{f431}',
-        Icon::ListStars => '\u{f432}',
-        Icon::ListTask => '\u{f433}',
-        Icon::ListUl => '\u{f434}',
-        Icon::Lock => '\u{f437}',
-        Icon::LockFill => '\u{f436}',
-        Icon::Mailbox => '\u{f438}',
-        Icon::Mailbox2 => '\u{f439}',
-        Icon::Map => '\u{f43b}',
-        Icon::MapFill => '\u{f43a}',
-        Icon::Markdown => '\u{f43d}',
-        Icon::MarkdownFill => '\u{f43c}',
-        Icon::MenuApp => '\u{f43f}',
-        Icon::MenuAppFill => '\u{f43e}',
-        Icon::MenuButton => '\u{f443}',
-        Icon::MenuButtonFill => '\u{f440}',
-        Icon::MenuButtonWide => '\u{f442}',
-        Icon::MenuButtonWideFill => '\u{f441}',
-        Icon::MenuDown => '\u{f444}',
-        Icon::MenuUp => '\u{f445}',
-        Icon::Mic => '\u{f449}',
-        Icon::MicFill => '\u{f446}',
-        Icon::MicMute => '\u{f448}',
-        Icon::MicMuteFill => '\u{f447}',
-        Icon::Minecart => '\u{f44b}',
-        Icon::MinecartLoaded => '\u{f44a}',
-        Icon::Moon => '\u{f44c}',
-        Icon::Mouse => '\u{f44d}',
-        Icon::Mouse2 => '\u{f44e}',
-        Icon::Mouse3 => '\u{f44f}',
-        Icon::MusicNote => '\u{f452}',
-        Icon::MusicNoteBeamed => '\u{f450}',
-        Icon::MusicNoteList => '\u{f451}',
-        Icon::MusicPlayer => '\u{f454}',
-        Icon::MusicPlayerFill => '\u{f453}',
-        Icon::Newspaper => '\u{f455}',
-        Icon::NodeMinus => '\u{f457}',
-        Icon::NodeMinusFill => '\u{f456}',
-        Icon::NodePlus => '\u{f459}',
-        Icon::NodePlusFill => '\u{f458}',
-        Icon::Nut => '\u{f45b}',
-        Icon::NutFill => '\u{f45a}',
-        Icon::Octagon => '\u{f45e}',
-        Icon::OctagonFill => '\u{f45c}',
-        Icon::OctagonHalf => '\u{f45d}',
-        Icon::Option => '\u{f45f}',
-        Icon::Outlet => '\u{f460}',
-        Icon::Paperclip => '\u{f461}',
-        Icon::Paragraph => '\u{f462}',
-        Icon::PatchCheck => '\u{f464}',
-        Icon::PatchCheckFll => '\u{f463}',
-        Icon::PatchExclamation => '\u{f466}',
-        Icon::PatchExclamationFll => '\u{f465}',
-        Icon::PatchMinus => '\u{f468}',
-        Icon::PatchMinusFll => '\u{f467}',
-        Icon::PatchPlus => '\u{f46a}',
-        Icon::PatchPlusFll => '\u{f469}',
-        Icon::PatchQuestion => '\u{f46c}',
-        Icon::PatchQuestionFll => '\u{f46b}',
-        Icon::Pause => '\u{f472}',
-        Icon::PauseBtn => '\u{f46e}',
-        Icon::PauseBtnFill => '\u{f46d}',
-        Icon::PauseCircle => '\u{f470}',
-        Icon::PauseCircleFill => '\u{f46f}',
-        Icon::PauseFill => '\u{f471}',
-        Icon::Peace => '\u{f474}',
-        Icon::PeaceFill => '\u{f473}',
-        Icon::Pen => '\u{f476}',
-        Icon::PenFill => '\u{f475}',
-        Icon::Pencil => '\u{f479}',
-        Icon::PencilFill => '\u{f477}',
-        Icon::PencilSquare => '\u{f478}',
-        Icon::Pentagon => '\u{f47c}',
-        Icon::PentagonFill => '\u{f47a}',
-        Icon::PentagonHalf => '\u{f47b}',
-        Icon::People => '\u{f47e}',
-        Icon::PeopleFill => '\u{f47d}',
-        Icon::Percent => '\u{f47f}',
-        Icon::Person => '\u{f48f}',
-        Icon::PersonBadge => '\u{f481}',
-        Icon::PersonBadgeFill => '\u{f480}',
-        Icon::PersonBoundingBox => '\u{f482}',
-        Icon::PersonCheck => '\u{f484}',
-        Icon::PersonCheckFill => '\u{f483}',
-        Icon::PersonCircle => '\u{f485}',
-        Icon::PersonDash => '\u{f487}',
-        Icon::PersonDashFill => '\u{f486}',
-        Icon::PersonFill => '\u{f488}',
-        Icon::PersonLinesFill => '\u{f489}',
-        Icon::PersonPlus => '\u{f48b}',
-        Icon::PersonPlusFill => '\u{f48a}',
-        Icon::PersonSquare => '\u{f48c}',
-        Icon::PersonX => '\u{f48e}',
-        Icon::PersonXFill => '\u{f48d}',
-        Icon::Phone => '\u{f494}',
-        Icon::PhoneFill => '\u{f490}',
-        Icon::PhoneLandscape => '\u{f492}',
-        Icon::PhoneLandscapeFill => '\u{f491}',
-        Icon::PhoneVibrate => '\u{f493}',
-        Icon::PieChart => '\u{f496}',
-        Icon::PieChartFill => '\u{f495}',
-        Icon::Pip => '\u{f498}',
-        Icon::PipFill => '\u{f497}',
-        Icon::Play => '\u{f49e}',
-        Icon::PlayBtn => '\u{f49a}',
-        Icon::PlayBtnFill => '\u{f499}',
-        Icon::PlayCircle => '\u{f49c}',
-        Icon::PlayCircleFill => '\u{f49b}',
-        Icon::PlayFill => '\u{f49d}',
-        Icon::Plug => '\u{f4a0}',
-        Icon::PlugFill => '\u{f49f}',
-        Icon::Plus => '\u{f4a5}',
-        Icon::PlusCircle => '\u{f4a2}',
-        Icon::PlusCircleFill => '\u{f4a1}',
-        Icon::PlusSquare => '\u{f4a4}',
-        Icon::PlusSquareFill => '\u{f4a3}',
-        Icon::Power => '\u{f4a6}',
-        Icon::Printer => '\u{f4a8}',
-        Icon::PrinterFill => '\u{f4a7}',
-        Icon::Puzzle => '\u{f4aa}',
-        Icon::PuzzleFill => '\u{f4a9}',
-        Icon::Question => '\u{f4b3}',
-        Icon::QuestionCircle => '\u{f4ac}',
-        Icon::QuestionCircleFill => '\u{f4ab}',
-        Icon::QuestionDiamond => '\u{f4ae}',
-        Icon::QuestionDiamondFill => '\u{f4ad}',
-        Icon::QuestionOctagon => '\u{f4b0}',
-        Icon::QuestionOctagonFill => '\u{f4af}',
-        Icon::QuestionSquare => '\u{f4b2}',
-        Icon::QuestionSquareFill => '\u{f4b1}',
-        Icon::Receipt => '\u{f4b5}',
-        Icon::ReceiptCutoff => '\u{f4b4}',
-        Icon::Reception0 => '\u{f4b6}',
-        Icon::Reception1 => '\u{f4b7}',
-        Icon::Reception2 => '\u{f4b8}',
-        Icon::Reception3 => '\u{f4b9}',
-        Icon::Reception4 => '\u{f4ba}',
-        Icon::Record => '\u{f4c0}',
-        Icon::RecordBtn => '\u{f4bc}',
-        Icon::RecordBtnFill => '\u{f4bb}',
-        Icon::RecordCircle => '\u{f4be}',
-        Icon::RecordCircleFill => '\u{f4bd}',
-        Icon::RecordFill => '\u{f4bf}',
-        Icon::Record2 => '\u{f4c2}',
-        Icon::Record2Fill => '\u{f4c1}',
-        Icon::Reply => '\u{f4c6}',
-        Icon::ReplyAll => '\u{f4c4}',
-        Icon::ReplyAllFill => '\u{f4c3}',
-        Icon::ReplyFill => '\u{f4c5}',
-        Icon::Rss => '\u{f4c8}',
-        Icon::RssFill => '\u{f4c7}',
-        Icon::Scissors => '\u{f4c9}',
-        Icon::Screwdriver => '\u{f4ca}',
-        Icon::Search => '\u{f4cb}',
-        Icon::SegmentedNav => '\u{f4cc}',
-        Icon::Server => '\u{f4cd}',
-        Icon::Share => '\u{f4cf}',
-        Icon::ShareFill => '\u{f4ce}',
-        Icon::Shield => '\u{f4e0}',
-        Icon::ShieldCheck => '\u{f4d0}',
-        Icon::ShieldExclamation => '\u{f4d1}',
-        Icon::ShieldFill => '\u{f4d7}',
-        Icon::ShieldFillCheck => '\u{f4d2}',
-        Icon::ShieldFillExclamation => '\u{f4d3}',
-        Icon::ShieldFillMinus => '\u{f4d4}',
-        Icon::ShieldFillPlus => '\u{f4d5}',
-        Icon::ShieldFillX => '\u{f4d6}',
-        Icon::ShieldLock => '\u{f4d9}',
-        Icon::ShieldLockFill => '\u{f4d8}',
-        Icon::ShieldMinus => '\u{f4da}',
-        Icon::ShieldPlus => '\u{f4db}',
-        Icon::ShieldShaded => '\u{f4dc}',
-        Icon::ShieldSlash => '\u{f4de}',
-        Icon::ShieldSlashFill => '\u{f4dd}',
-        Icon::ShieldX => '\u{f4df}',
-        Icon::Shift => '\u{f4e2}',
-        Icon::ShiftFill => '\u{f4e1}',
-        Icon::Shop => '\u{f4e4}',
-        Icon::ShopWindow => '\u{f4e3}',
-        Icon::Shuffle => '\u{f4e5}',
-        Icon::Signpost => '\u{f4eb}',
-        Icon::Signpost2 => '\u{f4e7}',
-        Icon::Signpost2Fill => '\u{f4e6}',
-        Icon::SignpostFill => '\u{f4e8}',
-        Icon::SignpostSplit => '\u{f4ea}',
-        Icon::SignpostSplitFill => '\u{f4e9}',
-        Icon::Sim => '\u{f4ed}',
-        Icon::SimFill => '\u{f4ec}',
-        Icon::SkipBackward => '\u{f4f3}',
-        Icon::SkipBackwardBtn => '\u{f4ef}',
-        Icon::SkipBackwardBtnFill => '\u{f4ee}',
-        Icon::SkipBackwardCircle => '\u{f4f1}',
-        Icon::SkipBackwardCircleFill => '\u{f4f0}',
-        Icon::SkipBackwardFill => '\u{f4f2}',
-        Icon::SkipEnd => '\u{f4f9}',
-        Icon::SkipEndBtn => '\u{f4f5}',
-        Icon::SkipEndBtnFill => '\u{f4f4}',
-        Icon::SkipEndCircle => '\u{f4f7}',
-        Icon::SkipEndCircleFill => '\u{f4f6}',
-        Icon::SkipEndFill => '\u{f4f8}',
-        Icon::SkipForward => '\u{f4ff}',
-        Icon::SkipForwardBtn => '\u{f4fb}',
-        Icon::SkipForwardBtnFill => '\u{f4fa}',
-        Icon::SkipForwardCircle => '\u{f4fd}',
-        Icon::SkipForwardCircleFill => '\u{f4fc}',
-        Icon::SkipForwardFill => '\u{f4fe}',
-        Icon::SkipStart => '\u{f505}',
-        Icon::SkipStartBtn => '\u{f501}',
-        Icon::SkipStartBtnFill => '\u{f500}',
-        Icon::SkipStartCircle => '\u{f503}',
-        Icon::SkipStartCircleFill => '\u{f502}',
-        Icon::SkipStartFill => '\u{f504}',
-        Icon::Slack => '\u{f506}',
-        Icon::Slash => '\u{f50b}',
-        Icon::SlashCircle => '\u{f508}',
-        Icon::SlashCircleFill => '\u{f507}',
-        Icon::SlashSquare => '\u{f50a}',
-        Icon::SlashSquareFill => '\u{f509}',
-        Icon::Sliders => '\u{f50c}',
-        Icon::Smartwatch => '\u{f50d}',
-        Icon::SortAlphaDown => '\u{f50f}',
-        Icon::SortAlphaDownAlt => '\u{f50e}',
-        Icon::SortAlphaUp => '\u{f511}',
-        Icon::SortAlphaUpAlt => '\u{f510}',
-        Icon::SortDown => '\u{f513}',
-        Icon::SortDownAlt => '\u{f512}',
-        Icon::SortNumericDown => '\u{f515}',
-        Icon::SortNumericDownAlt => '\u{f514}',
-        Icon::SortNumericUp => '\u{f517}',
-        Icon::SortNumericUpAlt => '\u{f516}',
-        Icon::SortUp => '\u{f519}',
-        Icon::SortUpAlt => '\u{f518}',
-        Icon::Soundwave => '\u{f51a}',
-        Icon::Speaker => '\u{f51c}',
-        Icon::SpeakerFill => '\u{f51b}',
-        Icon::Spellcheck => '\u{f51d}',
-        Icon::Square => '\u{f520}',
-        Icon::SquareFill => '\u{f51e}',
-        Icon::SquareHalf => '\u{f51f}',
-        Icon::Star => '\u{f523}',
-        Icon::StarFill => '\u{f521}',
-        Icon::StarHalf => '\u{f522}',
-        Icon::Stickies => '\u{f525}',
-        Icon::StickiesFill => '\u{f524}',
-        Icon::Sticky => '\u{f527}',
-        Icon::StickyFill => '\u{f526}',
-        Icon::Stop => '\u{f52d}',
-        Icon::StopBtn => '\u{f529}',
-        Icon::StopBtnFill => '\u{f528}',
-        Icon::StopCircle => '\u{f52b}',
-        Icon::StopCircleFill => '\u{f52a}',
-        Icon::StopFill => '\u{f52c}',
-        Icon::Stoplights => '\u{f52f}',
-        Icon::StoplightsFill => '\u{f52e}',
-        Icon::Stopwatch => '\u{f531}',
-        Icon::StopwatchFill => '\u{f530}',
-        Icon::Subtract => '\u{f532}',
-        Icon::SuitClub => '\u{f534}',
-        Icon::SuitClubFill => '\u{f533}',
-        Icon::SuitDiamond => '\u{f536}',
-        Icon::SuitDiamondFill => '\u{f535}',
-        Icon::SuitHeart => '\u{f538}',
-        Icon::SuitHeartFill => '\u{f537}',
-        Icon::SuitSpade => '\u{f53a}',
-        Icon::SuitSpadeFill => '\u{f539}',
-        Icon::Sun => '\u{f53b}',
-        Icon::Sunglasses => '\u{f53c}',
-        Icon::Table => '\u{f53d}',
-        Icon::Tablet => '\u{f541}',
-        Icon::TabletFill => '\u{f53e}',
-        Icon::TabletLandscape => '\u{f540}',
-        Icon::TabletLandscapeFill => '\u{f53f}',
-        Icon::Tag => '\u{f543}',
-        Icon::TagFill => '\u{f542}',
-        Icon::Tags => '\u{f545}',
-        Icon::TagsFill => '\u{f544}',
-        Icon::Telephone => '\u{f553}',
-        Icon::TelephoneFill => '\u{f546}',
-        Icon::TelephoneForward => '\u{f548}',
-        Icon::TelephoneForwardFill => '\u{f547}',
-        Icon::TelephoneInbound => '\u{f54a}',
-        Icon::TelephoneInboundFill => '\u{f549}',
-        Icon::TelephoneMinus => '\u{f54c}',
-        Icon::TelephoneMinusFill => '\u{f54b}',
-        Icon::TelephoneOutbound => '\u{f54e}',
-        Icon::TelephoneOutboundFill => '\u{f54d}',
-        Icon::TelephonePlus => '\u{f550}',
-        Icon::TelephonePlusFill => '\u{f54f}',
-        Icon::TelephoneX => '\u{f552}',
-        Icon::TelephoneXFill => '\u{f551}',
-        Icon::Terminal => '\u{f555}',
-        Icon::TerminalFill => '\u{f554}',
-        Icon::TextCenter => '\u{f556}',
-        Icon::TextIndentLeft => '\u{f557}',
-        Icon::TextIndentRight => '\u{f558}',
-        Icon::TextLeft => '\u{f559}',
-        Icon::TextParagraph => '\u{f55a}',
-        Icon::TextRight => '\u{f55b}',
-        Icon::Textarea => '\u{f55e}',
-        Icon::TextareaResize => '\u{f55c}',
-        Icon::TextareaT => '\u{f55d}',
-        Icon::Thermometer => '\u{f560}',
-        Icon::ThermometerHalf => '\u{f55f}',
-        Icon::ThreeDots => '\u{f562}',
-        Icon::ThreeDotsVertical => '\u{f561}',
-        Icon::ToggleOff => '\u{f563}',
-        Icon::ToggleOn => '\u{f564}',
-        Icon::Toggle2Off => '\u{f565}',
-        Icon::Toggle2On => '\u{f566}',
-        Icon::Toggles => '\u{f567}',
-        Icon::Toggles2 => '\u{f568}',
-        Icon::Tools => '\u{f569}',
-        Icon::Trash => '\u{f56b}',
-        Icon::TrashFill => '\u{f56a}',
-        Icon::Trash2 => '\u{f56d}',
-        Icon::Trash2Fill => '\u{f56c}',
-        Icon::Tree => '\u{f56f}',
-        Icon::TreeFill => '\u{f56e}',
-        Icon::Triangle => '\u{f572}',
-        Icon::TriangleFill => '\u{f570}',
-        Icon::TriangleHalf => '\u{f571}',
-        Icon::Trophy => '\u{f574}',
-        Icon::TrophyFill => '\u{f573}',
-        Icon::Truck => '\u{f576}',
-        Icon::TruckFlatbed => '\u{f575}',
-        Icon::Tv => '\u{f578}',
-        Icon::TvFill => '\u{f577}',
-        Icon::Twitch => '\u{f579}',
-        Icon::Twitter => '\u{f57a}',
-        Icon::Type => '\u{f582}',
-        Icon::TypeBold => '\u{f57b}',
-        Icon::TypeH1 => '\u{f57c}',
-        Icon::TypeH2 => '\u{f57d}',
-        Icon::TypeH3 => '\u{f57e}',
-        Icon::TypeItalic => '\u{f57f}',
-        Icon::TypeStrikethrough => '\u{f580}',
-        Icon::TypeUnderline => '\u{f581}',
-        Icon::UiChecks => '\u{f584}',
-        Icon::UiChecksGrid => '\u{f583}',
-        Icon::UiRadios => '\u{f586}',
-        Icon::UiRadiosGrid => '\u{f585}',
-        Icon::Union => '\u{f587}',
-        Icon::Unlock => '\u{f589}',
-        Icon::UnlockFill => '\u{f588}',
-        Icon::Upc => '\u{f58b}',
-        Icon::UpcScan => '\u{f58a}',
-        Icon::Upload => '\u{f58c}',
-        Icon::VectorPen => '\u{f58d}',
-        Icon::ViewList => '\u{f58e}',
-        Icon::ViewStacked => '\u{f58f}',
-        Icon::Vinyl => '\u{f591}',
-        Icon::VinylFill => '\u{f590}',
-        Icon::Voicemail => '\u{f592}',
-        Icon::VolumeDown => '\u{f594}',
-        Icon::VolumeDownFill => '\u{f593}',
-        Icon::VolumeMute => '\u{f596}',
-        Icon::VolumeMuteFill => '\u{f595}',
-        Icon::VolumeOff => '\u{f598}',
-        Icon::VolumeOffFill => '\u{f597}',
-        Icon::VolumeUp => '\u{f59a}',
-        Icon::VolumeUpFill => '\u{f599}',
-        Icon::Vr => '\u{f59b}',
-        Icon::Wallet => '\u{f59d}',
-        Icon::WalletFill => '\u{f59c}',
-        Icon::Wallet2 => '\u{f59e}',
-        Icon::Watch => '\u{f59f}',
-        Icon::Wifi => '\u{f5a3}',
-        Icon::Wifi1 => '\u{f5a0}',
-        Icon::Wifi2 => '\u{f5a1}',
-        Icon::WifiOff => '\u{f5a2}',
-        Icon::Window => '\u{f5a4}',
-        Icon::Wrench => '\u{f5a5}',
-        Icon::X => '\u{f5ae}',
-        Icon::XCircle => '\u{f5a7}',
-        Icon::XCircleFill => '\u{f5a6}',
-        Icon::XDiamond => '\u{f5a9}',
-        Icon::XDiamondFill => '\u{f5a8}',
-        Icon::XOctagon => '\u{f5ab}',
-        Icon::XOctagonFill => '\u{f5aa}',
-        Icon::XSquare => '\u{f5ad}',
-        Icon::XSquareFill => '\u{f5ac}',
-        Icon::Youtube => '\u{f5af}',
-        Icon::ZoomIn => '\u{f5b0}',
-        Icon::ZoomOut => '\u{f5b1}',
-    }
-}
+include!(concat!(env!("OUT_DIR"), "/bootstrap.rs"));
@@ -1,8 +1,12 @@
 //! Bootstrap icons.
-//! Machine generated code. Do not change!
+//!
+//! The icons required by the widgets, which are the glyphs of
+//! `fonts/required-icons.ttf`. Unlike the full set, this enum is not generated
+//! at build time, so using the widgets without the `icons` feature neither
+//! runs the build script on the fonts nor needs its dependencies.
 
 /// Bootstrap icons
-#[derive(Copy, Clone, Debug, Hash)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum Icon {
     /// caret-down-fill
     CaretDownFill,
@@ -16,6 +20,8 @@ pub enum Icon {
     Check,
     /// x
     X,
+    /// A glyph of a registered icon font, see [`Icon::custom`].
+    Custom(super::CustomIcon),
 }
 
 /// Converts an icon into a char.
@@ -28,5 +34,16 @@ pub const fn icon_to_char(icon: Icon) -> char {
         Icon::CaretUpFill => '\u{f223}',
         Icon::Check => '\u{f25c}',
         Icon::X => '\u{f5ae}',
+        Icon::Custom(custom) => custom.codepoint,
     }
 }
+
+/// The names of all icons, sorted by name.
+pub(super) const ICONS: &[(&str, Icon)] = &[
+    ("caret-down-fill", Icon::CaretDownFill),
+    ("caret-left-fill", Icon::CaretLeftFill),
+    ("caret-right-fill", Icon::CaretRightFill),
+    ("caret-up-fill", Icon::CaretUpFill),
+    ("check", Icon::Check),
+    ("x", Icon::X),
+];
@@ -1,3 +1,6 @@
 //! A module fitting `iced_graphics`.
 
 pub mod icons;
+
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
@@ -0,0 +1,449 @@
+//! Render widgets offscreen to images.
+//!
+//! A [`Snapshot`](Snapshot) lays out an [`Element`](iced_native::Element),
+//! draws it and its overlay with the wgpu renderer of iced into a texture and
+//! reads the texture back into an RGBA [`Image`](Image), without a window.
+//! This enables golden-image regression tests and previews of themes.
+//!
+//! Everything the renderer of an application draws is drawn: quads, meshes,
+//! text in the fallback font of the renderer, images (with the feature
+//! `image` of `iced_wgpu`) and SVGs (with the feature `svg` of this crate).
+//! Rendering needs a graphics adapter; without one,
+//! [`render`](Snapshot::render) fails with
+//! [`Error::GraphicsAdapterNotFound`](Error::GraphicsAdapterNotFound).
+//!
+//! # Example
+//! ```no_run
+//! # use iced_aw::graphics::snapshot::Snapshot;
+//! # use iced_native::{widget::Space, Length};
+//! #
+//! let snapshot: Snapshot = Snapshot::new(200, 100);
+//! let image = snapshot
+//!     .render::<()>(Space::new(Length::Fill, Length::Fill))
+//!     .expect("A graphics adapter should be available");
+//!
+//! assert_eq!(image.pixels().len(), 200 * 100 * 4);
+//! ```
+//!
+//! *This API requires the following crate features to be activated: snapshot*
+use std::{num::NonZeroU32, sync::mpsc};
+
+use iced_graphics::{Error, Viewport};
+use iced_native::{
+    layout::Limits, renderer::Style, widget::Tree, Color, Element, Layout, Point, Rectangle, Size,
+    Vector,
+};
+use iced_style::application::StyleSheet;
+use iced_wgpu::{wgpu, Backend, Settings};
+
+/// The format of the texture the elements are drawn into.
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// The renderer drawing the elements of a [`Snapshot`](Snapshot).
+pub type Renderer<Theme = iced_style::Theme> = iced_graphics::Renderer<Backend, Theme>;
+
+/// Renders elements offscreen to [`Image`](Image)s.
+#[derive(Clone, Debug)]
+pub struct Snapshot<Theme = iced_style::Theme> {
+    /// The width of the rendered images.
+    width: u32,
+    /// The height of the rendered images.
+    height: u32,
+    /// The theme the elements are drawn with.
+    theme: Theme,
+    /// The font text in the default font is drawn with.
+    default_font: Option<&'static [u8]>,
+    /// The position of the cursor, to draw hovered widgets.
+    cursor_position: Point,
+}
+
+impl<Theme> Snapshot<Theme>
+where
+    Theme: Default + StyleSheet,
+{
+    /// Creates a new [`Snapshot`](Snapshot) rendering images of the given size
+    /// with the default theme.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Snapshot {
+            width,
+            height,
+            theme: Theme::default(),
+            default_font: None,
+            cursor_position: Point::new(-1.0, -1.0),
+        }
+    }
+
+    /// Sets the theme the elements are drawn with.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the bytes of the font text in the default font is drawn with,
+    /// instead of the fallback font of the renderer.
+    #[must_use]
+    pub fn default_font(mut self, bytes: &'static [u8]) -> Self {
+        self.default_font = Some(bytes);
+        self
+    }
+
+    /// Sets the position of the cursor, to draw hovered widgets.
+    #[must_use]
+    pub fn cursor_position(mut self, cursor_position: Point) -> Self {
+        self.cursor_position = cursor_position;
+        self
+    }
+
+    /// Lays out and draws the element and its overlay and reads them back
+    /// into an [`Image`](Image).
+    ///
+    /// # Errors
+    /// Fails if no graphics adapter or device is available, or if the
+    /// rendered texture cannot be read back.
+    pub fn render<'a, Message>(
+        &self,
+        element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    ) -> Result<Image, Error> {
+        let (device, queue) = futures::executor::block_on(request_device())?;
+
+        let mut element = element.into();
+        let mut tree = Tree::new(&element);
+        let mut renderer = Renderer::new(Backend::new(
+            &device,
+            Settings {
+                default_font: self.default_font,
+                ..Settings::default()
+            },
+            FORMAT,
+        ));
+
+        let size = Size::new(self.width as f32, self.height as f32);
+        let viewport = Rectangle::new(Point::ORIGIN, size);
+        let layout = element
+            .as_widget()
+            .layout(&renderer, &Limits::new(Size::ZERO, size));
+
+        let appearance = self
+            .theme
+            .appearance(&<Theme as StyleSheet>::Style::default());
+        let style = Style {
+            text_color: appearance.text_color,
+        };
+
+        element.as_widget().draw(
+            &tree,
+            &mut renderer,
+            &self.theme,
+            &style,
+            Layout::new(&layout),
+            self.cursor_position,
+            &viewport,
+        );
+
+        if let Some(overlay) =
+            element
+                .as_widget_mut()
+                .overlay(&mut tree, Layout::new(&layout), &renderer)
+        {
+            let node = overlay.layout(&renderer, size, Vector::ZERO);
+            renderer.with_layer(viewport, |renderer| {
+                overlay.draw(
+                    renderer,
+                    &self.theme,
+                    &style,
+                    Layout::new(&node),
+                    self.cursor_position,
+                );
+            });
+        }
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_aw::snapshot texture"),
+            size: wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("iced_aw::snapshot encoder"),
+        });
+        clear(&mut encoder, &view, appearance.background_color);
+
+        let mut staging_belt = wgpu::util::StagingBelt::new(5 * 1024);
+        let overlay_text: [&str; 0] = [];
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                &device,
+                &mut staging_belt,
+                &mut encoder,
+                &view,
+                primitives,
+                &Viewport::with_physical_size(Size::new(self.width, self.height), 1.0),
+                &overlay_text,
+            );
+        });
+
+        // Rows of a buffer a texture is copied into are aligned
+        let bytes_per_row = self.width * 4;
+        let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = (bytes_per_row + alignment - 1) / alignment * alignment;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_aw::snapshot buffer"),
+            size: u64::from(padded_bytes_per_row * self.height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        staging_belt.finish();
+        let _ = queue.submit(Some(encoder.finish()));
+        staging_belt.recall();
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        let _ = device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .map_err(|error| Error::BackendError(error.to_string()))?
+            .map_err(|error| Error::BackendError(error.to_string()))?;
+
+        let pixels = slice
+            .get_mapped_range()
+            .chunks_exact(padded_bytes_per_row as usize)
+            .flat_map(|row| &row[..bytes_per_row as usize])
+            .copied()
+            .collect();
+        buffer.unmap();
+
+        Ok(Image {
+            width: self.width,
+            height: self.height,
+            pixels,
+        })
+    }
+}
+
+/// Requests a device of the default graphics adapter.
+async fn request_device() -> Result<(wgpu::Device, wgpu::Queue), Error> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: Settings::default().internal_backend,
+        ..wgpu::InstanceDescriptor::default()
+    });
+
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions::default())
+        .await
+        .ok_or(Error::GraphicsAdapterNotFound)?;
+
+    adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("iced_aw::snapshot device"),
+                features: wgpu::Features::empty(),
+                limits: wgpu::Limits::downlevel_defaults().using_resolution(adapter.limits()),
+            },
+            None,
+        )
+        .await
+        .map_err(|_| Error::GraphicsAdapterNotFound)
+}
+
+/// Clears the texture behind the view with the color.
+fn clear(encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView, color: Color) {
+    let [r, g, b, a] = color.into_linear();
+
+    let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_aw::snapshot clear"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(wgpu::Color {
+                    r: f64::from(r),
+                    g: f64::from(g),
+                    b: f64::from(b),
+                    a: f64::from(a),
+                }),
+                store: true,
+            },
+        })],
+        depth_stencil_attachment: None,
+    });
+}
+
+/// An image rendered by a [`Snapshot`](Snapshot).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    /// The width of the [`Image`](Image).
+    width: u32,
+    /// The height of the [`Image`](Image).
+    height: u32,
+    /// The RGBA pixels of the [`Image`](Image), row by row.
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Returns the width of the [`Image`](Image).
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Image`](Image).
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the RGBA pixels of the [`Image`](Image) in the sRGB color
+    /// space, row by row.
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Takes the RGBA pixels of the [`Image`](Image) in the sRGB color space,
+    /// row by row.
+    #[must_use]
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// Returns the RGBA values of the pixel at the position, if it is inside
+    /// of the [`Image`](Image).
+    #[must_use]
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels
+            .get(index..index + 4)
+            .and_then(|pixel| pixel.try_into().ok())
+    }
+
+    /// Returns the number of pixels with a channel differing by more than the
+    /// tolerance from the other [`Image`](Image). All pixels differ if the
+    /// sizes of the images differ.
+    #[must_use]
+    pub fn difference(&self, other: &Self, tolerance: u8) -> usize {
+        if self.width != other.width || self.height != other.height {
+            return self.pixels.len().max(other.pixels.len()) / 4;
+        }
+
+        self.pixels
+            .chunks_exact(4)
+            .zip(other.pixels.chunks_exact(4))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > tolerance)
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_graphics::Error;
+    use iced_native::{widget::Space, Length};
+
+    use super::{Image, Snapshot};
+
+    /// Renders a filling space with the snapshot, or returns `None` without a
+    /// graphics adapter.
+    fn render(snapshot: &Snapshot) -> Option<Image> {
+        match snapshot.render::<()>(Space::new(Length::Fill, Length::Fill)) {
+            Ok(image) => Some(image),
+            Err(Error::GraphicsAdapterNotFound) => None,
+            Err(error) => panic!("Rendering should succeed: {error}"),
+        }
+    }
+
+    #[test]
+    fn pixel_and_difference() {
+        let white = Image {
+            width: 2,
+            height: 2,
+            pixels: vec![255; 16],
+        };
+        let mut pixels = vec![255; 16];
+        pixels[12..].copy_from_slice(&[250, 0, 0, 255]);
+        let red = Image {
+            width: 2,
+            height: 2,
+            pixels,
+        };
+
+        assert_eq!(red.pixel(1, 1), Some([250, 0, 0, 255]));
+        assert_eq!(red.pixel(2, 0), None);
+        assert_eq!(white.difference(&red, 0), 1);
+        assert_eq!(white.difference(&red, 5), 1);
+        assert_eq!(white.difference(&white.clone(), 0), 0);
+        assert_eq!(
+            white.difference(
+                &Image {
+                    width: 1,
+                    height: 1,
+                    pixels: vec![255; 4],
+                },
+                0
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn render_background_of_theme() {
+        let light = Snapshot::new(20, 10);
+        let (Some(image), Some(other)) = (render(&light), render(&light)) else {
+            return;
+        };
+
+        assert_eq!((image.width(), image.height()), (20, 10));
+        assert_eq!(image.pixels().len(), 20 * 10 * 4);
+        assert_eq!(image.pixel(0, 0), Some([255, 255, 255, 255]));
+        assert_eq!(image.difference(&other, 0), 0);
+
+        let dark = light.theme(iced_style::Theme::Dark);
+        if let Some(dark) = render(&dark) {
+            assert_eq!(image.difference(&dark, 0), 200);
+        }
+    }
+}
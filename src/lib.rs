@@ -54,13 +54,32 @@ pub mod native;
 pub mod core;
 pub mod style;
 
+pub mod testing;
+
 pub use iced_native::Element;
+pub use style::Theme;
 
-/// Exports for all platforms that are not WASM32.
+/// Exports for all platforms, including WASM32.
 mod platform {
+    #[doc(no_inline)]
+    pub use crate::native::focus;
+
+    #[doc(no_inline)]
+    pub use crate::native::operation;
+
+    #[doc(no_inline)]
+    #[cfg(feature = "accessibility")]
+    pub use crate::core::accessibility;
+
     #[doc(no_inline)]
     #[cfg(feature = "icons")]
-    pub use {crate::graphics::icons::Icon, crate::graphics::icons::ICON_FONT};
+    pub use {
+        crate::graphics::icons, crate::graphics::icons::Icon, crate::graphics::icons::ICON_FONT,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "icon")]
+    pub use crate::native::icon;
 
     #[doc(no_inline)]
     #[cfg(feature = "icon_text")]
@@ -82,6 +101,25 @@ mod platform {
     #[cfg(feature = "date_picker")]
     pub use {crate::native::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "calendar_heatmap")]
+    pub use {
+        crate::native::calendar_heatmap, crate::style::CalendarHeatmapStyles,
+        calendar_heatmap::CalendarHeatmap,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "heatmap")]
+    pub use {
+        crate::native::heatmap,
+        crate::style::HeatmapStyles,
+        heatmap::{ColorScale, Heatmap},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "month_view")]
+    pub use {crate::native::month_view, crate::style::MonthViewStyles, month_view::MonthView};
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_element")]
     pub use {crate::native::floating_element, floating_element::FloatingElement};
@@ -97,6 +135,282 @@ mod platform {
     #[cfg(feature = "modal")]
     pub use {crate::native::modal, crate::style::ModalStyles, modal::Modal};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "popover")]
+    pub use {crate::native::popover, crate::style::PopoverStyles, popover::Popover};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "portal")]
+    pub use {crate::native::portal, portal::Portal};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tooltip")]
+    pub use {crate::native::tooltip, crate::style::TooltipStyles, tooltip::Tooltip};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tour")]
+    pub use {
+        crate::native::tour,
+        crate::style::TourStyles,
+        tour::{Tour, TourStep},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "hover_card")]
+    pub use {crate::native::hover_card, crate::style::HoverCardStyles, hover_card::HoverCard};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gesture_detector")]
+    pub use {crate::native::gesture_detector, gesture_detector::GestureDetector};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "responsive")]
+    pub use {crate::native::responsive, responsive::Responsive};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "toast")]
+    pub use {crate::native::toast, crate::style::ToastStyles, toast::Toasts};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "table")]
+    pub use {crate::native::table, crate::style::TableStyles, table::Table};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "spreadsheet_grid")]
+    pub use {
+        crate::native::spreadsheet_grid, crate::style::SpreadsheetGridStyles,
+        spreadsheet_grid::SpreadsheetGrid,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "accordion")]
+    pub use {crate::native::accordion, crate::style::AccordionStyles, accordion::Accordion};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "property_grid")]
+    pub use {
+        crate::native::property_grid, crate::style::PropertyGridStyles,
+        property_grid::PropertyGrid,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "breadcrumbs")]
+    pub use {crate::native::breadcrumbs, crate::style::BreadcrumbsStyles, breadcrumbs::Breadcrumbs};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "pagination")]
+    pub use {crate::native::pagination, crate::style::PaginationStyles, pagination::Pagination};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "range_slider")]
+    pub use {
+        crate::native::range_slider, crate::style::RangeSliderStyles, range_slider::RangeSlider,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tick_slider")]
+    pub use {crate::native::tick_slider, crate::style::TickSliderStyles, tick_slider::TickSlider};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "scrubber")]
+    pub use {crate::native::scrubber, crate::style::ScrubberStyles, scrubber::Scrubber};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "xy_pad")]
+    pub use {crate::native::xy_pad, crate::style::XYPadStyles, xy_pad::XYPad};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "rating")]
+    pub use {crate::native::rating, crate::style::RatingStyles, rating::Rating};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "stepper")]
+    pub use {crate::native::stepper, crate::style::StepperStyles, stepper::Stepper};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "combo_box")]
+    pub use {crate::native::combo_box, crate::style::ComboBoxStyles, combo_box::ComboBox};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "multi_select")]
+    pub use {
+        crate::native::multi_select,
+        crate::style::MultiSelectStyles,
+        multi_select::MultiSelect,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "menu_button")]
+    pub use {
+        crate::native::menu_button, crate::style::MenuButtonStyles, menu_button::MenuButton,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tag_input")]
+    pub use {crate::native::tag_input, crate::style::TagInputStyles, tag_input::TagInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "segmented_button")]
+    pub use {
+        crate::native::segmented_button,
+        crate::style::SegmentedButtonStyles,
+        segmented_button::SegmentedButton,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "circular_progress")]
+    pub use {
+        crate::native::circular_progress,
+        crate::style::CircularProgressStyles,
+        circular_progress::CircularProgress,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "skeleton")]
+    pub use {crate::native::skeleton, crate::style::SkeletonStyles, skeleton::Skeleton};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "status_dot")]
+    pub use {crate::native::status_dot, crate::style::StatusDotStyles, status_dot::StatusDot};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "avatar")]
+    pub use {
+        crate::native::avatar,
+        crate::style::AvatarStyles,
+        avatar::{Avatar, AvatarGroup},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "chip")]
+    pub use {crate::native::chip, crate::style::ChipStyles, chip::Chip};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "carousel")]
+    pub use {crate::native::carousel, crate::style::CarouselStyles, carousel::Carousel};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gallery")]
+    pub use {crate::native::gallery, crate::style::GalleryStyles, gallery::Gallery};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "chart")]
+    pub use {
+        crate::native::chart,
+        crate::style::ChartStyles,
+        chart::{BarChart, LineChart, PieChart, Series, Slice},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gauge")]
+    pub use {crate::native::gauge, crate::style::GaugeStyles, gauge::Gauge};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "knob")]
+    pub use {crate::native::knob, crate::style::KnobStyles, knob::Knob};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "drawing_pad")]
+    pub use {
+        crate::native::drawing_pad, crate::style::DrawingPadStyles, drawing_pad::DrawingPad,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "drop_zone")]
+    pub use {crate::native::drop_zone, crate::style::DropZoneStyles, drop_zone::DropZone};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "code_editor")]
+    pub use {
+        crate::native::code_editor, crate::style::CodeEditorStyles, code_editor::CodeEditor,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "rich_text")]
+    pub use {
+        crate::native::rich_text,
+        crate::style::RichTextStyles,
+        rich_text::{RichText, Span},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "virtual_list")]
+    pub use {
+        crate::native::virtual_list,
+        crate::style::VirtualListStyles,
+        virtual_list::{RowHeight, VirtualList},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "infinite_scroll")]
+    pub use {crate::native::infinite_scroll, infinite_scroll::InfiniteScroll};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "drag_and_drop")]
+    pub use {
+        crate::native::drag_and_drop,
+        crate::style::DragAndDropStyles,
+        drag_and_drop::{DragSource, DropTarget},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "search_bar")]
+    pub use {crate::native::search_bar, crate::style::SearchBarStyles, search_bar::SearchBar};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "qr_code")]
+    pub use {
+        crate::native::qr_code,
+        crate::style::QrCodeStyles,
+        qr_code::{ErrorCorrection, QrCode},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "image_viewer")]
+    pub use {crate::native::image_viewer, image_viewer::ImageViewer};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "minimap")]
+    pub use {
+        crate::native::minimap,
+        crate::style::MinimapStyles,
+        minimap::{Minimap, Navigation},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "pin_input")]
+    pub use {crate::native::pin_input, crate::style::PinInputStyles, pin_input::PinInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "masked_input")]
+    pub use {
+        crate::native::masked_input,
+        masked_input::{Mask, MaskedInput},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "hotkey_input")]
+    pub use {
+        crate::native::hotkey_input,
+        crate::style::HotkeyInputStyles,
+        hotkey_input::{Hotkey, HotkeyInput},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "shortcut_hint")]
+    pub use {
+        crate::native::shortcut_hint, crate::style::ShortcutHintStyles,
+        shortcut_hint::ShortcutHint,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "transfer_list")]
+    pub use {
+        crate::native::transfer_list,
+        crate::style::TransferListStyles,
+        transfer_list::{Transfer, TransferList},
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "tab_bar")]
     pub use {
@@ -137,6 +451,14 @@ mod platform {
     #[cfg(feature = "split")]
     pub use {crate::native::split, crate::style::SplitStyles, split::Split};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "switch")]
+    pub use {crate::native::switch, crate::style::SwitchStyles, switch::Switch};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "resizable")]
+    pub use {crate::native::resizable, crate::style::ResizableStyles, resizable::Resizable};
+
     #[doc(no_inline)]
     #[cfg(feature = "menu")]
     pub use {
@@ -146,6 +468,10 @@ mod platform {
         },
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "split_button")]
+    pub use crate::native::menu::SplitButton;
+
     #[doc(no_inline)]
     #[cfg(feature = "quad")]
     pub use crate::native::quad;
@@ -155,11 +481,26 @@ mod platform {
     #[cfg(feature = "spinner")]
     pub use {crate::native::spinner, crate::style::SpinnerStyle, spinner::Spinner};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "loading_overlay")]
+    pub use {
+        crate::native::loading_overlay, crate::style::LoadingOverlayStyles,
+        loading_overlay::LoadingOverlay,
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "context_menu")]
     pub use {
         crate::native::context_menu, crate::style::ContextMenuStyle, context_menu::ContextMenu,
     };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "radial_menu")]
+    pub use {
+        crate::native::radial_menu,
+        crate::style::RadialMenuStyles,
+        radial_menu::{RadialItem, RadialMenu},
+    };
 }
 
 #[doc(no_inline)]
@@ -0,0 +1,802 @@
+//! Show titled sections that expand and collapse their content.
+//!
+//! *This API requires the following crate features to be activated: accordion*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text,
+    time::{Duration, Instant},
+    touch,
+    widget::{tree, Operation, Tree},
+    window, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{
+    core::animation::{Animation, Easing},
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::accordion::{Appearance, StyleSheet};
+
+/// The default padding around the title and the content of a section.
+const DEFAULT_PADDING: f32 = 10.0;
+
+/// The default spacing between two sections.
+const DEFAULT_SPACING: f32 = 4.0;
+
+/// The time a section takes to expand or collapse.
+const TRANSITION: Duration = Duration::from_millis(200);
+
+/// The size of the chevron relative to the text size.
+const CHEVRON_SCALE: f32 = 0.6;
+
+/// Whether several sections of an [`Accordion`](Accordion) can be open at
+/// once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// Opening a section closes the others.
+    #[default]
+    Single,
+    /// Each section opens and closes on its own.
+    Multiple,
+}
+
+/// The open sections of an [`Accordion`](Accordion).
+///
+/// The application keeps the [`State`](State) and changes it when the
+/// [`Accordion`](Accordion) asks to toggle a section. The sections expand and
+/// collapse from where they are when their state changes.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// Whether several sections can be open at once.
+    mode: Mode,
+    /// The states of the sections.
+    sections: Vec<Section>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with all sections closed.
+    #[must_use]
+    pub const fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            sections: Vec::new(),
+        }
+    }
+
+    /// Returns the [`Mode`](Mode) of the [`State`](State).
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns true if the section at the index is open.
+    #[must_use]
+    pub fn is_open(&self, index: usize) -> bool {
+        self.sections
+            .get(index)
+            .map_or(false, |section| section.open)
+    }
+
+    /// Opens the section at the index, closing the others in
+    /// [`Single`](Mode::Single) mode.
+    pub fn open(&mut self, index: usize) {
+        self.set(index, true, Instant::now());
+    }
+
+    /// Closes the section at the index.
+    pub fn close(&mut self, index: usize) {
+        self.set(index, false, Instant::now());
+    }
+
+    /// Opens the section at the index if it is closed and closes it
+    /// otherwise.
+    pub fn toggle(&mut self, index: usize) {
+        self.set(index, !self.is_open(index), Instant::now());
+    }
+
+    /// Opens or closes the section at the index at the given time.
+    fn set(&mut self, index: usize, open: bool, now: Instant) {
+        if self.sections.len() <= index {
+            self.sections.resize_with(index + 1, Section::new);
+        }
+
+        if open && self.mode == Mode::Single {
+            for (other, section) in self.sections.iter_mut().enumerate() {
+                if other != index {
+                    section.set(false, now);
+                }
+            }
+        }
+
+        self.sections[index].set(open, now);
+    }
+
+    /// Returns how far the section at the index is expanded, between 0.0 and
+    /// 1.0.
+    fn expansion(&self, index: usize, now: Instant) -> f32 {
+        self.sections
+            .get(index)
+            .map_or(0.0, |section| section.animation.value(now))
+    }
+
+    /// Returns true while a section expands or collapses.
+    fn is_animating(&self, now: Instant) -> bool {
+        self.sections
+            .iter()
+            .any(|section| section.animation.is_running(now))
+    }
+}
+
+/// The state of a single section of an [`Accordion`](Accordion).
+#[derive(Clone, Copy, Debug)]
+struct Section {
+    /// Whether the section is open.
+    open: bool,
+    /// The animation expanding and collapsing the section.
+    animation: Animation,
+}
+
+impl Section {
+    /// Creates a new closed [`Section`](Section).
+    const fn new() -> Self {
+        Self {
+            open: false,
+            animation: Animation::new(TRANSITION).easing(Easing::EaseInOut),
+        }
+    }
+
+    /// Starts expanding or collapsing the section if its state changes.
+    fn set(&mut self, open: bool, now: Instant) {
+        if self.open != open {
+            self.open = open;
+            self.animation.go(open, now);
+        }
+    }
+}
+
+/// A list of titled sections expanding their content when their header is
+/// pressed.
+///
+/// The open sections are kept in a [`State`](State) of the application, which
+/// is asked to toggle the section at an index.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::accordion;
+/// #
+/// # pub type Accordion<'a, Message> = accordion::Accordion<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Toggle(usize),
+/// }
+///
+/// let state = accordion::State::new(accordion::Mode::Single);
+///
+/// let accordion = Accordion::new(&state, Message::Toggle)
+///     .push("General", Text::new("General settings"))
+///     .push("Advanced", Text::new("Advanced settings"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Accordion<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The open sections.
+    state: &'a State,
+    /// The titles and the contents of the sections.
+    sections: Vec<(String, Element<'a, Message, Renderer>)>,
+    /// The function producing the message to toggle the section at an index.
+    on_toggle: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The width of the accordion.
+    width: Length,
+    /// The spacing between two sections.
+    spacing: f32,
+    /// The padding around the title and the content of a section.
+    padding: f32,
+    /// The text size of the titles.
+    text_size: Option<f32>,
+    /// The font of the titles.
+    font: Font,
+    /// The style of the accordion.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Accordion<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Accordion`](Accordion) without sections.
+    ///
+    /// It expects:
+    ///     * the [`State`](State) keeping the open sections.
+    ///     * the function that will be called to toggle the section at an
+    ///         index when its header is pressed.
+    pub fn new<F>(state: &'a State, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Accordion {
+            state,
+            sections: Vec::new(),
+            on_toggle: Box::new(on_toggle),
+            width: Length::Fill,
+            spacing: DEFAULT_SPACING,
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a section with the title and the content to the
+    /// [`Accordion`](Accordion).
+    #[must_use]
+    pub fn push(
+        mut self,
+        title: impl Into<String>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.sections.push((title.into(), content.into()));
+        self
+    }
+
+    /// Sets the width of the [`Accordion`](Accordion).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between two sections.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding around the title and the content of a section.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the titles.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the titles.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Accordion`](Accordion).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Accordion<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Interaction>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Interaction::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.sections
+            .iter()
+            .map(|(_, content)| Tree::new(content))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let contents: Vec<_> = self.sections.iter().map(|(_, content)| content).collect();
+        tree.diff_children(&contents);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let padding = self.padding;
+        let header_height = text_size + 2.0 * padding;
+        let content_limits = Limits::new(
+            Size::ZERO,
+            Size::new((limits.max().width - 2.0 * padding).max(0.0), f32::INFINITY),
+        );
+        let now = Instant::now();
+
+        let contents: Vec<Node> = self
+            .sections
+            .iter()
+            .map(|(_, content)| content.as_widget().layout(renderer, &content_limits))
+            .collect();
+
+        // The widest title or content decides the width of a shrinking
+        // accordion
+        let intrinsic_width = self
+            .sections
+            .iter()
+            .zip(&contents)
+            .map(|((title, _), content)| {
+                let (title_width, _) = renderer.measure(
+                    title,
+                    text_size,
+                    self.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+
+                (title_width + text_size + padding).max(content.size().width) + 2.0 * padding
+            })
+            .fold(0.0, f32::max);
+        let width = limits.resolve(Size::new(intrinsic_width, 0.0)).width;
+
+        let mut y = 0.0;
+        let mut sections = Vec::with_capacity(contents.len());
+
+        for (index, mut content) in contents.into_iter().enumerate() {
+            if index > 0 {
+                y += self.spacing;
+            }
+
+            let expanded =
+                (content.size().height + 2.0 * padding) * self.state.expansion(index, now);
+            content.move_to(Point::new(padding, header_height + padding));
+
+            let header = Node::new(Size::new(width, header_height));
+            let mut section = Node::with_children(
+                Size::new(width, header_height + expanded),
+                vec![header, content],
+            );
+            section.move_to(Point::new(0.0, y));
+            y += section.size().height;
+
+            sections.push(section);
+        }
+
+        Node::with_children(limits.resolve(Size::new(width, y)), sections)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let interaction = state.state.downcast_mut::<Interaction>();
+            let is_animating = self.state.is_animating(now);
+
+            // The last frame of a transition needs a final layout as well
+            if is_animating || interaction.animating {
+                shell.invalidate_layout();
+            }
+            if is_animating {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            interaction.animating = is_animating;
+        }
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+        ) {
+            state.state.downcast_mut::<Interaction>().pressed = None;
+        }
+
+        let pressed = &mut state.state.downcast_mut::<Interaction>().pressed;
+
+        let mut status = event::Status::Ignored;
+
+        for (((index, (_, content)), state), section) in self
+            .sections
+            .iter_mut()
+            .enumerate()
+            .zip(&mut state.children)
+            .zip(layout.children())
+        {
+            let section_bounds = section.bounds();
+            let mut children = section.children();
+            let header = children
+                .next()
+                .expect("Native: Layout should have a header layout for an Accordion");
+            let content_layout = children
+                .next()
+                .expect("Native: Layout should have a content layout for an Accordion");
+
+            if self.state.is_open(index) {
+                // The content is cut off while it expands
+                let cursor_position = if section_bounds.contains(cursor_position) {
+                    cursor_position
+                } else {
+                    Point::new(-1.0, -1.0)
+                };
+
+                status = status.merge(content.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    content_layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                ));
+            }
+
+            if status == event::Status::Ignored
+                && header.bounds().contains(cursor_position)
+                && matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerPressed { .. })
+                )
+            {
+                shell.publish((self.on_toggle)(index));
+                *pressed = Some(index);
+                status = event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        for (((index, (_, content)), state), section) in self
+            .sections
+            .iter()
+            .enumerate()
+            .zip(&state.children)
+            .zip(layout.children())
+        {
+            if !section.bounds().contains(cursor_position) {
+                continue;
+            }
+
+            let mut children = section.children();
+            let header = children
+                .next()
+                .expect("Native: Layout should have a header layout for an Accordion");
+
+            if header.bounds().contains(cursor_position) {
+                return mouse::Interaction::Pointer;
+            }
+
+            if self.state.is_open(index) {
+                return content.as_widget().mouse_interaction(
+                    state,
+                    children
+                        .next()
+                        .expect("Native: Layout should have a content layout for an Accordion"),
+                    cursor_position,
+                    viewport,
+                    renderer,
+                );
+            }
+        }
+
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let now = Instant::now();
+        let pressed = state.state.downcast_ref::<Interaction>().pressed;
+
+        for (((index, (title, content)), state), section) in self
+            .sections
+            .iter()
+            .enumerate()
+            .zip(&state.children)
+            .zip(layout.children())
+        {
+            let section_bounds = section.bounds();
+
+            if section_bounds.intersection(viewport).is_none() {
+                continue;
+            }
+
+            let mut children = section.children();
+            let header_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a header layout for an Accordion")
+                .bounds();
+            let content_layout = children
+                .next()
+                .expect("Graphics: Layout should have a content layout for an Accordion");
+
+            let is_hovered = header_bounds.contains(cursor_position);
+            let style_state = StyleState::new(
+                is_hovered,
+                is_hovered && pressed == Some(index),
+                false,
+                false,
+            );
+            let appearance = appearance!(StyleSheet, theme, style_state, self.style);
+            let expansion = self.state.expansion(index, now);
+            let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+                appearance.border_radius.into();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: section_bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+
+            // The header keeps its bottom corners only while it is closed
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header_bounds,
+                    border_radius: if expansion > 0.0 {
+                        [top_left, top_right, 0.0, 0.0]
+                    } else {
+                        [top_left, top_right, bottom_right, bottom_left]
+                    }
+                    .into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.header_background,
+            );
+
+            renderer.fill_text(text::Text {
+                content: title,
+                bounds: Rectangle {
+                    x: header_bounds.x + self.padding,
+                    y: header_bounds.center_y(),
+                    ..header_bounds
+                },
+                size: text_size,
+                color: appearance.header_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+
+            fill_chevron(
+                renderer,
+                Point::new(
+                    header_bounds.x + header_bounds.width - self.padding - text_size / 2.0,
+                    header_bounds.center_y(),
+                ),
+                text_size * CHEVRON_SCALE,
+                expansion * std::f32::consts::FRAC_PI_2,
+                appearance.icon_color,
+            );
+
+            if expansion <= 0.0 {
+                continue;
+            }
+
+            let content_bounds = Rectangle {
+                y: header_bounds.y + header_bounds.height,
+                height: section_bounds.height - header_bounds.height,
+                ..section_bounds
+            };
+
+            renderer.with_layer(content_bounds, |renderer| {
+                content.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: appearance.text_color,
+                    },
+                    content_layout,
+                    cursor_position,
+                    &content_bounds,
+                );
+            });
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        for (((_, content), state), section) in self
+            .sections
+            .iter()
+            .zip(&mut state.children)
+            .zip(layout.children())
+        {
+            if let Some(content_layout) = section.children().nth(1) {
+                content
+                    .as_widget()
+                    .operate(state, content_layout, renderer, operation);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let open = self.state;
+
+        let overlays: Vec<_> = self
+            .sections
+            .iter_mut()
+            .enumerate()
+            .zip(&mut state.children)
+            .zip(layout.children())
+            .filter(|((index, _), _)| open.is_open(*index))
+            .filter_map(|(((_, (_, content)), state), section)| {
+                content
+                    .as_widget_mut()
+                    .overlay(state, section.children().nth(1)?, renderer)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Accordion<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(accordion: Accordion<'a, Message, Renderer>) -> Self {
+        Element::new(accordion)
+    }
+}
+
+/// The interaction with an [`Accordion`](Accordion).
+#[derive(Debug, Default)]
+struct Interaction {
+    /// Whether a section expanded or collapsed during the last frame.
+    animating: bool,
+    /// The index of the section whose header is pressed.
+    pressed: Option<usize>,
+}
+
+/// Draws a chevron pointing to the right, rotated clockwise by the angle,
+/// as a line of dots.
+fn fill_chevron<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    size: f32,
+    angle: f32,
+    color: Color,
+) where
+    Renderer: iced_native::Renderer,
+{
+    let stroke = (size / 6.0).max(1.5);
+    let radius = stroke / 2.0;
+    let (sin, cos) = angle.sin_cos();
+    let rotate = |x: f32, y: f32| {
+        Point::new(
+            center.x + (x * cos - y * sin) * size,
+            center.y + (x * sin + y * cos) * size,
+        )
+    };
+    let corners = [rotate(-0.25, -0.5), rotate(0.25, 0.0), rotate(-0.25, 0.5)];
+
+    for segment in corners.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let length = from.distance(to);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let steps = (length / radius).ceil().max(1.0) as usize;
+
+        for step in 0..=steps {
+            #[allow(clippy::cast_precision_loss)]
+            let t = step as f32 / steps as f32;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: from.x + (to.x - from.x) * t - radius,
+                        y: from.y + (to.y - from.y) * t - radius,
+                        width: stroke,
+                        height: stroke,
+                    },
+                    border_radius: radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::time::{Duration, Instant};
+
+    use super::{Mode, State, TRANSITION};
+
+    #[test]
+    fn single_mode_closes_the_others() {
+        let now = Instant::now();
+        let mut state = State::new(Mode::Single);
+
+        state.set(0, true, now);
+        state.set(2, true, now);
+        assert!(!state.is_open(0));
+        assert!(!state.is_open(1));
+        assert!(state.is_open(2));
+
+        let mut state = State::new(Mode::Multiple);
+
+        state.set(0, true, now);
+        state.set(2, true, now);
+        assert!(state.is_open(0));
+        assert!(state.is_open(2));
+    }
+
+    #[test]
+    fn expands_over_the_transition() {
+        let now = Instant::now();
+        let mut state = State::new(Mode::Single);
+
+        state.set(0, true, now);
+        assert!(state.is_animating(now));
+        assert!(state.expansion(0, now + TRANSITION / 2) < 1.0);
+        assert!(state.expansion(0, now + TRANSITION) >= 1.0);
+        assert!(!state.is_animating(now + TRANSITION + Duration::from_millis(1)));
+        assert!(state.expansion(1, now) <= 0.0);
+    }
+}
@@ -0,0 +1,705 @@
+//! Show a person as an image or their initials.
+//!
+//! *This API requires the following crate features to be activated: avatar*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    image,
+    layout::{Limits, Node},
+    renderer, text,
+    widget::Tree,
+    Color, Element, Font, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::avatar::{Appearance, StyleSheet};
+
+/// The colors the initials are drawn on, picked by the name of the person.
+const COLORS: [[f32; 3]; 8] = [
+    [0.84, 0.33, 0.31],
+    [0.91, 0.52, 0.22],
+    [0.75, 0.6, 0.12],
+    [0.36, 0.62, 0.33],
+    [0.18, 0.6, 0.6],
+    [0.26, 0.52, 0.84],
+    [0.48, 0.4, 0.8],
+    [0.78, 0.36, 0.62],
+];
+
+/// The diameter of the presence dot, relative to the size of the avatar.
+const PRESENCE_SIZE: f32 = 0.3;
+
+/// The width of the ring separating the presence dot and the stacked avatars
+/// of a group from what is behind them.
+const RING_WIDTH: f32 = 2.0;
+
+/// The default part of an avatar covered by the next one in a group.
+const DEFAULT_OVERLAP: f32 = 0.25;
+
+/// The size of an [`Avatar`](Avatar).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AvatarSize {
+    /// A small avatar, 24 pixels wide.
+    Small,
+    /// A medium avatar, 40 pixels wide.
+    #[default]
+    Medium,
+    /// A large avatar, 56 pixels wide.
+    Large,
+    /// An avatar of a custom size in pixels.
+    Custom(f32),
+}
+
+impl AvatarSize {
+    /// Returns the width and height of the avatar in pixels.
+    #[must_use]
+    pub const fn pixels(self) -> f32 {
+        match self {
+            Self::Small => 24.0,
+            Self::Medium => 40.0,
+            Self::Large => 56.0,
+            Self::Custom(size) => size,
+        }
+    }
+}
+
+impl From<f32> for AvatarSize {
+    fn from(size: f32) -> Self {
+        Self::Custom(size)
+    }
+}
+
+/// The shape of an [`Avatar`](Avatar).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Shape {
+    /// A circle.
+    #[default]
+    Circle,
+    /// A square with rounded corners.
+    Rounded,
+}
+
+/// Whether the person of an [`Avatar`](Avatar) is available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presence {
+    /// The person is available.
+    Online,
+    /// The person is away for a while.
+    Away,
+    /// The person does not want to be disturbed.
+    Busy,
+    /// The person is not available.
+    Offline,
+}
+
+/// An image of a person, or their colored initials if there is no image.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{avatar::{AvatarSize, Presence}, Avatar};
+/// #
+/// let avatar = Avatar::new("Ada Lovelace")
+///     .size(AvatarSize::Large)
+///     .presence(Presence::Online);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Avatar<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The name of the person.
+    name: String,
+    /// The image of the person.
+    image: Option<<Renderer as image::Renderer>::Handle>,
+    /// The size of the avatar.
+    size: AvatarSize,
+    /// The shape of the avatar.
+    shape: Shape,
+    /// Whether the person is available.
+    presence: Option<Presence>,
+    /// The color the initials are drawn on, instead of the one picked by the
+    /// name.
+    color: Option<Color>,
+    /// The font of the initials.
+    font: Font,
+    /// The style of the avatar.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> Avatar<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Avatar`](Avatar) of the person with the name.
+    ///
+    /// Until an image is set, the initials of the name are shown on a color
+    /// picked by the name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            image: None,
+            size: AvatarSize::default(),
+            shape: Shape::default(),
+            presence: None,
+            color: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the image of the [`Avatar`](Avatar).
+    ///
+    /// The image covers the avatar and is cropped to its shape.
+    #[must_use]
+    pub fn image(mut self, image: <Renderer as image::Renderer>::Handle) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Sets the size of the [`Avatar`](Avatar).
+    #[must_use]
+    pub fn size(mut self, size: impl Into<AvatarSize>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Sets the shape of the [`Avatar`](Avatar).
+    #[must_use]
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Shows a dot in the corner of the [`Avatar`](Avatar) telling whether the
+    /// person is available.
+    #[must_use]
+    pub fn presence(mut self, presence: Presence) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+
+    /// Sets the color the initials are drawn on.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the font of the initials.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Avatar`](Avatar).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Draws the [`Avatar`](Avatar) in the bounds.
+    fn draw_in(&self, renderer: &mut Renderer, appearance: &Appearance, bounds: Rectangle) {
+        let radius = radius(self.shape, bounds.width);
+
+        if let Some(handle) = &self.image {
+            let dimensions = renderer.dimensions(handle);
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.draw(
+                    handle.clone(),
+                    cover(
+                        bounds,
+                        Size::new(dimensions.width as f32, dimensions.height as f32),
+                    ),
+                );
+
+                // Images can not be rounded, so their corners are covered by a
+                // ring in the color of the surface around the shape
+                let mask = bounds.width / 2.0;
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: expand(bounds, mask),
+                        border_radius: (radius + mask).into(),
+                        border_width: mask,
+                        border_color: appearance.surface_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            });
+        } else {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                self.color.unwrap_or_else(|| color_of(&self.name)),
+            );
+
+            fill_centered_text(
+                renderer,
+                &initials(&self.name),
+                bounds,
+                self.font,
+                appearance.text_color,
+            );
+        }
+
+        if appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if let Some(presence) = self.presence {
+            let color = match presence {
+                Presence::Online => appearance.online_color,
+                Presence::Away => appearance.away_color,
+                Presence::Busy => appearance.busy_color,
+                Presence::Offline => appearance.offline_color,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: presence_bounds(self.shape, bounds),
+                    border_radius: f32::MAX.into(),
+                    border_width: RING_WIDTH,
+                    border_color: appearance.surface_color,
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Avatar<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Fixed(self.size.pixels())
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size.pixels())
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let size = self.size.pixels();
+
+        Node::new(
+            limits
+                .width(Length::Fixed(size))
+                .height(Length::Fixed(size))
+                .resolve(Size::new(size, size)),
+        )
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style_state = StyleState::new(bounds.contains(cursor_position), false, false, false);
+        let appearance = appearance!(StyleSheet, theme, style_state, self.style);
+
+        self.draw_in(renderer, &appearance, bounds);
+    }
+}
+
+impl<'a, Message, Renderer> From<Avatar<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(avatar: Avatar<Renderer>) -> Self {
+        Element::new(avatar)
+    }
+}
+
+/// [`Avatar`](Avatar)s stacked on top of each other, each covering a part of
+/// the previous one.
+///
+/// If there are more avatars than shown, the last one counts the hidden ones.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{avatar::AvatarGroup, Avatar};
+/// #
+/// let team = AvatarGroup::new(vec![
+///     Avatar::new("Ada Lovelace"),
+///     Avatar::new("Grace Hopper"),
+///     Avatar::new("Alan Turing"),
+/// ])
+/// .max(2);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AvatarGroup<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The avatars of the group.
+    avatars: Vec<Avatar<Renderer>>,
+    /// The number of avatars shown before the rest is counted.
+    max: Option<usize>,
+    /// The size of all avatars.
+    size: AvatarSize,
+    /// The part of an avatar covered by the next one.
+    overlap: f32,
+    /// The font of the count of hidden avatars.
+    font: Font,
+    /// The style of the group.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> AvatarGroup<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`AvatarGroup`](AvatarGroup) stacking the avatars.
+    #[must_use]
+    pub fn new(avatars: Vec<Avatar<Renderer>>) -> Self {
+        Self {
+            avatars,
+            max: None,
+            size: AvatarSize::default(),
+            overlap: DEFAULT_OVERLAP,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the number of avatars shown before the rest is counted.
+    #[must_use]
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the size of all avatars of the [`AvatarGroup`](AvatarGroup),
+    /// replacing their own sizes.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<AvatarSize>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Sets the part of an avatar covered by the next one, between 0.0 and
+    /// 1.0.
+    #[must_use]
+    pub fn overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the font of the count of hidden avatars.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`AvatarGroup`](AvatarGroup).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the number of shown and of hidden avatars.
+    fn counts(&self) -> (usize, usize) {
+        let shown = self
+            .max
+            .map_or(self.avatars.len(), |max| max.min(self.avatars.len()));
+
+        (shown, self.avatars.len() - shown)
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for AvatarGroup<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let (shown, hidden) = self.counts();
+        let count = shown + usize::from(hidden > 0);
+        let size = self.size.pixels();
+
+        Node::new(
+            limits
+                .width(Length::Shrink)
+                .height(Length::Shrink)
+                .resolve(Size::new(group_width(count, size, self.overlap), size)),
+        )
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style_state = StyleState::new(bounds.contains(cursor_position), false, false, false);
+        let appearance = appearance!(StyleSheet, theme, style_state, self.style);
+        let size = self.size.pixels();
+        let step = size * (1.0 - self.overlap);
+        let (shown, hidden) = self.counts();
+
+        let slot = |index: usize| Rectangle {
+            x: bounds.x + index as f32 * step,
+            y: bounds.y,
+            width: size,
+            height: size,
+        };
+
+        // Each avatar is separated from the previous one by a ring in the
+        // color of the surface
+        let ring = |renderer: &mut Renderer, index: usize, shape: Shape| {
+            if index > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: expand(slot(index), RING_WIDTH),
+                        border_radius: (radius(shape, size) + RING_WIDTH).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.surface_color,
+                );
+            }
+        };
+
+        for (index, avatar) in self.avatars.iter().take(shown).enumerate() {
+            ring(renderer, index, avatar.shape);
+            avatar.draw_in(renderer, &appearance, slot(index));
+        }
+
+        if hidden > 0 {
+            let shape = self
+                .avatars
+                .first()
+                .map_or_else(Shape::default, |avatar| avatar.shape);
+            let bounds = slot(shown);
+
+            ring(renderer, shown, shape);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: radius(shape, size).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.overflow_background,
+            );
+
+            fill_centered_text(
+                renderer,
+                &format!("+{hidden}"),
+                bounds,
+                self.font,
+                appearance.overflow_text_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<AvatarGroup<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::text::Renderer<Font = Font> + iced_native::image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(group: AvatarGroup<Renderer>) -> Self {
+        Element::new(group)
+    }
+}
+
+/// Draws the text centered in the bounds, sized to the bounds.
+fn fill_centered_text<Renderer>(
+    renderer: &mut Renderer,
+    content: &str,
+    bounds: Rectangle,
+    font: Font,
+    color: Color,
+) where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+{
+    renderer.fill_text(text::Text {
+        content,
+        bounds: Rectangle {
+            x: bounds.center_x(),
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size: bounds.height * 0.4,
+        color,
+        font,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+    });
+}
+
+/// Returns the corner radius of the shape of the size.
+fn radius(shape: Shape, size: f32) -> f32 {
+    match shape {
+        Shape::Circle => size / 2.0,
+        Shape::Rounded => size / 5.0,
+    }
+}
+
+/// Returns the bounds grown by the amount on every side.
+fn expand(bounds: Rectangle, amount: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x - amount,
+        y: bounds.y - amount,
+        width: bounds.width + 2.0 * amount,
+        height: bounds.height + 2.0 * amount,
+    }
+}
+
+/// Returns the bounds of the presence dot of an avatar of the shape, sitting
+/// on its outline at the bottom right.
+fn presence_bounds(shape: Shape, bounds: Rectangle) -> Rectangle {
+    let size = bounds.width * PRESENCE_SIZE;
+    let radius = radius(shape, bounds.width);
+    // The center of the corner arc moved along the diagonal onto the outline
+    let offset = radius - radius * std::f32::consts::FRAC_1_SQRT_2;
+
+    Rectangle {
+        x: bounds.x + bounds.width - offset - size / 2.0,
+        y: bounds.y + bounds.height - offset - size / 2.0,
+        width: size,
+        height: size,
+    }
+}
+
+/// Returns the bounds of an image of the size scaled to cover the bounds
+/// while keeping its aspect ratio, centered on the bounds.
+fn cover(bounds: Rectangle, image: Size) -> Rectangle {
+    if image.width <= 0.0 || image.height <= 0.0 {
+        return bounds;
+    }
+
+    let scale = (bounds.width / image.width).max(bounds.height / image.height);
+    let width = image.width * scale;
+    let height = image.height * scale;
+
+    Rectangle {
+        x: bounds.center_x() - width / 2.0,
+        y: bounds.center_y() - height / 2.0,
+        width,
+        height,
+    }
+}
+
+/// Returns the initials of the first and the last word of the name.
+fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    let first = words.next();
+    let last = words.last();
+
+    first
+        .into_iter()
+        .chain(last)
+        .filter_map(|word| word.chars().next())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Returns the color the initials of the name are drawn on.
+///
+/// The same name always gets the same color.
+fn color_of(name: &str) -> Color {
+    let hash = name.bytes().fold(0_u32, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(u32::from(byte))
+    });
+
+    COLORS[hash as usize % COLORS.len()].into()
+}
+
+/// Returns the width of a group of the number of avatars of the size, each
+/// covering the part of the previous one.
+fn group_width(count: usize, size: f32, overlap: f32) -> f32 {
+    match count {
+        0 => 0.0,
+        count => size + (count - 1) as f32 * size * (1.0 - overlap),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::{Rectangle, Size};
+
+    use super::{color_of, cover, group_width, initials};
+
+    #[test]
+    fn initials_of_first_and_last_word() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("grace"), "G");
+        assert_eq!(initials("  Jean Luc   Picard "), "JP");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn color_depends_on_the_name() {
+        assert_eq!(color_of("Ada Lovelace"), color_of("Ada Lovelace"));
+    }
+
+    #[test]
+    fn cover_keeps_aspect_ratio() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 40.0,
+            height: 40.0,
+        };
+
+        let image = cover(bounds, Size::new(200.0, 100.0));
+
+        assert_eq!(image.height, 40.0);
+        assert_eq!(image.width, 80.0);
+        assert_eq!(image.x, -20.0);
+        assert_eq!(image.y, 0.0);
+    }
+
+    #[test]
+    fn group_width_counts_overlap() {
+        assert_eq!(group_width(0, 40.0, 0.25), 0.0);
+        assert_eq!(group_width(1, 40.0, 0.25), 40.0);
+        assert_eq!(group_width(3, 40.0, 0.25), 100.0);
+    }
+}
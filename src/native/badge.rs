@@ -5,7 +5,16 @@ use iced_native::{
     event, layout, mouse,
     renderer::{self},
     widget::Tree,
-    Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+    Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{
+    core::{
+        icon_source::{self, IconSource},
+        renderer::{fill_gradient, IconRenderer},
+    },
+    style::style_state::{appearance, StyleState},
 };
 
 pub use crate::style::badge::{Appearance, StyleSheet};
@@ -13,6 +22,9 @@ pub use crate::style::badge::{Appearance, StyleSheet};
 /// The ratio of the border radius.
 const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
 
+/// The spacing between the icon and the content.
+const ICON_SPACING: f32 = 4.0;
+
 /// A badge for color highlighting small information.
 ///
 /// # Example
@@ -34,7 +46,7 @@ where
     Renderer::Theme: StyleSheet,
 {
     /// The padding of the [`Badge`].
-    padding: u16,
+    padding: f32,
     /// The width of the [`Badge`].
     width: Length,
     /// The height of the [`Badge`].
@@ -45,6 +57,10 @@ where
     vertical_alignment: Alignment,
     /// The style of the [`Badge`](Badge).
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The optional icon shown in front of the content of the [`Badge`](Badge).
+    icon: Option<IconSource>,
+    /// The size of the icon, defaulting to the text size of the renderer.
+    icon_size: Option<f32>,
     /// The content [`Element`](iced_native::Element) of the [`Badge`](Badge).
     content: Element<'a, Message, Renderer>,
 }
@@ -63,20 +79,22 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Badge {
-            padding: 7,
+            padding: 7.0,
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: Alignment::Center,
             vertical_alignment: Alignment::Center,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            icon: None,
+            icon_size: None,
             content: content.into(),
         }
     }
 
     /// Sets the padding of the [`Badge`](Badge).
     #[must_use]
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
         self
     }
 
@@ -114,12 +132,33 @@ where
         self.style = style;
         self
     }
+
+    /// Sets the icon shown in front of the content of the [`Badge`](Badge),
+    /// either an [`Icon`](crate::graphics::icons::Icon) or an
+    /// [`Svg`](iced_native::svg::Handle).
+    ///
+    /// An [`Svg`](iced_native::svg::Handle) icon is only drawn with the crate
+    /// feature `svg` activated.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<IconSource>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the size of the icon of the [`Badge`](Badge).
+    ///
+    /// Defaults to the text size of the renderer.
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = Some(icon_size);
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Badge<'a, Message, Renderer>
 where
     Message: 'a + Clone,
-    Renderer: 'a + iced_native::Renderer,
+    Renderer: 'a + IconRenderer,
     Renderer::Theme: StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
@@ -146,13 +185,42 @@ where
             .height(self.height)
             .pad(padding);
 
-        let mut content = self.content.as_widget().layout(renderer, &limits.loose());
-        let size = limits.resolve(content.size());
+        // The icon is laid out in front of the content
+        let (icon_size, icon_width) = if self.icon.is_some() {
+            let icon_size = self.icon_size.unwrap_or_else(|| renderer.default_size());
+            (icon_size, icon_size + ICON_SPACING)
+        } else {
+            (0.0, 0.0)
+        };
+
+        let mut content = self
+            .content
+            .as_widget()
+            .layout(renderer, &limits.loose().shrink(Size::new(icon_width, 0.0)));
+        let size = limits.resolve(Size::new(
+            content.size().width + icon_width,
+            content.size().height.max(icon_size),
+        ));
+
+        content.move_to(Point::new(padding.left + icon_width, padding.top));
+        content.align(
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            Size::new(size.width - icon_width, size.height),
+        );
 
-        content.move_to(Point::new(padding.left, padding.top));
-        content.align(self.horizontal_alignment, self.vertical_alignment, size);
+        let mut children = vec![content];
 
-        layout::Node::with_children(size.pad(padding), vec![content])
+        if self.icon.is_some() {
+            let mut icon = layout::Node::new(Size::new(icon_size, icon_size));
+            icon.move_to(Point::new(
+                padding.left,
+                padding.top + (size.height - icon_size) / 2.0,
+            ));
+            children.push(icon);
+        }
+
+        layout::Node::with_children(size.pad(padding), children)
     }
 
     fn on_event(
@@ -209,27 +277,32 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
         let is_mouse_over = bounds.contains(cursor_position);
-        let style_sheet = if is_mouse_over {
-            theme.hovered(self.style)
-        } else {
-            theme.active(self.style)
-        };
+        let style_state = StyleState::new(is_mouse_over, false, false, false);
+        let style_sheet = appearance!(StyleSheet, theme, style_state, self.style);
 
         //println!("height: {}", bounds.height);
         // 34 15
         //  x
         let border_radius = style_sheet
             .border_radius
-            .unwrap_or(bounds.height / BORDER_RADIUS_RATIO);
+            .unwrap_or_else(|| (bounds.height / BORDER_RADIUS_RATIO).into());
+
+        // The gradient is drawn below the quad, so the border stays on top of it
+        let background = if let Some(gradient) = &style_sheet.gradient {
+            fill_gradient(renderer, bounds, border_radius, gradient);
+            Color::TRANSPARENT.into()
+        } else {
+            style_sheet.background
+        };
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
-                border_radius: border_radius.into(),
+                border_radius,
                 border_width: style_sheet.border_width,
                 border_color: style_sheet.border_color.unwrap_or(Color::BLACK),
             },
-            style_sheet.background,
+            background,
         );
 
         self.content.as_widget().draw(
@@ -245,13 +318,17 @@ where
             cursor_position,
             viewport,
         );
+
+        if let (Some(icon), Some(icon_layout)) = (&self.icon, children.next()) {
+            icon_source::draw(renderer, icon, icon_layout.bounds(), style_sheet.text_color);
+        }
     }
 }
 
 impl<'a, Message, Renderer> From<Badge<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Message: Clone + 'a,
-    Renderer: iced_native::Renderer + 'a,
+    Renderer: IconRenderer + 'a,
     Renderer::Theme: StyleSheet,
 {
     fn from(badge: Badge<'a, Message, Renderer>) -> Self {
@@ -0,0 +1,491 @@
+//! Show the path to the current page as a trail of pressable segments.
+//!
+//! *This API requires the following crate features to be activated: breadcrumbs*
+use std::ops::Range;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::Tree,
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::breadcrumbs::{Appearance, StyleSheet};
+
+/// The default separator between two segments.
+const DEFAULT_SEPARATOR: &str = "/";
+
+/// The default padding around the label of a segment.
+const DEFAULT_PADDING: f32 = 4.0;
+
+/// The default spacing between a segment and a separator.
+const DEFAULT_SPACING: f32 = 4.0;
+
+/// The label standing in for the collapsed segments.
+const ELLIPSIS: &str = "\u{2026}";
+
+/// A trail of segments leading to the current page, each sending its message
+/// when pressed.
+///
+/// When the segments do not fit, the ones in the middle collapse into an
+/// ellipsis, while the first and the last segment stay visible.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::breadcrumbs;
+/// #
+/// # pub type Breadcrumbs<'a, Message> = breadcrumbs::Breadcrumbs<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Navigate(usize),
+/// }
+///
+/// let breadcrumbs = Breadcrumbs::new([
+///     ("Home", Message::Navigate(0)),
+///     ("Documents", Message::Navigate(1)),
+///     ("Invoices", Message::Navigate(2)),
+/// ])
+/// .separator("\u{203A}");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Breadcrumbs<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The labels and the messages of the segments.
+    segments: Vec<(String, Message)>,
+    /// The separator between two segments.
+    separator: String,
+    /// The width of the breadcrumbs.
+    width: Length,
+    /// The padding around the label of a segment.
+    padding: f32,
+    /// The spacing between a segment and a separator.
+    spacing: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the breadcrumbs.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// Ties the breadcrumbs to the lifetime of the element.
+    phantom: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a, Message, Renderer> Breadcrumbs<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates new [`Breadcrumbs`](Breadcrumbs) of the segments.
+    ///
+    /// It expects:
+    ///     * the labels of the segments together with the messages sent when
+    ///         they are pressed. The last segment is the current page.
+    pub fn new<S>(segments: impl IntoIterator<Item = (S, Message)>) -> Self
+    where
+        S: Into<String>,
+    {
+        Breadcrumbs {
+            segments: segments
+                .into_iter()
+                .map(|(label, message)| (label.into(), message))
+                .collect(),
+            separator: String::from(DEFAULT_SEPARATOR),
+            width: Length::Shrink,
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds a segment to the end of the [`Breadcrumbs`](Breadcrumbs).
+    #[must_use]
+    pub fn push(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.segments.push((label.into(), message));
+        self
+    }
+
+    /// Sets the separator between two segments.
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the width of the [`Breadcrumbs`](Breadcrumbs).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the label of a segment.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the spacing between a segment and a separator.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Breadcrumbs`](Breadcrumbs).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the gap between two segments, holding a separator.
+    fn gap(&self, renderer: &Renderer, text_size: f32) -> f32 {
+        let (separator_width, _) = renderer.measure(
+            &self.separator,
+            text_size,
+            self.font,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        );
+
+        separator_width + 2.0 * self.spacing
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Breadcrumbs<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = text_size + 2.0 * self.padding;
+        let gap = self.gap(renderer, text_size);
+        let measure = |label: &str| {
+            let (width, _) = renderer.measure(
+                label,
+                text_size,
+                self.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            );
+
+            width + 2.0 * self.padding
+        };
+
+        let widths: Vec<f32> = self
+            .segments
+            .iter()
+            .map(|(label, _)| measure(label))
+            .collect();
+        let ellipsis_width = measure(ELLIPSIS);
+        let hidden = collapse(&widths, ellipsis_width, gap, limits.max().width);
+
+        // The segments come first, followed by the ellipsis. Hidden ones keep
+        // an empty node.
+        let mut nodes = Vec::with_capacity(widths.len() + 1);
+        let mut ellipsis = Node::new(Size::ZERO);
+        let mut x = 0.0;
+
+        for (index, &width) in widths.iter().enumerate() {
+            if hidden.contains(&index) {
+                if index == hidden.start {
+                    ellipsis = Node::new(Size::new(ellipsis_width, height));
+                    ellipsis.move_to(Point::new(x, 0.0));
+                    x += ellipsis_width + gap;
+                }
+
+                nodes.push(Node::new(Size::ZERO));
+                continue;
+            }
+
+            let mut node = Node::new(Size::new(width, height));
+            node.move_to(Point::new(x, 0.0));
+            x += width + gap;
+
+            nodes.push(node);
+        }
+        nodes.push(ellipsis);
+
+        let width = (x - gap).max(0.0);
+
+        Node::with_children(limits.resolve(Size::new(width, height)), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let pressed = self
+            .segments
+            .iter()
+            .zip(layout.children())
+            .find(|(_, segment)| is_pressable(segment.bounds(), cursor_position));
+
+        if let Some(((_, message), _)) = pressed {
+            shell.publish(message.clone());
+            return event::Status::Captured;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let hovered = layout
+            .children()
+            .take(self.segments.len())
+            .any(|segment| is_pressable(segment.bounds(), cursor_position));
+
+        if hovered {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let style_state = StyleState::new(
+            layout.bounds().contains(cursor_position),
+            false,
+            false,
+            false,
+        );
+        let appearance = appearance!(StyleSheet, theme, style_state, self.style);
+        let last = self.segments.len().saturating_sub(1);
+        let labels = self
+            .segments
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .chain(std::iter::once(ELLIPSIS));
+
+        for (index, (label, item)) in labels.zip(layout.children()).enumerate() {
+            let bounds = item.bounds();
+
+            if bounds.width <= 0.0 {
+                continue;
+            }
+
+            let is_ellipsis = index == self.segments.len();
+            let is_hovered = !is_ellipsis && bounds.contains(cursor_position);
+
+            if is_hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.hovered_background,
+                );
+            }
+
+            let color = if is_ellipsis {
+                appearance.separator_color
+            } else if is_hovered {
+                appearance.hovered_text_color
+            } else if index == last {
+                appearance.current_text_color
+            } else {
+                appearance.text_color
+            };
+
+            fill_label(
+                renderer,
+                label,
+                bounds,
+                Horizontal::Center,
+                text_size,
+                self.font,
+                color,
+            );
+
+            if index != last {
+                fill_label(
+                    renderer,
+                    &self.separator,
+                    Rectangle {
+                        x: bounds.x + bounds.width + self.spacing,
+                        ..bounds
+                    },
+                    Horizontal::Left,
+                    text_size,
+                    self.font,
+                    appearance.separator_color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Breadcrumbs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(breadcrumbs: Breadcrumbs<'a, Message, Renderer>) -> Self {
+        Element::new(breadcrumbs)
+    }
+}
+
+/// Returns true if the segment is shown and the cursor is over it.
+fn is_pressable(bounds: Rectangle, cursor_position: Point) -> bool {
+    bounds.width > 0.0 && bounds.contains(cursor_position)
+}
+
+/// Returns the range of the segments collapsing into an ellipsis so that all
+/// segments fit into the available width.
+///
+/// The first and the last segment are always kept. The range is empty if all
+/// segments fit.
+fn collapse(widths: &[f32], ellipsis_width: f32, gap: f32, available: f32) -> Range<usize> {
+    let total = |hidden: &Range<usize>| {
+        let (items, ellipsis) = if hidden.is_empty() {
+            (widths.len(), 0.0)
+        } else {
+            (widths.len() - hidden.len() + 1, ellipsis_width)
+        };
+        let segments: f32 = widths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !hidden.contains(index))
+            .map(|(_, width)| width)
+            .sum();
+
+        #[allow(clippy::cast_precision_loss)]
+        let gaps = gap * items.saturating_sub(1) as f32;
+
+        segments + ellipsis + gaps
+    };
+
+    let mut hidden = 1..1;
+
+    while total(&hidden) > available && hidden.end + 1 < widths.len() {
+        hidden.end += 1;
+    }
+
+    hidden
+}
+
+/// Draws a label vertically centered in the bounds.
+fn fill_label<Renderer>(
+    renderer: &mut Renderer,
+    content: &str,
+    bounds: Rectangle,
+    horizontal_alignment: Horizontal,
+    size: f32,
+    font: Font,
+    color: Color,
+) where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+{
+    let x = match horizontal_alignment {
+        Horizontal::Left => bounds.x,
+        Horizontal::Center => bounds.center_x(),
+        Horizontal::Right => bounds.x + bounds.width,
+    };
+
+    renderer.fill_text(text::Text {
+        content,
+        bounds: Rectangle {
+            x,
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size,
+        color,
+        font,
+        horizontal_alignment,
+        vertical_alignment: Vertical::Center,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collapse;
+
+    #[test]
+    fn keeps_all_segments_that_fit() {
+        assert_eq!(collapse(&[50.0, 50.0, 50.0], 20.0, 10.0, 170.0), 1..1);
+    }
+
+    #[test]
+    fn collapses_the_middle_segments() {
+        let widths = [50.0, 50.0, 50.0, 50.0, 50.0];
+
+        // Hiding one segment still needs 50 * 4 + 20 + 10 * 4 = 260
+        assert_eq!(collapse(&widths, 20.0, 10.0, 250.0), 1..3);
+        assert_eq!(collapse(&widths, 20.0, 10.0, 260.0), 1..2);
+        // The first and the last segment are always kept
+        assert_eq!(collapse(&widths, 20.0, 10.0, 0.0), 1..4);
+        assert_eq!(collapse(&widths[..2], 20.0, 10.0, 0.0), 1..1);
+    }
+}
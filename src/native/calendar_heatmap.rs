@@ -0,0 +1,581 @@
+//! Show values of the days of a year as colored cells.
+//!
+//! *This API requires the following crate features to be activated: calendar_heatmap*
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::tree::{self, Tree},
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::{animation::Interpolate, date::WEEKDAY_LABELS},
+    native::overlay::cell_tooltip::{Bubble, CellTooltipOverlay},
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::core::date::Date;
+
+pub use crate::style::calendar_heatmap::{Appearance, StyleSheet};
+
+/// The default size of a day.
+const DEFAULT_CELL_SIZE: f32 = 11.0;
+
+/// The default space between the days.
+const DEFAULT_SPACING: f32 = 3.0;
+
+/// The default text size of the labels and the tooltip.
+const DEFAULT_TEXT_SIZE: f32 = 12.0;
+
+/// The number of buckets the values are split into by default.
+const DEFAULT_BUCKETS: usize = 4;
+
+/// A heatmap showing a value for every day of a year, like the contributions
+/// of a user to a repository.
+///
+/// The days are small cells arranged in a column per week, colored by the
+/// bucket their value falls into. Hovering a day shows its date and value in
+/// a tooltip.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::calendar_heatmap;
+/// #
+/// # pub type CalendarHeatmap<'a, Message> = calendar_heatmap::CalendarHeatmap<'a, Message, Null>;
+/// use iced_aw::native::calendar_heatmap::Date;
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Day(Date),
+/// }
+///
+/// let commits = vec![
+///     (Date::from_ymd(2023, 1, 2), 3.0),
+///     (Date::from_ymd(2023, 1, 3), 12.0),
+/// ];
+///
+/// let heatmap = CalendarHeatmap::new(2023, commits)
+///     .tooltip(|date, value| format!("{} commits on {date}", value.unwrap_or(0.0)))
+///     .on_click(Message::Day);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CalendarHeatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The year shown.
+    year: i32,
+    /// The values of the days.
+    values: BTreeMap<NaiveDate, f32>,
+    /// The lowest values of the buckets above the empty one.
+    thresholds: Option<Vec<f32>>,
+    /// The function producing the message when a day is clicked.
+    on_click: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    /// The function producing the text of the tooltip of a day.
+    tooltip: Option<Box<dyn Fn(Date, Option<f32>) -> String + 'a>>,
+    /// The size of a day.
+    cell_size: f32,
+    /// The space between the days.
+    spacing: f32,
+    /// The text size of the labels and the tooltip.
+    text_size: f32,
+    /// The font of the labels and the tooltip.
+    font: Font,
+    /// The style of the calendar heatmap.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> CalendarHeatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`CalendarHeatmap`](CalendarHeatmap) showing the values
+    /// of the days of the year.
+    ///
+    /// The values of days outside of the year are ignored.
+    pub fn new(year: i32, values: impl IntoIterator<Item = (Date, f32)>) -> Self {
+        Self {
+            year,
+            values: values
+                .into_iter()
+                .map(|(date, value)| (NaiveDate::from(date), value))
+                .filter(|(date, _)| date.year() == year)
+                .collect(),
+            thresholds: None,
+            on_click: None,
+            tooltip: None,
+            cell_size: DEFAULT_CELL_SIZE,
+            spacing: DEFAULT_SPACING,
+            text_size: DEFAULT_TEXT_SIZE,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the lowest values of the buckets above the empty one, in
+    /// ascending order.
+    ///
+    /// By default the values above zero are split into four buckets of the
+    /// same range up to the highest value.
+    #[must_use]
+    pub fn thresholds(mut self, thresholds: Vec<f32>) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+
+    /// Sets the function producing the message when a day is clicked.
+    #[must_use]
+    pub fn on_click<F>(mut self, on_click: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Sets the function producing the text of the tooltip of a day from its
+    /// date and value.
+    ///
+    /// By default the tooltip shows the value, or zero, and the date.
+    #[must_use]
+    pub fn tooltip<F>(mut self, tooltip: F) -> Self
+    where
+        F: 'a + Fn(Date, Option<f32>) -> String,
+    {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the size of a day.
+    #[must_use]
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the space between the days.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels and the tooltip.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels and the tooltip.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`CalendarHeatmap`](CalendarHeatmap).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the first day of the year.
+    fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, 1, 1).expect("Year, Month or Day doesnt Exist")
+    }
+
+    /// Returns the last day of the year.
+    fn last_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, 12, 31).expect("Year, Month or Day doesnt Exist")
+    }
+
+    /// Returns the monday of the first week of the year.
+    fn first_monday(&self) -> NaiveDate {
+        let first_day = self.first_day();
+
+        first_day - Duration::days(i64::from(first_day.weekday().num_days_from_monday()))
+    }
+
+    /// Returns the number of weeks shown.
+    fn weeks(&self) -> usize {
+        ((self.last_day() - self.first_monday()).num_days() / 7 + 1) as usize
+    }
+
+    /// Returns the width of the names of the weekdays left of the days.
+    fn label_width(&self, renderer: &Renderer) -> f32 {
+        WEEKDAY_LABELS
+            .iter()
+            .map(|label| renderer.measure_width(label, self.text_size, self.font))
+            .fold(0.0, f32::max)
+            + 2.0 * self.spacing
+    }
+
+    /// Returns the position of the first monday in the bounds of the heatmap,
+    /// below the names of the months and right of the names of the weekdays.
+    fn origin(&self, renderer: &Renderer, bounds: Rectangle) -> Point {
+        Point::new(
+            bounds.x + self.label_width(renderer),
+            bounds.y + self.text_size + 2.0 * self.spacing,
+        )
+    }
+
+    /// Returns the bounds of the day relative to the
+    /// [`origin`](Self::origin).
+    fn cell_bounds(&self, origin: Point, date: NaiveDate) -> Rectangle {
+        let days = (date - self.first_monday()).num_days();
+        let (column, row) = ((days / 7) as f32, (days % 7) as f32);
+        let step = self.cell_size + self.spacing;
+
+        Rectangle {
+            x: origin.x + column * step,
+            y: origin.y + row * step,
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    /// Returns the day at the cursor, if any.
+    fn date_at(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<NaiveDate> {
+        let origin = self.origin(renderer, bounds);
+        let step = self.cell_size + self.spacing;
+        let (x, y) = (cursor_position.x - origin.x, cursor_position.y - origin.y);
+
+        // The gaps between the days belong to no day
+        if x < 0.0 || y < 0.0 || x % step > self.cell_size || y % step > self.cell_size {
+            return None;
+        }
+
+        let (column, row) = ((x / step) as i64, (y / step) as i64);
+        let date = self.first_monday() + Duration::days(column * 7 + row);
+
+        (row < 7 && date.year() == self.year).then_some(date)
+    }
+
+    /// Returns the thresholds of the buckets.
+    fn thresholds(&self) -> Vec<f32> {
+        self.thresholds.clone().unwrap_or_else(|| {
+            default_thresholds(self.values.values().copied().fold(0.0, f32::max))
+        })
+    }
+
+    /// Returns the color of the day with the value.
+    fn color(appearance: &Appearance, thresholds: &[f32], value: Option<f32>) -> Color {
+        match value.map_or(0, |value| bucket(value, thresholds)) {
+            0 => appearance.empty_color,
+            bucket => {
+                let t = if thresholds.len() > 1 {
+                    (bucket - 1) as f32 / (thresholds.len() - 1) as f32
+                } else {
+                    1.0
+                };
+
+                appearance.low_color.interpolate(appearance.high_color, t)
+            }
+        }
+    }
+
+    /// Returns the text of the tooltip of the day.
+    fn tooltip_text(&self, date: NaiveDate) -> String {
+        let value = self.values.get(&date).copied();
+
+        match &self.tooltip {
+            Some(tooltip) => tooltip(date.into(), value),
+            None => format!("{} on {}", value.unwrap_or(0.0), Date::from(date)),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for CalendarHeatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let step = self.cell_size + self.spacing;
+
+        Node::new(limits.resolve(Size::new(
+            self.label_width(renderer) + self.weeks() as f32 * step - self.spacing,
+            self.text_size + 2.0 * self.spacing + 7.0 * step - self.spacing,
+        )))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                state.hovered = self.date_at(renderer, bounds, cursor_position);
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(on_click) = &self.on_click else {
+                    return event::Status::Ignored;
+                };
+
+                match self.date_at(renderer, bounds, cursor_position) {
+                    Some(date) => {
+                        shell.publish(on_click(date.into()));
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_click.is_some()
+            && self
+                .date_at(renderer, layout.bounds(), cursor_position)
+                .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let origin = self.origin(renderer, bounds);
+        let label_width = self.label_width(renderer);
+
+        // Every other weekday is named to keep the labels readable
+        for row in [0, 2, 4] {
+            let cell = self.cell_bounds(origin, self.first_monday() + Duration::days(row));
+
+            renderer.fill_text(text::Text {
+                content: &WEEKDAY_LABELS[row as usize],
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: cell.center_y(),
+                    width: label_width,
+                    height: cell.height,
+                },
+                size: self.text_size,
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        // The name of a month is shown above its first full week, unless it
+        // would overlap the name of the previous month
+        let mut free_x = bounds.x;
+        for month in 1..=12 {
+            let first = NaiveDate::from_ymd_opt(self.year, month, 1)
+                .expect("Year, Month or Day doesnt Exist");
+            let monday =
+                first + Duration::days(i64::from((7 - first.weekday().num_days_from_monday()) % 7));
+            let x = self.cell_bounds(origin, monday).x;
+            let label = first.format("%b").to_string();
+            let width = renderer.measure_width(&label, self.text_size, self.font);
+
+            if x < free_x || x + width > bounds.x + bounds.width {
+                continue;
+            }
+            free_x = x + width + self.spacing;
+
+            renderer.fill_text(text::Text {
+                content: &label,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.y,
+                    width,
+                    height: self.text_size,
+                },
+                size: self.text_size,
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+            });
+        }
+
+        let thresholds = self.thresholds();
+        let days = std::iter::successors(Some(self.first_day()), NaiveDate::succ_opt);
+        for date in days.take_while(|date| *date <= self.last_day()) {
+            let is_hovered = state.hovered == Some(date);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.cell_bounds(origin, date),
+                    border_radius: appearance.cell_border_radius.into(),
+                    border_width: if is_hovered { 1.0 } else { 0.0 },
+                    border_color: if is_hovered {
+                        appearance.hovered_border_color
+                    } else {
+                        Color::TRANSPARENT
+                    },
+                },
+                Self::color(&appearance, &thresholds, self.values.get(&date).copied()),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let date = tree.state.downcast_ref::<State>().hovered?;
+        let style = self.style;
+
+        Some(
+            CellTooltipOverlay::new(
+                self.tooltip_text(date),
+                self.cell_bounds(self.origin(renderer, layout.bounds()), date),
+                self.text_size,
+                self.font,
+                move |theme, state| {
+                    let appearance = appearance!(StyleSheet, theme, state, style);
+
+                    Bubble {
+                        background: appearance.tooltip_background,
+                        border_radius: appearance.tooltip_border_radius,
+                        text_color: appearance.tooltip_text_color,
+                    }
+                },
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<CalendarHeatmap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(calendar_heatmap: CalendarHeatmap<'a, Message, Renderer>) -> Self {
+        Element::new(calendar_heatmap)
+    }
+}
+
+/// The state of a [`CalendarHeatmap`](CalendarHeatmap).
+#[derive(Debug, Default)]
+struct State {
+    /// The day at the cursor.
+    hovered: Option<NaiveDate>,
+}
+
+/// Returns the bucket of the value, the number of thresholds it reaches.
+///
+/// The bucket 0 holds the values below the first threshold.
+fn bucket(value: f32, thresholds: &[f32]) -> usize {
+    thresholds
+        .iter()
+        .filter(|&&threshold| value >= threshold)
+        .count()
+}
+
+/// Returns the thresholds splitting the values above zero into buckets of the
+/// same range up to the highest value.
+fn default_thresholds(max: f32) -> Vec<f32> {
+    (0..DEFAULT_BUCKETS)
+        .map(|bucket| {
+            if bucket == 0 {
+                f32::MIN_POSITIVE
+            } else {
+                max * bucket as f32 / DEFAULT_BUCKETS as f32
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucket, default_thresholds};
+
+    #[test]
+    fn bucket_counts_reached_thresholds() {
+        let thresholds = [1.0, 5.0, 10.0];
+
+        assert_eq!(bucket(0.0, &thresholds), 0);
+        assert_eq!(bucket(1.0, &thresholds), 1);
+        assert_eq!(bucket(7.0, &thresholds), 2);
+        assert_eq!(bucket(42.0, &thresholds), 3);
+    }
+
+    #[test]
+    fn default_thresholds_split_range_evenly() {
+        let thresholds = default_thresholds(8.0);
+
+        assert_eq!(bucket(0.0, &thresholds), 0);
+        assert_eq!(bucket(0.5, &thresholds), 1);
+        assert_eq!(bucket(2.0, &thresholds), 2);
+        assert_eq!(bucket(5.0, &thresholds), 3);
+        assert_eq!(bucket(8.0, &thresholds), 4);
+    }
+}
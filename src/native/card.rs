@@ -2,23 +2,37 @@
 //!
 //! *This API requires the following crate features to be activated: card*
 use iced_native::{
-    alignment::{Horizontal, Vertical},
     event, mouse,
     renderer::{self, BorderRadius},
     touch,
-    widget::{Operation, Tree},
+    widget::{tree, Operation, Tree},
     Alignment, Clipboard, Color, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell,
     Size, Widget,
 };
 
-use crate::graphics::icons::Icon;
 pub use crate::style::card::{Appearance, StyleSheet};
+use crate::{
+    core::{
+        event::{bubble, capture},
+        renderer::{fill_gradient, GlyphRenderer},
+        sizing::hit_target,
+    },
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{Node, Role};
 
 /// The default padding of a [`Card`](Card).
 const DEFAULT_PADDING: f32 = 10.0;
 
 /// A card consisting of a head, body and optional foot.
 ///
+/// The renderer only needs to draw text for the close icon, so renderers
+/// without text support can be used by implementing
+/// [`GlyphRenderer`](crate::core::renderer::GlyphRenderer).
+///
 /// # Example
 /// ```
 /// # use iced_native::renderer::Null;
@@ -61,8 +75,12 @@ where
     padding_foot: f32,
     /// The optional size of the close icon of the [`Card`](Card).
     close_size: Option<f32>,
+    /// The close icon of the [`Card`](Card).
+    close_icon: Icon,
     /// The optional message that is send if the close icon of the [`Card`](Card) is pressed.
     on_close: Option<Message>,
+    /// The optional message that is send if the [`Card`](Card) itself is pressed.
+    on_press: Option<Message>,
     /// The head [`Element`](iced_native::Element) of the [`Card`](Card).
     head: Element<'a, Message, Renderer>,
     /// The body [`Element`](iced_native::Element) of the [`Card`](Card).
@@ -99,7 +117,9 @@ where
             padding_body: DEFAULT_PADDING,
             padding_foot: DEFAULT_PADDING,
             close_size: None,
+            close_icon: Icon::X,
             on_close: None,
+            on_press: None,
             head: head.into(),
             body: body.into(),
             foot: None,
@@ -186,6 +206,13 @@ where
         self
     }
 
+    /// Sets the [`Icon`](Icon) drawn as the close icon of the [`Card`](Card).
+    #[must_use]
+    pub fn close_icon(mut self, icon: Icon) -> Self {
+        self.close_icon = icon;
+        self
+    }
+
     /// Sets the message that will be produced when the close icon of the
     /// [`Card`](Card) is pressed.
     ///
@@ -196,20 +223,54 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Card`](Card) is
+    /// pressed.
+    ///
+    /// The message is only produced if no interactive element inside the
+    /// [`Card`](Card) handled the press, so pressing a button in the body
+    /// doesn't activate the [`Card`](Card) as well.
+    #[must_use]
+    pub fn on_press(mut self, msg: Message) -> Self {
+        self.on_press = Some(msg);
+        self
+    }
+
     /// Sets the style of the [`Card`](Card).
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
         self.style = style;
         self
     }
+
+    /// Describes the [`Card`](Card) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, _state: &Tree) -> Node {
+        let node = Node::new(Role::Group);
+
+        if self.on_close.is_some() {
+            node.push(Node::new(Role::Button).name("Close"))
+        } else {
+            node
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Card<'a, Message, Renderer>
 where
     Message: 'a + Clone,
-    Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: 'a + GlyphRenderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.foot.as_ref().map_or_else(
             || vec![Tree::new(&self.head), Tree::new(&self.body)],
@@ -294,81 +355,100 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+        ) {
+            state.state.downcast_mut::<State>().is_pressed = false;
+        }
+
         let mut children = layout.children();
 
         let head_layout = children
             .next()
             .expect("Native: Layout should have a head layout");
         let mut head_children = head_layout.children();
-        let head_status = self.head.as_widget_mut().on_event(
-            &mut state.children[0],
-            event.clone(),
-            head_children
-                .next()
-                .expect("Native: Layout should have a head content layout"),
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        );
+        let head_content_layout = head_children
+            .next()
+            .expect("Native: Layout should have a head content layout");
 
+        // The close icon lies on top of the head, so it gets the press first
         let close_status = head_children
             .next()
-            .map_or(event::Status::Ignored, |close_layout| {
-                match event {
-                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                    | Event::Touch(touch::Event::FingerPressed { .. }) => self
-                        .on_close
-                        .clone()
-                        // TODO: `let` expressions in this position are experimental
-                        // see issue #53667 <https://github.com/rust-lang/rust/issues/53667> for more information
-                        .filter(|_| close_layout.bounds().contains(cursor_position))
-                        .map_or(event::Status::Ignored, |on_close| {
-                            shell.publish(on_close);
-                            event::Status::Captured
-                        }),
-                    _ => event::Status::Ignored,
-                }
+            .filter(|close_layout| {
+                is_press && hit_target(close_layout.bounds()).contains(cursor_position)
+            })
+            .and_then(|_| self.on_close.clone())
+            .map_or(event::Status::Ignored, |on_close| {
+                shell.publish(on_close);
+                event::Status::Captured
             });
 
-        let body_layout = children
-            .next()
-            .expect("Native: Layout should have a body layout");
-        let mut body_children = body_layout.children();
-        let body_status = self.body.as_widget_mut().on_event(
-            &mut state.children[1],
-            event.clone(),
-            body_children
-                .next()
-                .expect("Native: Layout should have a body content layout"),
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        );
+        capture(close_status, || {
+            let head_status = self.head.as_widget_mut().on_event(
+                &mut state.children[0],
+                event.clone(),
+                head_content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
 
-        let foot_layout = children
-            .next()
-            .expect("Native: Layout should have a foot layout");
-        let mut foot_children = foot_layout.children();
-        let foot_status = self.foot.as_mut().map_or(event::Status::Ignored, |foot| {
-            foot.as_widget_mut().on_event(
-                &mut state.children[2],
-                event,
-                foot_children
+            let body_layout = children
+                .next()
+                .expect("Native: Layout should have a body layout");
+            let mut body_children = body_layout.children();
+            let body_status = self.body.as_widget_mut().on_event(
+                &mut state.children[1],
+                event.clone(),
+                body_children
                     .next()
-                    .expect("Native: Layout should have a foot content layout"),
+                    .expect("Native: Layout should have a body content layout"),
                 cursor_position,
                 renderer,
                 clipboard,
                 shell,
-            )
-        });
+            );
+
+            let foot_layout = children
+                .next()
+                .expect("Native: Layout should have a foot layout");
+            let mut foot_children = foot_layout.children();
+            let foot_status = self.foot.as_mut().map_or(event::Status::Ignored, |foot| {
+                foot.as_widget_mut().on_event(
+                    &mut state.children[2],
+                    event,
+                    foot_children
+                        .next()
+                        .expect("Native: Layout should have a foot content layout"),
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            });
 
-        head_status
-            .merge(close_status)
-            .merge(body_status)
-            .merge(foot_status)
+            // The card only reacts to presses none of its contents handled
+            bubble(
+                head_status.merge(body_status).merge(foot_status),
+                || match self.on_press.clone() {
+                    Some(on_press) if is_press && layout.bounds().contains(cursor_position) => {
+                        shell.publish(on_press);
+                        state.state.downcast_mut::<State>().is_pressed = true;
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                },
+            )
+        })
     }
 
     fn mouse_interaction(
@@ -486,13 +566,20 @@ where
     ) {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let style_sheet = theme.active(self.style);
+        let is_hovered = bounds.contains(cursor_position);
+        let is_pressed = is_hovered && state.state.downcast_ref::<State>().is_pressed;
+        let style_sheet = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(is_hovered, is_pressed, false, false),
+            self.style
+        );
 
         // Background
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
-                border_radius: style_sheet.border_radius.into(),
+                border_radius: style_sheet.border_radius,
                 border_width: style_sheet.border_width,
                 border_color: style_sheet.border_color,
             },
@@ -504,7 +591,7 @@ where
             // TODO: fill not necessary
             renderer::Quad {
                 bounds,
-                border_radius: style_sheet.border_radius.into(),
+                border_radius: style_sheet.border_radius,
                 border_width: style_sheet.border_width,
                 border_color: style_sheet.border_color,
             },
@@ -523,7 +610,8 @@ where
             cursor_position,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
+            self.close_icon,
         );
 
         // ----------- Body ----------------------
@@ -538,7 +626,7 @@ where
             cursor_position,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
         );
 
         // ----------- Foot ----------------------
@@ -553,11 +641,18 @@ where
             cursor_position,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
         );
     }
 }
 
+/// The state of a [`Card`](Card).
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the [`Card`](Card) itself is pressed.
+    is_pressed: bool,
+}
+
 /// Calculates the layout of the head.
 fn head_node<Message, Renderer>(
     renderer: &Renderer,
@@ -569,16 +664,16 @@ fn head_node<Message, Renderer>(
     close_size: Option<f32>,
 ) -> iced_native::layout::Node
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: GlyphRenderer,
 {
-    let pad = Padding::from(padding as u16);
+    let pad = Padding::from(padding);
     let mut limits = limits
         .loose()
         .width(width)
         .height(head.as_widget().height())
         .pad(pad);
 
-    let close_size = close_size.unwrap_or_else(|| renderer.default_size());
+    let close_size = close_size.unwrap_or_else(|| renderer.default_glyph_size());
     let mut close = if on_close {
         limits = limits.shrink(Size::new(close_size, 0.0));
         Some(iced_native::layout::Node::new(Size::new(
@@ -621,7 +716,7 @@ fn body_node<Message, Renderer>(
 where
     Renderer: iced_native::Renderer,
 {
-    let pad = Padding::from(padding as u16);
+    let pad = Padding::from(padding);
     let limits = limits
         .clone()
         .loose()
@@ -649,7 +744,7 @@ fn foot_node<Message, Renderer>(
 where
     Renderer: iced_native::Renderer,
 {
-    let pad = Padding::from(padding as u16);
+    let pad = Padding::from(padding);
     let limits = limits
         .clone()
         .loose()
@@ -676,42 +771,31 @@ fn draw_head<Message, Renderer>(
     cursor_position: Point,
     viewport: &Rectangle,
     theme: &Renderer::Theme,
-    style: &<Renderer::Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
+    close_icon: Icon,
 ) where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: GlyphRenderer,
     Renderer::Theme: StyleSheet,
 {
     let mut head_children = layout.children();
-    let style_sheet = theme.active(*style);
     let bounds = layout.bounds();
-    let border_radius = style_sheet.border_radius;
-
-    // Head background
-    renderer.fill_quad(
-        renderer::Quad {
-            bounds,
-            border_radius: BorderRadius::from(border_radius),
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-        },
-        style_sheet.head_background,
-    );
+    let [top_left, top_right, _, _] = <[f32; 4]>::from(style_sheet.border_radius);
 
-    // cover rounded button of header
-    renderer.fill_quad(
-        renderer::Quad {
-            bounds: Rectangle {
-                x: bounds.x,
-                y: bounds.y + bounds.height - border_radius,
-                width: bounds.width,
-                height: border_radius,
+    // Head background, only rounded at the top
+    let border_radius = BorderRadius::from([top_left, top_right, 0.0, 0.0]);
+    if let Some(gradient) = &style_sheet.head_gradient {
+        fill_gradient(renderer, bounds, border_radius, gradient);
+    } else {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
             },
-            border_radius: (0.0).into(),
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-        },
-        style_sheet.head_background,
-    );
+            style_sheet.head_background,
+        );
+    }
 
     head.as_widget().draw(
         state,
@@ -727,25 +811,17 @@ fn draw_head<Message, Renderer>(
         viewport,
     );
 
-    let mut buffer = [0; 4];
-
     if let Some(close_layout) = head_children.next() {
         let close_bounds = close_layout.bounds();
-        let is_mouse_over_close = close_bounds.contains(cursor_position);
-
-        renderer.fill_text(iced_native::text::Text {
-            content: char::from(Icon::X).encode_utf8(&mut buffer),
-            bounds: Rectangle {
-                x: close_bounds.center_x(),
-                y: close_bounds.center_y(),
-                ..close_bounds
-            },
-            size: close_layout.bounds().height + if is_mouse_over_close { 5.0 } else { 0.0 },
-            color: style_sheet.close_color,
-            font: crate::graphics::icons::ICON_FONT,
-            horizontal_alignment: Horizontal::Center,
-            vertical_alignment: Vertical::Center,
-        });
+        let is_mouse_over_close = hit_target(close_bounds).contains(cursor_position);
+
+        renderer.fill_glyph(
+            char::from(close_icon),
+            close_icon.font(),
+            close_bounds,
+            close_bounds.height + if is_mouse_over_close { 5.0 } else { 0.0 },
+            style_sheet.close_color,
+        );
     }
 }
 
@@ -759,13 +835,12 @@ fn draw_body<Message, Renderer>(
     cursor_position: Point,
     viewport: &Rectangle,
     theme: &Renderer::Theme,
-    style: &<Renderer::Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
 ) where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: iced_native::Renderer,
     Renderer::Theme: StyleSheet,
 {
     let mut body_children = layout.children();
-    let style_sheet = theme.active(*style);
 
     // Body background
     renderer.fill_quad(
@@ -803,19 +878,19 @@ fn draw_foot<Message, Renderer>(
     cursor_position: Point,
     viewport: &Rectangle,
     theme: &Renderer::Theme,
-    style: &<Renderer::Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
 ) where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: iced_native::Renderer,
     Renderer::Theme: StyleSheet,
 {
     let mut foot_children = layout.children();
-    let style_sheet = theme.active(*style);
+    let [_, _, bottom_right, bottom_left] = <[f32; 4]>::from(style_sheet.border_radius);
 
-    // Foot background
+    // Foot background, only rounded at the bottom
     renderer.fill_quad(
         renderer::Quad {
             bounds: layout.bounds(),
-            border_radius: style_sheet.border_radius.into(),
+            border_radius: BorderRadius::from([0.0, 0.0, bottom_right, bottom_left]),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
         },
@@ -841,7 +916,7 @@ fn draw_foot<Message, Renderer>(
 
 impl<'a, Message, Renderer> From<Card<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
-    Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: 'a + GlyphRenderer,
     Renderer::Theme: StyleSheet,
     Message: Clone + 'a,
 {
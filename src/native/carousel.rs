@@ -0,0 +1,803 @@
+//! Page through elements one at a time.
+//!
+//! *This API requires the following crate features to be activated: carousel*
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    time::{Duration, Instant},
+    touch,
+    widget::{
+        tree::{self, Tree},
+        Operation,
+    },
+    window, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use crate::{
+    core::{
+        animation::{Animation, Easing},
+        renderer::GlyphRenderer,
+        sizing::hit_target,
+    },
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::carousel::{Appearance, StyleSheet};
+
+/// The size of the previous and next arrows.
+const ARROW_SIZE: f32 = 32.0;
+
+/// The gap between the arrows and the sides of the carousel.
+const ARROW_MARGIN: f32 = 8.0;
+
+/// The size of the dot indicators.
+const DOT_SIZE: f32 = 8.0;
+
+/// The space between the dot indicators.
+const DOT_SPACING: f32 = 8.0;
+
+/// The gap between the dot indicators and the bottom of the carousel.
+const DOT_MARGIN: f32 = 12.0;
+
+/// The part of the width a swipe has to cover to page to another element.
+const SWIPE_THRESHOLD: f32 = 0.2;
+
+/// The time it takes to slide to another element.
+const TRANSITION: Duration = Duration::from_millis(300);
+
+/// A carousel showing one of its elements at a time.
+///
+/// The user pages through the elements by swiping or dragging them, with the
+/// previous and next arrows or with the dot indicators. The elements slide
+/// into view when the shown element changes. With autoplay the carousel
+/// pages to the next element after an interval, pausing while it is hovered.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::carousel;
+/// #
+/// # pub type Carousel<'a, Message> = carousel::Carousel<'a, Message, Null>;
+/// use std::time::Duration;
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Shown(usize),
+/// }
+///
+/// let carousel = Carousel::new(
+///     vec![Text::new("First").into(), Text::new("Second").into()],
+///     0,
+///     Message::Shown,
+/// )
+/// .autoplay(Duration::from_secs(5));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Carousel<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The elements to page through.
+    items: Vec<Element<'a, Message, Renderer>>,
+    /// The index of the shown element.
+    current: usize,
+    /// The function producing the message showing the element at an index.
+    on_change: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The width of the carousel.
+    width: Length,
+    /// The height of the carousel.
+    height: Length,
+    /// The interval after which the next element is shown, if any.
+    autoplay: Option<Duration>,
+    /// Whether paging past the last element shows the first one and the
+    /// other way around.
+    wrap_around: bool,
+    /// Whether the previous and next arrows are shown.
+    arrows: bool,
+    /// Whether the dot indicators are shown.
+    dots: bool,
+    /// The style of the carousel.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Carousel<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Carousel`](Carousel) with the elements.
+    ///
+    /// It expects:
+    ///     * the elements to page through
+    ///     * the index of the shown element
+    ///     * the function producing the message showing the element at an
+    ///       index
+    pub fn new<F>(items: Vec<Element<'a, Message, Renderer>>, current: usize, on_change: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Carousel {
+            items,
+            current,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Length::Shrink,
+            autoplay: None,
+            wrap_around: false,
+            arrows: true,
+            dots: true,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Carousel`](Carousel).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Carousel`](Carousel).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Shows the next element after the interval, starting over after the
+    /// last one.
+    ///
+    /// The interval restarts while the [`Carousel`](Carousel) is hovered or
+    /// dragged.
+    #[must_use]
+    pub fn autoplay(mut self, interval: Duration) -> Self {
+        self.autoplay = Some(interval);
+        self
+    }
+
+    /// Sets whether paging past the last element shows the first one and the
+    /// other way around.
+    #[must_use]
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets whether the previous and next arrows are shown.
+    #[must_use]
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Sets whether the dot indicators are shown.
+    #[must_use]
+    pub fn dots(mut self, dots: bool) -> Self {
+        self.dots = dots;
+        self
+    }
+
+    /// Sets the style of the [`Carousel`](Carousel).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Adds an element to the [`Carousel`](Carousel).
+    #[must_use]
+    pub fn push<E>(mut self, item: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Returns the index of the shown element, if there is any.
+    fn current(&self) -> Option<usize> {
+        self.items
+            .len()
+            .checked_sub(1)
+            .map(|last| self.current.min(last))
+    }
+
+    /// Returns the element the arrow in the direction at the cursor pages to,
+    /// if any.
+    fn arrow_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        let current = self.current()?;
+
+        [false, true].into_iter().find_map(|forward| {
+            step(current, self.items.len(), forward, self.wrap_around)
+                .filter(|_| self.arrows && arrow_bounds(bounds, forward).contains(cursor_position))
+        })
+    }
+
+    /// Returns the index of the dot indicator at the cursor, if any.
+    fn dot_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        let count = self.items.len();
+        if !self.dots || count < 2 {
+            return None;
+        }
+
+        let first = dot_bounds(bounds, count, 0).center_x();
+        let index = ((cursor_position.x - first) / (DOT_SIZE + DOT_SPACING))
+            .round()
+            .clamp(0.0, (count - 1) as f32) as usize;
+
+        hit_target(dot_bounds(bounds, count, index))
+            .contains(cursor_position)
+            .then_some(index)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Carousel<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.current().unwrap_or_default()))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.items);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let nodes: Vec<Node> = self
+            .items
+            .iter()
+            .map(|item| item.as_widget().layout(renderer, &limits))
+            .collect();
+
+        let intrinsic = nodes.iter().fold(Size::ZERO, |intrinsic, node| {
+            Size::new(
+                intrinsic.width.max(node.size().width),
+                intrinsic.height.max(node.size().height),
+            )
+        });
+
+        Node::with_children(limits.resolve(intrinsic), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(current) = self.current() else {
+            return event::Status::Ignored;
+        };
+
+        let bounds = layout.bounds();
+        let count = self.items.len();
+        let state = tree.state.downcast_mut::<State>();
+        state.hovered = bounds.contains(cursor_position);
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = Some(now);
+            state.show(current, count, self.wrap_around, now);
+            state.animation.request_redraw(now, shell);
+
+            if let Some(interval) = self.autoplay {
+                // Hovering, dragging and sliding hold the interval at its start
+                if state.hovered || state.drag.is_some() || state.animation.is_running(now) {
+                    state.since = Some(now);
+                }
+
+                let due = *state.since.get_or_insert(now) + interval;
+                if now < due {
+                    shell.request_redraw(window::RedrawRequest::At(due));
+                } else {
+                    if let Some(next) = step(current, count, true, true) {
+                        shell.publish((self.on_change)(next));
+                    }
+                    state.since = Some(now);
+                    shell.request_redraw(window::RedrawRequest::At(now + interval));
+                }
+            }
+        }
+
+        if let Some(drag) = &mut state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    drag.offset = cursor_position.x - drag.origin;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    let offset = drag.offset;
+                    state.release(offset / bounds.width.max(1.0), Instant::now());
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    if let Some(target) =
+                        swipe(current, count, offset, bounds.width, self.wrap_around)
+                    {
+                        shell.publish((self.on_change)(target));
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let pressed = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if pressed {
+            if let Some(target) = self
+                .arrow_at(bounds, cursor_position)
+                .or_else(|| self.dot_at(bounds, cursor_position))
+            {
+                if target != current {
+                    shell.publish((self.on_change)(target));
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = match layout.children().nth(current) {
+            Some(item_layout) => self.items[current].as_widget_mut().on_event(
+                &mut tree.children[current],
+                event,
+                item_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+            None => event::Status::Ignored,
+        };
+
+        // Pressing what the shown element ignores starts dragging it
+        if pressed && status == event::Status::Ignored && count > 1 && state.hovered {
+            state.drag = Some(Drag {
+                origin: cursor_position.x,
+                offset: 0.0,
+            });
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if self.arrow_at(bounds, cursor_position).is_some()
+            || self.dot_at(bounds, cursor_position).is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.current()
+            .and_then(|current| {
+                layout.children().nth(current).map(|item_layout| {
+                    self.items[current].as_widget().mouse_interaction(
+                        &tree.children[current],
+                        item_layout,
+                        cursor_position,
+                        viewport,
+                        renderer,
+                    )
+                })
+            })
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let Some(current) = self.current() else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        let count = self.items.len();
+        let state = tree.state.downcast_ref::<State>();
+
+        // The shown element is drawn in place until the first redraw
+        let mut position = if state.shown == current {
+            state.position(state.now.unwrap_or_else(Instant::now))
+        } else {
+            current as f32
+        };
+        if let Some(drag) = &state.drag {
+            position -= drag.offset / bounds.width.max(1.0);
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, ((item, item_tree), item_layout)) in self
+                .items
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let offset = offset(index, position, count, self.wrap_around) * bounds.width;
+                if offset.abs() >= bounds.width {
+                    continue;
+                }
+
+                let translation = Vector::new(offset, 0.0);
+                renderer.with_translation(translation, |renderer| {
+                    item.as_widget().draw(
+                        item_tree,
+                        renderer,
+                        theme,
+                        style,
+                        item_layout,
+                        cursor_position - translation,
+                        &bounds,
+                    );
+                });
+            }
+        });
+
+        // The arrows are hovered on their own, the dots show the drag
+        let is_dragging = state.drag.is_some();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(false, is_dragging, false, false),
+            self.style
+        );
+
+        for forward in [false, true] {
+            if !self.arrows || step(current, count, forward, self.wrap_around).is_none() {
+                continue;
+            }
+
+            let arrow = arrow_bounds(bounds, forward);
+            let appearance = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(arrow.contains(cursor_position), is_dragging, false, false),
+                self.style
+            );
+            let icon = if forward {
+                Icon::CaretRightFill
+            } else {
+                Icon::CaretLeftFill
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: arrow,
+                    border_radius: (ARROW_SIZE / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.arrow_background,
+            );
+
+            renderer.fill_glyph(
+                char::from(icon),
+                icon.font(),
+                arrow,
+                ARROW_SIZE / 2.0,
+                appearance.arrow_color,
+            );
+        }
+
+        if self.dots && count > 1 {
+            for index in 0..count {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: dot_bounds(bounds, count, index),
+                        border_radius: (DOT_SIZE / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    if index == current {
+                        appearance.active_dot_color
+                    } else {
+                        appearance.dot_color
+                    },
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let current = self.current()?;
+
+        self.items[current].as_widget_mut().overlay(
+            &mut tree.children[current],
+            layout.children().nth(current)?,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        for ((item, state), layout) in self
+            .items
+            .iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            item.as_widget().operate(state, layout, renderer, operation);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Carousel<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(carousel: Carousel<'a, Message, Renderer>) -> Self {
+        Element::new(carousel)
+    }
+}
+
+/// A drag of the elements of a [`Carousel`](Carousel).
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The horizontal position of the cursor when the drag started.
+    origin: f32,
+    /// The horizontal distance the cursor moved since.
+    offset: f32,
+}
+
+/// The state of a [`Carousel`](Carousel).
+#[derive(Debug)]
+struct State {
+    /// The element the carousel slides to.
+    shown: usize,
+    /// The position, in elements, when the carousel started sliding.
+    from: f32,
+    /// The animation sliding the elements.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+    /// The drag of the elements, if any.
+    drag: Option<Drag>,
+    /// Whether the cursor is over the carousel.
+    hovered: bool,
+    /// The start of the autoplay interval.
+    since: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) showing the element.
+    fn new(shown: usize) -> Self {
+        Self {
+            shown,
+            from: shown as f32,
+            animation: Animation::new(TRANSITION).easing(Easing::EaseInOut),
+            now: None,
+            drag: None,
+            hovered: false,
+            since: None,
+        }
+    }
+
+    /// Returns the position, in elements, the carousel has slid to.
+    fn position(&self, now: Instant) -> f32 {
+        self.animation
+            .interpolate(self.from, self.shown as f32, now)
+    }
+
+    /// Slides from where the carousel is to the element, the shortest way
+    /// around if it wraps around.
+    fn show(&mut self, target: usize, count: usize, wrap_around: bool, now: Instant) {
+        if self.shown == target {
+            return;
+        }
+
+        self.from = target as f32 - offset(target, self.position(now), count, wrap_around);
+        self.shown = target;
+        self.slide(now);
+    }
+
+    /// Ends a drag by the offset, in elements, sliding back from where the
+    /// elements were dropped.
+    fn release(&mut self, offset: f32, now: Instant) {
+        self.from = self.position(now) - offset;
+        self.drag = None;
+        self.slide(now);
+    }
+
+    /// Restarts the animation sliding the elements.
+    fn slide(&mut self, now: Instant) {
+        self.animation = Animation::new(TRANSITION).easing(Easing::EaseInOut);
+        self.animation.go(true, now);
+    }
+}
+
+/// Returns the element next to the current one in the direction, if there
+/// is one.
+fn step(current: usize, count: usize, forward: bool, wrap_around: bool) -> Option<usize> {
+    if count < 2 {
+        return None;
+    }
+
+    if forward {
+        (current + 1 < count)
+            .then_some(current + 1)
+            .or_else(|| wrap_around.then_some(0))
+    } else {
+        current
+            .checked_sub(1)
+            .or_else(|| wrap_around.then_some(count - 1))
+    }
+}
+
+/// Returns the element a swipe by the horizontal offset pages to, if it is
+/// long enough.
+fn swipe(
+    current: usize,
+    count: usize,
+    offset: f32,
+    width: f32,
+    wrap_around: bool,
+) -> Option<usize> {
+    if offset.abs() < width * SWIPE_THRESHOLD {
+        return None;
+    }
+
+    // Swiping to the left brings in the next element from the right
+    step(current, count, offset < 0.0, wrap_around)
+}
+
+/// Returns the offset, in elements, of the element from the position.
+///
+/// If the carousel wraps around, the elements form a ring and the offset is
+/// the shortest way around it.
+fn offset(index: usize, position: f32, count: usize, wrap_around: bool) -> f32 {
+    let offset = index as f32 - position;
+    if !wrap_around || count == 0 {
+        return offset;
+    }
+
+    let count = count as f32;
+    (offset + count / 2.0).rem_euclid(count) - count / 2.0
+}
+
+/// Returns the bounds of the previous or next arrow.
+fn arrow_bounds(bounds: Rectangle, forward: bool) -> Rectangle {
+    Rectangle {
+        x: if forward {
+            bounds.x + bounds.width - ARROW_MARGIN - ARROW_SIZE
+        } else {
+            bounds.x + ARROW_MARGIN
+        },
+        y: bounds.center_y() - ARROW_SIZE / 2.0,
+        width: ARROW_SIZE,
+        height: ARROW_SIZE,
+    }
+}
+
+/// Returns the bounds of the dot indicator of the element at the index.
+fn dot_bounds(bounds: Rectangle, count: usize, index: usize) -> Rectangle {
+    let row_width = count as f32 * DOT_SIZE + count.saturating_sub(1) as f32 * DOT_SPACING;
+
+    Rectangle {
+        x: bounds.center_x() - row_width / 2.0 + index as f32 * (DOT_SIZE + DOT_SPACING),
+        y: bounds.y + bounds.height - DOT_MARGIN - DOT_SIZE,
+        width: DOT_SIZE,
+        height: DOT_SIZE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::time::Instant;
+
+    use super::{offset, step, swipe, State, TRANSITION};
+
+    /// Asserts that the values are equal up to rounding errors.
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn steps_stop_at_the_ends_unless_wrapping_around() {
+        assert_eq!(step(0, 3, true, false), Some(1));
+        assert_eq!(step(2, 3, true, false), None);
+        assert_eq!(step(2, 3, true, true), Some(0));
+        assert_eq!(step(0, 3, false, false), None);
+        assert_eq!(step(0, 3, false, true), Some(2));
+        assert_eq!(step(0, 1, true, true), None);
+    }
+
+    #[test]
+    fn short_swipes_do_not_page() {
+        assert_eq!(swipe(1, 3, -10.0, 100.0, false), None);
+        assert_eq!(swipe(1, 3, -30.0, 100.0, false), Some(2));
+        assert_eq!(swipe(1, 3, 30.0, 100.0, false), Some(0));
+    }
+
+    #[test]
+    fn offsets_take_the_shortest_way_around() {
+        assert_close(offset(0, 3.0, 4, false), -3.0);
+        assert_close(offset(0, 3.0, 4, true), 1.0);
+        assert_close(offset(3, 0.5, 4, true), -1.5);
+    }
+
+    #[test]
+    fn wrapping_slides_forward_from_the_last_element() {
+        let now = Instant::now();
+        let mut state = State::new(3);
+
+        state.show(0, 4, true, now);
+
+        assert_close(state.position(now), -1.0);
+        assert_close(state.position(now + TRANSITION), 0.0);
+    }
+}
@@ -0,0 +1,670 @@
+//! Plot values as lines, bars or slices of a pie.
+//!
+//! The line and the bar chart plot the values of their series over
+//! categories along the horizontal axis, with a value axis, tick labels, a
+//! legend and a tooltip showing the values of the hovered category. The pie
+//! chart shows the shares of its slices in a pie or a donut.
+//!
+//! *This API requires the following crate features to be activated: chart*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    renderer, text, Color, Font, Point, Rectangle,
+};
+
+pub mod bar_chart;
+pub mod line_chart;
+pub mod pie_chart;
+
+pub use bar_chart::BarChart;
+pub use line_chart::LineChart;
+pub use pie_chart::{PieChart, Slice};
+
+pub use crate::style::chart::{Appearance, StyleSheet};
+
+/// The default height of a chart.
+const DEFAULT_HEIGHT: f32 = 240.0;
+
+/// The space around the plot area.
+const PADDING: f32 = 8.0;
+
+/// The space between lines of text.
+const LINE_SPACING: f32 = 4.0;
+
+/// The gap between the tick labels and the value axis.
+const TICK_GAP: f32 = 6.0;
+
+/// The least vertical distance between the ticks of the value axis.
+const TICK_SPACING: f32 = 40.0;
+
+/// The least gap between the labels of the categories.
+const LABEL_GAP: f32 = 8.0;
+
+/// The distance between the cursor and the tooltip.
+const TOOLTIP_OFFSET: f32 = 12.0;
+
+/// The colors of the series without a color of their own, in turn.
+const SERIES_COLORS: [Color; 8] = [
+    Color::from_rgb(0.12, 0.47, 0.71),
+    Color::from_rgb(1.0, 0.5, 0.05),
+    Color::from_rgb(0.17, 0.63, 0.17),
+    Color::from_rgb(0.84, 0.15, 0.16),
+    Color::from_rgb(0.58, 0.4, 0.74),
+    Color::from_rgb(0.55, 0.34, 0.29),
+    Color::from_rgb(0.89, 0.47, 0.76),
+    Color::from_rgb(0.09, 0.75, 0.81),
+];
+
+/// A named series of values plotted by a chart.
+///
+/// The values are plotted over the categories in order. Values that are not
+/// finite leave a gap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    /// The label of the series in the legend and the tooltip.
+    label: String,
+    /// The values of the series.
+    values: Vec<f32>,
+    /// The color of the series, if it does not take the next default one.
+    color: Option<Color>,
+}
+
+impl Series {
+    /// Creates a new [`Series`](Series) with the label and the values.
+    pub fn new(label: impl Into<String>, values: impl Into<Vec<f32>>) -> Self {
+        Self {
+            label: label.into(),
+            values: values.into(),
+            color: None,
+        }
+    }
+
+    /// Sets the color of the [`Series`](Series).
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the finite value at the index, if any.
+    fn value(&self, index: usize) -> Option<f32> {
+        self.values
+            .get(index)
+            .copied()
+            .filter(|value| value.is_finite())
+    }
+}
+
+/// Returns the color of the series at the index.
+fn color_of(series: &[Series], index: usize) -> Color {
+    series[index].color.unwrap_or_else(|| default_color(index))
+}
+
+/// Returns the default color of the series or slice at the index.
+fn default_color(index: usize) -> Color {
+    SERIES_COLORS[index % SERIES_COLORS.len()]
+}
+
+/// The options shared by the charts.
+#[derive(Clone, Debug)]
+struct Options {
+    /// The labels of the categories.
+    labels: Vec<String>,
+    /// The fixed range of the value axis, if any.
+    range: Option<(f32, f32)>,
+    /// Whether the legend is shown.
+    legend: bool,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            labels: Vec::new(),
+            range: None,
+            legend: true,
+            text_size: None,
+            font: Font::default(),
+        }
+    }
+}
+
+impl Options {
+    /// Returns the label of the category at the index, or its number if it
+    /// has none.
+    fn label(&self, index: usize) -> String {
+        self.labels
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| (index + 1).to_string())
+    }
+}
+
+/// The areas and the value axis of a chart, laid out when it is drawn.
+#[derive(Clone, Copy, Debug)]
+struct Plot {
+    /// The bounds of the chart.
+    bounds: Rectangle,
+    /// The area the values are plotted in.
+    area: Rectangle,
+    /// The value at the bottom of the plot area.
+    min: f32,
+    /// The value at the top of the plot area.
+    max: f32,
+    /// The distance between the ticks of the value axis.
+    step: f32,
+    /// The number of categories.
+    categories: usize,
+    /// The text size of the labels.
+    text_size: f32,
+}
+
+impl Plot {
+    /// Lays out the chart with the series in the bounds.
+    ///
+    /// Bars grow from zero, so their value axis includes it.
+    fn new<Renderer>(
+        renderer: &Renderer,
+        bounds: Rectangle,
+        options: &Options,
+        series: &[Series],
+        from_zero: bool,
+    ) -> Self
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let text_size = options.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = text_size + LINE_SPACING;
+        let legend_height = if options.legend && !series.is_empty() {
+            line_height + PADDING
+        } else {
+            0.0
+        };
+
+        let top = bounds.y + PADDING + legend_height;
+        let bottom = bounds.y + bounds.height - PADDING - line_height;
+        let height = (bottom - top).max(0.0);
+
+        let (data_min, data_max) = extent(series, from_zero);
+        let (nice_min, nice_max, step) = ticks(
+            options.range.map_or(data_min, |range| range.0),
+            options.range.map_or(data_max, |range| range.1),
+            (height / TICK_SPACING).max(1.0) as usize,
+        );
+        let (min, max) = options
+            .range
+            .filter(|(min, max)| max > min)
+            .unwrap_or((nice_min, nice_max));
+
+        let precision = precision(step);
+        let label_width = tick_values(min, max, step)
+            .into_iter()
+            .map(|value| {
+                renderer.measure_width(&format!("{value:.precision$}"), text_size, options.font)
+            })
+            .fold(0.0, f32::max);
+
+        let left = bounds.x + PADDING + label_width + TICK_GAP;
+        let right = bounds.x + bounds.width - PADDING;
+
+        Self {
+            bounds,
+            area: Rectangle {
+                x: left,
+                y: top,
+                width: (right - left).max(0.0),
+                height,
+            },
+            min,
+            max,
+            step,
+            categories: series
+                .iter()
+                .map(|series| series.values.len())
+                .max()
+                .unwrap_or_default()
+                .max(options.labels.len()),
+            text_size,
+        }
+    }
+
+    /// Returns the vertical position of the value.
+    fn y(&self, value: f32) -> f32 {
+        self.area.y + self.area.height * (self.max - value) / (self.max - self.min)
+    }
+
+    /// Returns the width of the slot of a category.
+    fn slot(&self) -> f32 {
+        self.area.width / self.categories.max(1) as f32
+    }
+
+    /// Returns the horizontal position of the center of the category at the
+    /// index.
+    fn x(&self, index: usize) -> f32 {
+        self.area.x + (index as f32 + 0.5) * self.slot()
+    }
+
+    /// Returns the index of the category at the cursor, if any.
+    fn category_at(&self, cursor_position: Point) -> Option<usize> {
+        if self.categories == 0 || !self.area.contains(cursor_position) {
+            return None;
+        }
+
+        Some((((cursor_position.x - self.area.x) / self.slot()) as usize).min(self.categories - 1))
+    }
+
+    /// Draws the background, the highlight of the hovered category, the
+    /// grid, the axes, the tick labels and the labels of the categories.
+    fn draw_grid<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        options: &Options,
+        hovered: Option<usize>,
+    ) where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        renderer.fill_quad(quad(self.bounds), appearance.background);
+
+        if let Some(index) = hovered {
+            renderer.fill_quad(
+                quad(Rectangle {
+                    x: self.x(index) - self.slot() / 2.0,
+                    width: self.slot(),
+                    ..self.area
+                }),
+                appearance.highlight_color,
+            );
+        }
+
+        let precision = precision(self.step);
+        for value in tick_values(self.min, self.max, self.step) {
+            let y = self.y(value);
+
+            renderer.fill_quad(
+                quad(Rectangle {
+                    x: self.area.x,
+                    y,
+                    width: self.area.width,
+                    height: 1.0,
+                }),
+                if value == 0.0 {
+                    appearance.axis_color
+                } else {
+                    appearance.grid_color
+                },
+            );
+
+            renderer.fill_text(text::Text {
+                content: &format!("{value:.precision$}"),
+                bounds: Rectangle {
+                    x: self.area.x - TICK_GAP,
+                    y,
+                    ..self.bounds
+                },
+                size: self.text_size,
+                color: appearance.text_color,
+                font: options.font,
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        renderer.fill_quad(
+            quad(Rectangle {
+                width: 1.0,
+                ..self.area
+            }),
+            appearance.axis_color,
+        );
+        renderer.fill_quad(
+            quad(Rectangle {
+                y: self.area.y + self.area.height,
+                height: 1.0,
+                ..self.area
+            }),
+            appearance.axis_color,
+        );
+
+        // Crowded labels are thinned out to every few categories
+        let label_width = (0..self.categories)
+            .map(|index| {
+                renderer.measure_width(&options.label(index), self.text_size, options.font)
+            })
+            .fold(0.0, f32::max);
+        let every = ((label_width + LABEL_GAP) / self.slot()).ceil().max(1.0) as usize;
+
+        for index in (0..self.categories).step_by(every) {
+            renderer.fill_text(text::Text {
+                content: &options.label(index),
+                bounds: Rectangle {
+                    x: self.x(index),
+                    y: self.area.y + self.area.height + LINE_SPACING,
+                    ..self.bounds
+                },
+                size: self.text_size,
+                color: appearance.text_color,
+                font: options.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Top,
+            });
+        }
+    }
+
+    /// Draws the legend above the plot area, if it is enabled.
+    fn draw_legend<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        options: &Options,
+        series: &[Series],
+    ) where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        if !options.legend {
+            return;
+        }
+
+        let swatch = self.text_size * 0.75;
+        let y = self.bounds.y + PADDING + self.text_size / 2.0;
+        let mut x = self.area.x;
+
+        for (index, entry) in series.iter().enumerate() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: y - swatch / 2.0,
+                        width: swatch,
+                        height: swatch,
+                    },
+                    border_radius: 2.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color_of(series, index),
+            );
+            x += swatch + LINE_SPACING;
+
+            renderer.fill_text(text::Text {
+                content: &entry.label,
+                bounds: Rectangle {
+                    x,
+                    y,
+                    ..self.bounds
+                },
+                size: self.text_size,
+                color: appearance.text_color,
+                font: options.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+            x += renderer.measure_width(&entry.label, self.text_size, options.font) + 3.0 * PADDING;
+        }
+    }
+
+    /// Draws the tooltip with the values of the category at the index next
+    /// to the cursor.
+    fn draw_tooltip<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        options: &Options,
+        series: &[Series],
+        index: usize,
+        cursor_position: Point,
+    ) where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let title = options.label(index);
+        let entries: Vec<(Color, String)> = series
+            .iter()
+            .enumerate()
+            .filter_map(|(position, entry)| {
+                entry.value(index).map(|value| {
+                    (
+                        color_of(series, position),
+                        format!("{}: {value}", entry.label),
+                    )
+                })
+            })
+            .collect();
+
+        draw_tooltip(
+            renderer,
+            appearance,
+            options.font,
+            self.text_size,
+            self.bounds,
+            &title,
+            &entries,
+            cursor_position,
+        );
+    }
+}
+
+/// Draws a tooltip with the title and the entries in their colors next to
+/// the cursor, inside the bounds of the chart.
+#[allow(clippy::too_many_arguments)]
+fn draw_tooltip<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    font: Font,
+    text_size: f32,
+    bounds: Rectangle,
+    title: &str,
+    entries: &[(Color, String)],
+    cursor_position: Point,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let swatch = text_size * 0.75;
+    let line_height = text_size + LINE_SPACING;
+    let width = entries
+        .iter()
+        .map(|(_, entry)| swatch + LINE_SPACING + renderer.measure_width(entry, text_size, font))
+        .fold(renderer.measure_width(title, text_size, font), f32::max)
+        + 2.0 * PADDING;
+    let height = (entries.len() + 1) as f32 * line_height - LINE_SPACING + 2.0 * PADDING;
+
+    // The tooltip flips to the other side of the cursor at the edges
+    let right = bounds.x + bounds.width;
+    let bottom = bounds.y + bounds.height;
+    let x = if cursor_position.x + TOOLTIP_OFFSET + width > right {
+        cursor_position.x - TOOLTIP_OFFSET - width
+    } else {
+        cursor_position.x + TOOLTIP_OFFSET
+    };
+    let y = if cursor_position.y + TOOLTIP_OFFSET + height > bottom {
+        cursor_position.y - TOOLTIP_OFFSET - height
+    } else {
+        cursor_position.y + TOOLTIP_OFFSET
+    };
+    let tooltip = Rectangle {
+        x: x.max(bounds.x),
+        y: y.max(bounds.y),
+        width,
+        height,
+    };
+
+    // The tooltip is drawn on a layer of its own to cover the plotted
+    // series
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: tooltip,
+                border_radius: 4.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.tooltip_background,
+        );
+
+        let x = tooltip.x + PADDING;
+        let mut y = tooltip.y + PADDING + text_size / 2.0;
+
+        renderer.fill_text(text::Text {
+            content: title,
+            bounds: Rectangle { x, y, ..tooltip },
+            size: text_size,
+            color: appearance.tooltip_text_color,
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+        });
+
+        for (color, entry) in entries {
+            y += line_height;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: y - swatch / 2.0,
+                        width: swatch,
+                        height: swatch,
+                    },
+                    border_radius: 2.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                *color,
+            );
+
+            renderer.fill_text(text::Text {
+                content: entry,
+                bounds: Rectangle {
+                    x: x + swatch + LINE_SPACING,
+                    y,
+                    ..tooltip
+                },
+                size: text_size,
+                color: appearance.tooltip_text_color,
+                font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    });
+}
+
+/// Returns a quad without a border in the bounds.
+fn quad(bounds: Rectangle) -> renderer::Quad {
+    renderer::Quad {
+        bounds,
+        border_radius: 0.0.into(),
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
+
+/// Returns the lowest and the highest finite value of the series.
+///
+/// The extent includes zero if the values are plotted from it and is never
+/// empty.
+fn extent(series: &[Series], from_zero: bool) -> (f32, f32) {
+    let (min, max) = series
+        .iter()
+        .flat_map(|series| &series.values)
+        .filter(|value| value.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+
+    let (min, max) = if min > max {
+        (0.0, 1.0)
+    } else if from_zero {
+        (min.min(0.0), max.max(0.0))
+    } else {
+        (min, max)
+    };
+
+    if max > min {
+        (min, max)
+    } else {
+        (min - 1.0, max + 1.0)
+    }
+}
+
+/// Returns the range rounded out to a round step and the step, so that
+/// about the count of steps covers the range.
+fn ticks(min: f32, max: f32, count: usize) -> (f32, f32, f32) {
+    let (min, max) = if max > min {
+        (min, max)
+    } else {
+        (min - 1.0, max + 1.0)
+    };
+
+    let rough = (max - min) / count.max(1) as f32;
+    let magnitude = 10.0_f32.powf(rough.log10().floor());
+    let step = magnitude
+        * match rough / magnitude {
+            normalized if normalized <= 1.0 => 1.0,
+            normalized if normalized <= 2.0 => 2.0,
+            normalized if normalized <= 5.0 => 5.0,
+            _ => 10.0,
+        };
+
+    (
+        (min / step).floor() * step,
+        (max / step).ceil() * step,
+        step,
+    )
+}
+
+/// Returns the multiples of the step in the range.
+fn tick_values(min: f32, max: f32, step: f32) -> Vec<f32> {
+    let first = (min / step).ceil();
+    let last = (max / step + 1e-3).floor();
+
+    // Multiplying instead of adding up the step keeps the values round
+    (0..=(last - first).max(0.0) as usize)
+        .map(|index| (first + index as f32) * step)
+        .map(|value| if value == 0.0 { 0.0 } else { value })
+        .collect()
+}
+
+/// Returns the number of decimals needed to tell apart values the step
+/// apart.
+fn precision(step: f32) -> usize {
+    // Steps like 0.1 are slightly off in floating point
+    (-(step.log10() + 1e-3).floor()).max(0.0) as usize
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{extent, precision, tick_values, ticks, Series};
+
+    #[test]
+    fn ticks_are_round() {
+        assert_eq!(ticks(0.0, 97.0, 5), (0.0, 100.0, 20.0));
+        assert_eq!(ticks(-3.0, 7.0, 4), (-5.0, 10.0, 5.0));
+        assert_eq!(ticks(0.0, 9.0, 3), (0.0, 10.0, 5.0));
+    }
+
+    #[test]
+    fn tick_values_are_multiples_of_the_step() {
+        assert_eq!(tick_values(-5.0, 10.0, 5.0), vec![-5.0, 0.0, 5.0, 10.0]);
+        assert_eq!(tick_values(0.5, 2.5, 1.0), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn precision_follows_the_step() {
+        assert_eq!(precision(20.0), 0);
+        assert_eq!(precision(1.0), 0);
+        assert_eq!(precision(0.5), 1);
+        assert_eq!(precision(0.02), 2);
+    }
+
+    #[test]
+    fn extent_skips_gaps_and_includes_zero_for_bars() {
+        let series = [Series::new("a", [3.0, f32::NAN, 5.0])];
+
+        assert_eq!(extent(&series, false), (3.0, 5.0));
+        assert_eq!(extent(&series, true), (0.0, 5.0));
+        assert_eq!(extent(&[], false), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,248 @@
+//! Plot series of values as bars.
+//!
+//! *This API requires the following crate features to be activated: chart*
+use iced_native::{
+    layout::{Limits, Node},
+    renderer,
+    widget::Tree,
+    Color, Element, Font, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use super::{color_of, Options, Plot, Series, StyleSheet, DEFAULT_HEIGHT};
+
+use crate::style::style_state::{appearance, StyleState};
+
+/// The part of the slot of a category left empty around its bars.
+const GROUP_GAP: f32 = 0.2;
+
+/// The gap between the bars of a category.
+const BAR_GAP: f32 = 2.0;
+
+/// The radius of the corners of the bars.
+const BAR_RADIUS: f32 = 2.0;
+
+/// A chart showing each value as a bar growing from zero.
+///
+/// The bars of the series stand side by side in each category. Hovering a
+/// category highlights it and shows its values in a tooltip.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::chart::{self, Series};
+/// #
+/// # pub type BarChart = chart::BarChart<Null>;
+/// let chart = BarChart::new(vec![
+///     Series::new("2022", [4.0, 7.0, 5.0]),
+///     Series::new("2023", [6.0, 8.0, 3.0]),
+/// ])
+/// .labels(["North", "Center", "South"]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct BarChart<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The plotted series.
+    series: Vec<Series>,
+    /// The options shared by the charts.
+    options: Options,
+    /// The width of the chart.
+    width: Length,
+    /// The height of the chart.
+    height: Length,
+    /// The style of the chart.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> BarChart<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`BarChart`](BarChart) plotting the series.
+    #[must_use]
+    pub fn new(series: Vec<Series>) -> Self {
+        Self {
+            series,
+            options: Options::default(),
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a series to the [`BarChart`](BarChart).
+    #[must_use]
+    pub fn push(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets the labels of the categories.
+    ///
+    /// Categories without a label are numbered.
+    #[must_use]
+    pub fn labels<L>(mut self, labels: impl IntoIterator<Item = L>) -> Self
+    where
+        L: Into<String>,
+    {
+        self.options.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Fixes the range of the value axis instead of fitting it to the
+    /// values.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.options.range = Some((min, max));
+        self
+    }
+
+    /// Sets whether the legend is shown.
+    #[must_use]
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.options.legend = legend;
+        self
+    }
+
+    /// Sets the width of the [`BarChart`](BarChart).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`BarChart`](BarChart).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.options.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.options.font = font;
+        self
+    }
+
+    /// Sets the style of the [`BarChart`](BarChart).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for BarChart<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(self.width)
+                .height(self.height)
+                .resolve(Size::ZERO),
+        )
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style
+        );
+        let plot = Plot::new(renderer, bounds, &self.options, &self.series, true);
+        let hovered = plot.category_at(cursor_position);
+
+        plot.draw_grid(renderer, &appearance, &self.options, hovered);
+
+        let baseline = plot.y(0.0_f32.clamp(plot.min, plot.max));
+        let group = plot.slot() * (1.0 - GROUP_GAP);
+        let bar_width = group / self.series.len().max(1) as f32;
+
+        for (position, series) in self.series.iter().enumerate() {
+            let color = color_of(&self.series, position);
+
+            for index in 0..plot.categories {
+                let Some(value) = series.value(index) else {
+                    continue;
+                };
+
+                // Negative values hang down from the baseline
+                let top = plot.y(value.clamp(plot.min, plot.max));
+                let width = (bar_width - BAR_GAP).max(1.0);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: plot.x(index) - group / 2.0
+                                + position as f32 * bar_width
+                                + (bar_width - width) / 2.0,
+                            y: top.min(baseline),
+                            width,
+                            height: (top - baseline).abs(),
+                        },
+                        border_radius: BAR_RADIUS.min(width / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            }
+        }
+
+        plot.draw_legend(renderer, &appearance, &self.options, &self.series);
+
+        if let Some(index) = hovered {
+            plot.draw_tooltip(
+                renderer,
+                &appearance,
+                &self.options,
+                &self.series,
+                index,
+                cursor_position,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<BarChart<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(bar_chart: BarChart<Renderer>) -> Self {
+        Element::new(bar_chart)
+    }
+}
@@ -0,0 +1,268 @@
+//! Plot series of values as lines.
+//!
+//! *This API requires the following crate features to be activated: chart*
+use iced_graphics::{
+    widget::canvas::{Frame, LineCap, LineJoin, Path, Stroke},
+    Backend, Renderer,
+};
+use iced_native::{
+    layout::{Limits, Node},
+    renderer,
+    widget::Tree,
+    Element, Font, Layout, Length, Point, Rectangle, Renderer as _, Size, Vector, Widget,
+};
+
+use super::{color_of, Options, Plot, Series, StyleSheet, DEFAULT_HEIGHT};
+
+use crate::style::style_state::{appearance, StyleState};
+
+/// The width of the lines.
+const LINE_WIDTH: f32 = 2.0;
+
+/// The radius of the markers at the values.
+const MARKER_RADIUS: f32 = 3.0;
+
+/// The radius of the markers at the values of the hovered category.
+const HOVERED_MARKER_RADIUS: f32 = 5.0;
+
+/// A chart connecting the values of each series with a line.
+///
+/// Hovering a category highlights it and shows its values in a tooltip.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{chart::Series, LineChart};
+/// #
+/// let chart = LineChart::new(vec![
+///     Series::new("Visitors", [120.0, 180.0, 150.0, 210.0]),
+///     Series::new("Signups", [12.0, 20.0, 14.0, 31.0]),
+/// ])
+/// .labels(["Q1", "Q2", "Q3", "Q4"]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LineChart<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// The plotted series.
+    series: Vec<Series>,
+    /// The options shared by the charts.
+    options: Options,
+    /// The width of the chart.
+    width: Length,
+    /// The height of the chart.
+    height: Length,
+    /// The style of the chart.
+    style: <Theme as StyleSheet>::Style,
+    #[allow(missing_docs)]
+    backend: std::marker::PhantomData<B>,
+}
+
+impl<B, Theme> LineChart<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`LineChart`](LineChart) plotting the series.
+    #[must_use]
+    pub fn new(series: Vec<Series>) -> Self {
+        Self {
+            series,
+            options: Options::default(),
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: <Theme as StyleSheet>::Style::default(),
+            backend: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds a series to the [`LineChart`](LineChart).
+    #[must_use]
+    pub fn push(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets the labels of the categories.
+    ///
+    /// Categories without a label are numbered.
+    #[must_use]
+    pub fn labels<L>(mut self, labels: impl IntoIterator<Item = L>) -> Self
+    where
+        L: Into<String>,
+    {
+        self.options.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Fixes the range of the value axis instead of fitting it to the
+    /// values.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.options.range = Some((min, max));
+        self
+    }
+
+    /// Sets whether the legend is shown.
+    #[must_use]
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.options.legend = legend;
+        self
+    }
+
+    /// Sets the width of the [`LineChart`](LineChart).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LineChart`](LineChart).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.options.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.options.font = font;
+        self
+    }
+
+    /// Sets the style of the [`LineChart`](LineChart).
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, B, Theme> Widget<Message, Renderer<B, Theme>> for LineChart<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(self.width)
+                .height(self.height)
+                .resolve(Size::ZERO),
+        )
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer<B, Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style
+        );
+        let plot = Plot::new(renderer, bounds, &self.options, &self.series, false);
+        let hovered = plot.category_at(cursor_position);
+
+        plot.draw_grid(renderer, &appearance, &self.options, hovered);
+
+        // The points are relative to the frame at the top left of the chart
+        let point = |index: usize, value: f32| {
+            Point::new(plot.x(index) - bounds.x, plot.y(value) - bounds.y)
+        };
+        let mut frame = Frame::new(bounds.size());
+
+        for (position, series) in self.series.iter().enumerate() {
+            let color = color_of(&self.series, position);
+
+            let line = Path::new(|builder| {
+                let mut connected = false;
+
+                for index in 0..plot.categories {
+                    match series.value(index) {
+                        Some(value) if connected => builder.line_to(point(index, value)),
+                        Some(value) => builder.move_to(point(index, value)),
+                        None => {}
+                    }
+                    connected = series.value(index).is_some();
+                }
+            });
+
+            frame.stroke(
+                &line,
+                Stroke::default()
+                    .with_color(color)
+                    .with_width(LINE_WIDTH)
+                    .with_line_cap(LineCap::Round)
+                    .with_line_join(LineJoin::Round),
+            );
+
+            for index in 0..plot.categories {
+                if let Some(value) = series.value(index) {
+                    let radius = if hovered == Some(index) {
+                        HOVERED_MARKER_RADIUS
+                    } else {
+                        MARKER_RADIUS
+                    };
+
+                    frame.fill(&Path::circle(point(index, value), radius), color);
+                }
+            }
+        }
+
+        // Values outside of a fixed range are cut off at the bounds
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw_primitive(frame.into_geometry().into_primitive());
+            });
+        });
+
+        plot.draw_legend(renderer, &appearance, &self.options, &self.series);
+
+        if let Some(index) = hovered {
+            plot.draw_tooltip(
+                renderer,
+                &appearance,
+                &self.options,
+                &self.series,
+                index,
+                cursor_position,
+            );
+        }
+    }
+}
+
+impl<'a, Message, B, Theme> From<LineChart<B, Theme>> for Element<'a, Message, Renderer<B, Theme>>
+where
+    B: 'a + Backend + iced_graphics::backend::Text,
+    Theme: 'a + StyleSheet,
+{
+    fn from(line_chart: LineChart<B, Theme>) -> Self {
+        Element::new(line_chart)
+    }
+}
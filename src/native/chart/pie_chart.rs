@@ -0,0 +1,700 @@
+//! Show the shares of values as slices of a pie or a donut.
+//!
+//! *This API requires the following crate features to be activated: chart*
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use iced_graphics::{
+    widget::canvas::{path, Frame, Path, Stroke},
+    Backend, Renderer,
+};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer,
+    text::{self, Renderer as _},
+    touch,
+    widget::Tree,
+    Background, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle,
+    Renderer as _, Shell, Size, Vector, Widget,
+};
+
+use super::{default_color, draw_tooltip, StyleSheet, DEFAULT_HEIGHT, LINE_SPACING, PADDING};
+
+use crate::style::style_state::{appearance, StyleState};
+
+/// The distance the hovered slice moves out of the pie.
+const HOVER_OFFSET: f32 = 6.0;
+
+/// The width of the gap between the slices.
+const SLICE_GAP: f32 = 1.5;
+
+/// The smallest share of a slice labeled with its percentage.
+const MIN_LABEL_SHARE: f32 = 0.05;
+
+/// A slice of a [`PieChart`](PieChart).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slice {
+    /// The label of the slice in the legend and the tooltip.
+    label: String,
+    /// The value of the slice.
+    value: f32,
+    /// The color of the slice, if it does not take the next default one.
+    color: Option<Color>,
+}
+
+impl Slice {
+    /// Creates a new [`Slice`](Slice) with the label and the value.
+    ///
+    /// Slices without a positive value take no share of the pie.
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the [`Slice`](Slice).
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A chart showing the shares of values as slices of a pie.
+///
+/// With a hole in the middle, the pie becomes a donut that can show an
+/// element in its center, like a total. The hovered slice moves out of the
+/// pie and shows its value in a tooltip, and pressing a slice produces a
+/// message to drill down into it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{chart::Slice, PieChart};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Pressed(usize),
+/// }
+///
+/// let chart = PieChart::new(vec![
+///     Slice::new("Rent", 950.0),
+///     Slice::new("Food", 420.0),
+///     Slice::new("Travel", 180.0),
+/// ])
+/// .donut(0.6)
+/// .center(Text::new("1550 €"))
+/// .on_press(Message::Pressed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PieChart<'a, Message, B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// The slices.
+    slices: Vec<Slice>,
+    /// The radius of the hole relative to the radius of the pie.
+    donut: f32,
+    /// The element in the hole of the donut, if any.
+    center: Option<Element<'a, Message, Renderer<B, Theme>>>,
+    /// The function producing the message when a slice is pressed, if any.
+    on_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// Whether the slices are labeled with their percentage.
+    labels: bool,
+    /// Whether the legend is shown.
+    legend: bool,
+    /// The width of the chart.
+    width: Length,
+    /// The height of the chart.
+    height: Length,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the chart.
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, B, Theme> PieChart<'a, Message, B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`PieChart`](PieChart) with the slices.
+    #[must_use]
+    pub fn new(slices: Vec<Slice>) -> Self {
+        Self {
+            slices,
+            donut: 0.0,
+            center: None,
+            on_press: None,
+            labels: true,
+            legend: true,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            text_size: None,
+            font: Font::default(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a slice to the [`PieChart`](PieChart).
+    #[must_use]
+    pub fn push(mut self, slice: Slice) -> Self {
+        self.slices.push(slice);
+        self
+    }
+
+    /// Turns the pie into a donut with a hole of the radius relative to the
+    /// radius of the pie, between 0.0 and 0.95.
+    #[must_use]
+    pub fn donut(mut self, ratio: f32) -> Self {
+        self.donut = ratio.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Sets the element shown in the hole of the donut.
+    #[must_use]
+    pub fn center<E>(mut self, center: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer<B, Theme>>>,
+    {
+        self.center = Some(center.into());
+        self
+    }
+
+    /// Sets the function producing the message when the slice at an index is
+    /// pressed.
+    #[must_use]
+    pub fn on_press<F>(mut self, on_press: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets whether the slices are labeled with their percentage.
+    #[must_use]
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets whether the legend is shown.
+    #[must_use]
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets the width of the [`PieChart`](PieChart).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`PieChart`](PieChart).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`PieChart`](PieChart).
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the color of the slice at the index.
+    fn color(&self, index: usize) -> Color {
+        self.slices[index]
+            .color
+            .unwrap_or_else(|| default_color(index))
+    }
+
+    /// Lays out the pie and the legend in the bounds.
+    fn pie(&self, renderer: &Renderer<B, Theme>, bounds: Rectangle) -> Pie {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let swatch = text_size * 0.75;
+
+        let legend_width = if self.legend && !self.slices.is_empty() {
+            self.slices
+                .iter()
+                .map(|slice| renderer.measure_width(&slice.label, text_size, self.font))
+                .fold(0.0, f32::max)
+                + swatch
+                + LINE_SPACING
+        } else {
+            0.0
+        };
+        let legend_height = self.slices.len() as f32 * (text_size + LINE_SPACING) - LINE_SPACING;
+
+        let area = Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: (bounds.width - 2.0 * PADDING).max(0.0),
+            height: (bounds.height - 2.0 * PADDING).max(0.0),
+        };
+        let pie_width = if legend_width > 0.0 {
+            (area.width - legend_width - 2.0 * PADDING).max(0.0)
+        } else {
+            area.width
+        };
+
+        // The hovered slice moves out of the pie, so it keeps room for it
+        let radius = (pie_width.min(area.height) / 2.0 - HOVER_OFFSET).max(0.0);
+
+        Pie {
+            center: Point::new(area.x + pie_width / 2.0, area.center_y()),
+            radius,
+            inner_radius: radius * self.donut,
+            legend: Rectangle {
+                x: area.x + pie_width + 2.0 * PADDING,
+                y: area.center_y() - legend_height / 2.0,
+                width: legend_width,
+                height: legend_height,
+            },
+            text_size,
+        }
+    }
+
+    /// Returns the index of the slice at the cursor, if any.
+    fn slice_at(&self, pie: &Pie, cursor_position: Point) -> Option<usize> {
+        let offset = cursor_position - pie.center;
+        let distance = offset.x.hypot(offset.y);
+        if distance > pie.radius || distance < pie.inner_radius {
+            return None;
+        }
+
+        // Turns are counted clockwise from the top
+        let turn = ((offset.y.atan2(offset.x) + FRAC_PI_2) / TAU).rem_euclid(1.0);
+
+        slice_at(&shares(&self.slices), turn)
+    }
+}
+
+impl<'a, Message, B, Theme> Widget<Message, Renderer<B, Theme>> for PieChart<'a, Message, B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.center.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.center {
+            Some(center) => tree.diff_children(std::slice::from_ref(center)),
+            None => tree.children.clear(),
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::ZERO);
+
+        let Some(center) = &self.center else {
+            return Node::new(size);
+        };
+
+        // The center fits into the largest square inside the hole
+        let pie = self.pie(renderer, Rectangle::new(Point::ORIGIN, size));
+        let side = pie.inner_radius * std::f32::consts::SQRT_2;
+
+        let mut node = center
+            .as_widget()
+            .layout(renderer, &Limits::new(Size::ZERO, Size::new(side, side)));
+        let center_size = node.size();
+        node.move_to(Point::new(
+            pie.center.x - center_size.width / 2.0,
+            pie.center.y - center_size.height / 2.0,
+        ));
+
+        Node::with_children(size, vec![node])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer<B, Theme>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let (Some(center), Some(center_layout)) = (&mut self.center, layout.children().next()) {
+            let status = center.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                center_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(on_press) = &self.on_press else {
+                    return event::Status::Ignored;
+                };
+                let pie = self.pie(renderer, layout.bounds());
+                let Some(index) = self.slice_at(&pie, cursor_position) else {
+                    return event::Status::Ignored;
+                };
+
+                shell.publish(on_press(index));
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer<B, Theme>,
+    ) -> mouse::Interaction {
+        let pie = self.pie(renderer, layout.bounds());
+
+        if self.on_press.is_some() && self.slice_at(&pie, cursor_position).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+
+        match (&self.center, layout.children().next()) {
+            (Some(center), Some(center_layout)) => center.as_widget().mouse_interaction(
+                &tree.children[0],
+                center_layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, Theme>,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style
+        );
+        let pie = self.pie(renderer, bounds);
+        let shares = shares(&self.slices);
+        let hovered = self.slice_at(&pie, cursor_position);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        // The gaps between the slices show the background
+        let gap_color = match appearance.background {
+            Background::Color(color) => color,
+        };
+
+        let mut frame = Frame::new(bounds.size());
+        let mut labels = Vec::new();
+        let mut start_angle = -FRAC_PI_2;
+
+        for (index, &share) in shares.iter().enumerate() {
+            if share <= 0.0 {
+                continue;
+            }
+
+            let end_angle = start_angle + share * TAU;
+            let middle = Vector::new(
+                ((start_angle + end_angle) / 2.0).cos(),
+                ((start_angle + end_angle) / 2.0).sin(),
+            );
+            let center = pie.center - Vector::new(bounds.x, bounds.y)
+                + if hovered == Some(index) {
+                    middle * HOVER_OFFSET
+                } else {
+                    Vector::new(0.0, 0.0)
+                };
+
+            let slice = Path::new(|builder| {
+                if pie.inner_radius > 0.0 {
+                    builder.arc(path::Arc {
+                        center,
+                        radius: pie.radius,
+                        start_angle,
+                        end_angle,
+                    });
+                    builder.arc(path::Arc {
+                        center,
+                        radius: pie.inner_radius,
+                        start_angle: end_angle,
+                        end_angle: start_angle,
+                    });
+                } else {
+                    builder.move_to(center);
+                    builder.arc(path::Arc {
+                        center,
+                        radius: pie.radius,
+                        start_angle,
+                        end_angle,
+                    });
+                }
+                builder.close();
+            });
+
+            frame.fill(&slice, self.color(index));
+            frame.stroke(
+                &slice,
+                Stroke::default()
+                    .with_color(gap_color)
+                    .with_width(SLICE_GAP),
+            );
+
+            if self.labels && share >= MIN_LABEL_SHARE {
+                let distance = if pie.inner_radius > 0.0 {
+                    (pie.radius + pie.inner_radius) / 2.0
+                } else {
+                    pie.radius * 0.65
+                };
+
+                labels.push((
+                    center + Vector::new(bounds.x, bounds.y) + middle * distance,
+                    format!("{:.0}%", share * 100.0),
+                ));
+            }
+
+            start_angle = end_angle;
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_primitive(frame.into_geometry().into_primitive());
+        });
+
+        for (position, label) in &labels {
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: position.x,
+                    y: position.y,
+                    ..bounds
+                },
+                size: pie.text_size,
+                color: appearance.slice_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        if let (Some(center), Some(center_layout)) = (&self.center, layout.children().next()) {
+            center.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                center_layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        if self.legend {
+            let swatch = pie.text_size * 0.75;
+
+            for (index, slice) in self.slices.iter().enumerate() {
+                let y = pie.legend.y
+                    + index as f32 * (pie.text_size + LINE_SPACING)
+                    + pie.text_size / 2.0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: pie.legend.x,
+                            y: y - swatch / 2.0,
+                            width: swatch,
+                            height: swatch,
+                        },
+                        border_radius: 2.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    self.color(index),
+                );
+
+                renderer.fill_text(text::Text {
+                    content: &slice.label,
+                    bounds: Rectangle {
+                        x: pie.legend.x + swatch + LINE_SPACING,
+                        y,
+                        ..bounds
+                    },
+                    size: pie.text_size,
+                    color: appearance.text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            }
+        }
+
+        if let Some(index) = hovered {
+            let slice = &self.slices[index];
+
+            draw_tooltip(
+                renderer,
+                &appearance,
+                self.font,
+                pie.text_size,
+                bounds,
+                &slice.label,
+                &[(
+                    self.color(index),
+                    format!("{} ({:.1}%)", slice.value, shares[index] * 100.0),
+                )],
+                cursor_position,
+            );
+        }
+    }
+}
+
+impl<'a, Message, B, Theme> From<PieChart<'a, Message, B, Theme>>
+    for Element<'a, Message, Renderer<B, Theme>>
+where
+    Message: 'a,
+    B: 'a + Backend + iced_graphics::backend::Text,
+    Theme: 'a + StyleSheet,
+{
+    fn from(pie_chart: PieChart<'a, Message, B, Theme>) -> Self {
+        Element::new(pie_chart)
+    }
+}
+
+/// The layout of a [`PieChart`](PieChart).
+#[derive(Clone, Copy, Debug)]
+struct Pie {
+    /// The center of the pie.
+    center: Point,
+    /// The radius of the pie.
+    radius: f32,
+    /// The radius of the hole of the donut, or zero.
+    inner_radius: f32,
+    /// The bounds of the legend.
+    legend: Rectangle,
+    /// The text size of the labels.
+    text_size: f32,
+}
+
+/// Returns the shares of the slices of the whole pie.
+///
+/// Slices without a positive, finite value take no share.
+fn shares(slices: &[Slice]) -> Vec<f32> {
+    let values: Vec<f32> = slices
+        .iter()
+        .map(|slice| {
+            if slice.value.is_finite() {
+                slice.value.max(0.0)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let total: f32 = values.iter().sum();
+
+    values
+        .into_iter()
+        .map(|value| if total > 0.0 { value / total } else { 0.0 })
+        .collect()
+}
+
+/// Returns the index of the slice at the turn, counted from the top, if any.
+fn slice_at(shares: &[f32], turn: f32) -> Option<usize> {
+    let mut end = 0.0;
+
+    shares.iter().position(|&share| {
+        end += share;
+        share > 0.0 && turn < end
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shares, slice_at, Slice};
+
+    #[test]
+    fn shares_ignore_negative_values() {
+        let slices = [
+            Slice::new("a", 3.0),
+            Slice::new("b", -2.0),
+            Slice::new("c", 1.0),
+        ];
+
+        assert_eq!(shares(&slices), vec![0.75, 0.0, 0.25]);
+        assert_eq!(shares(&[Slice::new("a", 0.0)]), vec![0.0]);
+    }
+
+    #[test]
+    fn slices_are_found_clockwise_from_the_top() {
+        let shares = [0.5, 0.0, 0.25, 0.25];
+
+        assert_eq!(slice_at(&shares, 0.1), Some(0));
+        assert_eq!(slice_at(&shares, 0.5), Some(2));
+        assert_eq!(slice_at(&shares, 0.9), Some(3));
+        assert_eq!(slice_at(&[], 0.5), None);
+    }
+}
@@ -0,0 +1,484 @@
+//! Show a compact label that can be pressed, toggled or deleted.
+//!
+//! *This API requires the following crate features to be activated: chip*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::{tree, Tree},
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::{renderer::GlyphRenderer, sizing::hit_target},
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::chip::{Appearance, StyleSheet};
+
+/// The default padding around the content of a chip.
+const DEFAULT_PADDING: f32 = 6.0;
+
+/// The space between the leading element, the label and the delete icon.
+const SPACING: f32 = 6.0;
+
+/// A compact label with an optional leading element, like an icon or an
+/// [`Avatar`](crate::native::avatar::Avatar), and an optional delete icon.
+///
+/// A chip can be pressed, toggled like a checkbox, showing a check while it
+/// is selected, or deleted. It shrinks to its content, so that chips flow
+/// into rows when put into a [`Wrap`](crate::native::wrap::Wrap).
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::chip;
+/// #
+/// # pub type Chip<'a, Message> = chip::Chip<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Toggled(bool),
+///     Deleted,
+/// }
+///
+/// let chip = Chip::new("Vegetarian")
+///     .selectable(true, Message::Toggled)
+///     .on_delete(Message::Deleted);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Chip<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The label of the chip.
+    label: String,
+    /// The element in front of the label.
+    leading: Option<Element<'a, Message, Renderer>>,
+    /// The message produced when the chip is pressed.
+    on_press: Option<Message>,
+    /// The message produced when the delete icon is pressed.
+    on_delete: Option<Message>,
+    /// Whether the chip is selected.
+    selected: bool,
+    /// The function producing the message toggling the chip.
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// The padding around the content of the chip.
+    padding: f32,
+    /// The text size of the label.
+    text_size: Option<f32>,
+    /// The font of the label.
+    font: Font,
+    /// The style of the chip.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Chip<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Chip`](Chip) with the label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            leading: None,
+            on_press: None,
+            on_delete: None,
+            selected: false,
+            on_toggle: None,
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the element in front of the label, like an icon or an avatar.
+    ///
+    /// It replaces the check of a selected [`Chip`](Chip).
+    #[must_use]
+    pub fn leading(mut self, leading: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.leading = Some(leading.into());
+        self
+    }
+
+    /// Sets the message produced when the [`Chip`](Chip) is pressed.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Shows a delete icon producing the message when it is pressed.
+    #[must_use]
+    pub fn on_delete(mut self, message: Message) -> Self {
+        self.on_delete = Some(message);
+        self
+    }
+
+    /// Makes the [`Chip`](Chip) toggle like a checkbox when it is pressed.
+    ///
+    /// It expects:
+    ///     * whether the chip is selected
+    ///     * the function producing the message with the toggled selection
+    #[must_use]
+    pub fn selectable<F>(mut self, selected: bool, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.selected = selected;
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the padding around the content of the [`Chip`](Chip).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the label.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the label.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Chip`](Chip).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns true if pressing the [`Chip`](Chip) produces a message.
+    fn is_pressable(&self) -> bool {
+        self.on_press.is_some() || self.on_toggle.is_some()
+    }
+
+    /// Returns true if a check is drawn in front of the label.
+    fn shows_check(&self) -> bool {
+        self.selected && self.leading.is_none()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Chip<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.leading.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.leading {
+            Some(leading) => tree.diff_children(std::slice::from_ref(leading)),
+            None => tree.children.clear(),
+        }
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let label_width = renderer.measure_width(&self.label, text_size, self.font);
+
+        let leading = self
+            .leading
+            .as_ref()
+            .map(|leading| leading.as_widget().layout(renderer, &Limits::NONE));
+        let leading_size = leading.as_ref().map_or_else(
+            || {
+                if self.shows_check() {
+                    Size::new(text_size, text_size)
+                } else {
+                    Size::ZERO
+                }
+            },
+            Node::size,
+        );
+
+        let content_height = text_size.max(leading_size.height);
+        let delete_width = if self.on_delete.is_some() {
+            SPACING + text_size
+        } else {
+            0.0
+        };
+        let leading_width = if leading_size.width > 0.0 {
+            leading_size.width + SPACING
+        } else {
+            0.0
+        };
+
+        let intrinsic = Size::new(
+            2.0 * self.padding + leading_width + label_width + delete_width,
+            2.0 * self.padding + content_height,
+        );
+        let size = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .resolve(intrinsic);
+
+        let children = leading
+            .map(|mut node| {
+                node.move_to(Point::new(
+                    self.padding,
+                    (size.height - leading_size.height) / 2.0,
+                ));
+                node
+            })
+            .into_iter()
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let (Some(leading), Some(leading_layout)) =
+            (self.leading.as_mut(), layout.children().next())
+        {
+            let status = leading.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                leading_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                tree.state.downcast_mut::<State>().is_pressed = false;
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let bounds = layout.bounds();
+                let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                if let Some(on_delete) = &self.on_delete {
+                    let delete = hit_target(delete_bounds(bounds, self.padding, text_size));
+                    if delete.contains(cursor_position) {
+                        shell.publish(on_delete.clone());
+                        return event::Status::Captured;
+                    }
+                }
+
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(!self.selected));
+                } else if let Some(on_press) = &self.on_press {
+                    shell.publish(on_press.clone());
+                } else {
+                    return event::Status::Ignored;
+                }
+
+                tree.state.downcast_mut::<State>().is_pressed = true;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let over_delete = self.on_delete.is_some()
+            && hit_target(delete_bounds(bounds, self.padding, text_size)).contains(cursor_position);
+
+        if over_delete || (self.is_pressable() && bounds.contains(cursor_position)) {
+            return mouse::Interaction::Pointer;
+        }
+
+        match (&self.leading, layout.children().next()) {
+            (Some(leading), Some(leading_layout)) => leading.as_widget().mouse_interaction(
+                &tree.children[0],
+                leading_layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let is_interactive = self.is_pressable() || self.on_delete.is_some();
+
+        let is_hovered = is_interactive && bounds.contains(cursor_position);
+        let is_pressed = is_hovered && tree.state.downcast_ref::<State>().is_pressed;
+
+        // A selected chip keeps its selected appearance unless it is pressed
+        let appearance = if self.selected && !is_pressed {
+            theme.selected(self.style)
+        } else {
+            appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(is_hovered, is_pressed, false, false),
+                self.style
+            )
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let mut label_x = bounds.x + self.padding;
+
+        if let (Some(leading), Some(leading_layout)) = (&self.leading, layout.children().next()) {
+            leading.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.text_color,
+                },
+                leading_layout,
+                cursor_position,
+                viewport,
+            );
+
+            label_x += leading_layout.bounds().width + SPACING;
+        } else if self.shows_check() {
+            renderer.fill_glyph(
+                char::from(Icon::Check),
+                Icon::Check.font(),
+                Rectangle {
+                    x: label_x,
+                    y: bounds.center_y() - text_size / 2.0,
+                    width: text_size,
+                    height: text_size,
+                },
+                text_size,
+                appearance.icon_color,
+            );
+
+            label_x += text_size + SPACING;
+        }
+
+        renderer.fill_text(text::Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: label_x,
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: text_size,
+            color: appearance.text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+        });
+
+        if self.on_delete.is_some() {
+            renderer.fill_glyph(
+                char::from(Icon::X),
+                Icon::X.font(),
+                delete_bounds(bounds, self.padding, text_size),
+                text_size,
+                appearance.icon_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Chip<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(chip: Chip<'a, Message, Renderer>) -> Self {
+        Element::new(chip)
+    }
+}
+
+/// The state of a [`Chip`](Chip).
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the [`Chip`](Chip) is pressed.
+    is_pressed: bool,
+}
+
+/// Returns the bounds of the delete icon at the end of a chip.
+fn delete_bounds(bounds: Rectangle, padding: f32, text_size: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x + bounds.width - padding - text_size,
+        y: bounds.center_y() - text_size / 2.0,
+        width: text_size,
+        height: text_size,
+    }
+}
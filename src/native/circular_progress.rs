@@ -0,0 +1,377 @@
+//! Show the progress of a task on a ring.
+//!
+//! *This API requires the following crate features to be activated: circular_progress*
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use iced_graphics::{
+    widget::canvas::{path, Frame, LineCap, Path, Stroke},
+    Backend, Renderer,
+};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    renderer,
+    text::{self, Renderer as _},
+    time::{Duration, Instant},
+    widget::tree::{self, Tree},
+    window, Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Renderer as _,
+    Shell, Size, Vector, Widget,
+};
+
+use crate::{
+    core::animation::Animation,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::circular_progress::{Appearance, StyleSheet};
+
+/// The default diameter of the ring.
+const DEFAULT_SIZE: f32 = 40.0;
+
+/// The default thickness of the ring.
+const DEFAULT_THICKNESS: f32 = 4.0;
+
+/// The time the arc of an indeterminate progress takes for one cycle.
+const CYCLE: Duration = Duration::from_millis(1400);
+
+/// The shortest and the longest arc of an indeterminate progress, in turns.
+const SWEEP: (f32, f32) = (0.1, 0.7);
+
+/// A ring showing the progress of a task.
+///
+/// A determinate progress fills an arc clockwise from the top, while an
+/// indeterminate one spins a growing and shrinking arc around the ring.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::CircularProgress;
+/// #
+/// let downloading = CircularProgress::new(0.42).label("42%");
+/// let connecting = CircularProgress::indeterminate().size(24.0).thickness(3.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CircularProgress<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// The progress between 0.0 and 1.0, if it is known.
+    progress: Option<f32>,
+    /// The diameter of the ring.
+    size: f32,
+    /// The thickness of the ring.
+    thickness: f32,
+    /// The label in the center of the ring.
+    label: Option<String>,
+    /// The text size of the label.
+    text_size: Option<f32>,
+    /// The font of the label.
+    font: Font,
+    /// The style of the circular progress.
+    style: <Theme as StyleSheet>::Style,
+    #[allow(missing_docs)]
+    backend: std::marker::PhantomData<B>,
+}
+
+impl<B, Theme> CircularProgress<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`CircularProgress`](CircularProgress) with the progress
+    /// between 0.0 and 1.0.
+    #[must_use]
+    pub fn new(progress: f32) -> Self {
+        Self::with_progress(Some(progress.clamp(0.0, 1.0)))
+    }
+
+    /// Creates a new [`CircularProgress`](CircularProgress) spinning while the
+    /// progress is not known.
+    #[must_use]
+    pub fn indeterminate() -> Self {
+        Self::with_progress(None)
+    }
+
+    /// Creates a new [`CircularProgress`](CircularProgress) with the progress.
+    fn with_progress(progress: Option<f32>) -> Self {
+        Self {
+            progress,
+            size: DEFAULT_SIZE,
+            thickness: DEFAULT_THICKNESS,
+            label: None,
+            text_size: None,
+            font: Font::default(),
+            style: <Theme as StyleSheet>::Style::default(),
+            backend: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the diameter of the [`CircularProgress`](CircularProgress).
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the thickness of the ring.
+    #[must_use]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the label in the center of the ring.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the text size of the label.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the label.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`CircularProgress`](CircularProgress).
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, B, Theme> Widget<Message, Renderer<B, Theme>> for CircularProgress<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(Length::Fixed(self.size))
+                .height(Length::Fixed(self.size))
+                .resolve(Size::new(self.size, self.size)),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer<B, Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.progress.is_none() {
+                let state = tree.state.downcast_mut::<State>();
+
+                if !state.animation.is_running(now) {
+                    state.animation.go(true, now);
+                }
+                state.animation.request_redraw(now, shell);
+                state.now = Some(now);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style
+        );
+
+        // The ring is stroked on its center line, so it stays inside the bounds
+        let radius = (bounds.width.min(bounds.height) - self.thickness) / 2.0;
+        if radius <= 0.0 {
+            return;
+        }
+
+        let (start_angle, end_angle) = match self.progress {
+            Some(progress) => arc(progress),
+            None => spin(
+                state
+                    .animation
+                    .progress(state.now.unwrap_or_else(Instant::now)),
+            ),
+        };
+
+        let mut frame = Frame::new(bounds.size());
+        let center = frame.center();
+
+        frame.stroke(
+            &Path::circle(center, radius),
+            Stroke::default()
+                .with_color(appearance.track_color)
+                .with_width(self.thickness),
+        );
+
+        if end_angle > start_angle {
+            frame.stroke(
+                &Path::new(|builder| {
+                    builder.arc(path::Arc {
+                        center,
+                        radius,
+                        start_angle,
+                        end_angle,
+                    });
+                }),
+                Stroke::default()
+                    .with_color(appearance.bar_color)
+                    .with_width(self.thickness)
+                    .with_line_cap(LineCap::Round),
+            );
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_primitive(frame.into_geometry().into_primitive());
+        });
+
+        if let Some(label) = &self.label {
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, B, Theme> From<CircularProgress<B, Theme>>
+    for Element<'a, Message, Renderer<B, Theme>>
+where
+    B: 'a + Backend + iced_graphics::backend::Text,
+    Theme: 'a + StyleSheet,
+{
+    fn from(circular_progress: CircularProgress<B, Theme>) -> Self {
+        Element::new(circular_progress)
+    }
+}
+
+/// The state of a [`CircularProgress`](CircularProgress).
+#[derive(Debug)]
+struct State {
+    /// The animation spinning an indeterminate progress.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            animation: Animation::new(CYCLE).repeat(true),
+            now: None,
+        }
+    }
+}
+
+/// Returns the start and end angle of the arc of a determinate progress,
+/// filling the ring clockwise from the top.
+fn arc(progress: f32) -> (f32, f32) {
+    (-FRAC_PI_2, -FRAC_PI_2 + progress * TAU)
+}
+
+/// Returns the start and end angle of the arc of an indeterminate progress at
+/// the time of its cycle.
+///
+/// The arc turns twice per cycle while it grows and shrinks again, so that
+/// two cycles join without a jump.
+fn spin(t: f32) -> (f32, f32) {
+    let (shortest, longest) = SWEEP;
+    let sweep = shortest + (longest - shortest) * (1.0 - (t * TAU).cos()) / 2.0;
+    let start = -FRAC_PI_2 + 2.0 * TAU * t;
+
+    (start, start + sweep * TAU)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, TAU};
+
+    use super::{arc, spin, SWEEP};
+
+    fn assert_close(actual: (f32, f32), expected: (f32, f32)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-4 && (actual.1 - expected.1).abs() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn arc_fills_clockwise_from_the_top() {
+        assert_close(arc(0.0), (-FRAC_PI_2, -FRAC_PI_2));
+        assert_close(arc(0.25), (-FRAC_PI_2, 0.0));
+        assert_close(arc(1.0), (-FRAC_PI_2, -FRAC_PI_2 + TAU));
+    }
+
+    #[test]
+    fn spin_grows_and_shrinks() {
+        let (shortest, longest) = SWEEP;
+
+        assert_close(spin(0.0), (-FRAC_PI_2, -FRAC_PI_2 + shortest * TAU));
+
+        let (start, end) = spin(0.5);
+        assert!((end - start - longest * TAU).abs() < 1e-4);
+    }
+
+    #[test]
+    fn spin_joins_two_cycles() {
+        let (start, end) = spin(1.0);
+        let (first_start, first_end) = spin(0.0);
+
+        assert!((start - first_start - 2.0 * TAU).abs() < 1e-4);
+        assert!((end - start - (first_end - first_start)).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,904 @@
+//! Edit code with line numbers and syntax highlighting.
+//!
+//! *This API requires the following crate features to be activated: code_editor*
+use std::ops::Range;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer,
+    text::{self, Hit},
+    touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Color, Element, Event, Font, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use crate::{
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::code_editor::{Appearance, StyleSheet};
+
+mod buffer;
+#[cfg(feature = "syntax_highlighting")]
+pub mod highlighter;
+
+use buffer::{byte_offset, Buffer, Position, Selection};
+
+/// The height of a line relative to the text size.
+const LINE_HEIGHT: f32 = 1.3;
+
+/// The horizontal padding around the line numbers in the gutter.
+const GUTTER_PADDING: f32 = 8.0;
+
+/// The width of the cursor.
+const CURSOR_WIDTH: f32 = 1.0;
+
+/// The indentation inserted by Tab.
+const INDENTATION: &str = "    ";
+
+/// The default height of a [`CodeEditor`](CodeEditor).
+const DEFAULT_HEIGHT: f32 = 300.0;
+
+/// The default padding around the code.
+const DEFAULT_PADDING: f32 = 4.0;
+
+/// A multi-line editor for code with a gutter showing the line numbers.
+///
+/// The code is selected by dragging over it or by moving the cursor with
+/// Shift held, and edited with the usual keys: Enter keeps the indentation of
+/// the line, Tab inserts four spaces and Ctrl+A, Ctrl+C, Ctrl+X and Ctrl+V
+/// select everything and use the clipboard. The editor is read-only without
+/// an [`on_change`](CodeEditor::on_change) function.
+///
+/// The columns of the code line up with a monospace [`Font`](Font), which
+/// iced doesn't bundle, so one should be set with
+/// [`font`](CodeEditor::font). With the `syntax_highlighting` feature, the
+/// code is colored by [`highlight`](CodeEditor::highlight).
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::code_editor;
+/// #
+/// # pub type CodeEditor<'a, Message> = code_editor::CodeEditor<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     CodeChanged(String),
+/// }
+///
+/// let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+///
+/// let code_editor = CodeEditor::new(code).on_change(Message::CodeChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CodeEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`CodeEditor`](CodeEditor) to focus it.
+    id: Option<Id>,
+    /// The edited code.
+    content: String,
+    /// The function producing the message with the edited code, if editable.
+    on_change: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The syntax and the theme highlighting the code, if any.
+    #[cfg(feature = "syntax_highlighting")]
+    syntax: Option<(String, highlighter::Theme)>,
+    /// The width of the [`CodeEditor`](CodeEditor).
+    width: Length,
+    /// The height of the [`CodeEditor`](CodeEditor).
+    height: Length,
+    /// The padding around the code.
+    padding: Padding,
+    /// The text size of the code and of the line numbers.
+    text_size: Option<f32>,
+    /// The font of the code and of the line numbers.
+    font: Font,
+    /// The style of the [`CodeEditor`](CodeEditor).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> CodeEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`CodeEditor`](CodeEditor) showing the code.
+    pub fn new(content: &str) -> Self {
+        Self {
+            id: None,
+            content: content.to_owned(),
+            on_change: None,
+            #[cfg(feature = "syntax_highlighting")]
+            syntax: None,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            padding: DEFAULT_PADDING.into(),
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the edited code, making
+    /// the [`CodeEditor`](CodeEditor) editable.
+    #[must_use]
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`CodeEditor`](CodeEditor) to focus it with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Highlights the code in the syntax of the file extension or the name
+    /// of the language, like `"rs"` or `"Rust"`, with the theme.
+    ///
+    /// *This API requires the following crate features to be activated:
+    /// syntax_highlighting*
+    #[cfg(feature = "syntax_highlighting")]
+    #[must_use]
+    pub fn highlight(mut self, syntax: impl Into<String>, theme: highlighter::Theme) -> Self {
+        self.syntax = Some((syntax.into(), theme));
+        self
+    }
+
+    /// Sets the width of the [`CodeEditor`](CodeEditor).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CodeEditor`](CodeEditor).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding around the code.
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the code and of the line numbers.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the code and of the line numbers.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`CodeEditor`](CodeEditor).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the areas of the [`CodeEditor`](CodeEditor) within its bounds.
+    fn metrics(&self, bounds: Rectangle, renderer: &Renderer) -> Metrics {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_count = Buffer::new(&self.content).line_count();
+
+        // The gutter fits at least two digits so it rarely grows while typing
+        let digits = line_count.to_string().len().max(2);
+        let gutter_width = renderer.measure_width(&"0".repeat(digits), text_size, self.font)
+            + 2.0 * GUTTER_PADDING;
+
+        let gutter = Rectangle {
+            width: gutter_width.min(bounds.width),
+            ..bounds
+        };
+        let content = Rectangle {
+            x: bounds.x + gutter.width,
+            width: bounds.width - gutter.width,
+            ..bounds
+        };
+        let code = Rectangle {
+            x: content.x + self.padding.left,
+            y: content.y + self.padding.top,
+            width: (content.width - self.padding.horizontal()).max(0.0),
+            height: (content.height - self.padding.vertical()).max(0.0),
+        };
+
+        Metrics {
+            text_size,
+            line_height: text_size * LINE_HEIGHT,
+            gutter,
+            content,
+            code,
+        }
+    }
+
+    /// Returns the width of the start of the line up to the column.
+    fn column_x(&self, renderer: &Renderer, text_size: f32, line: &str, column: usize) -> f32 {
+        renderer.measure_width(&line[..byte_offset(line, column)], text_size, self.font)
+    }
+
+    /// Returns the position in the code at the point.
+    fn position_at(
+        &self,
+        renderer: &Renderer,
+        metrics: &Metrics,
+        scroll: Vector,
+        point: Point,
+    ) -> Position {
+        let buffer = Buffer::new(&self.content);
+        let y = point.y - metrics.code.y + scroll.y;
+        let line = if y > 0.0 {
+            (y / metrics.line_height) as usize
+        } else {
+            0
+        };
+        let line = line.min(buffer.line_count() - 1);
+
+        let column = renderer
+            .hit_test(
+                buffer.line(line),
+                metrics.text_size,
+                self.font,
+                Size::INFINITY,
+                Point::new(point.x - metrics.code.x + scroll.x, metrics.text_size / 2.0),
+                true,
+            )
+            .map_or(0, Hit::cursor);
+
+        buffer.clamp(Position::new(line, column))
+    }
+
+    /// Scrolls the least possible to show the cursor.
+    fn reveal(&self, state: &mut State, renderer: &Renderer, metrics: &Metrics) {
+        let buffer = Buffer::new(&self.content);
+        let head = buffer.clamp(state.selection.head);
+
+        let top = head.line as f32 * metrics.line_height;
+        let bottom = top + metrics.line_height;
+        if top < state.scroll.y {
+            state.scroll.y = top;
+        } else if bottom > state.scroll.y + metrics.code.height {
+            state.scroll.y = bottom - metrics.code.height;
+        }
+
+        let x = self.column_x(
+            renderer,
+            metrics.text_size,
+            buffer.line(head.line),
+            head.column,
+        );
+        if x < state.scroll.x {
+            state.scroll.x = x;
+        } else if x + CURSOR_WIDTH > state.scroll.x + metrics.code.width {
+            state.scroll.x = x + CURSOR_WIDTH - metrics.code.width;
+        }
+
+        state.scroll.x = state.scroll.x.max(0.0);
+        state.scroll.y = state.scroll.y.max(0.0);
+    }
+
+    /// Scrolls by the delta, staying within the code.
+    fn scroll(&self, state: &mut State, renderer: &Renderer, metrics: &Metrics, delta: Vector) {
+        let buffer = Buffer::new(&self.content);
+
+        let max_y = buffer.line_count() as f32 * metrics.line_height - metrics.code.height;
+        let max_x = (0..buffer.line_count())
+            .map(|line| renderer.measure_width(buffer.line(line), metrics.text_size, self.font))
+            .fold(0.0, f32::max)
+            + CURSOR_WIDTH
+            - metrics.code.width;
+
+        state.scroll = Vector::new(
+            (state.scroll.x - delta.x).min(max_x).max(0.0),
+            (state.scroll.y - delta.y).min(max_y).max(0.0),
+        );
+    }
+
+    /// Replaces the code with the edited code and puts the cursor at the
+    /// position, if the [`CodeEditor`](CodeEditor) is editable.
+    fn edit(
+        &mut self,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+        (content, cursor): (String, Position),
+    ) {
+        let Some(on_change) = &self.on_change else {
+            return;
+        };
+
+        shell.publish(on_change(content.clone()));
+        self.content = content;
+        state.selection = Selection::caret(cursor);
+
+        #[cfg(feature = "syntax_highlighting")]
+        state.highlights.update(&self.content, self.syntax.as_ref());
+    }
+
+    /// Handles a key pressed while the [`CodeEditor`](CodeEditor) is focused.
+    ///
+    /// Returns true if the key was handled.
+    fn key_pressed(
+        &mut self,
+        state: &mut State,
+        key_code: keyboard::KeyCode,
+        metrics: &Metrics,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let modifiers = state.modifiers;
+        let extend = modifiers.shift();
+        let page = ((metrics.code.height / metrics.line_height) as usize).max(1);
+
+        let buffer = Buffer::new(&self.content);
+        let selection = state.selection;
+        let head = buffer.clamp(selection.head);
+
+        let moved = |position| Some(selection.moved(position, extend));
+        let (movement, edit) = match key_code {
+            keyboard::KeyCode::Left => (moved(buffer.left(head)), None),
+            keyboard::KeyCode::Right => (moved(buffer.right(head)), None),
+            keyboard::KeyCode::Up => (moved(buffer.up(head, 1)), None),
+            keyboard::KeyCode::Down => (moved(buffer.down(head, 1)), None),
+            keyboard::KeyCode::PageUp => (moved(buffer.up(head, page)), None),
+            keyboard::KeyCode::PageDown => (moved(buffer.down(head, page)), None),
+            keyboard::KeyCode::Home if modifiers.command() => (moved(Position::default()), None),
+            keyboard::KeyCode::Home => (moved(Position::new(head.line, 0)), None),
+            keyboard::KeyCode::End if modifiers.command() => (moved(buffer.end_of_text()), None),
+            keyboard::KeyCode::End => (
+                moved(Position::new(head.line, buffer.line_length(head.line))),
+                None,
+            ),
+            keyboard::KeyCode::A if modifiers.command() => (
+                Some(Selection {
+                    anchor: Position::default(),
+                    head: buffer.end_of_text(),
+                }),
+                None,
+            ),
+            keyboard::KeyCode::C if modifiers.command() => {
+                if !selection.is_empty() {
+                    clipboard.write(buffer.selected(&selection).to_owned());
+                }
+                (None, None)
+            }
+            keyboard::KeyCode::X if modifiers.command() && self.on_change.is_some() => {
+                if selection.is_empty() {
+                    (None, None)
+                } else {
+                    clipboard.write(buffer.selected(&selection).to_owned());
+                    (None, Some(buffer.replace(&selection, "")))
+                }
+            }
+            keyboard::KeyCode::V if modifiers.command() && self.on_change.is_some() => (
+                None,
+                clipboard
+                    .read()
+                    .map(|pasted| buffer.replace(&selection, &pasted.replace("\r\n", "\n"))),
+            ),
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                let indentation = buffer.indentation(head.line);
+                let indentation = &indentation[..byte_offset(indentation, head.column)];
+
+                (
+                    None,
+                    Some(buffer.replace(&selection, &format!("\n{indentation}"))),
+                )
+            }
+            keyboard::KeyCode::Tab => (None, Some(buffer.replace(&selection, INDENTATION))),
+            keyboard::KeyCode::Backspace => (None, buffer.backspace(&selection)),
+            keyboard::KeyCode::Delete => (None, buffer.delete(&selection)),
+            keyboard::KeyCode::Escape => {
+                state.focus.unfocus();
+                (None, None)
+            }
+            _ => return false,
+        };
+
+        if let Some(selection) = movement {
+            state.selection = selection;
+        }
+        if let Some(edit) = edit {
+            self.edit(state, shell, edit);
+        }
+
+        true
+    }
+
+    /// Returns the highlighted spans of the line at the index, if the code is
+    /// highlighted.
+    #[cfg(feature = "syntax_highlighting")]
+    fn highlighted<'s>(&self, state: &'s State, index: usize) -> Option<&'s [Span]> {
+        if state.highlights.text != self.content {
+            return None;
+        }
+
+        state.highlights.lines.get(index).map(Vec::as_slice)
+    }
+
+    /// Returns the highlighted spans of the line at the index, if the code is
+    /// highlighted.
+    #[cfg(not(feature = "syntax_highlighting"))]
+    #[allow(clippy::unused_self)]
+    fn highlighted<'s>(&self, _state: &'s State, _index: usize) -> Option<&'s [Span]> {
+        None
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for CodeEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        #[allow(unused_mut)]
+        let mut state = State::default();
+
+        #[cfg(feature = "syntax_highlighting")]
+        state.highlights.update(&self.content, self.syntax.as_ref());
+
+        tree::State::new(state)
+    }
+
+    #[allow(unused_variables)]
+    fn diff(&self, tree: &mut Tree) {
+        #[cfg(feature = "syntax_highlighting")]
+        tree.state
+            .downcast_mut::<State>()
+            .highlights
+            .update(&self.content, self.syntax.as_ref());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(bounds, renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let pressed = bounds.contains(cursor_position);
+                state.focus.press(pressed);
+
+                if !pressed {
+                    return event::Status::Ignored;
+                }
+
+                let position = self.position_at(renderer, &metrics, state.scroll, cursor_position);
+                state.selection = state.selection.moved(position, state.modifiers.shift());
+                state.dragging = true;
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.dragging =>
+            {
+                let position = self.position_at(renderer, &metrics, state.scroll, position);
+                state.selection = state.selection.moved(position, true);
+                self.reveal(state, renderer, &metrics);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging =>
+            {
+                state.dragging = false;
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        let step = 3.0 * metrics.line_height;
+                        Vector::new(x * step, y * step)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+                let delta = if state.modifiers.shift() {
+                    Vector::new(delta.y, delta.x)
+                } else {
+                    delta
+                };
+
+                self.scroll(state, renderer, &metrics, delta);
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+
+            Event::Keyboard(keyboard::Event::CharacterReceived(character))
+                if state.focus.is_focused()
+                    && !state.modifiers.command()
+                    && !character.is_control() =>
+            {
+                let edit =
+                    Buffer::new(&self.content).replace(&state.selection, &character.to_string());
+                self.edit(state, shell, edit);
+                self.reveal(state, renderer, &metrics);
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.focus.is_focused() =>
+            {
+                if !self.key_pressed(state, key_code, &metrics, clipboard, shell) {
+                    return event::Status::Ignored;
+                }
+                self.reveal(state, renderer, &metrics);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let metrics = self.metrics(layout.bounds(), renderer);
+
+        if state.dragging || metrics.content.contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(bounds, renderer);
+        let is_focused = state.focus.is_focused();
+
+        // Dragging a selection keeps the editor focused instead of pressed
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, is_focused, false),
+            self.style
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        // The gutter follows the rounded corners on the left
+        let [top_left, _, _, bottom_left] = <[f32; 4]>::from(appearance.border_radius);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: metrics.gutter,
+                border_radius: [top_left, 0.0, 0.0, bottom_left].into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.gutter_background,
+        );
+
+        let buffer = Buffer::new(&self.content);
+        let head = buffer.clamp(state.selection.head);
+        let (start, end) = state.selection.range();
+        let (start, end) = (buffer.clamp(start), buffer.clamp(end));
+
+        let line_y =
+            |line: usize| metrics.code.y + line as f32 * metrics.line_height - state.scroll.y;
+        let first = (state.scroll.y / metrics.line_height) as usize;
+        let visible = first
+            ..buffer
+                .line_count()
+                .min(first + (bounds.height / metrics.line_height) as usize + 2);
+
+        let numbers = Rectangle {
+            y: metrics.code.y,
+            height: metrics.code.height,
+            ..metrics.gutter
+        };
+        renderer.with_layer(numbers, |renderer| {
+            for line in visible.clone() {
+                renderer.fill_text(text::Text {
+                    content: &(line + 1).to_string(),
+                    bounds: Rectangle {
+                        x: numbers.x + numbers.width - GUTTER_PADDING,
+                        y: line_y(line) + metrics.line_height / 2.0,
+                        ..numbers
+                    },
+                    size: metrics.text_size,
+                    color: if line == head.line {
+                        appearance.current_line_number_color
+                    } else {
+                        appearance.line_number_color
+                    },
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Right,
+                    vertical_alignment: Vertical::Center,
+                });
+            }
+        });
+
+        renderer.with_layer(metrics.content, |renderer| {
+            let x = metrics.code.x - state.scroll.x;
+
+            for line in visible {
+                let content = buffer.line(line);
+                let y = line_y(line);
+
+                if line == head.line && state.selection.is_empty() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y,
+                                height: metrics.line_height,
+                                ..metrics.content
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.current_line_color,
+                    );
+                }
+
+                if (start.line..=end.line).contains(&line) && start != end {
+                    let from = if line == start.line { start.column } else { 0 };
+                    let to = if line == end.line {
+                        end.column
+                    } else {
+                        buffer.line_length(line)
+                    };
+                    let from = self.column_x(renderer, metrics.text_size, content, from);
+                    let to = self.column_x(renderer, metrics.text_size, content, to);
+
+                    // The selected line breaks show as a bit of extra width
+                    let line_break = if line == end.line {
+                        0.0
+                    } else {
+                        metrics.text_size / 3.0
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x + from,
+                                y,
+                                width: to - from + line_break,
+                                height: metrics.line_height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.selection_color,
+                    );
+                }
+
+                let plain = [(appearance.text_color, 0..content.len())];
+                let spans = self.highlighted(state, line).unwrap_or(&plain);
+
+                for (color, range) in spans {
+                    let Some(span) = content.get(range.clone()) else {
+                        continue;
+                    };
+                    let span_x = x + renderer.measure_width(
+                        &content[..range.start],
+                        metrics.text_size,
+                        self.font,
+                    );
+                    if span_x > metrics.content.x + metrics.content.width {
+                        break;
+                    }
+
+                    renderer.fill_text(text::Text {
+                        content: span,
+                        bounds: Rectangle {
+                            x: span_x,
+                            y: y + metrics.line_height / 2.0,
+                            width: f32::INFINITY,
+                            height: metrics.line_height,
+                        },
+                        size: metrics.text_size,
+                        color: *color,
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Center,
+                    });
+                }
+
+                if is_focused && line == head.line {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x + self.column_x(
+                                    renderer,
+                                    metrics.text_size,
+                                    content,
+                                    head.column,
+                                ),
+                                y,
+                                width: CURSOR_WIDTH,
+                                height: metrics.line_height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.cursor_color,
+                    );
+                }
+            }
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Renderer> From<CodeEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(code_editor: CodeEditor<'a, Message, Renderer>) -> Self {
+        Element::new(code_editor)
+    }
+}
+
+/// A colored span of a line, as a byte range.
+type Span = (Color, Range<usize>);
+
+/// The areas of a [`CodeEditor`](CodeEditor).
+#[derive(Clone, Copy, Debug)]
+struct Metrics {
+    /// The text size of the code.
+    text_size: f32,
+    /// The height of a line.
+    line_height: f32,
+    /// The gutter showing the line numbers.
+    gutter: Rectangle,
+    /// The area right of the gutter.
+    content: Rectangle,
+    /// The area of the code within the padding.
+    code: Rectangle,
+}
+
+/// The state of a [`CodeEditor`](CodeEditor).
+#[derive(Debug, Default)]
+struct State {
+    /// The keyboard focus.
+    focus: Focus,
+    /// The selected code.
+    selection: Selection,
+    /// The offset the code is scrolled by.
+    scroll: Vector,
+    /// Whether a selection is dragged.
+    dragging: bool,
+    /// The keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// The highlighted code.
+    #[cfg(feature = "syntax_highlighting")]
+    highlights: Highlights,
+}
+
+/// The cached highlights of the code.
+#[cfg(feature = "syntax_highlighting")]
+#[derive(Debug, Default)]
+struct Highlights {
+    /// The highlighted code.
+    text: String,
+    /// The syntax and the theme the code is highlighted with.
+    syntax: Option<(String, highlighter::Theme)>,
+    /// The colored spans of each line.
+    lines: Vec<highlighter::Spans>,
+}
+
+#[cfg(feature = "syntax_highlighting")]
+impl Highlights {
+    /// Highlights the code, unless it is already highlighted the same way.
+    fn update(&mut self, text: &str, syntax: Option<&(String, highlighter::Theme)>) {
+        if self.text == text && self.syntax.as_ref() == syntax {
+            return;
+        }
+
+        self.lines = syntax.map_or_else(Vec::new, |(syntax, theme)| {
+            highlighter::highlight(text, syntax, *theme)
+        });
+        self.text = text.to_owned();
+        self.syntax = syntax.cloned();
+    }
+}
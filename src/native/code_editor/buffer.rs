@@ -0,0 +1,314 @@
+//! Move through and edit the lines of a text.
+
+/// A position in a text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+    /// The index of the character in the line.
+    pub column: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`](Position).
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// A selection from an anchor to the position of the cursor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    /// The position the selection started at.
+    pub anchor: Position,
+    /// The position of the cursor.
+    pub head: Position,
+}
+
+impl Selection {
+    /// Creates an empty [`Selection`](Selection) with the cursor at the
+    /// position.
+    pub const fn caret(position: Position) -> Self {
+        Self {
+            anchor: position,
+            head: position,
+        }
+    }
+
+    /// Returns true if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.head
+    }
+
+    /// Returns the start and the end of the [`Selection`](Selection).
+    pub fn range(&self) -> (Position, Position) {
+        if self.anchor <= self.head {
+            (self.anchor, self.head)
+        } else {
+            (self.head, self.anchor)
+        }
+    }
+
+    /// Moves the cursor to the position, keeping the anchor if `extend` is
+    /// true.
+    pub const fn moved(self, position: Position, extend: bool) -> Self {
+        if extend {
+            Self {
+                anchor: self.anchor,
+                head: position,
+            }
+        } else {
+            Self::caret(position)
+        }
+    }
+}
+
+/// The lines of a text.
+#[derive(Debug)]
+pub struct Buffer<'a> {
+    /// The text.
+    text: &'a str,
+    /// The lines of the text, without their line breaks.
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Buffer<'a> {
+    /// Creates a new [`Buffer`](Buffer) of the text.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            lines: text.split('\n').collect(),
+        }
+    }
+
+    /// Returns the number of lines.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the line at the index, without its line break.
+    pub fn line(&self, index: usize) -> &'a str {
+        self.lines.get(index).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of characters in the line at the index.
+    pub fn line_length(&self, index: usize) -> usize {
+        self.line(index).chars().count()
+    }
+
+    /// Returns the nearest position inside of the text.
+    pub fn clamp(&self, position: Position) -> Position {
+        let line = position.line.min(self.line_count() - 1);
+
+        Position::new(line, position.column.min(self.line_length(line)))
+    }
+
+    /// Returns the byte offset of the position in the text.
+    pub fn offset(&self, position: Position) -> usize {
+        let position = self.clamp(position);
+        let line_start: usize = self.lines[..position.line]
+            .iter()
+            .map(|line| line.len() + 1)
+            .sum();
+
+        line_start + byte_offset(self.line(position.line), position.column)
+    }
+
+    /// Returns the selected text.
+    pub fn selected(&self, selection: &Selection) -> &'a str {
+        let (start, end) = selection.range();
+
+        &self.text[self.offset(start)..self.offset(end)]
+    }
+
+    /// Returns the text with the selection replaced by the insertion, and the
+    /// position of the cursor after the insertion.
+    pub fn replace(&self, selection: &Selection, insertion: &str) -> (String, Position) {
+        let (start, end) = selection.range();
+        let (start, end) = (self.clamp(start), self.clamp(end));
+
+        let mut text = String::with_capacity(self.text.len() + insertion.len());
+        text.push_str(&self.text[..self.offset(start)]);
+        text.push_str(insertion);
+        text.push_str(&self.text[self.offset(end)..]);
+
+        let inserted_lines = insertion.split('\n').count() - 1;
+        let last_line = insertion.rsplit('\n').next().unwrap_or_default();
+        let column = if inserted_lines == 0 {
+            start.column + last_line.chars().count()
+        } else {
+            last_line.chars().count()
+        };
+
+        (text, Position::new(start.line + inserted_lines, column))
+    }
+
+    /// Returns the text with the selection or the character before the cursor
+    /// removed, and the new position of the cursor, if anything was removed.
+    pub fn backspace(&self, selection: &Selection) -> Option<(String, Position)> {
+        if selection.is_empty() {
+            let head = self.clamp(selection.head);
+            let previous = self.left(head);
+
+            (previous != head)
+                .then(|| self.replace(&Selection::caret(head).moved(previous, true), ""))
+        } else {
+            Some(self.replace(selection, ""))
+        }
+    }
+
+    /// Returns the text with the selection or the character after the cursor
+    /// removed, and the new position of the cursor, if anything was removed.
+    pub fn delete(&self, selection: &Selection) -> Option<(String, Position)> {
+        if selection.is_empty() {
+            let head = self.clamp(selection.head);
+            let next = self.right(head);
+
+            (next != head).then(|| self.replace(&Selection::caret(head).moved(next, true), ""))
+        } else {
+            Some(self.replace(selection, ""))
+        }
+    }
+
+    /// Returns the position before the position, at the end of the previous
+    /// line at the start of a line.
+    pub fn left(&self, position: Position) -> Position {
+        let position = self.clamp(position);
+
+        match (position.line, position.column) {
+            (0, 0) => position,
+            (line, 0) => Position::new(line - 1, self.line_length(line - 1)),
+            (line, column) => Position::new(line, column - 1),
+        }
+    }
+
+    /// Returns the position after the position, at the start of the next line
+    /// at the end of a line.
+    pub fn right(&self, position: Position) -> Position {
+        let position = self.clamp(position);
+
+        if position.column < self.line_length(position.line) {
+            Position::new(position.line, position.column + 1)
+        } else if position.line + 1 < self.line_count() {
+            Position::new(position.line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    /// Returns the position the lines above the position.
+    pub fn up(&self, position: Position, lines: usize) -> Position {
+        position
+            .line
+            .checked_sub(lines)
+            .map_or_else(Position::default, |line| {
+                self.clamp(Position::new(line, position.column))
+            })
+    }
+
+    /// Returns the position the lines below the position.
+    pub fn down(&self, position: Position, lines: usize) -> Position {
+        let line = position.line + lines;
+
+        if line < self.line_count() {
+            self.clamp(Position::new(line, position.column))
+        } else {
+            self.end_of_text()
+        }
+    }
+
+    /// Returns the position at the end of the text.
+    pub fn end_of_text(&self) -> Position {
+        let line = self.line_count() - 1;
+
+        Position::new(line, self.line_length(line))
+    }
+
+    /// Returns the whitespace at the start of the line at the index.
+    pub fn indentation(&self, index: usize) -> &'a str {
+        let line = self.line(index);
+
+        &line[..line.len() - line.trim_start().len()]
+    }
+}
+
+/// Returns the byte offset of the character at the column of the line.
+pub fn byte_offset(line: &str, column: usize) -> usize {
+    line.char_indices()
+        .nth(column)
+        .map_or(line.len(), |(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Buffer, Position, Selection};
+
+    const TEXT: &str = "fn main() {\n    println!(\"ä\");\n}";
+
+    #[test]
+    fn positions_are_clamped_to_the_text() {
+        let buffer = Buffer::new(TEXT);
+
+        assert_eq!(buffer.line_count(), 3);
+        assert_eq!(buffer.clamp(Position::new(0, 40)), Position::new(0, 11));
+        assert_eq!(buffer.clamp(Position::new(9, 0)), Position::new(2, 0));
+        assert_eq!(
+            Buffer::new("").clamp(Position::new(1, 1)),
+            Position::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn offsets_count_bytes() {
+        let buffer = Buffer::new(TEXT);
+
+        assert_eq!(buffer.offset(Position::new(1, 0)), 12);
+        assert_eq!(buffer.offset(Position::new(1, 14)), 12 + 14);
+        assert_eq!(buffer.offset(Position::new(1, 15)), 12 + 16);
+    }
+
+    #[test]
+    fn replaces_across_lines() {
+        let buffer = Buffer::new(TEXT);
+        let selection = Selection {
+            anchor: Position::new(1, 4),
+            head: Position::new(0, 3),
+        };
+
+        assert_eq!(buffer.selected(&selection), "main() {\n    ");
+
+        let (text, cursor) = buffer.replace(&selection, "run() {\n  ");
+        assert_eq!(text, "fn run() {\n  println!(\"ä\");\n}");
+        assert_eq!(cursor, Position::new(1, 2));
+    }
+
+    #[test]
+    fn backspace_joins_lines() {
+        let buffer = Buffer::new(TEXT);
+
+        let (text, cursor) = buffer
+            .backspace(&Selection::caret(Position::new(2, 0)))
+            .expect("There is a line break to remove");
+        assert_eq!(text, "fn main() {\n    println!(\"ä\");}");
+        assert_eq!(cursor, Position::new(1, 18));
+
+        assert!(buffer
+            .backspace(&Selection::caret(Position::new(0, 0)))
+            .is_none());
+        assert!(buffer
+            .delete(&Selection::caret(Position::new(2, 1)))
+            .is_none());
+    }
+
+    #[test]
+    fn moves_between_lines() {
+        let buffer = Buffer::new(TEXT);
+
+        assert_eq!(buffer.right(Position::new(0, 11)), Position::new(1, 0));
+        assert_eq!(buffer.left(Position::new(1, 0)), Position::new(0, 11));
+        assert_eq!(buffer.down(Position::new(1, 10), 1), Position::new(2, 1));
+        assert_eq!(buffer.down(Position::new(1, 10), 5), Position::new(2, 1));
+        assert_eq!(buffer.up(Position::new(1, 10), 5), Position::new(0, 0));
+        assert_eq!(buffer.indentation(1), "    ");
+    }
+}
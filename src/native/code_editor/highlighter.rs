@@ -0,0 +1,122 @@
+//! Highlight the syntax of code with [`syntect`].
+//!
+//! *This API requires the following crate features to be activated: syntax_highlighting*
+use std::{fmt, ops::Range};
+
+use iced_native::Color;
+use lazy_static::lazy_static;
+use syntect::{
+    easy::HighlightLines, highlighting::ThemeSet, parsing::SyntaxSet, util::LinesWithEndings,
+};
+
+lazy_static! {
+    /// The syntaxes bundled with [`syntect`].
+    static ref SYNTAXES: SyntaxSet = SyntaxSet::load_defaults_newlines();
+
+    /// The themes bundled with [`syntect`].
+    static ref THEMES: ThemeSet = ThemeSet::load_defaults();
+}
+
+/// The colored spans of a line, as byte ranges without the line break.
+pub type Spans = Vec<(Color, Range<usize>)>;
+
+/// A theme coloring the highlighted code.
+///
+/// The background of the [`CodeEditor`](super::CodeEditor) is set by its
+/// style, so a dark theme goes along with a dark style.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Theme {
+    /// The dark ocean theme of base16.
+    #[default]
+    Base16Ocean,
+    /// The dark eighties theme of base16.
+    Base16Eighties,
+    /// The dark mocha theme of base16.
+    Base16Mocha,
+    /// The light theme inspired by GitHub.
+    InspiredGitHub,
+    /// The dark Solarized theme.
+    SolarizedDark,
+    /// The light Solarized theme.
+    SolarizedLight,
+}
+
+impl Theme {
+    /// All the themes.
+    pub const ALL: [Self; 6] = [
+        Self::Base16Ocean,
+        Self::Base16Eighties,
+        Self::Base16Mocha,
+        Self::InspiredGitHub,
+        Self::SolarizedDark,
+        Self::SolarizedLight,
+    ];
+
+    /// Returns the key of the theme in the bundled themes.
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Base16Ocean => "base16-ocean.dark",
+            Self::Base16Eighties => "base16-eighties.dark",
+            Self::Base16Mocha => "base16-mocha.dark",
+            Self::InspiredGitHub => "InspiredGitHub",
+            Self::SolarizedDark => "Solarized (dark)",
+            Self::SolarizedLight => "Solarized (light)",
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Base16Ocean => "Ocean",
+            Self::Base16Eighties => "Eighties",
+            Self::Base16Mocha => "Mocha",
+            Self::InspiredGitHub => "Inspired GitHub",
+            Self::SolarizedDark => "Solarized Dark",
+            Self::SolarizedLight => "Solarized Light",
+        })
+    }
+}
+
+/// Returns the colored spans of each line of the text in the syntax of the
+/// file extension or the name of the language.
+///
+/// Unknown syntaxes are highlighted as plain text.
+#[must_use]
+pub fn highlight(text: &str, syntax: &str, theme: Theme) -> Vec<Spans> {
+    let syntax = SYNTAXES
+        .find_syntax_by_extension(syntax)
+        .or_else(|| SYNTAXES.find_syntax_by_token(syntax))
+        .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+    let Some(theme) = THEMES.themes.get(theme.key()) else {
+        return Vec::new();
+    };
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    let mut lines = Vec::new();
+
+    for line in LinesWithEndings::from(text) {
+        // The lines after a failure keep the color of the editor
+        let Ok(ranges) = highlighter.highlight_line(line, &SYNTAXES) else {
+            break;
+        };
+
+        let mut start = 0;
+        let mut spans = Spans::new();
+        for (style, span) in ranges {
+            let end = start + span.trim_end_matches(['\r', '\n']).len();
+            if end > start {
+                let color = style.foreground;
+                spans.push((
+                    Color::from_rgba8(color.r, color.g, color.b, f32::from(color.a) / 255.0),
+                    start..end,
+                ));
+            }
+            start += span.len();
+        }
+
+        lines.push(spans);
+    }
+
+    lines
+}
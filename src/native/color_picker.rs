@@ -10,27 +10,33 @@ use iced_native::{
 use iced_native::{
     widget::{
         tree::{self, Tag},
-        Tree,
+        Id, Operation, Tree,
     },
     Element, Widget,
 };
 
 pub use crate::style::color_picker::{Appearance, StyleSheet};
 
+use super::operation::Request;
 use super::overlay::color_picker::{
     self, ColorBarDragged, ColorPickerOverlay, ColorPickerOverlayButtons,
 };
+#[cfg(feature = "accessibility")]
+use crate::core::{
+    accessibility::{Node, Role},
+    color::HexString,
+};
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking colors.
 ///
 /// # Example
 /// ```ignore
-/// # use iced_aw::pure::color_picker;
+/// # use iced_aw::color_picker;
 /// # use iced_native::{Color, renderer::Null};
-/// # use iced_pure::widget::{button, Button, Text};
+/// # use iced_native::widget::{button, Button, Text};
 /// #
-/// # pub type ColorPicker<'a, Message> = iced_aw::pure::ColorPicker<'a, Message, Null>;
+/// # pub type ColorPicker<'a, Message> = iced_aw::ColorPicker<'a, Message, Null>;
 /// #[derive(Clone, Debug)]
 /// enum Message {
 ///     Open,
@@ -60,7 +66,8 @@ where
     color: Color,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer<B, Theme>>,
-    /// The message that is send if the cancel button of the [`ColorPickerOverlay`](ColorPickerOverlay) is pressed.
+    /// The message that is send if the cancel button of the [`ColorPickerOverlay`](ColorPickerOverlay) is pressed
+    /// or the overlay is dismissed.
     on_cancel: Message,
     /// The function thet produces a message when the submit button of the [`ColorPickerOverlay`](ColorPickerOverlay) is pressed.
     on_submit: Box<dyn Fn(Color) -> Message>,
@@ -68,6 +75,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Renderer<B, Theme>>,
+    /// The id of the [`ColorPicker`](ColorPicker) used to open it.
+    id: Option<Id>,
 }
 
 impl<'a, Message, B, Theme> ColorPicker<'a, Message, B, Theme>
@@ -81,10 +90,10 @@ where
     /// It expects:
     ///     * if the overlay of the color picker is visible.
     ///     * the initial color to show.
-    ///     * the underlay [`Element`](iced_pure::Element) on which this [`ColorPicker`](ColorPicker)
+    ///     * the underlay [`Element`](iced_native::Element) on which this [`ColorPicker`](ColorPicker)
     ///         will be wrapped around.
     ///     * a message that will be send when the cancel button of the [`ColorPicker`](ColorPicker)
-    ///         is pressed.
+    ///         is pressed, the user clicks outside of it or presses the ESC key.
     ///     * a function that will be called when the submit button of the [`ColorPicker`](ColorPicker)
     ///         is pressed, which takes the picked [`Color`](iced_native::Color) value.
     pub fn new<U, F>(
@@ -106,19 +115,44 @@ where
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: ColorPickerOverlayButtons::default().into(),
+            id: None,
         }
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`ColorPicker`](ColorPicker) to
+    /// open or close its overlay with the commands of
+    /// [`operation`](crate::native::operation).
+    ///
+    /// Closing only hides an overlay opened by such a command, not one shown
+    /// by the application.
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`](ColorPicker).
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
         self.style = style;
         self
     }
+
+    /// Describes the [`ColorPicker`](ColorPicker) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, _state: &Tree) -> Node {
+        Node::new(Role::ColorWell)
+            .value(self.color.as_hex_string())
+            .expanded(self.show_picker)
+    }
 }
 
 /// The state of the [`ColorPicker`](ColorPicker).
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: color_picker::State,
@@ -238,6 +272,22 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<B, Theme>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let picker_state: &mut State = state.state.downcast_mut();
+        picker_state.overlay_state.is_opened = Request::receive(operation, self.id.as_ref())
+            .is_open(picker_state.overlay_state.is_opened);
+
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -246,7 +296,7 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer<B, Theme>>> {
         let picker_state: &mut State = state.state.downcast_mut();
 
-        if !self.show_picker {
+        if !self.show_picker && !picker_state.overlay_state.is_opened {
             return self
                 .underlay
                 .as_widget_mut()
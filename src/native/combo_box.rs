@@ -0,0 +1,531 @@
+//! Type into a field and pick one of the options matching the text.
+//!
+//! *This API requires the following crate features to be activated: combo_box*
+use std::{fmt::Display, ops::Range};
+
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    widget::{
+        text_input,
+        tree::{self, Tree},
+        Operation, TextInput,
+    },
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use crate::native::overlay::ComboBoxOverlay;
+pub use crate::style::combo_box::{Appearance, StyleSheet};
+
+/// The default number of options shown at once in the dropdown.
+const DEFAULT_VISIBLE_OPTIONS: usize = 6;
+
+/// Whether the text of a [`ComboBox`](ComboBox) may be anything or has to be
+/// one of its options.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Any text may be entered, the options only suggest some.
+    #[default]
+    Free,
+    /// The text has to be one of the options. Enter picks the best match and
+    /// other text is cleared when the field loses focus.
+    Strict,
+}
+
+/// A text input whose dropdown filters a list of options as the user types.
+///
+/// The options containing the typed text are listed below the field while it
+/// is focused, with the matching part highlighted. The arrow keys move the
+/// highlight and Enter picks the highlighted option, as does a click. Escape
+/// closes the dropdown until the text changes.
+///
+/// The text is owned by the application: the combo box produces a message when
+/// the user types and another one when the user picks an option, which usually
+/// sets the text to it.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::combo_box;
+/// #
+/// # pub type ComboBox<'a, T, Message> = combo_box::ComboBox<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Typed(String),
+///     Picked(&'static str),
+/// }
+///
+/// let fruits = ["Apple", "Banana", "Cherry"];
+///
+/// let combo_box = ComboBox::new(&fruits, "Fruit", "an", Message::Typed, Message::Picked)
+///     .mode(combo_box::Mode::Strict);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The options to pick from.
+    options: &'a [T],
+    /// The text in the field.
+    value: String,
+    /// Whether the text may be anything or has to be one of the options.
+    mode: Mode,
+    /// The function producing the message when the text changes.
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    /// The function producing the message when the user picks an option.
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    /// The field the text is typed into.
+    content: TextInput<'a, Message, Renderer>,
+    /// The number of options shown at once in the dropdown.
+    visible_options: usize,
+    /// The text size of the options.
+    text_size: Option<f32>,
+    /// The font of the options.
+    font: Font,
+    /// The style of the dropdown.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`ComboBox`](ComboBox).
+    ///
+    /// It expects:
+    ///     * the options to pick from
+    ///     * the placeholder shown while the field is empty
+    ///     * the text in the field
+    ///     * the function producing the message when the text changes
+    ///     * the function producing the message when the user picks an option
+    pub fn new<F, S>(
+        options: &'a [T],
+        placeholder: &str,
+        value: &str,
+        on_input: F,
+        on_select: S,
+    ) -> Self
+    where
+        F: 'a + Fn(String) -> Message + Clone,
+        S: 'a + Fn(T) -> Message,
+    {
+        ComboBox {
+            options,
+            value: value.to_owned(),
+            mode: Mode::default(),
+            on_input: Box::new(on_input.clone()),
+            on_select: Box::new(on_select),
+            content: TextInput::new(placeholder, value).on_input(on_input),
+            visible_options: DEFAULT_VISIBLE_OPTIONS,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether the text may be anything or has to be one of the options.
+    #[must_use]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the number of options shown at once in the dropdown.
+    ///
+    /// The dropdown scrolls through the other ones.
+    #[must_use]
+    pub fn visible_options(mut self, visible_options: usize) -> Self {
+        self.visible_options = visible_options.max(1);
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.content = self.content.width(width);
+        self
+    }
+
+    /// Sets the padding of the field of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.content = self.content.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self.content = self.content.size(text_size);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self.content = self.content.font(font);
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the [`ComboBox`](ComboBox) to focus
+    /// it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.content = self.content.id(id);
+        self
+    }
+
+    /// Sets the message produced when Enter is pressed while no option is
+    /// highlighted.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.content = self.content.on_submit(message);
+        self
+    }
+
+    /// Sets the style of the dropdown of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the field of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.content = self.content.style(style);
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree {
+            tag: self.content.tag(),
+            state: self.content.state(),
+            children: self.content.children(),
+        }]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &[&self.content],
+            |state, content| content.diff(state),
+            |&content| Tree {
+                tag: content.tag(),
+                state: content.state(),
+                children: content.children(),
+            },
+        );
+    }
+
+    fn width(&self) -> Length {
+        Widget::width(&self.content)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        state.sync(&self.value, self.mode);
+
+        let child = &mut tree.children[0];
+        let was_focused = child.state.downcast_ref::<text_input::State>().is_focused();
+
+        let status = self.content.on_event(
+            child,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let focused = child.state.downcast_ref::<text_input::State>().is_focused();
+
+        if focused && !was_focused {
+            state.dismissed = false;
+        }
+
+        // Strict combo boxes drop the text not naming an option
+        if was_focused
+            && !focused
+            && self.mode == Mode::Strict
+            && !self
+                .options
+                .iter()
+                .any(|option| option.to_string() == self.value)
+        {
+            shell.publish((self.on_input)(String::new()));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        self.content.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            None,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+        let state = tree.state.downcast_mut::<State>();
+        state.sync(&self.value, self.mode);
+
+        if !focused || state.dismissed {
+            return None;
+        }
+
+        let matches = matches(self.options, &self.value);
+        if matches.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(
+            ComboBoxOverlay::new(
+                self.options,
+                matches,
+                state,
+                self.on_select.as_ref(),
+                None,
+                bounds.size(),
+                self.visible_options,
+                self.text_size,
+                self.font,
+                self.style,
+            )
+            .overlay(bounds.position()),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Element::new(combo_box)
+    }
+}
+
+/// The state of a [`ComboBox`](ComboBox).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The text the dropdown was last filtered with.
+    pub(crate) value: String,
+    /// The position of the highlighted option among the matching ones.
+    pub(crate) highlighted: Option<usize>,
+    /// The position of the first option shown in the dropdown.
+    pub(crate) offset: usize,
+    /// Whether the dropdown was closed until the text changes.
+    pub(crate) dismissed: bool,
+}
+
+impl State {
+    /// Starts over with the first option when the text changed.
+    ///
+    /// Strict combo boxes always highlight an option, so that Enter picks the
+    /// best match.
+    pub(crate) fn sync(&mut self, value: &str, mode: Mode) {
+        if self.value == value {
+            return;
+        }
+
+        self.value = value.to_owned();
+        self.highlighted = (mode == Mode::Strict).then_some(0);
+        self.offset = 0;
+        self.dismissed = false;
+    }
+}
+
+/// An option containing the typed text.
+#[derive(Clone, Debug)]
+pub(crate) struct Match {
+    /// The index of the option.
+    pub(crate) index: usize,
+    /// The label of the option.
+    pub(crate) label: String,
+    /// The byte range of the typed text in the label.
+    pub(crate) range: Range<usize>,
+}
+
+/// Returns the options containing the text, ignoring the case.
+pub(crate) fn matches<T: Display>(options: &[T], text: &str) -> Vec<Match> {
+    options
+        .iter()
+        .enumerate()
+        .filter_map(|(index, option)| {
+            let label = option.to_string();
+            find(&label, text).map(|range| Match {
+                index,
+                label,
+                range,
+            })
+        })
+        .collect()
+}
+
+/// Returns all the options, marking the text where they contain it, ignoring
+/// the case.
+#[cfg(feature = "search_bar")]
+pub(crate) fn highlights<T: Display>(options: &[T], text: &str) -> Vec<Match> {
+    options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let label = option.to_string();
+            let range = find(&label, text).unwrap_or(0..0);
+
+            Match {
+                index,
+                label,
+                range,
+            }
+        })
+        .collect()
+}
+
+/// Returns the byte range of the first occurrence of the text in the label,
+/// ignoring the case.
+fn find(label: &str, text: &str) -> Option<Range<usize>> {
+    let same = |a: char, b: char| a == b || a.to_lowercase().eq(b.to_lowercase());
+
+    label
+        .char_indices()
+        .map(|(start, _)| start)
+        .chain(std::iter::once(label.len()))
+        .find_map(|start| {
+            let mut end = start;
+            let mut rest = label[start..].chars();
+
+            for expected in text.chars() {
+                let found = rest.next().filter(|&found| same(found, expected))?;
+                end += found.len_utf8();
+            }
+
+            Some(start..end)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find;
+
+    #[test]
+    fn find_ignores_the_case() {
+        assert_eq!(find("Banana", "nan"), Some(2..5));
+        assert_eq!(find("Banana", "BAN"), Some(0..3));
+        assert_eq!(find("Banana", "nab"), None);
+    }
+
+    #[test]
+    fn find_matches_everything_with_empty_text() {
+        assert_eq!(find("Apple", ""), Some(0..0));
+        assert_eq!(find("", ""), Some(0..0));
+        assert_eq!(find("", "a"), None);
+    }
+
+    #[test]
+    fn find_returns_byte_ranges() {
+        assert_eq!(find("Crème brûlée", "BRÛ"), Some(7..11));
+    }
+}
@@ -1,13 +1,21 @@
-//! A context menu for showing actions on right click.
+//! A context menu for showing actions on right click or long press.
 //!
 use iced_native::{
     event,
     mouse::{self, Button},
-    widget::{tree, Operation, Tree},
+    time::Instant,
+    widget::{tree, Id, Operation, Tree},
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
 };
 
-use crate::native::overlay::ContextMenuOverlay;
+use crate::core::{
+    event::is_input,
+    gesture::{Gesture, Recognizer},
+};
+use crate::native::{
+    operation::Request,
+    overlay::{ContextMenuOverlay, Layers},
+};
 pub use crate::style::context_menu::StyleSheet;
 
 /// A context menu
@@ -46,8 +54,13 @@ where
     underlay: Element<'a, Message, Renderer>,
     /// The content of [`ContextMenuOverlay`](ContextMenuOverlay).
     overlay: Overlay,
+    /// The optional message that will be send when the menu is closed by
+    /// clicking outside of it or pressing the ESC key.
+    on_dismiss: Option<Message>,
     /// The style of the [`ContextMenu`](ContextMenu).
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The id of the [`ContextMenu`](ContextMenu) used to open it.
+    id: Option<Id>,
 }
 
 impl<'a, Overlay, Message, Renderer> ContextMenu<'a, Overlay, Message, Renderer>
@@ -69,10 +82,30 @@ where
         ContextMenu {
             underlay: underlay.into(),
             overlay,
+            on_dismiss: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            id: None,
         }
     }
 
+    /// Sets the message that will be produced when the menu of the
+    /// [`ContextMenu`](ContextMenu) is closed by clicking outside of it or
+    /// pressing the ESC key.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`ContextMenu`](ContextMenu) to open or close its menu with the
+    /// commands of [`operation`](crate::native::operation).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`ContextMenu`](ContextMenu).
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
@@ -150,6 +183,12 @@ where
         operation: &mut dyn Operation<Message>,
     ) {
         let s: &mut State = state.state.downcast_mut();
+        let is_open = Request::receive(operation, self.id.as_ref()).is_open(s.show);
+        // A menu opened by the application appears in the center of the underlay
+        if is_open && !s.show {
+            s.cursor_position = layout.bounds().center();
+        }
+        s.show = is_open;
 
         if s.show {
             let content = (self.overlay)();
@@ -186,6 +225,22 @@ where
             }
         }
 
+        let s: &mut State = state.state.downcast_mut();
+        let gesture = s.gestures.on_event(&event, layout.bounds(), Instant::now());
+        s.gestures.request_redraw(shell);
+
+        if let Some(Gesture::LongPress(position)) = gesture {
+            s.cursor_position = position;
+            s.show = true;
+            return event::Status::Captured;
+        }
+
+        // The open menu handles the input, like closing it on escape, so it
+        // must not reach the underlay, too
+        if s.show && is_input(&event) {
+            return event::Status::Ignored;
+        }
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -231,12 +286,29 @@ where
 
         let position = s.cursor_position;
         let content = (self.overlay)();
-        content.as_widget().diff(&mut state.children[1]);
+        let (underlay_state, content_state) = state.children.split_at_mut(1);
+        content.as_widget().diff(&mut content_state[0]);
 
-        Some(
-            ContextMenuOverlay::new(&mut state.children[1], content, self.style, s)
+        // The overlays of the underlay stay visible below the menu
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut underlay_state[0], layout, renderer),
+            )
+            .push(
+                1,
+                ContextMenuOverlay::new(
+                    &mut content_state[0],
+                    content,
+                    self.on_dismiss.clone(),
+                    self.style,
+                    s,
+                )
                 .overlay(position),
-        )
+            )
+            .overlay()
     }
 }
 
@@ -260,6 +332,8 @@ pub(crate) struct State {
     pub show: bool,
     /// Use for showing the overlay where the click was made.
     pub cursor_position: Point,
+    /// The recognizer of the long press opening the overlay on touch screens.
+    pub gestures: Recognizer,
 }
 
 impl State {
@@ -268,6 +342,7 @@ impl State {
         Self {
             show: false,
             cursor_position: Point::ORIGIN,
+            gestures: Recognizer::new(),
         }
     }
 }
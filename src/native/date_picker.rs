@@ -6,7 +6,7 @@ use chrono::Local;
 use iced_graphics::{Backend, Renderer};
 use iced_native::widget::button;
 use iced_native::widget::tree::{self, Tag};
-use iced_native::widget::Tree;
+use iced_native::widget::{Id, Operation, Tree};
 use iced_native::{event, mouse, Clipboard, Event, Layout, Point, Rectangle, Shell};
 use iced_native::{Element, Widget};
 
@@ -14,7 +14,10 @@ pub use crate::core::date::Date;
 
 pub use crate::style::date_picker::{Appearance, StyleSheet};
 
+use super::operation::Request;
 use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{Node, Role};
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking dates.
@@ -55,7 +58,8 @@ where
     date: Date,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer<B, Theme>>,
-    /// The message that is send if the cancel button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
+    /// The message that is send if the cancel button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed
+    /// or the overlay is dismissed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
     on_submit: Box<dyn Fn(Date) -> Message>,
@@ -63,6 +67,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Renderer<B, Theme>>,
+    /// The id of the [`DatePicker`](DatePicker) used to open it.
+    id: Option<Id>,
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
 }
 
@@ -84,7 +90,7 @@ where
     ///     * the underlay [`Element`](iced_native::Element) on which this [`DatePicker`](DatePicker)
     ///         will be wrapped around.
     ///     * a message that will be send when the cancel button of the [`DatePicker`](DatePicker)
-    ///         is pressed.
+    ///         is pressed, the user clicks outside of it or presses the ESC key.
     ///     * a function that will be called when the submit button of the [`DatePicker`](DatePicker)
     ///         is pressed, which takes the picked [`Date`](crate::date_picker::Date) value.
     pub fn new<U, F>(
@@ -106,10 +112,23 @@ where
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: DatePickerOverlayButtons::default().into(),
+            id: None,
             //button_style: <Renderer as button::Renderer>::Style::default(),
         }
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`DatePicker`](DatePicker) to
+    /// open or close its overlay with the commands of
+    /// [`operation`](crate::native::operation).
+    ///
+    /// Closing only hides an overlay opened by such a command, not one shown
+    /// by the application.
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`DatePicker`](DatePicker).
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -117,10 +136,22 @@ where
         //self.button_style = style.into();
         self
     }
+
+    /// Describes the [`DatePicker`](DatePicker) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, _state: &Tree) -> Node {
+        Node::new(Role::DateInput)
+            .value(self.date.to_string())
+            .expanded(self.show_picker)
+    }
 }
 
 /// The state of the [`DatePicker`](DatePicker) / [`DatePickerOverlay`](DatePickerOverlay).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: date_picker::State,
@@ -250,6 +281,22 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<B, Theme>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let picker_state: &mut State = state.state.downcast_mut();
+        picker_state.overlay_state.is_opened = Request::receive(operation, self.id.as_ref())
+            .is_open(picker_state.overlay_state.is_opened);
+
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -258,7 +305,7 @@ where
     ) -> Option<iced_native::overlay::Element<'b, Message, Renderer<B, Theme>>> {
         let picker_state: &mut State = state.state.downcast_mut();
 
-        if !self.show_picker {
+        if !self.show_picker && !picker_state.overlay_state.is_opened {
             return self
                 .underlay
                 .as_widget_mut()
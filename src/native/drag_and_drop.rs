@@ -0,0 +1,797 @@
+//! Drag payloads from sources and drop them onto targets.
+//!
+//! *This API requires the following crate features to be activated: drag_and_drop*
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, touch,
+    widget::{
+        tree::{self, Tree},
+        Operation,
+    },
+    window, Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell, Vector,
+    Widget,
+};
+
+use super::overlay::DragGhostOverlay;
+use crate::core::drag;
+
+pub use crate::style::drag_and_drop::{Appearance, StyleSheet};
+
+/// The distance the cursor has to move with a pressed button to start a
+/// drag, so the content still receives clicks.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// An element whose payload can be dragged onto a
+/// [`DropTarget`](DropTarget).
+///
+/// The drag starts once the cursor moves a few pixels with the left button
+/// or a finger pressed, after which a ghost of the element follows the cursor
+/// until it is released. A payload released outside of a target accepting it
+/// or a drag stopped with the escape key produces the cancel message.
+///
+/// The payload is matched by its type, so a target only reacts to sources of
+/// the same payload type.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, widget::Text};
+/// # use iced_aw::native::drag_and_drop;
+/// #
+/// # pub type DragSource<'a, T, Message> = drag_and_drop::DragSource<'a, T, Message, Null>;
+/// # pub type DropTarget<'a, T, Message> = drag_and_drop::DropTarget<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     CardDropped(usize),
+/// }
+///
+/// let card: DragSource<'_, usize, Message> = DragSource::new(3, Text::new("Card 3"));
+///
+/// let column = DropTarget::new(Text::new("Done"))
+///     .on_drop(Message::CardDropped);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DragSource<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The payload dragged from the [`DragSource`](DragSource).
+    payload: T,
+    /// The content of the [`DragSource`](DragSource).
+    content: Element<'a, Message, Renderer>,
+    /// The element following the cursor while dragging, if not the content.
+    ghost: Option<Element<'a, Message, Renderer>>,
+    /// The message produced when a drag starts.
+    on_drag_start: Option<Message>,
+    /// The message produced when a drag ends without a drop.
+    on_cancel: Option<Message>,
+    /// The style of the ghost.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> DragSource<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`DragSource`](DragSource) of the payload showing the
+    /// content.
+    pub fn new<C>(payload: T, content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            payload,
+            content: content.into(),
+            ghost: None,
+            on_drag_start: None,
+            on_cancel: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the element following the cursor while dragging instead of the
+    /// content.
+    #[must_use]
+    pub fn ghost<G>(mut self, ghost: G) -> Self
+    where
+        G: Into<Element<'a, Message, Renderer>>,
+    {
+        self.ghost = Some(ghost.into());
+        self
+    }
+
+    /// Sets the message produced when a drag of the
+    /// [`DragSource`](DragSource) starts.
+    #[must_use]
+    pub fn on_drag_start(mut self, message: Message) -> Self {
+        self.on_drag_start = Some(message);
+        self
+    }
+
+    /// Sets the message produced when a drag of the
+    /// [`DragSource`](DragSource) ends without a drop.
+    #[must_use]
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+
+    /// Sets the style of the ghost.
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for DragSource<'a, T, Message, Renderer>
+where
+    T: 'static + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<SourceState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(SourceState::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(self.ghost.as_ref())
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(self.ghost.as_ref())
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<SourceState>();
+        let bounds = layout.bounds();
+        let cursor = touch_position(&event).unwrap_or(cursor_position);
+
+        // The targets saw the release by now
+        if state.released {
+            state.released = false;
+
+            if drag::finish(state.source) == Some(false) {
+                if let Some(on_cancel) = &self.on_cancel {
+                    shell.publish(on_cancel.clone());
+                }
+            }
+        }
+
+        // Another source may have started a drag in the meantime
+        if state.grab.is_some() && !drag::is_dragging(state.source) {
+            state.grab = None;
+        }
+
+        if state.grab.is_some() {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    state.position = cursor;
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    drag::release(state.source);
+                    state.grab = None;
+                    state.released = true;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Escape,
+                    ..
+                }) => {
+                    drag::cancel(state.source);
+                    state.grab = None;
+
+                    if let Some(on_cancel) = &self.on_cancel {
+                        shell.publish(on_cancel.clone());
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor) =>
+            {
+                state.press = Some(cursor);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(press) = state.press else {
+                    return status;
+                };
+
+                if press.distance(cursor) > DRAG_THRESHOLD {
+                    drag::start(state.source, self.payload.clone());
+                    state.press = None;
+                    state.grab = Some(press - bounds.position());
+                    state.position = cursor;
+
+                    if let Some(on_drag_start) = &self.on_drag_start {
+                        shell.publish(on_drag_start.clone());
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.press = None;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<SourceState>();
+
+        if state.grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::Idle && layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<SourceState>();
+
+        match state.grab {
+            Some(grab) if drag::is_dragging(state.source) => {
+                let position = state.position - grab;
+                let (ghost, ghost_tree) = match &self.ghost {
+                    Some(ghost) => (ghost, &tree.children[1]),
+                    None => (&self.content, &tree.children[0]),
+                };
+
+                Some(
+                    DragGhostOverlay::new(ghost_tree, ghost, layout.bounds().size(), self.style)
+                        .overlay(position),
+                )
+            }
+            _ => self
+                .content
+                .as_widget_mut()
+                .overlay(&mut tree.children[0], layout, renderer),
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DragSource<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'static + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(drag_source: DragSource<'a, T, Message, Renderer>) -> Self {
+        Element::new(drag_source)
+    }
+}
+
+/// An area accepting the payloads dragged from a [`DragSource`](DragSource)
+/// and dropped onto it.
+///
+/// The area is highlighted while a payload it accepts is dragged and more so
+/// while the payload is over it. Only payloads of the type `T` that pass the
+/// [`accept`](Self::accept) filter are accepted.
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content of the [`DropTarget`](DropTarget).
+    content: Element<'a, Message, Renderer>,
+    /// The function producing the message with the dropped payload.
+    on_drop: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    /// The function producing the message with a payload dragged over the
+    /// [`DropTarget`](DropTarget).
+    on_over: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    /// The message produced when the payload leaves the
+    /// [`DropTarget`](DropTarget).
+    on_leave: Option<Message>,
+    /// The filter of the accepted payloads, if not all are accepted.
+    filter: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    /// The width of the [`DropTarget`](DropTarget).
+    width: Length,
+    /// The height of the [`DropTarget`](DropTarget).
+    height: Length,
+    /// The padding around the content.
+    padding: Padding,
+    /// The style of the [`DropTarget`](DropTarget).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> DropTarget<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`DropTarget`](DropTarget) showing the content.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_over: None,
+            on_leave: None,
+            filter: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::ZERO,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the payload dropped onto
+    /// the [`DropTarget`](DropTarget).
+    #[must_use]
+    pub fn on_drop<F>(mut self, on_drop: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the function producing the message with the payload once it is
+    /// dragged over the [`DropTarget`](DropTarget).
+    #[must_use]
+    pub fn on_over<F>(mut self, on_over: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        self.on_over = Some(Box::new(on_over));
+        self
+    }
+
+    /// Sets the message produced when the dragged payload leaves the
+    /// [`DropTarget`](DropTarget) without being dropped.
+    #[must_use]
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the filter of the accepted payloads.
+    #[must_use]
+    pub fn accept<F>(mut self, filter: F) -> Self
+    where
+        F: 'a + Fn(&T) -> bool,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the width of the [`DropTarget`](DropTarget).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`DropTarget`](DropTarget).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding around the content of the [`DropTarget`](DropTarget).
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`](DropTarget).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns true if the payload passes the filter.
+    fn accepts(&self, payload: &T) -> bool {
+        self.filter.as_ref().map_or(true, |filter| filter(payload))
+    }
+}
+
+impl<'a, T, Message, Renderer> DropTarget<'a, T, Message, Renderer>
+where
+    T: 'static + Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the accepted payload being dragged, if any.
+    fn dragged(&self) -> Option<T> {
+        drag::dragged::<T>().filter(|payload| self.accepts(payload))
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for DropTarget<'a, T, Message, Renderer>
+where
+    T: 'static + Clone,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<TargetState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(TargetState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits
+            .loose()
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let mut content = self.content.as_widget().layout(renderer, &limits.loose());
+        let size = limits.resolve(content.size());
+
+        content.move_to(Point::new(self.padding.left, self.padding.top));
+
+        Node::with_children(size.pad(self.padding), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a drop target."),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let state = tree.state.downcast_mut::<TargetState>();
+        let bounds = layout.bounds();
+        let cursor = touch_position(&event).unwrap_or(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let over = self.dragged().filter(|_| bounds.contains(cursor));
+
+                if over.is_some() != state.over {
+                    state.over = over.is_some();
+
+                    match (over, &self.on_over, &self.on_leave) {
+                        (Some(payload), Some(on_over), _) => shell.publish(on_over(payload)),
+                        (None, _, Some(on_leave)) => shell.publish(on_leave.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                state.over = false;
+
+                let Some(on_drop) = &self.on_drop else {
+                    return status;
+                };
+
+                if bounds.contains(cursor) {
+                    if let Some(payload) = drag::accept(|payload| self.accepts(payload)) {
+                        shell.publish(on_drop(payload));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {
+                // The drag may have been cancelled elsewhere
+                if state.over && self.dragged().is_none() {
+                    state.over = false;
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a drop target."),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let appearance = if self.dragged().is_none() {
+            theme.active(self.style)
+        } else if bounds.contains(cursor_position) {
+            theme.hovered(self.style)
+        } else {
+            theme.dragging(self.style)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a content layout for a drop target."),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, operation);
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DropTarget<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'static + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(drop_target: DropTarget<'a, T, Message, Renderer>) -> Self {
+        Element::new(drop_target)
+    }
+}
+
+/// The state of a [`DragSource`](DragSource).
+#[derive(Debug)]
+struct SourceState {
+    /// The identifier of the source in the current drag.
+    source: u64,
+    /// The position the button or finger was pressed at, until a drag starts.
+    press: Option<Point>,
+    /// The offset of the grabbed point from the top left corner, while
+    /// dragging.
+    grab: Option<Vector>,
+    /// The last position of the cursor or finger.
+    position: Point,
+    /// Whether the drag was released and waits for the targets.
+    released: bool,
+}
+
+impl SourceState {
+    /// Creates a new [`SourceState`](SourceState) with its own identifier.
+    fn new() -> Self {
+        Self {
+            source: drag::next_source(),
+            press: None,
+            grab: None,
+            position: Point::ORIGIN,
+            released: false,
+        }
+    }
+}
+
+/// The state of a [`DropTarget`](DropTarget).
+#[derive(Debug, Default)]
+struct TargetState {
+    /// Whether an accepted payload is over the target.
+    over: bool,
+}
+
+/// Returns the position of the finger of a touch event.
+fn touch_position(event: &Event) -> Option<Point> {
+    match event {
+        Event::Touch(
+            touch::Event::FingerPressed { position, .. }
+            | touch::Event::FingerMoved { position, .. }
+            | touch::Event::FingerLifted { position, .. }
+            | touch::Event::FingerLost { position, .. },
+        ) => Some(*position),
+        _ => None,
+    }
+}
@@ -0,0 +1,672 @@
+//! Capture freehand strokes, like a signature or a quick annotation.
+//!
+//! *This API requires the following crate features to be activated: drawing_pad*
+use iced_graphics::{
+    widget::canvas::{self, Frame, LineCap, LineJoin, Path},
+    Backend, Renderer,
+};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, image,
+    layout::{Limits, Node},
+    mouse, renderer,
+    text::{self, Renderer as _},
+    touch,
+    widget::tree::{self, Tree},
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Renderer as _, Shell,
+    Size, Vector, Widget,
+};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::drawing_pad::{Appearance, StyleSheet};
+
+/// The default width of the strokes.
+const DEFAULT_STROKE_WIDTH: f32 = 2.5;
+
+/// The default height of the pad.
+const DEFAULT_HEIGHT: f32 = 160.0;
+
+/// The distance the pointer has to move before a new point is captured.
+const MIN_DISTANCE: f32 = 2.0;
+
+/// The number of corner cutting passes smoothing a finished stroke.
+const SMOOTHING_PASSES: usize = 2;
+
+/// A freehand stroke drawn on a [`DrawingPad`](DrawingPad).
+///
+/// The points are relative to the top left corner of the pad.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stroke {
+    /// The smoothed points of the stroke.
+    points: Vec<Point>,
+    /// The width of the stroke.
+    width: f32,
+}
+
+impl Stroke {
+    /// Creates a new [`Stroke`](Stroke) through the points with the width.
+    #[must_use]
+    pub fn new(points: Vec<Point>, width: f32) -> Self {
+        Self { points, width }
+    }
+
+    /// Returns the points of the [`Stroke`](Stroke).
+    #[must_use]
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Returns the width of the [`Stroke`](Stroke).
+    #[must_use]
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+/// The strokes drawn on a [`DrawingPad`](DrawingPad).
+///
+/// The application owns the drawing and adds each stroke the pad publishes,
+/// so undoing, clearing and exporting are plain method calls in its update.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Drawing {
+    /// The strokes in the order they were drawn.
+    strokes: Vec<Stroke>,
+}
+
+impl Drawing {
+    /// Creates a new empty [`Drawing`](Drawing).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the stroke on top of the [`Drawing`](Drawing).
+    pub fn push(&mut self, stroke: Stroke) {
+        self.strokes.push(stroke);
+    }
+
+    /// Removes the last stroke of the [`Drawing`](Drawing) and returns it.
+    pub fn undo(&mut self) -> Option<Stroke> {
+        self.strokes.pop()
+    }
+
+    /// Removes all strokes of the [`Drawing`](Drawing).
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+    }
+
+    /// Returns whether the [`Drawing`](Drawing) has no strokes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty()
+    }
+
+    /// Returns the strokes of the [`Drawing`](Drawing) in the order they
+    /// were drawn.
+    #[must_use]
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+
+    /// Renders the [`Drawing`](Drawing) to an image to save or share it.
+    ///
+    /// The image is cropped to the strokes, is `scale` pixels per unit of the
+    /// pad and has a transparent background. Returns `None` if the drawing
+    /// is empty.
+    #[must_use]
+    pub fn to_image(&self, color: Color, scale: f32) -> Option<image::Handle> {
+        let (width, height, pixels) = self.rgba(color, scale)?;
+
+        Some(image::Handle::from_pixels(width, height, pixels))
+    }
+
+    /// Returns the size in pixels and the RGBA pixels of the strokes rendered
+    /// with the color and the scale.
+    fn rgba(&self, color: Color, scale: f32) -> Option<(u32, u32, Vec<u8>)> {
+        let scale = scale.max(f32::EPSILON);
+        let area = extent(&self.strokes)?;
+        let width = (area.width * scale).ceil().max(1.0) as u32;
+        let height = (area.height * scale).ceil().max(1.0) as u32;
+
+        let [red, green, blue, alpha] = color.into_rgba8();
+        let pixels = coverage(&self.strokes, area.position(), width, height, scale)
+            .into_iter()
+            .flat_map(|coverage| {
+                [
+                    red,
+                    green,
+                    blue,
+                    (f32::from(alpha) * coverage).round() as u8,
+                ]
+            })
+            .collect();
+
+        Some((width, height, pixels))
+    }
+}
+
+/// A pad capturing freehand strokes, like a signature or a quick annotation.
+///
+/// Strokes are smoothed and drawn with a constant width. Each finished
+/// stroke is published to the application, which adds it to its
+/// [`Drawing`](Drawing).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{drawing_pad::{Drawing, Stroke}, DrawingPad};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Stroke(Stroke),
+/// }
+///
+/// let drawing = Drawing::new();
+///
+/// let pad = DrawingPad::new(&drawing, Message::Stroke).placeholder("Sign here");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DrawingPad<'a, Message, B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// The strokes drawn so far.
+    drawing: &'a Drawing,
+    /// The function producing the message of a finished stroke.
+    on_stroke: Box<dyn Fn(Stroke) -> Message + 'a>,
+    /// The width of the strokes.
+    stroke_width: f32,
+    /// The placeholder shown above a guide line while the pad is empty.
+    placeholder: Option<String>,
+    /// The width of the pad.
+    width: Length,
+    /// The height of the pad.
+    height: Length,
+    /// The text size of the placeholder.
+    text_size: Option<f32>,
+    /// The font of the placeholder.
+    font: Font,
+    /// The style of the pad.
+    style: <Theme as StyleSheet>::Style,
+    #[allow(missing_docs)]
+    backend: std::marker::PhantomData<B>,
+}
+
+impl<'a, Message, B, Theme> DrawingPad<'a, Message, B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`DrawingPad`](DrawingPad) showing the drawing.
+    ///
+    /// It expects:
+    ///     * the strokes drawn so far.
+    ///     * the function producing the message of a finished stroke.
+    #[must_use]
+    pub fn new<F>(drawing: &'a Drawing, on_stroke: F) -> Self
+    where
+        F: 'a + Fn(Stroke) -> Message,
+    {
+        Self {
+            drawing,
+            on_stroke: Box::new(on_stroke),
+            stroke_width: DEFAULT_STROKE_WIDTH,
+            placeholder: None,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            text_size: None,
+            font: Font::default(),
+            style: <Theme as StyleSheet>::Style::default(),
+            backend: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the width of the strokes.
+    #[must_use]
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// Sets the placeholder shown above a guide line while the
+    /// [`DrawingPad`](DrawingPad) is empty.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`DrawingPad`](DrawingPad).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`DrawingPad`](DrawingPad).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the placeholder.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the placeholder.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`DrawingPad`](DrawingPad).
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, B, Theme> Widget<Message, Renderer<B, Theme>>
+    for DrawingPad<'a, Message, B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(self.width)
+                .height(self.height)
+                .resolve(Size::ZERO),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer<B, Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let local = |position: Point| Point::new(position.x - bounds.x, position.y - bounds.y);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                state.current = Some(vec![local(cursor_position)]);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(current) = &mut state.current else {
+                    return event::Status::Ignored;
+                };
+
+                // Points outside of the pad are clamped to its edges
+                let point = local(position);
+                let point = Point::new(
+                    point.x.clamp(0.0, bounds.width),
+                    point.y.clamp(0.0, bounds.height),
+                );
+
+                if current
+                    .last()
+                    .map_or(true, |last| last.distance(point) >= MIN_DISTANCE)
+                {
+                    current.push(point);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(current) = state.current.take() else {
+                    return event::Status::Ignored;
+                };
+
+                let stroke = Stroke::new(smooth(&current, SMOOTHING_PASSES), self.stroke_width);
+                shell.publish((self.on_stroke)(stroke));
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<B, Theme>,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                state.current.is_some(),
+                false,
+                false,
+            ),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let mut frame = Frame::new(bounds.size());
+
+        if self.drawing.is_empty() && state.current.is_none() {
+            if let Some(placeholder) = &self.placeholder {
+                // The guide line sits low, like the line of a signature field
+                let margin = bounds.width * 0.1;
+                let y = bounds.height * 0.75;
+
+                frame.stroke(
+                    &Path::line(Point::new(margin, y), Point::new(bounds.width - margin, y)),
+                    canvas::Stroke::default()
+                        .with_color(appearance.placeholder_color)
+                        .with_width(1.0),
+                );
+
+                renderer.fill_text(text::Text {
+                    content: placeholder,
+                    bounds: Rectangle {
+                        x: bounds.x + margin,
+                        y: bounds.y + y - 4.0,
+                        ..bounds
+                    },
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    color: appearance.placeholder_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Bottom,
+                });
+            }
+        }
+
+        for stroke in self.drawing.strokes() {
+            draw_stroke(
+                &mut frame,
+                stroke.points(),
+                stroke.width(),
+                appearance.ink_color,
+            );
+        }
+
+        if let Some(current) = &state.current {
+            draw_stroke(
+                &mut frame,
+                &smooth(current, SMOOTHING_PASSES),
+                self.stroke_width,
+                appearance.ink_color,
+            );
+        }
+
+        // Strokes drawn close to an edge must not bleed out of the pad
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw_primitive(frame.into_geometry().into_primitive());
+            });
+        });
+    }
+}
+
+/// The state of a [`DrawingPad`](DrawingPad).
+#[derive(Debug, Default)]
+pub struct State {
+    /// The raw points of the stroke being drawn.
+    current: Option<Vec<Point>>,
+}
+
+/// Draws a stroke through the points on the frame.
+fn draw_stroke(frame: &mut Frame, points: &[Point], width: f32, color: Color) {
+    match points {
+        [] => {}
+        [point] => frame.fill(&Path::circle(*point, width / 2.0), color),
+        [first, rest @ ..] => {
+            let path = Path::new(|builder| {
+                builder.move_to(*first);
+                for point in rest {
+                    builder.line_to(*point);
+                }
+            });
+
+            frame.stroke(
+                &path,
+                canvas::Stroke::default()
+                    .with_color(color)
+                    .with_width(width)
+                    .with_line_cap(LineCap::Round)
+                    .with_line_join(LineJoin::Round),
+            );
+        }
+    }
+}
+
+/// Smoothes the points by cutting the corners between them.
+///
+/// Each pass replaces every segment with points at a quarter and at three
+/// quarters of it, while the first and the last point stay in place.
+fn smooth(points: &[Point], passes: usize) -> Vec<Point> {
+    let mut points = points.to_vec();
+
+    for _ in 0..passes {
+        if points.len() < 3 {
+            break;
+        }
+
+        let mut smoothed = Vec::with_capacity(points.len() * 2);
+        smoothed.push(points[0]);
+        for pair in points.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            smoothed.push(Point::new(
+                from.x * 0.75 + to.x * 0.25,
+                from.y * 0.75 + to.y * 0.25,
+            ));
+            smoothed.push(Point::new(
+                from.x * 0.25 + to.x * 0.75,
+                from.y * 0.25 + to.y * 0.75,
+            ));
+        }
+        smoothed.push(points[points.len() - 1]);
+
+        points = smoothed;
+    }
+
+    points
+}
+
+/// Returns the area covered by the strokes, including their width.
+fn extent(strokes: &[Stroke]) -> Option<Rectangle> {
+    let mut area: Option<(Point, Point)> = None;
+
+    for stroke in strokes {
+        let radius = stroke.width / 2.0;
+
+        for point in &stroke.points {
+            let (min, max) = area.get_or_insert((*point, *point));
+            min.x = min.x.min(point.x - radius);
+            min.y = min.y.min(point.y - radius);
+            max.x = max.x.max(point.x + radius);
+            max.y = max.y.max(point.y + radius);
+        }
+    }
+
+    area.map(|(min, max)| Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y)))
+}
+
+/// Returns how much of each pixel the strokes cover, row by row.
+///
+/// The pixels start at the origin and are `1 / scale` units of the pad wide.
+/// The edges of the strokes are antialiased.
+fn coverage(strokes: &[Stroke], origin: Point, width: u32, height: u32, scale: f32) -> Vec<f32> {
+    let mut coverage = vec![0.0_f32; (width * height) as usize];
+
+    for stroke in strokes {
+        let radius = stroke.width / 2.0;
+        let segments = stroke
+            .points
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .chain(
+                // A single point is a dot
+                (stroke.points.len() == 1).then(|| (stroke.points[0], stroke.points[0])),
+            );
+
+        for (from, to) in segments {
+            // Only the pixels around the segment can be covered by it
+            let pixel = |value: f32, origin: f32| ((value - origin) * scale).floor();
+            let left = pixel(from.x.min(to.x) - radius, origin.x).max(0.0) as u32;
+            let top = pixel(from.y.min(to.y) - radius, origin.y).max(0.0) as u32;
+            let right =
+                (pixel(from.x.max(to.x) + radius, origin.x) + 1.0).clamp(0.0, width as f32) as u32;
+            let bottom =
+                (pixel(from.y.max(to.y) + radius, origin.y) + 1.0).clamp(0.0, height as f32) as u32;
+
+            for y in top..bottom {
+                for x in left..right {
+                    let center = Point::new(
+                        origin.x + (x as f32 + 0.5) / scale,
+                        origin.y + (y as f32 + 0.5) / scale,
+                    );
+                    let covered = ((radius - distance_to_segment(center, from, to)) * scale + 0.5)
+                        .clamp(0.0, 1.0);
+
+                    let index = (y * width + x) as usize;
+                    coverage[index] = coverage[index].max(covered);
+                }
+            }
+        }
+    }
+
+    coverage
+}
+
+/// Returns the distance of the point to the segment between `from` and `to`.
+fn distance_to_segment(point: Point, from: Point, to: Point) -> f32 {
+    let segment = to - from;
+    let length = segment.x * segment.x + segment.y * segment.y;
+
+    if length <= f32::EPSILON {
+        return point.distance(from);
+    }
+
+    let offset = point - from;
+    let t = ((offset.x * segment.x + offset.y * segment.y) / length).clamp(0.0, 1.0);
+
+    point.distance(from + segment * t)
+}
+
+impl<'a, Message, B, Theme> From<DrawingPad<'a, Message, B, Theme>>
+    for Element<'a, Message, Renderer<B, Theme>>
+where
+    Message: 'a,
+    B: 'a + Backend + iced_graphics::backend::Text,
+    Theme: 'a + StyleSheet,
+{
+    fn from(drawing_pad: DrawingPad<'a, Message, B, Theme>) -> Self {
+        Self::new(drawing_pad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coverage, smooth, Point, Stroke};
+
+    #[test]
+    fn smoothing_keeps_the_ends_and_cuts_the_corners() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(20.0, 0.0),
+        ];
+
+        let smoothed = smooth(&points, 1);
+
+        assert_eq!(smoothed.len(), 6);
+        assert_eq!(smoothed.first(), points.first());
+        assert_eq!(smoothed.last(), points.last());
+        assert!(smoothed.iter().all(|point| point.y < 10.0));
+
+        // Too short to have corners
+        assert_eq!(smooth(&points[..2], 2), points[..2].to_vec());
+    }
+
+    #[test]
+    fn coverage_follows_the_strokes() {
+        let stroke = Stroke::new(vec![Point::new(0.0, 5.0), Point::new(10.0, 5.0)], 2.0);
+
+        let coverage = coverage(&[stroke], Point::ORIGIN, 10, 10, 1.0);
+
+        assert!(coverage[5 * 10 + 5] > 0.99);
+        assert!(coverage[5 * 10 + 5] > coverage[6 * 10 + 5]);
+        assert!(coverage[9 * 10 + 5] < 0.01);
+        assert!(coverage[5] < 0.01);
+    }
+}
@@ -0,0 +1,450 @@
+//! Drop files from outside of the application onto an area.
+//!
+//! *This API requires the following crate features to be activated: drop_zone*
+use std::path::{Path, PathBuf};
+
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    widget::{
+        tree::{self, Tree},
+        Operation,
+    },
+    window, Alignment, Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell,
+    Widget,
+};
+
+pub use crate::style::drop_zone::{Appearance, StyleSheet};
+
+/// The default padding around the content of a [`DropZone`](DropZone).
+const DEFAULT_PADDING: f32 = 20.0;
+
+/// An area accepting files dropped onto it from outside of the application.
+///
+/// The area highlights while files are dragged over it and produces a message
+/// with the paths of the files once they are dropped. An extension filter
+/// rejects the other files, which the area shows while they are dragged over
+/// it and reports with a separate message.
+///
+/// The window reports the files dragged over it without a position, so the
+/// area relies on the last known position of the cursor. Since some platforms
+/// don't move the cursor while dragging files, an area filling the window can
+/// accept files dropped anywhere with [`whole_window`](DropZone::whole_window).
+///
+/// # Example
+/// ```
+/// # use std::path::PathBuf;
+/// # use iced_native::{renderer::Null, widget::Text};
+/// # use iced_aw::native::drop_zone;
+/// #
+/// # pub type DropZone<'a, Message> = drop_zone::DropZone<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ImagesDropped(Vec<PathBuf>),
+///     OtherFilesDropped(Vec<PathBuf>),
+/// }
+///
+/// let drop_zone = DropZone::new(Text::new("Drop images here"))
+///     .accept(["png", "jpg"])
+///     .on_drop(Message::ImagesDropped)
+///     .on_reject(Message::OtherFilesDropped);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DropZone<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content of the [`DropZone`](DropZone).
+    content: Element<'a, Message, Renderer>,
+    /// The function producing the message with the accepted dropped files.
+    on_drop: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    /// The function producing the message with the rejected dropped files.
+    on_reject: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    /// The accepted extensions in lowercase, or all if empty.
+    extensions: Vec<String>,
+    /// Whether files dropped anywhere on the window are accepted.
+    whole_window: bool,
+    /// The width of the [`DropZone`](DropZone).
+    width: Length,
+    /// The height of the [`DropZone`](DropZone).
+    height: Length,
+    /// The padding around the content.
+    padding: Padding,
+    /// The style of the [`DropZone`](DropZone).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> DropZone<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`DropZone`](DropZone) showing the content.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_reject: None,
+            extensions: Vec::new(),
+            whole_window: false,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: DEFAULT_PADDING.into(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the paths of the accepted
+    /// files dropped onto the [`DropZone`](DropZone).
+    #[must_use]
+    pub fn on_drop<F>(mut self, on_drop: F) -> Self
+    where
+        F: 'a + Fn(Vec<PathBuf>) -> Message,
+    {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the function producing the message with the paths of the files
+    /// dropped onto the [`DropZone`](DropZone) without an accepted
+    /// extension.
+    #[must_use]
+    pub fn on_reject<F>(mut self, on_reject: F) -> Self
+    where
+        F: 'a + Fn(Vec<PathBuf>) -> Message,
+    {
+        self.on_reject = Some(Box::new(on_reject));
+        self
+    }
+
+    /// Sets the accepted extensions, like `"png"` or `".png"`, ignoring their
+    /// case.
+    ///
+    /// All files are accepted if no extension is set.
+    #[must_use]
+    pub fn accept<E>(mut self, extensions: impl IntoIterator<Item = E>) -> Self
+    where
+        E: Into<String>,
+    {
+        self.extensions = extensions
+            .into_iter()
+            .map(|extension| extension.into().trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Sets whether files dropped anywhere on the window are accepted, instead
+    /// of only those dropped onto the [`DropZone`](DropZone).
+    #[must_use]
+    pub fn whole_window(mut self, whole_window: bool) -> Self {
+        self.whole_window = whole_window;
+        self
+    }
+
+    /// Sets the width of the [`DropZone`](DropZone).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`DropZone`](DropZone).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding around the content of the [`DropZone`](DropZone).
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`DropZone`](DropZone).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns true if files at the cursor would be dropped onto the
+    /// [`DropZone`](DropZone).
+    fn is_over(&self, bounds: Rectangle, cursor_position: Point) -> bool {
+        self.whole_window || bounds.contains(cursor_position)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for DropZone<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits
+            .loose()
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let mut content = self.content.as_widget().layout(renderer, &limits.loose());
+        let size = limits.resolve(content.size());
+
+        content.move_to(Point::new(self.padding.left, self.padding.top));
+        content.align(Alignment::Center, Alignment::Center, size);
+
+        Node::with_children(size.pad(self.padding), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a drop zone."),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::FileHovered(path)) => {
+                state.hovered.push(path);
+            }
+            Event::Window(window::Event::FilesHoveredLeft) => {
+                state.hovered.clear();
+            }
+            Event::Window(window::Event::FileDropped(path)) => {
+                state.hovered.retain(|hovered| hovered != &path);
+
+                // The window reports each file on its own, so they are
+                // collected until the next redraw and published together
+                if self.is_over(bounds, cursor_position) {
+                    state.dropped.push(path);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(_)) if !state.dropped.is_empty() => {
+                let (accepted, rejected): (Vec<_>, Vec<_>) = state
+                    .dropped
+                    .drain(..)
+                    .partition(|path| accepts(&self.extensions, path));
+
+                if !accepted.is_empty() {
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(accepted));
+                    }
+                }
+                if !rejected.is_empty() {
+                    if let Some(on_reject) = &self.on_reject {
+                        shell.publish(on_reject(rejected));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a drop zone."),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = if state.hovered.is_empty() || !self.is_over(bounds, cursor_position) {
+            theme.active(self.style)
+        } else if state
+            .hovered
+            .iter()
+            .any(|path| accepts(&self.extensions, path))
+        {
+            theme.hovered(self.style)
+        } else {
+            theme.rejected(self.style)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a content layout for a drop zone."),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(layout) = layout.children().next() {
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, operation);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<DropZone<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(drop_zone: DropZone<'a, Message, Renderer>) -> Self {
+        Element::new(drop_zone)
+    }
+}
+
+/// The state of a [`DropZone`](DropZone).
+#[derive(Debug, Default)]
+struct State {
+    /// The files dragged over the window.
+    hovered: Vec<PathBuf>,
+    /// The files dropped since the last redraw.
+    dropped: Vec<PathBuf>,
+}
+
+/// Returns true if the file has one of the extensions, or if there are none.
+fn accepts(extensions: &[String], path: &Path) -> bool {
+    extensions.is_empty()
+        || path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or(false, |extension| {
+                extensions
+                    .iter()
+                    .any(|accepted| accepted.eq_ignore_ascii_case(extension))
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::accepts;
+
+    #[test]
+    fn accepts_the_extensions_ignoring_their_case() {
+        let extensions = [String::from("png"), String::from("jpg")];
+
+        assert!(accepts(&extensions, Path::new("/photos/beach.PNG")));
+        assert!(accepts(&extensions, Path::new("cat.jpg")));
+        assert!(!accepts(&extensions, Path::new("notes.txt")));
+        assert!(!accepts(&extensions, Path::new("README")));
+    }
+
+    #[test]
+    fn accepts_all_files_without_extensions() {
+        assert!(accepts(&[], Path::new("notes.txt")));
+        assert!(accepts(&[], Path::new("README")));
+    }
+}
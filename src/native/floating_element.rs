@@ -1,11 +1,16 @@
 //! Use a floating element to overlay an element over some content
 //!
 //! *This API requires the following crate features to be activated: `floating_element`*
+use iced_native::time::{Duration, Instant};
+
 use iced_native::{
-    event, mouse, overlay, Clipboard, Event, Layout, Length, Point, Rectangle, Shell,
+    event, mouse, overlay, window, Clipboard, Event, Layout, Length, Point, Rectangle, Shell,
 };
 use iced_native::{
-    widget::{Operation, Tree},
+    widget::{
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
     Element, Widget,
 };
 
@@ -15,7 +20,18 @@ pub use anchor::Anchor;
 pub mod offset;
 pub use offset::Offset;
 
-use super::overlay::floating_element::FloatingElementOverlay;
+pub mod snap;
+pub use snap::Snap;
+
+pub mod target;
+use target::FindTarget;
+pub use target::Target;
+
+use super::overlay::floating_element::{Drag, FloatingElementOverlay};
+use crate::core::animation::Animation;
+
+/// The default duration of the show/hide transition of a [`FloatingElement`](FloatingElement).
+const DEFAULT_TRANSITION: Duration = Duration::from_millis(200);
 
 /// A floating element floating over some content.
 ///
@@ -49,8 +65,22 @@ where
     anchor: Anchor,
     /// The offset of the element.
     offset: Offset,
+    /// The [`Id`](Id) of the [`Target`](Target) the element is anchored to.
+    target: Option<Id>,
     /// The visibility of the element.
     hidden: bool,
+    /// The animated visibility of the element.
+    visible: bool,
+    /// The duration of the show/hide transition.
+    transition: Duration,
+    /// The function that produces a message when the element was dragged.
+    on_drag: Option<Box<dyn Fn(Anchor, Offset) -> Message + 'a>>,
+    /// The snap behavior of the element after it was dragged.
+    snap: Snap,
+    /// The optional badge attached to the element.
+    badge: Option<Box<dyn Fn() -> Element<'a, Message, Renderer> + 'a>>,
+    /// The anchor of the badge on the element.
+    badge_anchor: Anchor,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer>,
     /// The floating element of the [`FloatingElementOverlay`](FloatingElementOverlay).
@@ -76,7 +106,14 @@ where
         FloatingElement {
             anchor: Anchor::SouthEast,
             offset: 5.0.into(),
+            target: None,
             hidden: false,
+            visible: true,
+            transition: DEFAULT_TRANSITION,
+            on_drag: None,
+            snap: Snap::None,
+            badge: None,
+            badge_anchor: Anchor::NorthEast,
             underlay: underlay.into(),
             element,
         }
@@ -99,6 +136,18 @@ where
         self
     }
 
+    /// Anchors the [`FloatingElement`](FloatingElement) to the bounds of the
+    /// [`Target`](Target) with the given [`Id`](Id) inside of the underlay
+    /// instead of the bounds of the whole underlay.
+    ///
+    /// If no [`Target`](Target) with the given [`Id`](Id) exists, the
+    /// [`FloatingElement`](FloatingElement) falls back to the underlay.
+    #[must_use]
+    pub fn target(mut self, id: Id) -> Self {
+        self.target = Some(id);
+        self
+    }
+
     /// Hide or unhide the [`Element`](iced_native::Element) on the
     /// [`FloatingElement`](FloatingElement).
     #[must_use]
@@ -106,6 +155,72 @@ where
         self.hidden = hide;
         self
     }
+
+    /// Shows or hides the [`Element`](iced_native::Element) on the
+    /// [`FloatingElement`](FloatingElement) with an animated transition.
+    ///
+    /// Unlike [`hide`](Self::hide), changing the visibility scales and fades
+    /// the floating element in or out instead of removing it abruptly.
+    #[must_use]
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the duration of the show/hide transition of the
+    /// [`FloatingElement`](FloatingElement).
+    #[must_use]
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = duration;
+        self
+    }
+
+    /// Makes the [`FloatingElement`](FloatingElement) draggable by the user.
+    ///
+    /// When the element is released, the given function is called with the
+    /// new [`Anchor`](Anchor) and [`Offset`](Offset), which should be stored
+    /// and passed back to the [`FloatingElement`](FloatingElement) to persist
+    /// the position.
+    #[must_use]
+    pub fn on_drag<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Anchor, Offset) -> Message,
+    {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
+    /// Attaches a badge to the floating element of the
+    /// [`FloatingElement`](FloatingElement), e.g. a
+    /// [`Badge`](crate::native::badge::Badge) showing an unread count.
+    ///
+    /// The badge is centered on the [`Anchor`](Anchor) of the floating
+    /// element given by [`badge_anchor`](Self::badge_anchor).
+    #[must_use]
+    pub fn badge<F, E>(mut self, badge: F) -> Self
+    where
+        F: 'a + Fn() -> E,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.badge = Some(Box::new(move || badge().into()));
+        self
+    }
+
+    /// Sets the [`Anchor`](Anchor) of the badge on the floating element of
+    /// the [`FloatingElement`](FloatingElement).
+    #[must_use]
+    pub fn badge_anchor(mut self, anchor: Anchor) -> Self {
+        self.badge_anchor = anchor;
+        self
+    }
+
+    /// Sets the [`Snap`](Snap) behavior of a draggable
+    /// [`FloatingElement`](FloatingElement) when it is released.
+    #[must_use]
+    pub fn snap(mut self, snap: Snap) -> Self {
+        self.snap = snap;
+        self
+    }
 }
 
 impl<'a, B, Message, Renderer> Widget<Message, Renderer>
@@ -115,12 +230,34 @@ where
     Message: 'a,
     Renderer: 'a + iced_native::Renderer,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.visible))
+    }
+
     fn children(&self) -> Vec<iced_native::widget::Tree> {
-        vec![Tree::new(&self.underlay), Tree::new(&(self.element)())]
+        let mut children = vec![Tree::new(&self.underlay), Tree::new(&(self.element)())];
+
+        if let Some(badge) = &self.badge {
+            children.push(Tree::new(&badge()));
+        }
+
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.underlay, &(self.element)()]);
+        let element = (self.element)();
+        let badge = self.badge.as_ref().map(|badge| badge());
+
+        let mut children = vec![&self.underlay, &element];
+        if let Some(badge) = &badge {
+            children.push(badge);
+        }
+
+        tree.diff_children(&children);
     }
 
     fn width(&self) -> Length {
@@ -149,6 +286,20 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let transition = state.state.downcast_mut::<State>();
+
+            // The element scales and fades whenever the application changes
+            // its visibility
+            transition.animation.set_duration(self.transition);
+            if transition.visible != self.visible {
+                transition.visible = self.visible;
+                transition.animation.go(self.visible, now);
+            }
+            transition.animation.request_redraw(now, shell);
+            transition.now = Some(now);
+        }
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -216,44 +367,96 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        if self.hidden {
+        let progress = state.state.downcast_ref::<State>().progress();
+
+        if self.hidden || progress <= 0.0 {
             return self
                 .underlay
                 .as_widget_mut()
                 .overlay(&mut state.children[0], layout, renderer);
         }
 
-        if state.children.len() == 2 {
-            let bounds = layout.bounds();
-
-            let position = match self.anchor {
-                Anchor::NorthWest => Point::new(0.0, 0.0),
-                Anchor::NorthEast => Point::new(bounds.width, 0.0),
-                Anchor::SouthWest => Point::new(0.0, bounds.height),
-                Anchor::SouthEast => Point::new(bounds.width, bounds.height),
-                Anchor::North => Point::new(bounds.center_x(), 0.0),
-                Anchor::East => Point::new(bounds.width, bounds.center_y()),
-                Anchor::South => Point::new(bounds.center_x(), bounds.height),
-                Anchor::West => Point::new(0.0, bounds.center_y()),
-            };
-
-            let position = Point::new(bounds.x + position.x, bounds.y + position.y);
-
-            Some(
-                FloatingElementOverlay::new(
-                    &mut state.children[1],
-                    (self.element)(),
-                    &self.anchor,
-                    &self.offset,
-                )
-                .overlay(position),
+        if state.children.len() >= 2 {
+            let underlay = layout.bounds();
+            let bounds = self
+                .target
+                .as_ref()
+                .and_then(|id| {
+                    let mut find = FindTarget::new(id);
+                    self.underlay.as_widget().operate(
+                        &mut state.children[0],
+                        layout,
+                        renderer,
+                        &mut find,
+                    );
+                    find.bounds()
+                })
+                .unwrap_or(underlay);
+
+            let position = self.anchor.position(bounds);
+
+            let floating = state.state.downcast_mut::<State>();
+            let mut children = state.children.iter_mut().skip(1);
+            let element_state = children.next()?;
+
+            let mut overlay = FloatingElementOverlay::new(
+                element_state,
+                (self.element)(),
+                &self.anchor,
+                &self.offset,
+                progress,
             )
+            .draggable(
+                &mut floating.drag,
+                self.on_drag.as_deref(),
+                self.snap,
+                underlay,
+            );
+
+            if let (Some(badge), Some(badge_state)) = (&self.badge, children.next()) {
+                overlay = overlay.badge(badge_state, badge(), self.badge_anchor);
+            }
+
+            Some(overlay.overlay(position))
         } else {
             None
         }
     }
 }
 
+/// The state of a [`FloatingElement`](FloatingElement).
+#[derive(Debug)]
+struct State {
+    /// The visibility the transition moves towards.
+    visible: bool,
+    /// The transition between hidden (0.0) and visible (1.0).
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+    /// The ongoing drag of the element, if any.
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`] that is already settled on the given visibility.
+    fn new(visible: bool) -> Self {
+        let mut animation = Animation::new(DEFAULT_TRANSITION);
+        animation.rest_at(if visible { 1.0 } else { 0.0 });
+
+        Self {
+            visible,
+            animation,
+            now: None,
+            drag: None,
+        }
+    }
+
+    /// Returns the current progress of the transition.
+    fn progress(&self) -> f32 {
+        self.animation.value(self.now.unwrap_or_else(Instant::now))
+    }
+}
+
 impl<'a, B, Message, Renderer> From<FloatingElement<'a, B, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -2,8 +2,10 @@
 //!
 //! *This API requires the following crate features to be activated: `floating_button`*
 
+use iced_native::{Point, Rectangle};
+
 /// Positional [`Anchor`](Anchor) for the [`FloatingButton`](super::FloatingButton).
-#[derive(Copy, Clone, Debug, Hash)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum Anchor {
     /// NorthWest [`Anchor`](Anchor) for positioning the
     /// [`Button`](iced_native::widget::button::Button) on the top left of the
@@ -45,3 +47,22 @@ pub enum Anchor {
     /// underlying element.
     West,
 }
+
+impl Anchor {
+    /// Returns the position of the [`Anchor`](Anchor) on the given bounds.
+    #[must_use]
+    pub fn position(self, bounds: Rectangle) -> Point {
+        let (x, y) = match self {
+            Self::NorthWest => (0.0, 0.0),
+            Self::NorthEast => (bounds.width, 0.0),
+            Self::SouthWest => (0.0, bounds.height),
+            Self::SouthEast => (bounds.width, bounds.height),
+            Self::North => (bounds.width / 2.0, 0.0),
+            Self::East => (bounds.width, bounds.height / 2.0),
+            Self::South => (bounds.width / 2.0, bounds.height),
+            Self::West => (0.0, bounds.height / 2.0),
+        };
+
+        Point::new(bounds.x + x, bounds.y + y)
+    }
+}
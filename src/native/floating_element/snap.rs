@@ -0,0 +1,139 @@
+//! Use a floating element to overlay an element over some content
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+
+use iced_native::Rectangle;
+
+use super::{Anchor, Offset};
+
+/// The [`Snap`](Snap) behavior of a draggable
+/// [`FloatingElement`](super::FloatingElement) when it is released.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Snap {
+    /// The element stays exactly where it was released.
+    #[default]
+    None,
+
+    /// The element snaps to the nearest vertical edge (left or right) of the
+    /// underlying element, keeping its vertical position.
+    Edges,
+
+    /// The element snaps to the nearest corner of the underlying element.
+    Corners,
+}
+
+impl Snap {
+    /// Computes the [`Anchor`](Anchor) and [`Offset`](Offset) of an element
+    /// released at the given bounds over the given underlay.
+    ///
+    /// The `margin` is used as the distance to the edges of the underlay when
+    /// snapping to an edge or corner.
+    #[must_use]
+    pub fn resolve(
+        self,
+        element: Rectangle,
+        underlay: Rectangle,
+        margin: Offset,
+    ) -> (Anchor, Offset) {
+        let x = element
+            .x
+            .min(underlay.x + underlay.width - element.width)
+            .max(underlay.x)
+            - underlay.x;
+        let y = element
+            .y
+            .min(underlay.y + underlay.height - element.height)
+            .max(underlay.y)
+            - underlay.y;
+
+        let left = element.center_x() < underlay.center_x();
+        let top = element.center_y() < underlay.center_y();
+
+        match self {
+            Self::None => (Anchor::NorthWest, Offset { x, y }),
+            Self::Edges => (
+                if left {
+                    Anchor::NorthWest
+                } else {
+                    Anchor::NorthEast
+                },
+                Offset { x: margin.x, y },
+            ),
+            Self::Corners => (
+                match (left, top) {
+                    (true, true) => Anchor::NorthWest,
+                    (false, true) => Anchor::NorthEast,
+                    (true, false) => Anchor::SouthWest,
+                    (false, false) => Anchor::SouthEast,
+                },
+                margin,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{Point, Rectangle, Size};
+
+    use super::{Anchor, Snap};
+
+    /// The bounds of the underlay used by the tests.
+    const UNDERLAY: Rectangle = Rectangle {
+        x: 100.0,
+        y: 50.0,
+        width: 400.0,
+        height: 300.0,
+    };
+
+    /// Returns the bounds of a 40x20 element at the given position.
+    fn element(x: f32, y: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(40.0, 20.0))
+    }
+
+    #[test]
+    fn none_keeps_the_position() {
+        let (anchor, offset) = Snap::None.resolve(element(230.0, 120.0), UNDERLAY, 8.0.into());
+
+        assert_eq!(anchor, Anchor::NorthWest);
+        assert_eq!(Point::from(offset), Point::new(130.0, 70.0));
+    }
+
+    #[test]
+    fn none_clamps_into_the_underlay() {
+        let (anchor, offset) = Snap::None.resolve(element(480.0, 20.0), UNDERLAY, 8.0.into());
+
+        assert_eq!(anchor, Anchor::NorthWest);
+        assert_eq!(Point::from(offset), Point::new(360.0, 0.0));
+    }
+
+    #[test]
+    fn edges_snap_to_the_nearest_side() {
+        let (anchor, offset) = Snap::Edges.resolve(element(150.0, 200.0), UNDERLAY, 8.0.into());
+
+        assert_eq!(anchor, Anchor::NorthWest);
+        assert_eq!(Point::from(offset), Point::new(8.0, 150.0));
+
+        let (anchor, offset) = Snap::Edges.resolve(element(400.0, 60.0), UNDERLAY, 8.0.into());
+
+        assert_eq!(anchor, Anchor::NorthEast);
+        assert_eq!(Point::from(offset), Point::new(8.0, 10.0));
+    }
+
+    #[test]
+    fn corners_snap_to_the_nearest_corner() {
+        let corners = [
+            (element(120.0, 60.0), Anchor::NorthWest),
+            (element(420.0, 60.0), Anchor::NorthEast),
+            (element(120.0, 300.0), Anchor::SouthWest),
+            (element(420.0, 300.0), Anchor::SouthEast),
+        ];
+
+        for (bounds, expected) in corners {
+            let (anchor, offset) = Snap::Corners.resolve(bounds, UNDERLAY, [8.0, 4.0].into());
+
+            assert_eq!(anchor, expected);
+            assert_eq!(Point::from(offset), Point::new(8.0, 4.0));
+        }
+    }
+}
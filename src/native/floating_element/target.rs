@@ -0,0 +1,230 @@
+//! Mark a descendant of the underlay by an [`Id`](Id) as the anchor of a
+//! [`FloatingElement`](super::FloatingElement).
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+
+use std::any::Any;
+
+use iced_native::{
+    event, layout, mouse, overlay,
+    widget::{operation::Operation, Id, Tree},
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A [`Target`](Target) marks a descendant of the underlay of a
+/// [`FloatingElement`](super::FloatingElement) by an [`Id`](Id), so the
+/// floating element can be anchored to its bounds instead of the bounds of the
+/// whole underlay.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::{button, Button, Column, Id, Text};
+/// # use iced_aw::native::floating_element;
+/// #
+/// # pub type FloatingElement<'a, B, Message> = floating_element::FloatingElement<'a, B, Message, Null>;
+/// # pub type Target<'a, Message> = floating_element::Target<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ButtonPressed,
+/// }
+///
+/// let id = Id::new("settings");
+/// let content = Column::new()
+///     .push(Target::new(id.clone(), Text::new("Settings")));
+/// let floating_element = FloatingElement::new(
+///     content,
+///     || Button::new(Text::new("Help"))
+///         .on_press(Message::ButtonPressed)
+///         .into()
+/// )
+/// .target(id);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Target<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// The [`Id`](Id) of the [`Target`](Target).
+    id: Id,
+    /// The content of the [`Target`](Target).
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Target<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Creates a new [`Target`](Target) with the given [`Id`](Id) wrapping
+    /// the given content.
+    pub fn new<C>(id: Id, content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Target {
+            id,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Target<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut bounds = layout.bounds();
+        operation.custom(&mut bounds, Some(&self.id));
+
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut state.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Target<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(target: Target<'a, Message, Renderer>) -> Self {
+        Element::new(target)
+    }
+}
+
+/// An [`Operation`](Operation) looking up the bounds of a [`Target`](Target)
+/// by its [`Id`](Id).
+pub(crate) struct FindTarget<'a> {
+    /// The [`Id`](Id) of the [`Target`](Target) to look for.
+    id: &'a Id,
+    /// The bounds of the [`Target`](Target), once found.
+    bounds: Option<Rectangle>,
+}
+
+impl<'a> FindTarget<'a> {
+    /// Creates a new [`FindTarget`](FindTarget) operation for the given [`Id`](Id).
+    pub(crate) fn new(id: &'a Id) -> Self {
+        Self { id, bounds: None }
+    }
+
+    /// Returns the bounds of the [`Target`](Target), if it was found.
+    pub(crate) fn bounds(&self) -> Option<Rectangle> {
+        self.bounds
+    }
+}
+
+impl<'a, T> Operation<T> for FindTarget<'a> {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.bounds.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id == Some(self.id) {
+            if let Some(bounds) = state.downcast_ref::<Rectangle>() {
+                self.bounds = Some(*bounds);
+            }
+        }
+    }
+}
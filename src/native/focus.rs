@@ -0,0 +1,104 @@
+//! Move the keyboard focus between the widgets.
+//!
+//! The interactive widgets of this crate, like the
+//! [`TabBar`](crate::native::tab_bar::TabBar) or the divider of the
+//! [`Split`](crate::native::split::Split), are focused by clicking on them or by
+//! the commands of this module, and can be operated by the keyboard while they
+//! are focused. They take part in the focus traversal of iced, so they are
+//! focused in turn with the text inputs. A focused
+//! [`MenuBar`](crate::native::menu::MenuBar) opens and navigates its menus with
+//! the arrow keys, Enter and Space.
+//!
+//! # Example
+//! ```ignore
+//! fn subscription(&self) -> Subscription<Message> {
+//!     iced_native::subscription::events().map(Message::Event)
+//! }
+//!
+//! fn update(&mut self, message: Message) -> Command<Message> {
+//!     match message {
+//!         Message::Event(event) => focus::traverse(&event).unwrap_or_else(Command::none),
+//!         ...
+//!     }
+//! }
+//! ```
+use iced_native::{
+    keyboard,
+    widget::{
+        operation::{focusable, Focusable},
+        Id,
+    },
+    Command, Event,
+};
+
+/// The focus state of a widget.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Focus {
+    /// Whether the widget is focused.
+    is_focused: bool,
+}
+
+impl Focus {
+    /// Creates a new unfocused [`Focus`](Focus).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { is_focused: false }
+    }
+
+    /// Focuses or unfocuses the widget depending on whether it was pressed.
+    pub fn press(&mut self, is_pressed: bool) {
+        self.is_focused = is_pressed;
+    }
+}
+
+impl Focusable for Focus {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Produces a [`Command`](iced_native::Command) focusing the widget with the
+/// given [`Id`](iced_native::widget::Id).
+#[must_use]
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(focusable::focus(id))
+}
+
+/// Produces a [`Command`](iced_native::Command) focusing the next focusable
+/// widget.
+#[must_use]
+pub fn focus_next<Message: 'static>() -> Command<Message> {
+    Command::widget(focusable::focus_next())
+}
+
+/// Produces a [`Command`](iced_native::Command) focusing the previous
+/// focusable widget.
+#[must_use]
+pub fn focus_previous<Message: 'static>() -> Command<Message> {
+    Command::widget(focusable::focus_previous())
+}
+
+/// Returns the [`Command`](iced_native::Command) moving the focus if the event
+/// is a press of Tab or Shift+Tab.
+#[must_use]
+pub fn traverse<Message: 'static>(event: &Event) -> Option<Command<Message>> {
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Tab,
+            modifiers,
+        }) => Some(if modifiers.shift() {
+            focus_previous()
+        } else {
+            focus_next()
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,577 @@
+//! Browse images in a viewer above a strip of thumbnails.
+//!
+//! *This API requires the following crate features to be activated: gallery*
+use iced_native::{
+    event, image, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::gallery::{Appearance, StyleSheet};
+
+/// The default size of the thumbnails.
+const DEFAULT_THUMBNAIL_SIZE: f32 = 64.0;
+
+/// The default space around the thumbnails.
+const DEFAULT_SPACING: f32 = 8.0;
+
+/// A viewer showing the selected image above a strip of thumbnails of all
+/// images.
+///
+/// Pressing a thumbnail selects its image. While the gallery is focused, the
+/// left and right arrow keys select the previous and next image and Home and
+/// End the first and last one. The strip scrolls with the mouse wheel and
+/// follows the selection.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Gallery;
+/// # use iced_native::image::Handle;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Selected(usize),
+/// }
+///
+/// let gallery = Gallery::new(
+///     vec![Handle::from_path("first.png"), Handle::from_path("second.png")],
+///     0,
+///     Message::Selected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Gallery<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The images.
+    images: Vec<<Renderer as image::Renderer>::Handle>,
+    /// The index of the selected image.
+    selected: usize,
+    /// The function producing the message selecting the image at an index.
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The width of the gallery.
+    width: Length,
+    /// The height of the gallery.
+    height: Length,
+    /// The size of the thumbnails.
+    thumbnail_size: f32,
+    /// The space around the thumbnails.
+    spacing: f32,
+    /// The id of the [`Gallery`](Gallery) used to focus it.
+    id: Option<Id>,
+    /// The style of the gallery.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Gallery<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Gallery`](Gallery) with the images.
+    ///
+    /// It expects:
+    ///     * the images
+    ///     * the index of the selected image
+    ///     * the function producing the message selecting the image at an
+    ///       index
+    pub fn new<F>(
+        images: Vec<<Renderer as image::Renderer>::Handle>,
+        selected: usize,
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Gallery {
+            images,
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Fill,
+            height: Length::Fill,
+            thumbnail_size: DEFAULT_THUMBNAIL_SIZE,
+            spacing: DEFAULT_SPACING,
+            id: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Gallery`](Gallery).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Gallery`](Gallery).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the size of the thumbnails.
+    #[must_use]
+    pub fn thumbnail_size(mut self, thumbnail_size: f32) -> Self {
+        self.thumbnail_size = thumbnail_size;
+        self
+    }
+
+    /// Sets the space around the thumbnails.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`Gallery`](Gallery)
+    /// to focus it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`Gallery`](Gallery).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the index of the selected image, if there is any.
+    fn selected(&self) -> Option<usize> {
+        self.images
+            .len()
+            .checked_sub(1)
+            .map(|last| self.selected.min(last))
+    }
+
+    /// Returns the bounds of the viewer and of the strip of thumbnails below
+    /// it.
+    fn areas(&self, bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let strip_height = (self.thumbnail_size + 2.0 * self.spacing).min(bounds.height);
+
+        (
+            Rectangle {
+                height: bounds.height - strip_height,
+                ..bounds
+            },
+            Rectangle {
+                y: bounds.y + bounds.height - strip_height,
+                height: strip_height,
+                ..bounds
+            },
+        )
+    }
+
+    /// Returns the bounds of the thumbnail at the index, scrolled by the
+    /// offset.
+    fn thumbnail_bounds(&self, strip: Rectangle, index: usize, scroll: f32) -> Rectangle {
+        Rectangle {
+            x: strip.x + offset(index, self.thumbnail_size, self.spacing) - scroll,
+            y: strip.y + self.spacing,
+            width: self.thumbnail_size,
+            height: self.thumbnail_size,
+        }
+    }
+
+    /// Returns the index of the thumbnail at the cursor, if any.
+    fn thumbnail_at(&self, strip: Rectangle, scroll: f32, cursor_position: Point) -> Option<usize> {
+        if !strip.contains(cursor_position) {
+            return None;
+        }
+
+        (0..self.images.len()).find(|&index| {
+            self.thumbnail_bounds(strip, index, scroll)
+                .contains(cursor_position)
+        })
+    }
+
+    /// Returns the farthest the strip of thumbnails scrolls.
+    fn max_scroll(&self, strip: Rectangle) -> f32 {
+        let content = offset(self.images.len(), self.thumbnail_size, self.spacing);
+
+        (content - strip.width).max(0.0)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Gallery<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(0.0, self.thumbnail_size + 2.0 * self.spacing)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let (_, strip) = self.areas(bounds);
+        let selected = self.selected();
+        let state = tree.state.downcast_mut::<State>();
+
+        // The strip follows the selection, wherever it came from
+        if state.revealed != selected {
+            if let Some(selected) = selected {
+                state.scroll = reveal(
+                    state.scroll,
+                    selected,
+                    self.thumbnail_size,
+                    self.spacing,
+                    strip.width,
+                );
+            }
+            state.revealed = selected;
+        }
+        state.scroll = state.scroll.clamp(0.0, self.max_scroll(strip));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_pressed = bounds.contains(cursor_position);
+                state.focus.press(is_pressed);
+
+                if !is_pressed {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(index) = self.thumbnail_at(strip, state.scroll, cursor_position) {
+                    if selected != Some(index) {
+                        shell.publish((self.on_select)(index));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.focus.is_focused() =>
+            {
+                let Some(selected) = selected else {
+                    return event::Status::Ignored;
+                };
+                let last = self.images.len() - 1;
+
+                let target = match key_code {
+                    keyboard::KeyCode::Left => selected.saturating_sub(1),
+                    keyboard::KeyCode::Right => (selected + 1).min(last),
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    _ => return event::Status::Ignored,
+                };
+
+                if target != selected {
+                    shell.publish((self.on_select)(target));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if strip.contains(cursor_position) =>
+            {
+                // A vertical wheel scrolls the strip as well
+                let (x, y, unit) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x, y, self.thumbnail_size + self.spacing)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y, 1.0),
+                };
+                let amount = if x.abs() > y.abs() { x } else { y };
+
+                state.scroll = (state.scroll - amount * unit).clamp(0.0, self.max_scroll(strip));
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let (_, strip) = self.areas(layout.bounds());
+
+        if self
+            .thumbnail_at(strip, state.scroll, cursor_position)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let (viewer, strip) = self.areas(bounds);
+        let state = tree.state.downcast_ref::<State>();
+        let scroll = state.scroll.clamp(0.0, self.max_scroll(strip));
+        let selected = self.selected();
+
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                false,
+                state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: viewer,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        if let Some(selected) = selected {
+            let handle = &self.images[selected];
+            let dimensions = renderer.dimensions(handle);
+
+            renderer.draw(
+                handle.clone(),
+                contain(
+                    viewer,
+                    Size::new(dimensions.width as f32, dimensions.height as f32),
+                ),
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: strip,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.strip_background,
+        );
+
+        let hovered = self.thumbnail_at(strip, scroll, cursor_position);
+
+        renderer.with_layer(strip, |renderer| {
+            for (index, handle) in self.images.iter().enumerate() {
+                let thumbnail = self.thumbnail_bounds(strip, index, scroll);
+                if thumbnail.x + thumbnail.width < strip.x || thumbnail.x > strip.x + strip.width {
+                    continue;
+                }
+
+                let dimensions = renderer.dimensions(handle);
+
+                // Thumbnails are square, so the images are cropped to them
+                renderer.with_layer(thumbnail, |renderer| {
+                    renderer.draw(
+                        handle.clone(),
+                        cover(
+                            thumbnail,
+                            Size::new(dimensions.width as f32, dimensions.height as f32),
+                        ),
+                    );
+                });
+
+                let border_color = if selected == Some(index) {
+                    appearance.selected_border_color
+                } else if hovered == Some(index) {
+                    appearance.hovered_border_color
+                } else {
+                    continue;
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: thumbnail,
+                        border_radius: 0.0.into(),
+                        border_width: appearance.border_width,
+                        border_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Renderer> From<Gallery<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + image::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(gallery: Gallery<'a, Message, Renderer>) -> Self {
+        Element::new(gallery)
+    }
+}
+
+/// The state of a [`Gallery`](Gallery).
+#[derive(Debug, Default)]
+struct State {
+    /// How far the strip of thumbnails is scrolled.
+    scroll: f32,
+    /// The image the strip was last scrolled to.
+    revealed: Option<usize>,
+    /// The keyboard focus of the gallery.
+    focus: Focus,
+}
+
+/// Returns the horizontal offset of the thumbnail at the index in the strip.
+fn offset(index: usize, thumbnail_size: f32, spacing: f32) -> f32 {
+    spacing + index as f32 * (thumbnail_size + spacing)
+}
+
+/// Returns the scroll offset closest to the current one showing the whole
+/// thumbnail at the index in a strip of the width.
+fn reveal(scroll: f32, index: usize, thumbnail_size: f32, spacing: f32, width: f32) -> f32 {
+    let start = offset(index, thumbnail_size, spacing) - spacing;
+    let end = start + thumbnail_size + 2.0 * spacing;
+
+    if start < scroll {
+        start
+    } else if end > scroll + width {
+        end - width
+    } else {
+        scroll
+    }
+}
+
+/// Returns the bounds of an image of the size scaled to fit into the bounds
+/// and centered in them.
+fn contain(bounds: Rectangle, image: Size) -> Rectangle {
+    fit(bounds, image, f32::min)
+}
+
+/// Returns the bounds of an image of the size scaled to cover the bounds and
+/// centered in them.
+fn cover(bounds: Rectangle, image: Size) -> Rectangle {
+    fit(bounds, image, f32::max)
+}
+
+/// Returns the bounds of an image of the size scaled by the chosen one of
+/// the horizontal and vertical scales and centered in the bounds.
+fn fit(bounds: Rectangle, image: Size, choose: fn(f32, f32) -> f32) -> Rectangle {
+    if image.width <= 0.0 || image.height <= 0.0 {
+        return bounds;
+    }
+
+    let scale = choose(bounds.width / image.width, bounds.height / image.height);
+    let width = image.width * scale;
+    let height = image.height * scale;
+
+    Rectangle {
+        x: bounds.center_x() - width / 2.0,
+        y: bounds.center_y() - height / 2.0,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::{Rectangle, Size};
+
+    use super::{contain, cover, reveal};
+
+    #[test]
+    fn contained_images_are_letterboxed() {
+        let bounds = Rectangle::new([0.0, 0.0].into(), Size::new(400.0, 200.0));
+
+        let image = contain(bounds, Size::new(100.0, 100.0));
+
+        assert_eq!(
+            image,
+            Rectangle::new([100.0, 0.0].into(), Size::new(200.0, 200.0))
+        );
+    }
+
+    #[test]
+    fn covering_images_overflow() {
+        let bounds = Rectangle::new([0.0, 0.0].into(), Size::new(64.0, 64.0));
+
+        let image = cover(bounds, Size::new(200.0, 100.0));
+
+        assert_eq!(
+            image,
+            Rectangle::new([-32.0, 0.0].into(), Size::new(128.0, 64.0))
+        );
+    }
+
+    #[test]
+    fn reveal_scrolls_as_little_as_possible() {
+        // Thumbnails of 64 with a spacing of 8 start every 72 pixels
+        assert_eq!(reveal(0.0, 1, 64.0, 8.0, 200.0), 0.0);
+        assert_eq!(reveal(0.0, 3, 64.0, 8.0, 200.0), 96.0);
+        assert_eq!(reveal(300.0, 2, 64.0, 8.0, 200.0), 144.0);
+    }
+}
@@ -0,0 +1,538 @@
+//! Show a value on an arc between a minimum and a maximum.
+//!
+//! *This API requires the following crate features to be activated: gauge*
+use std::{
+    f32::consts::{FRAC_PI_2, PI},
+    ops::RangeInclusive,
+};
+
+use iced_graphics::{
+    widget::canvas::{path, Frame, LineCap, Path, Stroke},
+    Backend, Renderer,
+};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    renderer,
+    text::{self, Renderer as _},
+    time::{Duration, Instant},
+    widget::tree::{self, Tree},
+    window, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle,
+    Renderer as _, Shell, Size, Vector, Widget,
+};
+
+use crate::core::animation::{Animation, Easing};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::gauge::{Appearance, StyleSheet};
+
+/// The default diameter of the gauge.
+const DEFAULT_SIZE: f32 = 160.0;
+
+/// The default thickness of the arc.
+const DEFAULT_THICKNESS: f32 = 12.0;
+
+/// The time the indicator takes to move to a new value.
+const TRANSITION: Duration = Duration::from_millis(400);
+
+/// The angle of the minimum, at the bottom left.
+const START_ANGLE: f32 = 3.0 * PI / 4.0;
+
+/// The angle the arc sweeps clockwise from the minimum to the maximum.
+const SWEEP: f32 = 3.0 * FRAC_PI_2;
+
+/// The thickness of the zones relative to the thickness of the arc.
+const ZONE_THICKNESS: f32 = 0.35;
+
+/// The radius of the hub of the needle relative to the thickness of the arc.
+const HUB_RADIUS: f32 = 0.6;
+
+/// How a [`Gauge`](Gauge) indicates its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Indicator {
+    /// The arc fills from the minimum up to the value.
+    #[default]
+    Fill,
+    /// A needle points from the center at the value.
+    Needle,
+}
+
+/// A gauge showing a value on an arc between a minimum and a maximum.
+///
+/// Zones color parts of the range, like a warning and a critical zone, and
+/// the filled arc takes the color of the zone its value is in. When the value
+/// changes, the indicator moves smoothly to it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{gauge::Indicator, Gauge};
+/// # use iced_native::Color;
+/// #
+/// let temperature = Gauge::new(0.0..=120.0, 87.0)
+///     .zone(80.0..=100.0, Color::from_rgb(0.9, 0.6, 0.1))
+///     .zone(100.0..=120.0, Color::from_rgb(0.8, 0.2, 0.2))
+///     .indicator(Indicator::Needle)
+///     .label("87 °C");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Gauge<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// The range of the values.
+    range: RangeInclusive<f32>,
+    /// The value.
+    value: f32,
+    /// The zones coloring parts of the range.
+    zones: Vec<(RangeInclusive<f32>, Color)>,
+    /// How the value is indicated.
+    indicator: Indicator,
+    /// The diameter of the gauge.
+    size: f32,
+    /// The thickness of the arc.
+    thickness: f32,
+    /// The label in the center of the gauge.
+    label: Option<String>,
+    /// Whether the bounds of the range are shown at the ends of the arc.
+    bounds: bool,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the gauge.
+    style: <Theme as StyleSheet>::Style,
+    #[allow(missing_docs)]
+    backend: std::marker::PhantomData<B>,
+}
+
+impl<B, Theme> Gauge<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Gauge`](Gauge) showing the value in the range.
+    #[must_use]
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self {
+            range,
+            value,
+            zones: Vec::new(),
+            indicator: Indicator::default(),
+            size: DEFAULT_SIZE,
+            thickness: DEFAULT_THICKNESS,
+            label: None,
+            bounds: true,
+            text_size: None,
+            font: Font::default(),
+            style: <Theme as StyleSheet>::Style::default(),
+            backend: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds a zone coloring a part of the range.
+    ///
+    /// Later zones are drawn over earlier ones where they overlap.
+    #[must_use]
+    pub fn zone(mut self, range: RangeInclusive<f32>, color: Color) -> Self {
+        self.zones.push((range, color));
+        self
+    }
+
+    /// Sets how the [`Gauge`](Gauge) indicates its value.
+    #[must_use]
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Sets the diameter of the [`Gauge`](Gauge).
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the thickness of the arc.
+    #[must_use]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the label in the center of the [`Gauge`](Gauge).
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the bounds of the range are shown at the ends of the arc.
+    #[must_use]
+    pub fn bounds(mut self, bounds: bool) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Gauge`](Gauge).
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, B, Theme> Widget<Message, Renderer<B, Theme>> for Gauge<B, Theme>
+where
+    B: Backend + iced_graphics::backend::Text,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.value))
+    }
+
+    fn width(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(Length::Fixed(self.size))
+                .height(Length::Fixed(self.size))
+                .resolve(Size::new(self.size, self.size)),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer<B, Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.set(self.value, now);
+            state.animation.request_redraw(now, shell);
+            state.now = Some(now);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        // The arc is stroked on its center line, so it stays inside the bounds
+        let radius = (bounds.width.min(bounds.height) - self.thickness) / 2.0;
+        if radius <= 0.0 {
+            return;
+        }
+
+        let value = state.value(state.now.unwrap_or_else(Instant::now));
+        let value_angle = angle(fraction(&self.range, value));
+
+        let mut frame = Frame::new(bounds.size());
+        let center = frame.center();
+        let arc = |from: f32, to: f32, radius: f32, width: f32, color: Color| {
+            (
+                Path::new(|builder| {
+                    builder.arc(path::Arc {
+                        center,
+                        radius,
+                        start_angle: from,
+                        end_angle: to,
+                    });
+                }),
+                Stroke::default()
+                    .with_color(color)
+                    .with_width(width)
+                    .with_line_cap(LineCap::Butt),
+            )
+        };
+
+        let (track, stroke) = arc(
+            START_ANGLE,
+            START_ANGLE + SWEEP,
+            radius,
+            self.thickness,
+            appearance.track_color,
+        );
+        frame.stroke(&track, stroke);
+
+        // The zones run along the inner edge of the arc
+        let zone_width = self.thickness * ZONE_THICKNESS;
+        for (range, color) in &self.zones {
+            let from = angle(fraction(&self.range, *range.start()));
+            let to = angle(fraction(&self.range, *range.end()));
+
+            if to > from {
+                let (zone, stroke) = arc(
+                    from,
+                    to,
+                    radius - (self.thickness - zone_width) / 2.0,
+                    zone_width,
+                    *color,
+                );
+                frame.stroke(&zone, stroke);
+            }
+        }
+
+        match self.indicator {
+            Indicator::Fill => {
+                if value_angle > START_ANGLE {
+                    let color = zone_color(&self.zones, value).unwrap_or(appearance.bar_color);
+                    let (bar, stroke) =
+                        arc(START_ANGLE, value_angle, radius, self.thickness, color);
+                    frame.stroke(&bar, stroke);
+                }
+            }
+            Indicator::Needle => {
+                let tip = center
+                    + Vector::new(value_angle.cos(), value_angle.sin())
+                        * (radius + self.thickness / 2.0);
+
+                frame.stroke(
+                    &Path::line(center, tip),
+                    Stroke::default()
+                        .with_color(appearance.needle_color)
+                        .with_width((self.thickness / 4.0).max(1.0))
+                        .with_line_cap(LineCap::Round),
+                );
+                frame.fill(
+                    &Path::circle(center, self.thickness * HUB_RADIUS),
+                    appearance.needle_color,
+                );
+            }
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_primitive(frame.into_geometry().into_primitive());
+        });
+
+        let center = bounds.center();
+
+        if let Some(label) = &self.label {
+            // The needle covers the center, so the label moves below it
+            let y = match self.indicator {
+                Indicator::Fill => center.y,
+                Indicator::Needle => center.y + radius / 2.0,
+            };
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: center.x,
+                    y,
+                    ..bounds
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        if self.bounds {
+            for (bound, angle) in [
+                (*self.range.start(), START_ANGLE),
+                (*self.range.end(), START_ANGLE + SWEEP),
+            ] {
+                renderer.fill_text(text::Text {
+                    content: &bound.to_string(),
+                    bounds: Rectangle {
+                        x: center.x + angle.cos() * radius,
+                        y: center.y + angle.sin() * radius + self.thickness / 2.0,
+                        ..bounds
+                    },
+                    size: text_size * 0.8,
+                    color: appearance.text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Top,
+                });
+            }
+        }
+    }
+}
+
+impl<'a, Message, B, Theme> From<Gauge<B, Theme>> for Element<'a, Message, Renderer<B, Theme>>
+where
+    B: 'a + Backend + iced_graphics::backend::Text,
+    Theme: 'a + StyleSheet,
+{
+    fn from(gauge: Gauge<B, Theme>) -> Self {
+        Element::new(gauge)
+    }
+}
+
+/// The state of a [`Gauge`](Gauge).
+#[derive(Debug)]
+struct State {
+    /// The value the indicator moves from.
+    from: f32,
+    /// The value the indicator moves to.
+    to: f32,
+    /// The animation moving the indicator.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) resting at the value.
+    fn new(value: f32) -> Self {
+        Self {
+            from: value,
+            to: value,
+            animation: Animation::new(TRANSITION).easing(Easing::EaseOut),
+            now: None,
+        }
+    }
+
+    /// Returns the value shown by the indicator.
+    fn value(&self, now: Instant) -> f32 {
+        if self.animation.is_running(now) {
+            self.animation.interpolate(self.from, self.to, now)
+        } else {
+            self.to
+        }
+    }
+
+    /// Moves the indicator from where it is to the value, if it changed.
+    #[allow(clippy::float_cmp)]
+    fn set(&mut self, value: f32, now: Instant) {
+        if value == self.to {
+            return;
+        }
+
+        self.from = self.value(now);
+        self.to = value;
+        self.animation = Animation::new(TRANSITION).easing(Easing::EaseOut);
+        self.animation.go(true, now);
+    }
+}
+
+/// Returns the position of the value in the range between 0.0 and 1.0.
+fn fraction(range: &RangeInclusive<f32>, value: f32) -> f32 {
+    let span = range.end() - range.start();
+
+    if span > 0.0 && value.is_finite() {
+        ((value - range.start()) / span).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns the angle of the arc at the position between 0.0 and 1.0.
+fn angle(fraction: f32) -> f32 {
+    START_ANGLE + fraction * SWEEP
+}
+
+/// Returns the color of the last zone the value is in, if any.
+fn zone_color(zones: &[(RangeInclusive<f32>, Color)], value: f32) -> Option<Color> {
+    zones
+        .iter()
+        .rev()
+        .find(|(range, _)| range.contains(&value))
+        .map(|(_, color)| *color)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::{
+        time::{Duration, Instant},
+        Color,
+    };
+
+    use super::{angle, fraction, zone_color, State, START_ANGLE, SWEEP, TRANSITION};
+
+    #[test]
+    fn fraction_is_clamped_to_the_range() {
+        assert_eq!(fraction(&(0.0..=200.0), 50.0), 0.25);
+        assert_eq!(fraction(&(-10.0..=10.0), -20.0), 0.0);
+        assert_eq!(fraction(&(-10.0..=10.0), 20.0), 1.0);
+        assert_eq!(fraction(&(5.0..=5.0), 5.0), 0.0);
+        assert_eq!(fraction(&(0.0..=1.0), f32::NAN), 0.0);
+    }
+
+    #[test]
+    fn angle_sweeps_clockwise_from_the_minimum() {
+        assert_eq!(angle(0.0), START_ANGLE);
+        assert_eq!(angle(1.0), START_ANGLE + SWEEP);
+    }
+
+    #[test]
+    fn last_zone_wins() {
+        let zones = [(0.0..=10.0, Color::WHITE), (5.0..=10.0, Color::BLACK)];
+
+        assert_eq!(zone_color(&zones, 2.0), Some(Color::WHITE));
+        assert_eq!(zone_color(&zones, 7.0), Some(Color::BLACK));
+        assert_eq!(zone_color(&zones, 12.0), None);
+    }
+
+    #[test]
+    fn value_moves_to_the_new_value() {
+        let now = Instant::now();
+        let mut state = State::new(10.0);
+
+        state.set(10.0, now);
+        assert!(!state.animation.is_running(now));
+
+        state.set(20.0, now);
+        assert_eq!(state.value(now), 10.0);
+        assert!(state.value(now + TRANSITION / 2) > 10.0);
+        assert_eq!(
+            state.value(now + TRANSITION + Duration::from_millis(1)),
+            20.0
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Recognize touch gestures on arbitrary content.
+//!
+//! *This API requires the following crate features to be activated: gesture_detector*
+use iced_native::{
+    event, mouse,
+    time::Instant,
+    widget::{
+        tree::{self, Tag},
+        Operation, Tree,
+    },
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use crate::core::gesture::{Gesture, Recognizer, Swipe};
+
+/// A wrapper recognizing taps, double taps, long presses and swipes on its
+/// content.
+///
+/// The content receives all events as usual, so it stays interactive.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::gesture_detector;
+/// # use iced_aw::core::gesture::Swipe;
+/// #
+/// # pub type GestureDetector<'a, Message> = gesture_detector::GestureDetector<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     LongPressed,
+///     Swiped(Swipe),
+/// }
+///
+/// let gesture_detector = GestureDetector::new(Text::new("Touch me"))
+///     .on_long_press(Message::LongPressed)
+///     .on_swipe(Message::Swiped);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GestureDetector<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// The content of the [`GestureDetector`](GestureDetector).
+    content: Element<'a, Message, Renderer>,
+    /// The message that is send when the content is tapped.
+    on_tap: Option<Message>,
+    /// The message that is send when the content is tapped twice.
+    on_double_tap: Option<Message>,
+    /// The message that is send when a finger rests on the content.
+    on_long_press: Option<Message>,
+    /// The function that produces a message when the content is swiped.
+    on_swipe: Option<Box<dyn Fn(Swipe) -> Message + 'a>>,
+}
+
+impl<'a, Message, Renderer> GestureDetector<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Creates a new [`GestureDetector`](GestureDetector) wrapping the content.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        GestureDetector {
+            content: content.into(),
+            on_tap: None,
+            on_double_tap: None,
+            on_long_press: None,
+            on_swipe: None,
+        }
+    }
+
+    /// Sets the message that is send when the content is tapped.
+    #[must_use]
+    pub fn on_tap(mut self, message: Message) -> Self {
+        self.on_tap = Some(message);
+        self
+    }
+
+    /// Sets the message that is send when the content is tapped twice in
+    /// quick succession.
+    #[must_use]
+    pub fn on_double_tap(mut self, message: Message) -> Self {
+        self.on_double_tap = Some(message);
+        self
+    }
+
+    /// Sets the message that is send when a finger rests on the content.
+    #[must_use]
+    pub fn on_long_press(mut self, message: Message) -> Self {
+        self.on_long_press = Some(message);
+        self
+    }
+
+    /// Sets the function that produces a message when the content is swiped.
+    #[must_use]
+    pub fn on_swipe<F>(mut self, on_swipe: F) -> Self
+    where
+        F: 'a + Fn(Swipe) -> Message,
+    {
+        self.on_swipe = Some(Box::new(on_swipe));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for GestureDetector<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<Recognizer>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Recognizer::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let recognizer: &mut Recognizer = state.state.downcast_mut();
+
+        let message = match recognizer.on_event(&event, layout.bounds(), Instant::now()) {
+            Some(Gesture::Tap(_)) => self.on_tap.clone(),
+            Some(Gesture::DoubleTap(_)) => self.on_double_tap.clone(),
+            Some(Gesture::LongPress(_)) => self.on_long_press.clone(),
+            Some(Gesture::Swipe(swipe)) => self.on_swipe.as_ref().map(|on_swipe| on_swipe(swipe)),
+            None => None,
+        };
+        if self.on_long_press.is_some() {
+            recognizer.request_redraw(shell);
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        match message {
+            Some(message) => {
+                shell.publish(message);
+                event::Status::Captured
+            }
+            None => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut state.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<GestureDetector<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(gesture_detector: GestureDetector<'a, Message, Renderer>) -> Self {
+        Element::new(gesture_detector)
+    }
+}
@@ -2,14 +2,21 @@
 //!
 //! *This API requires the following crate features to be activated: `grid`*
 use iced_native::{
-    event, layout::Node, mouse, Clipboard, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    event,
+    layout::{Limits, Node},
+    mouse, Clipboard, Event, Layout, Length, Point, Rectangle, Shell, Size,
 };
 use iced_native::{
     overlay,
-    widget::{Operation, Tree},
+    widget::{
+        tree::{self, Tag},
+        Operation, Tree,
+    },
     Element, Widget,
 };
 
+use crate::core::layout_cache::LayoutCache;
+
 /// A container that distributes its contents in a grid.
 ///
 /// # Example
@@ -37,10 +44,18 @@ pub struct Grid<'a, Message, Renderer> {
     strategy: Strategy,
     /// The elements in the [`Grid`](Grid).
     elements: Vec<Element<'a, Message, Renderer>>,
+    /// The key the layout of the [`Grid`](Grid) is kept for.
+    layout_key: Option<u64>,
+    /// The layout of the [`Grid`](Grid) kept in the state.
+    layout: LayoutCache<LayoutKey>,
 }
 
+/// The key, the number of elements and the [`Strategy`](Strategy) a layout
+/// of a [`Grid`](Grid) is kept for.
+type LayoutKey = (u64, usize, Strategy);
+
 /// The [`Strategy`](Strategy) of how to distribute the columns of the [`Grid`](Grid).
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Strategy {
     /// Use `n` columns.
     Columns(usize),
@@ -72,6 +87,8 @@ where
         Self {
             strategy: Strategy::default(),
             elements: children,
+            layout_key: None,
+            layout: LayoutCache::default(),
         }
     }
 
@@ -82,6 +99,8 @@ where
         Self {
             strategy: Strategy::Columns(columns),
             elements: Vec::new(),
+            layout_key: None,
+            layout: LayoutCache::default(),
         }
     }
 
@@ -92,6 +111,8 @@ where
         Self {
             strategy: Strategy::ColumnWidth(column_width),
             elements: Vec::new(),
+            layout_key: None,
+            layout: LayoutCache::default(),
         }
     }
 
@@ -113,6 +134,20 @@ where
         self
     }
 
+    /// Keeps the layout of the [`Grid`](Grid) in its state while the key
+    /// stays the same.
+    ///
+    /// The [`Grid`](Grid) can't tell when its elements change their size, so
+    /// it lays them all out whenever the view is rebuilt. With a key that
+    /// changes along with the elements, e.g. a hash of their content, it only
+    /// lays them out again when the key, the number of elements or the
+    /// available space changes.
+    #[must_use]
+    pub fn layout_key(mut self, key: u64) -> Self {
+        self.layout_key = Some(key);
+        self
+    }
+
     /// Inserts an [`Element`](Element) into the [`Grid`](Grid).
     pub fn insert<E>(&mut self, element: E)
     where
@@ -120,45 +155,9 @@ where
     {
         self.elements.push(element.into());
     }
-}
 
-impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer>
-where
-    Renderer: iced_native::Renderer,
-{
-    fn default() -> Self {
-        Self {
-            strategy: Strategy::default(),
-            elements: Vec::new(),
-        }
-    }
-}
-
-impl<'a, Message, Renderer> Widget<Message, Renderer> for Grid<'a, Message, Renderer>
-where
-    Renderer: iced_native::Renderer,
-{
-    fn children(&self) -> Vec<Tree> {
-        self.elements.iter().map(Tree::new).collect()
-    }
-
-    fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.elements);
-    }
-
-    fn width(&self) -> Length {
-        Length::Shrink
-    }
-
-    fn height(&self) -> Length {
-        Length::Shrink
-    }
-
-    fn layout(
-        &self,
-        renderer: &Renderer,
-        limits: &iced_native::layout::Limits,
-    ) -> iced_native::layout::Node {
+    /// Lays out the cells of the [`Grid`](Grid).
+    fn layout_cells(&self, renderer: &Renderer, limits: &Limits) -> Node {
         if self.elements.is_empty() {
             return Node::new(Size::ZERO);
         }
@@ -193,7 +192,7 @@ where
                         });
                 let grid_width = column_widths.iter().sum();
 
-                build_grid(columns, column_aligns, layouts.into_iter(), grid_width)
+                build_grid(columns, column_aligns, layouts, grid_width)
             }
             // find number of columns by checking how many can fit
             Strategy::ColumnWidth(column_width) => {
@@ -201,10 +200,15 @@ where
                 let max_width = limits.max().width;
                 let columns = (max_width / column_width).floor() as usize;
 
+                if columns == 0 {
+                    return Node::new(Size::ZERO);
+                }
+
                 let layouts = self
                     .elements
                     .iter()
-                    .map(|element| element.as_widget().layout(renderer, &column_limits));
+                    .map(|element| element.as_widget().layout(renderer, &column_limits))
+                    .collect();
                 let column_aligns =
                     std::iter::successors(Some(0.), |width| Some(width + column_width));
                 #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
@@ -214,6 +218,67 @@ where
             }
         }
     }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn default() -> Self {
+        Self {
+            strategy: Strategy::default(),
+            elements: Vec::new(),
+            layout_key: None,
+            layout: LayoutCache::default(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Grid<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<LayoutCache<LayoutKey>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(LayoutCache::<LayoutKey>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.layout
+            .share(tree.state.downcast_ref::<LayoutCache<LayoutKey>>());
+        tree.diff_children(&self.elements);
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        match self.layout_key {
+            Some(key) => {
+                self.layout
+                    .layout((key, self.elements.len(), self.strategy), limits, || {
+                        self.layout_cells(renderer, limits)
+                    })
+            }
+            None => self.layout_cells(renderer, limits),
+        }
+    }
 
     fn on_event(
         &mut self,
@@ -324,17 +389,23 @@ where
 }
 
 /// Builds the layout of the [`Grid`](grid).
+///
+/// The nodes of the cells are moved into place without being copied into a
+/// new buffer.
 fn build_grid(
     columns: usize,
     column_aligns: impl Iterator<Item = f32> + Clone,
-    layouts: impl Iterator<Item = Node> + ExactSizeIterator,
+    mut nodes: Vec<Node>,
     grid_width: f32,
 ) -> Node {
-    let mut nodes = Vec::with_capacity(layouts.len());
     let mut grid_height = 0.;
     let mut row_height = 0.;
 
-    for ((column, column_align), mut node) in (0..columns).zip(column_aligns).cycle().zip(layouts) {
+    for ((column, column_align), node) in (0..columns)
+        .zip(column_aligns)
+        .cycle()
+        .zip(nodes.iter_mut())
+    {
         if column == 0 {
             grid_height += row_height;
             row_height = 0.;
@@ -342,7 +413,6 @@ fn build_grid(
 
         node.move_to(Point::new(column_align, grid_height));
         row_height = row_height.max(node.size().height);
-        nodes.push(node);
     }
 
     grid_height += row_height;
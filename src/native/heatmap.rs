@@ -0,0 +1,691 @@
+//! Show a matrix of values as colored cells.
+//!
+//! *This API requires the following crate features to be activated: heatmap*
+use std::ops::RangeInclusive;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::tree::{self, Tree},
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::animation::Interpolate,
+    native::overlay::cell_tooltip::{Bubble, CellTooltipOverlay},
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::heatmap::{Appearance, StyleSheet};
+
+/// The default size of a cell.
+const DEFAULT_CELL_SIZE: f32 = 28.0;
+
+/// The default space between the cells.
+const DEFAULT_SPACING: f32 = 2.0;
+
+/// The default text size of the labels and the tooltip.
+const DEFAULT_TEXT_SIZE: f32 = 12.0;
+
+/// How the values of a [`Heatmap`](Heatmap) are mapped to the colors of its
+/// cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ColorScale {
+    /// The colors are blended from the low color at the lowest value to the
+    /// high color at the highest value.
+    #[default]
+    Sequential,
+    /// The colors are blended from the low color to the middle color at the
+    /// center value and on to the high color, like for correlations centered
+    /// on zero.
+    Diverging(f32),
+}
+
+/// A heatmap showing a matrix of values as colored cells, like a correlation
+/// matrix or a weekly schedule.
+///
+/// The values are given row by row and colored by their place in the range
+/// of the values, following the [`ColorScale`](ColorScale). Rows and columns
+/// can be labelled, hovering a cell shows its labels and value in a tooltip
+/// and clicking it sends a message with its row and column. Values that are
+/// `NaN` and cells missing from shorter rows are shown as empty.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::heatmap;
+/// #
+/// # pub type Heatmap<'a, Message> = heatmap::Heatmap<'a, Message, Null>;
+/// use iced_aw::native::heatmap::ColorScale;
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Cell(usize, usize),
+/// }
+///
+/// let correlations = vec![
+///     vec![1.0, 0.8, -0.3],
+///     vec![0.8, 1.0, -0.1],
+///     vec![-0.3, -0.1, 1.0],
+/// ];
+///
+/// let heatmap = Heatmap::new(correlations)
+///     .row_labels(["Height", "Weight", "Age"])
+///     .column_labels(["Height", "Weight", "Age"])
+///     .scale(ColorScale::Diverging(0.0))
+///     .range(-1.0..=1.0)
+///     .on_click(Message::Cell);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Heatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The values of the cells, row by row.
+    values: Vec<Vec<f32>>,
+    /// The labels left of the rows.
+    row_labels: Vec<String>,
+    /// The labels above the columns.
+    column_labels: Vec<String>,
+    /// How the values are mapped to colors.
+    scale: ColorScale,
+    /// The range of the values mapped to colors.
+    range: Option<RangeInclusive<f32>>,
+    /// The function producing the message when a cell is clicked.
+    on_click: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    /// The function producing the text of the tooltip of a cell.
+    tooltip: Option<Box<dyn Fn(usize, usize, f32) -> String + 'a>>,
+    /// The smallest size of a cell.
+    cell_size: f32,
+    /// The space between the cells.
+    spacing: f32,
+    /// The text size of the labels and the tooltip.
+    text_size: f32,
+    /// The font of the labels and the tooltip.
+    font: Font,
+    /// The style of the heatmap.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Heatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Heatmap`](Heatmap) showing the values, given row by
+    /// row.
+    pub fn new(values: Vec<Vec<f32>>) -> Self {
+        Self {
+            values,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            scale: ColorScale::default(),
+            range: None,
+            on_click: None,
+            tooltip: None,
+            cell_size: DEFAULT_CELL_SIZE,
+            spacing: DEFAULT_SPACING,
+            text_size: DEFAULT_TEXT_SIZE,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the labels left of the rows, from the top.
+    #[must_use]
+    pub fn row_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the labels above the columns, from the left.
+    ///
+    /// The columns are widened to fit their labels.
+    #[must_use]
+    pub fn column_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the [`ColorScale`](ColorScale) mapping the values to colors.
+    #[must_use]
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the range of the values mapped to colors, values outside of it
+    /// get the colors of its ends.
+    ///
+    /// By default the range spans the values, and is centered on the center
+    /// of a diverging [`ColorScale`](ColorScale).
+    #[must_use]
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the function producing the message when a cell is clicked from
+    /// its row and column.
+    #[must_use]
+    pub fn on_click<F>(mut self, on_click: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Sets the function producing the text of the tooltip of a cell from its
+    /// row, column and value.
+    ///
+    /// By default the tooltip shows the labels of the cell and its value.
+    /// Cells without a value always show the default tooltip.
+    #[must_use]
+    pub fn tooltip<F>(mut self, tooltip: F) -> Self
+    where
+        F: 'a + Fn(usize, usize, f32) -> String,
+    {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the smallest size of a cell.
+    #[must_use]
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the space between the cells.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels and the tooltip.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels and the tooltip.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Heatmap`](Heatmap).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the number of rows and columns shown.
+    fn dimensions(&self) -> (usize, usize) {
+        let columns = self.values.iter().map(Vec::len).max().unwrap_or(0);
+
+        (
+            self.values.len().max(self.row_labels.len()),
+            columns.max(self.column_labels.len()),
+        )
+    }
+
+    /// Returns the value of the cell, if it has one.
+    fn value(&self, row: usize, column: usize) -> Option<f32> {
+        self.values
+            .get(row)
+            .and_then(|values| values.get(column))
+            .copied()
+            .filter(|value| !value.is_nan())
+    }
+
+    /// Returns the width of the labels left of the rows.
+    fn row_label_width(&self, renderer: &Renderer) -> f32 {
+        if self.row_labels.is_empty() {
+            return 0.0;
+        }
+
+        self.row_labels
+            .iter()
+            .map(|label| renderer.measure_width(label, self.text_size, self.font))
+            .fold(0.0, f32::max)
+            + 2.0 * self.spacing
+    }
+
+    /// Returns the height of the labels above the columns.
+    fn column_label_height(&self) -> f32 {
+        if self.column_labels.is_empty() {
+            0.0
+        } else {
+            self.text_size + 2.0 * self.spacing
+        }
+    }
+
+    /// Returns the size of a cell, widened to fit the labels of the columns.
+    fn cell(&self, renderer: &Renderer) -> Size {
+        let width = self
+            .column_labels
+            .iter()
+            .map(|label| renderer.measure_width(label, self.text_size, self.font))
+            .fold(self.cell_size, f32::max);
+
+        Size::new(width, self.cell_size)
+    }
+
+    /// Returns the position of the first cell in the bounds of the heatmap,
+    /// below the labels of the columns and right of the labels of the rows.
+    fn origin(&self, renderer: &Renderer, bounds: Rectangle) -> Point {
+        Point::new(
+            bounds.x + self.row_label_width(renderer),
+            bounds.y + self.column_label_height(),
+        )
+    }
+
+    /// Returns the bounds of the cell.
+    fn cell_bounds(&self, origin: Point, cell: Size, row: usize, column: usize) -> Rectangle {
+        Rectangle {
+            x: origin.x + column as f32 * (cell.width + self.spacing),
+            y: origin.y + row as f32 * (cell.height + self.spacing),
+            width: cell.width,
+            height: cell.height,
+        }
+    }
+
+    /// Returns the row and the column of the cell at the cursor, if any.
+    fn cell_at(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<(usize, usize)> {
+        let origin = self.origin(renderer, bounds);
+        let cell = self.cell(renderer);
+        let (rows, columns) = self.dimensions();
+
+        let row = index_at(cursor_position.y - origin.y, cell.height, self.spacing)?;
+        let column = index_at(cursor_position.x - origin.x, cell.width, self.spacing)?;
+
+        (row < rows && column < columns).then_some((row, column))
+    }
+
+    /// Returns the range of the values mapped to colors.
+    fn resolved_range(&self) -> RangeInclusive<f32> {
+        self.range.clone().unwrap_or_else(|| {
+            let center = match self.scale {
+                ColorScale::Sequential => None,
+                ColorScale::Diverging(center) => Some(center),
+            };
+
+            value_range(self.values.iter().flatten().copied(), center)
+        })
+    }
+
+    /// Returns the color of a cell with the value.
+    fn color(&self, appearance: &Appearance, range: &RangeInclusive<f32>, value: f32) -> Color {
+        let t = normalize(value, range);
+
+        match self.scale {
+            ColorScale::Sequential => appearance.low_color.interpolate(appearance.high_color, t),
+            ColorScale::Diverging(center) => {
+                let t = diverge(t, normalize(center, range));
+
+                if t < 0.5 {
+                    appearance
+                        .low_color
+                        .interpolate(appearance.middle_color, 2.0 * t)
+                } else {
+                    appearance
+                        .middle_color
+                        .interpolate(appearance.high_color, 2.0 * t - 1.0)
+                }
+            }
+        }
+    }
+
+    /// Returns the text of the tooltip of the cell.
+    fn tooltip_text(&self, row: usize, column: usize) -> String {
+        let value = self.value(row, column);
+
+        if let (Some(tooltip), Some(value)) = (&self.tooltip, value) {
+            return tooltip(row, column, value);
+        }
+
+        let labels: Vec<&str> = [self.row_labels.get(row), self.column_labels.get(column)]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+
+        match (labels.is_empty(), value) {
+            (true, Some(value)) => value.to_string(),
+            (true, None) => String::from("No value"),
+            (false, Some(value)) => format!("{}: {value}", labels.join(", ")),
+            (false, None) => labels.join(", "),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Heatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let cell = self.cell(renderer);
+        let (rows, columns) = self.dimensions();
+
+        Node::new(limits.resolve(Size::new(
+            self.row_label_width(renderer) + span(columns, cell.width, self.spacing),
+            self.column_label_height() + span(rows, cell.height, self.spacing),
+        )))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                state.hovered = self.cell_at(renderer, bounds, cursor_position);
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(on_click) = &self.on_click else {
+                    return event::Status::Ignored;
+                };
+
+                match self.cell_at(renderer, bounds, cursor_position) {
+                    Some((row, column)) => {
+                        shell.publish(on_click(row, column));
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_click.is_some()
+            && self
+                .cell_at(renderer, layout.bounds(), cursor_position)
+                .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let origin = self.origin(renderer, bounds);
+        let cell = self.cell(renderer);
+        let (rows, columns) = self.dimensions();
+        let range = self.resolved_range();
+
+        for (row, label) in self.row_labels.iter().enumerate() {
+            let cell_bounds = self.cell_bounds(origin, cell, row, 0);
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: cell_bounds.center_y(),
+                    width: origin.x - bounds.x,
+                    height: cell.height,
+                },
+                size: self.text_size,
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        for (column, label) in self.column_labels.iter().enumerate() {
+            let cell_bounds = self.cell_bounds(origin, cell, 0, column);
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: cell_bounds.center_x(),
+                    y: bounds.y,
+                    width: cell.width,
+                    height: self.text_size,
+                },
+                size: self.text_size,
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Top,
+            });
+        }
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let is_hovered = state.hovered == Some((row, column));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.cell_bounds(origin, cell, row, column),
+                        border_radius: appearance.cell_border_radius.into(),
+                        border_width: if is_hovered { 1.0 } else { 0.0 },
+                        border_color: if is_hovered {
+                            appearance.hovered_border_color
+                        } else {
+                            Color::TRANSPARENT
+                        },
+                    },
+                    self.value(row, column)
+                        .map_or(appearance.empty_color, |value| {
+                            self.color(&appearance, &range, value)
+                        }),
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (row, column) = tree.state.downcast_ref::<State>().hovered?;
+        let origin = self.origin(renderer, layout.bounds());
+        let style = self.style;
+
+        Some(
+            CellTooltipOverlay::new(
+                self.tooltip_text(row, column),
+                self.cell_bounds(origin, self.cell(renderer), row, column),
+                self.text_size,
+                self.font,
+                move |theme, state| {
+                    let appearance = appearance!(StyleSheet, theme, state, style);
+
+                    Bubble {
+                        background: appearance.tooltip_background,
+                        border_radius: appearance.tooltip_border_radius,
+                        text_color: appearance.tooltip_text_color,
+                    }
+                },
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Heatmap<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(heatmap: Heatmap<'a, Message, Renderer>) -> Self {
+        Element::new(heatmap)
+    }
+}
+
+/// The state of a [`Heatmap`](Heatmap).
+#[derive(Debug, Default)]
+struct State {
+    /// The row and the column of the cell at the cursor.
+    hovered: Option<(usize, usize)>,
+}
+
+/// Returns the length of the number of cells with the spacing between them.
+fn span(count: usize, length: f32, spacing: f32) -> f32 {
+    (count as f32 * (length + spacing) - spacing).max(0.0)
+}
+
+/// Returns the index of the cell at the offset from the first one, if the
+/// offset is not in the space between two cells.
+fn index_at(offset: f32, length: f32, spacing: f32) -> Option<usize> {
+    let step = length + spacing;
+
+    (offset >= 0.0 && offset % step <= length).then_some((offset / step) as usize)
+}
+
+/// Returns the range spanning the values that are not `NaN`, centered on the
+/// center if there is one.
+fn value_range(values: impl Iterator<Item = f32>, center: Option<f32>) -> RangeInclusive<f32> {
+    let (min, max) = values
+        .filter(|value| !value.is_nan())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+
+    if min > max {
+        return 0.0..=1.0;
+    }
+
+    match center {
+        Some(center) => {
+            let extent = (max - center).abs().max((center - min).abs());
+
+            (center - extent)..=(center + extent)
+        }
+        None => min..=max,
+    }
+}
+
+/// Returns where the value is in the range, between zero and one.
+///
+/// An empty range puts its values in the middle.
+fn normalize(value: f32, range: &RangeInclusive<f32>) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+
+    if (end - start).abs() < f32::EPSILON {
+        return 0.5;
+    }
+
+    ((value - start) / (end - start)).clamp(0.0, 1.0)
+}
+
+/// Returns the position on a diverging scale of the normalized value, moving
+/// the normalized center to the middle.
+fn diverge(t: f32, center: f32) -> f32 {
+    if t < center {
+        0.5 * t / center
+    } else if center < 1.0 {
+        0.5 + 0.5 * (t - center) / (1.0 - center)
+    } else {
+        0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diverge, index_at, normalize, value_range};
+
+    #[test]
+    fn cells_are_found_outside_the_spacing() {
+        assert_eq!(index_at(-1.0, 10.0, 2.0), None);
+        assert_eq!(index_at(0.0, 10.0, 2.0), Some(0));
+        assert_eq!(index_at(11.0, 10.0, 2.0), None);
+        assert_eq!(index_at(12.0, 10.0, 2.0), Some(1));
+        assert_eq!(index_at(30.0, 10.0, 2.0), Some(2));
+    }
+
+    #[test]
+    fn values_are_placed_on_the_scale() {
+        let range = value_range([3.0, f32::NAN, -1.0, 2.0].into_iter(), None);
+        assert!((*range.start() + 1.0).abs() < f32::EPSILON);
+        assert!((*range.end() - 3.0).abs() < f32::EPSILON);
+        assert!((normalize(1.0, &range) - 0.5).abs() < f32::EPSILON);
+        assert!((normalize(10.0, &range) - 1.0).abs() < f32::EPSILON);
+        assert!((normalize(4.0, &(4.0..=4.0)) - 0.5).abs() < f32::EPSILON);
+
+        // A diverging scale is centered on its center
+        let range = value_range([0.2, 0.6].into_iter(), Some(0.0));
+        assert!((*range.start() + 0.6).abs() < f32::EPSILON);
+        assert!((*range.end() - 0.6).abs() < f32::EPSILON);
+
+        let center = normalize(0.0, &(-1.0..=3.0));
+        assert!((diverge(center, center) - 0.5).abs() < f32::EPSILON);
+        assert!((diverge(0.125, center) - 0.25).abs() < f32::EPSILON);
+        assert!((diverge(1.0, center) - 1.0).abs() < f32::EPSILON);
+        assert!(diverge(0.0, center).abs() < f32::EPSILON);
+    }
+}
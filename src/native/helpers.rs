@@ -9,6 +9,10 @@ use iced_style::Color;
 
 /// Creates a [`Grid`] with the given children.
 ///
+/// The children are laid out in a fixed number of columns with
+/// `grid!(columns: 3; a, b, c)` or in as many columns of a fixed width as fit
+/// with `grid!(column_width: 100.0; a, b, c)`.
+///
 /// [`Grid`]: iced_aw::Grid
 #[cfg(feature = "grid")]
 #[macro_export]
@@ -16,6 +20,12 @@ macro_rules! grid {
     () => (
         $crate::Grid::new()
     );
+    (columns: $columns:expr; $($x:expr),* $(,)?) => (
+        $crate::grid!($($x),*).strategy($crate::Strategy::Columns($columns))
+    );
+    (column_width: $width:expr; $($x:expr),* $(,)?) => (
+        $crate::grid!($($x),*).strategy($crate::Strategy::ColumnWidth($width))
+    );
     ($($x:expr),+ $(,)?) => (
         $crate::Grid::with_children(vec![$($crate::Element::from($x)),+])
     );
@@ -23,10 +33,17 @@ macro_rules! grid {
 
 /// Creates a [`MenuTree`] with the given children.
 ///
+/// `menu_tree!("Open" => Message::Open)` creates an item with a button
+/// producing the message when pressed, see [`menu_item`].
+///
 /// [`MenuTree`]: iced_aw::MenuTree
+/// [`menu_item`]: crate::native::helpers::menu_item
 #[cfg(feature = "menu")]
 #[macro_export]
 macro_rules! menu_tree {
+    ($label:literal => $message:expr) => (
+        $crate::native::helpers::menu_item($label, $message)
+    );
     ($x:expr) => (
         $crate::menu::menu_tree::MenuTree::new($x)
     );
@@ -75,6 +92,54 @@ where
     crate::menu::menu_tree::MenuTree::with_children(item, children)
 }
 
+#[cfg(feature = "menu")]
+/// Shortcut helper to create an item of a menu with a button filling the width
+/// of the menu and producing the message when pressed.
+#[must_use]
+pub fn menu_item<'a, Message, Renderer>(
+    label: impl Into<Element<'a, Message, Renderer>>,
+    message: Message,
+) -> crate::menu::menu_tree::MenuTree<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: iced_style::button::StyleSheet,
+{
+    crate::menu::menu_tree::MenuTree::new(
+        iced_native::widget::Button::new(label)
+            .on_press(message)
+            .width(iced_native::Length::Fill),
+    )
+}
+
+/// Creates [`Tabs`] producing the message of `on_select` when a tab is
+/// selected, followed by the tabs as `id => label, content`.
+///
+/// A label is anything that converts into a [`TabLabel`], like a text, an
+/// icon or a tuple of both.
+///
+/// ```ignore
+/// tabs!(
+///     Message::TabSelected;
+///     TabId::Login => "Login", login.view(),
+///     TabId::Ferris => (Icon::Heart, "Ferris"), ferris.view(),
+/// )
+/// ```
+///
+/// [`Tabs`]: iced_aw::Tabs
+/// [`TabLabel`]: iced_aw::TabLabel
+#[cfg(feature = "tabs")]
+#[macro_export]
+macro_rules! tabs {
+    ($on_select:expr $(;)?) => (
+        $crate::Tabs::new($on_select)
+    );
+    ($on_select:expr; $($id:expr => $label:expr, $content:expr),+ $(,)?) => (
+        $crate::Tabs::new($on_select)
+            $(.push($id, $crate::TabLabel::from($label), $content))+
+    );
+}
+
 #[cfg(feature = "badge")]
 /// Shortcut helper to create a Badge Widget.
 pub fn badge<'a, Message, Renderer>(
@@ -169,6 +234,13 @@ where
     crate::Grid::with_children(children)
 }
 
+#[cfg(feature = "icon")]
+/// Shortcut helper to create an Icon Widget.
+#[must_use]
+pub fn icon(icon: crate::graphics::icons::Icon) -> crate::native::Icon {
+    crate::native::Icon::new(icon)
+}
+
 #[cfg(feature = "icon_text")]
 /// Shortcut helper to create a Card Widget.
 #[must_use]
@@ -196,6 +268,742 @@ where
     crate::Modal::new(show_modal, underlay, content)
 }
 
+#[cfg(feature = "popover")]
+/// Shortcut helper to create a Popover Widget.
+#[must_use]
+pub fn popover<'a, Content, Message, Renderer>(
+    show_popover: bool,
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    content: Content,
+) -> crate::Popover<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::popover::StyleSheet,
+{
+    crate::Popover::new(show_popover, underlay, content)
+}
+
+#[cfg(feature = "portal")]
+/// Shortcut helper to create a Portal Widget.
+#[must_use]
+pub fn portal<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::Portal<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    crate::Portal::new(content)
+}
+
+#[cfg(feature = "tooltip")]
+/// Shortcut helper to create a Tooltip Widget.
+#[must_use]
+pub fn tooltip<'a, Content, Message, Renderer>(
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    content: Content,
+) -> crate::Tooltip<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::tooltip::StyleSheet,
+{
+    crate::Tooltip::new(underlay, content)
+}
+
+#[cfg(feature = "hover_card")]
+/// Shortcut helper to create a HoverCard Widget.
+#[must_use]
+pub fn hover_card<'a, Content, Message, Renderer>(
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    content: Content,
+) -> crate::HoverCard<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::hover_card::StyleSheet,
+{
+    crate::HoverCard::new(underlay, content)
+}
+
+#[cfg(feature = "gesture_detector")]
+/// Shortcut helper to create a GestureDetector Widget.
+#[must_use]
+pub fn gesture_detector<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::GestureDetector<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    crate::GestureDetector::new(content)
+}
+
+#[cfg(feature = "responsive")]
+/// Shortcut helper to create a Responsive Widget.
+#[must_use]
+pub fn responsive<'a, Message, Renderer>(
+    view: impl Fn(crate::core::breakpoint::Breakpoint) -> Element<'a, Message, Renderer> + 'a,
+) -> crate::Responsive<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    crate::Responsive::new(view)
+}
+
+#[cfg(feature = "toast")]
+/// Shortcut helper to create a Toasts Widget.
+#[must_use]
+pub fn toasts<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    toasts: &'a [crate::native::toast::Toast],
+    on_close: impl Fn(usize) -> Message + 'static,
+) -> crate::Toasts<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::toast::StyleSheet,
+{
+    crate::Toasts::new(content, toasts, on_close)
+}
+
+#[cfg(feature = "table")]
+/// Shortcut helper to create a Table Widget.
+#[must_use]
+pub fn table<'a, Message, Renderer>(
+    columns: Vec<crate::native::table::Column>,
+) -> crate::Table<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::table::StyleSheet,
+{
+    crate::Table::new(columns)
+}
+
+#[cfg(feature = "accordion")]
+/// Shortcut helper to create an Accordion Widget.
+#[must_use]
+pub fn accordion<'a, Message, Renderer>(
+    state: &'a crate::native::accordion::State,
+    on_toggle: impl Fn(usize) -> Message + 'a,
+) -> crate::Accordion<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::accordion::StyleSheet,
+{
+    crate::Accordion::new(state, on_toggle)
+}
+
+#[cfg(feature = "breadcrumbs")]
+/// Shortcut helper to create a Breadcrumbs Widget.
+#[must_use]
+pub fn breadcrumbs<'a, Message, Renderer, S>(
+    segments: impl IntoIterator<Item = (S, Message)>,
+) -> crate::Breadcrumbs<'a, Message, Renderer>
+where
+    Message: Clone,
+    S: Into<String>,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::breadcrumbs::StyleSheet,
+{
+    crate::Breadcrumbs::new(segments)
+}
+
+#[cfg(feature = "pagination")]
+/// Shortcut helper to create a Pagination Widget.
+#[must_use]
+pub fn pagination<'a, Message, Renderer>(
+    pages: usize,
+    current: usize,
+    on_page: impl Fn(usize) -> Message + 'a,
+) -> crate::Pagination<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::pagination::StyleSheet,
+{
+    crate::Pagination::new(pages, current, on_page)
+}
+
+#[cfg(feature = "range_slider")]
+/// Shortcut helper to create a RangeSlider Widget.
+#[must_use]
+pub fn range_slider<'a, T, Message, Renderer>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> crate::RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::range_slider::StyleSheet,
+{
+    crate::RangeSlider::new(range, values, on_change)
+}
+
+#[cfg(feature = "rating")]
+/// Shortcut helper to create a Rating Widget.
+#[must_use]
+pub fn rating<'a, Message, Renderer>(value: f32) -> crate::Rating<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::rating::StyleSheet,
+{
+    crate::Rating::new(value)
+}
+
+#[cfg(feature = "stepper")]
+/// Shortcut helper to create a Stepper Widget.
+#[must_use]
+pub fn stepper<'a, Message, Renderer>(
+    steps: Vec<crate::native::stepper::Step>,
+    current: usize,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::Stepper<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::stepper::StyleSheet,
+{
+    crate::Stepper::new(steps, current, content)
+}
+
+#[cfg(feature = "combo_box")]
+/// Shortcut helper to create a ComboBox Widget.
+#[must_use]
+pub fn combo_box<'a, T, Message, Renderer, F, S>(
+    options: &'a [T],
+    placeholder: &str,
+    value: &str,
+    on_input: F,
+    on_select: S,
+) -> crate::ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + std::fmt::Display,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::combo_box::StyleSheet + iced_style::text_input::StyleSheet,
+    F: 'a + Fn(String) -> Message + Clone,
+    S: 'a + Fn(T) -> Message,
+{
+    crate::ComboBox::new(options, placeholder, value, on_input, on_select)
+}
+
+#[cfg(feature = "multi_select")]
+/// Shortcut helper to create a MultiSelect Widget.
+#[must_use]
+pub fn multi_select<'a, T, Message, Renderer, F>(
+    options: &'a [T],
+    selected: &'a [T],
+    on_change: F,
+) -> crate::MultiSelect<'a, T, Message, Renderer>
+where
+    T: Clone + std::fmt::Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::multi_select::StyleSheet,
+    F: 'a + Fn(Vec<T>) -> Message,
+{
+    crate::MultiSelect::new(options, selected, on_change)
+}
+
+#[cfg(feature = "tag_input")]
+/// Shortcut helper to create a TagInput Widget.
+#[must_use]
+pub fn tag_input<'a, Message, Renderer, F, C>(
+    tags: &'a [String],
+    placeholder: &str,
+    value: &str,
+    on_input: F,
+    on_change: C,
+) -> crate::TagInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::tag_input::StyleSheet
+        + crate::style::combo_box::StyleSheet
+        + iced_style::text_input::StyleSheet,
+    F: 'a + Fn(String) -> Message + Clone,
+    C: 'a + Fn(Vec<String>) -> Message,
+{
+    crate::TagInput::new(tags, placeholder, value, on_input, on_change)
+}
+
+#[cfg(feature = "segmented_button")]
+/// Shortcut helper to create a SegmentedButton Widget.
+#[must_use]
+pub fn segmented_button<'a, Message, Renderer, F>(
+    segments: Vec<crate::native::segmented_button::Segment>,
+    selected: Option<usize>,
+    on_select: F,
+) -> crate::SegmentedButton<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::segmented_button::StyleSheet,
+    F: 'a + Fn(usize) -> Message,
+{
+    crate::SegmentedButton::new(segments, selected, on_select)
+}
+
+#[cfg(feature = "circular_progress")]
+/// Shortcut helper to create a CircularProgress Widget.
+#[must_use]
+pub fn circular_progress<B, Theme>(progress: f32) -> crate::CircularProgress<B, Theme>
+where
+    B: iced_graphics::Backend + iced_graphics::backend::Text,
+    Theme: crate::style::circular_progress::StyleSheet,
+{
+    crate::CircularProgress::new(progress)
+}
+
+#[cfg(feature = "skeleton")]
+/// Shortcut helper to create a Skeleton Widget.
+#[must_use]
+pub fn skeleton<Renderer>(shape: crate::native::skeleton::Shape) -> crate::Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::skeleton::StyleSheet,
+{
+    crate::Skeleton::new(shape)
+}
+
+#[cfg(feature = "avatar")]
+/// Shortcut helper to create an Avatar Widget.
+#[must_use]
+pub fn avatar<Renderer>(name: impl Into<String>) -> crate::Avatar<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font> + iced_native::image::Renderer,
+    Renderer::Theme: crate::style::avatar::StyleSheet,
+{
+    crate::Avatar::new(name)
+}
+
+#[cfg(feature = "chip")]
+/// Shortcut helper to create a Chip Widget.
+#[must_use]
+pub fn chip<'a, Message, Renderer>(label: impl Into<String>) -> crate::Chip<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::chip::StyleSheet,
+{
+    crate::Chip::new(label)
+}
+
+#[cfg(feature = "carousel")]
+/// Shortcut helper to create a Carousel Widget.
+#[must_use]
+pub fn carousel<'a, Message, Renderer, F>(
+    items: Vec<Element<'a, Message, Renderer>>,
+    current: usize,
+    on_change: F,
+) -> crate::Carousel<'a, Message, Renderer>
+where
+    F: 'a + Fn(usize) -> Message,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::carousel::StyleSheet,
+{
+    crate::Carousel::new(items, current, on_change)
+}
+
+#[cfg(feature = "gallery")]
+/// Shortcut helper to create a Gallery Widget.
+#[must_use]
+pub fn gallery<'a, Message, Renderer, F>(
+    images: Vec<<Renderer as iced_native::image::Renderer>::Handle>,
+    selected: usize,
+    on_select: F,
+) -> crate::Gallery<'a, Message, Renderer>
+where
+    F: 'a + Fn(usize) -> Message,
+    Renderer: iced_native::image::Renderer,
+    Renderer::Theme: crate::style::gallery::StyleSheet,
+{
+    crate::Gallery::new(images, selected, on_select)
+}
+
+#[cfg(feature = "chart")]
+/// Shortcut helper to create a LineChart Widget.
+#[must_use]
+pub fn line_chart<B, Theme>(series: Vec<crate::Series>) -> crate::LineChart<B, Theme>
+where
+    B: iced_graphics::Backend + iced_graphics::backend::Text,
+    Theme: crate::style::chart::StyleSheet,
+{
+    crate::LineChart::new(series)
+}
+
+#[cfg(feature = "chart")]
+/// Shortcut helper to create a BarChart Widget.
+#[must_use]
+pub fn bar_chart<Renderer>(series: Vec<crate::Series>) -> crate::BarChart<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::chart::StyleSheet,
+{
+    crate::BarChart::new(series)
+}
+
+#[cfg(feature = "chart")]
+/// Shortcut helper to create a PieChart Widget.
+#[must_use]
+pub fn pie_chart<'a, Message, B, Theme>(
+    slices: Vec<crate::Slice>,
+) -> crate::PieChart<'a, Message, B, Theme>
+where
+    B: iced_graphics::Backend + iced_graphics::backend::Text,
+    Theme: crate::style::chart::StyleSheet,
+{
+    crate::PieChart::new(slices)
+}
+
+#[cfg(feature = "gauge")]
+/// Shortcut helper to create a Gauge Widget.
+#[must_use]
+pub fn gauge<B, Theme>(range: std::ops::RangeInclusive<f32>, value: f32) -> crate::Gauge<B, Theme>
+where
+    B: iced_graphics::Backend + iced_graphics::backend::Text,
+    Theme: crate::style::gauge::StyleSheet,
+{
+    crate::Gauge::new(range, value)
+}
+
+#[cfg(feature = "knob")]
+/// Shortcut helper to create a Knob Widget.
+#[must_use]
+pub fn knob<'a, Message, B, Theme, F>(
+    range: std::ops::RangeInclusive<f32>,
+    value: f32,
+    on_change: F,
+) -> crate::Knob<'a, Message, B, Theme>
+where
+    B: iced_graphics::Backend,
+    Theme: crate::style::knob::StyleSheet,
+    F: 'a + Fn(f32) -> Message,
+{
+    crate::Knob::new(range, value, on_change)
+}
+
+#[cfg(feature = "drop_zone")]
+/// Shortcut helper to create a DropZone Widget.
+#[must_use]
+pub fn drop_zone<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::DropZone<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::drop_zone::StyleSheet,
+{
+    crate::DropZone::new(content)
+}
+
+#[cfg(feature = "code_editor")]
+/// Shortcut helper to create a CodeEditor Widget.
+#[must_use]
+pub fn code_editor<'a, Message, Renderer>(content: &str) -> crate::CodeEditor<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::code_editor::StyleSheet,
+{
+    crate::CodeEditor::new(content)
+}
+
+#[cfg(feature = "rich_text")]
+/// Shortcut helper to create a RichText Widget.
+#[must_use]
+pub fn rich_text<'a, Message, Renderer>(
+    spans: Vec<crate::native::rich_text::Span<'a, Message>>,
+) -> crate::RichText<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::rich_text::StyleSheet,
+{
+    crate::RichText::new(spans)
+}
+
+#[cfg(feature = "virtual_list")]
+/// Shortcut helper to create a VirtualList Widget.
+#[must_use]
+pub fn virtual_list<'a, Message, Renderer, F>(
+    count: usize,
+    view: F,
+) -> crate::VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::virtual_list::StyleSheet,
+    F: 'a + Fn(usize) -> iced_native::Element<'a, Message, Renderer>,
+{
+    crate::VirtualList::new(count, view)
+}
+
+#[cfg(feature = "infinite_scroll")]
+/// Shortcut helper to create an InfiniteScroll Widget.
+#[must_use]
+pub fn infinite_scroll<'a, Message, Renderer>(
+    content: impl Into<iced_native::Element<'a, Message, Renderer>>,
+) -> crate::InfiniteScroll<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer,
+    Renderer::Theme: iced_style::scrollable::StyleSheet + iced_style::text::StyleSheet,
+{
+    crate::InfiniteScroll::new(content)
+}
+
+#[cfg(feature = "drag_and_drop")]
+/// Shortcut helper to create a DragSource Widget.
+#[must_use]
+pub fn drag_source<'a, T, Message, Renderer>(
+    payload: T,
+    content: impl Into<iced_native::Element<'a, Message, Renderer>>,
+) -> crate::DragSource<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::drag_and_drop::StyleSheet,
+{
+    crate::DragSource::new(payload, content)
+}
+
+#[cfg(feature = "drag_and_drop")]
+/// Shortcut helper to create a DropTarget Widget.
+#[must_use]
+pub fn drop_target<'a, T, Message, Renderer>(
+    content: impl Into<iced_native::Element<'a, Message, Renderer>>,
+) -> crate::DropTarget<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::drag_and_drop::StyleSheet,
+{
+    crate::DropTarget::new(content)
+}
+
+#[cfg(feature = "search_bar")]
+/// Shortcut helper to create a SearchBar Widget.
+#[must_use]
+pub fn search_bar<'a, T, Message, Renderer, F>(
+    placeholder: &str,
+    value: &str,
+    on_input: F,
+) -> crate::SearchBar<'a, T, Message, Renderer>
+where
+    T: Clone + std::fmt::Display,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::search_bar::StyleSheet
+        + crate::style::combo_box::StyleSheet
+        + iced_style::text_input::StyleSheet,
+    F: 'a + Fn(String) -> Message + Clone,
+{
+    crate::SearchBar::new(placeholder, value, on_input)
+}
+
+#[cfg(feature = "qr_code")]
+/// Shortcut helper to create a QrCode Widget.
+#[must_use]
+pub fn qr_code<Message, Renderer>(
+    data: &crate::native::qr_code::Data,
+) -> crate::QrCode<'_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::qr_code::StyleSheet,
+{
+    crate::QrCode::new(data)
+}
+
+#[cfg(feature = "image_viewer")]
+/// Shortcut helper to create an ImageViewer Widget.
+#[must_use]
+pub fn image_viewer<'a, Message, Renderer>(
+    handle: <Renderer as iced_native::image::Renderer>::Handle,
+) -> crate::ImageViewer<'a, Message, Renderer>
+where
+    Renderer: iced_native::image::Renderer,
+{
+    crate::ImageViewer::new(handle)
+}
+
+#[cfg(feature = "pin_input")]
+/// Shortcut helper to create a PinInput Widget.
+#[must_use]
+pub fn pin_input<'a, Message, Renderer, F>(
+    length: usize,
+    value: &str,
+    on_input: F,
+) -> crate::PinInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::pin_input::StyleSheet,
+    F: 'a + Fn(String) -> Message,
+{
+    crate::PinInput::new(length, value, on_input)
+}
+
+#[cfg(feature = "masked_input")]
+/// Shortcut helper to create a MaskedInput Widget.
+#[must_use]
+pub fn masked_input<'a, Message, Renderer, F>(
+    mask: &crate::native::masked_input::Mask,
+    placeholder: &str,
+    value: &str,
+    on_input: F,
+) -> crate::MaskedInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: iced_style::text_input::StyleSheet,
+    F: 'a + Fn(crate::native::masked_input::Entry) -> Message,
+{
+    crate::MaskedInput::new(mask, placeholder, value, on_input)
+}
+
+#[cfg(feature = "transfer_list")]
+/// Shortcut helper to create a TransferList Widget.
+#[must_use]
+pub fn transfer_list<'a, T, Message, Renderer, F>(
+    source: &'a [T],
+    target: &'a [T],
+    on_transfer: F,
+) -> crate::TransferList<'a, T, Message, Renderer>
+where
+    T: Clone + std::fmt::Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme:
+        crate::style::transfer_list::StyleSheet + iced_style::text_input::StyleSheet,
+    F: 'a + Fn(crate::native::transfer_list::Transfer<T>) -> Message,
+{
+    crate::TransferList::new(source, target, on_transfer)
+}
+
+#[cfg(feature = "split_button")]
+/// Shortcut helper to create a SplitButton Widget.
+#[must_use]
+pub fn split_button<'a, Message, Renderer>(
+    content: impl Into<iced_native::Element<'a, Message, Renderer>>,
+    items: Vec<crate::menu::menu_tree::MenuTree<'a, Message, Renderer>>,
+) -> crate::menu::split_button::SplitButton<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: crate::style::menu_bar::StyleSheet + iced_style::button::StyleSheet,
+{
+    crate::menu::split_button::SplitButton::new(content, items)
+}
+
+#[cfg(feature = "menu_button")]
+/// Shortcut helper to create a MenuButton Widget.
+#[must_use]
+pub fn menu_button<'a, T, Message, Renderer, F>(
+    label: impl Into<String>,
+    items: &'a [T],
+    on_select: F,
+) -> crate::MenuButton<'a, T, Message, Renderer>
+where
+    T: Clone + std::fmt::Display,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::menu_button::StyleSheet,
+    F: 'a + Fn(T) -> Message,
+{
+    crate::MenuButton::new(label, items, on_select)
+}
+
+#[cfg(feature = "status_dot")]
+/// Shortcut helper to create a StatusDot Widget.
+#[must_use]
+pub fn status_dot<Renderer>(
+    status: crate::native::status_dot::Status,
+) -> crate::StatusDot<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::status_dot::StyleSheet,
+{
+    crate::StatusDot::new(status)
+}
+
+#[cfg(feature = "loading_overlay")]
+/// Shortcut helper to create a LoadingOverlay Widget.
+#[must_use]
+pub fn loading_overlay<'a, Message, Renderer>(
+    content: impl Into<iced_native::Element<'a, Message, Renderer>>,
+    is_active: bool,
+) -> crate::LoadingOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme:
+        crate::style::loading_overlay::StyleSheet + crate::style::spinner::StyleSheet,
+{
+    crate::LoadingOverlay::new(content, is_active)
+}
+
+#[cfg(feature = "switch")]
+/// Shortcut helper to create a Switch Widget.
+#[must_use]
+pub fn switch<'a, Message, Renderer>(
+    is_on: bool,
+    on_toggle: impl Fn(bool) -> Message + 'a,
+) -> crate::Switch<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::switch::StyleSheet,
+{
+    crate::Switch::new(is_on).on_toggle(on_toggle)
+}
+
+#[cfg(feature = "month_view")]
+/// Shortcut helper to create a MonthView Widget.
+#[must_use]
+pub fn month_view<'a, Message, Renderer>(
+    year: i32,
+    month: u32,
+) -> crate::MonthView<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::month_view::StyleSheet,
+{
+    crate::MonthView::new(year, month)
+}
+
+#[cfg(feature = "calendar_heatmap")]
+/// Shortcut helper to create a CalendarHeatmap Widget.
+#[must_use]
+pub fn calendar_heatmap<'a, Message, Renderer>(
+    year: i32,
+    values: impl IntoIterator<Item = (crate::native::calendar_heatmap::Date, f32)>,
+) -> crate::CalendarHeatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::calendar_heatmap::StyleSheet,
+{
+    crate::CalendarHeatmap::new(year, values)
+}
+
+#[cfg(feature = "resizable")]
+/// Shortcut helper to create a Resizable Widget.
+#[must_use]
+pub fn resizable<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    size: iced_native::Size,
+    on_resize: impl Fn(iced_native::Size) -> Message + 'a,
+) -> crate::Resizable<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::resizable::StyleSheet,
+{
+    crate::Resizable::new(content, size, on_resize)
+}
+
+#[cfg(feature = "spreadsheet_grid")]
+/// Shortcut helper to create a SpreadsheetGrid Widget.
+#[must_use]
+pub fn spreadsheet_grid<'a, Message, Renderer>(
+    cells: &'a [Vec<String>],
+    on_edit: impl Fn(crate::native::spreadsheet_grid::Cell, String) -> Message + 'a,
+) -> crate::SpreadsheetGrid<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::spreadsheet_grid::StyleSheet,
+{
+    crate::SpreadsheetGrid::new(cells).on_edit(on_edit)
+}
+
 #[cfg(feature = "number_input")]
 /// Shortcut helper to create a Card Widget.
 #[must_use]
@@ -211,7 +1019,7 @@ where
         + iced_style::text_input::StyleSheet
         + iced_style::container::StyleSheet
         + iced_style::text::StyleSheet,
-    F: 'static + Fn(T) -> Message + Copy,
+    F: 'static + Fn(T) -> Message,
     T: 'static
         + num_traits::Num
         + num_traits::NumAssignOps
@@ -222,3 +1030,298 @@ where
 {
     crate::NumberInput::new(value, max, on_changed)
 }
+
+#[cfg(feature = "selection_list")]
+/// Shortcut helper to create a ``SelectionList`` Widget.
+#[must_use]
+pub fn selection_list<'a, T, Message, Renderer>(
+    options: impl Into<std::borrow::Cow<'a, [T]>>,
+    on_selected: impl Fn(T) -> Message + 'static,
+) -> crate::SelectionList<'a, T, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::selection_list::StyleSheet
+        + iced_style::container::StyleSheet
+        + iced_style::scrollable::StyleSheet,
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    crate::SelectionList::new(options, on_selected)
+}
+
+#[cfg(feature = "split")]
+/// Shortcut helper to create a Split Widget.
+#[must_use]
+pub fn split<'a, Message, Renderer, F>(
+    first: impl Into<Element<'a, Message, Renderer>>,
+    second: impl Into<Element<'a, Message, Renderer>>,
+    divider_position: Option<u16>,
+    axis: crate::split::Axis,
+    on_resize: F,
+) -> crate::Split<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: crate::style::split::StyleSheet + iced_style::container::StyleSheet,
+    F: 'static + Fn(u16) -> Message,
+{
+    crate::Split::new(first, second, divider_position, axis, on_resize)
+}
+
+#[cfg(feature = "tab_bar")]
+/// Shortcut helper to create a ``TabBar`` Widget.
+#[must_use]
+pub fn tab_bar<Message, TabId, Renderer, F>(on_select: F) -> crate::TabBar<Message, TabId, Renderer>
+where
+    Renderer: crate::core::renderer::IconRenderer,
+    Renderer::Theme: crate::style::tab_bar::StyleSheet,
+    TabId: Eq + Clone,
+    F: 'static + Fn(TabId) -> Message,
+{
+    crate::TabBar::new(on_select)
+}
+
+#[cfg(feature = "tabs")]
+/// Shortcut helper to create a Tabs Widget.
+#[must_use]
+pub fn tabs<'a, Message, TabId, Renderer, F>(
+    on_select: F,
+) -> crate::Tabs<'a, Message, TabId, Renderer>
+where
+    Renderer: 'a + crate::core::renderer::IconRenderer,
+    Renderer::Theme: crate::style::tab_bar::StyleSheet + iced_style::text::StyleSheet,
+    TabId: Eq + Clone,
+    F: 'static + Fn(TabId) -> Message,
+{
+    crate::Tabs::new(on_select)
+}
+
+#[cfg(feature = "time_picker")]
+/// Shortcut helper to create a ``TimePicker`` Widget.
+pub fn time_picker<'a, Message, B, Theme, F>(
+    show_picker: bool,
+    time: impl Into<crate::core::time::Time>,
+    underlay: impl Into<Element<'a, Message, iced_graphics::Renderer<B, Theme>>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::TimePicker<'a, Message, B, Theme>
+where
+    Message: 'a + Clone,
+    B: 'a + iced_graphics::Backend + iced_graphics::backend::Text,
+    Theme: 'a
+        + crate::style::time_picker::StyleSheet
+        + iced_style::button::StyleSheet
+        + iced_style::text::StyleSheet,
+    F: 'static + Fn(crate::core::time::Time) -> Message,
+{
+    crate::TimePicker::new(show_picker, time, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "wrap")]
+/// Shortcut helper to create a horizontal Wrap Widget.
+#[must_use]
+pub fn wrap_horizontal<Message, Renderer>(
+    children: Vec<Element<Message, Renderer>>,
+) -> crate::Wrap<Message, Renderer, crate::wrap::direction::Horizontal> {
+    crate::Wrap::with_elements(children)
+}
+
+#[cfg(feature = "wrap")]
+/// Shortcut helper to create a vertical Wrap Widget.
+#[must_use]
+pub fn wrap_vertical<Message, Renderer>(
+    children: Vec<Element<Message, Renderer>>,
+) -> crate::Wrap<Message, Renderer, crate::wrap::direction::Vertical> {
+    crate::Wrap::with_elements_vertical(children)
+}
+
+#[cfg(feature = "spinner")]
+/// Shortcut helper to create a Spinner Widget.
+#[must_use]
+pub fn spinner<Renderer>() -> crate::Spinner<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::spinner::StyleSheet,
+{
+    crate::Spinner::new()
+}
+
+#[cfg(feature = "context_menu")]
+/// Shortcut helper to create a ``ContextMenu`` Widget.
+#[must_use]
+pub fn context_menu<'a, Overlay, Message, Renderer>(
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    overlay: Overlay,
+) -> crate::ContextMenu<'a, Overlay, Message, Renderer>
+where
+    Overlay: Fn() -> Element<'a, Message, Renderer>,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::context_menu::StyleSheet,
+{
+    crate::ContextMenu::new(underlay, overlay)
+}
+
+#[cfg(feature = "property_grid")]
+/// Shortcut helper to create a PropertyGrid Widget.
+#[must_use]
+pub fn property_grid<'a, Message, Renderer>(
+    state: &'a crate::native::property_grid::State,
+    on_action: impl Fn(crate::native::property_grid::Action) -> Message + 'static,
+) -> crate::PropertyGrid<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::property_grid::StyleSheet,
+{
+    crate::PropertyGrid::new(state, on_action)
+}
+
+#[cfg(feature = "tick_slider")]
+/// Shortcut helper to create a TickSlider Widget.
+#[must_use]
+pub fn tick_slider<'a, T, Message, Renderer>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> crate::TickSlider<'a, T, Message, Renderer>
+where
+    T: 'a + Copy + From<u8> + PartialOrd + std::fmt::Display,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::tick_slider::StyleSheet,
+{
+    crate::TickSlider::new(range, value, on_change)
+}
+
+#[cfg(feature = "xy_pad")]
+/// Shortcut helper to create a XYPad Widget.
+#[must_use]
+pub fn xy_pad<'a, T, Message, Renderer>(
+    x_range: std::ops::RangeInclusive<T>,
+    y_range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> crate::XYPad<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::xy_pad::StyleSheet,
+{
+    crate::XYPad::new(x_range, y_range, values, on_change)
+}
+
+#[cfg(feature = "drawing_pad")]
+/// Shortcut helper to create a DrawingPad Widget.
+#[must_use]
+pub fn drawing_pad<'a, Message, B, Theme>(
+    drawing: &'a crate::drawing_pad::Drawing,
+    on_stroke: impl Fn(crate::drawing_pad::Stroke) -> Message + 'a,
+) -> crate::DrawingPad<'a, Message, B, Theme>
+where
+    B: iced_graphics::Backend + iced_graphics::backend::Text,
+    Theme: crate::style::drawing_pad::StyleSheet,
+{
+    crate::DrawingPad::new(drawing, on_stroke)
+}
+
+#[cfg(feature = "minimap")]
+/// Shortcut helper to create a Minimap Widget.
+#[must_use]
+pub fn minimap<'a, Message, Renderer>(
+    content: iced_native::Size,
+    viewport: iced_native::Rectangle,
+    on_navigate: impl Fn(crate::Navigation) -> Message + 'a,
+) -> crate::Minimap<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: crate::style::minimap::StyleSheet,
+{
+    crate::Minimap::new(content, viewport, on_navigate)
+}
+
+#[cfg(feature = "hotkey_input")]
+/// Shortcut helper to create a HotkeyInput Widget.
+#[must_use]
+pub fn hotkey_input<'a, Message, Renderer>(
+    value: Option<crate::Hotkey>,
+    on_change: impl Fn(Option<crate::Hotkey>) -> Message + 'a,
+) -> crate::HotkeyInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::hotkey_input::StyleSheet,
+{
+    crate::HotkeyInput::new(value, on_change)
+}
+
+#[cfg(feature = "shortcut_hint")]
+/// Shortcut helper to create a ShortcutHint Widget.
+#[must_use]
+pub fn shortcut_hint<Renderer>(hotkey: crate::Hotkey) -> crate::ShortcutHint<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::shortcut_hint::StyleSheet,
+{
+    crate::ShortcutHint::new(hotkey)
+}
+
+#[cfg(feature = "scrubber")]
+/// Shortcut helper to create a Scrubber Widget.
+#[must_use]
+pub fn scrubber<'a, Message, Renderer>(
+    duration: std::time::Duration,
+    position: std::time::Duration,
+    on_seek: impl Fn(std::time::Duration) -> Message + 'a,
+) -> crate::Scrubber<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::scrubber::StyleSheet,
+{
+    crate::Scrubber::new(duration, position, on_seek)
+}
+
+#[cfg(feature = "heatmap")]
+/// Shortcut helper to create a Heatmap Widget.
+#[must_use]
+pub fn heatmap<'a, Message, Renderer>(
+    values: Vec<Vec<f32>>,
+) -> crate::Heatmap<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::heatmap::StyleSheet,
+{
+    crate::Heatmap::new(values)
+}
+
+#[cfg(feature = "radial_menu")]
+/// Shortcut helper to create a RadialMenu Widget.
+#[must_use]
+pub fn radial_menu<'a, Message, Renderer>(
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    items: Vec<crate::RadialItem<Message>>,
+) -> crate::RadialMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::radial_menu::StyleSheet,
+{
+    crate::RadialMenu::new(underlay, items)
+}
+
+#[cfg(feature = "tour")]
+/// Shortcut helper to create a Tour Widget.
+#[must_use]
+pub fn tour<'a, Message, Renderer>(
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    steps: Vec<crate::TourStep>,
+    current: Option<usize>,
+    on_step: impl Fn(usize) -> Message + 'a,
+) -> crate::Tour<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer::Theme: crate::style::tour::StyleSheet,
+{
+    crate::Tour::new(underlay, steps, current, on_step)
+}
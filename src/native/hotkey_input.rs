@@ -0,0 +1,699 @@
+//! Record a keyboard shortcut by pressing it.
+//!
+//! *This API requires the following crate features to be activated: hotkey_input*
+use std::fmt::{self, Display};
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, KeyCode, Modifiers},
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::{renderer::GlyphRenderer, sizing::hit_target},
+    graphics::icons::Icon,
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::hotkey_input::{Appearance, StyleSheet};
+
+/// The default width of a [`HotkeyInput`](HotkeyInput).
+const DEFAULT_WIDTH: f32 = 180.0;
+
+/// The default padding of the field.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The size of the clear button.
+const ICON_SIZE: f32 = 16.0;
+
+/// The default text shown while no shortcut is recorded.
+const DEFAULT_PLACEHOLDER: &str = "Click to record";
+
+/// The default text shown while recording before a modifier is held.
+const DEFAULT_PROMPT: &str = "Press a shortcut";
+
+/// The platform whose conventions a [`Hotkey`](Hotkey) is shown with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// The symbols of the modifiers and of the special keys, like `⌃⇧K`.
+    MacOs,
+    /// The names of the keys joined with plus signs, like `Ctrl+Shift+K`,
+    /// with the Windows key shown as `Win`.
+    Windows,
+    /// The names of the keys joined with plus signs, like `Ctrl+Shift+K`,
+    /// with the logo key shown as `Super`.
+    Linux,
+}
+
+impl Platform {
+    /// Returns the [`Platform`](Platform) the application is built for.
+    ///
+    /// The platforms other than macOS and Windows follow the conventions of
+    /// Linux.
+    #[must_use]
+    pub const fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+
+    /// Joins the labels of the keys of a shortcut.
+    fn join<S: AsRef<str>>(self, keys: &[S]) -> String {
+        let separator = match self {
+            Self::MacOs => "",
+            Self::Windows | Self::Linux => "+",
+        };
+
+        keys.iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+/// A keyboard shortcut, a key pressed while holding the modifiers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    /// The key of the shortcut.
+    key_code: KeyCode,
+    /// The modifiers held while the key is pressed.
+    modifiers: Modifiers,
+}
+
+impl Hotkey {
+    /// Creates a new [`Hotkey`](Hotkey) pressing the key while holding the
+    /// modifiers.
+    #[must_use]
+    pub const fn new(key_code: KeyCode, modifiers: Modifiers) -> Self {
+        Self {
+            key_code,
+            modifiers,
+        }
+    }
+
+    /// Returns the key of the [`Hotkey`](Hotkey).
+    #[must_use]
+    pub const fn key_code(&self) -> KeyCode {
+        self.key_code
+    }
+
+    /// Returns the modifiers of the [`Hotkey`](Hotkey).
+    #[must_use]
+    pub const fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Returns whether the key pressed with the modifiers triggers the
+    /// [`Hotkey`](Hotkey).
+    #[must_use]
+    pub fn matches(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+
+    /// Returns the labels of the held modifiers and of the key of the
+    /// [`Hotkey`](Hotkey) the way the platform shows them, like `["⌃", "⇧",
+    /// "K"]` on macOS and `["Ctrl", "Shift", "K"]` elsewhere.
+    #[must_use]
+    pub fn keys(&self, platform: Platform) -> Vec<String> {
+        modifier_keys(self.modifiers, platform)
+            .into_iter()
+            .map(String::from)
+            .chain(std::iter::once(key_label(self.key_code, platform)))
+            .collect()
+    }
+
+    /// Returns the [`Hotkey`](Hotkey) the way the platform shows shortcuts,
+    /// like `⌃⇧K` on macOS and `Ctrl+Shift+K` elsewhere.
+    #[must_use]
+    pub fn label(&self, platform: Platform) -> String {
+        platform.join(&self.keys(platform))
+    }
+}
+
+impl Display for Hotkey {
+    /// Formats the [`Hotkey`](Hotkey) the way the
+    /// [`current`](Platform::current) platform shows shortcuts.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label(Platform::current()))
+    }
+}
+
+/// A field recording a keyboard shortcut, like the shortcuts of the actions
+/// on a settings screen.
+///
+/// Once the field is focused by clicking on it, the next key pressed is
+/// recorded with the modifiers held. The held modifiers show while recording,
+/// Escape stops recording without a change, and Backspace, Delete or the
+/// clear button remove the shortcut.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::hotkey_input::{self, Hotkey};
+/// #
+/// # pub type HotkeyInput<'a, Message> = hotkey_input::HotkeyInput<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     SaveShortcut(Option<Hotkey>),
+/// }
+///
+/// let shortcut = None;
+///
+/// let hotkey_input = HotkeyInput::new(shortcut, Message::SaveShortcut);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HotkeyInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`HotkeyInput`](HotkeyInput) to focus it.
+    id: Option<Id>,
+    /// The recorded shortcut.
+    value: Option<Hotkey>,
+    /// The function producing the message of a recorded or cleared shortcut.
+    on_change: Box<dyn Fn(Option<Hotkey>) -> Message + 'a>,
+    /// Whether the shortcut can be removed.
+    clearable: bool,
+    /// The text shown while no shortcut is recorded.
+    placeholder: String,
+    /// The text shown while recording before a modifier is held.
+    prompt: String,
+    /// The width of the field.
+    width: Length,
+    /// The padding of the field.
+    padding: f32,
+    /// The text size of the field.
+    text_size: Option<f32>,
+    /// The font of the field.
+    font: Font,
+    /// The style of the field.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> HotkeyInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`HotkeyInput`](HotkeyInput).
+    ///
+    /// It expects:
+    ///     * the recorded shortcut, if any.
+    ///     * the function that will be called with the new shortcut when one
+    ///         is recorded or the shortcut is cleared.
+    pub fn new<F>(value: Option<Hotkey>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Option<Hotkey>) -> Message,
+    {
+        HotkeyInput {
+            id: None,
+            value,
+            on_change: Box::new(on_change),
+            clearable: true,
+            placeholder: String::from(DEFAULT_PLACEHOLDER),
+            prompt: String::from(DEFAULT_PROMPT),
+            width: Length::Fixed(DEFAULT_WIDTH),
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`HotkeyInput`](HotkeyInput) to focus it with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the shortcut can be removed with Backspace, Delete or the
+    /// clear button.
+    #[must_use]
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// Sets the text shown while no shortcut is recorded.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the text shown while recording before a modifier is held.
+    #[must_use]
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the width of the [`HotkeyInput`](HotkeyInput).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`HotkeyInput`](HotkeyInput).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`HotkeyInput`](HotkeyInput).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`HotkeyInput`](HotkeyInput).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`HotkeyInput`](HotkeyInput).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the bounds of the clear button in the field with the bounds.
+    fn clear_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + bounds.width - self.padding - ICON_SIZE,
+            y: bounds.center_y() - ICON_SIZE / 2.0,
+            width: ICON_SIZE,
+            height: ICON_SIZE,
+        }
+    }
+
+    /// Returns whether the cursor is over the shown clear button.
+    fn over_clear(&self, bounds: Rectangle, cursor_position: Point) -> bool {
+        self.clearable
+            && self.value.is_some()
+            && hit_target(self.clear_bounds(bounds)).contains(cursor_position)
+    }
+
+    /// Publishes the shortcut if it changed.
+    fn change(&self, value: Option<Hotkey>, shell: &mut Shell<'_, Message>) {
+        if value != self.value {
+            shell.publish((self.on_change)(value));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for HotkeyInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = text_size.max(ICON_SIZE) + 2.0 * self.padding;
+        let limits = limits.width(self.width).height(Length::Fixed(height));
+
+        Node::new(limits.resolve(Size::new(DEFAULT_WIDTH, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let input_state: &mut State = state.state.downcast_mut();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.over_clear(bounds, cursor_position) {
+                    input_state.focus.unfocus();
+                    self.change(None, shell);
+
+                    return event::Status::Captured;
+                }
+
+                let pressed = bounds.contains(cursor_position);
+                input_state.focus.press(pressed);
+
+                if pressed {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                input_state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if input_state.focus.is_focused() => {
+                // The modifiers are recorded with the key they are held for
+                if is_modifier(key_code) {
+                    return event::Status::Captured;
+                }
+
+                if modifiers.is_empty() {
+                    match key_code {
+                        KeyCode::Escape => {
+                            input_state.focus.unfocus();
+
+                            return event::Status::Captured;
+                        }
+                        KeyCode::Backspace | KeyCode::Delete if self.clearable => {
+                            input_state.focus.unfocus();
+                            self.change(None, shell);
+
+                            return event::Status::Captured;
+                        }
+                        _ => {}
+                    }
+                }
+
+                input_state.focus.unfocus();
+                self.change(Some(Hotkey::new(key_code, modifiers)), shell);
+
+                event::Status::Captured
+            }
+
+            // The recorded keys must not type into other widgets
+            Event::Keyboard(
+                keyboard::Event::KeyReleased { .. } | keyboard::Event::CharacterReceived(_),
+            ) if input_state.focus.is_focused() => event::Status::Captured,
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if bounds.contains(cursor_position) || self.over_clear(bounds, cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let input_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let recording = input_state.focus.is_focused();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, recording, false),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let platform = Platform::current();
+        let (content, color) = match (recording, self.value) {
+            (true, _) if input_state.modifiers.is_empty() => {
+                (self.prompt.clone(), appearance.placeholder_color)
+            }
+            (true, _) => {
+                let mut keys = modifier_keys(input_state.modifiers, platform);
+                keys.push("…");
+
+                (platform.join(&keys), appearance.text_color)
+            }
+            (false, Some(hotkey)) => (hotkey.to_string(), appearance.text_color),
+            (false, None) => (self.placeholder.clone(), appearance.placeholder_color),
+        };
+
+        renderer.fill_text(text::Text {
+            content: &content,
+            bounds: Rectangle {
+                x: bounds.x + self.padding,
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+        });
+
+        if self.clearable && self.value.is_some() && !recording {
+            renderer.fill_glyph(
+                char::from(Icon::X),
+                Icon::X.font(),
+                self.clear_bounds(bounds),
+                ICON_SIZE,
+                appearance.icon_color,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let input_state: &mut State = state.state.downcast_mut();
+
+        operation.focusable(&mut input_state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Renderer> From<HotkeyInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(hotkey_input: HotkeyInput<'a, Message, Renderer>) -> Self {
+        Element::new(hotkey_input)
+    }
+}
+
+/// The state of a [`HotkeyInput`](HotkeyInput).
+///
+/// The [`HotkeyInput`](HotkeyInput) keeps it in the widget tree, so it doesn't
+/// need to be held by the application.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The keyboard focus of the field, which records while it is focused.
+    focus: Focus,
+    /// The modifiers currently held.
+    modifiers: Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`HotkeyInput`](HotkeyInput).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            focus: Focus::new(),
+            modifiers: Modifiers::empty(),
+        }
+    }
+}
+
+/// Returns whether the key is a modifier key.
+fn is_modifier(key_code: KeyCode) -> bool {
+    matches!(
+        key_code,
+        KeyCode::LShift
+            | KeyCode::RShift
+            | KeyCode::LControl
+            | KeyCode::RControl
+            | KeyCode::LAlt
+            | KeyCode::RAlt
+            | KeyCode::LWin
+            | KeyCode::RWin
+    )
+}
+
+/// Returns the labels of the held modifiers the way the platform shows them
+/// in front of the key of a shortcut.
+///
+/// macOS shows the symbols of Control, Option, Shift and Command in this
+/// order, the other platforms the names of Ctrl, Alt, Shift and the logo key.
+fn modifier_keys(modifiers: Modifiers, platform: Platform) -> Vec<&'static str> {
+    let logo = match platform {
+        Platform::MacOs => "⌘",
+        Platform::Windows => "Win",
+        Platform::Linux => "Super",
+    };
+    let names: [(bool, &str, &str); 4] = [
+        (modifiers.control(), "⌃", "Ctrl"),
+        (modifiers.alt(), "⌥", "Alt"),
+        (modifiers.shift(), "⇧", "Shift"),
+        (modifiers.logo(), logo, logo),
+    ];
+
+    names
+        .into_iter()
+        .filter(|(held, _, _)| *held)
+        .map(|(_, symbol, name)| {
+            if platform == Platform::MacOs {
+                symbol
+            } else {
+                name
+            }
+        })
+        .collect()
+}
+
+/// Returns the name of the key the way the platform shows it in a shortcut.
+fn key_label(key_code: KeyCode, platform: Platform) -> String {
+    let (symbol, name) = match key_code {
+        KeyCode::Left => ("←", "Left"),
+        KeyCode::Right => ("→", "Right"),
+        KeyCode::Up => ("↑", "Up"),
+        KeyCode::Down => ("↓", "Down"),
+        KeyCode::Enter | KeyCode::NumpadEnter => ("↩", "Enter"),
+        KeyCode::Tab => ("⇥", "Tab"),
+        KeyCode::Escape => ("⎋", "Esc"),
+        KeyCode::Backspace => ("⌫", "Backspace"),
+        KeyCode::Delete => ("⌦", "Delete"),
+        KeyCode::Home => ("↖", "Home"),
+        KeyCode::End => ("↘", "End"),
+        KeyCode::PageUp => ("⇞", "PageUp"),
+        KeyCode::PageDown => ("⇟", "PageDown"),
+        KeyCode::Space => ("Space", "Space"),
+        KeyCode::Comma => (",", ","),
+        KeyCode::Period => (".", "."),
+        KeyCode::Slash => ("/", "/"),
+        KeyCode::Backslash => ("\\", "\\"),
+        KeyCode::Semicolon => (";", ";"),
+        KeyCode::Apostrophe => ("'", "'"),
+        KeyCode::Grave => ("`", "`"),
+        KeyCode::Minus => ("-", "-"),
+        KeyCode::Equals => ("=", "="),
+        KeyCode::Plus => ("+", "Plus"),
+        KeyCode::LBracket => ("[", "["),
+        KeyCode::RBracket => ("]", "]"),
+        _ => {
+            // The names of the other keys are readable without their prefix,
+            // like `Key1` and `Numpad1`
+            let name = format!("{key_code:?}");
+
+            return match name.strip_prefix("Key") {
+                Some(digit) => digit.to_owned(),
+                None => name.replacen("Numpad", "Num", 1),
+            };
+        }
+    };
+
+    String::from(if platform == Platform::MacOs {
+        symbol
+    } else {
+        name
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{key_label, Hotkey, KeyCode, Modifiers, Platform};
+
+    #[test]
+    fn labels_follow_the_platform() {
+        let hotkey = Hotkey::new(KeyCode::K, Modifiers::CTRL | Modifiers::SHIFT);
+
+        assert_eq!(hotkey.label(Platform::Linux), "Ctrl+Shift+K");
+        assert_eq!(hotkey.label(Platform::MacOs), "⌃⇧K");
+        assert_eq!(hotkey.keys(Platform::MacOs), ["⌃", "⇧", "K"]);
+
+        let hotkey = Hotkey::new(KeyCode::P, Modifiers::LOGO);
+        assert_eq!(hotkey.label(Platform::Windows), "Win+P");
+        assert_eq!(hotkey.label(Platform::Linux), "Super+P");
+        assert_eq!(hotkey.label(Platform::MacOs), "⌘P");
+    }
+
+    #[test]
+    fn keys_have_readable_names() {
+        assert_eq!(key_label(KeyCode::Key1, Platform::Linux), "1");
+        assert_eq!(key_label(KeyCode::Numpad1, Platform::Linux), "Num1");
+        assert_eq!(key_label(KeyCode::F5, Platform::MacOs), "F5");
+        assert_eq!(key_label(KeyCode::Left, Platform::Windows), "Left");
+        assert_eq!(key_label(KeyCode::Left, Platform::MacOs), "←");
+        assert_eq!(key_label(KeyCode::Plus, Platform::Linux), "Plus");
+    }
+}
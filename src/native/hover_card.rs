@@ -0,0 +1,381 @@
+//! Show a card previewing details while hovering a widget.
+//!
+//! *This API requires the following crate features to be activated: hover_card*
+use iced_native::time::{Duration, Instant};
+
+use iced_native::{
+    event, mouse, touch,
+    widget::{tree, Operation, Tree},
+    window, Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::{
+    overlay::{tooltip::TooltipOverlay, Layers},
+    tooltip::State,
+};
+use crate::style::style_state::appearance;
+
+pub use crate::{core::overlay::Placement, style::hover_card::StyleSheet};
+
+/// The default delay before a [`HoverCard`](HoverCard) is shown.
+const DEFAULT_SHOW_DELAY: Duration = Duration::from_millis(600);
+
+/// The default delay before a [`HoverCard`](HoverCard) is hidden.
+const DEFAULT_HIDE_DELAY: Duration = Duration::from_millis(300);
+
+/// The default padding between the card and its content.
+const DEFAULT_PADDING: f32 = 12.0;
+
+/// The default gap between the anchor and the card.
+const DEFAULT_GAP: f32 = 8.0;
+
+/// The default maximal width of the card.
+const DEFAULT_MAX_WIDTH: f32 = 320.0;
+
+/// A wrapper showing a card with a preview of the wrapped widget while it is
+/// hovered, like the profile of a user behind their name or the summary of a
+/// page behind a link.
+///
+/// Unlike a [`Tooltip`](crate::native::tooltip::Tooltip), the card looks like
+/// the surfaces of the page and is meant for richer content. It is shown after
+/// a delay and stays open while the cursor moves from the wrapped widget into
+/// the card, closing once the cursor has left both.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::{Column, Text};
+/// # use iced_aw::native::hover_card;
+/// #
+/// # pub type HoverCard<'a, Content, Message>
+/// #  = hover_card::HoverCard<'a, Content, Message, Null>;
+/// let hover_card = HoverCard::<_, ()>::new(Text::new("@ferris"), || {
+///     Column::new()
+///         .push(Text::new("Ferris"))
+///         .push(Text::new("Crab, Rustacean"))
+///         .into()
+/// })
+/// .placement(hover_card::Placement::Bottom);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HoverCard<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The underlying element showing the card when hovered.
+    underlay: Element<'a, Message, Renderer>,
+    /// The content of the card.
+    content: Content,
+    /// The preferred side of the anchor the card is placed at.
+    placement: Placement,
+    /// The alignment of the card along the side of the anchor.
+    alignment: Alignment,
+    /// The gap between the anchor and the card.
+    gap: f32,
+    /// The padding between the card and its content.
+    padding: f32,
+    /// The maximal width of the card.
+    max_width: f32,
+    /// The delay between hovering the underlay and showing the card.
+    show_delay: Duration,
+    /// The delay between leaving the underlay and the card and hiding the
+    /// card.
+    hide_delay: Duration,
+    /// The style of the card.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Content, Message, Renderer> HoverCard<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`HoverCard`](HoverCard) wrapping the underlying element
+    /// to show a card while it is hovered.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`](iced_native::Element) showing the card
+    ///         when hovered.
+    ///     * the content [`Element`](iced_native::Element) of the card.
+    pub fn new<U>(underlay: U, content: Content) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        HoverCard {
+            underlay: underlay.into(),
+            content,
+            placement: Placement::Bottom,
+            alignment: Alignment::Start,
+            gap: DEFAULT_GAP,
+            padding: DEFAULT_PADDING,
+            max_width: DEFAULT_MAX_WIDTH,
+            show_delay: DEFAULT_SHOW_DELAY,
+            hide_delay: DEFAULT_HIDE_DELAY,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the preferred [`Placement`](Placement) of the card.
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Alignment`](iced_native::Alignment) of the card along the
+    /// side of the anchor.
+    #[must_use]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the gap between the anchor and the card.
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding between the card and its content.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the maximal width of the card.
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the delay between hovering the underlay and showing the card.
+    #[must_use]
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.show_delay = delay;
+        self
+    }
+
+    /// Sets the delay between leaving the underlay and the card and hiding
+    /// the card.
+    ///
+    /// The cursor can cross the gap between the underlay and the card within
+    /// this delay.
+    #[must_use]
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// Sets the style of the [`HoverCard`](HoverCard).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Content, Message, Renderer> Widget<Message, Renderer>
+    for HoverCard<'a, Content, Message, Renderer>
+where
+    Content: 'a + Fn() -> Element<'a, Message, Renderer>,
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&(self.content)())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &(self.content)()]);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let hover = state.state.downcast_mut::<State>();
+        let over_underlay = layout.bounds().contains(cursor_position);
+
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => now,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if over_underlay =>
+            {
+                // Pressing the underlay, for example to follow a link, hides
+                // the card until it is hovered again
+                hover.press();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                Instant::now()
+            }
+            _ => Instant::now(),
+        };
+
+        if let Some(request) = hover.hover(
+            over_underlay || hover.over_tooltip,
+            now,
+            self.show_delay,
+            self.hide_delay,
+        ) {
+            shell.request_redraw(request);
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        let hover = state.state.downcast_mut::<State>();
+
+        if !hover.shown {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        let anchor = layout.bounds();
+        let content = (self.content)();
+        let style = self.style;
+        let (underlay_state, content_state) = state.children.split_at_mut(1);
+        content.as_widget().diff(&mut content_state[0]);
+
+        // The overlays of the underlay stay visible below the card
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut underlay_state[0], layout, renderer),
+            )
+            .push(
+                1,
+                TooltipOverlay::new(
+                    &mut content_state[0],
+                    content,
+                    hover,
+                    anchor.size(),
+                    self.placement,
+                    self.alignment,
+                    self.gap,
+                    self.padding,
+                    self.max_width,
+                    move |theme, state| appearance!(StyleSheet, theme, state, style).into(),
+                )
+                .overlay(anchor.position()),
+            )
+            .overlay()
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Content, Message, Renderer> From<HoverCard<'a, Content, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Content: 'a + Fn() -> Element<'a, Message, Renderer>,
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(hover_card: HoverCard<'a, Content, Message, Renderer>) -> Self {
+        Element::new(hover_card)
+    }
+}
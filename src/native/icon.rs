@@ -0,0 +1,303 @@
+//! Display an [`Icon`](crate::graphics::icons::Icon) of an icon font as a
+//! standalone widget.
+//!
+//! Other than an [`IconText`](crate::native::IconText), the icon is drawn
+//! from the outline of its glyph, so it can be freely rotated, e.g. for
+//! animated chevrons or spinners.
+//!
+//! *This API requires the following crate features to be activated: `icon`*
+use std::cell::RefCell;
+
+use iced_graphics::{
+    widget::canvas::{path, Fill, Frame, Path},
+    Backend, Renderer,
+};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    layout::{Limits, Node},
+    renderer,
+    widget::tree::{self, Tag},
+    Color, Element, Font, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use crate::graphics::icons;
+
+/// The default size of an [`Icon`](Icon).
+const DEFAULT_SIZE: f32 = 20.0;
+
+/// A widget displaying a single [`Icon`](icons::Icon).
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::icon::Icon;
+/// # use iced_aw::graphics::icons;
+/// # use iced_native::Color;
+/// #
+/// let chevron = Icon::new(icons::Icon::ChevronDown)
+///     .size(16.0)
+///     .color(Color::BLACK)
+///     .rotation(std::f32::consts::FRAC_PI_2);
+/// ```
+#[allow(missing_debug_implementations)]
+#[derive(Clone, Copy)]
+pub struct Icon {
+    /// The icon to display.
+    icon: icons::Icon,
+    /// The size of the [`Icon`](Icon).
+    size: f32,
+    /// The optional color of the [`Icon`](Icon).
+    color: Option<Color>,
+    /// The clockwise rotation of the [`Icon`](Icon) in radians.
+    rotation: f32,
+    /// The width of the [`Icon`](Icon).
+    width: Length,
+    /// The height of the [`Icon`](Icon).
+    height: Length,
+    /// The horizontal alignment of the [`Icon`](Icon).
+    horizontal_alignment: Horizontal,
+    /// The vertical alignment of the [`Icon`](Icon).
+    vertical_alignment: Vertical,
+}
+
+impl Icon {
+    /// Creates a new [`Icon`](Icon) displaying the given
+    /// [`Icon`](icons::Icon).
+    #[must_use]
+    pub fn new(icon: icons::Icon) -> Self {
+        Self {
+            icon,
+            size: DEFAULT_SIZE,
+            color: None,
+            rotation: 0.0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        }
+    }
+
+    /// Sets the size of the [`Icon`](Icon).
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the [`Color`](iced_native::Color) of the [`Icon`](Icon).
+    ///
+    /// Defaults to the text color of the surrounding widget.
+    #[must_use]
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the clockwise rotation of the [`Icon`](Icon) around its center in
+    /// radians.
+    #[must_use]
+    pub fn rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the width of the [`Icon`](Icon) boundaries.
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Icon`](Icon) boundaries.
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Horizontal`](iced_native::alignment::Horizontal) alignment
+    /// of the [`Icon`](Icon) inside its boundaries.
+    #[must_use]
+    pub fn horizontal_alignment(mut self, alignment: Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`Vertical`](iced_native::alignment::Vertical) alignment
+    /// of the [`Icon`](Icon) inside its boundaries.
+    #[must_use]
+    pub fn vertical_alignment(mut self, alignment: Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+}
+
+impl<Message, B, Theme> Widget<Message, Renderer<B, Theme>> for Icon
+where
+    B: Backend,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(self.size, self.size)))
+    }
+
+    fn draw(
+        &self,
+        state: &iced_native::widget::Tree,
+        renderer: &mut Renderer<B, Theme>,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        // The font is only parsed again if the icon or its size changed
+        let key = (self.icon, self.size.to_bits());
+        let mut cache = state.state.downcast_ref::<State>().glyph.borrow_mut();
+
+        if cache.as_ref().map(|(cached, _)| *cached) != Some(key) {
+            let glyph = match self.icon.font() {
+                Font::External { bytes, .. } => glyph_path(bytes, self.icon.into(), self.size),
+                Font::Default => None,
+            };
+            *cache = Some((key, glyph));
+        }
+
+        let Some((_, Some(glyph))) = cache.as_ref() else {
+            return;
+        };
+
+        let x = match self.horizontal_alignment {
+            Horizontal::Left => self.size / 2.0,
+            Horizontal::Center => bounds.width / 2.0,
+            Horizontal::Right => bounds.width - self.size / 2.0,
+        };
+
+        let y = match self.vertical_alignment {
+            Vertical::Top => self.size / 2.0,
+            Vertical::Center => bounds.height / 2.0,
+            Vertical::Bottom => bounds.height - self.size / 2.0,
+        };
+
+        let mut frame = Frame::new(bounds.size());
+        frame.translate(Vector::new(x, y));
+        frame.rotate(self.rotation);
+        frame.fill(glyph, Fill::from(self.color.unwrap_or(style.text_color)));
+
+        let geometry = frame.into_geometry();
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_primitive(geometry.into_primitive());
+        });
+    }
+}
+
+impl<'a, Message, B, Theme> From<Icon> for Element<'a, Message, Renderer<B, Theme>>
+where
+    B: Backend,
+{
+    fn from(icon: Icon) -> Self {
+        Element::new(icon)
+    }
+}
+
+/// The state of an [`Icon`](Icon).
+#[derive(Default)]
+struct State {
+    /// The [`Path`](Path) of the glyph last drawn, if the font has an outline
+    /// for it, together with the icon and the bits of the size it was built
+    /// for.
+    glyph: RefCell<Option<((icons::Icon, u32), Option<Path>)>>,
+}
+
+/// Builds the [`Path`](Path) of the outline of the glyph of the given
+/// character in the font, scaled to the given size and with the center of its
+/// bounding box at the origin, so rotating it keeps it in place.
+fn glyph_path(font: &[u8], character: char, size: f32) -> Option<Path> {
+    let face = ttf_parser::Face::parse(font, 0).ok()?;
+    let glyph = face.glyph_index(character)?;
+
+    let scale = size / f32::from(face.units_per_em());
+    let bounds = face.glyph_bounding_box(glyph)?;
+    let origin = Vector::new(
+        -(f32::from(bounds.x_min) + f32::from(bounds.x_max)) * scale / 2.0,
+        (f32::from(bounds.y_min) + f32::from(bounds.y_max)) * scale / 2.0,
+    );
+
+    let mut outline = None;
+    let path = Path::new(|builder| {
+        outline = face.outline_glyph(
+            glyph,
+            &mut Outline {
+                builder,
+                scale,
+                origin,
+            },
+        );
+    });
+
+    outline.map(|_| path)
+}
+
+/// Converts the outline of a glyph in font units into a [`Path`](Path).
+struct Outline<'a> {
+    /// The builder of the [`Path`](Path).
+    builder: &'a mut path::Builder,
+    /// The scale from font units to pixels.
+    scale: f32,
+    /// The position of the origin of the glyph.
+    origin: Vector,
+}
+
+impl<'a> Outline<'a> {
+    /// Converts a point in font units into a [`Point`](Point).
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point::new(x * self.scale, -y * self.scale) + self.origin
+    }
+}
+
+impl<'a> ttf_parser::OutlineBuilder for Outline<'a> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.builder.move_to(point);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.builder.line_to(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let control = self.point(x1, y1);
+        let to = self.point(x, y);
+        self.builder.quadratic_curve_to(control, to);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let control_a = self.point(x1, y1);
+        let control_b = self.point(x2, y2);
+        let to = self.point(x, y);
+        self.builder.bezier_curve_to(control_a, control_b, to);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
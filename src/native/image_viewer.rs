@@ -0,0 +1,551 @@
+//! Zoom into an image and pan around it.
+//!
+//! *This API requires the following crate features to be activated: image_viewer*
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use iced_native::{
+    event, image,
+    layout::{Limits, Node},
+    mouse, renderer,
+    widget::{
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use crate::{
+    core::gesture::Pinch,
+    native::operation::{Action, Request},
+};
+
+pub use crate::native::operation::Zoom;
+
+/// The default smallest scale of the image.
+const DEFAULT_MIN_SCALE: f32 = 0.1;
+
+/// The default largest scale of the image.
+const DEFAULT_MAX_SCALE: f32 = 10.0;
+
+/// The default factor a line of the mouse wheel zooms by.
+const DEFAULT_ZOOM_STEP: f32 = 1.25;
+
+/// The number of pixels of a precise scroll making up a line.
+const PIXELS_PER_LINE: f32 = 60.0;
+
+/// A viewer zooming into an image and panning around it.
+///
+/// The mouse wheel zooms around the cursor and dragging the image pans it,
+/// as do pinching and dragging with the fingers. The image starts at the
+/// [`zoom`](ImageViewer::zoom) level and keeps it while the viewer is resized,
+/// until the user zooms or pans. The
+/// [`zoom`](crate::native::operation::zoom) command sets the level again, e.g.
+/// from a toolbar.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{ImageViewer, operation::Zoom};
+/// # use iced_native::image::Handle;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Zoomed(f32),
+/// }
+///
+/// let image_viewer = ImageViewer::new(Handle::from_path("photo.png"))
+///     .zoom(Zoom::Fill)
+///     .on_zoom(Message::Zoomed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ImageViewer<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    /// The image shown.
+    handle: <Renderer as image::Renderer>::Handle,
+    /// The zoom level the image starts at.
+    zoom: Zoom,
+    /// The smallest scale of the image.
+    min_scale: f32,
+    /// The largest scale of the image.
+    max_scale: f32,
+    /// The factor a line of the mouse wheel zooms by.
+    zoom_step: f32,
+    /// The function producing the message with the new scale of the image.
+    on_zoom: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    /// The width of the viewer.
+    width: Length,
+    /// The height of the viewer.
+    height: Length,
+    /// The id of the [`ImageViewer`](ImageViewer) used to zoom it.
+    id: Option<Id>,
+}
+
+impl<'a, Message, Renderer> ImageViewer<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    /// Creates a new [`ImageViewer`](ImageViewer) showing the image.
+    pub fn new(handle: <Renderer as image::Renderer>::Handle) -> Self {
+        ImageViewer {
+            handle,
+            zoom: Zoom::default(),
+            min_scale: DEFAULT_MIN_SCALE,
+            max_scale: DEFAULT_MAX_SCALE,
+            zoom_step: DEFAULT_ZOOM_STEP,
+            on_zoom: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            id: None,
+        }
+    }
+
+    /// Sets the [`Zoom`](Zoom) level the image starts at.
+    #[must_use]
+    pub fn zoom(mut self, zoom: Zoom) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the smallest and the largest scale of the image, where 1 shows
+    /// each pixel of the image on one unit.
+    #[must_use]
+    pub fn scale_range(mut self, min_scale: f32, max_scale: f32) -> Self {
+        self.min_scale = min_scale.max(f32::EPSILON);
+        self.max_scale = max_scale.max(self.min_scale);
+        self
+    }
+
+    /// Sets the factor a line of the mouse wheel zooms by.
+    #[must_use]
+    pub fn zoom_step(mut self, zoom_step: f32) -> Self {
+        self.zoom_step = zoom_step.max(1.0);
+        self
+    }
+
+    /// Sets the function producing the message with the new scale of the
+    /// image whenever it changes.
+    #[must_use]
+    pub fn on_zoom<F>(mut self, on_zoom: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        self.on_zoom = Some(Box::new(on_zoom));
+        self
+    }
+
+    /// Sets the width of the [`ImageViewer`](ImageViewer).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ImageViewer`](ImageViewer).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Id`](Id) of the [`ImageViewer`](ImageViewer) to zoom it
+    /// with [`zoom`](crate::native::operation::zoom).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Returns the size of the image.
+    fn image_size(&self, renderer: &Renderer) -> Size {
+        let dimensions = renderer.dimensions(&self.handle);
+
+        Size::new(dimensions.width as f32, dimensions.height as f32)
+    }
+
+    /// Returns the view of the image currently shown in the bounds.
+    fn view(&self, state: &State, bounds: Size, image: Size) -> View {
+        let view = state.view.unwrap_or_else(|| {
+            let scale = preset_scale(state.zoom.unwrap_or(self.zoom), bounds, image);
+
+            View::centered(scale.clamp(self.min_scale, self.max_scale), bounds, image)
+        });
+
+        view.clamp(bounds, image)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ImageViewer<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(self.image_size(renderer)))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(Action::Zoom(zoom)) = Request::receive(operation, self.id.as_ref()).action() {
+            let state = tree.state.downcast_mut::<State>();
+            state.zoom = Some(zoom);
+            state.view = None;
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let image = self.image_size(renderer);
+
+        // Another image starts over at the zoom level
+        let image_hash = hash(&self.handle);
+        if state.image_hash != Some(image_hash) {
+            state.image_hash = Some(image_hash);
+            state.view = None;
+            state.zoom = None;
+        }
+
+        let view = self.view(state, bounds.size(), image);
+        let cursor = cursor_position - Vector::new(bounds.x, bounds.y);
+
+        let (status, changed) = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+                };
+                let scale =
+                    (view.scale * self.zoom_step.powf(lines)).clamp(self.min_scale, self.max_scale);
+
+                (event::Status::Captured, Some(view.zoom_at(cursor, scale)))
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                state.drag = Some((cursor, view.position));
+
+                (event::Status::Captured, None)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => match state.drag {
+                Some((origin, position)) => (
+                    event::Status::Captured,
+                    Some(View {
+                        position: position + (cursor - origin),
+                        ..view
+                    }),
+                ),
+                None => (event::Status::Ignored, None),
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                (event::Status::Captured, None)
+            }
+            Event::Touch(_) => {
+                let was_active = state.pinch.is_active();
+                let transform = state.pinch.on_event(&event, bounds);
+                let status = if was_active || state.pinch.is_active() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                };
+
+                let changed = transform.map(|transform| {
+                    let center = transform.center - Vector::new(bounds.x, bounds.y);
+                    let scale =
+                        (view.scale * transform.scale).clamp(self.min_scale, self.max_scale);
+                    let panned = View {
+                        position: view.position + transform.translation,
+                        ..view
+                    };
+
+                    panned.zoom_at(center, scale)
+                });
+
+                (status, changed)
+            }
+            _ => (event::Status::Ignored, None),
+        };
+
+        if let Some(changed) = changed {
+            state.view = Some(changed.clamp(bounds.size(), image));
+        }
+
+        let scale = self.view(state, bounds.size(), image).scale;
+        if state
+            .reported
+            .map_or(true, |reported| (reported - scale).abs() > f32::EPSILON)
+        {
+            state.reported = Some(scale);
+
+            if let Some(on_zoom) = &self.on_zoom {
+                shell.publish(on_zoom(scale));
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let view = self.view(state, bounds.size(), self.image_size(renderer));
+        let scaled = view.scaled(self.image_size(renderer));
+
+        if bounds.contains(cursor_position)
+            && (scaled.width > bounds.width || scaled.height > bounds.height)
+        {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let image = self.image_size(renderer);
+        let view = self.view(state, bounds.size(), image);
+        let scaled = view.scaled(image);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.draw(
+                self.handle.clone(),
+                Rectangle {
+                    x: bounds.x + view.position.x,
+                    y: bounds.y + view.position.y,
+                    width: scaled.width,
+                    height: scaled.height,
+                },
+            );
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<ImageViewer<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + image::Renderer,
+{
+    fn from(image_viewer: ImageViewer<'a, Message, Renderer>) -> Self {
+        Element::new(image_viewer)
+    }
+}
+
+/// The scale and the position of the image in an
+/// [`ImageViewer`](ImageViewer).
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct View {
+    /// The scale of the image.
+    scale: f32,
+    /// The position of the top left corner of the image relative to the
+    /// viewer.
+    position: Vector,
+}
+
+impl View {
+    /// Returns the view of the image of the size at the scale, centered in
+    /// the bounds.
+    fn centered(scale: f32, bounds: Size, image: Size) -> Self {
+        Self {
+            scale,
+            position: Vector::new(
+                (bounds.width - image.width * scale) / 2.0,
+                (bounds.height - image.height * scale) / 2.0,
+            ),
+        }
+    }
+
+    /// Returns the size of the image of the size at the scale.
+    fn scaled(self, image: Size) -> Size {
+        Size::new(image.width * self.scale, image.height * self.scale)
+    }
+
+    /// Returns the view zoomed to the scale, keeping the point of the image
+    /// under the cursor in place.
+    fn zoom_at(self, cursor: Point, scale: f32) -> Self {
+        let factor = scale / self.scale;
+        let anchor = Vector::new(cursor.x, cursor.y);
+
+        Self {
+            scale,
+            position: anchor + (self.position - anchor) * factor,
+        }
+    }
+
+    /// Keeps the image of the size inside of the bounds, centering it along
+    /// the axes it is smaller than the bounds in.
+    fn clamp(self, bounds: Size, image: Size) -> Self {
+        let scaled = self.scaled(image);
+        let axis = |position: f32, scaled: f32, bounds: f32| {
+            if scaled <= bounds {
+                (bounds - scaled) / 2.0
+            } else {
+                position.clamp(bounds - scaled, 0.0)
+            }
+        };
+
+        Self {
+            scale: self.scale,
+            position: Vector::new(
+                axis(self.position.x, scaled.width, bounds.width),
+                axis(self.position.y, scaled.height, bounds.height),
+            ),
+        }
+    }
+}
+
+/// Returns the scale of the image of the size in the bounds at the
+/// [`Zoom`](Zoom) level.
+fn preset_scale(zoom: Zoom, bounds: Size, image: Size) -> f32 {
+    if image.width <= 0.0 || image.height <= 0.0 {
+        return 1.0;
+    }
+
+    let (horizontal, vertical) = (bounds.width / image.width, bounds.height / image.height);
+
+    match zoom {
+        Zoom::Fit => horizontal.min(vertical),
+        Zoom::Fill => horizontal.max(vertical),
+        Zoom::Original => 1.0,
+    }
+}
+
+/// The state of an [`ImageViewer`](ImageViewer).
+#[derive(Debug, Default)]
+struct State {
+    /// The view set by the user, if the image was zoomed or panned.
+    view: Option<View>,
+    /// The zoom level set by a command, if any.
+    zoom: Option<Zoom>,
+    /// The position of the cursor and of the image when a drag started.
+    drag: Option<(Point, Vector)>,
+    /// The fingers touching the viewer.
+    pinch: Pinch,
+    /// The scale last reported with the zoom message.
+    reported: Option<f32>,
+    /// The hash of the image shown.
+    image_hash: Option<u64>,
+}
+
+/// Returns the hash of the handle of an image.
+fn hash<H: Hash>(handle: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    handle.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{Point, Size, Vector};
+
+    use super::{preset_scale, View, Zoom};
+
+    /// The size of the viewer used by the tests.
+    const BOUNDS: Size = Size::new(200.0, 100.0);
+
+    /// The size of the image used by the tests.
+    const IMAGE: Size = Size::new(400.0, 400.0);
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn presets_center_the_image() {
+        assert_eq!(preset_scale(Zoom::Fit, BOUNDS, IMAGE), 0.25);
+        assert_eq!(preset_scale(Zoom::Fill, BOUNDS, IMAGE), 0.5);
+        assert_eq!(preset_scale(Zoom::Original, BOUNDS, IMAGE), 1.0);
+
+        let fit = View::centered(0.25, BOUNDS, IMAGE);
+        assert_eq!(fit.position, Vector::new(50.0, 0.0));
+
+        let original = View::centered(1.0, BOUNDS, IMAGE);
+        assert_eq!(original.position, Vector::new(-100.0, -150.0));
+    }
+
+    #[test]
+    fn zooms_around_the_cursor() {
+        let view = View {
+            scale: 1.0,
+            position: Vector::new(0.0, 0.0),
+        };
+
+        // The pixel under the cursor stays under it
+        let zoomed = view.zoom_at(Point::new(100.0, 50.0), 2.0);
+        assert_eq!(zoomed.position, Vector::new(-100.0, -50.0));
+    }
+
+    #[test]
+    fn keeps_the_image_in_view() {
+        let view = View {
+            scale: 1.0,
+            position: Vector::new(50.0, -500.0),
+        };
+        assert_eq!(view.clamp(BOUNDS, IMAGE).position, Vector::new(0.0, -300.0));
+
+        let small = View {
+            scale: 0.1,
+            position: Vector::new(0.0, 0.0),
+        };
+        assert_eq!(small.clamp(BOUNDS, IMAGE).position, Vector::new(80.0, 30.0));
+    }
+}
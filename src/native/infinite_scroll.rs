@@ -0,0 +1,452 @@
+//! Scroll through content that loads more of itself near the end.
+//!
+//! *This API requires the following crate features to be activated: infinite_scroll*
+use iced_native::{
+    alignment::Horizontal,
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    widget::{
+        scrollable::{self, Scrollable},
+        tree::{self, Tag},
+        Operation, Text, Tree,
+    },
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+/// The default distance to the end of the content at which more is loaded.
+const DEFAULT_THRESHOLD: f32 = 200.0;
+
+/// Scrollable content producing a message to load more of it when it is
+/// scrolled near its end.
+///
+/// The message is produced once until the load finishes, which is either
+/// when the [`InfiniteScroll`](InfiniteScroll) stops [`loading`](Self::loading)
+/// or when the content changes its height. A footer, by default a short text,
+/// is shown below the content while loading. Once everything is loaded, the
+/// message should no longer be set.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, widget::{Column, Text}};
+/// # use iced_aw::native::infinite_scroll;
+/// #
+/// # pub type InfiniteScroll<'a, Message> = infinite_scroll::InfiniteScroll<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     LoadMore,
+/// }
+///
+/// let items = Column::with_children(
+///     (0..50).map(|index| Text::new(format!("Item {index}")).into()).collect(),
+/// );
+///
+/// let infinite_scroll = InfiniteScroll::new(items)
+///     .on_load_more(Message::LoadMore)
+///     .loading(false);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct InfiniteScroll<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+    Renderer::Theme: scrollable::StyleSheet + iced_style::text::StyleSheet,
+{
+    /// The scrollable content.
+    scrollable: Scrollable<'a, Message, Renderer>,
+    /// The footer shown below the content while loading.
+    footer: Element<'a, Message, Renderer>,
+    /// The message produced to load more content, if any.
+    on_load_more: Option<Message>,
+    /// Whether more content is being loaded.
+    loading: bool,
+    /// The distance to the end of the content at which more is loaded.
+    threshold: f32,
+    /// The width of the [`InfiniteScroll`](InfiniteScroll).
+    width: Length,
+    /// The height of the [`InfiniteScroll`](InfiniteScroll).
+    height: Length,
+}
+
+impl<'a, Message, Renderer> InfiniteScroll<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer,
+    Renderer::Theme: scrollable::StyleSheet + iced_style::text::StyleSheet,
+{
+    /// Creates a new [`InfiniteScroll`](InfiniteScroll) scrolling the content.
+    pub fn new<Content>(content: Content) -> Self
+    where
+        Content: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            scrollable: Scrollable::new(content)
+                .width(Length::Fill)
+                .height(Length::Fill),
+            footer: Text::new("Loading...")
+                .width(Length::Fill)
+                .horizontal_alignment(Horizontal::Center)
+                .into(),
+            on_load_more: None,
+            loading: false,
+            threshold: DEFAULT_THRESHOLD,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the message produced when the content is scrolled near its end.
+    #[must_use]
+    pub fn on_load_more(mut self, message: Message) -> Self {
+        self.on_load_more = Some(message);
+        self
+    }
+
+    /// Sets whether more content is being loaded, showing the footer.
+    #[must_use]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the footer shown below the content while loading.
+    #[must_use]
+    pub fn footer<Footer>(mut self, footer: Footer) -> Self
+    where
+        Footer: Into<Element<'a, Message, Renderer>>,
+    {
+        self.footer = footer.into();
+        self
+    }
+
+    /// Sets the distance to the end of the content at which more is loaded.
+    #[must_use]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Sets the [`Id`](scrollable::Id) of the scrollable content.
+    #[must_use]
+    pub fn id(mut self, id: scrollable::Id) -> Self {
+        self.scrollable = self.scrollable.id(id);
+        self
+    }
+
+    /// Sets the width of the [`InfiniteScroll`](InfiniteScroll).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self.scrollable = self.scrollable.width(width);
+        self
+    }
+
+    /// Sets the height of the [`InfiniteScroll`](InfiniteScroll).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self.scrollable = self.scrollable.height(height);
+        self
+    }
+
+    /// Sets the style of the scrollbar.
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as scrollable::StyleSheet>::Style) -> Self {
+        self.scrollable = self.scrollable.style(style);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for InfiniteScroll<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer,
+    Renderer::Theme: scrollable::StyleSheet + iced_style::text::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.scrollable as &dyn Widget<_, _>),
+            Tree::new(&self.footer),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[
+            &self.scrollable as &dyn Widget<_, _>,
+            self.footer.as_widget(),
+        ]);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let footer = self.loading.then(|| {
+            self.footer
+                .as_widget()
+                .layout(renderer, &Limits::new(Size::ZERO, limits.max()))
+        });
+        let footer_height = footer.as_ref().map_or(0.0, |footer| footer.size().height);
+
+        let scrollable = Widget::<Message, Renderer>::layout(
+            &self.scrollable,
+            renderer,
+            &limits.shrink(Size::new(0.0, footer_height)),
+        );
+        let scrollable_size = scrollable.size();
+
+        let mut children = vec![scrollable];
+        if let Some(mut footer) = footer {
+            footer.move_to(Point::new(0.0, scrollable_size.height));
+            children.push(footer);
+        }
+
+        let size = limits.resolve(Size::new(
+            scrollable_size.width,
+            scrollable_size.height + footer_height,
+        ));
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let Some(scrollable_layout) = children.next() else {
+            return event::Status::Ignored;
+        };
+        let footer_layout = children.next();
+
+        let mut status = self.scrollable.on_event(
+            &mut tree.children[0],
+            event.clone(),
+            scrollable_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if let Some(footer_layout) = footer_layout {
+            status = status.merge(self.footer.as_widget_mut().on_event(
+                &mut tree.children[1],
+                event,
+                footer_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ));
+        }
+
+        let Some(content_bounds) = scrollable_layout
+            .children()
+            .next()
+            .map(|content| content.bounds())
+        else {
+            return status;
+        };
+        let bounds = scrollable_layout.bounds();
+        let offset = tree.children[0]
+            .state
+            .downcast_ref::<scrollable::State>()
+            .offset(bounds, content_bounds);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.loading {
+            state.was_loading = true;
+        } else if state.was_loading {
+            state.was_loading = false;
+            state.pending = None;
+        }
+
+        if state.pending.map_or(false, |height| {
+            (content_bounds.height - height).abs() >= 1.0
+        }) {
+            state.pending = None;
+        }
+
+        let remaining = content_bounds.height - offset.y - bounds.height;
+
+        if let Some(message) = &self.on_load_more {
+            if !self.loading && state.pending.is_none() && remaining <= self.threshold {
+                state.pending = Some(content_bounds.height);
+                shell.publish(message.clone());
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+
+        let scrollable = children
+            .next()
+            .map_or_else(mouse::Interaction::default, |layout| {
+                self.scrollable.mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            });
+        let footer = children
+            .next()
+            .map_or_else(mouse::Interaction::default, |layout| {
+                self.footer.as_widget().mouse_interaction(
+                    &tree.children[1],
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            });
+
+        scrollable.max(footer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+
+        if let Some(scrollable_layout) = children.next() {
+            self.scrollable.draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                scrollable_layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        if let Some(footer_layout) = children.next() {
+            self.footer.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                footer_layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut children = layout.children();
+
+        if let Some(scrollable_layout) = children.next() {
+            self.scrollable.operate(
+                &mut tree.children[0],
+                scrollable_layout,
+                renderer,
+                operation,
+            );
+        }
+
+        if let Some(footer_layout) = children.next() {
+            self.footer.as_widget().operate(
+                &mut tree.children[1],
+                footer_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let mut children = layout.children();
+        let scrollable_layout = children.next()?;
+        let footer_layout = children.next();
+        let (scrollable_tree, footer_tree) = tree.children.split_at_mut(1);
+
+        Widget::<Message, Renderer>::overlay(
+            &mut self.scrollable,
+            &mut scrollable_tree[0],
+            scrollable_layout,
+            renderer,
+        )
+        .or_else(|| {
+            footer_layout.and_then(|footer_layout| {
+                self.footer
+                    .as_widget_mut()
+                    .overlay(&mut footer_tree[0], footer_layout, renderer)
+            })
+        })
+    }
+}
+
+impl<'a, Message, Renderer> From<InfiniteScroll<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer,
+    Renderer::Theme: scrollable::StyleSheet + iced_style::text::StyleSheet,
+{
+    fn from(infinite_scroll: InfiniteScroll<'a, Message, Renderer>) -> Self {
+        Element::new(infinite_scroll)
+    }
+}
+
+/// The state of an [`InfiniteScroll`](InfiniteScroll).
+#[derive(Debug, Default)]
+struct State {
+    /// The height of the content when more was requested, while the load is
+    /// in flight.
+    pending: Option<f32>,
+    /// Whether the [`InfiniteScroll`](InfiniteScroll) was loading.
+    was_loading: bool,
+}
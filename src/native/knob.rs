@@ -0,0 +1,630 @@
+//! Turn a value with a rotary knob.
+//!
+//! *This API requires the following crate features to be activated: knob*
+use std::{
+    f32::consts::{FRAC_PI_2, PI},
+    ops::RangeInclusive,
+};
+
+use iced_graphics::{
+    widget::canvas::{path, Frame, LineCap, Path, Stroke},
+    Backend, Renderer,
+};
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Renderer as _, Shell, Size,
+    Vector, Widget,
+};
+
+use crate::{
+    core::snap::snap,
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::knob::{Appearance, StyleSheet};
+
+/// The default diameter of a [`Knob`](Knob).
+const DEFAULT_SIZE: f32 = 48.0;
+
+/// The number of steps the value moves by a press of Page Up or Page Down.
+const PAGE_STEPS: f32 = 10.0;
+
+/// The default number of steps in the range, if no step is set.
+const DEFAULT_STEPS: f32 = 100.0;
+
+/// The vertical distance dragged to turn the knob through the whole range.
+const DRAG_DISTANCE: f32 = 200.0;
+
+/// The factor slowing down the drag while Shift is held.
+const FINE_FACTOR: f32 = 0.1;
+
+/// The part of the range around a detent that snaps to it.
+const DETENT_REACH: f32 = 0.03;
+
+/// The angle of the minimum, at the bottom left.
+const START_ANGLE: f32 = 3.0 * PI / 4.0;
+
+/// The angle the knob turns clockwise from the minimum to the maximum.
+const SWEEP: f32 = 3.0 * FRAC_PI_2;
+
+/// The width of the arc around the knob.
+const ARC_WIDTH: f32 = 3.0;
+
+/// The length of the tick marks.
+const TICK_LENGTH: f32 = 4.0;
+
+/// The gap between the tick marks, the arc and the knob.
+const GAP: f32 = 2.0;
+
+/// A rotary control turning a value in a range.
+///
+/// Dragging up or down turns the knob, or more finely while Shift is held, and
+/// so does the mouse wheel. Detents catch the value when it comes close to
+/// them, like the center of a panning knob. A focused knob is turned with the
+/// arrow keys by a step, with Page Up and Page Down by ten steps and with Home
+/// and End to the bounds of the range.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Knob;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PanChanged(f32),
+/// }
+///
+/// let pan = 0.0;
+///
+/// let knob = Knob::new(-1.0..=1.0, pan, Message::PanChanged)
+///     .step(0.01)
+///     .detents([0.0])
+///     .ticks(11);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, Message, B, Theme>
+where
+    B: Backend,
+    Theme: StyleSheet,
+{
+    /// The id of the [`Knob`](Knob) to focus it.
+    id: Option<Id>,
+    /// The range of the values.
+    range: RangeInclusive<f32>,
+    /// The value.
+    value: f32,
+    /// The step the values snap to, if any.
+    step: Option<f32>,
+    /// The values catching the value when it comes close to them.
+    detents: Vec<f32>,
+    /// The number of tick marks spread over the range.
+    ticks: usize,
+    /// The function producing the message when the value changes.
+    on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    /// The message produced when the knob is released, if any.
+    on_release: Option<Message>,
+    /// The diameter of the knob.
+    size: f32,
+    /// The style of the knob.
+    style: <Theme as StyleSheet>::Style,
+    #[allow(missing_docs)]
+    backend: std::marker::PhantomData<B>,
+}
+
+impl<'a, Message, B, Theme> Knob<'a, Message, B, Theme>
+where
+    B: Backend,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Knob`](Knob).
+    ///
+    /// It expects:
+    ///     * the inclusive range of the values.
+    ///     * the current value.
+    ///     * the function that will be called with the new value when the
+    ///         knob is turned.
+    pub fn new<F>(range: RangeInclusive<f32>, value: f32, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        Self {
+            id: None,
+            range,
+            value,
+            step: None,
+            detents: Vec::new(),
+            ticks: 0,
+            on_change: Box::new(on_change),
+            on_release: None,
+            size: DEFAULT_SIZE,
+            style: <Theme as StyleSheet>::Style::default(),
+            backend: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`Knob`](Knob) to
+    /// focus it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the step the values of the [`Knob`](Knob) snap to.
+    ///
+    /// Without a step, the values are continuous and the keys move them by a
+    /// hundredth of the range.
+    #[must_use]
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the values catching the value when it comes close to them.
+    #[must_use]
+    pub fn detents(mut self, detents: impl IntoIterator<Item = f32>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// Sets the number of tick marks spread over the range, including its
+    /// bounds.
+    #[must_use]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the message produced when the [`Knob`](Knob) is released after
+    /// a drag.
+    #[must_use]
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`](Knob).
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`Knob`](Knob).
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the difference between the bounds of the range.
+    fn span(&self) -> f32 {
+        (self.range.end() - self.range.start()).max(0.0)
+    }
+
+    /// Returns the step the keys move the value by.
+    fn key_step(&self) -> f32 {
+        self.step
+            .filter(|step| *step > 0.0)
+            .unwrap_or(self.span() / DEFAULT_STEPS)
+    }
+
+    /// Publishes the value, caught by the detents and snapped to the step, if
+    /// it changed.
+    fn change(&self, value: f32, shell: &mut Shell<'_, Message>) {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let value = detent(value, &self.detents, self.span() * DETENT_REACH)
+            .unwrap_or_else(|| snap(value, min, max, self.step.unwrap_or(0.0)));
+
+        self.publish(value, shell);
+    }
+
+    /// Publishes the value if it changed.
+    #[allow(clippy::float_cmp)]
+    fn publish(&self, value: f32, shell: &mut Shell<'_, Message>) {
+        if value != self.value {
+            shell.publish((self.on_change)(value));
+        }
+    }
+}
+
+impl<'a, Message, B, Theme> Widget<Message, Renderer<B, Theme>> for Knob<'a, Message, B, Theme>
+where
+    Message: Clone,
+    B: Backend,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer<B, Theme>, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(Length::Fixed(self.size))
+                .height(Length::Fixed(self.size))
+                .resolve(Size::new(self.size, self.size)),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer<B, Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let pressed = bounds.contains(cursor_position);
+                state.focus.press(pressed);
+
+                if !pressed {
+                    return event::Status::Ignored;
+                }
+
+                state.drag = Some(Drag {
+                    origin: cursor_position.y,
+                    value: self.value,
+                    fine: state.modifiers.shift(),
+                });
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                // Holding Shift turns the knob finely from where it is now
+                if state.modifiers.shift() != drag.fine {
+                    drag.value = drag.turn(position.y, self.span());
+                    drag.origin = position.y;
+                    drag.fine = state.modifiers.shift();
+                }
+
+                self.change(drag.turn(position.y, self.span()), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_none() {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let turn = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.key_step(),
+                    mouse::ScrollDelta::Pixels { y, .. } => y / DRAG_DISTANCE * self.span(),
+                };
+
+                self.change(self.value + turn, shell);
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.focus.is_focused() =>
+            {
+                let step = self.key_step();
+                let value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => self.value - step,
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => self.value + step,
+                    keyboard::KeyCode::PageDown => self.value - PAGE_STEPS * step,
+                    keyboard::KeyCode::PageUp => self.value + PAGE_STEPS * step,
+                    keyboard::KeyCode::Home => *self.range.start(),
+                    keyboard::KeyCode::End => *self.range.end(),
+                    _ => return event::Status::Ignored,
+                };
+
+                let (min, max) = (*self.range.start(), *self.range.end());
+                self.publish(snap(value, min, max, self.step.unwrap_or(0.0)), shell);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<B, Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::ResizingVertically
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                state.drag.is_some(),
+                state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
+
+        // The tick marks are on the outside, then the arc and the knob
+        let outer = bounds.width.min(bounds.height) / 2.0;
+        let arc_radius = if self.ticks > 0 {
+            outer - TICK_LENGTH - GAP - ARC_WIDTH / 2.0
+        } else {
+            outer - ARC_WIDTH / 2.0
+        };
+        let knob_radius = arc_radius - ARC_WIDTH / 2.0 - GAP;
+        if knob_radius <= 0.0 {
+            return;
+        }
+
+        let mut frame = Frame::new(bounds.size());
+        let center = frame.center();
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let value_angle = angle(fraction(min, max, self.value));
+        let direction = |angle: f32| Vector::new(angle.cos(), angle.sin());
+
+        let arc = |from: f32, to: f32| {
+            Path::new(|builder| {
+                builder.arc(path::Arc {
+                    center,
+                    radius: arc_radius,
+                    start_angle: from,
+                    end_angle: to,
+                });
+            })
+        };
+        let stroke = |color| {
+            Stroke::default()
+                .with_color(color)
+                .with_width(ARC_WIDTH)
+                .with_line_cap(LineCap::Round)
+        };
+
+        frame.stroke(
+            &arc(START_ANGLE, START_ANGLE + SWEEP),
+            stroke(appearance.track_color),
+        );
+
+        // Ranges around zero fill from zero, like a panning knob
+        let origin = angle(fraction(min, max, 0.0_f32.clamp(min, max)));
+        let (from, to) = if value_angle < origin {
+            (value_angle, origin)
+        } else {
+            (origin, value_angle)
+        };
+        if to > from {
+            frame.stroke(&arc(from, to), stroke(appearance.filled_color));
+        }
+
+        if self.ticks > 0 {
+            let tick = |angle: f32| {
+                Path::line(
+                    center + direction(angle) * (outer - TICK_LENGTH),
+                    center + direction(angle) * outer,
+                )
+            };
+            let tick_stroke = Stroke::default()
+                .with_color(appearance.tick_color)
+                .with_width(1.0);
+
+            for index in 0..self.ticks {
+                let t = if self.ticks > 1 {
+                    index as f32 / (self.ticks - 1) as f32
+                } else {
+                    0.5
+                };
+                frame.stroke(&tick(angle(t)), tick_stroke.clone());
+            }
+        }
+
+        let knob = Path::circle(center, knob_radius);
+        frame.fill(&knob, appearance.knob_color);
+        if appearance.knob_border_width > 0.0 {
+            frame.stroke(
+                &knob,
+                Stroke::default()
+                    .with_color(appearance.knob_border_color)
+                    .with_width(appearance.knob_border_width),
+            );
+        }
+
+        frame.stroke(
+            &Path::line(
+                center + direction(value_angle) * (knob_radius * 0.35),
+                center + direction(value_angle) * (knob_radius * 0.8),
+            ),
+            Stroke::default()
+                .with_color(appearance.indicator_color)
+                .with_width(ARC_WIDTH)
+                .with_line_cap(LineCap::Round),
+        );
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_primitive(frame.into_geometry().into_primitive());
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer<B, Theme>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, B, Theme> From<Knob<'a, Message, B, Theme>>
+    for Element<'a, Message, Renderer<B, Theme>>
+where
+    Message: 'a + Clone,
+    B: 'a + Backend,
+    Theme: 'a + StyleSheet,
+{
+    fn from(knob: Knob<'a, Message, B, Theme>) -> Self {
+        Element::new(knob)
+    }
+}
+
+/// The state of a [`Knob`](Knob).
+#[derive(Debug, Default)]
+struct State {
+    /// The drag turning the knob, if any.
+    drag: Option<Drag>,
+    /// The keyboard focus.
+    focus: Focus,
+    /// The keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+}
+
+/// A drag turning a [`Knob`](Knob).
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The vertical position the drag turns from.
+    origin: f32,
+    /// The value the drag turns from.
+    value: f32,
+    /// Whether the drag turns the knob finely.
+    fine: bool,
+}
+
+impl Drag {
+    /// Returns the value turned to at the vertical position.
+    ///
+    /// Dragging up turns the value up.
+    fn turn(&self, y: f32, span: f32) -> f32 {
+        let factor = if self.fine { FINE_FACTOR } else { 1.0 };
+
+        self.value + (self.origin - y) / DRAG_DISTANCE * span * factor
+    }
+}
+
+/// Returns the position of the value in the range between 0.0 and 1.0.
+fn fraction(min: f32, max: f32, value: f32) -> f32 {
+    if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns the angle of the knob at the position between 0.0 and 1.0.
+fn angle(fraction: f32) -> f32 {
+    START_ANGLE + fraction * SWEEP
+}
+
+/// Returns the nearest detent within reach of the value, if any.
+fn detent(value: f32, detents: &[f32], reach: f32) -> Option<f32> {
+    detents
+        .iter()
+        .copied()
+        .filter(|detent| (detent - value).abs() <= reach)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detent, Drag, DRAG_DISTANCE};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn dragging_up_turns_the_value_up() {
+        let drag = Drag {
+            origin: 100.0,
+            value: 0.5,
+            fine: false,
+        };
+
+        assert_close(drag.turn(100.0 - DRAG_DISTANCE / 4.0, 2.0), 1.0);
+        assert_close(drag.turn(100.0 + DRAG_DISTANCE / 4.0, 2.0), 0.0);
+
+        let fine = Drag { fine: true, ..drag };
+        assert_close(fine.turn(100.0 - DRAG_DISTANCE / 4.0, 2.0), 0.55);
+    }
+
+    #[test]
+    fn detents_catch_close_values() {
+        let detents = [0.0, 0.5];
+
+        assert_eq!(detent(0.02, &detents, 0.05), Some(0.0));
+        assert_eq!(detent(0.47, &detents, 0.05), Some(0.5));
+        assert_eq!(detent(0.2, &detents, 0.05), None);
+        assert_eq!(detent(0.2, &[], 0.05), None);
+    }
+}
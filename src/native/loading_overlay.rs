@@ -0,0 +1,424 @@
+//! Dim content while it loads and show a spinner above it.
+//!
+//! *This API requires the following crate features to be activated: loading_overlay*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text,
+    widget::{Operation, Tree},
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    native::spinner::Spinner,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::loading_overlay::{Appearance, StyleSheet};
+
+/// The default diameter of the spinner.
+const DEFAULT_SPINNER_SIZE: f32 = 40.0;
+
+/// The default space between the spinner and the message.
+const DEFAULT_SPACING: f32 = 10.0;
+
+/// A wrapper dimming its content while it is active and showing a spinner,
+/// followed by an optional message, in its center.
+///
+/// The content keeps its layout but receives no input while the overlay is
+/// active, so the user can not interact with a screen whose data is still
+/// loading.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, widget::Text};
+/// # use iced_aw::native::loading_overlay;
+/// #
+/// # pub type LoadingOverlay<'a, Message> = loading_overlay::LoadingOverlay<'a, Message, Null>;
+/// let is_loading = true;
+///
+/// let loading_overlay: LoadingOverlay<'_, ()> =
+///     LoadingOverlay::new(Text::new("Orders"), is_loading).message("Loading orders...");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LoadingOverlay<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + crate::style::spinner::StyleSheet,
+{
+    /// The content dimmed while the overlay is active.
+    content: Element<'a, Message, Renderer>,
+    /// The spinner shown while the overlay is active.
+    spinner: Element<'a, Message, Renderer>,
+    /// Whether the overlay is active.
+    is_active: bool,
+    /// The message shown below the spinner.
+    message: Option<String>,
+    /// The space between the spinner and the message.
+    spacing: f32,
+    /// The text size of the message.
+    text_size: Option<f32>,
+    /// The font of the message.
+    font: Font,
+    /// The style of the loading overlay.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> LoadingOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + crate::style::spinner::StyleSheet,
+{
+    /// Creates a new [`LoadingOverlay`](LoadingOverlay) wrapping the content,
+    /// active if `is_active` is true.
+    pub fn new<C>(content: C, is_active: bool) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        LoadingOverlay {
+            content: content.into(),
+            spinner: spinner(DEFAULT_SPINNER_SIZE),
+            is_active,
+            message: None,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message shown below the spinner.
+    #[must_use]
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Sets the diameter of the spinner.
+    #[must_use]
+    pub fn spinner_size(mut self, size: f32) -> Self {
+        self.spinner = spinner(size);
+        self
+    }
+
+    /// Sets the space between the spinner and the message.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the message.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the message.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`LoadingOverlay`](LoadingOverlay).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Returns a [`Spinner`](Spinner) with the diameter.
+fn spinner<'a, Message, Renderer>(size: f32) -> Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: crate::style::spinner::StyleSheet,
+{
+    Spinner::new()
+        .width(Length::Fixed(size))
+        .height(Length::Fixed(size))
+        .circle_radius(size / 10.0)
+        .into()
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for LoadingOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + crate::style::spinner::StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.spinner)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.spinner]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content.as_widget().layout(renderer, limits);
+        let size = content.size();
+
+        // The spinner and the message are centered together
+        let mut spinner = self
+            .spinner
+            .as_widget()
+            .layout(renderer, &Limits::new(Size::ZERO, size));
+        let message_height = if self.message.is_some() {
+            self.spacing + self.text_size.unwrap_or_else(|| renderer.default_size())
+        } else {
+            0.0
+        };
+        let spinner_size = spinner.size();
+        spinner.move_to(Point::new(
+            (size.width - spinner_size.width) / 2.0,
+            (size.height - spinner_size.height - message_height) / 2.0,
+        ));
+
+        Node::with_children(size, vec![content, spinner])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout for a loading overlay.");
+
+        if !self.is_active {
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+        }
+
+        match event {
+            // The content keeps animating and following the window
+            Event::Window(_) => {
+                let spinner_layout = children
+                    .next()
+                    .expect("Native: Layout should have a spinner layout for a loading overlay.");
+
+                let _ = self.spinner.as_widget_mut().on_event(
+                    &mut tree.children[1],
+                    event.clone(),
+                    spinner_layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+
+                self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    content_layout,
+                    Point::new(-1.0, -1.0),
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            }
+            Event::Mouse(_) | Event::Touch(_) if !layout.bounds().contains(cursor_position) => {
+                event::Status::Ignored
+            }
+            // Any other input is blocked
+            _ => event::Status::Captured,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_active {
+            return if layout.bounds().contains(cursor_position) {
+                mouse::Interaction::Idle
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a loading overlay."),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout for a loading overlay.");
+
+        // The dimmed content does not react to the cursor
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            if self.is_active {
+                Point::new(-1.0, -1.0)
+            } else {
+                cursor_position
+            },
+            viewport,
+        );
+
+        if !self.is_active {
+            return;
+        }
+
+        let spinner_layout = children
+            .next()
+            .expect("Native: Layout should have a spinner layout for a loading overlay.");
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+
+        // A new layer puts the scrim above the text of the content
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: iced_native::Color::TRANSPARENT,
+                },
+                appearance.scrim,
+            );
+
+            self.spinner.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.spinner_color,
+                },
+                spinner_layout,
+                cursor_position,
+                viewport,
+            );
+
+            if let Some(message) = &self.message {
+                let spinner_bounds = spinner_layout.bounds();
+                let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                renderer.fill_text(text::Text {
+                    content: message,
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: spinner_bounds.y + spinner_bounds.height + self.spacing,
+                        width: bounds.width,
+                        height: text_size,
+                    },
+                    size: text_size,
+                    color: appearance.message_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Top,
+                });
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a loading overlay."),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        // The overlays of the content are closed while it loads
+        if self.is_active {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout for a loading overlay."),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<LoadingOverlay<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + crate::style::spinner::StyleSheet,
+{
+    fn from(loading_overlay: LoadingOverlay<'a, Message, Renderer>) -> Self {
+        Element::new(loading_overlay)
+    }
+}
@@ -0,0 +1,620 @@
+//! Type text following a pattern like a phone number or a license plate.
+//!
+//! *This API requires the following crate features to be activated: masked_input*
+use std::rc::Rc;
+
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer,
+    widget::{
+        text_input::{self, cursor, Value},
+        tree::{self, Tree},
+        Operation, TextInput,
+    },
+    Clipboard, Element, Event, Font, Layout, Length, Padding, Point, Rectangle, Shell, Widget,
+};
+
+/// A place in a [`Mask`](Mask).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Slot {
+    /// A digit has to be typed.
+    Digit,
+    /// A letter has to be typed.
+    Letter,
+    /// A letter or a digit has to be typed.
+    Alphanumeric,
+    /// The character is inserted automatically.
+    Literal(char),
+}
+
+impl Slot {
+    /// Returns whether the character may be typed into the slot.
+    fn accepts(self, character: char) -> bool {
+        match self {
+            Self::Digit => character.is_ascii_digit(),
+            Self::Letter => character.is_alphabetic(),
+            Self::Alphanumeric => character.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+
+    /// Returns whether the character of the slot is inserted automatically.
+    fn is_literal(self) -> bool {
+        matches!(self, Self::Literal(_))
+    }
+}
+
+/// A pattern the text of a [`MaskedInput`](MaskedInput) has to follow.
+///
+/// In the pattern, `#` and `9` stand for a digit, `A` for a letter and `*` for
+/// a letter or a digit. Every other character is a literal that is inserted
+/// automatically, and a backslash turns the next character into a literal.
+///
+/// # Example
+/// ```
+/// # use iced_aw::native::masked_input::Mask;
+/// let phone = Mask::new("(###) ###-####");
+///
+/// assert_eq!(phone.format("5551234"), "(555) 123-4");
+/// assert_eq!(phone.raw("(555) 123-4"), "5551234");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mask {
+    /// The slots of the pattern.
+    slots: Vec<Slot>,
+}
+
+impl Mask {
+    /// Creates a new [`Mask`](Mask) from the pattern.
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        let mut characters = pattern.chars();
+        let mut slots = Vec::new();
+
+        while let Some(character) = characters.next() {
+            slots.push(match character {
+                '#' | '9' => Slot::Digit,
+                'A' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => Slot::Literal(characters.next().unwrap_or('\\')),
+                literal => Slot::Literal(literal),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Fits the text into the mask.
+    ///
+    /// The characters that may not be typed at their place are dropped and the
+    /// literals are inserted up to the last typed character.
+    #[must_use]
+    pub fn format(&self, text: &str) -> String {
+        self.fill(text, 0).0
+    }
+
+    /// Returns the typed characters of the text fitted into the mask, without
+    /// the literals.
+    #[must_use]
+    pub fn raw(&self, text: &str) -> String {
+        self.format(text)
+            .chars()
+            .zip(&self.slots)
+            .filter(|(_, slot)| !slot.is_literal())
+            .map(|(character, _)| character)
+            .collect()
+    }
+
+    /// Returns whether every slot of the mask is filled by the text.
+    #[must_use]
+    pub fn is_complete(&self, text: &str) -> bool {
+        let slots = self.slots.iter().filter(|slot| !slot.is_literal()).count();
+
+        self.raw(text).chars().count() == slots
+    }
+
+    /// Returns the pattern with the fill character in every slot to type
+    /// into, e.g. to show it as the placeholder.
+    #[must_use]
+    pub fn placeholder(&self, fill: char) -> String {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Literal(literal) => *literal,
+                _ => fill,
+            })
+            .collect()
+    }
+
+    /// Fits the text into the mask and returns it with the position the
+    /// cursor moves to, given the cursor was in the text before fitting it.
+    fn fill(&self, text: &str, cursor: usize) -> (String, usize) {
+        let mut input = text.chars().peekable();
+        let mut consumed = 0;
+        let mut output = String::new();
+        let mut length = 0;
+        let mut pending = String::new();
+        let mut caret = (cursor == 0).then_some(0);
+
+        for &slot in &self.slots {
+            if input.peek().is_none() {
+                break;
+            }
+
+            if let Slot::Literal(literal) = slot {
+                // Literals are only shown once a character is typed after them
+                pending.push(literal);
+
+                if input.next_if_eq(&literal).is_some() {
+                    consumed += 1;
+                    if consumed == cursor {
+                        caret = Some(length);
+                    }
+                }
+
+                continue;
+            }
+
+            let found = input.by_ref().find(|&character| {
+                consumed += 1;
+                let accepted = slot.accepts(character);
+
+                if !accepted && consumed == cursor {
+                    caret = Some(length);
+                }
+
+                accepted
+            });
+
+            let Some(character) = found else {
+                break;
+            };
+
+            length += pending.chars().count() + 1;
+            output.push_str(&pending);
+            output.push(character);
+            pending.clear();
+
+            if consumed == cursor {
+                caret = Some(length);
+            }
+        }
+
+        // The literals closing the pattern are shown once it is filled
+        if length > 0 && self.slots[length..].iter().all(|slot| slot.is_literal()) {
+            output.extend(self.slots[length..].iter().filter_map(|slot| match slot {
+                Slot::Literal(literal) => Some(*literal),
+                _ => None,
+            }));
+            length = self.slots.len();
+        }
+
+        (output, caret.unwrap_or(length).min(length))
+    }
+
+    /// Replaces the characters of the fitted text between start and end with
+    /// the typed ones.
+    fn insert(&self, text: &str, start: usize, end: usize, typed: &str) -> (String, usize) {
+        let characters: Vec<char> = text.chars().collect();
+        let start = start.min(characters.len());
+        let end = end.clamp(start, characters.len());
+
+        let mut edited: String = characters[..start].iter().collect();
+        edited.push_str(typed);
+        edited.extend(&characters[end..]);
+
+        self.fill(&edited, start + typed.chars().count())
+    }
+
+    /// Removes the typed character before the cursor from the fitted text.
+    fn backspace(&self, text: &str, cursor: usize) -> (String, usize) {
+        match (0..cursor).rev().find(|&index| self.is_typed(index)) {
+            Some(index) => self.insert(text, index, index + 1, ""),
+            None => (text.to_owned(), cursor),
+        }
+    }
+
+    /// Removes the typed character after the cursor from the fitted text.
+    fn delete(&self, text: &str, cursor: usize) -> (String, usize) {
+        let length = text.chars().count();
+
+        match (cursor..length).find(|&index| self.is_typed(index)) {
+            Some(index) => self.insert(text, index, index + 1, ""),
+            None => (text.to_owned(), cursor),
+        }
+    }
+
+    /// Returns whether the character at the index of a fitted text was typed.
+    fn is_typed(&self, index: usize) -> bool {
+        self.slots
+            .get(index)
+            .map_or(false, |slot| !slot.is_literal())
+    }
+}
+
+/// The text of a [`MaskedInput`](MaskedInput) after the user changed it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// The text with the literals of the mask, to show in the input.
+    pub masked: String,
+    /// The typed characters without the literals of the mask.
+    pub raw: String,
+    /// Whether every slot of the mask is filled.
+    pub is_complete: bool,
+}
+
+impl Entry {
+    /// Creates the [`Entry`](Entry) of the text fitted into the mask.
+    fn new(mask: &Mask, text: &str) -> Self {
+        let masked = mask.format(text);
+
+        Self {
+            raw: mask.raw(&masked),
+            is_complete: mask.is_complete(&masked),
+            masked,
+        }
+    }
+}
+
+/// A text input only accepting text that follows a [`Mask`](Mask).
+///
+/// Characters that may not be typed at the cursor are rejected, the literals
+/// of the mask are inserted automatically and backspace skips over them. Each
+/// change produces an [`Entry`](Entry) with both the masked and the raw text.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::masked_input::{self, Entry, Mask};
+/// #
+/// # pub type MaskedInput<'a, Message> = masked_input::MaskedInput<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Typed(Entry),
+/// }
+///
+/// let mask = Mask::new("AA-99-AA");
+///
+/// let masked_input = MaskedInput::new(&mask, &mask.placeholder('_'), "AB-12", Message::Typed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MaskedInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    /// The pattern the text has to follow.
+    mask: Mask,
+    /// The text fitted into the mask.
+    value: String,
+    /// The function producing the message when the text changes.
+    on_input: Rc<dyn Fn(Entry) -> Message + 'a>,
+    /// The field the text is typed into.
+    content: TextInput<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> MaskedInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    /// Creates a new [`MaskedInput`](MaskedInput).
+    ///
+    /// It expects:
+    ///     * the pattern the text has to follow
+    ///     * the placeholder shown while the field is empty
+    ///     * the text in the field
+    ///     * the function producing the message when the text changes
+    pub fn new<F>(mask: &Mask, placeholder: &str, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(Entry) -> Message,
+    {
+        let on_input: Rc<dyn Fn(Entry) -> Message + 'a> = Rc::new(on_input);
+        let value = mask.format(value);
+
+        // Edits handled by the text input itself, like cutting, are fitted too
+        let on_text = {
+            let mask = mask.clone();
+            let on_input = Rc::clone(&on_input);
+
+            move |text: String| on_input(Entry::new(&mask, &text))
+        };
+
+        MaskedInput {
+            mask: mask.clone(),
+            content: TextInput::new(placeholder, &value).on_input(on_text),
+            value,
+            on_input,
+        }
+    }
+
+    /// Sets the width of the [`MaskedInput`](MaskedInput).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.content = self.content.width(width);
+        self
+    }
+
+    /// Sets the padding of the [`MaskedInput`](MaskedInput).
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.content = self.content.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`MaskedInput`](MaskedInput).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.content = self.content.size(text_size);
+        self
+    }
+
+    /// Sets the font of the [`MaskedInput`](MaskedInput).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.content = self.content.font(font);
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the [`MaskedInput`](MaskedInput) to
+    /// focus it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.content = self.content.id(id);
+        self
+    }
+
+    /// Sets the message produced when Enter is pressed.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.content = self.content.on_submit(message);
+        self
+    }
+
+    /// Sets the style of the [`MaskedInput`](MaskedInput).
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.content = self.content.style(style);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for MaskedInput<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree {
+            tag: self.content.tag(),
+            state: self.content.state(),
+            children: self.content.children(),
+        }]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &[&self.content],
+            |state, content| content.diff(state),
+            |&content| Tree {
+                tag: content.tag(),
+                state: content.state(),
+                children: content.children(),
+            },
+        );
+    }
+
+    fn width(&self) -> Length {
+        Widget::width(&self.content)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let child = &mut tree.children[0];
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = modifiers;
+        }
+
+        let input = child.state.downcast_mut::<text_input::State>();
+
+        if input.is_focused() {
+            let (start, end) = match input.cursor().state(&Value::new(&self.value)) {
+                cursor::State::Index(index) => (index, index),
+                cursor::State::Selection { start, end } => (start.min(end), start.max(end)),
+            };
+
+            let edit = match event {
+                Event::Keyboard(keyboard::Event::CharacterReceived(character))
+                    if !state.modifiers.command() && !character.is_control() =>
+                {
+                    let mut typed = [0; 4];
+                    Some(self.mask.insert(
+                        &self.value,
+                        start,
+                        end,
+                        character.encode_utf8(&mut typed),
+                    ))
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => match key_code {
+                    keyboard::KeyCode::Backspace if start == end => {
+                        Some(self.mask.backspace(&self.value, start))
+                    }
+                    keyboard::KeyCode::Delete if start == end => {
+                        Some(self.mask.delete(&self.value, start))
+                    }
+                    keyboard::KeyCode::Backspace | keyboard::KeyCode::Delete => {
+                        Some(self.mask.insert(&self.value, start, end, ""))
+                    }
+                    keyboard::KeyCode::V if state.modifiers.command() => clipboard
+                        .read()
+                        .map(|pasted| self.mask.insert(&self.value, start, end, &pasted)),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some((text, caret)) = edit {
+                // Rejected characters leave the text as it is
+                if text != self.value {
+                    shell.publish((self.on_input)(Entry::new(&self.mask, &text)));
+                }
+
+                input.move_cursor_to(caret);
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.content.on_event(
+            child,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        self.content.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            None,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<MaskedInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    fn from(masked_input: MaskedInput<'a, Message, Renderer>) -> Self {
+        Element::new(masked_input)
+    }
+}
+
+/// The state of a [`MaskedInput`](MaskedInput).
+#[derive(Debug, Default)]
+struct State {
+    /// The keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mask;
+
+    #[test]
+    fn format_inserts_literals_and_rejects_characters() {
+        let mask = Mask::new("(###) ###-####");
+
+        assert_eq!(mask.format("555123"), "(555) 123");
+        assert_eq!(mask.format("(555) 1x23-4567890"), "(555) 123-4567");
+        assert_eq!(mask.format("abc"), "");
+        assert!(mask.is_complete("5551234567"));
+        assert!(!mask.is_complete("555123456"));
+
+        let plate = Mask::new("AA-99-AA");
+        assert_eq!(plate.format("ab12cd"), "ab-12-cd");
+        assert_eq!(plate.raw("ab-12-cd"), "ab12cd");
+        assert_eq!(plate.placeholder('_'), "__-__-__");
+    }
+
+    #[test]
+    fn cursor_skips_over_literals() {
+        let mask = Mask::new("(###) ###-####");
+
+        // Typing after the area code moves the cursor behind the literals
+        assert_eq!(mask.insert("(555", 4, 4, "1"), ("(555) 1".to_owned(), 7));
+        // A rejected character leaves the cursor where it was
+        assert_eq!(mask.insert("(555", 4, 4, "x"), ("(555".to_owned(), 4));
+        // Backspace removes the digit before the literals
+        assert_eq!(mask.backspace("(555) 1", 7), ("(555".to_owned(), 4));
+        assert_eq!(mask.backspace("(555) 1", 6), ("(551".to_owned(), 3));
+        assert_eq!(mask.delete("(555) 1", 4), ("(555".to_owned(), 4));
+    }
+
+    #[test]
+    fn closing_literals_follow_a_filled_pattern() {
+        let mask = Mask::new("##\\#");
+
+        assert_eq!(mask.format("12"), "12#");
+        assert_eq!(mask.format("1"), "1");
+    }
+}
@@ -56,6 +56,8 @@ mod flex;
 pub mod menu_bar;
 mod menu_inner;
 pub mod menu_tree;
+#[cfg(feature = "split_button")]
+pub mod split_button;
 
 pub use crate::style::menu_bar::{Appearance, StyleSheet};
 /// A `MenuBar` collects `MenuTree`s and handles
@@ -65,3 +67,7 @@ pub use menu_inner::{CloseCondition, ItemHeight, ItemWidth, PathHighlight};
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 pub type MenuTree<'a, Message, Backend, Theme> =
     menu_tree::MenuTree<'a, Message, iced_graphics::Renderer<Backend, Theme>>;
+#[cfg(feature = "split_button")]
+/// A button with a primary action and an arrow opening a menu of alternative actions
+pub type SplitButton<'a, Message, Backend, Theme> =
+    split_button::SplitButton<'a, Message, iced_graphics::Renderer<Backend, Theme>>;
@@ -60,35 +60,41 @@ impl Axis {
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
+/// The items are iterated several times, so they are passed as a cloneable
+/// iterator instead of a collected slice.
+///
 /// It returns a new layout [`Node`].
-pub fn resolve<'a, E, Message, Renderer>(
+pub fn resolve<'a, I, E, Message, Renderer>(
     axis: &Axis,
     renderer: &Renderer,
     limits: &Limits,
     padding: Padding,
     spacing: f32,
     align_items: Alignment,
-    items: &[E],
+    items: I,
 ) -> Node
 where
+    I: IntoIterator<Item = E> + Clone,
+    I::IntoIter: ExactSizeIterator,
     E: std::borrow::Borrow<Element<'a, Message, Renderer>>,
     Renderer: renderer::Renderer,
 {
+    let items_count = items.clone().into_iter().len();
     let limits = limits.pad(padding);
-    let total_spacing = spacing * items.len().saturating_sub(1) as f32;
+    let total_spacing = spacing * items_count.saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
 
     let mut fill_sum = 0;
     let mut cross = axis.cross(limits.min()).max(axis.cross(limits.fill()));
     let mut available = axis.main(limits.max()) - total_spacing;
 
-    let mut nodes: Vec<Node> = Vec::with_capacity(items.len());
-    nodes.resize(items.len(), Node::default());
+    let mut nodes: Vec<Node> = Vec::with_capacity(items_count);
+    nodes.resize(items_count, Node::default());
 
     if align_items == Alignment::Center {
         let mut fill_cross = axis.cross(limits.min());
 
-        for child in items.iter() {
+        for child in items.clone() {
             let child = child.borrow();
             let cross_fill_factor = match axis {
                 Axis::Horizontal => child.as_widget().height(),
@@ -111,7 +117,7 @@ where
         cross = fill_cross;
     }
 
-    for (i, child) in items.iter().enumerate() {
+    for (i, child) in items.clone().into_iter().enumerate() {
         let child = child.borrow();
         let fill_factor = match axis {
             Axis::Horizontal => child.as_widget().width(),
@@ -154,7 +160,7 @@ where
 
     let remaining = available.max(0.0);
 
-    for (i, child) in items.iter().enumerate() {
+    for (i, child) in items.into_iter().enumerate() {
         let child = child.borrow();
         let fill_factor = match axis {
             Axis::Horizontal => child.as_widget().width(),
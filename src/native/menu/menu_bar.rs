@@ -4,14 +4,28 @@ use super::menu_inner::{
     CloseCondition, Direction, ItemHeight, ItemWidth, Menu, MenuState, PathHighlight,
 };
 use super::menu_tree::MenuTree;
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{Node, Role};
+use crate::native::focus::Focus;
 use crate::style::menu_bar::StyleSheet;
-use iced_native::widget::{tree, Tree};
+use crate::style::style_state::{appearance, StyleState};
+use iced_native::widget::{operation::Focusable, tree, Id, Operation, Tree};
 use iced_native::{
-    event, layout, mouse, overlay, renderer, touch, Alignment, Clipboard, Color, Element, Length,
-    Padding, Point, Rectangle, Shell, Widget,
+    event, keyboard, layout, mouse, overlay, renderer, touch, window, Alignment, Clipboard, Color,
+    Element, Length, Padding, Point, Rectangle, Shell, Widget,
 };
 
 pub(super) struct MenuBarState {
+    /// The keyboard focus of the menu bar
+    pub(super) focus: Focus,
+    /// The root moved to with the arrow keys while the menus are closed
+    pub(super) focused_root: usize,
+    /// Whether the menus are navigated with the keyboard, which highlights
+    /// the active item until the cursor moves
+    pub(super) keyboard: bool,
+    /// Whether the overlay captured the current key press, which the menu bar
+    /// ignores then
+    pub(super) key_captured: bool,
     pub(super) pressed: bool,
     pub(super) view_cursor: Point,
     pub(super) open: bool,
@@ -30,6 +44,7 @@ impl MenuBarState {
 
     pub(super) fn reset(&mut self) {
         self.open = false;
+        self.keyboard = false;
         self.active_root = None;
         self.menu_states.clear();
     }
@@ -37,6 +52,10 @@ impl MenuBarState {
 impl Default for MenuBarState {
     fn default() -> Self {
         Self {
+            focus: Focus::new(),
+            focused_root: 0,
+            keyboard: false,
+            key_captured: false,
             pressed: false,
             view_cursor: Point::new(-0.5, -0.5),
             open: false,
@@ -66,6 +85,7 @@ where
     item_height: ItemHeight,
     path_highlight: Option<PathHighlight>,
     menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
+    id: Option<Id>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 impl<'a, Message, Renderer> MenuBar<'a, Message, Renderer>
@@ -94,6 +114,7 @@ where
             item_height: ItemHeight::Uniform(30),
             path_highlight: Some(PathHighlight::MenuActive),
             menu_roots,
+            id: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
     }
@@ -165,12 +186,40 @@ where
         self
     }
 
+    /// Sets the [`Id`] of the [`MenuBar`] to focus it with
+    /// [`focus`](crate::native::focus::focus)
+    ///
+    /// A focused [`MenuBar`] moves between its roots with the arrow keys and
+    /// opens the menu of a root with Enter, Space or the down arrow key
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the menu bar and its menus
     #[must_use]
     pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
         self.style = style.into();
         self
     }
+
+    /// Describes the [`MenuBar`](MenuBar) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, state: &Tree) -> Node {
+        let state = state.state.downcast_ref::<MenuBarState>();
+        let path: Vec<usize> = state.get_trimmed_indices().collect();
+
+        self.menu_roots
+            .iter()
+            .enumerate()
+            .fold(Node::new(Role::MenuBar), |node, (i, root)| {
+                let expanded = state.open && state.active_root == Some(i);
+                node.push(menu_item_node(root, expanded, &path))
+            })
+    }
 }
 impl<'a, Message, Renderer> Widget<Message, Renderer> for MenuBar<'a, Message, Renderer>
 where
@@ -186,6 +235,17 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        state.focused_root = state
+            .focused_root
+            .min(self.menu_roots.len().saturating_sub(1));
+
+        // The items of the menus are laid out again for the new view
+        state
+            .menu_states
+            .iter_mut()
+            .for_each(MenuState::clear_layout);
+
         if tree.children.len() > self.menu_roots.len() {
             tree.children.truncate(self.menu_roots.len());
         }
@@ -251,11 +311,7 @@ where
         use super::flex;
 
         let limits = limits.width(self.width).height(self.height);
-        let children = self
-            .menu_roots
-            .iter()
-            .map(|root| &root.item)
-            .collect::<Vec<_>>();
+        let children = self.menu_roots.iter().map(|root| &root.item);
         flex::resolve(
             &flex::Axis::Horizontal,
             renderer,
@@ -263,7 +319,7 @@ where
             self.padding,
             self.spacing,
             Alignment::Center,
-            &children,
+            children,
         )
     }
 
@@ -277,9 +333,12 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        use event::Event::{Mouse, Touch};
-        use mouse::{Button::Left, Event::ButtonReleased};
-        use touch::Event::{FingerLifted, FingerLost};
+        use event::Event::{Keyboard, Mouse, Touch};
+        use mouse::{
+            Button::Left,
+            Event::{ButtonPressed, ButtonReleased},
+        };
+        use touch::Event::{FingerLifted, FingerLost, FingerPressed};
 
         let root_status = process_root_events(
             &mut self.menu_roots,
@@ -293,14 +352,43 @@ where
         );
 
         let state = tree.state.downcast_mut::<MenuBarState>();
+        let key_captured = std::mem::take(&mut state.key_captured);
 
         match event {
+            Mouse(ButtonPressed(Left)) | Touch(FingerPressed { .. }) => {
+                state.focus.press(layout.bounds().contains(view_cursor));
+                state.keyboard = false;
+
+                if let Some(pressed) = layout
+                    .children()
+                    .position(|lo| lo.bounds().contains(view_cursor))
+                {
+                    state.focused_root = pressed;
+                }
+            }
             Mouse(ButtonReleased(Left)) | Touch(FingerLifted { .. } | FingerLost { .. }) => {
                 if state.menu_states.is_empty() && layout.bounds().contains(view_cursor) {
                     state.view_cursor = view_cursor;
                     state.open = true;
                 }
             }
+            Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if !key_captured
+                    && state.focus.is_focused()
+                    && !state.open
+                    && !self.menu_roots.is_empty() =>
+            {
+                return process_key_events(
+                    &mut self.menu_roots,
+                    key_code,
+                    tree,
+                    layout,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+                .merge(root_status);
+            }
             _ => (),
         }
         root_status
@@ -324,24 +412,37 @@ where
             view_cursor
         };
 
-        // draw path highlight
-        if self.path_highlight.is_some() {
-            let styling = theme.appearance(&self.style);
-            if let Some(active) = state.active_root {
-                let active_bounds = layout
-                    .children()
-                    .nth(active)
-                    .expect("Active child not found in menu?")
-                    .bounds();
-                let path_quad = renderer::Quad {
-                    bounds: active_bounds,
-                    border_radius: styling.border_radius.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                };
-                let path_color = styling.path;
-                renderer.fill_quad(path_quad, path_color);
-            }
+        let is_focused = state.focus.is_focused();
+
+        // draw path highlight, the root focused by the keyboard is highlighted
+        // while its menu is closed
+        let highlighted = match state.active_root {
+            Some(active) => self.path_highlight.map(|_| active),
+            None => is_focused.then_some(state.focused_root),
+        };
+        if let Some(active_bounds) = highlighted
+            .and_then(|active| layout.children().nth(active))
+            .map(|lo| lo.bounds())
+        {
+            let styling = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(
+                    layout.bounds().contains(position),
+                    state.open,
+                    is_focused,
+                    false
+                ),
+                &self.style,
+            );
+            let path_quad = renderer::Quad {
+                bounds: active_bounds,
+                border_radius: styling.border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            };
+            let path_color = styling.path;
+            renderer.fill_quad(path_quad, path_color);
         }
 
         self.menu_roots
@@ -361,6 +462,18 @@ where
             });
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: layout::Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<MenuBarState>();
+
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+
     fn overlay<'b>(
         &'b mut self,
         tree: &'b mut Tree,
@@ -389,6 +502,38 @@ where
         )
     }
 }
+
+/// Describes a menu item along with its menu while it is expanded
+#[cfg(feature = "accessibility")]
+fn menu_item_node<Message, Renderer>(
+    menu_tree: &MenuTree<'_, Message, Renderer>,
+    expanded: bool,
+    path: &[usize],
+) -> Node {
+    if menu_tree.children.is_empty() {
+        return Node::new(Role::MenuItem);
+    }
+
+    let node = Node::new(Role::MenuItem).expanded(expanded);
+    if !expanded {
+        return node;
+    }
+
+    let menu =
+        menu_tree
+            .children
+            .iter()
+            .enumerate()
+            .fold(Node::new(Role::Menu), |menu, (i, child)| {
+                menu.push(menu_item_node(
+                    child,
+                    path.first() == Some(&i),
+                    path.get(1..).unwrap_or_default(),
+                ))
+            });
+    node.push(menu)
+}
+
 impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Message: 'a,
@@ -432,3 +577,64 @@ where
         })
         .fold(event::Status::Ignored, event::Status::merge)
 }
+
+/// Moves between the roots with the arrow keys while the menus are closed
+///
+/// Enter, Space and the down arrow key open the menu of the focused root,
+/// Enter and Space activate a root without a menu
+fn process_key_events<Message, Renderer>(
+    menu_roots: &mut [MenuTree<'_, Message, Renderer>],
+    key_code: keyboard::KeyCode,
+    tree: &mut Tree,
+    layout: layout::Layout<'_>,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status
+where
+    Renderer: renderer::Renderer,
+{
+    use keyboard::KeyCode;
+
+    let state = tree.state.downcast_mut::<MenuBarState>();
+    let count = menu_roots.len();
+    let focused = state.focused_root;
+    let root = &mut menu_roots[focused];
+
+    match key_code {
+        KeyCode::Left => state.focused_root = (focused + count - 1) % count,
+        KeyCode::Right => state.focused_root = (focused + 1) % count,
+        KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space | KeyCode::Down
+            if !root.children.is_empty() =>
+        {
+            // The menus open on the next event the overlay receives
+            state.open = true;
+            state.keyboard = true;
+            state.active_root = Some(focused);
+            state.menu_states.clear();
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+        KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => {
+            let Some(root_layout) = layout.children().nth(focused) else {
+                return event::Status::Ignored;
+            };
+            let center = root_layout.bounds().center();
+            let tree = &mut tree.children[focused].children[root.index];
+
+            for event in [mouse::Event::ButtonPressed, mouse::Event::ButtonReleased] {
+                let _ = root.item.as_widget_mut().on_event(
+                    tree,
+                    event::Event::Mouse(event(mouse::Button::Left)),
+                    root_layout,
+                    center,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+            }
+        }
+        _ => return event::Status::Ignored,
+    }
+
+    event::Status::Captured
+}
@@ -2,11 +2,13 @@
 
 use super::menu_bar::MenuBarState;
 use super::menu_tree::MenuTree;
+use crate::core::overlay::Dismiss;
 use crate::style::menu_bar::StyleSheet;
-use iced_native::widget::Tree;
+use crate::style::style_state::{appearance, StyleState};
+use iced_native::widget::{operation::Focusable, Tree};
 use iced_native::{
-    event, layout, mouse, overlay, renderer, touch, Clipboard, Color, Padding, Point, Rectangle,
-    Shell, Size, Vector,
+    event, keyboard, layout, mouse, overlay, renderer, touch, Clipboard, Color, Padding, Point,
+    Rectangle, Shell, Size, Vector,
 };
 
 /// The condition of when to close a menu
@@ -246,12 +248,80 @@ impl MenuBounds {
     }
 }
 
+/// The layout of the visible items of a menu
+struct ItemsLayout {
+    /// The scroll offset, the viewport size and the overlay offset it was laid out for
+    key: (f32, Size, Vector),
+    start_index: usize,
+    end_index: usize,
+    node: layout::Node,
+}
+
 pub(super) struct MenuState {
     pub(super) index: Option<usize>,
     scroll_offset: f32,
     menu_bounds: MenuBounds,
+    /// The layout of the visible items, kept until the menu is scrolled or moved
+    items_layout: Option<ItemsLayout>,
 }
 impl MenuState {
+    /// Forgets the layout of the items, whose content may have changed
+    pub(super) fn clear_layout(&mut self) {
+        self.items_layout = None;
+    }
+
+    /// Lays out the visible items
+    fn layout_items<Message, Renderer>(
+        &self,
+        viewport_size: Size,
+        overlay_offset: Vector,
+        item_height: ItemHeight,
+        renderer: &Renderer,
+        menu_tree: &MenuTree<'_, Message, Renderer>,
+    ) -> ItemsLayout
+    where
+        Renderer: renderer::Renderer,
+    {
+        let slice = self.slice(viewport_size, overlay_offset, item_height, menu_tree);
+
+        ItemsLayout {
+            key: (self.scroll_offset, viewport_size, overlay_offset),
+            start_index: slice.start_index,
+            end_index: slice.end_index,
+            node: self.layout(overlay_offset, slice, item_height, renderer, menu_tree),
+        }
+    }
+
+    /// Returns the kept layout of the items if the menu was neither scrolled
+    /// nor moved since it was laid out
+    fn kept_layout(&self, viewport_size: Size, overlay_offset: Vector) -> Option<&ItemsLayout> {
+        self.items_layout
+            .as_ref()
+            .filter(|items| items.key == (self.scroll_offset, viewport_size, overlay_offset))
+    }
+
+    /// Lays out the visible items again if the menu was scrolled or moved
+    fn update_layout<Message, Renderer>(
+        &mut self,
+        viewport_size: Size,
+        overlay_offset: Vector,
+        item_height: ItemHeight,
+        renderer: &Renderer,
+        menu_tree: &MenuTree<'_, Message, Renderer>,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        if self.kept_layout(viewport_size, overlay_offset).is_none() {
+            self.items_layout = Some(self.layout_items(
+                viewport_size,
+                overlay_offset,
+                item_height,
+                renderer,
+                menu_tree,
+            ));
+        }
+    }
+
     fn layout<Message, Renderer>(
         &self,
         overlay_offset: Vector,
@@ -334,6 +404,58 @@ impl MenuState {
         node
     }
 
+    /// Creates the menu of the item at `index`, opened beside the item
+    #[allow(clippy::too_many_arguments)]
+    fn item_menu<Message, Renderer>(
+        &self,
+        index: usize,
+        item: &MenuTree<'_, Message, Renderer>,
+        item_width: ItemWidth,
+        item_height: ItemHeight,
+        viewport_size: Size,
+        overlay_offset: Vector,
+        directions: (Direction, Direction),
+        bounds_expand: u16,
+    ) -> Self
+    where
+        Renderer: renderer::Renderer,
+    {
+        let item_position = Point::new(
+            0.0,
+            self.menu_bounds.child_positions[index] + self.scroll_offset,
+        );
+        let item_size = get_item_size(item, self.menu_bounds.children_bounds.width, item_height);
+
+        // overlay space item bounds
+        let item_bounds = Rectangle::new(item_position, item_size)
+            + (self.menu_bounds.children_bounds.position() - Point::ORIGIN);
+
+        let aod = Aod {
+            horizontal: true,
+            vertical: true,
+            horizontal_overlap: false,
+            vertical_overlap: true,
+            horizontal_direction: directions.0,
+            vertical_direction: directions.1,
+        };
+
+        Self {
+            index: None,
+            scroll_offset: 0.0,
+            menu_bounds: MenuBounds::new(
+                item,
+                item_width,
+                item_height,
+                viewport_size,
+                overlay_offset,
+                &aod,
+                bounds_expand,
+                item_bounds,
+            ),
+            items_layout: None,
+        }
+    }
+
     fn slice<Message, Renderer>(
         &self,
         viewport_size: Size,
@@ -436,7 +558,7 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         use event::{
-            Event::{Mouse, Touch},
+            Event::{Keyboard, Mouse, Touch},
             Status::{Captured, Ignored},
         };
         use mouse::{
@@ -454,6 +576,12 @@ where
         let overlay_offset = Point::ORIGIN - viewport.position();
         let overlay_cursor = view_cursor - overlay_offset;
 
+        // clicks outside are handled by the close condition
+        if Dismiss::from_event(&event, view_cursor, viewport, &[]) == Some(Dismiss::Escape) {
+            self.tree.state.downcast_mut::<MenuBarState>().reset();
+            return Captured;
+        }
+
         let menu_status = process_menu_events(
             self.tree,
             self.menu_roots,
@@ -474,7 +602,7 @@ where
             self.bar_bounds,
         );
 
-        match event {
+        let status = match event {
             Mouse(WheelScrolled { delta }) => {
                 process_scroll_events(self, delta, overlay_cursor, viewport_size, overlay_offset)
                     .merge(menu_status)
@@ -505,25 +633,16 @@ where
                 state.pressed = false;
 
                 // process close condition
-                if state.view_cursor.distance(view_cursor) < 2.0 {
-                    let is_inside = state
-                        .menu_states
-                        .iter()
-                        .any(|ms| ms.menu_bounds.check_bounds.contains(overlay_cursor));
-
-                    if self.close_condition.click_inside && is_inside {
-                        state.reset();
-                        return Captured;
-                    }
-
-                    if self.close_condition.click_outside && !is_inside {
-                        state.reset();
-                        return Captured;
-                    }
-                }
+                let is_inside = state
+                    .menu_states
+                    .iter()
+                    .any(|ms| ms.menu_bounds.check_bounds.contains(overlay_cursor));
+                let close = state.view_cursor.distance(view_cursor) < 2.0
+                    && ((self.close_condition.click_inside && is_inside)
+                        || (self.close_condition.click_outside && !is_inside));
 
                 // close all menus when clicking inside the menu bar
-                if self.bar_bounds.contains(overlay_cursor) {
+                if close || self.bar_bounds.contains(overlay_cursor) {
                     state.reset();
                     Captured
                 } else {
@@ -531,8 +650,35 @@ where
                 }
             }
 
+            Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if menu_status == Ignored
+                    && self
+                        .tree
+                        .state
+                        .downcast_ref::<MenuBarState>()
+                        .focus
+                        .is_focused() =>
+            {
+                let status = process_key_events(
+                    self,
+                    key_code,
+                    viewport_size,
+                    overlay_offset,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+
+                // the menu bar receives the key press after the overlay
+                self.tree.state.downcast_mut::<MenuBarState>().key_captured = status == Captured;
+                status
+            }
+
             _ => menu_status,
-        }
+        };
+
+        update_layouts(self, viewport_size, overlay_offset, renderer);
+        status
     }
 
     #[allow(unused_results)]
@@ -552,23 +698,34 @@ where
         let overlay_offset = Point::ORIGIN - viewport.position();
         let render_bounds = Rectangle::new(Point::ORIGIN, viewport.size());
 
-        let styling = theme.appearance(self.style);
+        // The menus are hovered while the cursor is over one of their items
+        // and focused while they are navigated with the keyboard
+        let is_hovered = state.menu_states.iter().any(|ms| ms.index.is_some());
+        let styling = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(is_hovered, false, state.keyboard, false),
+            self.style,
+        );
 
         let tree = &self.tree.children[active_root].children;
         let root = &self.menu_roots[active_root];
 
-        let indices = state.get_trimmed_indices().collect::<Vec<_>>();
+        let active_depth = state.get_trimmed_indices().count();
 
         state
             .menu_states
             .iter()
             .enumerate()
             .fold(root, |menu_root, (i, ms)| {
-                let draw_path = self.path_highlight.as_ref().map_or(false, |ph| match ph {
-                    PathHighlight::Full => true,
-                    PathHighlight::OmitActive => !indices.is_empty() && i < indices.len() - 1,
-                    PathHighlight::MenuActive => i < state.menu_states.len() - 1,
-                });
+                // the path is always drawn for the keyboard, which has no
+                // other way to show the active item
+                let draw_path = state.keyboard
+                    || self.path_highlight.as_ref().map_or(false, |ph| match ph {
+                        PathHighlight::Full => true,
+                        PathHighlight::OmitActive => i + 1 < active_depth,
+                        PathHighlight::MenuActive => i < state.menu_states.len() - 1,
+                    });
 
                 // react only to the last menu
                 let view_cursor = if i == state.menu_states.len() - 1 {
@@ -578,16 +735,24 @@ where
                 };
 
                 let draw_menu = |r: &mut Renderer| {
-                    // calc slice
-                    let slice =
-                        ms.slice(viewport_size, overlay_offset, self.item_height, menu_root);
-                    let start_index = slice.start_index;
-                    let end_index = slice.end_index;
-
-                    // calc layout
-                    let children_node =
-                        ms.layout(overlay_offset, slice, self.item_height, r, menu_root);
-                    let children_layout = layout::Layout::new(&children_node);
+                    // reuse the layout kept by the events unless it is outdated
+                    let laid_out;
+                    let items = match ms.kept_layout(viewport_size, overlay_offset) {
+                        Some(items) => items,
+                        None => {
+                            laid_out = ms.layout_items(
+                                viewport_size,
+                                overlay_offset,
+                                self.item_height,
+                                r,
+                                menu_root,
+                            );
+                            &laid_out
+                        }
+                    };
+                    let start_index = items.start_index;
+                    let end_index = items.end_index;
+                    let children_layout = layout::Layout::new(&items.node);
                     let children_bounds = children_layout.bounds();
 
                     // draw menu background
@@ -663,60 +828,84 @@ fn init_root_menu<Message, Renderer>(
     Renderer::Theme: StyleSheet,
 {
     let state = menu.tree.state.downcast_mut::<MenuBarState>();
-    if !(state.menu_states.is_empty() && bar_bounds.contains(overlay_cursor)) {
+    if !state.menu_states.is_empty() {
         return;
     }
 
-    for (i, (&root_bounds, mt)) in menu
+    // a root opened with the keyboard starts at its first item
+    if let (true, Some(root)) = (state.keyboard, state.active_root) {
+        open_root_menu(menu, root, Some(0), viewport_size, overlay_offset);
+        return;
+    }
+
+    if !bar_bounds.contains(overlay_cursor) {
+        return;
+    }
+
+    let root = menu
         .root_bounds_list
         .iter()
         .zip(menu.menu_roots.iter())
-        .enumerate()
-    {
-        if mt.children.is_empty() {
-            continue;
-        }
+        .position(|(root_bounds, mt)| {
+            !mt.children.is_empty() && root_bounds.contains(overlay_cursor)
+        });
 
-        if root_bounds.contains(overlay_cursor) {
-            let view_center = viewport_size.width * 0.5;
-            let rb_center = root_bounds.center_x();
+    if let Some(root) = root {
+        open_root_menu(menu, root, None, viewport_size, overlay_offset);
+    }
+}
 
-            state.horizontal_direction = if rb_center > view_center {
-                Direction::Negative
-            } else {
-                Direction::Positive
-            };
+/// Opens the menu of the root at `root` with the item at `index` active
+fn open_root_menu<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    root: usize,
+    index: Option<usize>,
+    viewport_size: Size,
+    overlay_offset: Vector,
+) where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let root_bounds = menu.root_bounds_list[root];
 
-            let aod = Aod {
-                horizontal: true,
-                vertical: true,
-                horizontal_overlap: true,
-                vertical_overlap: false,
-                horizontal_direction: state.horizontal_direction,
-                vertical_direction: state.vertical_direction,
-            };
+    let view_center = viewport_size.width * 0.5;
+    let rb_center = root_bounds.center_x();
 
-            let menu_bounds = MenuBounds::new(
-                mt,
-                menu.item_width,
-                menu.item_height,
-                viewport_size,
-                overlay_offset,
-                &aod,
-                menu.bounds_expand,
-                root_bounds,
-            );
+    state.horizontal_direction = if rb_center > view_center {
+        Direction::Negative
+    } else {
+        Direction::Positive
+    };
 
-            state.active_root = Some(i);
-            state.menu_states.push(MenuState {
-                index: None,
-                scroll_offset: 0.0,
-                menu_bounds,
-            });
+    let aod = Aod {
+        horizontal: true,
+        vertical: true,
+        horizontal_overlap: true,
+        vertical_overlap: false,
+        horizontal_direction: state.horizontal_direction,
+        vertical_direction: state.vertical_direction,
+    };
 
-            break;
-        }
-    }
+    let menu_bounds = MenuBounds::new(
+        &menu.menu_roots[root],
+        menu.item_width,
+        menu.item_height,
+        viewport_size,
+        overlay_offset,
+        &aod,
+        menu.bounds_expand,
+        root_bounds,
+    );
+
+    state.active_root = Some(root);
+    state.focused_root = root;
+    state.menu_states.push(MenuState {
+        index,
+        scroll_offset: 0.0,
+        menu_bounds,
+        items_layout: None,
+    });
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -815,6 +1004,7 @@ where
     /* When overlay is running, cursor_position in any widget method will go negative
     but I still want Widget::draw() to react to cursor movement */
     state.view_cursor = view_cursor;
+    state.keyboard = false;
 
     // * remove invalid menus
     let mut prev_bounds = std::iter::once(menu.bar_bounds)
@@ -925,48 +1115,184 @@ where
 
     // * add new menu if the new item is a menu
     if !item.children.is_empty() {
-        let item_position = Point::new(
-            0.0,
-            last_menu_bounds.child_positions[new_index] + last_menu_state.scroll_offset,
-        );
-        let item_size = get_item_size(
+        let item_menu = last_menu_state.item_menu(
+            new_index,
             item,
-            last_menu_bounds.children_bounds.width,
+            menu.item_width,
             menu.item_height,
+            viewport_size,
+            overlay_offset,
+            (state.horizontal_direction, state.vertical_direction),
+            menu.bounds_expand,
         );
+        state.menu_states.push(item_menu);
+    }
 
-        // overlay space item bounds
-        let item_bounds = Rectangle::new(item_position, item_size)
-            + (last_menu_bounds.children_bounds.position() - Point::ORIGIN);
+    Captured
+}
 
-        let aod = Aod {
-            horizontal: true,
-            vertical: true,
-            horizontal_overlap: false,
-            vertical_overlap: true,
-            horizontal_direction: state.horizontal_direction,
-            vertical_direction: state.vertical_direction,
-        };
+/// Navigates the open menus of a focused menu bar with the keyboard
+///
+/// The up and down arrow keys move through the items of the last menu, the
+/// right arrow key opens the menu of the active item and the left arrow key
+/// closes the last menu. In the menu of a root they move to the menu of the
+/// previous or next root instead. Enter and Space open the menu of the active
+/// item or click it.
+#[allow(clippy::too_many_arguments)]
+fn process_key_events<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    key_code: keyboard::KeyCode,
+    viewport_size: Size,
+    overlay_offset: Vector,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    use event::Status::{Captured, Ignored};
+    use keyboard::KeyCode;
 
-        state.menu_states.push(MenuState {
-            index: None,
-            scroll_offset: 0.0,
-            menu_bounds: MenuBounds::new(
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let Some(active_root) = state.active_root else { return Ignored; };
+    let Some(last_menu_state) = state.menu_states.last() else { return Ignored; };
+
+    // only the last menu can have a None active index
+    let last_menu = state.menu_states[..state.menu_states.len() - 1]
+        .iter()
+        .fold(&menu.menu_roots[active_root], |mt, ms| {
+            &mt.children[ms.index.expect("missing active child index in menu")]
+        });
+    let count = last_menu.children.len();
+    let active_item = last_menu_state
+        .index
+        .map(|index| (index, &last_menu.children[index]));
+    let is_enter = matches!(
+        key_code,
+        KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space
+    );
+
+    match (key_code, active_item) {
+        (KeyCode::Up | KeyCode::Down, _) if count > 0 => {
+            let index = match (key_code, last_menu_state.index) {
+                (KeyCode::Up, Some(index)) => (index + count - 1) % count,
+                (KeyCode::Up, None) => count - 1,
+                (_, Some(index)) => (index + 1) % count,
+                (_, None) => 0,
+            };
+
+            if let Some(last_menu_state) = state.menu_states.last_mut() {
+                last_menu_state.index = Some(index);
+            }
+        }
+        (_, Some((index, item)))
+            if (key_code == KeyCode::Right || is_enter) && !item.children.is_empty() =>
+        {
+            let mut item_menu = last_menu_state.item_menu(
+                index,
                 item,
                 menu.item_width,
                 menu.item_height,
                 viewport_size,
                 overlay_offset,
-                &aod,
+                (state.horizontal_direction, state.vertical_direction),
                 menu.bounds_expand,
-                item_bounds,
-            ),
-        });
+            );
+            item_menu.index = Some(0);
+            state.menu_states.push(item_menu);
+        }
+        (KeyCode::Left, _) if state.menu_states.len() > 1 => {
+            let _ = state.menu_states.pop();
+        }
+        (KeyCode::Left | KeyCode::Right, _) => {
+            let roots = menu.menu_roots.len();
+            let next_root = (1..roots)
+                .map(|step| {
+                    if key_code == KeyCode::Left {
+                        (active_root + roots - step) % roots
+                    } else {
+                        (active_root + step) % roots
+                    }
+                })
+                .find(|&root| !menu.menu_roots[root].children.is_empty());
+
+            if let Some(root) = next_root {
+                state.menu_states.clear();
+                open_root_menu(menu, root, Some(0), viewport_size, overlay_offset);
+            }
+        }
+        (_, Some((index, item))) if is_enter => {
+            let item_node = last_menu_state.layout_single(
+                overlay_offset,
+                index,
+                menu.item_height,
+                renderer,
+                item,
+            );
+            let center = item_node.bounds().center();
+
+            for event in [mouse::Event::ButtonPressed, mouse::Event::ButtonReleased] {
+                let _ = process_menu_events(
+                    menu.tree,
+                    menu.menu_roots,
+                    menu.item_height,
+                    event::Event::Mouse(event(mouse::Button::Left)),
+                    center,
+                    renderer,
+                    clipboard,
+                    shell,
+                    overlay_offset,
+                );
+            }
+
+            if menu.close_condition.click_inside {
+                menu.tree.state.downcast_mut::<MenuBarState>().reset();
+            }
+        }
+        _ => return Ignored,
     }
 
+    // the active item is highlighted while the menus stay open
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    state.keyboard = state.open;
+
     Captured
 }
 
+/// Keeps the layouts of the items of the open menus for the draws, laying
+/// them out again only if a menu was opened, scrolled or moved
+fn update_layouts<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    viewport_size: Size,
+    overlay_offset: Vector,
+    renderer: &Renderer,
+) where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let Some(active_root) = state.active_root else { return; };
+
+    let _ = state
+        .menu_states
+        .iter_mut()
+        .fold(&menu.menu_roots[active_root], |menu_root, ms| {
+            ms.update_layout(
+                viewport_size,
+                overlay_offset,
+                menu.item_height,
+                renderer,
+                menu_root,
+            );
+
+            // only the last menu can have a None active index
+            ms.index
+                .map_or(menu_root, |active| &menu_root.children[active])
+        });
+}
+
 fn process_scroll_events<Message, Renderer>(
     menu: &mut Menu<'_, '_, Message, Renderer>,
     delta: mouse::ScrollDelta,
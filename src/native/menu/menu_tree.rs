@@ -1,6 +1,14 @@
 //! A tree structure for constructing a hierarchical menu
 
-use iced_native::{renderer, Element};
+use iced_native::{renderer, widget::Row, Alignment, Element};
+
+use crate::core::{
+    icon_source::{IconSource, Slot},
+    renderer::IconRenderer,
+};
+
+/// The spacing between the icon and the item.
+const ICON_SPACING: f32 = 8.0;
 
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 /// a menu itself can also be an item of another menu.
@@ -74,6 +82,29 @@ where
         self
     }
 
+    /// Sets the icon shown in front of the item of the menu tree, either an
+    /// [`Icon`](crate::graphics::icons::Icon) or an
+    /// [`Svg`](iced_native::svg::Handle) in the text size of the renderer.
+    ///
+    /// An [`Svg`](iced_native::svg::Handle) icon is only drawn with the crate
+    /// feature `svg` activated.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<IconSource>) -> Self
+    where
+        Message: 'a,
+        Renderer: IconRenderer + 'a,
+    {
+        let item = std::mem::replace(&mut self.item, Row::new().into());
+
+        self.item = Row::new()
+            .spacing(ICON_SPACING)
+            .align_items(Alignment::Center)
+            .push(Slot::new(icon.into(), None))
+            .push(item)
+            .into();
+        self
+    }
+
     /* Keep `set_index()` and `flattern()` recurse in the same order */
 
     /// Set the index of each item
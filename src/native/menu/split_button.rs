@@ -0,0 +1,598 @@
+//! A button with a primary action joined with an arrow opening a menu of
+//! alternative actions.
+//!
+//! *This API requires the following crate features to be activated: `split_button`*
+
+use super::menu_bar::MenuBarState;
+use super::menu_inner::{CloseCondition, ItemHeight, ItemWidth, Menu, PathHighlight};
+use super::menu_tree::MenuTree;
+use crate::core::renderer::GlyphRenderer;
+use crate::graphics::icons::Icon;
+use crate::style::menu_bar::StyleSheet;
+use iced_native::widget::{tree, Operation, Space, Tree};
+use iced_native::{
+    event, layout, mouse, overlay, renderer, touch, Clipboard, Color, Element, Event, Length,
+    Padding, Point, Rectangle, Shell, Size, Widget,
+};
+use iced_style::button;
+
+/// The default padding around the content of the primary button.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default width of the arrow opening the menu.
+const DEFAULT_ARROW_WIDTH: f32 = 24.0;
+
+/// A part of a [`SplitButton`](SplitButton).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Segment {
+    /// The button triggering the primary action.
+    Primary,
+    /// The arrow opening the menu.
+    Arrow,
+}
+
+/// The state of a [`SplitButton`](SplitButton).
+#[derive(Debug, Default)]
+struct State {
+    /// The segment being pressed.
+    pressed: Option<Segment>,
+}
+
+/// A button with a primary action joined with an arrow opening a menu of
+/// alternative actions.
+///
+/// The menu is a [`MenuTree`](MenuTree) like the menus of a
+/// [`MenuBar`](super::menu_bar::MenuBar), with the same style, so its items
+/// can open submenus, too. The segments are styled like a button of the theme.
+///
+/// # Example
+/// ```
+/// use iced::widget::{button, text};
+/// use iced_aw::menu::{split_button::SplitButton, MenuTree};
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Save,
+///     SaveAs,
+///     SaveAll,
+/// }
+///
+/// let split_button: SplitButton<'_, Message, iced::Renderer> = SplitButton::new(
+///     text("Save"),
+///     vec![
+///         MenuTree::new(button("Save as...").on_press(Message::SaveAs)),
+///         MenuTree::new(button("Save all").on_press(Message::SaveAll)),
+///     ],
+/// )
+/// .on_press(Message::Save);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SplitButton<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    /// The content of the primary button.
+    content: Element<'a, Message, Renderer>,
+    /// The message produced by the primary button.
+    on_press: Option<Message>,
+    /// The menu of alternative actions as the only root of a menu bar.
+    menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
+    /// The width of the [`SplitButton`](SplitButton).
+    width: Length,
+    /// The height of the [`SplitButton`](SplitButton).
+    height: Length,
+    /// The padding around the content of the primary button.
+    padding: Padding,
+    /// The width of the arrow opening the menu.
+    arrow_width: f32,
+    /// When to close the menu.
+    close_condition: CloseCondition,
+    /// The width of the items of the menu.
+    item_width: ItemWidth,
+    /// The height of the items of the menu.
+    item_height: ItemHeight,
+    /// The expand value of the bounds of the menu closing it when left.
+    bounds_expand: u16,
+    /// The style of the menu.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The style of the segments.
+    button_style: <Renderer::Theme as button::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> SplitButton<'a, Message, Renderer>
+where
+    Renderer: 'a + renderer::Renderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    /// Creates a new [`SplitButton`](SplitButton) with the content of the
+    /// primary button and the items of the menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        items: Vec<MenuTree<'a, Message, Renderer>>,
+    ) -> Self {
+        let mut root = MenuTree::with_children(Space::new(Length::Shrink, Length::Shrink), items);
+        root.set_index();
+
+        Self {
+            content: content.into(),
+            on_press: None,
+            menu_roots: vec![root],
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: DEFAULT_PADDING.into(),
+            arrow_width: DEFAULT_ARROW_WIDTH,
+            close_condition: CloseCondition {
+                leave: false,
+                click_outside: true,
+                click_inside: true,
+            },
+            item_width: ItemWidth::Uniform(150),
+            item_height: ItemHeight::Uniform(30),
+            bounds_expand: 15,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            button_style: <Renderer::Theme as button::StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced by the primary button.
+    ///
+    /// The primary button is disabled without one.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`SplitButton`](SplitButton).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SplitButton`](SplitButton).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] around the content of the primary button.
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the arrow opening the menu.
+    #[must_use]
+    pub fn arrow_width(mut self, arrow_width: f32) -> Self {
+        self.arrow_width = arrow_width;
+        self
+    }
+
+    /// [`CloseCondition`]
+    #[must_use]
+    pub fn close_condition(mut self, close_condition: CloseCondition) -> Self {
+        self.close_condition = close_condition;
+        self
+    }
+
+    /// [`ItemWidth`]
+    #[must_use]
+    pub fn item_width(mut self, item_width: ItemWidth) -> Self {
+        self.item_width = item_width;
+        self
+    }
+
+    /// [`ItemHeight`]
+    #[must_use]
+    pub fn item_height(mut self, item_height: ItemHeight) -> Self {
+        self.item_height = item_height;
+        self
+    }
+
+    /// Sets the style of the menu, shared with the menu bars.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the primary button and the arrow.
+    #[must_use]
+    pub fn button_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as button::StyleSheet>::Style>,
+    ) -> Self {
+        self.button_style = style.into();
+        self
+    }
+
+    /// Returns whether the segment does anything when pressed.
+    fn is_enabled(&self, segment: Segment) -> bool {
+        match segment {
+            Segment::Primary => self.on_press.is_some(),
+            Segment::Arrow => self
+                .menu_roots
+                .first()
+                .map_or(false, |root| !root.children.is_empty()),
+        }
+    }
+}
+
+/// Returns the segment of the [`SplitButton`](SplitButton) under the cursor.
+fn segment_at(layout: layout::Layout<'_>, cursor_position: Point) -> Option<Segment> {
+    let mut children = layout.children();
+    let primary = children.next()?;
+    let arrow = children.next()?;
+
+    if primary.bounds().contains(cursor_position) {
+        Some(Segment::Primary)
+    } else if arrow.bounds().contains(cursor_position) {
+        Some(Segment::Arrow)
+    } else {
+        None
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SplitButton<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: 'a + GlyphRenderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        /*
+        split button
+            content
+            menu (state of a menu bar)
+                menu root (stateless)
+                    flat tree
+        */
+
+        let roots = self
+            .menu_roots
+            .iter()
+            .map(|root| {
+                let mut tree = Tree::empty();
+                tree.children = root
+                    .flattern()
+                    .iter()
+                    .map(|mt| Tree::new(mt.item.as_widget()))
+                    .collect();
+                tree
+            })
+            .collect();
+
+        vec![
+            Tree::new(&self.content),
+            Tree {
+                tag: tree::Tag::of::<MenuBarState>(),
+                state: tree::State::new(MenuBarState::default()),
+                children: roots,
+            },
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(self.content.as_widget());
+
+        for (t, root) in tree.children[1].children.iter_mut().zip(&self.menu_roots) {
+            let flat = root
+                .flattern()
+                .iter()
+                .map(|mt| mt.item.as_widget())
+                .collect::<Vec<_>>();
+
+            t.diff_children(&flat);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let content_limits = limits
+            .shrink(Size::new(self.arrow_width, 0.0))
+            .pad(self.padding);
+        let mut content = self.content.as_widget().layout(renderer, &content_limits);
+        content.move_to(Point::new(self.padding.left, self.padding.top));
+
+        let primary_size = limits
+            .shrink(Size::new(self.arrow_width, 0.0))
+            .resolve(content.size().pad(self.padding));
+        let primary = layout::Node::with_children(primary_size, vec![content]);
+
+        let mut arrow = layout::Node::new(Size::new(self.arrow_width, primary_size.height));
+        arrow.move_to(Point::new(primary_size.width, 0.0));
+
+        layout::Node::with_children(
+            Size::new(primary_size.width + self.arrow_width, primary_size.height),
+            vec![primary, arrow],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(content_layout) = layout.children().next().and_then(|l| l.children().next()) {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                content_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: layout::Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = match layout.children().next().and_then(|l| l.children().next()) {
+            Some(content_layout) => self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                content_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+            None => event::Status::Ignored,
+        };
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let segment = segment_at(layout, cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(segment) = segment.filter(|&segment| self.is_enabled(segment)) else {
+                    return status;
+                };
+
+                tree.state.downcast_mut::<State>().pressed = Some(segment);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed) = tree.state.downcast_mut::<State>().pressed.take() else {
+                    return status;
+                };
+
+                if segment != Some(pressed) {
+                    return event::Status::Captured;
+                }
+
+                match pressed {
+                    Segment::Primary => {
+                        if let Some(on_press) = &self.on_press {
+                            shell.publish(on_press.clone());
+                        }
+                    }
+                    // The menu opens like the one of a menu bar
+                    Segment::Arrow => {
+                        let menu = tree.children[1].state.downcast_mut::<MenuBarState>();
+                        if menu.menu_states.is_empty() {
+                            menu.view_cursor = cursor_position;
+                            menu.open = true;
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                tree.state.downcast_mut::<State>().pressed = None;
+                status
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match segment_at(layout, cursor_position) {
+            Some(segment) if self.is_enabled(segment) => mouse::Interaction::Pointer,
+            _ => layout
+                .children()
+                .next()
+                .and_then(|l| l.children().next())
+                .map_or_else(mouse::Interaction::default, |content_layout| {
+                    self.content.as_widget().mouse_interaction(
+                        &tree.children[0],
+                        content_layout,
+                        cursor_position,
+                        viewport,
+                        renderer,
+                    )
+                }),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let menu = tree.children[1].state.downcast_ref::<MenuBarState>();
+        let mut children = layout.children();
+        let (Some(primary), Some(arrow)) = (children.next(), children.next()) else {
+            return;
+        };
+
+        let hovered = segment_at(layout, cursor_position);
+        let appearance = |segment| {
+            if !self.is_enabled(segment) {
+                button::StyleSheet::disabled(theme, &self.button_style)
+            } else if state.pressed == Some(segment) || (segment == Segment::Arrow && menu.open) {
+                button::StyleSheet::pressed(theme, &self.button_style)
+            } else if hovered == Some(segment) {
+                button::StyleSheet::hovered(theme, &self.button_style)
+            } else {
+                button::StyleSheet::active(theme, &self.button_style)
+            }
+        };
+
+        // The segments are joined by rounding only their outer corners
+        for (segment, bounds) in [
+            (Segment::Primary, primary.bounds()),
+            (Segment::Arrow, arrow.bounds()),
+        ] {
+            let appearance = appearance(segment);
+            let radius = appearance.border_radius;
+            let border_radius = match segment {
+                Segment::Primary => [radius, 0.0, 0.0, radius],
+                Segment::Arrow => [0.0, radius, radius, 0.0],
+            };
+
+            if appearance.background.is_some() || appearance.border_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance.background.unwrap_or(Color::TRANSPARENT.into()),
+                );
+            }
+
+            match segment {
+                Segment::Primary => {
+                    if let Some(content_layout) = primary.children().next() {
+                        self.content.as_widget().draw(
+                            &tree.children[0],
+                            renderer,
+                            theme,
+                            &renderer::Style {
+                                text_color: appearance.text_color,
+                            },
+                            content_layout,
+                            cursor_position,
+                            viewport,
+                        );
+                    }
+                }
+                Segment::Arrow => {
+                    // A thin line separates the arrow from the primary button
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                width: 1.0,
+                                ..bounds
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        Color {
+                            a: appearance.text_color.a * 0.3,
+                            ..appearance.text_color
+                        },
+                    );
+
+                    renderer.fill_glyph(
+                        char::from(Icon::CaretDownFill),
+                        Icon::CaretDownFill.font(),
+                        bounds,
+                        renderer.default_glyph_size() * 0.75,
+                        appearance.text_color,
+                    );
+                }
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (content_tree, menu_tree) = tree.children.split_at_mut(1);
+
+        if !menu_tree[0].state.downcast_ref::<MenuBarState>().open {
+            let content_layout = layout.children().next()?.children().next()?;
+
+            return self.content.as_widget_mut().overlay(
+                &mut content_tree[0],
+                content_layout,
+                renderer,
+            );
+        }
+
+        let arrow_bounds = layout.children().nth(1)?.bounds();
+
+        Some(
+            Menu {
+                tree: &mut menu_tree[0],
+                menu_roots: &mut self.menu_roots,
+                bounds_expand: self.bounds_expand,
+                close_condition: self.close_condition,
+                item_width: self.item_width,
+                item_height: self.item_height,
+                bar_bounds: arrow_bounds,
+                root_bounds_list: vec![arrow_bounds],
+                path_highlight: Some(PathHighlight::MenuActive),
+                style: &self.style,
+            }
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<SplitButton<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + GlyphRenderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    fn from(split_button: SplitButton<'a, Message, Renderer>) -> Self {
+        Self::new(split_button)
+    }
+}
@@ -0,0 +1,466 @@
+//! A button opening a menu of items.
+//!
+//! *This API requires the following crate features to be activated: menu_button*
+use std::fmt::Display;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    native::{focus::Focus, overlay::MenuButtonOverlay},
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::menu_button::{Appearance, StyleSheet};
+
+/// The default padding around the label of the button and of the items.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default number of items shown at once in the menu.
+const DEFAULT_VISIBLE_ITEMS: usize = 8;
+
+/// A button showing a label that opens a menu of items below it.
+///
+/// Picking an item produces its message and closes the menu, unless told
+/// otherwise. It is a simpler sibling of the
+/// [`MenuBar`](crate::native::menu::MenuBar) for a single menu of plain items.
+///
+/// The button is focused by clicking on it or by the commands of
+/// [`focus`](crate::native::focus). A focused button opens its menu with
+/// Enter, Space or the arrow keys, the arrow keys then move the highlight
+/// through the items, Enter picks the highlighted item and Escape closes the
+/// menu.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::menu_button;
+/// #
+/// # pub type MenuButton<'a, T, Message> = menu_button::MenuButton<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Picked(&'static str),
+/// }
+///
+/// let actions = ["Rename", "Duplicate", "Delete"];
+///
+/// let menu_button = MenuButton::new("Actions", &actions, Message::Picked);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuButton<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The label of the button.
+    label: String,
+    /// The items of the menu.
+    items: &'a [T],
+    /// The function producing the message when the user picks an item.
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    /// Whether picking an item closes the menu.
+    close_on_select: bool,
+    /// The id of the [`MenuButton`](MenuButton) used to focus it.
+    id: Option<Id>,
+    /// The width of the button.
+    width: Length,
+    /// The padding around the label of the button and of the items.
+    padding: f32,
+    /// The number of items shown at once in the menu.
+    visible_items: usize,
+    /// The text size of the button and of the items.
+    text_size: Option<f32>,
+    /// The font of the button and of the items.
+    font: Font,
+    /// The style of the menu button.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> MenuButton<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MenuButton`](MenuButton).
+    ///
+    /// It expects:
+    ///     * the label of the button
+    ///     * the items of the menu
+    ///     * the function producing the message when the user picks an item
+    pub fn new<F>(label: impl Into<String>, items: &'a [T], on_select: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        MenuButton {
+            label: label.into(),
+            items,
+            on_select: Box::new(on_select),
+            close_on_select: true,
+            id: None,
+            width: Length::Shrink,
+            padding: DEFAULT_PADDING,
+            visible_items: DEFAULT_VISIBLE_ITEMS,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether picking an item closes the menu.
+    ///
+    /// The menu closes by default.
+    #[must_use]
+    pub fn close_on_select(mut self, close_on_select: bool) -> Self {
+        self.close_on_select = close_on_select;
+        self
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`MenuButton`](MenuButton) to focus it with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`MenuButton`](MenuButton).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding around the label of the button and of the items.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the number of items shown at once in the menu.
+    ///
+    /// The menu scrolls through the other ones.
+    #[must_use]
+    pub fn visible_items(mut self, visible_items: usize) -> Self {
+        self.visible_items = visible_items.max(1);
+        self
+    }
+
+    /// Sets the text size of the [`MenuButton`](MenuButton).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`MenuButton`](MenuButton).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuButton`](MenuButton).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for MenuButton<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let text_width = renderer.measure_width(&self.label, text_size, self.font);
+
+        // The caret is as wide as the text is high
+        let intrinsic = Size::new(
+            text_width + text_size + 3.0 * self.padding,
+            text_size + 2.0 * self.padding,
+        );
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        Node::new(limits.resolve(intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_pressed = layout.bounds().contains(cursor_position);
+                state.focus.press(is_pressed);
+
+                // The menu captures the presses on its items, so any other
+                // press closes it
+                if state.is_open {
+                    state.is_open = false;
+                    event::Status::Captured
+                } else if is_pressed {
+                    state.open(None);
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.focus.is_focused() && !state.is_open && !self.items.is_empty() =>
+            {
+                let count = self.items.len();
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space
+                    | keyboard::KeyCode::Down => state.open(Some(0)),
+                    keyboard::KeyCode::Up => state.open(Some(count - 1)),
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                state.is_open,
+                state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let text_bounds = Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.center_y(),
+            width: (bounds.width - text_size - 3.0 * self.padding).max(0.0),
+            height: bounds.height,
+        };
+
+        renderer.with_layer(
+            Rectangle {
+                y: bounds.y,
+                ..text_bounds
+            },
+            |renderer| {
+                renderer.fill_text(text::Text {
+                    content: &self.label,
+                    bounds: text_bounds,
+                    size: text_size,
+                    color: appearance.text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            },
+        );
+
+        renderer.fill_glyph(
+            char::from(Icon::CaretDownFill),
+            Icon::CaretDownFill.font(),
+            Rectangle {
+                x: bounds.x + bounds.width - self.padding - text_size,
+                y: bounds.y,
+                width: text_size,
+                height: bounds.height,
+            },
+            text_size,
+            appearance.icon_color,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open || self.items.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(
+            MenuButtonOverlay::new(
+                self.items,
+                state,
+                self.on_select.as_ref(),
+                self.close_on_select,
+                bounds.size(),
+                self.padding,
+                self.visible_items,
+                self.text_size,
+                self.font,
+                self.style,
+            )
+            .overlay(bounds.position()),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<MenuButton<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_button: MenuButton<'a, T, Message, Renderer>) -> Self {
+        Element::new(menu_button)
+    }
+}
+
+/// The state of a [`MenuButton`](MenuButton).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the menu is open.
+    pub(crate) is_open: bool,
+    /// The index of the highlighted item.
+    pub(crate) hovered: Option<usize>,
+    /// The index of the first item shown in the menu.
+    pub(crate) offset: usize,
+    /// The keyboard focus of the button.
+    focus: Focus,
+}
+
+impl State {
+    /// Opens the menu with the item at the index highlighted.
+    fn open(&mut self, hovered: Option<usize>) {
+        self.is_open = true;
+        self.hovered = hovered;
+        self.offset = 0;
+    }
+}
+
+/// Returns the item highlighted after moving the highlight of the menu one
+/// item forwards or backwards, wrapping around at its ends.
+pub(crate) fn step(hovered: Option<usize>, count: usize, forwards: bool) -> usize {
+    match (hovered, forwards) {
+        (None, true) => 0,
+        (None, false) => count.saturating_sub(1),
+        (Some(index), true) => (index + 1) % count.max(1),
+        (Some(index), false) => index.checked_sub(1).unwrap_or(count.saturating_sub(1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step;
+
+    #[test]
+    fn step_wraps_around_the_ends() {
+        assert_eq!(step(None, 3, true), 0);
+        assert_eq!(step(None, 3, false), 2);
+        assert_eq!(step(Some(1), 3, true), 2);
+        assert_eq!(step(Some(2), 3, true), 0);
+        assert_eq!(step(Some(0), 3, false), 2);
+        assert_eq!(step(Some(2), 3, false), 1);
+    }
+}
@@ -0,0 +1,547 @@
+//! Navigate a large content area from a scaled-down overview of it.
+//!
+//! *This API requires the following crate features to be activated: minimap*
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, touch,
+    widget::tree::{self, Tag, Tree},
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::minimap::{Appearance, StyleSheet};
+
+/// The default width of a [`Minimap`](Minimap).
+const DEFAULT_WIDTH: f32 = 200.0;
+
+/// The default height of a [`Minimap`](Minimap).
+const DEFAULT_HEIGHT: f32 = 150.0;
+
+/// The default space between the border of a [`Minimap`](Minimap) and the
+/// content area.
+const DEFAULT_PADDING: f32 = 4.0;
+
+/// The factor a line of the mouse wheel zooms by.
+const ZOOM_STEP: f32 = 1.1;
+
+/// The number of pixels of a precise scroll counting as a line.
+const PIXELS_PER_LINE: f32 = 50.0;
+
+/// The smallest width and height the viewport rectangle is drawn with, so it
+/// stays visible and can be grabbed when the content is very large.
+const MIN_VIEWPORT_SIZE: f32 = 4.0;
+
+/// A command of a [`Minimap`](Minimap) to move or zoom the viewport of the
+/// content area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Navigation {
+    /// Scroll the content area until the top left corner of the viewport is
+    /// at the point of the content.
+    ScrollTo(Point),
+    /// Zoom the content area by the factor around the center of the
+    /// viewport. Factors above 1.0 zoom in.
+    Zoom(f32),
+}
+
+/// A scaled-down overview of a large content area, like a node editor or a
+/// long document, showing which part of it is visible.
+///
+/// The content is drawn as the rectangles of its items. Dragging the viewport
+/// rectangle or pressing next to it scrolls the content area, and the mouse
+/// wheel zooms it if the minimap is [`zoomable`](Minimap::zoomable). The
+/// application applies the [`Navigation`](Navigation) to its content area and
+/// passes the new viewport back to the minimap.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Point, Rectangle, Size};
+/// # use iced_aw::native::minimap::{self, Navigation};
+/// #
+/// # pub type Minimap<'a, Message> = minimap::Minimap<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Navigate(Navigation),
+/// }
+///
+/// let content = Size::new(4000.0, 3000.0);
+/// let viewport = Rectangle::new(Point::new(1200.0, 800.0), Size::new(800.0, 600.0));
+///
+/// let minimap = Minimap::new(content, viewport, Message::Navigate)
+///     .item(Rectangle::new(Point::new(1400.0, 900.0), Size::new(200.0, 120.0)))
+///     .zoomable(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Minimap<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The size of the whole content area.
+    content: Size,
+    /// The visible part of the content area.
+    viewport: Rectangle,
+    /// The rectangles of the items in the content area and their colors.
+    items: Vec<(Rectangle, Option<Color>)>,
+    /// The function producing the message of a navigation.
+    on_navigate: Box<dyn Fn(Navigation) -> Message + 'a>,
+    /// Whether the mouse wheel zooms the content area.
+    zoomable: bool,
+    /// The width of the minimap.
+    width: Length,
+    /// The height of the minimap.
+    height: Length,
+    /// The space between the border and the content area.
+    padding: f32,
+    /// The style of the minimap.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Minimap<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Minimap`](Minimap).
+    ///
+    /// It expects:
+    ///     * the size of the whole content area.
+    ///     * the visible part of the content area.
+    ///     * the function producing the message of a navigation.
+    pub fn new<F>(content: Size, viewport: Rectangle, on_navigate: F) -> Self
+    where
+        F: 'a + Fn(Navigation) -> Message,
+    {
+        Minimap {
+            content,
+            viewport,
+            items: Vec::new(),
+            on_navigate: Box::new(on_navigate),
+            zoomable: false,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            padding: DEFAULT_PADDING,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds an item of the content area drawn with the item color of the
+    /// style.
+    #[must_use]
+    pub fn item(mut self, bounds: Rectangle) -> Self {
+        self.items.push((bounds, None));
+        self
+    }
+
+    /// Adds an item of the content area drawn with its own color.
+    #[must_use]
+    pub fn colored_item(mut self, bounds: Rectangle, color: Color) -> Self {
+        self.items.push((bounds, Some(color)));
+        self
+    }
+
+    /// Adds the items of the content area drawn with the item color of the
+    /// style.
+    #[must_use]
+    pub fn items(mut self, items: impl IntoIterator<Item = Rectangle>) -> Self {
+        self.items
+            .extend(items.into_iter().map(|bounds| (bounds, None)));
+        self
+    }
+
+    /// Sets whether the mouse wheel zooms the content area.
+    #[must_use]
+    pub fn zoomable(mut self, zoomable: bool) -> Self {
+        self.zoomable = zoomable;
+        self
+    }
+
+    /// Sets the width of the [`Minimap`](Minimap).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Minimap`](Minimap).
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the space between the border of the [`Minimap`](Minimap) and the
+    /// content area.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the style of the [`Minimap`](Minimap).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the scale and the position of the content area drawn inside
+    /// the bounds.
+    fn fit(&self, bounds: Rectangle) -> Option<(f32, Point)> {
+        fit(
+            self.content,
+            Rectangle {
+                x: bounds.x + self.padding,
+                y: bounds.y + self.padding,
+                width: bounds.width - 2.0 * self.padding,
+                height: bounds.height - 2.0 * self.padding,
+            },
+        )
+    }
+
+    /// Returns the viewport rectangle drawn inside the bounds.
+    fn viewport_bounds(&self, bounds: Rectangle) -> Option<Rectangle> {
+        let (scale, origin) = self.fit(bounds)?;
+        let scaled = scale_rectangle(self.viewport, scale, origin);
+        let width = scaled.width.max(MIN_VIEWPORT_SIZE);
+        let height = scaled.height.max(MIN_VIEWPORT_SIZE);
+
+        Some(Rectangle {
+            x: scaled.center_x() - width / 2.0,
+            y: scaled.center_y() - height / 2.0,
+            width,
+            height,
+        })
+    }
+
+    /// Publishes a scroll moving the top left corner of the viewport to the
+    /// point of the content, if it moves the viewport.
+    fn scroll_to(&self, origin: Point, shell: &mut Shell<'_, Message>) {
+        let origin = clamp_origin(origin, self.viewport.size(), self.content);
+
+        if origin != self.viewport.position() {
+            shell.publish((self.on_navigate)(Navigation::ScrollTo(origin)));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Minimap<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(DEFAULT_WIDTH, DEFAULT_HEIGHT)))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let minimap_state: &mut State = state.state.downcast_mut();
+        let bounds = layout.bounds();
+        let Some((scale, origin)) = self.fit(bounds) else {
+            return event::Status::Ignored;
+        };
+        let to_content = |position: Point| {
+            Point::new(
+                (position.x - origin.x) / scale,
+                (position.y - origin.y) / scale,
+            )
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let point = to_content(cursor_position);
+                let grabbed = self
+                    .viewport_bounds(bounds)
+                    .map_or(false, |viewport| viewport.contains(cursor_position));
+
+                // Pressing next to the viewport centers it on the pressed point
+                let grab = if grabbed {
+                    point - self.viewport.position()
+                } else {
+                    Vector::new(self.viewport.width / 2.0, self.viewport.height / 2.0)
+                };
+
+                minimap_state.grab = Some(grab);
+                self.scroll_to(point - grab, shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(grab) = minimap_state.grab else {
+                    return event::Status::Ignored;
+                };
+
+                self.scroll_to(to_content(position) - grab, shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if minimap_state.grab.take().is_none() {
+                    return event::Status::Ignored;
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !self.zoomable || !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+                };
+
+                if lines.abs() > f32::EPSILON {
+                    shell.publish((self.on_navigate)(Navigation::Zoom(ZOOM_STEP.powf(lines))));
+                }
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let minimap_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+
+        if minimap_state.grab.is_some() {
+            mouse::Interaction::Grabbing
+        } else if self
+            .viewport_bounds(bounds)
+            .map_or(false, |viewport| viewport.contains(cursor_position))
+        {
+            mouse::Interaction::Grab
+        } else if bounds.contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let minimap_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let viewport = self.viewport_bounds(bounds);
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                viewport.map_or(false, |viewport| viewport.contains(cursor_position)),
+                minimap_state.grab.is_some(),
+                false,
+                false,
+            ),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let (Some((scale, origin)), Some(viewport)) = (self.fit(bounds), viewport) else {
+            return;
+        };
+        let rectangle = |bounds: Rectangle| renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        renderer.fill_quad(
+            rectangle(scale_rectangle(
+                Rectangle::new(Point::ORIGIN, self.content),
+                scale,
+                origin,
+            )),
+            appearance.content_background,
+        );
+
+        // A viewport beyond the edges of the content is cut off at the border
+        renderer.with_layer(bounds, |renderer| {
+            for (item, color) in &self.items {
+                renderer.fill_quad(
+                    rectangle(scale_rectangle(*item, scale, origin)),
+                    color.unwrap_or(appearance.item_color),
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    border_width: appearance.viewport_border_width,
+                    border_color: appearance.viewport_border_color,
+                    ..rectangle(viewport)
+                },
+                appearance.viewport_background,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Minimap<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(minimap: Minimap<'a, Message, Renderer>) -> Self {
+        Element::new(minimap)
+    }
+}
+
+/// The state of a [`Minimap`](Minimap).
+///
+/// The [`Minimap`](Minimap) keeps it in the widget tree, so it doesn't need to
+/// be held by the application.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// Where the viewport is grabbed, relative to its top left corner in the
+    /// units of the content, while it is dragged.
+    grab: Option<Vector>,
+}
+
+/// Returns the scale fitting the content inside the bounds and the position
+/// of its top left corner, centering it in the bounds.
+///
+/// Returns `None` if the content or the bounds are empty.
+fn fit(content: Size, bounds: Rectangle) -> Option<(f32, Point)> {
+    if content.width <= 0.0 || content.height <= 0.0 || bounds.width <= 0.0 || bounds.height <= 0.0
+    {
+        return None;
+    }
+
+    let scale = (bounds.width / content.width).min(bounds.height / content.height);
+
+    Some((
+        scale,
+        Point::new(
+            bounds.center_x() - content.width * scale / 2.0,
+            bounds.center_y() - content.height * scale / 2.0,
+        ),
+    ))
+}
+
+/// Returns the rectangle of the content scaled and moved to the origin.
+fn scale_rectangle(rectangle: Rectangle, scale: f32, origin: Point) -> Rectangle {
+    Rectangle {
+        x: origin.x + rectangle.x * scale,
+        y: origin.y + rectangle.y * scale,
+        width: rectangle.width * scale,
+        height: rectangle.height * scale,
+    }
+}
+
+/// Returns the top left corner of the viewport keeping it inside the content.
+///
+/// A viewport larger than the content stays at its start.
+fn clamp_origin(origin: Point, viewport: Size, content: Size) -> Point {
+    Point::new(
+        origin
+            .x
+            .clamp(0.0, (content.width - viewport.width).max(0.0)),
+        origin
+            .y
+            .clamp(0.0, (content.height - viewport.height).max(0.0)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clamp_origin, fit, Point, Rectangle, Size};
+
+    #[test]
+    fn fit_centers_the_scaled_content() {
+        let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(200.0, 200.0));
+
+        let (scale, origin) = fit(Size::new(1000.0, 500.0), bounds).expect("content fits");
+
+        assert!((scale - 0.2).abs() < f32::EPSILON);
+        assert!((origin.x - 10.0).abs() < 1e-4);
+        assert!((origin.y - 60.0).abs() < 1e-4);
+
+        assert!(fit(Size::ZERO, bounds).is_none());
+    }
+
+    #[test]
+    fn viewport_stays_inside_the_content() {
+        let content = Size::new(1000.0, 500.0);
+        let viewport = Size::new(200.0, 100.0);
+
+        assert_eq!(
+            clamp_origin(Point::new(-50.0, 450.0), viewport, content),
+            Point::new(0.0, 400.0)
+        );
+        assert_eq!(
+            clamp_origin(Point::new(300.0, 100.0), viewport, content),
+            Point::new(300.0, 100.0)
+        );
+        assert_eq!(
+            clamp_origin(Point::new(300.0, 100.0), Size::new(2000.0, 100.0), content),
+            Point::new(0.0, 100.0)
+        );
+    }
+}
@@ -1,9 +1,16 @@
 //! Stateless, pure widgets for iced
+//!
+//! Like the widgets of iced, they keep their internal state in the widget tree,
+//! so the application only holds its own data.
 use iced_graphics::Renderer;
 
 pub mod helpers;
 pub use helpers::*;
 
+pub mod focus;
+
+pub mod operation;
+
 pub mod overlay;
 
 #[cfg(feature = "badge")]
@@ -41,6 +48,27 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "calendar_heatmap")]
+pub mod calendar_heatmap;
+#[cfg(feature = "calendar_heatmap")]
+/// A heatmap showing a value for every day of a year.
+pub type CalendarHeatmap<'a, Message, Backend, Theme> =
+    calendar_heatmap::CalendarHeatmap<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "heatmap")]
+pub mod heatmap;
+#[cfg(feature = "heatmap")]
+/// A heatmap showing a matrix of values as colored cells.
+pub type Heatmap<'a, Message, Backend, Theme> =
+    heatmap::Heatmap<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "month_view")]
+pub mod month_view;
+#[cfg(feature = "month_view")]
+/// A calendar showing a month as a grid of days hosting events.
+pub type MonthView<'a, Message, Backend, Theme> =
+    month_view::MonthView<'a, Message, Renderer<Backend, Theme>>;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
@@ -65,6 +93,11 @@ pub type Grid<'a, Message, Backend, Theme> = grid::Grid<'a, Message, Renderer<Ba
 #[cfg(feature = "grid")]
 pub use grid::Strategy;
 
+#[cfg(feature = "icon")]
+pub mod icon;
+#[cfg(feature = "icon")]
+pub use icon::Icon;
+
 #[cfg(feature = "icon_text")]
 pub mod icon_text;
 #[cfg(feature = "icon_text")]
@@ -78,12 +111,368 @@ pub mod modal;
 pub type Modal<'a, Content, Message, Backend, Theme> =
     modal::Modal<'a, Content, Message, Renderer<Backend, Theme>>;
 
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+/// A popover showing content in a bubble anchored to another widget.
+pub type Popover<'a, Content, Message, Backend, Theme> =
+    popover::Popover<'a, Content, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "portal")]
+pub mod portal;
+#[cfg(feature = "portal")]
+/// A portal rendering its content on the overlay layer.
+pub type Portal<'a, Message, Backend, Theme> =
+    portal::Portal<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "tooltip")]
+/// A tooltip showing arbitrary content while the wrapped widget is hovered.
+pub type Tooltip<'a, Content, Message, Backend, Theme> =
+    tooltip::Tooltip<'a, Content, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+#[cfg(feature = "tour")]
+/// An onboarding tour highlighting the widgets of the content step by step.
+pub type Tour<'a, Message, Backend, Theme> = tour::Tour<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "hover_card")]
+pub mod hover_card;
+#[cfg(feature = "hover_card")]
+/// A card previewing details while the wrapped widget is hovered.
+pub type HoverCard<'a, Content, Message, Backend, Theme> =
+    hover_card::HoverCard<'a, Content, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "gesture_detector")]
+pub mod gesture_detector;
+#[cfg(feature = "gesture_detector")]
+/// A wrapper recognizing touch gestures on its content.
+pub type GestureDetector<'a, Message, Backend, Theme> =
+    gesture_detector::GestureDetector<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "responsive")]
+pub mod responsive;
+#[cfg(feature = "responsive")]
+/// A widget building its content for the breakpoint of its width.
+pub type Responsive<'a, Message, Backend, Theme> =
+    responsive::Responsive<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+/// A manager showing timed notifications on top of its content.
+pub type Toasts<'a, Message, Backend, Theme> =
+    toast::Toasts<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+/// A table showing rows of elements in sortable columns.
+pub type Table<'a, Message, Backend, Theme> = table::Table<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "spreadsheet_grid")]
+pub mod spreadsheet_grid;
+#[cfg(feature = "spreadsheet_grid")]
+/// A grid of text cells edited like a spreadsheet.
+pub type SpreadsheetGrid<'a, Message, Backend, Theme> =
+    spreadsheet_grid::SpreadsheetGrid<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "accordion")]
+/// A list of titled sections expanding their content when their header is pressed.
+pub type Accordion<'a, Message, Backend, Theme> =
+    accordion::Accordion<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "property_grid")]
+pub mod property_grid;
+#[cfg(feature = "property_grid")]
+/// A list of named properties edited in collapsible sections.
+pub type PropertyGrid<'a, Message, Backend, Theme> =
+    property_grid::PropertyGrid<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "breadcrumbs")]
+pub mod breadcrumbs;
+#[cfg(feature = "breadcrumbs")]
+/// A trail of segments leading to the current page.
+pub type Breadcrumbs<'a, Message, Backend, Theme> =
+    breadcrumbs::Breadcrumbs<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "pagination")]
+pub mod pagination;
+#[cfg(feature = "pagination")]
+/// A row of buttons to navigate between the pages of a long list.
+pub type Pagination<'a, Message, Backend, Theme> =
+    pagination::Pagination<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "qr_code")]
+pub mod qr_code;
+#[cfg(feature = "qr_code")]
+/// Data shown as a QR code, with an optional logo in its center.
+pub type QrCode<'a, Message, Backend, Theme> =
+    qr_code::QrCode<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+/// A slider with two handles selecting a sub-range of its values.
+pub type RangeSlider<'a, T, Message, Backend, Theme> =
+    range_slider::RangeSlider<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "tick_slider")]
+pub mod tick_slider;
+#[cfg(feature = "tick_slider")]
+/// A slider with tick marks, labels and a value bubble.
+pub type TickSlider<'a, T, Message, Backend, Theme> =
+    tick_slider::TickSlider<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "scrubber")]
+pub mod scrubber;
+#[cfg(feature = "scrubber")]
+/// A media timeline with buffered ranges, chapters and a preview bubble.
+pub type Scrubber<'a, Message, Backend, Theme> =
+    scrubber::Scrubber<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "xy_pad")]
+pub mod xy_pad;
+#[cfg(feature = "xy_pad")]
+/// A rectangle setting two values at once by dragging a handle inside it.
+pub type XYPad<'a, T, Message, Backend, Theme> =
+    xy_pad::XYPad<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "rating")]
+pub mod rating;
+#[cfg(feature = "rating")]
+/// A row of icons showing a rating and letting the user pick one.
+pub type Rating<'a, Message, Backend, Theme> =
+    rating::Rating<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "stepper")]
+pub mod stepper;
+#[cfg(feature = "stepper")]
+/// Numbered steps guiding the user through the content of each step.
+pub type Stepper<'a, Message, Backend, Theme> =
+    stepper::Stepper<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+/// A text input whose dropdown filters a list of options as the user types.
+pub type ComboBox<'a, T, Message, Backend, Theme> =
+    combo_box::ComboBox<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "multi_select")]
+pub mod multi_select;
+#[cfg(feature = "multi_select")]
+/// A dropdown of checkboxes to pick any number of options.
+pub type MultiSelect<'a, T, Message, Backend, Theme> =
+    multi_select::MultiSelect<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "menu_button")]
+pub mod menu_button;
+#[cfg(feature = "menu_button")]
+/// A button opening a menu of items.
+pub type MenuButton<'a, T, Message, Backend, Theme> =
+    menu_button::MenuButton<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "transfer_list")]
+pub mod transfer_list;
+#[cfg(feature = "transfer_list")]
+/// Two lists with buttons moving items from one to the other.
+pub type TransferList<'a, T, Message, Backend, Theme> =
+    transfer_list::TransferList<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "search_bar")]
+pub mod search_bar;
+#[cfg(feature = "search_bar")]
+/// A text input searching as the user types, with suggestions.
+pub type SearchBar<'a, T, Message, Backend, Theme> =
+    search_bar::SearchBar<'a, T, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "pin_input")]
+pub mod pin_input;
+#[cfg(feature = "pin_input")]
+/// A row of boxes to type a code into, one character per box.
+pub type PinInput<'a, Message, Backend, Theme> =
+    pin_input::PinInput<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "masked_input")]
+pub mod masked_input;
+#[cfg(feature = "masked_input")]
+/// A text input only accepting text that follows a pattern.
+pub type MaskedInput<'a, Message, Backend, Theme> =
+    masked_input::MaskedInput<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "hotkey_input")]
+pub mod hotkey_input;
+#[cfg(feature = "hotkey_input")]
+/// A field recording a keyboard shortcut by pressing it.
+pub type HotkeyInput<'a, Message, Backend, Theme> =
+    hotkey_input::HotkeyInput<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "shortcut_hint")]
+pub mod shortcut_hint;
+#[cfg(feature = "shortcut_hint")]
+/// A keyboard shortcut shown as a row of keycaps.
+pub type ShortcutHint<Backend, Theme> = shortcut_hint::ShortcutHint<Renderer<Backend, Theme>>;
+
+#[cfg(feature = "segmented_button")]
+pub mod segmented_button;
+#[cfg(feature = "segmented_button")]
+/// Mutually exclusive options lined up in one connected pill.
+pub type SegmentedButton<'a, Message, Backend, Theme> =
+    segmented_button::SegmentedButton<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "circular_progress")]
+pub mod circular_progress;
+#[cfg(feature = "circular_progress")]
+pub use circular_progress::CircularProgress;
+
+#[cfg(feature = "skeleton")]
+pub mod skeleton;
+#[cfg(feature = "skeleton")]
+/// A placeholder shape with a shimmer, shown while the content loads.
+pub type Skeleton<Backend, Theme> = skeleton::Skeleton<Renderer<Backend, Theme>>;
+
+#[cfg(feature = "status_dot")]
+pub mod status_dot;
+#[cfg(feature = "status_dot")]
+/// A colored dot showing the status of a device or a connection.
+pub type StatusDot<Backend, Theme> = status_dot::StatusDot<Renderer<Backend, Theme>>;
+
+#[cfg(feature = "avatar")]
+pub mod avatar;
+#[cfg(feature = "avatar")]
+/// An image of a person, or their colored initials if there is no image.
+pub type Avatar<Backend, Theme> = avatar::Avatar<Renderer<Backend, Theme>>;
+
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "chip")]
+/// A compact label that can be pressed, toggled or deleted.
+pub type Chip<'a, Message, Backend, Theme> = chip::Chip<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "carousel")]
+pub mod carousel;
+#[cfg(feature = "carousel")]
+/// A carousel showing one of its elements at a time.
+pub type Carousel<'a, Message, Backend, Theme> =
+    carousel::Carousel<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "gallery")]
+pub mod gallery;
+#[cfg(feature = "gallery")]
+/// A viewer showing the selected image above a strip of thumbnails.
+pub type Gallery<'a, Message, Backend, Theme> =
+    gallery::Gallery<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "image_viewer")]
+pub mod image_viewer;
+#[cfg(feature = "image_viewer")]
+/// A viewer zooming into an image and panning around it.
+pub type ImageViewer<'a, Message, Backend, Theme> =
+    image_viewer::ImageViewer<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "minimap")]
+pub mod minimap;
+#[cfg(feature = "minimap")]
+/// A scaled-down overview of a large content area with a draggable viewport.
+pub type Minimap<'a, Message, Backend, Theme> =
+    minimap::Minimap<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "chart")]
+pub mod chart;
+#[cfg(feature = "chart")]
+/// A chart showing each value as a bar growing from zero.
+pub type BarChart<Backend, Theme> = chart::BarChart<Renderer<Backend, Theme>>;
+#[cfg(feature = "chart")]
+pub use chart::LineChart;
+#[cfg(feature = "chart")]
+pub use chart::PieChart;
+
+#[cfg(feature = "gauge")]
+pub mod gauge;
+#[cfg(feature = "gauge")]
+pub use gauge::Gauge;
+
+#[cfg(feature = "drawing_pad")]
+pub mod drawing_pad;
+#[cfg(feature = "drawing_pad")]
+pub use drawing_pad::DrawingPad;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+pub use knob::Knob;
+
+#[cfg(feature = "drop_zone")]
+pub mod drop_zone;
+#[cfg(feature = "drop_zone")]
+/// An area accepting files dropped onto it from outside of the application.
+pub type DropZone<'a, Message, Backend, Theme> =
+    drop_zone::DropZone<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "code_editor")]
+pub mod code_editor;
+#[cfg(feature = "code_editor")]
+/// A multi-line editor for code with a gutter showing the line numbers.
+pub type CodeEditor<'a, Message, Backend, Theme> =
+    code_editor::CodeEditor<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "rich_text")]
+pub mod rich_text;
+#[cfg(feature = "rich_text")]
+/// A text made of spans with styles of their own, wrapped as a whole.
+pub type RichText<'a, Message, Backend, Theme> =
+    rich_text::RichText<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "virtual_list")]
+pub mod virtual_list;
+#[cfg(feature = "virtual_list")]
+/// A scrollable list building only the rows it shows.
+pub type VirtualList<'a, Message, Backend, Theme> =
+    virtual_list::VirtualList<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "infinite_scroll")]
+pub mod infinite_scroll;
+#[cfg(feature = "infinite_scroll")]
+/// Scrollable content loading more of itself near the end.
+pub type InfiniteScroll<'a, Message, Backend, Theme> =
+    infinite_scroll::InfiniteScroll<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "drag_and_drop")]
+pub mod drag_and_drop;
+#[cfg(feature = "drag_and_drop")]
+/// An element whose payload can be dragged onto a drop target.
+pub type DragSource<'a, T, Message, Backend, Theme> =
+    drag_and_drop::DragSource<'a, T, Message, Renderer<Backend, Theme>>;
+#[cfg(feature = "drag_and_drop")]
+/// An area accepting the payloads dropped onto it.
+pub type DropTarget<'a, T, Message, Backend, Theme> =
+    drag_and_drop::DropTarget<'a, T, Message, Renderer<Backend, Theme>>;
+
 #[cfg(feature = "split")]
 pub mod split;
 #[cfg(feature = "split")]
 /// A split can divide the available space by half to display two different elements.
 pub type Split<'a, Message, Backend, Theme> = split::Split<'a, Message, Renderer<Backend, Theme>>;
 
+#[cfg(feature = "switch")]
+pub mod switch;
+#[cfg(feature = "switch")]
+/// A switch turning an option on or off with a sliding thumb.
+pub type Switch<'a, Message, Backend, Theme> =
+    switch::Switch<'a, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "resizable")]
+pub mod resizable;
+#[cfg(feature = "resizable")]
+/// A wrapper resized by dragging handles on its edges and corners.
+pub type Resizable<'a, Message, Backend, Theme> =
+    resizable::Resizable<'a, Message, Renderer<Backend, Theme>>;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
@@ -104,6 +493,13 @@ pub type Tabs<'a, Message, TabId, Backend, Theme> =
 #[cfg(feature = "tabs")]
 pub use tabs::TabBarPosition;
 
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+#[cfg(feature = "tag_input")]
+/// A text input turning the typed text into removable chips.
+pub type TagInput<'a, Message, Backend, Theme> =
+    tag_input::TagInput<'a, Message, Renderer<Backend, Theme>>;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
@@ -128,9 +524,23 @@ pub mod spinner;
 /// A spinner widget, a circle spinning around the center of the widget.
 pub type Spinner<Backend, Theme> = spinner::Spinner<Renderer<Backend, Theme>>;
 
+#[cfg(feature = "loading_overlay")]
+pub mod loading_overlay;
+#[cfg(feature = "loading_overlay")]
+/// A wrapper dimming its content and showing a spinner while it loads.
+pub type LoadingOverlay<'a, Message, Backend, Theme> =
+    loading_overlay::LoadingOverlay<'a, Message, Renderer<Backend, Theme>>;
+
 #[cfg(feature = "context_menu")]
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
 /// A context menu
 pub type ContextMenu<'a, Overlay, Message, Backend, Theme> =
     context_menu::ContextMenu<'a, Overlay, Message, Renderer<Backend, Theme>>;
+
+#[cfg(feature = "radial_menu")]
+pub mod radial_menu;
+#[cfg(feature = "radial_menu")]
+/// A ring of icon actions opened around the cursor.
+pub type RadialMenu<'a, Message, Backend, Theme> =
+    radial_menu::RadialMenu<'a, Message, Renderer<Backend, Theme>>;
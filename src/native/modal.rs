@@ -7,7 +7,10 @@ use iced_native::{
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
 };
 
-use super::overlay::modal::ModalOverlay;
+use super::overlay::{modal::ModalOverlay, Layers};
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{Node, Role};
+use crate::core::event::is_input;
 
 pub use crate::style::modal::StyleSheet;
 
@@ -114,6 +117,15 @@ where
         self.style = style;
         self
     }
+
+    /// Describes the [`Modal`](Modal) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, _state: &Tree) -> Node {
+        // The dialog is expanded while it is shown
+        Node::new(Role::Dialog).expanded(self.show_modal)
+    }
 }
 
 impl<'a, Content, Message, Renderer> Widget<Message, Renderer>
@@ -158,6 +170,12 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        // The overlay handles the input while the modal is shown, so it must
+        // not reach the underlay, too
+        if self.show_modal && is_input(&event) {
+            return event::Status::Ignored;
+        }
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -223,18 +241,29 @@ where
         let bounds = layout.bounds();
         let position = Point::new(bounds.x, bounds.y);
         let content = (self.content)();
-        content.as_widget().diff(&mut state.children[1]);
+        let (underlay_state, content_state) = state.children.split_at_mut(1);
+        content.as_widget().diff(&mut content_state[0]);
 
-        Some(
-            ModalOverlay::new(
-                &mut state.children[1],
-                content,
-                self.backdrop.clone(),
-                self.esc.clone(),
-                self.style,
+        // The overlays of the underlay stay visible below the modal
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut underlay_state[0], layout, renderer),
             )
-            .overlay(position),
-        )
+            .push(
+                1,
+                ModalOverlay::new(
+                    &mut content_state[0],
+                    content,
+                    self.backdrop.clone(),
+                    self.esc.clone(),
+                    self.style,
+                )
+                .overlay(position),
+            )
+            .overlay()
     }
 
     fn operate<'b>(
@@ -273,6 +302,7 @@ where
 }
 /// The state of the modal.
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct State<S> {
     /// The visibility of the [`Modal`](Modal) overlay.
     show: bool,
@@ -0,0 +1,976 @@
+//! Show a month as a grid of days hosting events.
+//!
+//! *This API requires the following crate features to be activated: month_view*
+use chrono::{Datelike, Duration, NaiveDate};
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::Tree,
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::{
+        date::{
+            first_day_of_grid, month_as_string, pred_month, succ_month, weeks_of_month,
+            year_as_string, WEEKDAY_LABELS,
+        },
+        renderer::GlyphRenderer,
+    },
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::core::date::Date;
+
+pub use crate::style::month_view::{Appearance, StyleSheet};
+
+/// The default space between the edge of a day and its content.
+const DEFAULT_PADDING: f32 = 4.0;
+
+/// The default space between the events of a day.
+const DEFAULT_SPACING: f32 = 2.0;
+
+/// The space between the edge of an event and its content.
+const EVENT_PADDING: f32 = 2.0;
+
+/// The default size of a day if the month view shrinks.
+const DEFAULT_DAY_SIZE: Size = Size::new(100.0, 80.0);
+
+/// An event hosted by a day of a [`MonthView`](MonthView).
+struct MonthEvent<'a, Message, Renderer> {
+    /// The day of the event.
+    date: NaiveDate,
+    /// The content of the event.
+    content: Element<'a, Message, Renderer>,
+    /// The message produced when the event is clicked.
+    on_press: Option<Message>,
+}
+
+/// A calendar showing a full month as a grid of days, where each day hosts
+/// the events of a schedule.
+///
+/// Unlike a date picker, the month view is meant to fill a screen. The events are small elements, like the title of a
+/// meeting, drawn as chips below the number of their day. The events which do
+/// not fit into their day are counted in its corner.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, widget::Text};
+/// # use iced_aw::native::month_view;
+/// #
+/// # pub type MonthView<'a, Message> = month_view::MonthView<'a, Message, Null>;
+/// use iced_aw::native::month_view::Date;
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Day(Date),
+///     Meeting(usize),
+///     Navigate(Date),
+/// }
+///
+/// let month_view = MonthView::new(2023, 5)
+///     .event(
+///         Date::from_ymd(2023, 5, 12),
+///         Text::new("Planning"),
+///         Message::Meeting(0),
+///     )
+///     .on_day(Message::Day)
+///     .on_navigate(Message::Navigate);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MonthView<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The year of the month.
+    year: i32,
+    /// The month (1 - 12).
+    month: u32,
+    /// The events of the days.
+    events: Vec<MonthEvent<'a, Message, Renderer>>,
+    /// The function producing the message when a day is clicked.
+    on_day: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    /// The function producing the message when the user navigates to the
+    /// previous or next month.
+    on_navigate: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    /// The selected day.
+    selected: Option<NaiveDate>,
+    /// The day marked as today.
+    today: Option<NaiveDate>,
+    /// The width of the month view.
+    width: Length,
+    /// The height of the month view.
+    height: Length,
+    /// The space between the edge of a day and its content.
+    padding: f32,
+    /// The space between the events of a day.
+    spacing: f32,
+    /// The text size of the header and the numbers of the days.
+    text_size: Option<f32>,
+    /// The font of the header and the numbers of the days.
+    font: Font,
+    /// The style of the month view.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> MonthView<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MonthView`](MonthView) showing the month (1 - 12) of
+    /// the year.
+    ///
+    /// # Panics
+    /// Panics if the month is not between 1 and 12.
+    #[must_use]
+    pub fn new(year: i32, month: u32) -> Self {
+        assert!(
+            (1..=12).contains(&month),
+            "The month should be between 1 and 12"
+        );
+
+        Self {
+            year,
+            month,
+            events: Vec::new(),
+            on_day: None,
+            on_navigate: None,
+            selected: None,
+            today: Some(Date::today().into()),
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds an event to the day, producing the message when it is clicked.
+    ///
+    /// The events of a day are stacked in the order they are added.
+    #[must_use]
+    pub fn event<C>(mut self, date: Date, content: C, on_press: Message) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        self.events.push(MonthEvent {
+            date: date.into(),
+            content: content.into(),
+            on_press: Some(on_press),
+        });
+        self
+    }
+
+    /// Adds an event to the day which can not be clicked.
+    #[must_use]
+    pub fn passive_event<C>(mut self, date: Date, content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        self.events.push(MonthEvent {
+            date: date.into(),
+            content: content.into(),
+            on_press: None,
+        });
+        self
+    }
+
+    /// Sets the function producing the message when a day is clicked.
+    #[must_use]
+    pub fn on_day<F>(mut self, on_day: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        self.on_day = Some(Box::new(on_day));
+        self
+    }
+
+    /// Sets the function producing the message with the first day of the
+    /// previous or next month when one of the arrows of the header is
+    /// clicked.
+    ///
+    /// The arrows are hidden without one.
+    #[must_use]
+    pub fn on_navigate<F>(mut self, on_navigate: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        self.on_navigate = Some(Box::new(on_navigate));
+        self
+    }
+
+    /// Sets the selected day.
+    #[must_use]
+    pub fn selected(mut self, date: Option<Date>) -> Self {
+        self.selected = date.map(Into::into);
+        self
+    }
+
+    /// Sets the day marked as today, the current day by default.
+    #[must_use]
+    pub fn today(mut self, date: Option<Date>) -> Self {
+        self.today = date.map(Into::into);
+        self
+    }
+
+    /// Sets the width of the [`MonthView`](MonthView).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`MonthView`](MonthView).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the space between the edge of a day and its content.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the space between the events of a day.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the header and the numbers of the days.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the header and the numbers of the days.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MonthView`](MonthView).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the [`Grid`](Grid) of the month in the bounds.
+    fn grid(&self, renderer: &Renderer, bounds: Rectangle) -> Grid {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let header_height = text_size + 4.0 * self.padding;
+        let weekday_height = text_size + 2.0 * self.padding;
+        let weeks = weeks_of_month(self.year, self.month);
+
+        Grid {
+            first_day: first_day_of_grid(self.year, self.month),
+            weeks,
+            header: Rectangle {
+                height: header_height,
+                ..bounds
+            },
+            weekdays: Rectangle {
+                y: bounds.y + header_height,
+                height: weekday_height,
+                ..bounds
+            },
+            days: Rectangle {
+                y: bounds.y + header_height + weekday_height,
+                height: (bounds.height - header_height - weekday_height).max(0.0),
+                ..bounds
+            },
+            number_height: text_size + 2.0 * self.padding,
+        }
+    }
+
+    /// Returns the bounds of the arrows to the previous and the next month.
+    fn arrows(&self, grid: &Grid) -> Option<(Rectangle, Rectangle)> {
+        self.on_navigate.as_ref()?;
+
+        let header = grid.header;
+        let size = header.height;
+
+        Some((
+            Rectangle {
+                width: size,
+                ..header
+            },
+            Rectangle {
+                x: header.x + header.width - size,
+                width: size,
+                ..header
+            },
+        ))
+    }
+
+    /// Returns the index of the visible event at the cursor, if any.
+    fn event_at(&self, grid: &Grid, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        self.events
+            .iter()
+            .zip(layout.children())
+            .position(|(event, chip)| {
+                grid.is_visible(event.date, chip.bounds())
+                    && chip.bounds().contains(cursor_position)
+            })
+    }
+
+    /// Returns the first day of the month next to the shown one.
+    fn navigate(&self, forward: bool) -> Date {
+        let first = NaiveDate::from_ymd_opt(self.year, self.month, 1)
+            .expect("Year, Month or Day doesnt Exist");
+
+        if forward {
+            succ_month(first).into()
+        } else {
+            pred_month(first).into()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for MonthView<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.events
+            .iter()
+            .map(|event| Tree::new(&event.content))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self
+                .events
+                .iter()
+                .map(|event| &event.content)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let weeks = weeks_of_month(self.year, self.month) as f32;
+
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::new(
+            7.0 * DEFAULT_DAY_SIZE.width,
+            2.0 * text_size + 6.0 * self.padding + weeks * DEFAULT_DAY_SIZE.height,
+        ));
+
+        let grid = self.grid(renderer, Rectangle::new(Point::ORIGIN, size));
+        let day_size = grid.day_size();
+        let chip_width = (day_size.width - 2.0 * self.padding).max(0.0);
+
+        // The events of each day are stacked below its number
+        let mut stacked: Vec<f32> = vec![0.0; grid.weeks * 7];
+        let chips = self
+            .events
+            .iter()
+            .map(|event| {
+                let Some(index) = grid.index_of(event.date) else {
+                    return Node::with_children(Size::ZERO, vec![Node::new(Size::ZERO)]);
+                };
+
+                let mut content = event.content.as_widget().layout(
+                    renderer,
+                    &Limits::new(
+                        Size::ZERO,
+                        Size::new((chip_width - 2.0 * EVENT_PADDING).max(0.0), f32::INFINITY),
+                    ),
+                );
+                content.move_to(Point::new(EVENT_PADDING, EVENT_PADDING));
+
+                let day = grid.day_bounds(index);
+                let mut chip = Node::with_children(
+                    Size::new(chip_width, content.size().height + 2.0 * EVENT_PADDING),
+                    vec![content],
+                );
+                chip.move_to(Point::new(
+                    day.x + self.padding,
+                    day.y + grid.number_height + stacked[index],
+                ));
+                stacked[index] += chip.size().height + self.spacing;
+
+                chip
+            })
+            .collect();
+
+        Node::with_children(size, chips)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let grid = self.grid(renderer, bounds);
+
+        // The content of the visible events may be interactive on its own
+        let status = self
+            .events
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter(|((month_event, _), chip)| grid.is_visible(month_event.date, chip.bounds()))
+            .map(|((month_event, state), chip)| {
+                month_event.content.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    chip.children()
+                        .next()
+                        .expect("Native: Layout should have a content layout for an event."),
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                if let Some((previous, next)) = self.arrows(&grid) {
+                    if let Some(on_navigate) = &self.on_navigate {
+                        if previous.contains(cursor_position) {
+                            shell.publish(on_navigate(self.navigate(false)));
+                            return event::Status::Captured;
+                        }
+                        if next.contains(cursor_position) {
+                            shell.publish(on_navigate(self.navigate(true)));
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+
+                if let Some(index) = self.event_at(&grid, layout, cursor_position) {
+                    if let Some(message) = &self.events[index].on_press {
+                        shell.publish(message.clone());
+                        return event::Status::Captured;
+                    }
+                }
+
+                match (&self.on_day, grid.date_at(cursor_position)) {
+                    (Some(on_day), Some(date)) => {
+                        shell.publish(on_day(date.into()));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let grid = self.grid(renderer, layout.bounds());
+
+        let content = self
+            .events
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|((month_event, _), chip)| grid.is_visible(month_event.date, chip.bounds()))
+            .map(|((month_event, state), chip)| {
+                month_event.content.as_widget().mouse_interaction(
+                    state,
+                    chip.children()
+                        .next()
+                        .expect("Native: Layout should have a content layout for an event."),
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if content != mouse::Interaction::default() {
+            return content;
+        }
+
+        let on_arrow = self.arrows(&grid).map_or(false, |(previous, next)| {
+            previous.contains(cursor_position) || next.contains(cursor_position)
+        });
+        let on_event = self
+            .event_at(&grid, layout, cursor_position)
+            .map_or(false, |index| self.events[index].on_press.is_some());
+        let on_day = self.on_day.is_some() && grid.date_at(cursor_position).is_some();
+
+        if on_arrow || on_event || on_day {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let grid = self.grid(renderer, bounds);
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        // Header
+        let first = NaiveDate::from_ymd_opt(self.year, self.month, 1)
+            .expect("Year, Month or Day doesnt Exist");
+        renderer.fill_text(text::Text {
+            content: &format!("{} {}", month_as_string(first), year_as_string(first)),
+            bounds: Rectangle {
+                x: grid.header.center_x(),
+                y: grid.header.center_y(),
+                ..grid.header
+            },
+            size: text_size,
+            color: appearance.header_text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+
+        if let Some((previous, next)) = self.arrows(&grid) {
+            for (arrow, icon) in [
+                (previous, Icon::CaretLeftFill),
+                (next, Icon::CaretRightFill),
+            ] {
+                renderer.fill_glyph(
+                    char::from(icon),
+                    icon.font(),
+                    arrow,
+                    text_size,
+                    appearance.header_text_color,
+                );
+            }
+        }
+
+        // Weekdays
+        let day_size = grid.day_size();
+        for (column, label) in WEEKDAY_LABELS.iter().enumerate() {
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: grid.weekdays.x + (column as f32 + 0.5) * day_size.width,
+                    y: grid.weekdays.center_y(),
+                    width: day_size.width,
+                    height: grid.weekdays.height,
+                },
+                size: text_size,
+                color: appearance.weekday_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        // Days
+        let hovered = self
+            .on_day
+            .as_ref()
+            .and_then(|_| grid.date_at(cursor_position));
+        for index in 0..grid.weeks * 7 {
+            let date = grid.date_of(index);
+            let day = grid.day_bounds(index);
+
+            let background = if self.selected == Some(date) {
+                Some(appearance.selected_background)
+            } else if hovered == Some(date) {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: day,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            // The number of the day is centered in a badge marking today
+            let badge = Rectangle {
+                x: day.x + self.padding / 2.0,
+                y: day.y + self.padding / 2.0,
+                width: (text_size + self.padding).max(text_size * 1.4),
+                height: text_size + self.padding,
+            };
+            let color = if self.today == Some(date) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: badge,
+                        border_radius: (badge.height / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.today_background,
+                );
+                appearance.today_text_color
+            } else if date.month() == self.month {
+                appearance.day_text_color
+            } else {
+                appearance.outside_text_color
+            };
+
+            renderer.fill_text(text::Text {
+                content: &date.day().to_string(),
+                bounds: Rectangle {
+                    x: badge.center_x(),
+                    y: badge.center_y(),
+                    ..badge
+                },
+                size: text_size,
+                color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        // Grid lines
+        if appearance.grid_width > 0.0 {
+            let lines = (0..=grid.weeks)
+                .map(|row| Rectangle {
+                    y: grid.days.y + row as f32 * day_size.height - appearance.grid_width / 2.0,
+                    height: appearance.grid_width,
+                    ..grid.days
+                })
+                .chain((1..7).map(|column| Rectangle {
+                    x: grid.days.x + column as f32 * day_size.width - appearance.grid_width / 2.0,
+                    width: appearance.grid_width,
+                    ..grid.days
+                }));
+
+            for line in lines {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: line,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.grid_color,
+                );
+            }
+        }
+
+        // Events
+        let pressed = self.event_at(&grid, layout, cursor_position);
+        let mut hidden: Vec<usize> = vec![0; grid.weeks * 7];
+        for (position, ((month_event, state), chip)) in self
+            .events
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let Some(index) = grid.index_of(month_event.date) else {
+                continue;
+            };
+            let chip_bounds = chip.bounds();
+
+            if !grid.is_visible(month_event.date, chip_bounds) {
+                hidden[index] += 1;
+                continue;
+            }
+
+            let background = if pressed == Some(position) && month_event.on_press.is_some() {
+                appearance.event_hovered_background
+            } else {
+                appearance.event_background
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: chip_bounds,
+                    border_radius: appearance.event_border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            renderer.with_layer(chip_bounds, |renderer| {
+                month_event.content.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: appearance.event_text_color,
+                    },
+                    chip.children()
+                        .next()
+                        .expect("Native: Layout should have a content layout for an event."),
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+
+        // The events which do not fit are counted in the corner of their day
+        for (index, count) in hidden.into_iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+
+            let day = grid.day_bounds(index);
+            renderer.fill_text(text::Text {
+                content: &format!("+{count}"),
+                bounds: Rectangle {
+                    x: day.x + day.width - self.padding,
+                    y: day.y + grid.number_height / 2.0,
+                    ..day
+                },
+                size: text_size,
+                color: appearance.outside_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<MonthView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(month_view: MonthView<'a, Message, Renderer>) -> Self {
+        Element::new(month_view)
+    }
+}
+
+/// The areas of a [`MonthView`](MonthView).
+#[derive(Clone, Copy, Debug)]
+struct Grid {
+    /// The first day shown, the monday of the first week.
+    first_day: NaiveDate,
+    /// The number of weeks shown.
+    weeks: usize,
+    /// The bounds of the header showing the month and the arrows.
+    header: Rectangle,
+    /// The bounds of the names of the weekdays.
+    weekdays: Rectangle,
+    /// The bounds of the days.
+    days: Rectangle,
+    /// The height of the numbers of the days above their events.
+    number_height: f32,
+}
+
+impl Grid {
+    /// Returns the size of a day.
+    fn day_size(&self) -> Size {
+        Size::new(self.days.width / 7.0, self.days.height / self.weeks as f32)
+    }
+
+    /// Returns the bounds of the day at the index.
+    fn day_bounds(&self, index: usize) -> Rectangle {
+        let size = self.day_size();
+
+        Rectangle {
+            x: self.days.x + (index % 7) as f32 * size.width,
+            y: self.days.y + (index / 7) as f32 * size.height,
+            width: size.width,
+            height: size.height,
+        }
+    }
+
+    /// Returns the date of the day at the index.
+    fn date_of(&self, index: usize) -> NaiveDate {
+        self.first_day + Duration::days(index as i64)
+    }
+
+    /// Returns the index of the day of the date, if it is shown.
+    fn index_of(&self, date: NaiveDate) -> Option<usize> {
+        usize::try_from((date - self.first_day).num_days())
+            .ok()
+            .filter(|&index| index < self.weeks * 7)
+    }
+
+    /// Returns the date of the day at the cursor, if any.
+    fn date_at(&self, cursor_position: Point) -> Option<NaiveDate> {
+        if !self.days.contains(cursor_position) {
+            return None;
+        }
+
+        let size = self.day_size();
+        let column = (((cursor_position.x - self.days.x) / size.width) as usize).min(6);
+        let row = (((cursor_position.y - self.days.y) / size.height) as usize).min(self.weeks - 1);
+
+        Some(self.date_of(row * 7 + column))
+    }
+
+    /// Checks if the event of the date with the bounds fits into its day.
+    fn is_visible(&self, date: NaiveDate, bounds: Rectangle) -> bool {
+        self.index_of(date).map_or(false, |index| {
+            let day = self.day_bounds(index);
+            bounds.y + bounds.height <= day.y + day.height
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use iced_native::{renderer::Null, Point, Rectangle, Size};
+
+    use super::{Grid, MonthView};
+    use crate::core::date::{first_day_of_grid, weeks_of_month};
+
+    /// Creates the grid of the month with days of 100 by 100.
+    fn grid(year: i32, month: u32) -> Grid {
+        let weeks = weeks_of_month(year, month);
+
+        Grid {
+            first_day: first_day_of_grid(year, month),
+            weeks,
+            header: Rectangle::default(),
+            weekdays: Rectangle::default(),
+            days: Rectangle::new(Point::ORIGIN, Size::new(700.0, 100.0 * weeks as f32)),
+            number_height: 0.0,
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("Year, Month or Day doesnt Exist")
+    }
+
+    #[test]
+    fn month_starting_on_monday() {
+        let grid = grid(2023, 5);
+
+        assert_eq!(grid.weeks, 5);
+        assert_eq!(grid.date_of(0), date(2023, 5, 1));
+        assert_eq!(grid.index_of(date(2023, 5, 31)), Some(30));
+        assert_eq!(grid.index_of(date(2023, 4, 30)), None);
+        assert_eq!(grid.index_of(date(2023, 6, 4)), Some(34));
+        assert_eq!(grid.index_of(date(2023, 6, 5)), None);
+    }
+
+    #[test]
+    fn month_starting_on_sunday() {
+        let grid = grid(2023, 1);
+
+        assert_eq!(grid.weeks, 6);
+        assert_eq!(grid.date_of(0), date(2022, 12, 26));
+        assert_eq!(grid.date_of(6), date(2023, 1, 1));
+        assert_eq!(grid.index_of(date(2023, 1, 31)), Some(36));
+        assert_eq!(grid.date_of(41), date(2023, 2, 5));
+    }
+
+    #[test]
+    fn february_in_leap_year() {
+        let grid = grid(2024, 2);
+
+        assert_eq!(grid.weeks, 5);
+        assert_eq!(grid.date_of(3), date(2024, 2, 1));
+        assert_eq!(grid.index_of(date(2024, 2, 29)), Some(31));
+        assert_eq!(grid.date_of(32), date(2024, 3, 1));
+
+        // Without a leap day the month fits into four weeks
+        assert_eq!(weeks_of_month(2021, 2), 4);
+    }
+
+    #[test]
+    fn date_at_cursor() {
+        let grid = grid(2023, 5);
+
+        assert_eq!(grid.date_at(Point::new(50.0, 50.0)), Some(date(2023, 5, 1)));
+        assert_eq!(
+            grid.date_at(Point::new(650.0, 450.0)),
+            Some(date(2023, 6, 4))
+        );
+        assert_eq!(
+            grid.date_at(Point::new(250.0, 150.0)),
+            Some(date(2023, 5, 10))
+        );
+        assert_eq!(grid.date_at(Point::new(750.0, 50.0)), None);
+    }
+
+    #[test]
+    fn navigate_across_years() {
+        let december = MonthView::<(), Null>::new(2023, 12);
+        let next = december.navigate(true);
+        assert_eq!((next.year, next.month, next.day), (2024, 1, 1));
+        let previous = december.navigate(false);
+        assert_eq!((previous.year, previous.month, previous.day), (2023, 11, 1));
+
+        let january = MonthView::<(), Null>::new(2024, 1);
+        let previous = january.navigate(false);
+        assert_eq!((previous.year, previous.month, previous.day), (2023, 12, 1));
+    }
+}
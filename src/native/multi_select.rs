@@ -0,0 +1,451 @@
+//! Pick any number of options from a dropdown of checkboxes.
+//!
+//! *This API requires the following crate features to be activated: multi_select*
+use std::fmt::Display;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::tree::{self, Tree},
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    native::overlay::MultiSelectOverlay,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::multi_select::{Appearance, StyleSheet};
+
+/// The default padding around the text of the field and of the options.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default number of options shown at once in the dropdown.
+const DEFAULT_VISIBLE_OPTIONS: usize = 6;
+
+/// A dropdown of checkboxes to pick any number of options.
+///
+/// The closed field shows the chosen options, summing up the ones that do not
+/// fit as "+N". Each toggled checkbox produces the updated selection, in which
+/// a newly chosen option comes last.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::multi_select;
+/// #
+/// # pub type MultiSelect<'a, T, Message> = multi_select::MultiSelect<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Selected(Vec<&'static str>),
+/// }
+///
+/// let toppings = ["Cheese", "Ham", "Mushrooms", "Olives"];
+/// let selected = vec!["Cheese", "Olives"];
+///
+/// let multi_select = MultiSelect::new(&toppings, &selected, Message::Selected)
+///     .placeholder("Toppings");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MultiSelect<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The options to pick from.
+    options: &'a [T],
+    /// The chosen options.
+    selected: &'a [T],
+    /// The function producing the message with the updated selection.
+    on_change: Box<dyn Fn(Vec<T>) -> Message + 'a>,
+    /// The text shown while nothing is chosen.
+    placeholder: String,
+    /// The width of the field.
+    width: Length,
+    /// The padding around the text of the field and of the options.
+    padding: f32,
+    /// The number of options shown at once in the dropdown.
+    visible_options: usize,
+    /// The text size of the field and of the options.
+    text_size: Option<f32>,
+    /// The font of the field and of the options.
+    font: Font,
+    /// The style of the multi select.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> MultiSelect<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MultiSelect`](MultiSelect).
+    ///
+    /// It expects:
+    ///     * the options to pick from
+    ///     * the chosen options
+    ///     * the function producing the message with the updated selection
+    pub fn new<F>(options: &'a [T], selected: &'a [T], on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<T>) -> Message,
+    {
+        MultiSelect {
+            options,
+            selected,
+            on_change: Box::new(on_change),
+            placeholder: String::new(),
+            width: Length::Fill,
+            padding: DEFAULT_PADDING,
+            visible_options: DEFAULT_VISIBLE_OPTIONS,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the text shown while nothing is chosen.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the [`MultiSelect`](MultiSelect).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding around the text of the field and of the options.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the number of options shown at once in the dropdown.
+    ///
+    /// The dropdown scrolls through the other ones.
+    #[must_use]
+    pub fn visible_options(mut self, visible_options: usize) -> Self {
+        self.visible_options = visible_options.max(1);
+        self
+    }
+
+    /// Sets the text size of the [`MultiSelect`](MultiSelect).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`MultiSelect`](MultiSelect).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MultiSelect`](MultiSelect).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for MultiSelect<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let text_width = self
+            .options
+            .iter()
+            .map(|option| renderer.measure_width(&option.to_string(), text_size, self.font))
+            .fold(
+                renderer.measure_width(&self.placeholder, text_size, self.font),
+                f32::max,
+            );
+
+        // The caret is as wide as the text is high
+        let intrinsic = Size::new(
+            text_width + text_size + 3.0 * self.padding,
+            text_size + 2.0 * self.padding,
+        );
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        Node::new(limits.resolve(intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // The dropdown captures the presses on its options, so any
+                // other press closes it
+                if state.is_open {
+                    state.is_open = false;
+                    event::Status::Captured
+                } else if layout.bounds().contains(cursor_position) {
+                    *state = State {
+                        is_open: true,
+                        ..State::default()
+                    };
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                state.is_open,
+                false,
+                false,
+            ),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let text_bounds = Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.center_y(),
+            width: (bounds.width - text_size - 3.0 * self.padding).max(0.0),
+            height: bounds.height,
+        };
+
+        // The chosen options are listed in the order of the options
+        let labels: Vec<String> = self
+            .options
+            .iter()
+            .filter(|&option| self.selected.contains(option))
+            .map(ToString::to_string)
+            .collect();
+        let (content, color) = if labels.is_empty() {
+            (self.placeholder.clone(), appearance.placeholder_color)
+        } else {
+            let content = summary(&labels, text_bounds.width, |content| {
+                renderer.measure_width(content, text_size, self.font)
+            });
+
+            (content, appearance.text_color)
+        };
+
+        renderer.with_layer(
+            Rectangle {
+                y: bounds.y,
+                ..text_bounds
+            },
+            |renderer| {
+                renderer.fill_text(text::Text {
+                    content: &content,
+                    bounds: text_bounds,
+                    size: text_size,
+                    color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            },
+        );
+
+        renderer.fill_glyph(
+            char::from(Icon::CaretDownFill),
+            Icon::CaretDownFill.font(),
+            Rectangle {
+                x: bounds.x + bounds.width - self.padding - text_size,
+                y: bounds.y,
+                width: text_size,
+                height: bounds.height,
+            },
+            text_size,
+            appearance.icon_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open || self.options.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(
+            MultiSelectOverlay::new(
+                self.options,
+                self.selected,
+                state,
+                self.on_change.as_ref(),
+                bounds.size(),
+                self.padding,
+                self.visible_options,
+                self.text_size,
+                self.font,
+                self.style,
+            )
+            .overlay(bounds.position()),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<MultiSelect<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(multi_select: MultiSelect<'a, T, Message, Renderer>) -> Self {
+        Element::new(multi_select)
+    }
+}
+
+/// The state of a [`MultiSelect`](MultiSelect).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the dropdown is open.
+    pub(crate) is_open: bool,
+    /// The index of the hovered option.
+    pub(crate) hovered: Option<usize>,
+    /// The index of the first option shown in the dropdown.
+    pub(crate) offset: usize,
+}
+
+/// Returns the text listing as many labels as fit the width, followed by a
+/// "+N" summary of the other ones.
+fn summary(labels: &[String], width: f32, measure: impl Fn(&str) -> f32) -> String {
+    (0..=labels.len())
+        .rev()
+        .map(|shown| {
+            let listed = labels[..shown].join(", ");
+
+            match labels.len() - shown {
+                0 => listed,
+                hidden if listed.is_empty() => format!("+{hidden}"),
+                hidden => format!("{listed} +{hidden}"),
+            }
+        })
+        .find(|content| measure(content) <= width)
+        .unwrap_or_else(|| format!("+{}", labels.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::summary;
+
+    fn labels() -> Vec<String> {
+        ["Cheese", "Ham", "Olives"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn measure(content: &str) -> f32 {
+        content.chars().count() as f32
+    }
+
+    #[test]
+    fn summary_lists_all_labels_that_fit() {
+        assert_eq!(summary(&labels(), 100.0, measure), "Cheese, Ham, Olives");
+        assert_eq!(summary(&[], 0.0, measure), "");
+    }
+
+    #[test]
+    fn summary_sums_up_the_hidden_labels() {
+        assert_eq!(summary(&labels(), 18.0, measure), "Cheese, Ham +1");
+        assert_eq!(summary(&labels(), 9.0, measure), "Cheese +2");
+        assert_eq!(summary(&labels(), 2.0, measure), "+3");
+        assert_eq!(summary(&labels(), 0.0, measure), "+3");
+    }
+}
@@ -17,7 +17,9 @@ use iced_native::{
     Rectangle, Shell, Size, Widget,
 };
 use num_traits::{Num, NumAssignOps};
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, rc::Rc, str::FromStr};
+
+use crate::style::style_state::{appearance, StyleState};
 
 pub use crate::{
     graphics::icons::Icon,
@@ -72,7 +74,7 @@ where
     /// The underlying element of the [`NumberInput`](NumberInput).
     content: TextInput<'a, Message, Renderer>,
     /// The on_change event of the [`NumberInput`](NumberInput).
-    on_change: Box<dyn Fn(T) -> Message>,
+    on_change: Rc<dyn Fn(T) -> Message>,
     /// The style of the [`NumberInput`](NumberInput).
     style: <Renderer::Theme as number_input::StyleSheet>::Style,
     /// The font text of the [`NumberInput`](NumberInput).
@@ -98,12 +100,14 @@ where
     /// - a function that produces a message when the [`NumberInput`] changes
     pub fn new<F>(value: T, max: T, on_changed: F) -> Self
     where
-        F: 'static + Fn(T) -> Message + Copy,
+        F: 'static + Fn(T) -> Message,
         T: 'static,
     {
         let padding = DEFAULT_PADDING;
+        let on_change: Rc<dyn Fn(T) -> Message> = Rc::new(on_changed);
+        let on_input = Rc::clone(&on_change);
         let convert_to_num = move |s: String| {
-            on_changed(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
+            on_input(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
         };
 
         Self {
@@ -116,7 +120,7 @@ where
                 .on_input(convert_to_num)
                 .padding(padding)
                 .width(Length::Fixed(127.0)),
-            on_change: Box::new(on_changed),
+            on_change,
             style: <Renderer::Theme as number_input::StyleSheet>::Style::default(),
             font: iced_graphics::Font::default(),
         }
@@ -191,6 +195,14 @@ where
         self
     }
 
+    /// Sets the [`Id`](text_input::Id) of the [`NumberInput`] to focus it
+    /// with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.content = self.content.id(id);
+        self
+    }
+
     /// Sets the message that should be produced when the [`NumberInput`] is
     /// focused and the enter key is pressed.
     #[must_use]
@@ -668,24 +680,33 @@ where
             cursor_position,
             None,
         );
+        let modifier_state = state.state.downcast_ref::<ModifierState>();
         let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
         let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
 
-        let decrease_btn_style = if is_decrease_disabled {
-            theme.disabled(self.style)
-        } else if state.state.downcast_ref::<ModifierState>().decrease_pressed {
-            theme.pressed(self.style)
-        } else {
-            theme.active(self.style)
-        };
+        let decrease_btn_style = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                dec_bounds.contains(cursor_position),
+                modifier_state.decrease_pressed,
+                false,
+                is_decrease_disabled,
+            ),
+            self.style,
+        );
 
-        let increase_btn_style = if is_increase_disabled {
-            theme.disabled(self.style)
-        } else if state.state.downcast_ref::<ModifierState>().increase_pressed {
-            theme.pressed(self.style)
-        } else {
-            theme.active(self.style)
-        };
+        let increase_btn_style = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                inc_bounds.contains(cursor_position),
+                modifier_state.increase_pressed,
+                false,
+                is_increase_disabled,
+            ),
+            self.style,
+        );
 
         // decrease button section
         renderer.fill_quad(
@@ -0,0 +1,193 @@
+//! Operate the widgets of this crate from the application.
+//!
+//! The commands of this module find a widget by its
+//! [`Id`](iced_native::widget::Id) and act on it, e.g. open the overlay of a
+//! [`DatePicker`](crate::native::date_picker::DatePicker) or scroll an option
+//! of a [`SelectionList`](crate::native::selection_list::SelectionList) into
+//! view, without synthesizing events. Focusing widgets is done by the
+//! commands of [`focus`](super::focus).
+//!
+//! # Example
+//! ```ignore
+//! fn update(&mut self, message: Message) -> Command<Message> {
+//!     match message {
+//!         Message::PickDate => operation::open(DATE_PICKER.clone()),
+//!         ...
+//!     }
+//! }
+//! ```
+use std::any::Any;
+
+use iced_native::{widget::Operation, Command};
+
+#[doc(no_inline)]
+pub use iced_native::widget::Id;
+
+/// An action performed on a widget by a command of this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Opens the overlay of the widget.
+    Open,
+    /// Closes the overlay of the widget.
+    Close,
+    /// Scrolls the option at the index into view.
+    Reveal(usize),
+    /// Scrolls the row at the index to the top.
+    ScrollTo(usize),
+    /// Zooms the image to the level.
+    Zoom(Zoom),
+}
+
+/// A zoom level of a widget showing an image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Zoom {
+    /// The whole image fits into the widget.
+    #[default]
+    Fit,
+    /// The image fills the widget, cropping what does not fit.
+    Fill,
+    /// Each pixel of the image takes one unit of the widget.
+    Original,
+}
+
+/// The request a widget hands to the operations to receive an
+/// [`Action`](Action).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Request {
+    /// The action the widget is asked to perform.
+    action: Option<Action>,
+}
+
+impl Request {
+    /// Creates a new empty [`Request`](Request).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { action: None }
+    }
+
+    /// Returns the [`Action`](Action) the widget is asked to perform.
+    #[must_use]
+    pub const fn action(&self) -> Option<Action> {
+        self.action
+    }
+
+    /// Returns whether the overlay of a widget is open after the request,
+    /// given whether it was open before.
+    #[must_use]
+    pub const fn is_open(&self, was_open: bool) -> bool {
+        match self.action {
+            Some(Action::Open) => true,
+            Some(Action::Close) => false,
+            _ => was_open,
+        }
+    }
+
+    /// Hands the [`Request`](Request) to the operation and returns it with the
+    /// [`Action`](Action) for the widget with the
+    /// [`Id`](iced_native::widget::Id), if any.
+    pub fn receive<Message>(operation: &mut dyn Operation<Message>, id: Option<&Id>) -> Self {
+        let mut request = Self::new();
+        operation.custom(&mut request, id);
+        request
+    }
+}
+
+/// The [`Operation`](iced_native::widget::Operation) performing an
+/// [`Action`](Action) on the widget with the [`Id`](iced_native::widget::Id).
+#[derive(Debug)]
+struct Perform {
+    /// The id of the widget.
+    target: Id,
+    /// The action to perform.
+    action: Action,
+}
+
+impl<T> Operation<T> for Perform {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.target) {
+            return;
+        }
+
+        if let Some(request) = state.downcast_mut::<Request>() {
+            request.action = Some(self.action);
+        }
+    }
+}
+
+/// Produces an [`Operation`](iced_native::widget::Operation) performing the
+/// [`Action`](Action) on the widget with the given
+/// [`Id`](iced_native::widget::Id).
+#[must_use]
+pub fn perform<T>(id: Id, action: Action) -> impl Operation<T> {
+    Perform { target: id, action }
+}
+
+/// Produces a [`Command`](iced_native::Command) opening the overlay of the
+/// widget with the given [`Id`](iced_native::widget::Id).
+#[must_use]
+pub fn open<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(perform(id, Action::Open))
+}
+
+/// Produces a [`Command`](iced_native::Command) closing the overlay of the
+/// widget with the given [`Id`](iced_native::widget::Id).
+#[must_use]
+pub fn close<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(perform(id, Action::Close))
+}
+
+/// Produces a [`Command`](iced_native::Command) scrolling the option at the
+/// index of the widget with the given [`Id`](iced_native::widget::Id) into
+/// view.
+#[must_use]
+pub fn reveal<Message: 'static>(id: Id, index: usize) -> Command<Message> {
+    Command::widget(perform(id, Action::Reveal(index)))
+}
+
+/// Produces a [`Command`](iced_native::Command) scrolling the row at the index
+/// of the widget with the given [`Id`](iced_native::widget::Id) to the top,
+/// as far as it can be scrolled.
+#[must_use]
+pub fn scroll_to<Message: 'static>(id: Id, index: usize) -> Command<Message> {
+    Command::widget(perform(id, Action::ScrollTo(index)))
+}
+
+/// Produces a [`Command`](iced_native::Command) zooming the image of the
+/// widget with the given [`Id`](iced_native::widget::Id) to the
+/// [`Zoom`](Zoom) level.
+#[must_use]
+pub fn zoom<Message: 'static>(id: Id, zoom: Zoom) -> Command<Message> {
+    Command::widget(perform(id, Action::Zoom(zoom)))
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::widget::Id;
+
+    use super::{perform, Action, Request};
+
+    #[test]
+    fn reaches_only_the_target() {
+        let id = Id::new("picker");
+        let mut operation = perform::<()>(id.clone(), Action::Open);
+
+        let other = Request::receive(&mut operation, Some(&Id::new("other")));
+        assert_eq!(other.action(), None);
+        assert!(!other.is_open(false));
+
+        let target = Request::receive(&mut operation, Some(&id));
+        assert_eq!(target.action(), Some(Action::Open));
+        assert!(target.is_open(false));
+
+        let unnamed = Request::receive(&mut operation, None);
+        assert_eq!(unnamed.action(), None);
+    }
+}
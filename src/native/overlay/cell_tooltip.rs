@@ -0,0 +1,153 @@
+//! Show the value of the hovered cell of a heatmap in a tooltip.
+//!
+//! *This API requires the following crate features to be activated: calendar_heatmap or heatmap*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    layout::Node,
+    overlay, renderer, text, Alignment, Background, Color, Font, Layout, Point, Rectangle, Size,
+};
+
+use crate::{
+    core::overlay::{Placement, Position},
+    style::style_state::StyleState,
+};
+
+/// The padding between the bubble and the text of the tooltip.
+const PADDING: f32 = 6.0;
+
+/// The gap between the hovered cell and the tooltip.
+const GAP: f32 = 4.0;
+
+/// The appearance of the bubble of a [`CellTooltipOverlay`](CellTooltipOverlay),
+/// taken from the appearance of the heatmap showing it.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Bubble {
+    /// The background of the bubble.
+    pub background: Background,
+    /// The border radius of the bubble.
+    pub border_radius: f32,
+    /// The color of the text.
+    pub text_color: Color,
+}
+
+/// The tooltip of the hovered cell of a
+/// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap) or a
+/// [`Heatmap`](crate::native::heatmap::Heatmap).
+#[allow(missing_debug_implementations)]
+pub struct CellTooltipOverlay<'a, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+{
+    /// The text of the tooltip.
+    content: String,
+    /// The bounds of the hovered cell.
+    anchor: Rectangle,
+    /// The text size of the tooltip.
+    text_size: f32,
+    /// The font of the tooltip.
+    font: Font,
+    /// The appearance of the bubble in the state of the tooltip, resolved by
+    /// the style sheet of the heatmap.
+    bubble: Box<dyn Fn(&Renderer::Theme, StyleState) -> Bubble + 'a>,
+}
+
+impl<'a, Renderer> CellTooltipOverlay<'a, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+{
+    /// Creates a new [`CellTooltipOverlay`](CellTooltipOverlay) showing the
+    /// content above the anchor.
+    pub(crate) fn new<B>(
+        content: String,
+        anchor: Rectangle,
+        text_size: f32,
+        font: Font,
+        bubble: B,
+    ) -> Self
+    where
+        B: 'a + Fn(&Renderer::Theme, StyleState) -> Bubble,
+    {
+        CellTooltipOverlay {
+            content,
+            anchor,
+            text_size,
+            font,
+            bubble: Box::new(bubble),
+        }
+    }
+
+    /// Turn this [`CellTooltipOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element).
+    pub fn overlay<Message>(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.anchor.position(), Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for CellTooltipOverlay<'a, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let width = renderer.measure_width(&self.content, self.text_size, self.font);
+
+        let mut node = Node::new(Size::new(
+            width + 2.0 * PADDING,
+            self.text_size + 2.0 * PADDING,
+        ));
+        // The position follows the cell if the heatmap is translated, for
+        // example by a scrollable
+        let anchor = Rectangle::new(position, self.anchor.size());
+        node.place(anchor, Placement::Top, Alignment::Center, GAP, bounds);
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let bubble = (self.bubble)(
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: bubble.border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            bubble.background,
+        );
+
+        renderer.fill_text(text::Text {
+            content: &self.content,
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: self.text_size,
+            color: bubble.text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // The tooltip never covers the cell it belongs to, so it lets the
+        // cursor through
+        false
+    }
+}
@@ -25,7 +25,7 @@ use crate::{
     color_picker,
     core::{
         color::{HexString, Hsv},
-        overlay::Position,
+        overlay::{Dismiss, Position},
     },
     graphics::icons::Icon,
     native::IconText,
@@ -62,6 +62,9 @@ where
     cancel_button: Button<'a, Message, Renderer<B, Theme>>,
     /// The submit button of the [`ColorPickerOverlay`](ColorPickerOverlay).
     submit_button: Button<'a, Message, Renderer<B, Theme>>,
+    /// The message that is send when the [`ColorPickerOverlay`](ColorPickerOverlay) is
+    /// dismissed by clicking outside of it or pressing the ESC key.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`](ColorPickerOverlay).
     on_submit: &'a dyn Fn(Color) -> Message,
     /// The position of the [`ColorPickerOverlay`](ColorPickerOverlay).
@@ -98,7 +101,8 @@ where
                 .on_press(on_cancel.clone()),
             submit_button: Button::new(IconText::new(Icon::Check).width(Length::Fill))
                 .width(Length::Fill)
-                .on_press(on_cancel), // Sending a fake message
+                .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
             position,
             style,
@@ -596,6 +600,12 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        if let Some(dismiss) = Dismiss::from_event(&event, cursor_position, layout.bounds(), &[]) {
+            self.state.is_opened = false;
+            shell.publish(self.on_cancel.clone());
+            return dismiss.status();
+        }
+
         if event::Status::Captured
             == self.on_event_keyboard(&event, layout, cursor_position, shell, renderer, clipboard)
         {
@@ -652,6 +662,8 @@ where
         let cancel_button_layout = block2_children
             .next()
             .expect("Native: Layout should have a cancel button layout for a ColorPicker");
+        let mut cancel_messages: Vec<Message> = Vec::new();
+
         let cancel_button_status = self.cancel_button.on_event(
             &mut self.tree.children[0],
             event.clone(),
@@ -659,9 +671,14 @@ where
             cursor_position,
             renderer,
             clipboard,
-            shell,
+            &mut Shell::new(&mut cancel_messages),
         );
 
+        if !cancel_messages.is_empty() {
+            self.state.is_opened = false;
+            shell.publish(self.on_cancel.clone());
+        }
+
         let submit_button_layout = block2_children
             .next()
             .expect("Native: Layout should have a submit button layout for a ColorPicker");
@@ -676,6 +693,7 @@ where
         );
 
         if !fake_messages.is_empty() {
+            self.state.is_opened = false;
             shell.publish((self.on_submit)(self.state.color));
         }
         // ----------- Block 2 end ------------------
@@ -1587,19 +1605,31 @@ fn hex_text<B, Theme>(
 
 /// The state of the [`ColorPickerOverlay`](ColorPickerOverlay).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct State {
     /// The selected color of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub(crate) color: Color,
     /// The cache of the sat/value canvas of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) sat_value_canvas_cache: canvas::Cache,
     /// The cache of the hue canvas of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) hue_canvas_cache: canvas::Cache,
     /// The dragged color bar of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) color_bar_dragged: ColorBarDragged,
     /// the focus of the [`ColorPickerOverlay`](ColorPickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// If the [`ColorPickerOverlay`](ColorPickerOverlay) was opened by the application
+    /// with [`open`](crate::native::operation::open).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) is_opened: bool,
 }
 
 impl State {
@@ -1622,6 +1652,7 @@ impl Default for State {
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_opened: false,
         }
     }
 }
@@ -0,0 +1,360 @@
+//! Type into a field and pick one of the options matching the text.
+//!
+//! *This API requires the following crate features to be activated: combo_box*
+use std::fmt::Display;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::Node,
+    mouse, overlay, renderer, text, touch, Clipboard, Color, Event, Font, Layout, Point, Rectangle,
+    Shell, Size,
+};
+
+use crate::{
+    native::combo_box::{Match, State},
+    style::{
+        combo_box::StyleSheet,
+        style_state::{appearance, StyleState},
+    },
+};
+
+/// The padding around the label of an option.
+const OPTION_PADDING: f32 = 5.0;
+
+/// The dropdown of a [`ComboBox`](crate::native::combo_box::ComboBox).
+#[allow(missing_debug_implementations)]
+pub struct ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The options to pick from.
+    options: &'a [T],
+    /// The options containing the typed text.
+    matches: Vec<Match>,
+    /// The state shared between the
+    /// [`ComboBox`](crate::native::combo_box::ComboBox) and the dropdown.
+    state: &'a mut State,
+    /// The function producing the message when the user picks an option.
+    on_select: &'a dyn Fn(T) -> Message,
+    /// The function producing the message emptying the text once an option
+    /// was picked, if the text is emptied.
+    on_input: Option<&'a dyn Fn(String) -> Message>,
+    /// The size of the field the dropdown opens at.
+    anchor: Size,
+    /// The number of options shown at once.
+    visible_options: usize,
+    /// The text size of the options.
+    text_size: Option<f32>,
+    /// The font of the options.
+    font: Font,
+    /// The style of the dropdown.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ComboBoxOverlay`](ComboBoxOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        options: &'a [T],
+        matches: Vec<Match>,
+        state: &'a mut State,
+        on_select: &'a dyn Fn(T) -> Message,
+        on_input: Option<&'a dyn Fn(String) -> Message>,
+        anchor: Size,
+        visible_options: usize,
+        text_size: Option<f32>,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        // The options may have changed since the dropdown was last shown
+        state.highlighted = state
+            .highlighted
+            .filter(|&position| position < matches.len());
+        state.offset = state
+            .offset
+            .min(matches.len().saturating_sub(visible_options));
+
+        ComboBoxOverlay {
+            options,
+            matches,
+            state,
+            on_select,
+            on_input,
+            anchor,
+            visible_options,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`ComboBoxOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) opening at the field at the
+    /// given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the number of options shown at once.
+    fn rows(&self) -> usize {
+        self.matches.len().min(self.visible_options)
+    }
+
+    /// Returns the position among the matching options of the option at the
+    /// cursor, if any.
+    fn option_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let row_height = bounds.height / self.rows() as f32;
+        let row = ((cursor_position.y - bounds.y) / row_height) as usize;
+
+        Some(self.state.offset + row).filter(|&position| position < self.matches.len())
+    }
+
+    /// Highlights the option at the position and scrolls it into view.
+    fn highlight(&mut self, position: usize) {
+        let rows = self.rows();
+
+        self.state.highlighted = Some(position);
+        if position < self.state.offset {
+            self.state.offset = position;
+        } else if position >= self.state.offset + rows {
+            self.state.offset = position + 1 - rows;
+        }
+    }
+
+    /// Picks the option at the position and closes the dropdown.
+    fn select(&mut self, position: usize, shell: &mut Shell<Message>) {
+        let option = &self.matches[position];
+        shell.publish((self.on_select)(self.options[option.index].clone()));
+
+        // The text usually becomes the picked option or is emptied, which
+        // keeps the dropdown closed
+        if let Some(on_input) = self.on_input {
+            shell.publish(on_input(String::new()));
+            self.state.value.clear();
+        } else {
+            self.state.value = option.label.clone();
+        }
+        self.state.dismissed = true;
+    }
+}
+
+impl<'a, T, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for ComboBoxOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = self.rows() as f32 * (text_size + 2.0 * OPTION_PADDING);
+
+        // The dropdown opens above the field if there is no room below it
+        let below = position.y + self.anchor.height;
+        let y = if below + height > bounds.height && position.y >= height {
+            position.y - height
+        } else {
+            below
+        };
+
+        let mut node = Node::new(Size::new(self.anchor.width, height));
+        node.move_to(Point::new(position.x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                let last = self.matches.len() - 1;
+
+                match (key_code, self.state.highlighted) {
+                    (keyboard::KeyCode::Down, highlighted) => {
+                        self.highlight(
+                            highlighted.map_or(0, |position| (position + 1) % self.matches.len()),
+                        );
+                    }
+                    (keyboard::KeyCode::Up, highlighted) => {
+                        self.highlight(
+                            highlighted
+                                .map_or(last, |position| position.checked_sub(1).unwrap_or(last)),
+                        );
+                    }
+                    (keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter, Some(position)) => {
+                        self.select(position, shell);
+                    }
+                    (keyboard::KeyCode::Escape, _) => {
+                        self.state.dismissed = true;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(position) = self.option_at(bounds, cursor_position) {
+                    self.state.highlighted = Some(position);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.option_at(bounds, cursor_position) {
+                    Some(position) => {
+                        self.select(position, shell);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let row_height = bounds.height / self.rows() as f32;
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / row_height,
+                }
+                .round();
+                let max = self.matches.len() - self.rows();
+
+                self.state.offset = if rows > 0.0 {
+                    self.state.offset.saturating_sub(rows as usize)
+                } else {
+                    (self.state.offset + (-rows) as usize).min(max)
+                };
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.option_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let row_height = bounds.height / self.rows() as f32;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let shown = self.matches.iter().enumerate().skip(self.state.offset);
+
+        for (row, (position, option)) in shown.take(self.rows()).enumerate() {
+            let row_bounds = Rectangle {
+                y: bounds.y + row as f32 * row_height,
+                height: row_height,
+                ..bounds
+            };
+            let highlighted = self.state.highlighted == Some(position);
+            let text_color = if highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.highlighted_background,
+                );
+
+                appearance.highlighted_text_color
+            } else {
+                appearance.text_color
+            };
+
+            // The label is drawn in three parts to set the match apart
+            let label = &option.label;
+            let parts = [
+                (&label[..option.range.start], text_color),
+                (&label[option.range.clone()], appearance.match_color),
+                (&label[option.range.end..], text_color),
+            ];
+
+            renderer.with_layer(row_bounds, |renderer| {
+                let mut x = row_bounds.x + OPTION_PADDING;
+
+                for (content, color) in parts {
+                    if content.is_empty() {
+                        continue;
+                    }
+
+                    renderer.fill_text(text::Text {
+                        content,
+                        bounds: Rectangle {
+                            x,
+                            y: row_bounds.center_y(),
+                            ..row_bounds
+                        },
+                        size: text_size,
+                        color,
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Center,
+                    });
+
+                    x += renderer.measure_width(content, text_size, self.font);
+                }
+            });
+        }
+    }
+}
@@ -2,11 +2,12 @@
 //!
 //! *This API requires the following crate features to be activated: ``context_menu``*
 use crate::context_menu;
+use crate::core::overlay::Dismiss;
 use crate::style::context_menu::StyleSheet;
+use crate::style::style_state::{appearance, StyleState};
 use iced_native::event::Status;
 use iced_native::{
-    keyboard, layout::Limits, mouse, overlay, renderer, touch, Clipboard, Color, Event, Layout,
-    Point, Shell, Size,
+    layout::Limits, mouse, overlay, renderer, Clipboard, Color, Event, Layout, Point, Shell, Size,
 };
 use iced_native::{widget::Tree, Element};
 
@@ -22,6 +23,9 @@ where
     tree: &'a mut Tree,
     /// The content of the [`ContextMenuOverlay`](ContextMenuOverlay).
     content: Element<'a, Message, Renderer>,
+    /// The optional message that will be send when the menu is closed by
+    /// clicking outside of it or pressing the ESC key.
+    on_dismiss: Option<Message>,
     /// The style of the [`ContextMenuOverlay`](ContextMenuOverlay).
     style: <Renderer::Theme as StyleSheet>::Style,
     /// The state shared between [`ContextMenu`](crate::native::ContextMenu) and [`ContextMenuOverlay`](ContextMenuOverlay).
@@ -38,6 +42,7 @@ where
     pub(crate) fn new<C>(
         tree: &'a mut Tree,
         content: C,
+        on_dismiss: Option<Message>,
         style: <Renderer::Theme as StyleSheet>::Style,
         state: &'a mut context_menu::State,
     ) -> Self
@@ -47,6 +52,7 @@ where
         ContextMenuOverlay {
             tree,
             content: content.into(),
+            on_dismiss,
             style,
             state,
         }
@@ -90,8 +96,22 @@ where
         cursor_position: Point,
     ) {
         let bounds = layout.bounds();
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a content layout.");
 
-        let style_sheet = theme.active(self.style);
+        let style_sheet = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                content_layout.bounds().contains(cursor_position),
+                false,
+                false,
+                false,
+            ),
+            self.style
+        );
 
         // Background
         renderer.fill_quad(
@@ -104,11 +124,6 @@ where
             style_sheet.background,
         );
 
-        let content_layout = layout
-            .children()
-            .next()
-            .expect("Native: Layout should have a content layout.");
-
         // Modal
         self.content.as_widget().draw(
             self.tree,
@@ -135,40 +150,28 @@ where
             .next()
             .expect("Native: Layout should have a content layout.");
 
-        let status = match event {
-            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
-                if key_code == keyboard::KeyCode::Escape {
-                    self.state.show = false;
-                    Status::Captured
-                } else {
-                    Status::Ignored
-                }
-            }
-
-            Event::Mouse(mouse::Event::ButtonPressed(
-                mouse::Button::Left | mouse::Button::Right,
-            ))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout_children.bounds().contains(cursor_position) {
-                    Status::Ignored
-                } else {
+        let status =
+            match Dismiss::from_event(&event, cursor_position, layout_children.bounds(), &[]) {
+                Some(_) => {
                     self.state.show = false;
+                    if let Some(on_dismiss) = &self.on_dismiss {
+                        shell.publish(on_dismiss.clone());
+                    }
                     Status::Captured
                 }
-            }
-
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                // close when released because because button send message on release
-                self.state.show = false;
-                if layout_children.bounds().contains(cursor_position) {
-                    Status::Ignored
-                } else {
-                    Status::Captured
-                }
-            }
-
-            _ => Status::Ignored,
-        };
+                None => match event {
+                    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                        // close when released because because button send message on release
+                        self.state.show = false;
+                        if layout_children.bounds().contains(cursor_position) {
+                            Status::Ignored
+                        } else {
+                            Status::Captured
+                        }
+                    }
+                    _ => Status::Ignored,
+                },
+            };
 
         match status {
             Status::Ignored => self.content.as_widget_mut().on_event(
@@ -184,6 +187,22 @@ where
         }
     }
 
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        // Overlays of the content, like menus, are shown on top of it
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+        )
+    }
+
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
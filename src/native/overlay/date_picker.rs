@@ -20,7 +20,7 @@ use iced_native::{
 use crate::{
     core::{
         date::{Date, IsInMonth},
-        overlay::Position,
+        overlay::{Dismiss, Position},
     },
     date_picker,
     graphics::icons::{Icon, ICON_FONT},
@@ -53,6 +53,9 @@ where
     cancel_button: Button<'a, Message, Renderer<B, Theme>>,
     /// The submit button of the [`DatePickerOverlay`](DatePickerOverlay).
     submit_button: Button<'a, Message, Renderer<B, Theme>>,
+    /// The message that is send when the [`DatePickerOverlay`](DatePickerOverlay) is
+    /// dismissed by clicking outside of it or pressing the ESC key.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`](DatePickerOverlay) is pressed.
     on_submit: &'a dyn Fn(Date) -> Message,
     /// The position of the [`DatePickerOverlay`](DatePickerOverlay).
@@ -95,7 +98,8 @@ where
             submit_button: Button::new(IconText::new(Icon::Check).width(Length::Fill))
                 .width(Length::Fill)
                 //.style(button_style)
-                .on_press(on_cancel), // Sending a fake message
+                .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
             position,
             style,
@@ -523,6 +527,12 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        if let Some(dismiss) = Dismiss::from_event(&event, cursor_position, layout.bounds(), &[]) {
+            self.state.is_opened = false;
+            shell.publish(self.on_cancel.clone());
+            return dismiss.status();
+        }
+
         if event::Status::Captured
             == self.on_event_keyboard(&event, layout, cursor_position, shell, renderer, clipboard)
         {
@@ -570,6 +580,8 @@ where
             .next()
             .expect("Native: Layout should have a cancel button layout for a DatePicker");
 
+        let mut cancel_messages: Vec<Message> = Vec::new();
+
         let cancel_status = self.cancel_button.on_event(
             &mut self.tree.children[0],
             event.clone(),
@@ -577,9 +589,14 @@ where
             cursor_position,
             renderer,
             clipboard,
-            shell,
+            &mut Shell::new(&mut cancel_messages),
         );
 
+        if !cancel_messages.is_empty() {
+            self.state.is_opened = false;
+            shell.publish(self.on_cancel.clone());
+        }
+
         let submit_button_layout = children
             .next()
             .expect("Native: Layout should have a submit button layout for a DatePicker");
@@ -597,6 +614,7 @@ where
         );
 
         if !fake_messages.is_empty() {
+            self.state.is_opened = false;
             shell.publish((self.on_submit)(self.state.date.into()));
         }
 
@@ -851,13 +869,21 @@ where
 
 /// The state of the [`DatePickerOverlay`](DatePickerOverlay).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct State {
     /// The selected date of the [`DatePickerOverlay`](DatePickerOverlay).
     pub(crate) date: NaiveDate,
     /// The focus of the [`DatePickerOverlay`](DatePickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// If the [`DatePickerOverlay`](DatePickerOverlay) was opened by the application
+    /// with [`open`](crate::native::operation::open).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) is_opened: bool,
 }
 
 impl State {
@@ -877,6 +903,7 @@ impl Default for State {
             date: Local::now().naive_local().date(),
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_opened: false,
         }
     }
 }
@@ -0,0 +1,129 @@
+//! Show the ghost of a dragged element following the cursor.
+//!
+//! *This API requires the following crate features to be activated: drag_and_drop*
+use iced_native::{
+    event, layout::Limits, layout::Node, mouse, overlay, renderer, widget::Tree, Clipboard,
+    Element, Event, Layout, Point, Rectangle, Shell, Size,
+};
+
+use crate::style::drag_and_drop::StyleSheet;
+
+/// The cursor position given to the ghost, which never reacts to the cursor.
+const NO_CURSOR: Point = Point::new(-1.0, -1.0);
+
+/// The overlay of a dragged
+/// [`DragSource`](crate::native::drag_and_drop::DragSource).
+#[allow(missing_debug_implementations)]
+pub struct DragGhostOverlay<'a, 'b, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state of the ghost.
+    state: &'b Tree,
+    /// The element shown as the ghost.
+    ghost: &'b Element<'a, Message, Renderer>,
+    /// The size of the dragged element at its place in the layout.
+    size: Size,
+    /// The style of the ghost.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> DragGhostOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`DragGhostOverlay`](DragGhostOverlay) showing the
+    /// element with the size the dragged element has in the layout.
+    pub fn new(
+        state: &'b Tree,
+        ghost: &'b Element<'a, Message, Renderer>,
+        size: Size,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        DragGhostOverlay {
+            state,
+            ghost,
+            size,
+            style,
+        }
+    }
+
+    /// Turn this [`DragGhostOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) at the given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'b, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for DragGhostOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> Node {
+        let limits = Limits::new(Size::ZERO, self.size);
+
+        let mut node = self.ghost.as_widget().layout(renderer, &limits);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::Grabbing
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.ghost(self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        self.ghost.as_widget().draw(
+            self.state, renderer, theme, style, layout, NO_CURSOR, &bounds,
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // The ghost follows the cursor, so it must not hide the targets below
+        false
+    }
+}
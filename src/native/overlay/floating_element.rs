@@ -1,10 +1,27 @@
 //! Use a floating element to overlay a element over some content
 //!
 //! *This API requires the following crate features to be activated: `floating_element`*
-use iced_native::{event, layout::Limits, overlay, Clipboard, Event, Layout, Point, Shell, Size};
+use iced_native::{
+    event, layout::Limits, mouse, overlay, touch, Clipboard, Color, Event, Layout, Point,
+    Rectangle, Shell, Size, Vector,
+};
 use iced_native::{widget::Tree, Element};
 
-use crate::native::floating_element::{Anchor, Offset};
+use crate::native::floating_element::{Anchor, Offset, Snap};
+
+/// The distance the cursor has to travel before a press turns into a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// An ongoing drag of a [`FloatingElement`](crate::FloatingElement).
+#[derive(Clone, Copy, Debug)]
+pub struct Drag {
+    /// The cursor position where the drag started.
+    origin: Point,
+    /// The translation of the element since the drag started.
+    translation: Vector,
+    /// Whether the cursor traveled far enough to count as a drag.
+    moved: bool,
+}
 
 /// The internal overlay of a [`FloatingElement`](crate::FloatingElement) for
 /// rendering a [`Element`](iced_native::Element) as an overlay.
@@ -18,6 +35,18 @@ pub struct FloatingElementOverlay<'a, Message, Renderer: iced_native::Renderer>
     anchor: &'a Anchor,
     /// The offset of the element.
     offset: &'a Offset,
+    /// The progress of the show/hide transition, from `0.0` to `1.0`.
+    progress: f32,
+    /// The ongoing drag of the element.
+    drag: Option<&'a mut Option<Drag>>,
+    /// The function that produces a message when the element was dragged.
+    on_drag: Option<&'a dyn Fn(Anchor, Offset) -> Message>,
+    /// The snap behavior of the element after it was dragged.
+    snap: Snap,
+    /// The bounds of the underlying element.
+    underlay: Rectangle,
+    /// The optional badge attached to the element and its state.
+    badge: Option<(&'a mut Tree, Element<'a, Message, Renderer>, Anchor)>,
 }
 
 impl<'a, Message, Renderer> FloatingElementOverlay<'a, Message, Renderer>
@@ -27,7 +56,13 @@ where
 {
     /// Creates a new [`FloatingElementOverlay`] containing the given
     /// [`Element`](iced_native::Element).
-    pub fn new<B>(state: &'a mut Tree, element: B, anchor: &'a Anchor, offset: &'a Offset) -> Self
+    pub fn new<B>(
+        state: &'a mut Tree,
+        element: B,
+        anchor: &'a Anchor,
+        offset: &'a Offset,
+        progress: f32,
+    ) -> Self
     where
         B: Into<Element<'a, Message, Renderer>>,
     {
@@ -36,6 +71,98 @@ where
             element: element.into(),
             anchor,
             offset,
+            progress,
+            drag: None,
+            on_drag: None,
+            snap: Snap::None,
+            underlay: Rectangle::default(),
+            badge: None,
+        }
+    }
+
+    /// Attaches a badge to the element of the
+    /// [`FloatingElementOverlay`](FloatingElementOverlay), centered on the
+    /// given [`Anchor`](Anchor) of the element.
+    #[must_use]
+    pub fn badge<E>(mut self, state: &'a mut Tree, badge: E, anchor: Anchor) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.badge = Some((state, badge.into(), anchor));
+        self
+    }
+
+    /// Makes the [`FloatingElementOverlay`](FloatingElementOverlay) draggable
+    /// over the given bounds of the underlying element.
+    ///
+    /// Dragging is only enabled if a function producing a message is given.
+    #[must_use]
+    pub fn draggable(
+        mut self,
+        drag: &'a mut Option<Drag>,
+        on_drag: Option<&'a dyn Fn(Anchor, Offset) -> Message>,
+        snap: Snap,
+        underlay: Rectangle,
+    ) -> Self {
+        if on_drag.is_some() {
+            self.drag = Some(drag);
+            self.on_drag = on_drag;
+            self.snap = snap;
+            self.underlay = underlay;
+        } else {
+            *drag = None;
+        }
+        self
+    }
+
+    /// Returns the translation of an ongoing drag of the element.
+    fn translation(&self) -> Vector {
+        match self.drag.as_deref() {
+            Some(Some(drag)) if drag.moved => drag.translation,
+            _ => Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Returns `true` if the show/hide transition is still running.
+    fn is_transitioning(&self) -> bool {
+        self.progress < 1.0
+    }
+
+    /// Draws the element and the optional badge of the
+    /// [`FloatingElementOverlay`](FloatingElementOverlay).
+    fn draw_children(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let mut children = layout.children();
+        let element_layout = children
+            .next()
+            .expect("Graphics: Layout should have an element layout for a FloatingElement");
+
+        self.element.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            style,
+            element_layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+
+        if let (Some((state, badge, _)), Some(badge_layout)) = (&self.badge, children.next()) {
+            badge.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                badge_layout,
+                cursor_position,
+                &layout.bounds(),
+            );
         }
     }
 
@@ -98,7 +225,49 @@ where
             )),
         }
 
-        element
+        let translation = self.translation();
+        let bounds = element.bounds();
+        element.move_to(Point::new(
+            bounds.x + translation.x,
+            bounds.y + translation.y,
+        ));
+
+        let element_bounds = element.bounds();
+        let mut area = element_bounds;
+        let mut children = vec![element];
+
+        if let Some((_, badge, anchor)) = &self.badge {
+            let mut badge = badge.as_widget().layout(renderer, &limits);
+            let center = anchor.position(element_bounds);
+            let size = badge.size();
+            badge.move_to(Point::new(
+                center.x - size.width / 2.0,
+                center.y - size.height / 2.0,
+            ));
+
+            let badge_bounds = badge.bounds();
+            let x = area.x.min(badge_bounds.x);
+            let y = area.y.min(badge_bounds.y);
+            area = Rectangle {
+                x,
+                y,
+                width: (area.x + area.width).max(badge_bounds.x + badge_bounds.width) - x,
+                height: (area.y + area.height).max(badge_bounds.y + badge_bounds.height) - y,
+            };
+
+            children.push(badge);
+        }
+
+        for child in &mut children {
+            let bounds = child.bounds();
+            child.move_to(Point::new(bounds.x - area.x, bounds.y - area.y));
+        }
+
+        let mut node =
+            iced_native::layout::Node::with_children(Size::new(area.width, area.height), children);
+        node.move_to(Point::new(area.x, area.y));
+
+        node
     }
 
     fn on_event(
@@ -110,6 +279,70 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        if self.is_transitioning() {
+            return event::Status::Ignored;
+        }
+
+        let layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have an element layout for a FloatingElement");
+
+        if let (Some(drag), Some(on_drag)) = (self.drag.as_deref_mut(), self.on_drag) {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if layout.bounds().contains(cursor_position) {
+                        *drag = Some(Drag {
+                            origin: cursor_position,
+                            translation: Vector::new(0.0, 0.0),
+                            moved: false,
+                        });
+                    }
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let Some(drag) = drag {
+                        drag.translation = cursor_position - drag.origin;
+                        drag.moved = drag.moved
+                            || drag.translation.x.hypot(drag.translation.y) > DRAG_THRESHOLD;
+
+                        if drag.moved {
+                            shell.invalidate_layout();
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    if let Some(Drag { moved: true, .. }) = drag.take() {
+                        let (anchor, offset) =
+                            self.snap
+                                .resolve(layout.bounds(), self.underlay, *self.offset);
+                        shell.publish(on_drag(anchor, offset));
+                        shell.invalidate_layout();
+
+                        // Release the element outside of its bounds so a
+                        // pressed button does not register a click.
+                        let _ = self.element.as_widget_mut().on_event(
+                            self.state,
+                            event,
+                            layout,
+                            Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+                            renderer,
+                            clipboard,
+                            shell,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
         self.element.as_widget_mut().on_event(
             self.state,
             event,
@@ -128,9 +361,20 @@ where
         viewport: &iced_graphics::Rectangle,
         renderer: &Renderer,
     ) -> iced_native::mouse::Interaction {
+        if self.is_transitioning() {
+            return iced_native::mouse::Interaction::default();
+        }
+
+        if let Some(Some(Drag { moved: true, .. })) = self.drag.as_deref() {
+            return iced_native::mouse::Interaction::Grabbing;
+        }
+
         self.element.as_widget().mouse_interaction(
             self.state,
-            layout,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have an element layout for a FloatingElement"),
             cursor_position,
             viewport,
             renderer,
@@ -145,14 +389,31 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) {
-        self.element.as_widget().draw(
-            self.state,
-            renderer,
-            theme,
-            style,
-            layout,
-            cursor_position,
-            &layout.bounds(),
-        );
+        if !self.is_transitioning() {
+            self.draw_children(renderer, theme, style, layout, cursor_position);
+            return;
+        }
+
+        // Ease out the transition and reveal the element from its center.
+        let scale = 1.0 - (1.0 - self.progress).powi(3);
+        let bounds = layout.bounds();
+        let center = bounds.center();
+        let clip = Rectangle {
+            x: center.x - bounds.width * scale / 2.0,
+            y: center.y - bounds.height * scale / 2.0,
+            width: bounds.width * scale,
+            height: bounds.height * scale,
+        };
+
+        let style = iced_native::renderer::Style {
+            text_color: Color {
+                a: style.text_color.a * scale,
+                ..style.text_color
+            },
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            self.draw_children(renderer, theme, &style, layout, cursor_position);
+        });
     }
 }
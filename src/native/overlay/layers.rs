@@ -0,0 +1,207 @@
+//! Stack several overlays on explicit layers.
+//!
+//! Overlays on higher layers are drawn on top of the overlays on lower layers
+//! and receive events first. Events under the cursor are only routed down to
+//! the topmost overlay below the cursor, so overlays hidden by others do not
+//! react to them.
+use iced_native::{
+    event, layout::Node, mouse, overlay, renderer, Clipboard, Event, Layout, Point, Shell, Size,
+};
+
+/// The cursor position passed to overlays covered by an overlay on a higher
+/// layer, so they are not hovered.
+const COVERED_CURSOR: Point = Point::new(-1.0, -1.0);
+
+/// An overlay stacking several overlays on explicit layers.
+///
+/// # Example
+/// ```ignore
+/// Layers::new()
+///     .push(0, floating_element_overlay)
+///     .push(1, modal_overlay)
+///     .overlay()
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Layers<'a, Message, Renderer> {
+    /// The overlays with their layers, sorted from the bottom to the top.
+    children: Vec<(i32, overlay::Element<'a, Message, Renderer>)>,
+}
+
+impl<'a, Message, Renderer> Layers<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Creates a new empty [`Layers`](Layers) overlay.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds an overlay on the given layer.
+    ///
+    /// Overlays on the same layer are stacked in the order they are added.
+    #[must_use]
+    pub fn push(mut self, layer: i32, overlay: overlay::Element<'a, Message, Renderer>) -> Self {
+        let index = self.children.partition_point(|(other, _)| *other <= layer);
+        self.children.insert(index, (layer, overlay));
+        self
+    }
+
+    /// Adds the overlay on the given layer if there is one.
+    #[must_use]
+    pub fn push_maybe(
+        self,
+        layer: i32,
+        overlay: Option<overlay::Element<'a, Message, Renderer>>,
+    ) -> Self {
+        match overlay {
+            Some(overlay) => self.push(layer, overlay),
+            None => self,
+        }
+    }
+
+    /// Turn these [`Layers`](Layers) into an overlay
+    /// [`Element`](iced_native::overlay::Element).
+    ///
+    /// A single overlay is returned as it is and `None` if there is none.
+    #[must_use]
+    pub fn overlay(mut self) -> Option<overlay::Element<'a, Message, Renderer>>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        match self.children.len() {
+            0 => None,
+            1 => self.children.pop().map(|(_, overlay)| overlay),
+            _ => Some(overlay::Element::new(Point::ORIGIN, Box::new(self))),
+        }
+    }
+
+    /// Returns the index of the topmost overlay under the cursor.
+    fn topmost(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter()
+            .zip(layouts)
+            .rposition(|((_, child), layout)| child.is_over(layout, cursor_position))
+    }
+}
+
+impl<'a, Message, Renderer> Default for Layers<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for Layers<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let translation = position - Point::ORIGIN;
+
+        Node::with_children(
+            bounds,
+            self.children
+                .iter()
+                .map(|(_, child)| child.layout(renderer, bounds, translation))
+                .collect(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let is_pointer = matches!(event, Event::Mouse(_) | Event::Touch(_));
+        let layouts: Vec<_> = layout.children().collect();
+
+        let mut status = event::Status::Ignored;
+
+        for ((_, child), layout) in self.children.iter_mut().zip(layouts).rev() {
+            let is_over = child.is_over(layout, cursor_position);
+
+            status = status.merge(child.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ));
+
+            // The topmost overlay under the cursor consumes pointer events
+            if status == event::Status::Captured || (is_pointer && is_over) {
+                break;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &iced_native::Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.topmost(layout, cursor_position)
+            .and_then(|index| self.children.get(index).zip(layout.children().nth(index)))
+            .map_or_else(mouse::Interaction::default, |((_, child), layout)| {
+                child.mouse_interaction(layout, cursor_position, viewport, renderer)
+            })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let topmost = self.topmost(layout, cursor_position);
+
+        for (index, ((_, child), layout)) in self.children.iter().zip(layout.children()).enumerate()
+        {
+            let cursor_position = match topmost {
+                Some(topmost) if index < topmost => COVERED_CURSOR,
+                _ => cursor_position,
+            };
+
+            child.draw(renderer, theme, style, layout, cursor_position);
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.topmost(layout, cursor_position).is_some()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .fold(Layers::new(), |layers, ((layer, child), layout)| {
+                layers.push_maybe(*layer, child.overlay(layout, renderer))
+            })
+            .overlay()
+    }
+}
@@ -0,0 +1,338 @@
+//! A button opening a menu of items.
+//!
+//! *This API requires the following crate features to be activated: menu_button*
+use std::fmt::Display;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::Node,
+    mouse, overlay, renderer, text, touch, Clipboard, Color, Event, Font, Layout, Point, Rectangle,
+    Shell, Size,
+};
+
+use crate::{
+    native::menu_button::{step, State},
+    style::{
+        menu_button::StyleSheet,
+        style_state::{appearance, StyleState},
+    },
+};
+
+/// The menu of a [`MenuButton`](crate::native::menu_button::MenuButton).
+#[allow(missing_debug_implementations)]
+pub struct MenuButtonOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The items of the menu.
+    items: &'a [T],
+    /// The state shared between the
+    /// [`MenuButton`](crate::native::menu_button::MenuButton) and the menu.
+    state: &'a mut State,
+    /// The function producing the message when the user picks an item.
+    on_select: &'a dyn Fn(T) -> Message,
+    /// Whether picking an item closes the menu.
+    close_on_select: bool,
+    /// The size of the button the menu opens at.
+    anchor: Size,
+    /// The padding around the text of the items.
+    padding: f32,
+    /// The number of items shown at once.
+    visible_items: usize,
+    /// The text size of the items.
+    text_size: Option<f32>,
+    /// The font of the items.
+    font: Font,
+    /// The style of the menu.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> MenuButtonOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MenuButtonOverlay`](MenuButtonOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        items: &'a [T],
+        state: &'a mut State,
+        on_select: &'a dyn Fn(T) -> Message,
+        close_on_select: bool,
+        anchor: Size,
+        padding: f32,
+        visible_items: usize,
+        text_size: Option<f32>,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        // The items may have changed since the menu was opened
+        state.hovered = state.hovered.filter(|&index| index < items.len());
+        state.offset = state.offset.min(items.len().saturating_sub(visible_items));
+
+        MenuButtonOverlay {
+            items,
+            state,
+            on_select,
+            close_on_select,
+            anchor,
+            padding,
+            visible_items,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`MenuButtonOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) opening at the button at the
+    /// given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the number of items shown at once.
+    fn rows(&self) -> usize {
+        self.items.len().min(self.visible_items)
+    }
+
+    /// Returns the index of the item at the cursor, if any.
+    fn item_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let row_height = bounds.height / self.rows() as f32;
+        let row = ((cursor_position.y - bounds.y) / row_height) as usize;
+
+        Some(self.state.offset + row).filter(|&index| index < self.items.len())
+    }
+
+    /// Highlights the item at the index and scrolls it into view.
+    fn hover(&mut self, index: usize) {
+        let rows = self.rows();
+
+        self.state.hovered = Some(index);
+        if index < self.state.offset {
+            self.state.offset = index;
+        } else if index >= self.state.offset + rows {
+            self.state.offset = index + 1 - rows;
+        }
+    }
+
+    /// Picks the item at the index.
+    fn select(&mut self, index: usize, shell: &mut Shell<Message>) {
+        shell.publish((self.on_select)(self.items[index].clone()));
+
+        if self.close_on_select {
+            self.state.is_open = false;
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for MenuButtonOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = self.rows() as f32 * (text_size + 2.0 * self.padding);
+
+        // The menu is at least as wide as the button and fits its widest item
+        let width = self
+            .items
+            .iter()
+            .map(|item| {
+                renderer.measure_width(&item.to_string(), text_size, self.font) + 2.0 * self.padding
+            })
+            .fold(self.anchor.width, f32::max);
+
+        // The menu opens above the button if there is no room below it
+        let below = position.y + self.anchor.height;
+        let y = if below + height > bounds.height && position.y >= height {
+            position.y - height
+        } else {
+            below
+        };
+        let x = position.x.min((bounds.width - width).max(0.0));
+
+        let mut node = Node::new(Size::new(width, height));
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                let count = self.items.len();
+
+                match (key_code, self.state.hovered) {
+                    (keyboard::KeyCode::Down, hovered) => {
+                        self.hover(step(hovered, count, true));
+                    }
+                    (keyboard::KeyCode::Up, hovered) => {
+                        self.hover(step(hovered, count, false));
+                    }
+                    (keyboard::KeyCode::Home, _) => self.hover(0),
+                    (keyboard::KeyCode::End, _) => self.hover(count - 1),
+                    (
+                        keyboard::KeyCode::Space
+                        | keyboard::KeyCode::Enter
+                        | keyboard::KeyCode::NumpadEnter,
+                        Some(index),
+                    ) => {
+                        self.select(index, shell);
+                    }
+                    (keyboard::KeyCode::Escape, _) => {
+                        self.state.is_open = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.item_at(bounds, cursor_position) {
+                    self.state.hovered = Some(index);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.item_at(bounds, cursor_position) {
+                    Some(index) => {
+                        self.select(index, shell);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let row_height = bounds.height / self.rows() as f32;
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / row_height,
+                }
+                .round();
+                let max = self.items.len() - self.rows();
+
+                self.state.offset = if rows > 0.0 {
+                    self.state.offset.saturating_sub(rows as usize)
+                } else {
+                    (self.state.offset + (-rows) as usize).min(max)
+                };
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.item_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let row_height = bounds.height / self.rows() as f32;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.menu_background,
+        );
+
+        let shown = self.items.iter().enumerate().skip(self.state.offset);
+
+        for (row, (index, item)) in shown.take(self.rows()).enumerate() {
+            let row_bounds = Rectangle {
+                y: bounds.y + row as f32 * row_height,
+                height: row_height,
+                ..bounds
+            };
+
+            let color = if self.state.hovered == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.hovered_background,
+                );
+
+                appearance.hovered_text_color
+            } else {
+                appearance.text_color
+            };
+
+            renderer.with_layer(row_bounds, |renderer| {
+                renderer.fill_text(text::Text {
+                    content: &item.to_string(),
+                    bounds: Rectangle {
+                        x: row_bounds.x + self.padding,
+                        y: row_bounds.center_y(),
+                        ..row_bounds
+                    },
+                    size: text_size,
+                    color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            });
+        }
+    }
+}
@@ -1,30 +1,88 @@
 //! Display interactive elements on top of other widgets.
 
+#[cfg(any(feature = "calendar_heatmap", feature = "heatmap"))]
+pub mod cell_tooltip;
+#[cfg(any(feature = "calendar_heatmap", feature = "heatmap"))]
+pub use cell_tooltip::CellTooltipOverlay;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
 pub use color_picker::ColorPickerOverlay;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBoxOverlay;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePickerOverlay;
 
+#[cfg(feature = "drag_and_drop")]
+pub mod drag_ghost;
+#[cfg(feature = "drag_and_drop")]
+pub use drag_ghost::DragGhostOverlay;
+
 #[cfg(feature = "floating_element")]
 pub mod floating_element;
 #[cfg(feature = "floating_element")]
 pub use floating_element::FloatingElementOverlay;
 
+pub mod layers;
+pub use layers::Layers;
+
+#[cfg(feature = "menu_button")]
+pub mod menu_button;
+#[cfg(feature = "menu_button")]
+pub use menu_button::MenuButtonOverlay;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
 pub use modal::ModalOverlay;
 
+#[cfg(feature = "multi_select")]
+pub mod multi_select;
+#[cfg(feature = "multi_select")]
+pub use multi_select::MultiSelectOverlay;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverOverlay;
+
+#[cfg(feature = "portal")]
+pub mod portal;
+#[cfg(feature = "portal")]
+pub use portal::PortalOverlay;
+
+#[cfg(feature = "radial_menu")]
+pub mod radial_menu;
+#[cfg(feature = "radial_menu")]
+pub use radial_menu::RadialMenuOverlay;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::{State, TimePickerOverlay};
 
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastsOverlay;
+
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "tooltip")]
+pub use tooltip::TooltipOverlay;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+#[cfg(feature = "tour")]
+pub use tour::TourOverlay;
+
 #[cfg(feature = "context_menu")]
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
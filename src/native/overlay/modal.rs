@@ -3,12 +3,18 @@
 //! *This API requires the following crate features to be activated: modal*
 use iced_graphics::Vector;
 use iced_native::{
-    event, keyboard, layout::Limits, mouse, overlay, renderer, touch, Clipboard, Color, Event,
-    Layout, Point, Shell, Size,
+    event, layout::Limits, mouse, overlay, renderer, Clipboard, Color, Event, Layout, Point, Shell,
+    Size,
 };
 use iced_native::{widget::Tree, Element};
 
-use crate::style::modal::StyleSheet;
+use crate::{
+    core::overlay::Dismiss,
+    style::{
+        modal::StyleSheet,
+        style_state::{appearance, StyleState},
+    },
+};
 
 /// The overlay of the modal.
 #[allow(missing_debug_implementations)]
@@ -107,53 +113,50 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
-        // TODO clean this up
-        let esc_status = self
-            .esc
-            .as_ref()
-            .map_or(event::Status::Ignored, |esc| match event {
-                Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
-                    if key_code == keyboard::KeyCode::Escape {
-                        shell.publish(esc.to_owned());
-                        event::Status::Captured
-                    } else {
-                        event::Status::Ignored
-                    }
-                }
-                _ => event::Status::Ignored,
-            });
-
-        let backdrop_status = self.backdrop.as_ref().zip(layout.children().next()).map_or(
-            event::Status::Ignored,
-            |(backdrop, layout)| match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if layout.bounds().contains(cursor_position) {
-                        event::Status::Ignored
-                    } else {
-                        shell.publish(backdrop.to_owned());
-                        event::Status::Captured
-                    }
-                }
-                _ => event::Status::Ignored,
-            },
-        );
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a content layout.");
 
-        match esc_status.merge(backdrop_status) {
-            event::Status::Ignored => self.content.as_widget_mut().on_event(
-                self.state,
-                event,
-                layout
-                    .children()
-                    .next()
-                    .expect("Native: Layout should have a content layout."),
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            ),
-            event::Status::Captured => event::Status::Captured,
+        // The modal blocks the underlay, so dismissing presses are always
+        // captured
+        let message =
+            match Dismiss::from_event(&event, cursor_position, content_layout.bounds(), &[]) {
+                Some(Dismiss::Escape) => self.esc.as_ref(),
+                Some(Dismiss::Outside) => self.backdrop.as_ref(),
+                None => None,
+            };
+
+        if let Some(message) = message {
+            shell.publish(message.to_owned());
+            return event::Status::Captured;
         }
+
+        self.content.as_widget_mut().on_event(
+            self.state,
+            event,
+            content_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        // Overlays of the content, like menus, are shown on top of it
+        self.content.as_widget_mut().overlay(
+            self.state,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+        )
     }
 
     fn mouse_interaction(
@@ -185,7 +188,17 @@ where
     ) {
         let bounds = layout.bounds();
 
-        let style_sheet = theme.active(self.style);
+        // The modal is hovered while the cursor is over its content
+        let is_hovered = layout
+            .children()
+            .next()
+            .map_or(false, |content| content.bounds().contains(cursor_position));
+        let style_sheet = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(is_hovered, false, false, false),
+            self.style,
+        );
 
         // Background
         renderer.fill_quad(
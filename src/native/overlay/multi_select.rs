@@ -0,0 +1,376 @@
+//! Pick any number of options from a dropdown of checkboxes.
+//!
+//! *This API requires the following crate features to be activated: multi_select*
+use std::fmt::Display;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::Node,
+    mouse, overlay, renderer, text, touch, Clipboard, Color, Event, Font, Layout, Point, Rectangle,
+    Shell, Size,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    native::multi_select::State,
+    style::{
+        multi_select::StyleSheet,
+        style_state::{appearance, StyleState},
+    },
+};
+
+/// The dropdown of a [`MultiSelect`](crate::native::multi_select::MultiSelect).
+#[allow(missing_debug_implementations)]
+pub struct MultiSelectOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The options to pick from.
+    options: &'a [T],
+    /// The chosen options.
+    selected: &'a [T],
+    /// The state shared between the
+    /// [`MultiSelect`](crate::native::multi_select::MultiSelect) and the
+    /// dropdown.
+    state: &'a mut State,
+    /// The function producing the message with the updated selection.
+    on_change: &'a dyn Fn(Vec<T>) -> Message,
+    /// The size of the field the dropdown opens at.
+    anchor: Size,
+    /// The padding around the text of the options.
+    padding: f32,
+    /// The number of options shown at once.
+    visible_options: usize,
+    /// The text size of the options.
+    text_size: Option<f32>,
+    /// The font of the options.
+    font: Font,
+    /// The style of the dropdown.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> MultiSelectOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MultiSelectOverlay`](MultiSelectOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        options: &'a [T],
+        selected: &'a [T],
+        state: &'a mut State,
+        on_change: &'a dyn Fn(Vec<T>) -> Message,
+        anchor: Size,
+        padding: f32,
+        visible_options: usize,
+        text_size: Option<f32>,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        // The options may have changed since the dropdown was opened
+        state.hovered = state.hovered.filter(|&index| index < options.len());
+        state.offset = state
+            .offset
+            .min(options.len().saturating_sub(visible_options));
+
+        MultiSelectOverlay {
+            options,
+            selected,
+            state,
+            on_change,
+            anchor,
+            padding,
+            visible_options,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`MultiSelectOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) opening at the field at the
+    /// given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the number of options shown at once.
+    fn rows(&self) -> usize {
+        self.options.len().min(self.visible_options)
+    }
+
+    /// Returns the index of the option at the cursor, if any.
+    fn option_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let row_height = bounds.height / self.rows() as f32;
+        let row = ((cursor_position.y - bounds.y) / row_height) as usize;
+
+        Some(self.state.offset + row).filter(|&index| index < self.options.len())
+    }
+
+    /// Hovers the option at the index and scrolls it into view.
+    fn hover(&mut self, index: usize) {
+        let rows = self.rows();
+
+        self.state.hovered = Some(index);
+        if index < self.state.offset {
+            self.state.offset = index;
+        } else if index >= self.state.offset + rows {
+            self.state.offset = index + 1 - rows;
+        }
+    }
+
+    /// Chooses the option at the index or drops it from the selection.
+    fn toggle(&self, index: usize, shell: &mut Shell<Message>) {
+        let option = &self.options[index];
+        let mut selection = self.selected.to_vec();
+
+        match selection.iter().position(|chosen| chosen == option) {
+            Some(position) => {
+                let _ = selection.remove(position);
+            }
+            None => selection.push(option.clone()),
+        }
+
+        shell.publish((self.on_change)(selection));
+    }
+}
+
+impl<'a, T, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for MultiSelectOverlay<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = self.rows() as f32 * (text_size + 2.0 * self.padding);
+
+        // The dropdown opens above the field if there is no room below it
+        let below = position.y + self.anchor.height;
+        let y = if below + height > bounds.height && position.y >= height {
+            position.y - height
+        } else {
+            below
+        };
+
+        let mut node = Node::new(Size::new(self.anchor.width, height));
+        node.move_to(Point::new(position.x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                let count = self.options.len();
+
+                match (key_code, self.state.hovered) {
+                    (keyboard::KeyCode::Down, hovered) => {
+                        self.hover(hovered.map_or(0, |index| (index + 1) % count));
+                    }
+                    (keyboard::KeyCode::Up, hovered) => {
+                        self.hover(
+                            hovered.map_or(count - 1, |index| {
+                                index.checked_sub(1).unwrap_or(count - 1)
+                            }),
+                        );
+                    }
+                    (
+                        keyboard::KeyCode::Space
+                        | keyboard::KeyCode::Enter
+                        | keyboard::KeyCode::NumpadEnter,
+                        Some(index),
+                    ) => {
+                        self.toggle(index, shell);
+                    }
+                    (keyboard::KeyCode::Escape, _) => {
+                        self.state.is_open = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.option_at(bounds, cursor_position) {
+                    self.state.hovered = Some(index);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // The dropdown stays open to toggle more options
+                match self.option_at(bounds, cursor_position) {
+                    Some(index) => {
+                        self.toggle(index, shell);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let row_height = bounds.height / self.rows() as f32;
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / row_height,
+                }
+                .round();
+                let max = self.options.len() - self.rows();
+
+                self.state.offset = if rows > 0.0 {
+                    self.state.offset.saturating_sub(rows as usize)
+                } else {
+                    (self.state.offset + (-rows) as usize).min(max)
+                };
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.option_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let row_height = bounds.height / self.rows() as f32;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.menu_background,
+        );
+
+        let shown = self.options.iter().enumerate().skip(self.state.offset);
+
+        for (row, (index, option)) in shown.take(self.rows()).enumerate() {
+            let row_bounds = Rectangle {
+                y: bounds.y + row as f32 * row_height,
+                height: row_height,
+                ..bounds
+            };
+
+            if self.state.hovered == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.hovered_background,
+                );
+            }
+
+            let checkbox_bounds = Rectangle {
+                x: row_bounds.x + self.padding,
+                y: row_bounds.center_y() - text_size / 2.0,
+                width: text_size,
+                height: text_size,
+            };
+
+            if self.selected.contains(option) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: checkbox_bounds,
+                        border_radius: (text_size / 5.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.checked_background,
+                );
+                renderer.fill_glyph(
+                    char::from(Icon::Check),
+                    Icon::Check.font(),
+                    checkbox_bounds,
+                    text_size * 0.8,
+                    appearance.check_color,
+                );
+            } else {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: checkbox_bounds,
+                        border_radius: (text_size / 5.0).into(),
+                        border_width: 1.0,
+                        border_color: appearance.checkbox_border_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let label_x = checkbox_bounds.x + checkbox_bounds.width + self.padding;
+
+            renderer.with_layer(row_bounds, |renderer| {
+                renderer.fill_text(text::Text {
+                    content: &option.to_string(),
+                    bounds: Rectangle {
+                        x: label_x,
+                        y: row_bounds.center_y(),
+                        ..row_bounds
+                    },
+                    size: text_size,
+                    color: appearance.text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            });
+        }
+    }
+}
@@ -0,0 +1,380 @@
+//! Show content in a bubble anchored to another widget.
+//!
+//! *This API requires the following crate features to be activated: popover*
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, Alignment, Background, Clipboard, Color, Event, Layout, Point,
+    Rectangle, Shell, Size,
+};
+use iced_native::{widget::Tree, Element};
+
+use crate::core::overlay::{Dismiss, Placement, Position};
+use crate::style::{
+    popover::StyleSheet,
+    style_state::{appearance, StyleState},
+};
+
+/// The overlay of the [`Popover`](crate::native::popover::Popover).
+#[allow(missing_debug_implementations)]
+pub struct PopoverOverlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state of the [`PopoverOverlay`](PopoverOverlay).
+    state: &'a mut Tree,
+    /// The content of the [`PopoverOverlay`](PopoverOverlay).
+    content: Element<'a, Message, Renderer>,
+    /// The size of the anchor the bubble is placed at.
+    anchor: Size,
+    /// The preferred side of the anchor the bubble is placed at.
+    placement: Placement,
+    /// The alignment of the bubble along the side of the anchor.
+    alignment: Alignment,
+    /// The gap between the anchor and the bubble.
+    gap: f32,
+    /// The padding between the bubble and its content.
+    padding: f32,
+    /// The optional size of the arrow pointing at the anchor.
+    arrow: Option<f32>,
+    /// The optional message that will be send when the user clicked outside
+    /// of the bubble or pressed the ESC key.
+    on_dismiss: Option<Message>,
+    /// The style of the [`PopoverOverlay`](PopoverOverlay).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> PopoverOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`PopoverOverlay`](PopoverOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<C>(
+        state: &'a mut Tree,
+        content: C,
+        anchor: Size,
+        placement: Placement,
+        alignment: Alignment,
+        gap: f32,
+        padding: f32,
+        arrow: Option<f32>,
+        on_dismiss: Option<Message>,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        PopoverOverlay {
+            state,
+            content: content.into(),
+            anchor,
+            placement,
+            alignment,
+            gap,
+            padding,
+            arrow,
+            on_dismiss,
+            style,
+        }
+    }
+
+    /// Turn this [`PopoverOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) anchored at the given
+    /// position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for PopoverOverlay<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let anchor = Rectangle::new(position, self.anchor);
+        let padding = self.padding;
+
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (bounds.width - 2.0 * padding).max(0.0),
+                (bounds.height - 2.0 * padding).max(0.0),
+            ),
+        );
+        let mut content = self.content.as_widget().layout(renderer, &limits);
+        content.move_to(Point::new(padding, padding));
+
+        let size = Size::new(
+            content.bounds().width + 2.0 * padding,
+            content.bounds().height + 2.0 * padding,
+        );
+        let mut bubble = Node::new(size);
+        bubble.place(
+            anchor,
+            self.placement,
+            self.alignment,
+            self.gap + self.arrow.unwrap_or(0.0),
+            bounds,
+        );
+        let origin = bubble.bounds().position();
+
+        // The anchor is kept as a child, so drawing the arrow can point at it
+        let mut anchor_node = Node::new(anchor.size());
+        anchor_node.move_to(Point::new(anchor.x - origin.x, anchor.y - origin.y));
+
+        let mut node = Node::with_children(size, vec![content, anchor_node]);
+        node.move_to(origin);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout.");
+        let anchor = children
+            .next()
+            .expect("Native: Layout should have an anchor layout.")
+            .bounds();
+
+        if let Some(on_dismiss) = &self.on_dismiss {
+            // Clicks on the anchor are left to the anchor, which usually
+            // toggles the popover itself
+            if let Some(dismiss) =
+                Dismiss::from_event(&event, cursor_position, layout.bounds(), &[anchor])
+            {
+                shell.publish(on_dismiss.clone());
+
+                if dismiss == Dismiss::Escape {
+                    return dismiss.status();
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            self.state,
+            event,
+            content_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        // Overlays of the content, like menus, are shown on top of it
+        self.content.as_widget_mut().overlay(
+            self.state,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.state,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout.");
+        let anchor = children
+            .next()
+            .expect("Native: Layout should have an anchor layout.")
+            .bounds();
+
+        let style_sheet = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                content_layout.bounds().contains(cursor_position),
+                false,
+                false,
+                false,
+            ),
+            self.style,
+        );
+
+        // Bubble
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style_sheet.border_radius,
+                border_width: style_sheet.border_width,
+                border_color: style_sheet.border_color,
+            },
+            style_sheet.background,
+        );
+
+        if let Some(size) = self.arrow {
+            draw_arrow(
+                renderer,
+                bounds,
+                anchor,
+                size,
+                style_sheet.background,
+                style_sheet.border_width,
+            );
+        }
+
+        // Content
+        self.content.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.text_color,
+            },
+            content_layout,
+            cursor_position,
+            &bounds,
+        );
+    }
+}
+
+/// Draws the arrow of the given size from the edge of the bubble facing the
+/// anchor towards it.
+///
+/// The arrow is drawn as rows of quads narrowing towards its tip, centered on
+/// the part of the edge overlapping the anchor.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    bubble: Rectangle,
+    anchor: Rectangle,
+    size: f32,
+    background: Background,
+    border_width: f32,
+) where
+    Renderer: iced_native::Renderer,
+{
+    // The side of the anchor the bubble ended up at
+    let placement = if bubble.y >= anchor.y + anchor.height {
+        Placement::Bottom
+    } else if bubble.y + bubble.height <= anchor.y {
+        Placement::Top
+    } else if bubble.x >= anchor.x + anchor.width {
+        Placement::Right
+    } else if bubble.x + bubble.width <= anchor.x {
+        Placement::Left
+    } else {
+        return;
+    };
+
+    let center = |bubble_start: f32, bubble_length: f32, anchor_start: f32, anchor_length: f32| {
+        let start = bubble_start.max(anchor_start);
+        let end = (bubble_start + bubble_length).min(anchor_start + anchor_length);
+
+        ((start + end) / 2.0)
+            .min(bubble_start + bubble_length - size)
+            .max(bubble_start + size)
+    };
+
+    let center = match placement {
+        Placement::Top | Placement::Bottom => {
+            center(bubble.x, bubble.width, anchor.x, anchor.width)
+        }
+        Placement::Left | Placement::Right => {
+            center(bubble.y, bubble.height, anchor.y, anchor.height)
+        }
+    };
+
+    // A row at the given distance from the edge, reaching the given depth
+    // into the bubble
+    let row = |distance: f32, thickness: f32, width: f32| match placement {
+        Placement::Bottom => Rectangle {
+            x: center - width / 2.0,
+            y: bubble.y - distance - thickness,
+            width,
+            height: thickness,
+        },
+        Placement::Top => Rectangle {
+            x: center - width / 2.0,
+            y: bubble.y + bubble.height + distance,
+            width,
+            height: thickness,
+        },
+        Placement::Right => Rectangle {
+            x: bubble.x - distance - thickness,
+            y: center - width / 2.0,
+            width: thickness,
+            height: width,
+        },
+        Placement::Left => Rectangle {
+            x: bubble.x + bubble.width + distance,
+            y: center - width / 2.0,
+            width: thickness,
+            height: width,
+        },
+    };
+
+    let rows = size.ceil().max(1.0);
+    let step = size / rows;
+
+    // The base of the arrow covers the border of the bubble
+    let base = std::iter::once(row(-border_width, border_width, 2.0 * size));
+    let rows = (0..rows as usize).map(|i| {
+        let distance = i as f32 * step;
+        row(distance, step, 2.0 * (size - distance))
+    });
+
+    for bounds in base.chain(rows) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            background,
+        );
+    }
+}
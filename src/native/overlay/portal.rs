@@ -0,0 +1,128 @@
+//! Hoist content into the overlay layer to show it unclipped.
+//!
+//! *This API requires the following crate features to be activated: portal*
+use iced_native::{
+    event, layout::Limits, layout::Node, mouse, overlay, renderer, widget::Tree, Clipboard,
+    Element, Event, Layout, Point, Rectangle, Shell, Size,
+};
+
+/// The overlay of the [`Portal`](crate::native::portal::Portal).
+#[allow(missing_debug_implementations)]
+pub struct PortalOverlay<'a, 'b, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// The state of the content.
+    state: &'b mut Tree,
+    /// The content of the [`Portal`](crate::native::portal::Portal).
+    content: &'b mut Element<'a, Message, Renderer>,
+    /// The size of the content at its place in the layout.
+    size: Size,
+}
+
+impl<'a, 'b, Message, Renderer> PortalOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    /// Creates a new [`PortalOverlay`](PortalOverlay) rendering the content
+    /// with the size it has at its place in the layout.
+    pub fn new(
+        state: &'b mut Tree,
+        content: &'b mut Element<'a, Message, Renderer>,
+        size: Size,
+    ) -> Self {
+        PortalOverlay {
+            state,
+            content,
+            size,
+        }
+    }
+
+    /// Turn this [`PortalOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) at the given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'b, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for PortalOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> Node {
+        let limits = Limits::new(Size::ZERO, self.size);
+
+        let mut node = self.content.as_widget().layout(renderer, &limits);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            self.state,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.state,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.content.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(self.state, layout, renderer)
+    }
+}
@@ -0,0 +1,397 @@
+//! A ring of actions opened around the cursor, like the tool menus of pen and
+//! touch canvases.
+//!
+//! *This API requires the following crate features to be activated: radial_menu*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::Node,
+    mouse, overlay, renderer, text, touch, Clipboard, Color, Event, Font, Layout, Point, Rectangle,
+    Shell, Size, Vector,
+};
+
+use crate::{
+    core::{
+        overlay::{Dismiss, Position},
+        renderer::GlyphRenderer,
+    },
+    native::radial_menu::{self, RadialItem, DEAD_ZONE, RING_WIDTH},
+    style::{
+        radial_menu::StyleSheet,
+        style_state::{appearance, StyleState},
+    },
+};
+
+/// The radius of the bubble behind the icon of a hovered item.
+const ITEM_RADIUS: f32 = 20.0;
+
+/// The radius of the dot marking the items opening a submenu.
+const INDICATOR_RADIUS: f32 = 2.5;
+
+/// The padding between the bubble and the text of the label.
+const LABEL_PADDING: f32 = 6.0;
+
+/// The gap between the menu and the label.
+const LABEL_GAP: f32 = 6.0;
+
+/// The overlay of a [`RadialMenu`](crate::native::radial_menu::RadialMenu).
+#[allow(missing_debug_implementations)]
+pub struct RadialMenuOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The items of the innermost ring.
+    items: &'a [RadialItem<Message>],
+    /// The state shared with the
+    /// [`RadialMenu`](crate::native::radial_menu::RadialMenu).
+    state: &'a mut radial_menu::State,
+    /// The message sent when the menu is closed without selecting an action.
+    on_dismiss: Option<Message>,
+    /// The size of the icons.
+    icon_size: f32,
+    /// The text size of the label.
+    text_size: f32,
+    /// The font of the label.
+    font: Font,
+    /// The style of the menu.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> RadialMenuOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RadialMenuOverlay`](RadialMenuOverlay).
+    pub(crate) fn new(
+        items: &'a [RadialItem<Message>],
+        state: &'a mut radial_menu::State,
+        on_dismiss: Option<Message>,
+        icon_size: f32,
+        text_size: f32,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        RadialMenuOverlay {
+            items,
+            state,
+            on_dismiss,
+            icon_size,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`RadialMenuOverlay`] into an overlay
+    /// [`Element`](overlay::Element).
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer>
+    where
+        Renderer: 'a,
+    {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the radius of the menu with all of its rings open.
+    fn radius(&self) -> f32 {
+        DEAD_ZONE + radial_menu::depth(self.items) as f32 * RING_WIDTH
+    }
+
+    /// Returns the center of the menu in its layout.
+    fn center(&self, layout: Layout<'_>) -> Point {
+        let radius = self.radius();
+        let menu = menu_layout(layout).bounds();
+
+        Point::new(menu.x + radius, menu.y + radius)
+    }
+
+    /// Updates the hovered item and the open submenus for the cursor.
+    fn hover(&mut self, layout: Layout<'_>, cursor_position: Point) {
+        let offset = cursor_position - self.center(layout);
+        let rings = radial_menu::rings(self.items, &self.state.path);
+
+        let Some(ring) = radial_menu::ring_at(offset.x.hypot(offset.y), rings.len()) else {
+            // Returning to the center closes the submenus
+            self.state.hovered = None;
+            self.state.path.clear();
+            return;
+        };
+
+        let (items, first) = rings[ring];
+        let index = radial_menu::slot_at(offset.y.atan2(offset.x), first, items.len());
+
+        self.state.hovered = Some((ring, index));
+        self.state.path.truncate(ring);
+
+        if !items[index].children().is_empty() {
+            self.state.path.push(index);
+        }
+    }
+
+    /// Returns the hovered item, if any.
+    fn hovered(&self) -> Option<&'a RadialItem<Message>> {
+        let (ring, index) = self.state.hovered?;
+        let rings = radial_menu::rings(self.items, &self.state.path);
+
+        rings.get(ring).and_then(|(items, _)| items.get(index))
+    }
+
+    /// Closes the menu without selecting an action.
+    fn dismiss(&mut self, shell: &mut Shell<'_, Message>) {
+        self.state.close();
+
+        if let Some(on_dismiss) = &self.on_dismiss {
+            shell.publish(on_dismiss.clone());
+        }
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for RadialMenuOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let radius = self.radius();
+        let label_height = self.text_size + 2.0 * LABEL_PADDING;
+
+        // The menu is centered on the press point, with room for the label
+        // below it, and shifted to be fully visible on screen
+        let mut menu = Node::new(Size::new(
+            2.0 * radius,
+            2.0 * radius + LABEL_GAP + label_height,
+        ));
+        menu.move_to(position - Vector::new(radius, radius));
+        menu.bounce(bounds);
+
+        Node::with_children(bounds, vec![menu])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(self.hovered().is_some(), false, false, false),
+            self.style,
+        );
+        let center = self.center(layout);
+        let rings = radial_menu::rings(self.items, &self.state.path);
+
+        // The outer rings are drawn first, so the inner ones cover them
+        for ring in (0..rings.len()).rev() {
+            let radius = DEAD_ZONE + (ring + 1) as f32 * RING_WIDTH;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: circle(center, radius),
+                    border_radius: radius.into(),
+                    border_width: appearance.ring_border_width,
+                    border_color: appearance.ring_border_color,
+                },
+                appearance.ring_background,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: circle(center, DEAD_ZONE),
+                border_radius: DEAD_ZONE.into(),
+                border_width: appearance.ring_border_width,
+                border_color: appearance.ring_border_color,
+            },
+            appearance.center_background,
+        );
+
+        for (ring, (items, first)) in rings.iter().enumerate() {
+            let distance = DEAD_ZONE + (ring as f32 + 0.5) * RING_WIDTH;
+
+            for (index, item) in items.iter().enumerate() {
+                let angle = radial_menu::angle_of(index, *first, items.len());
+                let (sin, cos) = angle.sin_cos();
+                let position = center + Vector::new(cos * distance, sin * distance);
+                let is_hovered = self.state.hovered == Some((ring, index));
+                let is_open = self.state.path.get(ring) == Some(&index);
+
+                if is_hovered || is_open {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: circle(position, ITEM_RADIUS),
+                            border_radius: ITEM_RADIUS.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.hovered_background,
+                    );
+                }
+
+                let color = if is_hovered || is_open {
+                    appearance.hovered_icon_color
+                } else {
+                    appearance.icon_color
+                };
+
+                renderer.fill_glyph(
+                    char::from(item.icon()),
+                    item.icon().font(),
+                    circle(position, ITEM_RADIUS),
+                    self.icon_size,
+                    color,
+                );
+
+                // Submenus are marked with a dot on their outer side
+                if !item.children().is_empty() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: circle(
+                                position
+                                    + Vector::new(
+                                        cos * (ITEM_RADIUS + INDICATOR_RADIUS),
+                                        sin * (ITEM_RADIUS + INDICATOR_RADIUS),
+                                    ),
+                                INDICATOR_RADIUS,
+                            ),
+                            border_radius: INDICATOR_RADIUS.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.indicator_color,
+                    );
+                }
+            }
+        }
+
+        let Some(item) = self.hovered() else {
+            return;
+        };
+
+        let menu = menu_layout(layout).bounds();
+        let label_width = renderer.measure_width(item.label(), self.text_size, self.font);
+        let width = label_width + 2.0 * LABEL_PADDING;
+
+        // Labels wider than the menu are kept inside it where they can be
+        let label_bounds = Rectangle {
+            x: (center.x - width / 2.0)
+                .min(menu.x + menu.width - width)
+                .max(menu.x),
+            y: center.y + self.radius() + LABEL_GAP,
+            width,
+            height: self.text_size + 2.0 * LABEL_PADDING,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: label_bounds,
+                border_radius: LABEL_PADDING.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.label_background,
+        );
+
+        renderer.fill_text(text::Text {
+            content: item.label(),
+            bounds: Rectangle {
+                x: label_bounds.center_x(),
+                y: label_bounds.center_y(),
+                ..label_bounds
+            },
+            size: self.text_size,
+            color: appearance.label_text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let menu = circle(self.center(layout), self.radius());
+
+        if let Some(dismiss) = Dismiss::from_event(&event, cursor_position, menu, &[]) {
+            self.dismiss(shell);
+            return dismiss.status();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                self.hover(layout, position);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Actions are selected once the press is released
+                self.hover(layout, cursor_position);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(message) = self.hovered().and_then(RadialItem::message) {
+                    shell.publish(message.clone());
+                    self.state.close();
+                } else if self.state.released && self.state.hovered.is_none() {
+                    // Clicking the center closes the menu
+                    self.dismiss(shell);
+                } else {
+                    // Releasing the opening press over nothing keeps the menu
+                    // open to pick an action with a click
+                    self.state.released = true;
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.state.hovered.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Returns the layout of the menu inside the layout of the overlay.
+fn menu_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .next()
+        .expect("Native: Layout should have a menu layout.")
+}
+
+/// Returns the bounds of the circle.
+fn circle(center: Point, radius: f32) -> Rectangle {
+    Rectangle {
+        x: center.x - radius,
+        y: center.y - radius,
+        width: 2.0 * radius,
+        height: 2.0 * radius,
+    }
+}
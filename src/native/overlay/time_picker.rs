@@ -10,7 +10,11 @@ use crate::{
         MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
         SECOND_RADIUS_PERCENTAGE,
     },
-    core::{clock, overlay::Position, time::Period},
+    core::{
+        clock,
+        overlay::{Dismiss, Position},
+        time::Period,
+    },
     native::IconText,
     style::style_state::StyleState,
     Icon,
@@ -60,6 +64,9 @@ where
     cancel_button: Button<'a, Message, Renderer<B, Theme>>,
     /// The submit button of the [`TimePickerOverlay`](TimePickerOverlay).
     submit_button: Button<'a, Message, Renderer<B, Theme>>,
+    /// The message that is send when the [`TimePickerOverlay`](TimePickerOverlay) is
+    /// dismissed by clicking outside of it or pressing the ESC key.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
     on_submit: &'a dyn Fn(Time) -> Message,
     /// The position of the [`TimePickerOverlay`](TimePickerOverlay).
@@ -99,7 +106,8 @@ where
                 .on_press(on_cancel.clone()),
             submit_button: Button::new(IconText::new(Icon::Check).width(Length::Fill))
                 .width(Length::Fill)
-                .on_press(on_cancel), // Sending a fake message
+                .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
             position,
             style,
@@ -593,6 +601,12 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        if let Some(dismiss) = Dismiss::from_event(&event, cursor_position, layout.bounds(), &[]) {
+            self.state.is_opened = false;
+            shell.publish(self.on_cancel.clone());
+            return dismiss.status();
+        }
+
         if event::Status::Captured
             == self.on_event_keyboard(&event, layout, cursor_position, shell, renderer, clipboard)
         {
@@ -635,6 +649,8 @@ where
             .next()
             .expect("Native: Layout should have a cancel button layout for a TimePicker");
 
+        let mut cancel_messages: Vec<Message> = Vec::new();
+
         let cancel_status = self.cancel_button.on_event(
             &mut self.tree.children[0],
             event.clone(),
@@ -642,9 +658,14 @@ where
             cursor_position,
             renderer,
             clipboard,
-            shell,
+            &mut Shell::new(&mut cancel_messages),
         );
 
+        if !cancel_messages.is_empty() {
+            self.state.is_opened = false;
+            shell.publish(self.on_cancel.clone());
+        }
+
         let submit_button_layout = children
             .next()
             .expect("Native: Layout should have a submit button layout for a TimePicker");
@@ -684,6 +705,7 @@ where
                 }
             };
 
+            self.state.is_opened = false;
             shell.publish((self.on_submit)(time));
         }
 
@@ -1593,23 +1615,34 @@ fn draw_digital_clock<'a, Message, B, Theme>(
 
 /// The state of the [`TimePickerOverlay`](TimePickerOverlay).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct State {
     /// The selected time of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) time: NaiveTime,
     /// Toggle if the cache needs to be cleared.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) clock_cache_needs_clearance: bool,
     /// The cache of the clock of the [`TimePickerOverlay`](TimePickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) clock_cache: canvas::Cache,
     /// Toggle the use of the 24h clock of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`](TimePickerOverlay).
     pub(crate) show_seconds: bool,
     /// The dragged clock element of the [`TimePickerOverlay`](TimePickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) clock_dragged: ClockDragged,
     /// The focus of the [`TimePickerOverlay`](TimePickerOverlay).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// If the [`TimePickerOverlay`](TimePickerOverlay) was opened by the application
+    /// with [`open`](crate::native::operation::open).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) is_opened: bool,
 }
 
 impl State {
@@ -1634,6 +1667,7 @@ impl Default for State {
             clock_dragged: ClockDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_opened: false,
         }
     }
 }
@@ -1743,6 +1777,12 @@ pub enum ClockDragged {
     Second,
 }
 
+impl Default for ClockDragged {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
 /// An enumeration of all focusable elements of the [`TimePickerOverlay`](TimePickerOverlay).
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Focus {
@@ -0,0 +1,427 @@
+//! Show timed notifications stacked in a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: toast*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::Node,
+    mouse, overlay, renderer, text,
+    time::{Duration, Instant},
+    touch, window, Background, Clipboard, Color, Event, Font, Layout, Point, Rectangle, Shell,
+    Size, Vector,
+};
+
+use crate::{
+    core::{renderer::GlyphRenderer, sizing::hit_target},
+    graphics::icons::Icon,
+    native::toast::{Corner, State, Toast},
+    style::{
+        style_state::{appearance, StyleState},
+        toast::StyleSheet,
+    },
+};
+
+/// The height of the indicator of the remaining time.
+const PROGRESS_HEIGHT: f32 = 3.0;
+
+/// The spacing between the title and the body of a toast.
+const TITLE_SPACING: f32 = 4.0;
+
+/// The overlay of the [`Toasts`](crate::native::toast::Toasts).
+#[allow(missing_debug_implementations)]
+pub struct ToastsOverlay<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The shown toasts.
+    toasts: &'a [Toast],
+    /// The state shared between [`Toasts`](crate::native::toast::Toasts) and
+    /// [`ToastsOverlay`](ToastsOverlay).
+    state: &'a mut State,
+    /// The function producing the message of pressing the toast at an index.
+    on_press: Option<&'a dyn Fn(usize) -> Message>,
+    /// The size of the content the toasts are stacked in a corner of.
+    anchor: Size,
+    /// The time a toast is shown before it is dismissed.
+    timeout: Duration,
+    /// The corner the toasts are stacked in.
+    corner: Corner,
+    /// The width of a toast.
+    width: f32,
+    /// The padding between the border and the text of a toast.
+    padding: f32,
+    /// The spacing between two toasts.
+    spacing: f32,
+    /// The gap between the stack and the edges of the content.
+    margin: f32,
+    /// The text size of the toasts.
+    text_size: Option<f32>,
+    /// The font of the toasts.
+    font: Font,
+    /// The style of the toasts.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ToastsOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ToastsOverlay`](ToastsOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        toasts: &'a [Toast],
+        state: &'a mut State,
+        on_press: Option<&'a dyn Fn(usize) -> Message>,
+        anchor: Size,
+        timeout: Duration,
+        corner: Corner,
+        width: f32,
+        padding: f32,
+        spacing: f32,
+        margin: f32,
+        text_size: Option<f32>,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        ToastsOverlay {
+            toasts,
+            state,
+            on_press,
+            anchor,
+            timeout,
+            corner,
+            width,
+            padding,
+            spacing,
+            margin,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`ToastsOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) placed in the content at
+    /// the given position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the time of the last redraw the toasts were advanced to.
+    fn now(&self) -> Instant {
+        self.state.now.unwrap_or_else(Instant::now)
+    }
+
+    /// Lays out a single toast with the given width.
+    ///
+    /// The children are the title, the close icon, the body and the indicator
+    /// of the remaining time.
+    fn layout_toast(&self, renderer: &Renderer, toast: &Toast, width: f32, text_size: f32) -> Node {
+        let padding = self.padding;
+        let inner_width = (width - 2.0 * padding).max(0.0);
+
+        let (title_width, title_height) = renderer.measure(
+            &toast.title,
+            text_size,
+            self.font,
+            Size::new(
+                (inner_width - text_size - TITLE_SPACING).max(0.0),
+                f32::INFINITY,
+            ),
+        );
+        let mut title = Node::new(Size::new(title_width, title_height));
+        title.move_to(Point::new(padding, padding));
+
+        let mut close = Node::new(Size::new(text_size, text_size));
+        close.move_to(Point::new(width - padding - text_size, padding));
+
+        let mut y = padding + title_height.max(text_size);
+
+        let mut body = if toast.body.is_empty() {
+            Node::new(Size::ZERO)
+        } else {
+            let (body_width, body_height) = renderer.measure(
+                &toast.body,
+                text_size,
+                self.font,
+                Size::new(inner_width, f32::INFINITY),
+            );
+            y += TITLE_SPACING;
+
+            Node::new(Size::new(body_width, body_height))
+        };
+        body.move_to(Point::new(padding, y));
+        y += body.size().height + padding / 2.0;
+
+        let mut progress = Node::new(Size::new(inner_width, PROGRESS_HEIGHT));
+        progress.move_to(Point::new(padding, y));
+        y += PROGRESS_HEIGHT + padding / 2.0;
+
+        Node::with_children(Size::new(width, y), vec![title, close, body, progress])
+    }
+
+    /// Returns the offset a toast is drawn at while it slides in or out.
+    fn slide(&self, bounds: Rectangle, visibility: f32) -> Vector {
+        let distance = (1.0 - visibility) * (bounds.width + self.margin);
+
+        if self.corner.is_left() {
+            Vector::new(-distance, 0.0)
+        } else {
+            Vector::new(distance, 0.0)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for ToastsOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> Node {
+        let now = self.now();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let width = self
+            .width
+            .min(self.anchor.width - 2.0 * self.margin)
+            .max(0.0);
+
+        // The newest toast is closest to the corner and the others move away
+        // from it by the share of the toasts in front of them that slid in
+        let mut nodes = Vec::with_capacity(self.toasts.len());
+        let mut offset = 0.0;
+        let mut height: f32 = 0.0;
+
+        for (toast, entry) in self.toasts.iter().zip(&self.state.entries).rev() {
+            let mut node = self.layout_toast(renderer, toast, width, text_size);
+            node.move_to(Point::new(0.0, offset));
+
+            height = height.max(offset + node.size().height);
+            offset += (node.size().height + self.spacing) * entry.visibility(now);
+            nodes.push(node);
+        }
+        nodes.reverse();
+
+        if !self.corner.is_top() {
+            for node in &mut nodes {
+                let bounds = node.bounds();
+                node.move_to(Point::new(0.0, height - bounds.y - bounds.height));
+            }
+        }
+
+        let x = if self.corner.is_left() {
+            position.x + self.margin
+        } else {
+            position.x + self.anchor.width - self.margin - width
+        };
+        let y = if self.corner.is_top() {
+            position.y + self.margin
+        } else {
+            position.y + self.anchor.height - self.margin - height
+        };
+
+        let mut node = Node::with_children(Size::new(width, height), nodes);
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let pressed = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        let mut status = event::Status::Ignored;
+
+        for (index, (toast_layout, entry)) in layout
+            .children()
+            .zip(self.state.entries.iter_mut())
+            .enumerate()
+        {
+            // Hovering a toast pauses its timeout
+            entry.hovered = !entry.is_closing() && toast_layout.bounds().contains(cursor_position);
+
+            if !pressed || !entry.hovered {
+                continue;
+            }
+
+            let close_bounds = toast_layout
+                .children()
+                .nth(1)
+                .expect("Native: Layout should have a close layout for a Toast")
+                .bounds();
+
+            if hit_target(close_bounds).contains(cursor_position) {
+                entry.close(Instant::now());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            } else if let Some(on_press) = self.on_press {
+                shell.publish(on_press(index));
+            }
+
+            status = event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let pointer = layout
+            .children()
+            .zip(&self.state.entries)
+            .filter(|(toast_layout, entry)| {
+                !entry.is_closing() && toast_layout.bounds().contains(cursor_position)
+            })
+            .any(|(toast_layout, _)| {
+                self.on_press.is_some()
+                    || toast_layout.children().nth(1).map_or(false, |close| {
+                        hit_target(close.bounds()).contains(cursor_position)
+                    })
+            });
+
+        if pointer {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let now = self.now();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for ((toast_layout, toast), entry) in
+            layout.children().zip(self.toasts).zip(&self.state.entries)
+        {
+            let visibility = entry.visibility(now);
+            if visibility <= 0.0 {
+                continue;
+            }
+
+            let offset = self.slide(toast_layout.bounds(), visibility);
+            let appearance = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(entry.hovered, false, false, false),
+                self.style,
+                toast.status,
+            );
+
+            // The toast fades in and out while it slides
+            let fade = |color: Color| Color {
+                a: color.a * visibility,
+                ..color
+            };
+            let Background::Color(background) = appearance.background;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: toast_layout.bounds() + offset,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: fade(appearance.border_color),
+                },
+                fade(background),
+            );
+
+            let mut children = toast_layout.children();
+
+            let title_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a title layout for a Toast")
+                .bounds()
+                + offset;
+            renderer.fill_text(text::Text {
+                content: &toast.title,
+                bounds: title_bounds,
+                size: text_size,
+                color: fade(appearance.title_color),
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+            });
+
+            let close_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a close layout for a Toast")
+                .bounds()
+                + offset;
+            renderer.fill_glyph(
+                char::from(Icon::X),
+                Icon::X.font(),
+                close_bounds,
+                close_bounds.height,
+                fade(appearance.close_color),
+            );
+
+            let body_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a body layout for a Toast")
+                .bounds()
+                + offset;
+            if !toast.body.is_empty() {
+                renderer.fill_text(text::Text {
+                    content: &toast.body,
+                    bounds: body_bounds,
+                    size: text_size,
+                    color: fade(appearance.body_color),
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                });
+            }
+
+            let progress_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a progress layout for a Toast")
+                .bounds()
+                + offset;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: progress_bounds,
+                    border_radius: (PROGRESS_HEIGHT / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                fade(appearance.progress_background),
+            );
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: progress_bounds.width * entry.remaining(self.timeout),
+                        ..progress_bounds
+                    },
+                    border_radius: (PROGRESS_HEIGHT / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                fade(appearance.progress_color),
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Show rich content in a tooltip while hovering a widget.
+//!
+//! The overlay also shows the card of a
+//! [`HoverCard`](crate::native::hover_card::HoverCard).
+//!
+//! *This API requires the following crate features to be activated: tooltip*
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, Alignment, Clipboard, Event, Layout, Point, Rectangle, Shell, Size,
+};
+use iced_native::{widget::Tree, Element};
+
+use crate::core::overlay::{Placement, Position};
+use crate::native::tooltip::State;
+use crate::style::style_state::StyleState;
+use crate::style::tooltip::Appearance;
+
+/// The overlay of the [`Tooltip`](crate::native::tooltip::Tooltip) and the
+/// [`HoverCard`](crate::native::hover_card::HoverCard).
+#[allow(missing_debug_implementations)]
+pub struct TooltipOverlay<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+{
+    /// The state of the content of the [`TooltipOverlay`](TooltipOverlay).
+    state: &'a mut Tree,
+    /// The content of the [`TooltipOverlay`](TooltipOverlay).
+    content: Element<'a, Message, Renderer>,
+    /// The state shared between [`Tooltip`](crate::native::tooltip::Tooltip)
+    /// and [`TooltipOverlay`](TooltipOverlay).
+    tooltip: &'a mut State,
+    /// The size of the anchor the tooltip is placed at.
+    anchor: Size,
+    /// The preferred side of the anchor the tooltip is placed at.
+    placement: Placement,
+    /// The alignment of the tooltip along the side of the anchor.
+    alignment: Alignment,
+    /// The gap between the anchor and the tooltip.
+    gap: f32,
+    /// The padding between the bubble and its content.
+    padding: f32,
+    /// The maximal width of the bubble.
+    max_width: f32,
+    /// The appearance of the bubble in the state of the
+    /// [`TooltipOverlay`](TooltipOverlay), resolved by the style sheet of
+    /// the widget showing it.
+    appearance: Box<dyn Fn(&Renderer::Theme, StyleState) -> Appearance + 'a>,
+}
+
+impl<'a, Message, Renderer> TooltipOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    /// Creates a new [`TooltipOverlay`](TooltipOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new<C, A>(
+        state: &'a mut Tree,
+        content: C,
+        tooltip: &'a mut State,
+        anchor: Size,
+        placement: Placement,
+        alignment: Alignment,
+        gap: f32,
+        padding: f32,
+        max_width: f32,
+        appearance: A,
+    ) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        A: 'a + Fn(&Renderer::Theme, StyleState) -> Appearance,
+    {
+        TooltipOverlay {
+            state,
+            content: content.into(),
+            tooltip,
+            anchor,
+            placement,
+            alignment,
+            gap,
+            padding,
+            max_width,
+            appearance: Box::new(appearance),
+        }
+    }
+
+    /// Turn this [`TooltipOverlay`] into an overlay
+    /// [`Element`](iced_native::overlay::Element) anchored at the given
+    /// position.
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for TooltipOverlay<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let padding = self.padding;
+
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (self.max_width.min(bounds.width) - 2.0 * padding).max(0.0),
+                (bounds.height - 2.0 * padding).max(0.0),
+            ),
+        );
+        let mut content = self.content.as_widget().layout(renderer, &limits);
+        content.move_to(Point::new(padding, padding));
+
+        let size = Size::new(
+            content.bounds().width + 2.0 * padding,
+            content.bounds().height + 2.0 * padding,
+        );
+
+        let mut node = Node::with_children(size, vec![content]);
+        node.place(
+            Rectangle::new(position, self.anchor),
+            self.placement,
+            self.alignment,
+            self.gap,
+            bounds,
+        );
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        // Hovering the tooltip keeps it open
+        self.tooltip.over_tooltip = layout.bounds().contains(cursor_position);
+
+        self.content.as_widget_mut().on_event(
+            self.state,
+            event,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.state,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a content layout.");
+
+        let style_sheet = (self.appearance)(
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+        );
+
+        // Bubble
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style_sheet.border_radius,
+                border_width: style_sheet.border_width,
+                border_color: style_sheet.border_color,
+            },
+            style_sheet.background,
+        );
+
+        // Content
+        self.content.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.text_color,
+            },
+            content_layout,
+            cursor_position,
+            &bounds,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.state,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+        )
+    }
+}
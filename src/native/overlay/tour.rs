@@ -0,0 +1,436 @@
+//! Guide the user through the widgets of an application with coach marks.
+//!
+//! *This API requires the following crate features to be activated: tour*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::Node,
+    mouse, overlay, renderer, text, touch, Alignment, Clipboard, Color, Event, Font, Layout, Point,
+    Rectangle, Shell, Size,
+};
+
+use crate::{
+    core::overlay::Position,
+    native::tour::{self, TourStep},
+    style::{
+        style_state::{appearance, StyleState},
+        tour::StyleSheet,
+    },
+};
+
+/// The padding between the card and its content.
+const CARD_PADDING: f32 = 16.0;
+
+/// The spacing between the title, the description and the buttons.
+const SPACING: f32 = 8.0;
+
+/// The padding around the labels of the buttons.
+const BUTTON_PADDING: f32 = 6.0;
+
+/// The scale of the title relative to the text size.
+const TITLE_SCALE: f32 = 1.25;
+
+/// The overlay of a [`Tour`](crate::native::tour::Tour).
+#[allow(missing_debug_implementations)]
+pub struct TourOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The current step.
+    step: &'a TourStep,
+    /// The position of the current step in the tour, like `2 of 5`.
+    counter: String,
+    /// The cutout around the target, if it was found.
+    cutout: Option<Rectangle>,
+    /// The labels and messages of the skip, the back and the next button. The
+    /// buttons without a message are hidden.
+    buttons: [(&'a str, Option<Message>); 3],
+    /// The gap between the cutout and the card.
+    gap: f32,
+    /// The width of the card.
+    card_width: f32,
+    /// The text size of the card.
+    text_size: Option<f32>,
+    /// The font of the card.
+    font: Font,
+    /// The style of the tour.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TourOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TourOverlay`](TourOverlay).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        step: &'a TourStep,
+        counter: String,
+        cutout: Option<Rectangle>,
+        buttons: [(&'a str, Option<Message>); 3],
+        gap: f32,
+        card_width: f32,
+        text_size: Option<f32>,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        TourOverlay {
+            step,
+            counter,
+            cutout,
+            buttons,
+            gap,
+            card_width,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`TourOverlay`] into an overlay
+    /// [`Element`](overlay::Element).
+    pub fn overlay(self, position: Point) -> overlay::Element<'a, Message, Renderer>
+    where
+        Renderer: 'a,
+    {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Lays out the card.
+    ///
+    /// The children are the title, the description, the counter and the skip,
+    /// the back and the next button.
+    fn layout_card(&self, renderer: &Renderer, text_size: f32) -> Node {
+        let width = self.card_width;
+        let inner = Size::new((width - 2.0 * CARD_PADDING).max(0.0), f32::INFINITY);
+
+        let (title_width, title_height) =
+            renderer.measure(&self.step.title, text_size * TITLE_SCALE, self.font, inner);
+        let mut title = Node::new(Size::new(title_width, title_height));
+        title.move_to(Point::new(CARD_PADDING, CARD_PADDING));
+
+        let (description_width, description_height) =
+            renderer.measure(&self.step.description, text_size, self.font, inner);
+        let mut description = Node::new(Size::new(description_width, description_height));
+        description.move_to(Point::new(
+            CARD_PADDING,
+            CARD_PADDING + title_height + SPACING,
+        ));
+
+        let footer_y = CARD_PADDING + title_height + SPACING + description_height + SPACING;
+        let footer_height = text_size + 2.0 * BUTTON_PADDING;
+
+        let (counter_width, counter_height) = renderer.measure(
+            &self.counter,
+            text_size,
+            self.font,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        );
+        let mut counter = Node::new(Size::new(counter_width, counter_height));
+        counter.move_to(Point::new(
+            CARD_PADDING,
+            footer_y + (footer_height - counter_height) / 2.0,
+        ));
+
+        // The buttons are lined up from the right edge of the card
+        let mut x = width - CARD_PADDING;
+        let mut buttons: Vec<Node> = self
+            .buttons
+            .iter()
+            .rev()
+            .map(|(label, message)| {
+                if message.is_none() {
+                    return Node::new(Size::ZERO);
+                }
+
+                let label_width = renderer.measure_width(label, text_size, self.font);
+                let mut button =
+                    Node::new(Size::new(label_width + 2.0 * BUTTON_PADDING, footer_height));
+                x -= button.size().width;
+                button.move_to(Point::new(x, footer_y));
+                x -= SPACING;
+
+                button
+            })
+            .collect();
+        buttons.reverse();
+
+        let mut children = vec![title, description, counter];
+        children.extend(buttons);
+
+        Node::with_children(
+            Size::new(width, footer_y + footer_height + CARD_PADDING),
+            children,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for TourOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, _position: Point) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let mut card = self.layout_card(renderer, text_size);
+
+        match self.cutout {
+            Some(cutout) => card.place(
+                cutout,
+                self.step.placement,
+                Alignment::Center,
+                self.gap,
+                bounds,
+            ),
+            None => {
+                card.center_and_bounce(Point::new(bounds.width / 2.0, bounds.height / 2.0), bounds)
+            }
+        }
+
+        Node::with_children(bounds, vec![card])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                card_layout(layout).bounds().contains(cursor_position),
+                false,
+                false,
+                false,
+            ),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let bounds = layout.bounds();
+
+        let dimmed = self.cutout.map_or_else(
+            || vec![bounds],
+            |cutout| tour::backdrop(bounds, cutout).to_vec(),
+        );
+
+        for part in dimmed {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: part,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.backdrop,
+            );
+        }
+
+        if let Some(cutout) = self.cutout {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: cutout,
+                    border_radius: appearance.highlight_border_radius,
+                    border_width: appearance.highlight_border_width,
+                    border_color: appearance.highlight_border_color,
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        let card = card_layout(layout);
+        let mut children = card.children();
+        let title = children
+            .next()
+            .expect("Graphics: Layout should have a title layout for a Tour")
+            .bounds();
+        let description = children
+            .next()
+            .expect("Graphics: Layout should have a description layout for a Tour")
+            .bounds();
+        let counter = children
+            .next()
+            .expect("Graphics: Layout should have a counter layout for a Tour")
+            .bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: card.bounds(),
+                border_radius: appearance.card_border_radius,
+                border_width: appearance.card_border_width,
+                border_color: appearance.card_border_color,
+            },
+            appearance.card_background,
+        );
+
+        let texts = [
+            (
+                &self.step.title,
+                title,
+                text_size * TITLE_SCALE,
+                appearance.title_color,
+            ),
+            (
+                &self.step.description,
+                description,
+                text_size,
+                appearance.text_color,
+            ),
+            (&self.counter, counter, text_size, appearance.counter_color),
+        ];
+
+        for (content, bounds, size, color) in texts {
+            renderer.fill_text(text::Text {
+                content,
+                bounds,
+                size,
+                color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+            });
+        }
+
+        let count = self.buttons.len();
+
+        for (index, ((label, _), button)) in self.buttons.iter().zip(children).enumerate() {
+            let bounds = button.bounds();
+
+            if bounds.width <= 0.0 {
+                continue;
+            }
+
+            // The next button is the primary one, the others are flat
+            let is_primary = index + 1 == count;
+            let is_hovered = bounds.contains(cursor_position);
+            let (background, text_color) = match (is_primary, is_hovered) {
+                (true, false) => (appearance.button_background, appearance.button_text_color),
+                (true, true) => (
+                    appearance.button_hovered_background,
+                    appearance.button_text_color,
+                ),
+                (false, false) => (
+                    Color::TRANSPARENT.into(),
+                    appearance.secondary_button_text_color,
+                ),
+                (false, true) => (
+                    appearance.secondary_button_hovered_background,
+                    appearance.secondary_button_text_color,
+                ),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.button_border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let message = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => match &self.buttons[0] {
+                (_, Some(skip)) => Some(skip),
+                (_, None) => return event::Status::Ignored,
+            },
+            Event::Mouse(_) | Event::Touch(_) => {
+                // The target stays usable through the cutout
+                if !self.is_over(layout, cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let pressed = matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerPressed { .. })
+                );
+
+                self.buttons
+                    .iter()
+                    .zip(card_layout(layout).children().skip(3))
+                    .find(|(_, button)| pressed && button.bounds().contains(cursor_position))
+                    .and_then(|((_, message), _)| message.as_ref())
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        if let Some(message) = message {
+            shell.publish(message.clone());
+        }
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_button = card_layout(layout)
+            .children()
+            .skip(3)
+            .any(|button| button.bounds().width > 0.0 && button.bounds().contains(cursor_position));
+
+        if is_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        let in_cutout = self
+            .cutout
+            .map_or(false, |cutout| cutout.contains(cursor_position));
+
+        layout.bounds().contains(cursor_position) && !in_cutout
+    }
+}
+
+/// Returns the layout of the card inside the layout of the overlay.
+fn card_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .next()
+        .expect("Native: Layout should have a card layout.")
+}
@@ -0,0 +1,535 @@
+//! Navigate between the pages of a long list.
+//!
+//! *This API requires the following crate features to be activated: pagination*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::Tree,
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::pagination::{Appearance, StyleSheet};
+
+/// The default number of pages shown on each side of the current page.
+const DEFAULT_SIBLINGS: usize = 1;
+
+/// The default padding around the label of a button.
+const DEFAULT_PADDING: f32 = 6.0;
+
+/// The default spacing between two buttons.
+const DEFAULT_SPACING: f32 = 4.0;
+
+/// The space between the page buttons and the page size selector.
+const PAGE_SIZE_GAP: f32 = 16.0;
+
+/// The label standing in for the collapsed pages.
+const ELLIPSIS: &str = "\u{2026}";
+
+/// A row of buttons to go to the first, the previous, a numbered, the next or
+/// the last page.
+///
+/// Pages are counted from zero and shown counted from one. When there are
+/// many pages, the ones far from the current page collapse into an ellipsis.
+/// An optional selector sets the number of entries on a page.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::pagination;
+/// #
+/// # pub type Pagination<'a, Message> = pagination::Pagination<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Page(usize),
+///     PageSize(usize),
+/// }
+///
+/// let pagination = Pagination::new(42, 3, Message::Page)
+///     .page_sizes(vec![10, 25, 50], 25, Message::PageSize);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Pagination<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The number of pages.
+    pages: usize,
+    /// The current page.
+    current: usize,
+    /// The function producing the message to go to a page.
+    on_page: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The selectable page sizes, the selected one and the function producing
+    /// the message to select a page size.
+    page_sizes: Option<(Vec<usize>, usize, Box<dyn Fn(usize) -> Message + 'a>)>,
+    /// The number of pages shown on each side of the current page.
+    siblings: usize,
+    /// The padding around the label of a button.
+    padding: f32,
+    /// The spacing between two buttons.
+    spacing: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the pagination.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Pagination<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Pagination`](Pagination).
+    ///
+    /// It expects:
+    ///     * the number of pages.
+    ///     * the current page, counted from zero.
+    ///     * the function that will be called with the page to go to when a
+    ///         button is pressed.
+    pub fn new<F>(pages: usize, current: usize, on_page: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Pagination {
+            pages,
+            current,
+            on_page: Box::new(on_page),
+            page_sizes: None,
+            siblings: DEFAULT_SIBLINGS,
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a selector of the number of entries on a page to the
+    /// [`Pagination`](Pagination).
+    ///
+    /// It expects:
+    ///     * the selectable page sizes.
+    ///     * the selected page size.
+    ///     * the function that will be called with the page size to select
+    ///         when it is pressed.
+    #[must_use]
+    pub fn page_sizes<F>(mut self, sizes: Vec<usize>, selected: usize, on_page_size: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.page_sizes = Some((sizes, selected, Box::new(on_page_size)));
+        self
+    }
+
+    /// Sets the number of pages shown on each side of the current page.
+    #[must_use]
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Sets the padding around the label of a button.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the spacing between two buttons.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Pagination`](Pagination).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the items of the [`Pagination`](Pagination) from left to right.
+    fn items(&self) -> Vec<Item> {
+        let mut items = vec![Item::First, Item::Previous];
+
+        items.extend(
+            pages(self.pages, self.current, self.siblings)
+                .into_iter()
+                .map(|page| page.map_or(Item::Ellipsis, Item::Page)),
+        );
+        items.extend([Item::Next, Item::Last]);
+
+        if let Some((sizes, _, _)) = &self.page_sizes {
+            items.extend(sizes.iter().copied().map(Item::PageSize));
+        }
+
+        items
+    }
+
+    /// Returns the page an item leads to, if it is enabled.
+    fn target(&self, item: Item) -> Option<usize> {
+        let last = self.pages.checked_sub(1)?;
+
+        match item {
+            Item::First | Item::Previous if self.current == 0 => None,
+            Item::Next | Item::Last if self.current >= last => None,
+            Item::First => Some(0),
+            Item::Previous => Some((self.current - 1).min(last)),
+            Item::Next => Some(self.current + 1),
+            Item::Last => Some(last),
+            Item::Page(page) if page != self.current => Some(page),
+            Item::Page(_) | Item::Ellipsis | Item::PageSize(_) => None,
+        }
+    }
+
+    /// Returns true if the item can be pressed.
+    fn is_enabled(&self, item: Item) -> bool {
+        match item {
+            Item::PageSize(_) => !self.is_selected(item),
+            _ => self.target(item).is_some(),
+        }
+    }
+
+    /// Returns true if the item is the current page or the selected page
+    /// size.
+    fn is_selected(&self, item: Item) -> bool {
+        match item {
+            Item::Page(page) => page == self.current,
+            Item::PageSize(size) => self
+                .page_sizes
+                .as_ref()
+                .map_or(false, |(_, selected, _)| size == *selected),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Pagination<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = text_size + 2.0 * self.padding;
+        let mut x = 0.0;
+        let mut previous = None;
+
+        let nodes = self
+            .items()
+            .into_iter()
+            .map(|item| {
+                x += match previous {
+                    None => 0.0,
+                    Some(Item::PageSize(_)) => self.spacing,
+                    Some(_) if matches!(item, Item::PageSize(_)) => PAGE_SIZE_GAP,
+                    Some(_) => self.spacing,
+                };
+                previous = Some(item);
+
+                let (label_width, _) = renderer.measure(
+                    &item.label(),
+                    text_size,
+                    self.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+                let width = (label_width + 2.0 * self.padding).max(height);
+
+                let mut node = Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+                x += width;
+
+                node
+            })
+            .collect();
+
+        Node::with_children(limits.resolve(Size::new(x, height)), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let pressed = self
+            .items()
+            .into_iter()
+            .zip(layout.children())
+            .find(|(_, button)| button.bounds().contains(cursor_position))
+            .map(|(item, _)| item);
+
+        match pressed {
+            Some(Item::PageSize(size)) => {
+                if let Some((_, selected, on_page_size)) = &self.page_sizes {
+                    if size != *selected {
+                        shell.publish(on_page_size(size));
+                    }
+                }
+                event::Status::Captured
+            }
+            Some(item) => {
+                if let Some(page) = self.target(item) {
+                    shell.publish((self.on_page)(page));
+                }
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let hovered = self
+            .items()
+            .into_iter()
+            .zip(layout.children())
+            .find(|(_, button)| button.bounds().contains(cursor_position));
+
+        match hovered {
+            Some((item, _)) if self.is_enabled(item) => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                layout.bounds().contains(cursor_position),
+                false,
+                false,
+                false,
+            ),
+            self.style,
+        );
+
+        for (item, button) in self.items().into_iter().zip(layout.children()) {
+            let bounds = button.bounds();
+
+            let (background, text_color) = if self.is_selected(item) {
+                (
+                    Some(appearance.selected_background),
+                    appearance.selected_text_color,
+                )
+            } else if !self.is_enabled(item) {
+                (
+                    (item != Item::Ellipsis).then_some(appearance.background),
+                    appearance.disabled_text_color,
+                )
+            } else if bounds.contains(cursor_position) {
+                (
+                    Some(appearance.hovered_background),
+                    appearance.hovered_text_color,
+                )
+            } else {
+                (Some(appearance.background), appearance.text_color)
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(text::Text {
+                content: &item.label(),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Pagination<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(pagination: Pagination<'a, Message, Renderer>) -> Self {
+        Element::new(pagination)
+    }
+}
+
+/// An item of a [`Pagination`](Pagination).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Item {
+    /// The button going to the first page.
+    First,
+    /// The button going to the previous page.
+    Previous,
+    /// The button going to a page.
+    Page(usize),
+    /// The collapsed pages.
+    Ellipsis,
+    /// The button going to the next page.
+    Next,
+    /// The button going to the last page.
+    Last,
+    /// The button selecting a page size.
+    PageSize(usize),
+}
+
+impl Item {
+    /// Returns the label of the item.
+    fn label(self) -> String {
+        match self {
+            Self::First => String::from("\u{00AB}"),
+            Self::Previous => String::from("\u{2039}"),
+            Self::Page(page) => (page + 1).to_string(),
+            Self::Ellipsis => String::from(ELLIPSIS),
+            Self::Next => String::from("\u{203A}"),
+            Self::Last => String::from("\u{00BB}"),
+            Self::PageSize(size) => size.to_string(),
+        }
+    }
+}
+
+/// Returns the pages shown for the current page, where `None` stands for
+/// collapsed pages.
+///
+/// The first and the last page are always shown together with the siblings
+/// on each side of the current page. Their count stays the same while the
+/// current page moves, so the buttons do not jump around.
+fn pages(count: usize, current: usize, siblings: usize) -> Vec<Option<usize>> {
+    // The first and the last page, two ellipses, the current page and its
+    // siblings
+    let slots = 2 * siblings + 5;
+
+    if count <= slots {
+        return (0..count).map(Some).collect();
+    }
+
+    let last = count - 1;
+    let current = current.min(last);
+
+    if current < siblings + 3 {
+        (0..slots - 2).map(Some).chain([None, Some(last)]).collect()
+    } else if current + siblings + 3 > last {
+        [Some(0), None]
+            .into_iter()
+            .chain((count - (slots - 2)..count).map(Some))
+            .collect()
+    } else {
+        [Some(0), None]
+            .into_iter()
+            .chain((current - siblings..=current + siblings).map(Some))
+            .chain([None, Some(last)])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pages;
+
+    #[test]
+    fn shows_all_pages_that_fit() {
+        assert!(pages(0, 0, 1).is_empty());
+        assert_eq!(pages(7, 3, 1), (0..7).map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn collapses_the_pages_far_from_the_current() {
+        assert_eq!(
+            pages(10, 0, 1),
+            [Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(9)]
+        );
+        assert_eq!(
+            pages(10, 4, 1),
+            [Some(0), None, Some(3), Some(4), Some(5), None, Some(9)]
+        );
+        assert_eq!(
+            pages(10, 6, 1),
+            [Some(0), None, Some(5), Some(6), Some(7), Some(8), Some(9)]
+        );
+        assert_eq!(
+            pages(100, 50, 2),
+            [
+                Some(0),
+                None,
+                Some(48),
+                Some(49),
+                Some(50),
+                Some(51),
+                Some(52),
+                None,
+                Some(99)
+            ]
+        );
+    }
+}
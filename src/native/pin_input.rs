@@ -0,0 +1,627 @@
+//! Type a code into a row of boxes holding one character each.
+//!
+//! *This API requires the following crate features to be activated: pin_input*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::pin_input::{Appearance, StyleSheet};
+
+/// The default size of a box.
+const DEFAULT_BOX_SIZE: f32 = 40.0;
+
+/// The default spacing between two boxes.
+const DEFAULT_SPACING: f32 = 8.0;
+
+/// The character shown instead of the typed ones in masked mode.
+const MASK: &str = "\u{2022}";
+
+/// The width of the cursor.
+const CURSOR_WIDTH: f32 = 2.0;
+
+/// A row of boxes to type a code like a PIN or a one-time password into, one
+/// character per box.
+///
+/// Typing a character fills the box with the cursor and moves to the next
+/// one. Backspace empties the box with the cursor or, if it is empty, the box
+/// before it, the arrow keys move between the filled boxes and pasting fills
+/// the boxes from the cursor on. Once every box is filled, the
+/// [`on_complete`](PinInput::on_complete) message carries the code.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::pin_input;
+/// #
+/// # pub type PinInput<'a, Message> = pin_input::PinInput<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Typed(String),
+///     Completed(String),
+/// }
+///
+/// let pin_input = PinInput::new(6, "12", Message::Typed)
+///     .on_complete(Message::Completed)
+///     .masked(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PinInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The number of boxes.
+    length: usize,
+    /// The characters typed so far.
+    value: Vec<char>,
+    /// The function producing the message when the characters change.
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    /// The function producing the message with the code once every box is
+    /// filled.
+    on_complete: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// Whether the characters are hidden.
+    masked: bool,
+    /// Whether only digits may be typed.
+    numeric: bool,
+    /// The size of a box.
+    box_size: f32,
+    /// The spacing between two boxes.
+    spacing: f32,
+    /// The text size of the characters.
+    text_size: Option<f32>,
+    /// The font of the characters.
+    font: Font,
+    /// The id of the [`PinInput`](PinInput) used to focus it.
+    id: Option<Id>,
+    /// The style of the boxes.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> PinInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`PinInput`](PinInput).
+    ///
+    /// It expects:
+    ///     * the number of boxes
+    ///     * the characters typed so far
+    ///     * the function producing the message when the characters change
+    pub fn new<F>(length: usize, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        let length = length.max(1);
+
+        PinInput {
+            length,
+            value: value.chars().take(length).collect(),
+            on_input: Box::new(on_input),
+            on_complete: None,
+            masked: false,
+            numeric: true,
+            box_size: DEFAULT_BOX_SIZE,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            id: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the code once every box
+    /// is filled.
+    #[must_use]
+    pub fn on_complete<F>(mut self, on_complete: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_complete = Some(Box::new(on_complete));
+        self
+    }
+
+    /// Sets whether the characters are hidden behind dots.
+    #[must_use]
+    pub fn masked(mut self, masked: bool) -> Self {
+        self.masked = masked;
+        self
+    }
+
+    /// Sets whether only digits may be typed, or letters too.
+    #[must_use]
+    pub fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets the size of a box of the [`PinInput`](PinInput).
+    #[must_use]
+    pub fn box_size(mut self, box_size: f32) -> Self {
+        self.box_size = box_size;
+        self
+    }
+
+    /// Sets the spacing between two boxes of the [`PinInput`](PinInput).
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`PinInput`](PinInput).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`PinInput`](PinInput).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Id`](Id) of the [`PinInput`](PinInput) to focus it with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`PinInput`](PinInput).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns whether the character may be typed.
+    fn accepts(&self, character: char) -> bool {
+        if self.numeric {
+            character.is_ascii_digit()
+        } else {
+            character.is_alphanumeric()
+        }
+    }
+
+    /// Returns the box the cursor is in.
+    fn cursor(&self, state: &State) -> usize {
+        state.cursor.min(self.value.len()).min(self.length - 1)
+    }
+
+    /// Returns the bounds of the box at the index.
+    fn box_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + index as f32 * (self.box_size + self.spacing),
+            y: bounds.y,
+            width: self.box_size,
+            height: self.box_size,
+        }
+    }
+
+    /// Publishes the edited characters and moves the cursor.
+    fn edit(&self, state: &mut State, shell: &mut Shell<'_, Message>, edit: Edit) {
+        state.cursor = edit.cursor;
+
+        if edit.value == self.value {
+            return;
+        }
+
+        let code: String = edit.value.iter().collect();
+        shell.publish((self.on_input)(code.clone()));
+
+        if edit.value.len() == self.length {
+            if let Some(on_complete) = &self.on_complete {
+                shell.publish(on_complete(code));
+            }
+        }
+    }
+
+    /// Handles a key pressed while the [`PinInput`](PinInput) is focused.
+    ///
+    /// Returns the edit made by the key, if any.
+    fn key_pressed(
+        &self,
+        state: &mut State,
+        key_code: keyboard::KeyCode,
+        clipboard: &mut dyn Clipboard,
+    ) -> Option<Edit> {
+        let cursor = self.cursor(state);
+        let last = self.value.len().min(self.length - 1);
+
+        let moved = |cursor| Edit {
+            value: self.value.clone(),
+            cursor,
+        };
+
+        match key_code {
+            keyboard::KeyCode::Backspace => Some(backspace(&self.value, cursor)),
+            keyboard::KeyCode::Delete => Some(delete(&self.value, cursor)),
+            keyboard::KeyCode::Left => Some(moved(cursor.saturating_sub(1))),
+            keyboard::KeyCode::Right => Some(moved((cursor + 1).min(last))),
+            keyboard::KeyCode::Home => Some(moved(0)),
+            keyboard::KeyCode::End => Some(moved(last)),
+            keyboard::KeyCode::V if state.modifiers.command() => {
+                let pasted: Vec<char> = clipboard
+                    .read()?
+                    .chars()
+                    .filter(|&character| self.accepts(character))
+                    .collect();
+
+                Some(fill(&self.value, cursor, &pasted, self.length))
+            }
+            keyboard::KeyCode::Escape => {
+                state.focus.unfocus();
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for PinInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let width = self.length as f32 * self.box_size + (self.length - 1) as f32 * self.spacing;
+
+        Node::new(limits.resolve(Size::new(width, self.box_size)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let pressed = bounds.contains(cursor_position);
+                state.focus.press(pressed);
+
+                if !pressed {
+                    return event::Status::Ignored;
+                }
+
+                // Boxes after the typed characters can't be filled yet
+                let index = ((cursor_position.x - bounds.x) / (self.box_size + self.spacing))
+                    .max(0.0) as usize;
+                state.cursor = index;
+                state.cursor = self.cursor(state);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(character))
+                if state.focus.is_focused() && !state.modifiers.command() =>
+            {
+                if self.accepts(character) {
+                    let edit = fill(&self.value, self.cursor(state), &[character], self.length);
+                    self.edit(state, shell, edit);
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.focus.is_focused() =>
+            {
+                match self.key_pressed(state, key_code, clipboard) {
+                    Some(edit) => {
+                        self.edit(state, shell, edit);
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let cursor = state.focus.is_focused().then(|| self.cursor(state));
+        let mut buffer = [0; 4];
+
+        for index in 0..self.length {
+            let box_bounds = self.box_bounds(bounds, index);
+            let character = self.value.get(index);
+
+            let is_focused = cursor == Some(index);
+            let appearance = if !is_focused && character.is_some() {
+                theme.filled(self.style)
+            } else {
+                appearance!(
+                    StyleSheet,
+                    theme,
+                    StyleState::new(
+                        box_bounds.contains(cursor_position),
+                        false,
+                        is_focused,
+                        false,
+                    ),
+                    self.style,
+                )
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: box_bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+
+            if let Some(character) = character {
+                let content = if self.masked {
+                    MASK
+                } else {
+                    character.encode_utf8(&mut buffer)
+                };
+
+                renderer.fill_text(text::Text {
+                    content,
+                    bounds: Rectangle {
+                        x: box_bounds.center_x(),
+                        y: box_bounds.center_y(),
+                        ..box_bounds
+                    },
+                    size: text_size,
+                    color: appearance.text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                });
+            } else if cursor == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: box_bounds.center_x() - CURSOR_WIDTH / 2.0,
+                            y: box_bounds.center_y() - text_size / 2.0,
+                            width: CURSOR_WIDTH,
+                            height: text_size,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.cursor_color,
+                );
+            }
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Renderer> From<PinInput<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(pin_input: PinInput<'a, Message, Renderer>) -> Self {
+        Element::new(pin_input)
+    }
+}
+
+/// The state of a [`PinInput`](PinInput).
+#[derive(Debug, Default)]
+struct State {
+    /// The box the cursor is in.
+    cursor: usize,
+    /// The keyboard focus.
+    focus: Focus,
+    /// The keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+}
+
+/// The characters of a [`PinInput`](PinInput) after an edit, with the box the
+/// cursor moved to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Edit {
+    /// The characters.
+    value: Vec<char>,
+    /// The box the cursor is in.
+    cursor: usize,
+}
+
+/// Fills the boxes from the cursor on with the typed characters, keeping the
+/// characters after them, and moves the cursor behind them.
+fn fill(value: &[char], cursor: usize, typed: &[char], length: usize) -> Edit {
+    let end = (cursor + typed.len()).min(length);
+
+    let mut filled = value[..cursor].to_vec();
+    filled.extend_from_slice(&typed[..end - cursor]);
+    filled.extend(value.iter().skip(end));
+
+    Edit {
+        cursor: end.min(length - 1),
+        value: filled,
+    }
+}
+
+/// Empties the box with the cursor, or the one before it if it is empty.
+fn backspace(value: &[char], cursor: usize) -> Edit {
+    if cursor < value.len() {
+        return delete(value, cursor);
+    }
+
+    let mut value = value.to_vec();
+    let _ = value.pop();
+
+    Edit {
+        cursor: value.len(),
+        value,
+    }
+}
+
+/// Empties the box with the cursor, moving the characters after it.
+fn delete(value: &[char], cursor: usize) -> Edit {
+    let mut value = value.to_vec();
+    if cursor < value.len() {
+        let _ = value.remove(cursor);
+    }
+
+    Edit { value, cursor }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backspace, fill, Edit};
+
+    /// Returns the characters of the text.
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn typing_advances_to_the_next_box() {
+        assert_eq!(
+            fill(&chars("12"), 2, &['3'], 4),
+            Edit {
+                value: chars("123"),
+                cursor: 3
+            }
+        );
+
+        // The cursor stays in the last box once it is filled
+        assert_eq!(
+            fill(&chars("123"), 3, &['4'], 4),
+            Edit {
+                value: chars("1234"),
+                cursor: 3
+            }
+        );
+
+        // Typing into a filled box replaces its character
+        assert_eq!(
+            fill(&chars("1234"), 1, &['9'], 4),
+            Edit {
+                value: chars("1934"),
+                cursor: 2
+            }
+        );
+    }
+
+    #[test]
+    fn pasting_fills_the_boxes_from_the_cursor() {
+        assert_eq!(
+            fill(&chars("1"), 1, &chars("98765"), 4),
+            Edit {
+                value: chars("1987"),
+                cursor: 3
+            }
+        );
+    }
+
+    #[test]
+    fn backspace_moves_back_from_an_empty_box() {
+        assert_eq!(
+            backspace(&chars("12"), 2),
+            Edit {
+                value: chars("1"),
+                cursor: 1
+            }
+        );
+        assert_eq!(
+            backspace(&chars("12"), 0),
+            Edit {
+                value: chars("2"),
+                cursor: 0
+            }
+        );
+        assert_eq!(
+            backspace(&[], 0),
+            Edit {
+                value: Vec::new(),
+                cursor: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Show content in a bubble anchored to another widget.
+//!
+//! *This API requires the following crate features to be activated: popover*
+use iced_native::{
+    event, mouse,
+    widget::{Operation, Tree},
+    Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::overlay::{popover::PopoverOverlay, Layers};
+
+pub use crate::{core::overlay::Placement, style::popover::StyleSheet};
+
+/// The default padding between the bubble and the content of a [`Popover`](Popover).
+const DEFAULT_PADDING: f32 = 8.0;
+
+/// The default gap between the anchor and the bubble of a [`Popover`](Popover).
+const DEFAULT_GAP: f32 = 4.0;
+
+/// A popover showing content in a bubble anchored to the wrapped widget.
+///
+/// The bubble is placed at the preferred side of the anchor and flipped to
+/// the opposite side if it does not fit into the window.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::popover;
+/// #
+/// # pub type Popover<'a, Content, Message>
+/// #  = popover::Popover<'a, Content, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ClosePopover,
+/// }
+///
+/// let popover = Popover::new(
+///     true,
+///     Text::new("Anchor"),
+///     || Text::new("Content").into()
+/// )
+/// .placement(popover::Placement::Top)
+/// .arrow(6.0)
+/// .on_dismiss(Message::ClosePopover);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Popover<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Show the popover.
+    show_popover: bool,
+    /// The underlying element the bubble is anchored to.
+    underlay: Element<'a, Message, Renderer>,
+    /// The content of the [`PopoverOverlay`](PopoverOverlay).
+    content: Content,
+    /// The preferred side of the anchor the bubble is placed at.
+    placement: Placement,
+    /// The alignment of the bubble along the side of the anchor.
+    alignment: Alignment,
+    /// The gap between the anchor and the bubble.
+    gap: f32,
+    /// The padding between the bubble and its content.
+    padding: f32,
+    /// The optional size of the arrow pointing at the anchor.
+    arrow: Option<f32>,
+    /// The optional message that will be send when the user clicked outside
+    /// of the bubble or pressed the ESC key.
+    on_dismiss: Option<Message>,
+    /// The style of the [`PopoverOverlay`](PopoverOverlay).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Content, Message, Renderer> Popover<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Popover`](Popover) wrapping the underlying element to
+    /// show some content in a bubble anchored to it.
+    ///
+    /// It expects:
+    ///     * if the bubble of the popover is visible.
+    ///     * the underlay [`Element`](iced_native::Element) the bubble is
+    ///         anchored to.
+    ///     * the content [`Element`](iced_native::Element) of the bubble.
+    pub fn new<U>(show_popover: bool, underlay: U, content: Content) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        Popover {
+            show_popover,
+            underlay: underlay.into(),
+            content,
+            placement: Placement::default(),
+            alignment: Alignment::Center,
+            gap: DEFAULT_GAP,
+            padding: DEFAULT_PADDING,
+            arrow: None,
+            on_dismiss: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the preferred [`Placement`](Placement) of the bubble of the
+    /// [`Popover`](Popover).
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Alignment`](iced_native::Alignment) of the bubble along the
+    /// side of the anchor.
+    ///
+    /// `Start` aligns the bubble with the left or top edge of the anchor.
+    #[must_use]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the gap between the anchor and the bubble of the
+    /// [`Popover`](Popover).
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding between the bubble and the content of the
+    /// [`Popover`](Popover).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Adds an arrow of the given size pointing from the bubble at the
+    /// anchor.
+    #[must_use]
+    pub fn arrow(mut self, size: f32) -> Self {
+        self.arrow = Some(size);
+        self
+    }
+
+    /// Sets the message that will be produced when the user clicks outside
+    /// of the bubble and the anchor or presses the ESC key while the
+    /// [`Popover`](Popover) is shown.
+    ///
+    /// This can be used to close the popover.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Popover`](Popover).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Content, Message, Renderer> Widget<Message, Renderer>
+    for Popover<'a, Content, Message, Renderer>
+where
+    Content: 'a + Fn() -> Element<'a, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&(self.content)())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &(self.content)()]);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        if !self.show_popover {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.x, bounds.y);
+        let content = (self.content)();
+        let (underlay_state, content_state) = state.children.split_at_mut(1);
+        content.as_widget().diff(&mut content_state[0]);
+
+        // The overlays of the underlay stay visible below the bubble
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut underlay_state[0], layout, renderer),
+            )
+            .push(
+                1,
+                PopoverOverlay::new(
+                    &mut content_state[0],
+                    content,
+                    bounds.size(),
+                    self.placement,
+                    self.alignment,
+                    self.gap,
+                    self.padding,
+                    self.arrow,
+                    self.on_dismiss.clone(),
+                    self.style,
+                )
+                .overlay(position),
+            )
+            .overlay()
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Content, Message, Renderer> From<Popover<'a, Content, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Content: 'a + Fn() -> Element<'a, Message, Renderer>,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(popover: Popover<'a, Content, Message, Renderer>) -> Self {
+        Element::new(popover)
+    }
+}
@@ -0,0 +1,155 @@
+//! Hoist content into the overlay layer to show it unclipped.
+//!
+//! *This API requires the following crate features to be activated: portal*
+use iced_native::{
+    event, mouse,
+    widget::{Operation, Tree},
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::overlay::PortalOverlay;
+
+/// A portal rendering its content on the overlay layer.
+///
+/// The content keeps its place in the layout and the widget tree, but it is
+/// drawn and receives its events as an overlay on top of everything else. This
+/// way dropdowns and tooltips of widgets nested in scrollables or containers
+/// are not clipped by them.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::portal;
+/// #
+/// # pub type Portal<'a, Message> = portal::Portal<'a, Message, Null>;
+/// let portal: Portal<'_, ()> = Portal::new(Text::new("Unclipped"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Portal<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// The content of the [`Portal`](Portal).
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Portal<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Creates a new [`Portal`](Portal) rendering the content on the overlay
+    /// layer.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Portal {
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Portal<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // The events are handled by the overlay
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &iced_native::renderer::Style,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        // The content is drawn by the overlay
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        let bounds = layout.bounds();
+
+        Some(
+            PortalOverlay::new(&mut state.children[0], &mut self.content, bounds.size())
+                .overlay(bounds.position()),
+        )
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<Portal<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(portal: Portal<'a, Message, Renderer>) -> Self {
+        Element::new(portal)
+    }
+}
@@ -0,0 +1,974 @@
+//! Edit named properties grouped into collapsible sections.
+//!
+//! *This API requires the following crate features to be activated: property_grid*
+use std::rc::Rc;
+
+use iced_graphics::Backend;
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::{
+        button, container, pick_list, scrollable, text_input, Button, Operation, PickList, Row,
+        Text, TextInput, Tree,
+    },
+    Alignment, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+use iced_style::menu;
+
+use crate::{
+    core::color::HexString,
+    graphics::icons::{Icon, ICON_FONT},
+    native::{color_picker::ColorPicker, number_input::NumberInput, switch::Switch},
+    style::{
+        color_picker, number_input,
+        style_state::{appearance, StyleState},
+        switch,
+    },
+};
+
+pub use crate::style::property_grid::{Appearance, StyleSheet};
+
+/// The default width of the column showing the names of the properties.
+const DEFAULT_LABEL_WIDTH: f32 = 120.0;
+
+/// The default padding around the titles, the names and the editors.
+const DEFAULT_PADDING: f32 = 8.0;
+
+/// The width and height of the swatch showing a color.
+const SWATCH_SIZE: f32 = 16.0;
+
+/// The value of a [`Property`](Property), deciding the editor it is changed
+/// with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A flag changed with a [`Switch`](Switch).
+    Bool(bool),
+    /// A number changed with a [`NumberInput`](NumberInput).
+    Number(f64),
+    /// A text changed with a text input.
+    Text(String),
+    /// A color changed with a [`ColorPicker`](ColorPicker) opened from a
+    /// swatch.
+    Color(Color),
+    /// One of several options chosen from a dropdown.
+    Choice {
+        /// The options to choose from.
+        options: Vec<String>,
+        /// The index of the chosen option.
+        selected: usize,
+    },
+}
+
+/// A named property of a [`PropertyGrid`](PropertyGrid).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    /// The name shown next to the editor.
+    name: String,
+    /// The current value.
+    value: Value,
+    /// The smallest number of a [`Number`](Value::Number) property.
+    min: f64,
+    /// The largest number of a [`Number`](Value::Number) property.
+    max: f64,
+    /// The step of a [`Number`](Value::Number) property.
+    step: f64,
+}
+
+impl Property {
+    /// Creates a new [`Property`](Property) with the name and the value.
+    pub fn new(name: impl Into<String>, value: Value) -> Self {
+        Self {
+            name: name.into(),
+            value,
+            min: f64::MIN,
+            max: f64::MAX,
+            step: 1.0,
+        }
+    }
+
+    /// Sets the smallest and the largest number of a
+    /// [`Number`](Value::Number) property.
+    #[must_use]
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self
+    }
+
+    /// Sets the step of a [`Number`](Value::Number) property.
+    #[must_use]
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns the name of the [`Property`](Property).
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the current value of the [`Property`](Property).
+    #[must_use]
+    pub const fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Replaces the value of the [`Property`](Property).
+    pub fn set_value(&mut self, value: Value) {
+        self.value = value;
+    }
+}
+
+/// What happened in a [`PropertyGrid`](PropertyGrid).
+///
+/// The application passes it to [`State::update`](State::update), which
+/// returns the changed property, if any.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+    /// The property with the name got the value.
+    Changed(String, Value),
+    /// The header of the section at the index was pressed.
+    Toggled(usize),
+    /// The swatch of the color property with the name was pressed.
+    ColorPickerOpened(String),
+    /// The open color picker was cancelled.
+    ColorPickerClosed,
+}
+
+/// The collapsed sections and the open color picker of a
+/// [`PropertyGrid`](PropertyGrid).
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The indices of the collapsed sections.
+    collapsed: Vec<usize>,
+    /// The name of the property whose color picker is open.
+    picking: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with all sections open.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            collapsed: Vec::new(),
+            picking: None,
+        }
+    }
+
+    /// Returns true if the section at the index is open.
+    #[must_use]
+    pub fn is_open(&self, index: usize) -> bool {
+        !self.collapsed.contains(&index)
+    }
+
+    /// Collapses the section at the index if it is open and opens it
+    /// otherwise.
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(position) = self.collapsed.iter().position(|other| *other == index) {
+            let _ = self.collapsed.swap_remove(position);
+        } else {
+            self.collapsed.push(index);
+        }
+    }
+
+    /// Returns true if the color picker of the property with the name is
+    /// open.
+    #[must_use]
+    pub fn is_picking(&self, name: &str) -> bool {
+        self.picking.as_deref() == Some(name)
+    }
+
+    /// Applies the [`Action`](Action) of a [`PropertyGrid`](PropertyGrid),
+    /// returning the name and the new value of a changed property.
+    pub fn update(&mut self, action: Action) -> Option<(String, Value)> {
+        match action {
+            Action::Changed(name, value) => {
+                if matches!(value, Value::Color(_)) {
+                    self.picking = None;
+                }
+                Some((name, value))
+            }
+            Action::Toggled(index) => {
+                self.toggle(index);
+                None
+            }
+            Action::ColorPickerOpened(name) => {
+                self.picking = Some(name);
+                None
+            }
+            Action::ColorPickerClosed => {
+                self.picking = None;
+                None
+            }
+        }
+    }
+}
+
+/// A section of a [`PropertyGrid`](PropertyGrid).
+struct Section<'a, Message, Renderer> {
+    /// The title shown in the header.
+    title: String,
+    /// The names and the editors of the properties, empty while the section
+    /// is collapsed.
+    rows: Vec<(String, Element<'a, Message, Renderer>)>,
+}
+
+/// A list of named properties edited with the editor fitting their
+/// [`Value`](Value), grouped into sections collapsing when their header is
+/// pressed.
+///
+/// The collapsed sections and the open color picker are kept in a
+/// [`State`](State) of the application, which is updated with the
+/// [`Action`](Action)s of the grid.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::native::property_grid::{self, Action, Property, Value};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Property(Action),
+/// }
+///
+/// let state = property_grid::State::new();
+/// let transform = [
+///     Property::new("Visible", Value::Bool(true)),
+///     Property::new("Opacity", Value::Number(1.0)).range(0.0, 1.0).step(0.1),
+/// ];
+///
+/// let grid = iced_aw::PropertyGrid::new(&state, Message::Property)
+///     .section("Transform", &transform);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PropertyGrid<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The collapsed sections and the open color picker.
+    state: &'a State,
+    /// The function producing the message of an [`Action`](Action).
+    on_action: Rc<dyn Fn(Action) -> Message>,
+    /// The sections of the grid.
+    sections: Vec<Section<'a, Message, Renderer>>,
+    /// The width of the grid.
+    width: Length,
+    /// The width of the column showing the names of the properties.
+    label_width: f32,
+    /// The padding around the titles, the names and the editors.
+    padding: f32,
+    /// The text size of the titles and the names.
+    text_size: Option<f32>,
+    /// The font of the titles and the names.
+    font: Font,
+    /// The style of the grid.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> PropertyGrid<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`PropertyGrid`](PropertyGrid) without sections.
+    ///
+    /// It expects:
+    ///     * the [`State`](State) keeping the collapsed sections and the open
+    ///         color picker.
+    ///     * the function that will be called with the [`Action`](Action)s
+    ///         of the grid.
+    pub fn new<F>(state: &'a State, on_action: F) -> Self
+    where
+        F: 'static + Fn(Action) -> Message,
+    {
+        Self {
+            state,
+            on_action: Rc::new(on_action),
+            sections: Vec::new(),
+            width: Length::Fill,
+            label_width: DEFAULT_LABEL_WIDTH,
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`PropertyGrid`](PropertyGrid).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the width of the column showing the names of the properties.
+    #[must_use]
+    pub fn label_width(mut self, label_width: f32) -> Self {
+        self.label_width = label_width;
+        self
+    }
+
+    /// Sets the padding around the titles, the names and the editors.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the titles and the names.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the titles and the names.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`PropertyGrid`](PropertyGrid).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the editors of all open sections, in order.
+    fn editors(&self) -> impl Iterator<Item = &Element<'a, Message, Renderer>> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.rows.iter().map(|(_, editor)| editor))
+    }
+}
+
+impl<'a, Message, B, Theme> PropertyGrid<'a, Message, iced_graphics::Renderer<B, Theme>>
+where
+    Message: 'static + Clone,
+    B: 'a + Backend + iced_graphics::backend::Text,
+    Theme: 'a
+        + StyleSheet
+        + switch::StyleSheet
+        + number_input::StyleSheet
+        + color_picker::StyleSheet
+        + button::StyleSheet
+        + iced_style::text::StyleSheet
+        + text_input::StyleSheet
+        + container::StyleSheet
+        + pick_list::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet,
+    <Theme as menu::StyleSheet>::Style: From<<Theme as pick_list::StyleSheet>::Style>,
+{
+    /// Adds a section with the title and the properties to the
+    /// [`PropertyGrid`](PropertyGrid).
+    ///
+    /// The editors of the properties are only created while the section is
+    /// open.
+    #[must_use]
+    pub fn section(mut self, title: impl Into<String>, properties: &[Property]) -> Self {
+        let rows = if self.state.is_open(self.sections.len()) {
+            properties
+                .iter()
+                .map(|property| (property.name.clone(), self.editor(property)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self.sections.push(Section {
+            title: title.into(),
+            rows,
+        });
+        self
+    }
+
+    /// Creates the editor fitting the value of the property.
+    fn editor(
+        &self,
+        property: &Property,
+    ) -> Element<'a, Message, iced_graphics::Renderer<B, Theme>> {
+        let name = property.name.clone();
+        let on_action = Rc::clone(&self.on_action);
+        let on_change = move |value| on_action(Action::Changed(name.clone(), value));
+
+        match &property.value {
+            Value::Bool(is_on) => Switch::new(*is_on)
+                .on_toggle(move |is_on| on_change(Value::Bool(is_on)))
+                .into(),
+            Value::Number(number) => NumberInput::new(*number, property.max, move |number| {
+                on_change(Value::Number(number))
+            })
+            .min(property.min)
+            .step(property.step)
+            .into(),
+            Value::Text(content) => TextInput::new("", content)
+                .on_input(move |content| on_change(Value::Text(content)))
+                .width(Length::Fill)
+                .into(),
+            Value::Color(color) => {
+                let swatch = Button::new(
+                    Row::new()
+                        .spacing(self.padding)
+                        .align_items(Alignment::Center)
+                        .push(Swatch { color: *color })
+                        .push(Text::new(color.as_hex_string())),
+                )
+                .on_press((self.on_action)(Action::ColorPickerOpened(
+                    property.name.clone(),
+                )));
+
+                ColorPicker::new(
+                    self.state.is_picking(&property.name),
+                    *color,
+                    swatch,
+                    (self.on_action)(Action::ColorPickerClosed),
+                    move |color| on_change(Value::Color(color)),
+                )
+                .into()
+            }
+            Value::Choice { options, selected } => {
+                let choices = options.clone();
+
+                PickList::new(
+                    options.clone(),
+                    options.get(*selected).cloned(),
+                    move |option| {
+                        let selected = choices
+                            .iter()
+                            .position(|choice| *choice == option)
+                            .unwrap_or_default();
+
+                        on_change(Value::Choice {
+                            options: choices.clone(),
+                            selected,
+                        })
+                    },
+                )
+                .width(Length::Fill)
+                .into()
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for PropertyGrid<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.editors().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let editors: Vec<_> = self.editors().collect();
+        tree.diff_children(&editors);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let padding = self.padding;
+        let header_height = text_size + 2.0 * padding;
+        let editor_limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (limits.max().width - self.label_width - 2.0 * padding).max(0.0),
+                f32::INFINITY,
+            ),
+        );
+
+        let editors: Vec<Vec<Node>> = self
+            .sections
+            .iter()
+            .map(|section| {
+                section
+                    .rows
+                    .iter()
+                    .map(|(_, editor)| editor.as_widget().layout(renderer, &editor_limits))
+                    .collect()
+            })
+            .collect();
+
+        // The widest title or editor decides the width of a shrinking grid
+        let intrinsic_width = self
+            .sections
+            .iter()
+            .zip(&editors)
+            .map(|(section, editors)| {
+                let title_width = renderer.measure_width(&section.title, text_size, self.font)
+                    + text_size
+                    + 3.0 * padding;
+
+                editors
+                    .iter()
+                    .map(|editor| self.label_width + editor.size().width + 2.0 * padding)
+                    .fold(title_width, f32::max)
+            })
+            .fold(0.0, f32::max);
+        let width = limits.resolve(Size::new(intrinsic_width, 0.0)).width;
+
+        let mut y = 0.0;
+        let mut sections = Vec::with_capacity(editors.len());
+
+        for editors in editors {
+            let mut height = header_height;
+            let mut children = vec![Node::new(Size::new(width, header_height))];
+
+            for mut editor in editors {
+                let row_height = editor.size().height.max(text_size) + padding;
+                editor.move_to(Point::new(
+                    self.label_width + padding,
+                    (row_height - editor.size().height) / 2.0,
+                ));
+
+                let mut row = Node::with_children(Size::new(width, row_height), vec![editor]);
+                row.move_to(Point::new(0.0, height));
+                height += row_height;
+
+                children.push(row);
+            }
+
+            let mut section = Node::with_children(Size::new(width, height), children);
+            section.move_to(Point::new(0.0, y));
+            y += height;
+
+            sections.push(section);
+        }
+
+        Node::with_children(limits.resolve(Size::new(width, y)), sections)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+        let mut states = state.children.iter_mut();
+
+        for (index, (section, layout)) in
+            self.sections.iter_mut().zip(layout.children()).enumerate()
+        {
+            let mut rows = layout.children();
+            let header = rows
+                .next()
+                .expect("Native: Layout should have a header layout for a PropertyGrid");
+
+            for ((_, editor), (row, state)) in section.rows.iter_mut().zip(rows.zip(&mut states)) {
+                status =
+                    status.merge(editor.as_widget_mut().on_event(
+                        state,
+                        event.clone(),
+                        row.children().next().expect(
+                            "Native: Layout should have an editor layout for a PropertyGrid",
+                        ),
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        shell,
+                    ));
+            }
+
+            if status == event::Status::Ignored
+                && header.bounds().contains(cursor_position)
+                && matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerPressed { .. })
+                )
+            {
+                shell.publish((self.on_action)(Action::Toggled(index)));
+                status = event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut states = state.children.iter();
+
+        for (section, layout) in self.sections.iter().zip(layout.children()) {
+            let mut rows = layout.children();
+            let header = rows
+                .next()
+                .expect("Native: Layout should have a header layout for a PropertyGrid");
+
+            if header.bounds().contains(cursor_position) {
+                return mouse::Interaction::Pointer;
+            }
+
+            for ((_, editor), (row, state)) in section.rows.iter().zip(rows.zip(&mut states)) {
+                if row.bounds().contains(cursor_position) {
+                    return editor.as_widget().mouse_interaction(
+                        state,
+                        row.children().next().expect(
+                            "Native: Layout should have an editor layout for a PropertyGrid",
+                        ),
+                        cursor_position,
+                        viewport,
+                        renderer,
+                    );
+                }
+            }
+        }
+
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let active = theme.active(self.style);
+        let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+            active.border_radius.into();
+        let last = self.sections.len().saturating_sub(1);
+        let mut states = state.children.iter();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: active.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            active.background,
+        );
+
+        for (index, (section, layout)) in self.sections.iter().zip(layout.children()).enumerate() {
+            let mut rows = layout.children();
+            let header_bounds = rows
+                .next()
+                .expect("Graphics: Layout should have a header layout for a PropertyGrid")
+                .bounds();
+
+            let appearance = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(header_bounds.contains(cursor_position), false, false, false),
+                self.style,
+            );
+            let (first, bottom) = (index == 0, index == last && section.rows.is_empty());
+
+            // Only the outer headers keep the corners of the grid
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header_bounds,
+                    border_radius: [
+                        if first { top_left } else { 0.0 },
+                        if first { top_right } else { 0.0 },
+                        if bottom { bottom_right } else { 0.0 },
+                        if bottom { bottom_left } else { 0.0 },
+                    ]
+                    .into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.header_background,
+            );
+
+            let mut buffer = [0; 4];
+            let chevron = if self.state.is_open(index) {
+                Icon::CaretDownFill
+            } else {
+                Icon::CaretRightFill
+            };
+
+            renderer.fill_text(text::Text {
+                content: char::from(chevron).encode_utf8(&mut buffer),
+                bounds: Rectangle {
+                    x: header_bounds.x + self.padding + text_size / 2.0,
+                    y: header_bounds.center_y(),
+                    ..header_bounds
+                },
+                size: text_size,
+                color: appearance.icon_color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+
+            renderer.fill_text(text::Text {
+                content: &section.title,
+                bounds: Rectangle {
+                    x: header_bounds.x + 2.0 * self.padding + text_size,
+                    y: header_bounds.center_y(),
+                    ..header_bounds
+                },
+                size: text_size,
+                color: appearance.header_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+
+            for (position, ((name, editor), (row, state))) in
+                section.rows.iter().zip(rows.zip(&mut states)).enumerate()
+            {
+                let row_bounds = row.bounds();
+
+                if row_bounds.intersection(viewport).is_none() {
+                    continue;
+                }
+
+                if position > 0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                height: 1.0,
+                                ..row_bounds
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        active.separator_color,
+                    );
+                }
+
+                renderer.fill_text(text::Text {
+                    content: name,
+                    bounds: Rectangle {
+                        x: row_bounds.x + self.padding,
+                        y: row_bounds.center_y(),
+                        width: self.label_width,
+                        ..row_bounds
+                    },
+                    size: text_size,
+                    color: active.label_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+
+                editor.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: active.label_color,
+                    },
+                    row.children()
+                        .next()
+                        .expect("Graphics: Layout should have an editor layout for a PropertyGrid"),
+                    cursor_position,
+                    viewport,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: active.border_radius,
+                border_width: active.border_width,
+                border_color: active.border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let rows = layout
+            .children()
+            .flat_map(|section| section.children().skip(1));
+
+        for ((editor, state), row) in self.editors().zip(&mut state.children).zip(rows) {
+            if let Some(editor_layout) = row.children().next() {
+                editor
+                    .as_widget()
+                    .operate(state, editor_layout, renderer, operation);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let rows = layout
+            .children()
+            .flat_map(|section| section.children().skip(1));
+
+        let overlays: Vec<_> = self
+            .sections
+            .iter_mut()
+            .flat_map(|section| section.rows.iter_mut())
+            .zip(&mut state.children)
+            .zip(rows)
+            .filter_map(|(((_, editor), state), row)| {
+                editor
+                    .as_widget_mut()
+                    .overlay(state, row.children().next()?, renderer)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<PropertyGrid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(property_grid: PropertyGrid<'a, Message, Renderer>) -> Self {
+        Element::new(property_grid)
+    }
+}
+
+/// A small square filled with the color of a color property.
+#[derive(Clone, Copy, Debug)]
+struct Swatch {
+    /// The color to show.
+    color: Color,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Swatch
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fixed(SWATCH_SIZE)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(SWATCH_SIZE)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(Length::Fixed(SWATCH_SIZE))
+                .height(Length::Fixed(SWATCH_SIZE))
+                .resolve(Size::new(SWATCH_SIZE, SWATCH_SIZE)),
+        )
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: 2.0.into(),
+                border_width: 1.0,
+                border_color: style.text_color,
+            },
+            self.color,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Swatch> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(swatch: Swatch) -> Self {
+        Element::new(swatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::Color;
+
+    use super::{Action, State, Value};
+
+    #[test]
+    fn toggle_collapses_and_opens_sections() {
+        let mut state = State::new();
+        assert!(state.is_open(0));
+
+        state.toggle(1);
+        assert!(state.is_open(0));
+        assert!(!state.is_open(1));
+
+        state.toggle(1);
+        assert!(state.is_open(1));
+    }
+
+    #[test]
+    fn update_tracks_the_open_color_picker() {
+        let mut state = State::new();
+
+        assert_eq!(
+            state.update(Action::ColorPickerOpened(String::from("Fill"))),
+            None
+        );
+        assert!(state.is_picking("Fill"));
+        assert!(!state.is_picking("Stroke"));
+
+        let change = state.update(Action::Changed(
+            String::from("Fill"),
+            Value::Color(Color::BLACK),
+        ));
+        assert_eq!(
+            change,
+            Some((String::from("Fill"), Value::Color(Color::BLACK)))
+        );
+        assert!(!state.is_picking("Fill"));
+
+        let _ = state.update(Action::ColorPickerOpened(String::from("Fill")));
+        assert_eq!(state.update(Action::ColorPickerClosed), None);
+        assert!(!state.is_picking("Fill"));
+
+        assert_eq!(state.update(Action::Toggled(0)), None);
+        assert!(!state.is_open(0));
+    }
+}
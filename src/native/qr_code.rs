@@ -0,0 +1,528 @@
+//! Show data as a QR code.
+//!
+//! *This API requires the following crate features to be activated: qr_code*
+use iced_native::{
+    event, image,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    widget::{
+        tree::{self, Tree},
+        Operation,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use qrcode::EcLevel;
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::qr_code::{Appearance, StyleSheet};
+
+#[doc(no_inline)]
+pub use qrcode::types::QrError as Error;
+
+/// The default size of a module in pixels.
+const DEFAULT_MODULE_SIZE: f32 = 4.0;
+
+/// The default width of the quiet zone around the code in modules.
+const DEFAULT_QUIET_ZONE: u16 = 4;
+
+/// The default size of the logo relative to the size of the code.
+const DEFAULT_LOGO_RATIO: f32 = 0.2;
+
+/// How much of a QR code may be damaged or hidden while it can still be read.
+///
+/// A higher level makes the code larger for the same data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ErrorCorrection {
+    /// About 7% of the code may be lost.
+    Low,
+    /// About 15% of the code may be lost.
+    #[default]
+    Medium,
+    /// About 25% of the code may be lost.
+    Quartile,
+    /// About 30% of the code may be lost. Use it for codes with a logo.
+    High,
+}
+
+impl From<ErrorCorrection> for EcLevel {
+    fn from(error_correction: ErrorCorrection) -> Self {
+        match error_correction {
+            ErrorCorrection::Low => Self::L,
+            ErrorCorrection::Medium => Self::M,
+            ErrorCorrection::Quartile => Self::Q,
+            ErrorCorrection::High => Self::H,
+        }
+    }
+}
+
+/// The modules of a QR code encoding some data.
+///
+/// Encoding is slow compared to drawing, so the data should be kept in the
+/// state of the application and only encoded again when it changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Data {
+    /// The number of modules in a row or a column.
+    width: usize,
+    /// Whether each module is dark, row by row.
+    modules: Vec<bool>,
+}
+
+impl Data {
+    /// Encodes the data with the [`Medium`](ErrorCorrection::Medium) error
+    /// correction.
+    ///
+    /// # Errors
+    /// Returns an [`Error`](Error) if the data is too long for a QR code.
+    pub fn new(data: impl AsRef<[u8]>) -> Result<Self, Error> {
+        Self::with_error_correction(data, ErrorCorrection::default())
+    }
+
+    /// Encodes the data with the given error correction.
+    ///
+    /// # Errors
+    /// Returns an [`Error`](Error) if the data is too long for a QR code with
+    /// the error correction.
+    pub fn with_error_correction(
+        data: impl AsRef<[u8]>,
+        error_correction: ErrorCorrection,
+    ) -> Result<Self, Error> {
+        let code = qrcode::QrCode::with_error_correction_level(data, error_correction.into())?;
+
+        Ok(Self {
+            width: code.width(),
+            modules: code
+                .to_colors()
+                .into_iter()
+                .map(|color| color == qrcode::Color::Dark)
+                .collect(),
+        })
+    }
+
+    /// Returns the number of modules in a row or a column of the code.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns whether the module at the column and the row is dark.
+    #[must_use]
+    pub fn is_dark(&self, column: usize, row: usize) -> bool {
+        column < self.width && row < self.width && self.modules[row * self.width + column]
+    }
+
+    /// Renders the code to an image to save or share it.
+    ///
+    /// Each module is `module_size` pixels wide and the code is surrounded by
+    /// a quiet zone of `quiet_zone` light modules. The logo of a
+    /// [`QrCode`](QrCode) is not part of the image.
+    #[must_use]
+    pub fn to_image(
+        &self,
+        module_size: u32,
+        quiet_zone: u32,
+        dark: Color,
+        light: Color,
+    ) -> image::Handle {
+        let (size, pixels) = self.rgba(module_size, quiet_zone, dark, light);
+
+        image::Handle::from_pixels(size, size, pixels)
+    }
+
+    /// Returns the width in pixels and the RGBA pixels of the code rendered
+    /// with the module size and the quiet zone.
+    fn rgba(&self, module_size: u32, quiet_zone: u32, dark: Color, light: Color) -> (u32, Vec<u8>) {
+        let module_size = module_size.max(1) as usize;
+        let quiet_zone = quiet_zone as usize;
+        let size = (self.width + 2 * quiet_zone) * module_size;
+        let (dark, light) = (dark.into_rgba8(), light.into_rgba8());
+
+        let mut pixels = Vec::with_capacity(size * size * 4);
+        for y in 0..size {
+            let row = (y / module_size).wrapping_sub(quiet_zone);
+
+            for x in 0..size {
+                let column = (x / module_size).wrapping_sub(quiet_zone);
+                let color = if self.is_dark(column, row) {
+                    dark
+                } else {
+                    light
+                };
+
+                pixels.extend_from_slice(&color);
+            }
+        }
+
+        (size as u32, pixels)
+    }
+}
+
+/// A QR code showing [`Data`](Data), with an optional logo in its center.
+///
+/// The size of the code follows from its data, the module size and the quiet
+/// zone. A logo hides the modules below it, so the data should be encoded with
+/// the [`High`](ErrorCorrection::High) error correction to stay readable.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::qr_code;
+/// #
+/// # pub type QrCode<'a, Message> = qr_code::QrCode<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {}
+///
+/// let data = qr_code::Data::new("https://github.com/iced-rs/iced_aw").unwrap();
+///
+/// let qr_code = QrCode::<Message>::new(&data)
+///     .module_size(6.0)
+///     .quiet_zone(2);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct QrCode<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The data shown as the code.
+    data: &'a Data,
+    /// The size of a module.
+    module_size: f32,
+    /// The width of the quiet zone around the code in modules.
+    quiet_zone: u16,
+    /// The logo shown in the center of the code.
+    logo: Option<Element<'a, Message, Renderer>>,
+    /// The largest size of the logo relative to the size of the code.
+    logo_ratio: f32,
+    /// The style of the code.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> QrCode<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`QrCode`](QrCode) showing the data.
+    pub fn new(data: &'a Data) -> Self {
+        QrCode {
+            data,
+            module_size: DEFAULT_MODULE_SIZE,
+            quiet_zone: DEFAULT_QUIET_ZONE,
+            logo: None,
+            logo_ratio: DEFAULT_LOGO_RATIO,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the size of a module of the [`QrCode`](QrCode).
+    #[must_use]
+    pub fn module_size(mut self, module_size: f32) -> Self {
+        self.module_size = module_size.max(1.0);
+        self
+    }
+
+    /// Sets the width of the quiet zone around the [`QrCode`](QrCode) in
+    /// modules.
+    #[must_use]
+    pub fn quiet_zone(mut self, quiet_zone: u16) -> Self {
+        self.quiet_zone = quiet_zone;
+        self
+    }
+
+    /// Sets the logo shown in the center of the [`QrCode`](QrCode).
+    #[must_use]
+    pub fn logo(mut self, logo: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.logo = Some(logo.into());
+        self
+    }
+
+    /// Sets the largest size of the logo relative to the size of the code,
+    /// between 0 and 0.3.
+    ///
+    /// Larger logos hide more modules than the error correction can restore.
+    #[must_use]
+    pub fn logo_ratio(mut self, logo_ratio: f32) -> Self {
+        self.logo_ratio = logo_ratio.clamp(0.0, 0.3);
+        self
+    }
+
+    /// Sets the style of the [`QrCode`](QrCode).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the size of the code with its quiet zone.
+    fn size(&self) -> f32 {
+        (self.data.width + 2 * usize::from(self.quiet_zone)) as f32 * self.module_size
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for QrCode<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::None
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.logo.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if let Some(logo) = &self.logo {
+            tree.diff_children(std::slice::from_ref(logo));
+        } else {
+            tree.children.clear();
+        }
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, _limits: &Limits) -> Node {
+        let size = self.size();
+
+        let children = self
+            .logo
+            .iter()
+            .map(|logo| {
+                let max = size * self.logo_ratio;
+                let mut node = logo
+                    .as_widget()
+                    .layout(renderer, &Limits::new(Size::ZERO, Size::new(max, max)));
+                let logo_size = node.size();
+
+                node.move_to(Point::new(
+                    (size - logo_size.width) / 2.0,
+                    (size - logo_size.height) / 2.0,
+                ));
+                node
+            })
+            .collect();
+
+        Node::with_children(Size::new(size, size), children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let (Some(logo), Some(logo_layout)) = (&self.logo, layout.children().next()) {
+            logo.as_widget()
+                .operate(&mut tree.children[0], logo_layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match (&mut self.logo, layout.children().next()) {
+            (Some(logo), Some(logo_layout)) => logo.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                logo_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (&self.logo, layout.children().next()) {
+            (Some(logo), Some(logo_layout)) => logo.as_widget().mouse_interaction(
+                &tree.children[0],
+                logo_layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.light,
+        );
+
+        let origin = f32::from(self.quiet_zone) * self.module_size;
+        let width = self.data.width;
+
+        // Neighbouring dark modules of a row are drawn as one quad
+        for row in 0..width {
+            let mut column = 0;
+
+            while column < width {
+                if !self.data.is_dark(column, row) {
+                    column += 1;
+                    continue;
+                }
+
+                let start = column;
+                while column < width && self.data.is_dark(column, row) {
+                    column += 1;
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + origin + start as f32 * self.module_size,
+                            y: bounds.y + origin + row as f32 * self.module_size,
+                            width: (column - start) as f32 * self.module_size,
+                            height: self.module_size,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.dark,
+                );
+            }
+        }
+
+        if let (Some(logo), Some(logo_layout)) = (&self.logo, layout.children().next()) {
+            let logo_bounds = logo_layout.bounds();
+
+            // The logo sits on a plate hiding the modules below it
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: logo_bounds.x - self.module_size,
+                        y: logo_bounds.y - self.module_size,
+                        width: logo_bounds.width + 2.0 * self.module_size,
+                        height: logo_bounds.height + 2.0 * self.module_size,
+                    },
+                    border_radius: appearance.border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.light,
+            );
+
+            logo.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                logo_layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let logo_layout = layout.children().next()?;
+
+        self.logo
+            .as_mut()?
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], logo_layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<QrCode<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(qr_code: QrCode<'a, Message, Renderer>) -> Self {
+        Element::new(qr_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::Color;
+
+    use super::{Data, ErrorCorrection};
+
+    #[test]
+    fn higher_error_correction_needs_more_modules() {
+        let data = "https://github.com/iced-rs/iced_aw";
+        let low = Data::with_error_correction(data, ErrorCorrection::Low)
+            .expect("The data should fit a QR code with low error correction");
+        let high = Data::with_error_correction(data, ErrorCorrection::High)
+            .expect("The data should fit a QR code with high error correction");
+
+        assert!(low.width() < high.width());
+        assert!(!low.is_dark(low.width(), 0));
+    }
+
+    #[test]
+    fn renders_quiet_zone_and_finder_pattern() {
+        let data = Data::new("iced").expect("The data should fit a QR code");
+        let (size, pixels) = data.rgba(2, 1, Color::BLACK, Color::WHITE);
+
+        assert_eq!(size as usize, (data.width() + 2) * 2);
+        assert_eq!(pixels.len(), (size * size * 4) as usize);
+
+        let pixel = |x: u32, y: u32| &pixels[((y * size + x) * 4) as usize..][..4];
+        // The quiet zone is light and the finder pattern in the corner dark
+        assert_eq!(pixel(1, 1), [255, 255, 255, 255]);
+        assert_eq!(pixel(2, 2), [0, 0, 0, 255]);
+    }
+}
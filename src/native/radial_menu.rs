@@ -0,0 +1,571 @@
+//! A ring of actions opened around the cursor, like the tool menus of pen and
+//! touch canvases.
+//!
+//! *This API requires the following crate features to be activated: radial_menu*
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse,
+    time::Instant,
+    widget::{tree, Tree},
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use crate::{
+    core::{
+        event::is_input,
+        gesture::{Gesture, Recognizer},
+    },
+    graphics::icons::Icon,
+    native::overlay::{Layers, RadialMenuOverlay},
+};
+
+pub use crate::style::radial_menu::{Appearance, StyleSheet};
+
+/// The radius of the center of the menu, which selects nothing.
+pub(crate) const DEAD_ZONE: f32 = 28.0;
+
+/// The width of a ring of actions.
+pub(crate) const RING_WIDTH: f32 = 52.0;
+
+/// The default size of the icons of the actions.
+const DEFAULT_ICON_SIZE: f32 = 20.0;
+
+/// The default text size of the label of the hovered action.
+const DEFAULT_TEXT_SIZE: f32 = 14.0;
+
+/// The angle of the first action of the innermost ring, at the top.
+const FIRST_ANGLE: f32 = -FRAC_PI_2;
+
+/// An action of a [`RadialMenu`](RadialMenu), or a submenu opening a ring of
+/// further actions around it.
+#[derive(Clone, Debug)]
+pub struct RadialItem<Message> {
+    /// The icon of the item.
+    icon: Icon,
+    /// The label of the item, shown while it is hovered.
+    label: String,
+    /// The message sent when the action is selected.
+    message: Option<Message>,
+    /// The items of the submenu.
+    children: Vec<RadialItem<Message>>,
+}
+
+impl<Message> RadialItem<Message> {
+    /// Creates a new [`RadialItem`](RadialItem) sending the message when it
+    /// is selected.
+    pub fn new(icon: impl Into<Icon>, label: impl Into<String>, message: Message) -> Self {
+        Self {
+            icon: icon.into(),
+            label: label.into(),
+            message: Some(message),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`RadialItem`](RadialItem) opening a ring with the
+    /// children around the menu while it is hovered.
+    pub fn submenu(
+        icon: impl Into<Icon>,
+        label: impl Into<String>,
+        children: Vec<RadialItem<Message>>,
+    ) -> Self {
+        Self {
+            icon: icon.into(),
+            label: label.into(),
+            message: None,
+            children,
+        }
+    }
+
+    /// Returns the icon of the [`RadialItem`](RadialItem).
+    #[must_use]
+    pub const fn icon(&self) -> Icon {
+        self.icon
+    }
+
+    /// Returns the label of the [`RadialItem`](RadialItem).
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the message of the [`RadialItem`](RadialItem), or `None` for
+    /// a submenu.
+    #[must_use]
+    pub const fn message(&self) -> Option<&Message> {
+        self.message.as_ref()
+    }
+
+    /// Returns the items of the submenu of the [`RadialItem`](RadialItem).
+    #[must_use]
+    pub fn children(&self) -> &[RadialItem<Message>] {
+        &self.children
+    }
+}
+
+/// A wrapper opening a ring of icon actions around the cursor on right click,
+/// or around the finger on a long press.
+///
+/// An action is selected by pressing, moving in its direction and releasing,
+/// or by clicking it once the menu is open. Hovering a submenu opens a ring of
+/// its actions around the menu, with its middle action right behind it, so
+/// the actions of the submenu are reached by moving further out. The label of
+/// the hovered action is shown below the menu. Releasing in the center, a
+/// click outside of the menu or the ESC key close it.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, widget::Text};
+/// # use iced_aw::{graphics::icons::Icon, native::radial_menu};
+/// #
+/// # pub type RadialMenu<'a, Message> = radial_menu::RadialMenu<'a, Message, Null>;
+/// use iced_aw::native::radial_menu::RadialItem;
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Like,
+///     Favorite,
+///     Done,
+///     Color(usize),
+/// }
+///
+/// let canvas = Text::new("Right click me");
+///
+/// let menu = RadialMenu::new(
+///     canvas,
+///     vec![
+///         RadialItem::new(Icon::Heart, "Like", Message::Like),
+///         RadialItem::new(Icon::StarFill, "Favorite", Message::Favorite),
+///         RadialItem::new(Icon::Check, "Done", Message::Done),
+///         RadialItem::submenu(
+///             Icon::CaretRightFill,
+///             "Color",
+///             vec![
+///                 RadialItem::new(Icon::CaretLeftFill, "Red", Message::Color(0)),
+///                 RadialItem::new(Icon::CaretUpFill, "Green", Message::Color(1)),
+///             ],
+///         ),
+///     ],
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RadialMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The items of the innermost ring.
+    items: Vec<RadialItem<Message>>,
+    /// The message sent when the menu is closed without selecting an action.
+    on_dismiss: Option<Message>,
+    /// The mouse button opening the menu.
+    trigger: mouse::Button,
+    /// The size of the icons.
+    icon_size: f32,
+    /// The text size of the label.
+    text_size: f32,
+    /// The font of the label.
+    font: Font,
+    /// The style of the menu.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> RadialMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RadialMenu`](RadialMenu) opening the ring of the items
+    /// around the cursor when the underlay is right clicked.
+    pub fn new<U>(underlay: U, items: Vec<RadialItem<Message>>) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        RadialMenu {
+            underlay: underlay.into(),
+            items,
+            on_dismiss: None,
+            trigger: mouse::Button::Right,
+            icon_size: DEFAULT_ICON_SIZE,
+            text_size: DEFAULT_TEXT_SIZE,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the menu is closed
+    /// without selecting an action.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the mouse button opening the menu at the press point.
+    ///
+    /// The right button opens it by default. A long press opens it on touch
+    /// screens regardless of the button.
+    #[must_use]
+    pub fn trigger(mut self, button: mouse::Button) -> Self {
+        self.trigger = button;
+        self
+    }
+
+    /// Sets the size of the icons of the actions.
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the text size of the label of the hovered action.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the label of the hovered action.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`RadialMenu`](RadialMenu).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for RadialMenu<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay]);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let s: &mut State = state.state.downcast_mut();
+
+        if event == Event::Mouse(mouse::Event::ButtonPressed(self.trigger))
+            && bounds.contains(cursor_position)
+            && !s.show
+        {
+            s.open(cursor_position);
+            return event::Status::Captured;
+        }
+
+        let gesture = s.gestures.on_event(&event, bounds, Instant::now());
+        s.gestures.request_redraw(shell);
+
+        if let Some(Gesture::LongPress(position)) = gesture {
+            s.open(position);
+            return event::Status::Captured;
+        }
+
+        // The open menu handles the input, so it must not reach the underlay,
+        // too
+        if s.show && is_input(&event) {
+            return event::Status::Ignored;
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        let s: &mut State = state.state.downcast_mut();
+
+        if !s.show || self.items.is_empty() {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        let position = s.position;
+
+        // The overlays of the underlay stay visible below the menu
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut state.children[0], layout, renderer),
+            )
+            .push(
+                1,
+                RadialMenuOverlay::new(
+                    &self.items,
+                    s,
+                    self.on_dismiss.clone(),
+                    self.icon_size,
+                    self.text_size,
+                    self.font,
+                    self.style,
+                )
+                .overlay(position),
+            )
+            .overlay()
+    }
+}
+
+impl<'a, Message, Renderer> From<RadialMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(radial_menu: RadialMenu<'a, Message, Renderer>) -> Self {
+        Element::new(radial_menu)
+    }
+}
+
+/// The state of a [`RadialMenu`](RadialMenu), shared with its
+/// [`RadialMenuOverlay`](RadialMenuOverlay).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the menu is open.
+    pub show: bool,
+    /// The point the menu was opened at.
+    pub position: Point,
+    /// The index of the open submenu in each ring, from the innermost one.
+    pub path: Vec<usize>,
+    /// The ring and the index of the hovered item.
+    pub hovered: Option<(usize, usize)>,
+    /// Whether the press opening the menu was released, so the menu stays
+    /// open for a click.
+    pub released: bool,
+    /// The recognizer of the long press opening the menu on touch screens.
+    pub gestures: Recognizer,
+}
+
+impl State {
+    /// Creates a new [`State`](State) of a closed menu.
+    pub const fn new() -> Self {
+        Self {
+            show: false,
+            position: Point::ORIGIN,
+            path: Vec::new(),
+            hovered: None,
+            released: false,
+            gestures: Recognizer::new(),
+        }
+    }
+
+    /// Opens the menu at the position.
+    fn open(&mut self, position: Point) {
+        self.show = true;
+        self.position = position;
+        self.path.clear();
+        self.hovered = None;
+        self.released = false;
+    }
+
+    /// Closes the menu.
+    pub(crate) fn close(&mut self) {
+        self.show = false;
+        self.path.clear();
+        self.hovered = None;
+    }
+}
+
+/// Returns the items of each open ring with the angle of their first item,
+/// from the innermost ring along the path of the open submenus.
+pub(crate) fn rings<'b, Message>(
+    items: &'b [RadialItem<Message>],
+    path: &[usize],
+) -> Vec<(&'b [RadialItem<Message>], f32)> {
+    let mut rings = vec![(items, FIRST_ANGLE)];
+
+    for &index in path {
+        let (items, first) = rings[rings.len() - 1];
+        let Some(item) = items.get(index) else {
+            break;
+        };
+
+        if item.children.is_empty() {
+            break;
+        }
+
+        // The middle child sits right behind its parent
+        let count = item.children.len();
+        let angle = angle_of(index, first, items.len());
+        rings.push((
+            &item.children,
+            angle - (count - 1) as f32 / 2.0 * TAU / count as f32,
+        ));
+    }
+
+    rings
+}
+
+/// Returns the number of rings of the deepest path through the submenus.
+pub(crate) fn depth<Message>(items: &[RadialItem<Message>]) -> usize {
+    if items.is_empty() {
+        return 0;
+    }
+
+    1 + items
+        .iter()
+        .map(|item| depth(&item.children))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the angle of the center of the item in a ring of the count of
+/// items starting at the first angle, clockwise.
+pub(crate) fn angle_of(index: usize, first: f32, count: usize) -> f32 {
+    first + index as f32 * TAU / count.max(1) as f32
+}
+
+/// Returns the index of the item closest to the angle in a ring of the count
+/// of items starting at the first angle.
+pub(crate) fn slot_at(angle: f32, first: f32, count: usize) -> usize {
+    let count = count.max(1);
+    let slot = ((angle - first) / (TAU / count as f32)).round() as i64;
+
+    slot.rem_euclid(count as i64) as usize
+}
+
+/// Returns the ring at the distance from the center, if it is outside of the
+/// center. Distances beyond the outermost open ring select it, so only the
+/// direction matters there.
+pub(crate) fn ring_at(distance: f32, rings: usize) -> Option<usize> {
+    if distance < DEAD_ZONE || rings == 0 {
+        return None;
+    }
+
+    Some((((distance - DEAD_ZONE) / RING_WIDTH) as usize).min(rings - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::{angle_of, ring_at, slot_at, DEAD_ZONE, RING_WIDTH};
+
+    #[test]
+    fn directions_select_the_closest_slot() {
+        let first = -FRAC_PI_2;
+
+        assert_eq!(slot_at(-FRAC_PI_2, first, 4), 0);
+        assert_eq!(slot_at(0.0, first, 4), 1);
+        assert_eq!(slot_at(FRAC_PI_2, first, 4), 2);
+        assert_eq!(slot_at(PI, first, 4), 3);
+        assert_eq!(slot_at(-PI + 0.1, first, 4), 3);
+        assert_eq!(slot_at(-FRAC_PI_2 - 0.7, first, 4), 0);
+        assert_eq!(slot_at(1.0, first, 1), 0);
+
+        for index in 0..6 {
+            assert_eq!(slot_at(angle_of(index, 0.3, 6), 0.3, 6), index);
+        }
+    }
+
+    #[test]
+    fn distances_select_the_open_rings() {
+        assert_eq!(ring_at(DEAD_ZONE - 1.0, 2), None);
+        assert_eq!(ring_at(DEAD_ZONE + 1.0, 2), Some(0));
+        assert_eq!(ring_at(DEAD_ZONE + RING_WIDTH + 1.0, 2), Some(1));
+        assert_eq!(ring_at(DEAD_ZONE + 5.0 * RING_WIDTH, 2), Some(1));
+        assert_eq!(ring_at(DEAD_ZONE + 5.0 * RING_WIDTH, 0), None);
+    }
+}
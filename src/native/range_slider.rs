@@ -0,0 +1,551 @@
+//! Select a sub-range of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: range_slider*
+use std::ops::RangeInclusive;
+
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use num_traits::FromPrimitive;
+
+use crate::{
+    core::snap::snap,
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::range_slider::{Appearance, StyleSheet};
+
+/// The default height of a [`RangeSlider`](RangeSlider).
+const DEFAULT_HEIGHT: f32 = 22.0;
+
+/// The number of steps a handle is moved by a press of Page Up or Page Down.
+const PAGE_STEPS: f64 = 10.0;
+
+/// A slider with two handles selecting a sub-range of its values, like a
+/// price or a date filter.
+///
+/// The values snap to the step of the slider. Each handle can be focused and
+/// moved with the arrow keys by a step, with Page Up and Page Down by ten
+/// steps and with Home and End to the bounds of the range. A handle never
+/// passes the other one.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::range_slider;
+/// #
+/// # pub type RangeSlider<'a, T, Message> = range_slider::RangeSlider<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PriceChanged((u32, u32)),
+/// }
+///
+/// let price = (200, 600);
+///
+/// let range_slider = RangeSlider::new(0..=1000, price, Message::PriceChanged).step(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`RangeSlider`](RangeSlider) to focus its lower handle.
+    id: Option<Id>,
+    /// The range of the values.
+    range: RangeInclusive<T>,
+    /// The selected lower and upper value.
+    values: (T, T),
+    /// The step the values snap to.
+    step: T,
+    /// The function producing the message when a handle is moved.
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    /// The message sent when a handle is released.
+    on_release: Option<Message>,
+    /// Whether the rail between the handles is filled.
+    filled: bool,
+    /// The width of the slider.
+    width: Length,
+    /// The height of the slider.
+    height: f32,
+    /// The style of the slider.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RangeSlider`](RangeSlider).
+    ///
+    /// It expects:
+    ///     * the inclusive range of the values.
+    ///     * the selected lower and upper value.
+    ///     * the function that will be called with the new lower and upper
+    ///         value when a handle is moved.
+    pub fn new<F>(range: RangeInclusive<T>, values: (T, T), on_change: F) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        RangeSlider {
+            id: None,
+            range,
+            values,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            filled: true,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`RangeSlider`](RangeSlider) to focus its lower handle with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the step the values of the [`RangeSlider`](RangeSlider) snap to.
+    #[must_use]
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the message sent when a handle is released.
+    ///
+    /// This is useful to apply an expensive filter only once the user is
+    /// done dragging.
+    #[must_use]
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets whether the rail between the handles is filled.
+    #[must_use]
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the snapped value of the range nearest to the value.
+    fn snap(&self, value: f64) -> T {
+        let (min, max) = ((*self.range.start()).into(), (*self.range.end()).into());
+
+        T::from_f64(snap(value, min, max, self.step.into())).unwrap_or(*self.range.start())
+    }
+
+    /// Returns the value of the handle.
+    fn value(&self, handle: Handle) -> T {
+        match handle {
+            Handle::Low => self.values.0,
+            Handle::High => self.values.1,
+        }
+    }
+
+    /// Returns the value at the horizontal position.
+    fn value_at(&self, bounds: Rectangle, x: f32) -> T {
+        let (start, width) = rail(bounds);
+        let (min, max) = ((*self.range.start()).into(), (*self.range.end()).into());
+        let ratio = f64::from(((x - start) / width).clamp(0.0, 1.0));
+
+        self.snap(min + ratio * (max - min))
+    }
+
+    /// Returns the horizontal position of the center of the handle.
+    fn position(&self, bounds: Rectangle, handle: Handle) -> f32 {
+        let (start, width) = rail(bounds);
+        let (min, max) = ((*self.range.start()).into(), (*self.range.end()).into());
+        let value: f64 = self.value(handle).into();
+        let ratio = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let ratio = ratio as f32;
+
+        start + ratio * width
+    }
+
+    /// Returns the handle nearest to the horizontal position.
+    fn handle_at(&self, bounds: Rectangle, x: f32) -> Handle {
+        let low = self.position(bounds, Handle::Low);
+        let high = self.position(bounds, Handle::High);
+
+        // Stacked handles are split by the side they are pressed on
+        if (x - low).abs() < (x - high).abs() || (low >= high && x < low) {
+            Handle::Low
+        } else {
+            Handle::High
+        }
+    }
+
+    /// Returns the bounds of the handle.
+    fn handle_bounds(&self, bounds: Rectangle, handle: Handle, radius: f32) -> Rectangle {
+        Rectangle {
+            x: self.position(bounds, handle) - radius,
+            y: bounds.center_y() - radius,
+            width: 2.0 * radius,
+            height: 2.0 * radius,
+        }
+    }
+
+    /// Moves the handle to the value, keeping it on its side of the other
+    /// handle, and publishes the new values if they changed.
+    fn change(&self, handle: Handle, value: T, shell: &mut Shell<'_, Message>) {
+        let (low, high) = self.values;
+        let values = match handle {
+            Handle::Low if value > high => (high, high),
+            Handle::Low => (value, high),
+            Handle::High if value < low => (low, low),
+            Handle::High => (low, value),
+        };
+
+        if values != self.values {
+            shell.publish((self.on_change)(values));
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let slider_state: &mut State = state.state.downcast_mut();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    slider_state.focus(None);
+                    return event::Status::Ignored;
+                }
+
+                let handle = self.handle_at(bounds, cursor_position.x);
+
+                slider_state.dragging = Some(handle);
+                slider_state.focus(Some(handle));
+                self.change(handle, self.value_at(bounds, cursor_position.x), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(handle) = slider_state.dragging else {
+                    return event::Status::Ignored;
+                };
+
+                self.change(handle, self.value_at(bounds, position.x), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if slider_state.dragging.take().is_none() {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                let Some(handle) = slider_state.focused() else {
+                    return event::Status::Ignored;
+                };
+
+                let value: f64 = self.value(handle).into();
+                let step: f64 = self.step.into();
+                let value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => self.snap(value - step),
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => self.snap(value + step),
+                    keyboard::KeyCode::PageDown => self.snap(value - PAGE_STEPS * step),
+                    keyboard::KeyCode::PageUp => self.snap(value + PAGE_STEPS * step),
+                    keyboard::KeyCode::Home => *self.range.start(),
+                    keyboard::KeyCode::End => *self.range.end(),
+                    _ => return event::Status::Ignored,
+                };
+
+                self.change(handle, value, shell);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let slider_state: &State = state.state.downcast_ref();
+
+        if slider_state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let slider_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+        let (start, width) = rail(bounds);
+        let rail_bounds = |x: f32, width: f32| Rectangle {
+            x,
+            y: bounds.center_y() - appearance.rail_width / 2.0,
+            width,
+            height: appearance.rail_width,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail_bounds(start, width),
+                border_radius: (appearance.rail_width / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.rail_color,
+        );
+
+        if self.filled {
+            let low = self.position(bounds, Handle::Low);
+            let high = self.position(bounds, Handle::High);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rail_bounds(low, high - low),
+                    border_radius: (appearance.rail_width / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.filled_color,
+            );
+        }
+
+        // The dragged handle is drawn last to stay on top
+        let handles = if slider_state.dragging == Some(Handle::Low) {
+            [Handle::High, Handle::Low]
+        } else {
+            [Handle::Low, Handle::High]
+        };
+
+        for handle in handles {
+            let handle_bounds = self.handle_bounds(bounds, handle, appearance.handle_radius);
+            let appearance = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(
+                    handle_bounds.contains(cursor_position),
+                    slider_state.dragging == Some(handle),
+                    slider_state.focused() == Some(handle),
+                    false,
+                ),
+                self.style,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.handle_bounds(bounds, handle, appearance.handle_radius),
+                    border_radius: appearance.handle_radius.into(),
+                    border_width: appearance.handle_border_width,
+                    border_color: appearance.handle_border_color,
+                },
+                appearance.handle_color,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let slider_state: &mut State = state.state.downcast_mut();
+
+        // Each handle takes part in the focus traversal on its own
+        operation.focusable(&mut slider_state.low, self.id.as_ref());
+        operation.focusable(&mut slider_state.high, None);
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(range_slider: RangeSlider<'a, T, Message, Renderer>) -> Self {
+        Element::new(range_slider)
+    }
+}
+
+/// A handle of a [`RangeSlider`](RangeSlider).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Handle {
+    /// The handle of the lower value.
+    Low,
+    /// The handle of the upper value.
+    High,
+}
+
+/// The state of a [`RangeSlider`](RangeSlider).
+///
+/// The [`RangeSlider`](RangeSlider) keeps it in the widget tree, so it doesn't
+/// need to be held by the application.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The handle dragged by the user.
+    dragging: Option<Handle>,
+    /// The keyboard focus of the lower handle.
+    low: Focus,
+    /// The keyboard focus of the upper handle.
+    high: Focus,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            dragging: None,
+            low: Focus::new(),
+            high: Focus::new(),
+        }
+    }
+
+    /// Returns the focused handle.
+    fn focused(&self) -> Option<Handle> {
+        if self.low.is_focused() {
+            Some(Handle::Low)
+        } else if self.high.is_focused() {
+            Some(Handle::High)
+        } else {
+            None
+        }
+    }
+
+    /// Focuses the handle and unfocuses the other one.
+    fn focus(&mut self, handle: Option<Handle>) {
+        self.low.press(handle == Some(Handle::Low));
+        self.high.press(handle == Some(Handle::High));
+    }
+}
+
+/// Returns the horizontal start and the width of the rail, inset by half the
+/// height so the handles stay inside the bounds.
+fn rail(bounds: Rectangle) -> (f32, f32) {
+    let inset = bounds.height / 2.0;
+
+    (bounds.x + inset, (bounds.width - 2.0 * inset).max(1.0))
+}
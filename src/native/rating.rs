@@ -0,0 +1,308 @@
+//! Rate something with a row of icons, like stars.
+//!
+//! *This API requires the following crate features to be activated: rating*
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, touch,
+    widget::Tree,
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::rating::{Appearance, StyleSheet};
+
+/// The default number of icons.
+const DEFAULT_COUNT: u8 = 5;
+
+/// The default spacing between two icons.
+const DEFAULT_SPACING: f32 = 4.0;
+
+/// A row of icons, like stars, showing a rating and letting the user pick one.
+///
+/// Hovering an icon previews the rating it would set. With half steps, the left
+/// half of an icon sets half a point. Without an
+/// [`on_change`](Rating::on_change) function the rating is read-only and may
+/// show any fraction of a point.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::rating;
+/// #
+/// # pub type Rating<'a, Message> = rating::Rating<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Rated(f32),
+/// }
+///
+/// let rating = Rating::new(3.5).half_steps(true).on_change(Message::Rated);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Rating<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The rating shown.
+    value: f32,
+    /// The number of icons.
+    count: u8,
+    /// The icon of a point.
+    icon: Icon,
+    /// Whether the user can pick half a point.
+    half_steps: bool,
+    /// The function producing the message when the user picks a rating.
+    on_change: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    /// The size of the icons.
+    size: Option<f32>,
+    /// The spacing between two icons.
+    spacing: f32,
+    /// The style of the rating.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Rating<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new read-only [`Rating`](Rating) showing the value out of
+    /// five stars.
+    #[must_use]
+    pub fn new(value: f32) -> Self {
+        Rating {
+            value,
+            count: DEFAULT_COUNT,
+            icon: Icon::StarFill,
+            half_steps: false,
+            on_change: None,
+            size: None,
+            spacing: DEFAULT_SPACING,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function that will be called with the rating the user picks.
+    #[must_use]
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the number of icons of the [`Rating`](Rating).
+    #[must_use]
+    pub fn count(mut self, count: u8) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the [`Icon`](Icon) of a point.
+    ///
+    /// The icon should be filled, as its empty part is drawn in a different
+    /// color.
+    #[must_use]
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets whether the user can pick half a point.
+    #[must_use]
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Sets the size of the icons.
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the spacing between two icons.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the [`Rating`](Rating).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the rating the user picks at the position, if any.
+    fn value_at(&self, bounds: Rectangle, position: Point) -> Option<f32> {
+        if self.on_change.is_none() || !bounds.contains(position) {
+            return None;
+        }
+
+        let size = bounds.height;
+        let offset = position.x - bounds.x;
+        // The spacing after an icon still belongs to it
+        let slot = (offset / (size + self.spacing)).floor();
+        let within = offset - slot * (size + self.spacing);
+        let point = if self.half_steps && within < size / 2.0 {
+            0.5
+        } else {
+            1.0
+        };
+
+        Some((slot + point).min(f32::from(self.count)))
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Rating<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let count = f32::from(self.count);
+        let width = (count * size + (count - 1.0).max(0.0) * self.spacing).max(0.0);
+
+        Node::new(limits.resolve(Size::new(width, size)))
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let value = self.value_at(layout.bounds(), cursor_position);
+
+                match (value, &self.on_change) {
+                    (Some(value), Some(on_change)) => {
+                        shell.publish(on_change(value));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.value_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                false,
+                false,
+                self.on_change.is_none(),
+            ),
+            self.style,
+        );
+        let size = bounds.height;
+        let preview = self.value_at(bounds, cursor_position);
+        let (value, color) = preview.map_or((self.value, appearance.filled_color), |preview| {
+            (preview, appearance.hovered_color)
+        });
+
+        for index in 0..self.count {
+            let icon_bounds = Rectangle {
+                x: bounds.x + f32::from(index) * (size + self.spacing),
+                y: bounds.y,
+                width: size,
+                height: size,
+            };
+            let fill = (value - f32::from(index)).clamp(0.0, 1.0);
+
+            renderer.fill_glyph(
+                char::from(self.icon),
+                self.icon.font(),
+                icon_bounds,
+                size,
+                appearance.empty_color,
+            );
+
+            if fill <= 0.0 {
+                continue;
+            }
+
+            // The filled part covers the empty icon from the left
+            renderer.with_layer(
+                Rectangle {
+                    width: size * fill,
+                    ..icon_bounds
+                },
+                |renderer| {
+                    renderer.fill_glyph(
+                        char::from(self.icon),
+                        self.icon.font(),
+                        icon_bounds,
+                        size,
+                        color,
+                    );
+                },
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Rating<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(rating: Rating<'a, Message, Renderer>) -> Self {
+        Element::new(rating)
+    }
+}
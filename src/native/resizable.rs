@@ -0,0 +1,718 @@
+//! Resize a widget by dragging handles on its edges and corners.
+//!
+//! *This API requires the following crate features to be activated: resizable*
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, touch,
+    widget::{
+        tree::{self, Tree},
+        Operation,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::resizable::{Appearance, StyleSheet};
+
+/// The default width of the area along an edge grabbing its handle.
+const DEFAULT_HANDLE_SIZE: f32 = 8.0;
+
+/// The size of a dot of a grip.
+const GRIP_DOT: f32 = 2.0;
+
+/// The distance between the dots of a grip.
+const GRIP_SPACING: f32 = 4.0;
+
+/// An edge or a corner of a [`Resizable`](Resizable) that can be dragged to
+/// resize it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handle {
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl Handle {
+    /// Returns the directions the handle grows the width and the height in,
+    /// `-1` towards the left or the top and `1` towards the right or the
+    /// bottom.
+    fn directions(self) -> (i8, i8) {
+        match self {
+            Self::Top => (0, -1),
+            Self::Bottom => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::TopLeft => (-1, -1),
+            Self::TopRight => (1, -1),
+            Self::BottomLeft => (-1, 1),
+            Self::BottomRight => (1, 1),
+        }
+    }
+
+    /// Returns the handle growing the width and the height in the given
+    /// directions, if any.
+    fn from_directions(directions: (i8, i8)) -> Option<Self> {
+        match directions {
+            (0, -1) => Some(Self::Top),
+            (0, 1) => Some(Self::Bottom),
+            (-1, 0) => Some(Self::Left),
+            (1, 0) => Some(Self::Right),
+            (-1, -1) => Some(Self::TopLeft),
+            (1, -1) => Some(Self::TopRight),
+            (-1, 1) => Some(Self::BottomLeft),
+            (1, 1) => Some(Self::BottomRight),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the handle is a corner.
+    fn is_corner(self) -> bool {
+        let (horizontal, vertical) = self.directions();
+        horizontal != 0 && vertical != 0
+    }
+
+    /// Returns the mouse interaction while hovering or dragging the handle.
+    fn interaction(self, is_dragging: bool) -> mouse::Interaction {
+        match self {
+            Self::Left | Self::Right => mouse::Interaction::ResizingHorizontally,
+            Self::Top | Self::Bottom => mouse::Interaction::ResizingVertically,
+            _ if is_dragging => mouse::Interaction::Grabbing,
+            _ => mouse::Interaction::Grab,
+        }
+    }
+}
+
+/// A wrapper giving its content a size that the user changes by dragging
+/// handles on the chosen edges and corners, like a floating panel or a note.
+///
+/// The size is owned by the application: every drag of a handle produces a
+/// message with the new size, clamped between the minimal and the maximal
+/// size, which is expected to be passed back to the [`Resizable`](Resizable).
+/// Dragging the left or the top edge grows the size towards the left or the
+/// top, so the position of a floating panel should be moved by the change.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::{widget::Text, Size};
+/// # use iced_aw::native::resizable;
+/// #
+/// # pub type Resizable<'a, Message> = resizable::Resizable<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Resized(Size),
+/// }
+///
+/// let note = Text::new("A note");
+///
+/// let resizable = Resizable::new(note, Size::new(200.0, 150.0), Message::Resized)
+///     .min_size(Size::new(100.0, 80.0));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Resizable<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content of the [`Resizable`](Resizable).
+    content: Element<'a, Message, Renderer>,
+    /// The current size of the [`Resizable`](Resizable).
+    size: Size,
+    /// The function producing the message of a change of the size.
+    on_resize: Box<dyn Fn(Size) -> Message + 'a>,
+    /// The edges and corners that can be dragged.
+    handles: Vec<Handle>,
+    /// The minimal size.
+    min_size: Size,
+    /// The maximal size.
+    max_size: Size,
+    /// The width of the area along an edge grabbing its handle.
+    handle_size: f32,
+    /// The style of the [`Resizable`](Resizable).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Resizable<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Resizable`](Resizable) of the given size, resizable by
+    /// its right and bottom edges and its bottom right corner.
+    ///
+    /// It expects:
+    ///     * the content [`Element`](iced_native::Element).
+    ///     * the current [`Size`](iced_native::Size).
+    ///     * the function producing the message of a change of the size.
+    pub fn new<C, F>(content: C, size: Size, on_resize: F) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(Size) -> Message,
+    {
+        Resizable {
+            content: content.into(),
+            size,
+            on_resize: Box::new(on_resize),
+            handles: vec![Handle::Right, Handle::Bottom, Handle::BottomRight],
+            min_size: Size::ZERO,
+            max_size: Size::INFINITY,
+            handle_size: DEFAULT_HANDLE_SIZE,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Handle`](Handle)s that can be dragged.
+    #[must_use]
+    pub fn handles(mut self, handles: impl IntoIterator<Item = Handle>) -> Self {
+        self.handles = handles.into_iter().collect();
+        self
+    }
+
+    /// Sets the minimal [`Size`](iced_native::Size).
+    #[must_use]
+    pub fn min_size(mut self, min_size: Size) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the maximal [`Size`](iced_native::Size).
+    #[must_use]
+    pub fn max_size(mut self, max_size: Size) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets the width of the area along an edge grabbing its handle.
+    ///
+    /// The corners grab within twice this distance.
+    #[must_use]
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    /// Sets the style of the [`Resizable`](Resizable).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the enabled [`Handle`](Handle) under the cursor, if any.
+    fn handle_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<Handle> {
+        handle_at(&self.handles, bounds, cursor_position, self.handle_size)
+    }
+}
+
+/// The drag of a handle.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The dragged handle.
+    handle: Handle,
+    /// The position of the cursor when the drag started.
+    origin: Point,
+    /// The size when the drag started.
+    start: Size,
+    /// The last size a message was produced for.
+    last: Size,
+}
+
+/// The state of a [`Resizable`](Resizable).
+#[derive(Debug, Default)]
+struct State {
+    /// The current drag, if any.
+    drag: Option<Drag>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Resizable<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = clamp(self.size, self.min_size, self.max_size);
+        let limits = limits
+            .width(Length::Fixed(size.width))
+            .height(Length::Fixed(size.height));
+
+        let content = self.content.as_widget().layout(renderer, &limits);
+
+        Node::with_children(limits.max(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(drag) = state.drag.as_mut() {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    let size = resize(
+                        drag.handle,
+                        drag.start,
+                        position - drag.origin,
+                        self.min_size,
+                        self.max_size,
+                    );
+
+                    if size != drag.last {
+                        drag.last = size;
+                        shell.publish((self.on_resize)(size));
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.drag = None;
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some(handle) = self.handle_at(bounds, cursor_position) {
+                let start = clamp(self.size, self.min_size, self.max_size);
+                state.drag = Some(Drag {
+                    handle,
+                    origin: cursor_position,
+                    start,
+                    last: start,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(drag) = state.drag {
+            return drag.handle.interaction(true);
+        }
+
+        if let Some(handle) = self.handle_at(layout.bounds(), cursor_position) {
+            return handle.interaction(false);
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor_position,
+            viewport,
+        );
+
+        let highlighted = state
+            .drag
+            .map(|drag| drag.handle)
+            .or_else(|| self.handle_at(bounds, cursor_position));
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(highlighted.is_some(), state.drag.is_some(), false, false),
+            self.style,
+        );
+
+        for handle in self.handles.iter().filter(|handle| handle.is_corner()) {
+            draw_grip(renderer, bounds, *handle, appearance.grip_color);
+        }
+
+        if let Some(handle) = highlighted {
+            for bounds in lines(bounds, handle, appearance.handle_width, self.handle_size) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: (appearance.handle_width / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.handle_color,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Resizable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(resizable: Resizable<'a, Message, Renderer>) -> Self {
+        Element::new(resizable)
+    }
+}
+
+/// Clamps the size between the minimal and the maximal size, preferring the
+/// minimal size if they contradict.
+fn clamp(size: Size, min: Size, max: Size) -> Size {
+    Size::new(
+        size.width.min(max.width).max(min.width),
+        size.height.min(max.height).max(min.height),
+    )
+}
+
+/// Returns the size after dragging the handle by the distance, starting from
+/// the given size.
+fn resize(handle: Handle, start: Size, distance: Vector, min: Size, max: Size) -> Size {
+    let (horizontal, vertical) = handle.directions();
+
+    clamp(
+        Size::new(
+            start.width + f32::from(horizontal) * distance.x,
+            start.height + f32::from(vertical) * distance.y,
+        ),
+        min,
+        max,
+    )
+}
+
+/// Returns the enabled handle under the cursor, if any.
+///
+/// Corners grab within twice the handle size and take precedence over the
+/// edges they join.
+fn handle_at(
+    handles: &[Handle],
+    bounds: Rectangle,
+    cursor_position: Point,
+    handle_size: f32,
+) -> Option<Handle> {
+    if !bounds.contains(cursor_position) {
+        return None;
+    }
+
+    let direction = |start: f32, position: f32, length: f32, size: f32| {
+        if position - start < size {
+            -1
+        } else if start + length - position < size {
+            1
+        } else {
+            0
+        }
+    };
+    let directions = |size: f32| {
+        (
+            direction(bounds.x, cursor_position.x, bounds.width, size),
+            direction(bounds.y, cursor_position.y, bounds.height, size),
+        )
+    };
+
+    let (horizontal, vertical) = directions(2.0 * handle_size);
+    let corner = Handle::from_directions((horizontal, vertical))
+        .filter(|handle| handle.is_corner() && handles.contains(handle));
+
+    let (horizontal, vertical) = directions(handle_size);
+    let edge = || {
+        [(horizontal, 0), (0, vertical)]
+            .into_iter()
+            .filter_map(Handle::from_directions)
+            .find(|handle| handles.contains(handle))
+    };
+
+    corner.or_else(edge)
+}
+
+/// Returns the lines of the given thickness drawn along the edges of the
+/// handle.
+fn lines(bounds: Rectangle, handle: Handle, thickness: f32, handle_size: f32) -> Vec<Rectangle> {
+    let (horizontal, vertical) = handle.directions();
+    let length = |full: f32| {
+        if handle.is_corner() {
+            (2.0 * handle_size).min(full)
+        } else {
+            full
+        }
+    };
+
+    let mut lines = Vec::with_capacity(2);
+
+    if horizontal != 0 {
+        let height = length(bounds.height);
+        lines.push(Rectangle {
+            x: if horizontal < 0 {
+                bounds.x
+            } else {
+                bounds.x + bounds.width - thickness
+            },
+            y: if vertical > 0 {
+                bounds.y + bounds.height - height
+            } else {
+                bounds.y
+            },
+            width: thickness,
+            height,
+        });
+    }
+
+    if vertical != 0 {
+        let width = length(bounds.width);
+        lines.push(Rectangle {
+            x: if horizontal > 0 {
+                bounds.x + bounds.width - width
+            } else {
+                bounds.x
+            },
+            y: if vertical < 0 {
+                bounds.y
+            } else {
+                bounds.y + bounds.height - thickness
+            },
+            width,
+            height: thickness,
+        });
+    }
+
+    lines
+}
+
+/// Draws the grip of a corner as a triangle of dots.
+fn draw_grip<Renderer>(renderer: &mut Renderer, bounds: Rectangle, corner: Handle, color: Color)
+where
+    Renderer: iced_native::Renderer,
+{
+    let (horizontal, vertical) = corner.directions();
+    let x = if horizontal < 0 {
+        bounds.x + GRIP_SPACING
+    } else {
+        bounds.x + bounds.width - GRIP_SPACING - GRIP_DOT
+    };
+    let y = if vertical < 0 {
+        bounds.y + GRIP_SPACING
+    } else {
+        bounds.y + bounds.height - GRIP_SPACING - GRIP_DOT
+    };
+
+    for column in 0..3_u8 {
+        for row in 0..3 - column {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - f32::from(horizontal) * f32::from(column) * GRIP_SPACING,
+                        y: y - f32::from(vertical) * f32::from(row) * GRIP_SPACING,
+                        width: GRIP_DOT,
+                        height: GRIP_DOT,
+                    },
+                    border_radius: (GRIP_DOT / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle_at, resize, Handle};
+    use iced_native::{Point, Rectangle, Size, Vector};
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 80.0,
+    };
+
+    #[test]
+    fn resizes_towards_the_handle() {
+        let start = Size::new(100.0, 80.0);
+        let distance = Vector::new(10.0, -5.0);
+
+        assert_eq!(
+            resize(Handle::Right, start, distance, Size::ZERO, Size::INFINITY),
+            Size::new(110.0, 80.0)
+        );
+        assert_eq!(
+            resize(Handle::TopLeft, start, distance, Size::ZERO, Size::INFINITY),
+            Size::new(90.0, 85.0)
+        );
+    }
+
+    #[test]
+    fn clamps_the_size() {
+        let start = Size::new(100.0, 80.0);
+        let min = Size::new(50.0, 50.0);
+        let max = Size::new(120.0, 200.0);
+
+        assert_eq!(
+            resize(
+                Handle::BottomRight,
+                start,
+                Vector::new(40.0, -60.0),
+                min,
+                max
+            ),
+            Size::new(120.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn finds_enabled_handles() {
+        let handles = [Handle::Right, Handle::Bottom, Handle::BottomRight];
+
+        assert_eq!(
+            handle_at(&handles, BOUNDS, Point::new(97.0, 40.0), 8.0),
+            Some(Handle::Right)
+        );
+        assert_eq!(
+            handle_at(&handles, BOUNDS, Point::new(90.0, 70.0), 8.0),
+            Some(Handle::BottomRight)
+        );
+        assert_eq!(
+            handle_at(&handles, BOUNDS, Point::new(3.0, 40.0), 8.0),
+            None
+        );
+        assert_eq!(
+            handle_at(&handles, BOUNDS, Point::new(50.0, 40.0), 8.0),
+            None
+        );
+        assert_eq!(
+            handle_at(&[Handle::Bottom], BOUNDS, Point::new(97.0, 77.0), 8.0),
+            Some(Handle::Bottom)
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Build content adapting to the width it is given.
+//!
+//! *This API requires the following crate features to be activated: responsive*
+use std::cell::{Cell, RefCell};
+
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    widget::{
+        tree::{self, Tag},
+        Operation, Tree,
+    },
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::core::breakpoint::{Breakpoint, Breakpoints};
+
+/// A widget building its content for the [`Breakpoint`](Breakpoint) of the
+/// width it fills.
+///
+/// The content is only built again if the [`Breakpoint`](Breakpoint)
+/// changes. Widgets of this crate adapt to it, e.g. the
+/// [`TabBar`](crate::native::tab_bar::TabBar) only shows the icons of its
+/// labels and the [`Split`](crate::native::split::Split) turns into a drawer
+/// on [`Compact`](Breakpoint::Compact) layouts.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::responsive;
+/// # use iced_aw::core::breakpoint::Breakpoint;
+/// #
+/// # pub type Responsive<'a, Message> = responsive::Responsive<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+/// }
+///
+/// let responsive = Responsive::<Message>::new(|breakpoint| match breakpoint {
+///     Breakpoint::Compact => Text::new("Phone").into(),
+///     Breakpoint::Medium | Breakpoint::Expanded => Text::new("Desktop").into(),
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// The function building the content for a [`Breakpoint`](Breakpoint).
+    view: Box<dyn Fn(Breakpoint) -> Element<'a, Message, Renderer> + 'a>,
+    /// The widths separating the [`Breakpoint`](Breakpoint)s.
+    breakpoints: Breakpoints,
+    /// The content built for the last [`Breakpoint`](Breakpoint).
+    content: RefCell<Option<Content<'a, Message, Renderer>>>,
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Creates a new [`Responsive`](Responsive) building its content with the
+    /// given function.
+    pub fn new<F>(view: F) -> Self
+    where
+        F: 'a + Fn(Breakpoint) -> Element<'a, Message, Renderer>,
+    {
+        Responsive {
+            view: Box::new(view),
+            breakpoints: Breakpoints::default(),
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Sets the widths separating the [`Breakpoint`](Breakpoint)s.
+    #[must_use]
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Builds the content again if the [`Breakpoint`](Breakpoint) of the
+    /// size changed and lays it out if the size changed.
+    fn update(&self, state: &State, renderer: &Renderer, size: Size) {
+        let breakpoint = self.breakpoints.breakpoint(size.width);
+        let mut content = self.content.borrow_mut();
+
+        match content.as_mut() {
+            Some(content) if content.breakpoint == breakpoint => {
+                if content.size != size {
+                    content.layout(renderer, size);
+                }
+            }
+            _ => {
+                let element = (self.view)(breakpoint);
+                state.tree.borrow_mut().diff(&element);
+                state.breakpoint.set(Some(breakpoint));
+
+                let mut new_content = Content {
+                    breakpoint,
+                    size,
+                    layout: Node::new(Size::ZERO),
+                    element,
+                };
+                new_content.layout(renderer, size);
+                *content = Some(new_content);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Responsive<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+
+        // The tree is diffed against the content of the last breakpoint, so
+        // its state survives rebuilding the view
+        if let Some(breakpoint) = state.breakpoint.get() {
+            let element = (self.view)(breakpoint);
+            state.tree.get_mut().diff(&element);
+
+            *self.content.borrow_mut() = Some(Content {
+                breakpoint,
+                size: Size::ZERO,
+                layout: Node::new(Size::ZERO),
+                element,
+            });
+        }
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fill
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        // The content is built once the final size is known
+        Node::new(limits.max())
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state: &mut State = state.state.downcast_mut();
+        self.update(state, renderer, layout.bounds().size());
+
+        let content = self
+            .content
+            .get_mut()
+            .as_mut()
+            .expect("Native: Responsive should have a content after an update");
+
+        content.element.as_widget_mut().on_event(
+            state.tree.get_mut(),
+            event,
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state: &State = state.state.downcast_ref();
+        self.update(state, renderer, layout.bounds().size());
+
+        let content = self.content.borrow();
+        let content = content
+            .as_ref()
+            .expect("Native: Responsive should have a content after an update");
+
+        content.element.as_widget().mouse_interaction(
+            &state.tree.borrow(),
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state: &State = state.state.downcast_ref();
+        self.update(state, renderer, layout.bounds().size());
+
+        let content = self.content.borrow();
+        let content = content
+            .as_ref()
+            .expect("Native: Responsive should have a content after an update");
+
+        content.element.as_widget().draw(
+            &state.tree.borrow(),
+            renderer,
+            theme,
+            style,
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state: &mut State = state.state.downcast_mut();
+        self.update(state, renderer, layout.bounds().size());
+
+        let content = self.content.borrow();
+        let content = content
+            .as_ref()
+            .expect("Native: Responsive should have a content after an update");
+
+        content.element.as_widget().operate(
+            state.tree.get_mut(),
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state: &'b mut State = state.state.downcast_mut();
+        self.update(state, renderer, layout.bounds().size());
+
+        let Content {
+            element,
+            layout: content_layout,
+            ..
+        } = self.content.get_mut().as_mut()?;
+
+        element.as_widget_mut().overlay(
+            state.tree.get_mut(),
+            Layout::with_offset(layout.position() - Point::ORIGIN, content_layout),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(responsive: Responsive<'a, Message, Renderer>) -> Self {
+        Element::new(responsive)
+    }
+}
+
+/// The content of a [`Responsive`](Responsive) built for a
+/// [`Breakpoint`](Breakpoint).
+struct Content<'a, Message, Renderer> {
+    /// The [`Breakpoint`](Breakpoint) the content was built for.
+    breakpoint: Breakpoint,
+    /// The size the content was laid out for.
+    size: Size,
+    /// The layout of the content.
+    layout: Node,
+    /// The content.
+    element: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Lays the content out to fit into the size.
+    fn layout(&mut self, renderer: &Renderer, size: Size) {
+        self.size = size;
+        self.layout = self
+            .element
+            .as_widget()
+            .layout(renderer, &Limits::new(Size::ZERO, size));
+    }
+}
+
+/// The state of a [`Responsive`](Responsive).
+struct State {
+    /// The [`Breakpoint`](Breakpoint) the content was last built for.
+    breakpoint: Cell<Option<Breakpoint>>,
+    /// The tree of the content.
+    tree: RefCell<Tree>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) without content.
+    fn new() -> Self {
+        Self {
+            breakpoint: Cell::new(None),
+            tree: RefCell::new(Tree::empty()),
+        }
+    }
+}
@@ -0,0 +1,711 @@
+//! Display text made of differently styled spans.
+//!
+//! *This API requires the following crate features to be activated: rich_text*
+use std::{borrow::Cow, ops::Range};
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::Tree,
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+pub use crate::style::rich_text::{Appearance, StyleSheet};
+
+/// The height of a line relative to the text size.
+const LINE_HEIGHT: f32 = 1.3;
+
+/// The horizontal offset of the second stroke of a span made bold without a
+/// bold font.
+const FAUX_BOLD_OFFSET: f32 = 0.5;
+
+/// A piece of text of a [`RichText`](RichText) with a style of its own.
+///
+/// The properties a span doesn't set are taken from the
+/// [`RichText`](RichText) and its style.
+#[derive(Clone, Debug)]
+pub struct Span<'a, Message> {
+    /// The text of the [`Span`](Span).
+    text: Cow<'a, str>,
+    /// The color of the [`Span`](Span), if any.
+    color: Option<Color>,
+    /// The text size of the [`Span`](Span), if any.
+    size: Option<f32>,
+    /// The font of the [`Span`](Span), if any.
+    font: Option<Font>,
+    /// Whether the [`Span`](Span) is bold.
+    bold: bool,
+    /// Whether the [`Span`](Span) is underlined.
+    underline: bool,
+    /// The message produced by pressing the [`Span`](Span), if it is a link.
+    link: Option<Message>,
+}
+
+impl<'a, Message> Span<'a, Message> {
+    /// Creates a new [`Span`](Span) of the text.
+    ///
+    /// Line breaks in the text start a new line.
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+            size: None,
+            font: None,
+            bold: false,
+            underline: false,
+            link: None,
+        }
+    }
+
+    /// Sets the color of the [`Span`](Span).
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the text size of the [`Span`](Span).
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`Span`](Span).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Makes the [`Span`](Span) bold.
+    ///
+    /// The span takes the [`bold_font`](RichText::bold_font) of the
+    /// [`RichText`](RichText), unless it has a font of its own. Without a
+    /// bold font, the span is thickened by drawing it twice.
+    #[must_use]
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Underlines the [`Span`](Span).
+    #[must_use]
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Makes the [`Span`](Span) a link producing the message when pressed.
+    ///
+    /// Links are underlined and take the link color of the style, unless they
+    /// have a color of their own.
+    #[must_use]
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+impl<'a, Message> From<&'a str> for Span<'a, Message> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<'a, Message> From<String> for Span<'a, Message> {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// A text made of [`Span`](Span)s with styles of their own, wrapped as a
+/// whole.
+///
+/// The lines are only broken at whitespace, even across the boundaries of the
+/// spans, so a word made of differently styled spans stays together.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Color};
+/// # use iced_aw::native::rich_text::{self, Span};
+/// #
+/// # pub type RichText<'a, Message> = rich_text::RichText<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     LinkPressed(&'static str),
+/// }
+///
+/// let rich_text = RichText::new(vec![
+///     Span::new("Rich texts can be "),
+///     Span::new("bold").bold(),
+///     Span::new(", "),
+///     Span::new("red").color(Color::from_rgb(0.8, 0.2, 0.2)),
+///     Span::new(" or "),
+///     Span::new("links").link(Message::LinkPressed("https://iced.rs")),
+///     Span::new("."),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The spans of the [`RichText`](RichText).
+    spans: Vec<Span<'a, Message>>,
+    /// The width of the [`RichText`](RichText).
+    width: Length,
+    /// The text size of the spans without a size of their own.
+    text_size: Option<f32>,
+    /// The font of the spans without a font of their own.
+    font: Font,
+    /// The font of the bold spans without a font of their own, if any.
+    bold_font: Option<Font>,
+    /// The horizontal alignment of the lines.
+    horizontal_alignment: Horizontal,
+    /// The style of the [`RichText`](RichText).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> RichText<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RichText`](RichText) made of the spans.
+    pub fn new(spans: Vec<Span<'a, Message>>) -> Self {
+        Self {
+            spans,
+            width: Length::Shrink,
+            text_size: None,
+            font: Font::default(),
+            bold_font: None,
+            horizontal_alignment: Horizontal::Left,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a [`Span`](Span) to the end of the [`RichText`](RichText).
+    #[must_use]
+    pub fn push(mut self, span: impl Into<Span<'a, Message>>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the width of the [`RichText`](RichText).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the text size of the spans without a size of their own.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the spans without a font of their own.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font of the bold spans without a font of their own.
+    #[must_use]
+    pub fn bold_font(mut self, bold_font: Font) -> Self {
+        self.bold_font = Some(bold_font);
+        self
+    }
+
+    /// Sets the horizontal alignment of the lines.
+    #[must_use]
+    pub fn horizontal_alignment(mut self, alignment: Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the [`RichText`](RichText).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the text size of the [`Span`](Span).
+    fn span_size(&self, span: &Span<'a, Message>, renderer: &Renderer) -> f32 {
+        span.size
+            .or(self.text_size)
+            .unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the font of the [`Span`](Span).
+    fn span_font(&self, span: &Span<'a, Message>) -> Font {
+        span.font.unwrap_or(match self.bold_font {
+            Some(bold_font) if span.bold => bold_font,
+            _ => self.font,
+        })
+    }
+
+    /// Returns the [`Fragment`](Fragment)s of the spans wrapped at the width,
+    /// and the size they take.
+    fn fragments(&self, renderer: &Renderer, max_width: f32) -> (Vec<Fragment>, Size) {
+        let spans: Vec<_> = self
+            .spans
+            .iter()
+            .map(|span| {
+                (
+                    span.text.as_ref(),
+                    self.span_size(span, renderer) * LINE_HEIGHT,
+                )
+            })
+            .collect();
+
+        flow(
+            &spans,
+            |index, text| {
+                let span = &self.spans[index];
+                renderer.measure_width(text, self.span_size(span, renderer), self.span_font(span))
+            },
+            max_width,
+            self.horizontal_alignment,
+        )
+    }
+
+    /// Returns the index of the link [`Span`](Span) at the point, if any.
+    fn link_at(&self, fragments: &[Fragment], origin: Point, point: Point) -> Option<usize> {
+        let point = point - Vector::new(origin.x, origin.y);
+
+        fragments
+            .iter()
+            .find(|fragment| fragment.bounds.contains(point))
+            .map(|fragment| fragment.span)
+            .filter(|&span| self.spans[span].link.is_some())
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for RichText<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let (_, size) = self.fragments(renderer, limits.max().width);
+
+        Node::new(limits.resolve(size))
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let bounds = layout.bounds();
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let (fragments, _) = self.fragments(renderer, bounds.width);
+                let link = self
+                    .link_at(&fragments, bounds.position(), cursor_position)
+                    .and_then(|span| self.spans[span].link.clone());
+
+                link.map_or(event::Status::Ignored, |message| {
+                    shell.publish(message);
+                    event::Status::Captured
+                })
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        if !bounds.contains(cursor_position) {
+            return mouse::Interaction::default();
+        }
+
+        let (fragments, _) = self.fragments(renderer, bounds.width);
+
+        if self
+            .link_at(&fragments, bounds.position(), cursor_position)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let (fragments, _) = self.fragments(renderer, bounds.width);
+
+        let appearance = theme.active(self.style);
+        let hovered_link = bounds
+            .contains(cursor_position)
+            .then(|| self.link_at(&fragments, bounds.position(), cursor_position))
+            .flatten();
+        let hovered_link_color = hovered_link.map(|_| theme.hovered(self.style).link_color);
+
+        for (index, fragment) in fragments.iter().enumerate() {
+            let span = &self.spans[fragment.span];
+            let size = self.span_size(span, renderer);
+            let position = fragment.bounds.position() + Vector::new(bounds.x, bounds.y);
+
+            let color = span.color.unwrap_or_else(|| {
+                if span.link.is_none() {
+                    appearance.text_color
+                } else if hovered_link == Some(fragment.span) {
+                    hovered_link_color.unwrap_or(appearance.link_color)
+                } else {
+                    appearance.link_color
+                }
+            });
+
+            let content = span.text[fragment.range.clone()].trim_end();
+            let faux_bold = span.bold && span.font.is_none() && self.bold_font.is_none();
+            let strokes: &[f32] = if faux_bold {
+                &[0.0, FAUX_BOLD_OFFSET]
+            } else {
+                &[0.0]
+            };
+
+            for offset in strokes {
+                renderer.fill_text(text::Text {
+                    content,
+                    bounds: Rectangle {
+                        x: position.x + offset,
+                        y: position.y,
+                        width: f32::INFINITY,
+                        height: fragment.bounds.height,
+                    },
+                    size,
+                    color,
+                    font: self.span_font(span),
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                });
+            }
+
+            if span.underline || span.link.is_some() {
+                // The underline continues over the whitespace up to the next
+                // fragment of the same span on the line
+                let continues = fragments.get(index + 1).map_or(false, |next| {
+                    next.span == fragment.span && next.line == fragment.line
+                });
+                let width = if continues {
+                    fragment.bounds.width + fragment.trailing
+                } else {
+                    fragment.bounds.width
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: position.x,
+                            y: position.y + size,
+                            width,
+                            height: (size / 16.0).max(1.0),
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<RichText<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(rich_text: RichText<'a, Message, Renderer>) -> Self {
+        Element::new(rich_text)
+    }
+}
+
+/// A word of a [`Span`](Span) placed on a line.
+#[derive(Clone, Debug, PartialEq)]
+struct Fragment {
+    /// The index of the span.
+    span: usize,
+    /// The index of the line.
+    line: usize,
+    /// The byte range of the word and its trailing whitespace in the span.
+    range: Range<usize>,
+    /// The bounds of the word, without its trailing whitespace.
+    bounds: Rectangle,
+    /// The width of the trailing whitespace.
+    trailing: f32,
+}
+
+/// Returns the byte ranges of the words of the text with their trailing
+/// whitespace, and whether a line break follows them.
+fn words(text: &str) -> Vec<(Range<usize>, bool)> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut after_whitespace = false;
+
+    for (offset, c) in text.char_indices() {
+        if c == '\n' {
+            words.push((start..offset, true));
+            start = offset + 1;
+            after_whitespace = false;
+        } else if c.is_whitespace() {
+            after_whitespace = true;
+        } else if after_whitespace {
+            words.push((start..offset, false));
+            start = offset;
+            after_whitespace = false;
+        }
+    }
+
+    if start < text.len() {
+        words.push((start..text.len(), false));
+    }
+
+    words
+}
+
+/// Places the words of the spans, given as their text and line height, on
+/// lines no wider than the width, measuring them with the function.
+///
+/// Returns the [`Fragment`](Fragment)s and the size they take.
+fn flow(
+    spans: &[(&str, f32)],
+    measure: impl Fn(usize, &str) -> f32,
+    max_width: f32,
+    alignment: Horizontal,
+) -> (Vec<Fragment>, Size) {
+    let mut fragments: Vec<Fragment> = Vec::new();
+    let mut size = Size::ZERO;
+
+    let mut line = 0;
+    let mut line_start = 0;
+    // The start of the words not separated by whitespace, which wrap together
+    let mut group_start = 0;
+    let mut glued = false;
+    let mut x = 0.0;
+
+    for (index, &(text, line_height)) in spans.iter().enumerate() {
+        for (range, line_break) in words(text) {
+            let piece = &text[range.clone()];
+            let word = piece.trim_end();
+            let width = measure(index, word);
+            let trailing = measure(index, piece) - width;
+
+            if !glued {
+                group_start = fragments.len();
+            }
+
+            if x + width > max_width && group_start > line_start {
+                let shift = fragments
+                    .get(group_start)
+                    .map_or(x, |fragment| fragment.bounds.x);
+                finish_line(
+                    &mut fragments[line_start..group_start],
+                    &mut size,
+                    max_width,
+                    alignment,
+                );
+                line += 1;
+                for fragment in &mut fragments[group_start..] {
+                    fragment.bounds.x -= shift;
+                    fragment.line = line;
+                }
+
+                x -= shift;
+                line_start = group_start;
+            }
+
+            fragments.push(Fragment {
+                span: index,
+                line,
+                range,
+                bounds: Rectangle {
+                    x,
+                    y: 0.0,
+                    width,
+                    height: line_height,
+                },
+                trailing,
+            });
+            x += width + trailing;
+            glued = word.len() == piece.len();
+
+            if line_break {
+                finish_line(
+                    &mut fragments[line_start..],
+                    &mut size,
+                    max_width,
+                    alignment,
+                );
+                line += 1;
+                line_start = fragments.len();
+                glued = false;
+                x = 0.0;
+            }
+        }
+    }
+
+    finish_line(
+        &mut fragments[line_start..],
+        &mut size,
+        max_width,
+        alignment,
+    );
+
+    (fragments, size)
+}
+
+/// Places the [`Fragment`](Fragment)s of a line below the lines of the size,
+/// aligning them at the bottom and along the width, and grows the size by the
+/// line.
+fn finish_line(line: &mut [Fragment], size: &mut Size, max_width: f32, alignment: Horizontal) {
+    let height = line
+        .iter()
+        .map(|fragment| fragment.bounds.height)
+        .fold(0.0, f32::max);
+    let width = line
+        .last()
+        .map_or(0.0, |fragment| fragment.bounds.x + fragment.bounds.width);
+
+    let offset = if max_width.is_finite() {
+        match alignment {
+            Horizontal::Left => 0.0,
+            Horizontal::Center => (max_width - width) / 2.0,
+            Horizontal::Right => max_width - width,
+        }
+    } else {
+        0.0
+    };
+
+    for fragment in line {
+        fragment.bounds.x += offset;
+        fragment.bounds.y = size.height + height - fragment.bounds.height;
+    }
+
+    size.width = size.width.max(width);
+    size.height += height;
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::alignment::Horizontal;
+
+    use super::{flow, words};
+
+    #[test]
+    fn words_keep_their_trailing_whitespace() {
+        assert_eq!(
+            words("Hello, world!\n\nBye  now"),
+            vec![
+                (0..7, false),
+                (7..13, true),
+                (14..14, true),
+                (15..20, false),
+                (20..23, false),
+            ]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn wraps_at_whitespace() {
+        let measure = |_, text: &str| text.chars().count() as f32 * 10.0;
+        let (fragments, size) = flow(&[("aaa bbb ccc", 20.0)], measure, 75.0, Horizontal::Left);
+
+        let lines: Vec<_> = fragments
+            .iter()
+            .map(|fragment| (fragment.bounds.x, fragment.bounds.y))
+            .collect();
+        assert_eq!(lines, vec![(0.0, 0.0), (40.0, 0.0), (0.0, 20.0)]);
+        assert_eq!(size.height, 40.0);
+        assert_eq!(size.width, 70.0);
+    }
+
+    #[test]
+    fn words_across_spans_wrap_together() {
+        let measure = |_, text: &str| text.chars().count() as f32 * 10.0;
+        let (fragments, _) = flow(
+            &[("aaa bb", 20.0), ("cc", 30.0), (" d", 20.0)],
+            measure,
+            55.0,
+            Horizontal::Left,
+        );
+
+        let lines: Vec<_> = fragments
+            .iter()
+            .map(|fragment| (fragment.span, fragment.line, fragment.bounds.x))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (0, 0, 0.0),
+                (0, 1, 0.0),
+                (1, 1, 20.0),
+                (2, 1, 40.0),
+                (2, 2, 0.0)
+            ]
+        );
+
+        // The words on a line are aligned at the bottom
+        let tops: Vec<_> = fragments.iter().map(|fragment| fragment.bounds.y).collect();
+        assert_eq!(tops, vec![0.0, 30.0, 20.0, 30.0, 50.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn aligns_the_lines() {
+        let measure = |_, text: &str| text.chars().count() as f32 * 10.0;
+        let (fragments, _) = flow(&[("aa", 20.0)], measure, 100.0, Horizontal::Center);
+
+        assert_eq!(fragments[0].bounds.x, 40.0);
+    }
+}
@@ -0,0 +1,720 @@
+//! Seek through a media file on a timeline with buffered ranges and chapters.
+//!
+//! *This API requires the following crate features to be activated: scrubber*
+use std::{ops::Range, time::Duration};
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::scrubber::{Appearance, StyleSheet};
+
+/// The default height of the timeline of a [`Scrubber`](Scrubber).
+const DEFAULT_HEIGHT: f32 = 16.0;
+
+/// The default number of frames per second stepped through with the arrow
+/// keys.
+const DEFAULT_FRAME_RATE: f64 = 30.0;
+
+/// The default time skipped with the arrow keys while Shift is held.
+const DEFAULT_JUMP: Duration = Duration::from_secs(5);
+
+/// The padding around the text of the preview bubble.
+const BUBBLE_PADDING: f32 = 4.0;
+
+/// The gap between the preview bubble and the timeline.
+const BUBBLE_GAP: f32 = 4.0;
+
+/// The width of a chapter marker.
+const MARKER_WIDTH: f32 = 2.0;
+
+/// A timeline seeking through a media file, like the bar under a video.
+///
+/// Ranges that are already buffered are shaded and chapters are marked on the
+/// rail. Hovering the timeline shows the time under the cursor, and the title
+/// of its chapter, in a bubble above the rail. Dragging the handle sends the
+/// preview message for each move and seeks once it is released. The
+/// timeline can be focused and stepped through frame by frame with the arrow
+/// keys, by a jump while Shift is held and to the start or the end with Home
+/// and End.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::scrubber;
+/// #
+/// # pub type Scrubber<'a, Message> = scrubber::Scrubber<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Seek(Duration),
+///     Preview(Duration),
+/// }
+///
+/// let scrubber = Scrubber::new(
+///     Duration::from_secs(5 * 60),
+///     Duration::from_secs(42),
+///     Message::Seek,
+/// )
+/// .buffered(Duration::ZERO..Duration::from_secs(90))
+/// .chapter(Duration::ZERO, "Intro")
+/// .chapter(Duration::from_secs(75), "Verse")
+/// .on_preview(Message::Preview)
+/// .frame_rate(25.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Scrubber<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`Scrubber`](Scrubber) to focus it.
+    id: Option<Id>,
+    /// The length of the media.
+    duration: Duration,
+    /// The current playback position.
+    position: Duration,
+    /// The ranges of the media that are buffered.
+    buffered: Vec<Range<Duration>>,
+    /// The start and the title of each chapter, ordered by their start.
+    chapters: Vec<(Duration, String)>,
+    /// The time stepped with the arrow keys.
+    frame: Duration,
+    /// The time skipped with the arrow keys while Shift is held.
+    jump: Duration,
+    /// The function producing the message when the position is sought.
+    on_seek: Box<dyn Fn(Duration) -> Message + 'a>,
+    /// The function producing the message while the handle is dragged.
+    on_preview: Option<Box<dyn Fn(Duration) -> Message + 'a>>,
+    /// The width of the timeline.
+    width: Length,
+    /// The height of the timeline.
+    height: f32,
+    /// The text size of the preview bubble.
+    text_size: Option<f32>,
+    /// The font of the preview bubble.
+    font: Font,
+    /// The style of the timeline.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Scrubber<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Scrubber`](Scrubber).
+    ///
+    /// It expects:
+    ///     * the length of the media.
+    ///     * the current playback position.
+    ///     * the function that will be called with the position to seek to.
+    pub fn new<F>(duration: Duration, position: Duration, on_seek: F) -> Self
+    where
+        F: 'a + Fn(Duration) -> Message,
+    {
+        Scrubber {
+            id: None,
+            duration,
+            position: position.min(duration),
+            buffered: Vec::new(),
+            chapters: Vec::new(),
+            frame: Duration::from_secs_f64(1.0 / DEFAULT_FRAME_RATE),
+            jump: DEFAULT_JUMP,
+            on_seek: Box::new(on_seek),
+            on_preview: None,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`](Id) of the [`Scrubber`](Scrubber).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Adds a range of the media that is buffered.
+    #[must_use]
+    pub fn buffered(mut self, range: Range<Duration>) -> Self {
+        self.buffered.push(range);
+        self
+    }
+
+    /// Adds a chapter starting at the time with the title.
+    #[must_use]
+    pub fn chapter(mut self, start: Duration, title: impl Into<String>) -> Self {
+        let index = self.chapters.partition_point(|(other, _)| *other <= start);
+        self.chapters.insert(index, (start, title.into()));
+        self
+    }
+
+    /// Sets the function that will be called with the position under the
+    /// handle while it is dragged.
+    ///
+    /// The position is only sought once the handle is released, so the
+    /// preview can show a frame without seeking for every move.
+    #[must_use]
+    pub fn on_preview<F>(mut self, on_preview: F) -> Self
+    where
+        F: 'a + Fn(Duration) -> Message,
+    {
+        self.on_preview = Some(Box::new(on_preview));
+        self
+    }
+
+    /// Sets the number of frames per second of the media, stepped through
+    /// one by one with the arrow keys.
+    #[must_use]
+    pub fn frame_rate(mut self, frame_rate: f64) -> Self {
+        if frame_rate > 0.0 {
+            self.frame = Duration::from_secs_f64(1.0 / frame_rate);
+        }
+        self
+    }
+
+    /// Sets the time skipped with the arrow keys while Shift is held.
+    #[must_use]
+    pub fn jump(mut self, jump: Duration) -> Self {
+        self.jump = jump;
+        self
+    }
+
+    /// Sets the width of the [`Scrubber`](Scrubber).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the timeline, without the preview bubble.
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the preview bubble.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the preview bubble.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Scrubber`](Scrubber).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the height of the bubble above the timeline.
+    fn bubble_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text_size + 2.0 * BUBBLE_PADDING + BUBBLE_GAP
+    }
+
+    /// Returns the bounds of the timeline without the space kept free for the
+    /// preview bubble.
+    fn timeline(&self, bounds: Rectangle, renderer: &Renderer) -> Rectangle {
+        let bubble_height = self.bubble_height(renderer);
+
+        Rectangle {
+            y: bounds.y + bubble_height,
+            height: (bounds.height - bubble_height).max(0.0),
+            ..bounds
+        }
+    }
+
+    /// Returns the time under the horizontal position in the timeline.
+    fn time_at(&self, timeline: Rectangle, x: f32) -> Duration {
+        let (start, width) = rail(timeline, self.height);
+
+        time_at((x - start) / width, self.duration)
+    }
+
+    /// Returns the horizontal position of the time in the timeline.
+    fn x_of(&self, timeline: Rectangle, time: Duration) -> f32 {
+        let (start, width) = rail(timeline, self.height);
+
+        start + ratio(time, self.duration) * width
+    }
+
+    /// Publishes the position to seek to if it changed.
+    fn seek(&self, position: Duration, shell: &mut Shell<'_, Message>) {
+        if position != self.position {
+            shell.publish((self.on_seek)(position));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Scrubber<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width);
+        let height = self.bubble_height(renderer) + self.height;
+
+        Node::new(limits.resolve(Size::new(limits.max().width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let scrubber_state: &mut State = state.state.downcast_mut();
+        let timeline = self.timeline(layout.bounds(), renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // The space kept free for the bubble doesn't take presses
+                if !timeline.contains(cursor_position) {
+                    scrubber_state.focus.press(false);
+                    return event::Status::Ignored;
+                }
+
+                let time = self.time_at(timeline, cursor_position.x);
+                scrubber_state.drag = Some(time);
+                scrubber_state.focus.press(true);
+
+                if let Some(on_preview) = &self.on_preview {
+                    shell.publish(on_preview(time));
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(dragged) = scrubber_state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let time = self.time_at(timeline, position.x);
+
+                if time != dragged {
+                    scrubber_state.drag = Some(time);
+
+                    if let Some(on_preview) = &self.on_preview {
+                        shell.publish(on_preview(time));
+                    }
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(time) = scrubber_state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                self.seek(time, shell);
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                if !scrubber_state.focus.is_focused() || scrubber_state.drag.is_some() {
+                    return event::Status::Ignored;
+                }
+
+                let step = if modifiers.shift() {
+                    self.jump
+                } else {
+                    self.frame
+                };
+                let position = match key_code {
+                    keyboard::KeyCode::Left => self.position.saturating_sub(step),
+                    keyboard::KeyCode::Right => (self.position + step).min(self.duration),
+                    keyboard::KeyCode::Home => Duration::ZERO,
+                    keyboard::KeyCode::End => self.duration,
+                    _ => return event::Status::Ignored,
+                };
+
+                self.seek(position, shell);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let scrubber_state: &State = state.state.downcast_ref();
+
+        if scrubber_state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if self
+            .timeline(layout.bounds(), renderer)
+            .contains(cursor_position)
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let scrubber_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let timeline = self.timeline(bounds, renderer);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let hovered = timeline.contains(cursor_position);
+
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                hovered,
+                scrubber_state.drag.is_some(),
+                scrubber_state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
+
+        let (start, width) = rail(timeline, self.height);
+        let center_y = timeline.center_y();
+        let position = scrubber_state.drag.unwrap_or(self.position);
+        let handle_x = self.x_of(timeline, position);
+
+        let rail_bounds = |from: f32, to: f32| Rectangle {
+            x: from,
+            y: center_y - appearance.rail_width / 2.0,
+            width: (to - from).max(0.0),
+            height: appearance.rail_width,
+        };
+        let fill_rail = |renderer: &mut Renderer, from: f32, to: f32, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rail_bounds(from, to),
+                    border_radius: (appearance.rail_width / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        };
+
+        fill_rail(renderer, start, start + width, appearance.rail_color);
+
+        for range in &self.buffered {
+            fill_rail(
+                renderer,
+                self.x_of(timeline, range.start),
+                self.x_of(timeline, range.end),
+                appearance.buffered_color,
+            );
+        }
+
+        fill_rail(renderer, start, handle_x, appearance.played_color);
+
+        // The first chapter starts with the timeline, so it needs no marker
+        for (chapter_start, _) in &self.chapters {
+            if chapter_start.is_zero() || *chapter_start >= self.duration {
+                continue;
+            }
+
+            let x = self.x_of(timeline, *chapter_start);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - MARKER_WIDTH / 2.0,
+                        y: center_y - appearance.rail_width / 2.0 - 1.0,
+                        width: MARKER_WIDTH,
+                        height: appearance.rail_width + 2.0,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.marker_color,
+            );
+        }
+
+        let radius = appearance.handle_radius;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle_x - radius,
+                    y: center_y - radius,
+                    width: 2.0 * radius,
+                    height: 2.0 * radius,
+                },
+                border_radius: radius.into(),
+                border_width: appearance.handle_border_width,
+                border_color: appearance.handle_border_color,
+            },
+            appearance.handle_color,
+        );
+
+        // The bubble previews the dragged time or else the hovered one
+        let preview = scrubber_state
+            .drag
+            .or_else(|| hovered.then(|| self.time_at(timeline, cursor_position.x)));
+
+        let Some(preview) = preview else {
+            return;
+        };
+
+        let time = format_time(preview, self.duration >= Duration::from_secs(3600));
+        let content = match chapter_at(&self.chapters, preview) {
+            Some(title) => format!("{title} · {time}"),
+            None => time,
+        };
+        let text_width = renderer.measure_width(&content, text_size, self.font);
+        let bubble_width = text_width + 2.0 * BUBBLE_PADDING;
+
+        // The bubble follows the previewed time but stays inside the scrubber
+        let x = (self.x_of(timeline, preview) - bubble_width / 2.0)
+            .min(bounds.x + bounds.width - bubble_width)
+            .max(bounds.x);
+        let bubble_bounds = Rectangle {
+            x,
+            y: bounds.y,
+            width: bubble_width,
+            height: text_size + 2.0 * BUBBLE_PADDING,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bubble_bounds,
+                border_radius: BUBBLE_PADDING.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.bubble_background,
+        );
+
+        renderer.fill_text(text::Text {
+            content: &content,
+            bounds: Rectangle {
+                x: bubble_bounds.center_x(),
+                y: bubble_bounds.center_y(),
+                ..bubble_bounds
+            },
+            size: text_size,
+            color: appearance.bubble_text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+        });
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let scrubber_state: &mut State = state.state.downcast_mut();
+
+        operation.focusable(&mut scrubber_state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Renderer> From<Scrubber<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(scrubber: Scrubber<'a, Message, Renderer>) -> Self {
+        Element::new(scrubber)
+    }
+}
+
+/// The state of a [`Scrubber`](Scrubber).
+///
+/// The [`Scrubber`](Scrubber) keeps it in the widget tree, so it doesn't
+/// need to be held by the application.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The time under the handle while it is dragged by the user.
+    drag: Option<Duration>,
+    /// The keyboard focus of the timeline.
+    focus: Focus,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`Scrubber`](Scrubber).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            drag: None,
+            focus: Focus::new(),
+        }
+    }
+}
+
+/// Returns the horizontal start and the width of the rail, inset by half the
+/// height of the timeline so the handle stays inside the bounds.
+fn rail(bounds: Rectangle, height: f32) -> (f32, f32) {
+    let inset = height / 2.0;
+
+    (bounds.x + inset, (bounds.width - 2.0 * inset).max(1.0))
+}
+
+/// Returns how far the time is into the duration, between zero and one.
+#[allow(clippy::cast_possible_truncation)]
+fn ratio(time: Duration, duration: Duration) -> f32 {
+    if duration.is_zero() {
+        return 0.0;
+    }
+
+    (time.as_secs_f64() / duration.as_secs_f64()).clamp(0.0, 1.0) as f32
+}
+
+/// Returns the time how far into the duration the ratio is, clamped to the
+/// duration.
+fn time_at(ratio: f32, duration: Duration) -> Duration {
+    duration.mul_f64(f64::from(ratio.clamp(0.0, 1.0)))
+}
+
+/// Returns the title of the chapter the time is in, if any.
+fn chapter_at(chapters: &[(Duration, String)], time: Duration) -> Option<&str> {
+    chapters
+        .iter()
+        .take_while(|(start, _)| *start <= time)
+        .last()
+        .map(|(_, title)| title.as_str())
+}
+
+/// Formats the time as minutes and seconds, like `4:05`, or as hours,
+/// minutes and seconds, like `1:04:05`.
+fn format_time(time: Duration, hours: bool) -> String {
+    let seconds = time.as_secs();
+
+    if hours {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{chapter_at, format_time, ratio, time_at};
+
+    #[test]
+    fn times_are_formatted_with_hours_if_asked() {
+        assert_eq!(format_time(Duration::ZERO, false), "0:00");
+        assert_eq!(format_time(Duration::from_millis(245_900), false), "4:05");
+        assert_eq!(format_time(Duration::from_secs(3845), false), "64:05");
+        assert_eq!(format_time(Duration::from_secs(3845), true), "1:04:05");
+        assert_eq!(format_time(Duration::from_secs(65), true), "0:01:05");
+    }
+
+    #[test]
+    fn times_are_found_in_their_chapter() {
+        let duration = Duration::from_secs(100);
+
+        assert!((ratio(Duration::from_secs(25), duration) - 0.25).abs() < f32::EPSILON);
+        assert!((ratio(Duration::from_secs(200), duration) - 1.0).abs() < f32::EPSILON);
+        assert!(ratio(Duration::from_secs(5), Duration::ZERO).abs() < f32::EPSILON);
+        assert_eq!(time_at(0.5, duration), Duration::from_secs(50));
+        assert_eq!(time_at(-1.0, duration), Duration::ZERO);
+        assert_eq!(time_at(2.0, duration), duration);
+
+        let chapters = vec![
+            (Duration::from_secs(10), String::from("Verse")),
+            (Duration::from_secs(40), String::from("Chorus")),
+        ];
+
+        assert_eq!(chapter_at(&chapters, Duration::from_secs(5)), None);
+        assert_eq!(
+            chapter_at(&chapters, Duration::from_secs(10)),
+            Some("Verse")
+        );
+        assert_eq!(
+            chapter_at(&chapters, Duration::from_secs(39)),
+            Some("Verse")
+        );
+        assert_eq!(
+            chapter_at(&chapters, Duration::from_secs(90)),
+            Some("Chorus")
+        );
+    }
+}
@@ -0,0 +1,775 @@
+//! Search as you type with a clear button and suggestions.
+//!
+//! *This API requires the following crate features to be activated: search_bar*
+use std::fmt::Display;
+
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    time::{Duration, Instant},
+    touch,
+    widget::{
+        text_input,
+        tree::{self, Tree},
+        Operation, TextInput,
+    },
+    window, Clipboard, Color, Element, Event, Font, Layout, Length, Padding, Point, Rectangle,
+    Shell, Widget,
+};
+
+use crate::{
+    core::{renderer::GlyphRenderer, sizing::hit_target},
+    graphics::icons::Icon,
+    native::{
+        combo_box::{self, highlights, Mode},
+        overlay::ComboBoxOverlay,
+    },
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::search_bar::{Appearance, StyleSheet};
+
+/// The size of the clear button and of the loading indicator.
+const ICON_SIZE: f32 = 16.0;
+
+/// The spacing between the text, the loading indicator and the clear button.
+const ICON_SPACING: f32 = 4.0;
+
+/// The default padding of the field.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default time the text has to stay the same before it is searched.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The time each dot of the loading indicator is highlighted for.
+const INDICATOR_STEP: Duration = Duration::from_millis(300);
+
+/// The number of dots of the loading indicator.
+const INDICATOR_DOTS: u32 = 3;
+
+/// The default number of suggestions shown at once in the dropdown.
+const DEFAULT_VISIBLE_SUGGESTIONS: usize = 6;
+
+/// A text input to search with as the user types.
+///
+/// Every change of the text produces the message of the
+/// [`on_input`](SearchBar::new) function right away, while the message of the
+/// [`on_search`](SearchBar::on_search) function waits until the text stayed
+/// the same for the [`debounce`](SearchBar::debounce) time, so that a slow
+/// search only runs once the user paused typing. Enter searches the text
+/// right away.
+///
+/// A clear button empties the field, a loading indicator shows while the
+/// search is [`loading`](SearchBar::loading) and the
+/// [`suggestions`](SearchBar::suggestions) are listed below the field to pick
+/// one with the mouse or the keyboard.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::search_bar;
+/// #
+/// # pub type SearchBar<'a, Message> = search_bar::SearchBar<'a, String, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Typed(String),
+///     Search(String),
+///     Picked(String),
+/// }
+///
+/// let results = vec![String::from("Rust"), String::from("Rustacean")];
+///
+/// let search_bar = SearchBar::new("Search", "Rus", Message::Typed)
+///     .on_search(Message::Search)
+///     .suggestions(&results, Message::Picked)
+///     .loading(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SearchBar<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    /// The text in the field.
+    value: String,
+    /// The function producing the message when the text changes.
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    /// The function producing the message searching the text once it stayed
+    /// the same for the debounce time.
+    on_search: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The time the text has to stay the same before it is searched.
+    debounce: Duration,
+    /// Whether the loading indicator is shown.
+    loading: bool,
+    /// The suggestions listed below the field.
+    suggestions: &'a [T],
+    /// The function producing the message when a suggestion is picked.
+    on_select: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    /// The number of suggestions shown at once in the dropdown.
+    visible_suggestions: usize,
+    /// The field the text is typed into.
+    content: TextInput<'a, Message, Renderer>,
+    /// The padding of the field, without the space of the icons.
+    padding: Padding,
+    /// The width of the search bar.
+    width: Length,
+    /// The text size of the suggestions.
+    text_size: Option<f32>,
+    /// The font of the suggestions.
+    font: Font,
+    /// The style of the icons.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The style of the dropdown of the suggestions.
+    dropdown_style: <Renderer::Theme as combo_box::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> SearchBar<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`SearchBar`](SearchBar).
+    ///
+    /// It expects:
+    ///     * the placeholder shown while the field is empty
+    ///     * the text in the field
+    ///     * the function producing the message when the text changes
+    pub fn new<F>(placeholder: &str, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message + Clone,
+    {
+        let padding = Padding::new(DEFAULT_PADDING);
+
+        SearchBar {
+            value: value.to_owned(),
+            on_input: Box::new(on_input.clone()),
+            on_search: None,
+            debounce: DEFAULT_DEBOUNCE,
+            loading: false,
+            suggestions: &[],
+            on_select: None,
+            visible_suggestions: DEFAULT_VISIBLE_SUGGESTIONS,
+            content: TextInput::new(placeholder, value)
+                .on_input(on_input)
+                .padding(reserve_icons(padding))
+                .width(Length::Fill),
+            padding,
+            width: Length::Fill,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            dropdown_style: <Renderer::Theme as combo_box::StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message searching the text.
+    ///
+    /// The message is produced once the text stayed the same for the
+    /// [`debounce`](SearchBar::debounce) time, on Enter and when the field is
+    /// cleared.
+    #[must_use]
+    pub fn on_search<F>(mut self, on_search: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_search = Some(Box::new(on_search));
+        self
+    }
+
+    /// Sets the time the text has to stay the same before it is searched.
+    #[must_use]
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets whether the loading indicator is shown.
+    #[must_use]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the suggestions listed below the field and the function producing
+    /// the message when one is picked.
+    ///
+    /// All the suggestions are listed, so they are usually the results of the
+    /// last search.
+    #[must_use]
+    pub fn suggestions<F>(mut self, suggestions: &'a [T], on_select: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        self.suggestions = suggestions;
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the number of suggestions shown at once in the dropdown.
+    #[must_use]
+    pub fn visible_suggestions(mut self, visible_suggestions: usize) -> Self {
+        self.visible_suggestions = visible_suggestions.max(1);
+        self
+    }
+
+    /// Sets the width of the [`SearchBar`](SearchBar).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the field of the [`SearchBar`](SearchBar).
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self.content = self.content.padding(reserve_icons(self.padding));
+        self
+    }
+
+    /// Sets the text size of the [`SearchBar`](SearchBar).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self.content = self.content.size(text_size);
+        self
+    }
+
+    /// Sets the font of the [`SearchBar`](SearchBar).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self.content = self.content.font(font);
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the [`SearchBar`](SearchBar) to
+    /// focus it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.content = self.content.id(id);
+        self
+    }
+
+    /// Sets the style of the icons of the [`SearchBar`](SearchBar).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the dropdown of the suggestions.
+    #[must_use]
+    pub fn dropdown_style(
+        mut self,
+        style: <Renderer::Theme as combo_box::StyleSheet>::Style,
+    ) -> Self {
+        self.dropdown_style = style;
+        self
+    }
+
+    /// Sets the style of the field of the [`SearchBar`](SearchBar).
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.content = self.content.style(style);
+        self
+    }
+
+    /// Returns the bounds of the clear button in the field with the bounds.
+    fn clear_bounds(&self, input: Rectangle) -> Rectangle {
+        Rectangle {
+            x: input.x + input.width - self.padding.right - ICON_SIZE,
+            y: input.center_y() - ICON_SIZE / 2.0,
+            width: ICON_SIZE,
+            height: ICON_SIZE,
+        }
+    }
+
+    /// Returns whether the cursor is over the shown clear button.
+    fn over_clear(&self, input: Rectangle, cursor_position: Point) -> bool {
+        !self.value.is_empty() && hit_target(self.clear_bounds(input)).contains(cursor_position)
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for SearchBar<'a, T, Message, Renderer>
+where
+    T: Clone + Display,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.value))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree {
+            tag: self.content.tag(),
+            state: self.content.state(),
+            children: self.content.children(),
+        }]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &[&self.content],
+            |state, content| content.diff(state),
+            |&content| Tree {
+                tag: content.tag(),
+                state: content.state(),
+                children: content.children(),
+            },
+        );
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let input = self.content.layout(renderer, &limits);
+        let size = limits.resolve(input.size());
+
+        Node::with_children(size, vec![input])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let input_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have an input layout for a SearchBar");
+
+        self.content
+            .operate(&mut tree.children[0], input_layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        state.dropdown.sync(&self.value, Mode::Free);
+
+        let input_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have an input layout for a SearchBar");
+
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.now = now;
+                now
+            }
+            _ => Instant::now(),
+        };
+
+        if let Some(request) = state.animate(self.loading, now) {
+            shell.request_redraw(request);
+        }
+
+        let child = &mut tree.children[0];
+        let was_focused = child.state.downcast_ref::<text_input::State>().is_focused();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if self.over_clear(input_layout.bounds(), cursor_position) =>
+            {
+                shell.publish((self.on_input)(String::new()));
+                if let Some(on_search) = &self.on_search {
+                    shell.publish(on_search(String::new()));
+                }
+                state.searched(String::new());
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter,
+                ..
+            }) if was_focused => {
+                if let Some(on_search) = &self.on_search {
+                    shell.publish(on_search(self.value.clone()));
+                    state.searched(self.value.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        if self.on_search.is_some() {
+            match state.debounce(&self.value, now, self.debounce) {
+                Debounce::Idle => {}
+                Debounce::Wait(deadline) => {
+                    shell.request_redraw(window::RedrawRequest::At(deadline));
+                }
+                Debounce::Search => {
+                    if let Some(on_search) = &self.on_search {
+                        shell.publish(on_search(self.value.clone()));
+                    }
+                }
+            }
+        }
+
+        let status = self.content.on_event(
+            child,
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if !was_focused && child.state.downcast_ref::<text_input::State>().is_focused() {
+            state.dropdown.dismissed = false;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let input_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have an input layout for a SearchBar");
+
+        if self.over_clear(input_layout.bounds(), cursor_position) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.mouse_interaction(
+            &tree.children[0],
+            input_layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let input_layout = layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have an input layout for a SearchBar");
+        let input_bounds = input_layout.bounds();
+
+        self.content.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            input_layout,
+            cursor_position,
+            None,
+        );
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                self.over_clear(input_bounds, cursor_position),
+                false,
+                is_focused,
+                false,
+            ),
+            self.style,
+        );
+        let clear_bounds = self.clear_bounds(input_bounds);
+
+        if !self.value.is_empty() {
+            renderer.fill_glyph(
+                char::from(Icon::X),
+                Icon::X.font(),
+                clear_bounds,
+                ICON_SIZE,
+                appearance.icon_color,
+            );
+        }
+
+        if let Some(highlighted) = state.indicator_dot() {
+            let radius = ICON_SIZE / 8.0;
+            let step = ICON_SIZE / INDICATOR_DOTS as f32;
+            let x = clear_bounds.x - ICON_SPACING - ICON_SIZE;
+
+            for dot in 0..INDICATOR_DOTS {
+                let color = if dot == highlighted {
+                    appearance.indicator_color
+                } else {
+                    Color {
+                        a: appearance.indicator_color.a * 0.3,
+                        ..appearance.indicator_color
+                    }
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x + step * (dot as f32 + 0.5) - radius,
+                            y: clear_bounds.center_y() - radius,
+                            width: 2.0 * radius,
+                            height: 2.0 * radius,
+                        },
+                        border_radius: radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+        let state = tree.state.downcast_mut::<State>();
+        state.dropdown.sync(&self.value, Mode::Free);
+
+        let on_select = self.on_select.as_deref()?;
+        if !focused || state.dropdown.dismissed || self.suggestions.is_empty() {
+            return None;
+        }
+
+        let input_bounds = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have an input layout for a SearchBar")
+            .bounds();
+
+        Some(
+            ComboBoxOverlay::new(
+                self.suggestions,
+                highlights(self.suggestions, &self.value),
+                &mut state.dropdown,
+                on_select,
+                None,
+                input_bounds.size(),
+                self.visible_suggestions,
+                self.text_size,
+                self.font,
+                self.dropdown_style,
+            )
+            .overlay(input_bounds.position()),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<SearchBar<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + Display,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    fn from(search_bar: SearchBar<'a, T, Message, Renderer>) -> Self {
+        Element::new(search_bar)
+    }
+}
+
+/// What to do about the text of a [`SearchBar`](SearchBar) after an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Debounce {
+    /// The text was already searched.
+    Idle,
+    /// The text changed less than the debounce time ago, so it is searched at
+    /// the deadline.
+    Wait(Instant),
+    /// The text stayed the same for the debounce time and is searched now.
+    Search,
+}
+
+/// The state of a [`SearchBar`](SearchBar).
+#[derive(Debug)]
+struct State {
+    /// The state of the dropdown of the suggestions.
+    dropdown: combo_box::State,
+    /// The text last seen in the field.
+    value: String,
+    /// The time the text is searched at, if it changed since the last search.
+    deadline: Option<Instant>,
+    /// The time the loading indicator is shown since, while it is shown.
+    loading_since: Option<Instant>,
+    /// The time of the last redraw.
+    now: Instant,
+}
+
+impl State {
+    /// Creates the state of a [`SearchBar`](SearchBar) whose text was not
+    /// changed yet.
+    fn new(value: &str) -> Self {
+        Self {
+            dropdown: combo_box::State::default(),
+            value: value.to_owned(),
+            deadline: None,
+            loading_since: None,
+            now: Instant::now(),
+        }
+    }
+
+    /// Notes that the text was searched, so it is not searched again.
+    fn searched(&mut self, value: String) {
+        self.value = value;
+        self.deadline = None;
+    }
+
+    /// Tracks the changes of the text to search it once it stayed the same
+    /// for the delay.
+    fn debounce(&mut self, value: &str, now: Instant, delay: Duration) -> Debounce {
+        if self.value != value {
+            self.value = value.to_owned();
+            self.deadline = Some(now + delay);
+        }
+
+        match self.deadline {
+            None => Debounce::Idle,
+            Some(deadline) if now < deadline => Debounce::Wait(deadline),
+            Some(_) => {
+                self.deadline = None;
+                Debounce::Search
+            }
+        }
+    }
+
+    /// Starts or stops the loading indicator.
+    ///
+    /// Returns the redraw moving the indicator to its next dot while it is
+    /// shown.
+    fn animate(&mut self, loading: bool, now: Instant) -> Option<window::RedrawRequest> {
+        if !loading {
+            self.loading_since = None;
+            return None;
+        }
+
+        let since = *self.loading_since.get_or_insert(now);
+        let steps =
+            now.saturating_duration_since(since).as_millis() / INDICATOR_STEP.as_millis() + 1;
+
+        Some(window::RedrawRequest::At(
+            since + INDICATOR_STEP * steps as u32,
+        ))
+    }
+
+    /// Returns the highlighted dot of the loading indicator, if it is shown.
+    fn indicator_dot(&self) -> Option<u32> {
+        let since = self.loading_since?;
+        let steps =
+            self.now.saturating_duration_since(since).as_millis() / INDICATOR_STEP.as_millis();
+
+        Some((steps % u128::from(INDICATOR_DOTS)) as u32)
+    }
+}
+
+/// Returns the padding of the field with room for the loading indicator and
+/// the clear button on the right.
+fn reserve_icons(padding: Padding) -> Padding {
+    Padding {
+        right: padding.right + 2.0 * (ICON_SIZE + ICON_SPACING),
+        ..padding
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::time::{Duration, Instant};
+
+    use super::{Debounce, State};
+
+    #[test]
+    fn searches_once_the_text_settles() {
+        let delay = Duration::from_millis(300);
+        let start = Instant::now();
+        let mut state = State::new("");
+
+        assert_eq!(state.debounce("", start, delay), Debounce::Idle);
+
+        let typed = start + Duration::from_millis(100);
+        assert_eq!(
+            state.debounce("ru", typed, delay),
+            Debounce::Wait(typed + delay)
+        );
+
+        // Typing again starts the delay over
+        let typed_again = typed + Duration::from_millis(200);
+        assert_eq!(
+            state.debounce("rust", typed_again, delay),
+            Debounce::Wait(typed_again + delay)
+        );
+        assert_eq!(
+            state.debounce("rust", typed_again + delay, delay),
+            Debounce::Search
+        );
+        assert_eq!(
+            state.debounce("rust", typed_again + 2 * delay, delay),
+            Debounce::Idle
+        );
+    }
+
+    #[test]
+    fn skips_text_searched_right_away() {
+        let delay = Duration::from_millis(300);
+        let start = Instant::now();
+        let mut state = State::new("");
+
+        let _ = state.debounce("rust", start, delay);
+        state.searched(String::from("rust"));
+
+        assert_eq!(state.debounce("rust", start + delay, delay), Debounce::Idle);
+    }
+}
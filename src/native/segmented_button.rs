@@ -0,0 +1,639 @@
+//! Pick among options lined up in one connected pill.
+//!
+//! *This API requires the following crate features to be activated: segmented_button*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text,
+    time::{Duration, Instant},
+    touch,
+    widget::tree::{self, Tree},
+    window, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::core::animation::{Animation, Easing};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::segmented_button::{Appearance, StyleSheet};
+
+/// The default padding around the label of a segment.
+const DEFAULT_PADDING: f32 = 8.0;
+
+/// The gap between the indicator of a selected segment and the border.
+const INDICATOR_INSET: f32 = 2.0;
+
+/// The time it takes the indicator to move to another segment.
+const TRANSITION: Duration = Duration::from_millis(200);
+
+/// A segment of a [`SegmentedButton`](SegmentedButton).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segment {
+    /// The label of the segment.
+    label: String,
+    /// Whether the segment can not be selected.
+    disabled: bool,
+}
+
+impl Segment {
+    /// Creates a new [`Segment`](Segment) with the label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Marks the [`Segment`](Segment) as disabled, so that it can not be
+    /// selected.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The selected segments and the function producing the message when the
+/// user selects some.
+enum Selection<'a, Message> {
+    /// At most one segment is selected.
+    Single {
+        /// The selected segment.
+        selected: Option<usize>,
+        /// The function producing the message selecting the segment at an
+        /// index.
+        on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    },
+    /// Any number of segments is selected.
+    Multiple {
+        /// The selected segments.
+        selected: Vec<usize>,
+        /// The function producing the message with the updated selection.
+        on_change: Box<dyn Fn(Vec<usize>) -> Message + 'a>,
+    },
+}
+
+impl<'a, Message> Selection<'a, Message> {
+    /// Returns true if the segment at the index is selected.
+    fn is_selected(&self, index: usize) -> bool {
+        match self {
+            Selection::Single { selected, .. } => *selected == Some(index),
+            Selection::Multiple { selected, .. } => selected.contains(&index),
+        }
+    }
+
+    /// Returns the segment the indicator moves to, if the selection is
+    /// single.
+    fn indicated(&self) -> Option<usize> {
+        match self {
+            Selection::Single { selected, .. } => *selected,
+            Selection::Multiple { .. } => None,
+        }
+    }
+}
+
+/// Mutually exclusive options lined up in one connected pill, or toggles if
+/// any number of them may be selected.
+///
+/// With a single selection an indicator slides to the selected segment.
+/// Disabled segments can not be selected.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::segmented_button;
+/// #
+/// # pub type SegmentedButton<'a, Message> = segmented_button::SegmentedButton<'a, Message, Null>;
+/// use segmented_button::Segment;
+///
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Selected(usize),
+/// }
+///
+/// let segmented_button = SegmentedButton::new(
+///     vec![
+///         Segment::new("Day"),
+///         Segment::new("Week"),
+///         Segment::new("Year").disabled(true),
+///     ],
+///     Some(0),
+///     Message::Selected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SegmentedButton<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The segments.
+    segments: Vec<Segment>,
+    /// The selected segments.
+    selection: Selection<'a, Message>,
+    /// The width of the segmented button.
+    width: Length,
+    /// The padding around the label of a segment.
+    padding: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the segmented button.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> SegmentedButton<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SegmentedButton`](SegmentedButton) with at most one
+    /// selected segment.
+    ///
+    /// It expects:
+    ///     * the segments
+    ///     * the index of the selected segment, if any
+    ///     * the function producing the message selecting the segment at an
+    ///       index
+    pub fn new<F>(segments: Vec<Segment>, selected: Option<usize>, on_select: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self::with_selection(
+            segments,
+            Selection::Single {
+                selected,
+                on_select: Box::new(on_select),
+            },
+        )
+    }
+
+    /// Creates a new [`SegmentedButton`](SegmentedButton) with any number of
+    /// selected segments.
+    ///
+    /// It expects:
+    ///     * the segments
+    ///     * the indices of the selected segments
+    ///     * the function producing the message with the updated indices
+    pub fn multiple<F>(segments: Vec<Segment>, selected: &[usize], on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        Self::with_selection(
+            segments,
+            Selection::Multiple {
+                selected: selected.to_vec(),
+                on_change: Box::new(on_change),
+            },
+        )
+    }
+
+    /// Creates a new [`SegmentedButton`](SegmentedButton) with the selection.
+    fn with_selection(segments: Vec<Segment>, selection: Selection<'a, Message>) -> Self {
+        SegmentedButton {
+            segments,
+            selection,
+            width: Length::Shrink,
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`SegmentedButton`](SegmentedButton).
+    ///
+    /// The segments share the width equally.
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding around the label of a segment.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SegmentedButton`](SegmentedButton).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the index of the enabled segment at the cursor, if any.
+    fn segment_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        if self.segments.is_empty() || !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let segment_width = bounds.width / self.segments.len() as f32;
+        let index = ((cursor_position.x - bounds.x) / segment_width) as usize;
+
+        Some(index.min(self.segments.len() - 1)).filter(|&index| !self.segments[index].disabled)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SegmentedButton<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let label_width = self
+            .segments
+            .iter()
+            .map(|segment| renderer.measure_width(&segment.label, text_size, self.font))
+            .fold(0.0, f32::max);
+
+        let intrinsic = Size::new(
+            self.segments.len() as f32 * (label_width + 2.0 * self.padding),
+            text_size + 2.0 * self.padding,
+        );
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        Node::new(limits.resolve(intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                state.now = Some(now);
+                state.indicate(self.selection.indicated(), now);
+                state.animation.request_redraw(now, shell);
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = self.segment_at(layout.bounds(), cursor_position) else {
+                    return event::Status::Ignored;
+                };
+
+                match &self.selection {
+                    Selection::Single {
+                        selected,
+                        on_select,
+                    } => {
+                        if *selected != Some(index) {
+                            shell.publish(on_select(index));
+                        }
+                    }
+                    Selection::Multiple {
+                        selected,
+                        on_change,
+                    } => {
+                        let mut selected = selected.clone();
+                        match selected.iter().position(|&chosen| chosen == index) {
+                            Some(position) => {
+                                let _ = selected.remove(position);
+                            }
+                            None => selected.push(index),
+                        }
+                        selected.sort_unstable();
+
+                        shell.publish(on_change(selected));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.segment_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        if self.segments.is_empty() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let segment_width = bounds.width / self.segments.len() as f32;
+        let segment_bounds = |position: f32| Rectangle {
+            x: bounds.x + position * segment_width,
+            width: segment_width,
+            ..bounds
+        };
+
+        // The pill is rounded by at most half of its height and the indicator
+        // follows its curve
+        let radius = <[f32; 4]>::from(appearance.border_radius)
+            .map(|radius| radius.min(bounds.height / 2.0));
+        let inner_radius = radius.map(|radius| (radius - INDICATOR_INSET).max(0.0));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        if let Some(hovered) = self.segment_at(bounds, cursor_position) {
+            if !self.selection.is_selected(hovered) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: inset(segment_bounds(hovered as f32)),
+                        border_radius: inner_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.hovered_background,
+                );
+            }
+        }
+
+        // The indicator is drawn at the selection until the first redraw
+        let indicated = self.selection.indicated();
+        let position = if state.target == indicated {
+            state.position(state.now.unwrap_or_else(Instant::now))
+        } else {
+            indicated.map(|index| index as f32)
+        };
+
+        let filled: Vec<f32> = match &self.selection {
+            Selection::Single { .. } => position.into_iter().collect(),
+            Selection::Multiple { selected, .. } => {
+                selected.iter().map(|&index| index as f32).collect()
+            }
+        };
+
+        for &position in &filled {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: inset(segment_bounds(position)),
+                    border_radius: inner_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.selected_background,
+            );
+        }
+
+        // A divider is hidden while a filled segment touches it
+        for index in 1..self.segments.len() {
+            let edge = index as f32;
+            if filled
+                .iter()
+                .any(|&position| edge - 1.0 <= position && position <= edge)
+            {
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + edge * segment_width,
+                        y: bounds.y + INDICATOR_INSET,
+                        width: 1.0,
+                        height: (bounds.height - 2.0 * INDICATOR_INSET).max(0.0),
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.divider_color,
+            );
+        }
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            let color = if segment.disabled {
+                appearance.disabled_text_color
+            } else if self.selection.is_selected(index) {
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+            let label_bounds = segment_bounds(index as f32);
+
+            renderer.fill_text(text::Text {
+                content: &segment.label,
+                bounds: Rectangle {
+                    x: label_bounds.center_x(),
+                    y: label_bounds.center_y(),
+                    ..label_bounds
+                },
+                size: text_size,
+                color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<SegmentedButton<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(segmented_button: SegmentedButton<'a, Message, Renderer>) -> Self {
+        Element::new(segmented_button)
+    }
+}
+
+/// The state of a [`SegmentedButton`](SegmentedButton).
+#[derive(Debug)]
+struct State {
+    /// The segment the indicator moves to.
+    target: Option<usize>,
+    /// The position of the indicator, in segments, when it started moving.
+    from: f32,
+    /// The animation moving the indicator.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            target: None,
+            from: 0.0,
+            animation: Animation::new(TRANSITION).easing(Easing::EaseInOut),
+            now: None,
+        }
+    }
+}
+
+impl State {
+    /// Returns the position of the indicator, in segments, if a segment is
+    /// selected.
+    fn position(&self, now: Instant) -> Option<f32> {
+        self.target
+            .map(|target| self.animation.interpolate(self.from, target as f32, now))
+    }
+
+    /// Moves the indicator to the segment.
+    ///
+    /// The indicator appears at the first selected segment and then slides
+    /// from where it is to the next one.
+    fn indicate(&mut self, target: Option<usize>, now: Instant) {
+        if self.target == target {
+            return;
+        }
+
+        self.from = self
+            .position(now)
+            .filter(|_| target.is_some())
+            .or_else(|| target.map(|target| target as f32))
+            .unwrap_or_default();
+        self.target = target;
+        self.animation = Animation::new(TRANSITION).easing(Easing::EaseInOut);
+        self.animation.go(true, now);
+    }
+}
+
+/// Returns the bounds shrunk by the gap between the indicator and the border.
+fn inset(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + INDICATOR_INSET,
+        y: bounds.y + INDICATOR_INSET,
+        width: (bounds.width - 2.0 * INDICATOR_INSET).max(0.0),
+        height: (bounds.height - 2.0 * INDICATOR_INSET).max(0.0),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use iced_native::time::Instant;
+
+    use super::{State, TRANSITION};
+
+    #[test]
+    fn indicator_appears_at_the_first_selection() {
+        let now = Instant::now();
+        let mut state = State::default();
+
+        state.indicate(Some(2), now);
+
+        assert_eq!(state.position(now), Some(2.0));
+        assert_eq!(state.position(now + TRANSITION), Some(2.0));
+    }
+
+    #[test]
+    fn indicator_slides_from_where_it_is() {
+        let now = Instant::now();
+        let mut state = State::default();
+
+        state.indicate(Some(0), now);
+        state.indicate(Some(2), now);
+
+        assert_eq!(state.position(now), Some(0.0));
+        assert_eq!(state.position(now + TRANSITION), Some(2.0));
+
+        let halfway = now + TRANSITION / 2;
+        let position = state.position(halfway).expect("a segment is selected");
+        state.indicate(Some(0), halfway);
+
+        assert_eq!(state.position(halfway), Some(position));
+        assert_eq!(state.position(halfway + TRANSITION), Some(0.0));
+    }
+
+    #[test]
+    fn indicator_disappears_without_selection() {
+        let now = Instant::now();
+        let mut state = State::default();
+
+        state.indicate(Some(1), now);
+        state.indicate(None, now);
+
+        assert_eq!(state.position(now), None);
+    }
+}
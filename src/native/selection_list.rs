@@ -1,6 +1,7 @@
 //! Display a dropdown list of selectable values.
 pub mod list;
 use crate::style::selection_list::StyleSheet;
+use crate::style::style_state::{appearance, StyleState};
 
 use iced_native::{
     event,
@@ -9,10 +10,15 @@ use iced_native::{
 };
 
 use iced_native::widget::tree::Tree;
-use iced_native::widget::{Container, Scrollable};
+use iced_native::widget::{
+    operation::{self, scrollable::RelativeOffset},
+    Container, Id, Operation, Scrollable,
+};
 use iced_native::{Element, Widget};
 
 pub use list::List;
+
+use super::operation::{Action, Request};
 use std::borrow::Cow;
 use std::marker::PhantomData;
 
@@ -42,6 +48,8 @@ where
     text_size: f32,
     /// Style for Looks
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The id used to scroll options into view.
+    id: Option<Id>,
 }
 
 #[allow(clippy::type_repetition_in_bounds)]
@@ -83,6 +91,7 @@ where
             height: Length::Fill,
             padding: 5.0,
             text_size: 12.0,
+            id: None,
         }
     }
 
@@ -117,6 +126,7 @@ where
             height: Length::Fill,
             padding,
             text_size,
+            id: None,
         }
     }
 
@@ -134,6 +144,15 @@ where
         self
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`SelectionList`](SelectionList) to scroll its options into view with
+    /// [`reveal`](crate::native::operation::reveal).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`SelectionList`](SelectionList).
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
@@ -249,14 +268,22 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+
         renderer.fill_quad(
             renderer::Quad {
-                bounds: layout.bounds(),
-                border_color: theme.style(self.style).border_color,
-                border_width: theme.style(self.style).border_width,
+                bounds,
+                border_color: appearance.border_color,
+                border_width: appearance.border_width,
                 border_radius: (0.0).into(),
             },
-            theme.style(self.style).background,
+            appearance.background,
         );
 
         self.container.draw(
@@ -272,6 +299,67 @@ where
             &layout.bounds(),
         );
     }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let container_layout = layout
+            .children()
+            .next()
+            .expect("Scrollable Child Missing in Selection List");
+
+        if let Some(Action::Reveal(index)) = Request::receive(operation, self.id.as_ref()).action()
+        {
+            self.container.operate(
+                &mut state.children[0],
+                container_layout,
+                renderer,
+                &mut SnapTo(relative_offset(index, self.options.len())),
+            );
+        }
+
+        self.container.operate(
+            &mut state.children[0],
+            container_layout,
+            renderer,
+            operation,
+        );
+    }
+}
+
+/// Returns the offset scrolling the option at the index of a list with the
+/// given number of options into view.
+#[allow(clippy::cast_precision_loss)]
+fn relative_offset(index: usize, count: usize) -> RelativeOffset {
+    let y = if count > 1 {
+        index.min(count - 1) as f32 / (count - 1) as f32
+    } else {
+        0.0
+    };
+
+    RelativeOffset { x: 0.0, y }
+}
+
+/// The [`Operation`](Operation) snapping the scrollable of a
+/// [`SelectionList`](SelectionList) to an offset.
+struct SnapTo(RelativeOffset);
+
+impl<T> Operation<T> for SnapTo {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn operation::Scrollable, _id: Option<&Id>) {
+        state.snap_to(self.0);
+    }
 }
 
 impl<'a, T, Message, Renderer> From<SelectionList<'a, T, Message, Renderer>>
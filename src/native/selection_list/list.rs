@@ -1,5 +1,6 @@
 //! Build and show dropdown `ListMenus`.
 use crate::selection_list::StyleSheet;
+use crate::style::style_state::{appearance, StyleState};
 
 use iced_native::{
     alignment::{Horizontal, Vertical},
@@ -171,6 +172,12 @@ where
 
         let visible_options = &self.options[start..end.min(self.options.len())];
         let list_state = state.state.downcast_ref::<ListState>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(list_state.hovered_option.is_some(), false, false, false),
+            self.style,
+        );
 
         for (i, option) in visible_options.iter().enumerate() {
             let i = start + i;
@@ -193,19 +200,19 @@ where
                         border_color: Color::TRANSPARENT,
                     },
                     if is_selected {
-                        theme.style(self.style).selected_background
+                        appearance.selected_background
                     } else {
-                        theme.style(self.style).hovered_background
+                        appearance.hovered_background
                     },
                 );
             }
 
             let text_color = if is_selected {
-                theme.style(self.style).selected_text_color
+                appearance.selected_text_color
             } else if is_hovered {
-                theme.style(self.style).hovered_text_color
+                appearance.hovered_text_color
             } else {
-                theme.style(self.style).text_color
+                appearance.text_color
             };
 
             renderer.fill_text(iced_native::text::Text {
@@ -0,0 +1,393 @@
+//! Show a keyboard shortcut as a row of keycaps.
+//!
+//! *This API requires the following crate features to be activated: shortcut_hint*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    layout::{Limits, Node},
+    renderer, text,
+    widget::Tree,
+    Color, Element, Font, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::native::hotkey_input::{Hotkey, Platform};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::shortcut_hint::{Appearance, StyleSheet};
+
+/// The default space between the label and the sides of a keycap.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default space between the keycaps and the separators.
+const DEFAULT_SPACING: f32 = 3.0;
+
+/// The space between the label and the top and the bottom of a keycap,
+/// relative to the text size.
+const VERTICAL_PADDING: f32 = 0.2;
+
+/// The default text size relative to the default size of the renderer, so
+/// hints fit next to the labels of menu items.
+const DEFAULT_TEXT_SCALE: f32 = 0.85;
+
+/// A keyboard shortcut shown as a row of keycaps, like `⌘ ⇧ P` or
+/// `Ctrl + Shift + P`.
+///
+/// A [`Hotkey`](Hotkey) is shown with the symbols and the separators of the
+/// platform, while any other keys can be shown with
+/// [`from_keys`](ShortcutHint::from_keys). The hint does not react to input,
+/// so it can be placed next to the label of a menu item or inside a tooltip.
+///
+/// # Example
+/// ```
+/// # use iced_native::{keyboard::{KeyCode, Modifiers}, renderer::Null};
+/// # use iced_aw::native::{hotkey_input::Hotkey, shortcut_hint};
+/// #
+/// # pub type ShortcutHint = shortcut_hint::ShortcutHint<Null>;
+/// let command_palette = ShortcutHint::new(Hotkey::new(
+///     KeyCode::P,
+///     Modifiers::COMMAND | Modifiers::SHIFT,
+/// ));
+///
+/// let chord = ShortcutHint::from_keys(["Ctrl", "K", "S"]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ShortcutHint<Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The shortcut whose keys are shown in the conventions of the platform.
+    hotkey: Option<Hotkey>,
+    /// The labels of the keys shown without a shortcut.
+    keys: Vec<String>,
+    /// The platform whose conventions the shortcut is shown with.
+    platform: Platform,
+    /// The separator between the keycaps overriding the one of the platform.
+    separator: Option<String>,
+    /// The space between the label and the sides of a keycap.
+    padding: f32,
+    /// The space between the keycaps and the separators.
+    spacing: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the hint.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> ShortcutHint<Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ShortcutHint`](ShortcutHint) showing the
+    /// [`Hotkey`](Hotkey) in the conventions of the current platform.
+    #[must_use]
+    pub fn new(hotkey: Hotkey) -> Self {
+        Self::with(Some(hotkey), Vec::new())
+    }
+
+    /// Creates a new [`ShortcutHint`](ShortcutHint) showing a keycap for each
+    /// of the labels, like `["⌘", "Shift", "P"]`.
+    #[must_use]
+    pub fn from_keys<I>(keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self::with(None, keys.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a new [`ShortcutHint`](ShortcutHint) with the defaults.
+    fn with(hotkey: Option<Hotkey>, keys: Vec<String>) -> Self {
+        Self {
+            hotkey,
+            keys,
+            platform: Platform::current(),
+            separator: None,
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Platform`](Platform) whose conventions the shortcut is
+    /// shown with, instead of the current one.
+    #[must_use]
+    pub fn platform(mut self, platform: Platform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Sets the separator between the keycaps.
+    ///
+    /// By default macOS shows no separators and the other platforms show
+    /// plus signs. An empty separator shows the keycaps next to each other.
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Sets the space between the label and the sides of a keycap.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the space between the keycaps and the separators.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ShortcutHint`](ShortcutHint).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the labels of the keycaps.
+    fn labels(&self) -> Vec<String> {
+        self.hotkey
+            .map_or_else(|| self.keys.clone(), |hotkey| hotkey.keys(self.platform))
+    }
+
+    /// Returns the separator between the keycaps.
+    fn separator_label(&self) -> &str {
+        self.separator.as_deref().unwrap_or(match self.platform {
+            Platform::MacOs => "",
+            Platform::Windows | Platform::Linux => "+",
+        })
+    }
+
+    /// Returns the text size of the labels.
+    fn resolved_text_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size
+            .unwrap_or_else(|| (renderer.default_size() * DEFAULT_TEXT_SCALE).round())
+    }
+
+    /// Returns the widths of the keycaps and of the separator.
+    fn widths(&self, renderer: &Renderer, labels: &[String]) -> (Vec<f32>, f32) {
+        let text_size = self.resolved_text_size(renderer);
+        let height = keycap_height(text_size);
+
+        // Keycaps are at least square, so single characters look like keys
+        let keycaps = labels
+            .iter()
+            .map(|label| {
+                (renderer.measure_width(label, text_size, self.font) + 2.0 * self.padding)
+                    .max(height)
+            })
+            .collect();
+        let separator = match self.separator_label() {
+            "" => 0.0,
+            separator => renderer.measure_width(separator, text_size, self.font),
+        };
+
+        (keycaps, separator)
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for ShortcutHint<Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let labels = self.labels();
+        let (keycaps, separator) = self.widths(renderer, &labels);
+        let (_, width) = arrange(&keycaps, separator, self.spacing);
+        let height = keycap_height(self.resolved_text_size(renderer));
+
+        Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let labels = self.labels();
+        let (keycaps, separator) = self.widths(renderer, &labels);
+        let (positions, _) = arrange(&keycaps, separator, self.spacing);
+        let text_size = self.resolved_text_size(renderer);
+
+        // The edge below the keycaps is part of their height
+        let height = (bounds.height - appearance.depth).max(0.0);
+
+        for (index, (label, (x, width))) in labels
+            .iter()
+            .zip(positions.into_iter().zip(keycaps))
+            .enumerate()
+        {
+            let keycap = Rectangle {
+                x: bounds.x + x,
+                y: bounds.y,
+                width,
+                height,
+            };
+
+            if appearance.depth > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: keycap.y + appearance.depth,
+                            ..keycap
+                        },
+                        border_radius: appearance.border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.depth_color,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: keycap,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: keycap.center_x(),
+                    y: keycap.center_y(),
+                    ..keycap
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+
+            if separator > 0.0 && index + 1 < labels.len() {
+                renderer.fill_text(text::Text {
+                    content: self.separator_label(),
+                    bounds: Rectangle {
+                        x: keycap.x + width + self.spacing + separator / 2.0,
+                        y: keycap.center_y(),
+                        ..keycap
+                    },
+                    size: text_size,
+                    color: appearance.separator_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                });
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ShortcutHint<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(shortcut_hint: ShortcutHint<Renderer>) -> Self {
+        Element::new(shortcut_hint)
+    }
+}
+
+/// Returns the height of a keycap with the text size, including the edge
+/// below it.
+fn keycap_height(text_size: f32) -> f32 {
+    text_size * (1.0 + 2.0 * VERTICAL_PADDING)
+}
+
+/// Returns the offsets of the keycaps with the widths from the left and the
+/// total width of the row.
+///
+/// Keycaps are separated by the spacing, or by the separator with the spacing
+/// on both sides of it if it is wider than zero.
+fn arrange(keycaps: &[f32], separator: f32, spacing: f32) -> (Vec<f32>, f32) {
+    let gap = if separator > 0.0 {
+        2.0 * spacing + separator
+    } else {
+        spacing
+    };
+    let mut x = 0.0;
+
+    let offsets = keycaps
+        .iter()
+        .map(|width| {
+            let offset = x;
+            x += width + gap;
+            offset
+        })
+        .collect();
+
+    (offsets, (x - gap).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::arrange;
+
+    #[test]
+    fn keycaps_are_spaced_around_the_separators() {
+        let (offsets, width) = arrange(&[20.0, 30.0, 20.0], 8.0, 3.0);
+
+        assert_eq!(offsets.len(), 3);
+        assert!(offsets[0].abs() < f32::EPSILON);
+        assert!((offsets[1] - 34.0).abs() < f32::EPSILON);
+        assert!((offsets[2] - 78.0).abs() < f32::EPSILON);
+        assert!((width - 98.0).abs() < f32::EPSILON);
+
+        // Without a separator only the spacing is left between the keycaps
+        let (offsets, width) = arrange(&[20.0, 20.0], 0.0, 3.0);
+        assert!((offsets[1] - 23.0).abs() < f32::EPSILON);
+        assert!((width - 43.0).abs() < f32::EPSILON);
+
+        let (offsets, width) = arrange(&[], 8.0, 3.0);
+        assert!(offsets.is_empty());
+        assert!(width.abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,396 @@
+//! Show placeholder shapes while the content loads.
+//!
+//! *This API requires the following crate features to be activated: skeleton*
+use std::marker::PhantomData;
+
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    renderer,
+    time::{Duration, Instant},
+    widget::{
+        tree::{self, Tree},
+        Column, Row, Space,
+    },
+    window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{
+    core::{
+        animation::{Animation, Interpolate},
+        renderer::bands,
+    },
+    style::{
+        gradient::Direction,
+        style_state::{appearance, StyleState},
+    },
+};
+
+pub use crate::style::skeleton::{Appearance, StyleSheet};
+
+/// The default height of a line.
+const LINE_HEIGHT: f32 = 12.0;
+
+/// The default diameter of a circle.
+const CIRCLE_DIAMETER: f32 = 40.0;
+
+/// The default height of a rectangle.
+const RECTANGLE_HEIGHT: f32 = 80.0;
+
+/// The space between the lines of a [`paragraph`](paragraph).
+const LINE_SPACING: f32 = 8.0;
+
+/// The time the shimmer takes to run across a shape.
+const CYCLE: Duration = Duration::from_millis(1500);
+
+/// Half of the width of the shimmer, relative to the width of the shape.
+const SHIMMER_WIDTH: f32 = 0.3;
+
+/// The shape of a [`Skeleton`](Skeleton).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Shape {
+    /// A line standing in for a line of text.
+    #[default]
+    Line,
+    /// A circle standing in for an avatar or an icon.
+    Circle,
+    /// A rectangle standing in for an image or a card.
+    Rectangle,
+}
+
+/// A placeholder shape with a shimmer running across it, shown while the
+/// content loads.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::skeleton;
+/// #
+/// # pub type Skeleton = skeleton::Skeleton<Null>;
+/// use iced_native::Length;
+///
+/// let title = Skeleton::line().width(Length::Fixed(120.0));
+/// let avatar = Skeleton::circle(32.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The shape of the skeleton.
+    shape: Shape,
+    /// The width of the skeleton.
+    width: Length,
+    /// The height of the skeleton.
+    height: Length,
+    /// The style of the skeleton.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    #[allow(missing_docs)]
+    renderer: PhantomData<Renderer>,
+}
+
+impl<Renderer> Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Skeleton`](Skeleton) of the [`Shape`](Shape).
+    #[must_use]
+    pub fn new(shape: Shape) -> Self {
+        let (width, height) = match shape {
+            Shape::Line => (Length::Fill, Length::Fixed(LINE_HEIGHT)),
+            Shape::Circle => (
+                Length::Fixed(CIRCLE_DIAMETER),
+                Length::Fixed(CIRCLE_DIAMETER),
+            ),
+            Shape::Rectangle => (Length::Fill, Length::Fixed(RECTANGLE_HEIGHT)),
+        };
+
+        Self {
+            shape,
+            width,
+            height,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Skeleton`](Skeleton) line filling the width.
+    #[must_use]
+    pub fn line() -> Self {
+        Self::new(Shape::Line)
+    }
+
+    /// Creates a new [`Skeleton`](Skeleton) circle with the diameter.
+    #[must_use]
+    pub fn circle(diameter: f32) -> Self {
+        Self::new(Shape::Circle)
+            .width(Length::Fixed(diameter))
+            .height(Length::Fixed(diameter))
+    }
+
+    /// Creates a new [`Skeleton`](Skeleton) rectangle filling the width.
+    #[must_use]
+    pub fn rectangle() -> Self {
+        Self::new(Shape::Rectangle)
+    }
+
+    /// Sets the width of the [`Skeleton`](Skeleton).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Skeleton`](Skeleton).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Skeleton`](Skeleton).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(
+            limits
+                .width(self.width)
+                .height(self.height)
+                .resolve(Size::ZERO),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if !state.animation.is_running(now) {
+                state.animation.go(true, now);
+            }
+            state.animation.request_redraw(now, shell);
+            state.now = Some(now);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+        );
+        let t = state
+            .animation
+            .progress(state.now.unwrap_or_else(Instant::now));
+
+        let (bounds, border_radius) = match self.shape {
+            Shape::Circle => {
+                let diameter = bounds.width.min(bounds.height);
+                let circle = Rectangle {
+                    x: bounds.center_x() - diameter / 2.0,
+                    y: bounds.center_y() - diameter / 2.0,
+                    width: diameter,
+                    height: diameter,
+                };
+
+                (circle, (diameter / 2.0).into())
+            }
+            Shape::Line | Shape::Rectangle => (bounds, appearance.border_radius),
+        };
+
+        let color_at = move |offset| {
+            appearance
+                .background
+                .interpolate(appearance.highlight, shimmer(offset, t))
+        };
+
+        for (bounds, border_radius, color) in
+            bands(bounds, border_radius, Direction::Horizontal, color_at)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Skeleton<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(skeleton: Skeleton<Renderer>) -> Self {
+        Element::new(skeleton)
+    }
+}
+
+/// The state of a [`Skeleton`](Skeleton).
+#[derive(Debug)]
+struct State {
+    /// The animation running the shimmer across the shape.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            animation: Animation::new(CYCLE).repeat(true),
+            now: None,
+        }
+    }
+}
+
+/// Returns how strong the shimmer is at the offset across the shape, between
+/// 0.0 and 1.0, at the time of its cycle.
+///
+/// The shimmer enters the shape at the left edge and leaves it completely at
+/// the right edge before the next cycle begins.
+fn shimmer(offset: f32, t: f32) -> f32 {
+    let center = -SHIMMER_WIDTH + t * (1.0 + 2.0 * SHIMMER_WIDTH);
+
+    (1.0 - (offset - center).abs() / SHIMMER_WIDTH).max(0.0)
+}
+
+/// Returns a paragraph of [`Skeleton`](Skeleton) lines standing in for a
+/// text, with the last line shorter than the others.
+pub fn paragraph<'a, Message, Renderer>(lines: usize) -> Column<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let children = (0..lines)
+        .map(|line| {
+            if line + 1 == lines && lines > 1 {
+                Row::new()
+                    .push(Skeleton::line().width(Length::FillPortion(3)))
+                    .push(Space::with_width(Length::FillPortion(2)))
+                    .into()
+            } else {
+                Skeleton::line().into()
+            }
+        })
+        .collect();
+
+    Column::with_children(children).spacing(LINE_SPACING)
+}
+
+/// Returns a [`Skeleton`](Skeleton) circle next to a [`paragraph`](paragraph)
+/// of two lines, standing in for an item of a list with an avatar.
+pub fn list_item<'a, Message, Renderer>() -> Row<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    Row::new()
+        .push(Skeleton::circle(CIRCLE_DIAMETER))
+        .push(paragraph(2))
+        .spacing(2.0 * LINE_SPACING)
+        .align_items(iced_native::Alignment::Center)
+}
+
+/// Shows the skeleton while the data is loading and the view of the data once
+/// it is loaded.
+///
+/// The skeleton is only built while the data is missing.
+pub fn loaded<'a, T, Message, Renderer, V, S>(
+    data: Option<T>,
+    view: impl FnOnce(T) -> V,
+    skeleton: impl FnOnce() -> S,
+) -> Element<'a, Message, Renderer>
+where
+    V: Into<Element<'a, Message, Renderer>>,
+    S: Into<Element<'a, Message, Renderer>>,
+{
+    match data {
+        Some(data) => view(data).into(),
+        None => skeleton().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shimmer, SHIMMER_WIDTH};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn shimmer_runs_across_the_shape() {
+        assert_close(shimmer(0.0, 0.0), 0.0);
+        assert_close(shimmer(0.5, 0.5), 1.0);
+        assert_close(shimmer(1.0, 1.0), 0.0);
+        assert!(shimmer(0.25, 0.25) > shimmer(0.75, 0.25));
+    }
+
+    #[test]
+    fn shimmer_fades_towards_its_edges() {
+        assert_close(shimmer(0.5 + SHIMMER_WIDTH / 2.0, 0.5), 0.5);
+        assert_close(shimmer(0.5 + SHIMMER_WIDTH, 0.5), 0.0);
+    }
+}
@@ -3,14 +3,15 @@
 use iced_native::event::Status;
 use iced_native::layout::{Limits, Node};
 use iced_native::renderer::Style;
+use iced_native::time::{Duration, Instant};
 use iced_native::widget::tree::{State, Tag};
 use iced_native::widget::Tree;
 use iced_native::{renderer, Layout, Widget};
 use iced_native::{window, Clipboard, Color, Element, Event, Length, Point, Rectangle, Shell};
 use iced_native::{Size, Vector};
 use std::marker::PhantomData;
-use std::time::{Duration, Instant};
 
+use crate::core::animation::Animation;
 use crate::style::spinner::StyleSheet;
 
 /// A spinner widget, a circle spinning around the center of the widget.
@@ -82,8 +83,8 @@ where
 }
 
 struct SpinnerState {
-    last_update: Instant,
-    t: f32,
+    animation: Animation,
+    now: Instant,
 }
 
 fn is_visible(bounds: &Rectangle) -> bool {
@@ -158,7 +159,8 @@ where
         let state = state.state.downcast_ref::<SpinnerState>();
         let center = bounds.center();
         let distance_from_center = size - self.circle_radius;
-        let (y, x) = (state.t * std::f32::consts::PI * 2.0).sin_cos();
+        let t = state.animation.progress(state.now);
+        let (y, x) = (t * std::f32::consts::PI * 2.0).sin_cos();
         let position = Vector::new(
             center.x + x * distance_from_center - self.circle_radius,
             center.y + y * distance_from_center - self.circle_radius,
@@ -173,8 +175,8 @@ where
 
     fn state(&self) -> State {
         State::new(SpinnerState {
-            last_update: Instant::now(),
-            t: 0.0,
+            animation: Animation::new(self.rate).repeat(true),
+            now: Instant::now(),
         })
     }
 
@@ -188,30 +190,17 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> Status {
-        const FRAMES_PER_SECOND: u64 = 60;
-
         let bounds = layout.bounds();
 
         if let Event::Window(window::Event::RedrawRequested(now)) = event {
             if is_visible(&bounds) {
                 let state = state.state.downcast_mut::<SpinnerState>();
-                let duration = (now - state.last_update).as_secs_f32();
-                let increment = if self.rate == Duration::ZERO {
-                    0.0
-                } else {
-                    duration * 1.0 / self.rate.as_secs_f32()
-                };
-
-                state.t += increment;
 
-                if state.t > 1.0 {
-                    state.t -= 1.0;
+                if !state.animation.is_running(now) {
+                    state.animation.go(true, now);
                 }
-
-                shell.request_redraw(window::RedrawRequest::At(
-                    now + Duration::from_millis(1000 / FRAMES_PER_SECOND),
-                ));
-                state.last_update = now;
+                state.animation.request_redraw(now, shell);
+                state.now = now;
 
                 return Status::Captured;
             }
@@ -2,21 +2,38 @@
 //!
 //! *This API requires the following crate features to be activated: split*
 use iced_native::{
-    mouse, renderer, touch, Color, Event, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    keyboard, mouse, renderer, time::Instant, touch, Color, Event, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size,
 };
 use iced_native::{
     widget::{
+        operation::Focusable,
         tree::{self, Tag},
-        Container, Operation, Row, Tree,
+        Container, Id, Operation, Row, Tree,
     },
     Element, Widget,
 };
 
+use crate::core::{
+    breakpoint::Breakpoint,
+    gesture::{Gesture, Recognizer},
+};
+use crate::{
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
 pub use crate::style::split::{Appearance, StyleSheet};
 
+/// The distance the divider is moved by a press of an arrow key.
+const KEYBOARD_STEP: f32 = 10.0;
+
 /// A split can divide the available space by half to display two different elements.
 /// It can split horizontally or vertically.
 ///
+/// The divider is focused by clicking on it and can then be moved with the
+/// arrow keys. Double tapping the divider moves it back to the middle.
+///
 /// # Example
 /// ```
 /// # use iced_aw::split::{State, Axis};
@@ -62,10 +79,14 @@ where
     min_size_first: u16,
     /// The minimum size of the second element of the [`Split`](Split).
     min_size_second: u16,
+    /// If the first element slides over the second one like a drawer.
+    drawer: bool,
     /// The message that is send when the divider of the [`Split`](Split) is moved.
     on_resize: Box<dyn Fn(u16) -> Message>,
     /// The style of the [`Split`](Split).
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The id of the divider of the [`Split`](Split) used to focus it.
+    id: Option<Id>,
 }
 
 impl<'a, Message, Renderer> Split<'a, Message, Renderer>
@@ -111,11 +132,22 @@ where
             height: Length::Fill,
             min_size_first: 5,
             min_size_second: 5,
+            drawer: false,
             on_resize: Box::new(on_resize),
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            id: None,
         }
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the divider of the
+    /// [`Split`](Split) to focus it with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the padding of the [`Split`](Split) around the inner elements.
     #[must_use]
     pub fn padding(mut self, padding: f32) -> Self {
@@ -159,6 +191,18 @@ where
         self
     }
 
+    /// Adapts the [`Split`](Split) to the [`Breakpoint`](Breakpoint) of the
+    /// layout.
+    ///
+    /// On [`Compact`](Breakpoint::Compact) layouts, the first element becomes
+    /// a drawer sliding over the second one instead of shrinking it. The
+    /// drawer is closed as long as the divider has no position.
+    #[must_use]
+    pub fn breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.drawer = breakpoint == Breakpoint::Compact;
+        self
+    }
+
     /// Sets the style of the [`Split`](Split).
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
@@ -241,12 +285,52 @@ where
         let divider_layout = children
             .next()
             .expect("Native: Layout should have a divider layout");
+
+        // Double tapping the divider splits the space in half again
+        if let Some(Gesture::DoubleTap(_)) =
+            split_state
+                .gestures
+                .on_event(&event, divider_layout.bounds(), Instant::now())
+        {
+            let center = layout.bounds().center();
+            let position = match self.axis {
+                Axis::Horizontal => center.y,
+                Axis::Vertical => center.x,
+            };
+
+            split_state.dragging = false;
+            shell.publish((self.on_resize)(position as u16));
+            return iced_native::event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if divider_layout.bounds().contains(cursor_position) {
+                let is_pressed = divider_layout.bounds().contains(cursor_position);
+                if is_pressed {
                     split_state.dragging = true;
                 }
+                split_state.focus.press(is_pressed);
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if split_state.focus.is_focused() =>
+            {
+                let step = match (self.axis, key_code) {
+                    (Axis::Horizontal, keyboard::KeyCode::Up)
+                    | (Axis::Vertical, keyboard::KeyCode::Left) => -KEYBOARD_STEP,
+                    (Axis::Horizontal, keyboard::KeyCode::Down)
+                    | (Axis::Vertical, keyboard::KeyCode::Right) => KEYBOARD_STEP,
+                    _ => return first_status,
+                };
+                let center = divider_layout.bounds().center();
+                let position = match self.axis {
+                    Axis::Horizontal => center.y,
+                    Axis::Vertical => center.x,
+                };
+
+                shell.publish((self.on_resize)((position + step).max(0.0) as u16));
+                return iced_native::event::Status::Captured;
             }
 
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -271,6 +355,16 @@ where
             _ => {}
         }
 
+        // The second element doesn't get the pointer while it is covered by
+        // the drawer
+        if self.drawer
+            && matches!(event, Event::Mouse(_) | Event::Touch(_))
+            && (first_layout.bounds().contains(cursor_position)
+                || divider_layout.bounds().contains(cursor_position))
+        {
+            return first_status;
+        }
+
         let second_layout = children
             .next()
             .expect("Native: Layout should have a second layout");
@@ -320,13 +414,20 @@ where
         let second_layout = children
             .next()
             .expect("Graphics: Layout should have a second layout");
-        let second_mouse_interaction = self.second.as_widget().mouse_interaction(
-            &state.children[1],
-            second_layout,
-            cursor_position,
-            viewport,
-            renderer,
-        );
+        let is_covered = self.drawer
+            && (first_layout.bounds().contains(cursor_position)
+                || divider_layout.bounds().contains(cursor_position));
+        let second_mouse_interaction = if is_covered {
+            mouse::Interaction::default()
+        } else {
+            self.second.as_widget().mouse_interaction(
+                &state.children[1],
+                second_layout,
+                cursor_position,
+                viewport,
+                renderer,
+            )
+        };
         first_mouse_interaction
             .max(second_mouse_interaction)
             .max(divider_mouse_interaction)
@@ -363,75 +464,104 @@ where
         let first_layout = children
             .next()
             .expect("Graphics: Layout should have a first layout");
-
-        // First
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: first_layout.bounds(),
-                border_radius: (0.0).into(),
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-            if first_layout.bounds().contains(cursor_position) {
-                theme.hovered(self.style).first_background
-            } else {
-                theme.active(self.style).first_background
-            }
-            .unwrap_or_else(|| Color::TRANSPARENT.into()),
-        );
-
-        self.first.as_widget().draw(
-            &state.children[0],
-            renderer,
-            theme,
-            style,
-            first_layout,
-            cursor_position,
-            viewport,
-        );
-
         let divider_layout = children
             .next()
             .expect("Graphics: Layout should have a divider layout");
-
-        // Second
         let second_layout = children
             .next()
             .expect("Graphics: Layout should have a second layout");
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: second_layout.bounds(),
-                border_radius: (0.0).into(),
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-            if second_layout.bounds().contains(cursor_position) {
-                theme.hovered(self.style).second_background
-            } else {
-                theme.active(self.style).second_background
-            }
-            .unwrap_or_else(|| Color::TRANSPARENT.into()),
-        );
+        // First
+        let draw_first = |renderer: &mut Renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: first_layout.bounds(),
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance!(
+                    StyleSheet,
+                    theme,
+                    StyleState::new(
+                        first_layout.bounds().contains(cursor_position),
+                        false,
+                        false,
+                        false,
+                    ),
+                    self.style,
+                )
+                .first_background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+
+            self.first.as_widget().draw(
+                &state.children[0],
+                renderer,
+                theme,
+                style,
+                first_layout,
+                cursor_position,
+                viewport,
+            );
+        };
 
-        self.second.as_widget().draw(
-            &state.children[1],
-            renderer,
-            theme,
-            style,
-            second_layout,
-            cursor_position,
-            viewport,
-        );
+        // Second
+        let draw_second = |renderer: &mut Renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: second_layout.bounds(),
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance!(
+                    StyleSheet,
+                    theme,
+                    StyleState::new(
+                        second_layout.bounds().contains(cursor_position),
+                        false,
+                        false,
+                        false,
+                    ),
+                    self.style,
+                )
+                .second_background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+
+            self.second.as_widget().draw(
+                &state.children[1],
+                renderer,
+                theme,
+                style,
+                second_layout,
+                cursor_position,
+                viewport,
+            );
+        };
 
-        // Divider
-        let divider_style = if split_state.dragging {
-            theme.dragged(self.style)
-        } else if divider_layout.bounds().contains(cursor_position) {
-            theme.hovered(self.style)
+        // The drawer lies on top of the second element
+        if self.drawer {
+            draw_second(renderer);
+            draw_first(renderer);
         } else {
-            theme.active(self.style)
-        };
+            draw_first(renderer);
+            draw_second(renderer);
+        }
+
+        // Divider
+        let divider_style = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                divider_layout.bounds().contains(cursor_position),
+                split_state.dragging,
+                split_state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
 
         renderer.fill_quad(
             renderer::Quad {
@@ -456,6 +586,9 @@ where
         let _divider_layout = children.next().expect("Missing Split Divider");
         let second_layout = children.next().expect("Missing Split Second window");
 
+        let split_state: &mut State = state.state.downcast_mut();
+        operation.focusable(&mut split_state.focus, self.id.as_ref());
+
         let (first_state, second_state) = state.children.split_at_mut(1);
 
         self.first
@@ -525,22 +658,29 @@ where
         );
     }
 
+    // Positions stay in fractional logical pixels to not be misaligned when
+    // they are scaled to the display
     let divider_position = split
         .divider_position
-        .unwrap_or_else(|| (space.bounds().height / 2.0) as u16)
-        .max((split.spacing / 2.0) as u16);
-    let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
-        split.min_size_first,
-        space.bounds().height as u16 - split.min_size_second - split.spacing as u16,
+        .map_or(
+            if split.drawer {
+                0.0
+            } else {
+                space.bounds().height / 2.0
+            },
+            f32::from,
+        )
+        .max(split.spacing / 2.0)
+        - split.spacing / 2.0;
+    let divider_position = divider_position.clamp(
+        f32::from(split.min_size_first),
+        space.bounds().height - f32::from(split.min_size_second) - split.spacing,
     );
 
-    let padding = Padding::from(split.padding as u16);
+    let padding = Padding::from(split.padding);
     let first_limits = limits
         .clone()
-        .shrink(Size::new(
-            0.0,
-            space.bounds().height - f32::from(divider_position),
-        ))
+        .shrink(Size::new(0.0, space.bounds().height - divider_position))
         .pad(padding);
     let mut first = split.first.as_widget().layout(renderer, &first_limits);
     first.move_to(Point::new(
@@ -550,16 +690,22 @@ where
 
     let mut divider =
         iced_native::layout::Node::new(Size::new(space.bounds().width, split.spacing));
-    divider.move_to(Point::new(space.bounds().x, f32::from(divider_position)));
-
+    divider.move_to(Point::new(space.bounds().x, divider_position));
+
+    // A drawer slides over the second element instead of shrinking it
+    let second_offset = if split.drawer {
+        0.0
+    } else {
+        divider_position + split.spacing
+    };
     let second_limits = limits
         .clone()
-        .shrink(Size::new(0.0, f32::from(divider_position) + split.spacing))
+        .shrink(Size::new(0.0, second_offset))
         .pad(padding);
     let mut second = split.second.as_widget().layout(renderer, &second_limits);
     second.move_to(Point::new(
         space.bounds().x + split.padding,
-        space.bounds().y + f32::from(divider_position) + split.spacing + split.padding,
+        space.bounds().y + second_offset + split.padding,
     ));
 
     iced_native::layout::Node::with_children(space.bounds().size(), vec![first, divider, second])
@@ -597,20 +743,25 @@ where
 
     let divider_position = split
         .divider_position
-        .unwrap_or_else(|| (space.bounds().width / 2.0) as u16)
-        .max((split.spacing / 2.0) as u16);
-    let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
-        split.min_size_first,
-        space.bounds().width as u16 - split.min_size_second - split.spacing as u16,
+        .map_or(
+            if split.drawer {
+                0.0
+            } else {
+                space.bounds().width / 2.0
+            },
+            f32::from,
+        )
+        .max(split.spacing / 2.0)
+        - split.spacing / 2.0;
+    let divider_position = divider_position.clamp(
+        f32::from(split.min_size_first),
+        space.bounds().width - f32::from(split.min_size_second) - split.spacing,
     );
 
-    let padding = Padding::from(split.padding as u16);
+    let padding = Padding::from(split.padding);
     let first_limits = limits
         .clone()
-        .shrink(Size::new(
-            space.bounds().width - f32::from(divider_position),
-            0.0,
-        ))
+        .shrink(Size::new(space.bounds().width - divider_position, 0.0))
         .pad(padding);
     let mut first = split.first.as_widget().layout(renderer, &first_limits);
     first.move_to(Point::new(
@@ -620,15 +771,20 @@ where
 
     let mut divider =
         iced_native::layout::Node::new(Size::new(split.spacing, space.bounds().height));
-    divider.move_to(Point::new(f32::from(divider_position), space.bounds().y));
+    divider.move_to(Point::new(divider_position, space.bounds().y));
 
+    let second_offset = if split.drawer {
+        0.0
+    } else {
+        divider_position + split.spacing
+    };
     let second_limits = limits
         .clone()
-        .shrink(Size::new(f32::from(divider_position) + split.spacing, 0.0))
+        .shrink(Size::new(second_offset, 0.0))
         .pad(padding);
     let mut second = split.second.as_widget().layout(renderer, &second_limits);
     second.move_to(Point::new(
-        space.bounds().x + f32::from(divider_position) + split.spacing + split.padding,
+        space.bounds().x + second_offset + split.padding,
         space.bounds().y + split.padding,
     ));
 
@@ -647,26 +803,34 @@ where
 }
 
 /// The state of a [`Split`](Split).
+///
+/// The [`Split`](Split) keeps it in the widget tree, so it doesn't need to be
+/// held by the application.
 #[derive(Clone, Debug, Default)]
 pub struct State {
     /// If the divider is dragged by the user.
     dragging: bool,
+    /// The keyboard focus of the divider.
+    focus: Focus,
+    /// The recognizer of the double taps on the divider.
+    gestures: Recognizer,
 }
 
 impl State {
     /// Creates a new [`State`](State) for a [`Split`](Split).
-    ///
-    /// It expects:
-    ///     - The optional position of the divider. If none, the available space will be split in half.
-    ///     - The [`Axis`](Axis) to split at.
     #[must_use]
     pub const fn new() -> Self {
-        Self { dragging: false }
+        Self {
+            dragging: false,
+            focus: Focus::new(),
+            gestures: Recognizer::new(),
+        }
     }
 }
 
 /// The axis to split at.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Axis {
     /// Split horizontally.
     Horizontal,
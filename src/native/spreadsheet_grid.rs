@@ -0,0 +1,1529 @@
+//! Edit a grid of text cells like a spreadsheet.
+//!
+//! *This API requires the following crate features to be activated: spreadsheet_grid*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, text,
+    time::Instant,
+    touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tree},
+        Id, Operation,
+    },
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use crate::{
+    core::gesture::DOUBLE_TAP,
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::spreadsheet_grid::{Appearance, StyleSheet};
+
+/// The default width of a column.
+const DEFAULT_COLUMN_WIDTH: f32 = 100.0;
+
+/// The default height of a [`SpreadsheetGrid`](SpreadsheetGrid).
+const DEFAULT_HEIGHT: f32 = 300.0;
+
+/// The horizontal padding around the text of a cell.
+const CELL_PADDING: f32 = 6.0;
+
+/// The height of a row relative to the text size.
+const ROW_HEIGHT: f32 = 1.6;
+
+/// The width of the border around the active cell.
+const ACTIVE_BORDER_WIDTH: f32 = 2.0;
+
+/// The width of the text cursor.
+const CURSOR_WIDTH: f32 = 1.0;
+
+/// The position of a cell in a [`SpreadsheetGrid`](SpreadsheetGrid).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cell {
+    /// The index of the row.
+    pub row: usize,
+    /// The index of the column.
+    pub column: usize,
+}
+
+impl Cell {
+    /// Creates a new [`Cell`](Cell) at the row and the column.
+    #[must_use]
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// Returns the cell moved by the number of rows and columns, staying
+    /// within the given number of rows and columns.
+    fn step(self, rows: isize, columns: isize, size: (usize, usize)) -> Self {
+        let step = |index: usize, by: isize, count: usize| {
+            index.saturating_add_signed(by).min(count.saturating_sub(1))
+        };
+
+        Self {
+            row: step(self.row, rows, size.0),
+            column: step(self.column, columns, size.1),
+        }
+    }
+}
+
+/// A rectangular range of cells from the cell the selection started at to
+/// the active cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Selection {
+    /// The cell the selection started at.
+    pub anchor: Cell,
+    /// The active cell, which is edited.
+    pub head: Cell,
+}
+
+impl Selection {
+    /// Creates a new [`Selection`](Selection) of the single cell.
+    #[must_use]
+    pub const fn single(cell: Cell) -> Self {
+        Self {
+            anchor: cell,
+            head: cell,
+        }
+    }
+
+    /// Returns the top left and the bottom right cell of the
+    /// [`Selection`](Selection).
+    #[must_use]
+    pub fn bounds(&self) -> (Cell, Cell) {
+        (
+            Cell::new(
+                self.anchor.row.min(self.head.row),
+                self.anchor.column.min(self.head.column),
+            ),
+            Cell::new(
+                self.anchor.row.max(self.head.row),
+                self.anchor.column.max(self.head.column),
+            ),
+        )
+    }
+
+    /// Returns true if the cell is selected.
+    #[must_use]
+    pub fn contains(&self, cell: Cell) -> bool {
+        let (start, end) = self.bounds();
+
+        (start.row..=end.row).contains(&cell.row)
+            && (start.column..=end.column).contains(&cell.column)
+    }
+
+    /// Returns true if only one cell is selected.
+    #[must_use]
+    pub fn is_single(&self) -> bool {
+        self.anchor == self.head
+    }
+
+    /// Moves the active cell, keeping the anchor if `extend` is true.
+    const fn moved(self, cell: Cell, extend: bool) -> Self {
+        if extend {
+            Self {
+                anchor: self.anchor,
+                head: cell,
+            }
+        } else {
+            Self::single(cell)
+        }
+    }
+
+    /// Returns the [`Selection`](Selection) within the given number of rows
+    /// and columns.
+    fn clamped(self, size: (usize, usize)) -> Self {
+        Self {
+            anchor: self.anchor.step(0, 0, size),
+            head: self.head.step(0, 0, size),
+        }
+    }
+}
+
+/// A grid of text cells edited like a spreadsheet, with a frozen header row
+/// naming the columns and a frozen header column numbering the rows.
+///
+/// Cells are selected by clicking on them and ranges by dragging over them
+/// or by moving with Shift held. The arrow keys move the active cell, Tab
+/// and Enter move it to the right and down. Typing or pressing F2 edits the
+/// active cell in place, Enter and Tab commit the edit and Escape cancels
+/// it. Double clicking a cell edits it as well.
+///
+/// Ctrl+C copies the selected range as tab-separated values, Ctrl+V pastes
+/// them starting at the selected range and Delete clears it. A single
+/// pasted value fills the whole selected range.
+///
+/// The texts are owned by the application, which receives a message for each
+/// edited cell. The grid is read-only without an
+/// [`on_edit`](SpreadsheetGrid::on_edit) function.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::spreadsheet_grid;
+/// #
+/// # pub type SpreadsheetGrid<'a, Message> =
+/// #   spreadsheet_grid::SpreadsheetGrid<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Edited(spreadsheet_grid::Cell, String),
+/// }
+///
+/// let cells = vec![
+///     vec![String::from("Item"), String::from("Price")],
+///     vec![String::from("Coffee"), String::from("3.50")],
+/// ];
+///
+/// let grid = SpreadsheetGrid::new(&cells)
+///     .rows(20)
+///     .columns(6)
+///     .on_edit(Message::Edited);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SpreadsheetGrid<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`SpreadsheetGrid`](SpreadsheetGrid) to focus it.
+    id: Option<Id>,
+    /// The texts of the cells, row by row.
+    cells: &'a [Vec<String>],
+    /// The minimal number of rows shown.
+    rows: usize,
+    /// The minimal number of columns shown.
+    columns: usize,
+    /// The function producing the message with the new text of a cell, if
+    /// editable.
+    on_edit: Option<Box<dyn Fn(Cell, String) -> Message + 'a>>,
+    /// The function producing the message of a change of the selection.
+    on_select: Option<Box<dyn Fn(Selection) -> Message + 'a>>,
+    /// The width of a column.
+    column_width: f32,
+    /// The width of the [`SpreadsheetGrid`](SpreadsheetGrid).
+    width: Length,
+    /// The height of the [`SpreadsheetGrid`](SpreadsheetGrid).
+    height: Length,
+    /// The text size of the cells and the headers.
+    text_size: Option<f32>,
+    /// The font of the cells and the headers.
+    font: Font,
+    /// The style of the [`SpreadsheetGrid`](SpreadsheetGrid).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> SpreadsheetGrid<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SpreadsheetGrid`](SpreadsheetGrid) showing the texts of
+    /// the cells, row by row.
+    #[must_use]
+    pub fn new(cells: &'a [Vec<String>]) -> Self {
+        Self {
+            id: None,
+            cells,
+            rows: 0,
+            columns: 0,
+            on_edit: None,
+            on_select: None,
+            column_width: DEFAULT_COLUMN_WIDTH,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`SpreadsheetGrid`](SpreadsheetGrid) to focus it.
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the minimal number of rows shown, leaving the rows without text
+    /// empty.
+    #[must_use]
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the minimal number of columns shown, leaving the columns without
+    /// text empty.
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the function that will be called with a cell and its new text
+    /// when it is edited, pasted into or cleared.
+    ///
+    /// The cells can only be edited if it is set.
+    #[must_use]
+    pub fn on_edit<F>(mut self, on_edit: F) -> Self
+    where
+        F: 'a + Fn(Cell, String) -> Message,
+    {
+        self.on_edit = Some(Box::new(on_edit));
+        self
+    }
+
+    /// Sets the function that will be called with the new
+    /// [`Selection`](Selection) when it changes.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(Selection) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of a column.
+    #[must_use]
+    pub fn column_width(mut self, width: f32) -> Self {
+        self.column_width = width;
+        self
+    }
+
+    /// Sets the width of the [`SpreadsheetGrid`](SpreadsheetGrid).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SpreadsheetGrid`](SpreadsheetGrid).
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the cells and the headers.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the cells and the headers.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SpreadsheetGrid`](SpreadsheetGrid).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the number of rows and columns shown.
+    fn size(&self) -> (usize, usize) {
+        let columns = self.cells.iter().map(Vec::len).max().unwrap_or(0);
+
+        (
+            self.rows.max(self.cells.len()).max(1),
+            self.columns.max(columns).max(1),
+        )
+    }
+
+    /// Returns the text of the cell.
+    fn text(&self, cell: Cell) -> &str {
+        self.cells
+            .get(cell.row)
+            .and_then(|row| row.get(cell.column))
+            .map_or("", String::as_str)
+    }
+
+    /// Returns the areas of the [`SpreadsheetGrid`](SpreadsheetGrid) within
+    /// the bounds.
+    fn metrics(&self, bounds: Rectangle, renderer: &Renderer) -> Metrics {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let size = self.size();
+        let row_height = (text_size * ROW_HEIGHT).round();
+        let header_width = renderer
+            .measure_width(&size.0.to_string(), text_size, self.font)
+            .max(text_size)
+            + 2.0 * CELL_PADDING;
+
+        Metrics {
+            text_size,
+            size,
+            row_height,
+            column_width: self.column_width,
+            header_width,
+            cells: Rectangle {
+                x: bounds.x + header_width,
+                y: bounds.y + row_height,
+                width: (bounds.width - header_width).max(0.0),
+                height: (bounds.height - row_height).max(0.0),
+            },
+        }
+    }
+
+    /// Changes the selection, producing a message if it changed.
+    fn select(&self, state: &mut State, shell: &mut Shell<'_, Message>, selection: Selection) {
+        if state.selection == selection {
+            return;
+        }
+
+        state.selection = selection;
+
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(selection));
+        }
+    }
+
+    /// Starts editing the active cell with the text and the cursor at its
+    /// end, if the cells are editable.
+    fn start_edit(&self, state: &mut State, text: String) {
+        if self.on_edit.is_none() {
+            return;
+        }
+
+        state.edit = Some(Edit {
+            cell: state.selection.head,
+            cursor: text.chars().count(),
+            text,
+        });
+    }
+
+    /// Ends editing, producing a message with the edited text if it
+    /// changed.
+    fn commit_edit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let Some(edit) = state.edit.take() else {
+            return;
+        };
+
+        if let Some(on_edit) = &self.on_edit {
+            if edit.text != self.text(edit.cell) {
+                shell.publish(on_edit(edit.cell, edit.text));
+            }
+        }
+    }
+
+    /// Produces a message setting the text of each of the cells, if the
+    /// cells are editable.
+    fn set_texts(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        texts: impl IntoIterator<Item = (Cell, String)>,
+    ) {
+        let Some(on_edit) = &self.on_edit else {
+            return;
+        };
+
+        for (cell, text) in texts {
+            if text != self.text(cell) {
+                shell.publish(on_edit(cell, text));
+            }
+        }
+    }
+
+    /// Returns the texts of the selected cells as tab-separated values.
+    fn copy(&self, selection: Selection) -> String {
+        let (start, end) = selection.bounds();
+
+        to_tsv((start.row..=end.row).map(|row| {
+            (start.column..=end.column).map(move |column| self.text(Cell::new(row, column)))
+        }))
+    }
+
+    /// Pastes the tab-separated values starting at the selected cells and
+    /// selects the pasted cells.
+    ///
+    /// A single value fills all selected cells.
+    fn paste(
+        &self,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+        size: (usize, usize),
+        pasted: &str,
+    ) {
+        let values = parse_tsv(pasted);
+        let (start, end) = state.selection.bounds();
+
+        if let [row] = values.as_slice() {
+            if let [value] = row.as_slice() {
+                self.set_texts(
+                    shell,
+                    (start.row..=end.row).flat_map(|row| {
+                        (start.column..=end.column)
+                            .map(move |column| (Cell::new(row, column), value.clone()))
+                    }),
+                );
+                return;
+            }
+        }
+
+        // The values beyond the last row or column are dropped
+        let rows = values.len().min(size.0 - start.row);
+        let columns = values
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .min(size.1 - start.column);
+
+        if rows == 0 || columns == 0 {
+            return;
+        }
+
+        self.set_texts(
+            shell,
+            values
+                .into_iter()
+                .take(rows)
+                .enumerate()
+                .flat_map(|(row, values)| {
+                    values
+                        .into_iter()
+                        .take(columns)
+                        .enumerate()
+                        .map(move |(column, value)| {
+                            (Cell::new(start.row + row, start.column + column), value)
+                        })
+                }),
+        );
+        self.select(
+            state,
+            shell,
+            Selection {
+                anchor: start,
+                head: Cell::new(start.row + rows - 1, start.column + columns - 1),
+            },
+        );
+    }
+
+    /// Handles a key pressed while a cell is edited.
+    ///
+    /// Returns true if the key was handled.
+    fn edit_key_pressed(
+        &self,
+        state: &mut State,
+        key_code: keyboard::KeyCode,
+        metrics: &Metrics,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let modifiers = state.modifiers;
+        let Some(edit) = state.edit.as_mut() else {
+            return false;
+        };
+        let length = edit.text.chars().count();
+
+        let step = match key_code {
+            keyboard::KeyCode::Left => {
+                edit.cursor = edit.cursor.saturating_sub(1);
+                return true;
+            }
+            keyboard::KeyCode::Right => {
+                edit.cursor = (edit.cursor + 1).min(length);
+                return true;
+            }
+            keyboard::KeyCode::Home => {
+                edit.cursor = 0;
+                return true;
+            }
+            keyboard::KeyCode::End => {
+                edit.cursor = length;
+                return true;
+            }
+            keyboard::KeyCode::Backspace => {
+                if edit.cursor > 0 {
+                    edit.cursor -= 1;
+                    let offset = byte_offset(&edit.text, edit.cursor);
+                    let _ = edit.text.remove(offset);
+                }
+                return true;
+            }
+            keyboard::KeyCode::Delete => {
+                if edit.cursor < length {
+                    let offset = byte_offset(&edit.text, edit.cursor);
+                    let _ = edit.text.remove(offset);
+                }
+                return true;
+            }
+            keyboard::KeyCode::V if modifiers.command() => {
+                if let Some(pasted) = clipboard.read() {
+                    // Only the first value fits into a cell
+                    let pasted = pasted.split(['\t', '\r', '\n']).next().unwrap_or_default();
+                    edit.text
+                        .insert_str(byte_offset(&edit.text, edit.cursor), pasted);
+                    edit.cursor += pasted.chars().count();
+                }
+                return true;
+            }
+            keyboard::KeyCode::Escape => {
+                state.edit = None;
+                return true;
+            }
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter if modifiers.shift() => {
+                (-1, 0)
+            }
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter | keyboard::KeyCode::Down => {
+                (1, 0)
+            }
+            keyboard::KeyCode::Up => (-1, 0),
+            keyboard::KeyCode::Tab if modifiers.shift() => (0, -1),
+            keyboard::KeyCode::Tab => (0, 1),
+            _ => return false,
+        };
+
+        self.commit_edit(state, shell);
+        let cell = state.selection.head.step(step.0, step.1, metrics.size);
+        self.select(state, shell, Selection::single(cell));
+
+        true
+    }
+
+    /// Handles a key pressed while the [`SpreadsheetGrid`](SpreadsheetGrid)
+    /// is focused and no cell is edited.
+    ///
+    /// Returns true if the key was handled.
+    fn key_pressed(
+        &self,
+        state: &mut State,
+        key_code: keyboard::KeyCode,
+        metrics: &Metrics,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let modifiers = state.modifiers;
+        let size = metrics.size;
+        let selection = state.selection;
+        let head = selection.head;
+        let page = ((metrics.cells.height / metrics.row_height) as isize).max(1);
+
+        let moved = |cell: Cell| Some(selection.moved(cell, modifiers.shift()));
+        let selection = match key_code {
+            keyboard::KeyCode::Left => moved(head.step(0, -1, size)),
+            keyboard::KeyCode::Right => moved(head.step(0, 1, size)),
+            keyboard::KeyCode::Up => moved(head.step(-1, 0, size)),
+            keyboard::KeyCode::Down => moved(head.step(1, 0, size)),
+            keyboard::KeyCode::PageUp => moved(head.step(-page, 0, size)),
+            keyboard::KeyCode::PageDown => moved(head.step(page, 0, size)),
+            keyboard::KeyCode::Home if modifiers.command() => moved(Cell::default()),
+            keyboard::KeyCode::Home => moved(Cell::new(head.row, 0)),
+            keyboard::KeyCode::End if modifiers.command() => {
+                moved(Cell::new(size.0 - 1, size.1 - 1))
+            }
+            keyboard::KeyCode::End => moved(Cell::new(head.row, size.1 - 1)),
+            keyboard::KeyCode::Tab if modifiers.shift() => {
+                Some(Selection::single(head.step(0, -1, size)))
+            }
+            keyboard::KeyCode::Tab => Some(Selection::single(head.step(0, 1, size))),
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter if modifiers.shift() => {
+                Some(Selection::single(head.step(-1, 0, size)))
+            }
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                Some(Selection::single(head.step(1, 0, size)))
+            }
+            keyboard::KeyCode::A if modifiers.command() => Some(Selection {
+                anchor: Cell::default(),
+                head: Cell::new(size.0 - 1, size.1 - 1),
+            }),
+            keyboard::KeyCode::C if modifiers.command() => {
+                clipboard.write(self.copy(selection));
+                None
+            }
+            keyboard::KeyCode::X if modifiers.command() && self.on_edit.is_some() => {
+                clipboard.write(self.copy(selection));
+                self.clear(shell, selection);
+                None
+            }
+            keyboard::KeyCode::V if modifiers.command() => {
+                if let Some(pasted) = clipboard.read() {
+                    self.paste(state, shell, size, &pasted);
+                }
+                None
+            }
+            keyboard::KeyCode::Delete | keyboard::KeyCode::Backspace => {
+                self.clear(shell, selection);
+                None
+            }
+            keyboard::KeyCode::F2 => {
+                self.start_edit(state, self.text(head).to_owned());
+                None
+            }
+            keyboard::KeyCode::Escape if !selection.is_single() => Some(Selection::single(head)),
+            _ => return false,
+        };
+
+        if let Some(selection) = selection {
+            self.select(state, shell, selection);
+        }
+
+        true
+    }
+
+    /// Returns the text of a cell or a header, aligned within its bounds.
+    fn cell_text<'b>(
+        &self,
+        metrics: &Metrics,
+        content: &'b str,
+        bounds: Rectangle,
+        color: Color,
+        align: Horizontal,
+    ) -> text::Text<'b, Font> {
+        text::Text {
+            content,
+            bounds: Rectangle {
+                x: match align {
+                    Horizontal::Left => bounds.x + CELL_PADDING,
+                    Horizontal::Center => bounds.center_x(),
+                    Horizontal::Right => bounds.x + bounds.width - CELL_PADDING,
+                },
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: metrics.text_size,
+            color,
+            font: self.font,
+            horizontal_alignment: align,
+            vertical_alignment: Vertical::Center,
+        }
+    }
+
+    /// Produces a message clearing each of the selected cells.
+    fn clear(&self, shell: &mut Shell<'_, Message>, selection: Selection) {
+        let (start, end) = selection.bounds();
+
+        self.set_texts(
+            shell,
+            (start.row..=end.row).flat_map(|row| {
+                (start.column..=end.column)
+                    .map(move |column| (Cell::new(row, column), String::new()))
+            }),
+        );
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SpreadsheetGrid<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(bounds, renderer);
+
+        // The cells may have been removed since the last event
+        state.selection = state.selection.clamped(metrics.size);
+        state.scroll = metrics.clamp_scroll(state.scroll);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let pressed = bounds.contains(cursor_position);
+
+                if !pressed {
+                    self.commit_edit(state, shell);
+                    state.focus.press(false);
+                    return event::Status::Ignored;
+                }
+
+                state.focus.press(true);
+
+                let Some(target) = metrics.target_at(cursor_position, state.scroll) else {
+                    return event::Status::Captured;
+                };
+
+                if state
+                    .edit
+                    .as_ref()
+                    .map_or(false, |edit| Target::Cell(edit.cell) != target)
+                {
+                    self.commit_edit(state, shell);
+                } else if state.edit.is_some() {
+                    return event::Status::Captured;
+                }
+
+                let now = Instant::now();
+                let extend = state.modifiers.shift();
+                let selection = match target {
+                    Target::Cell(cell) => state.selection.moved(cell, extend),
+                    Target::Row(row) => Selection {
+                        anchor: Cell::new(
+                            if extend {
+                                state.selection.anchor.row
+                            } else {
+                                row
+                            },
+                            0,
+                        ),
+                        head: Cell::new(row, metrics.size.1 - 1),
+                    },
+                    Target::Column(column) => Selection {
+                        anchor: Cell::new(
+                            0,
+                            if extend {
+                                state.selection.anchor.column
+                            } else {
+                                column
+                            },
+                        ),
+                        head: Cell::new(metrics.size.0 - 1, column),
+                    },
+                    Target::All => Selection {
+                        anchor: Cell::default(),
+                        head: Cell::new(metrics.size.0 - 1, metrics.size.1 - 1),
+                    },
+                };
+                self.select(state, shell, selection);
+
+                if let Target::Cell(cell) = target {
+                    let is_double_click = state.last_click.map_or(false, |(clicked, at)| {
+                        clicked == cell && now.duration_since(at) <= DOUBLE_TAP
+                    });
+
+                    if is_double_click {
+                        state.last_click = None;
+                        self.start_edit(state, self.text(cell).to_owned());
+                    } else {
+                        state.last_click = Some((cell, now));
+                        state.drag = Some(target);
+                    }
+                } else {
+                    state.last_click = None;
+                    state.drag = Some(target);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let cell = metrics.clamped_cell_at(position, state.scroll);
+                let selection = match drag {
+                    Target::Cell(_) => state.selection.moved(cell, true),
+                    Target::Row(_) => Selection {
+                        head: Cell::new(cell.row, metrics.size.1 - 1),
+                        ..state.selection
+                    },
+                    Target::Column(_) => Selection {
+                        head: Cell::new(metrics.size.0 - 1, cell.column),
+                        ..state.selection
+                    },
+                    Target::All => return event::Status::Captured,
+                };
+                self.select(state, shell, selection);
+                state.scroll = metrics.reveal(cell, state.scroll);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        Vector::new(x * metrics.column_width, y * 3.0 * metrics.row_height)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+                let delta = if state.modifiers.shift() {
+                    Vector::new(delta.y, delta.x)
+                } else {
+                    delta
+                };
+
+                state.scroll = metrics.clamp_scroll(state.scroll - delta);
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+
+            Event::Keyboard(keyboard::Event::CharacterReceived(character))
+                if state.focus.is_focused()
+                    && !state.modifiers.command()
+                    && !character.is_control() =>
+            {
+                match state.edit.as_mut() {
+                    Some(edit) => {
+                        edit.text
+                            .insert(byte_offset(&edit.text, edit.cursor), character);
+                        edit.cursor += 1;
+                    }
+                    // Typing replaces the text of the active cell
+                    None => self.start_edit(state, character.to_string()),
+                }
+                state.scroll = metrics.reveal(state.selection.head, state.scroll);
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.focus.is_focused() =>
+            {
+                let handled = if state.edit.is_some() {
+                    self.edit_key_pressed(state, key_code, &metrics, clipboard, shell)
+                } else {
+                    self.key_pressed(state, key_code, &metrics, clipboard, shell)
+                };
+
+                if !handled {
+                    return event::Status::Ignored;
+                }
+                state.scroll = metrics.reveal(state.selection.head, state.scroll);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let metrics = self.metrics(layout.bounds(), renderer);
+
+        match metrics.target_at(cursor_position, state.scroll) {
+            Some(Target::Cell(cell))
+                if state.edit.as_ref().map_or(false, |edit| edit.cell == cell) =>
+            {
+                mouse::Interaction::Text
+            }
+            Some(Target::Cell(_)) => mouse::Interaction::Crosshair,
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(bounds, renderer);
+        let scroll = metrics.clamp_scroll(state.scroll);
+        let selection = state.selection.clamped(metrics.size);
+        let (start, end) = selection.bounds();
+        let is_focused = state.focus.is_focused();
+
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, is_focused, false),
+            self.style,
+        );
+
+        let quad = |bounds: Rectangle| renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let (rows, columns) = metrics.visible(scroll);
+        let cells = metrics.cells;
+        renderer.with_layer(cells, |renderer| {
+            for row in rows.clone() {
+                for column in columns.clone() {
+                    let cell = Cell::new(row, column);
+                    let cell_bounds = metrics.cell_bounds(cell, scroll);
+                    let content = self.text(cell);
+
+                    if content.is_empty() {
+                        continue;
+                    }
+
+                    // Texts wider than their cell are cut off at its edges
+                    if renderer.measure_width(content, metrics.text_size, self.font)
+                        > cell_bounds.width - 2.0 * CELL_PADDING
+                    {
+                        if let Some(clip) = cell_bounds.intersection(&cells) {
+                            renderer.with_layer(clip, |renderer| {
+                                renderer.fill_text(self.cell_text(
+                                    &metrics,
+                                    content,
+                                    cell_bounds,
+                                    appearance.text_color,
+                                    Horizontal::Left,
+                                ));
+                            });
+                        }
+                    } else {
+                        renderer.fill_text(self.cell_text(
+                            &metrics,
+                            content,
+                            cell_bounds,
+                            appearance.text_color,
+                            Horizontal::Left,
+                        ));
+                    }
+                }
+            }
+
+            for row in rows.clone() {
+                let y = metrics.cell_bounds(Cell::new(row, 0), scroll).y - 0.5;
+                renderer.fill_quad(
+                    quad(Rectangle {
+                        y,
+                        height: 1.0,
+                        ..cells
+                    }),
+                    appearance.grid_color,
+                );
+            }
+            for column in columns.clone() {
+                let x = metrics.cell_bounds(Cell::new(0, column), scroll).x - 0.5;
+                renderer.fill_quad(
+                    quad(Rectangle {
+                        x,
+                        width: 1.0,
+                        ..cells
+                    }),
+                    appearance.grid_color,
+                );
+            }
+
+            let top_left = metrics.cell_bounds(start, scroll);
+            let bottom_right = metrics.cell_bounds(end, scroll);
+            renderer.fill_quad(
+                quad(Rectangle {
+                    width: bottom_right.x + bottom_right.width - top_left.x,
+                    height: bottom_right.y + bottom_right.height - top_left.y,
+                    ..top_left
+                }),
+                appearance.selection_color,
+            );
+
+            let active = metrics.cell_bounds(selection.head, scroll);
+
+            if let Some(edit) = &state.edit {
+                renderer.fill_quad(quad(active), appearance.editing_background);
+                renderer.with_layer(active, |renderer| {
+                    renderer.fill_text(self.cell_text(
+                        &metrics,
+                        &edit.text,
+                        active,
+                        appearance.text_color,
+                        Horizontal::Left,
+                    ));
+
+                    if is_focused {
+                        let offset = byte_offset(&edit.text, edit.cursor);
+                        let x = renderer.measure_width(
+                            &edit.text[..offset],
+                            metrics.text_size,
+                            self.font,
+                        );
+
+                        renderer.fill_quad(
+                            quad(Rectangle {
+                                x: active.x + CELL_PADDING + x,
+                                y: active.center_y() - metrics.text_size / 2.0,
+                                width: CURSOR_WIDTH,
+                                height: metrics.text_size,
+                            }),
+                            appearance.cursor_color,
+                        );
+                    }
+                });
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: active,
+                    border_radius: 0.0.into(),
+                    border_width: ACTIVE_BORDER_WIDTH,
+                    border_color: appearance.active_cell_color,
+                },
+                Color::TRANSPARENT,
+            );
+        });
+
+        // The headers stay in place while the cells scroll
+        let header_row = Rectangle {
+            y: bounds.y,
+            height: metrics.row_height,
+            ..cells
+        };
+        renderer.with_layer(header_row, |renderer| {
+            renderer.fill_quad(quad(header_row), appearance.header_background);
+
+            for column in columns {
+                let column_bounds = Rectangle {
+                    y: header_row.y,
+                    height: header_row.height,
+                    ..metrics.cell_bounds(Cell::new(0, column), scroll)
+                };
+
+                if (start.column..=end.column).contains(&column) {
+                    renderer.fill_quad(quad(column_bounds), appearance.header_selected_background);
+                }
+
+                renderer.fill_text(self.cell_text(
+                    &metrics,
+                    &column_name(column),
+                    column_bounds,
+                    appearance.header_text_color,
+                    Horizontal::Center,
+                ));
+                renderer.fill_quad(
+                    quad(Rectangle {
+                        x: column_bounds.x + column_bounds.width - 0.5,
+                        width: 1.0,
+                        ..column_bounds
+                    }),
+                    appearance.grid_color,
+                );
+            }
+        });
+
+        let header_column = Rectangle {
+            x: bounds.x,
+            width: metrics.header_width,
+            ..cells
+        };
+        renderer.with_layer(header_column, |renderer| {
+            renderer.fill_quad(quad(header_column), appearance.header_background);
+
+            for row in rows {
+                let row_bounds = Rectangle {
+                    x: header_column.x,
+                    width: header_column.width,
+                    ..metrics.cell_bounds(Cell::new(row, 0), scroll)
+                };
+
+                if (start.row..=end.row).contains(&row) {
+                    renderer.fill_quad(quad(row_bounds), appearance.header_selected_background);
+                }
+
+                renderer.fill_text(self.cell_text(
+                    &metrics,
+                    &(row + 1).to_string(),
+                    row_bounds,
+                    appearance.header_text_color,
+                    Horizontal::Right,
+                ));
+                renderer.fill_quad(
+                    quad(Rectangle {
+                        y: row_bounds.y + row_bounds.height - 0.5,
+                        height: 1.0,
+                        ..row_bounds
+                    }),
+                    appearance.grid_color,
+                );
+            }
+        });
+
+        let corner = Rectangle {
+            x: bounds.x,
+            y: bounds.y,
+            width: metrics.header_width,
+            height: metrics.row_height,
+        };
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(quad(corner), appearance.header_background);
+            renderer.fill_quad(
+                quad(Rectangle {
+                    y: cells.y - 0.5,
+                    height: 1.0,
+                    ..bounds
+                }),
+                appearance.border_color,
+            );
+            renderer.fill_quad(
+                quad(Rectangle {
+                    x: cells.x - 0.5,
+                    width: 1.0,
+                    ..bounds
+                }),
+                appearance.border_color,
+            );
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                Color::TRANSPARENT,
+            );
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Renderer> From<SpreadsheetGrid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(spreadsheet_grid: SpreadsheetGrid<'a, Message, Renderer>) -> Self {
+        Element::new(spreadsheet_grid)
+    }
+}
+
+/// The part of a [`SpreadsheetGrid`](SpreadsheetGrid) under the cursor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    /// A cell.
+    Cell(Cell),
+    /// The header of a row.
+    Row(usize),
+    /// The header of a column.
+    Column(usize),
+    /// The corner between the headers.
+    All,
+}
+
+/// The areas of a [`SpreadsheetGrid`](SpreadsheetGrid).
+#[derive(Clone, Copy, Debug)]
+struct Metrics {
+    /// The text size of the cells.
+    text_size: f32,
+    /// The number of rows and columns.
+    size: (usize, usize),
+    /// The height of a row.
+    row_height: f32,
+    /// The width of a column.
+    column_width: f32,
+    /// The width of the header column.
+    header_width: f32,
+    /// The area of the cells below and right of the headers.
+    cells: Rectangle,
+}
+
+impl Metrics {
+    /// Returns the bounds of the cell scrolled by the offset.
+    fn cell_bounds(&self, cell: Cell, scroll: Vector) -> Rectangle {
+        Rectangle {
+            x: self.cells.x + cell.column as f32 * self.column_width - scroll.x,
+            y: self.cells.y + cell.row as f32 * self.row_height - scroll.y,
+            width: self.column_width,
+            height: self.row_height,
+        }
+    }
+
+    /// Returns the cell at the position scrolled by the offset, staying
+    /// within the rows and columns when the position is outside of them.
+    fn clamped_cell_at(&self, position: Point, scroll: Vector) -> Cell {
+        let index = |offset: f32, length: f32, count: usize| {
+            ((offset / length).max(0.0) as usize).min(count.saturating_sub(1))
+        };
+
+        Cell::new(
+            index(
+                position.y - self.cells.y + scroll.y,
+                self.row_height,
+                self.size.0,
+            ),
+            index(
+                position.x - self.cells.x + scroll.x,
+                self.column_width,
+                self.size.1,
+            ),
+        )
+    }
+
+    /// Returns the part under the cursor scrolled by the offset, if any.
+    fn target_at(&self, position: Point, scroll: Vector) -> Option<Target> {
+        let cell = self.clamped_cell_at(position, scroll);
+        let last = self.cell_bounds(Cell::new(self.size.0 - 1, self.size.1 - 1), scroll);
+        let in_rows = position.y < last.y + last.height;
+        let in_columns = position.x < last.x + last.width;
+        let in_header_row = position.y < self.cells.y;
+        let in_header_column = position.x < self.cells.x;
+
+        match (in_header_row, in_header_column) {
+            (true, true) => Some(Target::All),
+            (true, false) if in_columns => Some(Target::Column(cell.column)),
+            (false, true) if in_rows => Some(Target::Row(cell.row)),
+            (false, false) if in_rows && in_columns => Some(Target::Cell(cell)),
+            _ => None,
+        }
+    }
+
+    /// Returns the offset the cells can be scrolled by at most.
+    fn max_scroll(&self) -> Vector {
+        Vector::new(
+            (self.size.1 as f32 * self.column_width - self.cells.width).max(0.0),
+            (self.size.0 as f32 * self.row_height - self.cells.height).max(0.0),
+        )
+    }
+
+    /// Returns the offset within the cells.
+    fn clamp_scroll(&self, scroll: Vector) -> Vector {
+        let max = self.max_scroll();
+
+        Vector::new(scroll.x.min(max.x).max(0.0), scroll.y.min(max.y).max(0.0))
+    }
+
+    /// Returns the offset scrolling the cell into view.
+    fn reveal(&self, cell: Cell, scroll: Vector) -> Vector {
+        let reveal = |offset: f32, start: f32, length: f32, visible: f32| {
+            offset.min(start).max(start + length - visible)
+        };
+
+        self.clamp_scroll(Vector::new(
+            reveal(
+                scroll.x,
+                cell.column as f32 * self.column_width,
+                self.column_width,
+                self.cells.width,
+            ),
+            reveal(
+                scroll.y,
+                cell.row as f32 * self.row_height,
+                self.row_height,
+                self.cells.height,
+            ),
+        ))
+    }
+
+    /// Returns the rows and the columns visible with the offset.
+    fn visible(&self, scroll: Vector) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let range = |offset: f32, length: f32, visible: f32, count: usize| {
+            let first = (offset / length) as usize;
+            first.min(count)..count.min(((offset + visible) / length).ceil() as usize + 1)
+        };
+
+        (
+            range(scroll.y, self.row_height, self.cells.height, self.size.0),
+            range(scroll.x, self.column_width, self.cells.width, self.size.1),
+        )
+    }
+}
+
+/// The edit of the text of a cell.
+#[derive(Clone, Debug)]
+struct Edit {
+    /// The edited cell.
+    cell: Cell,
+    /// The edited text.
+    text: String,
+    /// The index of the character before which the cursor is.
+    cursor: usize,
+}
+
+/// The state of a [`SpreadsheetGrid`](SpreadsheetGrid).
+#[derive(Debug, Default)]
+struct State {
+    /// The keyboard focus.
+    focus: Focus,
+    /// The selected cells.
+    selection: Selection,
+    /// The edit of the active cell, if any.
+    edit: Option<Edit>,
+    /// The offset the cells are scrolled by.
+    scroll: Vector,
+    /// The part the selection is dragged from, if any.
+    drag: Option<Target>,
+    /// The cell clicked last and the time it was clicked, to recognize
+    /// double clicks.
+    last_click: Option<(Cell, Instant)>,
+    /// The keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+}
+
+/// Returns the name of the column at the index, like `A`, `Z` or `AA`.
+fn column_name(index: usize) -> String {
+    let mut name = Vec::new();
+    let mut index = index + 1;
+
+    while index > 0 {
+        let letter = (index - 1) % 26;
+        name.push(char::from(b'A' + letter as u8));
+        index = (index - 1) / 26;
+    }
+
+    name.into_iter().rev().collect()
+}
+
+/// Returns the byte offset of the character at the index in the text.
+fn byte_offset(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
+/// Parses rows of tab-separated values, ignoring a trailing line break.
+fn parse_tsv(text: &str) -> Vec<Vec<String>> {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+
+    text.split('\n')
+        .map(|line| {
+            line.strip_suffix('\r')
+                .unwrap_or(line)
+                .split('\t')
+                .map(str::to_owned)
+                .collect()
+        })
+        .collect()
+}
+
+/// Joins the rows of values into tab-separated values.
+fn to_tsv<'a, R, V>(rows: R) -> String
+where
+    R: IntoIterator<Item = V>,
+    V: IntoIterator<Item = &'a str>,
+{
+    rows.into_iter()
+        .map(|row| row.into_iter().collect::<Vec<_>>().join("\t"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{column_name, parse_tsv, to_tsv, Cell, Selection};
+
+    #[test]
+    fn names_columns_like_spreadsheets() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(27), "AB");
+        assert_eq!(column_name(701), "ZZ");
+        assert_eq!(column_name(702), "AAA");
+    }
+
+    #[test]
+    fn round_trips_tab_separated_values() {
+        let parsed = parse_tsv("a\tb\r\nc\t\r\n");
+
+        assert_eq!(
+            parsed,
+            vec![
+                vec![String::from("a"), String::from("b")],
+                vec![String::from("c"), String::new()],
+            ]
+        );
+        assert_eq!(
+            to_tsv(parsed.iter().map(|row| row.iter().map(String::as_str))),
+            "a\tb\nc\t"
+        );
+        assert_eq!(parse_tsv("single"), vec![vec![String::from("single")]]);
+    }
+
+    #[test]
+    fn selects_ranges_in_any_direction() {
+        let selection = Selection {
+            anchor: Cell::new(4, 1),
+            head: Cell::new(2, 3),
+        };
+
+        assert_eq!(selection.bounds(), (Cell::new(2, 1), Cell::new(4, 3)));
+        assert!(selection.contains(Cell::new(3, 2)));
+        assert!(!selection.contains(Cell::new(1, 2)));
+        assert!(!selection.is_single());
+    }
+
+    #[test]
+    fn steps_within_the_grid() {
+        let size = (3, 2);
+
+        assert_eq!(Cell::new(0, 0).step(-1, -1, size), Cell::new(0, 0));
+        assert_eq!(Cell::new(1, 0).step(5, 1, size), Cell::new(2, 1));
+        assert_eq!(Cell::new(4, 4).step(0, 0, size), Cell::new(2, 1));
+    }
+}
@@ -0,0 +1,331 @@
+//! Show the status of a device or a connection with a colored dot.
+//!
+//! *This API requires the following crate features to be activated: status_dot*
+use std::f32::consts::TAU;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    renderer, text,
+    time::{Duration, Instant},
+    widget::tree::{self, Tree},
+    window, Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::core::animation::{Animation, Interpolate};
+
+use crate::style::style_state::{appearance, StyleState};
+
+pub use crate::style::status_dot::{Appearance, Status, StyleSheet};
+
+/// The default diameter of the dot.
+const DEFAULT_SIZE: f32 = 10.0;
+
+/// The default space between the dot and the label.
+const DEFAULT_SPACING: f32 = 6.0;
+
+/// The default time a blink takes.
+const DEFAULT_BLINK_PERIOD: Duration = Duration::from_millis(1200);
+
+/// A small colored dot showing the [`Status`](Status) of a device or a
+/// connection, like an LED on a dashboard.
+///
+/// The dot can be followed by a label and can blink to draw attention, for
+/// example while a connection is being established.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::status_dot;
+/// #
+/// # pub type StatusDot = status_dot::StatusDot<Null>;
+/// use iced_aw::native::status_dot::Status;
+///
+/// let online = StatusDot::new(Status::Ok).label("Online");
+/// let reconnecting = StatusDot::new(Status::Warning)
+///     .label("Reconnecting")
+///     .blinking(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StatusDot<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The status shown by the dot.
+    status: Status,
+    /// The label following the dot.
+    label: Option<String>,
+    /// Whether the dot blinks.
+    blinking: bool,
+    /// The time a blink takes.
+    blink_period: Duration,
+    /// The diameter of the dot.
+    size: f32,
+    /// The space between the dot and the label.
+    spacing: f32,
+    /// The text size of the label.
+    text_size: Option<f32>,
+    /// The font of the label.
+    font: Font,
+    /// The style of the status dot.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> StatusDot<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`StatusDot`](StatusDot) showing the [`Status`](Status).
+    #[must_use]
+    pub fn new(status: Status) -> Self {
+        Self {
+            status,
+            label: None,
+            blinking: false,
+            blink_period: DEFAULT_BLINK_PERIOD,
+            size: DEFAULT_SIZE,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the label following the dot.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the dot blinks.
+    #[must_use]
+    pub fn blinking(mut self, blinking: bool) -> Self {
+        self.blinking = blinking;
+        self
+    }
+
+    /// Sets the time a blink takes.
+    #[must_use]
+    pub fn blink_period(mut self, blink_period: Duration) -> Self {
+        self.blink_period = blink_period;
+        self
+    }
+
+    /// Sets the diameter of the dot.
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the space between the dot and the label.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the label.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the label.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`StatusDot`](StatusDot).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for StatusDot<Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let intrinsic = match &self.label {
+            Some(label) => Size::new(
+                self.size + self.spacing + renderer.measure_width(label, text_size, self.font),
+                self.size.max(text_size),
+            ),
+            None => Size::new(self.size, self.size),
+        };
+
+        Node::new(limits.resolve(intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.animation.set_duration(self.blink_period);
+
+            if self.blinking {
+                if !state.animation.is_running(now) {
+                    state.animation.go(true, now);
+                }
+                state.animation.request_redraw(now, shell);
+            } else if state.animation.is_running(now) {
+                state.animation = Animation::new(self.blink_period).repeat(true);
+            }
+            state.now = Some(now);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(bounds.contains(cursor_position), false, false, false),
+            self.style,
+            self.status,
+        );
+
+        let color = if self.blinking {
+            let t = state
+                .animation
+                .progress(state.now.unwrap_or_else(Instant::now));
+
+            appearance
+                .color
+                .interpolate(appearance.dimmed_color, blink(t))
+        } else {
+            appearance.color
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: bounds.center_y() - self.size / 2.0,
+                    width: self.size,
+                    height: self.size,
+                },
+                border_radius: (self.size / 2.0).into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            color,
+        );
+
+        if let Some(label) = &self.label {
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.x + self.size + self.spacing,
+                    y: bounds.center_y(),
+                    width: (bounds.width - self.size - self.spacing).max(0.0),
+                    height: bounds.height,
+                },
+                size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<StatusDot<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(status_dot: StatusDot<Renderer>) -> Self {
+        Element::new(status_dot)
+    }
+}
+
+/// The state of a [`StatusDot`](StatusDot).
+#[derive(Debug)]
+struct State {
+    /// The animation running the blinks.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            animation: Animation::new(DEFAULT_BLINK_PERIOD).repeat(true),
+            now: None,
+        }
+    }
+}
+
+/// Returns how far the dot has faded at the time of a blink, between 0.0 and
+/// 1.0.
+///
+/// The dot fades out and back in smoothly, so it is fully lit at the start
+/// and at the end of a blink and dimmest halfway through.
+fn blink(t: f32) -> f32 {
+    0.5 - 0.5 * (t * TAU).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::blink;
+
+    #[test]
+    fn blink_is_dimmest_halfway_through() {
+        assert!(blink(0.0).abs() < f32::EPSILON);
+        assert!((blink(0.5) - 1.0).abs() < f32::EPSILON);
+        assert!((blink(0.25) - 0.5).abs() < 1e-6);
+        assert!(blink(1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,895 @@
+//! Guide the user through numbered steps, like in a wizard.
+//!
+//! *This API requires the following crate features to be activated: stepper*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::{Operation, Tree},
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::stepper::{Appearance, StyleSheet};
+
+/// The default spacing between the steps, the content and the buttons.
+const DEFAULT_SPACING: f32 = 16.0;
+
+/// The default padding around the labels of the buttons.
+const DEFAULT_PADDING: f32 = 8.0;
+
+/// The padding around the number in the marker of a step.
+const MARKER_PADDING: f32 = 6.0;
+
+/// The spacing between the marker and the label of a step.
+const LABEL_SPACING: f32 = 8.0;
+
+/// The minimum length of a connector between two steps.
+const MIN_CONNECTOR: f32 = 16.0;
+
+/// The gap between a connector and the steps it connects.
+const CONNECTOR_GAP: f32 = 8.0;
+
+/// The direction the steps of a [`Stepper`](Stepper) are lined up in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The steps are lined up above the content.
+    #[default]
+    Horizontal,
+    /// The steps are lined up left of the content.
+    Vertical,
+}
+
+/// A step of a [`Stepper`](Stepper).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// The label of the step.
+    label: String,
+    /// Whether the step has an error.
+    error: bool,
+}
+
+impl Step {
+    /// Creates a new [`Step`](Step) with the label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            error: false,
+        }
+    }
+
+    /// Marks the [`Step`](Step) as having an error, like invalid input.
+    #[must_use]
+    pub const fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+/// The state a step is shown in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StepState {
+    /// The step is before the current one.
+    Completed,
+    /// The step is the current one.
+    Current,
+    /// The step is after the current one.
+    Upcoming,
+    /// The step has an error.
+    Error,
+}
+
+/// A row or a column of numbered steps above or beside the content of the
+/// current step, with buttons to go back and forth.
+///
+/// The steps before the current one are shown as completed and the ones after
+/// it as upcoming, unless they are marked with an error. The application swaps
+/// the content when the current step changes.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::stepper::{self, Step};
+/// #
+/// # pub type Stepper<'a, Message> = stepper::Stepper<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Back,
+///     Next,
+///     Jump(usize),
+/// }
+///
+/// let stepper = Stepper::new(
+///     vec![Step::new("Account"), Step::new("Address"), Step::new("Payment")],
+///     1,
+///     Text::new("Enter your address"),
+/// )
+/// .on_back(Message::Back)
+/// .on_next(Message::Next)
+/// .on_jump(Message::Jump);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Stepper<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The steps.
+    steps: Vec<Step>,
+    /// The index of the current step.
+    current: usize,
+    /// The content of the current step.
+    content: Element<'a, Message, Renderer>,
+    /// The direction the steps are lined up in.
+    orientation: Orientation,
+    /// The function producing the message to jump to a pressed step.
+    on_jump: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The message sent by the back button.
+    on_back: Option<Message>,
+    /// The message sent by the next button.
+    on_next: Option<Message>,
+    /// The label of the back button.
+    back_label: String,
+    /// The label of the next button.
+    next_label: String,
+    /// The width of the stepper.
+    width: Length,
+    /// The spacing between the steps, the content and the buttons.
+    spacing: f32,
+    /// The padding around the labels of the buttons.
+    padding: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the stepper.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Stepper<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Stepper`](Stepper).
+    ///
+    /// It expects:
+    ///     * the [`Step`](Step)s.
+    ///     * the index of the current step.
+    ///     * the content of the current step.
+    pub fn new<C>(steps: Vec<Step>, current: usize, content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Stepper {
+            steps,
+            current,
+            content: content.into(),
+            orientation: Orientation::default(),
+            on_jump: None,
+            on_back: None,
+            on_next: None,
+            back_label: String::from("Back"),
+            next_label: String::from("Next"),
+            width: Length::Fill,
+            spacing: DEFAULT_SPACING,
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Orientation`](Orientation) of the steps.
+    #[must_use]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the function that will be called with the index of a step when
+    /// it is pressed, so the user can jump to it.
+    #[must_use]
+    pub fn on_jump<F>(mut self, on_jump: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_jump = Some(Box::new(on_jump));
+        self
+    }
+
+    /// Shows a back button sending the message. It is disabled on the first
+    /// step.
+    #[must_use]
+    pub fn on_back(mut self, message: Message) -> Self {
+        self.on_back = Some(message);
+        self
+    }
+
+    /// Shows a next button sending the message. It is disabled on the last
+    /// step.
+    #[must_use]
+    pub fn on_next(mut self, message: Message) -> Self {
+        self.on_next = Some(message);
+        self
+    }
+
+    /// Sets the label of the back button.
+    #[must_use]
+    pub fn back_label(mut self, label: impl Into<String>) -> Self {
+        self.back_label = label.into();
+        self
+    }
+
+    /// Sets the label of the next button.
+    #[must_use]
+    pub fn next_label(mut self, label: impl Into<String>) -> Self {
+        self.next_label = label.into();
+        self
+    }
+
+    /// Sets the width of the [`Stepper`](Stepper).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the steps, the content and the buttons.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding around the labels of the buttons.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Stepper`](Stepper).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Stepper<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the [`StepState`](StepState) of the step at the index.
+    fn step_state(&self, index: usize) -> StepState {
+        if self.steps[index].error {
+            StepState::Error
+        } else if index < self.current {
+            StepState::Completed
+        } else if index == self.current {
+            StepState::Current
+        } else {
+            StepState::Upcoming
+        }
+    }
+
+    /// Returns true if the back button can be pressed.
+    const fn can_go_back(&self) -> bool {
+        self.current > 0
+    }
+
+    /// Returns true if the next button can be pressed.
+    fn can_go_next(&self) -> bool {
+        self.current + 1 < self.steps.len()
+    }
+
+    /// Lays out the steps.
+    fn layout_steps(&self, renderer: &Renderer, text_size: f32, max_width: f32) -> Node {
+        let marker = text_size + 2.0 * MARKER_PADDING;
+
+        let mut steps: Vec<Node> = self
+            .steps
+            .iter()
+            .map(|step| {
+                let (label_width, label_height) = renderer.measure(
+                    &step.label,
+                    text_size,
+                    self.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+
+                let marker_node = Node::new(Size::new(marker, marker));
+                let mut label = Node::new(Size::new(label_width, label_height));
+                label.move_to(Point::new(
+                    marker + LABEL_SPACING,
+                    (marker - label_height) / 2.0,
+                ));
+
+                Node::with_children(
+                    Size::new(marker + LABEL_SPACING + label_width, marker),
+                    vec![marker_node, label],
+                )
+            })
+            .collect();
+
+        let gaps = steps.len().saturating_sub(1);
+        let size = match self.orientation {
+            Orientation::Horizontal => {
+                let items: f32 = steps.iter().map(|step| step.size().width).sum();
+
+                // The connectors stretch to fill the width
+                #[allow(clippy::cast_precision_loss)]
+                let connector = if gaps > 0 && max_width.is_finite() {
+                    ((max_width - items) / gaps as f32 - 2.0 * CONNECTOR_GAP).max(MIN_CONNECTOR)
+                } else {
+                    MIN_CONNECTOR
+                };
+                let mut x = 0.0;
+
+                for step in &mut steps {
+                    step.move_to(Point::new(x, 0.0));
+                    x += step.size().width + connector + 2.0 * CONNECTOR_GAP;
+                }
+
+                Size::new((x - connector - 2.0 * CONNECTOR_GAP).max(0.0), marker)
+            }
+            Orientation::Vertical => {
+                let mut y = 0.0;
+
+                for step in &mut steps {
+                    step.move_to(Point::new(0.0, y));
+                    y += marker + MIN_CONNECTOR + 2.0 * CONNECTOR_GAP;
+                }
+
+                Size::new(
+                    steps
+                        .iter()
+                        .map(|step| step.size().width)
+                        .fold(0.0, f32::max),
+                    (y - MIN_CONNECTOR - 2.0 * CONNECTOR_GAP).max(0.0),
+                )
+            }
+        };
+
+        Node::with_children(size, steps)
+    }
+
+    /// Returns the size of a button with the label, or zero if there is no
+    /// such button.
+    fn button_size(
+        &self,
+        renderer: &Renderer,
+        label: &str,
+        text_size: f32,
+        is_shown: bool,
+    ) -> Size {
+        if !is_shown {
+            return Size::ZERO;
+        }
+
+        let (width, height) = renderer.measure(
+            label,
+            text_size,
+            self.font,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        );
+
+        Size::new(width + 2.0 * self.padding, height + 2.0 * self.padding)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Stepper<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let max_width = limits.max().width;
+
+        let steps = self.layout_steps(renderer, text_size, max_width);
+        let back = self.button_size(
+            renderer,
+            &self.back_label,
+            text_size,
+            self.on_back.is_some(),
+        );
+        let next = self.button_size(
+            renderer,
+            &self.next_label,
+            text_size,
+            self.on_next.is_some(),
+        );
+        let buttons_width = back.width + self.spacing + next.width;
+        let buttons_height = back.height.max(next.height);
+
+        // The content and the buttons are placed below or beside the steps
+        let origin = match self.orientation {
+            Orientation::Horizontal => Point::new(0.0, steps.size().height + self.spacing),
+            Orientation::Vertical => Point::new(steps.size().width + self.spacing, 0.0),
+        };
+        let content_limits = Limits::new(
+            Size::ZERO,
+            Size::new((max_width - origin.x).max(0.0), f32::INFINITY),
+        );
+        let mut content = self.content.as_widget().layout(renderer, &content_limits);
+        content.move_to(origin);
+
+        let intrinsic_width = match self.orientation {
+            Orientation::Horizontal => steps
+                .size()
+                .width
+                .max(content.size().width)
+                .max(buttons_width),
+            Orientation::Vertical => origin.x + content.size().width.max(buttons_width),
+        };
+        let width = limits.resolve(Size::new(intrinsic_width, 0.0)).width;
+
+        let buttons_y = if buttons_height > 0.0 {
+            origin.y + content.size().height + self.spacing
+        } else {
+            origin.y + content.size().height
+        };
+        let mut back = Node::new(back);
+        back.move_to(Point::new(origin.x, buttons_y));
+        let mut next = Node::new(next);
+        next.move_to(Point::new(width - next.size().width, buttons_y));
+
+        let height = (buttons_y + buttons_height).max(steps.size().height);
+
+        Node::with_children(
+            limits.resolve(Size::new(width, height)),
+            vec![steps, content, back, next],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let steps = children
+            .next()
+            .expect("Native: Layout should have a steps layout for a Stepper");
+        let content = children
+            .next()
+            .expect("Native: Layout should have a content layout for a Stepper");
+        let back = children
+            .next()
+            .expect("Native: Layout should have a back layout for a Stepper");
+        let next = children
+            .next()
+            .expect("Native: Layout should have a next layout for a Stepper");
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event.clone(),
+            content,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Captured
+            || !matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            )
+        {
+            return status;
+        }
+
+        if let Some(on_jump) = &self.on_jump {
+            let pressed = steps
+                .children()
+                .position(|step| step.bounds().contains(cursor_position));
+
+            if let Some(index) = pressed.filter(|index| *index != self.current) {
+                shell.publish(on_jump(index));
+                return event::Status::Captured;
+            }
+        }
+
+        let buttons = [
+            (back, self.can_go_back(), &self.on_back),
+            (next, self.can_go_next(), &self.on_next),
+        ];
+
+        for (button, is_enabled, message) in buttons {
+            if !button.bounds().contains(cursor_position) {
+                continue;
+            }
+
+            if let (true, Some(message)) = (is_enabled, message) {
+                shell.publish(message.clone());
+            }
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let steps = children
+            .next()
+            .expect("Native: Layout should have a steps layout for a Stepper");
+        let content = children
+            .next()
+            .expect("Native: Layout should have a content layout for a Stepper");
+        let back = children
+            .next()
+            .expect("Native: Layout should have a back layout for a Stepper");
+        let next = children
+            .next()
+            .expect("Native: Layout should have a next layout for a Stepper");
+
+        let is_jumpable = self.on_jump.is_some()
+            && steps.children().enumerate().any(|(index, step)| {
+                index != self.current && step.bounds().contains(cursor_position)
+            });
+        let is_button = (self.can_go_back() && back.bounds().contains(cursor_position))
+            || (self.can_go_next() && next.bounds().contains(cursor_position));
+
+        if is_jumpable || is_button {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            content,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let steps = children
+            .next()
+            .expect("Graphics: Layout should have a steps layout for a Stepper");
+        let content = children
+            .next()
+            .expect("Graphics: Layout should have a content layout for a Stepper");
+        let back = children
+            .next()
+            .expect("Graphics: Layout should have a back layout for a Stepper");
+        let next = children
+            .next()
+            .expect("Graphics: Layout should have a next layout for a Stepper");
+
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                steps.bounds().contains(cursor_position),
+                false,
+                false,
+                false,
+            ),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        draw_connectors(renderer, &appearance, steps, self.orientation, self.current);
+
+        for (index, (step, step_layout)) in self.steps.iter().zip(steps.children()).enumerate() {
+            let mut parts = step_layout.children();
+            let marker = parts
+                .next()
+                .expect("Graphics: Layout should have a marker layout for a step")
+                .bounds();
+            let label = parts
+                .next()
+                .expect("Graphics: Layout should have a label layout for a step")
+                .bounds();
+
+            let step_state = self.step_state(index);
+            let (background, marker_color, label_color) = match step_state {
+                StepState::Completed => (
+                    appearance.completed_background,
+                    appearance.marker_text_color,
+                    appearance.label_color,
+                ),
+                StepState::Current => (
+                    appearance.current_background,
+                    appearance.marker_text_color,
+                    appearance.label_color,
+                ),
+                StepState::Upcoming => (
+                    appearance.upcoming_background,
+                    appearance.upcoming_text_color,
+                    appearance.upcoming_label_color,
+                ),
+                StepState::Error => (
+                    appearance.error_background,
+                    appearance.marker_text_color,
+                    appearance.label_color,
+                ),
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: marker,
+                    border_radius: (marker.width / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            match step_state {
+                StepState::Completed | StepState::Error => {
+                    let icon = if step_state == StepState::Error {
+                        Icon::X
+                    } else {
+                        Icon::Check
+                    };
+
+                    renderer.fill_glyph(
+                        char::from(icon),
+                        icon.font(),
+                        marker,
+                        text_size,
+                        marker_color,
+                    );
+                }
+                StepState::Current | StepState::Upcoming => {
+                    fill_centered_text(
+                        renderer,
+                        &(index + 1).to_string(),
+                        marker,
+                        text_size,
+                        self.font,
+                        marker_color,
+                    );
+                }
+            }
+
+            renderer.fill_text(text::Text {
+                content: &step.label,
+                bounds: Rectangle {
+                    y: label.center_y(),
+                    ..label
+                },
+                size: text_size,
+                color: label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            content,
+            cursor_position,
+            viewport,
+        );
+
+        let buttons = [
+            (back, self.can_go_back(), &self.back_label),
+            (next, self.can_go_next(), &self.next_label),
+        ];
+
+        for (button, is_enabled, label) in buttons {
+            let bounds = button.bounds();
+
+            if bounds.width <= 0.0 {
+                continue;
+            }
+
+            let (background, text_color) = if !is_enabled {
+                (
+                    appearance.upcoming_background,
+                    appearance.button_disabled_text_color,
+                )
+            } else if bounds.contains(cursor_position) {
+                (
+                    appearance.button_hovered_background,
+                    appearance.button_text_color,
+                )
+            } else {
+                (appearance.button_background, appearance.button_text_color)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.button_border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            fill_centered_text(renderer, label, bounds, text_size, self.font, text_color);
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(content) = layout.children().nth(1) {
+            self.content
+                .as_widget()
+                .operate(&mut state.children[0], content, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut state.children[0],
+            layout.children().nth(1)?,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Stepper<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(stepper: Stepper<'a, Message, Renderer>) -> Self {
+        Element::new(stepper)
+    }
+}
+
+/// Draws the connectors between the markers of the steps.
+///
+/// A connector leading to a completed or the current step is highlighted.
+fn draw_connectors<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    steps: Layout<'_>,
+    orientation: Orientation,
+    current: usize,
+) where
+    Renderer: iced_native::Renderer,
+{
+    let bounds: Vec<Rectangle> = steps.children().map(|step| step.bounds()).collect();
+
+    for (index, pair) in bounds.windows(2).enumerate() {
+        let (from, to) = (pair[0], pair[1]);
+        let marker = from.height;
+        let connector = match orientation {
+            Orientation::Horizontal => Rectangle {
+                x: from.x + from.width + CONNECTOR_GAP,
+                y: from.center_y() - appearance.connector_width / 2.0,
+                width: to.x - from.x - from.width - 2.0 * CONNECTOR_GAP,
+                height: appearance.connector_width,
+            },
+            Orientation::Vertical => Rectangle {
+                x: from.x + marker / 2.0 - appearance.connector_width / 2.0,
+                y: from.y + marker + CONNECTOR_GAP,
+                width: appearance.connector_width,
+                height: to.y - from.y - marker - 2.0 * CONNECTOR_GAP,
+            },
+        };
+        let color = if index < current {
+            appearance.completed_connector_color
+        } else {
+            appearance.connector_color
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: connector,
+                border_radius: (appearance.connector_width / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+/// Draws the text centered in the bounds.
+fn fill_centered_text<Renderer>(
+    renderer: &mut Renderer,
+    content: &str,
+    bounds: Rectangle,
+    size: f32,
+    font: Font,
+    color: Color,
+) where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+{
+    renderer.fill_text(text::Text {
+        content,
+        bounds: Rectangle {
+            x: bounds.center_x(),
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size,
+        color,
+        font,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+    });
+}
@@ -0,0 +1,520 @@
+//! Turn an option on or off with a sliding thumb.
+//!
+//! *This API requires the following crate features to be activated: switch*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse, renderer, text,
+    time::{Duration, Instant},
+    touch,
+    widget::tree::{self, Tree},
+    window, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use crate::{
+    core::{
+        animation::{Animation, Easing, Interpolate},
+        renderer::GlyphRenderer,
+    },
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::switch::{Appearance, StyleSheet};
+
+/// The default width of the track.
+const DEFAULT_WIDTH: f32 = 44.0;
+
+/// The default height of the track.
+const DEFAULT_HEIGHT: f32 = 24.0;
+
+/// The space between the thumb and the edge of the track.
+const THUMB_MARGIN: f32 = 3.0;
+
+/// The time the thumb takes to slide to the other side.
+const TRANSITION: Duration = Duration::from_millis(150);
+
+/// The distance the cursor moves before a press becomes a drag.
+const DRAG_THRESHOLD: f32 = 2.0;
+
+/// A label shown inside the track of a [`Switch`](Switch).
+#[derive(Clone, Debug)]
+enum Label {
+    /// A text.
+    Text(String),
+    /// An icon.
+    Icon(Icon),
+}
+
+/// A switch turning an option on or off, with a thumb sliding along its track.
+///
+/// The switch is toggled by clicking on it or by dragging the thumb to the
+/// other side. Without a function producing the message it is disabled.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::switch;
+/// #
+/// # pub type Switch<'a, Message> = switch::Switch<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Wifi(bool),
+/// }
+///
+/// let is_on = true;
+///
+/// let switch = Switch::new(is_on)
+///     .on_toggle(Message::Wifi)
+///     .labels("ON", "OFF");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Switch<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Whether the switch is on.
+    is_on: bool,
+    /// The function producing the message when the switch is toggled.
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// The label shown inside the track while the switch is on.
+    on_label: Option<Label>,
+    /// The label shown inside the track while the switch is off.
+    off_label: Option<Label>,
+    /// The minimal width of the track.
+    width: f32,
+    /// The height of the track.
+    height: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the switch.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Switch<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Switch`](Switch) that is on if `is_on` is true.
+    #[must_use]
+    pub fn new(is_on: bool) -> Self {
+        Self {
+            is_on,
+            on_toggle: None,
+            on_label: None,
+            off_label: None,
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the new state when the
+    /// [`Switch`](Switch) is toggled.
+    ///
+    /// The [`Switch`](Switch) is disabled without one.
+    #[must_use]
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the texts shown inside the track while the [`Switch`](Switch) is
+    /// on and off.
+    #[must_use]
+    pub fn labels(mut self, on: impl Into<String>, off: impl Into<String>) -> Self {
+        self.on_label = Some(Label::Text(on.into()));
+        self.off_label = Some(Label::Text(off.into()));
+        self
+    }
+
+    /// Sets the icons shown inside the track while the [`Switch`](Switch) is
+    /// on and off.
+    #[must_use]
+    pub fn icons(mut self, on: Icon, off: Icon) -> Self {
+        self.on_label = Some(Label::Icon(on));
+        self.off_label = Some(Label::Icon(off));
+        self
+    }
+
+    /// Sets the minimal width of the track.
+    ///
+    /// The track grows to fit its labels.
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the track.
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the labels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Switch`](Switch).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the text size of the labels, fitting the track by default.
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size
+            .unwrap_or_else(|| renderer.default_size().min(self.height * 0.6))
+    }
+
+    /// Returns the width of the label.
+    fn label_width(&self, renderer: &Renderer, label: Option<&Label>) -> f32 {
+        let text_size = self.label_size(renderer);
+
+        match label {
+            Some(Label::Text(content)) => renderer.measure_width(content, text_size, self.font),
+            Some(Label::Icon(_)) => text_size,
+            None => 0.0,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Switch<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.is_on))
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        // The labels are shown next to the thumb with some room on both sides
+        let label_width = self
+            .label_width(renderer, self.on_label.as_ref())
+            .max(self.label_width(renderer, self.off_label.as_ref()));
+        let width = if label_width > 0.0 {
+            self.width
+                .max(self.height + label_width + self.height / 2.0)
+        } else {
+            self.width.max(self.height)
+        };
+
+        Node::new(limits.resolve(Size::new(width, self.height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                // The thumb slides whenever the application changes the value
+                if state.is_on != self.is_on && state.drag.is_none() {
+                    state.is_on = self.is_on;
+                    state.animation.go(self.is_on, now);
+                }
+                state.animation.request_redraw(now, shell);
+                state.now = Some(now);
+
+                event::Status::Ignored
+            }
+            _ if self.on_toggle.is_none() => event::Status::Ignored,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                state.drag = Some(Drag {
+                    origin: cursor_position.x,
+                    from: state.progress(),
+                    progress: state.progress(),
+                    is_moved: false,
+                });
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let delta = position.x - drag.origin;
+                drag.is_moved |= delta.abs() > DRAG_THRESHOLD;
+                drag.progress = (drag.from + delta / travel(bounds)).clamp(0.0, 1.0);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                // A click toggles the switch, a drag drops the thumb on the
+                // nearer side
+                let is_on = if drag.is_moved {
+                    drag.progress > 0.5
+                } else {
+                    !self.is_on
+                };
+
+                state.is_on = is_on;
+                state.animation.rest_at(drag.progress);
+                state.animation.go(is_on, Instant::now());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                if is_on != self.is_on {
+                    if let Some(on_toggle) = &self.on_toggle {
+                        shell.publish(on_toggle(is_on));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_toggle.is_some() && layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        let style_state = StyleState::new(
+            bounds.contains(cursor_position),
+            state.drag.is_some(),
+            false,
+            self.on_toggle.is_none(),
+        );
+        let appearance = |is_on| appearance!(StyleSheet, theme, style_state, self.style, is_on);
+        let (off, on) = (appearance(false), appearance(true));
+
+        // The colors follow the thumb from one side to the other
+        let t = match &state.drag {
+            Some(drag) => drag.progress,
+            None => state
+                .animation
+                .value(state.now.unwrap_or_else(Instant::now)),
+        };
+        let radius = bounds.height / 2.0;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: radius.into(),
+                border_width: off.track_border_width.interpolate(on.track_border_width, t),
+                border_color: off.track_border_color.interpolate(on.track_border_color, t),
+            },
+            off.track_color.interpolate(on.track_color, t),
+        );
+
+        // The label of each side fades in as the thumb leaves it
+        let text_size = self.label_size(renderer);
+        for (label, label_bounds, color) in [
+            (
+                &self.on_label,
+                Rectangle {
+                    width: bounds.width - bounds.height,
+                    ..bounds
+                },
+                fade(on.label_color, t),
+            ),
+            (
+                &self.off_label,
+                Rectangle {
+                    x: bounds.x + bounds.height,
+                    width: bounds.width - bounds.height,
+                    ..bounds
+                },
+                fade(off.label_color, 1.0 - t),
+            ),
+        ] {
+            match label {
+                Some(Label::Text(content)) => {
+                    renderer.fill_text(text::Text {
+                        content,
+                        bounds: Rectangle {
+                            x: label_bounds.center_x(),
+                            y: label_bounds.center_y(),
+                            ..label_bounds
+                        },
+                        size: text_size,
+                        color,
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                    });
+                }
+                Some(Label::Icon(icon)) => {
+                    renderer.fill_glyph(
+                        char::from(*icon),
+                        icon.font(),
+                        label_bounds,
+                        text_size,
+                        color,
+                    );
+                }
+                None => {}
+            }
+        }
+
+        let diameter = (bounds.height - 2.0 * THUMB_MARGIN).max(0.0);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + THUMB_MARGIN + t * travel(bounds),
+                    y: bounds.y + THUMB_MARGIN,
+                    width: diameter,
+                    height: diameter,
+                },
+                border_radius: (diameter / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            off.thumb_color.interpolate(on.thumb_color, t),
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Switch<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(switch: Switch<'a, Message, Renderer>) -> Self {
+        Element::new(switch)
+    }
+}
+
+/// A drag of the thumb of a [`Switch`](Switch).
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The horizontal position of the cursor when the thumb was pressed.
+    origin: f32,
+    /// The position of the thumb when it was pressed.
+    from: f32,
+    /// The position of the thumb following the cursor.
+    progress: f32,
+    /// Whether the cursor moved far enough to be a drag instead of a click.
+    is_moved: bool,
+}
+
+/// The state of a [`Switch`](Switch).
+#[derive(Debug)]
+struct State {
+    /// The value the thumb slides towards.
+    is_on: bool,
+    /// The animation sliding the thumb between off (0.0) and on (1.0).
+    animation: Animation,
+    /// The drag of the thumb, if it is pressed.
+    drag: Option<Drag>,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) with the thumb resting on the side of
+    /// the value.
+    fn new(is_on: bool) -> Self {
+        let mut animation = Animation::new(TRANSITION).easing(Easing::EaseInOut);
+        animation.rest_at(if is_on { 1.0 } else { 0.0 });
+
+        Self {
+            is_on,
+            animation,
+            drag: None,
+            now: None,
+        }
+    }
+
+    /// Returns the current linear position of the thumb.
+    fn progress(&self) -> f32 {
+        self.animation
+            .progress(self.now.unwrap_or_else(Instant::now))
+    }
+}
+
+/// Returns the distance the thumb travels from one side of the track to the
+/// other.
+fn travel(bounds: Rectangle) -> f32 {
+    (bounds.width - bounds.height).max(1.0)
+}
+
+/// Returns the color faded by the opacity.
+fn fade(color: Color, opacity: f32) -> Color {
+    Color {
+        a: color.a * opacity,
+        ..color
+    }
+}
@@ -6,22 +6,48 @@
 //! *This API requires the following crate features to be activated: `tab_bar`*
 use iced_native::{
     alignment::{Horizontal, Vertical},
-    event,
+    event, keyboard,
     layout::{Limits, Node},
     mouse, renderer, touch, Alignment, Clipboard, Color, Event, Font, Layout, Length, Point,
     Rectangle, Shell,
 };
 use iced_native::{
-    widget::{Column, Row, Text, Tree},
+    widget::{
+        operation::Focusable,
+        tree::{self, Tag},
+        Column, Id, Operation, Row, Text, Tree,
+    },
     Element, Widget,
 };
 
 pub mod tab_label;
 pub use tab_label::TabLabel;
 
-use crate::{graphics::icons, style::tab_bar::StyleSheet};
+use crate::{
+    core::{
+        breakpoint::Breakpoint,
+        icon_source::{self, IconSource},
+        layout_cache::LayoutCache,
+        renderer::{fill_gradient, IconRenderer},
+        sizing::hit_target,
+    },
+    graphics::icons,
+    native::focus::Focus,
+    style::{
+        style_state::{appearance, StyleState},
+        tab_bar::StyleSheet,
+    },
+};
+
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{self, Role};
 
-use std::marker::PhantomData;
+use std::{
+    borrow::Cow,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
 /// The default icon size.
 const DEFAULT_ICON_SIZE: f32 = 32.0;
 /// The default text size.
@@ -35,6 +61,10 @@ const DEFAULT_SPACING: f32 = 0.0;
 
 /// A tab bar to show tabs.
 ///
+/// The [`TabBar`](TabBar) is focused by clicking on a tab. While focused, the
+/// arrow keys select the previous or next tab and Home and End select the first
+/// or last tab.
+///
 /// # Example
 /// ```
 /// # use iced_aw::{TabLabel};
@@ -93,23 +123,31 @@ where
     text_size: f32,
     /// The size of the close icon.
     close_size: f32,
+    /// The close icon.
+    close_icon: icons::Icon,
     /// The padding of the tabs of the [`TabBar`](TabBar).
     padding: f32,
     /// The spacing of the tabs of the [`TabBar`](TabBar).
     spacing: f32,
     /// The optional icon font of the [`TabBar`](TabBar).
     icon_font: Option<Font>,
+    /// If only the icons of labels with an icon and a text are shown.
+    icons_only: bool,
     /// The optional text font of the [`TabBar`](TabBar).
     text_font: Option<Font>,
     /// The style of the [`TabBar`](TabBar).
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The id of the [`TabBar`](TabBar) used to focus it.
+    id: Option<Id>,
+    /// The layout of the tabs kept in the state.
+    layout: LayoutCache<LayoutKey>,
     #[allow(clippy::missing_docs_in_private_items)]
     _renderer: PhantomData<Renderer>,
 }
 
 impl<Message, TabId, Renderer> TabBar<Message, TabId, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: IconRenderer,
     Renderer::Theme: StyleSheet,
     TabId: Eq + Clone,
 {
@@ -152,11 +190,15 @@ where
             icon_size: DEFAULT_ICON_SIZE,
             text_size: DEFAULT_TEXT_SIZE,
             close_size: DEFAULT_CLOSE_SIZE,
+            close_icon: icons::Icon::X,
             padding: DEFAULT_PADDING,
             spacing: DEFAULT_SPACING,
             icon_font: None,
             text_font: None,
+            icons_only: false,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            id: None,
+            layout: LayoutCache::default(),
             _renderer: PhantomData,
         }
     }
@@ -173,6 +215,13 @@ where
         self.tab_indices.get(self.active_tab)
     }
 
+    /// Returns the message selecting the tab at the index, if it exists.
+    pub(crate) fn select(&self, index: usize) -> Option<Message> {
+        self.tab_indices
+            .get(index)
+            .map(|id| (self.on_select)(id.clone()))
+    }
+
     /// Gets the amount of tabs on the [`TabBar`](TabBar).
     #[must_use]
     pub fn size(&self) -> usize {
@@ -256,6 +305,14 @@ where
         self
     }
 
+    /// Sets the [`Icon`](icons::Icon) drawn as the close icon of the
+    /// [`TabLabel`](crate::tab_bar::TabLabel)s of the [`TabBar`](TabBar).
+    #[must_use]
+    pub fn close_icon(mut self, close_icon: icons::Icon) -> Self {
+        self.close_icon = close_icon;
+        self
+    }
+
     /// Sets the padding of the tabs of the [`TabBar`](TabBar).
     #[must_use]
     pub fn padding(mut self, padding: f32) -> Self {
@@ -293,6 +350,25 @@ where
         self
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`TabBar`](TabBar) to
+    /// focus it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Adapts the [`TabBar`](TabBar) to the [`Breakpoint`](Breakpoint) of the
+    /// layout.
+    ///
+    /// On [`Compact`](Breakpoint::Compact) layouts, labels with an icon and a
+    /// text only show their icon.
+    #[must_use]
+    pub fn breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.icons_only = breakpoint == Breakpoint::Compact;
+        self
+    }
+
     /// Pushes a [`TabLabel`](crate::tab_bar::TabLabel) to the [`TabBar`](TabBar).
     #[must_use]
     pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
@@ -311,14 +387,76 @@ where
             .map_or(0, |a| a);
         self
     }
+
+    /// Returns the [`LayoutKey`](LayoutKey) describing everything the layout
+    /// depends on.
+    fn layout_key(&self) -> LayoutKey {
+        let mut labels = DefaultHasher::new();
+        self.tab_labels.hash(&mut labels);
+
+        LayoutKey {
+            labels: labels.finish(),
+            icons_only: self.icons_only,
+            closable: self.on_close.is_some(),
+            sizes: [
+                self.icon_size,
+                self.text_size,
+                self.close_size,
+                self.padding,
+                self.spacing,
+            ],
+            lengths: [self.width, self.tab_width, self.height],
+        }
+    }
+
+    /// Returns the [`TabLabel`](TabLabel) shown for the tab.
+    fn shown_label<'b>(&self, tab_label: &'b TabLabel) -> Cow<'b, TabLabel> {
+        match tab_label {
+            TabLabel::IconText(icon, _) if self.icons_only => Cow::Owned(TabLabel::Icon(*icon)),
+            TabLabel::SvgText(handle, _) if self.icons_only => {
+                Cow::Owned(TabLabel::Svg(handle.clone()))
+            }
+            _ => Cow::Borrowed(tab_label),
+        }
+    }
+
+    /// Describes the [`TabBar`](TabBar) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, _state: &Tree) -> accessibility::Node {
+        self.tab_labels.iter().enumerate().fold(
+            accessibility::Node::new(Role::TabList),
+            |node, (i, label)| {
+                let tab = accessibility::Node::new(Role::Tab).selected(i == self.active_tab);
+                node.push(match label.text() {
+                    Some(text) => tab.name(text),
+                    None => tab,
+                })
+            },
+        )
+    }
 }
 
 impl<Message, TabId, Renderer> Widget<Message, Renderer> for TabBar<Message, TabId, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: IconRenderer,
     Renderer::Theme: StyleSheet + iced_style::text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.layout
+            .share(&tree.state.downcast_ref::<State>().layout);
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -328,56 +466,69 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
-        self.tab_labels
-            .iter()
-            .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
-                let label = match tab_label {
-                    TabLabel::Icon(_icon) => Column::new().align_items(Alignment::Center).push(
-                        Row::new()
-                            .width(Length::Fixed(self.icon_size))
-                            .height(Length::Fixed(self.icon_size)),
-                    ),
-                    TabLabel::Text(text) => Column::new()
-                        .align_items(Alignment::Center)
-                        .push(Text::new(text).size(self.text_size).width(self.tab_width)),
-                    TabLabel::IconText(_icon, text) => Column::new()
-                        .align_items(Alignment::Center)
-                        .push(
+        // Measuring the labels of many tabs is expensive, so the tabs are only
+        // laid out again when they change
+        self.layout.layout(self.layout_key(), limits, || {
+            self.tab_labels
+                .iter()
+                .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
+                    // Labels only showing their icon are laid out like icons
+                    let label = match tab_label {
+                        TabLabel::Text(text) => Column::new()
+                            .align_items(Alignment::Center)
+                            .push(Text::new(text).size(self.text_size).width(self.tab_width)),
+                        TabLabel::IconText(_icon, text) if !self.icons_only => Column::new()
+                            .align_items(Alignment::Center)
+                            .push(
+                                Row::new()
+                                    .width(Length::Fixed(self.icon_size))
+                                    .height(Length::Fixed(self.icon_size)),
+                            )
+                            .push(Text::new(text).size(self.text_size).width(self.tab_width)),
+                        TabLabel::SvgText(_handle, text) if !self.icons_only => Column::new()
+                            .align_items(Alignment::Center)
+                            .push(
+                                Row::new()
+                                    .width(Length::Fixed(self.icon_size))
+                                    .height(Length::Fixed(self.icon_size)),
+                            )
+                            .push(Text::new(text).size(self.text_size).width(self.tab_width)),
+                        _ => Column::new().align_items(Alignment::Center).push(
                             Row::new()
                                 .width(Length::Fixed(self.icon_size))
                                 .height(Length::Fixed(self.icon_size)),
-                        )
-                        .push(Text::new(text).size(self.text_size).width(self.tab_width)),
-                }
-                .width(self.tab_width)
-                .height(self.height);
-
-                let mut label_row = Row::new()
-                    .align_items(Alignment::Center)
-                    .padding(self.padding)
+                        ),
+                    }
                     .width(self.tab_width)
-                    .push(label);
-
-                if self.on_close.is_some() {
-                    label_row = label_row.push(
-                        Row::new()
-                            .width(Length::Fixed(self.close_size))
-                            .height(Length::Fixed(self.close_size))
-                            .align_items(Alignment::Center),
-                    );
-                }
+                    .height(self.height);
 
-                row.push(label_row)
-            })
-            .width(self.width)
-            .height(self.height)
-            .spacing(self.spacing)
-            .layout(renderer, limits)
+                    let mut label_row = Row::new()
+                        .align_items(Alignment::Center)
+                        .padding(self.padding)
+                        .width(self.tab_width)
+                        .push(label);
+
+                    if self.on_close.is_some() {
+                        label_row = label_row.push(
+                            Row::new()
+                                .width(Length::Fixed(self.close_size))
+                                .height(Length::Fixed(self.close_size))
+                                .align_items(Alignment::Center),
+                        );
+                    }
+
+                    row.push(label_row)
+                })
+                .width(self.width)
+                .height(self.height)
+                .spacing(self.spacing)
+                .layout(renderer, limits)
+        })
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -385,16 +536,18 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let focus = &mut state.state.downcast_mut::<State>().focus;
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                focus.press(layout.bounds().contains(cursor_position));
+
                 if layout.bounds().contains(cursor_position) {
-                    let tabs_map: Vec<bool> = layout
+                    if let Some(new_selected) = layout
                         .children()
-                        .map(|layout| layout.bounds().contains(cursor_position))
-                        .collect();
-
-                    if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
+                        .position(|layout| layout.bounds().contains(cursor_position))
+                    {
                         shell.publish(
                             self.on_close
                                 .as_ref()
@@ -402,7 +555,7 @@ where
                                     let tab_layout = layout.children().nth(new_selected).expect("Native: Layout should have a tab layout at the selected index");
                                     let cross_layout = tab_layout.children().nth(1).expect("Native: Layout should have a close layout");
 
-                                    cross_layout.bounds().contains(cursor_position)
+                                    hit_target(cross_layout.bounds()).contains(cursor_position)
                                 })
                                 .map_or_else(
                                     || (self.on_select)(self.tab_indices[new_selected].clone()),
@@ -414,6 +567,29 @@ where
                 }
                 event::Status::Ignored
             }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if focus.is_focused() && !self.tab_indices.is_empty() =>
+            {
+                let last = self.tab_indices.len() - 1;
+                let new_selected = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Up => {
+                        self.active_tab.saturating_sub(1)
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Down => {
+                        (self.active_tab + 1).min(last)
+                    }
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    _ => return event::Status::Ignored,
+                };
+
+                if new_selected != self.active_tab {
+                    if let Some(message) = self.select(new_selected) {
+                        shell.publish(message);
+                    }
+                }
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -447,7 +623,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -455,19 +631,22 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let focus = &state.state.downcast_ref::<State>().focus;
         let bounds = layout.bounds();
         let children = layout.children();
         let is_mouse_over = bounds.contains(cursor_position);
-        let style_sheet = if is_mouse_over {
-            theme.hovered(self.style, false)
-        } else {
-            theme.active(self.style, false)
-        };
+        let style_sheet = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(is_mouse_over, false, false, false),
+            self.style,
+            false,
+        );
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
-                border_radius: (0.0).into(),
+                border_radius: style_sheet.border_radius,
                 border_width: style_sheet.border_width,
                 border_color: style_sheet.border_color.unwrap_or(Color::TRANSPARENT),
             },
@@ -479,17 +658,30 @@ where
         for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
             draw_tab(
                 renderer,
-                tab,
+                &self.shown_label(tab),
                 layout,
                 theme,
                 self.style,
                 i == self.get_active_tab_idx(),
+                focus.is_focused(),
                 cursor_position,
                 self.icon_font.unwrap_or(icons::ICON_FONT),
                 self.text_font.unwrap_or_default(),
+                self.close_icon,
             );
         }
     }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state: &mut State = state.state.downcast_mut();
+        operation.focusable(&mut state.focus, self.id.as_ref());
+    }
 }
 
 /// Draws a tab.
@@ -505,19 +697,23 @@ fn draw_tab<Renderer>(
     theme: &Renderer::Theme,
     style: <Renderer::Theme as StyleSheet>::Style,
     is_selected: bool,
+    is_focused: bool,
     cursor_position: iced_native::Point,
     icon_font: Font,
     text_font: Font,
+    close_icon: icons::Icon,
 ) where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: IconRenderer,
     Renderer::Theme: StyleSheet + iced_style::text::StyleSheet,
 {
     let is_mouse_over = layout.bounds().contains(cursor_position);
-    let style = if is_mouse_over {
-        theme.hovered(style, is_selected)
-    } else {
-        theme.active(style, is_selected)
-    };
+    let style = appearance!(
+        StyleSheet,
+        theme,
+        StyleState::new(is_mouse_over, false, is_selected && is_focused, false),
+        style,
+        is_selected,
+    );
 
     let bounds = layout.bounds();
     let mut children = layout.children();
@@ -526,14 +722,22 @@ fn draw_tab<Renderer>(
         .expect("Graphics: Layout should have a label layout");
     let mut label_layout_children = label_layout.children();
 
+    // The gradient is drawn below the quad, so the border stays on top of it
+    let background = if let Some(gradient) = &style.tab_label_gradient {
+        fill_gradient(renderer, bounds, style.tab_label_border_radius, gradient);
+        Color::TRANSPARENT.into()
+    } else {
+        style.tab_label_background
+    };
+
     renderer.fill_quad(
         renderer::Quad {
             bounds,
-            border_radius: (0.0).into(),
+            border_radius: style.tab_label_border_radius,
             border_width: style.tab_label_border_width,
             border_color: style.tab_label_border_color,
         },
-        style.tab_label_background,
+        background,
     );
 
     match tab {
@@ -607,6 +811,50 @@ fn draw_tab<Renderer>(
                 vertical_alignment: Vertical::Center,
             });
 
+            renderer.fill_text(iced_native::text::Text {
+                content: &text[..],
+                font: text_font,
+                size: text_bounds.height,
+                bounds: Rectangle {
+                    x: text_bounds.center_x(),
+                    y: text_bounds.center_y(),
+                    ..text_bounds
+                },
+                color: style.text_color,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+        TabLabel::Svg(handle) => {
+            let icon_bounds = label_layout_children
+                .next()
+                .expect("Graphics: Layout should have an icon layout for a Svg")
+                .bounds();
+
+            icon_source::draw(
+                renderer,
+                &IconSource::Svg(handle.clone()),
+                icon_bounds,
+                style.icon_color,
+            );
+        }
+        TabLabel::SvgText(handle, text) => {
+            let icon_bounds = label_layout_children
+                .next()
+                .expect("Graphics: Layout should have an icon layout for a SvgText")
+                .bounds();
+            let text_bounds = label_layout_children
+                .next()
+                .expect("Graphics: Layout should have a text layout for a SvgText")
+                .bounds();
+
+            icon_source::draw(
+                renderer,
+                &IconSource::Svg(handle.clone()),
+                icon_bounds,
+                style.icon_color,
+            );
+
             renderer.fill_text(iced_native::text::Text {
                 content: &text[..],
                 font: text_font,
@@ -625,14 +873,14 @@ fn draw_tab<Renderer>(
 
     if let Some(cross_layout) = children.next() {
         let cross_bounds = cross_layout.bounds();
-        let is_mouse_over_cross = cross_bounds.contains(cursor_position);
+        let is_mouse_over_cross = hit_target(cross_bounds).contains(cursor_position);
 
         let mut buffer = [0; 4];
-        let icon = icons::icon_to_char(icons::Icon::X).encode_utf8(&mut buffer);
+        let icon = icons::icon_to_char(close_icon).encode_utf8(&mut buffer);
 
         renderer.fill_text(iced_native::text::Text {
             content: icon,
-            font: icons::ICON_FONT,
+            font: close_icon.font(),
             size: cross_bounds.height + if is_mouse_over_cross { 5.0 } else { 0.0 },
             bounds: Rectangle {
                 x: cross_bounds.center_x(),
@@ -649,7 +897,7 @@ fn draw_tab<Renderer>(
 impl<'a, Message, TabId, Renderer> From<TabBar<Message, TabId, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: 'a + IconRenderer,
     Renderer::Theme: StyleSheet + iced_style::text::StyleSheet,
     Message: 'a,
     TabId: 'a + Eq + Clone,
@@ -658,3 +906,28 @@ where
         Element::new(tab_bar)
     }
 }
+
+/// The state of a [`TabBar`](TabBar).
+#[derive(Debug, Default)]
+struct State {
+    /// The keyboard focus of the tab bar.
+    focus: Focus,
+    /// The layout of the tabs, laid out again only when they change.
+    layout: LayoutCache<LayoutKey>,
+}
+
+/// Everything the layout of a [`TabBar`](TabBar) depends on besides the
+/// limits.
+#[derive(Debug, PartialEq)]
+struct LayoutKey {
+    /// The hash of the labels.
+    labels: u64,
+    /// Whether only the icons of the labels are shown.
+    icons_only: bool,
+    /// Whether the tabs have a close icon.
+    closable: bool,
+    /// The icon, the text and the close size, the padding and the spacing.
+    sizes: [f32; 5],
+    /// The width, the width of the tabs and the height.
+    lengths: [Length; 3],
+}
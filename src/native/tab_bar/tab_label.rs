@@ -1,6 +1,7 @@
 //! A [`TabLabel`](TabLabel) showing an icon and/or a text on a tab.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
+use crate::graphics::icons::Icon;
 
 /// A [`TabLabel`](TabLabel) showing an icon and/or a text on a tab
 /// on a [`TabBar`](super::TabBar).
@@ -15,5 +16,76 @@ pub enum TabLabel {
 
     /// A [`TabLabel`](TabLabel) showing an icon and a text on the tab.
     IconText(char, String),
+
+    /// A [`TabLabel`](TabLabel) showing only an [`Svg`](iced_native::svg::Handle)
+    /// icon on the tab.
+    ///
+    /// *The icon is only drawn with the crate feature `svg` activated.*
+    Svg(iced_native::svg::Handle),
+
+    /// A [`TabLabel`](TabLabel) showing an [`Svg`](iced_native::svg::Handle)
+    /// icon and a text on the tab.
+    ///
+    /// *The icon is only drawn with the crate feature `svg` activated.*
+    SvgText(iced_native::svg::Handle, String),
     // TODO: Support any element as a label.
 }
+
+impl TabLabel {
+    /// Returns the text shown on the tab, if the [`TabLabel`](TabLabel) has one.
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) | Self::IconText(_, text) | Self::SvgText(_, text) => Some(text),
+            Self::Icon(_) | Self::Svg(_) => None,
+        }
+    }
+}
+
+impl From<&str> for TabLabel {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for TabLabel {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<iced_native::svg::Handle> for TabLabel {
+    fn from(handle: iced_native::svg::Handle) -> Self {
+        Self::Svg(handle)
+    }
+}
+
+impl<T: Into<String>> From<(iced_native::svg::Handle, T)> for TabLabel {
+    fn from((handle, text): (iced_native::svg::Handle, T)) -> Self {
+        Self::SvgText(handle, text.into())
+    }
+}
+
+impl From<char> for TabLabel {
+    fn from(icon: char) -> Self {
+        Self::Icon(icon)
+    }
+}
+
+impl From<Icon> for TabLabel {
+    fn from(icon: Icon) -> Self {
+        Self::Icon(icon.into())
+    }
+}
+
+impl<T: Into<String>> From<(char, T)> for TabLabel {
+    fn from((icon, text): (char, T)) -> Self {
+        Self::IconText(icon, text.into())
+    }
+}
+
+impl<T: Into<String>> From<(Icon, T)> for TabLabel {
+    fn from((icon, text): (Icon, T)) -> Self {
+        Self::IconText(icon.into(), text.into())
+    }
+}
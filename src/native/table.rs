@@ -0,0 +1,1664 @@
+//! Display rows of data in columns with a header.
+//!
+//! *This API requires the following crate features to be activated: table*
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::{tree, Operation, Tree},
+    Background, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::table::{Appearance, StyleSheet};
+
+/// The default padding around the content of a cell.
+const DEFAULT_PADDING: f32 = 8.0;
+
+/// The height of the line between the header and the rows.
+const DIVIDER_HEIGHT: f32 = 1.0;
+
+/// The smallest width a column can be resized to.
+const MIN_COLUMN_WIDTH: f32 = 24.0;
+
+/// The distance from the divider after a title within which it can be
+/// dragged.
+const DIVIDER_TOLERANCE: f32 = 4.0;
+
+/// The distance the cursor has to move before a pressed title is dragged.
+const DRAG_THRESHOLD: f32 = 8.0;
+
+/// The width of the line showing where a dragged title is dropped.
+const DROP_INDICATOR_WIDTH: f32 = 2.0;
+
+/// The height of the horizontal scrollbar.
+const SCROLLBAR_HEIGHT: f32 = 4.0;
+
+/// The gap between the horizontal scrollbar and the bottom of the table.
+const SCROLLBAR_MARGIN: f32 = 2.0;
+
+/// A column of a [`Table`](Table).
+#[derive(Clone, Debug)]
+pub struct Column {
+    /// The title shown in the header.
+    title: String,
+    /// The width of the column.
+    width: Length,
+    /// The horizontal alignment of the title and the cells.
+    align_x: Horizontal,
+    /// Whether pressing the title sorts the rows by the column.
+    sortable: bool,
+}
+
+impl Column {
+    /// Creates a new [`Column`](Column) with the given title, filling the
+    /// remaining width of the [`Table`](Table).
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            width: Length::Fill,
+            align_x: Horizontal::Left,
+            sortable: false,
+        }
+    }
+
+    /// Sets the width of the [`Column`](Column).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the title and the cells of the
+    /// [`Column`](Column).
+    #[must_use]
+    pub fn align_x(mut self, align_x: Horizontal) -> Self {
+        self.align_x = align_x;
+        self
+    }
+
+    /// Sets whether pressing the title of the [`Column`](Column) sorts the
+    /// rows by it.
+    #[must_use]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// The order the rows of a [`Table`](Table) are sorted in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// From the smallest to the largest value.
+    #[default]
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite [`SortOrder`](SortOrder).
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// A table showing rows of elements in columns below a header.
+///
+/// Pressing the title of a sortable column asks the application to sort the
+/// rows by it, in ascending order at first and in the reverse order when the
+/// rows are already sorted by the column. Pressing a row asks the application
+/// to select it.
+///
+/// Dragging the divider after a title asks the application to resize the
+/// column and dragging a title onto another one asks it to move the column
+/// there. Columns wider than the table scroll horizontally, except for the
+/// frozen leading columns.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::table;
+/// #
+/// # pub type Table<'a, Message> = table::Table<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Sort(usize, table::SortOrder),
+///     Select(usize),
+///     Resize(usize, f32),
+/// }
+///
+/// let table = Table::new(vec![
+///     table::Column::new("Name").sortable(true),
+///     table::Column::new("Age").width(60.0),
+/// ])
+/// .push(vec![Text::new("Alice"), Text::new("32")])
+/// .push(vec![Text::new("Bob"), Text::new("27")])
+/// .on_sort(Message::Sort)
+/// .on_select(Message::Select)
+/// .on_resize(Message::Resize)
+/// .frozen_columns(1);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The columns of the table.
+    columns: Vec<Column>,
+    /// The cells of the rows of the table.
+    rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    /// The column and the order the rows are sorted by.
+    sort: Option<(usize, SortOrder)>,
+    /// The function producing the message to sort the rows by a column.
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    /// The index of the selected row.
+    selected: Option<usize>,
+    /// The function producing the message to select the row at an index.
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function producing the message to change the width of a column.
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    /// The function producing the message to move a column to another index.
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    /// The number of leading columns that do not scroll horizontally.
+    frozen_columns: usize,
+    /// The width of the table.
+    width: Length,
+    /// The padding around the content of a cell.
+    padding: f32,
+    /// Whether every second row has a different background.
+    striped: bool,
+    /// The text size of the header.
+    text_size: Option<f32>,
+    /// The font of the header.
+    font: Font,
+    /// The style of the table.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Table`](Table) with the given [`Column`](Column)s and
+    /// no rows.
+    #[must_use]
+    pub fn new(columns: Vec<Column>) -> Self {
+        Table {
+            columns,
+            rows: Vec::new(),
+            sort: None,
+            on_sort: None,
+            selected: None,
+            on_select: None,
+            on_resize: None,
+            on_reorder: None,
+            frozen_columns: 0,
+            width: Length::Fill,
+            padding: DEFAULT_PADDING,
+            striped: true,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a row with a cell for each [`Column`](Column) to the
+    /// [`Table`](Table).
+    ///
+    /// Cells beyond the number of columns are dropped.
+    #[must_use]
+    pub fn push<E>(mut self, row: impl IntoIterator<Item = E>) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.rows.push(
+            row.into_iter()
+                .take(self.columns.len())
+                .map(Into::into)
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets the column and the [`SortOrder`](SortOrder) the rows are sorted
+    /// by, showing an indicator in the header.
+    #[must_use]
+    pub fn sorted_by(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the function that will be called when the title of a sortable
+    /// [`Column`](Column) is pressed.
+    #[must_use]
+    pub fn on_sort<F>(mut self, on_sort: F) -> Self
+    where
+        F: 'a + Fn(usize, SortOrder) -> Message,
+    {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the index of the selected row.
+    #[must_use]
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the function that will be called when a row is pressed.
+    ///
+    /// The rows are only highlighted while hovered if it is set.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function that will be called with the index and the new
+    /// width of a [`Column`](Column) while the divider after its title is
+    /// dragged.
+    ///
+    /// The dividers can only be dragged if it is set.
+    #[must_use]
+    pub fn on_resize<F>(mut self, on_resize: F) -> Self
+    where
+        F: 'a + Fn(usize, f32) -> Message,
+    {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the function that will be called with the current and the new
+    /// index of a [`Column`](Column) when its title is dropped onto another
+    /// one.
+    ///
+    /// The titles can only be dragged if it is set.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the number of leading [`Column`](Column)s that stay in place
+    /// while the others scroll horizontally.
+    #[must_use]
+    pub fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Sets the width of the [`Table`](Table).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the content of a cell.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets whether every second row has a different background.
+    #[must_use]
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Sets the text size of the header.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the header.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Table`](Table).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the width of the column at the index fitting its title and
+    /// its widest cell.
+    fn shrink_width(&self, renderer: &Renderer, index: usize, text_size: f32) -> f32 {
+        let column = &self.columns[index];
+        let (title_width, _) = renderer.measure(
+            &column.title,
+            text_size,
+            self.font,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        );
+        let indicator = if column.sortable { text_size } else { 0.0 };
+        let limits = Limits::new(Size::ZERO, Size::new(f32::INFINITY, f32::INFINITY));
+
+        self.rows
+            .iter()
+            .filter_map(|row| row.get(index))
+            .map(|cell| cell.as_widget().layout(renderer, &limits).size().width)
+            .fold(title_width + indicator, f32::max)
+            + 2.0 * self.padding
+    }
+
+    /// Returns the background and the text color of the row at the index.
+    fn row_colors(
+        &self,
+        index: usize,
+        bounds: Rectangle,
+        cursor_position: Point,
+        appearance: &Appearance,
+    ) -> (Option<Background>, Color) {
+        if self.selected == Some(index) {
+            (
+                Some(appearance.selected_background),
+                appearance.selected_text_color,
+            )
+        } else if self.on_select.is_some() && bounds.contains(cursor_position) {
+            (
+                Some(appearance.hovered_background),
+                appearance.hovered_text_color,
+            )
+        } else if self.striped && index % 2 == 1 {
+            (Some(appearance.stripe_background), appearance.text_color)
+        } else {
+            (None, appearance.text_color)
+        }
+    }
+
+    /// Returns the index of the column whose divider is under the cursor.
+    fn divider_at(
+        &self,
+        header: Layout<'_>,
+        scroll: &Scroll,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if self.on_resize.is_none() || !header.bounds().contains(cursor_position) {
+            return None;
+        }
+
+        header
+            .children()
+            .enumerate()
+            .map(|(index, title)| (index, scroll.bounds(index, title.bounds())))
+            .filter(|(index, bounds)| scroll.is_visible(*index, bounds.x + bounds.width))
+            .find(|(_, bounds)| {
+                (bounds.x + bounds.width - cursor_position.x).abs() <= DIVIDER_TOLERANCE
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Draws the titles and the cells of either the frozen or the scrolled
+    /// columns.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_columns(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        appearance: &Appearance,
+        scroll: &Scroll,
+        frozen: bool,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children
+            .next()
+            .expect("Graphics: Layout should have a header layout for a Table");
+
+        for ((index, column), title) in self.columns.iter().enumerate().zip(header.children()) {
+            if scroll.is_frozen(index) != frozen {
+                continue;
+            }
+
+            let title_bounds = title.bounds();
+            let indicator = if column.sortable { text_size } else { 0.0 };
+            let x = match column.align_x {
+                Horizontal::Left => title_bounds.x + self.padding,
+                Horizontal::Center => title_bounds.center_x() - indicator / 2.0,
+                Horizontal::Right => title_bounds.x + title_bounds.width - self.padding - indicator,
+            };
+
+            renderer.fill_text(text::Text {
+                content: &column.title,
+                bounds: Rectangle {
+                    x,
+                    y: title_bounds.center_y(),
+                    ..title_bounds
+                },
+                size: text_size,
+                color: appearance.header_text_color,
+                font: self.font,
+                horizontal_alignment: column.align_x,
+                vertical_alignment: Vertical::Center,
+            });
+
+            let icon = match self.sort {
+                Some((sorted, SortOrder::Ascending)) if sorted == index => Icon::CaretUpFill,
+                Some((sorted, SortOrder::Descending)) if sorted == index => Icon::CaretDownFill,
+                _ => continue,
+            };
+
+            renderer.fill_glyph(
+                char::from(icon),
+                icon.font(),
+                Rectangle {
+                    x: title_bounds.x + title_bounds.width - self.padding - indicator,
+                    y: title_bounds.center_y() - indicator / 2.0,
+                    width: indicator,
+                    height: indicator,
+                },
+                text_size * 0.75,
+                appearance.header_text_color,
+            );
+        }
+
+        let mut cells = self.rows.iter().flatten().zip(&state.children);
+
+        for ((index, row), row_layout) in self.rows.iter().enumerate().zip(children) {
+            let row_bounds = row_layout.bounds();
+            let row_cells = cells.by_ref().take(row.len());
+
+            if row_bounds.intersection(viewport).is_none() {
+                let _ = row_cells.count();
+                continue;
+            }
+
+            let (_, text_color) =
+                self.row_colors(index, clip(row_bounds, bounds), cursor_position, appearance);
+
+            for (column, ((cell, state), layout)) in
+                row_cells.zip(row_layout.children()).enumerate()
+            {
+                if scroll.is_frozen(column) != frozen {
+                    continue;
+                }
+
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    layout,
+                    scroll.cursor(column, cursor_position),
+                    viewport,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Table<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows.iter().flatten().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let cells: Vec<_> = self.rows.iter().flatten().collect();
+        tree.diff_children(&cells);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let padding = self.padding;
+        let max_width = limits.max().width;
+
+        // Fixed and shrinking columns are measured first, the filling columns
+        // share the remaining width. Without a bound they shrink as well.
+        let mut widths: Vec<f32> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| match column.width {
+                Length::Fixed(width) => width,
+                Length::Fill | Length::FillPortion(_) if max_width.is_finite() => 0.0,
+                _ => self.shrink_width(renderer, index, text_size),
+            })
+            .collect();
+
+        if max_width.is_finite() {
+            let portions: u16 = self
+                .columns
+                .iter()
+                .map(|column| column.width.fill_factor())
+                .sum();
+            let available = (max_width - widths.iter().sum::<f32>()).max(0.0);
+
+            // Filling columns never vanish, the table scrolls instead
+            for (width, column) in widths.iter_mut().zip(&self.columns) {
+                let factor = column.width.fill_factor();
+
+                if factor > 0 {
+                    *width =
+                        (available * f32::from(factor) / f32::from(portions)).max(MIN_COLUMN_WIDTH);
+                }
+            }
+        }
+
+        let table_width: f32 = widths.iter().sum();
+        let offsets: Vec<f32> = widths
+            .iter()
+            .scan(0.0, |offset, width| {
+                let x = *offset;
+                *offset += width;
+                Some(x)
+            })
+            .collect();
+
+        let header_height = text_size + 2.0 * padding;
+        let header = Node::with_children(
+            Size::new(table_width, header_height),
+            offsets
+                .iter()
+                .zip(&widths)
+                .map(|(&x, &width)| {
+                    let mut node = Node::new(Size::new(width, header_height));
+                    node.move_to(Point::new(x, 0.0));
+                    node
+                })
+                .collect(),
+        );
+
+        let mut nodes = Vec::with_capacity(self.rows.len() + 1);
+        nodes.push(header);
+        let mut y = header_height + DIVIDER_HEIGHT;
+
+        for row in &self.rows {
+            let mut cells: Vec<Node> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| {
+                    let inner_width = (width - 2.0 * padding).max(0.0);
+
+                    cell.as_widget().layout(
+                        renderer,
+                        &Limits::new(Size::ZERO, Size::new(inner_width, f32::INFINITY)),
+                    )
+                })
+                .collect();
+
+            let row_height = cells
+                .iter()
+                .map(|cell| cell.size().height)
+                .fold(text_size, f32::max)
+                + 2.0 * padding;
+
+            for (((cell, column), &x), &width) in cells
+                .iter_mut()
+                .zip(&self.columns)
+                .zip(&offsets)
+                .zip(&widths)
+            {
+                let size = cell.size();
+                let free = (width - 2.0 * padding - size.width).max(0.0);
+                let align = match column.align_x {
+                    Horizontal::Left => 0.0,
+                    Horizontal::Center => free / 2.0,
+                    Horizontal::Right => free,
+                };
+
+                cell.move_to(Point::new(
+                    x + padding + align,
+                    (row_height - size.height) / 2.0,
+                ));
+            }
+
+            let mut node = Node::with_children(Size::new(table_width, row_height), cells);
+            node.move_to(Point::new(0.0, y));
+            y += row_height;
+
+            nodes.push(node);
+        }
+
+        let size = limits.resolve(Size::new(table_width, y));
+
+        Node::with_children(size, nodes)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let header = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a header layout for a Table");
+        let table_state = state.state.downcast_mut::<State>();
+        let scroll = Scroll::new(header, bounds, self.frozen_columns, table_state.offset);
+        table_state.offset = scroll.offset;
+
+        // A dragged divider or title keeps the events until it is released
+        if let Some(drag) = &mut table_state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => match drag {
+                    Drag::Resize {
+                        column,
+                        origin,
+                        width,
+                    } => {
+                        if let Some(on_resize) = &self.on_resize {
+                            let width =
+                                (*width + cursor_position.x - *origin).max(MIN_COLUMN_WIDTH);
+                            shell.publish(on_resize(*column, width));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    Drag::Title { origin, moved, .. } => {
+                        if self.on_reorder.is_some()
+                            && (cursor_position.x - *origin).abs() > DRAG_THRESHOLD
+                        {
+                            *moved = true;
+                        }
+
+                        if *moved {
+                            return event::Status::Captured;
+                        }
+                    }
+                    Drag::Scrollbar { origin, offset } => {
+                        if let Some(track) = scroll.track(bounds) {
+                            table_state.offset =
+                                scroll.dragged(track, *offset, cursor_position.x - *origin);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                },
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    let drag = *drag;
+                    table_state.drag = None;
+
+                    match drag {
+                        Drag::Title {
+                            column,
+                            moved: true,
+                            ..
+                        } => {
+                            let target = scroll.column_at(header, cursor_position.x);
+
+                            if let (Some(on_reorder), Some(target)) = (&self.on_reorder, target) {
+                                if target != column {
+                                    shell.publish(on_reorder(column, target));
+                                }
+                            }
+                        }
+                        Drag::Title {
+                            column,
+                            moved: false,
+                            ..
+                        } => {
+                            let over_title = header.bounds().contains(cursor_position)
+                                && scroll.column_at(header, cursor_position.x) == Some(column);
+
+                            if let (Some(on_sort), true) = (&self.on_sort, over_title) {
+                                if self.columns[column].sortable {
+                                    let order = match self.sort {
+                                        Some((sorted, order)) if sorted == column => {
+                                            order.reverse()
+                                        }
+                                        _ => SortOrder::Ascending,
+                                    };
+
+                                    shell.publish(on_sort(column, order));
+                                }
+                            }
+                        }
+                        Drag::Resize { .. } | Drag::Scrollbar { .. } => {}
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        // The scrollbar is drawn above the last row and takes its presses
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some(track) = scroll
+                .track(bounds)
+                .filter(|track| track.contains(cursor_position))
+            {
+                // A press next to the thumb moves its center to the cursor
+                let thumb = scroll.thumb(track);
+                let offset = if thumb.contains(cursor_position) {
+                    scroll.offset
+                } else {
+                    scroll.dragged(track, scroll.offset, cursor_position.x - thumb.center_x())
+                };
+
+                table_state.offset = offset;
+                table_state.drag = Some(Drag::Scrollbar {
+                    origin: cursor_position.x,
+                    offset,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.iter_mut().enumerate())
+            .zip(&mut state.children)
+            .zip(layout.children().skip(1).flat_map(|row| row.children()))
+            .map(|(((column, cell), state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    scroll.cursor(column, cursor_position),
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let table_state = state.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(column) = self.divider_at(header, &scroll, cursor_position) {
+                    table_state.drag = Some(Drag::Resize {
+                        column,
+                        origin: cursor_position.x,
+                        width: header
+                            .children()
+                            .nth(column)
+                            .map_or(0.0, |title| title.bounds().width),
+                    });
+
+                    return event::Status::Captured;
+                }
+
+                if header.bounds().contains(cursor_position) {
+                    if let Some(column) = scroll.column_at(header, cursor_position.x) {
+                        if self.on_sort.is_some() || self.on_reorder.is_some() {
+                            table_state.drag = Some(Drag::Title {
+                                column,
+                                origin: cursor_position.x,
+                                moved: false,
+                            });
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+
+                if let Some(on_select) = &self.on_select {
+                    let pressed = layout
+                        .children()
+                        .skip(1)
+                        .position(|row| clip(row.bounds(), bounds).contains(cursor_position));
+
+                    if let Some(index) = pressed {
+                        shell.publish(on_select(index));
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if scroll.max > 0.0 && bounds.contains(cursor_position) =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+                // Vertical scrolling with Shift held scrolls horizontally
+                let delta_x = if table_state.modifiers.shift() { y } else { x };
+
+                if delta_x != 0.0 {
+                    table_state.offset = (scroll.offset - delta_x).clamp(0.0, scroll.max);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                table_state.modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children
+            .next()
+            .expect("Native: Layout should have a header layout for a Table");
+        let table_state = state.state.downcast_ref::<State>();
+        let scroll = Scroll::new(header, bounds, self.frozen_columns, table_state.offset);
+
+        match table_state.drag {
+            Some(Drag::Resize { .. }) => return mouse::Interaction::ResizingHorizontally,
+            Some(Drag::Title { moved: true, .. }) => return mouse::Interaction::Grabbing,
+            _ => {}
+        }
+
+        if self.divider_at(header, &scroll, cursor_position).is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let interaction = self
+            .rows
+            .iter()
+            .flat_map(|row| row.iter().enumerate())
+            .zip(&state.children)
+            .zip(layout.children().skip(1).flat_map(|row| row.children()))
+            .map(|(((column, cell), state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    scroll.cursor(column, cursor_position),
+                    viewport,
+                    renderer,
+                )
+            })
+            .fold(mouse::Interaction::default(), mouse::Interaction::max);
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        let over_title = header.bounds().contains(cursor_position)
+            && scroll
+                .column_at(header, cursor_position.x)
+                .map_or(false, |column| {
+                    self.on_reorder.is_some()
+                        || (self.on_sort.is_some() && self.columns[column].sortable)
+                });
+        let over_row = self.on_select.is_some()
+            && children.any(|row| clip(row.bounds(), bounds).contains(cursor_position));
+
+        if over_title || over_row {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let table_state = state.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                table_state.drag.is_some(),
+                false,
+                false,
+            ),
+            self.style,
+        );
+        let [top_left, top_right, _, _]: [f32; 4] = appearance.border_radius.into();
+
+        let mut children = layout.children();
+        let header = children
+            .next()
+            .expect("Graphics: Layout should have a header layout for a Table");
+        let header_bounds = clip(header.bounds(), bounds);
+        let scroll = Scroll::new(header, bounds, self.frozen_columns, table_state.offset);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header_bounds,
+                border_radius: [top_left, top_right, 0.0, 0.0].into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.header_background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: header_bounds.y + header_bounds.height,
+                    height: DIVIDER_HEIGHT,
+                    ..header_bounds
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.divider_color,
+        );
+
+        for (index, row_layout) in children.enumerate() {
+            let row_bounds = clip(row_layout.bounds(), bounds);
+
+            if row_bounds.intersection(viewport).is_none() {
+                continue;
+            }
+
+            if let (Some(background), _) =
+                self.row_colors(index, row_bounds, cursor_position, &appearance)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+        }
+
+        // The scrolled columns are clipped to the space right of the frozen
+        // ones, which are drawn on top
+        renderer.with_layer(scroll.clip(bounds), |renderer| {
+            renderer.with_translation(Vector::new(-scroll.offset, 0.0), |renderer| {
+                self.draw_columns(
+                    state,
+                    renderer,
+                    theme,
+                    layout,
+                    cursor_position,
+                    &(*viewport + Vector::new(scroll.offset, 0.0)),
+                    &appearance,
+                    &scroll,
+                    false,
+                );
+            });
+        });
+
+        self.draw_columns(
+            state,
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            viewport,
+            &appearance,
+            &scroll,
+            true,
+        );
+
+        if self.frozen_columns > 0 && scroll.offset > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: scroll.edge - DIVIDER_HEIGHT / 2.0,
+                        width: DIVIDER_HEIGHT,
+                        ..bounds
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.divider_color,
+            );
+        }
+
+        // The gap the dragged title is dropped into
+        if let Some(Drag::Title {
+            column,
+            moved: true,
+            ..
+        }) = table_state.drag
+        {
+            let target = scroll
+                .column_at(header, cursor_position.x)
+                .and_then(|target| Some((target, header.children().nth(target)?.bounds())));
+
+            if let Some((target, title_bounds)) = target {
+                let title_bounds = scroll.bounds(target, title_bounds);
+                let x = if target > column {
+                    title_bounds.x + title_bounds.width
+                } else {
+                    title_bounds.x
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x - DROP_INDICATOR_WIDTH / 2.0,
+                            width: DROP_INDICATOR_WIDTH,
+                            ..bounds
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.header_text_color,
+                );
+            }
+        }
+
+        if let Some(track) = scroll.track(bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scroll.thumb(track),
+                    border_radius: (SCROLLBAR_HEIGHT / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.divider_color,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        for ((cell, state), layout) in self
+            .rows
+            .iter()
+            .flatten()
+            .zip(&mut state.children)
+            .zip(layout.children().skip(1).flat_map(|row| row.children()))
+        {
+            cell.as_widget().operate(state, layout, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let header = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a header layout for a Table");
+        let scroll = Scroll::new(
+            header,
+            layout.bounds(),
+            self.frozen_columns,
+            state.state.downcast_ref::<State>().offset,
+        );
+
+        let overlays: Vec<_> = self
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.iter_mut().enumerate())
+            .zip(&mut state.children)
+            .zip(layout.children().skip(1).flat_map(|row| row.children()))
+            .filter_map(|(((column, cell), state), layout)| {
+                let overlay = cell.as_widget_mut().overlay(state, layout, renderer)?;
+
+                Some(if scroll.is_frozen(column) {
+                    overlay
+                } else {
+                    overlay.translate(Vector::new(-scroll.offset, 0.0))
+                })
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// The state of a [`Table`](Table).
+#[derive(Debug, Default)]
+struct State {
+    /// The distance the scrolled columns are moved to the left.
+    offset: f32,
+    /// The divider, title or scrollbar that is dragged.
+    drag: Option<Drag>,
+    /// The pressed keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+}
+
+/// A divider, title or the scrollbar of a [`Table`](Table) that is dragged.
+#[derive(Clone, Copy, Debug)]
+enum Drag {
+    /// The divider after the title of a column is dragged.
+    Resize {
+        /// The index of the column.
+        column: usize,
+        /// The x coordinate of the cursor when the drag started.
+        origin: f32,
+        /// The width of the column when the drag started.
+        width: f32,
+    },
+    /// The title of a column is pressed and maybe dragged.
+    Title {
+        /// The index of the column.
+        column: usize,
+        /// The x coordinate of the cursor when the title was pressed.
+        origin: f32,
+        /// Whether the title was dragged further than a press.
+        moved: bool,
+    },
+    /// The thumb of the horizontal scrollbar is dragged.
+    Scrollbar {
+        /// The x coordinate of the cursor when the drag started.
+        origin: f32,
+        /// The offset of the scrolled columns when the drag started.
+        offset: f32,
+    },
+}
+
+/// The horizontal scrolling of the columns of a [`Table`](Table) after the
+/// frozen ones.
+#[derive(Clone, Copy, Debug)]
+struct Scroll {
+    /// The number of frozen columns.
+    frozen: usize,
+    /// The x coordinate where the scrolled columns start.
+    edge: f32,
+    /// The distance the scrolled columns are moved to the left.
+    offset: f32,
+    /// The largest distance the scrolled columns can be moved.
+    max: f32,
+}
+
+impl Scroll {
+    /// Creates the [`Scroll`](Scroll) of a table with the given header
+    /// layout and bounds, clamping the offset.
+    fn new(header: Layout<'_>, bounds: Rectangle, frozen: usize, offset: f32) -> Self {
+        let edge = header
+            .children()
+            .take(frozen)
+            .last()
+            .map_or(bounds.x, |title| {
+                let title = title.bounds();
+                title.x + title.width
+            });
+        let max = (header.bounds().width - bounds.width).max(0.0);
+
+        Self {
+            frozen,
+            edge,
+            offset: offset.clamp(0.0, max),
+            max,
+        }
+    }
+
+    /// Returns true if the column at the index does not scroll.
+    const fn is_frozen(&self, column: usize) -> bool {
+        column < self.frozen
+    }
+
+    /// Returns true if the given x coordinate of the column at the index is
+    /// not hidden behind the frozen columns.
+    fn is_visible(&self, column: usize, x: f32) -> bool {
+        self.is_frozen(column) || x >= self.edge
+    }
+
+    /// Returns the bounds of the column at the index where it is shown.
+    fn bounds(&self, column: usize, bounds: Rectangle) -> Rectangle {
+        if self.is_frozen(column) {
+            bounds
+        } else {
+            bounds + Vector::new(-self.offset, 0.0)
+        }
+    }
+
+    /// Returns the cursor position in the unscrolled space of the column at
+    /// the index.
+    fn cursor(&self, column: usize, cursor_position: Point) -> Point {
+        if self.is_frozen(column) {
+            cursor_position
+        } else if cursor_position.x < self.edge {
+            // The cursor is over the frozen columns
+            Point::new(-1.0, -1.0)
+        } else {
+            cursor_position + Vector::new(self.offset, 0.0)
+        }
+    }
+
+    /// Returns the space the scrolled columns are shown in.
+    fn clip(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: self.edge,
+            width: (bounds.x + bounds.width - self.edge).max(0.0),
+            ..bounds
+        }
+    }
+
+    /// Returns the track of the horizontal scrollbar below the scrolled
+    /// columns, if they can be scrolled.
+    fn track(&self, bounds: Rectangle) -> Option<Rectangle> {
+        (self.max > 0.0).then(|| Rectangle {
+            y: bounds.y + bounds.height - SCROLLBAR_HEIGHT - 2.0 * SCROLLBAR_MARGIN,
+            height: SCROLLBAR_HEIGHT + 2.0 * SCROLLBAR_MARGIN,
+            ..self.clip(bounds)
+        })
+    }
+
+    /// Returns the thumb of the horizontal scrollbar in the track.
+    fn thumb(&self, track: Rectangle) -> Rectangle {
+        let width = track.width * track.width / (track.width + self.max);
+
+        Rectangle {
+            x: track.x + (track.width - width) * self.offset / self.max,
+            y: track.y + SCROLLBAR_MARGIN,
+            width,
+            height: SCROLLBAR_HEIGHT,
+        }
+    }
+
+    /// Returns the offset after the thumb in the track is moved by the
+    /// distance from where it is at the given offset.
+    fn dragged(&self, track: Rectangle, offset: f32, distance: f32) -> f32 {
+        let free = track.width - self.thumb(track).width;
+
+        if free > 0.0 {
+            (offset + distance * self.max / free).clamp(0.0, self.max)
+        } else {
+            offset
+        }
+    }
+
+    /// Returns the index of the column shown at the given x coordinate.
+    fn column_at(&self, header: Layout<'_>, x: f32) -> Option<usize> {
+        header
+            .children()
+            .enumerate()
+            .map(|(index, title)| (index, self.bounds(index, title.bounds())))
+            .find(|(index, bounds)| {
+                self.is_visible(*index, x) && x >= bounds.x && x < bounds.x + bounds.width
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+/// Returns the bounds cut off at the right edge of the table.
+fn clip(bounds: Rectangle, table: Rectangle) -> Rectangle {
+    Rectangle {
+        width: bounds.width.min(table.x + table.width - bounds.x).max(0.0),
+        ..bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        event, keyboard, mouse, renderer::Null, widget::Text, Event, Length, Point, Size,
+    };
+
+    use super::{Column, Scroll, SortOrder, Table, MIN_COLUMN_WIDTH};
+    use crate::testing::Harness;
+
+    /// The size of the simulated window.
+    const SIZE: Size = Size::new(400.0, 300.0);
+
+    /// The y coordinate of the center of the header.
+    const TITLE_Y: f32 = 18.0;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        Sort(usize, SortOrder),
+        Select(usize),
+        Resize(usize, f32),
+        Reorder(usize, usize),
+    }
+
+    /// Creates a table 250 wide with two rows and four columns 100 wide, all
+    /// of them sortable except for the second one.
+    fn table() -> Table<'static, Message, Null> {
+        let columns = (0..4)
+            .map(|index| {
+                Column::new(format!("Column {index}"))
+                    .width(Length::Fixed(100.0))
+                    .sortable(index != 1)
+            })
+            .collect();
+
+        Table::new(columns)
+            .push((0..4).map(|index| Text::new(format!("A{index}"))))
+            .push((0..4).map(|index| Text::new(format!("B{index}"))))
+            .width(Length::Fixed(250.0))
+            .padding(8.0)
+            .text_size(20.0)
+            .on_sort(Message::Sort)
+            .on_select(Message::Select)
+            .on_resize(Message::Resize)
+            .on_reorder(Message::Reorder)
+    }
+
+    /// Scrolls the wheel by the pixels over the header.
+    fn wheel(harness: &mut Harness<'_, Message>, x: f32, y: f32) -> event::Status {
+        let _ = harness.move_cursor(Point::new(150.0, TITLE_Y));
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x, y },
+        }))
+    }
+
+    /// Presses the left mouse button at the given position.
+    fn press(harness: &mut Harness<'_, Message>, position: Point) -> event::Status {
+        let _ = harness.move_cursor(position);
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )))
+    }
+
+    /// Releases the left mouse button at the given position.
+    fn release(harness: &mut Harness<'_, Message>, position: Point) -> event::Status {
+        let _ = harness.move_cursor(position);
+        harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )))
+    }
+
+    #[test]
+    fn sort_by_the_pressed_title() {
+        let mut harness = Harness::new(table(), SIZE);
+
+        let _ = harness.click(Point::new(220.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Sort(2, SortOrder::Ascending)]
+        );
+
+        // The column is not sortable
+        let _ = harness.click(Point::new(150.0, TITLE_Y));
+        assert!(harness.messages().is_empty());
+
+        let mut harness = Harness::new(table().sorted_by(2, SortOrder::Ascending), SIZE);
+
+        let _ = harness.click(Point::new(220.0, TITLE_Y));
+        let _ = harness.click(Point::new(50.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![
+                Message::Sort(2, SortOrder::Descending),
+                Message::Sort(0, SortOrder::Ascending),
+            ]
+        );
+
+        // A press released outside of the title does not sort
+        let _ = press(&mut harness, Point::new(50.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(50.0, 100.0));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn select_the_pressed_row() {
+        let mut harness = Harness::new(table(), SIZE);
+        let first = harness
+            .bounds_at(&[1])
+            .expect("The table should have a first row");
+        let second = harness
+            .bounds_at(&[2])
+            .expect("The table should have a second row");
+
+        let _ = harness.click(Point::new(200.0, second.center_y()));
+        let _ = harness.click(Point::new(20.0, first.center_y()));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Select(1), Message::Select(0)]
+        );
+
+        // The rows are cut off at the right edge of the table
+        let _ = harness.click(Point::new(300.0, first.center_y()));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn resize_by_dragging_the_divider() {
+        let mut harness = Harness::new(table(), SIZE);
+
+        assert_eq!(
+            press(&mut harness, Point::new(101.0, TITLE_Y)),
+            event::Status::Captured
+        );
+        let _ = harness.move_cursor(Point::new(131.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(0.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(0.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(131.0, TITLE_Y));
+
+        assert_eq!(
+            harness.take_messages(),
+            vec![
+                Message::Resize(0, 130.0),
+                Message::Resize(0, MIN_COLUMN_WIDTH),
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_or_reorder_by_the_distance_of_the_drag() {
+        let mut harness = Harness::new(table(), SIZE);
+
+        // Moving less than the threshold still sorts
+        let _ = press(&mut harness, Point::new(50.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(55.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(55.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Sort(0, SortOrder::Ascending)]
+        );
+
+        let _ = press(&mut harness, Point::new(50.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(230.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(230.0, TITLE_Y));
+        let _ = press(&mut harness, Point::new(150.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(20.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(20.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Reorder(0, 2), Message::Reorder(1, 0)]
+        );
+
+        // Dropping a title onto itself does nothing
+        let _ = press(&mut harness, Point::new(50.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(90.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(90.0, TITLE_Y));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn hit_the_frozen_and_the_scrolled_columns() {
+        let harness = Harness::new(table(), SIZE);
+        let header = harness
+            .layout()
+            .children()
+            .next()
+            .expect("The table should have a header");
+
+        assert_eq!(
+            Scroll::new(header, harness.bounds(), 1, 500.0).offset,
+            150.0
+        );
+
+        let scroll = Scroll::new(header, harness.bounds(), 1, 50.0);
+        assert_eq!(scroll.column_at(header, 60.0), Some(0));
+        assert_eq!(scroll.column_at(header, 120.0), Some(1));
+        assert_eq!(scroll.column_at(header, 160.0), Some(2));
+        assert_eq!(scroll.column_at(header, 260.0), Some(3));
+
+        let point = Point::new(60.0, TITLE_Y);
+        assert_eq!(scroll.cursor(0, point), point);
+        assert_eq!(scroll.cursor(1, point), Point::new(-1.0, -1.0));
+        assert_eq!(
+            scroll.cursor(1, Point::new(120.0, TITLE_Y)),
+            Point::new(170.0, TITLE_Y)
+        );
+
+        let mut harness = Harness::new(table().frozen_columns(1), SIZE);
+
+        assert_eq!(wheel(&mut harness, -50.0, 0.0), event::Status::Captured);
+        let _ = harness.click(Point::new(60.0, TITLE_Y));
+        let _ = harness.click(Point::new(160.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![
+                Message::Sort(0, SortOrder::Ascending),
+                Message::Sort(2, SortOrder::Ascending),
+            ]
+        );
+
+        // The second column now ends at 80, its divider is hidden behind the
+        // frozen column and the press drags the title of the frozen one
+        assert_eq!(wheel(&mut harness, -70.0, 0.0), event::Status::Captured);
+        let _ = press(&mut harness, Point::new(81.0, TITLE_Y));
+        let _ = harness.move_cursor(Point::new(111.0, TITLE_Y));
+        let _ = release(&mut harness, Point::new(111.0, TITLE_Y));
+        assert_eq!(harness.take_messages(), vec![Message::Reorder(0, 2)]);
+    }
+
+    #[test]
+    fn scroll_with_shift_and_the_vertical_wheel() {
+        let mut harness = Harness::new(table().frozen_columns(1), SIZE);
+
+        assert_eq!(wheel(&mut harness, 0.0, -150.0), event::Status::Ignored);
+
+        let _ = harness.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            keyboard::Modifiers::SHIFT,
+        )));
+        assert_eq!(wheel(&mut harness, 0.0, -150.0), event::Status::Captured);
+
+        let _ = harness.click(Point::new(160.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Sort(3, SortOrder::Ascending)]
+        );
+    }
+
+    #[test]
+    fn drag_the_scrollbar() {
+        let mut harness = Harness::new(table(), SIZE);
+        let bounds = harness.bounds();
+        let y = bounds.y + bounds.height - 4.0;
+
+        // The thumb is 156.25 wide and can move by 93.75
+        assert_eq!(
+            press(&mut harness, Point::new(50.0, y)),
+            event::Status::Captured
+        );
+        let _ = harness.move_cursor(Point::new(143.75, y));
+        let _ = release(&mut harness, Point::new(143.75, y));
+        assert!(harness.messages().is_empty());
+
+        let _ = harness.click(Point::new(60.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Sort(2, SortOrder::Ascending)]
+        );
+
+        // A press next to the thumb moves it under the cursor
+        let _ = harness.click(Point::new(10.0, y));
+        let _ = harness.click(Point::new(60.0, TITLE_Y));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Sort(0, SortOrder::Ascending)]
+        );
+    }
+}
@@ -8,14 +8,32 @@
 use iced_native::{
     event,
     layout::{Limits, Node},
-    mouse, Clipboard, Event, Font, Layout, Length, Point, Rectangle, Shell, Size,
+    mouse,
+    time::Instant,
+    Clipboard, Event, Font, Layout, Length, Point, Rectangle, Shell, Size,
 };
 use iced_native::{
-    widget::{Operation, Row, Tree},
+    widget::{
+        tree::{self, Tag},
+        Id, Operation, Row, Tree,
+    },
     Element, Widget,
 };
 
-use crate::{native::tab_bar::TabBar, style::tab_bar::StyleSheet, TabLabel};
+use crate::{
+    core::{
+        breakpoint::Breakpoint,
+        gesture::{Gesture, Recognizer, Swipe},
+        renderer::IconRenderer,
+    },
+    graphics::icons::Icon,
+    native::tab_bar::TabBar,
+    style::tab_bar::StyleSheet,
+    TabLabel,
+};
+
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{self, Role};
 
 pub mod tab_bar_position;
 pub use tab_bar_position::TabBarPosition;
@@ -73,7 +91,7 @@ where
 
 impl<'a, Message, TabId, Renderer> Tabs<'a, Message, TabId, Renderer>
 where
-    Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: 'a + IconRenderer,
     Renderer::Theme: StyleSheet + iced_style::text::StyleSheet,
     TabId: Eq + Clone,
 {
@@ -179,6 +197,17 @@ where
         self
     }
 
+    /// Adapts the [`TabBar`](super::tab_bar::TabBar) to the
+    /// [`Breakpoint`](Breakpoint) of the layout.
+    ///
+    /// On [`Compact`](Breakpoint::Compact) layouts, labels with an icon and a
+    /// text only show their icon.
+    #[must_use]
+    pub fn breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.tab_bar = self.tab_bar.breakpoint(breakpoint);
+        self
+    }
+
     /// Sets the icon size of the [`TabLabel`](super::tab_bar::TabLabel) of the
     /// [`TabBar`](super::tab_bar::TabBar).
     #[must_use]
@@ -204,6 +233,15 @@ where
         self
     }
 
+    /// Sets the [`Icon`](Icon) drawn as the close icon of the
+    /// [`TabLabel`](super::tab_bar::TabLabel)s of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    #[must_use]
+    pub fn close_icon(mut self, close_icon: Icon) -> Self {
+        self.tab_bar = self.tab_bar.close_icon(close_icon);
+        self
+    }
+
     /// Sets the padding of the tabs of the [`TabBar`](super::tab_bar::TabBar).
     #[must_use]
     pub fn tab_label_padding(mut self, padding: f32) -> Self {
@@ -237,6 +275,15 @@ where
         self
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`TabBar`](super::tab_bar::TabBar) to focus it with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn tab_bar_id(mut self, id: Id) -> Self {
+        self.tab_bar = self.tab_bar.id(id);
+        self
+    }
+
     /// Sets the style of the [`TabBar`](super::tab_bar::TabBar).
     #[must_use]
     pub fn tab_bar_style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
@@ -271,19 +318,60 @@ where
         self.tab_bar = self.tab_bar.set_active_tab(id);
         self
     }
+
+    /// Describes the [`Tabs`](Tabs) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, state: &Tree) -> accessibility::Node {
+        let tab_list = self
+            .tab_bar
+            .accessibility(&state.state.downcast_ref::<State>().tab_bar);
+        let panel = accessibility::Node::new(Role::TabPanel);
+        let panel = match tab_list
+            .children
+            .get(self.tab_bar.get_active_tab_idx())
+            .and_then(|tab| tab.name.clone())
+        {
+            Some(name) => panel.name(name),
+            None => panel,
+        };
+
+        let node = accessibility::Node::new(Role::Group);
+        match self.tab_bar_position {
+            TabBarPosition::Top => node.push(tab_list).push(panel),
+            TabBarPosition::Bottom => node.push(panel).push(tab_list),
+        }
+    }
 }
 
 impl<'a, Message, TabId, Renderer> Widget<Message, Renderer> for Tabs<'a, Message, TabId, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: IconRenderer,
     Renderer::Theme: StyleSheet + iced_style::text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            tab_bar: Tree::new(&self.tab_bar as &dyn Widget<Message, Renderer>),
+            gestures: Recognizer::new(),
+        })
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.tabs.iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
+        // The tab bar keeps the layout of its tabs in its state
+        tree.state
+            .downcast_mut::<State>()
+            .tab_bar
+            .diff(&self.tab_bar as &dyn Widget<Message, Renderer>);
         tree.diff_children(&self.tabs);
     }
 
@@ -381,8 +469,24 @@ where
             }
         };
 
+        let tabs_state: &mut State = state.state.downcast_mut();
+
+        // Swiping the content changes to the next or previous tab
+        let gesture =
+            tabs_state
+                .gestures
+                .on_event(&event, tab_content_layout.bounds(), Instant::now());
+        let active_tab = self.tab_bar.get_active_tab_idx();
+        let swiped = match gesture {
+            Some(Gesture::Swipe(Swipe::Left)) => self.tab_bar.select(active_tab + 1),
+            Some(Gesture::Swipe(Swipe::Right)) => active_tab
+                .checked_sub(1)
+                .and_then(|index| self.tab_bar.select(index)),
+            _ => None,
+        };
+
         let status_tab_bar = self.tab_bar.on_event(
-            &mut Tree::empty(),
+            &mut tabs_state.tab_bar,
             event.clone(),
             tab_bar_layout,
             cursor_position,
@@ -406,6 +510,11 @@ where
                 )
             });
 
+        if let Some(message) = swiped {
+            shell.publish(message);
+            return event::Status::Captured;
+        }
+
         status_tab_bar.merge(status_element)
     }
 
@@ -430,7 +539,7 @@ where
 
         let mut mouse_interaction = mouse::Interaction::default();
         let new_mouse_interaction = self.tab_bar.mouse_interaction(
-            &Tree::empty(),
+            &state.state.downcast_ref::<State>().tab_bar,
             tab_bar_layout,
             cursor_position,
             viewport,
@@ -490,7 +599,7 @@ where
         };
 
         self.tab_bar.draw(
-            &Tree::empty(),
+            &state.state.downcast_ref::<State>().tab_bar,
             renderer,
             theme,
             style,
@@ -551,6 +660,18 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        let tab_bar_layout = match self.tab_bar_position {
+            TabBarPosition::Top => layout.children().next(),
+            TabBarPosition::Bottom => layout.children().nth(1),
+        }
+        .expect("Native: There should be a TabBar at the top or bottom");
+        self.tab_bar.operate(
+            &mut tree.state.downcast_mut::<State>().tab_bar,
+            tab_bar_layout,
+            renderer,
+            operation,
+        );
+
         let active_tab = self.tab_bar.get_active_tab_idx();
         operation.container(None, &mut |operation| {
             self.tabs[active_tab].as_widget().operate(
@@ -566,10 +687,18 @@ where
     }
 }
 
+/// The state of a [`Tabs`](Tabs) widget.
+struct State {
+    /// The state of the tab bar, which keeps its focus.
+    tab_bar: Tree,
+    /// The recognizer of the swipes changing the tab.
+    gestures: Recognizer,
+}
+
 impl<'a, Message, TabId, Renderer> From<Tabs<'a, Message, TabId, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer<Font = iced_native::Font>,
+    Renderer: 'a + IconRenderer,
     Renderer::Theme: StyleSheet + iced_style::text::StyleSheet,
     Message: 'a,
     TabId: 'a + Eq + Clone,
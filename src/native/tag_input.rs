@@ -0,0 +1,695 @@
+//! Type tags that turn into removable chips.
+//!
+//! *This API requires the following crate features to be activated: tag_input*
+use std::rc::Rc;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, overlay, renderer, text, touch,
+    widget::{
+        text_input,
+        tree::{self, Tree},
+        Operation, TextInput,
+    },
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    core::{renderer::GlyphRenderer, sizing::hit_target},
+    graphics::icons::Icon,
+    native::{
+        combo_box::{self, matches, Mode},
+        overlay::ComboBoxOverlay,
+    },
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::tag_input::{Appearance, StyleSheet};
+
+/// The padding between the border and the content of a chip.
+const CHIP_PADDING: f32 = 8.0;
+
+/// The default spacing between two chips.
+const DEFAULT_SPACING: f32 = 4.0;
+
+/// The narrowest the field is next to the chips before it moves to a row of
+/// its own.
+const MIN_INPUT_WIDTH: f32 = 80.0;
+
+/// The default number of suggestions shown at once in the dropdown.
+const DEFAULT_VISIBLE_SUGGESTIONS: usize = 6;
+
+/// A text input turning the typed text into removable chips.
+///
+/// Enter or a comma adds the typed text as a tag, unless it is empty, already
+/// a tag, rejected by the [`validate`](TagInput::validate) function or there
+/// are [`max_tags`](TagInput::max_tags) already. Backspace in the empty field
+/// removes the last tag, as does pressing the remove icon of a chip.
+///
+/// Optional suggestions are listed below the field like the options of a
+/// [`ComboBox`](crate::native::combo_box::ComboBox), picking one adds it as a
+/// tag.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::tag_input;
+/// #
+/// # pub type TagInput<'a, Message> = tag_input::TagInput<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Typed(String),
+///     Tagged(Vec<String>),
+/// }
+///
+/// let tags = vec![String::from("rust"), String::from("gui")];
+///
+/// let tag_input = TagInput::new(&tags, "Add a tag", "", Message::Typed, Message::Tagged)
+///     .max_tags(5)
+///     .validate(|tag| !tag.contains(' '));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TagInput<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    /// The tags shown as chips.
+    tags: &'a [String],
+    /// The text in the field.
+    value: String,
+    /// The function producing the message when the text changes.
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    /// The function producing the message with the updated tags.
+    on_change: Rc<dyn Fn(Vec<String>) -> Message + 'a>,
+    /// The function producing the message adding a picked suggestion.
+    on_select: Box<dyn Fn(String) -> Message + 'a>,
+    /// The function deciding whether a tag is valid.
+    validate: Option<Box<dyn Fn(&str) -> bool + 'a>>,
+    /// The most tags there may be.
+    max_tags: Option<usize>,
+    /// The suggested tags.
+    suggestions: &'a [String],
+    /// The number of suggestions shown at once in the dropdown.
+    visible_suggestions: usize,
+    /// The field the text is typed into.
+    content: TextInput<'a, Message, Renderer>,
+    /// The width of the tag input.
+    width: Length,
+    /// The spacing between two chips.
+    spacing: f32,
+    /// The text size of the chips and of the suggestions.
+    text_size: Option<f32>,
+    /// The font of the chips and of the suggestions.
+    font: Font,
+    /// The style of the chips.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The style of the dropdown of the suggestions.
+    dropdown_style: <Renderer::Theme as combo_box::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TagInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`TagInput`](TagInput).
+    ///
+    /// It expects:
+    ///     * the tags shown as chips
+    ///     * the placeholder shown while the field is empty
+    ///     * the text in the field
+    ///     * the function producing the message when the text changes
+    ///     * the function producing the message with the updated tags
+    pub fn new<F, C>(
+        tags: &'a [String],
+        placeholder: &str,
+        value: &str,
+        on_input: F,
+        on_change: C,
+    ) -> Self
+    where
+        F: 'a + Fn(String) -> Message + Clone,
+        C: 'a + Fn(Vec<String>) -> Message,
+    {
+        let on_change: Rc<dyn Fn(Vec<String>) -> Message + 'a> = Rc::new(on_change);
+        let on_select = {
+            let on_change = Rc::clone(&on_change);
+
+            move |tag| {
+                let mut tags = tags.to_vec();
+                tags.push(tag);
+                on_change(tags)
+            }
+        };
+
+        TagInput {
+            tags,
+            value: value.to_owned(),
+            on_input: Box::new(on_input.clone()),
+            on_change,
+            on_select: Box::new(on_select),
+            validate: None,
+            max_tags: None,
+            suggestions: &[],
+            visible_suggestions: DEFAULT_VISIBLE_SUGGESTIONS,
+            content: TextInput::new(placeholder, value)
+                .on_input(on_input)
+                .width(Length::Fill),
+            width: Length::Fill,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            dropdown_style: <Renderer::Theme as combo_box::StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function deciding whether a tag is valid.
+    ///
+    /// Typed text that is not valid is not added. Tags that are not valid are
+    /// shown in a different color.
+    #[must_use]
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: 'a + Fn(&str) -> bool,
+    {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Sets the most tags there may be.
+    #[must_use]
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = Some(max_tags);
+        self
+    }
+
+    /// Sets the suggested tags listed below the field.
+    ///
+    /// Only the suggestions containing the typed text and that could be added
+    /// are listed.
+    #[must_use]
+    pub fn suggestions(mut self, suggestions: &'a [String]) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Sets the number of suggestions shown at once in the dropdown.
+    #[must_use]
+    pub fn visible_suggestions(mut self, visible_suggestions: usize) -> Self {
+        self.visible_suggestions = visible_suggestions.max(1);
+        self
+    }
+
+    /// Sets the width of the [`TagInput`](TagInput).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between two chips.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the field of the [`TagInput`](TagInput).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.content = self.content.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`TagInput`](TagInput).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self.content = self.content.size(text_size);
+        self
+    }
+
+    /// Sets the font of the [`TagInput`](TagInput).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self.content = self.content.font(font);
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the [`TagInput`](TagInput) to focus
+    /// it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.content = self.content.id(id);
+        self
+    }
+
+    /// Sets the style of the chips of the [`TagInput`](TagInput).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the dropdown of the suggestions.
+    #[must_use]
+    pub fn dropdown_style(
+        mut self,
+        style: <Renderer::Theme as combo_box::StyleSheet>::Style,
+    ) -> Self {
+        self.dropdown_style = style;
+        self
+    }
+
+    /// Sets the style of the field of the [`TagInput`](TagInput).
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.content = self.content.style(style);
+        self
+    }
+
+    /// Returns whether the tag is valid.
+    fn is_valid(&self, tag: &str) -> bool {
+        self.validate
+            .as_ref()
+            .map_or(true, |validate| validate(tag))
+    }
+
+    /// Returns whether the tag could be added.
+    fn accepts(&self, tag: &str) -> bool {
+        self.max_tags.map_or(true, |max| self.tags.len() < max)
+            && !self.tags.iter().any(|existing| existing == tag)
+            && self.is_valid(tag)
+    }
+
+    /// Adds the typed text as a tag and empties the field, if the text could
+    /// be added.
+    fn commit(&self, shell: &mut Shell<'_, Message>) -> bool {
+        let tag = self.value.trim();
+
+        if tag.is_empty() || !self.accepts(tag) {
+            return false;
+        }
+
+        let mut tags = self.tags.to_vec();
+        tags.push(tag.to_owned());
+
+        shell.publish((self.on_change)(tags));
+        shell.publish((self.on_input)(String::new()));
+
+        true
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TagInput<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree {
+            tag: self.content.tag(),
+            state: self.content.state(),
+            children: self.content.children(),
+        }]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &[&self.content],
+            |state, content| content.diff(state),
+            |&content| Tree {
+                tag: content.tag(),
+                state: content.state(),
+                children: content.children(),
+            },
+        );
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        // The chips are as high as the field to line up with it
+        let row_height = self
+            .content
+            .layout(renderer, &Limits::new(Size::ZERO, limits.max()))
+            .size()
+            .height;
+
+        let mut nodes = Vec::with_capacity(self.tags.len() + 1);
+        let mut position = Point::ORIGIN;
+
+        for tag in self.tags {
+            let width =
+                renderer.measure_width(tag, text_size, self.font) + text_size + 3.0 * CHIP_PADDING;
+            let width = width.min(max_width);
+
+            if position.x > 0.0 && position.x + width > max_width {
+                position = Point::new(0.0, position.y + row_height + self.spacing);
+            }
+
+            let mut node = Node::new(Size::new(width, row_height));
+            node.move_to(position);
+            nodes.push(node);
+
+            position.x += width + self.spacing;
+        }
+
+        // The field takes the rest of the last row or a row of its own
+        if position.x > 0.0 && max_width - position.x < MIN_INPUT_WIDTH {
+            position = Point::new(0.0, position.y + row_height + self.spacing);
+        }
+
+        let mut input = self.content.layout(
+            renderer,
+            &Limits::new(
+                Size::ZERO,
+                Size::new(max_width - position.x, limits.max().height),
+            ),
+        );
+        input.move_to(position);
+        nodes.push(input);
+
+        let size = limits.resolve(Size::new(max_width, position.y + row_height));
+        Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let input_layout = layout
+            .children()
+            .last()
+            .expect("Native: Layout should have an input layout for a TagInput");
+
+        self.content
+            .operate(&mut tree.children[0], input_layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let state = tree.state.downcast_mut::<State>();
+        state.suggestions.sync(&self.value, Mode::Free);
+
+        let child = &mut tree.children[0];
+        let was_focused = child.state.downcast_ref::<text_input::State>().is_focused();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let removed = layout.children().take(self.tags.len()).position(|chip| {
+                    hit_target(remove_bounds(chip.bounds(), text_size)).contains(cursor_position)
+                });
+
+                if let Some(index) = removed {
+                    let mut tags = self.tags.to_vec();
+                    let _ = tags.remove(index);
+                    shell.publish((self.on_change)(tags));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(',')) if was_focused => {
+                let _ = self.commit(shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter,
+                ..
+            }) if was_focused => {
+                if self.commit(shell) {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Backspace,
+                ..
+            }) if was_focused && self.value.is_empty() => {
+                if let Some((_, tags)) = self.tags.split_last() {
+                    shell.publish((self.on_change)(tags.to_vec()));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        let input_layout = layout
+            .children()
+            .last()
+            .expect("Native: Layout should have an input layout for a TagInput");
+
+        let status = self.content.on_event(
+            child,
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if !was_focused && child.state.downcast_ref::<text_input::State>().is_focused() {
+            state.suggestions.dismissed = false;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let mut children = layout.children();
+
+        let over_remove = children.by_ref().take(self.tags.len()).any(|chip| {
+            hit_target(remove_bounds(chip.bounds(), text_size)).contains(cursor_position)
+        });
+
+        if over_remove {
+            return mouse::Interaction::Pointer;
+        }
+
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have an input layout for a TagInput");
+
+        self.content.mouse_interaction(
+            &tree.children[0],
+            input_layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let mut children = layout.children();
+
+        for (tag, chip_layout) in self.tags.iter().zip(children.by_ref()) {
+            let bounds = chip_layout.bounds();
+            let remove_bounds = remove_bounds(bounds, text_size);
+            let appearance = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(
+                    hit_target(remove_bounds).contains(cursor_position),
+                    false,
+                    false,
+                    false,
+                ),
+                self.style,
+            );
+            let (background, text_color) = if self.is_valid(tag) {
+                (appearance.background, appearance.text_color)
+            } else {
+                (appearance.invalid_background, appearance.invalid_text_color)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                background,
+            );
+
+            let label_bounds = Rectangle {
+                x: bounds.x + CHIP_PADDING,
+                width: (remove_bounds.x - bounds.x - 2.0 * CHIP_PADDING).max(0.0),
+                ..bounds
+            };
+
+            renderer.with_layer(label_bounds, |renderer| {
+                renderer.fill_text(text::Text {
+                    content: tag,
+                    bounds: Rectangle {
+                        y: label_bounds.center_y(),
+                        ..label_bounds
+                    },
+                    size: text_size,
+                    color: text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            });
+
+            renderer.fill_glyph(
+                char::from(Icon::X),
+                Icon::X.font(),
+                remove_bounds,
+                text_size,
+                appearance.icon_color,
+            );
+        }
+
+        let input_layout = children
+            .next()
+            .expect("Graphics: Layout should have an input layout for a TagInput");
+
+        self.content.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            input_layout,
+            cursor_position,
+            None,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+        let state = tree.state.downcast_mut::<State>();
+        state.suggestions.sync(&self.value, Mode::Free);
+
+        if !focused || state.suggestions.dismissed {
+            return None;
+        }
+
+        let matches: Vec<_> = matches(self.suggestions, &self.value)
+            .into_iter()
+            .filter(|suggestion| self.accepts(&suggestion.label))
+            .collect();
+        if matches.is_empty() {
+            return None;
+        }
+
+        let input_bounds = layout
+            .children()
+            .last()
+            .expect("Native: Layout should have an input layout for a TagInput")
+            .bounds();
+
+        Some(
+            ComboBoxOverlay::new(
+                self.suggestions,
+                matches,
+                &mut state.suggestions,
+                self.on_select.as_ref(),
+                Some(self.on_input.as_ref()),
+                input_bounds.size(),
+                self.visible_suggestions,
+                self.text_size,
+                self.font,
+                self.dropdown_style,
+            )
+            .overlay(input_bounds.position()),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<TagInput<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + combo_box::StyleSheet + text_input::StyleSheet,
+{
+    fn from(tag_input: TagInput<'a, Message, Renderer>) -> Self {
+        Element::new(tag_input)
+    }
+}
+
+/// The state of a [`TagInput`](TagInput).
+#[derive(Debug, Default)]
+struct State {
+    /// The state of the dropdown of the suggestions.
+    suggestions: combo_box::State,
+}
+
+/// Returns the bounds of the remove icon of the chip with the bounds.
+fn remove_bounds(chip: Rectangle, text_size: f32) -> Rectangle {
+    Rectangle {
+        x: chip.x + chip.width - CHIP_PADDING - text_size,
+        y: chip.center_y() - text_size / 2.0,
+        width: text_size,
+        height: text_size,
+    }
+}
@@ -0,0 +1,741 @@
+//! Select a value on a slider with tick marks and labels.
+//!
+//! *This API requires the following crate features to be activated: tick_slider*
+use std::{fmt::Display, ops::RangeInclusive};
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use num_traits::FromPrimitive;
+
+use crate::{
+    core::snap::snap,
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::tick_slider::{Appearance, StyleSheet};
+
+/// The default height of the rail of a [`TickSlider`](TickSlider).
+const DEFAULT_HEIGHT: f32 = 22.0;
+
+/// The number of steps the handle is moved by a press of Page Up or Page
+/// Down.
+const PAGE_STEPS: f64 = 10.0;
+
+/// The length of a minor tick mark.
+const TICK_LENGTH: f32 = 4.0;
+
+/// The length of a major tick mark.
+const MAJOR_TICK_LENGTH: f32 = 8.0;
+
+/// The gap between the major tick marks and their labels.
+const LABEL_GAP: f32 = 2.0;
+
+/// The padding around the text of the value bubble.
+const BUBBLE_PADDING: f32 = 4.0;
+
+/// The gap between the value bubble and the handle.
+const BUBBLE_GAP: f32 = 2.0;
+
+/// The largest number of tick marks drawn, denser ticks are left out.
+const MAX_TICKS: usize = 1000;
+
+/// A slider selecting a value, with a tick mark at each step and labels under
+/// the major tick marks.
+///
+/// The value snaps to the ticks unless snapping is turned off. While the
+/// handle is dragged, a bubble above it shows the value. The handle can be
+/// focused and moved with the arrow keys by a step, with Page Up and Page
+/// Down by ten steps and with Home and End to the bounds of the range.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::tick_slider;
+/// #
+/// # pub type TickSlider<'a, T, Message> = tick_slider::TickSlider<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     VolumeChanged(u8),
+/// }
+///
+/// let volume = 40;
+///
+/// let tick_slider = TickSlider::new(0..=100, volume, Message::VolumeChanged)
+///     .step(10)
+///     .major_ticks(5)
+///     .labels(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TickSlider<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`TickSlider`](TickSlider) to focus its handle.
+    id: Option<Id>,
+    /// The range of the values.
+    range: RangeInclusive<T>,
+    /// The selected value.
+    value: T,
+    /// The distance between two tick marks.
+    step: T,
+    /// Every how many tick marks one is major, besides the first and the
+    /// last one.
+    major: usize,
+    /// Whether the major tick marks are labelled.
+    labels: bool,
+    /// Whether the value snaps to the tick marks.
+    snap: bool,
+    /// Whether a bubble shows the value while dragging.
+    bubble: bool,
+    /// The function formatting the values of the labels and the bubble.
+    format: Box<dyn Fn(T) -> String + 'a>,
+    /// The function producing the message when the handle is moved.
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    /// The message sent when the handle is released.
+    on_release: Option<Message>,
+    /// The width of the slider.
+    width: Length,
+    /// The height of the rail.
+    height: f32,
+    /// The text size of the labels and the bubble.
+    text_size: Option<f32>,
+    /// The font of the labels and the bubble.
+    font: Font,
+    /// The style of the slider.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> TickSlider<'a, T, Message, Renderer>
+where
+    T: 'a + Copy + From<u8> + PartialOrd + Display,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TickSlider`](TickSlider).
+    ///
+    /// It expects:
+    ///     * the inclusive range of the values.
+    ///     * the selected value.
+    ///     * the function that will be called with the new value when the
+    ///         handle is moved.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        TickSlider {
+            id: None,
+            range,
+            value,
+            step: T::from(1),
+            major: 0,
+            labels: false,
+            snap: true,
+            bubble: true,
+            format: Box::new(|value: T| value.to_string()),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`TickSlider`](TickSlider) to focus its handle with
+    /// [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the distance between two tick marks, which is also the step of
+    /// the keyboard.
+    #[must_use]
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Makes every n-th tick mark a major one. The first and the last tick
+    /// marks are always major.
+    #[must_use]
+    pub fn major_ticks(mut self, every: usize) -> Self {
+        self.major = every;
+        self
+    }
+
+    /// Sets whether the major tick marks are labelled with their value.
+    #[must_use]
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets whether the value snaps to the tick marks while dragging.
+    #[must_use]
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Sets whether a bubble above the handle shows the value while
+    /// dragging.
+    #[must_use]
+    pub fn bubble(mut self, bubble: bool) -> Self {
+        self.bubble = bubble;
+        self
+    }
+
+    /// Sets the function formatting the values of the labels and the bubble.
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(T) -> String,
+    {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the message sent when the handle is released.
+    #[must_use]
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`TickSlider`](TickSlider).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the rail of the [`TickSlider`](TickSlider).
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the labels and the bubble.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the labels and the bubble.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TickSlider`](TickSlider).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> TickSlider<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the smallest and the largest value of the range.
+    fn bounds(&self) -> (f64, f64) {
+        ((*self.range.start()).into(), (*self.range.end()).into())
+    }
+
+    /// Returns the value of the range nearest to the value, snapped to the
+    /// tick marks if snapping is on.
+    fn snap_to(&self, value: f64, snapping: bool) -> T {
+        let (min, max) = self.bounds();
+        let step = if snapping { self.step.into() } else { 0.0 };
+
+        T::from_f64(snap(value, min, max, step)).unwrap_or(*self.range.start())
+    }
+
+    /// Returns the value at the horizontal position.
+    fn value_at(&self, bounds: Rectangle, x: f32) -> T {
+        let (start, width) = rail(bounds, self.height);
+        let (min, max) = self.bounds();
+        let ratio = f64::from(((x - start) / width).clamp(0.0, 1.0));
+
+        self.snap_to(min + ratio * (max - min), self.snap)
+    }
+
+    /// Returns the horizontal position of the value on the rail.
+    fn position(&self, bounds: Rectangle, value: f64) -> f32 {
+        let (start, width) = rail(bounds, self.height);
+        let (min, max) = self.bounds();
+        let ratio = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let ratio = ratio as f32;
+
+        start + ratio * width
+    }
+
+    /// Returns the height of the bubble above the rail, if it is shown.
+    fn bubble_height(&self, text_size: f32) -> f32 {
+        if self.bubble {
+            text_size + 2.0 * BUBBLE_PADDING + BUBBLE_GAP
+        } else {
+            0.0
+        }
+    }
+
+    /// Publishes the value if it changed.
+    fn change(&self, value: T, shell: &mut Shell<'_, Message>) {
+        if value != self.value {
+            shell.publish((self.on_change)(value));
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for TickSlider<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let labels = if self.labels {
+            LABEL_GAP + text_size
+        } else {
+            0.0
+        };
+        let height = self.bubble_height(text_size) + self.height + MAJOR_TICK_LENGTH + labels;
+        let limits = limits.width(self.width).height(Length::Fixed(height));
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let slider_state: &mut State = state.state.downcast_mut();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                let bubble_height = self.bubble_height(text_size);
+
+                // The space kept free for the bubble doesn't take presses
+                let slider_bounds = Rectangle {
+                    y: bounds.y + bubble_height,
+                    height: bounds.height - bubble_height,
+                    ..bounds
+                };
+
+                if !slider_bounds.contains(cursor_position) {
+                    slider_state.focus.press(false);
+                    return event::Status::Ignored;
+                }
+
+                slider_state.dragging = true;
+                slider_state.focus.press(true);
+                self.change(self.value_at(bounds, cursor_position.x), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if !slider_state.dragging {
+                    return event::Status::Ignored;
+                }
+
+                self.change(self.value_at(bounds, position.x), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if !slider_state.dragging {
+                    return event::Status::Ignored;
+                }
+
+                slider_state.dragging = false;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                if !slider_state.focus.is_focused() {
+                    return event::Status::Ignored;
+                }
+
+                let value: f64 = self.value.into();
+                let step: f64 = self.step.into();
+                let value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                        self.snap_to(value - step, true)
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                        self.snap_to(value + step, true)
+                    }
+                    keyboard::KeyCode::PageDown => self.snap_to(value - PAGE_STEPS * step, true),
+                    keyboard::KeyCode::PageUp => self.snap_to(value + PAGE_STEPS * step, true),
+                    keyboard::KeyCode::Home => *self.range.start(),
+                    keyboard::KeyCode::End => *self.range.end(),
+                    _ => return event::Status::Ignored,
+                };
+
+                self.change(value, shell);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let slider_state: &State = state.state.downcast_ref();
+
+        if slider_state.dragging {
+            mouse::Interaction::Grabbing
+        } else if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let slider_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let appearance = theme.active(self.style);
+        let (start, width) = rail(bounds, self.height);
+        let center_y = bounds.y + self.bubble_height(text_size) + self.height / 2.0;
+        let ticks_y = center_y + self.height / 2.0;
+        let (min, max) = self.bounds();
+        let value: f64 = self.value.into();
+        let handle_x = self.position(bounds, value);
+
+        let rail_bounds = |x: f32, width: f32| Rectangle {
+            x,
+            y: center_y - appearance.rail_width / 2.0,
+            width,
+            height: appearance.rail_width,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail_bounds(start, width),
+                border_radius: (appearance.rail_width / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.rail_color,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail_bounds(start, handle_x - start),
+                border_radius: (appearance.rail_width / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.filled_color,
+        );
+
+        let ticks = ticks(min, max, self.step.into());
+
+        for (index, tick) in ticks.iter().enumerate() {
+            let x = self.position(bounds, *tick);
+            let major = is_major(index, ticks.len(), self.major);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - 0.5,
+                        y: ticks_y,
+                        width: 1.0,
+                        height: if major {
+                            MAJOR_TICK_LENGTH
+                        } else {
+                            TICK_LENGTH
+                        },
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.tick_color,
+            );
+
+            if major && self.labels {
+                let label = T::from_f64(*tick).map_or_else(String::new, &self.format);
+
+                renderer.fill_text(text::Text {
+                    content: &label,
+                    bounds: Rectangle {
+                        x,
+                        y: ticks_y + MAJOR_TICK_LENGTH + LABEL_GAP,
+                        width: bounds.width,
+                        height: text_size,
+                    },
+                    size: text_size,
+                    color: appearance.label_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Top,
+                });
+            }
+        }
+
+        let radius = appearance.handle_radius;
+        let handle_bounds = Rectangle {
+            x: handle_x - radius,
+            y: center_y - radius,
+            width: 2.0 * radius,
+            height: 2.0 * radius,
+        };
+        let handle = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                handle_bounds.contains(cursor_position),
+                slider_state.dragging,
+                slider_state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle_x - handle.handle_radius,
+                    y: center_y - handle.handle_radius,
+                    width: 2.0 * handle.handle_radius,
+                    height: 2.0 * handle.handle_radius,
+                },
+                border_radius: handle.handle_radius.into(),
+                border_width: handle.handle_border_width,
+                border_color: handle.handle_border_color,
+            },
+            handle.handle_color,
+        );
+
+        if self.bubble && slider_state.dragging {
+            let content = (self.format)(self.value);
+            let text_width = renderer.measure_width(&content, text_size, self.font);
+            let bubble_width = text_width + 2.0 * BUBBLE_PADDING;
+
+            // The bubble follows the handle but stays inside the slider
+            let x = (handle_x - bubble_width / 2.0)
+                .min(bounds.x + bounds.width - bubble_width)
+                .max(bounds.x);
+            let bubble_bounds = Rectangle {
+                x,
+                y: bounds.y,
+                width: bubble_width,
+                height: text_size + 2.0 * BUBBLE_PADDING,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bubble_bounds,
+                    border_radius: BUBBLE_PADDING.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.bubble_background,
+            );
+
+            renderer.fill_text(text::Text {
+                content: &content,
+                bounds: Rectangle {
+                    x: bubble_bounds.center_x(),
+                    y: bubble_bounds.center_y(),
+                    ..bubble_bounds
+                },
+                size: text_size,
+                color: appearance.bubble_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+            });
+        }
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let slider_state: &mut State = state.state.downcast_mut();
+
+        operation.focusable(&mut slider_state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, T, Message, Renderer> From<TickSlider<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tick_slider: TickSlider<'a, T, Message, Renderer>) -> Self {
+        Element::new(tick_slider)
+    }
+}
+
+/// The state of a [`TickSlider`](TickSlider).
+///
+/// The [`TickSlider`](TickSlider) keeps it in the widget tree, so it doesn't
+/// need to be held by the application.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// Whether the handle is dragged by the user.
+    dragging: bool,
+    /// The keyboard focus of the handle.
+    focus: Focus,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`TickSlider`](TickSlider).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            dragging: false,
+            focus: Focus::new(),
+        }
+    }
+}
+
+/// Returns the horizontal start and the width of the rail, inset by half the
+/// height of the rail so the handle stays inside the bounds.
+fn rail(bounds: Rectangle, height: f32) -> (f32, f32) {
+    let inset = height / 2.0;
+
+    (bounds.x + inset, (bounds.width - 2.0 * inset).max(1.0))
+}
+
+/// Returns the values of the tick marks from the minimum to the maximum, or
+/// none if there would be more than [`MAX_TICKS`](MAX_TICKS).
+fn ticks(min: f64, max: f64, step: f64) -> Vec<f64> {
+    if step <= 0.0 || max < min {
+        return Vec::new();
+    }
+
+    // The tolerance keeps the maximum when it is reached by rounded steps
+    let count = ((max - min) / step + 1e-9).floor();
+
+    if count >= MAX_TICKS as f64 {
+        return Vec::new();
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let count = count as usize;
+
+    (0..=count).map(|index| min + index as f64 * step).collect()
+}
+
+/// Returns true if the tick mark at the index is a major one.
+fn is_major(index: usize, count: usize, every: usize) -> bool {
+    index == 0 || index + 1 == count || (every > 0 && index % every == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_major, ticks};
+
+    #[test]
+    fn ticks_cover_the_range() {
+        assert_eq!(ticks(0.0, 10.0, 2.5), vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+        assert_eq!(ticks(0.0, 1.0, 0.1).len(), 11);
+        assert_eq!(ticks(0.0, 7.0, 2.0), vec![0.0, 2.0, 4.0, 6.0]);
+        assert!(ticks(0.0, 10.0, 0.0).is_empty());
+        assert!(ticks(0.0, 1_000_000.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn ends_and_every_nth_tick_are_major() {
+        let major: Vec<_> = (0..11).filter(|index| is_major(*index, 11, 5)).collect();
+        assert_eq!(major, vec![0, 5, 10]);
+
+        let major: Vec<_> = (0..4).filter(|index| is_major(*index, 4, 0)).collect();
+        assert_eq!(major, vec![0, 3]);
+    }
+}
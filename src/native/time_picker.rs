@@ -5,12 +5,15 @@ use chrono::Local;
 use iced_graphics::{Backend, Renderer};
 use iced_native::widget::button;
 use iced_native::widget::tree::{self, Tag};
-use iced_native::widget::Tree;
+use iced_native::widget::{Id, Operation, Tree};
 use iced_native::{event, mouse, Clipboard, Event, Layout, Point, Rectangle};
 use iced_native::{overlay, Shell};
 use iced_native::{Element, Widget};
 
+use super::operation::Request;
 use super::overlay::time_picker::{self, TimePickerOverlay, TimePickerOverlayButtons};
+#[cfg(feature = "accessibility")]
+use crate::core::accessibility::{Node, Role};
 
 pub use crate::core::time::{Period, Time};
 
@@ -55,7 +58,8 @@ where
     time: Time,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer<B, Theme>>,
-    /// The message that is send if the cancel button of the [`TimePickerOverlay`](TimePickerOverlay) is pressed.
+    /// The message that is send if the cancel button of the [`TimePickerOverlay`](TimePickerOverlay) is pressed
+    /// or the overlay is dismissed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`](TimePickerOverlay) is pressed.
     on_submit: Box<dyn Fn(Time) -> Message>,
@@ -63,6 +67,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Renderer<B, Theme>>,
+    /// The id of the [`TimePicker`](TimePicker) used to open it.
+    id: Option<Id>,
     /// Toggle the use of the 24h clock of the [`TimePickerOverlay`](TimePickerOverlay).
     use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`](TimePickerOverlay).
@@ -83,7 +89,7 @@ where
     ///     * the underlay [`Element`](iced_native::Element) on which this [`TimePicker`](TimePicker)
     ///         will be wrapped around.
     ///     * a message that will be send when the cancel button of the [`TimePicker`](TimePicker)
-    ///         is pressed.
+    ///         is pressed, the user clicks outside of it or presses the ESC key.
     ///     * a function that will be called when the submit button of the [`TimePicker`](TimePicker)
     ///         is pressed, which takes the picked [`Time`](crate::time_picker::Time) value.
     pub fn new<U, F>(
@@ -105,6 +111,7 @@ where
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: TimePickerOverlayButtons::default().into(),
+            id: None,
             use_24h: false,
             show_seconds: false,
         }
@@ -124,16 +131,40 @@ where
         self
     }
 
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`TimePicker`](TimePicker) to
+    /// open or close its overlay with the commands of
+    /// [`operation`](crate::native::operation).
+    ///
+    /// Closing only hides an overlay opened by such a command, not one shown
+    /// by the application.
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`TimePicker`](TimePicker).
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
         self.style = style;
         self
     }
+
+    /// Describes the [`TimePicker`](TimePicker) for assistive technologies with the state
+    /// of its widget tree.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub fn accessibility(&self, _state: &Tree) -> Node {
+        Node::new(Role::TimeInput)
+            .value(self.time.to_string())
+            .expanded(self.show_picker)
+    }
 }
 
 /// The state of the [`TimePicker`](TimePicker) / [`TimePickerOverlay`](TimePickerOverlay).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: time_picker::State,
@@ -266,6 +297,22 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<B, Theme>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let picker_state: &mut State = state.state.downcast_mut();
+        picker_state.overlay_state.is_opened = Request::receive(operation, self.id.as_ref())
+            .is_open(picker_state.overlay_state.is_opened);
+
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -274,7 +321,7 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer<B, Theme>>> {
         let picker_state: &mut State = state.state.downcast_mut();
 
-        if !self.show_picker {
+        if !self.show_picker && !picker_state.overlay_state.is_opened {
             return self
                 .underlay
                 .as_widget_mut()
@@ -0,0 +1,665 @@
+//! Show timed notifications stacked in a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: toast*
+use iced_native::{
+    event, mouse,
+    time::{Duration, Instant},
+    widget::{tree, Operation, Tree},
+    window, Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::overlay::{toast::ToastsOverlay, Layers};
+use crate::core::animation::{Animation, Easing};
+
+pub use crate::style::toast::{Appearance, Status, StyleSheet};
+
+/// The default time a [`Toast`](Toast) is shown before it is dismissed.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The default width of a [`Toast`](Toast).
+const DEFAULT_WIDTH: f32 = 300.0;
+
+/// The default padding between the border and the text of a [`Toast`](Toast).
+const DEFAULT_PADDING: f32 = 10.0;
+
+/// The default spacing between two [`Toast`](Toast)s.
+const DEFAULT_SPACING: f32 = 10.0;
+
+/// The default gap between the stack and the edges of the
+/// [`Toasts`](Toasts).
+const DEFAULT_MARGIN: f32 = 16.0;
+
+/// The time a [`Toast`](Toast) takes to slide in or out.
+const TRANSITION: Duration = Duration::from_millis(200);
+
+/// A timed notification shown by the [`Toasts`](Toasts).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Toast {
+    /// The [`Status`](Status) selecting the accent color of the toast.
+    pub status: Status,
+    /// The title of the toast.
+    pub title: String,
+    /// The body of the toast, hidden if it is empty.
+    pub body: String,
+}
+
+impl Toast {
+    /// Creates a new [`Toast`](Toast) of the [`Status`](Status) with a title
+    /// and a body.
+    pub fn new(status: Status, title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            title: title.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Creates a new [`Info`](Status::Info) [`Toast`](Toast).
+    pub fn info(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self::new(Status::Info, title, body)
+    }
+
+    /// Creates a new [`Success`](Status::Success) [`Toast`](Toast).
+    pub fn success(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self::new(Status::Success, title, body)
+    }
+
+    /// Creates a new [`Warning`](Status::Warning) [`Toast`](Toast).
+    pub fn warning(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self::new(Status::Warning, title, body)
+    }
+
+    /// Creates a new [`Error`](Status::Error) [`Toast`](Toast).
+    pub fn error(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self::new(Status::Error, title, body)
+    }
+}
+
+/// The corner of the [`Toasts`](Toasts) the notifications are stacked in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl Corner {
+    /// Returns true if the [`Corner`](Corner) is at the top.
+    #[must_use]
+    pub const fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopRight)
+    }
+
+    /// Returns true if the [`Corner`](Corner) is at the left.
+    #[must_use]
+    pub const fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// A manager showing [`Toast`](Toast)s on top of its content.
+///
+/// The toasts are stacked in a corner with the newest one closest to it. They
+/// slide in, count down their timeout while they are not hovered and slide
+/// out again when the timeout elapsed or their close button was pressed.
+/// Afterwards the manager asks the application to remove the toast at its
+/// index.
+///
+/// The state of a toast is kept for the toast itself rather than for its
+/// index, so toasts can be added and removed at any index. Equal toasts are
+/// told apart by their order.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::toast;
+/// #
+/// # pub type Toasts<'a, Message> = toast::Toasts<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Close(usize),
+/// }
+///
+/// let notifications = vec![toast::Toast::success("Saved", "The file was saved.")];
+///
+/// let toasts = Toasts::new(Text::new("Content"), &notifications, Message::Close)
+///     .corner(toast::Corner::TopRight);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toasts<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content the toasts are shown on top of.
+    content: Element<'a, Message, Renderer>,
+    /// The shown toasts.
+    toasts: &'a [Toast],
+    /// The function producing the message to remove the toast at an index.
+    on_close: Box<dyn Fn(usize) -> Message>,
+    /// The function producing the message of pressing the toast at an index.
+    on_press: Option<Box<dyn Fn(usize) -> Message>>,
+    /// The time a toast is shown before it is dismissed.
+    timeout: Duration,
+    /// The corner the toasts are stacked in.
+    corner: Corner,
+    /// The width of a toast.
+    width: f32,
+    /// The padding between the border and the text of a toast.
+    padding: f32,
+    /// The spacing between two toasts.
+    spacing: f32,
+    /// The gap between the stack and the edges of the content.
+    margin: f32,
+    /// The text size of the toasts.
+    text_size: Option<f32>,
+    /// The font of the toasts.
+    font: Font,
+    /// The style of the toasts.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Toasts<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Toasts`](Toasts) showing the toasts on top of the
+    /// content.
+    ///
+    /// It expects:
+    ///     * the content [`Element`](iced_native::Element) the toasts are shown
+    ///         on top of.
+    ///     * the [`Toast`](Toast)s to show.
+    ///     * the function that will be called to remove the toast at an index
+    ///         once it was dismissed.
+    pub fn new<C, F>(content: C, toasts: &'a [Toast], on_close: F) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        Toasts {
+            content: content.into(),
+            toasts,
+            on_close: Box::new(on_close),
+            on_press: None,
+            timeout: DEFAULT_TIMEOUT,
+            corner: Corner::default(),
+            width: DEFAULT_WIDTH,
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            margin: DEFAULT_MARGIN,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function that will be called when the toast at an index is
+    /// pressed.
+    #[must_use]
+    pub fn on_press<F>(mut self, on_press: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets the time a [`Toast`](Toast) is shown before it is dismissed.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the [`Corner`](Corner) the [`Toast`](Toast)s are stacked in.
+    #[must_use]
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the width of a [`Toast`](Toast).
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding between the border and the text of a
+    /// [`Toast`](Toast).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the spacing between two [`Toast`](Toast)s.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the gap between the stack of [`Toast`](Toast)s and the edges of
+    /// the content.
+    #[must_use]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the text size of the [`Toast`](Toast)s.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`Toast`](Toast)s.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Toast`](Toast)s.
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Toasts<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::default();
+        state.sync(self.toasts);
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+        tree.state.downcast_mut::<State>().sync(self.toasts);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let toasts = state.state.downcast_mut::<State>();
+        toasts.sync(self.toasts);
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let on_close = &self.on_close;
+
+            if toasts.tick(now, self.timeout, |index| shell.publish(on_close(index))) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        let content_overlay =
+            self.content
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+
+        if self.toasts.is_empty() {
+            return content_overlay;
+        }
+
+        let toasts = state.state.downcast_mut::<State>();
+        toasts.sync(self.toasts);
+
+        // The toasts stay on top of the overlays of the content
+        Layers::new()
+            .push_maybe(0, content_overlay)
+            .push(
+                1,
+                ToastsOverlay::new(
+                    self.toasts,
+                    toasts,
+                    self.on_press.as_deref(),
+                    layout.bounds().size(),
+                    self.timeout,
+                    self.corner,
+                    self.width,
+                    self.padding,
+                    self.spacing,
+                    self.margin,
+                    self.text_size,
+                    self.font,
+                    self.style,
+                )
+                .overlay(layout.position()),
+            )
+            .overlay()
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<Toasts<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(toasts: Toasts<'a, Message, Renderer>) -> Self {
+        Element::new(toasts)
+    }
+}
+
+/// The state of a [`Toasts`](Toasts).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The states of the shown toasts.
+    pub entries: Vec<Entry>,
+    /// The time of the last redraw.
+    pub now: Option<Instant>,
+}
+
+impl State {
+    /// Matches the entries to the toasts.
+    ///
+    /// Every toast keeps the first entry of an equal toast after the entry
+    /// of the previous toast. The entries skipped over belong to removed
+    /// toasts and are dropped, and new toasts get a new entry.
+    fn sync(&mut self, toasts: &[Toast]) {
+        let mut entries = std::mem::take(&mut self.entries);
+
+        self.entries = toasts
+            .iter()
+            .map(|toast| {
+                entries
+                    .iter()
+                    .position(|entry| entry.toast == *toast)
+                    .and_then(|position| entries.drain(..=position).next_back())
+                    .unwrap_or_else(|| Entry::new(toast.clone()))
+            })
+            .collect();
+    }
+
+    /// Advances the toasts to the time of a redraw.
+    ///
+    /// The indices of the toasts that finished sliding out after being
+    /// dismissed are handed to `closed` once, from the last to the first one,
+    /// so removing them one after another keeps the remaining indices valid.
+    /// Their entries stay hidden until the toasts are removed. Returns true
+    /// while toasts are shown and need the next frame.
+    fn tick<F>(&mut self, now: Instant, timeout: Duration, mut closed: F) -> bool
+    where
+        F: FnMut(usize),
+    {
+        let delta = self
+            .now
+            .map_or(Duration::ZERO, |last| now.saturating_duration_since(last));
+        self.now = Some(now);
+
+        for (index, entry) in self.entries.iter_mut().enumerate().rev() {
+            if entry.closing {
+                if !entry.closed && !entry.animation.is_running(now) {
+                    entry.closed = true;
+                    closed(index);
+                }
+                continue;
+            }
+
+            if !entry.shown {
+                entry.shown = true;
+                entry.animation.go(true, now);
+            } else if !entry.hovered {
+                entry.elapsed += delta;
+            }
+
+            if entry.elapsed >= timeout {
+                entry.close(now);
+            }
+        }
+
+        self.entries.iter().any(|entry| !entry.closed)
+    }
+}
+
+/// The state of a single shown [`Toast`](Toast).
+#[derive(Debug)]
+pub(crate) struct Entry {
+    /// The toast the entry belongs to.
+    toast: Toast,
+    /// The animation sliding the toast in and out.
+    animation: Animation,
+    /// Whether the toast started sliding in.
+    shown: bool,
+    /// The time the toast was shown while not being hovered.
+    elapsed: Duration,
+    /// Whether the toast slides out after being dismissed.
+    closing: bool,
+    /// Whether the toast slid out and its removal was requested.
+    closed: bool,
+    /// Whether the cursor is over the toast, pausing its timeout.
+    pub hovered: bool,
+}
+
+impl Entry {
+    /// Creates a new [`Entry`](Entry) of a toast that was not shown yet.
+    fn new(toast: Toast) -> Self {
+        Self {
+            toast,
+            animation: Animation::new(TRANSITION).easing(Easing::EaseOut),
+            shown: false,
+            elapsed: Duration::ZERO,
+            closing: false,
+            closed: false,
+            hovered: false,
+        }
+    }
+
+    /// Starts sliding the toast out.
+    pub fn close(&mut self, now: Instant) {
+        if !self.closing {
+            self.closing = true;
+            self.animation.go(false, now);
+        }
+    }
+
+    /// Returns true if the toast slides out after being dismissed.
+    pub const fn is_closing(&self) -> bool {
+        self.closing
+    }
+
+    /// Returns how far the toast slid in, between 0.0 and 1.0.
+    pub fn visibility(&self, now: Instant) -> f32 {
+        self.animation.value(now)
+    }
+
+    /// Returns the share of the timeout that is left, between 0.0 and 1.0.
+    pub fn remaining(&self, timeout: Duration) -> f32 {
+        if timeout.is_zero() {
+            return 0.0;
+        }
+
+        1.0 - (self.elapsed.as_secs_f32() / timeout.as_secs_f32()).min(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::time::{Duration, Instant};
+
+    use super::{State, Toast, TRANSITION};
+
+    #[test]
+    fn closes_after_timeout() {
+        let now = Instant::now();
+        let timeout = Duration::from_secs(1);
+        let toasts = [Toast::info("First", ""), Toast::info("Second", "")];
+        let mut state = State::default();
+        state.sync(&toasts);
+
+        let mut closed = Vec::new();
+        assert!(state.tick(now, timeout, |index| closed.push(index)));
+
+        // Hovering the second toast pauses its timeout
+        state.entries[1].hovered = true;
+        let expired = now + timeout;
+        assert!(state.tick(expired, timeout, |index| closed.push(index)));
+        assert!(closed.is_empty());
+        assert!(state.entries[0].closing);
+        assert!(!state.entries[1].closing);
+
+        let slid_out = expired + TRANSITION;
+        assert!(state.tick(slid_out, timeout, |index| closed.push(index)));
+        assert!(state.tick(slid_out, timeout, |index| closed.push(index)));
+        assert_eq!(closed, vec![0]);
+
+        // The entry of the closed toast is dropped once it was removed
+        state.sync(&toasts[1..]);
+        assert_eq!(state.entries.len(), 1);
+        assert!(state.entries[0].hovered);
+    }
+
+    #[test]
+    fn new_toasts_start_counting_when_shown() {
+        let now = Instant::now();
+        let timeout = Duration::from_secs(1);
+        let mut state = State::default();
+
+        assert!(!state.tick(now, timeout, |_| ()));
+
+        // A toast pushed long after the last redraw is not dismissed at once
+        state.sync(&[Toast::info("Saved", "")]);
+        let later = now + Duration::from_secs(60);
+        assert!(state.tick(later, timeout, |_| ()));
+        assert!(!state.entries[0].closing);
+        assert!(state.entries[0].remaining(timeout) > 0.99);
+    }
+
+    #[test]
+    fn keeps_the_state_of_the_toasts() {
+        let now = Instant::now();
+        let timeout = Duration::from_secs(1);
+        let first = Toast::info("First", "");
+        let second = Toast::warning("Second", "");
+        let third = Toast::error("Third", "");
+        let mut state = State::default();
+        state.sync(&[first.clone(), second.clone(), third.clone()]);
+        state.entries[2].close(now);
+
+        // Removing a toast in the middle and inserting one at the front
+        let inserted = Toast::success("Inserted", "");
+        state.sync(&[inserted, first, third]);
+        assert_eq!(state.entries.len(), 3);
+        assert!(!state.entries[0].shown);
+        assert!(!state.entries[1].closing);
+        assert!(state.entries[2].closing);
+        assert!(state.entries.iter().all(|entry| entry.toast != second));
+    }
+}
@@ -0,0 +1,460 @@
+//! Show rich content in a tooltip while hovering a widget.
+//!
+//! *This API requires the following crate features to be activated: tooltip*
+use iced_native::time::{Duration, Instant};
+
+use iced_native::{
+    event, mouse, touch,
+    widget::{tree, Operation, Tree},
+    window, Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::overlay::{tooltip::TooltipOverlay, Layers};
+use crate::style::style_state::appearance;
+
+pub use crate::{core::overlay::Placement, style::tooltip::StyleSheet};
+
+/// The default delay before a [`Tooltip`](Tooltip) is shown.
+const DEFAULT_SHOW_DELAY: Duration = Duration::from_millis(500);
+
+/// The default padding between the bubble and the content of a [`Tooltip`](Tooltip).
+const DEFAULT_PADDING: f32 = 8.0;
+
+/// The default gap between the anchor and the bubble of a [`Tooltip`](Tooltip).
+const DEFAULT_GAP: f32 = 4.0;
+
+/// A tooltip showing arbitrary content while the wrapped widget is hovered.
+///
+/// The tooltip is shown after a delay and placed at a side of the widget or
+/// of the cursor, flipping to the opposite side if it does not fit into the
+/// window. It stays open while it is hovered itself, so its content can be
+/// interactive.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::Text;
+/// # use iced_aw::native::tooltip;
+/// # use iced_native::time::Duration;
+/// #
+/// # pub type Tooltip<'a, Content, Message>
+/// #  = tooltip::Tooltip<'a, Content, Message, Null>;
+/// let tooltip = Tooltip::<_, ()>::new(
+///     Text::new("Hover me"),
+///     || Text::new("Some details").into()
+/// )
+/// .placement(tooltip::Placement::Bottom)
+/// .show_delay(Duration::from_millis(200))
+/// .follow_cursor(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The underlying element showing the tooltip when hovered.
+    underlay: Element<'a, Message, Renderer>,
+    /// The content of the [`TooltipOverlay`](TooltipOverlay).
+    content: Content,
+    /// The preferred side of the anchor the tooltip is placed at.
+    placement: Placement,
+    /// The alignment of the tooltip along the side of the anchor.
+    alignment: Alignment,
+    /// Whether the tooltip is anchored to the cursor instead of the underlay.
+    follow_cursor: bool,
+    /// The gap between the anchor and the tooltip.
+    gap: f32,
+    /// The padding between the bubble and its content.
+    padding: f32,
+    /// The delay between hovering the underlay and showing the tooltip.
+    show_delay: Duration,
+    /// The delay between leaving the underlay and hiding the tooltip.
+    hide_delay: Duration,
+    /// The style of the [`TooltipOverlay`](TooltipOverlay).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Content, Message, Renderer> Tooltip<'a, Content, Message, Renderer>
+where
+    Content: Fn() -> Element<'a, Message, Renderer>,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Tooltip`](Tooltip) wrapping the underlying element to
+    /// show some content while it is hovered.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`](iced_native::Element) showing the
+    ///         tooltip when hovered.
+    ///     * the content [`Element`](iced_native::Element) of the tooltip.
+    pub fn new<U>(underlay: U, content: Content) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        Tooltip {
+            underlay: underlay.into(),
+            content,
+            placement: Placement::Top,
+            alignment: Alignment::Center,
+            follow_cursor: false,
+            gap: DEFAULT_GAP,
+            padding: DEFAULT_PADDING,
+            show_delay: DEFAULT_SHOW_DELAY,
+            hide_delay: Duration::ZERO,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the preferred [`Placement`](Placement) of the [`Tooltip`](Tooltip).
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Alignment`](iced_native::Alignment) of the tooltip along
+    /// the side of the anchor.
+    #[must_use]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets whether the [`Tooltip`](Tooltip) follows the cursor instead of
+    /// being anchored to the underlay.
+    #[must_use]
+    pub fn follow_cursor(mut self, follow_cursor: bool) -> Self {
+        self.follow_cursor = follow_cursor;
+        self
+    }
+
+    /// Sets the gap between the anchor and the [`Tooltip`](Tooltip).
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding between the bubble and the content of the
+    /// [`Tooltip`](Tooltip).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the delay between hovering the underlay and showing the
+    /// [`Tooltip`](Tooltip).
+    #[must_use]
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.show_delay = delay;
+        self
+    }
+
+    /// Sets the delay between leaving the underlay and hiding the
+    /// [`Tooltip`](Tooltip).
+    ///
+    /// This gives the user the time to move the cursor into the tooltip to
+    /// interact with its content.
+    #[must_use]
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`](Tooltip).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Content, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Content, Message, Renderer>
+where
+    Content: 'a + Fn() -> Element<'a, Message, Renderer>,
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&(self.content)())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &(self.content)()]);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let tooltip = state.state.downcast_mut::<State>();
+        let over_underlay = layout.bounds().contains(cursor_position);
+
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => now,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if over_underlay =>
+            {
+                // Pressing the underlay hides the tooltip until it is hovered again
+                tooltip.press();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                Instant::now()
+            }
+            _ => Instant::now(),
+        };
+
+        if over_underlay {
+            tooltip.cursor_position = cursor_position;
+        }
+
+        if let Some(request) = tooltip.hover(
+            over_underlay || tooltip.over_tooltip,
+            now,
+            self.show_delay,
+            self.hide_delay,
+        ) {
+            shell.request_redraw(request);
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        let tooltip = state.state.downcast_mut::<State>();
+
+        if !tooltip.shown {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        let anchor = if self.follow_cursor {
+            Rectangle::new(tooltip.cursor_position, iced_native::Size::ZERO)
+        } else {
+            layout.bounds()
+        };
+
+        let content = (self.content)();
+        let style = self.style;
+        let (underlay_state, content_state) = state.children.split_at_mut(1);
+        content.as_widget().diff(&mut content_state[0]);
+
+        // The overlays of the underlay stay visible below the tooltip
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut underlay_state[0], layout, renderer),
+            )
+            .push(
+                1,
+                TooltipOverlay::new(
+                    &mut content_state[0],
+                    content,
+                    tooltip,
+                    anchor.size(),
+                    self.placement,
+                    self.alignment,
+                    self.gap,
+                    self.padding,
+                    f32::INFINITY,
+                    move |theme, state| appearance!(StyleSheet, theme, state, style),
+                )
+                .overlay(anchor.position()),
+            )
+            .overlay()
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Content, Message, Renderer> From<Tooltip<'a, Content, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Content: 'a + Fn() -> Element<'a, Message, Renderer>,
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tooltip: Tooltip<'a, Content, Message, Renderer>) -> Self {
+        Element::new(tooltip)
+    }
+}
+
+/// The state of a [`Tooltip`](Tooltip).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the tooltip is shown.
+    pub shown: bool,
+    /// Whether the cursor is over the shown tooltip.
+    pub over_tooltip: bool,
+    /// The last position of the cursor over the underlay.
+    pub cursor_position: Point,
+    /// The time the underlay is hovered since, while the tooltip is hidden.
+    hovered_since: Option<Instant>,
+    /// The time the cursor left the underlay and the tooltip, while the
+    /// tooltip is shown.
+    left_since: Option<Instant>,
+    /// Whether the underlay was pressed, keeping the tooltip hidden until the
+    /// cursor leaves it.
+    pressed: bool,
+}
+
+impl State {
+    /// Updates the visibility of the tooltip on hovering the underlay or the
+    /// tooltip, delaying it by the given delays.
+    ///
+    /// Returns the redraw needed to show a change or to apply a pending delay.
+    pub(crate) fn hover(
+        &mut self,
+        hovered: bool,
+        now: Instant,
+        show_delay: Duration,
+        hide_delay: Duration,
+    ) -> Option<window::RedrawRequest> {
+        if hovered {
+            self.left_since = None;
+
+            if self.shown || self.pressed {
+                return None;
+            }
+
+            let deadline = *self.hovered_since.get_or_insert(now) + show_delay;
+            if now < deadline {
+                return Some(window::RedrawRequest::At(deadline));
+            }
+
+            self.shown = true;
+            self.hovered_since = None;
+        } else {
+            self.hovered_since = None;
+            self.pressed = false;
+
+            if !self.shown {
+                return None;
+            }
+
+            let deadline = *self.left_since.get_or_insert(now) + hide_delay;
+            if now < deadline {
+                return Some(window::RedrawRequest::At(deadline));
+            }
+
+            self.hide();
+        }
+
+        Some(window::RedrawRequest::NextFrame)
+    }
+
+    /// Hides the tooltip after pressing the underlay.
+    pub(crate) fn press(&mut self) {
+        self.hide();
+        self.hovered_since = None;
+        self.pressed = true;
+    }
+
+    /// Hides the tooltip immediately.
+    fn hide(&mut self) {
+        self.shown = false;
+        self.over_tooltip = false;
+        self.left_since = None;
+    }
+}
@@ -0,0 +1,536 @@
+//! Guide the user through the widgets of an application with coach marks.
+//!
+//! *This API requires the following crate features to be activated: tour*
+use iced_native::{
+    event, mouse,
+    widget::{Id, Operation, Tree},
+    Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::{
+    floating_element::target::FindTarget,
+    overlay::{Layers, TourOverlay},
+};
+
+pub use crate::{
+    core::overlay::Placement,
+    native::floating_element::Target,
+    style::tour::{Appearance, StyleSheet},
+};
+
+/// The default padding between a target and the edge of its cutout.
+const DEFAULT_PADDING: f32 = 6.0;
+
+/// The default gap between the cutout and the card.
+const DEFAULT_GAP: f32 = 12.0;
+
+/// The default width of the card.
+const DEFAULT_CARD_WIDTH: f32 = 280.0;
+
+/// A step of a [`Tour`](Tour), explaining the widget marked as
+/// [`Target`](Target) with the [`Id`](Id).
+#[derive(Clone, Debug)]
+pub struct TourStep {
+    /// The id of the target of the step.
+    target: Id,
+    /// The title of the step.
+    pub(crate) title: String,
+    /// The explanation of the target.
+    pub(crate) description: String,
+    /// The preferred side of the target the card is placed at.
+    pub(crate) placement: Placement,
+}
+
+impl TourStep {
+    /// Creates a new [`TourStep`](TourStep) highlighting the
+    /// [`Target`](Target) with the [`Id`](Id).
+    pub fn new(target: Id, title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            target,
+            title: title.into(),
+            description: description.into(),
+            placement: Placement::default(),
+        }
+    }
+
+    /// Sets the preferred [`Placement`](Placement) of the card at the target.
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+/// An onboarding tour highlighting the widgets of the wrapped content one
+/// step after another.
+///
+/// The widgets are marked by wrapping them in a [`Target`](Target) with the
+/// [`Id`](Id) of their step. While a step is shown, everything but its target
+/// is dimmed and a card next to the target explains it, with buttons to go
+/// back, to go on and to skip the tour. The target itself stays usable. A step
+/// whose target is not part of the content shows its card in the center.
+///
+/// The application keeps the index of the current step and changes it on the
+/// messages of the buttons.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::{Column, Id, Text};
+/// # use iced_aw::native::tour::{self, TourStep};
+/// #
+/// # pub type Tour<'a, Message> = tour::Tour<'a, Message, Null>;
+/// # pub type Target<'a, Message> = tour::Target<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Step(usize),
+///     Skip,
+///     Finish,
+/// }
+///
+/// let search = Id::new("search");
+/// let content = Column::new()
+///     .push(Target::new(search.clone(), Text::new("Search")));
+///
+/// let tour = Tour::new(
+///     content,
+///     vec![TourStep::new(search, "Search", "Find anything from here.")],
+///     Some(0),
+///     Message::Step,
+/// )
+/// .on_skip(Message::Skip)
+/// .on_finish(Message::Finish);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tour<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content containing the targets.
+    underlay: Element<'a, Message, Renderer>,
+    /// The steps of the tour.
+    steps: Vec<TourStep>,
+    /// The index of the current step, if the tour is shown.
+    current: Option<usize>,
+    /// The function producing the message to go to the step at the index.
+    on_step: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The message sent by the skip button and the ESC key.
+    on_skip: Option<Message>,
+    /// The message sent by the next button of the last step.
+    on_finish: Option<Message>,
+    /// The label of the back button.
+    back_label: String,
+    /// The label of the next button.
+    next_label: String,
+    /// The label of the skip button.
+    skip_label: String,
+    /// The label of the next button of the last step.
+    finish_label: String,
+    /// The padding between a target and the edge of its cutout.
+    padding: f32,
+    /// The gap between the cutout and the card.
+    gap: f32,
+    /// The width of the card.
+    card_width: f32,
+    /// The text size of the card.
+    text_size: Option<f32>,
+    /// The font of the card.
+    font: Font,
+    /// The style of the tour.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Tour<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Tour`](Tour).
+    ///
+    /// It expects:
+    ///     * the content containing the [`Target`](Target)s.
+    ///     * the [`TourStep`](TourStep)s.
+    ///     * the index of the current step, or `None` to hide the tour.
+    ///     * the function producing the message to go to the step at the
+    ///         index.
+    pub fn new<U, F>(underlay: U, steps: Vec<TourStep>, current: Option<usize>, on_step: F) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(usize) -> Message,
+    {
+        Tour {
+            underlay: underlay.into(),
+            steps,
+            current,
+            on_step: Box::new(on_step),
+            on_skip: None,
+            on_finish: None,
+            back_label: String::from("Back"),
+            next_label: String::from("Next"),
+            skip_label: String::from("Skip"),
+            finish_label: String::from("Done"),
+            padding: DEFAULT_PADDING,
+            gap: DEFAULT_GAP,
+            card_width: DEFAULT_CARD_WIDTH,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Shows a skip button sending the message, which is sent by the ESC key
+    /// as well.
+    #[must_use]
+    pub fn on_skip(mut self, message: Message) -> Self {
+        self.on_skip = Some(message);
+        self
+    }
+
+    /// Shows a finish button on the last step sending the message.
+    #[must_use]
+    pub fn on_finish(mut self, message: Message) -> Self {
+        self.on_finish = Some(message);
+        self
+    }
+
+    /// Sets the label of the back button.
+    #[must_use]
+    pub fn back_label(mut self, label: impl Into<String>) -> Self {
+        self.back_label = label.into();
+        self
+    }
+
+    /// Sets the label of the next button.
+    #[must_use]
+    pub fn next_label(mut self, label: impl Into<String>) -> Self {
+        self.next_label = label.into();
+        self
+    }
+
+    /// Sets the label of the skip button.
+    #[must_use]
+    pub fn skip_label(mut self, label: impl Into<String>) -> Self {
+        self.skip_label = label.into();
+        self
+    }
+
+    /// Sets the label of the finish button.
+    #[must_use]
+    pub fn finish_label(mut self, label: impl Into<String>) -> Self {
+        self.finish_label = label.into();
+        self
+    }
+
+    /// Sets the padding between a target and the edge of its cutout.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the gap between the cutout and the card.
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the width of the card.
+    #[must_use]
+    pub fn card_width(mut self, width: f32) -> Self {
+        self.card_width = width;
+        self
+    }
+
+    /// Sets the text size of the card.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the card.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tour`](Tour).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Tour<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.underlay));
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &iced_native::renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_native::overlay::Element<'b, Message, Renderer>> {
+        let Some((index, step)) = self
+            .current
+            .and_then(|index| self.steps.get(index).map(|step| (index, step)))
+        else {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        };
+
+        let mut find = FindTarget::new(&step.target);
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, &mut find);
+        let cutout = find.bounds().map(|target| cutout(target, self.padding));
+
+        let is_last = index + 1 == self.steps.len();
+        let back = (index > 0).then(|| (self.on_step)(index - 1));
+        let next = if is_last {
+            self.on_finish.clone()
+        } else {
+            Some((self.on_step)(index + 1))
+        };
+        let next_label = if is_last {
+            self.finish_label.as_str()
+        } else {
+            self.next_label.as_str()
+        };
+        let bounds = layout.bounds();
+
+        // The overlays of the underlay stay visible below the backdrop
+        Layers::new()
+            .push_maybe(
+                0,
+                self.underlay
+                    .as_widget_mut()
+                    .overlay(&mut state.children[0], layout, renderer),
+            )
+            .push(
+                1,
+                TourOverlay::new(
+                    step,
+                    format!("{} of {}", index + 1, self.steps.len()),
+                    cutout,
+                    [
+                        (self.skip_label.as_str(), self.on_skip.clone()),
+                        (self.back_label.as_str(), back),
+                        (next_label, next),
+                    ],
+                    self.gap,
+                    self.card_width,
+                    self.text_size,
+                    self.font,
+                    self.style,
+                )
+                .overlay(Point::new(bounds.x, bounds.y)),
+            )
+            .overlay()
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<Tour<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tour: Tour<'a, Message, Renderer>) -> Self {
+        Element::new(tour)
+    }
+}
+
+/// Returns the cutout highlighting the target, grown by the padding.
+fn cutout(target: Rectangle, padding: f32) -> Rectangle {
+    Rectangle {
+        x: target.x - padding,
+        y: target.y - padding,
+        width: target.width + 2.0 * padding,
+        height: target.height + 2.0 * padding,
+    }
+}
+
+/// Returns the parts of the bounds above, below, left and right of the
+/// cutout, which are dimmed by the backdrop.
+///
+/// The cutout is clipped to the bounds, so the parts never overlap or extend
+/// beyond them.
+pub(crate) fn backdrop(bounds: Rectangle, cutout: Rectangle) -> [Rectangle; 4] {
+    let left = cutout.x.clamp(bounds.x, bounds.x + bounds.width);
+    let right = (cutout.x + cutout.width).clamp(left, bounds.x + bounds.width);
+    let top = cutout.y.clamp(bounds.y, bounds.y + bounds.height);
+    let bottom = (cutout.y + cutout.height).clamp(top, bounds.y + bounds.height);
+
+    [
+        Rectangle {
+            height: top - bounds.y,
+            ..bounds
+        },
+        Rectangle {
+            y: bottom,
+            height: bounds.y + bounds.height - bottom,
+            ..bounds
+        },
+        Rectangle {
+            x: bounds.x,
+            y: top,
+            width: left - bounds.x,
+            height: bottom - top,
+        },
+        Rectangle {
+            x: right,
+            y: top,
+            width: bounds.x + bounds.width - right,
+            height: bottom - top,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::Rectangle;
+
+    use super::{backdrop, cutout};
+
+    #[test]
+    fn backdrop_surrounds_the_cutout() {
+        let bounds = Rectangle::new([0.0, 0.0].into(), [100.0, 80.0].into());
+        let target = Rectangle::new([20.0, 30.0].into(), [40.0, 10.0].into());
+        let [top, bottom, left, right] = backdrop(bounds, cutout(target, 5.0));
+
+        assert_eq!(top, Rectangle::new([0.0, 0.0].into(), [100.0, 25.0].into()));
+        assert_eq!(
+            bottom,
+            Rectangle::new([0.0, 45.0].into(), [100.0, 35.0].into())
+        );
+        assert_eq!(
+            left,
+            Rectangle::new([0.0, 25.0].into(), [15.0, 20.0].into())
+        );
+        assert_eq!(
+            right,
+            Rectangle::new([65.0, 25.0].into(), [35.0, 20.0].into())
+        );
+    }
+
+    #[test]
+    fn backdrop_clips_the_cutout() {
+        let bounds = Rectangle::new([0.0, 0.0].into(), [100.0, 80.0].into());
+        let target = Rectangle::new([-10.0, 70.0].into(), [30.0, 30.0].into());
+        let [top, bottom, left, right] = backdrop(bounds, target);
+
+        assert_eq!(top, Rectangle::new([0.0, 0.0].into(), [100.0, 70.0].into()));
+        assert!(bottom.height.abs() <= f32::EPSILON);
+        assert!(left.width.abs() <= f32::EPSILON);
+        assert_eq!(
+            right,
+            Rectangle::new([20.0, 70.0].into(), [80.0, 10.0].into())
+        );
+    }
+}
@@ -0,0 +1,887 @@
+//! Move items between two lists, e.g. to grant permissions or fill a playlist.
+//!
+//! *This API requires the following crate features to be activated: transfer_list*
+use std::fmt::Display;
+
+use iced_native::{
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, text, touch,
+    widget::{
+        text_input,
+        tree::{self, Tree},
+        TextInput,
+    },
+    Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use crate::{
+    core::renderer::GlyphRenderer,
+    graphics::icons::Icon,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::transfer_list::{Appearance, StyleSheet};
+
+/// The default padding around the items and the search fields.
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default spacing between the lists and the buttons.
+const DEFAULT_SPACING: f32 = 10.0;
+
+/// The default height of the [`TransferList`](TransferList).
+const DEFAULT_HEIGHT: f32 = 300.0;
+
+/// The size of a button moving the items.
+const BUTTON_SIZE: f32 = 28.0;
+
+/// The number of items scrolled by a line of the mouse wheel.
+const SCROLL_ITEMS: f32 = 3.0;
+
+/// One of the two lists of a [`TransferList`](TransferList).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The list on the left, holding the items to choose from.
+    Source,
+    /// The list on the right, holding the chosen items.
+    Target,
+}
+
+impl Side {
+    /// Returns the other list.
+    #[must_use]
+    pub const fn other(self) -> Self {
+        match self {
+            Self::Source => Self::Target,
+            Self::Target => Self::Source,
+        }
+    }
+
+    /// Returns the index of the list in the state.
+    const fn index(self) -> usize {
+        match self {
+            Self::Source => 0,
+            Self::Target => 1,
+        }
+    }
+}
+
+/// Items the user moved from one list of a [`TransferList`](TransferList) to
+/// the other.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer<T> {
+    /// The moved items, in the order of the list they came from.
+    pub items: Vec<T>,
+    /// The list the items were moved to.
+    pub to: Side,
+}
+
+impl<T: PartialEq> Transfer<T> {
+    /// Moves the items from the one list to the end of the other.
+    pub fn apply(self, source: &mut Vec<T>, target: &mut Vec<T>) {
+        let (from, to) = match self.to {
+            Side::Source => (target, source),
+            Side::Target => (source, target),
+        };
+
+        from.retain(|item| !self.items.contains(item));
+        to.extend(self.items);
+    }
+}
+
+/// Two lists side by side with buttons moving the selected or all items from
+/// one to the other.
+///
+/// A click selects an item, holding the command key adds it to the selection
+/// and holding shift selects the range up to the last clicked item. A double
+/// click moves an item right away. The search field above each list filters
+/// its items and the buttons only move the items passing the filter.
+///
+/// The lists are owned by the application: each move produces a
+/// [`Transfer`](Transfer), which can be applied to the lists with
+/// [`Transfer::apply`](Transfer::apply).
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::transfer_list;
+/// #
+/// # pub type TransferList<'a, T, Message> = transfer_list::TransferList<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Moved(transfer_list::Transfer<&'static str>),
+/// }
+///
+/// let available = ["Read", "Write", "Delete"];
+/// let granted = ["Comment"];
+///
+/// let transfer_list = TransferList::new(&available, &granted, Message::Moved)
+///     .titles("Available", "Granted");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TransferList<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The items to choose from.
+    source: &'a [T],
+    /// The chosen items.
+    target: &'a [T],
+    /// The function producing the message when items are moved.
+    on_transfer: Box<dyn Fn(Transfer<T>) -> Message + 'a>,
+    /// The titles above the source and the target list.
+    titles: Option<(String, String)>,
+    /// The placeholder of the search fields.
+    search_placeholder: String,
+    /// The width of the [`TransferList`](TransferList).
+    width: Length,
+    /// The height of the [`TransferList`](TransferList).
+    height: Length,
+    /// The padding around the items and the search fields.
+    padding: f32,
+    /// The spacing between the lists and the buttons.
+    spacing: f32,
+    /// The text size of the items.
+    text_size: Option<f32>,
+    /// The font of the items.
+    font: Font,
+    /// The style of the [`TransferList`](TransferList).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> TransferList<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`TransferList`](TransferList).
+    ///
+    /// It expects:
+    ///     * the items to choose from
+    ///     * the chosen items
+    ///     * the function producing the message when items are moved
+    pub fn new<F>(source: &'a [T], target: &'a [T], on_transfer: F) -> Self
+    where
+        F: 'a + Fn(Transfer<T>) -> Message,
+    {
+        TransferList {
+            source,
+            target,
+            on_transfer: Box::new(on_transfer),
+            titles: None,
+            search_placeholder: String::from("Search"),
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the titles shown above the source and the target list.
+    #[must_use]
+    pub fn titles(mut self, source: impl Into<String>, target: impl Into<String>) -> Self {
+        self.titles = Some((source.into(), target.into()));
+        self
+    }
+
+    /// Sets the placeholder of the search fields.
+    #[must_use]
+    pub fn search_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.search_placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the [`TransferList`](TransferList).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TransferList`](TransferList).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding around the items and the search fields.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the spacing between the lists and the buttons.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`TransferList`](TransferList).
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`TransferList`](TransferList).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TransferList`](TransferList).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the items of the list.
+    fn items(&self, side: Side) -> &'a [T] {
+        match side {
+            Side::Source => self.source,
+            Side::Target => self.target,
+        }
+    }
+
+    /// Returns the search field of a list with the filter.
+    ///
+    /// The filter is kept in the state of the [`TransferList`](TransferList),
+    /// so the field is created whenever it is needed.
+    fn search<'b>(&self, filter: &'b str, text_size: f32) -> TextInput<'b, String, Renderer> {
+        TextInput::new(&self.search_placeholder, filter)
+            .on_input(|filter| filter)
+            .padding(self.padding)
+            .size(text_size)
+            .font(self.font)
+    }
+
+    /// Returns the height of an item.
+    fn item_height(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size()) + 2.0 * self.padding
+    }
+
+    /// Returns the height of the titles, if any.
+    fn title_height(&self, renderer: &Renderer) -> f32 {
+        if self.titles.is_some() {
+            self.item_height(renderer)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the items of the list the buttons move, either the selected or
+    /// all of the shown ones.
+    fn movable(&self, state: &State, side: Side, all: bool) -> Vec<T> {
+        let items = self.items(side);
+
+        shown(items, &state.filters[side.index()])
+            .into_iter()
+            .filter(|index| all || state.selected[side.index()].contains(index))
+            .map(|index| items[index].clone())
+            .collect()
+    }
+
+    /// Publishes the moved items and drops the selections.
+    fn transfer(&self, state: &mut State, shell: &mut Shell<'_, Message>, items: Vec<T>, to: Side) {
+        if items.is_empty() {
+            return;
+        }
+
+        shell.publish((self.on_transfer)(Transfer { items, to }));
+
+        for side in [Side::Source, Side::Target] {
+            state.selected[side.index()].clear();
+            state.anchors[side.index()] = None;
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for TransferList<'a, T, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // The search fields of the source and the target list
+        (0..2)
+            .map(|_| Tree {
+                tag: tree::Tag::of::<text_input::State>(),
+                state: tree::State::new(text_input::State::new()),
+                children: Vec::new(),
+            })
+            .collect()
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let list_width = ((size.width - BUTTON_SIZE - 2.0 * self.spacing) / 2.0).max(0.0);
+        let title_height = self.title_height(renderer);
+
+        let pane = |x: f32| {
+            let mut search = self.search("", text_size).layout(
+                renderer,
+                &Limits::new(Size::ZERO, Size::new(list_width, size.height)),
+            );
+            search.move_to(Point::new(0.0, title_height));
+
+            let top = title_height + search.size().height + self.padding;
+            let mut list = Node::new(Size::new(list_width, (size.height - top).max(0.0)));
+            list.move_to(Point::new(0.0, top));
+
+            let mut node =
+                Node::with_children(Size::new(list_width, size.height), vec![search, list]);
+            node.move_to(Point::new(x, 0.0));
+            node
+        };
+
+        // The buttons are stacked in the middle between the lists
+        let buttons_height = 4.0 * BUTTON_SIZE + 3.0 * self.padding;
+        let buttons = (0..4)
+            .map(|index| {
+                let mut node = Node::new(Size::new(BUTTON_SIZE, BUTTON_SIZE));
+                node.move_to(Point::new(
+                    0.0,
+                    (size.height - buttons_height) / 2.0
+                        + index as f32 * (BUTTON_SIZE + self.padding),
+                ));
+                node
+            })
+            .collect();
+        let mut buttons = Node::with_children(Size::new(BUTTON_SIZE, size.height), buttons);
+        buttons.move_to(Point::new(list_width + self.spacing, 0.0));
+
+        Node::with_children(
+            size,
+            vec![
+                pane(0.0),
+                buttons,
+                pane(list_width + BUTTON_SIZE + 2.0 * self.spacing),
+            ],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        state.sync([self.source.len(), self.target.len()]);
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let item_height = self.item_height(renderer);
+        let mut children = layout.children();
+        let (Some(source), Some(buttons), Some(target)) =
+            (children.next(), children.next(), children.next())
+        else {
+            return event::Status::Ignored;
+        };
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = modifiers;
+        }
+
+        let mut status = event::Status::Ignored;
+
+        for (side, pane) in [(Side::Source, source), (Side::Target, target)] {
+            let mut pane_children = pane.children();
+            let (Some(search), Some(list)) = (pane_children.next(), pane_children.next()) else {
+                continue;
+            };
+            let index = side.index();
+
+            // The search fields publish their new filter into a shell of their own
+            let filter = state.filters[index].clone();
+            let mut filters = Vec::new();
+            status = status.merge(self.search(&filter, text_size).on_event(
+                &mut tree.children[index],
+                event.clone(),
+                search,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut Shell::new(&mut filters),
+            ));
+
+            if let Some(filter) = filters.pop() {
+                state.filters[index] = filter;
+                state.offsets[index] = 0.0;
+            }
+
+            let bounds = list.bounds();
+            if !bounds.contains(cursor_position) {
+                continue;
+            }
+
+            let items = self.items(side);
+            let shown = shown(items, &state.filters[index]);
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    let row = ((cursor_position.y - bounds.y + state.offsets[index]) / item_height)
+                        as usize;
+                    let Some(&clicked) = shown.get(row) else {
+                        continue;
+                    };
+
+                    let click = mouse::Click::new(cursor_position, state.last_click);
+                    state.last_click = Some(click);
+
+                    if matches!(click.kind(), mouse::click::Kind::Double) {
+                        let item = items[clicked].clone();
+                        self.transfer(state, shell, vec![item], side.other());
+                    } else {
+                        state.select(side, clicked, &shown);
+                    }
+
+                    status = event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_ITEMS * item_height,
+                        mouse::ScrollDelta::Pixels { y, .. } => y,
+                    };
+                    let max = (shown.len() as f32 * item_height - bounds.height).max(0.0);
+                    state.offsets[index] = (state.offsets[index] - delta).clamp(0.0, max);
+
+                    status = event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let pressed = buttons
+                .children()
+                .position(|button| button.bounds().contains(cursor_position));
+
+            if let Some(button) = pressed {
+                let (from, all) = BUTTONS[button];
+                let items = self.movable(state, from, all);
+                self.transfer(state, shell, items, from.other());
+
+                status = event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let item_height = self.item_height(renderer);
+        let mut children = layout.children();
+        let (Some(source), Some(buttons), Some(target)) =
+            (children.next(), children.next(), children.next())
+        else {
+            return mouse::Interaction::default();
+        };
+
+        for (side, pane) in [(Side::Source, source), (Side::Target, target)] {
+            let mut pane_children = pane.children();
+            let (Some(search), Some(list)) = (pane_children.next(), pane_children.next()) else {
+                continue;
+            };
+            let index = side.index();
+
+            if search.bounds().contains(cursor_position) {
+                return self
+                    .search(&state.filters[index], text_size)
+                    .mouse_interaction(
+                        &tree.children[index],
+                        search,
+                        cursor_position,
+                        viewport,
+                        renderer,
+                    );
+            }
+
+            let bounds = list.bounds();
+            if bounds.contains(cursor_position) {
+                let row =
+                    ((cursor_position.y - bounds.y + state.offsets[index]) / item_height) as usize;
+
+                if row < shown(self.items(side), &state.filters[index]).len() {
+                    return mouse::Interaction::Pointer;
+                }
+            }
+        }
+
+        let enabled = buttons
+            .children()
+            .zip(BUTTONS)
+            .any(|(button, (from, all))| {
+                button.bounds().contains(cursor_position)
+                    && !self.movable(state, from, all).is_empty()
+            });
+
+        if enabled {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                layout.bounds().contains(cursor_position),
+                false,
+                false,
+                false,
+            ),
+            self.style,
+        );
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let item_height = self.item_height(renderer);
+        let mut children = layout.children();
+        let (Some(source), Some(buttons), Some(target)) =
+            (children.next(), children.next(), children.next())
+        else {
+            return;
+        };
+
+        for (side, pane) in [(Side::Source, source), (Side::Target, target)] {
+            let mut pane_children = pane.children();
+            let (Some(search), Some(list)) = (pane_children.next(), pane_children.next()) else {
+                continue;
+            };
+            let index = side.index();
+
+            if let Some((source_title, target_title)) = &self.titles {
+                let title = match side {
+                    Side::Source => source_title,
+                    Side::Target => target_title,
+                };
+                let bounds = pane.bounds();
+
+                renderer.fill_text(text::Text {
+                    content: title,
+                    bounds: Rectangle {
+                        y: bounds.y + self.title_height(renderer) / 2.0,
+                        ..bounds
+                    },
+                    size: text_size,
+                    color: appearance.title_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                });
+            }
+
+            self.search(&state.filters[index], text_size).draw(
+                &tree.children[index],
+                renderer,
+                theme,
+                search,
+                cursor_position,
+                None,
+            );
+
+            let bounds = list.bounds();
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+
+            let items = self.items(side);
+            let shown = shown(items, &state.filters[index]);
+            let offset = state.offsets[index];
+            let first = (offset / item_height) as usize;
+            let last = ((offset + bounds.height) / item_height).ceil() as usize;
+
+            renderer.with_layer(bounds, |renderer| {
+                for (row, &item) in shown.iter().enumerate().take(last).skip(first) {
+                    let row_bounds = Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + row as f32 * item_height - offset,
+                        width: bounds.width,
+                        height: item_height,
+                    };
+                    let selected = state.selected[index].contains(&item);
+
+                    let background = if selected {
+                        Some(appearance.selected_background)
+                    } else if row_bounds.contains(cursor_position) {
+                        Some(appearance.hovered_background)
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row_bounds,
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            background,
+                        );
+                    }
+
+                    renderer.fill_text(text::Text {
+                        content: &items[item].to_string(),
+                        bounds: Rectangle {
+                            x: row_bounds.x + self.padding,
+                            y: row_bounds.center_y(),
+                            width: row_bounds.width - 2.0 * self.padding,
+                            ..row_bounds
+                        },
+                        size: text_size,
+                        color: if selected {
+                            appearance.selected_text_color
+                        } else {
+                            appearance.text_color
+                        },
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Center,
+                    });
+                }
+            });
+        }
+
+        for (button, (from, all)) in buttons.children().zip(BUTTONS) {
+            let bounds = button.bounds();
+
+            let button_appearance = appearance!(
+                StyleSheet,
+                theme,
+                StyleState::new(
+                    bounds.contains(cursor_position),
+                    false,
+                    false,
+                    self.movable(state, from, all).is_empty(),
+                ),
+                self.style,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: button_appearance.border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                button_appearance.button_background,
+            );
+
+            let icon = match from {
+                Side::Source => Icon::CaretRightFill,
+                Side::Target => Icon::CaretLeftFill,
+            };
+            let glyph_size = renderer.default_glyph_size();
+
+            // Moving all items is shown with a double arrow
+            let shifts: &[f32] = if all { &[-0.2, 0.2] } else { &[0.0] };
+            for shift in shifts {
+                renderer.fill_glyph(
+                    char::from(icon),
+                    icon.font(),
+                    bounds + Vector::new(shift * BUTTON_SIZE, 0.0),
+                    glyph_size,
+                    button_appearance.button_icon_color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<TransferList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Message: 'a,
+    Renderer: 'a + iced_native::text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(transfer_list: TransferList<'a, T, Message, Renderer>) -> Self {
+        Element::new(transfer_list)
+    }
+}
+
+/// The list each button moves items from, and whether it moves all of them.
+const BUTTONS: [(Side, bool); 4] = [
+    (Side::Source, false),
+    (Side::Source, true),
+    (Side::Target, false),
+    (Side::Target, true),
+];
+
+/// The state of a [`TransferList`](TransferList).
+#[derive(Debug, Default)]
+struct State {
+    /// The number of items in each list when the selections were made.
+    lengths: [usize; 2],
+    /// The indices of the selected items of each list.
+    selected: [Vec<usize>; 2],
+    /// The index of the last clicked item of each list.
+    anchors: [Option<usize>; 2],
+    /// The search filter of each list.
+    filters: [String; 2],
+    /// The scroll offset of each list.
+    offsets: [f32; 2],
+    /// The last click on an item.
+    last_click: Option<mouse::Click>,
+    /// The keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Drops the selections of the lists changed by the application.
+    fn sync(&mut self, lengths: [usize; 2]) {
+        for (index, &length) in lengths.iter().enumerate() {
+            if self.lengths[index] != length {
+                self.selected[index].clear();
+                self.anchors[index] = None;
+            }
+        }
+
+        self.lengths = lengths;
+    }
+
+    /// Selects the clicked item of the list, given the indices of the shown
+    /// items.
+    fn select(&mut self, side: Side, clicked: usize, shown: &[usize]) {
+        let index = side.index();
+        let selected = &mut self.selected[index];
+
+        if self.modifiers.shift() {
+            let position = |item| shown.iter().position(|&shown| shown == item);
+            let end = position(clicked).unwrap_or_default();
+            let start = self.anchors[index].and_then(position).unwrap_or(end);
+
+            selected.clear();
+            selected.extend_from_slice(&shown[start.min(end)..=start.max(end)]);
+
+            // The anchor stays for the next range
+            return;
+        }
+
+        if self.modifiers.command() {
+            if let Some(position) = selected.iter().position(|&item| item == clicked) {
+                let _ = selected.remove(position);
+            } else {
+                selected.push(clicked);
+            }
+        } else {
+            selected.clear();
+            selected.push(clicked);
+        }
+
+        self.anchors[index] = Some(clicked);
+    }
+}
+
+/// Returns the indices of the items containing the filter, ignoring the case.
+fn shown<T: Display>(items: &[T], filter: &str) -> Vec<usize> {
+    let filter = filter.to_lowercase();
+
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.to_string().to_lowercase().contains(&filter))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shown, Side, State, Transfer};
+
+    #[test]
+    fn transfer_moves_items_to_the_end() {
+        let mut source = vec!["Read", "Write", "Delete"];
+        let mut target = vec!["Comment"];
+
+        Transfer {
+            items: vec!["Read", "Delete"],
+            to: Side::Target,
+        }
+        .apply(&mut source, &mut target);
+
+        assert_eq!(source, ["Write"]);
+        assert_eq!(target, ["Comment", "Read", "Delete"]);
+    }
+
+    #[test]
+    fn shift_selects_the_shown_range() {
+        let items = ["Apple", "Banana", "Cherry", "Blueberry", "Blackberry"];
+        let shown = shown(&items, "b");
+        assert_eq!(shown, [1, 3, 4]);
+
+        let mut state = State::default();
+        state.select(Side::Source, 1, &shown);
+        state.modifiers = iced_native::keyboard::Modifiers::SHIFT;
+        state.select(Side::Source, 4, &shown);
+
+        assert_eq!(state.selected[0], [1, 3, 4]);
+    }
+}
@@ -0,0 +1,853 @@
+//! Scroll through long lists building only the visible rows.
+//!
+//! *This API requires the following crate features to be activated: virtual_list*
+use std::cell::{Cell, RefCell};
+
+use iced_native::{
+    event,
+    layout::{Limits, Node},
+    mouse, overlay, renderer,
+    time::{Duration, Instant},
+    touch,
+    widget::{
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
+    window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use crate::{
+    core::animation::{Animation, Easing},
+    native::operation::{Action, Request},
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::virtual_list::{Appearance, StyleSheet};
+
+mod heights;
+
+use heights::Heights;
+
+/// The default estimated height of the rows.
+const DEFAULT_ROW_HEIGHT: f32 = 32.0;
+
+/// The default width of the scrollbar.
+const DEFAULT_SCROLLBAR_WIDTH: f32 = 10.0;
+
+/// The smallest height of the scroller.
+const MIN_SCROLLER_HEIGHT: f32 = 20.0;
+
+/// The distance scrolled by a line of the mouse wheel.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// The time it takes to glide to the scrolled offset.
+const SCROLL_DURATION: Duration = Duration::from_millis(150);
+
+/// The heights of the rows of a [`VirtualList`](VirtualList).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All rows have the given height.
+    Fixed(f32),
+    /// The rows are as high as their content, assuming the given height for
+    /// the rows not shown yet.
+    Measured(f32),
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Measured(DEFAULT_ROW_HEIGHT)
+    }
+}
+
+/// A scrollable list building only the rows it shows.
+///
+/// The rows are built by a function from their index whenever they scroll
+/// into view, so the cost of a frame depends on the number of visible rows
+/// rather than on the length of the list. The state of a row, like the text
+/// selection of an input, is dropped once it scrolls out of view.
+///
+/// The rows either have a [`Fixed`](RowHeight::Fixed) height, or are
+/// [`Measured`](RowHeight::Measured) when they are shown, replacing an
+/// estimated height. The wheel glides smoothly to the scrolled offset and the
+/// list is scrolled to a row with
+/// [`scroll_to`](crate::native::operation::scroll_to) or
+/// [`reveal`](crate::native::operation::reveal).
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, widget::Text};
+/// # use iced_aw::native::virtual_list::{self, RowHeight};
+/// #
+/// # pub type VirtualList<'a, Message> = virtual_list::VirtualList<'a, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+/// }
+///
+/// let virtual_list = VirtualList::<Message>::new(100_000, |index| {
+///     Text::new(format!("Row {index}")).into()
+/// })
+/// .row_height(RowHeight::Fixed(24.0));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`VirtualList`](VirtualList) to scroll it.
+    id: Option<Id>,
+    /// The number of rows.
+    count: usize,
+    /// The function building the row at an index.
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    /// The heights of the rows.
+    row_height: RowHeight,
+    /// The width of the [`VirtualList`](VirtualList).
+    width: Length,
+    /// The height of the [`VirtualList`](VirtualList).
+    height: Length,
+    /// The width of the scrollbar.
+    scrollbar_width: f32,
+    /// The style of the [`VirtualList`](VirtualList).
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The rows built for the visible part of the list.
+    rows: RefCell<Vec<Row<'a, Message, Renderer>>>,
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`VirtualList`](VirtualList) with the number of rows,
+    /// building the row at an index with the given function.
+    pub fn new<F>(count: usize, view: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        Self {
+            id: None,
+            count,
+            view: Box::new(view),
+            row_height: RowHeight::default(),
+            width: Length::Fill,
+            height: Length::Fill,
+            scrollbar_width: DEFAULT_SCROLLBAR_WIDTH,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            rows: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the
+    /// [`VirtualList`](VirtualList) to scroll it with
+    /// [`scroll_to`](crate::native::operation::scroll_to) or
+    /// [`reveal`](crate::native::operation::reveal).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the heights of the rows.
+    #[must_use]
+    pub fn row_height(mut self, row_height: RowHeight) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`](VirtualList).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`](VirtualList).
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the width of the scrollbar.
+    #[must_use]
+    pub fn scrollbar_width(mut self, scrollbar_width: f32) -> Self {
+        self.scrollbar_width = scrollbar_width;
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`](VirtualList).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the area of the rows and of the scrollbar, if the rows don't
+    /// fit into the bounds.
+    fn areas(&self, bounds: Rectangle, total: f32) -> (Rectangle, Option<Rectangle>) {
+        if total <= bounds.height {
+            return (bounds, None);
+        }
+
+        let scrollbar_width = self.scrollbar_width.min(bounds.width);
+        let scrollbar = Rectangle {
+            x: bounds.x + bounds.width - scrollbar_width,
+            width: scrollbar_width,
+            ..bounds
+        };
+
+        (
+            Rectangle {
+                width: bounds.width - scrollbar_width,
+                ..bounds
+            },
+            Some(scrollbar),
+        )
+    }
+
+    /// Builds and lays out the rows visible at the current offset, reusing
+    /// the rows already built, and returns the resulting [`Frame`](Frame).
+    fn update(&self, state: &State, renderer: &Renderer, bounds: Rectangle) -> Frame {
+        let mut heights = state.heights.borrow_mut();
+        heights.sync(self.row_height, self.count);
+
+        let (area, _) = self.areas(bounds, heights.total());
+        let mut scroll = state.scroll.get();
+        scroll.clamp((heights.total() - area.height).max(0.0));
+        state.scroll.set(scroll);
+        let offset = scroll.offset();
+
+        let (min_height, max_height) = match self.row_height {
+            RowHeight::Fixed(height) => (height, height),
+            RowHeight::Measured(_) => (0.0, f32::INFINITY),
+        };
+        let limits = Size::new(area.width, max_height);
+
+        let mut rows = self.rows.borrow_mut();
+        let mut trees = state.trees.borrow_mut();
+        let mut old_rows = std::mem::take(&mut *rows).into_iter().peekable();
+        let mut old_trees = std::mem::take(&mut *trees).into_iter().peekable();
+
+        let mut index = heights.index_at(offset);
+        let mut top = heights.offset(index);
+
+        while index < self.count && top < offset + area.height {
+            while old_rows.next_if(|row| row.index < index).is_some() {}
+            while old_trees
+                .next_if(|(tree_index, _)| *tree_index < index)
+                .is_some()
+            {}
+
+            let tree = old_trees
+                .next_if(|(tree_index, _)| *tree_index == index)
+                .map(|(_, tree)| tree);
+
+            let (mut row, tree) = if let Some(row) = old_rows.next_if(|row| row.index == index) {
+                let tree = tree.unwrap_or_else(|| Tree::new(&row.element));
+                (row, tree)
+            } else {
+                let element = (self.view)(index);
+                let tree = match tree {
+                    Some(mut tree) => {
+                        tree.diff(&element);
+                        tree
+                    }
+                    None => Tree::new(&element),
+                };
+
+                let row = Row {
+                    index,
+                    top,
+                    limits: Size::ZERO,
+                    layout: Node::new(Size::ZERO),
+                    element,
+                };
+                (row, tree)
+            };
+
+            if row.limits != limits {
+                row.limits = limits;
+                row.layout = row
+                    .element
+                    .as_widget()
+                    .layout(renderer, &Limits::new(Size::new(0.0, min_height), limits));
+                heights.set(index, row.layout.size().height);
+            }
+
+            row.top = top;
+            top += heights.height(index);
+            index += 1;
+
+            rows.push(row);
+            trees.push((index - 1, tree));
+        }
+
+        let total = heights.total();
+        let (area, scrollbar) = self.areas(bounds, total);
+
+        Frame {
+            area,
+            scrollbar,
+            offset,
+            total,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        // The rows are built once the final size is known
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let scroll = state.scroll.get_mut();
+            scroll.now = Some(now);
+            scroll.animation.request_redraw(now, shell);
+        }
+
+        let frame = self.update(state, renderer, bounds);
+
+        if let Some(drag) = state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let Some(offset) = frame.dragged(drag, cursor_position.y) {
+                        state.scroll.get_mut().jump(offset);
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.drag = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let (
+            Some(scrollbar),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }),
+        ) = (frame.scrollbar, &event)
+        {
+            if scrollbar.contains(cursor_position) {
+                // Pressing the track beside the scroller moves it under the cursor
+                let scroller = frame.scroller().unwrap_or(scrollbar);
+                if !scroller.contains(cursor_position) {
+                    let offset = frame
+                        .dragged(
+                            Drag {
+                                origin: scroller.center_y(),
+                                offset: frame.offset,
+                            },
+                            cursor_position.y,
+                        )
+                        .unwrap_or(frame.offset);
+                    state.scroll.get_mut().glide(offset, Instant::now());
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                state.drag = Some(Drag {
+                    origin: cursor_position.y,
+                    offset: state.scroll.get_mut().to,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        let is_over = frame.area.contains(cursor_position);
+        let row_cursor = if is_over {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        let status = self
+            .rows
+            .get_mut()
+            .iter_mut()
+            .zip(state.trees.get_mut().iter_mut())
+            .map(|(row, (_, tree))| {
+                row.element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    Layout::with_offset(frame.position(row.top), &row.layout),
+                    row_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if is_over => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * LINE_HEIGHT,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let scroll = state.scroll.get_mut();
+                let offset = (scroll.to - delta).min(frame.max_offset()).max(0.0);
+                scroll.glide(offset, Instant::now());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. }) if is_over => {
+                state.touch = Some(position.y);
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(last) = state.touch else {
+                    return event::Status::Ignored;
+                };
+
+                let scroll = state.scroll.get_mut();
+                let offset = (scroll.to - (position.y - last))
+                    .min(frame.max_offset())
+                    .max(0.0);
+                scroll.jump(offset);
+                state.touch = Some(position.y);
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.touch.is_some() =>
+            {
+                state.touch = None;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let frame = self.update(state, renderer, layout.bounds());
+
+        if state.drag.is_some()
+            || frame
+                .scrollbar
+                .map_or(false, |scrollbar| scrollbar.contains(cursor_position))
+            || !frame.area.contains(cursor_position)
+        {
+            return mouse::Interaction::default();
+        }
+
+        let rows = self.rows.borrow();
+        let trees = state.trees.borrow();
+
+        rows.iter()
+            .zip(trees.iter())
+            .map(|(row, (_, tree))| {
+                row.element.as_widget().mouse_interaction(
+                    tree,
+                    Layout::with_offset(frame.position(row.top), &row.layout),
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let frame = self.update(state, renderer, bounds);
+
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                frame
+                    .scrollbar
+                    .map_or(false, |scrollbar| scrollbar.contains(cursor_position)),
+                state.drag.is_some(),
+                false,
+                false,
+            ),
+            self.style,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let row_cursor = if frame.area.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        renderer.with_layer(frame.area, |renderer| {
+            let rows = self.rows.borrow();
+            let trees = state.trees.borrow();
+
+            for (row, (_, tree)) in rows.iter().zip(trees.iter()) {
+                row.element.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    Layout::with_offset(frame.position(row.top), &row.layout),
+                    row_cursor,
+                    &frame.area,
+                );
+            }
+        });
+
+        if let (Some(scrollbar), Some(scroller)) = (frame.scrollbar, frame.scroller()) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scrollbar,
+                    border_radius: appearance.scrollbar_border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.scrollbar_background,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scroller,
+                    border_radius: appearance.scrollbar_border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.scroller_color,
+            );
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Some(action) = Request::receive(operation, self.id.as_ref()).action() {
+            let heights = state.heights.get_mut();
+            heights.sync(self.row_height, self.count);
+
+            let (area, _) = self.areas(bounds, heights.total());
+            let scroll = state.scroll.get_mut();
+            let offset = scroll.offset();
+
+            let target = match action {
+                Action::ScrollTo(index) => Some(heights.offset(index)),
+                Action::Reveal(index) if index < self.count => {
+                    let top = heights.offset(index);
+                    let bottom = top + heights.height(index);
+
+                    Some(if top < offset {
+                        top
+                    } else if bottom > offset + area.height {
+                        bottom - area.height
+                    } else {
+                        offset
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some(target) = target {
+                let max_offset = (heights.total() - area.height).max(0.0);
+                scroll.jump(target.min(max_offset).max(0.0));
+            }
+        }
+
+        let frame = self.update(state, renderer, bounds);
+
+        operation.container(self.id.as_ref(), &mut |operation| {
+            let rows = self.rows.borrow();
+            let mut trees = state.trees.borrow_mut();
+
+            for (row, (_, tree)) in rows.iter().zip(trees.iter_mut()) {
+                row.element.as_widget().operate(
+                    tree,
+                    Layout::with_offset(frame.position(row.top), &row.layout),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let frame = self.update(state, renderer, layout.bounds());
+
+        self.rows
+            .get_mut()
+            .iter_mut()
+            .zip(state.trees.get_mut().iter_mut())
+            .find_map(|(row, (_, tree))| {
+                let Row {
+                    element,
+                    layout,
+                    top,
+                    ..
+                } = row;
+
+                element.as_widget_mut().overlay(
+                    tree,
+                    Layout::with_offset(frame.position(*top), layout),
+                    renderer,
+                )
+            })
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(virtual_list: VirtualList<'a, Message, Renderer>) -> Self {
+        Element::new(virtual_list)
+    }
+}
+
+/// A row built for the visible part of a [`VirtualList`](VirtualList).
+struct Row<'a, Message, Renderer> {
+    /// The index of the row.
+    index: usize,
+    /// The offset of the top of the row in the list.
+    top: f32,
+    /// The largest size the row was laid out for.
+    limits: Size,
+    /// The layout of the row.
+    layout: Node,
+    /// The content of the row.
+    element: Element<'a, Message, Renderer>,
+}
+
+/// The areas of a [`VirtualList`](VirtualList) scrolled to an offset.
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    /// The area of the rows.
+    area: Rectangle,
+    /// The area of the scrollbar, if the rows don't fit.
+    scrollbar: Option<Rectangle>,
+    /// The offset the rows are scrolled by.
+    offset: f32,
+    /// The height of all rows.
+    total: f32,
+}
+
+impl Frame {
+    /// Returns the offset of the layout of a row with its top at the offset
+    /// in the list.
+    fn position(&self, top: f32) -> Vector {
+        Vector::new(self.area.x, self.area.y + top - self.offset)
+    }
+
+    /// Returns the largest offset the rows can be scrolled by.
+    fn max_offset(&self) -> f32 {
+        (self.total - self.area.height).max(0.0)
+    }
+
+    /// Returns the bounds of the scroller, if the rows don't fit.
+    fn scroller(&self) -> Option<Rectangle> {
+        let scrollbar = self.scrollbar?;
+        let height = (scrollbar.height * self.area.height / self.total)
+            .max(MIN_SCROLLER_HEIGHT)
+            .min(scrollbar.height);
+        let progress = if self.max_offset() > 0.0 {
+            self.offset / self.max_offset()
+        } else {
+            0.0
+        };
+
+        Some(Rectangle {
+            y: scrollbar.y + (scrollbar.height - height) * progress,
+            height,
+            ..scrollbar
+        })
+    }
+
+    /// Returns the offset the [`Drag`](Drag) of the scroller scrolls to at the
+    /// vertical position, if the rows don't fit.
+    fn dragged(&self, drag: Drag, y: f32) -> Option<f32> {
+        let scrollbar = self.scrollbar?;
+        let scroller = self.scroller()?;
+        let range = scrollbar.height - scroller.height;
+        if range <= 0.0 {
+            return None;
+        }
+
+        let offset = drag.offset + (y - drag.origin) * self.max_offset() / range;
+
+        Some(offset.min(self.max_offset()).max(0.0))
+    }
+}
+
+/// A drag of the scroller of a [`VirtualList`](VirtualList).
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The vertical position the drag started at.
+    origin: f32,
+    /// The offset the drag started at.
+    offset: f32,
+}
+
+/// The offset a [`VirtualList`](VirtualList) is scrolled by, gliding from
+/// one offset to another.
+#[derive(Clone, Copy, Debug)]
+struct Scroll {
+    /// The offset the list glides from.
+    from: f32,
+    /// The offset the list glides to.
+    to: f32,
+    /// The animation gliding from one offset to the other.
+    animation: Animation,
+    /// The time of the last redraw.
+    now: Option<Instant>,
+}
+
+impl Scroll {
+    /// Returns the current offset.
+    fn offset(&self) -> f32 {
+        self.now.map_or(self.to, |now| {
+            self.animation.interpolate(self.from, self.to, now)
+        })
+    }
+
+    /// Jumps to the offset.
+    fn jump(&mut self, offset: f32) {
+        self.from = offset;
+        self.to = offset;
+        self.animation = Animation::new(SCROLL_DURATION).easing(Easing::EaseOut);
+    }
+
+    /// Glides from the current offset to the offset.
+    fn glide(&mut self, offset: f32, now: Instant) {
+        self.from = self.offset();
+        self.to = offset;
+        self.animation = Animation::new(SCROLL_DURATION).easing(Easing::EaseOut);
+        self.animation.go(true, now);
+    }
+
+    /// Keeps the offsets below the largest offset.
+    fn clamp(&mut self, max_offset: f32) {
+        self.from = self.from.min(max_offset);
+        self.to = self.to.min(max_offset);
+    }
+}
+
+/// The state of a [`VirtualList`](VirtualList).
+struct State {
+    /// The offset the rows are scrolled by.
+    scroll: Cell<Scroll>,
+    /// The heights of the rows.
+    heights: RefCell<Heights>,
+    /// The trees of the visible rows with their index.
+    trees: RefCell<Vec<(usize, Tree)>>,
+    /// The drag of the scroller, if any.
+    drag: Option<Drag>,
+    /// The last vertical position of a finger scrolling the list, if any.
+    touch: Option<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) scrolled to the top.
+    fn new() -> Self {
+        Self {
+            scroll: Cell::new(Scroll {
+                from: 0.0,
+                to: 0.0,
+                animation: Animation::new(SCROLL_DURATION).easing(Easing::EaseOut),
+                now: None,
+            }),
+            heights: RefCell::new(Heights::default()),
+            trees: RefCell::new(Vec::new()),
+            drag: None,
+            touch: None,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Keep track of the heights and the offsets of the rows of a list.
+
+use super::RowHeight;
+
+/// The heights of the rows of a [`VirtualList`](super::VirtualList).
+///
+/// Measured heights are kept in a Fenwick tree, so the offset of a row and
+/// the row at an offset are found in logarithmic time, even for lists with
+/// hundreds of thousands of rows.
+#[derive(Debug)]
+pub enum Heights {
+    /// All rows have the same height.
+    Fixed {
+        /// The height of a row.
+        height: f32,
+        /// The number of rows.
+        count: usize,
+    },
+    /// The rows have heights of their own.
+    Measured {
+        /// The height of each row.
+        heights: Vec<f32>,
+        /// The Fenwick tree of the heights, where the entry at the index `i`
+        /// is the sum of the heights of the rows from `i + 1 - lowbit(i + 1)`
+        /// to `i`.
+        sums: Vec<f32>,
+    },
+}
+
+impl Default for Heights {
+    fn default() -> Self {
+        Self::Fixed {
+            height: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl Heights {
+    /// Creates new measured [`Heights`](Heights) of the rows.
+    fn measured(heights: Vec<f32>) -> Self {
+        let mut sums = heights.clone();
+
+        for index in 0..sums.len() {
+            let parent = index | (index + 1);
+            if parent < sums.len() {
+                sums[parent] += sums[index];
+            }
+        }
+
+        Self::Measured { heights, sums }
+    }
+
+    /// Adapts the [`Heights`](Heights) to the [`RowHeight`](RowHeight) and
+    /// the number of rows, keeping the heights measured so far.
+    pub fn sync(&mut self, row_height: RowHeight, count: usize) {
+        match (row_height, &mut *self) {
+            (RowHeight::Fixed(height), _) => *self = Self::Fixed { height, count },
+            (RowHeight::Measured(_), Self::Measured { heights, .. }) if heights.len() == count => {}
+            (RowHeight::Measured(estimate), Self::Measured { heights, .. }) => {
+                let mut heights = std::mem::take(heights);
+                heights.resize(count, estimate);
+                *self = Self::measured(heights);
+            }
+            (RowHeight::Measured(estimate), Self::Fixed { .. }) => {
+                *self = Self::measured(vec![estimate; count]);
+            }
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Fixed { count, .. } => *count,
+            Self::Measured { heights, .. } => heights.len(),
+        }
+    }
+
+    /// Returns the height of the row at the index.
+    pub fn height(&self, index: usize) -> f32 {
+        match self {
+            Self::Fixed { height, .. } => *height,
+            Self::Measured { heights, .. } => heights.get(index).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Sets the measured height of the row at the index.
+    ///
+    /// Fixed heights are kept.
+    pub fn set(&mut self, index: usize, height: f32) {
+        let Self::Measured { heights, sums } = self else {
+            return;
+        };
+        let Some(previous) = heights.get_mut(index) else {
+            return;
+        };
+
+        let delta = height - *previous;
+        *previous = height;
+
+        let mut index = index;
+        while index < sums.len() {
+            sums[index] += delta;
+            index |= index + 1;
+        }
+    }
+
+    /// Returns the offset of the top of the row at the index, which is the
+    /// sum of the heights of the rows before it.
+    pub fn offset(&self, index: usize) -> f32 {
+        let index = index.min(self.count());
+
+        match self {
+            Self::Fixed { height, .. } => index as f32 * height,
+            Self::Measured { sums, .. } => {
+                let mut offset = 0.0;
+                let mut end = index;
+                while end > 0 {
+                    offset += sums[end - 1];
+                    end &= end - 1;
+                }
+                offset
+            }
+        }
+    }
+
+    /// Returns the sum of the heights of all rows.
+    pub fn total(&self) -> f32 {
+        self.offset(self.count())
+    }
+
+    /// Returns the index of the row at the offset, or of the nearest row if
+    /// the offset is outside of the rows.
+    pub fn index_at(&self, offset: f32) -> usize {
+        let last = self.count().saturating_sub(1);
+
+        match self {
+            Self::Fixed { height, .. } if *height > 0.0 => ((offset / height) as usize).min(last),
+            Self::Fixed { .. } => 0,
+            Self::Measured { sums, .. } => {
+                // Descends the tree to the most rows with their bottom at or
+                // above the offset
+                let mut rows = 0;
+                let mut remaining = offset;
+                let mut step = sums.len().checked_next_power_of_two().unwrap_or(0);
+
+                while step > 0 {
+                    let next = rows + step;
+                    if next <= sums.len() && sums[next - 1] <= remaining {
+                        rows = next;
+                        remaining -= sums[next - 1];
+                    }
+                    step /= 2;
+                }
+
+                rows.min(last)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{Heights, RowHeight};
+
+    #[test]
+    fn fixed_heights() {
+        let mut heights = Heights::default();
+        heights.sync(RowHeight::Fixed(20.0), 100);
+
+        assert_eq!(heights.total(), 2000.0);
+        assert_eq!(heights.offset(3), 60.0);
+        assert_eq!(heights.index_at(59.0), 2);
+        assert_eq!(heights.index_at(60.0), 3);
+        assert_eq!(heights.index_at(5000.0), 99);
+
+        heights.set(3, 50.0);
+        assert_eq!(heights.height(3), 20.0);
+    }
+
+    #[test]
+    fn measured_heights() {
+        let mut heights = Heights::default();
+        heights.sync(RowHeight::Measured(10.0), 7);
+
+        assert_eq!(heights.total(), 70.0);
+
+        heights.set(2, 30.0);
+        heights.set(5, 5.0);
+
+        let offsets: Vec<_> = (0..=7).map(|index| heights.offset(index)).collect();
+        assert_eq!(offsets, vec![0.0, 10.0, 20.0, 50.0, 60.0, 70.0, 75.0, 85.0]);
+
+        assert_eq!(heights.index_at(0.0), 0);
+        assert_eq!(heights.index_at(25.0), 2);
+        assert_eq!(heights.index_at(50.0), 3);
+        assert_eq!(heights.index_at(72.0), 5);
+        assert_eq!(heights.index_at(100.0), 6);
+    }
+
+    #[test]
+    fn keeps_measured_heights_while_growing() {
+        let mut heights = Heights::default();
+        heights.sync(RowHeight::Measured(10.0), 2);
+        heights.set(1, 40.0);
+
+        heights.sync(RowHeight::Measured(10.0), 4);
+
+        assert_eq!(heights.count(), 4);
+        assert_eq!(heights.height(1), 40.0);
+        assert_eq!(heights.total(), 70.0);
+        assert_eq!(heights.index_at(55.0), 2);
+    }
+}
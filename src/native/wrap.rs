@@ -374,53 +374,48 @@ where
         let mut deep_curse = padding.left;
         let mut current_line_height = line_minimal_length;
         let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
-        let mut nodes: Vec<Node> = self
-            .elements
-            .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(limits.min().width, line_minimal_length),
-                    limits.max(),
-                );
-                let mut node = elem.as_widget().layout(renderer, &node_limit);
+        let mut line_start = 0;
+        let mut nodes: Vec<Node> = Vec::with_capacity(self.elements.len());
 
-                let size = node.size();
-
-                let offset_init = size.width + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_width {
-                    deep_curse += current_line_height + line_spacing;
-                    align.push((start..end, current_line_height));
-                    start = end;
-                    end += 1;
-                    current_line_height = line_minimal_length;
-                    node.move_to(Point::new(padding.left, deep_curse));
-                    curse = offset_init + padding.left;
-                } else {
-                    node.move_to(Point::new(curse, deep_curse));
-                    curse = offset;
-                    end += 1;
-                }
-                current_line_height = current_line_height.max(size.height);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_height));
-        }
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
+        // A line is aligned as soon as it is complete, so no list of the
+        // lines has to be kept
+        let align_line = |line: &mut [Node], max_length: f32| {
+            for node in line {
                 let size = node.size();
                 let space = Size::new(size.width, max_length);
                 node.align(Alignment::Start, self.alignment, space);
-            });
+            }
+        };
+
+        for elem in &self.elements {
+            let node_limit = Limits::new(
+                Size::new(limits.min().width, line_minimal_length),
+                limits.max(),
+            );
+            let mut node = elem.as_widget().layout(renderer, &node_limit);
+
+            let size = node.size();
+
+            let offset_init = size.width + spacing;
+            let offset = curse + offset_init;
+
+            if offset > max_width {
+                deep_curse += current_line_height + line_spacing;
+                align_line(&mut nodes[line_start..], current_line_height);
+                line_start = nodes.len();
+                current_line_height = line_minimal_length;
+                node.move_to(Point::new(padding.left, deep_curse));
+                curse = offset_init + padding.left;
+            } else {
+                node.move_to(Point::new(curse, deep_curse));
+                curse = offset;
+            }
+            current_line_height = current_line_height.max(size.height);
+            max_main = max_main.max(curse);
+
+            nodes.push(node);
         }
+        align_line(&mut nodes[line_start..], current_line_height);
         let (width, height) = (
             max_main - padding.left,
             deep_curse - padding.left + current_line_height,
@@ -456,54 +451,46 @@ where
         let mut wide_curse = padding.left;
         let mut current_line_width = line_minimal_length;
         let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
-        let mut nodes: Vec<Node> = self
-            .elements
-            .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(line_minimal_length, limits.min().height),
-                    limits.max(),
-                );
-                let mut node = elem.as_widget().layout(renderer, &node_limit);
-
-                let size = node.size();
-
-                let offset_init = size.height + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_height {
-                    wide_curse += current_line_width + line_spacing;
-                    align.push((start..end, current_line_width));
-                    start = end;
-                    end += 1;
-                    current_line_width = line_minimal_length;
-                    node.move_to(Point::new(wide_curse, padding.left));
-                    curse = offset_init + padding.left;
-                } else {
-                    node.move_to(Point::new(wide_curse, curse));
-                    end += 1;
-                    curse = offset;
-                }
-                current_line_width = current_line_width.max(size.width);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_width));
-        }
+        let mut line_start = 0;
+        let mut nodes: Vec<Node> = Vec::with_capacity(self.elements.len());
 
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
+        let align_line = |line: &mut [Node], max_length: f32| {
+            for node in line {
                 let size = node.size();
                 let space = Size::new(max_length, size.height);
                 node.align(self.alignment, Alignment::Start, space);
-            });
+            }
+        };
+
+        for elem in &self.elements {
+            let node_limit = Limits::new(
+                Size::new(line_minimal_length, limits.min().height),
+                limits.max(),
+            );
+            let mut node = elem.as_widget().layout(renderer, &node_limit);
+
+            let size = node.size();
+
+            let offset_init = size.height + spacing;
+            let offset = curse + offset_init;
+
+            if offset > max_height {
+                wide_curse += current_line_width + line_spacing;
+                align_line(&mut nodes[line_start..], current_line_width);
+                line_start = nodes.len();
+                current_line_width = line_minimal_length;
+                node.move_to(Point::new(wide_curse, padding.left));
+                curse = offset_init + padding.left;
+            } else {
+                node.move_to(Point::new(wide_curse, curse));
+                curse = offset;
+            }
+            current_line_width = current_line_width.max(size.width);
+            max_main = max_main.max(curse);
+
+            nodes.push(node);
         }
+        align_line(&mut nodes[line_start..], current_line_width);
 
         let (width, height) = (
             wide_curse - padding.left + current_line_width,
@@ -0,0 +1,560 @@
+//! Set two values at once by dragging a handle inside a rectangle.
+//!
+//! *This API requires the following crate features to be activated: xy_pad*
+use std::ops::RangeInclusive;
+
+use iced_native::{
+    event, keyboard,
+    layout::{Limits, Node},
+    mouse, renderer, touch,
+    widget::{
+        operation::Focusable,
+        tree::{self, Tag},
+        Id, Operation, Tree,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use num_traits::FromPrimitive;
+
+use crate::{
+    core::snap::snap,
+    native::focus::Focus,
+    style::style_state::{appearance, StyleState},
+};
+
+pub use crate::style::xy_pad::{Appearance, StyleSheet};
+
+/// The default width and height of a [`XYPad`](XYPad).
+const DEFAULT_SIZE: f32 = 200.0;
+
+/// The number of steps the handle is moved by an arrow key while Shift is
+/// held.
+const PAGE_STEPS: f64 = 10.0;
+
+/// A rectangle setting two values at once by dragging a handle inside it,
+/// like the pan and tilt of a camera or the saturation and brightness of a
+/// color.
+///
+/// The horizontal value grows to the right and the vertical value grows
+/// upwards. Both values snap to the step of the pad. The pad can be focused
+/// and its handle nudged with the arrow keys by a step, or by ten steps while
+/// Shift is held.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_aw::native::xy_pad;
+/// #
+/// # pub type XYPad<'a, T, Message> = xy_pad::XYPad<'a, T, Message, Null>;
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PanTilt((f32, f32)),
+/// }
+///
+/// let pan_tilt = (0.0, 15.0);
+///
+/// let xy_pad = XYPad::new(-180.0..=180.0, -90.0..=90.0, pan_tilt, Message::PanTilt).step(5.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct XYPad<'a, T, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The id of the [`XYPad`](XYPad) to focus it.
+    id: Option<Id>,
+    /// The range of the horizontal value.
+    x_range: RangeInclusive<T>,
+    /// The range of the vertical value.
+    y_range: RangeInclusive<T>,
+    /// The horizontal and the vertical value.
+    values: (T, T),
+    /// The step both values snap to.
+    step: T,
+    /// The function producing the message when the handle is moved.
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    /// The message sent when the handle is released.
+    on_release: Option<Message>,
+    /// The width of the pad.
+    width: Length,
+    /// The height of the pad.
+    height: Length,
+    /// The style of the pad.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> XYPad<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`XYPad`](XYPad).
+    ///
+    /// It expects:
+    ///     * the inclusive range of the horizontal value.
+    ///     * the inclusive range of the vertical value.
+    ///     * the horizontal and the vertical value.
+    ///     * the function that will be called with the new values when the
+    ///         handle is moved.
+    pub fn new<F>(
+        x_range: RangeInclusive<T>,
+        y_range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        XYPad {
+            id: None,
+            x_range,
+            y_range,
+            values,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fixed(DEFAULT_SIZE),
+            height: Length::Fixed(DEFAULT_SIZE),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`](iced_native::widget::Id) of the [`XYPad`](XYPad) to
+    /// focus it with [`focus`](crate::native::focus::focus).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the step both values of the [`XYPad`](XYPad) snap to.
+    #[must_use]
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the message sent when the handle is released.
+    #[must_use]
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`XYPad`](XYPad).
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`XYPad`](XYPad).
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`XYPad`](XYPad).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> XYPad<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the values of the range nearest to the values, snapped to the
+    /// step.
+    fn snap(&self, (x, y): (f64, f64)) -> (T, T) {
+        let step = self.step.into();
+        let snap_axis = |value: f64, range: &RangeInclusive<T>| {
+            let (min, max) = ((*range.start()).into(), (*range.end()).into());
+
+            T::from_f64(snap(value, min, max, step)).unwrap_or(*range.start())
+        };
+
+        (snap_axis(x, &self.x_range), snap_axis(y, &self.y_range))
+    }
+
+    /// Returns the values at the position inside the bounds.
+    fn values_at(&self, bounds: Rectangle, position: Point) -> (T, T) {
+        let (x, y) = ratios_at(bounds, position);
+
+        self.snap((
+            lerp(&self.x_range, f64::from(x)),
+            lerp(&self.y_range, f64::from(y)),
+        ))
+    }
+
+    /// Returns the position of the handle inside the bounds.
+    fn handle_position(&self, bounds: Rectangle) -> Point {
+        let x = ratio(&self.x_range, self.values.0.into());
+        let y = ratio(&self.y_range, self.values.1.into());
+
+        Point::new(
+            bounds.x + x * bounds.width,
+            bounds.y + (1.0 - y) * bounds.height,
+        )
+    }
+
+    /// Publishes the values if they changed.
+    fn change(&self, values: (T, T), shell: &mut Shell<'_, Message>) {
+        if values != self.values {
+            shell.publish((self.on_change)(values));
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for XYPad<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: Clone,
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(DEFAULT_SIZE, DEFAULT_SIZE)))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let pad_state: &mut State = state.state.downcast_mut();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    pad_state.focus.press(false);
+                    return event::Status::Ignored;
+                }
+
+                pad_state.dragging = true;
+                pad_state.focus.press(true);
+                self.change(self.values_at(bounds, cursor_position), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if !pad_state.dragging {
+                    return event::Status::Ignored;
+                }
+
+                self.change(self.values_at(bounds, position), shell);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if !pad_state.dragging {
+                    return event::Status::Ignored;
+                }
+
+                pad_state.dragging = false;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                if !pad_state.focus.is_focused() {
+                    return event::Status::Ignored;
+                }
+
+                let (dx, dy) = match key_code {
+                    keyboard::KeyCode::Left => (-1.0, 0.0),
+                    keyboard::KeyCode::Right => (1.0, 0.0),
+                    keyboard::KeyCode::Down => (0.0, -1.0),
+                    keyboard::KeyCode::Up => (0.0, 1.0),
+                    _ => return event::Status::Ignored,
+                };
+                let step = if modifiers.shift() {
+                    PAGE_STEPS * self.step.into()
+                } else {
+                    self.step.into()
+                };
+                let (x, y): (f64, f64) = (self.values.0.into(), self.values.1.into());
+
+                self.change(self.snap((x + dx * step, y + dy * step)), shell);
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let pad_state: &State = state.state.downcast_ref();
+
+        if pad_state.dragging {
+            mouse::Interaction::Grabbing
+        } else if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let pad_state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let appearance = appearance!(
+            StyleSheet,
+            theme,
+            StyleState::new(
+                bounds.contains(cursor_position),
+                pad_state.dragging,
+                pad_state.focus.is_focused(),
+                false,
+            ),
+            self.style,
+        );
+        let handle = self.handle_position(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        // The handle is cut off at the edges of the pad
+        renderer.with_layer(bounds, |renderer| {
+            let line = |bounds: Rectangle| renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            };
+            let half = appearance.crosshair_width / 2.0;
+
+            renderer.fill_quad(
+                line(Rectangle {
+                    y: handle.y - half,
+                    height: appearance.crosshair_width,
+                    ..bounds
+                }),
+                appearance.crosshair_color,
+            );
+            renderer.fill_quad(
+                line(Rectangle {
+                    x: handle.x - half,
+                    width: appearance.crosshair_width,
+                    ..bounds
+                }),
+                appearance.crosshair_color,
+            );
+
+            let radius = appearance.handle_radius;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: handle.x - radius,
+                        y: handle.y - radius,
+                        width: 2.0 * radius,
+                        height: 2.0 * radius,
+                    },
+                    border_radius: radius.into(),
+                    border_width: appearance.handle_border_width,
+                    border_color: appearance.handle_border_color,
+                },
+                appearance.handle_color,
+            );
+        });
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let pad_state: &mut State = state.state.downcast_mut();
+
+        operation.focusable(&mut pad_state.focus, self.id.as_ref());
+    }
+}
+
+impl<'a, T, Message, Renderer> From<XYPad<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + PartialOrd + Into<f64> + FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(xy_pad: XYPad<'a, T, Message, Renderer>) -> Self {
+        Element::new(xy_pad)
+    }
+}
+
+/// The state of a [`XYPad`](XYPad).
+///
+/// The [`XYPad`](XYPad) keeps it in the widget tree, so it doesn't need to be
+/// held by the application.
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// Whether the handle is dragged by the user.
+    dragging: bool,
+    /// The keyboard focus of the pad.
+    focus: Focus,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`XYPad`](XYPad).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            dragging: false,
+            focus: Focus::new(),
+        }
+    }
+}
+
+/// Returns where the position lies inside the bounds, from 0.0 at the left
+/// and the bottom to 1.0 at the right and the top.
+fn ratios_at(bounds: Rectangle, position: Point) -> (f32, f32) {
+    let ratio = |offset: f32, length: f32| {
+        if length > 0.0 {
+            (offset / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    };
+
+    (
+        ratio(position.x - bounds.x, bounds.width),
+        1.0 - ratio(position.y - bounds.y, bounds.height),
+    )
+}
+
+/// Returns where the value lies in the range, from 0.0 at its start to 1.0
+/// at its end.
+#[allow(clippy::cast_possible_truncation)]
+fn ratio<T>(range: &RangeInclusive<T>, value: f64) -> f32
+where
+    T: Copy + Into<f64>,
+{
+    let (min, max) = ((*range.start()).into(), (*range.end()).into());
+    let ratio = if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    ratio as f32
+}
+
+/// Returns the value at the ratio between the start and the end of the range.
+fn lerp<T>(range: &RangeInclusive<T>, ratio: f64) -> f64
+where
+    T: Copy + Into<f64>,
+{
+    let (min, max) = ((*range.start()).into(), (*range.end()).into());
+
+    min + ratio * (max - min)
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{Point, Rectangle};
+
+    use super::{lerp, ratio, ratios_at};
+
+    #[test]
+    fn vertical_values_grow_upwards() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 10.0,
+            width: 100.0,
+            height: 50.0,
+        };
+
+        let at = |x: f32, y: f32, expected: (f32, f32)| {
+            let (ratio_x, ratio_y) = ratios_at(bounds, Point::new(x, y));
+
+            (ratio_x - expected.0).abs() < f32::EPSILON
+                && (ratio_y - expected.1).abs() < f32::EPSILON
+        };
+
+        assert!(at(10.0, 60.0, (0.0, 0.0)));
+        assert!(at(110.0, 10.0, (1.0, 1.0)));
+        assert!(at(60.0, 35.0, (0.5, 0.5)));
+        assert!(at(-20.0, 200.0, (0.0, 0.0)));
+    }
+
+    #[test]
+    fn maps_between_ratios_and_ranges() {
+        let range = -90.0_f32..=90.0;
+
+        assert!((lerp(&range, 0.25) + 45.0).abs() < f64::EPSILON);
+        assert!((ratio(&range, 45.0) - 0.75).abs() < f32::EPSILON);
+        assert!(ratio(&range, -120.0).abs() < f32::EPSILON);
+        assert!(ratio(&(5.0_f32..=5.0), 5.0).abs() < f32::EPSILON);
+    }
+}
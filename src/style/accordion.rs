@@ -0,0 +1,144 @@
+//! Show titled sections that expand and collapse their content.
+//!
+//! *This API requires the following crate features to be activated: accordion*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of an [`Accordion`](crate::native::accordion::Accordion).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of a section.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of a section.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of a section.
+    pub border_width: f32,
+
+    /// The border color of a section.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The background of the header of a section.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub header_background: Background,
+
+    /// The text color of the title of a section.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub header_text_color: Color,
+
+    /// The color of the chevron of a section.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The text color of the content of a section.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            header_background: Background::Color([0.87, 0.87, 0.87].into()),
+            header_text_color: Color::BLACK,
+            icon_color: Color::BLACK,
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of an [`Accordion`](crate::native::accordion::Accordion).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of an
+    /// [`Accordion`](crate::native::accordion::Accordion).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a section whose header is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Accordion`](crate::native::accordion::Accordion)
+    /// is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Accordion`](crate::native::accordion::Accordion)
+    /// is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Accordion`](crate::native::accordion::Accordion)
+    /// is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of an
+/// [`Accordion`](crate::native::accordion::Accordion).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AccordionStyles {
+    #[default]
+    Default,
+    Patched(Patched<AccordionStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = AccordionStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let AccordionStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            icon_color: palette.background.weak.text,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.accordion, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            header_background: palette.primary.weak.color.into(),
+            header_text_color: palette.primary.weak.text,
+            icon_color: palette.primary.weak.text,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Show a person as an image or their initials.
+//!
+//! *This API requires the following crate features to be activated: avatar*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of an [`Avatar`](crate::native::avatar::Avatar).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the initials.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the surface behind the avatar.
+    ///
+    /// It rounds off the corners of images and separates the presence dot and
+    /// the stacked avatars of a group.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub surface_color: Color,
+
+    /// The width of the ring around the avatar.
+    pub border_width: f32,
+
+    /// The color of the ring around the avatar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the presence dot of an online person.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub online_color: Color,
+
+    /// The color of the presence dot of an away person.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub away_color: Color,
+
+    /// The color of the presence dot of a busy person.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub busy_color: Color,
+
+    /// The color of the presence dot of an offline person.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub offline_color: Color,
+
+    /// The background of the avatar counting the hidden avatars of a group.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub overflow_background: Color,
+
+    /// The text color of the avatar counting the hidden avatars of a group.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub overflow_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            text_color: Color::WHITE,
+            surface_color: Color::WHITE,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            online_color: [0.2, 0.7, 0.3].into(),
+            away_color: [0.95, 0.7, 0.1].into(),
+            busy_color: [0.85, 0.2, 0.2].into(),
+            offline_color: [0.6, 0.6, 0.6].into(),
+            overflow_background: [0.87, 0.87, 0.87].into(),
+            overflow_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of an [`Avatar`](crate::native::avatar::Avatar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of an [`Avatar`](crate::native::avatar::Avatar).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Avatar`](crate::native::avatar::Avatar) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Avatar`](crate::native::avatar::Avatar) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Avatar`](crate::native::avatar::Avatar) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Avatar`](crate::native::avatar::Avatar) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of an [`Avatar`](crate::native::avatar::Avatar).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AvatarStyles {
+    #[default]
+    Default,
+    Patched(Patched<AvatarStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = AvatarStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let AvatarStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            surface_color: palette.background.base.color,
+            online_color: palette.success.base.color,
+            busy_color: palette.danger.base.color,
+            offline_color: palette.background.strong.color,
+            overflow_background: palette.background.weak.color,
+            overflow_text_color: palette.background.weak.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.avatar, appearance)
+    }
+}
@@ -1,28 +1,49 @@
 //! Use a badge for color highlighting important information.
 //!
 //! *This API requires the following crate features to be activated: badge*
-use super::colors;
+use super::{colors, Gradient};
 
-use iced_native::{Background, Color};
+use iced_native::{renderer::BorderRadius, Background, Color};
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The background of the [`Badge`](crate::native::badge::Badge).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 
+    /// The optional [`Gradient`](Gradient) of the [`Badge`](crate::native::badge::Badge).
+    /// If specified, it fills the badge instead of the background.
+    pub gradient: Option<Gradient>,
+
     /// The border radius of the [`Badge`](crate::native::badge::Badge).
     /// If no radius is specified the default one will be used.
-    pub border_radius: Option<f32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_border_radius")
+    )]
+    pub border_radius: Option<BorderRadius>,
 
     /// The border with of the [`Badge`](crate::native::badge::Badge).
     pub border_width: f32,
 
     /// The border color of the [`Badge`](crate::native::badge::Badge).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_color")
+    )]
     pub border_color: Option<Color>,
 
     /// The default text color of the [`Badge`](crate::native::badge::Badge).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub text_color: Color,
 }
 
@@ -37,12 +58,28 @@ pub trait StyleSheet {
     fn hovered(&self, style: Self::Style) -> Appearance {
         self.active(style)
     }
+
+    /// The appearance when the [`Badge`](crate::native::badge::Badge) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Badge`](crate::native::badge::Badge) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Badge`](crate::native::badge::Badge) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 impl std::default::Default for Appearance {
     fn default() -> Self {
         Self {
             background: Background::Color([0.87, 0.87, 0.87].into()),
+            gradient: None,
             border_radius: None,
             border_width: 1.0,
             border_color: Some([0.8, 0.8, 0.8].into()),
@@ -66,12 +103,15 @@ pub enum BadgeStyles {
     White,
     #[default]
     Default,
+    Patched(Patched<BadgeStyles, Appearance>),
 }
 
 impl StyleSheet for Theme {
     type Style = BadgeStyles;
 
     fn active(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
         let from_colors = |color: Color, text_color: Color| Appearance {
             background: Background::Color(color),
             border_color: Some(color),
@@ -79,7 +119,8 @@ impl StyleSheet for Theme {
             ..Appearance::default()
         };
 
-        match style {
+        let appearance = match style {
+            BadgeStyles::Patched(patched) => return patched.apply(self.active(*patched.base)),
             BadgeStyles::Primary => from_colors(colors::PRIMARY, colors::WHITE),
             BadgeStyles::Secondary => from_colors(colors::SECONDARY, colors::WHITE),
             BadgeStyles::Success => from_colors(colors::SUCCESS, colors::WHITE),
@@ -89,8 +130,15 @@ impl StyleSheet for Theme {
             BadgeStyles::Light => from_colors(colors::LIGHT, colors::BLACK),
             BadgeStyles::Dark => from_colors(colors::DARK, colors::WHITE),
             BadgeStyles::White => from_colors(colors::WHITE, colors::BLACK),
-            BadgeStyles::Default => Appearance::default(),
-        }
+            BadgeStyles::Default => Appearance {
+                background: Background::Color(palette.background.weak.color),
+                border_color: Some(palette.background.strong.color),
+                text_color: palette.background.weak.text,
+                ..Appearance::default()
+            },
+        };
+
+        customize(self, |hooks| hooks.badge, appearance)
     }
 
     fn hovered(&self, style: Self::Style) -> Appearance {
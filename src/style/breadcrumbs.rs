@@ -0,0 +1,122 @@
+//! Show the path to the current page as a trail of pressable segments.
+//!
+//! *This API requires the following crate features to be activated: breadcrumbs*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The text color of a segment.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The text color of the last segment, the current page.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub current_text_color: Color,
+
+    /// The color of the separators and of the ellipsis.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub separator_color: Color,
+
+    /// The background of a hovered segment.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The text color of a hovered segment.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_text_color: Color,
+
+    /// The border radius of a hovered segment.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            text_color: [0.0, 0.4, 0.8].into(),
+            current_text_color: Color::BLACK,
+            separator_color: [0.6, 0.6, 0.6].into(),
+            hovered_background: Background::Color([0.9, 0.9, 0.9].into()),
+            hovered_text_color: [0.0, 0.3, 0.6].into(),
+            border_radius: 4.0.into(),
+        }
+    }
+}
+
+/// The appearance of [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of
+    /// [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of
+/// [`Breadcrumbs`](crate::native::breadcrumbs::Breadcrumbs).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum BreadcrumbsStyles {
+    #[default]
+    Default,
+    Patched(Patched<BreadcrumbsStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = BreadcrumbsStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let BreadcrumbsStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            text_color: palette.primary.base.color,
+            current_text_color: palette.background.base.text,
+            separator_color: palette.background.strong.color,
+            hovered_background: palette.background.weak.color.into(),
+            hovered_text_color: palette.primary.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.breadcrumbs, appearance)
+    }
+}
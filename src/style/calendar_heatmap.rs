@@ -0,0 +1,143 @@
+//! Show values of the days of a year as colored cells.
+//!
+//! *This API requires the following crate features to be activated: calendar_heatmap*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a
+/// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the days without a value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub empty_color: Color,
+
+    /// The color of the days in the lowest bucket.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub low_color: Color,
+
+    /// The color of the days in the highest bucket.
+    ///
+    /// The buckets between the lowest and the highest one are blended from
+    /// the low and the high color.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub high_color: Color,
+
+    /// The border radius of the days.
+    pub cell_border_radius: f32,
+
+    /// The border color of the hovered day.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_border_color: Color,
+
+    /// The text color of the names of the months and the weekdays.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_color: Color,
+
+    /// The background of the tooltip of the hovered day.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub tooltip_background: Background,
+
+    /// The text color of the tooltip of the hovered day.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub tooltip_text_color: Color,
+
+    /// The border radius of the tooltip of the hovered day.
+    pub tooltip_border_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            empty_color: [0.92, 0.93, 0.94].into(),
+            low_color: [0.61, 0.91, 0.66].into(),
+            high_color: [0.13, 0.43, 0.22].into(),
+            cell_border_radius: 2.0,
+            hovered_border_color: Color::BLACK,
+            label_color: [0.4, 0.4, 0.4].into(),
+            tooltip_background: Background::Color([0.14, 0.16, 0.18].into()),
+            tooltip_text_color: Color::WHITE,
+            tooltip_border_radius: 4.0,
+        }
+    }
+}
+
+/// The appearance of a
+/// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`CalendarHeatmap`](crate::native::calendar_heatmap::CalendarHeatmap).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum CalendarHeatmapStyles {
+    #[default]
+    Default,
+    Patched(Patched<CalendarHeatmapStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = CalendarHeatmapStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let CalendarHeatmapStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            empty_color: palette.background.weak.color,
+            low_color: palette.primary.weak.color,
+            high_color: palette.primary.strong.color,
+            hovered_border_color: palette.background.base.text,
+            label_color: palette.background.strong.color,
+            tooltip_background: palette.background.base.text.into(),
+            tooltip_text_color: palette.background.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.calendar_heatmap, appearance)
+    }
+}
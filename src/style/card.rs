@@ -2,44 +2,78 @@
 //!
 //! *This API requires the following crate features to be activated: card*
 
-use super::colors;
-use iced_native::{Background, Color};
+use super::{colors, Gradient};
+use iced_native::{renderer::BorderRadius, Background, Color};
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a [`Card`](crate::native::card::Card).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The background of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`Card`](crate::native::card::Card).
-    pub border_radius: f32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
 
     /// The border width of the [`Card`](crate::native::card::Card).
     pub border_width: f32,
 
     /// The border color of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
 
     /// The background of the head of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub head_background: Background,
 
+    /// The optional [`Gradient`](Gradient) of the head of the
+    /// [`Card`](crate::native::card::Card). If specified, it fills the head
+    /// instead of the head background.
+    pub head_gradient: Option<Gradient>,
+
     /// The text color of the head of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub head_text_color: Color,
 
     /// The background of the body of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub body_background: Background,
 
     /// The text color of the body of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub body_text_color: Color,
 
     /// The background of the foot of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub foot_background: Background,
 
     /// The text color of the foot of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub foot_text_color: Color,
 
     /// The color of the close icon of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub close_color: Color,
 }
 
@@ -49,6 +83,26 @@ pub trait StyleSheet {
     type Style: std::default::Default + Copy;
     /// The normal appearance of a [`Card`](crate::native::card::Card).
     fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Card`](crate::native::card::Card) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Card`](crate::native::card::Card) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Card`](crate::native::card::Card) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Card`](crate::native::card::Card) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -66,6 +120,7 @@ pub enum CardStyles {
     White,
     #[default]
     Default,
+    Patched(Patched<CardStyles, Appearance>),
 }
 
 impl StyleSheet for Theme {
@@ -95,7 +150,8 @@ impl StyleSheet for Theme {
             ..Appearance::default()
         };
 
-        match style {
+        let appearance = match style {
+            CardStyles::Patched(patched) => return patched.apply(self.active(*patched.base)),
             CardStyles::Primary => backing_with_text(colors::PRIMARY, colors::WHITE),
             CardStyles::Secondary => backing_with_text(colors::SECONDARY, colors::WHITE),
             CardStyles::Success => backing_with_text(colors::SUCCESS, colors::WHITE),
@@ -105,8 +161,14 @@ impl StyleSheet for Theme {
             CardStyles::Light => backing_only(colors::LIGHT),
             CardStyles::Dark => backing_with_text(colors::DARK, colors::WHITE),
             CardStyles::White => backing_only(colors::WHITE),
-            CardStyles::Default => backing_only([0.87, 0.87, 0.87].into()),
-        }
+            CardStyles::Default => Appearance {
+                head_text_color: palette.background.weak.text,
+                close_color: palette.background.weak.text,
+                ..backing_only(palette.background.weak.color)
+            },
+        };
+
+        customize(self, |hooks| hooks.card, appearance)
     }
 }
 
@@ -114,10 +176,11 @@ impl std::default::Default for Appearance {
     fn default() -> Self {
         Self {
             background: Color::WHITE.into(),
-            border_radius: 10.0,
+            border_radius: 10.0.into(),
             border_width: 1.0,
             border_color: [0.87, 0.87, 0.87].into(),
             head_background: Background::Color([0.87, 0.87, 0.87].into()),
+            head_gradient: None,
             head_text_color: Color::BLACK,
             body_background: Color::TRANSPARENT.into(),
             body_text_color: Color::BLACK,
@@ -0,0 +1,120 @@
+//! Page through elements one at a time.
+//!
+//! *This API requires the following crate features to be activated: carousel*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Carousel`](crate::native::carousel::Carousel).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the previous and next arrows.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub arrow_background: Background,
+
+    /// The color of the previous and next arrows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub arrow_color: Color,
+
+    /// The color of the dots of the other elements.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub dot_color: Color,
+
+    /// The color of the dot of the shown element.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub active_dot_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            arrow_background: Background::Color([1.0, 1.0, 1.0, 0.8].into()),
+            arrow_color: Color::BLACK,
+            dot_color: [0.7, 0.7, 0.7].into(),
+            active_dot_color: [0.2, 0.4, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Carousel`](crate::native::carousel::Carousel).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`Carousel`](crate::native::carousel::Carousel).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered arrow of a
+    /// [`Carousel`](crate::native::carousel::Carousel).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Carousel`](crate::native::carousel::Carousel) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Carousel`](crate::native::carousel::Carousel) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Carousel`](crate::native::carousel::Carousel) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Carousel`](crate::native::carousel::Carousel).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum CarouselStyles {
+    #[default]
+    Default,
+    Patched(Patched<CarouselStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = CarouselStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let CarouselStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            arrow_background: Color {
+                a: 0.8,
+                ..palette.background.base.color
+            }
+            .into(),
+            arrow_color: palette.background.base.text,
+            dot_color: palette.background.strong.color,
+            active_dot_color: palette.primary.base.color,
+        };
+
+        customize(self, |hooks| hooks.carousel, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            arrow_background: palette.background.base.color.into(),
+            ..active
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Plot values as lines, bars or slices of a pie.
+//!
+//! *This API requires the following crate features to be activated: chart*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`LineChart`](crate::native::chart::LineChart), a
+/// [`BarChart`](crate::native::chart::BarChart) and a
+/// [`PieChart`](crate::native::chart::PieChart).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the chart.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The color of the axes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub axis_color: Color,
+
+    /// The color of the grid lines at the ticks of the value axis.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub grid_color: Color,
+
+    /// The color of the tick labels and of the legend.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color highlighting the hovered category.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub highlight_color: Color,
+
+    /// The color of the labels on the slices of a pie chart.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub slice_text_color: Color,
+
+    /// The background of the tooltip showing the values of the hovered
+    /// category.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub tooltip_background: Background,
+
+    /// The text color of the tooltip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            axis_color: [0.5, 0.5, 0.5].into(),
+            grid_color: [0.9, 0.9, 0.9].into(),
+            text_color: Color::BLACK,
+            highlight_color: [0.95, 0.95, 0.95].into(),
+            slice_text_color: Color::WHITE,
+            tooltip_background: Color::from_rgb(0.2, 0.2, 0.2).into(),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`LineChart`](crate::native::chart::LineChart), a
+/// [`BarChart`](crate::native::chart::BarChart) and a
+/// [`PieChart`](crate::native::chart::PieChart).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a chart.
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the chart is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the chart is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the chart is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the chart is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`LineChart`](crate::native::chart::LineChart),
+/// a [`BarChart`](crate::native::chart::BarChart) and a
+/// [`PieChart`](crate::native::chart::PieChart).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ChartStyles {
+    #[default]
+    Default,
+    Patched(Patched<ChartStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ChartStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ChartStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            axis_color: palette.background.strong.color,
+            grid_color: palette.background.weak.color,
+            text_color: palette.background.base.text,
+            highlight_color: palette.background.weak.color,
+            slice_text_color: Color::WHITE,
+            tooltip_background: palette.background.strong.color.into(),
+            tooltip_text_color: palette.background.strong.text,
+        };
+
+        customize(self, |hooks| hooks.chart, appearance)
+    }
+}
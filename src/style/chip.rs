@@ -0,0 +1,143 @@
+//! Show a compact label that can be pressed, toggled or deleted.
+//!
+//! *This API requires the following crate features to be activated: chip*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Chip`](crate::native::chip::Chip).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the chip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the chip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the chip.
+    pub border_width: f32,
+
+    /// The border color of the chip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the label.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the check and of the delete icon.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 8.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            icon_color: [0.3, 0.3, 0.3].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Chip`](crate::native::chip::Chip).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Chip`](crate::native::chip::Chip).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Chip`](crate::native::chip::Chip) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Chip`](crate::native::chip::Chip) is
+    /// selected.
+    fn selected(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Chip`](crate::native::chip::Chip) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Chip`](crate::native::chip::Chip) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Chip`](crate::native::chip::Chip) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Chip`](crate::native::chip::Chip).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ChipStyles {
+    #[default]
+    Default,
+    Patched(Patched<ChipStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ChipStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ChipStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            icon_color: palette.background.strong.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.chip, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            ..active
+        }
+    }
+
+    fn selected(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.weak.color.into(),
+            border_color: palette.primary.weak.color,
+            text_color: palette.primary.weak.text,
+            icon_color: palette.primary.weak.text,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Show the progress of a task on a ring.
+//!
+//! *This API requires the following crate features to be activated: circular_progress*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a
+/// [`CircularProgress`](crate::native::circular_progress::CircularProgress).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the full ring behind the arc.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub track_color: Color,
+
+    /// The color of the arc showing the progress.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub bar_color: Color,
+
+    /// The color of the label.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            track_color: [0.87, 0.87, 0.87].into(),
+            bar_color: [0.2, 0.4, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a
+/// [`CircularProgress`](crate::native::circular_progress::CircularProgress).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`CircularProgress`](crate::native::circular_progress::CircularProgress).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`CircularProgress`](crate::native::circular_progress::CircularProgress) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CircularProgress`](crate::native::circular_progress::CircularProgress) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CircularProgress`](crate::native::circular_progress::CircularProgress) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CircularProgress`](crate::native::circular_progress::CircularProgress) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`CircularProgress`](crate::native::circular_progress::CircularProgress).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum CircularProgressStyles {
+    #[default]
+    Default,
+    Success,
+    Danger,
+    Patched(Patched<CircularProgressStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = CircularProgressStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let bar_color = match style {
+            CircularProgressStyles::Patched(patched) => {
+                return patched.apply(self.active(*patched.base));
+            }
+            CircularProgressStyles::Default => palette.primary.base.color,
+            CircularProgressStyles::Success => palette.success.base.color,
+            CircularProgressStyles::Danger => palette.danger.base.color,
+        };
+
+        let appearance = Appearance {
+            track_color: palette.background.weak.color,
+            bar_color,
+            text_color: palette.background.base.text,
+        };
+
+        customize(self, |hooks| hooks.circular_progress, appearance)
+    }
+}
@@ -0,0 +1,169 @@
+//! Edit code with line numbers and syntax highlighting.
+//!
+//! *This API requires the following crate features to be activated: code_editor*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`CodeEditor`](crate::native::code_editor::CodeEditor).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the code editor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the code editor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the code editor.
+    pub border_width: f32,
+
+    /// The border color of the code editor.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The background of the gutter showing the line numbers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub gutter_background: Background,
+
+    /// The color of the line numbers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub line_number_color: Color,
+
+    /// The color of the number of the line with the cursor.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub current_line_number_color: Color,
+
+    /// The color of the code that isn't highlighted.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The background of the line with the cursor.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub current_line_color: Color,
+
+    /// The color of the selection.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selection_color: Color,
+
+    /// The color of the cursor.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub cursor_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            gutter_background: Color::from_rgb(0.96, 0.96, 0.96).into(),
+            line_number_color: [0.6, 0.6, 0.6].into(),
+            current_line_number_color: [0.2, 0.2, 0.2].into(),
+            text_color: Color::BLACK,
+            current_line_color: Color::from_rgba(0.0, 0.0, 0.0, 0.04),
+            selection_color: Color::from_rgba(0.2, 0.4, 0.8, 0.3),
+            cursor_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`CodeEditor`](crate::native::code_editor::CodeEditor).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`CodeEditor`](crate::native::code_editor::CodeEditor).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a focused
+    /// [`CodeEditor`](crate::native::code_editor::CodeEditor).
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CodeEditor`](crate::native::code_editor::CodeEditor) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CodeEditor`](crate::native::code_editor::CodeEditor) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`CodeEditor`](crate::native::code_editor::CodeEditor) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`CodeEditor`](crate::native::code_editor::CodeEditor).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum CodeEditorStyles {
+    #[default]
+    Default,
+    Patched(Patched<CodeEditorStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = CodeEditorStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let CodeEditorStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            gutter_background: palette.background.weak.color.into(),
+            line_number_color: palette.background.strong.color,
+            current_line_number_color: palette.background.base.text,
+            text_color: palette.background.base.text,
+            current_line_color: Color {
+                a: 0.5,
+                ..palette.background.weak.color
+            },
+            selection_color: Color {
+                a: 0.4,
+                ..palette.primary.weak.color
+            },
+            cursor_color: palette.primary.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.code_editor, appearance)
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -5,10 +5,17 @@
 use iced_native::{Background, Color};
 use iced_style::Theme;
 
+use super::theme::builder::customize;
+
 /// The appearance of a [`ColorPicker`](crate::native::ColorPicker).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// The background of the [`ColorPicker`](crate::native::ColorPicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`ColorPicker`](crate::native::ColorPicker).
@@ -18,6 +25,7 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`ColorPicker`](crate::native::ColorPicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
 
     /// The border radius of the bars of the [`ColorPicker`](crate::native::ColorPicker).
@@ -27,6 +35,7 @@ pub struct Appearance {
     pub bar_border_width: f32,
 
     /// The border color of the bars of the [`ColorPicker`](crate::native::ColorPicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub bar_border_color: Color,
 }
 
@@ -48,6 +57,18 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`ColorPicker`](crate::native::ColorPicker).
     fn focused(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`ColorPicker`](crate::native::ColorPicker) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ColorPicker`](crate::native::ColorPicker) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of the [`ColorPicker`](crate::native::ColorPicker).
@@ -61,7 +82,7 @@ impl StyleSheet for Theme {
         let palette = self.extended_palette();
         let foreground = self.palette();
 
-        Appearance {
+        let appearance = Appearance {
             background: palette.background.base.color.into(),
             border_radius: 15.0,
             border_width: 1.0,
@@ -69,7 +90,9 @@ impl StyleSheet for Theme {
             bar_border_radius: 5.0,
             bar_border_width: 1.0,
             bar_border_color: foreground.text,
-        }
+        };
+
+        customize(self, |hooks| hooks.color_picker, appearance)
     }
 
     fn selected(&self, style: Self::Style) -> Appearance {
@@ -0,0 +1,137 @@
+//! Type into a field and pick one of the options matching the text.
+//!
+//! *This API requires the following crate features to be activated: combo_box*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of the dropdown of a
+/// [`ComboBox`](crate::native::combo_box::ComboBox).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the dropdown.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the dropdown.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the dropdown.
+    pub border_width: f32,
+
+    /// The border color of the dropdown.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of an option.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The text color of the part of an option matching the typed text.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub match_color: Color,
+
+    /// The background of the highlighted option.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub highlighted_background: Background,
+
+    /// The text color of the highlighted option.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub highlighted_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 0.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            match_color: [0.2, 0.4, 0.8].into(),
+            highlighted_background: Background::Color([0.87, 0.87, 0.87].into()),
+            highlighted_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of the dropdown of a
+/// [`ComboBox`](crate::native::combo_box::ComboBox).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of the dropdown of a
+    /// [`ComboBox`](crate::native::combo_box::ComboBox).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`ComboBox`](crate::native::combo_box::ComboBox) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ComboBox`](crate::native::combo_box::ComboBox) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ComboBox`](crate::native::combo_box::ComboBox) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ComboBox`](crate::native::combo_box::ComboBox) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`ComboBox`](crate::native::combo_box::ComboBox).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ComboBoxStyles {
+    #[default]
+    Default,
+    Patched(Patched<ComboBoxStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ComboBoxStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ComboBoxStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            match_color: palette.primary.strong.color,
+            highlighted_background: palette.primary.weak.color.into(),
+            highlighted_text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.combo_box, appearance)
+    }
+}
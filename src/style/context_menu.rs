@@ -5,12 +5,20 @@ use iced_graphics::Color;
 use iced_native::Background;
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a [`ContextMenu`](crate::native::ContextMenu).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The backgronud of the [`ContextMenu`](crate::native::ContextMenu).
     ///
     /// This is used to color the backdrop of the modal.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 }
 
@@ -28,6 +36,30 @@ pub trait StyleSheet {
     type Style: Default + Copy;
     /// The normal appearance of a [`ContextMenu`](crate::native::ContextMenu).
     fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`ContextMenu`](crate::native::ContextMenu) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ContextMenu`](crate::native::ContextMenu) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ContextMenu`](crate::native::ContextMenu) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`ContextMenu`](crate::native::ContextMenu) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of a [`ContextMenu`](crate::native::ContextMenu).
@@ -36,20 +68,27 @@ pub trait StyleSheet {
 pub enum ContextMenuStyle {
     #[default]
     Default,
+    Patched(Patched<ContextMenuStyle, Appearance>),
 }
 
 impl StyleSheet for Theme {
     type Style = ContextMenuStyle;
 
-    fn active(&self, _style: Self::Style) -> Appearance {
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ContextMenuStyle::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
         let palette = self.extended_palette();
 
-        Appearance {
+        let appearance = Appearance {
             background: Color {
                 a: 0f32,
                 ..palette.background.base.color
             }
             .into(),
-        }
+        };
+
+        customize(self, |hooks| hooks.context_menu, appearance)
     }
 }
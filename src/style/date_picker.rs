@@ -5,10 +5,17 @@
 use iced_native::{Background, Color};
 use iced_style::Theme;
 
+use super::theme::builder::customize;
+
 /// The appearance of a [`DatePicker`](crate::native::DatePicker).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// The background of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`DatePicker`](crate::native::DatePicker).
@@ -18,17 +25,24 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
 
     /// The text color of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub text_color: Color,
 
     /// The attenuated color of the days which are not in the selected month
     /// of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub text_attenuated_color: Color,
 
     /// The background of the days in the calender of the
     /// [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub day_background: Background,
 }
 
@@ -50,6 +64,18 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`DatePicker`](crate::native::DatePicker).
     fn focused(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`DatePicker`](crate::native::DatePicker) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`DatePicker`](crate::native::DatePicker) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of the [`DatePicker`](crate::native::DatePicker).
@@ -63,7 +89,7 @@ impl StyleSheet for Theme {
         let palette = self.extended_palette();
         let foreground = self.palette();
 
-        Appearance {
+        let appearance = Appearance {
             background: palette.background.base.color.into(),
             border_radius: 15.0,
             border_width: 1.0,
@@ -74,7 +100,9 @@ impl StyleSheet for Theme {
                 ..foreground.text
             },
             day_background: palette.background.base.color.into(),
-        }
+        };
+
+        customize(self, |hooks| hooks.date_picker, appearance)
     }
 
     fn selected(&self, style: Self::Style) -> Appearance {
@@ -98,8 +126,10 @@ impl StyleSheet for Theme {
     }
 
     fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
         Appearance {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            border_color: palette.background.strong.color,
             ..self.active(style)
         }
     }
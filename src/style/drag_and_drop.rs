@@ -0,0 +1,154 @@
+//! Drag payloads from sources and drop them onto targets.
+//!
+//! *This API requires the following crate features to be activated: drag_and_drop*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`DropTarget`](crate::native::drag_and_drop::DropTarget)
+/// and of the ghost of a [`DragSource`](crate::native::drag_and_drop::DragSource).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the drop target or of the ghost.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the drop target or of the ghost.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the drop target or of the ghost.
+    pub border_width: f32,
+
+    /// The border color of the drop target or of the ghost.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            border_radius: 4.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// The appearance of a [`DropTarget`](crate::native::drag_and_drop::DropTarget)
+/// and of the ghost of a [`DragSource`](crate::native::drag_and_drop::DragSource).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`DropTarget`](crate::native::drag_and_drop::DropTarget).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a
+    /// [`DropTarget`](crate::native::drag_and_drop::DropTarget) while a
+    /// payload it accepts is dragged elsewhere.
+    fn dragging(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a
+    /// [`DropTarget`](crate::native::drag_and_drop::DropTarget) while a
+    /// payload it accepts is dragged over it.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.dragging(style)
+    }
+
+    /// The appearance behind the ghost following the cursor while a
+    /// [`DragSource`](crate::native::drag_and_drop::DragSource) is dragged.
+    fn ghost(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`DropTarget`](crate::native::drag_and_drop::DropTarget) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`DropTarget`](crate::native::drag_and_drop::DropTarget) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`DropTarget`](crate::native::drag_and_drop::DropTarget) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`DropTarget`](crate::native::drag_and_drop::DropTarget) and of the ghost
+/// of a [`DragSource`](crate::native::drag_and_drop::DragSource).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DragAndDropStyles {
+    #[default]
+    Default,
+    Patched(Patched<DragAndDropStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = DragAndDropStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let DragAndDropStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        customize(self, |hooks| hooks.drag_and_drop, Appearance::default())
+    }
+
+    fn dragging(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_width: 1.0,
+            border_color: palette.primary.weak.color,
+            ..active
+        }
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let dragging = self.dragging(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: Color {
+                a: 0.2,
+                ..palette.primary.base.color
+            }
+            .into(),
+            border_width: 2.0,
+            border_color: palette.primary.base.color,
+            ..dragging
+        }
+    }
+
+    fn ghost(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_width: 1.0,
+            border_color: palette.primary.base.color,
+            ..active
+        }
+    }
+}
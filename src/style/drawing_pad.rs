@@ -0,0 +1,144 @@
+//! Capture freehand strokes, like a signature or a quick annotation.
+//!
+//! *This API requires the following crate features to be activated: drawing_pad*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`DrawingPad`](crate::native::drawing_pad::DrawingPad).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the pad.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the pad.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the pad.
+    pub border_width: f32,
+
+    /// The border color of the pad.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the strokes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub ink_color: Color,
+
+    /// The color of the placeholder and its guide line.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub placeholder_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            ink_color: Color::from_rgb(0.1, 0.1, 0.3),
+            placeholder_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// The appearance of a [`DrawingPad`](crate::native::drawing_pad::DrawingPad).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`DrawingPad`](crate::native::drawing_pad::DrawingPad).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`DrawingPad`](crate::native::drawing_pad::DrawingPad).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`DrawingPad`](crate::native::drawing_pad::DrawingPad)
+    /// while a stroke is drawn.
+    fn drawing(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`DrawingPad`](crate::native::drawing_pad::DrawingPad) is pressed,
+    /// which is the [`drawing`](StyleSheet::drawing) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.drawing(style)
+    }
+
+    /// The appearance when the
+    /// [`DrawingPad`](crate::native::drawing_pad::DrawingPad) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`DrawingPad`](crate::native::drawing_pad::DrawingPad) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`DrawingPad`](crate::native::drawing_pad::DrawingPad).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DrawingPadStyles {
+    #[default]
+    Default,
+    Patched(Patched<DrawingPadStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = DrawingPadStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let DrawingPadStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            ink_color: palette.background.base.text,
+            placeholder_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.drawing_pad, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            border_color: palette.primary.weak.color,
+            ..active
+        }
+    }
+
+    fn drawing(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Drop files from outside of the application onto an area.
+//!
+//! *This API requires the following crate features to be activated: drop_zone*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`DropZone`](crate::native::drop_zone::DropZone).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the drop zone.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the drop zone.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the drop zone.
+    pub border_width: f32,
+
+    /// The border color of the drop zone.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            border_radius: 8.0.into(),
+            border_width: 2.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`DropZone`](crate::native::drop_zone::DropZone).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`DropZone`](crate::native::drop_zone::DropZone).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when accepted files are dragged over the
+    /// [`DropZone`](crate::native::drop_zone::DropZone).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when only files without an accepted extension are
+    /// dragged over the [`DropZone`](crate::native::drop_zone::DropZone).
+    fn rejected(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`DropZone`](crate::native::drop_zone::DropZone) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`DropZone`](crate::native::drop_zone::DropZone) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`DropZone`](crate::native::drop_zone::DropZone) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`DropZone`](crate::native::drop_zone::DropZone).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DropZoneStyles {
+    #[default]
+    Default,
+    Patched(Patched<DropZoneStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = DropZoneStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let DropZoneStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.drop_zone, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.weak.color.into(),
+            border_color: palette.primary.base.color,
+            text_color: palette.primary.weak.text,
+            ..active
+        }
+    }
+
+    fn rejected(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.danger.weak.color.into(),
+            border_color: palette.danger.base.color,
+            text_color: palette.danger.weak.text,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Browse images in a viewer above a strip of thumbnails.
+//!
+//! *This API requires the following crate features to be activated: gallery*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Gallery`](crate::native::gallery::Gallery).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background behind the shown image.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The background of the strip of thumbnails.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub strip_background: Background,
+
+    /// The width of the border around the selected and the hovered
+    /// thumbnail.
+    pub border_width: f32,
+
+    /// The border color of the selected thumbnail.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selected_border_color: Color,
+
+    /// The border color of the hovered thumbnail.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::BLACK.into(),
+            strip_background: [0.95, 0.95, 0.95].into(),
+            border_width: 2.0,
+            selected_border_color: [0.2, 0.4, 0.8].into(),
+            hovered_border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Gallery`](crate::native::gallery::Gallery).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Gallery`](crate::native::gallery::Gallery).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Gallery`](crate::native::gallery::Gallery)
+    /// is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Gallery`](crate::native::gallery::Gallery) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Gallery`](crate::native::gallery::Gallery) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Gallery`](crate::native::gallery::Gallery) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Gallery`](crate::native::gallery::Gallery).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum GalleryStyles {
+    #[default]
+    Default,
+    Patched(Patched<GalleryStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = GalleryStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let GalleryStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            strip_background: palette.background.weak.color.into(),
+            selected_border_color: palette.primary.base.color,
+            hovered_border_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.gallery, appearance)
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            selected_border_color: palette.primary.strong.color,
+            border_width: active.border_width + 1.0,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Show a value on an arc between a minimum and a maximum.
+//!
+//! *This API requires the following crate features to be activated: gauge*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Gauge`](crate::native::gauge::Gauge).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the arc behind the indicator.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub track_color: Color,
+
+    /// The color of the filled indicator outside of the zones.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub bar_color: Color,
+
+    /// The color of the needle and its hub.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub needle_color: Color,
+
+    /// The color of the label and of the bounds of the range.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            track_color: [0.87, 0.87, 0.87].into(),
+            bar_color: [0.2, 0.4, 0.8].into(),
+            needle_color: [0.2, 0.2, 0.2].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Gauge`](crate::native::gauge::Gauge).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Gauge`](crate::native::gauge::Gauge).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Gauge`](crate::native::gauge::Gauge) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Gauge`](crate::native::gauge::Gauge) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Gauge`](crate::native::gauge::Gauge) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Gauge`](crate::native::gauge::Gauge) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Gauge`](crate::native::gauge::Gauge).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum GaugeStyles {
+    #[default]
+    Default,
+    Success,
+    Danger,
+    Patched(Patched<GaugeStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = GaugeStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let bar_color = match style {
+            GaugeStyles::Patched(patched) => {
+                return patched.apply(self.active(*patched.base));
+            }
+            GaugeStyles::Default => palette.primary.base.color,
+            GaugeStyles::Success => palette.success.base.color,
+            GaugeStyles::Danger => palette.danger.base.color,
+        };
+
+        let appearance = Appearance {
+            track_color: palette.background.weak.color,
+            bar_color,
+            needle_color: palette.background.base.text,
+            text_color: palette.background.base.text,
+        };
+
+        customize(self, |hooks| hooks.gauge, appearance)
+    }
+}
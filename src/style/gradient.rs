@@ -0,0 +1,79 @@
+//! A linear gradient between two colors used as a background.
+//!
+//! Since the renderers only fill quads with a single color, a [`Gradient`](Gradient)
+//! is drawn as a sequence of thin bands, each filled with the color at its
+//! center.
+use iced_native::Color;
+
+/// The direction in which a [`Gradient`](Gradient) runs from its start to its
+/// end color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Direction {
+    /// From the left to the right edge.
+    Horizontal,
+    /// From the top to the bottom edge.
+    #[default]
+    Vertical,
+}
+
+/// A linear gradient between two colors.
+///
+/// # Example
+/// ```
+/// # use iced_aw::style::gradient::{Direction, Gradient};
+/// # use iced_native::Color;
+/// #
+/// let gradient = Gradient::new(Color::from_rgb8(0x1E, 0x90, 0xFF), Color::WHITE)
+///     .direction(Direction::Horizontal);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// The color at the start of the [`Gradient`](Gradient).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub start: Color,
+
+    /// The color at the end of the [`Gradient`](Gradient).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub end: Color,
+
+    /// The direction of the [`Gradient`](Gradient).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub direction: Direction,
+}
+
+impl Gradient {
+    /// Creates a new vertical [`Gradient`](Gradient) from the start to the end
+    /// color.
+    #[must_use]
+    pub const fn new(start: Color, end: Color) -> Self {
+        Self {
+            start,
+            end,
+            direction: Direction::Vertical,
+        }
+    }
+
+    /// Sets the [`Direction`](Direction) of the [`Gradient`](Gradient).
+    #[must_use]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the color at the given offset between 0 (start) and 1 (end).
+    #[must_use]
+    pub fn color_at(&self, offset: f32) -> Color {
+        let offset = offset.clamp(0.0, 1.0);
+        let mix = |start: f32, end: f32| start + (end - start) * offset;
+
+        Color::from_rgba(
+            mix(self.start.r, self.end.r),
+            mix(self.start.g, self.end.g),
+            mix(self.start.b, self.end.b),
+            mix(self.start.a, self.end.a),
+        )
+    }
+}
@@ -0,0 +1,142 @@
+//! Show a matrix of values as colored cells.
+//!
+//! *This API requires the following crate features to be activated: heatmap*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Heatmap`](crate::native::heatmap::Heatmap).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the cells without a value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub empty_color: Color,
+
+    /// The color of the lowest values.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub low_color: Color,
+
+    /// The color of the center of a diverging color scale.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub middle_color: Color,
+
+    /// The color of the highest values.
+    ///
+    /// The values between the lowest and the highest ones are blended from
+    /// the low and the high color, through the middle color on a diverging
+    /// color scale.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub high_color: Color,
+
+    /// The border radius of the cells.
+    pub cell_border_radius: f32,
+
+    /// The border color of the hovered cell.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_border_color: Color,
+
+    /// The text color of the labels of the rows and the columns.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_color: Color,
+
+    /// The background of the tooltip of the hovered cell.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub tooltip_background: Background,
+
+    /// The text color of the tooltip of the hovered cell.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub tooltip_text_color: Color,
+
+    /// The border radius of the tooltip of the hovered cell.
+    pub tooltip_border_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            empty_color: [0.97, 0.97, 0.97].into(),
+            low_color: [0.23, 0.30, 0.75].into(),
+            middle_color: [0.87, 0.87, 0.87].into(),
+            high_color: [0.71, 0.02, 0.15].into(),
+            cell_border_radius: 0.0,
+            hovered_border_color: Color::BLACK,
+            label_color: [0.4, 0.4, 0.4].into(),
+            tooltip_background: Background::Color([0.14, 0.16, 0.18].into()),
+            tooltip_text_color: Color::WHITE,
+            tooltip_border_radius: 4.0,
+        }
+    }
+}
+
+/// The appearance of a [`Heatmap`](crate::native::heatmap::Heatmap).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Heatmap`](crate::native::heatmap::Heatmap).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Heatmap`](crate::native::heatmap::Heatmap) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Heatmap`](crate::native::heatmap::Heatmap) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Heatmap`](crate::native::heatmap::Heatmap) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Heatmap`](crate::native::heatmap::Heatmap) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Heatmap`](crate::native::heatmap::Heatmap).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HeatmapStyles {
+    #[default]
+    Default,
+    Patched(Patched<HeatmapStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = HeatmapStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let HeatmapStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            empty_color: palette.background.base.color,
+            low_color: palette.primary.strong.color,
+            middle_color: palette.background.weak.color,
+            high_color: palette.danger.strong.color,
+            hovered_border_color: palette.background.base.text,
+            label_color: palette.background.strong.color,
+            tooltip_background: palette.background.base.text.into(),
+            tooltip_text_color: palette.background.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.heatmap, appearance)
+    }
+}
@@ -0,0 +1,145 @@
+//! Record a keyboard shortcut by pressing it.
+//!
+//! *This API requires the following crate features to be activated: hotkey_input*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the field.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the field.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the field.
+    pub border_width: f32,
+
+    /// The border color of the field.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the recorded shortcut.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the placeholder and of the prompt while recording.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub placeholder_color: Color,
+
+    /// The color of the clear button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            placeholder_color: [0.6, 0.6, 0.6].into(),
+            icon_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+/// The appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput)
+    /// recording a shortcut while it is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HotkeyInputStyles {
+    #[default]
+    Default,
+    Patched(Patched<HotkeyInputStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = HotkeyInputStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let HotkeyInputStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            placeholder_color: palette.background.strong.color,
+            icon_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.hotkey_input, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.background.base.text,
+            icon_color: palette.background.base.text,
+            ..active
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_width: active.border_width.max(2.0),
+            border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Show a card previewing details while hovering a widget.
+//!
+//! *This API requires the following crate features to be activated: hover_card*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`HoverCard`](crate::native::hover_card::HoverCard).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the card.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the card.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the card.
+    pub border_width: f32,
+
+    /// The border color of the card.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The default text color of the content of the card.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 8.0.into(),
+            border_width: 1.0,
+            border_color: [0.85, 0.85, 0.85].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+// The card is drawn by the overlay of the tooltip
+impl From<Appearance> for super::tooltip::Appearance {
+    fn from(appearance: Appearance) -> Self {
+        Self {
+            background: appearance.background,
+            border_radius: appearance.border_radius,
+            border_width: appearance.border_width,
+            border_color: appearance.border_color,
+            text_color: appearance.text_color,
+        }
+    }
+}
+
+/// The appearance of a [`HoverCard`](crate::native::hover_card::HoverCard).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`HoverCard`](crate::native::hover_card::HoverCard).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`HoverCard`](crate::native::hover_card::HoverCard)
+    /// is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`HoverCard`](crate::native::hover_card::HoverCard)
+    /// is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`HoverCard`](crate::native::hover_card::HoverCard)
+    /// is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`HoverCard`](crate::native::hover_card::HoverCard)
+    /// is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`HoverCard`](crate::native::hover_card::HoverCard).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HoverCardStyles {
+    #[default]
+    Default,
+    Patched(Patched<HoverCardStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = HoverCardStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let HoverCardStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        // Unlike tooltips, hover cards look like the surfaces of the page
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.hover_card, appearance)
+    }
+}
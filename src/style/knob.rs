@@ -0,0 +1,153 @@
+//! Turn a value with a rotary knob.
+//!
+//! *This API requires the following crate features to be activated: knob*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Knob`](crate::native::knob::Knob).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the arc around the knob.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub track_color: Color,
+
+    /// The color of the arc up to the value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub filled_color: Color,
+
+    /// The color of the knob.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub knob_color: Color,
+
+    /// The border width of the knob.
+    pub knob_border_width: f32,
+
+    /// The border color of the knob.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub knob_border_color: Color,
+
+    /// The color of the line on the knob pointing at the value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub indicator_color: Color,
+
+    /// The color of the tick marks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub tick_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            track_color: [0.8, 0.8, 0.8].into(),
+            filled_color: [0.0, 0.4, 0.8].into(),
+            knob_color: Color::WHITE,
+            knob_border_width: 1.0,
+            knob_border_color: [0.6, 0.6, 0.6].into(),
+            indicator_color: [0.2, 0.2, 0.2].into(),
+            tick_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Knob`](crate::native::knob::Knob).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Knob`](crate::native::knob::Knob).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`Knob`](crate::native::knob::Knob).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a dragged [`Knob`](crate::native::knob::Knob).
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance of a focused [`Knob`](crate::native::knob::Knob).
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the [`Knob`](crate::native::knob::Knob) is pressed,
+    /// which is the [`dragged`](StyleSheet::dragged) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the [`Knob`](crate::native::knob::Knob) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Knob`](crate::native::knob::Knob).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum KnobStyles {
+    #[default]
+    Default,
+    Patched(Patched<KnobStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = KnobStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let KnobStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            track_color: palette.background.strong.color,
+            filled_color: palette.primary.base.color,
+            knob_color: palette.background.base.color,
+            knob_border_color: palette.background.strong.color,
+            indicator_color: palette.background.base.text,
+            tick_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.knob, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            knob_border_color: palette.primary.weak.color,
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            knob_border_color: palette.primary.base.color,
+            indicator_color: palette.primary.base.color,
+            ..active
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            knob_border_color: palette.primary.strong.color,
+            knob_border_width: active.knob_border_width + 1.0,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Dim content while it loads and show a spinner above it.
+//!
+//! *This API requires the following crate features to be activated: loading_overlay*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a
+/// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background dimming the content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub scrim: Background,
+
+    /// The color of the spinner.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub spinner_color: Color,
+
+    /// The text color of the message below the spinner.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub message_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            scrim: Background::Color([0.87, 0.87, 0.87, 0.7].into()),
+            spinner_color: [0.2, 0.4, 0.8].into(),
+            message_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a
+/// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The appearance of an active
+    /// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`LoadingOverlay`](crate::native::loading_overlay::LoadingOverlay).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum LoadingOverlayStyles {
+    #[default]
+    Default,
+    Patched(Patched<LoadingOverlayStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = LoadingOverlayStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let LoadingOverlayStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            scrim: Color {
+                a: palette.background.base.color.a * 0.7,
+                ..palette.background.base.color
+            }
+            .into(),
+            spinner_color: palette.primary.base.color,
+            message_color: palette.background.base.text,
+        };
+
+        customize(self, |hooks| hooks.loading_overlay, appearance)
+    }
+}
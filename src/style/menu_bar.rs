@@ -2,20 +2,27 @@
 use iced_native::Color;
 use iced_style::Theme;
 
+use super::theme::builder::customize;
+
 /// The appearance of a menu bar and its menus.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The background color of the menu bar and its menus.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub background: Color,
     /// The border width of the menu bar and its menus.
     pub border_width: f32,
     /// The border radius of the menu bar and its menus.
     pub border_radius: [f32; 4],
     /// The border [`Color`] of the menu bar and its menus.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
     /// The expand value of the menus' background
     pub background_expand: [u16; 4],
     /// The highlighted path [`Color`] of the the menu bar and its menus.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub path: Color,
 }
 impl std::default::Default for Appearance {
@@ -37,7 +44,31 @@ pub trait StyleSheet {
     type Style: Default;
 
     /// Produces the [`Appearance`] of a menu bar and its menus.
-    fn appearance(&self, style: &Self::Style) -> Appearance;
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`MenuBar`](crate::native::menu::MenuBar) is
+    /// hovered.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`MenuBar`](crate::native::menu::MenuBar) is
+    /// pressed.
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`MenuBar`](crate::native::menu::MenuBar) is
+    /// focused.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`MenuBar`](crate::native::menu::MenuBar) is
+    /// disabled.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The style of a menu bar and its menus
@@ -59,7 +90,7 @@ impl From<fn(&Theme) -> Appearance> for MenuBarStyle {
 impl StyleSheet for fn(&Theme) -> Appearance {
     type Style = Theme;
 
-    fn appearance(&self, style: &Self::Style) -> Appearance {
+    fn active(&self, style: &Self::Style) -> Appearance {
         (self)(style)
     }
 }
@@ -67,10 +98,10 @@ impl StyleSheet for fn(&Theme) -> Appearance {
 impl StyleSheet for Theme {
     type Style = MenuBarStyle;
 
-    fn appearance(&self, style: &Self::Style) -> Appearance {
+    fn active(&self, style: &Self::Style) -> Appearance {
         let palette = self.extended_palette();
 
-        match style {
+        let appearance = match style {
             MenuBarStyle::Default => Appearance {
                 background: palette.background.base.color,
                 border_width: 1.0,
@@ -79,7 +110,9 @@ impl StyleSheet for Theme {
                 background_expand: [6; 4],
                 path: palette.primary.weak.color,
             },
-            MenuBarStyle::Custom(c) => c.appearance(self),
-        }
+            MenuBarStyle::Custom(c) => c.active(self),
+        };
+
+        customize(self, |hooks| hooks.menu_bar, appearance)
     }
 }
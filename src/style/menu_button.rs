@@ -0,0 +1,166 @@
+//! A button opening a menu of items.
+//!
+//! *This API requires the following crate features to be activated: menu_button*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`MenuButton`](crate::native::menu_button::MenuButton).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the button and the menu.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the button and the menu.
+    pub border_width: f32,
+
+    /// The border color of the button and the menu.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the label of the button and of the items.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the caret of the button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The background of the menu.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub menu_background: Background,
+
+    /// The background of the hovered item.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The text color of the hovered item.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            icon_color: Color::BLACK,
+            menu_background: Color::WHITE.into(),
+            hovered_background: Background::Color([0.2, 0.4, 0.8].into()),
+            hovered_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`MenuButton`](crate::native::menu_button::MenuButton).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`MenuButton`](crate::native::menu_button::MenuButton).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered or open
+    /// [`MenuButton`](crate::native::menu_button::MenuButton).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a focused
+    /// [`MenuButton`](crate::native::menu_button::MenuButton).
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`MenuButton`](crate::native::menu_button::MenuButton) is pressed,
+    /// which is while its menu is open. This is the
+    /// [`hovered`](StyleSheet::hovered) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`MenuButton`](crate::native::menu_button::MenuButton) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`MenuButton`](crate::native::menu_button::MenuButton).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MenuButtonStyles {
+    #[default]
+    Default,
+    Patched(Patched<MenuButtonStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = MenuButtonStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let MenuButtonStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.weak.text,
+            icon_color: palette.background.weak.text,
+            menu_background: palette.background.base.color.into(),
+            hovered_background: palette.primary.weak.color.into(),
+            hovered_text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.menu_button, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.strong.color.into(),
+            ..active
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let hovered = self.hovered(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..hovered
+        }
+    }
+}
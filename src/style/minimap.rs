@@ -0,0 +1,181 @@
+//! Navigate a large content area from a scaled-down overview of it.
+//!
+//! *This API requires the following crate features to be activated: minimap*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Minimap`](crate::native::minimap::Minimap).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the minimap.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the minimap.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the minimap.
+    pub border_width: f32,
+
+    /// The border color of the minimap.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The background of the scaled-down content area.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub content_background: Background,
+
+    /// The color of the items without a color of their own.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub item_color: Color,
+
+    /// The background of the viewport rectangle.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub viewport_background: Background,
+
+    /// The border width of the viewport rectangle.
+    pub viewport_border_width: f32,
+
+    /// The border color of the viewport rectangle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub viewport_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            content_background: Color::WHITE.into(),
+            item_color: [0.6, 0.6, 0.6].into(),
+            viewport_background: Color::from_rgba(0.2, 0.4, 0.8, 0.15).into(),
+            viewport_border_width: 1.0,
+            viewport_border_color: Color::from_rgb(0.2, 0.4, 0.8),
+        }
+    }
+}
+
+/// The appearance of a [`Minimap`](crate::native::minimap::Minimap).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Minimap`](crate::native::minimap::Minimap).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a [`Minimap`](crate::native::minimap::Minimap)
+    /// whose viewport rectangle is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`Minimap`](crate::native::minimap::Minimap)
+    /// whose viewport rectangle is dragged.
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the [`Minimap`](crate::native::minimap::Minimap) is
+    /// pressed, which is the [`dragged`](StyleSheet::dragged) appearance by
+    /// default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the [`Minimap`](crate::native::minimap::Minimap) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Minimap`](crate::native::minimap::Minimap) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Minimap`](crate::native::minimap::Minimap).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MinimapStyles {
+    #[default]
+    Default,
+    Patched(Patched<MinimapStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = MinimapStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let MinimapStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            content_background: palette.background.base.color.into(),
+            item_color: palette.background.strong.color,
+            viewport_background: Color {
+                a: 0.15,
+                ..palette.primary.base.color
+            }
+            .into(),
+            viewport_border_color: palette.primary.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.minimap, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            viewport_background: Color {
+                a: 0.25,
+                ..palette.primary.base.color
+            }
+            .into(),
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            viewport_background: Color {
+                a: 0.3,
+                ..palette.primary.strong.color
+            }
+            .into(),
+            viewport_border_width: active.viewport_border_width + 1.0,
+            viewport_border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -1,34 +1,238 @@
 //! The appearance of the widgets
 
 pub mod colors;
+pub mod gradient;
+pub use gradient::Gradient;
+pub mod patch;
+pub mod presets;
 pub mod style_state;
+pub mod theme;
+pub use theme::Theme;
+
+#[cfg(feature = "serde")]
+pub mod serialization;
+
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "accordion")]
+pub use accordion::AccordionStyles;
+
+#[cfg(feature = "avatar")]
+pub mod avatar;
+#[cfg(feature = "avatar")]
+pub use avatar::AvatarStyles;
 
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
 pub use badge::BadgeStyles;
 
+#[cfg(feature = "breadcrumbs")]
+pub mod breadcrumbs;
+#[cfg(feature = "breadcrumbs")]
+pub use breadcrumbs::BreadcrumbsStyles;
+
+#[cfg(feature = "calendar_heatmap")]
+pub mod calendar_heatmap;
+#[cfg(feature = "calendar_heatmap")]
+pub use calendar_heatmap::CalendarHeatmapStyles;
+
 #[cfg(feature = "card")]
 pub mod card;
 #[cfg(feature = "card")]
 pub use card::CardStyles;
 
+#[cfg(feature = "carousel")]
+pub mod carousel;
+#[cfg(feature = "carousel")]
+pub use carousel::CarouselStyles;
+
+#[cfg(feature = "chart")]
+pub mod chart;
+#[cfg(feature = "chart")]
+pub use chart::ChartStyles;
+
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "chip")]
+pub use chip::ChipStyles;
+
+#[cfg(feature = "circular_progress")]
+pub mod circular_progress;
+#[cfg(feature = "circular_progress")]
+pub use circular_progress::CircularProgressStyles;
+
+#[cfg(feature = "code_editor")]
+pub mod code_editor;
+#[cfg(feature = "code_editor")]
+pub use code_editor::CodeEditorStyles;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBoxStyles;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 
+#[cfg(feature = "drag_and_drop")]
+pub mod drag_and_drop;
+#[cfg(feature = "drag_and_drop")]
+pub use drag_and_drop::DragAndDropStyles;
+
+#[cfg(feature = "drawing_pad")]
+pub mod drawing_pad;
+#[cfg(feature = "drawing_pad")]
+pub use drawing_pad::DrawingPadStyles;
+
+#[cfg(feature = "drop_zone")]
+pub mod drop_zone;
+#[cfg(feature = "drop_zone")]
+pub use drop_zone::DropZoneStyles;
+
+#[cfg(feature = "gallery")]
+pub mod gallery;
+#[cfg(feature = "gallery")]
+pub use gallery::GalleryStyles;
+
+#[cfg(feature = "gauge")]
+pub mod gauge;
+#[cfg(feature = "gauge")]
+pub use gauge::GaugeStyles;
+
+#[cfg(feature = "heatmap")]
+pub mod heatmap;
+#[cfg(feature = "heatmap")]
+pub use heatmap::HeatmapStyles;
+
+#[cfg(feature = "hotkey_input")]
+pub mod hotkey_input;
+#[cfg(feature = "hotkey_input")]
+pub use hotkey_input::HotkeyInputStyles;
+
+#[cfg(feature = "hover_card")]
+pub mod hover_card;
+#[cfg(feature = "hover_card")]
+pub use hover_card::HoverCardStyles;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+pub use knob::KnobStyles;
+
+#[cfg(feature = "loading_overlay")]
+pub mod loading_overlay;
+#[cfg(feature = "loading_overlay")]
+pub use loading_overlay::LoadingOverlayStyles;
+
+#[cfg(feature = "menu_button")]
+pub mod menu_button;
+#[cfg(feature = "menu_button")]
+pub use menu_button::MenuButtonStyles;
+
+#[cfg(feature = "minimap")]
+pub mod minimap;
+#[cfg(feature = "minimap")]
+pub use minimap::MinimapStyles;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
 pub use modal::ModalStyles;
 
+#[cfg(feature = "month_view")]
+pub mod month_view;
+#[cfg(feature = "month_view")]
+pub use month_view::MonthViewStyles;
+
+#[cfg(feature = "multi_select")]
+pub mod multi_select;
+#[cfg(feature = "multi_select")]
+pub use multi_select::MultiSelectStyles;
+
+#[cfg(feature = "pagination")]
+pub mod pagination;
+#[cfg(feature = "pagination")]
+pub use pagination::PaginationStyles;
+
+#[cfg(feature = "pin_input")]
+pub mod pin_input;
+#[cfg(feature = "pin_input")]
+pub use pin_input::PinInputStyles;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverStyles;
+
+#[cfg(feature = "property_grid")]
+pub mod property_grid;
+#[cfg(feature = "property_grid")]
+pub use property_grid::PropertyGridStyles;
+
+#[cfg(feature = "spreadsheet_grid")]
+pub mod spreadsheet_grid;
+#[cfg(feature = "spreadsheet_grid")]
+pub use spreadsheet_grid::SpreadsheetGridStyles;
+
+#[cfg(feature = "status_dot")]
+pub mod status_dot;
+#[cfg(feature = "status_dot")]
+pub use status_dot::StatusDotStyles;
+
+#[cfg(feature = "stepper")]
+pub mod stepper;
+#[cfg(feature = "stepper")]
+pub use stepper::StepperStyles;
+
+#[cfg(feature = "switch")]
+pub mod switch;
+#[cfg(feature = "switch")]
+pub use switch::SwitchStyles;
+
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+pub use table::TableStyles;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastStyles;
+
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "tooltip")]
+pub use tooltip::TooltipStyles;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+#[cfg(feature = "tour")]
+pub use tour::TourStyles;
+
+#[cfg(feature = "transfer_list")]
+pub mod transfer_list;
+#[cfg(feature = "transfer_list")]
+pub use transfer_list::TransferListStyles;
+
+#[cfg(feature = "virtual_list")]
+pub mod virtual_list;
+#[cfg(feature = "virtual_list")]
+pub use virtual_list::VirtualListStyles;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
 pub use tab_bar::TabBarStyles;
 
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+#[cfg(feature = "tag_input")]
+pub use tag_input::TagInputStyles;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 
@@ -37,11 +241,76 @@ pub mod number_input;
 #[cfg(feature = "number_input")]
 pub use number_input::NumberInputStyles;
 
+#[cfg(feature = "qr_code")]
+pub mod qr_code;
+#[cfg(feature = "qr_code")]
+pub use qr_code::QrCodeStyles;
+
+#[cfg(feature = "radial_menu")]
+pub mod radial_menu;
+#[cfg(feature = "radial_menu")]
+pub use radial_menu::RadialMenuStyles;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+pub use range_slider::RangeSliderStyles;
+
+#[cfg(feature = "tick_slider")]
+pub mod tick_slider;
+#[cfg(feature = "tick_slider")]
+pub use tick_slider::TickSliderStyles;
+
+#[cfg(feature = "xy_pad")]
+pub mod xy_pad;
+#[cfg(feature = "xy_pad")]
+pub use xy_pad::XYPadStyles;
+
+#[cfg(feature = "rating")]
+pub mod rating;
+#[cfg(feature = "rating")]
+pub use rating::RatingStyles;
+
+#[cfg(feature = "resizable")]
+pub mod resizable;
+#[cfg(feature = "resizable")]
+pub use resizable::ResizableStyles;
+
+#[cfg(feature = "rich_text")]
+pub mod rich_text;
+#[cfg(feature = "rich_text")]
+pub use rich_text::RichTextStyles;
+
+#[cfg(feature = "scrubber")]
+pub mod scrubber;
+#[cfg(feature = "scrubber")]
+pub use scrubber::ScrubberStyles;
+
+#[cfg(feature = "search_bar")]
+pub mod search_bar;
+#[cfg(feature = "search_bar")]
+pub use search_bar::SearchBarStyles;
+
+#[cfg(feature = "segmented_button")]
+pub mod segmented_button;
+#[cfg(feature = "segmented_button")]
+pub use segmented_button::SegmentedButtonStyles;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
 pub use selection_list::SelectionListStyles;
 
+#[cfg(feature = "shortcut_hint")]
+pub mod shortcut_hint;
+#[cfg(feature = "shortcut_hint")]
+pub use shortcut_hint::ShortcutHintStyles;
+
+#[cfg(feature = "skeleton")]
+pub mod skeleton;
+#[cfg(feature = "skeleton")]
+pub use skeleton::SkeletonStyles;
+
 #[cfg(feature = "split")]
 pub mod split;
 #[cfg(feature = "split")]
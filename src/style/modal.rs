@@ -6,12 +6,20 @@ use iced_graphics::Color;
 use iced_native::Background;
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a [`Modal`](crate::native::Modal).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The backgronud of the [`Modal`](crate::native::Modal).
     ///
     /// This is used to color the backdrop of the modal.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 }
 
@@ -28,6 +36,26 @@ pub trait StyleSheet {
     type Style: Default + Copy;
     /// The normal appearance of a [`Modal`](crate::native::Modal).
     fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Modal`](crate::native::Modal) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Modal`](crate::native::Modal) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Modal`](crate::native::Modal) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Modal`](crate::native::Modal) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of a [`Modal`](crate::native::Modal).
@@ -36,20 +64,27 @@ pub trait StyleSheet {
 pub enum ModalStyles {
     #[default]
     Default,
+    Patched(Patched<ModalStyles, Appearance>),
 }
 
 impl StyleSheet for Theme {
     type Style = ModalStyles;
 
-    fn active(&self, _style: Self::Style) -> Appearance {
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ModalStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
         let palette = self.extended_palette();
 
-        Appearance {
+        let appearance = Appearance {
             background: Color {
                 a: palette.background.base.color.a * 0.5,
                 ..palette.background.base.color
             }
             .into(),
-        }
+        };
+
+        customize(self, |hooks| hooks.modal, appearance)
     }
 }
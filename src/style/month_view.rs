@@ -0,0 +1,185 @@
+//! Show a month as a grid of days hosting events.
+//!
+//! *This API requires the following crate features to be activated: month_view*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`MonthView`](crate::native::month_view::MonthView).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the month view.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The width of the lines between the days.
+    pub grid_width: f32,
+
+    /// The color of the lines between the days.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub grid_color: Color,
+
+    /// The text color of the title and the arrows of the header.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub header_text_color: Color,
+
+    /// The text color of the names of the weekdays.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub weekday_text_color: Color,
+
+    /// The text color of the days of the month.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub day_text_color: Color,
+
+    /// The text color of the days of the previous and next months.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub outside_text_color: Color,
+
+    /// The background of the hovered day.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The background of the selected day.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub selected_background: Background,
+
+    /// The background behind the number of today.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub today_background: Background,
+
+    /// The text color of the number of today.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub today_text_color: Color,
+
+    /// The background of the events.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub event_background: Background,
+
+    /// The background of the hovered event.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub event_hovered_background: Background,
+
+    /// The text color of the events.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub event_text_color: Color,
+
+    /// The border radius of the events.
+    pub event_border_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            grid_width: 1.0,
+            grid_color: [0.85, 0.85, 0.85].into(),
+            header_text_color: Color::BLACK,
+            weekday_text_color: [0.4, 0.4, 0.4].into(),
+            day_text_color: Color::BLACK,
+            outside_text_color: [0.6, 0.6, 0.6].into(),
+            hovered_background: Background::Color([0.95, 0.95, 0.95].into()),
+            selected_background: Background::Color([0.87, 0.91, 0.98].into()),
+            today_background: Background::Color([0.2, 0.4, 0.8].into()),
+            today_text_color: Color::WHITE,
+            event_background: Background::Color([0.8, 0.86, 0.96].into()),
+            event_hovered_background: Background::Color([0.7, 0.79, 0.94].into()),
+            event_text_color: Color::BLACK,
+            event_border_radius: 3.0,
+        }
+    }
+}
+
+/// The appearance of a [`MonthView`](crate::native::month_view::MonthView).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`MonthView`](crate::native::month_view::MonthView).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`MonthView`](crate::native::month_view::MonthView)
+    /// is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`MonthView`](crate::native::month_view::MonthView)
+    /// is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`MonthView`](crate::native::month_view::MonthView)
+    /// is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`MonthView`](crate::native::month_view::MonthView)
+    /// is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`MonthView`](crate::native::month_view::MonthView).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MonthViewStyles {
+    #[default]
+    Default,
+    Patched(Patched<MonthViewStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = MonthViewStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let MonthViewStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            grid_color: palette.background.weak.color,
+            header_text_color: palette.background.base.text,
+            weekday_text_color: palette.background.strong.color,
+            day_text_color: palette.background.base.text,
+            outside_text_color: palette.background.strong.color,
+            hovered_background: palette.background.weak.color.into(),
+            selected_background: palette.primary.weak.color.into(),
+            today_background: palette.primary.strong.color.into(),
+            today_text_color: palette.primary.strong.text,
+            event_background: palette.primary.base.color.into(),
+            event_hovered_background: palette.primary.strong.color.into(),
+            event_text_color: palette.primary.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.month_view, appearance)
+    }
+}
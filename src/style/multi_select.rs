@@ -0,0 +1,178 @@
+//! Pick any number of options from a dropdown of checkboxes.
+//!
+//! *This API requires the following crate features to be activated: multi_select*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`MultiSelect`](crate::native::multi_select::MultiSelect).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the field.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the field and the dropdown.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the field and the dropdown.
+    pub border_width: f32,
+
+    /// The border color of the field and the dropdown.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the chosen options in the field and of the options
+    /// in the dropdown.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The text color of the placeholder shown while nothing is chosen.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub placeholder_color: Color,
+
+    /// The color of the caret of the field.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The background of the dropdown.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub menu_background: Background,
+
+    /// The background of the hovered option.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The border color of an unchecked checkbox.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub checkbox_border_color: Color,
+
+    /// The background of a checked checkbox.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub checked_background: Background,
+
+    /// The color of the check mark of a checked checkbox.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub check_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            placeholder_color: [0.4, 0.4, 0.4].into(),
+            icon_color: Color::BLACK,
+            menu_background: Color::WHITE.into(),
+            hovered_background: Background::Color([0.87, 0.87, 0.87].into()),
+            checkbox_border_color: [0.4, 0.4, 0.4].into(),
+            checked_background: Background::Color([0.2, 0.4, 0.8].into()),
+            check_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`MultiSelect`](crate::native::multi_select::MultiSelect).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`MultiSelect`](crate::native::multi_select::MultiSelect).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered or open
+    /// [`MultiSelect`](crate::native::multi_select::MultiSelect).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`MultiSelect`](crate::native::multi_select::MultiSelect) is pressed,
+    /// which is while its dropdown is open. This is the
+    /// [`hovered`](StyleSheet::hovered) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`MultiSelect`](crate::native::multi_select::MultiSelect) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`MultiSelect`](crate::native::multi_select::MultiSelect) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`MultiSelect`](crate::native::multi_select::MultiSelect).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MultiSelectStyles {
+    #[default]
+    Default,
+    Patched(Patched<MultiSelectStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = MultiSelectStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let MultiSelectStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            placeholder_color: palette.background.strong.color,
+            icon_color: palette.background.base.text,
+            menu_background: palette.background.base.color.into(),
+            hovered_background: palette.background.weak.color.into(),
+            checkbox_border_color: palette.background.strong.color,
+            checked_background: palette.primary.strong.color.into(),
+            check_color: palette.primary.strong.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.multi_select, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
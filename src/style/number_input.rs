@@ -5,12 +5,21 @@
 use iced_native::{Background, Color};
 use iced_style::theme::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a [`NumberInput`](crate::native::number_input::NumberInput).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The background of the [`NumberInput`](crate::native::number_input::NumberInput).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_background")
+    )]
     pub button_background: Option<Background>,
     /// The Color of the arrows of [`NumberInput`](crate::native::number_input::NumberInput).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub icon_color: Color,
 }
 
@@ -30,6 +39,11 @@ pub trait StyleSheet {
     /// The normal appearance of a [`NumberInput`](crate::native::number_input::NumberInput).
     fn active(&self, style: Self::Style) -> Appearance;
 
+    /// The appearance when a button of the [`NumberInput`](crate::native::number_input::NumberInput) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
     /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is pressed.
     fn pressed(&self, style: Self::Style) -> Appearance {
         self.active(style)
@@ -51,6 +65,12 @@ pub trait StyleSheet {
             },
         }
     }
+
+    /// The appearance when the
+    /// [`NumberInput`](crate::native::number_input::NumberInput) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 #[derive(Default, Debug, Copy, Clone)]
@@ -59,18 +79,25 @@ pub trait StyleSheet {
 pub enum NumberInputStyles {
     #[default]
     Default,
+    Patched(Patched<NumberInputStyles, Appearance>),
 }
 
 impl StyleSheet for Theme {
     type Style = NumberInputStyles;
 
-    fn active(&self, _style: Self::Style) -> Appearance {
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let NumberInputStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
         let palette = self.extended_palette();
 
-        Appearance {
+        let appearance = Appearance {
             button_background: Some(palette.primary.strong.color.into()),
             icon_color: palette.primary.strong.text,
-        }
+        };
+
+        customize(self, |hooks| hooks.number_input, appearance)
     }
 
     /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is pressed.
@@ -0,0 +1,150 @@
+//! Navigate between the pages of a long list.
+//!
+//! *This API requires the following crate features to be activated: pagination*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Pagination`](crate::native::pagination::Pagination).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of a button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of a button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of a button.
+    pub border_width: f32,
+
+    /// The border color of a button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of a button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The background of a hovered button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The text color of a hovered button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_text_color: Color,
+
+    /// The background of the current page and of the selected page size.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub selected_background: Background,
+
+    /// The text color of the current page and of the selected page size.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selected_text_color: Color,
+
+    /// The text color of a disabled button and of the ellipsis.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub disabled_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+            hovered_background: Background::Color([0.9, 0.9, 0.9].into()),
+            hovered_text_color: Color::BLACK,
+            selected_background: Background::Color([0.0, 0.4, 0.8].into()),
+            selected_text_color: Color::WHITE,
+            disabled_text_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Pagination`](crate::native::pagination::Pagination).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`Pagination`](crate::native::pagination::Pagination).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`Pagination`](crate::native::pagination::Pagination) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`Pagination`](crate::native::pagination::Pagination) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`Pagination`](crate::native::pagination::Pagination) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`Pagination`](crate::native::pagination::Pagination) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`Pagination`](crate::native::pagination::Pagination).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PaginationStyles {
+    #[default]
+    Default,
+    Patched(Patched<PaginationStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = PaginationStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let PaginationStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            hovered_background: palette.background.weak.color.into(),
+            hovered_text_color: palette.background.weak.text,
+            selected_background: palette.primary.base.color.into(),
+            selected_text_color: palette.primary.base.text,
+            disabled_text_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.pagination, appearance)
+    }
+}
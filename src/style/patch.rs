@@ -0,0 +1,77 @@
+//! Derive a style from another one by patching single fields of its
+//! appearance.
+//!
+//! The prebuilt styles of the widgets have a `Patched` variant wrapping a
+//! [`Patched`](Patched) style. It takes the appearances of its base style in
+//! every state, like active or hovered, and passes them through the patch, so
+//! only the overridden fields change:
+//!
+//! ```
+//! # use iced_aw::style::{card::Appearance, patch::Patched, CardStyles};
+//! # use iced_native::Color;
+//! #
+//! let style = CardStyles::Patched(Patched::new(&CardStyles::Primary, |appearance| {
+//!     Appearance {
+//!         border_color: Color::from_rgb(1.0, 0.0, 0.0),
+//!         ..appearance
+//!     }
+//! }));
+//! ```
+//!
+//! Patches can be layered by patching a patched style stored in a constant:
+//!
+//! ```
+//! # use iced_aw::style::{card::Appearance, patch::Patched, CardStyles};
+//! #
+//! const FLAT: CardStyles = CardStyles::Patched(Patched::new(&CardStyles::Primary, |appearance| {
+//!     Appearance {
+//!         border_width: 0.0,
+//!         ..appearance
+//!     }
+//! }));
+//!
+//! let square = CardStyles::Patched(Patched::new(&FLAT, |appearance| Appearance {
+//!     border_radius: 0.0.into(),
+//!     ..appearance
+//! }));
+//! ```
+
+/// A style deriving its appearances from a base style by a patch.
+#[derive(Debug)]
+pub struct Patched<Style: 'static, Appearance> {
+    /// The style the appearances are derived from.
+    pub base: &'static Style,
+    /// The patch applied to the appearances of the base style.
+    pub patch: fn(Appearance) -> Appearance,
+}
+
+impl<Style, Appearance> Patched<Style, Appearance> {
+    /// Creates a new [`Patched`](Patched) style, applying the patch to the
+    /// appearances of the base style.
+    #[must_use]
+    pub const fn new(base: &'static Style, patch: fn(Appearance) -> Appearance) -> Self {
+        Self { base, patch }
+    }
+
+    /// Applies the patch to an appearance of the base style.
+    #[must_use]
+    pub fn apply(&self, appearance: Appearance) -> Appearance {
+        (self.patch)(appearance)
+    }
+}
+
+impl<Style, Appearance> Clone for Patched<Style, Appearance> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Style, Appearance> Copy for Patched<Style, Appearance> {}
+
+impl<Style: PartialEq, Appearance> PartialEq for Patched<Style, Appearance> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base && self.patch as usize == other.patch as usize
+    }
+}
+
+impl<Style: Eq, Appearance> Eq for Patched<Style, Appearance> {}
@@ -0,0 +1,147 @@
+//! Type a code into a row of boxes holding one character each.
+//!
+//! *This API requires the following crate features to be activated: pin_input*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of the boxes of a
+/// [`PinInput`](crate::native::pin_input::PinInput).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of a box.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of a box.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of a box.
+    pub border_width: f32,
+
+    /// The border color of a box.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the character in a box.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the cursor in the box typed into next.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub cursor_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            cursor_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of the boxes of a
+/// [`PinInput`](crate::native::pin_input::PinInput).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The appearance of an empty box.
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a box holding a character.
+    fn filled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of the box typed into next while the
+    /// [`PinInput`](crate::native::pin_input::PinInput) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`PinInput`](crate::native::pin_input::PinInput) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`PinInput`](crate::native::pin_input::PinInput) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`PinInput`](crate::native::pin_input::PinInput) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`PinInput`](crate::native::pin_input::PinInput).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PinInputStyles {
+    #[default]
+    Default,
+    Patched(Patched<PinInputStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = PinInputStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let PinInputStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            cursor_color: palette.primary.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.pin_input, appearance)
+    }
+
+    fn filled(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.background.base.text,
+            ..active
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_width: active.border_width.max(2.0),
+            border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Show content in a bubble anchored to another widget.
+//!
+//! *This API requires the following crate features to be activated: popover*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Popover`](crate::native::popover::Popover).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the bubble of the
+    /// [`Popover`](crate::native::popover::Popover), also filling its arrow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the bubble.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the bubble.
+    pub border_width: f32,
+
+    /// The border color of the bubble.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The default text color of the content of the bubble.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Popover`](crate::native::popover::Popover).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Popover`](crate::native::popover::Popover).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Popover`](crate::native::popover::Popover) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Popover`](crate::native::popover::Popover) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Popover`](crate::native::popover::Popover) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Popover`](crate::native::popover::Popover) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Popover`](crate::native::popover::Popover).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PopoverStyles {
+    #[default]
+    Default,
+    Patched(Patched<PopoverStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = PopoverStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let PopoverStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.popover, appearance)
+    }
+}
@@ -0,0 +1,33 @@
+//! Ready-made themes styling all widgets after popular design languages.
+//!
+//! Each preset provides a light and a dark theme, built by a
+//! [`ThemeBuilder`](super::theme::ThemeBuilder) from the colors of the design
+//! language, and the [`Hooks`](super::theme::Hooks) reshaping the widgets to
+//! it. The hooks can also be combined with colors of your own:
+//!
+//! ```
+//! # use iced_aw::style::{presets::material, theme::ThemeBuilder};
+//! # use iced_native::Color;
+//! #
+//! let theme = ThemeBuilder::new(Color::from_rgb8(0x00, 0x6A, 0x6A), Color::WHITE)
+//!     .hooks(material::hooks())
+//!     .build();
+//! ```
+use iced_native::Color;
+
+use super::theme::builder::mix;
+
+pub mod fluent;
+pub mod material;
+
+/// Returns the background color shaded towards the text color by the given
+/// factor, used for tonal surfaces and strokes.
+fn shade(palette: &iced_style::theme::Palette, factor: f32) -> Color {
+    mix(palette.background, palette.text, factor)
+}
+
+/// Returns the background color tinted towards the primary color by the given
+/// factor.
+fn tint(palette: &iced_style::theme::Palette, factor: f32) -> Color {
+    mix(palette.background, palette.primary, factor)
+}
@@ -0,0 +1,1083 @@
+//! Themes after the [Fluent 2](https://fluent2.microsoft.design) design
+//! language.
+//!
+//! Fluent surfaces are subtly rounded and outlined by thin neutral strokes,
+//! using the brand color sparingly for selections and accents.
+use iced_native::Color;
+use iced_style::theme::Palette;
+
+use super::{
+    super::theme::{Hooks, ThemeBuilder},
+    shade, tint,
+};
+
+/// The corner radius of the controls, like tab labels and menus.
+const CONTROL_RADIUS: f32 = 4.0;
+
+/// The corner radius of the surfaces, like cards and the pickers.
+const SURFACE_RADIUS: f32 = 8.0;
+
+/// The width of the strokes outlining the surfaces.
+const STROKE_WIDTH: f32 = 1.0;
+
+/// The factor the strokes are shaded from the background by.
+const STROKE_SHADE: f32 = 0.15;
+
+/// The light Fluent theme with the default brand colors.
+#[must_use]
+pub fn light() -> iced_style::Theme {
+    ThemeBuilder::new(
+        Color::from_rgb8(0x0F, 0x6C, 0xBD),
+        Color::from_rgb8(0xFF, 0xFF, 0xFF),
+    )
+    .text(Color::from_rgb8(0x24, 0x24, 0x24))
+    .success(Color::from_rgb8(0x10, 0x7C, 0x10))
+    .danger(Color::from_rgb8(0xC5, 0x0F, 0x1F))
+    .hooks(hooks())
+    .build()
+}
+
+/// The dark Fluent theme with the default brand colors.
+#[must_use]
+pub fn dark() -> iced_style::Theme {
+    ThemeBuilder::new(
+        Color::from_rgb8(0x47, 0x9E, 0xF5),
+        Color::from_rgb8(0x29, 0x29, 0x29),
+    )
+    .text(Color::from_rgb8(0xFF, 0xFF, 0xFF))
+    .success(Color::from_rgb8(0x54, 0xB0, 0x54))
+    .danger(Color::from_rgb8(0xDC, 0x62, 0x6D))
+    .hooks(hooks())
+    .build()
+}
+
+/// The [`Hooks`](Hooks) reshaping all widgets after Fluent.
+#[must_use]
+pub fn hooks() -> Hooks {
+    Hooks {
+        #[cfg(feature = "accordion")]
+        accordion: Some(accordion),
+        #[cfg(feature = "avatar")]
+        avatar: Some(avatar),
+        #[cfg(feature = "badge")]
+        badge: Some(badge),
+        #[cfg(feature = "breadcrumbs")]
+        breadcrumbs: Some(breadcrumbs),
+        #[cfg(feature = "calendar_heatmap")]
+        calendar_heatmap: Some(calendar_heatmap),
+        #[cfg(feature = "card")]
+        card: Some(card),
+        #[cfg(feature = "carousel")]
+        carousel: Some(carousel),
+        #[cfg(feature = "chart")]
+        chart: Some(chart),
+        #[cfg(feature = "chip")]
+        chip: Some(chip),
+        #[cfg(feature = "circular_progress")]
+        circular_progress: Some(circular_progress),
+        #[cfg(feature = "code_editor")]
+        code_editor: Some(code_editor),
+        #[cfg(feature = "color_picker")]
+        color_picker: Some(color_picker),
+        #[cfg(feature = "combo_box")]
+        combo_box: Some(combo_box),
+        #[cfg(feature = "context_menu")]
+        context_menu: Some(context_menu),
+        #[cfg(feature = "date_picker")]
+        date_picker: Some(date_picker),
+        #[cfg(feature = "drag_and_drop")]
+        drag_and_drop: Some(drag_and_drop),
+        #[cfg(feature = "drawing_pad")]
+        drawing_pad: Some(drawing_pad),
+        #[cfg(feature = "drop_zone")]
+        drop_zone: Some(drop_zone),
+        #[cfg(feature = "gallery")]
+        gallery: Some(gallery),
+        #[cfg(feature = "gauge")]
+        gauge: Some(gauge),
+        #[cfg(feature = "heatmap")]
+        heatmap: Some(heatmap),
+        #[cfg(feature = "hotkey_input")]
+        hotkey_input: Some(hotkey_input),
+        #[cfg(feature = "hover_card")]
+        hover_card: Some(hover_card),
+        #[cfg(feature = "knob")]
+        knob: Some(knob),
+        #[cfg(feature = "menu")]
+        menu_bar: Some(menu_bar),
+        #[cfg(feature = "menu_button")]
+        menu_button: Some(menu_button),
+        #[cfg(feature = "minimap")]
+        minimap: Some(minimap),
+        #[cfg(feature = "modal")]
+        modal: Some(modal),
+        #[cfg(feature = "month_view")]
+        month_view: Some(month_view),
+        #[cfg(feature = "multi_select")]
+        multi_select: Some(multi_select),
+        #[cfg(feature = "number_input")]
+        number_input: Some(number_input),
+        #[cfg(feature = "pagination")]
+        pagination: Some(pagination),
+        #[cfg(feature = "pin_input")]
+        pin_input: Some(pin_input),
+        #[cfg(feature = "popover")]
+        popover: Some(popover),
+        #[cfg(feature = "property_grid")]
+        property_grid: Some(property_grid),
+        #[cfg(feature = "qr_code")]
+        qr_code: Some(qr_code),
+        #[cfg(feature = "radial_menu")]
+        radial_menu: Some(radial_menu),
+        #[cfg(feature = "range_slider")]
+        range_slider: Some(range_slider),
+        #[cfg(feature = "rating")]
+        rating: Some(rating),
+        #[cfg(feature = "resizable")]
+        resizable: Some(resizable),
+        #[cfg(feature = "rich_text")]
+        rich_text: Some(rich_text),
+        #[cfg(feature = "scrubber")]
+        scrubber: Some(scrubber),
+        #[cfg(feature = "search_bar")]
+        search_bar: Some(search_bar),
+        #[cfg(feature = "segmented_button")]
+        segmented_button: Some(segmented_button),
+        #[cfg(feature = "selection_list")]
+        selection_list: Some(selection_list),
+        #[cfg(feature = "shortcut_hint")]
+        shortcut_hint: Some(shortcut_hint),
+        #[cfg(feature = "skeleton")]
+        skeleton: Some(skeleton),
+        #[cfg(feature = "split")]
+        split: Some(split),
+        #[cfg(feature = "spreadsheet_grid")]
+        spreadsheet_grid: Some(spreadsheet_grid),
+        #[cfg(feature = "status_dot")]
+        status_dot: Some(status_dot),
+        #[cfg(feature = "stepper")]
+        stepper: Some(stepper),
+        #[cfg(feature = "switch")]
+        switch: Some(switch),
+        #[cfg(feature = "table")]
+        table: Some(table),
+        #[cfg(feature = "tab_bar")]
+        tab_bar: Some(tab_bar),
+        #[cfg(feature = "tag_input")]
+        tag_input: Some(tag_input),
+        #[cfg(feature = "tick_slider")]
+        tick_slider: Some(tick_slider),
+        #[cfg(feature = "time_picker")]
+        time_picker: Some(time_picker),
+        #[cfg(feature = "toast")]
+        toast: Some(toast),
+        #[cfg(feature = "tooltip")]
+        tooltip: Some(tooltip),
+        #[cfg(feature = "tour")]
+        tour: Some(tour),
+        #[cfg(feature = "transfer_list")]
+        transfer_list: Some(transfer_list),
+        #[cfg(feature = "virtual_list")]
+        virtual_list: Some(virtual_list),
+        #[cfg(feature = "xy_pad")]
+        xy_pad: Some(xy_pad),
+    }
+}
+
+/// The smoke behind modal surfaces.
+#[cfg(any(feature = "context_menu", feature = "modal"))]
+const SMOKE: Color = Color {
+    a: 0.4,
+    ..Color::BLACK
+};
+
+/// Accordions are outlined surfaces.
+#[cfg(feature = "accordion")]
+fn accordion(
+    palette: &Palette,
+    appearance: crate::style::accordion::Appearance,
+) -> crate::style::accordion::Appearance {
+    crate::style::accordion::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Avatars are outlined by a thin stroke.
+#[cfg(feature = "avatar")]
+fn avatar(
+    palette: &Palette,
+    appearance: crate::style::avatar::Appearance,
+) -> crate::style::avatar::Appearance {
+    crate::style::avatar::Appearance {
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        overflow_background: shade(palette, 0.08),
+        ..appearance
+    }
+}
+
+/// Badges are slightly rounded.
+#[cfg(feature = "badge")]
+fn badge(
+    _palette: &Palette,
+    appearance: crate::style::badge::Appearance,
+) -> crate::style::badge::Appearance {
+    crate::style::badge::Appearance {
+        border_radius: Some(CONTROL_RADIUS.into()),
+        border_width: STROKE_WIDTH,
+        ..appearance
+    }
+}
+
+/// Breadcrumbs highlight hovered segments with a subtle fill.
+#[cfg(feature = "breadcrumbs")]
+fn breadcrumbs(
+    palette: &Palette,
+    appearance: crate::style::breadcrumbs::Appearance,
+) -> crate::style::breadcrumbs::Appearance {
+    crate::style::breadcrumbs::Appearance {
+        hovered_background: shade(palette, 0.05).into(),
+        border_radius: CONTROL_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// The empty days of calendar heatmaps are subtly shaded.
+#[cfg(feature = "calendar_heatmap")]
+fn calendar_heatmap(
+    palette: &Palette,
+    appearance: crate::style::calendar_heatmap::Appearance,
+) -> crate::style::calendar_heatmap::Appearance {
+    crate::style::calendar_heatmap::Appearance {
+        empty_color: shade(palette, 0.06),
+        tooltip_border_radius: CONTROL_RADIUS,
+        ..appearance
+    }
+}
+
+/// Cards are outlined surfaces.
+#[cfg(feature = "card")]
+fn card(
+    palette: &Palette,
+    appearance: crate::style::card::Appearance,
+) -> crate::style::card::Appearance {
+    crate::style::card::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Carousels have neutral dots as light as the control strokes.
+#[cfg(feature = "carousel")]
+fn carousel(
+    palette: &Palette,
+    appearance: crate::style::carousel::Appearance,
+) -> crate::style::carousel::Appearance {
+    crate::style::carousel::Appearance {
+        dot_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Charts draw their grid like the control strokes.
+#[cfg(feature = "chart")]
+fn chart(
+    palette: &Palette,
+    appearance: crate::style::chart::Appearance,
+) -> crate::style::chart::Appearance {
+    crate::style::chart::Appearance {
+        grid_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Chips are slightly rounded, outlined controls.
+#[cfg(feature = "chip")]
+fn chip(
+    palette: &Palette,
+    appearance: crate::style::chip::Appearance,
+) -> crate::style::chip::Appearance {
+    crate::style::chip::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Circular progress indicators run on a track as light as the control strokes.
+#[cfg(feature = "circular_progress")]
+fn circular_progress(
+    palette: &Palette,
+    appearance: crate::style::circular_progress::Appearance,
+) -> crate::style::circular_progress::Appearance {
+    crate::style::circular_progress::Appearance {
+        track_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Code editors are controls outlined like the other strokes.
+#[cfg(feature = "code_editor")]
+fn code_editor(
+    palette: &Palette,
+    appearance: crate::style::code_editor::Appearance,
+) -> crate::style::code_editor::Appearance {
+    crate::style::code_editor::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Color pickers are outlined surfaces.
+#[cfg(feature = "color_picker")]
+fn color_picker(
+    palette: &Palette,
+    appearance: crate::style::color_picker::Appearance,
+) -> crate::style::color_picker::Appearance {
+    crate::style::color_picker::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS,
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        bar_border_radius: CONTROL_RADIUS,
+        bar_border_width: STROKE_WIDTH,
+        ..appearance
+    }
+}
+
+/// Combo boxes open outlined surfaces highlighting the option with a subtle shade.
+#[cfg(feature = "combo_box")]
+fn combo_box(
+    palette: &Palette,
+    appearance: crate::style::combo_box::Appearance,
+) -> crate::style::combo_box::Appearance {
+    crate::style::combo_box::Appearance {
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        highlighted_background: shade(palette, 0.06).into(),
+        highlighted_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Context menus dim the content behind them with the smoke.
+#[cfg(feature = "context_menu")]
+fn context_menu(
+    _palette: &Palette,
+    appearance: crate::style::context_menu::Appearance,
+) -> crate::style::context_menu::Appearance {
+    crate::style::context_menu::Appearance {
+        background: SMOKE.into(),
+        ..appearance
+    }
+}
+
+/// Date pickers are outlined surfaces.
+#[cfg(feature = "date_picker")]
+fn date_picker(
+    palette: &Palette,
+    appearance: crate::style::date_picker::Appearance,
+) -> crate::style::date_picker::Appearance {
+    crate::style::date_picker::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS,
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Drop targets and drag ghosts have the rounded corners of surfaces.
+#[cfg(feature = "drag_and_drop")]
+fn drag_and_drop(
+    _palette: &Palette,
+    appearance: crate::style::drag_and_drop::Appearance,
+) -> crate::style::drag_and_drop::Appearance {
+    crate::style::drag_and_drop::Appearance {
+        border_radius: SURFACE_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Drawing pads are surfaces outlined like the control strokes.
+#[cfg(feature = "drawing_pad")]
+fn drawing_pad(
+    palette: &Palette,
+    appearance: crate::style::drawing_pad::Appearance,
+) -> crate::style::drawing_pad::Appearance {
+    crate::style::drawing_pad::Appearance {
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Drop zones are surfaces outlined like the control strokes.
+#[cfg(feature = "drop_zone")]
+fn drop_zone(
+    palette: &Palette,
+    appearance: crate::style::drop_zone::Appearance,
+) -> crate::style::drop_zone::Appearance {
+    crate::style::drop_zone::Appearance {
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Galleries outline the hovered thumbnail like the control strokes.
+#[cfg(feature = "gallery")]
+fn gallery(
+    palette: &Palette,
+    appearance: crate::style::gallery::Appearance,
+) -> crate::style::gallery::Appearance {
+    crate::style::gallery::Appearance {
+        hovered_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Gauges run on a track colored like the control strokes.
+#[cfg(feature = "gauge")]
+fn gauge(
+    palette: &Palette,
+    appearance: crate::style::gauge::Appearance,
+) -> crate::style::gauge::Appearance {
+    crate::style::gauge::Appearance {
+        track_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// The empty cells of heatmaps are subtly shaded.
+#[cfg(feature = "heatmap")]
+fn heatmap(
+    palette: &Palette,
+    appearance: crate::style::heatmap::Appearance,
+) -> crate::style::heatmap::Appearance {
+    crate::style::heatmap::Appearance {
+        empty_color: shade(palette, 0.06),
+        tooltip_border_radius: CONTROL_RADIUS,
+        ..appearance
+    }
+}
+
+/// Hotkey inputs have the rounded corners and the strokes of controls.
+#[cfg(feature = "hotkey_input")]
+fn hotkey_input(
+    palette: &Palette,
+    appearance: crate::style::hotkey_input::Appearance,
+) -> crate::style::hotkey_input::Appearance {
+    crate::style::hotkey_input::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Hover cards are outlined surfaces.
+#[cfg(feature = "hover_card")]
+fn hover_card(
+    palette: &Palette,
+    appearance: crate::style::hover_card::Appearance,
+) -> crate::style::hover_card::Appearance {
+    crate::style::hover_card::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Knobs are outlined like the control strokes.
+#[cfg(feature = "knob")]
+fn knob(
+    palette: &Palette,
+    appearance: crate::style::knob::Appearance,
+) -> crate::style::knob::Appearance {
+    crate::style::knob::Appearance {
+        track_color: shade(palette, STROKE_SHADE),
+        knob_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Menus are outlined and slightly rounded.
+#[cfg(feature = "menu")]
+fn menu_bar(
+    palette: &Palette,
+    appearance: crate::style::menu_bar::Appearance,
+) -> crate::style::menu_bar::Appearance {
+    crate::style::menu_bar::Appearance {
+        background: palette.background,
+        border_radius: [CONTROL_RADIUS; 4],
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        path: tint(palette, 0.15),
+        ..appearance
+    }
+}
+
+/// Menu buttons have a thin stroke and a subtle highlight of the hovered item.
+#[cfg(feature = "menu_button")]
+fn menu_button(
+    palette: &Palette,
+    appearance: crate::style::menu_button::Appearance,
+) -> crate::style::menu_button::Appearance {
+    crate::style::menu_button::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        hovered_background: shade(palette, 0.06).into(),
+        hovered_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Minimaps are surfaces outlined like the control strokes.
+#[cfg(feature = "minimap")]
+fn minimap(
+    palette: &Palette,
+    appearance: crate::style::minimap::Appearance,
+) -> crate::style::minimap::Appearance {
+    crate::style::minimap::Appearance {
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Modals dim the content behind them with the smoke.
+#[cfg(feature = "modal")]
+fn modal(
+    _palette: &Palette,
+    appearance: crate::style::modal::Appearance,
+) -> crate::style::modal::Appearance {
+    crate::style::modal::Appearance {
+        background: SMOKE.into(),
+        ..appearance
+    }
+}
+
+/// Month views are divided by single strokes with subtly rounded events.
+#[cfg(feature = "month_view")]
+fn month_view(
+    palette: &Palette,
+    appearance: crate::style::month_view::Appearance,
+) -> crate::style::month_view::Appearance {
+    crate::style::month_view::Appearance {
+        grid_width: STROKE_WIDTH,
+        grid_color: shade(palette, STROKE_SHADE),
+        event_border_radius: CONTROL_RADIUS,
+        ..appearance
+    }
+}
+
+/// Multi selects are outlined controls with subtly shaded hovered options.
+#[cfg(feature = "multi_select")]
+fn multi_select(
+    palette: &Palette,
+    appearance: crate::style::multi_select::Appearance,
+) -> crate::style::multi_select::Appearance {
+    crate::style::multi_select::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        hovered_background: shade(palette, 0.06).into(),
+        ..appearance
+    }
+}
+
+/// The buttons of number inputs are subtle.
+#[cfg(feature = "number_input")]
+fn number_input(
+    palette: &Palette,
+    appearance: crate::style::number_input::Appearance,
+) -> crate::style::number_input::Appearance {
+    crate::style::number_input::Appearance {
+        button_background: Some(shade(palette, 0.05).into()),
+        icon_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Paginations are outlined controls.
+#[cfg(feature = "pagination")]
+fn pagination(
+    palette: &Palette,
+    appearance: crate::style::pagination::Appearance,
+) -> crate::style::pagination::Appearance {
+    crate::style::pagination::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// PIN inputs show their boxes as controls outlined like the other strokes.
+#[cfg(feature = "pin_input")]
+fn pin_input(
+    palette: &Palette,
+    appearance: crate::style::pin_input::Appearance,
+) -> crate::style::pin_input::Appearance {
+    crate::style::pin_input::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Popovers are outlined surfaces.
+#[cfg(feature = "popover")]
+fn popover(
+    palette: &Palette,
+    appearance: crate::style::popover::Appearance,
+) -> crate::style::popover::Appearance {
+    crate::style::popover::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Property grids are outlined surfaces.
+#[cfg(feature = "property_grid")]
+fn property_grid(
+    palette: &Palette,
+    appearance: crate::style::property_grid::Appearance,
+) -> crate::style::property_grid::Appearance {
+    crate::style::property_grid::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// QR codes have the rounded corners of controls.
+#[cfg(feature = "qr_code")]
+fn qr_code(
+    _palette: &Palette,
+    appearance: crate::style::qr_code::Appearance,
+) -> crate::style::qr_code::Appearance {
+    crate::style::qr_code::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Radial menus have the strokes of surfaces.
+#[cfg(feature = "radial_menu")]
+fn radial_menu(
+    palette: &Palette,
+    appearance: crate::style::radial_menu::Appearance,
+) -> crate::style::radial_menu::Appearance {
+    crate::style::radial_menu::Appearance {
+        ring_border_width: STROKE_WIDTH,
+        ring_border_color: shade(palette, STROKE_SHADE),
+        center_background: shade(palette, 0.04).into(),
+        ..appearance
+    }
+}
+
+/// Range sliders have large handles with a thin stroke.
+#[cfg(feature = "range_slider")]
+fn range_slider(
+    palette: &Palette,
+    appearance: crate::style::range_slider::Appearance,
+) -> crate::style::range_slider::Appearance {
+    crate::style::range_slider::Appearance {
+        handle_radius: 10.0,
+        handle_border_width: STROKE_WIDTH,
+        handle_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Ratings keep their empty icons faint.
+#[cfg(feature = "rating")]
+fn rating(
+    palette: &Palette,
+    appearance: crate::style::rating::Appearance,
+) -> crate::style::rating::Appearance {
+    crate::style::rating::Appearance {
+        empty_color: shade(palette, 2.0 * STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Resizables have thin handles and stroke colored grips.
+#[cfg(feature = "resizable")]
+fn resizable(
+    palette: &Palette,
+    appearance: crate::style::resizable::Appearance,
+) -> crate::style::resizable::Appearance {
+    crate::style::resizable::Appearance {
+        handle_width: STROKE_WIDTH * 2.0,
+        grip_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Rich texts draw their links in the brand color, like the other accents.
+#[cfg(feature = "rich_text")]
+fn rich_text(
+    palette: &Palette,
+    appearance: crate::style::rich_text::Appearance,
+) -> crate::style::rich_text::Appearance {
+    crate::style::rich_text::Appearance {
+        link_color: palette.primary,
+        ..appearance
+    }
+}
+
+/// Scrubbers have an outlined handle in the color of the background.
+#[cfg(feature = "scrubber")]
+fn scrubber(
+    palette: &Palette,
+    appearance: crate::style::scrubber::Appearance,
+) -> crate::style::scrubber::Appearance {
+    crate::style::scrubber::Appearance {
+        handle_color: palette.background,
+        handle_border_width: STROKE_WIDTH,
+        handle_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Search bars draw their icons in the secondary text color.
+#[cfg(feature = "search_bar")]
+fn search_bar(
+    palette: &Palette,
+    appearance: crate::style::search_bar::Appearance,
+) -> crate::style::search_bar::Appearance {
+    crate::style::search_bar::Appearance {
+        icon_color: shade(palette, 0.55),
+        ..appearance
+    }
+}
+
+/// Segmented buttons are slightly rounded, outlined controls.
+#[cfg(feature = "segmented_button")]
+fn segmented_button(
+    palette: &Palette,
+    appearance: crate::style::segmented_button::Appearance,
+) -> crate::style::segmented_button::Appearance {
+    crate::style::segmented_button::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        divider_color: shade(palette, STROKE_SHADE),
+        hovered_background: shade(palette, 0.04).into(),
+        ..appearance
+    }
+}
+
+/// Selection lists are outlined, marking the selection with a brand tint.
+#[cfg(feature = "selection_list")]
+fn selection_list(
+    palette: &Palette,
+    appearance: crate::style::selection_list::Appearance,
+) -> crate::style::selection_list::Appearance {
+    crate::style::selection_list::Appearance {
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        hovered_background: shade(palette, 0.05).into(),
+        hovered_text_color: palette.text,
+        selected_background: tint(palette, 0.15).into(),
+        selected_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Shortcut hints have the rounded corners and the strokes of controls.
+#[cfg(feature = "shortcut_hint")]
+fn shortcut_hint(
+    palette: &Palette,
+    appearance: crate::style::shortcut_hint::Appearance,
+) -> crate::style::shortcut_hint::Appearance {
+    crate::style::shortcut_hint::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        depth_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Skeletons are neutral shapes rounded like the controls.
+#[cfg(feature = "skeleton")]
+fn skeleton(
+    palette: &Palette,
+    appearance: crate::style::skeleton::Appearance,
+) -> crate::style::skeleton::Appearance {
+    crate::style::skeleton::Appearance {
+        background: shade(palette, 0.08),
+        highlight: shade(palette, 0.02),
+        border_radius: CONTROL_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Splits are divided by a single stroke.
+#[cfg(feature = "split")]
+fn split(
+    palette: &Palette,
+    appearance: crate::style::split::Appearance,
+) -> crate::style::split::Appearance {
+    crate::style::split::Appearance {
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        divider_background: shade(palette, STROKE_SHADE).into(),
+        divider_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Spreadsheet grids are outlined controls.
+#[cfg(feature = "spreadsheet_grid")]
+fn spreadsheet_grid(
+    palette: &Palette,
+    appearance: crate::style::spreadsheet_grid::Appearance,
+) -> crate::style::spreadsheet_grid::Appearance {
+    crate::style::spreadsheet_grid::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Status dots are outlined by a thin stroke.
+#[cfg(feature = "status_dot")]
+fn status_dot(
+    palette: &Palette,
+    appearance: crate::style::status_dot::Appearance,
+) -> crate::style::status_dot::Appearance {
+    crate::style::status_dot::Appearance {
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Steppers have slightly rounded buttons.
+#[cfg(feature = "stepper")]
+fn stepper(
+    palette: &Palette,
+    appearance: crate::style::stepper::Appearance,
+) -> crate::style::stepper::Appearance {
+    crate::style::stepper::Appearance {
+        button_border_radius: CONTROL_RADIUS.into(),
+        connector_color: shade(palette, STROKE_SHADE),
+        connector_width: STROKE_WIDTH,
+        ..appearance
+    }
+}
+
+/// Switches have a track outlined by a thin stroke.
+#[cfg(feature = "switch")]
+fn switch(
+    palette: &Palette,
+    appearance: crate::style::switch::Appearance,
+) -> crate::style::switch::Appearance {
+    crate::style::switch::Appearance {
+        track_border_width: STROKE_WIDTH,
+        track_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Tables are outlined surfaces divided by single strokes, marking the
+/// selection with a brand tint.
+#[cfg(feature = "table")]
+fn table(
+    palette: &Palette,
+    appearance: crate::style::table::Appearance,
+) -> crate::style::table::Appearance {
+    crate::style::table::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        header_background: palette.background.into(),
+        header_text_color: palette.text,
+        divider_color: shade(palette, STROKE_SHADE),
+        stripe_background: shade(palette, 0.02).into(),
+        hovered_background: shade(palette, 0.05).into(),
+        hovered_text_color: palette.text,
+        selected_background: tint(palette, 0.15).into(),
+        selected_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Tab labels are borderless and slightly rounded at the top.
+#[cfg(feature = "tab_bar")]
+fn tab_bar(
+    _palette: &Palette,
+    appearance: crate::style::tab_bar::Appearance,
+) -> crate::style::tab_bar::Appearance {
+    crate::style::tab_bar::Appearance {
+        tab_label_border_width: 0.0,
+        tab_label_border_radius: [CONTROL_RADIUS, CONTROL_RADIUS, 0.0, 0.0].into(),
+        ..appearance
+    }
+}
+
+/// Tag inputs show their tags as outlined, slightly rounded chips.
+#[cfg(feature = "tag_input")]
+fn tag_input(
+    palette: &Palette,
+    appearance: crate::style::tag_input::Appearance,
+) -> crate::style::tag_input::Appearance {
+    crate::style::tag_input::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Tick sliders have a large outlined handle.
+#[cfg(feature = "tick_slider")]
+fn tick_slider(
+    palette: &Palette,
+    appearance: crate::style::tick_slider::Appearance,
+) -> crate::style::tick_slider::Appearance {
+    crate::style::tick_slider::Appearance {
+        handle_radius: 10.0,
+        handle_border_width: STROKE_WIDTH,
+        handle_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Time pickers are outlined surfaces with a brand clock hand.
+#[cfg(feature = "time_picker")]
+fn time_picker(
+    palette: &Palette,
+    appearance: crate::style::time_picker::Appearance,
+) -> crate::style::time_picker::Appearance {
+    crate::style::time_picker::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS,
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        clock_hand_color: palette.primary,
+        ..appearance
+    }
+}
+
+/// Toasts are outlined surfaces with a neutral stroke, keeping the status
+/// color for the indicator of the remaining time.
+#[cfg(feature = "toast")]
+fn toast(
+    palette: &Palette,
+    appearance: crate::style::toast::Appearance,
+) -> crate::style::toast::Appearance {
+    crate::style::toast::Appearance {
+        background: palette.background.into(),
+        border_radius: SURFACE_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// Tooltips are outlined surfaces like the controls.
+#[cfg(feature = "tooltip")]
+fn tooltip(
+    palette: &Palette,
+    _appearance: crate::style::tooltip::Appearance,
+) -> crate::style::tooltip::Appearance {
+    crate::style::tooltip::Appearance {
+        background: palette.background.into(),
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        text_color: palette.text,
+    }
+}
+
+/// Tours have cards with the strokes and the radius of surfaces.
+#[cfg(feature = "tour")]
+fn tour(
+    palette: &Palette,
+    appearance: crate::style::tour::Appearance,
+) -> crate::style::tour::Appearance {
+    crate::style::tour::Appearance {
+        card_border_radius: SURFACE_RADIUS.into(),
+        card_border_width: STROKE_WIDTH,
+        card_border_color: shade(palette, STROKE_SHADE),
+        button_border_radius: CONTROL_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Transfer lists are outlined controls with subtle buttons.
+#[cfg(feature = "transfer_list")]
+fn transfer_list(
+    palette: &Palette,
+    appearance: crate::style::transfer_list::Appearance,
+) -> crate::style::transfer_list::Appearance {
+    crate::style::transfer_list::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        hovered_background: shade(palette, 0.06).into(),
+        button_background: shade(palette, 0.06).into(),
+        ..appearance
+    }
+}
+
+/// Virtual lists have slightly rounded scrollers in the stroke color.
+#[cfg(feature = "virtual_list")]
+fn virtual_list(
+    palette: &Palette,
+    appearance: crate::style::virtual_list::Appearance,
+) -> crate::style::virtual_list::Appearance {
+    crate::style::virtual_list::Appearance {
+        scrollbar_border_radius: CONTROL_RADIUS.into(),
+        scroller_color: shade(palette, 2.0 * STROKE_SHADE),
+        ..appearance
+    }
+}
+
+/// XY pads are outlined surfaces with a large outlined handle.
+#[cfg(feature = "xy_pad")]
+fn xy_pad(
+    palette: &Palette,
+    appearance: crate::style::xy_pad::Appearance,
+) -> crate::style::xy_pad::Appearance {
+    crate::style::xy_pad::Appearance {
+        border_radius: CONTROL_RADIUS.into(),
+        border_width: STROKE_WIDTH,
+        border_color: shade(palette, STROKE_SHADE),
+        handle_radius: 10.0,
+        handle_border_width: STROKE_WIDTH,
+        handle_border_color: shade(palette, STROKE_SHADE),
+        ..appearance
+    }
+}
@@ -0,0 +1,1059 @@
+//! Themes after the [Material Design 3](https://m3.material.io) design
+//! language.
+//!
+//! Material surfaces are borderless and strongly rounded. They are set apart by
+//! tonal shades of the primary color instead of outlines.
+use iced_native::Color;
+use iced_style::theme::Palette;
+
+use super::{
+    super::theme::{Hooks, ThemeBuilder},
+    shade, tint,
+};
+
+/// The corner radius of the containers, like cards and menus.
+const CONTAINER_RADIUS: f32 = 12.0;
+
+/// The corner radius of the dialogs, like the pickers.
+const DIALOG_RADIUS: f32 = 28.0;
+
+/// The corner radius of the menus.
+const MENU_RADIUS: f32 = 4.0;
+
+/// The light Material theme with the baseline color scheme.
+#[must_use]
+pub fn light() -> iced_style::Theme {
+    ThemeBuilder::new(
+        Color::from_rgb8(0x67, 0x50, 0xA4),
+        Color::from_rgb8(0xFF, 0xFB, 0xFE),
+    )
+    .text(Color::from_rgb8(0x1C, 0x1B, 0x1F))
+    .success(Color::from_rgb8(0x38, 0x6A, 0x20))
+    .danger(Color::from_rgb8(0xB3, 0x26, 0x1E))
+    .hooks(hooks())
+    .build()
+}
+
+/// The dark Material theme with the baseline color scheme.
+#[must_use]
+pub fn dark() -> iced_style::Theme {
+    ThemeBuilder::new(
+        Color::from_rgb8(0xD0, 0xBC, 0xFF),
+        Color::from_rgb8(0x1C, 0x1B, 0x1F),
+    )
+    .text(Color::from_rgb8(0xE6, 0xE1, 0xE5))
+    .success(Color::from_rgb8(0x9C, 0xD6, 0x7D))
+    .danger(Color::from_rgb8(0xF2, 0xB8, 0xB5))
+    .hooks(hooks())
+    .build()
+}
+
+/// The [`Hooks`](Hooks) reshaping all widgets after Material Design.
+#[must_use]
+pub fn hooks() -> Hooks {
+    Hooks {
+        #[cfg(feature = "accordion")]
+        accordion: Some(accordion),
+        #[cfg(feature = "avatar")]
+        avatar: Some(avatar),
+        #[cfg(feature = "badge")]
+        badge: Some(badge),
+        #[cfg(feature = "breadcrumbs")]
+        breadcrumbs: Some(breadcrumbs),
+        #[cfg(feature = "calendar_heatmap")]
+        calendar_heatmap: Some(calendar_heatmap),
+        #[cfg(feature = "card")]
+        card: Some(card),
+        #[cfg(feature = "carousel")]
+        carousel: Some(carousel),
+        #[cfg(feature = "chart")]
+        chart: Some(chart),
+        #[cfg(feature = "chip")]
+        chip: Some(chip),
+        #[cfg(feature = "circular_progress")]
+        circular_progress: Some(circular_progress),
+        #[cfg(feature = "code_editor")]
+        code_editor: Some(code_editor),
+        #[cfg(feature = "color_picker")]
+        color_picker: Some(color_picker),
+        #[cfg(feature = "combo_box")]
+        combo_box: Some(combo_box),
+        #[cfg(feature = "context_menu")]
+        context_menu: Some(context_menu),
+        #[cfg(feature = "date_picker")]
+        date_picker: Some(date_picker),
+        #[cfg(feature = "drag_and_drop")]
+        drag_and_drop: Some(drag_and_drop),
+        #[cfg(feature = "drawing_pad")]
+        drawing_pad: Some(drawing_pad),
+        #[cfg(feature = "drop_zone")]
+        drop_zone: Some(drop_zone),
+        #[cfg(feature = "gallery")]
+        gallery: Some(gallery),
+        #[cfg(feature = "gauge")]
+        gauge: Some(gauge),
+        #[cfg(feature = "heatmap")]
+        heatmap: Some(heatmap),
+        #[cfg(feature = "hotkey_input")]
+        hotkey_input: Some(hotkey_input),
+        #[cfg(feature = "hover_card")]
+        hover_card: Some(hover_card),
+        #[cfg(feature = "knob")]
+        knob: Some(knob),
+        #[cfg(feature = "menu")]
+        menu_bar: Some(menu_bar),
+        #[cfg(feature = "menu_button")]
+        menu_button: Some(menu_button),
+        #[cfg(feature = "minimap")]
+        minimap: Some(minimap),
+        #[cfg(feature = "modal")]
+        modal: Some(modal),
+        #[cfg(feature = "month_view")]
+        month_view: Some(month_view),
+        #[cfg(feature = "multi_select")]
+        multi_select: Some(multi_select),
+        #[cfg(feature = "number_input")]
+        number_input: Some(number_input),
+        #[cfg(feature = "pagination")]
+        pagination: Some(pagination),
+        #[cfg(feature = "pin_input")]
+        pin_input: Some(pin_input),
+        #[cfg(feature = "popover")]
+        popover: Some(popover),
+        #[cfg(feature = "property_grid")]
+        property_grid: Some(property_grid),
+        #[cfg(feature = "qr_code")]
+        qr_code: Some(qr_code),
+        #[cfg(feature = "radial_menu")]
+        radial_menu: Some(radial_menu),
+        #[cfg(feature = "range_slider")]
+        range_slider: Some(range_slider),
+        #[cfg(feature = "rating")]
+        rating: Some(rating),
+        #[cfg(feature = "resizable")]
+        resizable: Some(resizable),
+        #[cfg(feature = "rich_text")]
+        rich_text: Some(rich_text),
+        #[cfg(feature = "scrubber")]
+        scrubber: Some(scrubber),
+        #[cfg(feature = "search_bar")]
+        search_bar: Some(search_bar),
+        #[cfg(feature = "segmented_button")]
+        segmented_button: Some(segmented_button),
+        #[cfg(feature = "selection_list")]
+        selection_list: Some(selection_list),
+        #[cfg(feature = "shortcut_hint")]
+        shortcut_hint: Some(shortcut_hint),
+        #[cfg(feature = "skeleton")]
+        skeleton: Some(skeleton),
+        #[cfg(feature = "split")]
+        split: Some(split),
+        #[cfg(feature = "spreadsheet_grid")]
+        spreadsheet_grid: Some(spreadsheet_grid),
+        #[cfg(feature = "status_dot")]
+        status_dot: Some(status_dot),
+        #[cfg(feature = "stepper")]
+        stepper: Some(stepper),
+        #[cfg(feature = "switch")]
+        switch: Some(switch),
+        #[cfg(feature = "table")]
+        table: Some(table),
+        #[cfg(feature = "tab_bar")]
+        tab_bar: Some(tab_bar),
+        #[cfg(feature = "tag_input")]
+        tag_input: Some(tag_input),
+        #[cfg(feature = "tick_slider")]
+        tick_slider: Some(tick_slider),
+        #[cfg(feature = "time_picker")]
+        time_picker: Some(time_picker),
+        #[cfg(feature = "toast")]
+        toast: Some(toast),
+        #[cfg(feature = "tooltip")]
+        tooltip: Some(tooltip),
+        #[cfg(feature = "tour")]
+        tour: Some(tour),
+        #[cfg(feature = "transfer_list")]
+        transfer_list: Some(transfer_list),
+        #[cfg(feature = "virtual_list")]
+        virtual_list: Some(virtual_list),
+        #[cfg(feature = "xy_pad")]
+        xy_pad: Some(xy_pad),
+    }
+}
+
+/// The scrim behind modal surfaces.
+#[cfg(any(feature = "context_menu", feature = "modal"))]
+const SCRIM: Color = Color {
+    a: 0.32,
+    ..Color::BLACK
+};
+
+/// Accordions are borderless containers with a tinted header.
+#[cfg(feature = "accordion")]
+fn accordion(
+    palette: &Palette,
+    appearance: crate::style::accordion::Appearance,
+) -> crate::style::accordion::Appearance {
+    crate::style::accordion::Appearance {
+        background: tint(palette, 0.05).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        header_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// Avatars count the hidden members of a group on a tonal shade.
+#[cfg(feature = "avatar")]
+fn avatar(
+    palette: &Palette,
+    appearance: crate::style::avatar::Appearance,
+) -> crate::style::avatar::Appearance {
+    crate::style::avatar::Appearance {
+        overflow_background: tint(palette, 0.24),
+        overflow_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Badges are borderless pills.
+#[cfg(feature = "badge")]
+fn badge(
+    _palette: &Palette,
+    appearance: crate::style::badge::Appearance,
+) -> crate::style::badge::Appearance {
+    crate::style::badge::Appearance {
+        border_radius: None,
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Breadcrumbs highlight hovered segments with a tinted, rounded surface.
+#[cfg(feature = "breadcrumbs")]
+fn breadcrumbs(
+    palette: &Palette,
+    appearance: crate::style::breadcrumbs::Appearance,
+) -> crate::style::breadcrumbs::Appearance {
+    crate::style::breadcrumbs::Appearance {
+        hovered_background: tint(palette, 0.08).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Calendar heatmaps fade from a tonal surface to the primary color.
+#[cfg(feature = "calendar_heatmap")]
+fn calendar_heatmap(
+    palette: &Palette,
+    appearance: crate::style::calendar_heatmap::Appearance,
+) -> crate::style::calendar_heatmap::Appearance {
+    crate::style::calendar_heatmap::Appearance {
+        empty_color: tint(palette, 0.08),
+        low_color: tint(palette, 0.3),
+        high_color: palette.primary,
+        tooltip_border_radius: MENU_RADIUS,
+        ..appearance
+    }
+}
+
+/// Cards are filled, borderless containers on a tinted surface.
+#[cfg(feature = "card")]
+fn card(
+    palette: &Palette,
+    appearance: crate::style::card::Appearance,
+) -> crate::style::card::Appearance {
+    crate::style::card::Appearance {
+        background: tint(palette, 0.05).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Carousels mark the shown element with a tonal dot.
+#[cfg(feature = "carousel")]
+fn carousel(
+    palette: &Palette,
+    appearance: crate::style::carousel::Appearance,
+) -> crate::style::carousel::Appearance {
+    crate::style::carousel::Appearance {
+        dot_color: tint(palette, 0.24),
+        ..appearance
+    }
+}
+
+/// Charts draw their grid in a light tint of the primary color.
+#[cfg(feature = "chart")]
+fn chart(
+    palette: &Palette,
+    appearance: crate::style::chart::Appearance,
+) -> crate::style::chart::Appearance {
+    crate::style::chart::Appearance {
+        grid_color: tint(palette, 0.12),
+        ..appearance
+    }
+}
+
+/// Chips are outlined with small rounded corners.
+#[cfg(feature = "chip")]
+fn chip(
+    palette: &Palette,
+    appearance: crate::style::chip::Appearance,
+) -> crate::style::chip::Appearance {
+    crate::style::chip::Appearance {
+        border_radius: 8.0.into(),
+        border_color: shade(palette, 0.32),
+        ..appearance
+    }
+}
+
+/// Circular progress indicators run on a tonal track.
+#[cfg(feature = "circular_progress")]
+fn circular_progress(
+    palette: &Palette,
+    appearance: crate::style::circular_progress::Appearance,
+) -> crate::style::circular_progress::Appearance {
+    crate::style::circular_progress::Appearance {
+        track_color: tint(palette, 0.24),
+        ..appearance
+    }
+}
+
+/// Code editors are tonal containers with a borderless gutter.
+#[cfg(feature = "code_editor")]
+fn code_editor(
+    palette: &Palette,
+    appearance: crate::style::code_editor::Appearance,
+) -> crate::style::code_editor::Appearance {
+    crate::style::code_editor::Appearance {
+        border_radius: CONTAINER_RADIUS.into(),
+        border_color: shade(palette, 0.32),
+        gutter_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// Color pickers are borderless dialogs.
+#[cfg(feature = "color_picker")]
+fn color_picker(
+    palette: &Palette,
+    appearance: crate::style::color_picker::Appearance,
+) -> crate::style::color_picker::Appearance {
+    crate::style::color_picker::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: DIALOG_RADIUS,
+        border_width: 0.0,
+        bar_border_radius: MENU_RADIUS,
+        bar_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Combo boxes open slightly rounded, borderless menus with a tinted highlight.
+#[cfg(feature = "combo_box")]
+fn combo_box(
+    palette: &Palette,
+    appearance: crate::style::combo_box::Appearance,
+) -> crate::style::combo_box::Appearance {
+    crate::style::combo_box::Appearance {
+        background: tint(palette, 0.05).into(),
+        border_radius: MENU_RADIUS.into(),
+        border_width: 0.0,
+        highlighted_background: tint(palette, 0.12).into(),
+        highlighted_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Context menus dim the content behind them with the scrim.
+#[cfg(feature = "context_menu")]
+fn context_menu(
+    _palette: &Palette,
+    appearance: crate::style::context_menu::Appearance,
+) -> crate::style::context_menu::Appearance {
+    crate::style::context_menu::Appearance {
+        background: SCRIM.into(),
+        ..appearance
+    }
+}
+
+/// Date pickers are borderless dialogs.
+#[cfg(feature = "date_picker")]
+fn date_picker(
+    palette: &Palette,
+    appearance: crate::style::date_picker::Appearance,
+) -> crate::style::date_picker::Appearance {
+    crate::style::date_picker::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: DIALOG_RADIUS,
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Drop targets and drag ghosts have the rounded corners of containers.
+#[cfg(feature = "drag_and_drop")]
+fn drag_and_drop(
+    _palette: &Palette,
+    appearance: crate::style::drag_and_drop::Appearance,
+) -> crate::style::drag_and_drop::Appearance {
+    crate::style::drag_and_drop::Appearance {
+        border_radius: CONTAINER_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Drawing pads are tonal containers.
+#[cfg(feature = "drawing_pad")]
+fn drawing_pad(
+    palette: &Palette,
+    appearance: crate::style::drawing_pad::Appearance,
+) -> crate::style::drawing_pad::Appearance {
+    crate::style::drawing_pad::Appearance {
+        background: tint(palette, 0.04).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_color: shade(palette, 0.32),
+        ..appearance
+    }
+}
+
+/// Drop zones are tonal containers.
+#[cfg(feature = "drop_zone")]
+fn drop_zone(
+    palette: &Palette,
+    appearance: crate::style::drop_zone::Appearance,
+) -> crate::style::drop_zone::Appearance {
+    crate::style::drop_zone::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_color: shade(palette, 0.32),
+        ..appearance
+    }
+}
+
+/// Galleries put their thumbnails on a tonal surface.
+#[cfg(feature = "gallery")]
+fn gallery(
+    palette: &Palette,
+    appearance: crate::style::gallery::Appearance,
+) -> crate::style::gallery::Appearance {
+    crate::style::gallery::Appearance {
+        strip_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// Gauges run on a tonal track.
+#[cfg(feature = "gauge")]
+fn gauge(
+    palette: &Palette,
+    appearance: crate::style::gauge::Appearance,
+) -> crate::style::gauge::Appearance {
+    crate::style::gauge::Appearance {
+        track_color: tint(palette, 0.24),
+        ..appearance
+    }
+}
+
+/// Heatmaps blend from the primary color through a tonal surface to the error color.
+#[cfg(feature = "heatmap")]
+fn heatmap(
+    palette: &Palette,
+    appearance: crate::style::heatmap::Appearance,
+) -> crate::style::heatmap::Appearance {
+    crate::style::heatmap::Appearance {
+        empty_color: tint(palette, 0.04),
+        low_color: palette.primary,
+        middle_color: tint(palette, 0.12),
+        high_color: palette.danger,
+        tooltip_border_radius: MENU_RADIUS,
+        ..appearance
+    }
+}
+
+/// Hotkey inputs are filled fields without a border.
+#[cfg(feature = "hotkey_input")]
+fn hotkey_input(
+    palette: &Palette,
+    appearance: crate::style::hotkey_input::Appearance,
+) -> crate::style::hotkey_input::Appearance {
+    crate::style::hotkey_input::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Hover cards are borderless, tinted containers.
+#[cfg(feature = "hover_card")]
+fn hover_card(
+    palette: &Palette,
+    appearance: crate::style::hover_card::Appearance,
+) -> crate::style::hover_card::Appearance {
+    crate::style::hover_card::Appearance {
+        background: tint(palette, 0.05).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Knobs run on a tonal track.
+#[cfg(feature = "knob")]
+fn knob(
+    palette: &Palette,
+    appearance: crate::style::knob::Appearance,
+) -> crate::style::knob::Appearance {
+    crate::style::knob::Appearance {
+        track_color: tint(palette, 0.24),
+        ..appearance
+    }
+}
+
+/// Menus are borderless, slightly rounded and tinted.
+#[cfg(feature = "menu")]
+fn menu_bar(
+    palette: &Palette,
+    appearance: crate::style::menu_bar::Appearance,
+) -> crate::style::menu_bar::Appearance {
+    crate::style::menu_bar::Appearance {
+        background: tint(palette, 0.08),
+        border_radius: [MENU_RADIUS; 4],
+        border_width: 0.0,
+        path: tint(palette, 0.12),
+        ..appearance
+    }
+}
+
+/// The menus of menu buttons are tinted surfaces with rounded corners.
+#[cfg(feature = "menu_button")]
+fn menu_button(
+    palette: &Palette,
+    appearance: crate::style::menu_button::Appearance,
+) -> crate::style::menu_button::Appearance {
+    crate::style::menu_button::Appearance {
+        border_radius: MENU_RADIUS.into(),
+        menu_background: tint(palette, 0.05).into(),
+        hovered_background: tint(palette, 0.12).into(),
+        hovered_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Minimaps are tonal containers.
+#[cfg(feature = "minimap")]
+fn minimap(
+    palette: &Palette,
+    appearance: crate::style::minimap::Appearance,
+) -> crate::style::minimap::Appearance {
+    crate::style::minimap::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Modals dim the content behind them with the scrim.
+#[cfg(feature = "modal")]
+fn modal(
+    _palette: &Palette,
+    appearance: crate::style::modal::Appearance,
+) -> crate::style::modal::Appearance {
+    crate::style::modal::Appearance {
+        background: SCRIM.into(),
+        ..appearance
+    }
+}
+
+/// The events of month views are rounded tonal chips.
+#[cfg(feature = "month_view")]
+fn month_view(
+    palette: &Palette,
+    appearance: crate::style::month_view::Appearance,
+) -> crate::style::month_view::Appearance {
+    crate::style::month_view::Appearance {
+        grid_width: 0.0,
+        event_border_radius: MENU_RADIUS,
+        event_background: tint(palette, 0.12).into(),
+        event_hovered_background: tint(palette, 0.2).into(),
+        event_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Multi selects open borderless, tinted menus with slightly rounded corners.
+#[cfg(feature = "multi_select")]
+fn multi_select(
+    palette: &Palette,
+    appearance: crate::style::multi_select::Appearance,
+) -> crate::style::multi_select::Appearance {
+    crate::style::multi_select::Appearance {
+        border_radius: MENU_RADIUS.into(),
+        menu_background: tint(palette, 0.05).into(),
+        hovered_background: tint(palette, 0.12).into(),
+        ..appearance
+    }
+}
+
+/// The buttons of number inputs are tonal.
+#[cfg(feature = "number_input")]
+fn number_input(
+    palette: &Palette,
+    appearance: crate::style::number_input::Appearance,
+) -> crate::style::number_input::Appearance {
+    crate::style::number_input::Appearance {
+        button_background: Some(tint(palette, 0.12).into()),
+        icon_color: palette.primary,
+        ..appearance
+    }
+}
+
+/// Paginations are borderless buttons with a tinted hover.
+#[cfg(feature = "pagination")]
+fn pagination(
+    palette: &Palette,
+    appearance: crate::style::pagination::Appearance,
+) -> crate::style::pagination::Appearance {
+    crate::style::pagination::Appearance {
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        hovered_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// PIN inputs show their boxes with the rounded corners of small containers.
+#[cfg(feature = "pin_input")]
+fn pin_input(
+    palette: &Palette,
+    appearance: crate::style::pin_input::Appearance,
+) -> crate::style::pin_input::Appearance {
+    crate::style::pin_input::Appearance {
+        border_radius: 8.0.into(),
+        border_color: shade(palette, 0.32),
+        ..appearance
+    }
+}
+
+/// Popovers are borderless, tinted menus.
+#[cfg(feature = "popover")]
+fn popover(
+    palette: &Palette,
+    appearance: crate::style::popover::Appearance,
+) -> crate::style::popover::Appearance {
+    crate::style::popover::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: MENU_RADIUS.into(),
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Property grids have rounded corners and tinted section headers.
+#[cfg(feature = "property_grid")]
+fn property_grid(
+    palette: &Palette,
+    appearance: crate::style::property_grid::Appearance,
+) -> crate::style::property_grid::Appearance {
+    crate::style::property_grid::Appearance {
+        border_radius: CONTAINER_RADIUS.into(),
+        border_color: shade(palette, 0.32),
+        header_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// QR codes have the slightly rounded corners of menus.
+#[cfg(feature = "qr_code")]
+fn qr_code(
+    _palette: &Palette,
+    appearance: crate::style::qr_code::Appearance,
+) -> crate::style::qr_code::Appearance {
+    crate::style::qr_code::Appearance {
+        border_radius: MENU_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Radial menus are borderless tonal surfaces with a primary hovered action.
+#[cfg(feature = "radial_menu")]
+fn radial_menu(
+    palette: &Palette,
+    appearance: crate::style::radial_menu::Appearance,
+) -> crate::style::radial_menu::Appearance {
+    crate::style::radial_menu::Appearance {
+        ring_background: tint(palette, 0.04).into(),
+        ring_border_width: 0.0,
+        center_background: tint(palette, 0.12).into(),
+        hovered_background: palette.primary.into(),
+        ..appearance
+    }
+}
+
+/// Range sliders have filled, borderless handles on a tinted rail.
+#[cfg(feature = "range_slider")]
+fn range_slider(
+    palette: &Palette,
+    appearance: crate::style::range_slider::Appearance,
+) -> crate::style::range_slider::Appearance {
+    crate::style::range_slider::Appearance {
+        rail_color: tint(palette, 0.24),
+        handle_color: palette.primary,
+        handle_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Ratings are filled with the primary color.
+#[cfg(feature = "rating")]
+fn rating(
+    palette: &Palette,
+    appearance: crate::style::rating::Appearance,
+) -> crate::style::rating::Appearance {
+    crate::style::rating::Appearance {
+        filled_color: palette.primary,
+        hovered_color: tint(palette, 0.6),
+        ..appearance
+    }
+}
+
+/// Resizables have thicker handles.
+#[cfg(feature = "resizable")]
+fn resizable(
+    _palette: &Palette,
+    appearance: crate::style::resizable::Appearance,
+) -> crate::style::resizable::Appearance {
+    crate::style::resizable::Appearance {
+        handle_width: 3.0,
+        ..appearance
+    }
+}
+
+/// Rich texts use the high emphasis text color, 87% of the way to the text.
+#[cfg(feature = "rich_text")]
+fn rich_text(
+    palette: &Palette,
+    appearance: crate::style::rich_text::Appearance,
+) -> crate::style::rich_text::Appearance {
+    crate::style::rich_text::Appearance {
+        text_color: shade(palette, 0.87),
+        ..appearance
+    }
+}
+
+/// Scrubbers have a thin tinted rail and a primary played range.
+#[cfg(feature = "scrubber")]
+fn scrubber(
+    palette: &Palette,
+    appearance: crate::style::scrubber::Appearance,
+) -> crate::style::scrubber::Appearance {
+    crate::style::scrubber::Appearance {
+        rail_color: tint(palette, 0.12),
+        buffered_color: tint(palette, 0.32),
+        played_color: palette.primary,
+        handle_color: palette.primary,
+        ..appearance
+    }
+}
+
+/// Search bars draw their icons in the text color at medium emphasis.
+#[cfg(feature = "search_bar")]
+fn search_bar(
+    palette: &Palette,
+    appearance: crate::style::search_bar::Appearance,
+) -> crate::style::search_bar::Appearance {
+    crate::style::search_bar::Appearance {
+        icon_color: shade(palette, 0.6),
+        ..appearance
+    }
+}
+
+/// Segmented buttons are outlined pills marking the selection with a tonal shade.
+#[cfg(feature = "segmented_button")]
+fn segmented_button(
+    palette: &Palette,
+    appearance: crate::style::segmented_button::Appearance,
+) -> crate::style::segmented_button::Appearance {
+    crate::style::segmented_button::Appearance {
+        selected_background: tint(palette, 0.24).into(),
+        selected_text_color: palette.text,
+        hovered_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// Selection lists are borderless, marking the selection with a tonal shade.
+#[cfg(feature = "selection_list")]
+fn selection_list(
+    palette: &Palette,
+    appearance: crate::style::selection_list::Appearance,
+) -> crate::style::selection_list::Appearance {
+    crate::style::selection_list::Appearance {
+        border_width: 0.0,
+        hovered_background: shade(palette, 0.08).into(),
+        hovered_text_color: palette.text,
+        selected_background: tint(palette, 0.12).into(),
+        selected_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Shortcut hints are flat tonal keycaps.
+#[cfg(feature = "shortcut_hint")]
+fn shortcut_hint(
+    palette: &Palette,
+    appearance: crate::style::shortcut_hint::Appearance,
+) -> crate::style::shortcut_hint::Appearance {
+    crate::style::shortcut_hint::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_width: 0.0,
+        depth: 0.0,
+        ..appearance
+    }
+}
+
+/// Skeletons are tonal shapes with a lighter shimmer.
+#[cfg(feature = "skeleton")]
+fn skeleton(
+    palette: &Palette,
+    appearance: crate::style::skeleton::Appearance,
+) -> crate::style::skeleton::Appearance {
+    crate::style::skeleton::Appearance {
+        background: tint(palette, 0.12),
+        highlight: tint(palette, 0.04),
+        ..appearance
+    }
+}
+
+/// Splits are divided by a thin, borderless divider.
+#[cfg(feature = "split")]
+fn split(
+    palette: &Palette,
+    appearance: crate::style::split::Appearance,
+) -> crate::style::split::Appearance {
+    crate::style::split::Appearance {
+        border_width: 0.0,
+        divider_background: shade(palette, 0.12).into(),
+        divider_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Spreadsheet grids have rounded corners and tinted headers.
+#[cfg(feature = "spreadsheet_grid")]
+fn spreadsheet_grid(
+    palette: &Palette,
+    appearance: crate::style::spreadsheet_grid::Appearance,
+) -> crate::style::spreadsheet_grid::Appearance {
+    crate::style::spreadsheet_grid::Appearance {
+        border_radius: CONTAINER_RADIUS.into(),
+        border_color: shade(palette, 0.32),
+        header_background: tint(palette, 0.08).into(),
+        ..appearance
+    }
+}
+
+/// Status dots are flat and borderless.
+#[cfg(feature = "status_dot")]
+fn status_dot(
+    _palette: &Palette,
+    appearance: crate::style::status_dot::Appearance,
+) -> crate::style::status_dot::Appearance {
+    crate::style::status_dot::Appearance {
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Steppers have tinted upcoming markers and strongly rounded buttons.
+#[cfg(feature = "stepper")]
+fn stepper(
+    palette: &Palette,
+    appearance: crate::style::stepper::Appearance,
+) -> crate::style::stepper::Appearance {
+    crate::style::stepper::Appearance {
+        upcoming_background: tint(palette, 0.12).into(),
+        button_border_radius: CONTAINER_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Switches have a borderless track.
+#[cfg(feature = "switch")]
+fn switch(
+    _palette: &Palette,
+    appearance: crate::style::switch::Appearance,
+) -> crate::style::switch::Appearance {
+    crate::style::switch::Appearance {
+        track_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Tables are borderless, setting the header and the selection apart with
+/// tonal shades.
+#[cfg(feature = "table")]
+fn table(
+    palette: &Palette,
+    appearance: crate::style::table::Appearance,
+) -> crate::style::table::Appearance {
+    crate::style::table::Appearance {
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        header_background: tint(palette, 0.08).into(),
+        header_text_color: palette.text,
+        divider_color: shade(palette, 0.12),
+        stripe_background: shade(palette, 0.03).into(),
+        hovered_background: shade(palette, 0.08).into(),
+        hovered_text_color: palette.text,
+        selected_background: tint(palette, 0.12).into(),
+        selected_text_color: palette.text,
+        ..appearance
+    }
+}
+
+/// Tab labels are borderless.
+#[cfg(feature = "tab_bar")]
+fn tab_bar(
+    _palette: &Palette,
+    appearance: crate::style::tab_bar::Appearance,
+) -> crate::style::tab_bar::Appearance {
+    crate::style::tab_bar::Appearance {
+        border_width: 0.0,
+        tab_label_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Tag inputs show their tags as tinted, slightly rounded chips.
+#[cfg(feature = "tag_input")]
+fn tag_input(
+    palette: &Palette,
+    appearance: crate::style::tag_input::Appearance,
+) -> crate::style::tag_input::Appearance {
+    crate::style::tag_input::Appearance {
+        background: tint(palette, 0.12).into(),
+        border_radius: 8.0.into(),
+        ..appearance
+    }
+}
+
+/// Tick sliders have a filled handle and a tinted rail.
+#[cfg(feature = "tick_slider")]
+fn tick_slider(
+    palette: &Palette,
+    appearance: crate::style::tick_slider::Appearance,
+) -> crate::style::tick_slider::Appearance {
+    crate::style::tick_slider::Appearance {
+        rail_color: tint(palette, 0.24),
+        handle_color: palette.primary,
+        handle_border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Time pickers are borderless dialogs with a primary clock hand.
+#[cfg(feature = "time_picker")]
+fn time_picker(
+    palette: &Palette,
+    appearance: crate::style::time_picker::Appearance,
+) -> crate::style::time_picker::Appearance {
+    crate::style::time_picker::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: DIALOG_RADIUS,
+        border_width: 0.0,
+        clock_number_background: shade(palette, 0.08),
+        clock_hand_color: palette.primary,
+        ..appearance
+    }
+}
+
+/// Toasts are borderless tinted surfaces, keeping the status color for the
+/// indicator of the remaining time.
+#[cfg(feature = "toast")]
+fn toast(
+    palette: &Palette,
+    appearance: crate::style::toast::Appearance,
+) -> crate::style::toast::Appearance {
+    crate::style::toast::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        border_width: 0.0,
+        ..appearance
+    }
+}
+
+/// Tooltips are small, borderless inverse surfaces.
+#[cfg(feature = "tooltip")]
+fn tooltip(
+    palette: &Palette,
+    appearance: crate::style::tooltip::Appearance,
+) -> crate::style::tooltip::Appearance {
+    crate::style::tooltip::Appearance {
+        background: shade(palette, 0.9).into(),
+        border_radius: MENU_RADIUS.into(),
+        border_width: 0.0,
+        text_color: palette.background,
+        ..appearance
+    }
+}
+
+/// Tours have strongly rounded cards and buttons.
+#[cfg(feature = "tour")]
+fn tour(
+    palette: &Palette,
+    appearance: crate::style::tour::Appearance,
+) -> crate::style::tour::Appearance {
+    crate::style::tour::Appearance {
+        card_background: tint(palette, 0.04).into(),
+        card_border_radius: CONTAINER_RADIUS.into(),
+        button_border_radius: CONTAINER_RADIUS.into(),
+        ..appearance
+    }
+}
+
+/// Transfer lists are borderless, tinted containers with tonal buttons.
+#[cfg(feature = "transfer_list")]
+fn transfer_list(
+    palette: &Palette,
+    appearance: crate::style::transfer_list::Appearance,
+) -> crate::style::transfer_list::Appearance {
+    crate::style::transfer_list::Appearance {
+        background: tint(palette, 0.05).into(),
+        border_radius: MENU_RADIUS.into(),
+        border_width: 0.0,
+        hovered_background: tint(palette, 0.12).into(),
+        button_background: tint(palette, 0.12).into(),
+        ..appearance
+    }
+}
+
+/// Virtual lists have tonal scrollers.
+#[cfg(feature = "virtual_list")]
+fn virtual_list(
+    palette: &Palette,
+    appearance: crate::style::virtual_list::Appearance,
+) -> crate::style::virtual_list::Appearance {
+    crate::style::virtual_list::Appearance {
+        scroller_color: tint(palette, 0.4),
+        ..appearance
+    }
+}
+
+/// XY pads have a tinted surface and a filled handle.
+#[cfg(feature = "xy_pad")]
+fn xy_pad(
+    palette: &Palette,
+    appearance: crate::style::xy_pad::Appearance,
+) -> crate::style::xy_pad::Appearance {
+    crate::style::xy_pad::Appearance {
+        background: tint(palette, 0.08).into(),
+        border_radius: CONTAINER_RADIUS.into(),
+        handle_color: palette.primary,
+        handle_border_width: 0.0,
+        ..appearance
+    }
+}
@@ -0,0 +1,152 @@
+//! Edit named properties grouped into collapsible sections.
+//!
+//! *This API requires the following crate features to be activated: property_grid*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the grid.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the grid.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the grid.
+    pub border_width: f32,
+
+    /// The border color of the grid.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The background of the header of a section.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub header_background: Background,
+
+    /// The text color of the title of a section.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub header_text_color: Color,
+
+    /// The color of the chevron of a section.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The text color of the names of the properties.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_color: Color,
+
+    /// The color of the lines between the properties.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub separator_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 0.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            header_background: Color::from_rgb(0.93, 0.93, 0.93).into(),
+            header_text_color: Color::BLACK,
+            icon_color: Color::BLACK,
+            label_color: [0.25, 0.25, 0.25].into(),
+            separator_color: [0.9, 0.9, 0.9].into(),
+        }
+    }
+}
+
+/// The appearance of a [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a section whose header is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`PropertyGrid`](crate::native::property_grid::PropertyGrid) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`PropertyGrid`](crate::native::property_grid::PropertyGrid) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`PropertyGrid`](crate::native::property_grid::PropertyGrid) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PropertyGridStyles {
+    #[default]
+    Default,
+    Patched(Patched<PropertyGridStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = PropertyGridStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let PropertyGridStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            icon_color: palette.background.weak.text,
+            label_color: palette.background.base.text,
+            separator_color: palette.background.weak.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.property_grid, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            header_background: palette.primary.weak.color.into(),
+            header_text_color: palette.primary.weak.text,
+            icon_color: palette.primary.weak.text,
+            ..active
+        }
+    }
+}
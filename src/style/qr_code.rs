@@ -0,0 +1,94 @@
+//! Show data as a QR code.
+//!
+//! *This API requires the following crate features to be activated: qr_code*
+use iced_native::{renderer::BorderRadius, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`QrCode`](crate::native::qr_code::QrCode).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the dark modules.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub dark: Color,
+
+    /// The color of the light modules, of the quiet zone and behind the logo.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub light: Color,
+
+    /// The border radius of the code and of the plate behind the logo.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            dark: Color::BLACK,
+            light: Color::WHITE,
+            border_radius: 0.0.into(),
+        }
+    }
+}
+
+/// The appearance of a [`QrCode`](crate::native::qr_code::QrCode).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The appearance of a [`QrCode`](crate::native::qr_code::QrCode).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`QrCode`](crate::native::qr_code::QrCode) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`QrCode`](crate::native::qr_code::QrCode) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`QrCode`](crate::native::qr_code::QrCode) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`QrCode`](crate::native::qr_code::QrCode) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`QrCode`](crate::native::qr_code::QrCode).
+///
+/// Scanners read dark modules on a light background best, so the code stays
+/// black on white in dark themes too.
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum QrCodeStyles {
+    #[default]
+    Default,
+    Patched(Patched<QrCodeStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = QrCodeStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let QrCodeStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        customize(self, |hooks| hooks.qr_code, Appearance::default())
+    }
+}
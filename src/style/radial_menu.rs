@@ -0,0 +1,152 @@
+//! A ring of actions opened around the cursor, like the tool menus of pen and
+//! touch canvases.
+//!
+//! *This API requires the following crate features to be activated: radial_menu*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`RadialMenu`](crate::native::radial_menu::RadialMenu).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the rings.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub ring_background: Background,
+
+    /// The border width of the rings and the center.
+    pub ring_border_width: f32,
+
+    /// The border color of the rings and the center.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub ring_border_color: Color,
+
+    /// The background of the center, which selects nothing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub center_background: Background,
+
+    /// The color of the icons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The background of the hovered item and the open submenus.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The color of the icons of the hovered item and the open submenus.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_icon_color: Color,
+
+    /// The color of the dots marking the items opening a submenu.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub indicator_color: Color,
+
+    /// The background of the label of the hovered item.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub label_background: Background,
+
+    /// The text color of the label of the hovered item.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            ring_background: Color::WHITE.into(),
+            ring_border_width: 1.0,
+            ring_border_color: [0.8, 0.8, 0.8].into(),
+            center_background: Color::from_rgb(0.95, 0.95, 0.95).into(),
+            icon_color: [0.2, 0.2, 0.2].into(),
+            hovered_background: Color::from_rgb(0.0, 0.4, 0.8).into(),
+            hovered_icon_color: Color::WHITE,
+            indicator_color: [0.6, 0.6, 0.6].into(),
+            label_background: Color::from_rgb(0.2, 0.2, 0.2).into(),
+            label_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`RadialMenu`](crate::native::radial_menu::RadialMenu).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`RadialMenu`](crate::native::radial_menu::RadialMenu).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`RadialMenu`](crate::native::radial_menu::RadialMenu) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`RadialMenu`](crate::native::radial_menu::RadialMenu) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`RadialMenu`](crate::native::radial_menu::RadialMenu) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`RadialMenu`](crate::native::radial_menu::RadialMenu) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`RadialMenu`](crate::native::radial_menu::RadialMenu).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RadialMenuStyles {
+    #[default]
+    Default,
+    Patched(Patched<RadialMenuStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = RadialMenuStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let RadialMenuStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            ring_background: palette.background.base.color.into(),
+            ring_border_color: palette.background.strong.color,
+            center_background: palette.background.weak.color.into(),
+            icon_color: palette.background.base.text,
+            hovered_background: palette.primary.base.color.into(),
+            hovered_icon_color: palette.primary.base.text,
+            indicator_color: palette.background.strong.color,
+            label_background: palette.background.base.text.into(),
+            label_text_color: palette.background.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.radial_menu, appearance)
+    }
+}
@@ -0,0 +1,153 @@
+//! Select a sub-range of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: range_slider*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the rail outside of the selected range.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub rail_color: Color,
+
+    /// The width of the rail.
+    pub rail_width: f32,
+
+    /// The color of the rail between the handles.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub filled_color: Color,
+
+    /// The radius of the handles.
+    pub handle_radius: f32,
+
+    /// The color of the handles.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_color: Color,
+
+    /// The border width of the handles.
+    pub handle_border_width: f32,
+
+    /// The border color of the handles.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            rail_color: [0.8, 0.8, 0.8].into(),
+            rail_width: 4.0,
+            filled_color: [0.0, 0.4, 0.8].into(),
+            handle_radius: 8.0,
+            handle_color: Color::WHITE,
+            handle_border_width: 1.0,
+            handle_border_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`RangeSlider`](crate::native::range_slider::RangeSlider).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered handle.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a dragged handle.
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance of a focused handle.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`RangeSlider`](crate::native::range_slider::RangeSlider) is pressed, which is the
+    /// [`dragged`](StyleSheet::dragged) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the
+    /// [`RangeSlider`](crate::native::range_slider::RangeSlider) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RangeSliderStyles {
+    #[default]
+    Default,
+    Patched(Patched<RangeSliderStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = RangeSliderStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let RangeSliderStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            rail_color: palette.background.strong.color,
+            filled_color: palette.primary.base.color,
+            handle_color: palette.background.base.color,
+            handle_border_color: palette.primary.base.color,
+            handle_border_width: 2.0,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.range_slider, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            handle_color: palette.primary.weak.color,
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            handle_color: palette.primary.base.color,
+            ..active
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            handle_border_color: palette.primary.strong.color,
+            handle_border_width: active.handle_border_width + 1.0,
+            ..active
+        }
+    }
+}
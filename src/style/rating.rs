@@ -0,0 +1,96 @@
+//! Rate something with a row of icons, like stars.
+//!
+//! *This API requires the following crate features to be activated: rating*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Rating`](crate::native::rating::Rating).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the filled part of the icons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub filled_color: Color,
+
+    /// The color of the empty part of the icons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub empty_color: Color,
+
+    /// The color of the icons filled up to the hovered one, previewing the
+    /// rating.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            filled_color: [1.0, 0.76, 0.03].into(),
+            empty_color: [0.8, 0.8, 0.8].into(),
+            hovered_color: [1.0, 0.85, 0.4].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Rating`](crate::native::rating::Rating).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Rating`](crate::native::rating::Rating).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Rating`](crate::native::rating::Rating) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Rating`](crate::native::rating::Rating) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Rating`](crate::native::rating::Rating) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Rating`](crate::native::rating::Rating) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Rating`](crate::native::rating::Rating).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RatingStyles {
+    #[default]
+    Default,
+    Patched(Patched<RatingStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = RatingStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let RatingStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            empty_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.rating, appearance)
+    }
+}
@@ -0,0 +1,125 @@
+//! Resize a widget by dragging handles on its edges and corners.
+//!
+//! *This API requires the following crate features to be activated: resizable*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Resizable`](crate::native::resizable::Resizable).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the line drawn along the edges of a handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_color: Color,
+
+    /// The width of the line drawn along the edges of a handle.
+    pub handle_width: f32,
+
+    /// The color of the dotted grips in the corners with a handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub grip_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            handle_color: Color::TRANSPARENT,
+            handle_width: 2.0,
+            grip_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Resizable`](crate::native::resizable::Resizable).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`Resizable`](crate::native::resizable::Resizable).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a
+    /// [`Resizable`](crate::native::resizable::Resizable) with a hovered
+    /// handle.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a
+    /// [`Resizable`](crate::native::resizable::Resizable) being resized.
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the [`Resizable`](crate::native::resizable::Resizable)
+    /// is pressed, which is the [`dragged`](StyleSheet::dragged) appearance by
+    /// default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the [`Resizable`](crate::native::resizable::Resizable)
+    /// is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Resizable`](crate::native::resizable::Resizable)
+    /// is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`Resizable`](crate::native::resizable::Resizable).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ResizableStyles {
+    #[default]
+    Default,
+    Patched(Patched<ResizableStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ResizableStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ResizableStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            grip_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.resizable, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_color: palette.primary.weak.color,
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_color: palette.primary.base.color,
+            grip_color: palette.primary.base.color,
+            ..active
+        }
+    }
+}
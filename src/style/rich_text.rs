@@ -0,0 +1,101 @@
+//! Display text made of differently styled spans.
+//!
+//! *This API requires the following crate features to be activated: rich_text*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`RichText`](crate::native::rich_text::RichText).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the spans without a color of their own.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the links without a color of their own.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub link_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            link_color: [0.0, 0.4, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`RichText`](crate::native::rich_text::RichText).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`RichText`](crate::native::rich_text::RichText).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a [`RichText`](crate::native::rich_text::RichText)
+    /// while a link is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`RichText`](crate::native::rich_text::RichText) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`RichText`](crate::native::rich_text::RichText) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`RichText`](crate::native::rich_text::RichText) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`RichText`](crate::native::rich_text::RichText).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RichTextStyles {
+    #[default]
+    Default,
+    Patched(Patched<RichTextStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = RichTextStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let RichTextStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            text_color: palette.background.base.text,
+            link_color: palette.primary.base.color,
+        };
+
+        customize(self, |hooks| hooks.rich_text, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            link_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Seek through a media file on a timeline with buffered ranges and chapters.
+//!
+//! *This API requires the following crate features to be activated: scrubber*
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Scrubber`](crate::native::scrubber::Scrubber).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the rail that is neither played nor buffered.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub rail_color: Color,
+
+    /// The width of the rail.
+    pub rail_width: f32,
+
+    /// The color of the buffered ranges of the rail.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub buffered_color: Color,
+
+    /// The color of the rail before the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub played_color: Color,
+
+    /// The color of the markers at the start of the chapters.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub marker_color: Color,
+
+    /// The radius of the handle.
+    pub handle_radius: f32,
+
+    /// The color of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_color: Color,
+
+    /// The border width of the handle.
+    pub handle_border_width: f32,
+
+    /// The border color of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_border_color: Color,
+
+    /// The background of the bubble previewing the time.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub bubble_background: Background,
+
+    /// The text color of the bubble previewing the time.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub bubble_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            rail_color: [0.85, 0.85, 0.85].into(),
+            rail_width: 4.0,
+            buffered_color: [0.65, 0.65, 0.65].into(),
+            played_color: [0.8, 0.1, 0.1].into(),
+            marker_color: Color::WHITE,
+            handle_radius: 6.0,
+            handle_color: [0.8, 0.1, 0.1].into(),
+            handle_border_width: 0.0,
+            handle_border_color: Color::TRANSPARENT,
+            bubble_background: Color::from_rgb(0.2, 0.2, 0.2).into(),
+            bubble_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Scrubber`](crate::native::scrubber::Scrubber).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`Scrubber`](crate::native::scrubber::Scrubber).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered timeline.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a timeline whose handle is dragged.
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance of a focused timeline.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the [`Scrubber`](crate::native::scrubber::Scrubber) is
+    /// pressed, which is the [`dragged`](StyleSheet::dragged) appearance by
+    /// default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the [`Scrubber`](crate::native::scrubber::Scrubber) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`Scrubber`](crate::native::scrubber::Scrubber).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ScrubberStyles {
+    #[default]
+    Default,
+    Patched(Patched<ScrubberStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ScrubberStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ScrubberStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            rail_color: palette.background.weak.color,
+            buffered_color: palette.background.strong.color,
+            played_color: palette.primary.base.color,
+            marker_color: palette.background.base.color,
+            handle_color: palette.primary.base.color,
+            bubble_background: palette.primary.strong.color.into(),
+            bubble_text_color: palette.primary.strong.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.scrubber, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            rail_width: active.rail_width + 2.0,
+            handle_radius: active.handle_radius + 1.0,
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let hovered = self.hovered(style);
+
+        Appearance {
+            handle_color: palette.primary.strong.color,
+            ..hovered
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let hovered = self.hovered(style);
+
+        Appearance {
+            handle_border_width: 2.0,
+            handle_border_color: palette.primary.strong.text,
+            ..hovered
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Search as you type with a clear button and suggestions.
+//!
+//! *This API requires the following crate features to be activated: search_bar*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of the icons of a
+/// [`SearchBar`](crate::native::search_bar::SearchBar).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the clear button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The color of the dots of the loading indicator.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub indicator_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            icon_color: [0.5, 0.5, 0.5].into(),
+            indicator_color: [0.2, 0.4, 0.9].into(),
+        }
+    }
+}
+
+/// The appearance of the icons of a
+/// [`SearchBar`](crate::native::search_bar::SearchBar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of the icons of a
+    /// [`SearchBar`](crate::native::search_bar::SearchBar).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of the icons while the clear button is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`SearchBar`](crate::native::search_bar::SearchBar)
+    /// is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`SearchBar`](crate::native::search_bar::SearchBar)
+    /// is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`SearchBar`](crate::native::search_bar::SearchBar)
+    /// is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`SearchBar`](crate::native::search_bar::SearchBar).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SearchBarStyles {
+    #[default]
+    Default,
+    Patched(Patched<SearchBarStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = SearchBarStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let SearchBarStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            icon_color: palette.background.strong.color,
+            indicator_color: palette.primary.base.color,
+        };
+
+        customize(self, |hooks| hooks.search_bar, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.background.base.text,
+            ..active
+        }
+    }
+}
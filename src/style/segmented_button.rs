@@ -0,0 +1,156 @@
+//! Pick among options lined up in one connected pill.
+//!
+//! *This API requires the following crate features to be activated: segmented_button*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a
+/// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the pill.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the pill, at most half of its height.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the pill.
+    pub border_width: f32,
+
+    /// The border color of the pill.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the dividers between two segments.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub divider_color: Color,
+
+    /// The text color of a segment.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The background of a hovered segment.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The background of the indicator of a selected segment.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub selected_background: Background,
+
+    /// The text color of a selected segment.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selected_text_color: Color,
+
+    /// The text color of a disabled segment.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub disabled_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: f32::MAX.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            divider_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            hovered_background: Background::Color([0.93, 0.93, 0.93].into()),
+            selected_background: Background::Color([0.2, 0.4, 0.8].into()),
+            selected_text_color: Color::WHITE,
+            disabled_text_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a
+/// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`SegmentedButton`](crate::native::segmented_button::SegmentedButton).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SegmentedButtonStyles {
+    #[default]
+    Default,
+    Patched(Patched<SegmentedButtonStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = SegmentedButtonStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let SegmentedButtonStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            divider_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            hovered_background: palette.background.weak.color.into(),
+            selected_background: palette.primary.strong.color.into(),
+            selected_text_color: palette.primary.strong.text,
+            disabled_text_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.segmented_button, appearance)
+    }
+}
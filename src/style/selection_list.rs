@@ -5,24 +5,44 @@
 use iced_native::{Background, Color};
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a menu.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The List Label Text Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub text_color: Color,
     /// The background
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
     /// The container Border width
     pub border_width: f32,
     /// The container Border color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
     /// The List Label Text Select Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub hovered_text_color: Color,
     /// The List Label Text Select Background Color
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub hovered_background: Background,
     /// The List Label Text Select Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub selected_text_color: Color,
     /// The List Label Text Select Background Color
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub selected_background: Background,
 }
 
@@ -45,8 +65,33 @@ impl std::default::Default for Appearance {
 pub trait StyleSheet {
     ///Style for the trait to use.
     type Style: std::default::Default + Copy;
-    /// Produces the style of a container.
-    fn style(&self, style: Self::Style) -> Appearance;
+    /// The normal appearance of a
+    /// [`SelectionList`](crate::native::selection_list::SelectionList).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`SelectionList`](crate::native::selection_list::SelectionList) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SelectionList`](crate::native::selection_list::SelectionList) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SelectionList`](crate::native::selection_list::SelectionList) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SelectionList`](crate::native::selection_list::SelectionList) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -55,15 +100,20 @@ pub trait StyleSheet {
 pub enum SelectionListStyles {
     #[default]
     Default,
+    Patched(Patched<SelectionListStyles, Appearance>),
 }
 
 impl StyleSheet for Theme {
     type Style = SelectionListStyles;
-    fn style(&self, _style: Self::Style) -> Appearance {
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let SelectionListStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
         let palette = self.extended_palette();
         let foreground = self.palette();
 
-        Appearance {
+        let appearance = Appearance {
             text_color: foreground.text,
             background: palette.background.base.color.into(),
             border_color: foreground.text,
@@ -72,6 +122,8 @@ impl StyleSheet for Theme {
             selected_text_color: palette.primary.strong.text,
             selected_background: palette.primary.strong.color.into(),
             ..Appearance::default()
-        }
+        };
+
+        customize(self, |hooks| hooks.selection_list, appearance)
     }
 }
@@ -0,0 +1,439 @@
+//! (De)serialization of the colors and backgrounds of the appearances.
+//!
+//! Colors are written as hex strings (`"#1e90ff"`, `"#1e90ff80"`) and can be
+//! read from
+//! * hex strings with or without alpha (`"#1e90ff"`, `"#1e90ff80"`, `"#19f"`),
+//! * CSS functions (`"rgb(30, 144, 255)"`, `"rgba(30, 144, 255, 0.5)"`),
+//! * the names of the [`colors`](super::colors) (`"dodgerblue"`, `"primary"`),
+//! * arrays of floats between 0 and 1 (`[0.12, 0.56, 1.0]`).
+//!
+//! A background is written as its color, a border radius as the radii of its
+//! corners or a single radius for all corners.
+//!
+//! *This API requires the following crate features to be activated: `serde`*
+
+use std::fmt;
+
+use iced_native::{renderer::BorderRadius, Background, Color};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::colors;
+
+/// Parses a [`Color`](Color) from any of the supported string formats.
+#[must_use]
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    if let Some(arguments) = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+        .and_then(|arguments| arguments.strip_suffix(')'))
+    {
+        return parse_rgba(arguments);
+    }
+
+    colors::from_name(value)
+}
+
+/// Formats a [`Color`](Color) as a hex string, omitting the alpha channel if
+/// the color is opaque.
+#[must_use]
+pub fn format_color(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Parses the digits of a hex color with 3, 4, 6 or 8 digits.
+fn parse_hex(hex: &str) -> Option<Color> {
+    // `from_str_radix` would also accept a plus sign in front of the digits
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |index: usize, width: usize| {
+        let value = u8::from_str_radix(hex.get(index * width..(index + 1) * width)?, 16).ok()?;
+        Some(if width == 1 { value * 0x11 } else { value })
+    };
+
+    let width = match hex.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let alpha = if hex.len() / width == 4 {
+        digit(3, width)?
+    } else {
+        u8::MAX
+    };
+
+    Some(Color::from_rgba8(
+        digit(0, width)?,
+        digit(1, width)?,
+        digit(2, width)?,
+        f32::from(alpha) / 255.0,
+    ))
+}
+
+/// Parses the comma separated arguments of a CSS `rgb()` or `rgba()` function.
+///
+/// The channels are integers between 0 and 255 and the alpha is a float
+/// between 0 and 1, all without a sign.
+fn parse_rgba(arguments: &str) -> Option<Color> {
+    let mut arguments = arguments.split(',').map(str::trim);
+
+    // `parse` would also accept a plus sign in front of the numbers
+    if arguments.clone().any(|argument| argument.starts_with('+')) {
+        return None;
+    }
+
+    let r = arguments.next()?.parse().ok()?;
+    let g = arguments.next()?.parse().ok()?;
+    let b = arguments.next()?.parse().ok()?;
+    let a = match arguments.next() {
+        Some(a) => a.parse().ok().filter(|a| (0.0..=1.0).contains(a))?,
+        None => 1.0,
+    };
+
+    if arguments.next().is_some() {
+        return None;
+    }
+
+    Some(Color::from_rgba8(r, g, b, a))
+}
+
+/// A [`Visitor`](Visitor) reading a [`Color`](Color) from a string or an
+/// array of floats.
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a hex color, a CSS color or an array of 3 or 4 floats")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_color(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut channel = |index| -> Result<f32, A::Error> {
+            seq.next_element::<f32>()?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))
+        };
+
+        let r = channel(0)?;
+        let g = channel(1)?;
+        let b = channel(2)?;
+        let a = seq.next_element::<f32>()?.unwrap_or(1.0);
+
+        Ok(Color::from_rgba(r, g, b, a))
+    }
+}
+
+/// Deserializes a [`Color`](Color) in any of the supported formats.
+fn deserialize_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    deserializer.deserialize_any(ColorVisitor)
+}
+
+/// (De)serializes a [`Color`](Color), for `#[serde(with = "...")]`.
+pub mod color {
+    use super::{Color, Deserializer, Serializer};
+
+    /// Serializes a [`Color`](Color) as a hex string.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::format_color(*color))
+    }
+
+    /// Deserializes a [`Color`](Color) in any of the supported formats.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a supported color.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        super::deserialize_color(deserializer)
+    }
+}
+
+/// (De)serializes an optional [`Color`](Color), for `#[serde(with = "...")]`.
+pub mod option_color {
+    use super::{Color, Deserialize, Deserializer, Serializer};
+
+    /// Serializes an optional [`Color`](Color) as a hex string or none.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match color {
+            Some(color) => serializer.serialize_some(&super::format_color(*color)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional [`Color`](Color) in any of the supported
+    /// formats.
+    ///
+    /// # Errors
+    /// Returns an error if the value is neither none nor a supported color.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color>, D::Error> {
+        /// A [`Color`](Color) deserialized in any of the supported formats.
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(deserialize_with = "super::deserialize_color")] Color);
+
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(color)| color))
+    }
+}
+
+/// (De)serializes a [`Background`](Background) as its color, for
+/// `#[serde(with = "...")]`.
+pub mod background {
+    use super::{Background, Deserializer, Serializer};
+
+    /// Serializes a [`Background`](Background) as the hex string of its color.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S: Serializer>(
+        background: &Background,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match background {
+            Background::Color(color) => super::color::serialize(color, serializer),
+        }
+    }
+
+    /// Deserializes a [`Background`](Background) from a color in any of the
+    /// supported formats.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a supported color.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Background, D::Error> {
+        super::deserialize_color(deserializer).map(Background::Color)
+    }
+}
+
+/// (De)serializes an optional [`Background`](Background) as its color, for
+/// `#[serde(with = "...")]`.
+pub mod option_background {
+    use super::{Background, Deserializer, Serializer};
+
+    /// Serializes an optional [`Background`](Background) as the hex string of
+    /// its color or none.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S: Serializer>(
+        background: &Option<Background>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let color = background.map(|Background::Color(color)| color);
+        super::option_color::serialize(&color, serializer)
+    }
+
+    /// Deserializes an optional [`Background`](Background) from a color in
+    /// any of the supported formats.
+    ///
+    /// # Errors
+    /// Returns an error if the value is neither none nor a supported color.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Background>, D::Error> {
+        Ok(super::option_color::deserialize(deserializer)?.map(Background::Color))
+    }
+}
+
+/// A border radius, either the same for all corners or per corner.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Radius {
+    /// The same radius for all corners.
+    All(f32),
+    /// The radii of the top left, top right, bottom right and bottom left
+    /// corner.
+    Corners([f32; 4]),
+}
+
+impl From<Radius> for BorderRadius {
+    fn from(radius: Radius) -> Self {
+        match radius {
+            Radius::All(radius) => radius.into(),
+            Radius::Corners(radii) => radii.into(),
+        }
+    }
+}
+
+/// (De)serializes a [`BorderRadius`](BorderRadius) as the radii of its
+/// corners, for `#[serde(with = "...")]`.
+///
+/// A single number is read as the radius of all corners.
+pub mod border_radius {
+    use super::{BorderRadius, Deserialize, Deserializer, Radius, Serialize, Serializer};
+
+    /// Serializes a [`BorderRadius`](BorderRadius) as the radii of the top
+    /// left, top right, bottom right and bottom left corner.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S: Serializer>(
+        border_radius: &BorderRadius,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        <[f32; 4]>::from(*border_radius).serialize(serializer)
+    }
+
+    /// Deserializes a [`BorderRadius`](BorderRadius) from a single radius or
+    /// the radii of its corners.
+    ///
+    /// # Errors
+    /// Returns an error if the value is neither a number nor an array of four
+    /// numbers.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BorderRadius, D::Error> {
+        Radius::deserialize(deserializer).map(BorderRadius::from)
+    }
+}
+
+/// (De)serializes an optional [`BorderRadius`](BorderRadius) as the radii of
+/// its corners, for `#[serde(with = "...")]`.
+pub mod option_border_radius {
+    use super::{BorderRadius, Deserialize, Deserializer, Radius, Serialize, Serializer};
+
+    /// Serializes an optional [`BorderRadius`](BorderRadius) as the radii of
+    /// its corners or none.
+    ///
+    /// # Errors
+    /// Returns the error of the serializer.
+    pub fn serialize<S: Serializer>(
+        border_radius: &Option<BorderRadius>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        border_radius.map(<[f32; 4]>::from).serialize(serializer)
+    }
+
+    /// Deserializes an optional [`BorderRadius`](BorderRadius) from a single
+    /// radius or the radii of its corners.
+    ///
+    /// # Errors
+    /// Returns an error if the value is neither none, a number nor an array of
+    /// four numbers.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<BorderRadius>, D::Error> {
+        Ok(Option::<Radius>::deserialize(deserializer)?.map(BorderRadius::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::Color;
+
+    use super::{format_color, parse_color};
+    use crate::style::colors;
+
+    #[test]
+    fn parses_hex_colors() {
+        let dodger_blue = Color::from_rgb8(0x1e, 0x90, 0xff);
+
+        assert_eq!(parse_color("#1e90ff"), Some(dodger_blue));
+        assert_eq!(parse_color("#1E90FF"), Some(dodger_blue));
+        assert_eq!(
+            parse_color("#1e90ff80"),
+            Some(Color::from_rgba8(0x1e, 0x90, 0xff, 128.0 / 255.0))
+        );
+        assert_eq!(
+            parse_color("#19f"),
+            Some(Color::from_rgb8(0x11, 0x99, 0xff))
+        );
+        assert_eq!(
+            parse_color("#19f8"),
+            Some(Color::from_rgba8(0x11, 0x99, 0xff, 136.0 / 255.0))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_hex_colors() {
+        for value in ["#", "#1e", "#1e90f", "#1e90ff8", "#1e90ff800", "#1g90ff"] {
+            assert_eq!(parse_color(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn rejects_signed_hex_colors() {
+        assert_eq!(parse_color("#+e90ff"), None);
+        assert_eq!(parse_color("#+9f"), None);
+        assert_eq!(parse_color("#1e+0ff"), None);
+    }
+
+    #[test]
+    fn parses_css_functions() {
+        assert_eq!(
+            parse_color("rgb(30, 144, 255)"),
+            Some(Color::from_rgb8(30, 144, 255))
+        );
+        assert_eq!(
+            parse_color("rgba(30,144,255,0.5)"),
+            Some(Color::from_rgba8(30, 144, 255, 0.5))
+        );
+        assert_eq!(
+            parse_color("rgba(30, 144, 255)"),
+            Some(Color::from_rgb8(30, 144, 255))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_css_functions() {
+        for value in [
+            "rgb()",
+            "rgb(30, 144)",
+            "rgb(30, 144, 255, 0.5, 1)",
+            "rgb(30, 144, 256)",
+            "rgb(-1, 144, 255)",
+            "rgb(30.5, 144, 255)",
+            "rgba(30, 144, 255, 1.5)",
+            "rgba(30, 144, 255, -0.5)",
+            "rgb(+30, 144, 255)",
+            "rgba(30, 144, 255, +0.5)",
+            "rgb(30, 144, 255",
+        ] {
+            assert_eq!(parse_color(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn parses_named_colors() {
+        assert_eq!(parse_color("dodgerblue"), Some(colors::DODGER_BLUE));
+        assert_eq!(parse_color(" Dodger Blue "), Some(colors::DODGER_BLUE));
+        assert_eq!(parse_color("primary"), Some(colors::PRIMARY));
+        assert_eq!(parse_color("not a color"), None);
+    }
+
+    #[test]
+    fn formats_colors_readable_by_the_parser() {
+        let opaque = Color::from_rgb8(0x1e, 0x90, 0xff);
+        let translucent = Color::from_rgba8(0x1e, 0x90, 0xff, 128.0 / 255.0);
+
+        assert_eq!(format_color(opaque), "#1e90ff");
+        assert_eq!(format_color(translucent), "#1e90ff80");
+        assert_eq!(parse_color(&format_color(translucent)), Some(translucent));
+    }
+}
@@ -0,0 +1,128 @@
+//! Show a keyboard shortcut as a row of keycaps.
+//!
+//! *This API requires the following crate features to be activated: shortcut_hint*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the keycaps.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the keycaps.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the keycaps.
+    pub border_width: f32,
+
+    /// The border color of the keycaps.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The height of the edge below the keycaps, making them look pressable.
+    pub depth: f32,
+
+    /// The color of the edge below the keycaps.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub depth_color: Color,
+
+    /// The color of the labels of the keys.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the separators between the keycaps.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub separator_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.97, 0.97, 0.97).into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            depth: 2.0,
+            depth_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::from_rgb(0.2, 0.2, 0.2),
+            separator_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+/// The appearance of a [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The appearance of a [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the
+    /// [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`ShortcutHint`](crate::native::shortcut_hint::ShortcutHint).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ShortcutHintStyles {
+    #[default]
+    Default,
+    Patched(Patched<ShortcutHintStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ShortcutHintStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let ShortcutHintStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            depth_color: palette.background.strong.color,
+            text_color: palette.background.weak.text,
+            separator_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.shortcut_hint, appearance)
+    }
+}
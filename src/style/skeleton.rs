@@ -0,0 +1,100 @@
+//! Show placeholder shapes while the content loads.
+//!
+//! *This API requires the following crate features to be activated: skeleton*
+use iced_native::{renderer::BorderRadius, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Skeleton`](crate::native::skeleton::Skeleton).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the shape.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub background: Color,
+
+    /// The color at the center of the shimmer running across the shape.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub highlight: Color,
+
+    /// The border radius of the lines and rectangles.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: [0.88, 0.88, 0.88].into(),
+            highlight: [0.95, 0.95, 0.95].into(),
+            border_radius: 4.0.into(),
+        }
+    }
+}
+
+/// The appearance of a [`Skeleton`](crate::native::skeleton::Skeleton).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`Skeleton`](crate::native::skeleton::Skeleton).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Skeleton`](crate::native::skeleton::Skeleton) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Skeleton`](crate::native::skeleton::Skeleton) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Skeleton`](crate::native::skeleton::Skeleton) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Skeleton`](crate::native::skeleton::Skeleton) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Skeleton`](crate::native::skeleton::Skeleton).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SkeletonStyles {
+    #[default]
+    Default,
+    Patched(Patched<SkeletonStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = SkeletonStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let SkeletonStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.weak.color,
+            highlight: palette.background.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.skeleton, appearance)
+    }
+}
@@ -17,6 +17,7 @@ pub enum SpinnerStyle {
 
 /// The appearance of a [`Spinner`](crate::native::spinner::Spinner).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {}
 
 /// A set of rules that dictate the style of a [`Spinner`](crate::native::spinner::Spinner).
@@ -24,13 +25,37 @@ pub trait StyleSheet {
     /// Style for the trait to use.
     type Style: Default;
     /// The normal appearance of a [`Spinner`](crate::native::spinner::Spinner).
-    fn appearance(&self, style: &Self::Style) -> Appearance;
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`Spinner`](crate::native::spinner::Spinner) is
+    /// hovered.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Spinner`](crate::native::spinner::Spinner) is
+    /// pressed.
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Spinner`](crate::native::spinner::Spinner) is
+    /// focused.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Spinner`](crate::native::spinner::Spinner) is
+    /// disabled.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 impl StyleSheet for Theme {
     type Style = SpinnerStyle;
 
-    fn appearance(&self, _style: &Self::Style) -> Appearance {
+    fn active(&self, _style: &Self::Style) -> Appearance {
         Appearance {}
     }
 }
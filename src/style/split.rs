@@ -5,24 +5,46 @@
 use iced_native::{Background, Color};
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize};
+
 /// The appearance of a [`Split`](crate::native::split::Split).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The optional background of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_background")
+    )]
     pub background: Option<Background>,
     /// The optional background of the first element of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_background")
+    )]
     pub first_background: Option<Background>,
     /// The optional background of the second element of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_background")
+    )]
     pub second_background: Option<Background>,
     /// The border width of the [`Split`](crate::native::split::Split).
     pub border_width: f32,
     /// The border color of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
     /// The background of the divider of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub divider_background: Background,
     /// The border width of the divider of the [`Split`](crate::native::split::Split).
     pub divider_border_width: f32,
     /// The border color of the divider of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub divider_border_color: Color,
 }
 
@@ -34,10 +56,30 @@ pub trait StyleSheet {
     fn active(&self, style: Self::Style) -> Appearance;
 
     /// The appearance when the [`Split`](crate::native::split::Split) is hovered.
-    fn hovered(&self, style: Self::Style) -> Appearance;
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 
     /// The appearance when the divider of the [`Split`](crate::native::split::Split) is dragged
-    fn dragged(&self, style: Self::Style) -> Appearance;
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the divider of the [`Split`](crate::native::split::Split) is focused
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the [`Split`](crate::native::split::Split) is pressed,
+    /// which is the [`dragged`](StyleSheet::dragged) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the [`Split`](crate::native::split::Split) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of the [`Split`](crate::native::split::Split).
@@ -47,6 +89,7 @@ pub trait StyleSheet {
 pub enum SplitStyles {
     #[default]
     Default,
+    Patched(Patched<SplitStyles, Appearance>),
 }
 
 impl std::default::Default for Appearance {
@@ -66,15 +109,21 @@ impl std::default::Default for Appearance {
 
 impl StyleSheet for Theme {
     type Style = SplitStyles;
-    fn active(&self, _style: Self::Style) -> Appearance {
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let SplitStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
         let palette = self.extended_palette();
 
-        Appearance {
+        let appearance = Appearance {
             divider_background: palette.background.base.color.into(),
             divider_border_color: palette.background.weak.color,
             border_color: palette.background.base.color,
             ..Appearance::default()
-        }
+        };
+
+        customize(self, |hooks| hooks.split, appearance)
     }
 
     fn hovered(&self, style: Self::Style) -> Appearance {
@@ -96,4 +145,14 @@ impl StyleSheet for Theme {
             ..active
         }
     }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            divider_border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
 }
@@ -0,0 +1,189 @@
+//! Edit a grid of text cells like a spreadsheet.
+//!
+//! *This API requires the following crate features to be activated: spreadsheet_grid*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a
+/// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the cells.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the grid.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the grid.
+    pub border_width: f32,
+
+    /// The border color of the grid.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the lines between the cells.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub grid_color: Color,
+
+    /// The text color of the cells.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The background of the header row and the header column.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub header_background: Background,
+
+    /// The text color of the header row and the header column.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub header_text_color: Color,
+
+    /// The background of the headers of the selected rows and columns.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub header_selected_background: Background,
+
+    /// The color covering the selected cells.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selection_color: Color,
+
+    /// The color of the border around the active cell.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub active_cell_color: Color,
+
+    /// The background of the edited cell.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub editing_background: Background,
+
+    /// The color of the text cursor in the edited cell.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub cursor_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 0.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            grid_color: [0.88, 0.88, 0.88].into(),
+            text_color: Color::BLACK,
+            header_background: Color::from_rgb(0.95, 0.95, 0.95).into(),
+            header_text_color: [0.3, 0.3, 0.3].into(),
+            header_selected_background: Color::from_rgb(0.85, 0.88, 0.95).into(),
+            selection_color: Color::from_rgba(0.2, 0.4, 0.9, 0.15),
+            active_cell_color: [0.2, 0.4, 0.9].into(),
+            editing_background: Color::WHITE.into(),
+            cursor_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a
+/// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a focused
+    /// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid).
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`SpreadsheetGrid`](crate::native::spreadsheet_grid::SpreadsheetGrid).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SpreadsheetGridStyles {
+    #[default]
+    Default,
+    Patched(Patched<SpreadsheetGridStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = SpreadsheetGridStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let SpreadsheetGridStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            grid_color: palette.background.weak.color,
+            text_color: palette.background.base.text,
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            header_selected_background: palette.primary.weak.color.into(),
+            selection_color: Color {
+                a: 0.2,
+                ..palette.primary.weak.color
+            },
+            active_cell_color: palette.primary.base.color,
+            editing_background: palette.background.base.color.into(),
+            cursor_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.spreadsheet_grid, appearance)
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Show the status of a device or a connection with a colored dot.
+//!
+//! *This API requires the following crate features to be activated: status_dot*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{colors, patch::Patched, theme::builder::customize};
+
+/// The status shown by a [`StatusDot`](crate::native::status_dot::StatusDot),
+/// selecting its color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    /// Everything works.
+    #[default]
+    Ok,
+    /// Something needs attention.
+    Warning,
+    /// Something failed.
+    Error,
+    /// The device or the connection is not available.
+    Offline,
+}
+
+/// The appearance of a [`StatusDot`](crate::native::status_dot::StatusDot).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the dot.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub color: Color,
+
+    /// The color the dot fades to while blinking.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub dimmed_color: Color,
+
+    /// The border width of the dot.
+    pub border_width: f32,
+
+    /// The border color of the dot.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the label.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            color: colors::SUCCESS,
+            dimmed_color: Color {
+                a: 0.25,
+                ..colors::SUCCESS
+            },
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            label_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`StatusDot`](crate::native::status_dot::StatusDot).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`StatusDot`](crate::native::status_dot::StatusDot) showing the given
+    /// [`Status`](Status).
+    fn active(&self, style: Self::Style, status: Status) -> Appearance;
+
+    /// The appearance when the [`StatusDot`](crate::native::status_dot::StatusDot)
+    /// is hovered.
+    fn hovered(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+
+    /// The appearance when the [`StatusDot`](crate::native::status_dot::StatusDot)
+    /// is pressed.
+    fn pressed(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+
+    /// The appearance when the [`StatusDot`](crate::native::status_dot::StatusDot)
+    /// is focused.
+    fn focused(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+
+    /// The appearance when the [`StatusDot`](crate::native::status_dot::StatusDot)
+    /// is disabled.
+    fn disabled(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+}
+
+/// The default appearance of a
+/// [`StatusDot`](crate::native::status_dot::StatusDot).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StatusDotStyles {
+    #[default]
+    Default,
+    Patched(Patched<StatusDotStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = StatusDotStyles;
+
+    fn active(&self, style: Self::Style, status: Status) -> Appearance {
+        if let StatusDotStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base, status));
+        }
+
+        let palette = self.extended_palette();
+        let color = match status {
+            Status::Ok => palette.success.base.color,
+            Status::Warning => colors::WARNING,
+            Status::Error => palette.danger.base.color,
+            Status::Offline => palette.background.strong.color,
+        };
+
+        let appearance = Appearance {
+            color,
+            dimmed_color: Color { a: 0.25, ..color },
+            label_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.status_dot, appearance)
+    }
+}
@@ -0,0 +1,200 @@
+//! Guide the user through numbered steps, like in a wizard.
+//!
+//! *This API requires the following crate features to be activated: stepper*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Stepper`](crate::native::stepper::Stepper).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the marker of a completed step.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub completed_background: Background,
+
+    /// The background of the marker of the current step.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub current_background: Background,
+
+    /// The background of the marker of an upcoming step.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub upcoming_background: Background,
+
+    /// The background of the marker of a step with an error.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub error_background: Background,
+
+    /// The color of the number or the icon in the marker of a completed, the
+    /// current or a failed step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub marker_text_color: Color,
+
+    /// The color of the number in the marker of an upcoming step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub upcoming_text_color: Color,
+
+    /// The color of the label of a step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_color: Color,
+
+    /// The color of the label of an upcoming step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub upcoming_label_color: Color,
+
+    /// The color of a connector leading to an upcoming step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub connector_color: Color,
+
+    /// The color of a connector leading to a completed or the current step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub completed_connector_color: Color,
+
+    /// The width of the connectors.
+    pub connector_width: f32,
+
+    /// The background of the back and the next button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub button_background: Background,
+
+    /// The background of a hovered button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub button_hovered_background: Background,
+
+    /// The border radius of the buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub button_border_radius: BorderRadius,
+
+    /// The text color of the buttons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub button_text_color: Color,
+
+    /// The text color of a disabled button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub button_disabled_text_color: Color,
+
+    /// The text color of the content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            completed_background: Background::Color([0.0, 0.4, 0.8].into()),
+            current_background: Background::Color([0.0, 0.4, 0.8].into()),
+            upcoming_background: Background::Color([0.87, 0.87, 0.87].into()),
+            error_background: Background::Color([0.8, 0.1, 0.1].into()),
+            marker_text_color: Color::WHITE,
+            upcoming_text_color: Color::BLACK,
+            label_color: Color::BLACK,
+            upcoming_label_color: [0.5, 0.5, 0.5].into(),
+            connector_color: [0.8, 0.8, 0.8].into(),
+            completed_connector_color: [0.0, 0.4, 0.8].into(),
+            connector_width: 2.0,
+            button_background: Background::Color([0.0, 0.4, 0.8].into()),
+            button_hovered_background: Background::Color([0.0, 0.3, 0.6].into()),
+            button_border_radius: 4.0.into(),
+            button_text_color: Color::WHITE,
+            button_disabled_text_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Stepper`](crate::native::stepper::Stepper).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Stepper`](crate::native::stepper::Stepper).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Stepper`](crate::native::stepper::Stepper) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Stepper`](crate::native::stepper::Stepper) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Stepper`](crate::native::stepper::Stepper) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Stepper`](crate::native::stepper::Stepper) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Stepper`](crate::native::stepper::Stepper).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StepperStyles {
+    #[default]
+    Default,
+    Patched(Patched<StepperStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = StepperStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let StepperStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            completed_background: palette.primary.strong.color.into(),
+            current_background: palette.primary.base.color.into(),
+            upcoming_background: palette.background.weak.color.into(),
+            error_background: palette.danger.base.color.into(),
+            marker_text_color: palette.primary.base.text,
+            upcoming_text_color: palette.background.weak.text,
+            label_color: palette.background.base.text,
+            upcoming_label_color: palette.background.strong.color,
+            connector_color: palette.background.strong.color,
+            completed_connector_color: palette.primary.base.color,
+            button_background: palette.primary.base.color.into(),
+            button_hovered_background: palette.primary.strong.color.into(),
+            button_text_color: palette.primary.base.text,
+            button_disabled_text_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.stepper, appearance)
+    }
+}
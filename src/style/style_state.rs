@@ -1,7 +1,10 @@
 //! Helper enum for the state of the style
 
 /// The state of the style
-#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+///
+/// The states are ordered by their priority, so the state of a widget is the
+/// [`max`](Ord::max) of the states it is in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum StyleState {
     /// Use the active style
     Active,
@@ -11,4 +14,61 @@ pub enum StyleState {
     Hovered,
     /// Use the focused style
     Focused,
+    /// Use the pressed style
+    Pressed,
+    /// Use the disabled style
+    Disabled,
 }
+
+impl StyleState {
+    /// Returns the state of a widget from its interactions.
+    ///
+    /// A disabled widget ignores all interactions, a pressed widget is drawn
+    /// pressed even if it is focused and a focused widget is drawn focused
+    /// even if it is hovered.
+    #[must_use]
+    pub fn new(is_hovered: bool, is_pressed: bool, is_focused: bool, is_disabled: bool) -> Self {
+        [
+            (is_hovered, Self::Hovered),
+            (is_focused, Self::Focused),
+            (is_pressed, Self::Pressed),
+            (is_disabled, Self::Disabled),
+        ]
+        .into_iter()
+        .filter_map(|(is_in, state)| is_in.then_some(state))
+        .fold(Self::Active, Self::max)
+    }
+}
+
+/// Returns the appearance of the style sheet for the [`StyleState`](StyleState)
+/// of a widget, e.g. `appearance!(StyleSheet, theme, state, self.style)`.
+///
+/// The style sheet is named by the trait in scope, since the theme usually
+/// implements the style sheets of iced with the same methods as well. The
+/// selected state falls back to the active appearance.
+macro_rules! appearance {
+    ($style_sheet:ident, $theme:expr, $state:expr, $($argument:expr),+ $(,)?) => {
+        match $state {
+            $crate::style::style_state::StyleState::Active
+            | $crate::style::style_state::StyleState::Selected => {
+                $style_sheet::active($theme, $($argument),+)
+            }
+            $crate::style::style_state::StyleState::Hovered => {
+                $style_sheet::hovered($theme, $($argument),+)
+            }
+            $crate::style::style_state::StyleState::Focused => {
+                $style_sheet::focused($theme, $($argument),+)
+            }
+            $crate::style::style_state::StyleState::Pressed => {
+                $style_sheet::pressed($theme, $($argument),+)
+            }
+            $crate::style::style_state::StyleState::Disabled => {
+                $style_sheet::disabled($theme, $($argument),+)
+            }
+        }
+    };
+}
+
+// Without the features of the widgets there is no style sheet to use it
+#[allow(unused_imports)]
+pub(crate) use appearance;
@@ -0,0 +1,142 @@
+//! Turn an option on or off with a sliding thumb.
+//!
+//! *This API requires the following crate features to be activated: switch*
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Switch`](crate::native::switch::Switch).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The color of the track.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub track_color: Color,
+
+    /// The border width of the track.
+    pub track_border_width: f32,
+
+    /// The border color of the track.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub track_border_color: Color,
+
+    /// The color of the thumb.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub thumb_color: Color,
+
+    /// The color of the labels and icons inside the track.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            track_color: [0.7, 0.7, 0.7].into(),
+            track_border_width: 0.0,
+            track_border_color: Color::TRANSPARENT,
+            thumb_color: Color::WHITE,
+            label_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Switch`](crate::native::switch::Switch).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Switch`](crate::native::switch::Switch)
+    /// that is on or off.
+    fn active(&self, style: Self::Style, is_on: bool) -> Appearance;
+
+    /// The appearance of a hovered [`Switch`](crate::native::switch::Switch).
+    fn hovered(&self, style: Self::Style, is_on: bool) -> Appearance {
+        self.active(style, is_on)
+    }
+
+    /// The appearance of a disabled
+    /// [`Switch`](crate::native::switch::Switch).
+    fn disabled(&self, style: Self::Style, is_on: bool) -> Appearance {
+        self.active(style, is_on)
+    }
+
+    /// The appearance when the [`Switch`](crate::native::switch::Switch) is
+    /// pressed, which is while its thumb is dragged. This is the
+    /// [`hovered`](StyleSheet::hovered) appearance by default.
+    fn pressed(&self, style: Self::Style, is_on: bool) -> Appearance {
+        self.hovered(style, is_on)
+    }
+
+    /// The appearance when the [`Switch`](crate::native::switch::Switch) is
+    /// focused.
+    fn focused(&self, style: Self::Style, is_on: bool) -> Appearance {
+        self.active(style, is_on)
+    }
+}
+
+/// The default appearance of a [`Switch`](crate::native::switch::Switch).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SwitchStyles {
+    #[default]
+    Default,
+    Patched(Patched<SwitchStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = SwitchStyles;
+
+    fn active(&self, style: Self::Style, is_on: bool) -> Appearance {
+        if let SwitchStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base, is_on));
+        }
+
+        let palette = self.extended_palette();
+        let track = if is_on {
+            palette.primary.base
+        } else {
+            palette.background.strong
+        };
+
+        let appearance = Appearance {
+            track_color: track.color,
+            thumb_color: palette.background.base.color,
+            label_color: track.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.switch, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style, is_on: bool) -> Appearance {
+        let active = self.active(style, is_on);
+        let palette = self.extended_palette();
+
+        Appearance {
+            track_color: if is_on {
+                palette.primary.strong.color
+            } else {
+                palette.background.weak.color
+            },
+            ..active
+        }
+    }
+
+    fn disabled(&self, style: Self::Style, is_on: bool) -> Appearance {
+        let active = self.active(style, is_on);
+        let fade = |color: Color| Color {
+            a: color.a * 0.5,
+            ..color
+        };
+
+        Appearance {
+            track_color: fade(active.track_color),
+            track_border_color: fade(active.track_border_color),
+            thumb_color: fade(active.thumb_color),
+            label_color: fade(active.label_color),
+            ..active
+        }
+    }
+}
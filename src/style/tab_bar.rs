@@ -6,34 +6,73 @@
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
-use iced_native::{Background, Color};
+use iced_native::{renderer::BorderRadius, Background, Color};
 use iced_style::Theme;
 
+use super::{patch::Patched, theme::builder::customize, Gradient};
+
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Appearance {
     /// The background of the tab bar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_background")
+    )]
     pub background: Option<Background>,
 
     /// The border color of the tab bar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::option_color")
+    )]
     pub border_color: Option<Color>,
 
     /// The border width of the tab bar.
     pub border_width: f32,
 
+    /// The border radius of the tab bar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
     /// The background of the tab labels.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub tab_label_background: Background,
 
+    /// The optional [`Gradient`](Gradient) of the tab labels. If specified,
+    /// it fills the tab labels instead of their background.
+    pub tab_label_gradient: Option<Gradient>,
+
     /// The border color of the tab labels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub tab_label_border_color: Color,
 
     /// The border with of the tab labels.
     pub tab_label_border_width: f32,
 
+    /// The border radius of the tab labels.
+    ///
+    /// Rounding only the top corners attaches the tabs to the content below.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub tab_label_border_radius: BorderRadius,
+
     /// The icon color of the tab labels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub icon_color: Color,
 
     /// The text color of the tab labels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub text_color: Color,
 }
 
@@ -50,7 +89,26 @@ pub trait StyleSheet {
     /// The appearance when the tab bar and/or a tab label is hovered.
     ///
     /// `is_active` is true if the tab is selected.
-    fn hovered(&self, style: Self::Style, is_active: bool) -> Appearance;
+    fn hovered(&self, style: Self::Style, is_active: bool) -> Appearance {
+        self.active(style, is_active)
+    }
+
+    /// The appearance of the selected tab label when the tab bar is focused.
+    ///
+    /// `is_active` is true if the tab is selected.
+    fn focused(&self, style: Self::Style, is_active: bool) -> Appearance {
+        self.hovered(style, is_active)
+    }
+
+    /// The appearance when the tab label is pressed.
+    fn pressed(&self, style: Self::Style, is_active: bool) -> Appearance {
+        self.active(style, is_active)
+    }
+
+    /// The appearance when the tab label is disabled.
+    fn disabled(&self, style: Self::Style, is_active: bool) -> Appearance {
+        self.active(style, is_active)
+    }
 }
 
 impl Default for Appearance {
@@ -59,9 +117,12 @@ impl Default for Appearance {
             background: None,
             border_color: None,
             border_width: 0.0,
+            border_radius: 0.0.into(),
             tab_label_background: Background::Color([0.87, 0.87, 0.87].into()),
+            tab_label_gradient: None,
             tab_label_border_color: [0.7, 0.7, 0.7].into(),
             tab_label_border_width: 1.0,
+            tab_label_border_radius: 0.0.into(),
             icon_color: Color::BLACK,
             text_color: Color::BLACK,
         }
@@ -79,11 +140,13 @@ pub enum TabBarStyles {
     Blue,
     Green,
     Purple,
+    Patched(Patched<TabBarStyles, Appearance>),
 }
 
 impl From<TabBarStyles> for String {
     fn from(style: TabBarStyles) -> Self {
         Self::from(match style {
+            TabBarStyles::Patched(patched) => return Self::from(*patched.base),
             TabBarStyles::Default => "Default",
             TabBarStyles::Dark => "Dark",
             TabBarStyles::Red => "Red",
@@ -99,14 +162,21 @@ impl StyleSheet for Theme {
 
     fn active(&self, style: Self::Style, is_active: bool) -> Appearance {
         let mut appearance = Appearance::default();
+        let palette = self.extended_palette();
 
         match style {
+            TabBarStyles::Patched(patched) => {
+                return patched.apply(self.active(*patched.base, is_active));
+            }
             TabBarStyles::Default => {
                 appearance.tab_label_background = if is_active {
-                    Background::Color([0.9, 0.9, 0.9].into())
+                    Background::Color(palette.background.weak.color)
                 } else {
-                    Background::Color([0.87, 0.87, 0.87].into())
+                    Background::Color(palette.background.base.color)
                 };
+                appearance.tab_label_border_color = palette.background.strong.color;
+                appearance.icon_color = palette.background.base.text;
+                appearance.text_color = palette.background.base.text;
             }
             TabBarStyles::Dark => {
                 appearance.tab_label_background = if is_active {
@@ -177,13 +247,16 @@ impl StyleSheet for Theme {
             }
         }
 
-        appearance
+        customize(self, |hooks| hooks.tab_bar, appearance)
     }
 
     fn hovered(&self, style: Self::Style, is_active: bool) -> Appearance {
+        let palette = self.extended_palette();
+
         match style {
+            TabBarStyles::Patched(patched) => patched.apply(self.hovered(*patched.base, is_active)),
             TabBarStyles::Default => Appearance {
-                tab_label_background: Background::Color([0.9, 0.9, 0.9].into()),
+                tab_label_background: Background::Color(palette.background.weak.color),
                 ..self.active(style, is_active)
             },
             TabBarStyles::Dark => Appearance {
@@ -217,4 +290,19 @@ impl StyleSheet for Theme {
             }
         }
     }
+
+    fn focused(&self, style: Self::Style, is_active: bool) -> Appearance {
+        if let TabBarStyles::Patched(patched) = style {
+            return patched.apply(self.focused(*patched.base, is_active));
+        }
+
+        let palette = self.extended_palette();
+        let active = self.active(style, is_active);
+
+        Appearance {
+            tab_label_border_color: palette.primary.strong.color,
+            tab_label_border_width: active.tab_label_border_width.max(1.0),
+            ..active
+        }
+    }
 }
@@ -0,0 +1,176 @@
+//! Display rows of data in columns with a header.
+//!
+//! *This API requires the following crate features to be activated: table*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{
+    patch::Patched,
+    theme::builder::{customize, mix},
+};
+
+/// The appearance of a [`Table`](crate::native::table::Table).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the [`Table`](crate::native::table::Table).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the [`Table`](crate::native::table::Table).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the [`Table`](crate::native::table::Table).
+    pub border_width: f32,
+
+    /// The border color of the [`Table`](crate::native::table::Table).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The background of the header row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub header_background: Background,
+
+    /// The text color of the header row.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub header_text_color: Color,
+
+    /// The color of the line between the header and the rows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub divider_color: Color,
+
+    /// The text color of the rows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The background of every second row, if the rows are striped.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub stripe_background: Background,
+
+    /// The background of the hovered row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The text color of the hovered row.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub hovered_text_color: Color,
+
+    /// The background of the selected row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub selected_background: Background,
+
+    /// The text color of the selected row.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selected_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 0.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            header_background: Background::Color([0.87, 0.87, 0.87].into()),
+            header_text_color: Color::BLACK,
+            divider_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            stripe_background: Background::Color([0.96, 0.96, 0.96].into()),
+            hovered_background: Background::Color([0.9, 0.95, 1.0].into()),
+            hovered_text_color: Color::BLACK,
+            selected_background: Background::Color([0.2, 0.5, 0.8].into()),
+            selected_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Table`](crate::native::table::Table).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Table`](crate::native::table::Table).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Table`](crate::native::table::Table) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Table`](crate::native::table::Table) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Table`](crate::native::table::Table) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Table`](crate::native::table::Table) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Table`](crate::native::table::Table).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TableStyles {
+    #[default]
+    Default,
+    Patched(Patched<TableStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = TableStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let TableStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            divider_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            stripe_background: mix(
+                palette.background.base.color,
+                palette.background.weak.color,
+                0.5,
+            )
+            .into(),
+            hovered_background: palette.primary.weak.color.into(),
+            hovered_text_color: palette.primary.weak.text,
+            selected_background: palette.primary.strong.color.into(),
+            selected_text_color: palette.primary.strong.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.table, appearance)
+    }
+}
@@ -0,0 +1,145 @@
+//! Type tags that turn into removable chips.
+//!
+//! *This API requires the following crate features to be activated: tag_input*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of the chips of a
+/// [`TagInput`](crate::native::tag_input::TagInput).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of a chip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of a chip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of a chip.
+    pub border_width: f32,
+
+    /// The border color of a chip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of a chip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the remove icon of a chip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub icon_color: Color,
+
+    /// The background of a chip whose tag is not valid.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub invalid_background: Background,
+
+    /// The text color of a chip whose tag is not valid.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub invalid_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 12.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::BLACK,
+            icon_color: [0.3, 0.3, 0.3].into(),
+            invalid_background: Background::Color([0.95, 0.8, 0.8].into()),
+            invalid_text_color: [0.6, 0.1, 0.1].into(),
+        }
+    }
+}
+
+/// The appearance of the chips of a
+/// [`TagInput`](crate::native::tag_input::TagInput).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of the chips of a
+    /// [`TagInput`](crate::native::tag_input::TagInput).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a chip whose remove icon is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`TagInput`](crate::native::tag_input::TagInput) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`TagInput`](crate::native::tag_input::TagInput) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`TagInput`](crate::native::tag_input::TagInput) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`TagInput`](crate::native::tag_input::TagInput).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TagInputStyles {
+    #[default]
+    Default,
+    Patched(Patched<TagInputStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = TagInputStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let TagInputStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.weak.color.into(),
+            text_color: palette.background.weak.text,
+            icon_color: palette.background.strong.color,
+            invalid_background: palette.danger.weak.color.into(),
+            invalid_text_color: palette.danger.weak.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.tag_input, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.danger.base.color,
+            ..active
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! A theme styling all widgets of this crate from a single palette.
+use iced_style::theme::Palette;
+
+pub mod builder;
+pub use builder::{Hook, Hooks, ThemeBuilder};
+
+pub mod transition;
+pub use transition::Transition;
+
+/// A theme styling all widgets of this crate from a single [`Palette`](Palette).
+///
+/// The [`Theme`](Theme) does not implement the style sheets itself. It
+/// converts into an [`iced_style::Theme`] with a custom palette, which is the
+/// theme the widgets of this crate are styled by, so the widgets of iced are
+/// styled by the same palette. Every default style derives its colors from the
+/// palette, so one [`Theme`](Theme) styles all widgets consistently. The
+/// prebuilt color styles, like `BadgeStyles::Primary`, keep the fixed colors
+/// of the [`colors`](super::colors) module.
+///
+/// # Example
+/// ```
+/// # use iced_aw::style::Theme;
+/// # use iced_native::Color;
+/// #
+/// let theme: iced_style::Theme = Theme {
+///     palette: iced_style::theme::Palette {
+///         primary: Color::from_rgb8(0xE6, 0x7E, 0x22),
+///         ..Theme::DARK.palette
+///     },
+/// }
+/// .into();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    /// The palette the widgets derive their colors from.
+    pub palette: Palette,
+}
+
+impl Theme {
+    /// A [`Theme`](Theme) with the light palette of iced.
+    pub const LIGHT: Self = Self {
+        palette: Palette::LIGHT,
+    };
+
+    /// A [`Theme`](Theme) with the dark palette of iced.
+    pub const DARK: Self = Self {
+        palette: Palette::DARK,
+    };
+
+    /// Creates a new [`Theme`](Theme) from the given [`Palette`](Palette).
+    #[must_use]
+    pub const fn new(palette: Palette) -> Self {
+        Self { palette }
+    }
+
+    /// Interpolates the colors of the palette between this and another
+    /// [`Theme`](Theme) by the given factor, from 0 (this theme) to 1 (the
+    /// other theme).
+    #[must_use]
+    pub fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        let factor = factor.clamp(0.0, 1.0);
+        let mix = |from, to| builder::mix(from, to, factor);
+
+        Self {
+            palette: Palette {
+                background: mix(self.palette.background, other.palette.background),
+                text: mix(self.palette.text, other.palette.text),
+                primary: mix(self.palette.primary, other.palette.primary),
+                success: mix(self.palette.success, other.palette.success),
+                danger: mix(self.palette.danger, other.palette.danger),
+            },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::LIGHT
+    }
+}
+
+impl From<Theme> for iced_style::Theme {
+    fn from(theme: Theme) -> Self {
+        Self::custom(theme.palette)
+    }
+}
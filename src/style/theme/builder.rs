@@ -0,0 +1,476 @@
+//! Build a theme for all widgets from a primary and a background color.
+use std::sync::RwLock;
+
+use iced_native::Color;
+use iced_style::theme::Palette;
+
+/// The hooks registered by [`ThemeBuilder::build`](ThemeBuilder::build) for
+/// the palettes of the built themes.
+static HOOKS: RwLock<Vec<(Palette, Hooks)>> = RwLock::new(Vec::new());
+
+/// The minimum contrast ratio between the primary and the background color.
+const MIN_PRIMARY_CONTRAST: f32 = 3.0;
+
+/// An override hook of the appearance of a widget.
+pub type Hook<Appearance> = fn(&Palette, Appearance) -> Appearance;
+
+/// The override hooks of the widgets of a theme built by a
+/// [`ThemeBuilder`](ThemeBuilder).
+///
+/// A hook receives the palette of the theme and the derived normal appearance
+/// of its widget and returns the appearance to use instead. The appearances of
+/// the other states, like hovered or focused, are derived from the overridden
+/// normal appearance.
+#[derive(Clone, Copy, Default)]
+#[allow(missing_debug_implementations)]
+pub struct Hooks {
+    /// The override hook of the accordions.
+    #[cfg(feature = "accordion")]
+    pub accordion: Option<Hook<crate::style::accordion::Appearance>>,
+
+    /// The override hook of the avatars.
+    #[cfg(feature = "avatar")]
+    pub avatar: Option<Hook<crate::style::avatar::Appearance>>,
+
+    /// The override hook of the badges.
+    #[cfg(feature = "badge")]
+    pub badge: Option<Hook<crate::style::badge::Appearance>>,
+
+    /// The override hook of the breadcrumbs.
+    #[cfg(feature = "breadcrumbs")]
+    pub breadcrumbs: Option<Hook<crate::style::breadcrumbs::Appearance>>,
+
+    /// The override hook of the calendar heatmaps.
+    #[cfg(feature = "calendar_heatmap")]
+    pub calendar_heatmap: Option<Hook<crate::style::calendar_heatmap::Appearance>>,
+
+    /// The override hook of the cards.
+    #[cfg(feature = "card")]
+    pub card: Option<Hook<crate::style::card::Appearance>>,
+
+    /// The override hook of the carousels.
+    #[cfg(feature = "carousel")]
+    pub carousel: Option<Hook<crate::style::carousel::Appearance>>,
+
+    /// The override hook of the charts.
+    #[cfg(feature = "chart")]
+    pub chart: Option<Hook<crate::style::chart::Appearance>>,
+
+    /// The override hook of the chips.
+    #[cfg(feature = "chip")]
+    pub chip: Option<Hook<crate::style::chip::Appearance>>,
+
+    /// The override hook of the circular progress indicators.
+    #[cfg(feature = "circular_progress")]
+    pub circular_progress: Option<Hook<crate::style::circular_progress::Appearance>>,
+
+    /// The override hook of the code editors.
+    #[cfg(feature = "code_editor")]
+    pub code_editor: Option<Hook<crate::style::code_editor::Appearance>>,
+
+    /// The override hook of the color pickers.
+    #[cfg(feature = "color_picker")]
+    pub color_picker: Option<Hook<crate::style::color_picker::Appearance>>,
+
+    /// The override hook of the combo boxes.
+    #[cfg(feature = "combo_box")]
+    pub combo_box: Option<Hook<crate::style::combo_box::Appearance>>,
+
+    /// The override hook of the context menus.
+    #[cfg(feature = "context_menu")]
+    pub context_menu: Option<Hook<crate::style::context_menu::Appearance>>,
+
+    /// The override hook of the date pickers.
+    #[cfg(feature = "date_picker")]
+    pub date_picker: Option<Hook<crate::style::date_picker::Appearance>>,
+
+    /// The override hook of the drop targets and drag ghosts.
+    #[cfg(feature = "drag_and_drop")]
+    pub drag_and_drop: Option<Hook<crate::style::drag_and_drop::Appearance>>,
+
+    /// The override hook of the drawing pads.
+    #[cfg(feature = "drawing_pad")]
+    pub drawing_pad: Option<Hook<crate::style::drawing_pad::Appearance>>,
+
+    /// The override hook of the drop zones.
+    #[cfg(feature = "drop_zone")]
+    pub drop_zone: Option<Hook<crate::style::drop_zone::Appearance>>,
+
+    /// The override hook of the galleries.
+    #[cfg(feature = "gallery")]
+    pub gallery: Option<Hook<crate::style::gallery::Appearance>>,
+
+    /// The override hook of the gauges.
+    #[cfg(feature = "gauge")]
+    pub gauge: Option<Hook<crate::style::gauge::Appearance>>,
+
+    /// The override hook of the heatmaps.
+    #[cfg(feature = "heatmap")]
+    pub heatmap: Option<Hook<crate::style::heatmap::Appearance>>,
+
+    /// The override hook of the hotkey inputs.
+    #[cfg(feature = "hotkey_input")]
+    pub hotkey_input: Option<Hook<crate::style::hotkey_input::Appearance>>,
+
+    /// The override hook of the hover cards.
+    #[cfg(feature = "hover_card")]
+    pub hover_card: Option<Hook<crate::style::hover_card::Appearance>>,
+
+    /// The override hook of the knobs.
+    #[cfg(feature = "knob")]
+    pub knob: Option<Hook<crate::style::knob::Appearance>>,
+
+    /// The override hook of the loading overlays.
+    #[cfg(feature = "loading_overlay")]
+    pub loading_overlay: Option<Hook<crate::style::loading_overlay::Appearance>>,
+
+    /// The override hook of the menu bars.
+    #[cfg(feature = "menu")]
+    pub menu_bar: Option<Hook<crate::style::menu_bar::Appearance>>,
+
+    /// The override hook of the menu buttons.
+    #[cfg(feature = "menu_button")]
+    pub menu_button: Option<Hook<crate::style::menu_button::Appearance>>,
+
+    /// The override hook of the minimaps.
+    #[cfg(feature = "minimap")]
+    pub minimap: Option<Hook<crate::style::minimap::Appearance>>,
+
+    /// The override hook of the modals.
+    #[cfg(feature = "modal")]
+    pub modal: Option<Hook<crate::style::modal::Appearance>>,
+
+    /// The override hook of the month views.
+    #[cfg(feature = "month_view")]
+    pub month_view: Option<Hook<crate::style::month_view::Appearance>>,
+
+    /// The override hook of the multi selects.
+    #[cfg(feature = "multi_select")]
+    pub multi_select: Option<Hook<crate::style::multi_select::Appearance>>,
+
+    /// The override hook of the number inputs.
+    #[cfg(feature = "number_input")]
+    pub number_input: Option<Hook<crate::style::number_input::Appearance>>,
+
+    /// The override hook of the paginations.
+    #[cfg(feature = "pagination")]
+    pub pagination: Option<Hook<crate::style::pagination::Appearance>>,
+
+    /// The override hook of the PIN inputs.
+    #[cfg(feature = "pin_input")]
+    pub pin_input: Option<Hook<crate::style::pin_input::Appearance>>,
+
+    /// The override hook of the popovers.
+    #[cfg(feature = "popover")]
+    pub popover: Option<Hook<crate::style::popover::Appearance>>,
+
+    /// The override hook of the property grids.
+    #[cfg(feature = "property_grid")]
+    pub property_grid: Option<Hook<crate::style::property_grid::Appearance>>,
+
+    /// The override hook of the QR codes.
+    #[cfg(feature = "qr_code")]
+    pub qr_code: Option<Hook<crate::style::qr_code::Appearance>>,
+
+    /// The override hook of the radial menus.
+    #[cfg(feature = "radial_menu")]
+    pub radial_menu: Option<Hook<crate::style::radial_menu::Appearance>>,
+
+    /// The override hook of the range sliders.
+    #[cfg(feature = "range_slider")]
+    pub range_slider: Option<Hook<crate::style::range_slider::Appearance>>,
+
+    /// The override hook of the ratings.
+    #[cfg(feature = "rating")]
+    pub rating: Option<Hook<crate::style::rating::Appearance>>,
+
+    /// The override hook of the resizables.
+    #[cfg(feature = "resizable")]
+    pub resizable: Option<Hook<crate::style::resizable::Appearance>>,
+
+    /// The override hook of the rich texts.
+    #[cfg(feature = "rich_text")]
+    pub rich_text: Option<Hook<crate::style::rich_text::Appearance>>,
+
+    /// The override hook of the scrubbers.
+    #[cfg(feature = "scrubber")]
+    pub scrubber: Option<Hook<crate::style::scrubber::Appearance>>,
+
+    /// The override hook of the search bars.
+    #[cfg(feature = "search_bar")]
+    pub search_bar: Option<Hook<crate::style::search_bar::Appearance>>,
+
+    /// The override hook of the segmented buttons.
+    #[cfg(feature = "segmented_button")]
+    pub segmented_button: Option<Hook<crate::style::segmented_button::Appearance>>,
+
+    /// The override hook of the selection lists.
+    #[cfg(feature = "selection_list")]
+    pub selection_list: Option<Hook<crate::style::selection_list::Appearance>>,
+
+    /// The override hook of the shortcut hints.
+    #[cfg(feature = "shortcut_hint")]
+    pub shortcut_hint: Option<Hook<crate::style::shortcut_hint::Appearance>>,
+
+    /// The override hook of the skeletons.
+    #[cfg(feature = "skeleton")]
+    pub skeleton: Option<Hook<crate::style::skeleton::Appearance>>,
+
+    /// The override hook of the splits.
+    #[cfg(feature = "split")]
+    pub split: Option<Hook<crate::style::split::Appearance>>,
+
+    /// The override hook of the spreadsheet grids.
+    #[cfg(feature = "spreadsheet_grid")]
+    pub spreadsheet_grid: Option<Hook<crate::style::spreadsheet_grid::Appearance>>,
+
+    /// The override hook of the status dots.
+    #[cfg(feature = "status_dot")]
+    pub status_dot: Option<Hook<crate::style::status_dot::Appearance>>,
+
+    /// The override hook of the steppers.
+    #[cfg(feature = "stepper")]
+    pub stepper: Option<Hook<crate::style::stepper::Appearance>>,
+
+    /// The override hook of the switches.
+    #[cfg(feature = "switch")]
+    pub switch: Option<Hook<crate::style::switch::Appearance>>,
+
+    /// The override hook of the tables.
+    #[cfg(feature = "table")]
+    pub table: Option<Hook<crate::style::table::Appearance>>,
+
+    /// The override hook of the tab bars.
+    #[cfg(feature = "tab_bar")]
+    pub tab_bar: Option<Hook<crate::style::tab_bar::Appearance>>,
+
+    /// The override hook of the tag inputs.
+    #[cfg(feature = "tag_input")]
+    pub tag_input: Option<Hook<crate::style::tag_input::Appearance>>,
+
+    /// The override hook of the tick sliders.
+    #[cfg(feature = "tick_slider")]
+    pub tick_slider: Option<Hook<crate::style::tick_slider::Appearance>>,
+
+    /// The override hook of the time pickers.
+    #[cfg(feature = "time_picker")]
+    pub time_picker: Option<Hook<crate::style::time_picker::Appearance>>,
+
+    /// The override hook of the toasts.
+    #[cfg(feature = "toast")]
+    pub toast: Option<Hook<crate::style::toast::Appearance>>,
+
+    /// The override hook of the tooltips.
+    #[cfg(feature = "tooltip")]
+    pub tooltip: Option<Hook<crate::style::tooltip::Appearance>>,
+
+    /// The override hook of the tours.
+    #[cfg(feature = "tour")]
+    pub tour: Option<Hook<crate::style::tour::Appearance>>,
+
+    /// The override hook of the transfer lists.
+    #[cfg(feature = "transfer_list")]
+    pub transfer_list: Option<Hook<crate::style::transfer_list::Appearance>>,
+
+    /// The override hook of the virtual lists.
+    #[cfg(feature = "virtual_list")]
+    pub virtual_list: Option<Hook<crate::style::virtual_list::Appearance>>,
+
+    /// The override hook of the XY pads.
+    #[cfg(feature = "xy_pad")]
+    pub xy_pad: Option<Hook<crate::style::xy_pad::Appearance>>,
+}
+
+/// Builds a theme for all widgets of this crate from a primary and a
+/// background color.
+///
+/// The remaining colors of the palette are derived from the background: the
+/// text color is the one contrasting the most with it, and the success and
+/// danger colors are taken from the light or dark palette of iced. The primary
+/// color is adjusted until it contrasts enough with the background. The hover
+/// shades, borders and disabled tints of the widgets are derived from this
+/// palette, and can be overridden per widget by [`Hooks`](Hooks).
+///
+/// # Example
+/// ```
+/// # use iced_aw::style::theme::ThemeBuilder;
+/// # use iced_native::Color;
+/// #
+/// let theme: iced_style::Theme = ThemeBuilder::new(
+///     Color::from_rgb8(0xE6, 0x7E, 0x22),
+///     Color::from_rgb8(0x20, 0x22, 0x25),
+/// )
+/// .build();
+/// ```
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct ThemeBuilder {
+    /// The primary color.
+    primary: Color,
+    /// The background color.
+    background: Color,
+    /// The optional text color overriding the derived one.
+    text: Option<Color>,
+    /// The optional success color overriding the derived one.
+    success: Option<Color>,
+    /// The optional danger color overriding the derived one.
+    danger: Option<Color>,
+    /// The override hooks of the widgets.
+    hooks: Hooks,
+}
+
+impl ThemeBuilder {
+    /// Creates a new [`ThemeBuilder`](ThemeBuilder) with the given primary and
+    /// background color.
+    #[must_use]
+    pub fn new(primary: Color, background: Color) -> Self {
+        Self {
+            primary,
+            background,
+            text: None,
+            success: None,
+            danger: None,
+            hooks: Hooks::default(),
+        }
+    }
+
+    /// Sets the text color instead of deriving it from the background.
+    #[must_use]
+    pub fn text(mut self, text: Color) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Sets the success color instead of deriving it from the background.
+    #[must_use]
+    pub fn success(mut self, success: Color) -> Self {
+        self.success = Some(success);
+        self
+    }
+
+    /// Sets the danger color instead of deriving it from the background.
+    #[must_use]
+    pub fn danger(mut self, danger: Color) -> Self {
+        self.danger = Some(danger);
+        self
+    }
+
+    /// Sets the override [`Hooks`](Hooks) of the widgets.
+    ///
+    /// ```
+    /// # use iced_aw::style::theme::{Hooks, ThemeBuilder};
+    /// # use iced_native::Color;
+    /// #
+    /// let theme = ThemeBuilder::new(Color::from_rgb(0.9, 0.5, 0.1), Color::WHITE)
+    ///     .hooks(Hooks {
+    ///         card: Some(|_palette, appearance| iced_aw::style::card::Appearance {
+    ///             border_radius: 0.0.into(),
+    ///             ..appearance
+    ///         }),
+    ///         ..Hooks::default()
+    ///     })
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn hooks(mut self, hooks: Hooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Derives the [`Palette`](Palette) of the theme.
+    #[must_use]
+    pub fn palette(&self) -> Palette {
+        let is_dark =
+            contrast(Color::WHITE, self.background) > contrast(Color::BLACK, self.background);
+        let base = if is_dark {
+            Palette::DARK
+        } else {
+            Palette::LIGHT
+        };
+
+        let text = self.text.unwrap_or(base.text);
+
+        let mut primary = self.primary;
+        for _ in 0..10 {
+            if contrast(primary, self.background) >= MIN_PRIMARY_CONTRAST {
+                break;
+            }
+            primary = mix(primary, text, 0.1);
+        }
+
+        Palette {
+            background: self.background,
+            text,
+            primary,
+            success: self.success.unwrap_or(base.success),
+            danger: self.danger.unwrap_or(base.danger),
+        }
+    }
+
+    /// Builds the theme and registers the override hooks for its palette.
+    #[must_use]
+    pub fn build(self) -> iced_style::Theme {
+        let palette = self.palette();
+
+        let mut hooks = match HOOKS.write() {
+            Ok(hooks) => hooks,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        hooks.retain(|(registered, _)| *registered != palette);
+        hooks.push((palette, self.hooks));
+
+        iced_style::Theme::custom(palette)
+    }
+}
+
+/// Applies the override hook selected by `hook` of the theme to the given
+/// appearance, if the theme was built by a [`ThemeBuilder`](ThemeBuilder)
+/// with such a hook.
+pub(crate) fn customize<A>(
+    theme: &iced_style::Theme,
+    hook: impl Fn(&Hooks) -> Option<Hook<A>>,
+    appearance: A,
+) -> A {
+    let palette = theme.palette();
+    let hook = HOOKS.read().ok().and_then(|hooks| {
+        hooks
+            .iter()
+            .find(|(registered, _)| *registered == palette)
+            .and_then(|(_, hooks)| hook(hooks))
+    });
+
+    match hook {
+        Some(hook) => hook(&palette, appearance),
+        None => appearance,
+    }
+}
+
+/// Returns the relative luminance of the color.
+fn luminance(color: Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.039_28 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+/// Returns the contrast ratio between two colors, from 1 to 21.
+fn contrast(a: Color, b: Color) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Mixes the color with another one by the given factor.
+pub(crate) fn mix(color: Color, other: Color, factor: f32) -> Color {
+    Color {
+        r: color.r + (other.r - color.r) * factor,
+        g: color.g + (other.g - color.g) * factor,
+        b: color.b + (other.b - color.b) * factor,
+        a: color.a + (other.a - color.a) * factor,
+    }
+}
@@ -0,0 +1,96 @@
+//! Animate the switch between two themes.
+use iced_native::time::{Duration, Instant};
+
+use super::Theme;
+
+/// A transition fading from one [`Theme`](Theme) to another over time.
+///
+/// The application returns the theme of the transition at the current instant
+/// from its `theme` method and redraws on every frame while the transition
+/// [`is_running`](Transition::is_running), e.g. by subscribing to
+/// `iced::window::frames()`. Since all widgets read their colors from the theme
+/// of the application, they fade along without further changes.
+///
+/// Override hooks registered by a [`ThemeBuilder`](super::ThemeBuilder) only
+/// apply to the exact palette of the built theme, so they take effect again
+/// once the transition finished.
+///
+/// # Example
+/// ```
+/// # use iced_aw::style::{theme::Transition, Theme};
+/// # use iced_native::time::{Duration, Instant};
+/// #
+/// let transition = Transition::new(Theme::LIGHT, Theme::DARK, Duration::from_millis(300));
+///
+/// let theme: iced_style::Theme = transition.theme(Instant::now()).into();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    /// The [`Theme`](Theme) the transition starts from.
+    from: Theme,
+    /// The [`Theme`](Theme) the transition ends at.
+    to: Theme,
+    /// The instant the transition started.
+    started: Instant,
+    /// The duration of the transition.
+    duration: Duration,
+}
+
+impl Transition {
+    /// Starts a new [`Transition`](Transition) from one [`Theme`](Theme) to
+    /// another, taking the given duration.
+    #[must_use]
+    pub fn new(from: Theme, to: Theme, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            started: Instant::now(),
+            duration,
+        }
+    }
+
+    /// Starts a new [`Transition`](Transition) from the [`Theme`](Theme)
+    /// currently shown by this transition to another one.
+    ///
+    /// Reversing a running transition this way continues smoothly from its
+    /// current colors.
+    #[must_use]
+    pub fn to(&self, to: Theme, duration: Duration) -> Self {
+        Self::new(self.theme(Instant::now()), to, duration)
+    }
+
+    /// Returns the progress of the [`Transition`](Transition) at the given
+    /// instant, from 0 at its start to 1 at its end.
+    #[must_use]
+    pub fn progress(&self, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        let elapsed = now.saturating_duration_since(self.started);
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns whether the [`Transition`](Transition) is still running at the
+    /// given instant.
+    #[must_use]
+    pub fn is_running(&self, now: Instant) -> bool {
+        self.progress(now) < 1.0
+    }
+
+    /// Returns the [`Theme`](Theme) of the [`Transition`](Transition) at the
+    /// given instant, easing in and out of the fade.
+    #[must_use]
+    pub fn theme(&self, now: Instant) -> Theme {
+        let progress = self.progress(now);
+        let eased = progress * progress * (3.0 - 2.0 * progress);
+
+        self.from.interpolate(&self.to, eased)
+    }
+
+    /// Returns the [`Theme`](Theme) the [`Transition`](Transition) ends at.
+    #[must_use]
+    pub fn target(&self) -> Theme {
+        self.to
+    }
+}
@@ -3,12 +3,19 @@
 //! *This API requires the following crate features to be activated: `time_picker`*
 use iced_native::Theme;
 
+use super::theme::builder::customize;
+
 use iced_native::{Background, Color};
 
 /// The appearance of a [`TimePicker`](crate::native::TimePicker).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// The background of the [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`TimePicker`](crate::native::TimePicker).
@@ -18,25 +25,31 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub border_color: Color,
 
     /// The text color of the [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub text_color: Color,
 
     /// The color of the clock numbers of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub clock_number_color: Color,
 
     /// The background of the clock numbers of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub clock_number_background: Color,
 
     /// The color of the dots on the clock of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub clock_dots_color: Color,
 
     /// The color of the hands of the clock of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
     pub clock_hand_color: Color,
 
     /// The with of the hands of the clock of the
@@ -62,6 +75,18 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`TimePicker`](crate::native::TimePicker).
     fn focused(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`TimePicker`](crate::native::TimePicker) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`TimePicker`](crate::native::TimePicker) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of the [`TimePicker`](crate::native::TimePicker)
@@ -75,7 +100,7 @@ impl StyleSheet for Theme {
         let palette = self.extended_palette();
         let foreground = self.palette();
 
-        Appearance {
+        let appearance = Appearance {
             background: palette.background.base.color.into(),
             border_radius: 15.0,
             border_width: 1.0,
@@ -83,10 +108,12 @@ impl StyleSheet for Theme {
             text_color: foreground.text,
             clock_number_color: foreground.text,
             clock_number_background: palette.background.base.color,
-            clock_dots_color: [0.87, 0.87, 0.87].into(),
-            clock_hand_color: [0.87, 0.87, 0.87].into(),
+            clock_dots_color: palette.background.weak.color,
+            clock_hand_color: palette.background.weak.color,
             clock_hand_width: 3.0,
-        }
+        };
+
+        customize(self, |hooks| hooks.time_picker, appearance)
     }
 
     fn selected(&self, style: Self::Style) -> Appearance {
@@ -110,8 +137,10 @@ impl StyleSheet for Theme {
     }
 
     fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
         Appearance {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            border_color: palette.background.strong.color,
             ..self.active(style)
         }
     }
@@ -0,0 +1,174 @@
+//! Show timed notifications stacked in a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: toast*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{
+    colors,
+    patch::Patched,
+    theme::builder::{customize, mix},
+};
+
+/// The status of a [`Toast`](crate::native::toast::Toast), selecting its
+/// accent color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    /// A neutral information.
+    #[default]
+    Info,
+    /// A finished action.
+    Success,
+    /// Something that needs attention.
+    Warning,
+    /// A failed action.
+    Error,
+}
+
+/// The appearance of a [`Toast`](crate::native::toast::Toast).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the [`Toast`](crate::native::toast::Toast).
+    pub border_width: f32,
+
+    /// The border color of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the title.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub title_color: Color,
+
+    /// The text color of the body.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub body_color: Color,
+
+    /// The color of the close icon.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub close_color: Color,
+
+    /// The color of the indicator of the remaining time.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub progress_color: Color,
+
+    /// The color of the track behind the indicator of the remaining time.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub progress_background: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 6.0.into(),
+            border_width: 1.0,
+            border_color: colors::INFO,
+            title_color: Color::BLACK,
+            body_color: Color::from_rgb(0.3, 0.3, 0.3),
+            close_color: Color::from_rgb(0.3, 0.3, 0.3),
+            progress_color: colors::INFO,
+            progress_background: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// The appearance of a [`Toast`](crate::native::toast::Toast).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Toast`](crate::native::toast::Toast) of
+    /// the given [`Status`](Status).
+    fn active(&self, style: Self::Style, status: Status) -> Appearance;
+
+    /// The appearance when the [`Toast`](crate::native::toast::Toast) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+
+    /// The appearance when the [`Toast`](crate::native::toast::Toast) is pressed.
+    fn pressed(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+
+    /// The appearance when the [`Toast`](crate::native::toast::Toast) is focused.
+    fn focused(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+
+    /// The appearance when the [`Toast`](crate::native::toast::Toast) is disabled.
+    fn disabled(&self, style: Self::Style, status: Status) -> Appearance {
+        self.active(style, status)
+    }
+}
+
+/// The default appearance of a [`Toast`](crate::native::toast::Toast).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ToastStyles {
+    #[default]
+    Default,
+    Patched(Patched<ToastStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = ToastStyles;
+
+    fn active(&self, style: Self::Style, status: Status) -> Appearance {
+        if let ToastStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base, status));
+        }
+
+        let palette = self.extended_palette();
+        let accent = match status {
+            Status::Info => palette.primary.base.color,
+            Status::Success => palette.success.base.color,
+            Status::Warning => colors::WARNING,
+            Status::Error => palette.danger.base.color,
+        };
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: accent,
+            title_color: palette.background.base.text,
+            body_color: mix(
+                palette.background.base.text,
+                palette.background.base.color,
+                0.25,
+            ),
+            close_color: palette.background.strong.color,
+            progress_color: accent,
+            progress_background: palette.background.weak.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.toast, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style, status: Status) -> Appearance {
+        let active = self.active(style, status);
+
+        Appearance {
+            border_width: active.border_width + 1.0,
+            close_color: active.title_color,
+            ..active
+        }
+    }
+}
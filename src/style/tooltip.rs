@@ -0,0 +1,113 @@
+//! Show rich content in a tooltip while hovering a widget.
+//!
+//! *This API requires the following crate features to be activated: tooltip*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Tooltip`](crate::native::tooltip::Tooltip).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the bubble of the
+    /// [`Tooltip`](crate::native::tooltip::Tooltip).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the bubble.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the bubble.
+    pub border_width: f32,
+
+    /// The border color of the bubble.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The default text color of the content of the bubble.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.2, 0.2, 0.2).into(),
+            border_radius: 4.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Tooltip`](crate::native::tooltip::Tooltip).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Tooltip`](crate::native::tooltip::Tooltip).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Tooltip`](crate::native::tooltip::Tooltip) is
+    /// hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Tooltip`](crate::native::tooltip::Tooltip) is
+    /// pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Tooltip`](crate::native::tooltip::Tooltip) is
+    /// focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Tooltip`](crate::native::tooltip::Tooltip) is
+    /// disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Tooltip`](crate::native::tooltip::Tooltip).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TooltipStyles {
+    #[default]
+    Default,
+    Patched(Patched<TooltipStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = TooltipStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let TooltipStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        // Tooltips stand out by inverting the colors of the background
+        let appearance = Appearance {
+            background: palette.background.base.text.into(),
+            text_color: palette.background.base.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.tooltip, appearance)
+    }
+}
@@ -0,0 +1,192 @@
+//! Guide the user through the widgets of an application with coach marks.
+//!
+//! *This API requires the following crate features to be activated: tour*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`Tour`](crate::native::tour::Tour).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The backdrop dimming everything but the target.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub backdrop: Background,
+
+    /// The border width of the highlight around the target.
+    pub highlight_border_width: f32,
+
+    /// The border color of the highlight around the target.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub highlight_border_color: Color,
+
+    /// The border radius of the highlight around the target.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub highlight_border_radius: BorderRadius,
+
+    /// The background of the card.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub card_background: Background,
+
+    /// The border radius of the card.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub card_border_radius: BorderRadius,
+
+    /// The border width of the card.
+    pub card_border_width: f32,
+
+    /// The border color of the card.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub card_border_color: Color,
+
+    /// The color of the title.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub title_color: Color,
+
+    /// The color of the description.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The color of the counter of the steps.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub counter_color: Color,
+
+    /// The background of the next button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub button_background: Background,
+
+    /// The background of the hovered next button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub button_hovered_background: Background,
+
+    /// The text color of the next button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub button_text_color: Color,
+
+    /// The border radius of the buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub button_border_radius: BorderRadius,
+
+    /// The text color of the back and the skip button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub secondary_button_text_color: Color,
+
+    /// The background of the hovered back and skip button.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub secondary_button_hovered_background: Background,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            backdrop: Color::from_rgba(0.0, 0.0, 0.0, 0.5).into(),
+            highlight_border_width: 2.0,
+            highlight_border_color: [0.0, 0.4, 0.8].into(),
+            highlight_border_radius: 4.0.into(),
+            card_background: Color::WHITE.into(),
+            card_border_radius: 8.0.into(),
+            card_border_width: 0.0,
+            card_border_color: Color::TRANSPARENT,
+            title_color: Color::BLACK,
+            text_color: [0.2, 0.2, 0.2].into(),
+            counter_color: [0.5, 0.5, 0.5].into(),
+            button_background: Background::Color([0.0, 0.4, 0.8].into()),
+            button_hovered_background: Background::Color([0.0, 0.3, 0.6].into()),
+            button_text_color: Color::WHITE,
+            button_border_radius: 4.0.into(),
+            secondary_button_text_color: [0.0, 0.4, 0.8].into(),
+            secondary_button_hovered_background: Background::Color([0.93, 0.93, 0.93].into()),
+        }
+    }
+}
+
+/// The appearance of a [`Tour`](crate::native::tour::Tour).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Tour`](crate::native::tour::Tour).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`Tour`](crate::native::tour::Tour) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Tour`](crate::native::tour::Tour) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Tour`](crate::native::tour::Tour) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Tour`](crate::native::tour::Tour) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Tour`](crate::native::tour::Tour).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TourStyles {
+    #[default]
+    Default,
+    Patched(Patched<TourStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = TourStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let TourStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            highlight_border_color: palette.primary.base.color,
+            card_background: palette.background.base.color.into(),
+            title_color: palette.background.base.text,
+            text_color: palette.background.base.text,
+            counter_color: palette.background.strong.color,
+            button_background: palette.primary.base.color.into(),
+            button_hovered_background: palette.primary.strong.color.into(),
+            button_text_color: palette.primary.base.text,
+            secondary_button_text_color: palette.primary.base.color,
+            secondary_button_hovered_background: palette.background.weak.color.into(),
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.tour, appearance)
+    }
+}
@@ -0,0 +1,179 @@
+//! Move items between two lists.
+//!
+//! *This API requires the following crate features to be activated: transfer_list*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`TransferList`](crate::native::transfer_list::TransferList).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the lists.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the lists and the buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the lists.
+    pub border_width: f32,
+
+    /// The border color of the lists.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The text color of the titles above the lists.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub title_color: Color,
+
+    /// The text color of the items.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub text_color: Color,
+
+    /// The background of the hovered item.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The background of the selected items.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub selected_background: Background,
+
+    /// The text color of the selected items.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub selected_text_color: Color,
+
+    /// The background of the buttons moving the items.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub button_background: Background,
+
+    /// The color of the arrows on the buttons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub button_icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            title_color: Color::BLACK,
+            text_color: Color::BLACK,
+            hovered_background: Background::Color([0.87, 0.87, 0.87].into()),
+            selected_background: Background::Color([0.2, 0.4, 0.8].into()),
+            selected_text_color: Color::WHITE,
+            button_background: Background::Color([0.87, 0.87, 0.87].into()),
+            button_icon_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`TransferList`](crate::native::transfer_list::TransferList).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`TransferList`](crate::native::transfer_list::TransferList).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered button.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a button with no items to move.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`TransferList`](crate::native::transfer_list::TransferList) is pressed.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`TransferList`](crate::native::transfer_list::TransferList) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`TransferList`](crate::native::transfer_list::TransferList).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TransferListStyles {
+    #[default]
+    Default,
+    Patched(Patched<TransferListStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = TransferListStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let TransferListStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            title_color: palette.background.base.text,
+            text_color: palette.background.base.text,
+            hovered_background: palette.background.weak.color.into(),
+            selected_background: palette.primary.base.color.into(),
+            selected_text_color: palette.primary.base.text,
+            button_background: palette.background.weak.color.into(),
+            button_icon_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.transfer_list, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            button_background: palette.background.strong.color.into(),
+            ..active
+        }
+    }
+
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            button_icon_color: Color {
+                a: active.button_icon_color.a * 0.3,
+                ..active.button_icon_color
+            },
+            ..active
+        }
+    }
+}
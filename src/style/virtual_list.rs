@@ -0,0 +1,157 @@
+//! Scroll through long lists building only the visible rows.
+//!
+//! *This API requires the following crate features to be activated: virtual_list*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the virtual list.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the virtual list.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the virtual list.
+    pub border_width: f32,
+
+    /// The border color of the virtual list.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The background of the scrollbar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub scrollbar_background: Background,
+
+    /// The border radius of the scrollbar and of its scroller.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub scrollbar_border_radius: BorderRadius,
+
+    /// The color of the scroller.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub scroller_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            scrollbar_background: Color::TRANSPARENT.into(),
+            scrollbar_border_radius: 4.0.into(),
+            scroller_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a
+    /// [`VirtualList`](crate::native::virtual_list::VirtualList).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance while the cursor is over the scrollbar of a
+    /// [`VirtualList`](crate::native::virtual_list::VirtualList).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance while the scroller of a
+    /// [`VirtualList`](crate::native::virtual_list::VirtualList) is dragged.
+    fn dragging(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the
+    /// [`VirtualList`](crate::native::virtual_list::VirtualList) is pressed,
+    /// which is while its scrollbar is dragged. This is the
+    /// [`dragging`](StyleSheet::dragging) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragging(style)
+    }
+
+    /// The appearance when the
+    /// [`VirtualList`](crate::native::virtual_list::VirtualList) is focused.
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the
+    /// [`VirtualList`](crate::native::virtual_list::VirtualList) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a
+/// [`VirtualList`](crate::native::virtual_list::VirtualList).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum VirtualListStyles {
+    #[default]
+    Default,
+    Patched(Patched<VirtualListStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = VirtualListStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let VirtualListStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            scroller_color: palette.background.strong.color,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.virtual_list, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let active = self.active(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            scrollbar_background: palette.background.weak.color.into(),
+            scroller_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+
+    fn dragging(&self, style: Self::Style) -> Appearance {
+        let hovered = self.hovered(style);
+        let palette = self.extended_palette();
+
+        Appearance {
+            scroller_color: palette.primary.base.color,
+            ..hovered
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Set two values at once by dragging a handle inside a rectangle.
+//!
+//! *This API requires the following crate features to be activated: xy_pad*
+use iced_native::{renderer::BorderRadius, Background, Color};
+use iced_style::Theme;
+
+use super::{patch::Patched, theme::builder::customize};
+
+/// The appearance of a [`XYPad`](crate::native::xy_pad::XYPad).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Appearance {
+    /// The background of the pad.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the pad.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::serialization::border_radius")
+    )]
+    pub border_radius: BorderRadius,
+
+    /// The border width of the pad.
+    pub border_width: f32,
+
+    /// The border color of the pad.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub border_color: Color,
+
+    /// The color of the crosshair lines through the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub crosshair_color: Color,
+
+    /// The width of the crosshair lines through the handle.
+    pub crosshair_width: f32,
+
+    /// The radius of the handle.
+    pub handle_radius: f32,
+
+    /// The color of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_color: Color,
+
+    /// The border width of the handle.
+    pub handle_border_width: f32,
+
+    /// The border color of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serialization::color"))]
+    pub handle_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.95, 0.95, 0.95).into(),
+            border_radius: 4.0.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            crosshair_color: [0.6, 0.6, 0.6].into(),
+            crosshair_width: 1.0,
+            handle_radius: 8.0,
+            handle_color: Color::WHITE,
+            handle_border_width: 1.0,
+            handle_border_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`XYPad`](crate::native::xy_pad::XYPad).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Copy;
+    /// The normal appearance of a [`XYPad`](crate::native::xy_pad::XYPad).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`XYPad`](crate::native::xy_pad::XYPad).
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`XYPad`](crate::native::xy_pad::XYPad) whose
+    /// handle is dragged.
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance of a focused [`XYPad`](crate::native::xy_pad::XYPad).
+    fn focused(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance when the [`XYPad`](crate::native::xy_pad::XYPad) is pressed,
+    /// which is the [`dragged`](StyleSheet::dragged) appearance by default.
+    fn pressed(&self, style: Self::Style) -> Appearance {
+        self.dragged(style)
+    }
+
+    /// The appearance when the [`XYPad`](crate::native::xy_pad::XYPad) is disabled.
+    fn disabled(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`XYPad`](crate::native::xy_pad::XYPad).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum XYPadStyles {
+    #[default]
+    Default,
+    Patched(Patched<XYPadStyles, Appearance>),
+}
+
+impl StyleSheet for Theme {
+    type Style = XYPadStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        if let XYPadStyles::Patched(patched) = style {
+            return patched.apply(self.active(*patched.base));
+        }
+
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            crosshair_color: palette.background.strong.color,
+            handle_color: palette.background.base.color,
+            handle_border_color: palette.primary.base.color,
+            handle_border_width: 2.0,
+            ..Appearance::default()
+        };
+
+        customize(self, |hooks| hooks.xy_pad, appearance)
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            handle_color: palette.primary.weak.color,
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            crosshair_color: palette.primary.base.color,
+            handle_color: palette.primary.base.color,
+            ..active
+        }
+    }
+
+    fn focused(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            handle_border_color: palette.primary.strong.color,
+            handle_border_width: active.handle_border_width + 1.0,
+            ..active
+        }
+    }
+}
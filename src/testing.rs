@@ -0,0 +1,344 @@
+//! Test widgets without a window.
+//!
+//! A [`Harness`](Harness) lays out an [`Element`](iced_native::Element) with the
+//! headless [`Null`](iced_native::renderer::Null) renderer, feeds it synthetic
+//! events and collects the messages it produces, so widgets and the views of
+//! applications can be unit-tested.
+//!
+//! # Example
+//! ```
+//! # use iced_aw::testing::Harness;
+//! # use iced_native::{widget::{Button, Text}, Length, Point, Size};
+//! #
+//! let button = Button::new(Text::new("Press"))
+//!     .width(Length::Fixed(100.0))
+//!     .height(Length::Fixed(40.0))
+//!     .on_press("pressed");
+//!
+//! let mut harness = Harness::new(button, Size::new(400.0, 300.0));
+//! harness.click(Point::new(50.0, 20.0));
+//!
+//! assert_eq!(harness.take_messages(), vec!["pressed"]);
+//! ```
+use iced_native::{
+    clipboard, event, keyboard,
+    layout::{Limits, Node},
+    mouse,
+    renderer::{self, Null},
+    time::Instant,
+    touch,
+    widget::Tree,
+    window, Color, Element, Event, Layout, Point, Rectangle, Shell, Size, Vector,
+};
+use iced_style::Theme;
+
+/// A headless host for an [`Element`](iced_native::Element) receiving
+/// synthetic events.
+#[allow(missing_debug_implementations)]
+pub struct Harness<'a, Message> {
+    /// The element under test.
+    element: Element<'a, Message, Null>,
+    /// The widget tree of the element.
+    tree: Tree,
+    /// The headless renderer.
+    renderer: Null,
+    /// The size of the simulated window.
+    size: Size,
+    /// The layout of the element.
+    layout: Node,
+    /// The current position of the cursor.
+    cursor_position: Point,
+    /// The messages produced by the element.
+    messages: Vec<Message>,
+}
+
+impl<'a, Message> Harness<'a, Message> {
+    /// Creates a new [`Harness`](Harness) laying out the element in a
+    /// simulated window of the given size.
+    pub fn new<E>(element: E, size: Size) -> Self
+    where
+        E: Into<Element<'a, Message, Null>>,
+    {
+        let element = element.into();
+        let tree = Tree::new(&element);
+        let renderer = Null::new();
+        let layout = element
+            .as_widget()
+            .layout(&renderer, &Limits::new(Size::ZERO, size));
+
+        Harness {
+            element,
+            tree,
+            renderer,
+            size,
+            layout,
+            cursor_position: Point::new(-1.0, -1.0),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Lays the element out again in a simulated window of the given size.
+    pub fn resize(&mut self, size: Size) {
+        self.size = size;
+        self.layout = self
+            .element
+            .as_widget()
+            .layout(&self.renderer, &Limits::new(Size::ZERO, size));
+    }
+
+    /// Returns the [`Layout`](iced_native::Layout) of the element.
+    #[must_use]
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.layout)
+    }
+
+    /// Returns the bounds of the element.
+    #[must_use]
+    pub fn bounds(&self) -> Rectangle {
+        self.layout.bounds()
+    }
+
+    /// Returns the bounds of the nested layout reached by following the given
+    /// child indices from the layout of the element.
+    #[must_use]
+    pub fn bounds_at(&self, path: &[usize]) -> Option<Rectangle> {
+        path.iter()
+            .try_fold(self.layout(), |layout, &index| layout.children().nth(index))
+            .map(|layout| layout.bounds())
+    }
+
+    /// Returns the messages produced by the element so far.
+    #[must_use]
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Takes the messages produced by the element so far.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Feeds the event to the overlay and then to the element, like the
+    /// runtime of iced, and returns whether it was captured.
+    pub fn event(&mut self, event: Event) -> event::Status {
+        if let Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(
+            touch::Event::FingerPressed { position, .. }
+            | touch::Event::FingerMoved { position, .. }
+            | touch::Event::FingerLifted { position, .. },
+        ) = event
+        {
+            self.cursor_position = position;
+        }
+
+        let mut clipboard = clipboard::Null;
+        let mut shell = Shell::new(&mut self.messages);
+        let mut base_cursor = self.cursor_position;
+
+        let overlay_status = if let Some(mut overlay) = self.element.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+        ) {
+            let node = overlay.layout(&self.renderer, self.size, Vector::ZERO);
+            let status = overlay.on_event(
+                event.clone(),
+                Layout::new(&node),
+                self.cursor_position,
+                &self.renderer,
+                &mut clipboard,
+                &mut shell,
+            );
+
+            // The overlay hides the element below it from the cursor
+            if overlay.is_over(Layout::new(&node), self.cursor_position) {
+                base_cursor = Point::new(-1.0, -1.0);
+            }
+
+            status
+        } else {
+            event::Status::Ignored
+        };
+
+        let status = if overlay_status == event::Status::Captured {
+            overlay_status
+        } else {
+            self.element.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.layout),
+                base_cursor,
+                &self.renderer,
+                &mut clipboard,
+                &mut shell,
+            )
+        };
+
+        if shell.is_layout_invalid() {
+            self.layout = self
+                .element
+                .as_widget()
+                .layout(&self.renderer, &Limits::new(Size::ZERO, self.size));
+        }
+
+        status
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_cursor(&mut self, position: Point) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: Point) -> event::Status {
+        let _ = self.move_cursor(position);
+        let pressed = self.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        let released = self.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        pressed.merge(released)
+    }
+
+    /// Taps the given position with a finger.
+    pub fn tap(&mut self, position: Point) -> event::Status {
+        let id = touch::Finger(0);
+        let pressed = self.event(Event::Touch(touch::Event::FingerPressed { id, position }));
+        let lifted = self.event(Event::Touch(touch::Event::FingerLifted { id, position }));
+
+        pressed.merge(lifted)
+    }
+
+    /// Presses and releases the key with the given modifiers.
+    pub fn press_key(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        let pressed = self.event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }));
+        let released = self.event(Event::Keyboard(keyboard::Event::KeyReleased {
+            key_code,
+            modifiers,
+        }));
+
+        pressed.merge(released)
+    }
+
+    /// Types the characters of the text.
+    pub fn type_text(&mut self, text: &str) -> event::Status {
+        text.chars().fold(event::Status::Ignored, |status, c| {
+            status.merge(self.event(Event::Keyboard(keyboard::Event::CharacterReceived(c))))
+        })
+    }
+
+    /// Sends a redraw request of the given instant, advancing animations.
+    pub fn redraw(&mut self, now: Instant) -> event::Status {
+        self.event(Event::Window(window::Event::RedrawRequested(now)))
+    }
+
+    /// Draws the element and its overlay with the headless renderer.
+    pub fn draw(&mut self) {
+        let theme = Theme::default();
+        let style = renderer::Style {
+            text_color: Color::BLACK,
+        };
+
+        self.element.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            &theme,
+            &style,
+            Layout::new(&self.layout),
+            self.cursor_position,
+            &self.layout.bounds(),
+        );
+
+        let mut renderer = Null::new();
+        if let Some(overlay) = self.element.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+        ) {
+            let node = overlay.layout(&self.renderer, self.size, Vector::ZERO);
+            overlay.draw(
+                &mut renderer,
+                &theme,
+                &style,
+                Layout::new(&node),
+                self.cursor_position,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{
+        keyboard,
+        widget::{Button, Text},
+        Length, Point, Rectangle, Size,
+    };
+
+    use super::Harness;
+
+    /// Creates a harness with a button producing a message when pressed.
+    fn button() -> Harness<'static, &'static str> {
+        let button = Button::new(Text::new("Press"))
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(40.0))
+            .on_press("pressed");
+
+        Harness::new(button, Size::new(400.0, 300.0))
+    }
+
+    #[test]
+    fn click() {
+        let mut harness = button();
+
+        let _ = harness.click(Point::new(200.0, 200.0));
+        assert!(harness.messages().is_empty());
+
+        let _ = harness.click(Point::new(50.0, 20.0));
+        assert_eq!(harness.take_messages(), vec!["pressed"]);
+        assert!(harness.messages().is_empty());
+
+        let _ = harness.tap(Point::new(50.0, 20.0));
+        assert_eq!(harness.take_messages(), vec!["pressed"]);
+
+        let _ = harness.press_key(keyboard::KeyCode::Enter, keyboard::Modifiers::default());
+        assert!(harness.messages().is_empty());
+
+        harness.draw();
+    }
+
+    #[test]
+    fn bounds() {
+        let harness = button();
+
+        assert_eq!(
+            harness.bounds(),
+            Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0))
+        );
+        assert_eq!(harness.bounds_at(&[]), Some(harness.bounds()));
+        assert!(harness.bounds_at(&[0]).is_some());
+        assert_eq!(harness.bounds_at(&[1]), None);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn resize() {
+        let mut harness = Harness::new(
+            Button::new(Text::new("Fill")).width(Length::Fill),
+            Size::new(400.0, 300.0),
+        );
+        assert_eq!(harness.bounds().width, 400.0);
+
+        harness.resize(Size::new(200.0, 300.0));
+        assert_eq!(harness.bounds().width, 200.0);
+    }
+}